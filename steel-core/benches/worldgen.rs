@@ -578,6 +578,8 @@ fn build_feature_fixture_at(
         generation_settings,
         view_distance: 10,
         simulation_distance: 10,
+        entity_broadcast_range_percentage:
+            steel_core::entity::DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE,
         compression: None,
         is_flat: false,
         sea_level: output.sea_level,
@@ -847,6 +849,8 @@ fn build_concurrent_feature_fixture(
         generation_settings,
         view_distance: 10,
         simulation_distance: 10,
+        entity_broadcast_range_percentage:
+            steel_core::entity::DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE,
         compression: None,
         is_flat: false,
         sea_level: output.sea_level,
@@ -936,6 +940,8 @@ fn build_concurrent_full_pipeline_fixture(
         generation_settings,
         view_distance: 10,
         simulation_distance: 10,
+        entity_broadcast_range_percentage:
+            steel_core::entity::DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE,
         compression: None,
         is_flat: false,
         sea_level: output.sea_level,
@@ -1031,6 +1037,8 @@ fn build_concurrent_light_fixture(
         generation_settings,
         view_distance: 10,
         simulation_distance: 10,
+        entity_broadcast_range_percentage:
+            steel_core::entity::DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE,
         compression: None,
         is_flat: false,
         sea_level: output.sea_level,
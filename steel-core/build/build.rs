@@ -7,7 +7,7 @@
 use heck::ToShoutySnakeCase;
 use proc_macro2::Span;
 use serde::Deserialize;
-use std::{env, fs, path::Path};
+use std::{env, fs, path::Path, process::Command};
 use syn::Ident;
 
 mod blocks;
@@ -66,12 +66,26 @@ pub fn main() {
         entities::build(&classes.entities),
     );
 
+    emit_rustc_version();
+
     println!("cargo:rerun-if-changed={manifest_dir}/build/classes.json");
     println!("cargo:rerun-if-changed={manifest_dir}/src/behavior/blocks");
     println!("cargo:rerun-if-changed={manifest_dir}/src/behavior/items");
     println!("cargo:rerun-if-changed={manifest_dir}/src/entity/entities");
 }
 
+/// Exposes `rustc --version` as `STEEL_RUSTC_VERSION`, which native plugins
+/// must have been built with.
+fn emit_rustc_version() {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let output = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .expect("Failed to run rustc --version");
+    let version = String::from_utf8(output.stdout).expect("rustc version is not UTF-8");
+    println!("cargo:rustc-env=STEEL_RUSTC_VERSION={}", version.trim());
+}
+
 /// Items use `SCREAMING_SNAKE_CASE` statics (`vanilla_items::STONE`)
 #[must_use]
 fn to_item_ident(name: &str) -> Ident {
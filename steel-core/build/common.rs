@@ -23,6 +23,9 @@ pub(crate) fn path_ends_with(path: &syn::Path, name: &str) -> bool {
 pub(crate) enum JsonArgKind {
     /// Raw JSON value → token literal (handles numbers, strings, bools)
     Value,
+    /// Flattened `IntProvider` → `IntProvider::Constant` from `{key}_value`, or
+    /// `IntProvider::Uniform` from `{key}_min_inclusive` and `{key}_max_inclusive`.
+    IntProvider,
     /// JSON string → `module::IDENT`. Stores the module name.
     Registry(String),
     /// JSON string → `EnumType::Variant` (`PascalCase`).
@@ -99,6 +102,8 @@ pub(crate) fn parse_json_arg(field: &syn::Field) -> Option<JsonArgField> {
         meta.parse_nested_meta(|meta| {
             if meta.path.is_ident("value") {
                 kind = Some(JsonArgKind::Value);
+            } else if meta.path.is_ident("int_provider") {
+                kind = Some(JsonArgKind::IntProvider);
             } else if meta.path.is_ident("r#enum") || meta.path.is_ident("enum") {
                 let value = meta.value()?;
                 let lit: syn::LitStr = value.parse()?;
@@ -125,7 +130,7 @@ pub(crate) fn parse_json_arg(field: &syn::Field) -> Option<JsonArgField> {
                 assert!(
                     KNOWN_REGISTRIES.contains(&name.as_str()),
                     "Unknown json_arg attribute '{name}' on field '{field_name}'. \
-                     Expected: value, enum, ref, json, optional, or a registry module ({}).",
+                     Expected: value, int_provider, enum, ref, json, optional, or a registry module ({}).",
                     KNOWN_REGISTRIES.join(", ")
                 );
                 kind = Some(JsonArgKind::Registry(name));
@@ -145,7 +150,7 @@ pub(crate) fn parse_json_arg(field: &syn::Field) -> Option<JsonArgField> {
     };
 
     let kind = kind.unwrap_or_else(|| {
-        panic!("json_arg on field '{field_name}' must specify a kind (value, enum, or a registry module name)")
+        panic!("json_arg on field '{field_name}' must specify a kind (value, int_provider, enum, or a registry module name)")
     });
 
     Some(JsonArgField {
@@ -228,6 +233,31 @@ pub(crate) fn generate_arg(
             let value = get_json_value(extra, entry_name, json_key);
             json_value_to_tokens(value, entry_name, json_key)
         }
+        JsonArgKind::IntProvider => {
+            if let Some(value) = extra.get(&format!("{json_key}_value")) {
+                let value = json_value_to_tokens(value, entry_name, json_key);
+                quote! { steel_utils::value_providers::IntProvider::Constant(#value) }
+            } else {
+                let min_key = format!("{json_key}_min_inclusive");
+                let max_key = format!("{json_key}_max_inclusive");
+                let min = json_value_to_tokens(
+                    get_json_value(extra, entry_name, &min_key),
+                    entry_name,
+                    &min_key,
+                );
+                let max = json_value_to_tokens(
+                    get_json_value(extra, entry_name, &max_key),
+                    entry_name,
+                    &max_key,
+                );
+                quote! {
+                    steel_utils::value_providers::IntProvider::Uniform {
+                        min_inclusive: #min,
+                        max_inclusive: #max,
+                    }
+                }
+            }
+        }
         JsonArgKind::Registry(module) => {
             let name = get_json_str(extra, entry_name, json_key);
             if module == "vanilla_items" {
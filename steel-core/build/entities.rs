@@ -58,7 +58,7 @@ pub fn build(entities: &[EntityClass]) -> String {
                 JsonArgKind::Registry(module) => {
                     registry_modules_used.insert(module.clone());
                 }
-                JsonArgKind::Value => {}
+                JsonArgKind::Value | JsonArgKind::IntProvider => {}
             }
         }
 
@@ -54,7 +54,7 @@ pub fn build(items: &[ItemClass]) -> String {
                 JsonArgKind::Registry(module) => {
                     registry_modules_used.insert(module.clone());
                 }
-                JsonArgKind::Value => {}
+                JsonArgKind::Value | JsonArgKind::IntProvider => {}
             }
         }
 
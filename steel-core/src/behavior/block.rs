@@ -3,6 +3,7 @@
 use std::sync::{Arc, Weak};
 
 use glam::DVec3;
+use steel_registry::DyeColor;
 use steel_registry::blocks::BlockRef;
 use steel_registry::blocks::block_state_ext::BlockStateExt;
 use steel_registry::blocks::properties::{BlockStateProperties, Direction};
@@ -632,6 +633,27 @@ pub trait BlockBehavior: Send + Sync {
         state
     }
 
+    /// Called after this block's drops are spawned, for extra side effects
+    /// such as experience orbs.
+    ///
+    /// Vanilla parity: `BlockBehaviour.spawnAfterBreak(BlockState, ServerLevel,
+    /// BlockPos, ItemStack, boolean)`. `tool` is empty when the block was not
+    /// broken by a player.
+    #[expect(
+        unused_variables,
+        reason = "default trait implementation ignores all params"
+    )]
+    fn spawn_after_break(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        tool: &ItemStack,
+        drop_experience: bool,
+    ) {
+        // Default: no-op
+    }
+
     /// Called after this block is removed from the world, to affect neighbors.
     ///
     /// This is used for things like rails notifying neighbors when removed.
@@ -1259,6 +1281,13 @@ pub trait BlockBehavior: Send + Sync {
     fn as_bonemealable(&self) -> Option<&dyn Bonemealable> {
         None
     }
+
+    /// Returns the color this block tints a beacon beam passing through it.
+    ///
+    /// Vanilla equivalent: `BeaconBeamBlock.getColor`.
+    fn get_beacon_beam_color(&self) -> Option<DyeColor> {
+        None
+    }
 }
 
 /// Default block behavior that returns the block's default state for placement.
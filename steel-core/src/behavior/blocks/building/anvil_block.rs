@@ -0,0 +1,130 @@
+//! Anvil block behavior.
+
+use std::sync::Arc;
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::BlockStateProperties;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::{level_events, vanilla_blocks};
+use steel_utils::types::UpdateFlags;
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::behavior::{BlockBehavior, BlockPlaceContext};
+use crate::player::Player;
+use crate::world::World;
+
+/// Chance that taking a result out of an anvil damages it.
+const DAMAGE_CHANCE: f32 = 0.12;
+
+/// Vanilla `AnvilBlock`, shared by the three damage stages.
+///
+/// The repair screen and falling are not implemented yet; `use_anvil`,
+/// `on_land` and `on_broken_after_fall` are the hooks the anvil menu and
+/// falling block entity call.
+#[block_behavior]
+pub struct AnvilBlock {
+    block: BlockRef,
+}
+
+impl AnvilBlock {
+    /// Creates an anvil behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+
+    /// Vanilla `AnvilBlock.damage`: the next damage stage of `state`, or
+    /// `None` if the anvil breaks.
+    #[must_use]
+    pub fn damage(state: BlockStateId) -> Option<BlockStateId> {
+        let next = if state.get_block() == &vanilla_blocks::ANVIL {
+            &vanilla_blocks::CHIPPED_ANVIL
+        } else if state.get_block() == &vanilla_blocks::CHIPPED_ANVIL {
+            &vanilla_blocks::DAMAGED_ANVIL
+        } else {
+            return None;
+        };
+        Some(next.default_state().set_value(
+            &BlockStateProperties::HORIZONTAL_FACING,
+            state.get_value(&BlockStateProperties::HORIZONTAL_FACING),
+        ))
+    }
+
+    /// Vanilla `AnvilMenu.onTake`: may damage the anvil at `pos` after
+    /// `player` took a result, and plays the use or break sound.
+    pub fn use_anvil(world: &Arc<World>, pos: BlockPos, player: &Player) {
+        let state = world.get_block_state(pos);
+        if !player.has_infinite_materials()
+            && state.get_block().has_tag(&BlockTag::ANVIL)
+            && rand::random::<f32>() < DAMAGE_CHANCE
+        {
+            if let Some(damaged) = Self::damage(state) {
+                world.set_block(pos, damaged, UpdateFlags::UPDATE_CLIENTS);
+                world.level_event(level_events::SOUND_ANVIL_USED, pos, 0, None);
+            } else {
+                world.remove_block(pos);
+                world.level_event(level_events::SOUND_ANVIL_BROKEN, pos, 0, None);
+            }
+        } else {
+            world.level_event(level_events::SOUND_ANVIL_USED, pos, 0, None);
+        }
+    }
+
+    /// Vanilla `AnvilBlock.onLand`: plays the landing sound unless the falling
+    /// anvil is silent.
+    pub fn on_land(world: &World, pos: BlockPos, silent: bool) {
+        if !silent {
+            world.level_event(level_events::SOUND_ANVIL_LAND, pos, 0, None);
+        }
+    }
+
+    /// Vanilla `AnvilBlock.onBrokenAfterFall`: plays the break sound unless the
+    /// falling anvil is silent.
+    pub fn on_broken_after_fall(world: &World, pos: BlockPos, silent: bool) {
+        if !silent {
+            world.level_event(level_events::SOUND_ANVIL_BROKEN, pos, 0, None);
+        }
+    }
+}
+
+impl BlockBehavior for AnvilBlock {
+    fn get_state_for_placement(&self, context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(self.block.default_state().set_value(
+            &BlockStateProperties::HORIZONTAL_FACING,
+            context.horizontal_direction().rotate_y_clockwise(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::blocks::properties::Direction;
+    use steel_registry::test_support::init_test_registry;
+
+    use super::*;
+
+    #[test]
+    fn damage_steps_through_the_anvil_stages_keeping_facing() {
+        init_test_registry();
+        let anvil = vanilla_blocks::ANVIL
+            .default_state()
+            .set_value(&BlockStateProperties::HORIZONTAL_FACING, Direction::East);
+
+        let Some(chipped) = AnvilBlock::damage(anvil) else {
+            panic!("an intact anvil should chip");
+        };
+        assert_eq!(chipped.get_block(), &vanilla_blocks::CHIPPED_ANVIL);
+        assert_eq!(
+            chipped.get_value(&BlockStateProperties::HORIZONTAL_FACING),
+            Direction::East
+        );
+
+        let Some(damaged) = AnvilBlock::damage(chipped) else {
+            panic!("a chipped anvil should become damaged");
+        };
+        assert_eq!(damaged.get_block(), &vanilla_blocks::DAMAGED_ANVIL);
+        assert_eq!(AnvilBlock::damage(damaged), None);
+    }
+}
@@ -2,23 +2,30 @@ use std::sync::Arc;
 
 use glam::DVec3;
 use steel_macros::block_behavior;
-use steel_registry::blocks::BlockRef;
-use steel_utils::{BlockPos, BlockStateId};
+use steel_registry::{
+    blocks::{
+        BlockRef,
+        block_state_ext::BlockStateExt as _,
+        properties::{BedPart, BlockStateProperties, Direction},
+    },
+    vanilla_blocks, vanilla_damage_types,
+};
+use steel_utils::{BlockPos, BlockStateId, translations, types::UpdateFlags};
+use text_components::TextComponent;
 
 use crate::{
     behavior::{
-        BlockBehavior, BlockPlaceContext, EntityFallDamage, EntityFallOnContext,
-        EntityLandingContext,
+        BlockBehavior, BlockHitResult, BlockPlaceContext, EntityFallDamage, EntityFallOnContext,
+        EntityLandingContext, InteractionResult, InventoryAccess, PlacementSource,
     },
-    world::World,
+    entity::{Entity as _, damage::DamageSource},
+    player::Player,
+    world::{ExplosionInteraction, ScheduledTickAccess, World},
 };
 
 const BED_BOUNCE_SCALE: f64 = 0.660_000_026_226_043_7;
 
 /// Behavior for beds.
-///
-/// TODO: Add two-block placement, bed block entities, sleep interaction, and
-/// invalid-dimension explosion behavior with the rest of the bed system.
 #[block_behavior]
 pub struct BedBlock {
     block: BlockRef,
@@ -31,6 +38,18 @@ impl BedBlock {
         Self { block }
     }
 
+    /// Returns the direction from `part` toward the other half of the bed.
+    ///
+    /// Vanilla `BedBlock.getNeighbourDirection`.
+    #[must_use]
+    fn neighbour_direction(part: BedPart, facing: Direction) -> Direction {
+        if part == BedPart::Foot {
+            facing
+        } else {
+            facing.opposite()
+        }
+    }
+
     #[must_use]
     fn fall_context(context: EntityFallOnContext<'_>) -> EntityFallOnContext<'_> {
         context.with_fall_distance(context.fall_distance * 0.5)
@@ -52,8 +71,158 @@ impl BedBlock {
 }
 
 impl BlockBehavior for BedBlock {
-    fn get_state_for_placement(&self, _context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
-        Some(self.block.default_state())
+    fn get_state_for_placement(&self, context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        let facing = context.horizontal_direction();
+        let head_pos = facing.relative(context.place_pos());
+        if !context.world.get_block_state(head_pos).is_replaceable() {
+            return None;
+        }
+        Some(
+            self.block
+                .default_state()
+                .set_value(&BlockStateProperties::HORIZONTAL_FACING, facing),
+        )
+    }
+
+    fn set_placed_by(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        _source: &PlacementSource<'_>,
+    ) {
+        let facing = state.get_value(&BlockStateProperties::HORIZONTAL_FACING);
+        world.set_block(
+            facing.relative(pos),
+            state.set_value(&BlockStateProperties::BED_PART, BedPart::Head),
+            UpdateFlags::UPDATE_ALL,
+        );
+    }
+
+    fn update_shape(
+        &self,
+        state: BlockStateId,
+        _world: &dyn ScheduledTickAccess,
+        _pos: BlockPos,
+        direction: Direction,
+        _neighbor_pos: BlockPos,
+        neighbor_state: BlockStateId,
+    ) -> BlockStateId {
+        let part = state.get_value(&BlockStateProperties::BED_PART);
+        let facing = state.get_value(&BlockStateProperties::HORIZONTAL_FACING);
+        if direction != Self::neighbour_direction(part, facing) {
+            return state;
+        }
+
+        if neighbor_state.get_block() == self.block
+            && neighbor_state.get_value(&BlockStateProperties::BED_PART) != part
+        {
+            state.set_value(
+                &BlockStateProperties::OCCUPIED,
+                neighbor_state.get_value(&BlockStateProperties::OCCUPIED),
+            )
+        } else {
+            vanilla_blocks::AIR.default_state()
+        }
+    }
+
+    fn player_will_destroy(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        player: &Player,
+    ) -> BlockStateId {
+        // Creative players breaking the foot must not drop the head half.
+        let part = state.get_value(&BlockStateProperties::BED_PART);
+        if player.has_infinite_materials() && part == BedPart::Foot {
+            let facing = state.get_value(&BlockStateProperties::HORIZONTAL_FACING);
+            let other_pos = Self::neighbour_direction(part, facing).relative(pos);
+            let other_state = world.get_block_state(other_pos);
+            if other_state.get_block() == self.block
+                && other_state.get_value(&BlockStateProperties::BED_PART) == BedPart::Head
+            {
+                world.set_block(
+                    other_pos,
+                    vanilla_blocks::AIR.default_state(),
+                    UpdateFlags::UPDATE_ALL | UpdateFlags::UPDATE_SUPPRESS_DROPS,
+                );
+                world.destroy_block_effect(other_pos, u32::from(other_state.0), Some(player.id()));
+            }
+        }
+        state
+    }
+
+    fn use_without_item(
+        &self,
+        mut state: BlockStateId,
+        world: &Arc<World>,
+        mut pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        if state.get_value(&BlockStateProperties::BED_PART) != BedPart::Head {
+            pos = state
+                .get_value(&BlockStateProperties::HORIZONTAL_FACING)
+                .relative(pos);
+            state = world.get_block_state(pos);
+            if state.get_block() != self.block {
+                return InteractionResult::Consume;
+            }
+        }
+
+        if world.dimension_type.bed_rule.explodes {
+            if let Some(message) = world.bed_rule_error_message() {
+                player.display_client_message(&message, true);
+            }
+            world.set_block(
+                pos,
+                vanilla_blocks::AIR.default_state(),
+                UpdateFlags::UPDATE_ALL,
+            );
+            let foot_pos = state
+                .get_value(&BlockStateProperties::HORIZONTAL_FACING)
+                .opposite()
+                .relative(pos);
+            if world.get_block_state(foot_pos).get_block() == self.block {
+                world.set_block(
+                    foot_pos,
+                    vanilla_blocks::AIR.default_state(),
+                    UpdateFlags::UPDATE_ALL,
+                );
+            }
+            let (x, y, z) = pos.get_center();
+            let center = DVec3::new(x, y, z);
+            world.explode(
+                None,
+                Some(
+                    DamageSource::environment(&vanilla_damage_types::BAD_RESPAWN_POINT)
+                        .with_source_position(center),
+                ),
+                center,
+                5.0,
+                true,
+                ExplosionInteraction::for_block(world),
+            );
+            return InteractionResult::SuccessServer;
+        }
+
+        if state.get_value(&BlockStateProperties::OCCUPIED) {
+            // TODO: Kick a sleeping villager out of the bed once villagers sleep.
+            player.display_client_message(
+                &TextComponent::translated(translations::BLOCK_MINECRAFT_BED_OCCUPIED.msg()),
+                true,
+            );
+            return InteractionResult::SuccessServer;
+        }
+
+        if let Err(problem) = player.start_sleep_in_bed(pos)
+            && let Some(message) = problem.message()
+        {
+            player.display_client_message(&message, true);
+        }
+        InteractionResult::SuccessServer
     }
 
     fn fall_on(
@@ -0,0 +1,293 @@
+//! `CommandBlock` behavior
+
+use std::sync::{Arc, Weak};
+
+use steel_macros::block_behavior;
+use steel_protocol::packets::game::CommandBlockMode;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::{BlockStateProperties, Direction};
+use steel_registry::vanilla_game_rules::{
+    COMMAND_BLOCKS_WORK, MAX_COMMAND_SEQUENCE_LENGTH, SEND_COMMAND_FEEDBACK,
+};
+use steel_registry::{vanilla_block_entity_types, vanilla_blocks};
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::behavior::{
+    BlockBehavior, BlockHitResult, BlockPlaceContext, InteractionResult, InventoryAccess,
+    PlacementSource,
+};
+use crate::block_entity::entities::{CommandBlockEntity, is_command_block};
+use crate::block_entity::{BLOCK_ENTITIES, BlockEntity as _, SharedBlockEntity};
+use crate::command::execution::CommandSource;
+use crate::command::sender::CommandBlockSender;
+use crate::player::Player;
+use crate::world::{SignalGetter as _, World};
+
+/// Vanilla `CommandBlock` behavior, shared by impulse, chain and repeating
+/// command blocks.
+///
+/// The command block screen is client side; the server only receives its
+/// settings, see `Player::handle_set_command_block`.
+#[block_behavior]
+pub struct CommandBlock {
+    block: BlockRef,
+    #[json_arg(value)]
+    automatic: bool,
+}
+
+impl CommandBlock {
+    /// Creates a command block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef, automatic: bool) -> Self {
+        Self { block, automatic }
+    }
+
+    /// Vanilla `CommandBlock.setPoweredAndUpdate`.
+    fn set_powered_and_update(world: &World, pos: BlockPos, block: BlockRef, powered: bool) {
+        let Some(schedule) = CommandBlockEntity::with_at(world, pos, |command_block| {
+            command_block.update_powered(powered)
+        }) else {
+            return;
+        };
+        if schedule {
+            CommandBlockEntity::mark_condition_met(world, pos);
+            world.schedule_block_tick_default(pos, block, 1);
+        }
+    }
+
+    /// Vanilla `BaseCommandBlock.performCommand`.
+    ///
+    /// Returns `false` if the block already ran this game tick.
+    fn perform_command(world: &Arc<World>, pos: BlockPos) -> bool {
+        let game_time = world.game_time();
+        let Some(Some((command, name))) =
+            CommandBlockEntity::with_at(world, pos, |command_block| {
+                command_block.start_command(game_time)
+            })
+        else {
+            return false;
+        };
+
+        if world.get_game_rule(&COMMAND_BLOCKS_WORK)
+            && !command.is_empty()
+            && let Some(server) = world.server()
+        {
+            CommandBlockEntity::with_at(world, pos, CommandBlockEntity::clear_last_output);
+            let sender = CommandBlockSender::new(Arc::clone(world), pos, name);
+            let source = CommandSource::for_command_block(sender, Arc::clone(&server));
+            let success_count = server.perform_command(source, &command);
+            CommandBlockEntity::with_at(world, pos, |command_block| {
+                command_block.set_success_count(success_count);
+                command_block.set_changed();
+            });
+        }
+        true
+    }
+
+    /// Vanilla `CommandBlock.execute`: runs this block, then the chain it points into.
+    fn execute(world: &Arc<World>, pos: BlockPos, state: BlockStateId, command_set: bool) {
+        if command_set {
+            Self::perform_command(world, pos);
+        } else {
+            CommandBlockEntity::with_at(world, pos, |command_block| {
+                command_block.set_success_count(0);
+            });
+        }
+        Self::execute_chain(world, pos, state.get_value(&BlockStateProperties::FACING));
+    }
+
+    /// Vanilla `CommandBlock.executeChain`.
+    fn execute_chain(world: &Arc<World>, start: BlockPos, mut direction: Direction) {
+        let limit = world.get_game_rule(&MAX_COMMAND_SEQUENCE_LENGTH);
+        let mut pos = start;
+        let mut remaining = limit;
+        while remaining > 0 {
+            remaining -= 1;
+            pos = pos.relative(direction);
+            let state = world.get_block_state(pos);
+            let block = state.get_block();
+            if block != &vanilla_blocks::CHAIN_COMMAND_BLOCK {
+                break;
+            }
+            let Some((active, conditional)) = CommandBlockEntity::with_at(world, pos, |next| {
+                (
+                    next.is_powered() || next.is_automatic(),
+                    next.is_conditional(),
+                )
+            }) else {
+                break;
+            };
+
+            if active {
+                if CommandBlockEntity::mark_condition_met(world, pos) {
+                    if !Self::perform_command(world, pos) {
+                        break;
+                    }
+                    world.update_neighbor_for_output_signal(pos, block);
+                } else if conditional {
+                    CommandBlockEntity::with_at(world, pos, |next| next.set_success_count(0));
+                }
+            }
+            direction = state.get_value(&BlockStateProperties::FACING);
+        }
+
+        if remaining <= 0 {
+            log::warn!(
+                "Command Block chain tried to execute more than {} steps!",
+                limit.max(0)
+            );
+        }
+    }
+
+    /// Vanilla `CommandBlock.tick`, run once every region has ticked since
+    /// commands reach far outside the block's chunk.
+    fn tick_at_sync_point(world: &Arc<World>, pos: BlockPos) {
+        let state = world.get_block_state(pos);
+        let block = state.get_block();
+        if !is_command_block(block) {
+            return;
+        }
+        let Some((command_set, mode, was_condition_met, conditional)) =
+            CommandBlockEntity::with_at(world, pos, |command_block| {
+                (
+                    !command_block.command().is_empty(),
+                    command_block.mode(),
+                    command_block.was_condition_met(),
+                    command_block.is_conditional(),
+                )
+            })
+        else {
+            return;
+        };
+
+        match mode {
+            CommandBlockMode::Auto => {
+                CommandBlockEntity::mark_condition_met(world, pos);
+                if was_condition_met {
+                    Self::execute(world, pos, state, command_set);
+                } else if conditional {
+                    CommandBlockEntity::with_at(world, pos, |command_block| {
+                        command_block.set_success_count(0);
+                    });
+                }
+                let keep_running = CommandBlockEntity::with_at(world, pos, |command_block| {
+                    command_block.is_powered() || command_block.is_automatic()
+                })
+                .unwrap_or(false);
+                if keep_running {
+                    world.schedule_block_tick_default(pos, block, 1);
+                }
+            }
+            CommandBlockMode::Redstone => {
+                if was_condition_met {
+                    Self::execute(world, pos, state, command_set);
+                } else if conditional {
+                    CommandBlockEntity::with_at(world, pos, |command_block| {
+                        command_block.set_success_count(0);
+                    });
+                }
+            }
+            CommandBlockMode::Sequence => {}
+        }
+        world.update_neighbor_for_output_signal(pos, block);
+    }
+}
+
+impl BlockBehavior for CommandBlock {
+    fn get_state_for_placement(&self, context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(self.block.default_state().set_value(
+            &BlockStateProperties::FACING,
+            context.get_nearest_looking_direction().opposite(),
+        ))
+    }
+
+    fn set_placed_by(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        _source: &PlacementSource<'_>,
+    ) {
+        // TODO: Keep the stack's settings once items carry `minecraft:block_entity_data`.
+        let track_output = world.get_game_rule(&SEND_COMMAND_FEEDBACK);
+        let Some(schedule) = CommandBlockEntity::with_at(world, pos, |command_block| {
+            command_block.set_track_output(track_output);
+            command_block.set_automatic(self.automatic)
+        }) else {
+            return;
+        };
+        if schedule {
+            CommandBlockEntity::schedule_tick(world, pos);
+        }
+        Self::set_powered_and_update(world, pos, self.block, world.has_neighbor_signal(pos));
+    }
+
+    fn use_without_item(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        if player.can_use_game_master_blocks() && player.open_command_block(world, pos) {
+            InteractionResult::Success
+        } else {
+            InteractionResult::Pass
+        }
+    }
+
+    fn handle_neighbor_changed(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        _source_block: BlockRef,
+        _moved_by_piston: bool,
+    ) {
+        Self::set_powered_and_update(world, pos, self.block, world.has_neighbor_signal(pos));
+    }
+
+    fn tick(&self, _state: BlockStateId, world: &Arc<World>, pos: BlockPos) {
+        world.run_at_sync_point(move |world| Self::tick_at_sync_point(world, pos));
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        BLOCK_ENTITIES.create(
+            &vanilla_block_entity_types::COMMAND_BLOCK,
+            level,
+            pos,
+            state,
+        )
+    }
+
+    fn should_keep_block_entity(&self, old_state: BlockStateId, new_state: BlockStateId) -> bool {
+        // The command block screen switches between the three blocks in place.
+        is_command_block(old_state.get_block()) && is_command_block(new_state.get_block())
+    }
+
+    fn has_analog_output_signal(&self, _state: BlockStateId) -> bool {
+        true
+    }
+
+    fn get_analog_output_signal(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+    ) -> i32 {
+        CommandBlockEntity::with_at(world, pos, |command_block| command_block.success_count())
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,47 @@
+//! `DropExperienceBlock` behavior
+
+use std::sync::Arc;
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::item_stack::ItemStack;
+use steel_utils::value_providers::IntProvider;
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::behavior::{BlockBehavior, BlockPlaceContext};
+use crate::world::World;
+
+/// Vanilla `DropExperienceBlock` behavior, used by most ores and sculk.
+#[block_behavior]
+pub struct DropExperienceBlock {
+    block: BlockRef,
+    #[json_arg(int_provider, json = "xp_range")]
+    xp_range: IntProvider,
+}
+
+impl DropExperienceBlock {
+    /// Creates a block behavior that drops experience sampled from `xp_range`.
+    #[must_use]
+    pub const fn new(block: BlockRef, xp_range: IntProvider) -> Self {
+        Self { block, xp_range }
+    }
+}
+
+impl BlockBehavior for DropExperienceBlock {
+    fn get_state_for_placement(&self, _context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(self.block.default_state())
+    }
+
+    fn spawn_after_break(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        tool: &ItemStack,
+        drop_experience: bool,
+    ) {
+        if drop_experience {
+            world.try_drop_experience(pos, tool, &self.xp_range);
+        }
+    }
+}
@@ -4,8 +4,11 @@
 
 use std::sync::Arc;
 
+use crate::behavior::InventoryAccess;
 use crate::behavior::block::BlockBehavior;
-use crate::behavior::context::BlockPlaceContext;
+use crate::behavior::context::{BlockHitResult, BlockPlaceContext, InteractionResult};
+use crate::behavior::items::LeadItem;
+use crate::player::Player;
 use crate::world::{ScheduledTickAccess, World};
 use steel_macros::block_behavior;
 use steel_registry::blocks::BlockRef;
@@ -175,6 +178,18 @@ impl BlockBehavior for FenceBlock {
             Direction::Up | Direction::Down => state,
         }
     }
+
+    fn use_without_item(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        LeadItem::bind_player_mobs(player, world, pos)
+    }
 }
 
 #[cfg(test)]
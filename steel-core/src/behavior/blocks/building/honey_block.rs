@@ -13,6 +13,7 @@ use crate::{
         EntityFallOnContext,
     },
     entity::{Entity, InsideBlockEffectCollector, damage::DamageSource},
+    player::CriteriaTrigger,
     world::{LevelReader, World},
 };
 
@@ -76,6 +77,15 @@ impl HoneyBlock {
     }
 
     #[must_use]
+    /// Vanilla `HoneyBlock.maybeDoSlideAchievement`.
+    fn maybe_do_slide_achievement(state: BlockStateId, world: &World, entity: &dyn Entity) {
+        if let Some(player) = entity.as_player()
+            && world.game_time() % 20 == 0
+        {
+            player.trigger_criteria(CriteriaTrigger::SlideDownBlock(state));
+        }
+    }
+
     fn is_sliding_down(pos: BlockPos, entity: &dyn Entity) -> bool {
         Self::is_sliding_down_at(
             pos,
@@ -198,7 +208,7 @@ impl BlockBehavior for HoneyBlock {
         is_precise: bool,
     ) {
         if Self::is_sliding_down(pos, entity) {
-            // TODO: Award the honey-block slide advancement once advancements exist.
+            Self::maybe_do_slide_achievement(state, world, entity);
             Self::do_slide_movement(entity);
             Self::maybe_do_slide_effects(world, entity);
         }
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::vanilla_blocks;
+use steel_utils::{BlockPos, BlockStateId, types::UpdateFlags};
+
+use crate::behavior::{BlockBehavior, BlockPlaceContext};
+use crate::chunk::light::LightLayer;
+use crate::world::World;
+
+/// Vanilla `IceBlock`: melts into water when block light is bright enough.
+// TODO: Leave water behind when broken without silk touch once player block
+// destruction has a per-block hook.
+#[block_behavior]
+pub struct IceBlock {
+    block: BlockRef,
+}
+
+impl IceBlock {
+    /// Creates a new ice block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+
+    /// Vanilla `IceBlock.meltsInto`.
+    #[must_use]
+    pub fn melts_into() -> BlockStateId {
+        vanilla_blocks::WATER.default_state()
+    }
+
+    /// Vanilla `IceBlock.melt`: evaporates in dimensions where water cannot
+    /// exist, otherwise turns into water and lets it start flowing.
+    pub fn melt(world: &Arc<World>, pos: BlockPos) {
+        if world.dimension_type.water_evaporates {
+            world.remove_block(pos);
+            return;
+        }
+
+        let water = Self::melts_into();
+        world.set_block(pos, water, UpdateFlags::UPDATE_ALL);
+        world.neighbor_changed(pos, water.get_block(), false);
+    }
+}
+
+impl BlockBehavior for IceBlock {
+    fn get_state_for_placement(&self, _context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(self.block.default_state())
+    }
+
+    fn is_randomly_ticking(&self, _state: BlockStateId) -> bool {
+        true
+    }
+
+    fn random_tick(&self, state: BlockStateId, world: &Arc<World>, pos: BlockPos) {
+        let max_light = 11 - i32::from(state.get_light_dampening());
+        if i32::from(world.light_value_at(LightLayer::Block, pos)) > max_light {
+            Self::melt(world, pos);
+        }
+    }
+}
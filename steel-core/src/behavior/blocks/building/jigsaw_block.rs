@@ -0,0 +1,97 @@
+//! `JigsawBlock` behavior
+
+use std::sync::{Arc, Weak};
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::{BlockStateProperties, Direction, FrontAndTop};
+use steel_registry::vanilla_block_entity_types;
+use steel_utils::axis::Axis;
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::behavior::{
+    BlockBehavior, BlockHitResult, BlockPlaceContext, InteractionResult, InventoryAccess,
+};
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::player::Player;
+use crate::world::World;
+
+/// Vanilla `FrontAndTop.fromFrontAndTop`.
+const fn front_and_top(front: Direction, top: Direction) -> FrontAndTop {
+    match (front, top) {
+        (Direction::Down, Direction::North) => FrontAndTop::DownNorth,
+        (Direction::Down, Direction::South) => FrontAndTop::DownSouth,
+        (Direction::Down, Direction::West) => FrontAndTop::DownWest,
+        (Direction::Down, _) => FrontAndTop::DownEast,
+        (Direction::Up, Direction::North) => FrontAndTop::UpNorth,
+        (Direction::Up, Direction::South) => FrontAndTop::UpSouth,
+        (Direction::Up, Direction::West) => FrontAndTop::UpWest,
+        (Direction::Up, _) => FrontAndTop::UpEast,
+        (Direction::North, _) => FrontAndTop::NorthUp,
+        (Direction::South, _) => FrontAndTop::SouthUp,
+        (Direction::West, _) => FrontAndTop::WestUp,
+        (Direction::East, _) => FrontAndTop::EastUp,
+    }
+}
+
+/// Vanilla `JigsawBlock` behavior.
+///
+/// The jigsaw screen is client side; the server only receives its settings and
+/// the "Generate" request, see `Player::handle_set_jigsaw_block`.
+#[block_behavior]
+pub struct JigsawBlock {
+    block: BlockRef,
+}
+
+impl JigsawBlock {
+    /// Creates a jigsaw block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+}
+
+impl BlockBehavior for JigsawBlock {
+    fn get_state_for_placement(&self, context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        let front = context.clicked_face();
+        let top = if matches!(front.get_axis(), Axis::Y) {
+            context.horizontal_direction().opposite()
+        } else {
+            Direction::Up
+        };
+        Some(self.block.default_state().set_value(
+            &BlockStateProperties::ORIENTATION,
+            front_and_top(front, top),
+        ))
+    }
+
+    fn use_without_item(
+        &self,
+        _state: BlockStateId,
+        _world: &Arc<World>,
+        _pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        if player.can_use_game_master_blocks() {
+            InteractionResult::Success
+        } else {
+            InteractionResult::Pass
+        }
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        BLOCK_ENTITIES.create(&vanilla_block_entity_types::JIGSAW, level, pos, state)
+    }
+}
@@ -1,15 +1,20 @@
 mod amethyst_block;
 mod amethyst_cluster;
+mod anvil_block;
 mod bar_block;
 mod barrier_block;
 mod bed_block;
 mod budding_amethyst;
 mod campfire_block;
+mod command_block;
 mod door_block;
+mod drop_experience_block;
 mod fence_block;
 mod fence_gate_block;
 mod hay_block;
 mod honey_block;
+mod ice_block;
+mod jigsaw_block;
 mod ladder_block;
 mod lava_cauldron_block;
 mod magma_block;
@@ -17,10 +22,13 @@ mod potent_sulfur_block;
 mod powder_snow_block;
 mod rotated_pillar_block;
 mod scaffolding_block;
+mod sculk_shrieker_block;
 mod slab_block;
 mod slime_block;
+mod spawner_block;
 mod sponge_block;
 mod stair_block;
+mod structure_block;
 mod trapdoor_block;
 mod wall_block;
 mod waterlogged_transparent_block;
@@ -30,16 +38,21 @@ mod wet_sponge_block;
 
 pub use amethyst_block::AmethystBlock;
 pub use amethyst_cluster::AmethystClusterBlock;
+pub use anvil_block::AnvilBlock;
 pub use bar_block::{IronBarsBlock, WeatheringCopperBarsBlock, get_connection_state, update_shape};
 pub use barrier_block::BarrierBlock;
 pub use bed_block::BedBlock;
 pub use budding_amethyst::BuddingAmethystBlock;
 pub use campfire_block::CampfireBlock;
+pub use command_block::CommandBlock;
 pub use door_block::{DoorBlock, WeatheringCopperDoorBlock};
+pub use drop_experience_block::DropExperienceBlock;
 pub use fence_block::FenceBlock;
 pub use fence_gate_block::FenceGateBlock;
 pub use hay_block::HayBlock;
 pub use honey_block::HoneyBlock;
+pub use ice_block::IceBlock;
+pub use jigsaw_block::JigsawBlock;
 pub use ladder_block::LadderBlock;
 pub use lava_cauldron_block::LavaCauldronBlock;
 pub use magma_block::MagmaBlock;
@@ -47,10 +60,13 @@ pub use potent_sulfur_block::PotentSulfurBlock;
 pub use powder_snow_block::PowderSnowBlock;
 pub use rotated_pillar_block::RotatedPillarBlock;
 pub use scaffolding_block::ScaffoldingBlock;
+pub use sculk_shrieker_block::SculkShriekerBlock;
 pub use slab_block::{SlabBlock, WeatheringCopperSlabBlock};
 pub use slime_block::SlimeBlock;
+pub use spawner_block::SpawnerBlock;
 pub use sponge_block::SpongeBlock;
 pub use stair_block::{StairBlock, WeatheringCopperStairBlock};
+pub use structure_block::StructureBlock;
 pub use trapdoor_block::{TrapDoorBlock, WeatheringCopperTrapDoorBlock};
 pub use wall_block::WallBlock;
 pub use waterlogged_transparent_block::{WaterloggedTransparentBlock, WeatheringCopperGrateBlock};
@@ -0,0 +1,116 @@
+//! Sculk shrieker block behavior.
+//!
+//! Vanilla equivalent: `SculkShriekerBlock`.
+
+use std::sync::{Arc, Weak};
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::blocks::properties::{BlockStateProperties, Direction};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::{vanilla_block_entity_types, vanilla_fluids};
+use steel_utils::types::UpdateFlags;
+use steel_utils::value_providers::IntProvider;
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::behavior::{BlockBehavior, BlockPlaceContext};
+use crate::block_entity::entities::SculkShriekerBlockEntity;
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::entity::Entity;
+use crate::world::{ScheduledTickAccess, World};
+
+/// Experience dropped when a sculk shrieker is broken without silk touch.
+const SHRIEKER_XP: IntProvider = IntProvider::Constant(5);
+
+/// Behavior for the sculk shrieker.
+#[block_behavior]
+pub struct SculkShriekerBlock {
+    block: BlockRef,
+}
+
+impl SculkShriekerBlock {
+    /// Creates a new sculk shrieker block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+}
+
+impl BlockBehavior for SculkShriekerBlock {
+    fn get_state_for_placement(&self, context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(
+            self.block
+                .default_state()
+                .set_value(&BlockStateProperties::WATERLOGGED, context.is_full_water()),
+        )
+    }
+
+    fn update_shape(
+        &self,
+        state: BlockStateId,
+        world: &dyn ScheduledTickAccess,
+        pos: BlockPos,
+        _direction: Direction,
+        _neighbor_pos: BlockPos,
+        _neighbor_state: BlockStateId,
+    ) -> BlockStateId {
+        if state.get_value(&BlockStateProperties::WATERLOGGED) {
+            let delay = world.fluid_tick_delay(&vanilla_fluids::WATER);
+            let _ = world.schedule_fluid_tick_default(pos, &vanilla_fluids::WATER, delay);
+        }
+        state
+    }
+
+    /// Ends the shriek and lets the block entity respond.
+    fn tick(&self, state: BlockStateId, world: &Arc<World>, pos: BlockPos) {
+        if state.get_value(&BlockStateProperties::SHRIEKING) {
+            world.set_block(
+                pos,
+                state.set_value(&BlockStateProperties::SHRIEKING, false),
+                UpdateFlags::UPDATE_ALL,
+            );
+            SculkShriekerBlockEntity::try_respond(world, pos);
+        }
+    }
+
+    fn step_on(&self, state: BlockStateId, world: &Arc<World>, pos: BlockPos, entity: &dyn Entity) {
+        if !state.get_value(&BlockStateProperties::SHRIEKING)
+            && let Some(player) = SculkShriekerBlockEntity::try_get_player(world, entity)
+        {
+            SculkShriekerBlockEntity::try_shriek(world, pos, &player);
+        }
+        self.default_step_on(state, world, pos, entity);
+    }
+
+    fn spawn_after_break(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        tool: &ItemStack,
+        drop_experience: bool,
+    ) {
+        if drop_experience {
+            world.try_drop_experience(pos, tool, &SHRIEKER_XP);
+        }
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        Some(BLOCK_ENTITIES.create_or_raw(
+            &vanilla_block_entity_types::SCULK_SHRIEKER,
+            level,
+            pos,
+            state,
+        ))
+    }
+}
@@ -0,0 +1,66 @@
+//! `SpawnerBlock` behavior
+
+use std::sync::{Arc, Weak};
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::vanilla_block_entity_types;
+use steel_utils::value_providers::IntProvider;
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::behavior::{BlockBehavior, BlockPlaceContext};
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::world::World;
+
+/// Experience range used by vanilla `SpawnerBlock.spawnAfterBreak`.
+const SPAWNER_XP_RANGE: IntProvider = IntProvider::Uniform {
+    min_inclusive: 15,
+    max_inclusive: 43,
+};
+
+/// Vanilla `SpawnerBlock` behavior.
+#[block_behavior]
+pub struct SpawnerBlock {
+    block: BlockRef,
+}
+
+impl SpawnerBlock {
+    /// Creates a monster spawner block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+}
+
+impl BlockBehavior for SpawnerBlock {
+    fn get_state_for_placement(&self, _context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(self.block.default_state())
+    }
+
+    fn spawn_after_break(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        tool: &ItemStack,
+        drop_experience: bool,
+    ) {
+        if drop_experience {
+            world.try_drop_experience(pos, tool, &SPAWNER_XP_RANGE);
+        }
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        BLOCK_ENTITIES.create(&vanilla_block_entity_types::MOB_SPAWNER, level, pos, state)
+    }
+}
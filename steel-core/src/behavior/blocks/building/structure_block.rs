@@ -0,0 +1,131 @@
+//! `StructureBlock` behavior
+
+use std::sync::{Arc, Weak};
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::properties::StructureMode;
+use steel_registry::vanilla_block_entity_types;
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::behavior::{
+    BlockBehavior, BlockHitResult, BlockPlaceContext, InteractionResult, InventoryAccess,
+    PlacementSource,
+};
+use crate::block_entity::entities::StructureBlockEntity;
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::player::Player;
+use crate::world::{SignalGetter as _, World};
+
+/// Vanilla `StructureBlock` behavior.
+///
+/// The structure block screen is client side; the server only receives its
+/// settings, see `Player::handle_set_structure_block`.
+#[block_behavior]
+pub struct StructureBlock {
+    block: BlockRef,
+}
+
+impl StructureBlock {
+    /// Creates a structure block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+
+    /// Vanilla `StructureBlock.trigger`.
+    fn trigger(world: &Arc<World>, pos: BlockPos) {
+        let Some(mode) =
+            StructureBlockEntity::with_at(world, pos, |structure| structure.mode().clone())
+        else {
+            return;
+        };
+        match mode {
+            StructureMode::Save => {
+                StructureBlockEntity::save_structure(world, pos, false);
+            }
+            StructureMode::Load => {
+                StructureBlockEntity::place_structure_if_same_size(world, pos);
+            }
+            StructureMode::Corner => StructureBlockEntity::unload_structure(world, pos),
+            StructureMode::Data => {}
+        }
+    }
+}
+
+impl BlockBehavior for StructureBlock {
+    fn get_state_for_placement(&self, _context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(self.block.default_state())
+    }
+
+    fn set_placed_by(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        source: &PlacementSource<'_>,
+    ) {
+        let Some(player) = source.player() else {
+            return;
+        };
+        let author = player.gameprofile.name.clone();
+        StructureBlockEntity::with_at(world, pos, |structure| structure.created_by(author));
+    }
+
+    fn use_without_item(
+        &self,
+        _state: BlockStateId,
+        _world: &Arc<World>,
+        _pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        if player.can_use_game_master_blocks() {
+            InteractionResult::Success
+        } else {
+            InteractionResult::Pass
+        }
+    }
+
+    fn handle_neighbor_changed(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        _source_block: BlockRef,
+        _moved_by_piston: bool,
+    ) {
+        let has_signal = world.has_neighbor_signal(pos);
+        let Some(was_powered) = StructureBlockEntity::with_at(world, pos, |structure| {
+            let was_powered = structure.is_powered();
+            if has_signal != was_powered {
+                structure.set_powered(has_signal);
+            }
+            was_powered
+        }) else {
+            return;
+        };
+        if has_signal && !was_powered {
+            Self::trigger(world, pos);
+        }
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        BLOCK_ENTITIES.create(
+            &vanilla_block_entity_types::STRUCTURE_BLOCK,
+            level,
+            pos,
+            state,
+        )
+    }
+}
@@ -1,2 +1,4 @@
+mod stained_glass_block;
 mod stained_glass_pane_block;
+pub use stained_glass_block::StainedGlassBlock;
 pub use stained_glass_pane_block::StainedGlassPaneBlock;
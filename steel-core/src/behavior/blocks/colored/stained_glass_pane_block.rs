@@ -25,7 +25,6 @@ pub struct StainedGlassPaneBlock {
         json = "color",
         module = "steel_registry::dye_color"
     )]
-    #[expect(unused, reason = "Is needed for beacon beam")]
     color: DyeColor,
 }
 
@@ -71,4 +70,8 @@ impl BlockBehavior for StainedGlassPaneBlock {
     ) -> bool {
         false
     }
+
+    fn get_beacon_beam_color(&self) -> Option<DyeColor> {
+        Some(self.color)
+    }
 }
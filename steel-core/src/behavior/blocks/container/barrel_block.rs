@@ -8,13 +8,16 @@ use steel_macros::block_behavior;
 use steel_registry::blocks::BlockRef;
 use steel_registry::blocks::block_state_ext::BlockStateExt;
 use steel_registry::blocks::properties::BlockStateProperties;
+use steel_registry::stat::{CustomStat, Stat};
 use steel_registry::vanilla_block_entity_types;
-use steel_utils::{BlockPos, BlockStateId, translations};
+use steel_utils::types::UpdateFlags;
+use steel_utils::{BlockPos, BlockStateId, Downcast as _, translations};
 use text_components::TextComponent;
 
 use crate::behavior::InventoryAccess;
 use crate::behavior::block::BlockBehavior;
 use crate::behavior::context::{BlockHitResult, BlockPlaceContext, InteractionResult};
+use crate::block_entity::entities::BarrelBlockEntity;
 use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
 use crate::inventory::chest_menu::ChestMenuProvider;
 use crate::inventory::container::calculate_redstone_signal_from_container;
@@ -77,15 +80,37 @@ impl BlockBehavior for BarrelBlock {
             TextComponent::translated(translations::CONTAINER_BARREL.msg()),
         ));
 
-        // TODO: Award stat OPEN_BARREL
+        player.award_stat(Stat::custom(CustomStat::OpenBarrel));
         // TODO: Anger nearby piglins (PiglinAi.angerNearbyPiglins)
-        // TODO: Implement ContainerOpenersCounter to track open state, play sounds,
-        //       and update OPEN block property. Requires scheduled block ticks (scheduleTick)
-        //       for recheck functionality. See vanilla BarrelBlockEntity and ContainerOpenersCounter.
 
         InteractionResult::Success
     }
 
+    /// Syncs the `OPEN` property with the barrel's opener count.
+    ///
+    /// Scheduled by the barrel block entity when the first player opens it or
+    /// the last one closes it, like vanilla `BarrelBlock.tick`.
+    fn tick(&self, state: BlockStateId, world: &Arc<World>, pos: BlockPos) {
+        let Some(block_entity) = world.get_block_entity(pos) else {
+            return;
+        };
+        let Some(open) = block_entity
+            .lock()
+            .downcast_ref::<BarrelBlockEntity>()
+            .map(BarrelBlockEntity::is_open)
+        else {
+            return;
+        };
+
+        if state.get_value(&BlockStateProperties::OPEN) != open {
+            world.set_block(
+                pos,
+                state.set_value(&BlockStateProperties::OPEN, open),
+                UpdateFlags::UPDATE_ALL,
+            );
+        }
+    }
+
     fn has_block_entity(&self) -> bool {
         true
     }
@@ -0,0 +1,73 @@
+//! Beacon block behavior implementation.
+//!
+//! Opens the beacon menu when right-clicked. The block entity scans the beam
+//! and the pyramid below and grants the selected powers.
+
+use std::sync::{Arc, Weak};
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::stat::{CustomStat, Stat};
+use steel_registry::vanilla_block_entity_types;
+use steel_utils::{BlockPos, BlockStateId, translations};
+use text_components::TextComponent;
+
+use crate::behavior::InventoryAccess;
+use crate::behavior::block::BlockBehavior;
+use crate::behavior::context::{BlockHitResult, BlockPlaceContext, InteractionResult};
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::inventory::BeaconMenuProvider;
+use crate::player::Player;
+use crate::world::World;
+
+/// Behavior for the beacon block.
+#[block_behavior]
+pub struct BeaconBlock {
+    block: BlockRef,
+}
+
+impl BeaconBlock {
+    /// Creates a new beacon block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+}
+
+impl BlockBehavior for BeaconBlock {
+    fn get_state_for_placement(&self, _context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(self.block.default_state())
+    }
+
+    fn use_without_item(
+        &self,
+        _state: BlockStateId,
+        _world: &Arc<World>,
+        pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        // TODO: Use the block entity's custom name as the title when it has one
+        player.open_menu(&BeaconMenuProvider::new(
+            player.inventory.clone(),
+            pos,
+            TextComponent::translated(translations::CONTAINER_BEACON.msg()),
+        ));
+        player.award_stat(Stat::custom(CustomStat::InteractWithBeacon));
+        InteractionResult::Success
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        Some(BLOCK_ENTITIES.create_or_raw(&vanilla_block_entity_types::BEACON, level, pos, state))
+    }
+}
@@ -0,0 +1,55 @@
+//! Cartography table block behavior implementation.
+//!
+//! Opens the cartography menu when right-clicked, where maps are copied,
+//! zoomed out and locked.
+
+use std::sync::Arc;
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::stat::{CustomStat, Stat};
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::behavior::InventoryAccess;
+use crate::behavior::block::BlockBehavior;
+use crate::behavior::context::{BlockHitResult, BlockPlaceContext, InteractionResult};
+use crate::inventory::CartographyTableMenuProvider;
+use crate::player::Player;
+use crate::world::World;
+
+/// Behavior for the cartography table block.
+#[block_behavior]
+pub struct CartographyTableBlock {
+    block: BlockRef,
+}
+
+impl CartographyTableBlock {
+    /// Creates a new cartography table block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+}
+
+impl BlockBehavior for CartographyTableBlock {
+    fn get_state_for_placement(&self, _context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(self.block.default_state())
+    }
+
+    fn use_without_item(
+        &self,
+        _state: BlockStateId,
+        _world: &Arc<World>,
+        pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        player.open_menu(&CartographyTableMenuProvider::new(
+            player.inventory.clone(),
+            pos,
+        ));
+        player.award_stat(Stat::custom(CustomStat::InteractWithCartographyTable));
+        InteractionResult::Success
+    }
+}
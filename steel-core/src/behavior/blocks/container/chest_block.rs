@@ -0,0 +1,283 @@
+//! Chest block behavior implementation.
+//!
+//! Chests connect to an adjacent chest facing the same way to form a double
+//! chest, and open a 27 or 54 slot menu when right-clicked.
+
+use std::sync::{Arc, Weak};
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::blocks::properties::{
+    BlockStateProperties, BoolProperty, ChestType, EnumProperty,
+};
+use steel_registry::stat::{CustomStat, Stat};
+use steel_registry::{vanilla_block_entity_types, vanilla_fluids};
+use steel_utils::{BlockPos, BlockStateId, Direction, translations};
+use text_components::TextComponent;
+
+use crate::behavior::InventoryAccess;
+use crate::behavior::block::BlockBehavior;
+use crate::behavior::context::{BlockHitResult, BlockPlaceContext, InteractionResult};
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::inventory::chest_menu::ChestMenuProvider;
+use crate::inventory::container::calculate_redstone_signal_from_containers;
+use crate::inventory::lock::{ContainerLockGuard, ContainerRef};
+use crate::player::Player;
+use crate::world::{ScheduledTickAccess, World};
+
+const FACING: EnumProperty<Direction> = BlockStateProperties::HORIZONTAL_FACING;
+const TYPE: EnumProperty<ChestType> = BlockStateProperties::CHEST_TYPE;
+const WATERLOGGED: BoolProperty = BlockStateProperties::WATERLOGGED;
+
+/// Returns the direction from a connected chest half towards its partner.
+///
+/// Vanilla `ChestBlock.getConnectedDirection`.
+#[must_use]
+pub fn get_connected_direction(state: BlockStateId) -> Direction {
+    let facing: Direction = state.get_value(&FACING);
+    if state.get_value(&TYPE) == ChestType::Left {
+        facing.rotate_y_clockwise()
+    } else {
+        facing.rotate_y_counter_clockwise()
+    }
+}
+
+/// Behavior for chest blocks.
+#[block_behavior]
+pub struct ChestBlock {
+    block: BlockRef,
+}
+
+impl ChestBlock {
+    /// Creates a new chest block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+
+    /// Vanilla `ChestBlock.candidatePartnerFacing`.
+    fn candidate_partner_facing(
+        &self,
+        context: &BlockPlaceContext<'_>,
+        direction: Direction,
+    ) -> Option<Direction> {
+        let state = context
+            .world
+            .get_block_state(context.place_pos().relative(direction));
+        (state.get_block() == self.block && state.get_value(&TYPE) == ChestType::Single)
+            .then(|| state.get_value(&FACING))
+    }
+}
+
+/// Returns the position of the other half if `state` forms a valid double chest.
+///
+/// Mirrors the neighbour check in vanilla `DoubleBlockCombiner.combineWithNeigbour`.
+fn partner_pos(state: BlockStateId, world: &World, pos: BlockPos) -> Option<BlockPos> {
+    if state.get_value(&TYPE) == ChestType::Single {
+        return None;
+    }
+
+    let partner_pos = pos.relative(get_connected_direction(state));
+    let partner = world.get_block_state(partner_pos);
+    if partner.get_block() != state.get_block() {
+        return None;
+    }
+
+    let partner_type: ChestType = partner.get_value(&TYPE);
+    (partner_type != ChestType::Single
+        && partner_type != state.get_value(&TYPE)
+        && partner.get_value(&FACING) == state.get_value(&FACING))
+    .then_some(partner_pos)
+}
+
+/// Resolves the containers behind a chest, the `RIGHT` half first.
+///
+/// Matches the order of vanilla's `CompoundContainer` for double chests.
+#[must_use]
+pub fn chest_containers(
+    state: BlockStateId,
+    world: &World,
+    pos: BlockPos,
+) -> Option<(ContainerRef, Option<ContainerRef>)> {
+    let container = ContainerRef::from_block_entity(world.get_block_entity(pos)?)?;
+    let Some(partner_pos) = partner_pos(state, world, pos) else {
+        return Some((container, None));
+    };
+    let Some(partner) = world
+        .get_block_entity(partner_pos)
+        .and_then(ContainerRef::from_block_entity)
+    else {
+        return Some((container, None));
+    };
+
+    if state.get_value(&TYPE) == ChestType::Right {
+        Some((container, Some(partner)))
+    } else {
+        Some((partner, Some(container)))
+    }
+}
+
+/// Vanilla `ChestBlock.isChestBlockedAt`.
+fn is_chest_blocked_at(world: &World, pos: BlockPos) -> bool {
+    // TODO: Cats sitting on the chest also block it.
+    let above = pos.above();
+    World::is_redstone_conductor(world.get_block_state(above), above)
+}
+
+impl BlockBehavior for ChestBlock {
+    fn get_state_for_placement(&self, context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        let mut chest_type = ChestType::Single;
+        let mut facing = context.horizontal_direction().opposite();
+        let secondary_use = context.is_secondary_use_active();
+        let clicked_face = context.clicked_face();
+
+        if clicked_face.is_horizontal() && secondary_use {
+            let neighbour = self.candidate_partner_facing(context, clicked_face.opposite());
+            if let Some(neighbour) = neighbour
+                && neighbour.get_axis() != clicked_face.get_axis()
+            {
+                facing = neighbour;
+                chest_type = if neighbour.rotate_y_counter_clockwise() == clicked_face.opposite() {
+                    ChestType::Right
+                } else {
+                    ChestType::Left
+                };
+            }
+        }
+
+        if chest_type == ChestType::Single && !secondary_use {
+            if self.candidate_partner_facing(context, facing.rotate_y_clockwise()) == Some(facing) {
+                chest_type = ChestType::Left;
+            } else if self.candidate_partner_facing(context, facing.rotate_y_counter_clockwise())
+                == Some(facing)
+            {
+                chest_type = ChestType::Right;
+            }
+        }
+
+        Some(
+            self.block
+                .default_state()
+                .set_value(&FACING, facing)
+                .set_value(&TYPE, chest_type)
+                .set_value(&WATERLOGGED, context.is_water_source()),
+        )
+    }
+
+    fn update_shape(
+        &self,
+        state: BlockStateId,
+        world: &dyn ScheduledTickAccess,
+        pos: BlockPos,
+        direction: Direction,
+        _neighbor_pos: BlockPos,
+        neighbor_state: BlockStateId,
+    ) -> BlockStateId {
+        if state.get_value(&WATERLOGGED) {
+            let delay = world.fluid_tick_delay(&vanilla_fluids::WATER);
+            let _ = world.schedule_fluid_tick_default(pos, &vanilla_fluids::WATER, delay);
+        }
+
+        if neighbor_state.get_block() == self.block && direction.is_horizontal() {
+            let neighbor_type: ChestType = neighbor_state.get_value(&TYPE);
+            if state.get_value(&TYPE) == ChestType::Single
+                && neighbor_type != ChestType::Single
+                && state.get_value(&FACING) == neighbor_state.get_value(&FACING)
+                && get_connected_direction(neighbor_state) == direction.opposite()
+            {
+                let chest_type = if neighbor_type == ChestType::Left {
+                    ChestType::Right
+                } else {
+                    ChestType::Left
+                };
+                return state.set_value(&TYPE, chest_type);
+            }
+        } else if get_connected_direction(state) == direction {
+            return state.set_value(&TYPE, ChestType::Single);
+        }
+
+        state
+    }
+
+    fn use_without_item(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        let partner_pos = partner_pos(state, world, pos);
+        if is_chest_blocked_at(world, pos)
+            || partner_pos.is_some_and(|partner_pos| is_chest_blocked_at(world, partner_pos))
+        {
+            return InteractionResult::Success;
+        }
+
+        let Some((first, second)) = chest_containers(state, world, pos) else {
+            return InteractionResult::Pass;
+        };
+
+        let provider = if let Some(second) = second {
+            ChestMenuProvider::double(
+                player.inventory.clone(),
+                first,
+                second,
+                TextComponent::translated(translations::CONTAINER_CHEST_DOUBLE.msg()),
+            )
+        } else {
+            ChestMenuProvider::three_rows(
+                player.inventory.clone(),
+                first,
+                TextComponent::translated(translations::CONTAINER_CHEST.msg()),
+            )
+        };
+        player.open_menu(&provider);
+
+        player.award_stat(Stat::custom(CustomStat::OpenChest));
+        // TODO: Anger nearby piglins (PiglinAi.angerNearbyPiglins)
+
+        InteractionResult::Success
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        BLOCK_ENTITIES.create(&vanilla_block_entity_types::CHEST, level, pos, state)
+    }
+
+    fn has_analog_output_signal(&self, _state: BlockStateId) -> bool {
+        true
+    }
+
+    fn get_analog_output_signal(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+    ) -> i32 {
+        let Some((first, second)) = chest_containers(state, world, pos) else {
+            return 0;
+        };
+
+        let refs: Vec<&ContainerRef> = [Some(&first), second.as_ref()]
+            .into_iter()
+            .flatten()
+            .collect();
+        let guard = ContainerLockGuard::lock_all(&refs);
+        let containers: Vec<_> = refs
+            .iter()
+            .filter_map(|container| guard.get(container.container_id()))
+            .collect();
+        calculate_redstone_signal_from_containers(&containers)
+    }
+}
@@ -0,0 +1,71 @@
+//! Conduit block behavior implementation.
+//!
+//! The conduit can be waterlogged; its block entity checks the surrounding
+//! water and prismarine frame.
+
+use std::sync::Weak;
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::blocks::properties::{BlockStateProperties, Direction};
+use steel_registry::{vanilla_block_entity_types, vanilla_fluids};
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::behavior::block::BlockBehavior;
+use crate::behavior::context::BlockPlaceContext;
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::world::{ScheduledTickAccess, World};
+
+/// Behavior for the conduit block.
+#[block_behavior]
+pub struct ConduitBlock {
+    block: BlockRef,
+}
+
+impl ConduitBlock {
+    /// Creates a new conduit block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+}
+
+impl BlockBehavior for ConduitBlock {
+    fn get_state_for_placement(&self, context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(
+            self.block
+                .default_state()
+                .set_value(&BlockStateProperties::WATERLOGGED, context.is_full_water()),
+        )
+    }
+
+    fn update_shape(
+        &self,
+        state: BlockStateId,
+        world: &dyn ScheduledTickAccess,
+        pos: BlockPos,
+        _direction: Direction,
+        _neighbor_pos: BlockPos,
+        _neighbor_state: BlockStateId,
+    ) -> BlockStateId {
+        if state.get_value(&BlockStateProperties::WATERLOGGED) {
+            let delay = world.fluid_tick_delay(&vanilla_fluids::WATER);
+            let _ = world.schedule_fluid_tick_default(pos, &vanilla_fluids::WATER, delay);
+        }
+        state
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        Some(BLOCK_ENTITIES.create_or_raw(&vanilla_block_entity_types::CONDUIT, level, pos, state))
+    }
+}
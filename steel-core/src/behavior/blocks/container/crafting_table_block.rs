@@ -6,6 +6,7 @@ use std::sync::Arc;
 
 use steel_macros::block_behavior;
 use steel_registry::blocks::BlockRef;
+use steel_registry::stat::{CustomStat, Stat};
 use steel_utils::{BlockPos, BlockStateId};
 
 use crate::behavior::InventoryAccess;
@@ -47,7 +48,7 @@ impl BlockBehavior for CraftingTableBlock {
         _inv: &mut InventoryAccess,
     ) -> InteractionResult {
         player.open_menu(&CraftingMenuProvider::new(player.inventory.clone(), pos));
-        // TODO: Award stat INTERACT_WITH_CRAFTING_TABLE
+        player.award_stat(Stat::custom(CustomStat::InteractWithCraftingTable));
         InteractionResult::Success
     }
 }
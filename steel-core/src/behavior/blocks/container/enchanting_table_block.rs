@@ -0,0 +1,104 @@
+//! Enchanting table block behavior implementation.
+//!
+//! Opens the enchanting menu when right-clicked. Bookshelves around the table
+//! raise the level of the offered enchantments.
+
+use std::sync::{Arc, Weak};
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::vanilla_block_entity_types;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_utils::{BlockPos, BlockStateId, translations};
+use text_components::TextComponent;
+
+use crate::behavior::InventoryAccess;
+use crate::behavior::block::BlockBehavior;
+use crate::behavior::context::{BlockHitResult, BlockPlaceContext, InteractionResult};
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::inventory::EnchantmentMenuProvider;
+use crate::player::Player;
+use crate::world::World;
+
+/// Returns the offsets of every bookshelf position that can power the table.
+///
+/// Vanilla `EnchantingTableBlock.BOOKSHELF_OFFSETS`: the ring two blocks out,
+/// on the table's level and the one above.
+pub fn bookshelf_offsets() -> impl Iterator<Item = (i32, i32, i32)> {
+    (-2..=2)
+        .flat_map(|x| (0..=1).flat_map(move |y| (-2..=2).map(move |z| (x, y, z))))
+        .filter(|&(x, _, z)| x.abs() == 2 || z.abs() == 2)
+}
+
+/// Vanilla `EnchantingTableBlock.isValidBookShelf`.
+///
+/// The block halfway towards the table must let the power through.
+#[must_use]
+pub fn is_valid_book_shelf(world: &World, pos: BlockPos, (x, y, z): (i32, i32, i32)) -> bool {
+    world
+        .get_block_state(pos.offset(x, y, z))
+        .get_block()
+        .has_tag(&BlockTag::ENCHANTMENT_POWER_PROVIDER)
+        && world
+            .get_block_state(pos.offset(x / 2, y, z / 2))
+            .get_block()
+            .has_tag(&BlockTag::ENCHANTMENT_POWER_TRANSMITTER)
+}
+
+/// Behavior for the enchanting table block.
+#[block_behavior]
+pub struct EnchantingTableBlock {
+    block: BlockRef,
+}
+
+impl EnchantingTableBlock {
+    /// Creates a new enchanting table block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+}
+
+impl BlockBehavior for EnchantingTableBlock {
+    fn get_state_for_placement(&self, _context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(self.block.default_state())
+    }
+
+    fn use_without_item(
+        &self,
+        _state: BlockStateId,
+        _world: &Arc<World>,
+        pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        // TODO: Use the block entity's custom name as the title when it has one
+        player.open_menu(&EnchantmentMenuProvider::new(
+            player.inventory.clone(),
+            pos,
+            TextComponent::translated(translations::CONTAINER_ENCHANT.msg()),
+        ));
+        InteractionResult::Success
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    /// The client renders the floating book from the block entity.
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        Some(BLOCK_ENTITIES.create_or_raw(
+            &vanilla_block_entity_types::ENCHANTING_TABLE,
+            level,
+            pos,
+            state,
+        ))
+    }
+}
@@ -0,0 +1,115 @@
+//! Ender chest block behavior implementation.
+//!
+//! Every ender chest opens the using player's own 27-slot ender chest
+//! inventory, so items put in one can be taken out of any other.
+
+use std::sync::{Arc, Weak};
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::blocks::properties::{BlockStateProperties, BoolProperty, EnumProperty};
+use steel_registry::stat::{CustomStat, Stat};
+use steel_registry::{vanilla_block_entity_types, vanilla_fluids};
+use steel_utils::{BlockPos, BlockStateId, Direction, translations};
+use text_components::TextComponent;
+
+use crate::behavior::InventoryAccess;
+use crate::behavior::block::BlockBehavior;
+use crate::behavior::context::{BlockHitResult, BlockPlaceContext, InteractionResult};
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::inventory::chest_menu::ChestMenuProvider;
+use crate::inventory::lock::ContainerRef;
+use crate::player::Player;
+use crate::world::{ScheduledTickAccess, World};
+
+const FACING: EnumProperty<Direction> = BlockStateProperties::HORIZONTAL_FACING;
+const WATERLOGGED: BoolProperty = BlockStateProperties::WATERLOGGED;
+
+/// Behavior for the ender chest block.
+#[block_behavior]
+pub struct EnderChestBlock {
+    block: BlockRef,
+}
+
+impl EnderChestBlock {
+    /// Creates a new ender chest block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+}
+
+impl BlockBehavior for EnderChestBlock {
+    fn get_state_for_placement(&self, context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(
+            self.block
+                .default_state()
+                .set_value(&FACING, context.horizontal_direction().opposite())
+                .set_value(&WATERLOGGED, context.is_water_source()),
+        )
+    }
+
+    fn update_shape(
+        &self,
+        state: BlockStateId,
+        world: &dyn ScheduledTickAccess,
+        pos: BlockPos,
+        _direction: Direction,
+        _neighbor_pos: BlockPos,
+        _neighbor_state: BlockStateId,
+    ) -> BlockStateId {
+        if state.get_value(&WATERLOGGED) {
+            let delay = world.fluid_tick_delay(&vanilla_fluids::WATER);
+            let _ = world.schedule_fluid_tick_default(pos, &vanilla_fluids::WATER, delay);
+        }
+        state
+    }
+
+    fn use_without_item(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        let Some(block_entity) = world.get_block_entity(pos) else {
+            return InteractionResult::Pass;
+        };
+
+        let above = pos.above();
+        if World::is_redstone_conductor(world.get_block_state(above), above) {
+            return InteractionResult::Success;
+        }
+
+        player
+            .ender_chest_inventory
+            .lock()
+            .set_active_chest(block_entity);
+        player.open_menu(&ChestMenuProvider::three_rows(
+            player.inventory.clone(),
+            ContainerRef::from(Arc::clone(&player.ender_chest_inventory)),
+            TextComponent::translated(translations::CONTAINER_ENDERCHEST.msg()),
+        ));
+
+        player.award_stat(Stat::custom(CustomStat::OpenEnderchest));
+        // TODO: Anger nearby piglins (PiglinAi.angerNearbyPiglins)
+
+        InteractionResult::Success
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        BLOCK_ENTITIES.create(&vanilla_block_entity_types::ENDER_CHEST, level, pos, state)
+    }
+}
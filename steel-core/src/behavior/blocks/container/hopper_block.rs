@@ -0,0 +1,175 @@
+//! Hopper block behavior implementation.
+//!
+//! Opens a 5-slot hopper menu when right-clicked. A redstone signal disables
+//! the hopper through its `ENABLED` property.
+
+use std::sync::{Arc, Weak};
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::blocks::properties::{BlockStateProperties, BoolProperty, EnumProperty};
+use steel_registry::stat::{CustomStat, Stat};
+use steel_registry::vanilla_block_entity_types;
+use steel_utils::axis::Axis;
+use steel_utils::types::UpdateFlags;
+use steel_utils::{BlockPos, BlockStateId, Direction, Downcast as _, translations};
+use text_components::TextComponent;
+
+use crate::behavior::InventoryAccess;
+use crate::behavior::block::BlockBehavior;
+use crate::behavior::context::{BlockHitResult, BlockPlaceContext, InteractionResult};
+use crate::block_entity::entities::HopperBlockEntity;
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::entity::{Entity, InsideBlockEffectCollector};
+use crate::inventory::container::calculate_redstone_signal_from_container;
+use crate::inventory::hopper_menu::HopperMenuProvider;
+use crate::inventory::lock::ContainerRef;
+use crate::player::Player;
+use crate::world::{SignalGetter as _, World};
+
+const FACING: EnumProperty<Direction> = BlockStateProperties::FACING_HOPPER;
+const ENABLED: BoolProperty = BlockStateProperties::ENABLED;
+
+/// Behavior for the hopper block.
+#[block_behavior]
+pub struct HopperBlock {
+    block: BlockRef,
+}
+
+impl HopperBlock {
+    /// Creates a new hopper block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+
+    /// Vanilla `HopperBlock.checkPoweredState`.
+    fn check_powered_state(world: &Arc<World>, pos: BlockPos, state: BlockStateId) {
+        let enabled = !world.has_neighbor_signal(pos);
+        if enabled != state.get_value(&ENABLED) {
+            world.set_block(
+                pos,
+                state.set_value(&ENABLED, enabled),
+                UpdateFlags::UPDATE_CLIENTS,
+            );
+        }
+    }
+}
+
+impl BlockBehavior for HopperBlock {
+    fn get_state_for_placement(&self, context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        let direction = context.clicked_face().opposite();
+        let facing = if direction.get_axis() == Axis::Y {
+            Direction::Down
+        } else {
+            direction
+        };
+
+        Some(
+            self.block
+                .default_state()
+                .set_value(&FACING, facing)
+                .set_value(&ENABLED, true),
+        )
+    }
+
+    fn on_place(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        old_state: BlockStateId,
+        _moved_by_piston: bool,
+    ) {
+        if old_state.get_block() != state.get_block() {
+            Self::check_powered_state(world, pos, state);
+        }
+    }
+
+    fn handle_neighbor_changed(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        _source_block: BlockRef,
+        _moved_by_piston: bool,
+    ) {
+        Self::check_powered_state(world, pos, state);
+    }
+
+    fn use_without_item(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        let Some(block_entity) = world.get_block_entity(pos) else {
+            return InteractionResult::Pass;
+        };
+        let Some(container_ref) = ContainerRef::from_block_entity(block_entity) else {
+            return InteractionResult::Pass;
+        };
+
+        player.open_menu(&HopperMenuProvider::new(
+            player.inventory.clone(),
+            container_ref,
+            TextComponent::translated(translations::CONTAINER_HOPPER.msg()),
+        ));
+
+        player.award_stat(Stat::custom(CustomStat::InspectHopper));
+
+        InteractionResult::Success
+    }
+
+    /// Lets items that fall into the bowl be collected right away.
+    fn entity_inside(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        entity: &dyn Entity,
+        _effect_collector: &mut InsideBlockEffectCollector,
+        _is_precise: bool,
+    ) {
+        if let Some(block_entity) = world.get_block_entity(pos)
+            && let Some(hopper) = block_entity.lock().downcast_mut::<HopperBlockEntity>()
+        {
+            hopper.entity_inside(world, entity);
+        }
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        BLOCK_ENTITIES.create(&vanilla_block_entity_types::HOPPER, level, pos, state)
+    }
+
+    fn has_analog_output_signal(&self, _state: BlockStateId) -> bool {
+        true
+    }
+
+    fn get_analog_output_signal(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+    ) -> i32 {
+        world.get_block_entity(pos).map_or(0, |be| {
+            let guard = be.lock();
+            guard
+                .as_container()
+                .map_or(0, calculate_redstone_signal_from_container)
+        })
+    }
+}
@@ -1,7 +1,23 @@
 mod barrel_block;
+mod beacon_block;
 mod beehive_block;
+mod cartography_table_block;
+mod chest_block;
+mod conduit_block;
 mod crafting_table_block;
+mod enchanting_table_block;
+mod ender_chest_block;
+mod hopper_block;
+mod shulker_box_block;
 
 pub use barrel_block::BarrelBlock;
+pub use beacon_block::BeaconBlock;
 pub use beehive_block::BeehiveBlock;
+pub use cartography_table_block::CartographyTableBlock;
+pub use chest_block::{ChestBlock, chest_containers, get_connected_direction};
+pub use conduit_block::ConduitBlock;
 pub use crafting_table_block::CraftingTableBlock;
+pub use enchanting_table_block::{EnchantingTableBlock, bookshelf_offsets, is_valid_book_shelf};
+pub use ender_chest_block::EnderChestBlock;
+pub use hopper_block::HopperBlock;
+pub use shulker_box_block::ShulkerBoxBlock;
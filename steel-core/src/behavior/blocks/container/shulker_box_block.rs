@@ -0,0 +1,190 @@
+//! Shulker box block behavior implementation.
+//!
+//! Opens a 27-slot shulker box menu when right-clicked, unless the lid is
+//! obstructed.
+
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::blocks::properties::BlockStateProperties;
+use steel_registry::data_components::vanilla_components::CONTAINER;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::stat::{CustomStat, Stat};
+use steel_registry::{REGISTRY, vanilla_block_entity_types};
+use steel_utils::{BlockPos, BlockStateId, Direction, Downcast as _, WorldAabb, translations};
+use text_components::TextComponent;
+
+use crate::behavior::InventoryAccess;
+use crate::behavior::block::BlockBehavior;
+use crate::behavior::context::{BlockHitResult, BlockPlaceContext, InteractionResult};
+use crate::block_entity::entities::ShulkerBoxBlockEntity;
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::inventory::container::{Container as _, calculate_redstone_signal_from_container};
+use crate::inventory::lock::ContainerRef;
+use crate::inventory::shulker_box_menu::ShulkerBoxMenuProvider;
+use crate::physics::{WorldCollisionProvider, has_collision};
+use crate::player::Player;
+use crate::world::World;
+
+/// Behavior for shulker boxes of every color.
+#[block_behavior]
+pub struct ShulkerBoxBlock {
+    block: BlockRef,
+}
+
+impl ShulkerBoxBlock {
+    /// Creates a new shulker box block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+}
+
+/// Returns the half block the lid sweeps through when it opens.
+///
+/// Vanilla `Shulker.getProgressDeltaAabb(1.0F, facing, 0.0F, 0.5F)` moved to `pos`.
+fn lid_aabb(facing: Direction, pos: BlockPos) -> WorldAabb {
+    let (x, y, z) = facing.offset();
+    let axis_range = |step: i32| match step {
+        1 => (1.0, 1.5),
+        -1 => (-0.5, 0.0),
+        _ => (0.0, 1.0),
+    };
+    let ((min_x, max_x), (min_y, max_y), (min_z, max_z)) =
+        (axis_range(x), axis_range(y), axis_range(z));
+    let origin = DVec3::new(f64::from(pos.x()), f64::from(pos.y()), f64::from(pos.z()));
+
+    WorldAabb::from_min_max(
+        origin + DVec3::new(min_x, min_y, min_z),
+        origin + DVec3::new(max_x, max_y, max_z),
+    )
+}
+
+/// Vanilla `ShulkerBoxBlock.canOpen`.
+///
+/// An already open box can always be opened; a closed one needs room for its lid.
+fn can_open(state: BlockStateId, world: &Arc<World>, pos: BlockPos, is_open: bool) -> bool {
+    if is_open {
+        return true;
+    }
+    let facing = state.get_value(&BlockStateProperties::FACING);
+    !has_collision(&WorldCollisionProvider::new(world), lid_aabb(facing, pos))
+}
+
+impl BlockBehavior for ShulkerBoxBlock {
+    fn get_state_for_placement(&self, context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(
+            self.block
+                .default_state()
+                .set_value(&BlockStateProperties::FACING, context.clicked_face()),
+        )
+    }
+
+    fn use_without_item(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        player: &Player,
+        _hit_result: &BlockHitResult,
+        _inv: &mut InventoryAccess,
+    ) -> InteractionResult {
+        let Some(block_entity) = world.get_block_entity(pos) else {
+            return InteractionResult::Pass;
+        };
+        let Some(is_open) = block_entity
+            .lock()
+            .downcast_ref::<ShulkerBoxBlockEntity>()
+            .map(ShulkerBoxBlockEntity::is_open)
+        else {
+            return InteractionResult::Pass;
+        };
+        if !can_open(state, world, pos, is_open) {
+            return InteractionResult::Success;
+        }
+
+        let Some(container_ref) = ContainerRef::from_block_entity(block_entity) else {
+            return InteractionResult::Pass;
+        };
+
+        player.open_menu(&ShulkerBoxMenuProvider::new(
+            player.inventory.clone(),
+            container_ref,
+            TextComponent::translated(translations::CONTAINER_SHULKER_BOX.msg()),
+        ));
+
+        player.award_stat(Stat::custom(CustomStat::OpenShulkerBox));
+        // TODO: Anger nearby piglins (PiglinAi.angerNearbyPiglins)
+
+        InteractionResult::Success
+    }
+
+    /// Drops the filled box for creative players, who get no block loot.
+    fn player_will_destroy(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        player: &Player,
+    ) -> BlockStateId {
+        if !player.has_infinite_materials() {
+            return state;
+        }
+        // TODO: Otherwise unpack the structure loot table
+        let Some(contents) = world.get_block_entity(pos).and_then(|block_entity| {
+            block_entity
+                .lock()
+                .downcast_ref::<ShulkerBoxBlockEntity>()
+                .filter(|shulker_box| !shulker_box.is_empty())
+                .map(ShulkerBoxBlockEntity::container_contents)
+        }) else {
+            return state;
+        };
+
+        let mut item = ItemStack::new(REGISTRY.items.by_block(self.block));
+        item.set(CONTAINER, contents);
+        let center = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 0.5,
+            f64::from(pos.z()) + 0.5,
+        );
+        if let Some(item_entity) = world.spawn_item(center, item) {
+            item_entity.set_default_pickup_delay();
+        }
+        state
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        BLOCK_ENTITIES.create(&vanilla_block_entity_types::SHULKER_BOX, level, pos, state)
+    }
+
+    fn has_analog_output_signal(&self, _state: BlockStateId) -> bool {
+        true
+    }
+
+    fn get_analog_output_signal(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+    ) -> i32 {
+        world.get_block_entity(pos).map_or(0, |be| {
+            let guard = be.lock();
+            guard
+                .as_container()
+                .map_or(0, calculate_redstone_signal_from_container)
+        })
+    }
+}
@@ -14,17 +14,22 @@ mod utils;
 pub mod vegetation;
 
 pub use building::{
-    AmethystBlock, AmethystClusterBlock, BarrierBlock, BedBlock, BuddingAmethystBlock,
-    CampfireBlock, DoorBlock, FenceBlock, FenceGateBlock, HayBlock, HoneyBlock, IronBarsBlock,
-    LadderBlock, LavaCauldronBlock, MagmaBlock, PotentSulfurBlock, PowderSnowBlock,
-    RotatedPillarBlock, ScaffoldingBlock, SlabBlock, SlimeBlock, SpongeBlock, StairBlock,
-    TrapDoorBlock, WallBlock, WaterloggedTransparentBlock, WeatherState, WeatheringCopper,
-    WeatheringCopperBarsBlock, WeatheringCopperDoorBlock, WeatheringCopperFullBlock,
-    WeatheringCopperGrateBlock, WeatheringCopperSlabBlock, WeatheringCopperStairBlock,
-    WeatheringCopperTrapDoorBlock, WebBlock, WetSpongeBlock,
+    AmethystBlock, AmethystClusterBlock, AnvilBlock, BarrierBlock, BedBlock, BuddingAmethystBlock,
+    CampfireBlock, CommandBlock, DoorBlock, DropExperienceBlock, FenceBlock, FenceGateBlock,
+    HayBlock, HoneyBlock, IceBlock, IronBarsBlock, JigsawBlock, LadderBlock, LavaCauldronBlock,
+    MagmaBlock, PotentSulfurBlock, PowderSnowBlock, RotatedPillarBlock, ScaffoldingBlock,
+    SculkShriekerBlock, SlabBlock, SlimeBlock, SpawnerBlock, SpongeBlock, StairBlock,
+    StructureBlock, TrapDoorBlock, WallBlock, WaterloggedTransparentBlock, WeatherState,
+    WeatheringCopper, WeatheringCopperBarsBlock, WeatheringCopperDoorBlock,
+    WeatheringCopperFullBlock, WeatheringCopperGrateBlock, WeatheringCopperSlabBlock,
+    WeatheringCopperStairBlock, WeatheringCopperTrapDoorBlock, WebBlock, WetSpongeBlock,
+};
+pub use colored::{StainedGlassBlock, StainedGlassPaneBlock};
+pub use container::{
+    BarrelBlock, BeaconBlock, BeehiveBlock, CartographyTableBlock, ChestBlock, ConduitBlock,
+    CraftingTableBlock, EnchantingTableBlock, EnderChestBlock, HopperBlock, ShulkerBoxBlock,
+    bookshelf_offsets, chest_containers, get_connected_direction, is_valid_book_shelf,
 };
-pub use colored::StainedGlassPaneBlock;
-pub use container::{BarrelBlock, BeehiveBlock, CraftingTableBlock};
 pub use decoration::{
     CakeBlock, CandleBlock, CandleCakeBlock, CeilingHangingSignBlock, ChainBlock,
     StandingSignBlock, TorchBlock, WallHangingSignBlock, WallSignBlock, WallTorchBlock,
@@ -35,7 +40,9 @@ pub use portal::{
     EndGatewayBlock, EndPortalBlock, EndPortalFrameBlock, FireBlock, NetherPortalBlock,
     SoulFireBlock,
 };
-pub use redstone::{ButtonBlock, RedstoneTorchBlock, RedstoneWallTorchBlock};
+pub use redstone::{
+    ButtonBlock, DetectorRailBlock, RedstoneTorchBlock, RedstoneWallTorchBlock, SculkSensorBlock,
+};
 pub use vegetation::{
     AzaleaBlock, BambooSaplingBlock, BambooStalkBlock, BeetrootBlock, CactusBlock,
     CactusFlowerBlock, CarrotBlock, CocoaBlock, CoralBlock, CropBlock, DoublePlantBlock,
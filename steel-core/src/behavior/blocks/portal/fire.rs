@@ -68,7 +68,8 @@ impl FireBlock {
         }
     }
 
-    fn selected_fire_can_survive_at(world: &dyn LevelReader, pos: BlockPos) -> bool {
+    /// Returns whether the fire state `get_state` picks at `pos` can survive there.
+    pub(crate) fn selected_fire_can_survive_at(world: &dyn LevelReader, pos: BlockPos) -> bool {
         SoulFireBlock::can_survive_at(world, pos) || Self::can_survive_at(world, pos)
     }
 
@@ -0,0 +1,108 @@
+//! Detector rail block behavior.
+//!
+//! Detector rails power themselves while a minecart is on them and re-check every
+//! 20 ticks until the minecart has left.
+//!
+//! Vanilla equivalent: `DetectorRailBlock`.
+
+use std::sync::Arc;
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::blocks::properties::BlockStateProperties;
+use steel_utils::types::UpdateFlags;
+use steel_utils::{BlockPos, BlockStateId, WorldAabb};
+
+use crate::behavior::block::BlockBehavior;
+use crate::entity::{Entity, InsideBlockEffectCollector};
+use crate::world::World;
+
+const PRESSED_CHECK_INTERVAL: i32 = 20;
+
+/// Behavior for the detector rail.
+#[block_behavior]
+pub struct DetectorRailBlock {
+    block: BlockRef,
+}
+
+impl DetectorRailBlock {
+    /// Creates a new detector rail block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+
+    /// Vanilla equivalent: `DetectorRailBlock.getSearchBB()`.
+    fn search_box(pos: BlockPos) -> WorldAabb {
+        let (x, y, z) = (f64::from(pos.x()), f64::from(pos.y()), f64::from(pos.z()));
+        WorldAabb::new(x + 0.2, y, z + 0.2, x + 0.8, y + 0.8, z + 0.8)
+    }
+
+    fn update_rail_neighbors(&self, world: &Arc<World>, pos: BlockPos) {
+        world.update_neighbors_at(pos, self.block);
+        world.update_neighbors_at(pos.offset(0, -1, 0), self.block);
+    }
+
+    /// Updates the powered state from the minecarts currently on the rail.
+    ///
+    /// Vanilla equivalent: `DetectorRailBlock.checkPressed()`.
+    fn check_pressed(&self, state: BlockStateId, world: &Arc<World>, pos: BlockPos) {
+        let was_pressed: bool = state.get_value(&BlockStateProperties::POWERED);
+        let pressed = world
+            .get_entities_in_aabb(&Self::search_box(pos))
+            .iter()
+            .any(|entity| entity.entity_type().is_abstract_minecart && entity.is_alive());
+
+        if pressed != was_pressed {
+            let new_state = state.set_value(&BlockStateProperties::POWERED, pressed);
+            world.set_block(pos, new_state, UpdateFlags::UPDATE_ALL);
+            // TODO: Propagate power to connected powered/activator rails once
+            // `updatePowerToConnected` has a redstone signal system to drive.
+            self.update_rail_neighbors(world, pos);
+        }
+        if pressed {
+            world.schedule_block_tick_default(pos, self.block, PRESSED_CHECK_INTERVAL);
+        }
+    }
+}
+
+impl BlockBehavior for DetectorRailBlock {
+    fn entity_inside(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        _entity: &dyn Entity,
+        _effect_collector: &mut InsideBlockEffectCollector,
+        _is_precise: bool,
+    ) {
+        let powered: bool = state.get_value(&BlockStateProperties::POWERED);
+        if !powered {
+            self.check_pressed(state, world, pos);
+        }
+    }
+
+    fn tick(&self, state: BlockStateId, world: &Arc<World>, pos: BlockPos) {
+        let powered: bool = state.get_value(&BlockStateProperties::POWERED);
+        if powered {
+            self.check_pressed(state, world, pos);
+        }
+    }
+
+    fn affect_neighbors_after_removal(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        moved_by_piston: bool,
+    ) {
+        if moved_by_piston {
+            return;
+        }
+        let powered: bool = state.get_value(&BlockStateProperties::POWERED);
+        if powered {
+            self.update_rail_neighbors(world, pos);
+        }
+    }
+}
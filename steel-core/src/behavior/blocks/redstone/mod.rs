@@ -1,5 +1,9 @@
 mod button_block;
+mod detector_rail_block;
 mod redstone_torch_block;
+mod sculk_sensor_block;
 
 pub use button_block::ButtonBlock;
+pub use detector_rail_block::DetectorRailBlock;
 pub use redstone_torch_block::{RedstoneTorchBlock, RedstoneWallTorchBlock};
+pub use sculk_sensor_block::SculkSensorBlock;
@@ -0,0 +1,304 @@
+//! Sculk sensor block behavior.
+//!
+//! A sculk sensor turns vibrations heard by its block entity into a short
+//! redstone pulse, then cools down before it can activate again. Adjacent
+//! amethyst re-emits the vibration's frequency as a resonance event.
+//!
+//! Vanilla equivalent: `SculkSensorBlock`.
+
+use std::sync::{Arc, Weak};
+
+use steel_macros::block_behavior;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::blocks::properties::{BlockStateProperties, Direction, SculkSensorPhase};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::{
+    sound_events, vanilla_block_entity_types, vanilla_entities, vanilla_fluids, vanilla_game_events,
+};
+use steel_utils::types::UpdateFlags;
+use steel_utils::value_providers::IntProvider;
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::behavior::block::BlockBehavior;
+use crate::behavior::context::BlockPlaceContext;
+use crate::block_entity::entities::SculkSensorBlockEntity;
+use crate::block_entity::{BLOCK_ENTITIES, SharedBlockEntity};
+use crate::entity::Entity;
+use crate::world::vibration::{VibrationUser as _, resonance_event};
+use crate::world::{ScheduledTickAccess, World, game_event_context::GameEventContext};
+
+/// Ticks the sensor stays active after a vibration.
+const ACTIVE_TICKS: i32 = 30;
+/// Ticks the sensor cools down before it can activate again.
+const COOLDOWN_TICKS: i32 = 10;
+/// Experience dropped when a sculk sensor is broken without silk touch.
+const SENSOR_XP: IntProvider = IntProvider::Constant(5);
+
+/// Behavior for the sculk sensor.
+#[block_behavior]
+pub struct SculkSensorBlock {
+    block: BlockRef,
+}
+
+impl SculkSensorBlock {
+    /// Creates a new sculk sensor block behavior.
+    #[must_use]
+    pub const fn new(block: BlockRef) -> Self {
+        Self { block }
+    }
+
+    /// Returns whether a sensor in `state` is idle and can hear a vibration.
+    ///
+    /// Vanilla equivalent: `SculkSensorBlock.canActivate`.
+    #[must_use]
+    pub fn can_activate(state: BlockStateId) -> bool {
+        state.try_get_value(&BlockStateProperties::SCULK_SENSOR_PHASE)
+            == Some(SculkSensorPhase::Inactive)
+    }
+
+    /// Powers the sensor for a vibration of `frequency` heard with `power`.
+    ///
+    /// Vanilla equivalent: `SculkSensorBlock.activate`.
+    pub fn activate(
+        entity: Option<&dyn Entity>,
+        world: &Arc<World>,
+        pos: BlockPos,
+        power: i32,
+        frequency: i32,
+    ) {
+        let state = world.get_block_state(pos);
+        if !Self::can_activate(state) {
+            return;
+        }
+        let block = state.get_block();
+        world.set_block(
+            pos,
+            state
+                .set_value(
+                    &BlockStateProperties::SCULK_SENSOR_PHASE,
+                    SculkSensorPhase::Active,
+                )
+                .set_value(&BlockStateProperties::POWER, power as u8),
+            UpdateFlags::UPDATE_ALL,
+        );
+        world.schedule_block_tick_default(pos, block, ACTIVE_TICKS);
+        Self::update_neighbours(world, pos, block);
+        Self::try_resonate_vibration(entity, world, pos, frequency);
+        world.game_event(
+            &vanilla_game_events::SCULK_SENSOR_TENDRILS_CLICKING,
+            pos,
+            &GameEventContext::new(entity, None),
+        );
+        if !state.get_value(&BlockStateProperties::WATERLOGGED) {
+            world.play_block_sound(
+                &sound_events::BLOCK_SCULK_SENSOR_CLICKING,
+                pos,
+                1.0,
+                rand::random::<f32>() * 0.2 + 0.8,
+                None,
+            );
+        }
+    }
+
+    /// Ends the redstone pulse and starts the cooldown.
+    ///
+    /// Vanilla equivalent: `SculkSensorBlock.deactivate`.
+    fn deactivate(world: &Arc<World>, pos: BlockPos, state: BlockStateId) {
+        let block = state.get_block();
+        world.set_block(
+            pos,
+            state
+                .set_value(
+                    &BlockStateProperties::SCULK_SENSOR_PHASE,
+                    SculkSensorPhase::Cooldown,
+                )
+                .set_value(&BlockStateProperties::POWER, 0),
+            UpdateFlags::UPDATE_ALL,
+        );
+        world.schedule_block_tick_default(pos, block, COOLDOWN_TICKS);
+        Self::update_neighbours(world, pos, block);
+    }
+
+    /// Vanilla equivalent: `SculkSensorBlock.updateNeighbours`.
+    fn update_neighbours(world: &Arc<World>, pos: BlockPos, block: BlockRef) {
+        world.update_neighbors_at(pos, block);
+        world.update_neighbors_at(pos.below(), block);
+    }
+
+    /// Lets adjacent vibration resonators re-emit `frequency`.
+    ///
+    /// Vanilla equivalent: `SculkSensorBlock.tryResonateVibration`.
+    fn try_resonate_vibration(
+        entity: Option<&dyn Entity>,
+        world: &Arc<World>,
+        pos: BlockPos,
+        frequency: i32,
+    ) {
+        for direction in Direction::ALL {
+            let neighbor = direction.relative(pos);
+            let state = world.get_block_state(neighbor);
+            if state.get_block().has_tag(&BlockTag::VIBRATION_RESONATORS) {
+                world.game_event(
+                    resonance_event(frequency),
+                    neighbor,
+                    &GameEventContext::new(entity, Some(state)),
+                );
+                world.play_block_sound(
+                    &sound_events::BLOCK_AMETHYST_BLOCK_RESONATE,
+                    neighbor,
+                    1.0,
+                    1.0,
+                    None,
+                );
+            }
+        }
+    }
+}
+
+impl BlockBehavior for SculkSensorBlock {
+    fn get_state_for_placement(&self, context: &BlockPlaceContext<'_>) -> Option<BlockStateId> {
+        Some(
+            self.block
+                .default_state()
+                .set_value(&BlockStateProperties::WATERLOGGED, context.is_full_water()),
+        )
+    }
+
+    fn update_shape(
+        &self,
+        state: BlockStateId,
+        world: &dyn ScheduledTickAccess,
+        pos: BlockPos,
+        _direction: Direction,
+        _neighbor_pos: BlockPos,
+        _neighbor_state: BlockStateId,
+    ) -> BlockStateId {
+        if state.get_value(&BlockStateProperties::WATERLOGGED) {
+            let delay = world.fluid_tick_delay(&vanilla_fluids::WATER);
+            let _ = world.schedule_fluid_tick_default(pos, &vanilla_fluids::WATER, delay);
+        }
+        state
+    }
+
+    fn on_place(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        old_state: BlockStateId,
+        _moved_by_piston: bool,
+    ) {
+        // A sensor placed with power left over, e.g. by a structure, never got
+        // its deactivation tick.
+        if old_state.get_block() != self.block
+            && state.get_value(&BlockStateProperties::POWER) > 0
+            && !world.has_scheduled_block_tick(pos, self.block)
+        {
+            world.set_block(
+                pos,
+                state.set_value(&BlockStateProperties::POWER, 0),
+                UpdateFlags::UPDATE_CLIENTS | UpdateFlags::UPDATE_KNOWN_SHAPE,
+            );
+        }
+    }
+
+    fn tick(&self, state: BlockStateId, world: &Arc<World>, pos: BlockPos) {
+        match state.get_value(&BlockStateProperties::SCULK_SENSOR_PHASE) {
+            SculkSensorPhase::Active => Self::deactivate(world, pos, state),
+            SculkSensorPhase::Cooldown => {
+                world.set_block(
+                    pos,
+                    state.set_value(
+                        &BlockStateProperties::SCULK_SENSOR_PHASE,
+                        SculkSensorPhase::Inactive,
+                    ),
+                    UpdateFlags::UPDATE_ALL,
+                );
+                if !state.get_value(&BlockStateProperties::WATERLOGGED) {
+                    world.play_block_sound(
+                        &sound_events::BLOCK_SCULK_SENSOR_CLICKING_STOP,
+                        pos,
+                        1.0,
+                        rand::random::<f32>() * 0.2 + 0.8,
+                        None,
+                    );
+                }
+            }
+            SculkSensorPhase::Inactive => {}
+        }
+    }
+
+    fn affect_neighbors_after_removal(
+        &self,
+        state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        _moved_by_piston: bool,
+    ) {
+        if state.get_value(&BlockStateProperties::SCULK_SENSOR_PHASE) == SculkSensorPhase::Active {
+            Self::update_neighbours(world, pos, self.block);
+        }
+    }
+
+    /// Stepping on an idle sensor always reaches it, even through occluding blocks.
+    fn step_on(&self, state: BlockStateId, world: &Arc<World>, pos: BlockPos, entity: &dyn Entity) {
+        if Self::can_activate(state)
+            && entity.entity_type() != &vanilla_entities::WARDEN
+            && let Some(block_entity) = world.get_block_entity(pos)
+        {
+            let listener = block_entity
+                .lock()
+                .downcast_ref::<SculkSensorBlockEntity>()
+                .map(|sensor| Arc::clone(sensor.listener()));
+            if let Some(listener) = listener
+                && listener.user().can_receive_vibration(
+                    world,
+                    pos,
+                    &vanilla_game_events::STEP,
+                    &GameEventContext::new(None, Some(state)),
+                )
+            {
+                listener.force_schedule_vibration(
+                    world,
+                    &vanilla_game_events::STEP,
+                    &GameEventContext::new(Some(entity), None),
+                    entity.position(),
+                );
+            }
+        }
+        self.default_step_on(state, world, pos, entity);
+    }
+
+    fn spawn_after_break(
+        &self,
+        _state: BlockStateId,
+        world: &Arc<World>,
+        pos: BlockPos,
+        tool: &ItemStack,
+        drop_experience: bool,
+    ) {
+        if drop_experience {
+            world.try_drop_experience(pos, tool, &SENSOR_XP);
+        }
+    }
+
+    fn has_block_entity(&self) -> bool {
+        true
+    }
+
+    fn new_block_entity(
+        &self,
+        level: Weak<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> Option<SharedBlockEntity> {
+        Some(BLOCK_ENTITIES.create_or_raw(
+            &vanilla_block_entity_types::SCULK_SENSOR,
+            level,
+            pos,
+            state,
+        ))
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use steel_macros::block_behavior;
 use steel_registry::blocks::block_state_ext::BlockStateExt;
 use steel_registry::blocks::properties::BlockStateProperties;
@@ -7,8 +9,9 @@ use steel_utils::{BlockPos, BlockStateId, Direction};
 
 use crate::behavior::block::BlockBehavior;
 use crate::behavior::context::BlockPlaceContext;
+use crate::chunk::light::LightLayer;
 use crate::entity::ai::path::PathComputationType;
-use crate::world::LevelReader;
+use crate::world::{LevelReader, World};
 
 use super::BlockRef;
 
@@ -18,7 +21,9 @@ use super::BlockRef;
 /// 2. If below is in `support_override_snow_layer`, true.
 /// 3. Otherwise: below's collision shape has a full UP face, or below is snow
 ///    with `LAYERS = 8`.
-// TODO: Implement melting, layering on placement, and entity step damage.
+///
+/// Random ticks melt the whole stack once block light rises above 11.
+// TODO: Implement layering on placement and entity step damage.
 #[block_behavior]
 pub struct SnowLayerBlock {
     block: BlockRef,
@@ -63,4 +68,15 @@ impl BlockBehavior for SnowLayerBlock {
         self.can_survive(state, context.world, context.place_pos())
             .then_some(state)
     }
+
+    fn is_randomly_ticking(&self, _state: BlockStateId) -> bool {
+        true
+    }
+
+    fn random_tick(&self, state: BlockStateId, world: &Arc<World>, pos: BlockPos) {
+        if world.light_value_at(LightLayer::Block, pos) > 11 {
+            world.drop_resources(state, pos);
+            world.remove_block(pos);
+        }
+    }
 }
@@ -0,0 +1,228 @@
+//! Server-side handling of the vanilla `consumable` item component.
+//!
+//! Mirrors vanilla `Consumable`: starting to eat or drink, the periodic use
+//! sounds, and what happens when the item is finally consumed.
+
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::consume_effect::{
+    ApplyStatusEffectsConsumeEffect, ClearAllStatusEffectsConsumeEffect, ConsumeEffectData,
+    PlaySoundConsumeEffect, RemoveStatusEffectsConsumeEffect,
+};
+use steel_registry::data_components::components::{
+    Consumable, FoodProperties, ItemUseAnimation, OminousBottleAmplifier,
+};
+use steel_registry::data_components::vanilla_components::{FOOD, OMINOUS_BOTTLE_AMPLIFIER};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::stat::Stat;
+use steel_registry::{sound_events, vanilla_game_events, vanilla_mob_effects};
+
+use crate::behavior::{InteractionResult, UseItemContext};
+use crate::entity::{Entity, LivingEntity, MobEffectInstance};
+use crate::player::{CriteriaTrigger, Player};
+
+/// Returns vanilla `Consumable.consumeTicks`.
+#[must_use]
+pub(crate) fn consume_ticks(consumable: &Consumable) -> i32 {
+    (consumable.consume_seconds() * 20.0) as i32
+}
+
+/// Returns vanilla `Consumable.canConsume`.
+fn can_consume(player: &Player, stack: &ItemStack) -> bool {
+    stack
+        .get(FOOD)
+        .is_none_or(|food| player.can_eat(food.can_always_eat()))
+}
+
+/// Vanilla `Consumable.startConsuming`.
+///
+/// Items with a consume time start being used; instant ones are consumed right away.
+pub(crate) fn start_consuming(
+    consumable: &Consumable,
+    context: &UseItemContext,
+) -> InteractionResult {
+    let stack = context.inv.with_item(|item| item.clone());
+    if !can_consume(context.player, &stack) {
+        return InteractionResult::Fail;
+    }
+
+    if consume_ticks(consumable) > 0 {
+        context.player.start_using_item(context.hand);
+        return InteractionResult::Consume;
+    }
+
+    let result = on_consume(consumable, stack, context.player);
+    context
+        .inv
+        .with_inventory(|inventory| inventory.set_item_in_hand(context.hand, result));
+    InteractionResult::Consume
+}
+
+/// Returns vanilla `Consumable.shouldEmitParticlesAndSounds`.
+#[must_use]
+pub(crate) fn should_emit_particles_and_sounds(
+    consumable: &Consumable,
+    remaining_ticks: i32,
+) -> bool {
+    remaining_ticks <= consume_ticks(consumable) - 7 && remaining_ticks % 4 == 0
+}
+
+/// Vanilla `Consumable.emitParticlesAndSounds`.
+///
+/// The item particles are spawned client-side, so only the sound is sent.
+pub(crate) fn emit_sounds(consumable: &Consumable, player: &Player) {
+    let Some(sound) = consumable.sound().registry_ref() else {
+        // TODO: Support direct sound holders when entity sound playback can send them.
+        return;
+    };
+    let Some(world) = player.level() else {
+        return;
+    };
+
+    let volume = if rand::random::<bool>() { 0.5 } else { 1.0 };
+    let pitch = 1.0 + 0.2 * (rand::random::<f32>() - rand::random::<f32>());
+    world.play_sound_at(
+        sound,
+        player.sound_source(),
+        player.position(),
+        volume,
+        pitch,
+        Some(player.id()),
+    );
+}
+
+/// Vanilla `Consumable.onConsume`.
+///
+/// Applies the food and consume effects and returns the shrunk stack.
+#[must_use]
+pub(crate) fn on_consume(
+    consumable: &Consumable,
+    mut stack: ItemStack,
+    player: &Player,
+) -> ItemStack {
+    emit_sounds(consumable, player);
+    player.award_stat(Stat::used(stack.item()));
+    player.trigger_criteria(CriteriaTrigger::ConsumeItem(&stack));
+
+    if let Some(food) = stack.get(FOOD) {
+        on_consume_food(food, consumable, player);
+    }
+    if let Some(amplifier) = stack.get(OMINOUS_BOTTLE_AMPLIFIER) {
+        player.add_mob_effect(
+            MobEffectInstance::with_duration(
+                vanilla_mob_effects::BAD_OMEN,
+                OminousBottleAmplifier::EFFECT_DURATION,
+                amplifier.value(),
+            )
+            .with_ambient(false)
+            .with_visible(false)
+            .with_show_icon(true),
+        );
+    }
+    // TODO: Potion contents and suspicious stew consume listeners.
+
+    for effect in consumable.on_consume_effects() {
+        apply_consume_effect(effect, player);
+    }
+
+    player.game_event(if consumable.animation() == ItemUseAnimation::Drink {
+        &vanilla_game_events::DRINK
+    } else {
+        &vanilla_game_events::EAT
+    });
+
+    if !player.has_infinite_materials() {
+        stack.shrink(1);
+    }
+    stack
+}
+
+/// Vanilla `FoodProperties.onConsume`.
+fn on_consume_food(food: &FoodProperties, consumable: &Consumable, player: &Player) {
+    let Some(world) = player.level() else {
+        return;
+    };
+
+    if let Some(sound) = consumable.sound().registry_ref() {
+        world.play_sound_at(
+            sound,
+            SoundSource::Neutral,
+            player.position(),
+            1.0,
+            1.0 + 0.4 * (rand::random::<f32>() - rand::random::<f32>()),
+            None,
+        );
+    }
+
+    player.food_data.lock().eat_food(food);
+    world.play_sound_at(
+        &sound_events::ENTITY_PLAYER_BURP,
+        SoundSource::Players,
+        player.position(),
+        0.5,
+        0.9 + rand::random::<f32>() * 0.1,
+        None,
+    );
+}
+
+/// Applies one vanilla `ConsumeEffect` to the consuming player.
+fn apply_consume_effect(effect: &ConsumeEffectData, player: &Player) {
+    if let Some(apply) = effect.downcast_ref::<ApplyStatusEffectsConsumeEffect>() {
+        if rand::random::<f32>() < apply.probability() {
+            for instance in apply.effects() {
+                player.add_mob_effect(instance.clone());
+            }
+        }
+    } else if let Some(remove) = effect.downcast_ref::<RemoveStatusEffectsConsumeEffect>() {
+        for active in player.active_mob_effects() {
+            if remove.effects().contains(active.effect()) {
+                player.remove_mob_effect(active.effect());
+            }
+        }
+    } else if effect
+        .downcast_ref::<ClearAllStatusEffectsConsumeEffect>()
+        .is_some()
+    {
+        for active in player.active_mob_effects() {
+            player.remove_mob_effect(active.effect());
+        }
+    } else if let Some(play_sound) = effect.downcast_ref::<PlaySoundConsumeEffect>() {
+        if let Some(sound) = play_sound.sound().registry_ref()
+            && let Some(world) = player.level()
+        {
+            world.play_sound_at(
+                sound,
+                player.sound_source(),
+                player.position(),
+                1.0,
+                1.0,
+                None,
+            );
+        }
+    }
+    // TODO: teleport_randomly (chorus fruit) once random entity teleports exist.
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::data_components::vanilla_components::CONSUMABLE;
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::vanilla_items;
+
+    use super::*;
+
+    #[test]
+    fn bread_emits_use_effects_every_four_ticks_after_the_first_seven() {
+        init_test_registry();
+
+        let bread = ItemStack::new(&vanilla_items::BREAD);
+        let Some(consumable) = bread.get(CONSUMABLE) else {
+            panic!("bread should be consumable");
+        };
+
+        assert_eq!(consume_ticks(consumable), 32);
+        assert!(!should_emit_particles_and_sounds(consumable, 28));
+        assert!(!should_emit_particles_and_sounds(consumable, 26));
+        assert!(should_emit_particles_and_sounds(consumable, 24));
+        assert!(should_emit_particles_and_sounds(consumable, 4));
+    }
+}
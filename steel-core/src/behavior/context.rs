@@ -594,6 +594,31 @@ impl InventoryAccess {
     }
 }
 
+/// A player using an item on a block, as seen by the stages of the use-item-on
+/// pipeline that run before block and item behaviors.
+pub struct BlockInteraction<'a> {
+    /// The player using the item.
+    pub player: &'a Player,
+    /// Which hand the item is in.
+    pub hand: InteractionHand,
+    /// Information about where the block was hit.
+    pub hit_result: &'a BlockHitResult,
+    /// The world where the interaction is happening.
+    pub world: &'a Arc<World>,
+}
+
+impl BlockInteraction<'_> {
+    /// Returns a copy of the item in the interacting hand.
+    #[must_use]
+    pub fn held_item(&self) -> ItemStack {
+        self.player
+            .inventory
+            .lock()
+            .get_item_in_hand(self.hand)
+            .clone()
+    }
+}
+
 /// Context for using an item on a block.
 ///
 /// Immutable fields (`player`, `hand`, `world`, `hit_result`) can be accessed
@@ -1,14 +1,19 @@
 //! Item behavior trait and registry.
 
+use steel_registry::data_components::vanilla_components::CONSUMABLE;
 use steel_registry::item_stack::ItemStack;
 use steel_registry::items::ItemRef;
 use steel_registry::{REGISTRY, RegistryEntry, RegistryExt};
 use steel_utils::types::InteractionHand;
 
+use crate::behavior::consumable;
 use crate::behavior::items::DefaultItemBehavior;
 use crate::behavior::{InteractionResult, UseItemContext, UseOnContext};
 use crate::entity::damage::DamageSource;
 use crate::entity::{Entity, LivingEntity};
+use crate::inventory::lock::ContainerLockGuard;
+use crate::inventory::menu::ClickAction;
+use crate::inventory::slot::SlotType;
 use crate::player::Player;
 
 /// Trait defining the behavior of an item.
@@ -36,6 +41,24 @@ pub trait ItemBehavior: Send + Sync {
         InteractionResult::Pass
     }
 
+    /// Returns vanilla `Item.getUseDuration`, how many ticks this item can be used for.
+    fn get_use_duration(&self, stack: &ItemStack) -> i32 {
+        stack.get(CONSUMABLE).map_or(0, consumable::consume_ticks)
+    }
+
+    /// Called by vanilla `Item.finishUsingItem` once the use duration runs out.
+    ///
+    /// Returns the stack to leave in the player's hand.
+    fn finish_using_item(&self, stack: ItemStack, player: &Player) -> ItemStack {
+        match stack.get(CONSUMABLE).cloned() {
+            Some(consumable) => consumable::on_consume(&consumable, stack, player),
+            None => stack,
+        }
+    }
+
+    /// Called by vanilla `Item.releaseUsing` when the player stops using this item early.
+    fn release_using(&self, _stack: &ItemStack, _player: &Player, _remaining_ticks: i32) {}
+
     /// Called by vanilla `ItemStack.interactLivingEntity`.
     fn interact_living_entity(
         &self,
@@ -81,6 +104,37 @@ pub trait ItemBehavior: Send + Sync {
     ) {
     }
 
+    /// Called when this item is carried and clicked onto `slot` in a menu.
+    ///
+    /// Returns true if the click was handled. Based on Java's
+    /// `Item.overrideStackedOnOther`.
+    fn override_stacked_on_other(
+        &self,
+        _stack: &mut ItemStack,
+        _slot: &SlotType,
+        _guard: &mut ContainerLockGuard,
+        _action: ClickAction,
+        _player: &Player,
+    ) -> bool {
+        false
+    }
+
+    /// Called when `carried` is clicked onto a slot holding this item.
+    ///
+    /// Returns true if the click was handled. Based on Java's
+    /// `Item.overrideOtherStackedOnMe`.
+    fn override_other_stacked_on_me(
+        &self,
+        _stack: &mut ItemStack,
+        _carried: &mut ItemStack,
+        _slot: &SlotType,
+        _guard: &ContainerLockGuard,
+        _action: ClickAction,
+        _player: &Player,
+    ) -> bool {
+        false
+    }
+
     /// Returns how much durability this weapon consumes after a successful entity hit.
     fn item_damage_per_attack(&self, stack: &ItemStack) -> Option<i32> {
         stack
@@ -0,0 +1,84 @@
+//! Armor stand item behavior.
+//!
+//! Places an armor stand facing the player, snapped to 45 degrees. Mirrors
+//! vanilla `ArmorStandItem.useOn`.
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use steel_macros::item_behavior;
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::data_components::vanilla_components::CUSTOM_NAME;
+use steel_registry::{sound_events, vanilla_entities, vanilla_game_events};
+use steel_utils::Direction;
+
+use crate::behavior::{InteractionResult, ItemBehavior, UseOnContext};
+use crate::entity::entities::ArmorStandEntity;
+use crate::entity::{Entity, next_entity_id};
+use crate::physics::{WorldCollisionProvider, has_collision};
+use crate::world::game_event_context::GameEventContext;
+
+/// Behavior for vanilla `ArmorStandItem`.
+#[item_behavior]
+pub struct ArmorStandItem;
+
+impl ItemBehavior for ArmorStandItem {
+    fn use_on(&self, context: &mut UseOnContext) -> InteractionResult {
+        if context.hit_result.direction == Direction::Down {
+            return InteractionResult::Fail;
+        }
+
+        let world = context.world;
+        let pos = context.build_place_context().place_pos();
+        let bottom_center = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()),
+            f64::from(pos.z()) + 0.5,
+        );
+        let armor_stand = Arc::new(ArmorStandEntity::new(
+            &vanilla_entities::ARMOR_STAND,
+            next_entity_id(),
+            bottom_center,
+            Arc::downgrade(world),
+        ));
+        let bounding_box = armor_stand.bounding_box();
+        if has_collision(&WorldCollisionProvider::new(world), bounding_box)
+            || !world
+                .get_entities_in_aabb_matching(&bounding_box, |_| true)
+                .is_empty()
+        {
+            return InteractionResult::Fail;
+        }
+
+        let (player_yaw, _) = context.player.rotation();
+        // `Mth.wrapDegrees(yaw - 180)` simplifies to this.
+        let wrapped = player_yaw.rem_euclid(360.0) - 180.0;
+        let yaw = ((wrapped + 22.5) / 45.0).floor() * 45.0;
+        armor_stand.set_rotation((yaw, 0.0));
+        if let Some(custom_name) = context.inv.with_item(|item| item.get(CUSTOM_NAME).cloned()) {
+            armor_stand.set_custom_name(Some(custom_name));
+        }
+
+        if let Err(error) = world.try_add_entity(Arc::clone(&armor_stand) as _) {
+            log::debug!("failed to spawn armor stand: {error}");
+            return InteractionResult::Fail;
+        }
+        world.play_sound_at(
+            &sound_events::ENTITY_ARMOR_STAND_PLACE,
+            SoundSource::Blocks,
+            bottom_center,
+            0.75,
+            0.8,
+            None,
+        );
+        world.game_event_at(
+            &vanilla_game_events::ENTITY_PLACE,
+            bottom_center,
+            &GameEventContext::new(Some(context.player), None),
+        );
+
+        context.inv.with_item(|item| item.shrink(1));
+
+        InteractionResult::Success
+    }
+}
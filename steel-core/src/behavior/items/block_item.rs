@@ -3,6 +3,7 @@
 use steel_macros::item_behavior;
 use steel_registry::{
     blocks::{BlockRef, block_state_ext::BlockStateExt},
+    item_stack::ItemStack,
     vanilla_blocks, vanilla_game_events,
 };
 use steel_utils::{BlockStateId, types::UpdateFlags};
@@ -11,6 +12,7 @@ use crate::behavior::context::{BlockPlaceContext, InteractionResult, UseOnContex
 use crate::behavior::{BLOCK_BEHAVIORS, ItemBehavior};
 use crate::entity::Entity;
 use crate::fluid::{FluidStateExt as _, get_fluid_state};
+use crate::player::CriteriaTrigger;
 use crate::world::game_event_context::GameEventContext;
 
 /// Behavior for items that place blocks.
@@ -60,8 +62,17 @@ impl BlockItem {
 
         let placed_state = context.world.get_block_state(place_pos);
         if placed_state.get_block() == self.block {
+            if let Some(block_entity) = context.world.get_block_entity(place_pos) {
+                let item = context.with_item(ItemStack::clone);
+                let mut block_entity = block_entity.lock();
+                block_entity.apply_implicit_components(&item);
+                block_entity.set_changed();
+            }
             let placed_behavior = BLOCK_BEHAVIORS.get_behavior(placed_state.get_block());
             placed_behavior.set_placed_by(placed_state, context.world, place_pos, context.source());
+            if let Some(player) = context.player() {
+                player.trigger_criteria(CriteriaTrigger::PlacedBlock(placed_state));
+            }
         }
 
         // Play place sound (exclude the placing player, they hear it client-side)
@@ -0,0 +1,100 @@
+//! Boat item behavior.
+//!
+//! Places a boat where the player's view ray hits a block or fluid surface, facing
+//! the player's yaw. Mirrors vanilla `BoatItem.use`.
+
+use std::sync::Arc;
+
+use steel_macros::item_behavior;
+use steel_registry::data_components::vanilla_components::CUSTOM_NAME;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::stat::Stat;
+use steel_registry::vanilla_game_events;
+
+use crate::behavior::{InteractionResult, ItemBehavior, UseItemContext};
+use crate::entity::{ENTITIES, Entity, next_entity_id};
+use crate::physics::{WorldCollisionProvider, has_collision};
+use crate::world::game_event_context::GameEventContext;
+use crate::world::{ClipBlockShape, ClipFluid};
+
+/// Vanilla `BoatItem` entity search distance along the player's view.
+const ENTITY_SEARCH_DISTANCE: f64 = 5.0;
+
+/// Behavior for boat items.
+#[item_behavior]
+pub struct BoatItem {
+    #[json_arg(vanilla_entities, json = "entity_type")]
+    entity_type: EntityTypeRef,
+}
+
+impl BoatItem {
+    /// Creates a boat item behavior for one boat entity type.
+    #[must_use]
+    pub const fn new(entity_type: EntityTypeRef) -> Self {
+        Self { entity_type }
+    }
+}
+
+impl ItemBehavior for BoatItem {
+    fn use_item(&self, context: &mut UseItemContext) -> InteractionResult {
+        let player = context.player;
+        let world = context.world;
+
+        let (start, end) = player.get_ray_endpoints();
+        let hit = world.clip(start, end, ClipBlockShape::Outline, ClipFluid::Any);
+        if hit.miss {
+            return InteractionResult::Pass;
+        }
+
+        let (yaw, pitch) = player.rotation();
+        let view = player.calculate_view_vector(pitch, yaw);
+        let search_box = player
+            .bounding_box()
+            .expand_towards(view * ENTITY_SEARCH_DISTANCE)
+            .inflate(1.0);
+        let eye = start;
+        let blocked_by_entity = world
+            .get_entities_in_aabb_matching(&search_box, |entity| {
+                entity.id() != player.id() && !entity.is_spectator() && entity.is_pickable()
+            })
+            .iter()
+            .any(|entity| entity.bounding_box().contains(eye));
+        if blocked_by_entity {
+            return InteractionResult::Pass;
+        }
+
+        let Some(boat) = ENTITIES.create(
+            self.entity_type,
+            next_entity_id(),
+            hit.location,
+            Arc::downgrade(world),
+        ) else {
+            return InteractionResult::Fail;
+        };
+        boat.set_rotation((yaw, 0.0));
+        if let Some(custom_name) = context.inv.with_item(|item| item.get(CUSTOM_NAME).cloned()) {
+            boat.set_custom_name(Some(custom_name));
+        }
+        if has_collision(
+            &WorldCollisionProvider::for_entity(world, boat.as_ref()),
+            boat.bounding_box(),
+        ) {
+            return InteractionResult::Fail;
+        }
+
+        if let Err(error) = world.try_add_entity(Arc::clone(&boat)) {
+            log::debug!("failed to spawn boat: {error}");
+            return InteractionResult::Fail;
+        }
+        world.game_event_at(
+            &vanilla_game_events::ENTITY_PLACE,
+            hit.location,
+            &GameEventContext::new(Some(player), None),
+        );
+
+        player.award_stat(Stat::used(context.inv.with_item(|item| item.item())));
+        context.inv.with_item(|item| item.shrink(1));
+
+        InteractionResult::Success
+    }
+}
@@ -0,0 +1,92 @@
+//! Writable and written book item behaviors.
+//!
+//! Writable books are edited entirely on the client, which sends the pages
+//! back in `SEditBook`. Written books are opened by the server, which first
+//! resolves any score, selector and NBT text on their pages for the reader.
+
+use steel_macros::item_behavior;
+use steel_protocol::packets::game::COpenBook;
+use steel_registry::data_components::components::{Filterable, WrittenBookContent};
+use steel_registry::data_components::vanilla_components::WRITTEN_BOOK_CONTENT;
+use steel_registry::stat::Stat;
+use text_components::TextComponent;
+
+use crate::behavior::context::{InteractionResult, UseItemContext};
+use crate::behavior::item::ItemBehavior;
+use crate::player::Player;
+
+/// Behavior for vanilla `WritableBookItem`.
+#[item_behavior]
+pub struct WritableBookItem;
+
+impl ItemBehavior for WritableBookItem {
+    fn use_item(&self, context: &mut UseItemContext) -> InteractionResult {
+        context
+            .player
+            .award_stat(Stat::used(context.inv.with_item(|item| item.item())));
+        InteractionResult::Success
+    }
+}
+
+/// Behavior for vanilla `WrittenBookItem`.
+#[item_behavior]
+pub struct WrittenBookItem;
+
+impl ItemBehavior for WrittenBookItem {
+    fn use_item(&self, context: &mut UseItemContext) -> InteractionResult {
+        let player = context.player;
+        // Resolved without the inventory lock, as NBT text can read the
+        // player's own inventory.
+        let content = context
+            .inv
+            .with_item(|item| item.get(WRITTEN_BOOK_CONTENT).cloned());
+        if let Some(content) = content {
+            if let Some(resolved) = resolve_for_player(&content, player) {
+                context.inv.with_item(|item| {
+                    if item.get(WRITTEN_BOOK_CONTENT) == Some(&content) {
+                        item.set(WRITTEN_BOOK_CONTENT, resolved);
+                    }
+                });
+            }
+            player.send_packet(COpenBook { hand: context.hand });
+        }
+        player.award_stat(Stat::used(context.inv.with_item(|item| item.item())));
+        InteractionResult::Success
+    }
+}
+
+/// Vanilla `WrittenBookContent.resolveForItem`: resolves the pages for
+/// `player` the first time the book is opened. A book whose resolved pages
+/// would be too long is only marked resolved.
+fn resolve_for_player(content: &WrittenBookContent, player: &Player) -> Option<WrittenBookContent> {
+    if content.resolved() {
+        return None;
+    }
+    let resolve_page = |page: &TextComponent| {
+        let resolved = page.resolve(player);
+        serde_json::to_string(&resolved)
+            .is_ok_and(|json| json.len() <= WrittenBookContent::PAGE_LENGTH)
+            .then_some(resolved)
+    };
+    let pages = content
+        .pages()
+        .iter()
+        .map(|page| {
+            let filtered = match page.filtered() {
+                Some(filtered) => Some(resolve_page(filtered)?),
+                None => None,
+            };
+            Some(Filterable::new(resolve_page(page.raw())?, filtered))
+        })
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_else(|| content.pages().to_vec());
+    WrittenBookContent::new(
+        content.title().clone(),
+        content.author().to_owned(),
+        content.generation(),
+        pages,
+        true,
+    )
+    .inspect_err(|error| log::debug!("Could not resolve written book: {error}"))
+    .ok()
+}
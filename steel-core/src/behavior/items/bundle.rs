@@ -0,0 +1,124 @@
+//! Bundle item behavior implementation.
+//!
+//! Bundles hold a mix of items up to one stack's worth of weight. Items go in
+//! and come out through clicks in any menu.
+
+use steel_macros::item_behavior;
+use steel_registry::data_components::vanilla_components::BUNDLE_CONTENTS;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::sound_events;
+
+use crate::behavior::ItemBehavior;
+use crate::entity::Entity as _;
+use crate::inventory::lock::ContainerLockGuard;
+use crate::inventory::menu::ClickAction;
+use crate::inventory::slot::{Slot as _, SlotType};
+use crate::player::Player;
+
+/// Behavior for vanilla `BundleItem`.
+// TODO: Selecting the item to take out (ServerboundSelectBundleItemPacket) and
+// dropping the contents on use
+#[item_behavior]
+pub struct BundleItem;
+
+impl BundleItem {
+    fn play_sound(player: &Player, sound: SoundEventRef) {
+        player.play_sound(sound, 0.8, 0.8 + rand::random::<f32>() * 0.4);
+    }
+
+    fn play_insert_sound(player: &Player, inserted: bool) {
+        Self::play_sound(
+            player,
+            if inserted {
+                &sound_events::ITEM_BUNDLE_INSERT
+            } else {
+                &sound_events::ITEM_BUNDLE_INSERT_FAIL
+            },
+        );
+    }
+}
+
+impl ItemBehavior for BundleItem {
+    /// Puts the clicked slot's items into the carried bundle, or takes one entry out.
+    fn override_stacked_on_other(
+        &self,
+        stack: &mut ItemStack,
+        slot: &SlotType,
+        guard: &mut ContainerLockGuard,
+        action: ClickAction,
+        player: &Player,
+    ) -> bool {
+        let Some(mut contents) = stack.get(BUNDLE_CONTENTS).cloned() else {
+            return false;
+        };
+        let other = slot.get_item(guard).clone();
+
+        match action {
+            ClickAction::Primary if !other.is_empty() => {
+                // Vanilla `BundleContents.Mutable.tryTransfer`
+                let inserted = if other.can_fit_inside_container_items() {
+                    let max = contents.max_amount_to_add(&other);
+                    let mut taken = slot.safe_take(guard, other.count(), max, player);
+                    let inserted = contents.try_insert(&mut taken);
+                    if !taken.is_empty() {
+                        player.add_item_or_drop_with_guard(guard, taken);
+                    }
+                    inserted
+                } else {
+                    0
+                };
+                Self::play_insert_sound(player, inserted > 0);
+            }
+            ClickAction::Secondary if other.is_empty() => {
+                if let Some(removed) = contents.remove_one() {
+                    let mut remainder = slot.safe_insert(guard, removed);
+                    if remainder.is_empty() {
+                        Self::play_sound(player, &sound_events::ITEM_BUNDLE_REMOVE_ONE);
+                    } else {
+                        contents.try_insert(&mut remainder);
+                    }
+                }
+            }
+            _ => return false,
+        }
+
+        stack.set(BUNDLE_CONTENTS, contents);
+        true
+    }
+
+    /// Puts the carried items into the clicked bundle, or takes one entry out.
+    fn override_other_stacked_on_me(
+        &self,
+        stack: &mut ItemStack,
+        carried: &mut ItemStack,
+        slot: &SlotType,
+        guard: &ContainerLockGuard,
+        action: ClickAction,
+        player: &Player,
+    ) -> bool {
+        let Some(mut contents) = stack.get(BUNDLE_CONTENTS).cloned() else {
+            return false;
+        };
+
+        match action {
+            ClickAction::Primary if !carried.is_empty() => {
+                let inserted =
+                    slot.allow_modification(guard, player) && contents.try_insert(carried) > 0;
+                Self::play_insert_sound(player, inserted);
+            }
+            ClickAction::Secondary if carried.is_empty() => {
+                if slot.allow_modification(guard, player)
+                    && let Some(removed) = contents.remove_one()
+                {
+                    Self::play_sound(player, &sound_events::ITEM_BUNDLE_REMOVE_ONE);
+                    *carried = removed;
+                }
+            }
+            _ => return false,
+        }
+
+        stack.set(BUNDLE_CONTENTS, contents);
+        true
+    }
+}
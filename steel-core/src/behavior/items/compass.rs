@@ -0,0 +1,98 @@
+//! Compass item behavior.
+//!
+//! Using a compass on a lodestone binds it to that lodestone through the
+//! `lodestone_tracker` component. Recovery compasses need no server logic:
+//! the client points them at the death location sent in the login and
+//! respawn packets.
+
+use steel_macros::item_behavior;
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::data_components::components::{GlobalPos, LodestoneTracker};
+use steel_registry::data_components::vanilla_components::LODESTONE_TRACKER;
+use steel_registry::{RegistryEntry as _, sound_events, vanilla_blocks, vanilla_poi_types};
+
+use crate::behavior::{InteractionResult, ItemBehavior, UseOnContext};
+use crate::inventory::container::Container as _;
+use crate::player::Player;
+use crate::world::World;
+
+/// Behavior for vanilla `CompassItem`.
+#[item_behavior]
+pub struct CompassItem;
+
+impl CompassItem {
+    /// Vanilla `CompassItem.inventoryTick`: clears the target of tracked
+    /// compasses in `player`'s inventory whose lodestone is gone.
+    pub(crate) fn inventory_tick(player: &Player) {
+        let world = player.get_world();
+        let mut inventory = player.inventory.lock();
+        for slot in 0..inventory.get_container_size() {
+            let Some(tracker) = inventory.get_item(slot).get(LODESTONE_TRACKER) else {
+                continue;
+            };
+            if let Some(ticked) = tick_tracker(tracker, &world) {
+                inventory.get_item_mut(slot).set(LODESTONE_TRACKER, ticked);
+            }
+        }
+    }
+}
+
+/// Vanilla `LodestoneTracker.tick`: the tracker to replace `tracker` with, or
+/// `None` if it stays unchanged.
+fn tick_tracker(tracker: &LodestoneTracker, world: &World) -> Option<LodestoneTracker> {
+    if !tracker.tracked() {
+        return None;
+    }
+    let target = tracker.target()?;
+    if *target.dimension() != world.key {
+        return None;
+    }
+    let pos = target.pos();
+    let is_lodestone = vanilla_poi_types::LODESTONE
+        .try_id()
+        .is_some_and(|lodestone| {
+            world
+                .poi_storage
+                .lock()
+                .exists_at_position(&|type_id| type_id == lodestone, pos)
+        });
+    (!world.is_in_valid_bounds(pos) || !is_lodestone).then(|| LodestoneTracker::new(None, true))
+}
+
+impl ItemBehavior for CompassItem {
+    fn use_on(&self, context: &mut UseOnContext) -> InteractionResult {
+        let pos = context.hit_result.block_pos;
+        let world = context.world;
+        if world.get_block_state(pos).get_block() != &vanilla_blocks::LODESTONE {
+            return InteractionResult::Pass;
+        }
+
+        world.play_sound(
+            &sound_events::ITEM_LODESTONE_COMPASS_LOCK,
+            SoundSource::Players,
+            pos,
+            1.0,
+            1.0,
+            None,
+        );
+        let tracker = LodestoneTracker::new(Some(GlobalPos::new(world.key.clone(), pos)), true);
+        let infinite = context.player.has_infinite_materials();
+        let new_compass = context.inv.with_item(|item| {
+            if !infinite && item.count() == 1 {
+                item.set(LODESTONE_TRACKER, tracker);
+                return None;
+            }
+            let mut new_compass = item.copy_with_count(1);
+            new_compass.set(LODESTONE_TRACKER, tracker);
+            if !infinite {
+                item.shrink(1);
+            }
+            Some(new_compass)
+        });
+        if let Some(new_compass) = new_compass {
+            context.player.add_item_or_drop(new_compass);
+        }
+        InteractionResult::Success
+    }
+}
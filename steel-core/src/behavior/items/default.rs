@@ -1,5 +1,8 @@
 //! Default item behavior implementation.
 
+use steel_registry::data_components::vanilla_components::CONSUMABLE;
+
+use crate::behavior::consumable;
 use crate::behavior::{InteractionResult, ItemBehavior, UseItemContext};
 use crate::entity::Entity;
 use crate::player::player_inventory::EquipmentSwapResult;
@@ -9,6 +12,10 @@ pub struct DefaultItemBehavior;
 
 impl ItemBehavior for DefaultItemBehavior {
     fn use_item(&self, context: &mut UseItemContext) -> InteractionResult {
+        if let Some(consumable) = context.inv.with_item(|item| item.get(CONSUMABLE).cloned()) {
+            return consumable::start_consuming(&consumable, context);
+        }
+
         let Some(equippable) = context.inv.with_item(|item| item.get_equippable().cloned()) else {
             return InteractionResult::Pass;
         };
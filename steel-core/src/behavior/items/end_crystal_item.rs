@@ -0,0 +1,74 @@
+//! End crystal item behavior.
+//!
+//! Places an end crystal on obsidian or bedrock and lets the dragon fight
+//! check for a respawn. Mirrors vanilla `EndCrystalItem.useOn`.
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use steel_macros::item_behavior;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::{vanilla_blocks, vanilla_entities, vanilla_game_events};
+use steel_utils::WorldAabb;
+
+use crate::behavior::{InteractionResult, ItemBehavior, UseOnContext};
+use crate::entity::entities::EndCrystalEntity;
+use crate::entity::next_entity_id;
+use crate::world::game_event_context::GameEventContext;
+
+/// Behavior for vanilla `EndCrystalItem`.
+#[item_behavior]
+pub struct EndCrystalItem;
+
+impl ItemBehavior for EndCrystalItem {
+    fn use_on(&self, context: &mut UseOnContext) -> InteractionResult {
+        let world = context.world;
+        let pos = context.hit_result.block_pos;
+        let block = world.get_block_state(pos).get_block();
+        if block != &vanilla_blocks::OBSIDIAN && block != &vanilla_blocks::BEDROCK {
+            return InteractionResult::Fail;
+        }
+
+        let above = pos.above();
+        if !world.get_block_state(above).is_air() {
+            return InteractionResult::Fail;
+        }
+
+        let (x, y, z) = (
+            f64::from(above.x()),
+            f64::from(above.y()),
+            f64::from(above.z()),
+        );
+        if !world
+            .get_entities_in_aabb_matching(
+                &WorldAabb::new(x, y, z, x + 1.0, y + 2.0, z + 1.0),
+                |_| true,
+            )
+            .is_empty()
+        {
+            return InteractionResult::Fail;
+        }
+
+        let crystal = Arc::new(EndCrystalEntity::new(
+            &vanilla_entities::END_CRYSTAL,
+            next_entity_id(),
+            DVec3::new(x + 0.5, y, z + 0.5),
+            Arc::downgrade(world),
+        ));
+        crystal.set_show_bottom(false);
+        if let Err(error) = world.try_add_entity(crystal as _) {
+            log::debug!("failed to spawn end crystal: {error}");
+            return InteractionResult::Fail;
+        }
+        world.game_event(
+            &vanilla_game_events::ENTITY_PLACE,
+            above,
+            &GameEventContext::new(Some(context.player), None),
+        );
+        world.try_respawn_dragon();
+
+        context.inv.with_item(|item| item.shrink(1));
+
+        InteractionResult::Success
+    }
+}
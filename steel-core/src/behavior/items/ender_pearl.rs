@@ -9,6 +9,7 @@ use std::sync::Arc;
 use glam::DVec3;
 use steel_macros::item_behavior;
 use steel_protocol::packets::game::SoundSource;
+use steel_registry::stat::Stat;
 use steel_registry::{sound_events, vanilla_entities};
 
 use crate::behavior::context::{InteractionResult, UseItemContext};
@@ -68,7 +69,7 @@ impl ItemBehavior for EnderPearlItem {
         }
         player.register_ender_pearl(&entity);
 
-        // TODO: award the ITEM_USED stat once a stats system exists.
+        player.award_stat(Stat::used(context.inv.with_item(|item| item.item())));
         context.inv.with_item(|item| item.shrink(1));
 
         InteractionResult::Success
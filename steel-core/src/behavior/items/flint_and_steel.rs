@@ -31,10 +31,7 @@ impl ItemBehavior for FlintAndSteelItem {
             &sound_events::ITEM_FLINTANDSTEEL_USE,
             flint_and_steel_pitch(),
         ) {
-            let has_infinite_materials = context.player.has_infinite_materials();
-            context
-                .inv
-                .with_item(|item| item.hurt_and_break(1, has_infinite_materials));
+            context.player.hurt_item_in_hand(context.hand, 1);
             return InteractionResult::Success;
         }
 
@@ -65,10 +62,7 @@ impl ItemBehavior for FlintAndSteelItem {
             &GameEventContext::new(Some(context.player), None),
         );
 
-        let has_infinite_materials = context.player.has_infinite_materials();
-        context
-            .inv
-            .with_item(|item| item.hurt_and_break(1, has_infinite_materials));
+        context.player.hurt_item_in_hand(context.hand, 1);
 
         InteractionResult::Success
     }
@@ -0,0 +1,131 @@
+//! Hanging entity item behaviors for paintings and item frames.
+//!
+//! Mirrors vanilla `HangingEntityItem.useOn` and `ItemFrameItem`.
+
+use std::sync::Arc;
+
+use steel_macros::item_behavior;
+use steel_registry::data_components::vanilla_components::{CUSTOM_NAME, PAINTING_VARIANT};
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::{vanilla_entities, vanilla_game_events};
+use steel_utils::BlockPos;
+
+use crate::behavior::{InteractionResult, ItemBehavior, UseOnContext};
+use crate::entity::entities::{ItemFrameEntity, PaintingEntity};
+use crate::entity::{Entity, SharedEntity, next_entity_id};
+use crate::world::World;
+use crate::world::game_event_context::GameEventContext;
+
+/// Behavior for vanilla `HangingEntityItem` (paintings).
+#[item_behavior]
+pub struct HangingEntityItem {
+    #[json_arg(vanilla_entities, json = "type")]
+    entity_type: EntityTypeRef,
+}
+
+impl HangingEntityItem {
+    /// Creates a hanging entity item behavior for one entity type.
+    #[must_use]
+    pub const fn new(entity_type: EntityTypeRef) -> Self {
+        Self { entity_type }
+    }
+}
+
+impl ItemBehavior for HangingEntityItem {
+    fn use_on(&self, context: &mut UseOnContext) -> InteractionResult {
+        let direction = context.hit_result.direction;
+        let pos = context.hit_result.block_pos.relative(direction);
+        if !may_place(context.world, pos) {
+            return InteractionResult::Fail;
+        }
+        if self.entity_type != &vanilla_entities::PAINTING {
+            return InteractionResult::Pass;
+        }
+
+        let Some(painting) = PaintingEntity::create(context.world, pos, direction) else {
+            return InteractionResult::Consume;
+        };
+        let (variant, custom_name) = context.inv.with_item(|item| {
+            (
+                item.get(PAINTING_VARIANT)
+                    .and_then(|component| component.variant().as_reference()),
+                item.get(CUSTOM_NAME).cloned(),
+            )
+        });
+        if let Some(variant) = variant {
+            painting.set_variant(variant);
+        }
+        if custom_name.is_some() {
+            painting.set_custom_name(custom_name);
+        }
+        if !painting.survives() {
+            return InteractionResult::Consume;
+        }
+
+        painting.play_placement_sound();
+        place(context, Arc::new(painting))
+    }
+}
+
+/// Behavior for vanilla `ItemFrameItem`.
+#[item_behavior]
+pub struct ItemFrameItem {
+    #[json_arg(vanilla_entities, json = "type")]
+    entity_type: EntityTypeRef,
+}
+
+impl ItemFrameItem {
+    /// Creates an item frame item behavior for one frame entity type.
+    #[must_use]
+    pub const fn new(entity_type: EntityTypeRef) -> Self {
+        Self { entity_type }
+    }
+}
+
+impl ItemBehavior for ItemFrameItem {
+    fn use_on(&self, context: &mut UseOnContext) -> InteractionResult {
+        let direction = context.hit_result.direction;
+        let pos = context.hit_result.block_pos.relative(direction);
+        if !may_place(context.world, pos) {
+            return InteractionResult::Fail;
+        }
+
+        let frame = ItemFrameEntity::new_attached(
+            self.entity_type,
+            next_entity_id(),
+            pos,
+            direction,
+            Arc::downgrade(context.world),
+        );
+        if let Some(custom_name) = context.inv.with_item(|item| item.get(CUSTOM_NAME).cloned()) {
+            frame.set_custom_name(Some(custom_name));
+        }
+        if !frame.survives() {
+            return InteractionResult::Consume;
+        }
+
+        frame.play_placement_sound();
+        place(context, Arc::new(frame))
+    }
+}
+
+/// Vanilla `HangingEntityItem.mayPlace`.
+fn may_place(world: &World, pos: BlockPos) -> bool {
+    // TODO: check `Player.mayUseItemAt` once adventure-mode build rules exist.
+    !world.is_outside_build_height(pos.y())
+}
+
+/// Adds a surviving hanging entity to the world and consumes the item.
+fn place(context: &mut UseOnContext, entity: SharedEntity) -> InteractionResult {
+    context.world.game_event_at(
+        &vanilla_game_events::ENTITY_PLACE,
+        entity.position(),
+        &GameEventContext::new(Some(context.player), None),
+    );
+    if let Err(error) = context.world.try_add_entity(entity) {
+        log::debug!("failed to spawn hanging entity: {error}");
+        return InteractionResult::Fail;
+    }
+    context.inv.with_item(|item| item.shrink(1));
+    InteractionResult::Success
+}
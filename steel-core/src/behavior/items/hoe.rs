@@ -77,10 +77,7 @@ impl ItemBehavior for HoeItem {
             Some(context.player.id()),
         );
 
-        let has_infinite_materials = context.player.has_infinite_materials();
-        context
-            .inv
-            .with_item(|item| item.hurt_and_break(1, has_infinite_materials));
+        context.player.hurt_item_in_hand(context.hand, 1);
 
         InteractionResult::Success
     }
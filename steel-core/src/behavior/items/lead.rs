@@ -0,0 +1,77 @@
+//! Lead item behavior implementation.
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use steel_macros::item_behavior;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::{sound_events, vanilla_game_events};
+use steel_utils::BlockPos;
+
+use crate::behavior::{InteractionResult, ItemBehavior, UseOnContext};
+use crate::entity::entities::LeashFenceKnotEntity;
+use crate::entity::{Entity as _, leashables_leashed_to_holder_near};
+use crate::player::Player;
+use crate::world::World;
+use crate::world::game_event_context::GameEventContext;
+
+/// Behavior for vanilla `LeadItem`.
+#[item_behavior]
+pub struct LeadItem;
+
+impl LeadItem {
+    /// Ties every mob the player is leading to a knot on the fence at `pos`.
+    ///
+    /// Vanilla equivalent: `LeadItem.bindPlayerMobs`.
+    pub fn bind_player_mobs(
+        player: &Player,
+        world: &Arc<World>,
+        pos: BlockPos,
+    ) -> InteractionResult {
+        let center = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 0.5,
+            f64::from(pos.z()) + 0.5,
+        );
+        let leashables = leashables_leashed_to_holder_near(world, center, player);
+        if leashables.is_empty() {
+            return InteractionResult::Pass;
+        }
+        let Some(knot) = LeashFenceKnotEntity::get_or_create_knot(world, pos) else {
+            return InteractionResult::Pass;
+        };
+        knot.play_sound(&sound_events::ITEM_LEAD_TIED, 1.0, 1.0);
+
+        let mut any_leashed = false;
+        for leashable in leashables {
+            if let Some(mob) = leashable.as_mob()
+                && mob.can_have_a_leash_attached_to(knot.as_ref())
+                && mob.set_leashed_to(&knot)
+            {
+                any_leashed = true;
+            }
+        }
+
+        if !any_leashed {
+            return InteractionResult::Pass;
+        }
+        world.game_event(
+            &vanilla_game_events::BLOCK_ATTACH,
+            pos,
+            &GameEventContext::new(Some(player), None),
+        );
+        InteractionResult::SuccessServer
+    }
+}
+
+impl ItemBehavior for LeadItem {
+    fn use_on(&self, context: &mut UseOnContext) -> InteractionResult {
+        let pos = context.hit_result.block_pos;
+        let state = context.world.get_block_state(pos);
+        if !state.get_block().has_tag(&BlockTag::FENCES) {
+            return InteractionResult::Pass;
+        }
+        Self::bind_player_mobs(context.player, context.world, pos)
+    }
+}
@@ -0,0 +1,73 @@
+//! Empty and filled map item behaviors.
+
+use steel_macros::item_behavior;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::sound_events;
+use steel_registry::stat::Stat;
+use steel_registry::vanilla_block_tags::BlockTag;
+
+use crate::behavior::context::{InteractionResult, UseItemContext, UseOnContext};
+use crate::behavior::item::ItemBehavior;
+use crate::behavior::item_utils::create_filled_result;
+use crate::entity::Entity as _;
+use crate::map::create_map;
+
+/// Behavior for vanilla `EmptyMapItem`.
+#[item_behavior]
+pub struct EmptyMapItem;
+
+impl ItemBehavior for EmptyMapItem {
+    fn use_item(&self, context: &mut UseItemContext) -> InteractionResult {
+        let player = context.player;
+        let pos = player.block_position();
+        let Some(map) = create_map(
+            context.world,
+            f64::from(pos.x()),
+            f64::from(pos.z()),
+            0,
+            true,
+            false,
+        ) else {
+            return InteractionResult::Fail;
+        };
+        player.award_stat(Stat::used(context.inv.with_item(|item| item.item())));
+        context.world.play_sound_at(
+            &sound_events::UI_CARTOGRAPHY_TABLE_TAKE_RESULT,
+            player.sound_source(),
+            player.position(),
+            1.0,
+            1.0,
+            None,
+        );
+        create_filled_result(context, map, false);
+        InteractionResult::Success
+    }
+}
+
+/// Behavior for vanilla `MapItem`.
+#[item_behavior]
+pub struct MapItem;
+
+impl ItemBehavior for MapItem {
+    fn use_on(&self, context: &mut UseOnContext) -> InteractionResult {
+        let pos = context.hit_result.block_pos;
+        if !context
+            .world
+            .get_block_state(pos)
+            .get_block()
+            .has_tag(&BlockTag::BANNERS)
+        {
+            return InteractionResult::Pass;
+        }
+        let stack = context.inv.with_item(|item| item.clone());
+        if let Some((_, data)) = context
+            .world
+            .maps()
+            .and_then(|maps| maps.get_for_item(&stack))
+            && !data.lock().toggle_banner(context.world, pos)
+        {
+            return InteractionResult::Fail;
+        }
+        InteractionResult::Success
+    }
+}
@@ -0,0 +1,87 @@
+//! Minecart item behavior.
+//!
+//! Places a minecart on the clicked rail. Mirrors vanilla `MinecartItem.useOn`.
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use steel_macros::item_behavior;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::{BlockStateProperties, RailShape};
+use steel_registry::data_components::vanilla_components::CUSTOM_NAME;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::vanilla_game_events;
+
+use crate::behavior::{InteractionResult, ItemBehavior, UseOnContext};
+use crate::entity::{ENTITIES, Entity, next_entity_id};
+use crate::world::game_event_context::GameEventContext;
+
+/// Behavior for minecart items.
+#[item_behavior]
+pub struct MinecartItem {
+    #[json_arg(vanilla_entities, json = "type")]
+    entity_type: EntityTypeRef,
+}
+
+impl MinecartItem {
+    /// Creates a minecart item behavior for one minecart entity type.
+    #[must_use]
+    pub const fn new(entity_type: EntityTypeRef) -> Self {
+        Self { entity_type }
+    }
+}
+
+impl ItemBehavior for MinecartItem {
+    fn use_on(&self, context: &mut UseOnContext) -> InteractionResult {
+        let world = context.world;
+        let pos = context.hit_result.block_pos;
+        let state = world.get_block_state(pos);
+        if !state.get_block().has_tag(&BlockTag::RAILS) {
+            return InteractionResult::Fail;
+        }
+
+        let shape = state
+            .try_get_value(&BlockStateProperties::RAIL_SHAPE)
+            .or_else(|| state.try_get_value(&BlockStateProperties::RAIL_SHAPE_STRAIGHT))
+            .unwrap_or(RailShape::NorthSouth);
+        let ascending = matches!(
+            shape,
+            RailShape::AscendingEast
+                | RailShape::AscendingWest
+                | RailShape::AscendingNorth
+                | RailShape::AscendingSouth
+        );
+        let spawn_pos = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 0.0625 + if ascending { 0.5 } else { 0.0 },
+            f64::from(pos.z()) + 0.5,
+        );
+
+        let Some(minecart) = ENTITIES.create(
+            self.entity_type,
+            next_entity_id(),
+            spawn_pos,
+            Arc::downgrade(world),
+        ) else {
+            return InteractionResult::Fail;
+        };
+        if let Some(custom_name) = context.inv.with_item(|item| item.get(CUSTOM_NAME).cloned()) {
+            minecart.set_custom_name(Some(custom_name));
+        }
+
+        if let Err(error) = world.try_add_entity(Arc::clone(&minecart)) {
+            log::debug!("failed to spawn minecart: {error}");
+            return InteractionResult::Fail;
+        }
+        world.game_event(
+            &vanilla_game_events::ENTITY_PLACE,
+            pos,
+            &GameEventContext::new(Some(context.player), None),
+        );
+
+        context.inv.with_item(|item| item.shrink(1));
+
+        InteractionResult::Success
+    }
+}
@@ -3,38 +3,60 @@
 //! The actual behavior registration is auto-generated from classes.json.
 //! See `src/behavior/generated/items.rs` for the generated registration code.
 
+mod armor_stand_item;
 mod axe;
 mod block_item;
+mod boat_item;
 mod bonemeal;
+mod book_item;
 mod bucket;
+mod bundle;
+mod compass;
 mod copper_chest_events;
 mod default;
+mod end_crystal_item;
 mod ender_eye;
 mod ender_pearl;
 mod firework_rocket;
 mod food_on_a_stick;
+mod hanging_entity_item;
 mod hoe;
 mod honeycomb;
+mod lead;
 mod mace;
+mod map_item;
+mod minecart_item;
+mod name_tag;
 mod shovel;
 mod sign_item;
 mod standing_and_wall_block_item;
 
 mod flint_and_steel;
 
+pub use armor_stand_item::ArmorStandItem;
 pub use axe::AxeItem;
 pub use block_item::{BlockItem, DoubleHighBlockItem};
+pub use boat_item::BoatItem;
 pub use bonemeal::BoneMealItem;
+pub use book_item::{WritableBookItem, WrittenBookItem};
 pub use bucket::BucketItem;
+pub use bundle::BundleItem;
+pub use compass::CompassItem;
 pub use default::DefaultItemBehavior;
+pub use end_crystal_item::EndCrystalItem;
 pub use ender_eye::EnderEyeItem;
 pub use ender_pearl::EnderPearlItem;
 pub use firework_rocket::FireworkRocketItem;
 pub use flint_and_steel::{FireChargeItem, FlintAndSteelItem};
 pub use food_on_a_stick::FoodOnAStickItem;
+pub use hanging_entity_item::{HangingEntityItem, ItemFrameItem};
 pub use hoe::HoeItem;
 pub use honeycomb::HoneycombItem;
+pub use lead::LeadItem;
 pub use mace::MaceItem;
+pub use map_item::{EmptyMapItem, MapItem};
+pub use minecart_item::MinecartItem;
+pub use name_tag::NameTagItem;
 pub use shovel::ShovelItem;
 pub use sign_item::{HangingSignItem, SignItem};
 pub use standing_and_wall_block_item::StandingAndWallBlockItem;
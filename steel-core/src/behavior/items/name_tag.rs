@@ -0,0 +1,40 @@
+//! Name tag item behavior implementation.
+
+use steel_macros::item_behavior;
+use steel_registry::data_components::vanilla_components::CUSTOM_NAME;
+use steel_registry::item_stack::ItemStack;
+use steel_utils::types::InteractionHand;
+
+use crate::behavior::{InteractionResult, ItemBehavior};
+use crate::entity::{Entity as _, LivingEntity};
+use crate::player::Player;
+
+/// Behavior for vanilla `NameTagItem`.
+#[item_behavior]
+pub struct NameTagItem;
+
+impl ItemBehavior for NameTagItem {
+    fn interact_living_entity(
+        &self,
+        stack: &mut ItemStack,
+        _player: &Player,
+        target: &dyn LivingEntity,
+        _hand: InteractionHand,
+    ) -> InteractionResult {
+        let Some(name) = stack.get(CUSTOM_NAME).cloned() else {
+            return InteractionResult::Pass;
+        };
+        if !target.entity_type().can_serialize {
+            return InteractionResult::Pass;
+        }
+
+        if LivingEntity::is_alive(target) {
+            target.set_custom_name(Some(name));
+            if let Some(mob) = target.as_mob() {
+                mob.set_persistence_required();
+            }
+            stack.shrink(1);
+        }
+        InteractionResult::Success
+    }
+}
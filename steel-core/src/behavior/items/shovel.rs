@@ -51,10 +51,7 @@ impl ItemBehavior for ShovelItem {
                 return InteractionResult::Pass;
             }
             // TODO: Play SoundEvents.SHOVEL_FLATTEN
-            let infinite_materials = context.player.has_infinite_materials();
-            context
-                .inv
-                .with_item(|item| item.hurt_and_break(1, infinite_materials));
+            context.player.hurt_item_in_hand(context.hand, 1);
             let updated_state = vanilla_blocks::DIRT_PATH.default_state();
             context.world.set_block(
                 context.hit_result.block_pos,
@@ -87,7 +84,7 @@ impl ItemBehavior for ShovelItem {
                 updated_state,
                 UpdateFlags::UPDATE_ALL_IMMEDIATE,
             );
-            // TODO: hurt_and_break(1, ...) — shovels take durability damage
+            context.player.hurt_item_in_hand(context.hand, 1);
             context.world.game_event(
                 &vanilla_game_events::BLOCK_CHANGE,
                 context.hit_result.block_pos,
@@ -26,6 +26,7 @@
 
 mod block;
 pub mod blocks;
+pub(crate) mod consumable;
 mod context;
 pub mod fluid;
 mod item;
@@ -69,8 +70,8 @@ pub use block::{
 };
 use block_behaviors::register_block_behaviors;
 pub use context::{
-    BlockHitResult, BlockPlaceContext, InteractionResult, InventoryAccess, PlacementOrientation,
-    PlacementSource, UseItemContext, UseOnContext,
+    BlockHitResult, BlockInteraction, BlockPlaceContext, InteractionResult, InventoryAccess,
+    PlacementOrientation, PlacementSource, UseItemContext, UseOnContext,
 };
 pub use fluid::{FLUID_BEHAVIORS, FluidBehaviorRegistry};
 pub use item::{ItemBehavior, ItemBehaviorRegistry};
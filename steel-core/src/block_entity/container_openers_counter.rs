@@ -0,0 +1,97 @@
+//! Tracks how many players have a container block entity open.
+//!
+//! Mirrors vanilla `ContainerOpenersCounter`, which drives lid animations,
+//! open/close sounds and the barrel `OPEN` property.
+
+use std::sync::Arc;
+
+use steel_registry::vanilla_game_events;
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::player::Player;
+use crate::world::World;
+use crate::world::game_event_context::GameEventContext;
+
+/// Callbacks fired by a [`ContainerOpenersCounter`] as players open and close a container.
+pub trait ContainerOpeners {
+    /// Called when the first player opens the container.
+    fn on_open(&self, world: &Arc<World>, pos: BlockPos, state: BlockStateId);
+
+    /// Called when the last player closes the container.
+    fn on_close(&self, world: &Arc<World>, pos: BlockPos, state: BlockStateId);
+
+    /// Called whenever the opener count changes.
+    fn opener_count_changed(
+        &self,
+        world: &Arc<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+        previous: i32,
+        current: i32,
+    );
+}
+
+/// Counts the players viewing a container block entity.
+#[derive(Debug, Default)]
+pub struct ContainerOpenersCounter {
+    open_count: i32,
+}
+
+impl ContainerOpenersCounter {
+    /// Returns the number of players currently viewing the container.
+    #[must_use]
+    pub const fn open_count(&self) -> i32 {
+        self.open_count
+    }
+
+    /// Records `player` opening the container.
+    ///
+    /// Based on Java's `ContainerOpenersCounter.incrementOpeners`.
+    pub fn increment_openers(
+        &mut self,
+        callbacks: &impl ContainerOpeners,
+        player: &Player,
+        world: &Arc<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) {
+        let previous = self.open_count;
+        self.open_count += 1;
+        if previous == 0 {
+            callbacks.on_open(world, pos, state);
+            world.game_event(
+                &vanilla_game_events::CONTAINER_OPEN,
+                pos,
+                &GameEventContext::new(Some(player), None),
+            );
+        }
+        callbacks.opener_count_changed(world, pos, state, previous, self.open_count);
+    }
+
+    /// Records `player` closing the container.
+    ///
+    /// Based on Java's `ContainerOpenersCounter.decrementOpeners`.
+    pub fn decrement_openers(
+        &mut self,
+        callbacks: &impl ContainerOpeners,
+        player: &Player,
+        world: &Arc<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) {
+        if self.open_count == 0 {
+            return;
+        }
+        let previous = self.open_count;
+        self.open_count -= 1;
+        if self.open_count == 0 {
+            callbacks.on_close(world, pos, state);
+            world.game_event(
+                &vanilla_game_events::CONTAINER_CLOSE,
+                pos,
+                &GameEventContext::new(Some(player), None),
+            );
+        }
+        callbacks.opener_count_changed(world, pos, state, previous, self.open_count);
+    }
+}
@@ -5,17 +5,21 @@
 
 use std::sync::{Arc, Weak};
 
+use glam::DVec3;
 use simdnbt::ToNbtTag;
 use simdnbt::borrow::{BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView};
 use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_protocol::packets::game::SoundSource;
 use steel_registry::block_entity_type::BlockEntityTypeRef;
 use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::blocks::properties::BlockStateProperties;
 use steel_registry::item_stack::ItemStack;
-use steel_registry::vanilla_block_entity_types;
-use steel_registry::vanilla_blocks;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::{sound_events, vanilla_block_entity_types, vanilla_blocks};
 use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey};
 
-use crate::block_entity::BlockEntity;
+use crate::block_entity::{BlockEntity, ContainerOpeners, ContainerOpenersCounter};
+use crate::entity::Entity;
 use crate::inventory::container::Container;
 use crate::player::Player;
 use crate::world::World;
@@ -37,6 +41,8 @@ pub struct BarrelBlockEntity {
     removed: bool,
     /// The 27 item slots.
     items: Vec<ItemStack>,
+    /// Players currently viewing this barrel.
+    openers: ContainerOpenersCounter,
 }
 
 // SAFETY: This key is owned by Steel and uniquely identifies `BarrelBlockEntity`.
@@ -54,8 +60,56 @@ impl BarrelBlockEntity {
             state,
             removed: false,
             items: vec![ItemStack::empty(); BARREL_SLOTS],
+            openers: ContainerOpenersCounter::default(),
         }
     }
+
+    /// Returns whether any player has this barrel open.
+    #[must_use]
+    pub const fn is_open(&self) -> bool {
+        self.openers.open_count() > 0
+    }
+}
+
+/// Barrel reactions to players opening and closing it.
+struct BarrelOpeners;
+
+impl BarrelOpeners {
+    /// Based on Java's `BarrelBlockEntity.playSound`.
+    fn play_sound(world: &World, pos: BlockPos, state: BlockStateId, sound: SoundEventRef) {
+        let (x, y, z) = state.get_value(&BlockStateProperties::FACING).offset();
+        let position = DVec3::new(
+            f64::from(pos.x()) + 0.5 + f64::from(x) / 2.0,
+            f64::from(pos.y()) + 0.5 + f64::from(y) / 2.0,
+            f64::from(pos.z()) + 0.5 + f64::from(z) / 2.0,
+        );
+        let pitch = rand::random::<f32>() * 0.1 + 0.9;
+        world.play_sound_at(sound, SoundSource::Blocks, position, 0.5, pitch, None);
+    }
+}
+
+impl ContainerOpeners for BarrelOpeners {
+    // The OPEN property is applied from `BarrelBlock::tick`, since setting the
+    // block here would relock this block entity.
+    fn on_open(&self, world: &Arc<World>, pos: BlockPos, state: BlockStateId) {
+        Self::play_sound(world, pos, state, &sound_events::BLOCK_BARREL_OPEN);
+        world.schedule_block_tick_default(pos, &vanilla_blocks::BARREL, 1);
+    }
+
+    fn on_close(&self, world: &Arc<World>, pos: BlockPos, state: BlockStateId) {
+        Self::play_sound(world, pos, state, &sound_events::BLOCK_BARREL_CLOSE);
+        world.schedule_block_tick_default(pos, &vanilla_blocks::BARREL, 1);
+    }
+
+    fn opener_count_changed(
+        &self,
+        _world: &Arc<World>,
+        _pos: BlockPos,
+        _state: BlockStateId,
+        _previous: i32,
+        _current: i32,
+    ) {
+    }
 }
 
 impl BlockEntity for BarrelBlockEntity {
@@ -193,4 +247,24 @@ impl Container for BarrelBlockEntity {
     fn set_changed(&mut self) {
         BlockEntity::set_changed(self);
     }
+
+    fn start_open(&mut self, player: &Player) {
+        if self.removed || player.is_spectator() {
+            return;
+        }
+        if let Some(world) = self.level.upgrade() {
+            self.openers
+                .increment_openers(&BarrelOpeners, player, &world, self.pos, self.state);
+        }
+    }
+
+    fn stop_open(&mut self, player: &Player) {
+        if self.removed || player.is_spectator() {
+            return;
+        }
+        if let Some(world) = self.level.upgrade() {
+            self.openers
+                .decrement_openers(&BarrelOpeners, player, &world, self.pos, self.state);
+        }
+    }
 }
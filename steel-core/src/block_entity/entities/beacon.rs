@@ -0,0 +1,420 @@
+//! Beacon block entity.
+//!
+//! Beacons scan their beam and pyramid base while ticking and apply the
+//! selected powers to nearby players every four seconds.
+
+use std::str::FromStr;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::borrow::{BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView};
+use simdnbt::owned::NbtCompound;
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::mob_effect::MobEffectRef;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::{
+    REGISTRY, RegistryExt as _, sound_events, vanilla_block_entity_types, vanilla_blocks,
+    vanilla_mob_effects,
+};
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey, Identifier, WorldAabb};
+
+use crate::behavior::BLOCK_BEHAVIORS;
+use crate::block_entity::{BlockEntity, BlockEntityTickAction};
+use crate::chunk::heightmap::HeightmapType;
+use crate::entity::{Entity as _, LivingEntity as _, MobEffectInstance};
+use crate::player::Player;
+use crate::world::World;
+
+/// Number of pyramid levels that can power a beacon.
+pub const BEACON_MAX_LEVELS: i32 = 4;
+/// Beam blocks checked per tick.
+const BLOCKS_CHECK_PER_TICK: i32 = 10;
+/// Ticks between base scans and effect applications.
+const EFFECT_INTERVAL: i64 = 80;
+
+/// Returns whether a beacon can grant `effect`.
+///
+/// Vanilla equivalent: `BeaconBlockEntity.VALID_EFFECTS`.
+#[must_use]
+pub fn is_valid_beacon_effect(effect: MobEffectRef) -> bool {
+    [
+        vanilla_mob_effects::SPEED,
+        vanilla_mob_effects::HASTE,
+        vanilla_mob_effects::RESISTANCE,
+        vanilla_mob_effects::JUMP_BOOST,
+        vanilla_mob_effects::STRENGTH,
+        vanilla_mob_effects::REGENERATION,
+    ]
+    .contains(&effect)
+}
+
+/// Encodes an effect for a beacon menu data slot, 0 meaning none.
+///
+/// Vanilla equivalent: `BeaconMenu.encodeEffect`.
+#[must_use]
+pub fn encode_beacon_effect(effect: Option<MobEffectRef>) -> i32 {
+    effect.map_or(0, |effect| effect.packet_holder_id() + 1)
+}
+
+/// One colored part of a beacon beam.
+///
+/// Vanilla equivalent: `BeaconBlockEntity.BeaconBeamSection`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BeaconBeamSection {
+    /// ARGB color of the section.
+    pub color: i32,
+    /// Height of the section in blocks.
+    pub height: i32,
+}
+
+impl BeaconBeamSection {
+    const fn new(color: i32) -> Self {
+        Self { color, height: 1 }
+    }
+}
+
+/// Vanilla `BeaconBlockEntity`.
+pub struct BeaconBlockEntity {
+    world: Weak<World>,
+    pos: BlockPos,
+    state: BlockStateId,
+    removed: bool,
+    beam_sections: Vec<BeaconBeamSection>,
+    checking_beam_sections: Vec<BeaconBeamSection>,
+    levels: i32,
+    last_check_y: i32,
+    primary_power: Option<MobEffectRef>,
+    secondary_power: Option<MobEffectRef>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `BeaconBlockEntity`.
+unsafe impl DowncastType for BeaconBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/beacon");
+}
+
+impl BeaconBlockEntity {
+    /// Creates an inactive beacon block entity.
+    #[must_use]
+    pub const fn new(world: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            world,
+            pos,
+            state,
+            removed: false,
+            beam_sections: Vec::new(),
+            checking_beam_sections: Vec::new(),
+            levels: 0,
+            last_check_y: 0,
+            primary_power: None,
+            secondary_power: None,
+        }
+    }
+
+    /// Returns the number of complete pyramid levels below the beacon.
+    #[must_use]
+    pub const fn levels(&self) -> i32 {
+        self.levels
+    }
+
+    /// Returns the sections of the last fully scanned beam.
+    #[must_use]
+    pub fn beam_sections(&self) -> &[BeaconBeamSection] {
+        &self.beam_sections
+    }
+
+    /// Returns the selected primary power.
+    #[must_use]
+    pub const fn primary_power(&self) -> Option<MobEffectRef> {
+        self.primary_power
+    }
+
+    /// Returns the selected secondary power.
+    #[must_use]
+    pub const fn secondary_power(&self) -> Option<MobEffectRef> {
+        self.secondary_power
+    }
+
+    /// Selects the primary power, dropping effects a beacon cannot grant.
+    ///
+    /// Vanilla equivalent: the `DATA_PRIMARY` branch of the beacon's
+    /// `ContainerData.set`.
+    pub fn set_primary_power(&mut self, world: &World, effect: Option<MobEffectRef>) {
+        if !self.beam_sections.is_empty() {
+            play_sound(world, self.pos, &sound_events::BLOCK_BEACON_POWER_SELECT);
+        }
+        self.primary_power = effect.filter(|&effect| is_valid_beacon_effect(effect));
+    }
+
+    /// Selects the secondary power, dropping effects a beacon cannot grant.
+    pub fn set_secondary_power(&mut self, effect: Option<MobEffectRef>) {
+        self.secondary_power = effect.filter(|&effect| is_valid_beacon_effect(effect));
+    }
+
+    /// Scans the beam up to ten blocks at a time.
+    fn update_beam(&mut self, world: &World, last_set_block: i32) {
+        let (x, y, z) = (self.pos.x(), self.pos.y(), self.pos.z());
+        let mut check_pos = if self.last_check_y < y {
+            self.checking_beam_sections.clear();
+            self.last_check_y = y - 1;
+            self.pos
+        } else {
+            BlockPos::new(x, self.last_check_y + 1, z)
+        };
+
+        for _ in 0..BLOCKS_CHECK_PER_TICK {
+            if check_pos.y() > last_set_block {
+                break;
+            }
+            let state = world.get_block_state(check_pos);
+            let beam_color = BLOCK_BEHAVIORS
+                .get_behavior(state.get_block())
+                .get_beacon_beam_color();
+            if let Some(color) = beam_color {
+                let color = color.texture_diffuse_color();
+                if self.checking_beam_sections.len() <= 1 {
+                    self.checking_beam_sections
+                        .push(BeaconBeamSection::new(color));
+                } else if let Some(last) = self.checking_beam_sections.last_mut() {
+                    if last.color == color {
+                        last.height += 1;
+                    } else {
+                        let average = argb_average(last.color, color);
+                        self.checking_beam_sections
+                            .push(BeaconBeamSection::new(average));
+                    }
+                }
+            } else {
+                let Some(last) = self.checking_beam_sections.last_mut() else {
+                    self.checking_beam_sections.clear();
+                    self.last_check_y = last_set_block;
+                    break;
+                };
+                if state.get_light_dampening() >= 15
+                    && state.get_block() != &vanilla_blocks::BEDROCK
+                {
+                    self.checking_beam_sections.clear();
+                    self.last_check_y = last_set_block;
+                    break;
+                }
+                last.height += 1;
+            }
+            check_pos = check_pos.above();
+            self.last_check_y += 1;
+        }
+    }
+
+    /// Counts the complete pyramid levels below the beacon.
+    ///
+    /// Vanilla equivalent: `BeaconBlockEntity.updateBase`.
+    fn update_base(world: &World, pos: BlockPos) -> i32 {
+        let mut levels = 0;
+        for step in 1..=BEACON_MAX_LEVELS {
+            let y = pos.y() - step;
+            if y < world.get_min_y() {
+                break;
+            }
+            let complete = (pos.x() - step..=pos.x() + step).all(|x| {
+                (pos.z() - step..=pos.z() + step).all(|z| {
+                    world
+                        .get_block_state(BlockPos::new(x, y, z))
+                        .get_block()
+                        .has_tag(&BlockTag::BEACON_BASE_BLOCKS)
+                })
+            });
+            if !complete {
+                break;
+            }
+            levels = step;
+        }
+        levels
+    }
+
+    /// Gives the selected powers to every player in range.
+    ///
+    /// Vanilla equivalent: `BeaconBlockEntity.applyEffects`.
+    fn apply_effects(&self, world: &World) {
+        let Some(primary) = self.primary_power else {
+            return;
+        };
+        let range = f64::from(self.levels * 10 + 10);
+        let amplifier = i32::from(self.levels >= 4 && self.secondary_power == Some(primary));
+        let duration = (9 + self.levels * 2) * 20;
+        let (x, y, z) = (
+            f64::from(self.pos.x()),
+            f64::from(self.pos.y()),
+            f64::from(self.pos.z()),
+        );
+        let area = WorldAabb::new(x, y, z, x + 1.0, y + 1.0, z + 1.0)
+            .inflate(range)
+            .expand_towards(DVec3::new(0.0, f64::from(world.get_height()), 0.0));
+        let players = players_in(world, area);
+
+        for player in &players {
+            player.add_mob_effect(
+                MobEffectInstance::with_duration(primary, duration, amplifier).with_ambient(true),
+            );
+        }
+        if self.levels >= 4
+            && let Some(secondary) = self.secondary_power
+            && secondary != primary
+        {
+            for player in &players {
+                player.add_mob_effect(
+                    MobEffectInstance::with_duration(secondary, duration, 0).with_ambient(true),
+                );
+            }
+        }
+    }
+
+    fn load_effect(nbt: &NbtCompoundView<'_, '_>, key: &str) -> Option<MobEffectRef> {
+        let id = Identifier::from_str(&nbt.string(key)?.to_string()).ok()?;
+        REGISTRY
+            .mob_effects
+            .by_key(&id)
+            .filter(|&effect| is_valid_beacon_effect(effect))
+    }
+}
+
+/// Returns the non-spectator players touching `area`.
+fn players_in(world: &World, area: WorldAabb) -> Vec<Arc<Player>> {
+    let mut players = Vec::new();
+    world.players.iter_players(|_, player| {
+        if !player.is_spectator() && player.bounding_box().intersects(area) {
+            players.push(Arc::clone(player));
+        }
+        true
+    });
+    players
+}
+
+fn play_sound(world: &World, pos: BlockPos, sound: SoundEventRef) {
+    world.play_sound(sound, SoundSource::Blocks, pos, 1.0, 1.0, None);
+}
+
+/// Vanilla `ARGB.average`: the per-channel mean of two colors.
+const fn argb_average(a: i32, b: i32) -> i32 {
+    let mut result = 0;
+    let mut shift = 0;
+    while shift < 32 {
+        let channel = (((a >> shift) & 0xFF) + ((b >> shift) & 0xFF)) / 2;
+        result |= channel << shift;
+        shift += 8;
+    }
+    result
+}
+
+impl BlockEntity for BeaconBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::BEACON
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.world.upgrade()
+    }
+
+    fn pre_remove_side_effects(&mut self, pos: BlockPos, _state: BlockStateId) {
+        if let Some(world) = self.get_level() {
+            play_sound(&world, pos, &sound_events::BLOCK_BEACON_DEACTIVATE);
+        }
+    }
+
+    fn load_additional(&mut self, nbt: &BorrowedNbtCompound<'_>) {
+        let nbt: NbtCompoundView<'_, '_> = nbt.into();
+        self.primary_power = Self::load_effect(&nbt, "primary_effect");
+        self.secondary_power = Self::load_effect(&nbt, "secondary_effect");
+        // TODO: Load CustomName and lock once block entities support them
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        if let Some(primary) = self.primary_power {
+            nbt.insert("primary_effect", primary.key.to_string());
+        }
+        if let Some(secondary) = self.secondary_power {
+            nbt.insert("secondary_effect", secondary.key.to_string());
+        }
+        nbt.insert("Levels", self.levels);
+    }
+
+    fn get_update_tag(&self) -> Option<NbtCompound> {
+        Some(self.save_custom_only())
+    }
+
+    fn is_ticking(&self) -> bool {
+        true
+    }
+
+    /// Vanilla equivalent: `BeaconBlockEntity.tick`.
+    fn tick(&mut self, world: &Arc<World>) -> Option<BlockEntityTickAction> {
+        let last_set_block = world
+            .height_at(HeightmapType::WorldSurface, self.pos.x(), self.pos.z())
+            .unwrap_or(world.get_min_y());
+        self.update_beam(world, last_set_block);
+
+        let previous_levels = self.levels;
+        if world.game_time() % EFFECT_INTERVAL == 0 {
+            if !self.beam_sections.is_empty() {
+                self.levels = Self::update_base(world, self.pos);
+            }
+            if self.levels > 0 && !self.beam_sections.is_empty() {
+                self.apply_effects(world);
+                play_sound(world, self.pos, &sound_events::BLOCK_BEACON_AMBIENT);
+            }
+        }
+
+        if self.last_check_y >= last_set_block {
+            self.last_check_y = world.get_min_y() - 1;
+            self.beam_sections = std::mem::take(&mut self.checking_beam_sections);
+            let was_active = previous_levels > 0;
+            let is_active = self.levels > 0;
+            if !was_active && is_active {
+                play_sound(world, self.pos, &sound_events::BLOCK_BEACON_ACTIVATE);
+                // TODO: Trigger the construct_beacon criterion for nearby players
+            } else if was_active && !is_active {
+                play_sound(world, self.pos, &sound_events::BLOCK_BEACON_DEACTIVATE);
+            }
+        }
+
+        if previous_levels != self.levels {
+            self.set_changed();
+            world.send_block_updated(self.pos);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn argb_average_averages_each_channel() {
+        assert_eq!(argb_average(0x7F00_00FF, 0x01FF_0001), 0x407F_0080);
+    }
+}
@@ -109,6 +109,12 @@ impl BeehiveBlockEntity {
         self.stored.len()
     }
 
+    /// Returns whether the hive cannot accept another occupant.
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.stored.len() >= BEEHIVE_MAX_OCCUPANTS
+    }
+
     /// Returns whether the hive currently stores no occupants.
     #[must_use]
     pub const fn is_empty(&self) -> bool {
@@ -0,0 +1,331 @@
+//! Chest block entity implementation.
+//!
+//! Chests hold 27 slots each. Two adjacent chests form a double chest, but each
+//! half keeps its own inventory and opener count.
+
+use std::str::FromStr;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::ToNbtTag;
+use simdnbt::borrow::{BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView};
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::blocks::properties::{BlockStateProperties, ChestType};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::{sound_events, vanilla_block_entity_types, vanilla_blocks};
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey, Identifier};
+
+use crate::behavior::blocks::get_connected_direction;
+use crate::block_entity::{BlockEntity, ContainerOpeners, ContainerOpenersCounter};
+use crate::entity::Entity;
+use crate::inventory::container::Container;
+use crate::player::Player;
+use crate::world::World;
+
+/// Number of slots in a single chest (3 rows of 9).
+pub const CHEST_SLOTS: usize = 27;
+
+/// Chest block entity.
+///
+/// Holds one chest's items and tracks viewers for the lid animation.
+pub struct ChestBlockEntity {
+    /// Weak reference to the world for marking chunks dirty.
+    level: Weak<World>,
+    /// Position in the world.
+    pos: BlockPos,
+    /// Current block state.
+    state: BlockStateId,
+    /// Whether this entity has been marked for removal.
+    removed: bool,
+    /// The 27 item slots.
+    items: Vec<ItemStack>,
+    /// Players currently viewing this chest.
+    openers: ContainerOpenersCounter,
+    /// Loot table placed by structure generation, kept until it is unpacked.
+    loot_table: Option<Identifier>,
+    /// Seed for `loot_table`, or 0 for a random seed.
+    loot_table_seed: i64,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `ChestBlockEntity`.
+unsafe impl DowncastType for ChestBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/chest");
+}
+
+impl ChestBlockEntity {
+    /// Creates a new chest block entity.
+    #[must_use]
+    pub fn new(level: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            level,
+            pos,
+            state,
+            removed: false,
+            items: vec![ItemStack::empty(); CHEST_SLOTS],
+            openers: ContainerOpenersCounter::default(),
+            loot_table: None,
+            loot_table_seed: 0,
+        }
+    }
+
+    /// Returns the number of players viewing this chest.
+    #[must_use]
+    pub const fn open_count(&self) -> i32 {
+        self.openers.open_count()
+    }
+}
+
+/// Chest reactions to players opening and closing it.
+struct ChestOpeners;
+
+impl ChestOpeners {
+    /// Based on Java's `ChestBlockEntity.playSound`.
+    ///
+    /// Double chests play one sound from the middle of the left half.
+    fn play_sound(world: &World, pos: BlockPos, state: BlockStateId, sound: SoundEventRef) {
+        let chest_type: ChestType = state.get_value(&BlockStateProperties::CHEST_TYPE);
+        if chest_type == ChestType::Right {
+            return;
+        }
+
+        let mut position = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 0.5,
+            f64::from(pos.z()) + 0.5,
+        );
+        if chest_type == ChestType::Left {
+            let (x, _, z) = get_connected_direction(state).offset();
+            position.x += f64::from(x) * 0.5;
+            position.z += f64::from(z) * 0.5;
+        }
+
+        let pitch = rand::random::<f32>() * 0.1 + 0.9;
+        world.play_sound_at(sound, SoundSource::Blocks, position, 0.5, pitch, None);
+    }
+}
+
+impl ContainerOpeners for ChestOpeners {
+    fn on_open(&self, world: &Arc<World>, pos: BlockPos, state: BlockStateId) {
+        Self::play_sound(world, pos, state, &sound_events::BLOCK_CHEST_OPEN);
+    }
+
+    fn on_close(&self, world: &Arc<World>, pos: BlockPos, state: BlockStateId) {
+        Self::play_sound(world, pos, state, &sound_events::BLOCK_CHEST_CLOSE);
+    }
+
+    /// Sends the lid animation block event, like Java's `ChestBlockEntity.signalOpenCount`.
+    fn opener_count_changed(
+        &self,
+        world: &Arc<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+        _previous: i32,
+        current: i32,
+    ) {
+        let count = u8::try_from(current).unwrap_or(u8::MAX);
+        world.block_event(pos, state.get_block(), 1, count);
+    }
+}
+
+impl BlockEntity for ChestBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::CHEST
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.level.upgrade()
+    }
+
+    fn pre_remove_side_effects(&mut self, pos: BlockPos, _state: BlockStateId) {
+        if let Some(world) = self.level.upgrade() {
+            for item in self.items.drain(..) {
+                world.drop_item_stack(pos, item);
+            }
+        }
+    }
+
+    fn load_additional(&mut self, nbt: &BorrowedNbtCompound<'_>) {
+        let nbt_view: NbtCompoundView<'_, '_> = nbt.into();
+
+        self.loot_table = nbt_view
+            .string("LootTable")
+            .and_then(|value| Identifier::from_str(&value.to_string()).ok());
+        self.loot_table_seed = nbt_view.long("LootTableSeed").unwrap_or(0);
+
+        if let Some(items_list) = nbt_view.list("Items")
+            && let Some(compounds) = items_list.compounds()
+        {
+            for compound in compounds {
+                if let Some(slot) = compound.byte("Slot") {
+                    let slot = slot as usize;
+                    if slot < CHEST_SLOTS
+                        && let Some(item) = ItemStack::from_borrowed_compound(&compound)
+                    {
+                        self.items[slot] = item;
+                    }
+                }
+            }
+        }
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        // TODO: Unpack the loot table into the items when the chest is first opened.
+        if let Some(loot_table) = &self.loot_table {
+            nbt.insert("LootTable", loot_table.to_string());
+            if self.loot_table_seed != 0 {
+                nbt.insert("LootTableSeed", NbtTag::Long(self.loot_table_seed));
+            }
+        }
+
+        let mut items: Vec<NbtCompound> = Vec::new();
+        for (slot, item) in self.items.iter().enumerate() {
+            if !item.is_empty()
+                && let NbtTag::Compound(mut item_nbt) = item.clone().to_nbt_tag()
+            {
+                item_nbt.insert("Slot", slot as i8);
+                items.push(item_nbt);
+            }
+        }
+        nbt.insert("Items", NbtList::Compound(items));
+    }
+
+    fn get_update_tag(&self) -> Option<NbtCompound> {
+        None
+    }
+
+    fn as_container(&self) -> Option<&(dyn Container + 'static)> {
+        Some(self)
+    }
+
+    fn as_container_mut(&mut self) -> Option<&mut (dyn Container + 'static)> {
+        Some(self)
+    }
+}
+
+impl Container for ChestBlockEntity {
+    fn get_container_size(&self) -> usize {
+        CHEST_SLOTS
+    }
+
+    fn get_item(&self, slot: usize) -> &ItemStack {
+        &self.items[slot]
+    }
+
+    fn get_item_mut(&mut self, slot: usize) -> &mut ItemStack {
+        &mut self.items[slot]
+    }
+
+    fn set_item(&mut self, slot: usize, stack: ItemStack) {
+        if slot < CHEST_SLOTS {
+            self.items[slot] = stack;
+            self.set_changed();
+        }
+    }
+
+    fn get_max_stack_size(&self) -> i32 {
+        64
+    }
+
+    fn still_valid(&self, player: &Player) -> bool {
+        if self.removed {
+            return false;
+        }
+
+        let Some(level) = self.level.upgrade() else {
+            return false;
+        };
+
+        level.get_block_state(self.pos).get_block() == &vanilla_blocks::CHEST
+            && player.is_within_block_interaction_range_with_buffer(self.pos, 4.0)
+    }
+
+    fn set_changed(&mut self) {
+        BlockEntity::set_changed(self);
+    }
+
+    fn start_open(&mut self, player: &Player) {
+        if self.removed || player.is_spectator() {
+            return;
+        }
+        if let Some(world) = self.level.upgrade() {
+            self.openers
+                .increment_openers(&ChestOpeners, player, &world, self.pos, self.state);
+        }
+    }
+
+    fn stop_open(&mut self, player: &Player) {
+        if self.removed || player.is_spectator() {
+            return;
+        }
+        if let Some(world) = self.level.upgrade() {
+            self.openers
+                .decrement_openers(&ChestOpeners, player, &world, self.pos, self.state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::sync::Weak;
+
+    use simdnbt::borrow::read_compound as read_borrowed_compound;
+    use steel_registry::test_support::init_test_registry;
+
+    use super::*;
+
+    #[test]
+    fn chest_keeps_structure_loot_table() {
+        init_test_registry();
+        let mut nbt = NbtCompound::new();
+        nbt.insert("LootTable", "minecraft:chests/simple_dungeon");
+        nbt.insert("LootTableSeed", 42_i64);
+        let mut bytes = Vec::new();
+        nbt.write(&mut bytes);
+        let borrowed = read_borrowed_compound(&mut Cursor::new(bytes.as_slice()))
+            .expect("test nbt should reborrow");
+
+        let mut chest = ChestBlockEntity::new(
+            Weak::new(),
+            BlockPos::new(0, 40, 0),
+            vanilla_blocks::CHEST.default_state(),
+        );
+        chest.load_additional(&borrowed);
+
+        let mut saved = NbtCompound::new();
+        chest.save_additional(&mut saved);
+        assert_eq!(
+            saved.string("LootTable").map(ToString::to_string),
+            Some("minecraft:chests/simple_dungeon".to_owned())
+        );
+        assert_eq!(saved.long("LootTableSeed"), Some(42));
+    }
+}
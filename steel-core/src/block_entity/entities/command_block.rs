@@ -0,0 +1,444 @@
+//! Command block entity.
+
+use std::sync::{Arc, Weak};
+
+use simdnbt::borrow::{BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView};
+use simdnbt::owned::NbtCompound;
+use steel_protocol::packets::game::CommandBlockMode;
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::blocks::BlockRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::BlockStateProperties;
+use steel_registry::{vanilla_block_entity_types, vanilla_blocks};
+use steel_utils::{BlockPos, BlockStateId, Downcast as _, DowncastType, DowncastTypeKey};
+use text_components::TextComponent;
+
+use crate::block_entity::BlockEntity;
+use crate::world::World;
+
+/// Vanilla `CommandBlockEntity`, including the state of its `BaseCommandBlock`.
+pub struct CommandBlockEntity {
+    world: Weak<World>,
+    pos: BlockPos,
+    state: BlockStateId,
+    removed: bool,
+    command: String,
+    success_count: i32,
+    track_output: bool,
+    last_output: Option<TextComponent>,
+    custom_name: Option<TextComponent>,
+    update_last_execution: bool,
+    last_execution: i64,
+    powered: bool,
+    auto: bool,
+    condition_met: bool,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `CommandBlockEntity`.
+unsafe impl DowncastType for CommandBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/command_block");
+}
+
+/// Returns whether `block` is one of the three command blocks.
+#[must_use]
+pub fn is_command_block(block: BlockRef) -> bool {
+    block == &vanilla_blocks::COMMAND_BLOCK
+        || block == &vanilla_blocks::CHAIN_COMMAND_BLOCK
+        || block == &vanilla_blocks::REPEATING_COMMAND_BLOCK
+}
+
+/// Returns the command block that runs in `mode`.
+#[must_use]
+pub const fn command_block_for_mode(mode: CommandBlockMode) -> BlockRef {
+    match mode {
+        CommandBlockMode::Sequence => &vanilla_blocks::CHAIN_COMMAND_BLOCK,
+        CommandBlockMode::Auto => &vanilla_blocks::REPEATING_COMMAND_BLOCK,
+        CommandBlockMode::Redstone => &vanilla_blocks::COMMAND_BLOCK,
+    }
+}
+
+impl CommandBlockEntity {
+    /// Creates an empty command block entity.
+    #[must_use]
+    pub const fn new(world: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            world,
+            pos,
+            state,
+            removed: false,
+            command: String::new(),
+            success_count: 0,
+            track_output: true,
+            last_output: None,
+            custom_name: None,
+            update_last_execution: true,
+            last_execution: -1,
+            powered: false,
+            auto: false,
+            condition_met: false,
+        }
+    }
+
+    /// Returns the command, as typed into the screen.
+    #[must_use]
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Sets the command.
+    pub fn set_command(&mut self, command: String) {
+        self.command = command;
+    }
+
+    /// Returns how many executions of the last command succeeded.
+    ///
+    /// Comparators read this as their signal.
+    #[must_use]
+    pub const fn success_count(&self) -> i32 {
+        self.success_count
+    }
+
+    /// Sets the success count of the last command.
+    pub const fn set_success_count(&mut self, success_count: i32) {
+        self.success_count = success_count;
+    }
+
+    /// Returns whether command output is kept as the last output.
+    #[must_use]
+    pub const fn track_output(&self) -> bool {
+        self.track_output
+    }
+
+    /// Sets whether command output is kept. Turning it off clears the last output.
+    pub fn set_track_output(&mut self, track_output: bool) {
+        self.track_output = track_output;
+        if !track_output {
+            self.last_output = None;
+        }
+    }
+
+    /// Returns the last message the command sent, prefixed with its time.
+    #[must_use]
+    pub const fn last_output(&self) -> Option<&TextComponent> {
+        self.last_output.as_ref()
+    }
+
+    /// Clears the last output before a new command runs.
+    pub fn clear_last_output(&mut self) {
+        self.last_output = None;
+    }
+
+    /// Vanilla `BaseCommandBlock.getName`: the custom name, or `@`.
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.custom_name
+            .as_ref()
+            .map_or_else(|| "@".to_owned(), ToString::to_string)
+    }
+
+    /// Returns whether the block was powered on the last neighbor update.
+    #[must_use]
+    pub const fn is_powered(&self) -> bool {
+        self.powered
+    }
+
+    /// Records the redstone power seen by the block.
+    pub const fn set_powered(&mut self, powered: bool) {
+        self.powered = powered;
+    }
+
+    /// Vanilla `CommandBlock.setPoweredAndUpdate`: records the redstone power
+    /// seen by the block.
+    ///
+    /// Returns whether an impulse block has just been powered and needs its run
+    /// scheduled, which happens once per rising edge.
+    pub fn update_powered(&mut self, powered: bool) -> bool {
+        if powered == self.powered {
+            return false;
+        }
+        self.powered = powered;
+        powered && !self.auto && self.mode() != CommandBlockMode::Sequence
+    }
+
+    /// Returns whether the block runs without redstone power.
+    #[must_use]
+    pub const fn is_automatic(&self) -> bool {
+        self.auto
+    }
+
+    /// Vanilla `CommandBlockEntity.setAutomatic`.
+    ///
+    /// Returns whether the block has just become active and needs a tick
+    /// scheduled, see [`Self::schedule_tick`].
+    pub fn set_automatic(&mut self, automatic: bool) -> bool {
+        let was_automatic = self.auto;
+        self.auto = automatic;
+        !was_automatic && automatic && !self.powered && self.mode() != CommandBlockMode::Sequence
+    }
+
+    /// Returns whether the conditional check passed when the block was last activated.
+    #[must_use]
+    pub const fn was_condition_met(&self) -> bool {
+        self.condition_met
+    }
+
+    /// Vanilla `CommandBlockEntity.getMode`, decided by the block.
+    #[must_use]
+    pub fn mode(&self) -> CommandBlockMode {
+        let block = self.state.get_block();
+        if block == &vanilla_blocks::CHAIN_COMMAND_BLOCK {
+            CommandBlockMode::Sequence
+        } else if block == &vanilla_blocks::REPEATING_COMMAND_BLOCK {
+            CommandBlockMode::Auto
+        } else {
+            CommandBlockMode::Redstone
+        }
+    }
+
+    /// Returns whether the block only runs after the block behind it succeeded.
+    #[must_use]
+    pub fn is_conditional(&self) -> bool {
+        self.state.get_value(&BlockStateProperties::CONDITIONAL)
+    }
+
+    /// Starts a command run at `game_time`.
+    ///
+    /// Vanilla `BaseCommandBlock.performCommand` runs a block at most once per
+    /// game tick. Returns the command and the source name, or `None` if the
+    /// block already ran this tick.
+    pub fn start_command(&mut self, game_time: i64) -> Option<(String, String)> {
+        if game_time == self.last_execution {
+            return None;
+        }
+        self.success_count = 0;
+        self.last_execution = if self.update_last_execution {
+            game_time
+        } else {
+            -1
+        };
+        Some((self.command.clone(), self.name()))
+    }
+
+    /// Runs `f` on the command block entity at `pos`, if there is one.
+    pub fn with_at<R>(world: &World, pos: BlockPos, f: impl FnOnce(&mut Self) -> R) -> Option<R> {
+        let block_entity = world.get_block_entity(pos)?;
+        let mut guard = block_entity.lock();
+        let command_block = guard.downcast_mut::<Self>()?;
+        Some(f(command_block))
+    }
+
+    /// Vanilla `BaseCommandBlock.sendSystemMessage`: keeps `message` as the
+    /// last output, stamped with the local time.
+    pub fn record_output(world: &World, pos: BlockPos, message: &TextComponent) {
+        Self::with_at(world, pos, |this| {
+            if !this.track_output {
+                return;
+            }
+            let time = chrono::Local::now().format("[%H:%M:%S] ").to_string();
+            this.last_output = Some(TextComponent::plain(time).add_child(message.clone()));
+            this.set_changed();
+        });
+    }
+
+    /// Vanilla `CommandBlockEntity.markConditionMet`.
+    ///
+    /// Unconditional blocks always pass. Conditional ones pass if the command
+    /// block they face away from last succeeded.
+    pub fn mark_condition_met(world: &World, pos: BlockPos) -> bool {
+        let state = world.get_block_state(pos);
+        let condition_met = if state.get_value(&BlockStateProperties::CONDITIONAL) {
+            let facing = state.get_value(&BlockStateProperties::FACING);
+            let behind = pos.relative(facing.opposite());
+            is_command_block(world.get_block_state(behind).get_block())
+                && Self::with_at(world, behind, |behind| behind.success_count > 0).unwrap_or(false)
+        } else {
+            true
+        };
+        Self::with_at(world, pos, |this| this.condition_met = condition_met);
+        condition_met
+    }
+
+    /// Vanilla `CommandBlockEntity.scheduleTick`.
+    pub fn schedule_tick(world: &World, pos: BlockPos) {
+        let block = world.get_block_state(pos).get_block();
+        if is_command_block(block) {
+            Self::mark_condition_met(world, pos);
+            world.schedule_block_tick_default(pos, block, 1);
+        }
+    }
+
+    fn nbt_bool(value: bool) -> i8 {
+        i8::from(value)
+    }
+
+    fn read_bool(nbt: &NbtCompoundView<'_, '_>, key: &str, default: bool) -> bool {
+        nbt.byte(key).map_or(default, |value| value != 0)
+    }
+
+    fn read_component(nbt: &NbtCompoundView<'_, '_>, key: &str) -> Option<TextComponent> {
+        let tag = nbt.get(key)?;
+        TextComponent::from_nbt(&tag.to_owned())
+    }
+}
+
+impl BlockEntity for CommandBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::COMMAND_BLOCK
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.world.upgrade()
+    }
+
+    fn load_additional(&mut self, nbt: &BorrowedNbtCompound<'_>) {
+        let nbt: NbtCompoundView<'_, '_> = nbt.into();
+        self.command = nbt
+            .string("Command")
+            .map(|value| value.to_str().into_owned())
+            .unwrap_or_default();
+        self.success_count = nbt.int("SuccessCount").unwrap_or(0);
+        self.custom_name = Self::read_component(&nbt, "CustomName");
+        self.track_output = Self::read_bool(&nbt, "TrackOutput", true);
+        self.last_output = if self.track_output {
+            Self::read_component(&nbt, "LastOutput")
+        } else {
+            None
+        };
+        self.update_last_execution = Self::read_bool(&nbt, "UpdateLastExecution", true);
+        self.last_execution = if self.update_last_execution {
+            nbt.long("LastExecution").unwrap_or(-1)
+        } else {
+            -1
+        };
+        self.powered = Self::read_bool(&nbt, "powered", false);
+        self.condition_met = Self::read_bool(&nbt, "conditionMet", false);
+        // Pending ticks are saved with the chunk, so unlike `setAutomatic`
+        // loading doesn't schedule one.
+        self.auto = Self::read_bool(&nbt, "auto", false);
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        nbt.insert("Command", self.command.clone());
+        nbt.insert("SuccessCount", self.success_count);
+        if let Some(custom_name) = &self.custom_name {
+            nbt.insert("CustomName", custom_name.to_codec_nbt());
+        }
+        nbt.insert("TrackOutput", Self::nbt_bool(self.track_output));
+        if self.track_output
+            && let Some(last_output) = &self.last_output
+        {
+            nbt.insert("LastOutput", last_output.to_codec_nbt());
+        }
+        nbt.insert(
+            "UpdateLastExecution",
+            Self::nbt_bool(self.update_last_execution),
+        );
+        if self.update_last_execution && self.last_execution != -1 {
+            nbt.insert("LastExecution", self.last_execution);
+        }
+        nbt.insert("powered", Self::nbt_bool(self.powered));
+        nbt.insert("conditionMet", Self::nbt_bool(self.condition_met));
+        nbt.insert("auto", Self::nbt_bool(self.auto));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use simdnbt::borrow::read_compound as read_borrowed_compound;
+    use steel_registry::test_support::init_test_registry;
+
+    use super::*;
+
+    #[test]
+    fn command_block_round_trips_and_runs_once_per_tick() {
+        init_test_registry();
+        let state = vanilla_blocks::REPEATING_COMMAND_BLOCK.default_state();
+        let mut nbt = NbtCompound::new();
+        nbt.insert("Command", "say hi");
+        nbt.insert("SuccessCount", 3);
+        nbt.insert("TrackOutput", 0i8);
+        nbt.insert("LastOutput", TextComponent::plain("dropped").to_codec_nbt());
+        nbt.insert("LastExecution", 40i64);
+        nbt.insert("auto", 1i8);
+        let mut bytes = Vec::new();
+        nbt.write(&mut bytes);
+        let borrowed = read_borrowed_compound(&mut Cursor::new(bytes.as_slice()))
+            .expect("test nbt should reborrow");
+
+        let mut command_block =
+            CommandBlockEntity::new(Weak::new(), BlockPos::new(0, 64, 0), state);
+        command_block.load_additional(&borrowed);
+
+        assert_eq!(command_block.mode(), CommandBlockMode::Auto);
+        assert_eq!(command_block.command(), "say hi");
+        assert!(command_block.is_automatic());
+        assert!(command_block.last_output().is_none());
+        assert_eq!(command_block.start_command(40), None);
+        assert_eq!(
+            command_block.start_command(41),
+            Some(("say hi".to_owned(), "@".to_owned()))
+        );
+        assert_eq!(command_block.success_count(), 0);
+
+        let mut saved = NbtCompound::new();
+        command_block.save_additional(&mut saved);
+        assert_eq!(saved.long("LastExecution"), Some(41));
+        assert_eq!(saved.byte("TrackOutput"), Some(0));
+        assert!(saved.get("LastOutput").is_none());
+    }
+    #[test]
+    fn powered_impulse_block_runs_once_per_rising_edge() {
+        init_test_registry();
+        let pos = BlockPos::new(0, 64, 0);
+        let mut impulse = CommandBlockEntity::new(
+            Weak::new(),
+            pos,
+            vanilla_blocks::COMMAND_BLOCK.default_state(),
+        );
+
+        assert!(impulse.update_powered(true));
+        assert!(impulse.is_powered());
+        assert!(!impulse.update_powered(true));
+        assert!(!impulse.update_powered(false));
+        assert!(!impulse.is_powered());
+        assert!(impulse.update_powered(true));
+
+        impulse.update_powered(false);
+        impulse.set_automatic(true);
+        assert!(!impulse.update_powered(true));
+
+        let mut chain = CommandBlockEntity::new(
+            Weak::new(),
+            pos,
+            vanilla_blocks::CHAIN_COMMAND_BLOCK.default_state(),
+        );
+        assert!(!chain.update_powered(true));
+        assert!(chain.is_powered());
+    }
+}
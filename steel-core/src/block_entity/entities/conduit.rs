@@ -0,0 +1,334 @@
+//! Conduit block entity.
+//!
+//! A conduit surrounded by water and a prismarine frame grants conduit power
+//! to players in water or rain, and attacks hostile mobs once the frame is
+//! complete.
+
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::borrow::{BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView};
+use simdnbt::owned::{NbtCompound, NbtTag};
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::entity_type::MobCategory;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::{
+    sound_events, vanilla_block_entity_types, vanilla_blocks, vanilla_damage_types,
+    vanilla_mob_effects,
+};
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey, UuidExt as _, WorldAabb};
+use uuid::Uuid;
+
+use crate::behavior::BlockStateBehaviorExt as _;
+use crate::block_entity::{BlockEntity, BlockEntityTickAction};
+use crate::entity::damage::DamageSource;
+use crate::entity::{Entity, LivingEntity, MobEffectInstance, SharedEntity};
+use crate::world::World;
+
+/// Frame blocks needed to activate a conduit.
+const MIN_ACTIVE_SIZE: usize = 16;
+/// Frame blocks needed for a conduit to attack hostile mobs.
+const MIN_KILL_SIZE: usize = 42;
+/// Range within which a conduit attacks hostile mobs.
+const KILL_RANGE: f64 = 8.0;
+/// Ticks between frame scans.
+const SHAPE_CHECK_INTERVAL: i64 = 40;
+/// Ticks between ambient sounds.
+const AMBIENT_SOUND_INTERVAL: i64 = 80;
+/// Duration of the conduit power granted on each scan, in ticks.
+const EFFECT_DURATION: i32 = 260;
+
+/// Vanilla `ConduitBlockEntity`.
+pub struct ConduitBlockEntity {
+    world: Weak<World>,
+    pos: BlockPos,
+    state: BlockStateId,
+    removed: bool,
+    is_active: bool,
+    is_hunting: bool,
+    effect_blocks: Vec<BlockPos>,
+    destroy_target: Option<Uuid>,
+    next_ambient_sound_activation: i64,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `ConduitBlockEntity`.
+unsafe impl DowncastType for ConduitBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/conduit");
+}
+
+impl ConduitBlockEntity {
+    /// Creates an inactive conduit block entity.
+    #[must_use]
+    pub const fn new(world: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            world,
+            pos,
+            state,
+            removed: false,
+            is_active: false,
+            is_hunting: false,
+            effect_blocks: Vec::new(),
+            destroy_target: None,
+            next_ambient_sound_activation: 0,
+        }
+    }
+
+    /// Returns whether the conduit is surrounded by water and enough frame.
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    /// Returns whether the frame is complete enough to attack hostile mobs.
+    #[must_use]
+    pub const fn is_hunting(&self) -> bool {
+        self.is_hunting
+    }
+
+    /// Rescans the water and frame around the conduit.
+    ///
+    /// Vanilla equivalent: `ConduitBlockEntity.updateShape`.
+    fn update_shape(&mut self, world: &World) -> bool {
+        self.effect_blocks.clear();
+        for x in -1..=1 {
+            for y in -1..=1 {
+                for z in -1..=1 {
+                    let state = world.get_block_state(self.pos.offset(x, y, z));
+                    if !state.get_fluid_state().is_water() {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        for x in -2_i32..=2 {
+            for y in -2_i32..=2 {
+                for z in -2_i32..=2 {
+                    let (ax, ay, az) = (x.abs(), y.abs(), z.abs());
+                    let on_frame = (ax > 1 || ay > 1 || az > 1)
+                        && ((x == 0 && (ay == 2 || az == 2))
+                            || (y == 0 && (ax == 2 || az == 2))
+                            || (z == 0 && (ax == 2 || ay == 2)));
+                    if !on_frame {
+                        continue;
+                    }
+                    let pos = self.pos.offset(x, y, z);
+                    let block = world.get_block_state(pos).get_block();
+                    if block == &vanilla_blocks::PRISMARINE
+                        || block == &vanilla_blocks::PRISMARINE_BRICKS
+                        || block == &vanilla_blocks::SEA_LANTERN
+                        || block == &vanilla_blocks::DARK_PRISMARINE
+                    {
+                        self.effect_blocks.push(pos);
+                    }
+                }
+            }
+        }
+        self.effect_blocks.len() >= MIN_ACTIVE_SIZE
+    }
+
+    /// Grants conduit power to players in water or rain within range.
+    ///
+    /// Vanilla equivalent: `ConduitBlockEntity.applyEffects`.
+    fn apply_effects(&self, world: &World) {
+        let effect_range = (self.effect_blocks.len() / 7 * 16) as f64;
+        let area = block_aabb(self.pos)
+            .inflate(effect_range)
+            .expand_towards(DVec3::new(0.0, f64::from(world.get_height()), 0.0));
+        world.players.iter_players(|_, player| {
+            if !player.is_spectator()
+                && player.bounding_box().intersects(area)
+                && closer_than(self.pos, player.block_position(), effect_range)
+                && player.is_in_water_or_rain()
+            {
+                player.add_mob_effect(
+                    MobEffectInstance::with_duration(
+                        vanilla_mob_effects::CONDUIT_POWER,
+                        EFFECT_DURATION,
+                        0,
+                    )
+                    .with_ambient(true),
+                );
+            }
+            true
+        });
+    }
+
+    /// Keeps, drops or picks the hostile mob to attack and damages it.
+    ///
+    /// Vanilla equivalent: `ConduitBlockEntity.updateDestroyTarget`.
+    fn update_destroy_target(&mut self, world: &World) {
+        let previous = self.destroy_target;
+        let target = if self.effect_blocks.len() < MIN_KILL_SIZE {
+            None
+        } else if let Some(target) = self
+            .destroy_target
+            .and_then(|uuid| world.get_entity_by_uuid(&uuid))
+        {
+            let alive = target
+                .as_living_entity()
+                .is_some_and(LivingEntity::is_alive);
+            (alive && closer_than(self.pos, target.block_position(), KILL_RANGE)).then_some(target)
+        } else {
+            self.select_new_target(world)
+        };
+        self.destroy_target = target.as_ref().map(|target| target.uuid());
+
+        if let Some(target) = target {
+            world.play_sound_at(
+                &sound_events::BLOCK_CONDUIT_ATTACK_TARGET,
+                SoundSource::Blocks,
+                target.position(),
+                1.0,
+                1.0,
+                None,
+            );
+            target.hurt(
+                world,
+                &DamageSource::environment(&vanilla_damage_types::MAGIC),
+                4.0,
+            );
+        }
+        if previous != self.destroy_target {
+            world.send_block_updated(self.pos);
+        }
+    }
+
+    /// Picks a random hostile mob in water or rain near the conduit.
+    fn select_new_target(&self, world: &World) -> Option<SharedEntity> {
+        // Vanilla targets `Enemy` implementations, which are the monster
+        // category mobs.
+        let mut candidates = world.get_entities_in_aabb_matching(
+            &block_aabb(self.pos).inflate(KILL_RANGE),
+            |entity| {
+                entity.as_living_entity().is_some()
+                    && entity.entity_type().mob_category == MobCategory::Monster
+                    && entity.is_in_water_or_rain()
+            },
+        );
+        if candidates.is_empty() {
+            return None;
+        }
+        let index = rand::random_range(0..candidates.len());
+        Some(candidates.swap_remove(index))
+    }
+}
+
+fn block_aabb(pos: BlockPos) -> WorldAabb {
+    let (x, y, z) = (f64::from(pos.x()), f64::from(pos.y()), f64::from(pos.z()));
+    WorldAabb::new(x, y, z, x + 1.0, y + 1.0, z + 1.0)
+}
+
+/// Vanilla `Vec3i.closerThan`: squared block distance below `distance` squared.
+fn closer_than(a: BlockPos, b: BlockPos, distance: f64) -> bool {
+    let dx = f64::from(a.x() - b.x());
+    let dy = f64::from(a.y() - b.y());
+    let dz = f64::from(a.z() - b.z());
+    dx * dx + dy * dy + dz * dz < distance * distance
+}
+
+fn play_sound(world: &World, pos: BlockPos, sound: SoundEventRef) {
+    world.play_sound(sound, SoundSource::Blocks, pos, 1.0, 1.0, None);
+}
+
+impl BlockEntity for ConduitBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::CONDUIT
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.world.upgrade()
+    }
+
+    fn load_additional(&mut self, nbt: &BorrowedNbtCompound<'_>) {
+        let nbt: NbtCompoundView<'_, '_> = nbt.into();
+        self.destroy_target = nbt
+            .int_array("Target")
+            .and_then(|target| Uuid::from_int_array(&target));
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        if let Some(target) = self.destroy_target {
+            nbt.insert("Target", NbtTag::IntArray(target.to_int_array().to_vec()));
+        }
+    }
+
+    fn get_update_tag(&self) -> Option<NbtCompound> {
+        Some(self.save_custom_only())
+    }
+
+    fn is_ticking(&self) -> bool {
+        true
+    }
+
+    /// Vanilla equivalent: `ConduitBlockEntity.serverTick`.
+    fn tick(&mut self, world: &Arc<World>) -> Option<BlockEntityTickAction> {
+        let game_time = world.game_time();
+        if game_time % SHAPE_CHECK_INTERVAL == 0 {
+            let active = self.update_shape(world);
+            if active != self.is_active {
+                let sound = if active {
+                    &sound_events::BLOCK_CONDUIT_ACTIVATE
+                } else {
+                    &sound_events::BLOCK_CONDUIT_DEACTIVATE
+                };
+                play_sound(world, self.pos, sound);
+            }
+            self.is_active = active;
+            self.is_hunting = self.effect_blocks.len() >= MIN_KILL_SIZE;
+            if active {
+                self.apply_effects(world);
+                self.update_destroy_target(world);
+            }
+        }
+
+        if self.is_active {
+            if game_time % AMBIENT_SOUND_INTERVAL == 0 {
+                play_sound(world, self.pos, &sound_events::BLOCK_CONDUIT_AMBIENT);
+            }
+            if game_time > self.next_ambient_sound_activation {
+                self.next_ambient_sound_activation = game_time + 60 + rand::random_range(0..40);
+                play_sound(world, self.pos, &sound_events::BLOCK_CONDUIT_AMBIENT_SHORT);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closer_than_is_exclusive() {
+        let origin = BlockPos::new(0, 0, 0);
+        assert!(closer_than(origin, BlockPos::new(7, 0, 0), KILL_RANGE));
+        assert!(!closer_than(origin, BlockPos::new(8, 0, 0), KILL_RANGE));
+    }
+}
@@ -0,0 +1,177 @@
+//! Ender chest block entity implementation.
+//!
+//! Ender chests hold no items themselves. They only track viewers so the lid
+//! animates; the items live in each player's ender chest inventory.
+
+use std::sync::{Arc, Weak};
+
+use simdnbt::borrow::BaseNbtCompound as BorrowedNbtCompound;
+use simdnbt::owned::NbtCompound;
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::{sound_events, vanilla_block_entity_types};
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey};
+
+use crate::block_entity::{BlockEntity, ContainerOpeners, ContainerOpenersCounter};
+use crate::entity::Entity;
+use crate::player::Player;
+use crate::world::World;
+
+/// Ender chest block entity.
+pub struct EnderChestBlockEntity {
+    /// Weak reference to the world.
+    level: Weak<World>,
+    /// Position in the world.
+    pos: BlockPos,
+    /// Current block state.
+    state: BlockStateId,
+    /// Whether this entity has been marked for removal.
+    removed: bool,
+    /// Players currently viewing this ender chest.
+    openers: ContainerOpenersCounter,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `EnderChestBlockEntity`.
+unsafe impl DowncastType for EnderChestBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/ender_chest");
+}
+
+impl EnderChestBlockEntity {
+    /// Creates a new ender chest block entity.
+    #[must_use]
+    pub fn new(level: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            level,
+            pos,
+            state,
+            removed: false,
+            openers: ContainerOpenersCounter::default(),
+        }
+    }
+
+    /// Returns the number of players viewing this ender chest.
+    #[must_use]
+    pub const fn open_count(&self) -> i32 {
+        self.openers.open_count()
+    }
+
+    /// Based on Java's `EnderChestBlockEntity.startOpen`.
+    pub fn start_open(&mut self, player: &Player) {
+        if self.removed || player.is_spectator() {
+            return;
+        }
+        if let Some(world) = self.level.upgrade() {
+            self.openers.increment_openers(
+                &EnderChestOpeners,
+                player,
+                &world,
+                self.pos,
+                self.state,
+            );
+        }
+    }
+
+    /// Based on Java's `EnderChestBlockEntity.stopOpen`.
+    pub fn stop_open(&mut self, player: &Player) {
+        if self.removed || player.is_spectator() {
+            return;
+        }
+        if let Some(world) = self.level.upgrade() {
+            self.openers.decrement_openers(
+                &EnderChestOpeners,
+                player,
+                &world,
+                self.pos,
+                self.state,
+            );
+        }
+    }
+
+    /// Based on Java's `EnderChestBlockEntity.stillValid`.
+    #[must_use]
+    pub fn still_valid(&self, player: &Player) -> bool {
+        if self.removed {
+            return false;
+        }
+
+        let Some(level) = self.level.upgrade() else {
+            return false;
+        };
+
+        level.get_block_state(self.pos).get_block() == self.state.get_block()
+            && player.is_within_block_interaction_range_with_buffer(self.pos, 4.0)
+    }
+}
+
+/// Ender chest reactions to players opening and closing it.
+struct EnderChestOpeners;
+
+impl EnderChestOpeners {
+    fn play_sound(world: &World, pos: BlockPos, sound: SoundEventRef) {
+        let pitch = rand::random::<f32>() * 0.1 + 0.9;
+        world.play_sound(sound, SoundSource::Blocks, pos, 0.5, pitch, None);
+    }
+}
+
+impl ContainerOpeners for EnderChestOpeners {
+    fn on_open(&self, world: &Arc<World>, pos: BlockPos, _state: BlockStateId) {
+        Self::play_sound(world, pos, &sound_events::BLOCK_ENDER_CHEST_OPEN);
+    }
+
+    fn on_close(&self, world: &Arc<World>, pos: BlockPos, _state: BlockStateId) {
+        Self::play_sound(world, pos, &sound_events::BLOCK_ENDER_CHEST_CLOSE);
+    }
+
+    /// Sends the lid animation block event.
+    fn opener_count_changed(
+        &self,
+        world: &Arc<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+        _previous: i32,
+        current: i32,
+    ) {
+        let count = u8::try_from(current).unwrap_or(u8::MAX);
+        world.block_event(pos, state.get_block(), 1, count);
+    }
+}
+
+impl BlockEntity for EnderChestBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::ENDER_CHEST
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.level.upgrade()
+    }
+
+    fn load_additional(&mut self, _nbt: &BorrowedNbtCompound<'_>) {}
+
+    fn save_additional(&self, _nbt: &mut NbtCompound) {}
+}
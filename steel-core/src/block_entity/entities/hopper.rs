@@ -0,0 +1,546 @@
+//! Hopper block entity implementation.
+//!
+//! Hoppers pull items from the container or item entities above them and push
+//! them into the container they face, moving one item every 8 ticks. The
+//! transfer helpers are shared with hopper minecarts, like vanilla's `Hopper`
+//! interface.
+
+use std::mem;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::ToNbtTag;
+use simdnbt::borrow::{BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView};
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::blocks::properties::BlockStateProperties;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::{vanilla_block_entity_types, vanilla_blocks};
+use steel_utils::{
+    BlockPos, BlockStateId, Downcast as _, DowncastType, DowncastTypeKey, WorldAabb,
+};
+
+use crate::behavior::blocks::chest_containers;
+use crate::block_entity::entities::ChestBlockEntity;
+use crate::block_entity::{BlockEntity, BlockEntityTickAction};
+use crate::entity::entities::{HopperMinecartEntity, ItemEntity};
+use crate::entity::{Entity, RemovalReason};
+use crate::inventory::container::Container;
+use crate::inventory::lock::{ContainerLockGuard, ContainerRef};
+use crate::player::Player;
+use crate::world::World;
+
+/// Number of slots in a hopper.
+pub const HOPPER_SLOTS: usize = 5;
+
+/// Ticks between item transfers.
+///
+/// Vanilla `HopperBlockEntity.MOVE_ITEM_SPEED`.
+pub const MOVE_ITEM_SPEED: i32 = 8;
+
+/// Returns the area a hopper centered on `origin` collects item entities from.
+///
+/// Vanilla `Hopper.SUCK_AABB`, a full-width column from the hopper's bowl up to
+/// the top of the block above, moved to the hopper.
+#[must_use]
+pub fn suck_aabb(origin: DVec3) -> WorldAabb {
+    WorldAabb::from_min_max(
+        DVec3::new(origin.x - 0.5, origin.y - 0.5 + 11.0 / 16.0, origin.z - 0.5),
+        DVec3::new(origin.x + 0.5, origin.y + 1.5, origin.z + 0.5),
+    )
+}
+
+/// Hopper block entity.
+pub struct HopperBlockEntity {
+    /// Weak reference to the world for marking chunks dirty.
+    level: Weak<World>,
+    /// Position in the world.
+    pos: BlockPos,
+    /// Current block state.
+    state: BlockStateId,
+    /// Whether this entity has been marked for removal.
+    removed: bool,
+    /// The 5 item slots.
+    items: Vec<ItemStack>,
+    /// Ticks until the next transfer, or -1 when idle.
+    cooldown_time: i32,
+    /// Game time of the last tick, used to order transfers between hoppers.
+    ticked_game_time: i64,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `HopperBlockEntity`.
+unsafe impl DowncastType for HopperBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/hopper");
+}
+
+impl HopperBlockEntity {
+    /// Creates a new hopper block entity.
+    #[must_use]
+    pub fn new(level: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            level,
+            pos,
+            state,
+            removed: false,
+            items: vec![ItemStack::empty(); HOPPER_SLOTS],
+            cooldown_time: -1,
+            ticked_game_time: 0,
+        }
+    }
+
+    const fn set_cooldown(&mut self, cooldown_time: i32) {
+        self.cooldown_time = cooldown_time;
+    }
+
+    const fn is_on_cooldown(&self) -> bool {
+        self.cooldown_time > 0
+    }
+
+    /// Whether a neighbouring hopper pushed the cooldown past the usual delay.
+    const fn is_on_custom_cooldown(&self) -> bool {
+        self.cooldown_time > MOVE_ITEM_SPEED
+    }
+
+    /// Returns the center of the hopper, vanilla `getLevelX/Y/Z`.
+    fn origin(&self) -> DVec3 {
+        DVec3::new(
+            f64::from(self.pos.x()) + 0.5,
+            f64::from(self.pos.y()) + 0.5,
+            f64::from(self.pos.z()) + 0.5,
+        )
+    }
+
+    /// Returns whether every slot holds a full stack.
+    fn inventory_full(&self) -> bool {
+        self.items
+            .iter()
+            .all(|item| !item.is_empty() && item.count() == item.max_stack_size())
+    }
+
+    /// Runs a transfer step unless the hopper is cooling down or disabled.
+    ///
+    /// Vanilla `HopperBlockEntity.tryMoveItems`. `action` pulls items in.
+    fn try_move_items(&mut self, world: &World, action: impl FnOnce(&mut Self) -> bool) -> bool {
+        if self.is_on_cooldown() || !self.state.get_value(&BlockStateProperties::ENABLED) {
+            return false;
+        }
+
+        let mut changed = false;
+        if !Container::is_empty(self) {
+            changed = self.eject_items(world);
+        }
+        if !self.inventory_full() {
+            changed |= action(self);
+        }
+
+        if changed {
+            self.set_cooldown(MOVE_ITEM_SPEED);
+            BlockEntity::set_changed(self);
+        }
+        changed
+    }
+
+    /// Pushes one item into the container the hopper faces.
+    ///
+    /// Vanilla `HopperBlockEntity.ejectItems`.
+    fn eject_items(&mut self, world: &World) -> bool {
+        let facing = self.state.get_value(&BlockStateProperties::FACING_HOPPER);
+        let Some(target) = get_container_at(world, self.pos.relative(facing)) else {
+            return false;
+        };
+
+        let refs: Vec<&ContainerRef> = target.iter().collect();
+        let mut guard = ContainerLockGuard::lock_all(&refs);
+        if is_full_container(&guard, &target) {
+            return false;
+        }
+
+        for slot in 0..HOPPER_SLOTS {
+            if self.items[slot].is_empty() {
+                continue;
+            }
+            let remainder = add_item_to_containers(
+                &mut guard,
+                &target,
+                self.items[slot].copy_with_count(1),
+                Some(self.ticked_game_time),
+            );
+            if remainder.is_empty() {
+                self.items[slot].shrink(1);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Picks up `entity` if it is an item that fell into the hopper.
+    ///
+    /// Vanilla `HopperBlockEntity.entityInside`.
+    pub fn entity_inside(&mut self, world: &World, entity: &dyn Entity) {
+        let Some(item_entity) = entity.downcast_ref::<ItemEntity>() else {
+            return;
+        };
+        if item_entity.get_item().is_empty()
+            || !entity.bounding_box().intersects(suck_aabb(self.origin()))
+        {
+            return;
+        }
+        self.try_move_items(world, |hopper| add_item_entity(hopper, item_entity));
+    }
+}
+
+impl BlockEntity for HopperBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::HOPPER
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.level.upgrade()
+    }
+
+    fn pre_remove_side_effects(&mut self, pos: BlockPos, _state: BlockStateId) {
+        if let Some(world) = self.level.upgrade() {
+            for item in self.items.drain(..) {
+                world.drop_item_stack(pos, item);
+            }
+        }
+    }
+
+    fn load_additional(&mut self, nbt: &BorrowedNbtCompound<'_>) {
+        let nbt_view: NbtCompoundView<'_, '_> = nbt.into();
+
+        self.cooldown_time = nbt_view.int("TransferCooldown").unwrap_or(-1);
+        if let Some(items_list) = nbt_view.list("Items")
+            && let Some(compounds) = items_list.compounds()
+        {
+            for compound in compounds {
+                if let Some(slot) = compound.byte("Slot") {
+                    let slot = slot as usize;
+                    if slot < HOPPER_SLOTS
+                        && let Some(item) = ItemStack::from_borrowed_compound(&compound)
+                    {
+                        self.items[slot] = item;
+                    }
+                }
+            }
+        }
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        let mut items: Vec<NbtCompound> = Vec::new();
+        for (slot, item) in self.items.iter().enumerate() {
+            if !item.is_empty()
+                && let NbtTag::Compound(mut item_nbt) = item.clone().to_nbt_tag()
+            {
+                item_nbt.insert("Slot", slot as i8);
+                items.push(item_nbt);
+            }
+        }
+        nbt.insert("Items", NbtList::Compound(items));
+        nbt.insert("TransferCooldown", self.cooldown_time);
+    }
+
+    fn is_ticking(&self) -> bool {
+        true
+    }
+
+    /// Vanilla `HopperBlockEntity.pushItemsTick`.
+    fn tick(&mut self, world: &Arc<World>) -> Option<BlockEntityTickAction> {
+        self.cooldown_time -= 1;
+        self.ticked_game_time = world.game_time();
+        if !self.is_on_cooldown() {
+            self.set_cooldown(0);
+            let origin = self.origin();
+            self.try_move_items(world, |hopper| suck_in_items(world, hopper, origin, true));
+        }
+        None
+    }
+
+    fn as_container(&self) -> Option<&(dyn Container + 'static)> {
+        Some(self)
+    }
+
+    fn as_container_mut(&mut self) -> Option<&mut (dyn Container + 'static)> {
+        Some(self)
+    }
+}
+
+impl Container for HopperBlockEntity {
+    fn get_container_size(&self) -> usize {
+        HOPPER_SLOTS
+    }
+
+    fn get_item(&self, slot: usize) -> &ItemStack {
+        &self.items[slot]
+    }
+
+    fn get_item_mut(&mut self, slot: usize) -> &mut ItemStack {
+        &mut self.items[slot]
+    }
+
+    fn set_item(&mut self, slot: usize, stack: ItemStack) {
+        if slot < HOPPER_SLOTS {
+            self.items[slot] = stack;
+            self.set_changed();
+        }
+    }
+
+    fn still_valid(&self, player: &Player) -> bool {
+        if self.removed {
+            return false;
+        }
+
+        let Some(level) = self.level.upgrade() else {
+            return false;
+        };
+
+        level.get_block_state(self.pos).get_block() == &vanilla_blocks::HOPPER
+            && player.is_within_block_interaction_range_with_buffer(self.pos, 4.0)
+    }
+
+    fn set_changed(&mut self) {
+        BlockEntity::set_changed(self);
+    }
+}
+
+/// Resolves the container at `pos`, double chests as both halves.
+///
+/// Vanilla `HopperBlockEntity.getContainerAt`: a container block entity, or else
+/// a random container entity inside the block.
+fn get_container_at(world: &World, pos: BlockPos) -> Option<Vec<ContainerRef>> {
+    // TODO: Blocks that are containers without a block entity (composters)
+    if let Some(block_entity) = world.get_block_entity(pos) {
+        if block_entity.lock().is::<ChestBlockEntity>() {
+            let (first, second) = chest_containers(world.get_block_state(pos), world, pos)?;
+            return Some([Some(first), second].into_iter().flatten().collect());
+        }
+        if let Some(container) = ContainerRef::from_block_entity(block_entity) {
+            return Some(vec![container]);
+        }
+    }
+
+    // TODO: Chest minecarts once they carry items
+    let center = DVec3::new(
+        f64::from(pos.x()) + 0.5,
+        f64::from(pos.y()) + 0.5,
+        f64::from(pos.z()) + 0.5,
+    );
+    let aabb = WorldAabb::from_min_max(center - 0.5, center + 0.5);
+    let entities = world.get_entities_in_aabb_matching(&aabb, |entity| {
+        entity.is_alive() && entity.is::<HopperMinecartEntity>()
+    });
+    if entities.is_empty() {
+        return None;
+    }
+    let entity = &entities[rand::random_range(0..entities.len())];
+    let minecart = entity.downcast_ref::<HopperMinecartEntity>()?;
+    Some(vec![ContainerRef::from(Arc::clone(minecart.container()))])
+}
+
+/// Vanilla `HopperBlockEntity.isFullContainer`.
+fn is_full_container(guard: &ContainerLockGuard, target: &[ContainerRef]) -> bool {
+    target
+        .iter()
+        .filter_map(|part| guard.get(part.container_id()))
+        .all(|container| {
+            (0..container.get_container_size()).all(|slot| {
+                let item = container.get_item(slot);
+                !item.is_empty() && item.count() >= item.max_stack_size()
+            })
+        })
+}
+
+/// Inserts `stack` into the halves of `target` in slot order, returning what is left.
+fn add_item_to_containers(
+    guard: &mut ContainerLockGuard,
+    target: &[ContainerRef],
+    mut stack: ItemStack,
+    source_ticked_game_time: Option<i64>,
+) -> ItemStack {
+    for part in target {
+        if stack.is_empty() {
+            break;
+        }
+        if let Some(container) = guard.get_mut(part.container_id()) {
+            stack = add_item(container, stack, source_ticked_game_time);
+        }
+    }
+    stack
+}
+
+/// Inserts `stack` into `destination`, returning what did not fit.
+///
+/// Vanilla `HopperBlockEntity.addItem`. `source_ticked_game_time` is set when
+/// the items come from another hopper block.
+pub(crate) fn add_item(
+    destination: &mut dyn Container,
+    mut stack: ItemStack,
+    source_ticked_game_time: Option<i64>,
+) -> ItemStack {
+    // TODO: Respect the faces of sided containers (WorldlyContainer)
+    for slot in 0..destination.get_container_size() {
+        if stack.is_empty() {
+            break;
+        }
+        stack = try_move_in_item(destination, stack, slot, source_ticked_game_time);
+    }
+    stack
+}
+
+/// Vanilla `HopperBlockEntity.tryMoveInItem`.
+fn try_move_in_item(
+    destination: &mut dyn Container,
+    mut stack: ItemStack,
+    slot: usize,
+    source_ticked_game_time: Option<i64>,
+) -> ItemStack {
+    if !destination.can_place_item(slot, &stack) {
+        return stack;
+    }
+
+    let was_empty = destination.is_empty();
+    let existing = destination.get_item(slot);
+    let moved = if existing.is_empty() {
+        destination.set_item(slot, mem::take(&mut stack));
+        true
+    } else if existing.count() <= existing.max_stack_size()
+        && ItemStack::is_same_item_same_components(existing, &stack)
+    {
+        let space = stack.max_stack_size() - existing.count();
+        let count = stack.count().min(space);
+        stack.shrink(count);
+        destination.get_item_mut(slot).grow(count);
+        count > 0
+    } else {
+        false
+    };
+
+    if moved {
+        if was_empty
+            && let Some(hopper) = destination.downcast_mut::<HopperBlockEntity>()
+            && !hopper.is_on_custom_cooldown()
+        {
+            // The hopper that ticks later this game tick waits one tick less.
+            let offset = i32::from(
+                source_ticked_game_time.is_some_and(|ticked| hopper.ticked_game_time >= ticked),
+            );
+            hopper.set_cooldown(MOVE_ITEM_SPEED - offset);
+        }
+        destination.set_changed();
+    }
+    stack
+}
+
+/// Pulls one item from the container above, or collects item entities there.
+///
+/// Vanilla `HopperBlockEntity.suckInItems`. `origin` is the hopper's center;
+/// `grid_aligned` hoppers are blocked by a full block above them.
+pub(crate) fn suck_in_items(
+    world: &World,
+    hopper: &mut dyn Container,
+    origin: DVec3,
+    grid_aligned: bool,
+) -> bool {
+    let above = BlockPos::containing(origin.x, origin.y + 1.0, origin.z);
+    if let Some(source) = get_container_at(world, above) {
+        let refs: Vec<&ContainerRef> = source.iter().collect();
+        let mut guard = ContainerLockGuard::lock_all(&refs);
+        for part in &source {
+            let Some(container) = guard.get_mut(part.container_id()) else {
+                continue;
+            };
+            for slot in 0..container.get_container_size() {
+                if try_take_in_item_from_slot(hopper, container, slot) {
+                    return true;
+                }
+            }
+        }
+        return false;
+    }
+
+    let state = world.get_block_state(above);
+    if grid_aligned
+        && world.is_collision_shape_full_block_at(above, state)
+        && !state.get_block().has_tag(&BlockTag::DOES_NOT_BLOCK_HOPPERS)
+    {
+        return false;
+    }
+
+    let items = world.get_entities_in_aabb_matching(&suck_aabb(origin), |entity| {
+        entity.is_alive() && entity.is::<ItemEntity>()
+    });
+    items.iter().any(|entity| {
+        entity
+            .downcast_ref::<ItemEntity>()
+            .is_some_and(|item_entity| add_item_entity(hopper, item_entity))
+    })
+}
+
+/// Vanilla `HopperBlockEntity.tryTakeInItemFromSlot`.
+fn try_take_in_item_from_slot(
+    hopper: &mut dyn Container,
+    container: &mut dyn Container,
+    slot: usize,
+) -> bool {
+    let item = container.get_item(slot);
+    if item.is_empty() || !container.can_take_item(slot, item) {
+        return false;
+    }
+
+    let source_ticked_game_time = container
+        .downcast_ref::<HopperBlockEntity>()
+        .map(|source| source.ticked_game_time);
+    let remainder = add_item(hopper, item.copy_with_count(1), source_ticked_game_time);
+    if !remainder.is_empty() {
+        return false;
+    }
+    container.get_item_mut(slot).shrink(1);
+    container.set_changed();
+    true
+}
+
+/// Moves as much of `item_entity`'s stack into `hopper` as fits.
+///
+/// Vanilla `HopperBlockEntity.addItem(Container, ItemEntity)`. Returns whether
+/// the whole stack was taken.
+pub(crate) fn add_item_entity(hopper: &mut dyn Container, item_entity: &ItemEntity) -> bool {
+    let stack = item_entity.get_item();
+    let count = stack.count();
+    let remainder = add_item(hopper, stack, None);
+    if remainder.is_empty() {
+        item_entity.set_item(ItemStack::empty());
+        item_entity.set_removed(RemovalReason::Discarded);
+        true
+    } else {
+        if remainder.count() != count {
+            item_entity.set_item(remainder);
+        }
+        false
+    }
+}
@@ -0,0 +1,241 @@
+//! Jigsaw block entity.
+
+use std::str::FromStr;
+use std::sync::{Arc, Weak};
+
+use simdnbt::borrow::{BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView};
+use simdnbt::owned::NbtCompound;
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::{BlockStateProperties, FrontAndTop};
+use steel_registry::template_pool::JointType;
+use steel_registry::vanilla_block_entity_types;
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey, Identifier};
+
+use crate::block_entity::BlockEntity;
+use crate::world::World;
+
+/// Vanilla `JigsawBlockEntity`.
+pub struct JigsawBlockEntity {
+    world: Weak<World>,
+    pos: BlockPos,
+    state: BlockStateId,
+    removed: bool,
+    name: Identifier,
+    target: Identifier,
+    pool: Identifier,
+    joint: JointType,
+    final_state: String,
+    placement_priority: i32,
+    selection_priority: i32,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `JigsawBlockEntity`.
+unsafe impl DowncastType for JigsawBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/jigsaw");
+}
+
+/// Vanilla `JigsawBlockEntity.JointType` default for a jigsaw without a stored
+/// joint: horizontal jigsaws are aligned, vertical ones can roll.
+fn default_joint_type(state: BlockStateId) -> JointType {
+    match state.get_value(&BlockStateProperties::ORIENTATION) {
+        FrontAndTop::DownEast
+        | FrontAndTop::DownNorth
+        | FrontAndTop::DownSouth
+        | FrontAndTop::DownWest
+        | FrontAndTop::UpEast
+        | FrontAndTop::UpNorth
+        | FrontAndTop::UpSouth
+        | FrontAndTop::UpWest => JointType::Rollable,
+        FrontAndTop::WestUp | FrontAndTop::EastUp | FrontAndTop::NorthUp | FrontAndTop::SouthUp => {
+            JointType::Aligned
+        }
+    }
+}
+
+fn read_identifier(nbt: &NbtCompoundView<'_, '_>, key: &str) -> Identifier {
+    nbt.string(key)
+        .and_then(|value| Identifier::from_str(&value.to_str()).ok())
+        .unwrap_or_else(|| Identifier::vanilla_static("empty"))
+}
+
+impl JigsawBlockEntity {
+    /// Creates a jigsaw block entity with vanilla default settings.
+    #[must_use]
+    pub fn new(world: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            world,
+            pos,
+            state,
+            removed: false,
+            name: Identifier::vanilla_static("empty"),
+            target: Identifier::vanilla_static("empty"),
+            pool: Identifier::vanilla_static("empty"),
+            joint: JointType::Rollable,
+            final_state: "minecraft:air".to_owned(),
+            placement_priority: 0,
+            selection_priority: 0,
+        }
+    }
+
+    /// Returns the jigsaw's own connection name.
+    #[must_use]
+    pub const fn name(&self) -> &Identifier {
+        &self.name
+    }
+
+    /// Returns the connection name this jigsaw attaches to.
+    #[must_use]
+    pub const fn target(&self) -> &Identifier {
+        &self.target
+    }
+
+    /// Returns the template pool this jigsaw generates from.
+    #[must_use]
+    pub const fn pool(&self) -> &Identifier {
+        &self.pool
+    }
+
+    /// Returns how the attached piece may rotate around the connection.
+    #[must_use]
+    pub const fn joint(&self) -> JointType {
+        self.joint
+    }
+
+    /// Returns the block state string the jigsaw turns into after generation.
+    #[must_use]
+    pub fn final_state(&self) -> &str {
+        &self.final_state
+    }
+
+    /// Applies the settings sent from the jigsaw block screen.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "mirrors the fields of vanilla's jigsaw block screen packet"
+    )]
+    pub fn apply_settings(
+        &mut self,
+        name: Identifier,
+        target: Identifier,
+        pool: Identifier,
+        final_state: String,
+        joint: JointType,
+        placement_priority: i32,
+        selection_priority: i32,
+    ) {
+        self.name = name;
+        self.target = target;
+        self.pool = pool;
+        self.final_state = final_state;
+        self.joint = joint;
+        self.placement_priority = placement_priority;
+        self.selection_priority = selection_priority;
+    }
+}
+
+impl BlockEntity for JigsawBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::JIGSAW
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.world.upgrade()
+    }
+
+    fn load_additional(&mut self, nbt: &BorrowedNbtCompound<'_>) {
+        let nbt: NbtCompoundView<'_, '_> = nbt.into();
+        self.name = read_identifier(&nbt, "name");
+        self.target = read_identifier(&nbt, "target");
+        self.pool = read_identifier(&nbt, "pool");
+        self.final_state = nbt
+            .string("final_state")
+            .map_or_else(|| "minecraft:air".to_owned(), |value| value.to_string());
+        self.joint = nbt
+            .string("joint")
+            .and_then(|value| JointType::from_name(&value.to_str()))
+            .unwrap_or_else(|| default_joint_type(self.state));
+        self.placement_priority = nbt.int("placement_priority").unwrap_or(0);
+        self.selection_priority = nbt.int("selection_priority").unwrap_or(0);
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        nbt.insert("name", self.name.to_string());
+        nbt.insert("target", self.target.to_string());
+        nbt.insert("pool", self.pool.to_string());
+        nbt.insert("final_state", self.final_state.clone());
+        nbt.insert("joint", self.joint.name());
+        nbt.insert("placement_priority", self.placement_priority);
+        nbt.insert("selection_priority", self.selection_priority);
+    }
+
+    fn get_update_tag(&self) -> Option<NbtCompound> {
+        Some(self.save_custom_only())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use simdnbt::borrow::read_compound as read_borrowed_compound;
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::vanilla_blocks;
+
+    use super::*;
+
+    #[test]
+    fn jigsaw_defaults_joint_from_orientation() {
+        init_test_registry();
+        let state = vanilla_blocks::JIGSAW
+            .default_state()
+            .set_value(&BlockStateProperties::ORIENTATION, FrontAndTop::NorthUp);
+        let mut nbt = NbtCompound::new();
+        nbt.insert("pool", "minecraft:village/plains/houses");
+        let mut bytes = Vec::new();
+        nbt.write(&mut bytes);
+        let borrowed = read_borrowed_compound(&mut Cursor::new(bytes.as_slice()))
+            .expect("test nbt should reborrow");
+
+        let mut jigsaw = JigsawBlockEntity::new(Weak::new(), BlockPos::new(0, 64, 0), state);
+        jigsaw.load_additional(&borrowed);
+
+        assert_eq!(jigsaw.joint(), JointType::Aligned);
+        assert_eq!(jigsaw.pool().to_string(), "minecraft:village/plains/houses");
+        assert_eq!(jigsaw.target().to_string(), "minecraft:empty");
+
+        let mut saved = NbtCompound::new();
+        jigsaw.save_additional(&mut saved);
+        assert_eq!(
+            saved.string("joint").map(ToString::to_string),
+            Some("aligned".to_owned())
+        );
+        assert_eq!(
+            saved.string("final_state").map(ToString::to_string),
+            Some("minecraft:air".to_owned())
+        );
+    }
+}
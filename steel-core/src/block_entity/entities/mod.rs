@@ -1,19 +1,47 @@
 //! Block entity implementations.
 
 mod barrel;
+mod beacon;
 mod beehive;
+mod chest;
+mod command_block;
+mod conduit;
 mod end_gateway;
 mod end_portal;
+mod ender_chest;
+mod hopper;
+mod jigsaw;
 mod potent_sulfur;
 mod raw;
+mod sculk_sensor;
+mod sculk_shrieker;
+mod shulker_box;
 mod sign;
+mod spawner;
+mod structure_block;
 
 pub use barrel::{BARREL_SLOTS, BarrelBlockEntity};
+pub use beacon::{
+    BEACON_MAX_LEVELS, BeaconBeamSection, BeaconBlockEntity, encode_beacon_effect,
+    is_valid_beacon_effect,
+};
 pub use beehive::{
     BEEHIVE_MAX_OCCUPANTS, BEEHIVE_MIN_OCCUPATION_TICKS_NECTARLESS, BeehiveBlockEntity,
 };
+pub use chest::{CHEST_SLOTS, ChestBlockEntity};
+pub use command_block::{CommandBlockEntity, command_block_for_mode, is_command_block};
+pub use conduit::ConduitBlockEntity;
 pub use end_gateway::EndGatewayBlockEntity;
 pub use end_portal::EndPortalBlockEntity;
+pub use ender_chest::EnderChestBlockEntity;
+pub use hopper::{HOPPER_SLOTS, HopperBlockEntity, MOVE_ITEM_SPEED, suck_aabb};
+pub(crate) use hopper::{add_item, add_item_entity, suck_in_items};
+pub use jigsaw::JigsawBlockEntity;
 pub use potent_sulfur::PotentSulfurBlockEntity;
 pub use raw::RawBlockEntity;
+pub use sculk_sensor::{SculkSensorBlockEntity, SculkSensorVibrationUser};
+pub use sculk_shrieker::{SculkShriekerBlockEntity, SculkShriekerVibrationUser};
+pub use shulker_box::{SHULKER_BOX_SLOTS, ShulkerBoxBlockEntity};
 pub use sign::{SIGN_LINES, SignBlockEntity, SignText};
+pub use spawner::SpawnerBlockEntity;
+pub use structure_block::{MAX_STRUCTURE_SIZE, StructureBlockEntity, StructureBlockFlags};
@@ -0,0 +1,176 @@
+//! Sculk sensor block entity.
+//!
+//! Listens for vibrations within eight blocks and activates the sensor when
+//! one arrives, with a redstone strength that falls off with distance.
+
+use std::sync::{Arc, Weak};
+
+use simdnbt::borrow::{BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView};
+use simdnbt::owned::{NbtCompound, NbtTag};
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::game_events::GameEventRef;
+use steel_registry::{vanilla_block_entity_types, vanilla_game_events};
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey};
+
+use crate::behavior::blocks::SculkSensorBlock;
+use crate::block_entity::{BlockEntity, BlockEntityTickAction};
+use crate::world::World;
+use crate::world::game_event_context::GameEventContext;
+use crate::world::game_event_listener::SharedGameEventListener;
+use crate::world::vibration::{
+    VibrationListener, VibrationUser, game_event_frequency, redstone_strength_for_distance,
+};
+
+/// Block distance within which a sculk sensor hears vibrations.
+const LISTENER_RANGE: i32 = 8;
+
+/// Vanilla `SculkSensorBlockEntity.VibrationUser`.
+pub struct SculkSensorVibrationUser {
+    pos: BlockPos,
+}
+
+impl VibrationUser for SculkSensorVibrationUser {
+    fn listener_radius(&self) -> i32 {
+        LISTENER_RANGE
+    }
+
+    fn can_receive_vibration(
+        &self,
+        world: &World,
+        pos: BlockPos,
+        event: GameEventRef,
+        _context: &GameEventContext<'_>,
+    ) -> bool {
+        // The sensor ignores its own placement and removal.
+        if pos == self.pos
+            && (event == &vanilla_game_events::BLOCK_DESTROY
+                || event == &vanilla_game_events::BLOCK_PLACE)
+        {
+            return false;
+        }
+        SculkSensorBlock::can_activate(world.get_block_state(self.pos))
+    }
+
+    fn can_trigger_avoid_vibration(&self) -> bool {
+        true
+    }
+}
+
+/// Vanilla `SculkSensorBlockEntity`.
+pub struct SculkSensorBlockEntity {
+    world: Weak<World>,
+    pos: BlockPos,
+    state: BlockStateId,
+    removed: bool,
+    listener: Arc<VibrationListener<SculkSensorVibrationUser>>,
+    last_vibration_frequency: i32,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `SculkSensorBlockEntity`.
+unsafe impl DowncastType for SculkSensorBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/sculk_sensor");
+}
+
+impl SculkSensorBlockEntity {
+    /// Creates an idle sculk sensor block entity.
+    #[must_use]
+    pub fn new(world: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            world,
+            pos,
+            state,
+            removed: false,
+            listener: Arc::new(VibrationListener::new(
+                pos,
+                SculkSensorVibrationUser { pos },
+            )),
+            last_vibration_frequency: 0,
+        }
+    }
+
+    /// Returns the vibration listener of this sensor.
+    #[must_use]
+    pub const fn listener(&self) -> &Arc<VibrationListener<SculkSensorVibrationUser>> {
+        &self.listener
+    }
+
+    /// Returns the frequency of the last vibration that activated the sensor.
+    #[must_use]
+    pub const fn last_vibration_frequency(&self) -> i32 {
+        self.last_vibration_frequency
+    }
+}
+
+impl BlockEntity for SculkSensorBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::SCULK_SENSOR
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.world.upgrade()
+    }
+
+    fn load_additional(&mut self, nbt: &BorrowedNbtCompound<'_>) {
+        let nbt: NbtCompoundView<'_, '_> = nbt.into();
+        self.last_vibration_frequency = nbt.int("last_vibration_frequency").unwrap_or(0);
+        if let Some(listener) = nbt.compound("listener") {
+            self.listener.load(listener);
+        }
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        nbt.insert("last_vibration_frequency", self.last_vibration_frequency);
+        nbt.insert("listener", NbtTag::Compound(self.listener.save()));
+    }
+
+    fn is_ticking(&self) -> bool {
+        true
+    }
+
+    /// Vanilla equivalent: `VibrationSystem.Ticker.tick` with
+    /// `SculkSensorBlockEntity.VibrationUser.onReceiveVibration`.
+    fn tick(&mut self, world: &Arc<World>) -> Option<BlockEntityTickAction> {
+        let vibration = self.listener.tick(world)?;
+        if !SculkSensorBlock::can_activate(world.get_block_state(self.pos)) {
+            return None;
+        }
+        let frequency = game_event_frequency(vibration.event);
+        let power = redstone_strength_for_distance(vibration.distance, LISTENER_RANGE);
+        self.last_vibration_frequency = frequency;
+        let pos = self.pos;
+        Some(BlockEntityTickAction::Callback(Box::new(move |world| {
+            let source = vibration
+                .source
+                .and_then(|uuid| world.get_entity_by_uuid(&uuid));
+            SculkSensorBlock::activate(source.as_deref(), world, pos, power, frequency);
+        })))
+    }
+
+    fn game_event_listener(&self) -> Option<SharedGameEventListener> {
+        Some(self.listener.clone())
+    }
+}
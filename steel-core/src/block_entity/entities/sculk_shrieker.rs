@@ -0,0 +1,365 @@
+//! Sculk shrieker block entity.
+//!
+//! Listens for vibrations caused by players, shrieks, and raises the warning
+//! level shared by nearby players. Once the shriek ends it answers with a
+//! warden sound and darkness.
+
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::borrow::{BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView};
+use simdnbt::owned::{NbtCompound, NbtTag};
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::BlockStateProperties;
+use steel_registry::game_events::GameEventRef;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_game_event_tags::GameEventsTag;
+use steel_registry::{
+    level_events, sound_events, vanilla_block_entity_types, vanilla_game_events,
+    vanilla_game_rules, vanilla_mob_effects,
+};
+use steel_utils::types::{Difficulty, GameType, UpdateFlags};
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey, Identifier};
+use uuid::Uuid;
+
+use crate::block_entity::{BlockEntity, BlockEntityTickAction};
+use crate::entity::{Entity, LivingEntity as _, MobEffectInstance};
+use crate::player::{MAX_WARNING_LEVEL, Player, WardenSpawnTracker};
+use crate::world::World;
+use crate::world::game_event_context::GameEventContext;
+use crate::world::game_event_listener::SharedGameEventListener;
+use crate::world::vibration::{VibrationListener, VibrationUser};
+
+/// Block distance within which a sculk shrieker hears vibrations.
+const LISTENER_RADIUS: i32 = 8;
+/// Ticks a shrieker keeps shrieking before it responds.
+const SHRIEKING_TICKS: i32 = 90;
+/// Maximum block offset of the warden reply sound.
+const WARNING_SOUND_RADIUS: i32 = 10;
+/// Block distance within which the response applies darkness.
+const DARKNESS_RADIUS: f64 = 40.0;
+/// Duration of the darkness applied by a response.
+const DARKNESS_DURATION: i32 = 260;
+
+/// Resolves the player behind a vibration from its stored source UUIDs.
+fn try_get_player_by_uuid(world: &World, uuid: Uuid) -> Option<Arc<Player>> {
+    if let Some(player) = world.players.get_by_uuid(&uuid) {
+        return Some(player);
+    }
+    let entity = world.get_entity_by_uuid(&uuid)?;
+    SculkShriekerBlockEntity::try_get_player(world, entity.as_ref())
+}
+
+/// Vanilla `SculkShriekerBlockEntity.VibrationUser`.
+pub struct SculkShriekerVibrationUser {
+    pos: BlockPos,
+}
+
+impl VibrationUser for SculkShriekerVibrationUser {
+    fn listener_radius(&self) -> i32 {
+        LISTENER_RADIUS
+    }
+
+    fn listenable_events(&self) -> Identifier {
+        GameEventsTag::SHRIEKER_CAN_LISTEN
+    }
+
+    fn can_receive_vibration(
+        &self,
+        world: &World,
+        _pos: BlockPos,
+        _event: GameEventRef,
+        context: &GameEventContext<'_>,
+    ) -> bool {
+        !world
+            .get_block_state(self.pos)
+            .get_value(&BlockStateProperties::SHRIEKING)
+            && context
+                .source_entity()
+                .and_then(|entity| SculkShriekerBlockEntity::try_get_player(world, entity))
+                .is_some()
+    }
+}
+
+/// Vanilla `SculkShriekerBlockEntity`.
+pub struct SculkShriekerBlockEntity {
+    world: Weak<World>,
+    pos: BlockPos,
+    state: BlockStateId,
+    removed: bool,
+    listener: Arc<VibrationListener<SculkShriekerVibrationUser>>,
+    warning_level: i32,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `SculkShriekerBlockEntity`.
+unsafe impl DowncastType for SculkShriekerBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/sculk_shrieker");
+}
+
+impl SculkShriekerBlockEntity {
+    /// Creates an idle sculk shrieker block entity.
+    #[must_use]
+    pub fn new(world: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            world,
+            pos,
+            state,
+            removed: false,
+            listener: Arc::new(VibrationListener::new(
+                pos,
+                SculkShriekerVibrationUser { pos },
+            )),
+            warning_level: 0,
+        }
+    }
+
+    /// Returns the warning level reached by the last shriek.
+    #[must_use]
+    pub const fn warning_level(&self) -> i32 {
+        self.warning_level
+    }
+
+    /// Resolves the player responsible for `entity`: the entity itself, its
+    /// rider, or the owner of a projectile.
+    ///
+    /// Vanilla equivalent: `SculkShriekerBlockEntity.tryGetPlayer`.
+    pub fn try_get_player(world: &World, entity: &dyn Entity) -> Option<Arc<Player>> {
+        if entity.as_player().is_some() {
+            return world.players.get_by_uuid(&entity.uuid());
+        }
+        if let Some(passenger) = entity.controlling_passenger()
+            && passenger.as_player().is_some()
+        {
+            return world.players.get_by_uuid(&passenger.uuid());
+        }
+        // TODO: Also resolve the thrower of an item entity.
+        world.players.get_by_uuid(&entity.projectile_owner_uuid()?)
+    }
+
+    fn set_warning_level_at(world: &World, pos: BlockPos, warning_level: i32) {
+        let Some(block_entity) = world.get_block_entity(pos) else {
+            return;
+        };
+        let mut guard = block_entity.lock();
+        if let Some(shrieker) = guard.downcast_mut::<Self>() {
+            shrieker.warning_level = warning_level;
+            shrieker.set_changed();
+        }
+    }
+
+    fn warning_level_at(world: &World, pos: BlockPos) -> i32 {
+        world.get_block_entity(pos).map_or(0, |block_entity| {
+            block_entity
+                .lock()
+                .downcast_ref::<Self>()
+                .map_or(0, Self::warning_level)
+        })
+    }
+
+    /// Shrieks for `player`, first raising the warning level when the
+    /// shrieker can summon wardens.
+    ///
+    /// Vanilla equivalent: `SculkShriekerBlockEntity.tryShriek`.
+    pub fn try_shriek(world: &Arc<World>, pos: BlockPos, player: &Arc<Player>) {
+        let state = world.get_block_state(pos);
+        if state.get_value(&BlockStateProperties::SHRIEKING) {
+            return;
+        }
+        let warning_level = if Self::can_respond(world, state) {
+            let Some(warning_level) = WardenSpawnTracker::try_warn(world, pos, player) else {
+                return;
+            };
+            warning_level
+        } else {
+            0
+        };
+        Self::set_warning_level_at(world, pos, warning_level);
+        Self::shriek(world, pos, state, Some(player.as_ref()));
+    }
+
+    /// Vanilla equivalent: `SculkShriekerBlockEntity.shriek`.
+    fn shriek(world: &Arc<World>, pos: BlockPos, state: BlockStateId, entity: Option<&dyn Entity>) {
+        world.set_block(
+            pos,
+            state.set_value(&BlockStateProperties::SHRIEKING, true),
+            UpdateFlags::UPDATE_CLIENTS,
+        );
+        world.schedule_block_tick_default(pos, state.get_block(), SHRIEKING_TICKS);
+        world.level_event(level_events::PARTICLES_SCULK_SHRIEK, pos, 0, None);
+        world.game_event(
+            &vanilla_game_events::SHRIEK,
+            pos,
+            &GameEventContext::new(entity, None),
+        );
+    }
+
+    /// Vanilla equivalent: `SculkShriekerBlockEntity.canRespond`.
+    fn can_respond(world: &World, state: BlockStateId) -> bool {
+        state.get_value(&BlockStateProperties::CAN_SUMMON)
+            && world.difficulty() != Difficulty::Peaceful
+            && world.get_game_rule(&vanilla_game_rules::SPAWN_WARDENS)
+    }
+
+    /// Answers a finished shriek with a warden sound and darkness.
+    ///
+    /// Vanilla equivalent: `SculkShriekerBlockEntity.tryRespond`.
+    pub fn try_respond(world: &Arc<World>, pos: BlockPos) {
+        let warning_level = Self::warning_level_at(world, pos);
+        Self::respond(world, pos, world.get_block_state(pos), warning_level);
+    }
+
+    fn respond(world: &World, pos: BlockPos, state: BlockStateId, warning_level: i32) {
+        if !Self::can_respond(world, state) || warning_level <= 0 {
+            return;
+        }
+        if !Self::try_summon_warden(warning_level) {
+            Self::play_warden_reply_sound(world, pos, warning_level);
+        }
+        Self::apply_darkness_around(world, pos);
+    }
+
+    /// Vanilla equivalent: `SculkShriekerBlockEntity.trySummonWarden`.
+    const fn try_summon_warden(warning_level: i32) -> bool {
+        if warning_level < MAX_WARNING_LEVEL {
+            return false;
+        }
+        // TODO: Spawn a warden nearby once wardens exist (vanilla `SpawnUtil.trySpawnMob`).
+        false
+    }
+
+    /// Vanilla equivalent: `SculkShriekerBlockEntity.playWardenReplySound`.
+    fn play_warden_reply_sound(world: &World, pos: BlockPos, warning_level: i32) {
+        let sound: SoundEventRef = match warning_level {
+            1 => &sound_events::ENTITY_WARDEN_NEARBY_CLOSE,
+            2 => &sound_events::ENTITY_WARDEN_NEARBY_CLOSER,
+            3 => &sound_events::ENTITY_WARDEN_NEARBY_CLOSEST,
+            4 => &sound_events::ENTITY_WARDEN_LISTENING_ANGRY,
+            _ => return,
+        };
+        let offset = || {
+            f64::from(rand::random_range(
+                -WARNING_SOUND_RADIUS..=WARNING_SOUND_RADIUS,
+            ))
+        };
+        let sound_pos = DVec3::new(
+            f64::from(pos.x()) + offset(),
+            f64::from(pos.y()) + offset(),
+            f64::from(pos.z()) + offset(),
+        );
+        world.play_sound_at(sound, SoundSource::Hostile, sound_pos, 5.0, 1.0, None);
+    }
+
+    /// Gives darkness to survival and adventure players around `pos`.
+    ///
+    /// Vanilla equivalent: `Warden.applyDarknessAround`.
+    fn apply_darkness_around(world: &World, pos: BlockPos) {
+        let center = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 0.5,
+            f64::from(pos.z()) + 0.5,
+        );
+        world.players.iter_players(|_, player| {
+            let refresh = player
+                .mob_effect(vanilla_mob_effects::DARKNESS)
+                .is_none_or(|effect| {
+                    !effect.is_infinite_duration() && effect.duration() < DARKNESS_DURATION - 60
+                });
+            if matches!(player.game_mode(), GameType::Survival | GameType::Adventure)
+                && player.position().distance_squared(center) < DARKNESS_RADIUS * DARKNESS_RADIUS
+                && refresh
+            {
+                player.add_mob_effect(
+                    MobEffectInstance::with_duration(
+                        vanilla_mob_effects::DARKNESS,
+                        DARKNESS_DURATION,
+                        0,
+                    )
+                    .with_visible(false),
+                );
+            }
+            true
+        });
+    }
+}
+
+impl BlockEntity for SculkShriekerBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::SCULK_SHRIEKER
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.world.upgrade()
+    }
+
+    fn load_additional(&mut self, nbt: &BorrowedNbtCompound<'_>) {
+        let nbt: NbtCompoundView<'_, '_> = nbt.into();
+        self.warning_level = nbt.int("warning_level").unwrap_or(0);
+        if let Some(listener) = nbt.compound("listener") {
+            self.listener.load(listener);
+        }
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        nbt.insert("warning_level", self.warning_level);
+        nbt.insert("listener", NbtTag::Compound(self.listener.save()));
+    }
+
+    /// A shrieker broken mid-shriek still responds.
+    fn pre_remove_side_effects(&mut self, pos: BlockPos, state: BlockStateId) {
+        if state.get_value(&BlockStateProperties::SHRIEKING)
+            && let Some(world) = self.get_level()
+        {
+            Self::respond(&world, pos, state, self.warning_level);
+        }
+    }
+
+    fn is_ticking(&self) -> bool {
+        true
+    }
+
+    /// Vanilla equivalent: `VibrationSystem.Ticker.tick` with
+    /// `SculkShriekerBlockEntity.VibrationUser.onReceiveVibration`.
+    fn tick(&mut self, world: &Arc<World>) -> Option<BlockEntityTickAction> {
+        let vibration = self.listener.tick(world)?;
+        let pos = self.pos;
+        Some(BlockEntityTickAction::Callback(Box::new(move |world| {
+            let player = vibration
+                .projectile_owner
+                .or(vibration.source)
+                .and_then(|uuid| try_get_player_by_uuid(world, uuid));
+            if let Some(player) = player {
+                Self::try_shriek(world, pos, &player);
+            }
+        })))
+    }
+
+    fn game_event_listener(&self) -> Option<SharedGameEventListener> {
+        Some(self.listener.clone())
+    }
+}
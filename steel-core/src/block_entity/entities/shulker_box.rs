@@ -0,0 +1,291 @@
+//! Shulker box block entity implementation.
+//!
+//! Shulker boxes hold 27 slots and refuse other shulker boxes.
+
+use std::str::FromStr;
+use std::sync::{Arc, Weak};
+
+use simdnbt::ToNbtTag;
+use simdnbt::borrow::{BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView};
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::data_components::vanilla_components::{CONTAINER, ItemContainerContents};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::{sound_events, vanilla_block_entity_types};
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey, Identifier};
+
+use crate::block_entity::{BlockEntity, ContainerOpeners, ContainerOpenersCounter};
+use crate::entity::Entity;
+use crate::inventory::container::Container;
+use crate::player::Player;
+use crate::world::World;
+
+/// Number of slots in a shulker box (3 rows of 9).
+pub const SHULKER_BOX_SLOTS: usize = 27;
+
+/// Shulker box block entity.
+pub struct ShulkerBoxBlockEntity {
+    /// Weak reference to the world for marking chunks dirty.
+    level: Weak<World>,
+    /// Position in the world.
+    pos: BlockPos,
+    /// Current block state.
+    state: BlockStateId,
+    /// Whether this entity has been marked for removal.
+    removed: bool,
+    /// The 27 item slots.
+    items: Vec<ItemStack>,
+    /// Players currently viewing this shulker box.
+    openers: ContainerOpenersCounter,
+    /// Loot table placed by structure generation, kept until it is unpacked.
+    loot_table: Option<Identifier>,
+    /// Seed for `loot_table`, or 0 for a random seed.
+    loot_table_seed: i64,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `ShulkerBoxBlockEntity`.
+unsafe impl DowncastType for ShulkerBoxBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/shulker_box");
+}
+
+impl ShulkerBoxBlockEntity {
+    /// Creates a new shulker box block entity.
+    #[must_use]
+    pub fn new(level: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            level,
+            pos,
+            state,
+            removed: false,
+            items: vec![ItemStack::empty(); SHULKER_BOX_SLOTS],
+            openers: ContainerOpenersCounter::default(),
+            loot_table: None,
+            loot_table_seed: 0,
+        }
+    }
+
+    /// Returns whether any player has this shulker box open.
+    #[must_use]
+    pub const fn is_open(&self) -> bool {
+        self.openers.open_count() > 0
+    }
+
+    /// Returns the contents as the item component kept by a dropped shulker box.
+    #[must_use]
+    pub fn container_contents(&self) -> ItemContainerContents {
+        ItemContainerContents::from_items(&self.items)
+    }
+}
+
+/// Shulker box reactions to players opening and closing it.
+struct ShulkerBoxOpeners;
+
+impl ShulkerBoxOpeners {
+    fn play_sound(world: &World, pos: BlockPos, sound: SoundEventRef) {
+        let pitch = rand::random::<f32>() * 0.1 + 0.9;
+        world.play_sound(sound, SoundSource::Blocks, pos, 0.5, pitch, None);
+    }
+}
+
+impl ContainerOpeners for ShulkerBoxOpeners {
+    fn on_open(&self, world: &Arc<World>, pos: BlockPos, _state: BlockStateId) {
+        Self::play_sound(world, pos, &sound_events::BLOCK_SHULKER_BOX_OPEN);
+    }
+
+    fn on_close(&self, world: &Arc<World>, pos: BlockPos, _state: BlockStateId) {
+        Self::play_sound(world, pos, &sound_events::BLOCK_SHULKER_BOX_CLOSE);
+    }
+
+    /// Sends the lid animation block event, like Java's `ShulkerBoxBlockEntity.startOpen`.
+    fn opener_count_changed(
+        &self,
+        world: &Arc<World>,
+        pos: BlockPos,
+        state: BlockStateId,
+        _previous: i32,
+        current: i32,
+    ) {
+        let count = u8::try_from(current).unwrap_or(u8::MAX);
+        world.block_event(pos, state.get_block(), 1, count);
+    }
+}
+
+impl BlockEntity for ShulkerBoxBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::SHULKER_BOX
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.level.upgrade()
+    }
+
+    fn apply_implicit_components(&mut self, stack: &ItemStack) {
+        // TODO: Apply CUSTOM_NAME, LOCK and CONTAINER_LOOT as well
+        if let Some(contents) = stack.get(CONTAINER) {
+            contents.copy_into(&mut self.items);
+        }
+    }
+
+    fn load_additional(&mut self, nbt: &BorrowedNbtCompound<'_>) {
+        let nbt_view: NbtCompoundView<'_, '_> = nbt.into();
+
+        self.loot_table = nbt_view
+            .string("LootTable")
+            .and_then(|value| Identifier::from_str(&value.to_string()).ok());
+        self.loot_table_seed = nbt_view.long("LootTableSeed").unwrap_or(0);
+
+        if let Some(items_list) = nbt_view.list("Items")
+            && let Some(compounds) = items_list.compounds()
+        {
+            for compound in compounds {
+                if let Some(slot) = compound.byte("Slot") {
+                    let slot = slot as usize;
+                    if slot < SHULKER_BOX_SLOTS
+                        && let Some(item) = ItemStack::from_borrowed_compound(&compound)
+                    {
+                        self.items[slot] = item;
+                    }
+                }
+            }
+        }
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        if let Some(loot_table) = &self.loot_table {
+            nbt.insert("LootTable", loot_table.to_string());
+            if self.loot_table_seed != 0 {
+                nbt.insert("LootTableSeed", NbtTag::Long(self.loot_table_seed));
+            }
+        }
+
+        let mut items: Vec<NbtCompound> = Vec::new();
+        for (slot, item) in self.items.iter().enumerate() {
+            if !item.is_empty()
+                && let NbtTag::Compound(mut item_nbt) = item.clone().to_nbt_tag()
+            {
+                item_nbt.insert("Slot", slot as i8);
+                items.push(item_nbt);
+            }
+        }
+        nbt.insert("Items", NbtList::Compound(items));
+    }
+
+    fn get_update_tag(&self) -> Option<NbtCompound> {
+        None
+    }
+
+    fn as_container(&self) -> Option<&(dyn Container + 'static)> {
+        Some(self)
+    }
+
+    fn as_container_mut(&mut self) -> Option<&mut (dyn Container + 'static)> {
+        Some(self)
+    }
+}
+
+impl Container for ShulkerBoxBlockEntity {
+    fn get_container_size(&self) -> usize {
+        SHULKER_BOX_SLOTS
+    }
+
+    fn get_item(&self, slot: usize) -> &ItemStack {
+        &self.items[slot]
+    }
+
+    fn get_item_mut(&mut self, slot: usize) -> &mut ItemStack {
+        &mut self.items[slot]
+    }
+
+    fn set_item(&mut self, slot: usize, stack: ItemStack) {
+        if slot < SHULKER_BOX_SLOTS {
+            self.items[slot] = stack;
+            self.set_changed();
+        }
+    }
+
+    fn get_max_stack_size(&self) -> i32 {
+        64
+    }
+
+    fn still_valid(&self, player: &Player) -> bool {
+        if self.removed {
+            return false;
+        }
+
+        let Some(level) = self.level.upgrade() else {
+            return false;
+        };
+
+        level
+            .get_block_state(self.pos)
+            .get_block()
+            .has_tag(&BlockTag::SHULKER_BOXES)
+            && player.is_within_block_interaction_range_with_buffer(self.pos, 4.0)
+    }
+
+    fn can_place_item(&self, _slot: usize, stack: &ItemStack) -> bool {
+        stack.can_fit_inside_container_items()
+    }
+
+    fn set_changed(&mut self) {
+        BlockEntity::set_changed(self);
+    }
+
+    fn start_open(&mut self, player: &Player) {
+        if self.removed || player.is_spectator() {
+            return;
+        }
+        if let Some(world) = self.level.upgrade() {
+            self.openers.increment_openers(
+                &ShulkerBoxOpeners,
+                player,
+                &world,
+                self.pos,
+                self.state,
+            );
+        }
+    }
+
+    fn stop_open(&mut self, player: &Player) {
+        if self.removed || player.is_spectator() {
+            return;
+        }
+        if let Some(world) = self.level.upgrade() {
+            self.openers.decrement_openers(
+                &ShulkerBoxOpeners,
+                player,
+                &world,
+                self.pos,
+                self.state,
+            );
+        }
+    }
+}
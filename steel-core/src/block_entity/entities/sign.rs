@@ -32,6 +32,8 @@ pub const SIGN_LINES: usize = 4;
 pub struct SignText {
     /// The 4 lines of text (raw, unfiltered).
     pub messages: [TextComponent; SIGN_LINES],
+    /// The 4 lines as shown to players with text filtering enabled.
+    pub filtered_messages: [TextComponent; SIGN_LINES],
     /// Text color (dye color applied to the sign).
     pub color: DyeColor,
     /// Whether the text has a glowing effect (from glow ink sac).
@@ -50,24 +52,45 @@ impl SignText {
     pub fn new() -> Self {
         Self {
             messages: array::from_fn(|_| TextComponent::new()),
+            filtered_messages: array::from_fn(|_| TextComponent::new()),
             color: DyeColor::Black,
             has_glowing_text: false,
         }
     }
 
-    /// Gets a message line by index.
+    /// Gets a message line by index, the filtered one if `filtered` is set.
     #[must_use]
-    pub fn get_message(&self, index: usize) -> Option<&TextComponent> {
-        self.messages.get(index)
+    pub fn get_message(&self, index: usize, filtered: bool) -> Option<&TextComponent> {
+        if filtered {
+            self.filtered_messages.get(index)
+        } else {
+            self.messages.get(index)
+        }
     }
 
-    /// Sets a message line by index.
+    /// Sets a message line by index, unfiltered.
     pub fn set_message(&mut self, index: usize, message: TextComponent) {
+        self.set_filtered_message(index, message.clone(), message);
+    }
+
+    /// Sets a message line by index with a separate filtered version.
+    pub fn set_filtered_message(
+        &mut self,
+        index: usize,
+        message: TextComponent,
+        filtered: TextComponent,
+    ) {
         if index < SIGN_LINES {
             self.messages[index] = message;
+            self.filtered_messages[index] = filtered;
         }
     }
 
+    /// Whether any line has a filtered version that differs from the raw one.
+    fn has_filtered_messages(&self) -> bool {
+        self.messages != self.filtered_messages
+    }
+
     /// Checks if any line has text content.
     #[must_use]
     pub fn has_message(&self) -> bool {
@@ -82,18 +105,12 @@ impl SignText {
 
     /// Loads sign text from borrowed NBT.
     pub fn load(&mut self, nbt: BorrowedNbtCompoundView<'_, '_>) {
-        if let Some(messages) = nbt.list("messages") {
-            let tags = messages.to_owned().as_nbt_tags();
-            let messages = tags
-                .iter()
-                .map(TextComponent::from_nbt)
-                .collect::<Option<Vec<_>>>();
-            if let Some(messages) = messages
-                && let Ok(messages) = <[TextComponent; SIGN_LINES]>::try_from(messages)
-            {
-                self.messages = messages;
-            }
+        if let Some(messages) = load_messages(&nbt, "messages") {
+            self.messages = messages;
         }
+        // Vanilla only saves filtered lines that differ from the raw ones.
+        self.filtered_messages =
+            load_messages(&nbt, "filtered_messages").unwrap_or_else(|| self.messages.clone());
 
         // Load color
         if let Some(color_str) = nbt.string("color") {
@@ -109,15 +126,10 @@ impl SignText {
 
     /// Saves sign text to NBT.
     pub fn save(&self, nbt: &mut NbtCompound) {
-        nbt.insert(
-            "messages",
-            NbtList::from(
-                self.messages
-                    .iter()
-                    .map(TextComponent::to_codec_nbt)
-                    .collect::<Vec<_>>(),
-            ),
-        );
+        nbt.insert("messages", save_messages(&self.messages));
+        if self.has_filtered_messages() {
+            nbt.insert("filtered_messages", save_messages(&self.filtered_messages));
+        }
 
         // Save color
         nbt.insert("color", self.color.serialized_name());
@@ -127,6 +139,27 @@ impl SignText {
     }
 }
 
+fn load_messages(
+    nbt: &BorrowedNbtCompoundView<'_, '_>,
+    key: &str,
+) -> Option<[TextComponent; SIGN_LINES]> {
+    let tags = nbt.list(key)?.to_owned().as_nbt_tags();
+    let messages = tags
+        .iter()
+        .map(TextComponent::from_nbt)
+        .collect::<Option<Vec<_>>>()?;
+    messages.try_into().ok()
+}
+
+fn save_messages(messages: &[TextComponent; SIGN_LINES]) -> NbtList {
+    NbtList::from(
+        messages
+            .iter()
+            .map(TextComponent::to_codec_nbt)
+            .collect::<Vec<_>>(),
+    )
+}
+
 /// Sign block entity.
 ///
 /// Stores text on both front and back sides of the sign.
@@ -357,6 +390,7 @@ mod tests {
     fn plain_sign_lines_save_as_a_string_list() {
         let mut text = SignText::new();
         text.messages = array::from_fn(|index| TextComponent::plain(index.to_string()));
+        text.filtered_messages.clone_from(&text.messages);
 
         let mut nbt = NbtCompound::new();
         text.save(&mut nbt);
@@ -370,13 +404,19 @@ mod tests {
                 "3".into(),
             ])))
         );
+        assert_eq!(nbt.get("filtered_messages"), None);
     }
 
     #[test]
     fn mixed_sign_lines_round_trip_through_the_component_codec() {
         let mut expected = SignText::new();
-        expected.messages[0] = TextComponent::plain("plain");
-        expected.messages[1] = TextComponent::plain("styled").bold(true);
+        expected.set_message(0, TextComponent::plain("plain"));
+        expected.set_message(1, TextComponent::plain("styled").bold(true));
+        expected.set_filtered_message(
+            2,
+            TextComponent::plain("rude"),
+            TextComponent::plain("****"),
+        );
 
         let mut nbt = NbtCompound::new();
         expected.save(&mut nbt);
@@ -398,6 +438,11 @@ mod tests {
         decoded.load(compound);
 
         assert_eq!(decoded.messages, expected.messages);
+        assert_eq!(decoded.filtered_messages, expected.filtered_messages);
+        assert_eq!(
+            decoded.get_message(0, true),
+            Some(&TextComponent::plain("plain"))
+        );
         assert_eq!(decoded.color, expected.color);
         assert_eq!(decoded.has_glowing_text, expected.has_glowing_text);
     }
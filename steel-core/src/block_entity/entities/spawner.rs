@@ -0,0 +1,712 @@
+//! Monster spawner block entity.
+
+use std::io::Cursor;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use rand::RngExt as _;
+use simdnbt::borrow::{
+    BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView,
+    read_compound as read_borrowed_compound,
+};
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::vanilla_game_rules::SPAWNER_BLOCKS_WORK;
+use steel_registry::{
+    REGISTRY, RegistryExt as _, level_events, vanilla_block_entity_types, vanilla_blocks,
+    vanilla_game_events,
+};
+use steel_utils::types::Difficulty;
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey, Identifier, WorldAabb};
+
+use crate::behavior::BlockCollisionContext;
+use crate::block_entity::{BlockEntity, BlockEntityTickAction};
+use crate::chunk::light::LightLayer;
+use crate::entity::{ENTITIES, EntitySpawnReason, SharedEntity, next_entity_id, spawn_placement};
+use crate::physics::{CollisionWorld as _, WorldCollisionProvider};
+use crate::world::World;
+use crate::world::game_event_context::GameEventContext;
+
+const DEFAULT_SPAWN_DELAY: i32 = 20;
+const DEFAULT_MIN_SPAWN_DELAY: i32 = 200;
+const DEFAULT_MAX_SPAWN_DELAY: i32 = 800;
+const DEFAULT_SPAWN_COUNT: i32 = 4;
+const DEFAULT_MAX_NEARBY_ENTITIES: i32 = 6;
+const DEFAULT_REQUIRED_PLAYER_RANGE: i32 = 16;
+const DEFAULT_SPAWN_RANGE: i32 = 4;
+const EVENT_SET_MIN_DELAY: u8 = 1;
+const LIGHT_RANGE: InclusiveRange = InclusiveRange { min: 0, max: 15 };
+
+/// Vanilla `InclusiveRange<Integer>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct InclusiveRange {
+    min: i32,
+    max: i32,
+}
+
+impl InclusiveRange {
+    const fn is_value_in_range(self, value: i32) -> bool {
+        value >= self.min && value <= self.max
+    }
+
+    /// Reads vanilla's interval codec: a single value, `[min, max]`, or
+    /// `{min_inclusive, max_inclusive}`.
+    fn load(nbt: &NbtCompoundView<'_, '_>, key: &str) -> Option<Self> {
+        if let Some(value) = nbt.int(key) {
+            return Some(Self {
+                min: value,
+                max: value,
+            });
+        }
+        let (min, max) = if let Some(values) = nbt.int_array(key) {
+            (*values.first()?, *values.get(1)?)
+        } else if let Some(values) = nbt.list(key).and_then(|list| list.ints()) {
+            (*values.first()?, *values.get(1)?)
+        } else {
+            let range = nbt.compound(key)?;
+            (range.int("min_inclusive")?, range.int("max_inclusive")?)
+        };
+        (min <= max).then_some(Self { min, max })
+    }
+
+    fn save(self) -> NbtTag {
+        NbtTag::IntArray(vec![self.min, self.max])
+    }
+}
+
+/// Vanilla `SpawnData.CustomSpawnRules`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CustomSpawnRules {
+    block_light_limit: InclusiveRange,
+    sky_light_limit: InclusiveRange,
+}
+
+impl CustomSpawnRules {
+    fn load(nbt: &NbtCompoundView<'_, '_>) -> Self {
+        Self {
+            block_light_limit: InclusiveRange::load(nbt, "block_light_limit")
+                .unwrap_or(LIGHT_RANGE),
+            sky_light_limit: InclusiveRange::load(nbt, "sky_light_limit").unwrap_or(LIGHT_RANGE),
+        }
+    }
+
+    fn save(self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+        nbt.insert("block_light_limit", self.block_light_limit.save());
+        nbt.insert("sky_light_limit", self.sky_light_limit.save());
+        nbt
+    }
+
+    fn is_valid_position(self, world: &World, pos: BlockPos) -> bool {
+        self.block_light_limit
+            .is_value_in_range(i32::from(world.light_value_at(LightLayer::Block, pos)))
+            && self
+                .sky_light_limit
+                .is_value_in_range(i32::from(world.light_value_at(LightLayer::Sky, pos)))
+    }
+}
+
+/// Vanilla `SpawnData`: the entity NBT a spawner creates plus its placement rules.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct SpawnData {
+    entity: NbtCompound,
+    custom_spawn_rules: Option<CustomSpawnRules>,
+    /// Vanilla `EquipmentTable`, preserved but not applied yet.
+    equipment: Option<NbtTag>,
+}
+
+impl SpawnData {
+    /// Returns the entity type named by the `id` of the entity NBT.
+    fn entity_type(&self) -> Option<EntityTypeRef> {
+        let id = self.entity.string("id")?.to_str();
+        let key = id.parse::<Identifier>().ok()?;
+        REGISTRY.entity_types.by_key(&key)
+    }
+
+    fn load(nbt: &NbtCompoundView<'_, '_>) -> Self {
+        let entity = nbt
+            .compound("entity")
+            .map_or_else(NbtCompound::new, |entity| entity.to_owned());
+        let custom_spawn_rules = nbt
+            .compound("custom_spawn_rules")
+            .map(|rules| CustomSpawnRules::load(&rules));
+        let equipment = if let Some(equipment) = nbt.compound("equipment") {
+            Some(NbtTag::Compound(equipment.to_owned()))
+        } else {
+            nbt.string("equipment")
+                .map(|equipment| NbtTag::String(equipment.to_owned()))
+        };
+        Self {
+            entity,
+            custom_spawn_rules,
+            equipment,
+        }
+    }
+
+    fn save(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+        nbt.insert("entity", self.entity.clone());
+        if let Some(rules) = self.custom_spawn_rules {
+            nbt.insert("custom_spawn_rules", rules.save());
+        }
+        if let Some(equipment) = &self.equipment {
+            nbt.insert("equipment", equipment.clone());
+        }
+        nbt
+    }
+
+    /// Vanilla only finalizes spawns whose entity NBT is a bare `id`.
+    fn has_no_configuration(&self) -> bool {
+        self.entity.len() == 1 && self.entity.string("id").is_some()
+    }
+
+    fn position(&self) -> Option<DVec3> {
+        let pos = self.entity.list("Pos")?.doubles()?;
+        let [x, y, z] = pos else {
+            return None;
+        };
+        Some(DVec3::new(*x, *y, *z))
+    }
+}
+
+/// One weighted entry of vanilla's `SpawnPotentials` list.
+#[derive(Debug, Clone, PartialEq)]
+struct WeightedSpawnData {
+    data: SpawnData,
+    weight: i32,
+}
+
+/// Vanilla `SpawnerBlockEntity` together with its `BaseSpawner` logic.
+pub struct SpawnerBlockEntity {
+    world: Weak<World>,
+    pos: BlockPos,
+    state: BlockStateId,
+    removed: bool,
+    spawn_delay: i32,
+    spawn_potentials: Vec<WeightedSpawnData>,
+    next_spawn_data: Option<SpawnData>,
+    min_spawn_delay: i32,
+    max_spawn_delay: i32,
+    spawn_count: i32,
+    max_nearby_entities: i32,
+    required_player_range: i32,
+    spawn_range: i32,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `SpawnerBlockEntity`.
+unsafe impl DowncastType for SpawnerBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/mob_spawner");
+}
+
+impl SpawnerBlockEntity {
+    /// Creates a spawner block entity with vanilla default settings.
+    #[must_use]
+    pub const fn new(world: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            world,
+            pos,
+            state,
+            removed: false,
+            spawn_delay: DEFAULT_SPAWN_DELAY,
+            spawn_potentials: Vec::new(),
+            next_spawn_data: None,
+            min_spawn_delay: DEFAULT_MIN_SPAWN_DELAY,
+            max_spawn_delay: DEFAULT_MAX_SPAWN_DELAY,
+            spawn_count: DEFAULT_SPAWN_COUNT,
+            max_nearby_entities: DEFAULT_MAX_NEARBY_ENTITIES,
+            required_player_range: DEFAULT_REQUIRED_PLAYER_RANGE,
+            spawn_range: DEFAULT_SPAWN_RANGE,
+        }
+    }
+
+    /// Returns the entity type the spawner will create next.
+    #[must_use]
+    pub fn spawn_entity_type(&self) -> Option<EntityTypeRef> {
+        self.next_spawn_data.as_ref()?.entity_type()
+    }
+
+    /// Returns the remaining ticks until the next spawn attempt.
+    #[must_use]
+    pub const fn spawn_delay(&self) -> i32 {
+        self.spawn_delay
+    }
+
+    fn is_near_player(&self, world: &World) -> bool {
+        let center = DVec3::new(
+            f64::from(self.pos.x()) + 0.5,
+            f64::from(self.pos.y()) + 0.5,
+            f64::from(self.pos.z()) + 0.5,
+        );
+        world.has_nearby_alive_player(center, f64::from(self.required_player_range))
+    }
+
+    /// Mirrors vanilla `BaseSpawner.serverTick`.
+    fn server_tick(&mut self, world: &Arc<World>) {
+        if !self.is_near_player(world) || !world.get_game_rule(&SPAWNER_BLOCKS_WORK) {
+            return;
+        }
+
+        if self.spawn_delay == -1 {
+            self.delay();
+        }
+        if self.spawn_delay > 0 {
+            self.spawn_delay -= 1;
+            return;
+        }
+
+        let next_spawn_data = self.get_or_create_next_spawn_data().clone();
+        let mut delay = false;
+        for _ in 0..self.spawn_count {
+            let Some(entity_type) = next_spawn_data.entity_type() else {
+                self.delay();
+                return;
+            };
+
+            let spawn_pos = next_spawn_data.position().unwrap_or_else(|| {
+                let mut rng = rand::rng();
+                let spawn_range = f64::from(self.spawn_range);
+                DVec3::new(
+                    f64::from(self.pos.x())
+                        + (rng.random::<f64>() - rng.random::<f64>()) * spawn_range
+                        + 0.5,
+                    f64::from(self.pos.y() + rng.random_range(0..3) - 1),
+                    f64::from(self.pos.z())
+                        + (rng.random::<f64>() - rng.random::<f64>()) * spawn_range
+                        + 0.5,
+                )
+            });
+            if !Self::no_collision(world, entity_type, spawn_pos) {
+                continue;
+            }
+
+            let spawn_block_pos = BlockPos::containing(spawn_pos.x, spawn_pos.y, spawn_pos.z);
+            if let Some(rules) = next_spawn_data.custom_spawn_rules {
+                if !entity_type.mob_category.is_friendly()
+                    && world.difficulty() == Difficulty::Peaceful
+                {
+                    continue;
+                }
+                if !rules.is_valid_position(world, spawn_block_pos) {
+                    continue;
+                }
+            } else if !spawn_placement(entity_type).is_none_or(|placement| {
+                placement.check_spawn_rules(
+                    entity_type,
+                    world,
+                    EntitySpawnReason::Spawner,
+                    spawn_block_pos,
+                )
+            }) {
+                continue;
+            }
+
+            let Some(entity) = Self::load_entity(world, entity_type, &next_spawn_data, spawn_pos)
+            else {
+                self.delay();
+                return;
+            };
+
+            let nearby = self.nearby_entity_count(world, entity_type);
+            if nearby >= self.max_nearby_entities {
+                self.delay();
+                return;
+            }
+
+            entity.set_rotation((rand::random::<f32>() * 360.0, 0.0));
+            if let Some(mob) = entity.as_mob() {
+                if (next_spawn_data.custom_spawn_rules.is_none()
+                    && !mob.check_spawn_rules(world, EntitySpawnReason::Spawner))
+                    || !mob.check_spawn_obstruction(world)
+                {
+                    continue;
+                }
+
+                if next_spawn_data.has_no_configuration() {
+                    mob.finalize_spawn(world, EntitySpawnReason::Spawner, None);
+                }
+            }
+
+            if world.try_add_entity(Arc::clone(&entity)).is_err() {
+                self.delay();
+                return;
+            }
+
+            world.level_event(level_events::PARTICLES_MOBBLOCK_SPAWN, self.pos, 0, None);
+            world.game_event(
+                &vanilla_game_events::ENTITY_PLACE,
+                spawn_block_pos,
+                &GameEventContext::new(Some(entity.as_entity_event_source()), None),
+            );
+            if let Some(mob) = entity.as_mob() {
+                mob.spawn_anim();
+            }
+            delay = true;
+        }
+
+        if delay {
+            self.delay();
+        }
+    }
+
+    fn no_collision(world: &Arc<World>, entity_type: EntityTypeRef, spawn_pos: DVec3) -> bool {
+        let dimensions = entity_type.dimensions;
+        let spawn_box = WorldAabb::entity_box(
+            spawn_pos.x,
+            spawn_pos.y,
+            spawn_pos.z,
+            f64::from(dimensions.half_width()),
+            f64::from(dimensions.height),
+        );
+        let collision_world = WorldCollisionProvider::new(world);
+        !collision_world.has_entity_collision(&spawn_box)
+            && !collision_world
+                .has_block_collision_with_context(&spawn_box, BlockCollisionContext::empty())
+    }
+
+    /// Creates the spawned entity and applies any extra entity NBT.
+    ///
+    /// Vanilla loads the full entity tag; Steel applies the type-specific data
+    /// on top of a freshly created entity.
+    fn load_entity(
+        world: &Arc<World>,
+        entity_type: EntityTypeRef,
+        spawn_data: &SpawnData,
+        spawn_pos: DVec3,
+    ) -> Option<SharedEntity> {
+        let entity = ENTITIES.create(
+            entity_type,
+            next_entity_id(),
+            spawn_pos,
+            Arc::downgrade(world),
+        )?;
+        if !spawn_data.has_no_configuration() {
+            let mut bytes = Vec::new();
+            spawn_data.entity.write(&mut bytes);
+            let Ok(nbt) = read_borrowed_compound(&mut Cursor::new(bytes.as_slice())) else {
+                return Some(entity);
+            };
+            entity.load_additional((&nbt).into());
+            entity.sync_base_entity_data();
+        }
+        Some(entity)
+    }
+
+    /// Counts entities of the spawned type inside the spawn range, like vanilla's
+    /// exact-class `EntityTypeTest`.
+    fn nearby_entity_count(&self, world: &World, entity_type: EntityTypeRef) -> i32 {
+        let area = WorldAabb::new(
+            f64::from(self.pos.x()),
+            f64::from(self.pos.y()),
+            f64::from(self.pos.z()),
+            f64::from(self.pos.x() + 1),
+            f64::from(self.pos.y() + 1),
+            f64::from(self.pos.z() + 1),
+        )
+        .inflate(f64::from(self.spawn_range));
+        world
+            .get_entities_in_aabb(&area)
+            .iter()
+            .filter(|entity| entity.entity_type() == entity_type && !entity.is_spectator())
+            .count() as i32
+    }
+
+    /// Mirrors vanilla `BaseSpawner.delay`.
+    fn delay(&mut self) {
+        self.spawn_delay = if self.max_spawn_delay <= self.min_spawn_delay {
+            self.min_spawn_delay
+        } else {
+            self.min_spawn_delay
+                + rand::rng().random_range(0..self.max_spawn_delay - self.min_spawn_delay)
+        };
+
+        if let Some(data) = random_spawn_data(&self.spawn_potentials).cloned() {
+            self.set_next_spawn_data(data);
+        }
+        if let Some(world) = self.get_level() {
+            world.block_event(self.pos, &vanilla_blocks::SPAWNER, EVENT_SET_MIN_DELAY, 0);
+        }
+    }
+
+    fn get_or_create_next_spawn_data(&mut self) -> &SpawnData {
+        if self.next_spawn_data.is_none() {
+            let data = random_spawn_data(&self.spawn_potentials)
+                .cloned()
+                .unwrap_or_default();
+            self.set_next_spawn_data(data);
+        }
+        self.next_spawn_data.get_or_insert_default()
+    }
+
+    /// Mirrors vanilla `SpawnerBlockEntity`'s `setNextSpawnData`, which also
+    /// resends the displayed entity to clients.
+    fn set_next_spawn_data(&mut self, data: SpawnData) {
+        self.next_spawn_data = Some(data);
+        if let Some(world) = self.get_level()
+            && let Some(update) = self.get_update_tag()
+        {
+            world.broadcast_block_entity_update(self.pos, self.get_type(), update);
+        }
+    }
+
+    fn load_spawn_potentials(nbt: &NbtCompoundView<'_, '_>) -> Option<Vec<WeightedSpawnData>> {
+        let entries = nbt.list("SpawnPotentials")?.compounds()?;
+        Some(
+            entries
+                .into_iter()
+                .filter_map(|entry| {
+                    let weight = entry.int("weight")?;
+                    let data = entry.compound("data")?;
+                    (weight > 0).then(|| WeightedSpawnData {
+                        data: SpawnData::load(&data),
+                        weight,
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    fn save_spawn_potentials(&self) -> NbtList {
+        NbtList::Compound(
+            self.spawn_potentials
+                .iter()
+                .map(|entry| {
+                    let mut nbt = NbtCompound::new();
+                    nbt.insert("data", entry.data.save());
+                    nbt.insert("weight", entry.weight);
+                    nbt
+                })
+                .collect(),
+        )
+    }
+}
+
+impl BlockEntity for SpawnerBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::MOB_SPAWNER
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.world.upgrade()
+    }
+
+    fn load_additional(&mut self, nbt: &BorrowedNbtCompound<'_>) {
+        let nbt: NbtCompoundView<'_, '_> = nbt.into();
+        self.spawn_delay = nbt.short("Delay").map_or(DEFAULT_SPAWN_DELAY, i32::from);
+        self.next_spawn_data = nbt.compound("SpawnData").map(|data| SpawnData::load(&data));
+        self.spawn_potentials = Self::load_spawn_potentials(&nbt).unwrap_or_else(|| {
+            vec![WeightedSpawnData {
+                data: self.next_spawn_data.clone().unwrap_or_default(),
+                weight: 1,
+            }]
+        });
+        self.min_spawn_delay = load_int(&nbt, "MinSpawnDelay", DEFAULT_MIN_SPAWN_DELAY);
+        self.max_spawn_delay = load_int(&nbt, "MaxSpawnDelay", DEFAULT_MAX_SPAWN_DELAY);
+        self.spawn_count = load_int(&nbt, "SpawnCount", DEFAULT_SPAWN_COUNT);
+        self.max_nearby_entities = load_int(&nbt, "MaxNearbyEntities", DEFAULT_MAX_NEARBY_ENTITIES);
+        self.required_player_range =
+            load_int(&nbt, "RequiredPlayerRange", DEFAULT_REQUIRED_PLAYER_RANGE);
+        self.spawn_range = load_int(&nbt, "SpawnRange", DEFAULT_SPAWN_RANGE);
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        nbt.insert("Delay", self.spawn_delay as i16);
+        nbt.insert("MinSpawnDelay", self.min_spawn_delay as i16);
+        nbt.insert("MaxSpawnDelay", self.max_spawn_delay as i16);
+        nbt.insert("SpawnCount", self.spawn_count as i16);
+        nbt.insert("MaxNearbyEntities", self.max_nearby_entities as i16);
+        nbt.insert("RequiredPlayerRange", self.required_player_range as i16);
+        nbt.insert("SpawnRange", self.spawn_range as i16);
+        if let Some(data) = &self.next_spawn_data {
+            nbt.insert("SpawnData", data.save());
+        }
+        nbt.insert("SpawnPotentials", self.save_spawn_potentials());
+    }
+
+    fn get_update_tag(&self) -> Option<NbtCompound> {
+        let mut nbt = self.save_custom_only();
+        let _ = nbt.remove("SpawnPotentials");
+        Some(nbt)
+    }
+
+    fn is_ticking(&self) -> bool {
+        true
+    }
+
+    fn tick(&mut self, world: &Arc<World>) -> Option<BlockEntityTickAction> {
+        self.server_tick(world);
+        None
+    }
+}
+
+/// Reads an integer that vanilla writes as a short but accepts as any int.
+fn load_int(nbt: &NbtCompoundView<'_, '_>, key: &str, default: i32) -> i32 {
+    nbt.short(key)
+        .map(i32::from)
+        .or_else(|| nbt.int(key))
+        .unwrap_or(default)
+}
+
+/// Mirrors vanilla `WeightedList.getRandom`.
+fn random_spawn_data(potentials: &[WeightedSpawnData]) -> Option<&SpawnData> {
+    let total_weight: i32 = potentials.iter().map(|entry| entry.weight).sum();
+    if total_weight <= 0 {
+        return None;
+    }
+
+    let mut selection = rand::rng().random_range(0..total_weight);
+    potentials.iter().find_map(|entry| {
+        selection -= entry.weight;
+        (selection < 0).then_some(&entry.data)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::{test_support::init_test_registry, vanilla_entities};
+
+    use super::*;
+
+    fn load_from_owned_nbt(spawner: &mut SpawnerBlockEntity, nbt: &NbtCompound) {
+        let mut bytes = Vec::new();
+        nbt.write(&mut bytes);
+        let borrowed = read_borrowed_compound(&mut Cursor::new(bytes.as_slice()))
+            .expect("test nbt should reborrow");
+        spawner.load_additional(&borrowed);
+    }
+
+    fn spawner() -> SpawnerBlockEntity {
+        init_test_registry();
+        SpawnerBlockEntity::new(
+            Weak::new(),
+            BlockPos::new(3, 20, -7),
+            vanilla_blocks::SPAWNER.default_state(),
+        )
+    }
+
+    fn worldgen_spawner_nbt() -> NbtCompound {
+        let mut entity = NbtCompound::new();
+        entity.insert("id", "minecraft:zombie");
+        let mut spawn_data = NbtCompound::new();
+        spawn_data.insert("entity", NbtTag::Compound(entity));
+
+        let mut nbt = NbtCompound::new();
+        nbt.insert("Delay", 20_i16);
+        nbt.insert("MinSpawnDelay", 200_i16);
+        nbt.insert("MaxSpawnDelay", 800_i16);
+        nbt.insert("SpawnCount", 4_i16);
+        nbt.insert("MaxNearbyEntities", 6_i16);
+        nbt.insert("RequiredPlayerRange", 16_i16);
+        nbt.insert("SpawnRange", 4_i16);
+        nbt.insert("SpawnData", NbtTag::Compound(spawn_data));
+        nbt.insert(
+            "SpawnPotentials",
+            NbtTag::List(NbtList::Compound(Vec::new())),
+        );
+        nbt
+    }
+
+    #[test]
+    fn spawner_loads_vanilla_worldgen_nbt() {
+        let mut spawner = spawner();
+        load_from_owned_nbt(&mut spawner, &worldgen_spawner_nbt());
+
+        assert_eq!(spawner.spawn_delay(), 20);
+        assert_eq!(spawner.spawn_entity_type(), Some(&vanilla_entities::ZOMBIE));
+        assert!(spawner.spawn_potentials.is_empty());
+        assert_eq!(spawner.required_player_range, 16);
+    }
+
+    #[test]
+    fn spawner_defaults_spawn_potentials_to_spawn_data() {
+        let mut nbt = worldgen_spawner_nbt();
+        let _ = nbt.remove("SpawnPotentials");
+
+        let mut spawner = spawner();
+        load_from_owned_nbt(&mut spawner, &nbt);
+
+        assert_eq!(spawner.spawn_potentials.len(), 1);
+        assert_eq!(
+            spawner.spawn_potentials[0].data.entity_type(),
+            Some(&vanilla_entities::ZOMBIE)
+        );
+    }
+
+    #[test]
+    fn spawner_loads_weighted_potentials_and_custom_rules() {
+        let mut entity = NbtCompound::new();
+        entity.insert("id", "minecraft:skeleton");
+        let mut rules = NbtCompound::new();
+        rules.insert("block_light_limit", NbtTag::IntArray(vec![0, 7]));
+        rules.insert("sky_light_limit", 3_i32);
+        let mut data = NbtCompound::new();
+        data.insert("entity", NbtTag::Compound(entity));
+        data.insert("custom_spawn_rules", NbtTag::Compound(rules));
+        let mut potential = NbtCompound::new();
+        potential.insert("data", NbtTag::Compound(data));
+        potential.insert("weight", 5_i32);
+
+        let mut nbt = NbtCompound::new();
+        nbt.insert(
+            "SpawnPotentials",
+            NbtTag::List(NbtList::Compound(vec![potential])),
+        );
+        nbt.insert("SpawnRange", 8_i32);
+
+        let mut spawner = spawner();
+        load_from_owned_nbt(&mut spawner, &nbt);
+
+        assert_eq!(spawner.spawn_range, 8);
+        assert_eq!(spawner.spawn_potentials[0].weight, 5);
+        assert_eq!(
+            spawner.spawn_potentials[0].data.custom_spawn_rules,
+            Some(CustomSpawnRules {
+                block_light_limit: InclusiveRange { min: 0, max: 7 },
+                sky_light_limit: InclusiveRange { min: 3, max: 3 },
+            })
+        );
+        assert_eq!(
+            random_spawn_data(&spawner.spawn_potentials).and_then(SpawnData::entity_type),
+            Some(&vanilla_entities::SKELETON)
+        );
+    }
+
+    #[test]
+    fn spawner_update_tag_omits_spawn_potentials() {
+        let mut spawner = spawner();
+        load_from_owned_nbt(&mut spawner, &worldgen_spawner_nbt());
+
+        let update = spawner.get_update_tag().expect("spawner has an update tag");
+        let mut saved = NbtCompound::new();
+        spawner.save_additional(&mut saved);
+
+        assert!(update.list("SpawnPotentials").is_none());
+        assert!(update.compound("SpawnData").is_some());
+        assert_eq!(update.short("Delay"), Some(20));
+        assert!(saved.list("SpawnPotentials").is_some());
+    }
+}
@@ -0,0 +1,652 @@
+//! Structure block entity.
+
+use std::str::FromStr;
+use std::sync::{Arc, Weak};
+
+use glam::IVec3;
+use simdnbt::borrow::{BaseNbtCompound as BorrowedNbtCompound, NbtCompound as NbtCompoundView};
+use simdnbt::owned::NbtCompound;
+use steel_registry::block_entity_type::BlockEntityTypeRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::{BlockStateProperties, StructureMode};
+use steel_registry::{REGISTRY, vanilla_block_entity_types, vanilla_blocks};
+use steel_utils::types::UpdateFlags;
+use steel_utils::{
+    BlockPos, BlockStateId, BoundingBox, ChunkPos, Downcast as _, DowncastType, DowncastTypeKey,
+    Identifier, Mirror, Rotation,
+};
+
+use crate::block_entity::BlockEntity;
+use crate::world::World;
+use crate::worldgen::template::StructureTemplate;
+
+/// Largest structure block offset or size on any axis.
+pub const MAX_STRUCTURE_SIZE: i32 = 48;
+
+/// Horizontal distance in which "DETECT" looks for corner blocks.
+const SCAN_CORNER_BLOCKS_RANGE: i32 = 80;
+
+/// Vanilla `StructureBlockEntity`.
+pub struct StructureBlockEntity {
+    world: Weak<World>,
+    pos: BlockPos,
+    state: BlockStateId,
+    removed: bool,
+    structure_name: Option<Identifier>,
+    author: String,
+    metadata: String,
+    structure_pos: BlockPos,
+    structure_size: IVec3,
+    mirror: Mirror,
+    rotation: Rotation,
+    mode: StructureMode,
+    ignore_entities: bool,
+    strict: bool,
+    powered: bool,
+    show_air: bool,
+    show_bounding_box: bool,
+    integrity: f32,
+    seed: i64,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `StructureBlockEntity`.
+unsafe impl DowncastType for StructureBlockEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:block_entity/structure_block");
+}
+
+/// Vanilla `StructureMode.getSerializedName` in upper case, as stored in NBT.
+const fn mode_name(mode: &StructureMode) -> &'static str {
+    match mode {
+        StructureMode::Save => "SAVE",
+        StructureMode::Load => "LOAD",
+        StructureMode::Corner => "CORNER",
+        StructureMode::Data => "DATA",
+    }
+}
+
+fn mode_from_name(name: &str) -> Option<StructureMode> {
+    match name {
+        "SAVE" => Some(StructureMode::Save),
+        "LOAD" => Some(StructureMode::Load),
+        "CORNER" => Some(StructureMode::Corner),
+        "DATA" => Some(StructureMode::Data),
+        _ => None,
+    }
+}
+
+impl StructureBlockEntity {
+    /// Creates a structure block entity with vanilla default settings.
+    #[must_use]
+    pub fn new(world: Weak<World>, pos: BlockPos, state: BlockStateId) -> Self {
+        Self {
+            world,
+            pos,
+            state,
+            removed: false,
+            structure_name: None,
+            author: String::new(),
+            metadata: String::new(),
+            structure_pos: BlockPos::new(0, 1, 0),
+            structure_size: IVec3::ZERO,
+            mirror: Mirror::None,
+            rotation: Rotation::None,
+            mode: state.get_value(&BlockStateProperties::STRUCTUREBLOCK_MODE),
+            ignore_entities: true,
+            strict: false,
+            powered: false,
+            show_air: false,
+            show_bounding_box: true,
+            integrity: 1.0,
+            seed: 0,
+        }
+    }
+
+    /// Returns the structure template name, if it is a valid identifier.
+    #[must_use]
+    pub const fn structure_name(&self) -> Option<&Identifier> {
+        self.structure_name.as_ref()
+    }
+
+    /// Vanilla `StructureBlockEntity.setStructureName`: empty or invalid names clear it.
+    pub fn set_structure_name(&mut self, name: &str) {
+        self.structure_name = if name.is_empty() {
+            None
+        } else {
+            Identifier::from_str(name).ok()
+        };
+    }
+
+    /// Vanilla `StructureBlockEntity.createdBy`: records who placed the block.
+    pub fn created_by(&mut self, author: String) {
+        self.author = author;
+    }
+
+    /// Returns the current structure block mode.
+    #[must_use]
+    pub const fn mode(&self) -> &StructureMode {
+        &self.mode
+    }
+
+    /// Sets the mode. Callers keep the block state's `mode` property in sync.
+    pub const fn set_mode(&mut self, mode: StructureMode) {
+        self.mode = mode;
+    }
+
+    /// Returns whether the block was powered on the last neighbor update.
+    #[must_use]
+    pub const fn is_powered(&self) -> bool {
+        self.powered
+    }
+
+    /// Records the redstone power seen by the block.
+    pub const fn set_powered(&mut self, powered: bool) {
+        self.powered = powered;
+    }
+
+    /// Applies the settings sent from the structure block screen.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "mirrors the fields of vanilla's structure block screen packet"
+    )]
+    pub fn apply_settings(
+        &mut self,
+        offset: BlockPos,
+        size: BlockPos,
+        mirror: Mirror,
+        rotation: Rotation,
+        metadata: String,
+        integrity: f32,
+        seed: i64,
+        flags: StructureBlockFlags,
+    ) {
+        self.structure_pos = offset;
+        self.structure_size = size.0;
+        self.mirror = mirror;
+        self.rotation = rotation;
+        self.metadata = metadata;
+        self.ignore_entities = flags.ignore_entities;
+        self.strict = flags.strict;
+        self.show_air = flags.show_air;
+        self.show_bounding_box = flags.show_bounding_box;
+        self.integrity = integrity;
+        self.seed = seed;
+    }
+
+    /// Runs `f` on the structure block entity at `pos`, if there is one.
+    pub fn with_at<R>(world: &World, pos: BlockPos, f: impl FnOnce(&mut Self) -> R) -> Option<R> {
+        let block_entity = world.get_block_entity(pos)?;
+        let mut guard = block_entity.lock();
+        let structure = guard.downcast_mut::<Self>()?;
+        Some(f(structure))
+    }
+
+    /// Vanilla `StructureBlockEntity.saveStructure`: captures the configured area
+    /// as a template, writing it to disk when `keep` is set.
+    ///
+    /// The block entity at `pos` is only locked while its settings are read, so
+    /// the captured area may include the structure block itself.
+    pub fn save_structure(world: &Arc<World>, pos: BlockPos, keep: bool) -> bool {
+        let Some(Some((name, origin, size, include_entities))) =
+            Self::with_at(world, pos, |this| {
+                let name = this.structure_name.clone()?;
+                Some((
+                    name,
+                    pos.offset(
+                        this.structure_pos.x(),
+                        this.structure_pos.y(),
+                        this.structure_pos.z(),
+                    ),
+                    this.structure_size,
+                    !this.ignore_entities,
+                ))
+            })
+        else {
+            return false;
+        };
+        let Some(templates) = world.structure_templates() else {
+            return false;
+        };
+        let Some(template) = StructureTemplate::fill_from_world(
+            world,
+            &REGISTRY,
+            origin,
+            size,
+            include_entities,
+            &[&vanilla_blocks::STRUCTURE_VOID],
+        ) else {
+            return false;
+        };
+
+        if !keep {
+            templates.put(name, template);
+            return true;
+        }
+        match templates.save(&REGISTRY, &name, template) {
+            Ok(()) => true,
+            Err(error) => {
+                log::warn!("Failed to save structure {name}: {error}");
+                false
+            }
+        }
+    }
+
+    /// Vanilla `StructureBlockEntity.isStructureLoadable`.
+    #[must_use]
+    pub fn is_structure_loadable(world: &World, pos: BlockPos) -> bool {
+        let Some(Some(name)) = Self::with_at(world, pos, |this| {
+            (this.mode == StructureMode::Load)
+                .then(|| this.structure_name.clone())
+                .flatten()
+        }) else {
+            return false;
+        };
+        world
+            .structure_templates()
+            .is_some_and(|templates| templates.get(&REGISTRY, &name).is_some())
+    }
+
+    /// Vanilla `StructureBlockEntity.placeStructureIfSameSize`: places the template
+    /// when its size matches the configured size, otherwise only adopts its size so
+    /// the bounding box preview can be checked first.
+    pub fn place_structure_if_same_size(world: &Arc<World>, pos: BlockPos) -> bool {
+        let Some(Some((name, size))) = Self::with_at(world, pos, |this| {
+            (this.mode == StructureMode::Load)
+                .then(|| this.structure_name.clone())
+                .flatten()
+                .map(|name| (name, this.structure_size))
+        }) else {
+            return false;
+        };
+        let Some(template) = world
+            .structure_templates()
+            .and_then(|templates| templates.get(&REGISTRY, &name))
+        else {
+            return false;
+        };
+
+        if template.size(Rotation::None) == size {
+            Self::place_structure(world, pos, &template);
+            return true;
+        }
+        Self::load_structure_info(world, pos, &template);
+        false
+    }
+
+    /// Vanilla `StructureBlockEntity.loadStructureInfo`.
+    fn load_structure_info(world: &World, pos: BlockPos, template: &StructureTemplate) {
+        let updated = Self::with_at(world, pos, |this| {
+            this.structure_size = template.size(Rotation::None);
+            this.set_changed();
+        });
+        if updated.is_some() {
+            world.send_block_updated(pos);
+        }
+    }
+
+    /// Vanilla `StructureBlockEntity.placeStructure(level, template)`.
+    fn place_structure(world: &Arc<World>, pos: BlockPos, template: &StructureTemplate) {
+        Self::load_structure_info(world, pos, template);
+        let Some((origin, mirror, rotation, include_entities, seed)) =
+            Self::with_at(world, pos, |this| {
+                (
+                    pos.offset(
+                        this.structure_pos.x(),
+                        this.structure_pos.y(),
+                        this.structure_pos.z(),
+                    ),
+                    this.mirror,
+                    this.rotation,
+                    !this.ignore_entities,
+                    this.seed,
+                )
+            })
+        else {
+            return;
+        };
+        template.place_with_transform(
+            world,
+            &REGISTRY,
+            origin,
+            mirror,
+            rotation,
+            include_entities,
+            seed,
+        );
+    }
+
+    /// Vanilla `StructureBlockEntity.unloadStructure`: drops the cached template so
+    /// the next load reads it from disk again.
+    pub fn unload_structure(world: &World, pos: BlockPos) {
+        let Some(Some(name)) = Self::with_at(world, pos, |this| this.structure_name.clone()) else {
+            return;
+        };
+        if let Some(templates) = world.structure_templates() {
+            templates.remove(&name);
+        }
+    }
+
+    /// Vanilla `StructureBlockEntity.detectSize`: fits the saved area between the
+    /// corner blocks with the same name within 80 blocks horizontally.
+    pub fn detect_size(world: &World, pos: BlockPos) -> bool {
+        let Some(Some(name)) = Self::with_at(world, pos, |this| {
+            (this.mode == StructureMode::Save)
+                .then(|| this.structure_name.clone())
+                .flatten()
+        }) else {
+            return false;
+        };
+
+        let corners = Self::related_corners(world, pos, &name);
+        let bounds = match corners.as_slice() {
+            [] => return false,
+            [corner] => BoundingBox::from_corners(pos, *corner),
+            [first, rest @ ..] => rest.iter().fold(
+                BoundingBox::from_corners(*first, *first),
+                |bounds, &corner| {
+                    BoundingBox::encapsulating(&bounds, &BoundingBox::from_corners(corner, corner))
+                },
+            ),
+        };
+
+        let min = bounds.min_corner();
+        let delta = bounds.max_corner() - min;
+        if delta.x <= 1 || delta.y <= 1 || delta.z <= 1 {
+            return false;
+        }
+
+        let updated = Self::with_at(world, pos, |this| {
+            this.structure_pos = BlockPos(min - pos.0 + IVec3::ONE);
+            this.structure_size = delta - IVec3::ONE;
+            this.set_changed();
+        });
+        if updated.is_some() {
+            world.send_block_updated(pos);
+        }
+        updated.is_some()
+    }
+
+    /// Vanilla `StructureBlockEntity.getRelatedCorners`, reading loaded chunks'
+    /// block entities instead of scanning every block in range.
+    fn related_corners(world: &World, pos: BlockPos, name: &Identifier) -> Vec<BlockPos> {
+        let min = BlockPos::new(
+            pos.x() - SCAN_CORNER_BLOCKS_RANGE,
+            world.get_min_y(),
+            pos.z() - SCAN_CORNER_BLOCKS_RANGE,
+        );
+        let max = BlockPos::new(
+            pos.x() + SCAN_CORNER_BLOCKS_RANGE,
+            world.get_max_y(),
+            pos.z() + SCAN_CORNER_BLOCKS_RANGE,
+        );
+        let range = BoundingBox::from_corners(min, max);
+
+        let mut corners = Vec::new();
+        for chunk_x in (min.x() >> 4)..=(max.x() >> 4) {
+            for chunk_z in (min.z() >> 4)..=(max.z() >> 4) {
+                let Some(block_entities) = world
+                    .chunk_map
+                    .with_full_chunk(ChunkPos::new(chunk_x, chunk_z), |chunk| {
+                        chunk.get_block_entities()
+                    })
+                else {
+                    continue;
+                };
+                for block_entity in block_entities {
+                    let guard = block_entity.lock();
+                    let Some(corner) = guard.downcast_ref::<Self>() else {
+                        continue;
+                    };
+                    if corner.mode == StructureMode::Corner
+                        && corner.structure_name.as_ref() == Some(name)
+                        && range.contains_blockpos(corner.pos)
+                    {
+                        corners.push(corner.pos);
+                    }
+                }
+            }
+        }
+        corners
+    }
+
+    /// Syncs the block state's `mode` property with the stored mode.
+    ///
+    /// Vanilla `StructureBlockEntity.updateBlockState`.
+    pub fn update_block_state(world: &Arc<World>, pos: BlockPos) {
+        let Some(mode) = Self::with_at(world, pos, |this| this.mode.clone()) else {
+            return;
+        };
+        let state = world.get_block_state(pos);
+        if state.get_block() == &vanilla_blocks::STRUCTURE_BLOCK {
+            world.set_block(
+                pos,
+                state.set_value(&BlockStateProperties::STRUCTUREBLOCK_MODE, mode),
+                UpdateFlags::UPDATE_CLIENTS,
+            );
+        }
+    }
+
+    fn nbt_bool(value: bool) -> i8 {
+        i8::from(value)
+    }
+
+    fn read_bool(nbt: &NbtCompoundView<'_, '_>, key: &str, default: bool) -> bool {
+        nbt.byte(key).map_or(default, |value| value != 0)
+    }
+
+    fn read_string(nbt: &NbtCompoundView<'_, '_>, key: &str) -> String {
+        nbt.string(key)
+            .map(|value| value.to_str().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+/// Boolean toggles of the structure block screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StructureBlockFlags {
+    /// Whether entities are left out when saving or loading.
+    pub ignore_entities: bool,
+    /// Whether loaded block states are placed without shape updates.
+    pub strict: bool,
+    /// Whether air blocks are rendered in the bounding box preview.
+    pub show_air: bool,
+    /// Whether the bounding box preview is rendered.
+    pub show_bounding_box: bool,
+}
+
+impl BlockEntity for StructureBlockEntity {
+    fn get_type(&self) -> BlockEntityTypeRef {
+        &vanilla_block_entity_types::STRUCTURE_BLOCK
+    }
+
+    fn get_block_pos(&self) -> BlockPos {
+        self.pos
+    }
+
+    fn get_block_state(&self) -> BlockStateId {
+        self.state
+    }
+
+    fn set_block_state(&mut self, state: BlockStateId) {
+        self.state = state;
+    }
+
+    fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn set_removed(&mut self) {
+        self.removed = true;
+    }
+
+    fn clear_removed(&mut self) {
+        self.removed = false;
+    }
+
+    fn get_level(&self) -> Option<Arc<World>> {
+        self.world.upgrade()
+    }
+
+    fn load_additional(&mut self, nbt: &BorrowedNbtCompound<'_>) {
+        let nbt: NbtCompoundView<'_, '_> = nbt.into();
+        self.set_structure_name(&Self::read_string(&nbt, "name"));
+        self.author = Self::read_string(&nbt, "author");
+        self.metadata = Self::read_string(&nbt, "metadata");
+
+        let int = |key: &str, min: i32| nbt.int(key).unwrap_or(0).clamp(min, MAX_STRUCTURE_SIZE);
+        self.structure_pos = BlockPos::new(
+            int("posX", -MAX_STRUCTURE_SIZE),
+            int("posY", -MAX_STRUCTURE_SIZE),
+            int("posZ", -MAX_STRUCTURE_SIZE),
+        );
+        self.structure_size = IVec3::new(int("sizeX", 0), int("sizeY", 0), int("sizeZ", 0));
+
+        self.rotation = nbt
+            .string("rotation")
+            .and_then(|name| Rotation::from_name(&name.to_str()))
+            .unwrap_or(Rotation::None);
+        self.mirror = nbt
+            .string("mirror")
+            .and_then(|name| Mirror::from_name(&name.to_str()))
+            .unwrap_or(Mirror::None);
+        self.mode = nbt
+            .string("mode")
+            .and_then(|name| mode_from_name(&name.to_str()))
+            .unwrap_or(StructureMode::Data);
+        self.ignore_entities = Self::read_bool(&nbt, "ignoreEntities", true);
+        self.strict = Self::read_bool(&nbt, "strict", false);
+        self.powered = Self::read_bool(&nbt, "powered", false);
+        self.show_air = Self::read_bool(&nbt, "showair", false);
+        self.show_bounding_box = Self::read_bool(&nbt, "showboundingbox", true);
+        self.integrity = nbt.float("integrity").unwrap_or(1.0);
+        self.seed = nbt.long("seed").unwrap_or(0);
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        nbt.insert(
+            "name",
+            self.structure_name
+                .as_ref()
+                .map_or_else(String::new, ToString::to_string),
+        );
+        nbt.insert("author", self.author.clone());
+        nbt.insert("metadata", self.metadata.clone());
+        nbt.insert("posX", self.structure_pos.x());
+        nbt.insert("posY", self.structure_pos.y());
+        nbt.insert("posZ", self.structure_pos.z());
+        nbt.insert("sizeX", self.structure_size.x);
+        nbt.insert("sizeY", self.structure_size.y);
+        nbt.insert("sizeZ", self.structure_size.z);
+        nbt.insert("rotation", self.rotation.name());
+        nbt.insert("mirror", self.mirror.name());
+        nbt.insert("mode", mode_name(&self.mode));
+        nbt.insert("ignoreEntities", Self::nbt_bool(self.ignore_entities));
+        nbt.insert("strict", Self::nbt_bool(self.strict));
+        nbt.insert("powered", Self::nbt_bool(self.powered));
+        nbt.insert("showair", Self::nbt_bool(self.show_air));
+        nbt.insert("showboundingbox", Self::nbt_bool(self.show_bounding_box));
+        nbt.insert("integrity", self.integrity);
+        nbt.insert("seed", self.seed);
+    }
+
+    fn get_update_tag(&self) -> Option<NbtCompound> {
+        Some(self.save_custom_only())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use simdnbt::borrow::read_compound as read_borrowed_compound;
+    use steel_registry::test_support::init_test_registry;
+
+    use super::*;
+
+    fn structure_block() -> StructureBlockEntity {
+        init_test_registry();
+        StructureBlockEntity::new(
+            Weak::new(),
+            BlockPos::new(0, 64, 0),
+            vanilla_blocks::STRUCTURE_BLOCK.default_state(),
+        )
+    }
+
+    fn load_from_owned_nbt(structure: &mut StructureBlockEntity, nbt: &NbtCompound) {
+        let mut bytes = Vec::new();
+        nbt.write(&mut bytes);
+        let borrowed = read_borrowed_compound(&mut Cursor::new(bytes.as_slice()))
+            .expect("test nbt should reborrow");
+        structure.load_additional(&borrowed);
+    }
+
+    #[test]
+    fn structure_block_saves_vanilla_nbt_keys() {
+        let mut structure = structure_block();
+        structure.set_structure_name("steel:house");
+        structure.set_mode(StructureMode::Save);
+        structure.apply_settings(
+            BlockPos::new(1, 2, 3),
+            BlockPos::new(4, 5, 6),
+            Mirror::LeftRight,
+            Rotation::Clockwise90,
+            "chest".to_owned(),
+            0.5,
+            7,
+            StructureBlockFlags {
+                ignore_entities: false,
+                strict: true,
+                show_air: true,
+                show_bounding_box: false,
+            },
+        );
+
+        let mut nbt = NbtCompound::new();
+        structure.save_additional(&mut nbt);
+
+        assert_eq!(
+            nbt.string("name").map(ToString::to_string),
+            Some("steel:house".to_owned())
+        );
+        assert_eq!(nbt.int("posY"), Some(2));
+        assert_eq!(nbt.int("sizeZ"), Some(6));
+        assert_eq!(
+            nbt.string("rotation").map(ToString::to_string),
+            Some("CLOCKWISE_90".to_owned())
+        );
+        assert_eq!(
+            nbt.string("mirror").map(ToString::to_string),
+            Some("LEFT_RIGHT".to_owned())
+        );
+        assert_eq!(
+            nbt.string("mode").map(ToString::to_string),
+            Some("SAVE".to_owned())
+        );
+        assert_eq!(nbt.byte("ignoreEntities"), Some(0));
+        assert_eq!(nbt.byte("strict"), Some(1));
+        assert_eq!(nbt.byte("showboundingbox"), Some(0));
+        assert_eq!(nbt.long("seed"), Some(7));
+    }
+
+    #[test]
+    fn structure_block_loads_with_vanilla_defaults_and_clamps() {
+        let mut nbt = NbtCompound::new();
+        nbt.insert("name", "Not Valid");
+        nbt.insert("posX", 100_i32);
+        nbt.insert("sizeY", -4_i32);
+        nbt.insert("mode", "CORNER");
+
+        let mut structure = structure_block();
+        load_from_owned_nbt(&mut structure, &nbt);
+
+        assert_eq!(structure.structure_name(), None);
+        assert_eq!(
+            structure.structure_pos,
+            BlockPos::new(MAX_STRUCTURE_SIZE, 0, 0)
+        );
+        assert_eq!(structure.structure_size, IVec3::ZERO);
+        assert_eq!(structure.mode(), &StructureMode::Corner);
+        assert!(structure.ignore_entities);
+        assert!(structure.show_bounding_box);
+        assert!((structure.integrity - 1.0).abs() < f32::EPSILON);
+    }
+}
@@ -23,6 +23,7 @@
 //! let entity = BLOCK_ENTITIES.create(block_entity_type, pos, state);
 //! ```
 
+mod container_openers_counter;
 pub mod entities;
 mod registry;
 mod storage;
@@ -33,14 +34,18 @@ use simdnbt::borrow::BaseNbtCompound as BorrowedNbtCompound;
 use simdnbt::owned::NbtCompound;
 use steel_registry::block_entity_type::BlockEntityTypeRef;
 use steel_registry::game_events::GameEventRef;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::loot_table::BlockEntityRef;
 use steel_utils::{BlockPos, BlockStateId, ErasedType, locks::SyncMutex, types::UpdateFlags};
 
+pub use container_openers_counter::{ContainerOpeners, ContainerOpenersCounter};
 pub use registry::{BLOCK_ENTITIES, BlockEntityFactory, BlockEntityRegistry, init_block_entities};
 pub use storage::BlockEntityStorage;
 
 use crate::inventory::container::Container;
 
 use crate::world::World;
+use crate::world::game_event_listener::SharedGameEventListener;
 
 /// World mutations requested by a block entity tick
 ///
@@ -59,6 +64,8 @@ pub enum BlockEntityTickAction {
         /// Optional game event dispatched after the block update.
         game_event: Option<(GameEventRef, BlockStateId)>,
     },
+    /// Runs a world callback, such as a sculk sensor reacting to a vibration.
+    Callback(Box<dyn FnOnce(&Arc<World>) + Send>),
 }
 
 /// Trait for all block entities.
@@ -124,6 +131,17 @@ pub trait BlockEntity: ErasedType + Send + Sync {
         // Default: no side effects
     }
 
+    /// Applies the components of the item stack this block was placed from.
+    ///
+    /// Based on Java's `BlockEntity.applyImplicitComponents`.
+    #[expect(
+        unused_variables,
+        reason = "default trait impl; parameter used by overrides"
+    )]
+    fn apply_implicit_components(&mut self, stack: &ItemStack) {
+        // Default: no components
+    }
+
     /// Loads additional data from NBT.
     ///
     /// Called when loading the block entity from disk or receiving initial
@@ -183,6 +201,14 @@ pub trait BlockEntity: ErasedType + Send + Sync {
         None
     }
 
+    /// Returns the game event listener this block entity provides, if any.
+    ///
+    /// The chunk registers it while the block entity is loaded. Vanilla
+    /// equivalent: `GameEventListener.Provider`.
+    fn game_event_listener(&self) -> Option<SharedGameEventListener> {
+        None
+    }
+
     /// Returns this block entity as a container, if it implements Container.
     ///
     /// Override this in block entities that are also containers (e.g., chests,
@@ -202,3 +228,37 @@ pub trait BlockEntity: ErasedType + Send + Sync {
 
 /// Type alias for a shared, thread-safe block entity.
 pub type SharedBlockEntity = Arc<SyncMutex<dyn BlockEntity>>;
+
+/// A copy of the block entity data that block loot tables can read.
+///
+/// Taken before the block is removed, since removal may clear the block entity.
+pub struct BlockEntityLootData {
+    block_entity_type: BlockEntityTypeRef,
+    items: Option<Vec<ItemStack>>,
+}
+
+impl BlockEntityLootData {
+    /// Captures the type and container contents of `block_entity`.
+    #[must_use]
+    pub fn capture(block_entity: &dyn BlockEntity) -> Self {
+        let items = block_entity.as_container().map(|container| {
+            (0..container.get_container_size())
+                .map(|slot| container.get_item(slot).clone())
+                .collect()
+        });
+        Self {
+            block_entity_type: block_entity.get_type(),
+            items,
+        }
+    }
+
+    /// Returns the loot context view of this data.
+    #[must_use]
+    pub fn as_loot_ref(&self) -> BlockEntityRef<'_> {
+        BlockEntityRef {
+            block_entity_type: Some(&self.block_entity_type.key),
+            custom_name: None,
+            inventory: self.items.as_deref(),
+        }
+    }
+}
@@ -17,8 +17,11 @@ use steel_utils::{BlockPos, BlockStateId};
 
 use super::SharedBlockEntity;
 use super::entities::{
-    BarrelBlockEntity, BeehiveBlockEntity, EndGatewayBlockEntity, EndPortalBlockEntity,
-    PotentSulfurBlockEntity, RawBlockEntity, SignBlockEntity,
+    BarrelBlockEntity, BeaconBlockEntity, BeehiveBlockEntity, ChestBlockEntity, CommandBlockEntity,
+    ConduitBlockEntity, EndGatewayBlockEntity, EndPortalBlockEntity, EnderChestBlockEntity,
+    HopperBlockEntity, JigsawBlockEntity, PotentSulfurBlockEntity, RawBlockEntity,
+    SculkSensorBlockEntity, SculkShriekerBlockEntity, ShulkerBoxBlockEntity, SignBlockEntity,
+    SpawnerBlockEntity, StructureBlockEntity,
 };
 use crate::world::World;
 
@@ -247,6 +250,78 @@ pub fn init_block_entities() {
         Arc::new(SyncMutex::new(BarrelBlockEntity::new(level, pos, state)))
     });
 
+    // Register chest block entity factory
+    registry.register(&vanilla_block_entity_types::CHEST, |level, pos, state| {
+        Arc::new(SyncMutex::new(ChestBlockEntity::new(level, pos, state)))
+    });
+
+    // Register ender chest block entity factory
+    registry.register(
+        &vanilla_block_entity_types::ENDER_CHEST,
+        |level, pos, state| {
+            Arc::new(SyncMutex::new(EnderChestBlockEntity::new(
+                level, pos, state,
+            )))
+        },
+    );
+
+    // Register shulker box block entity factory
+    registry.register(
+        &vanilla_block_entity_types::SHULKER_BOX,
+        |level, pos, state| {
+            Arc::new(SyncMutex::new(ShulkerBoxBlockEntity::new(
+                level, pos, state,
+            )))
+        },
+    );
+
+    // Register beacon block entity factory
+    registry.register(&vanilla_block_entity_types::BEACON, |level, pos, state| {
+        Arc::new(SyncMutex::new(BeaconBlockEntity::new(level, pos, state)))
+    });
+
+    // Register structure block entity factory
+    registry.register(
+        &vanilla_block_entity_types::STRUCTURE_BLOCK,
+        |level, pos, state| Arc::new(SyncMutex::new(StructureBlockEntity::new(level, pos, state))),
+    );
+
+    // Register jigsaw block entity factory
+    registry.register(&vanilla_block_entity_types::JIGSAW, |level, pos, state| {
+        Arc::new(SyncMutex::new(JigsawBlockEntity::new(level, pos, state)))
+    });
+
+    // Register command block entity factory
+    registry.register(
+        &vanilla_block_entity_types::COMMAND_BLOCK,
+        |level, pos, state| Arc::new(SyncMutex::new(CommandBlockEntity::new(level, pos, state))),
+    );
+
+    // Register conduit block entity factory
+    registry.register(&vanilla_block_entity_types::CONDUIT, |level, pos, state| {
+        Arc::new(SyncMutex::new(ConduitBlockEntity::new(level, pos, state)))
+    });
+
+    // Register sculk sensor block entity factory
+    registry.register(
+        &vanilla_block_entity_types::SCULK_SENSOR,
+        |level, pos, state| {
+            Arc::new(SyncMutex::new(SculkSensorBlockEntity::new(
+                level, pos, state,
+            )))
+        },
+    );
+
+    // Register sculk shrieker block entity factory
+    registry.register(
+        &vanilla_block_entity_types::SCULK_SHRIEKER,
+        |level, pos, state| {
+            Arc::new(SyncMutex::new(SculkShriekerBlockEntity::new(
+                level, pos, state,
+            )))
+        },
+    );
+
     // Register beehive block entity factory
     registry.register(&vanilla_block_entity_types::BEEHIVE, |level, pos, state| {
         Arc::new(SyncMutex::new(BeehiveBlockEntity::new(level, pos, state)))
@@ -268,6 +343,11 @@ pub fn init_block_entities() {
         |level, pos, state| Arc::new(SyncMutex::new(EndPortalBlockEntity::new(level, pos, state))),
     );
 
+    // Register hopper block entity factory
+    registry.register(&vanilla_block_entity_types::HOPPER, |level, pos, state| {
+        Arc::new(SyncMutex::new(HopperBlockEntity::new(level, pos, state)))
+    });
+
     // Register potent sulfur block entity factory
     registry.register(
         &vanilla_block_entity_types::POTENT_SULFUR,
@@ -278,6 +358,12 @@ pub fn init_block_entities() {
         },
     );
 
+    // Register monster spawner block entity factory
+    registry.register(
+        &vanilla_block_entity_types::MOB_SPAWNER,
+        |level, pos, state| Arc::new(SyncMutex::new(SpawnerBlockEntity::new(level, pos, state))),
+    );
+
     assert!(
         BLOCK_ENTITIES.set(registry).is_ok(),
         "Block entity registry already initialized"
@@ -6,6 +6,7 @@ use std::fmt::Debug;
 use std::mem;
 use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Weak};
+use steel_protocol::packet_traits::{CompressionInfo, EncodedPacket};
 use steel_utils::locks::SyncRwLock;
 use steel_utils::{BlockPos, ChunkPos, PackedSectionBlockPos, SectionPos, locks::SyncMutex};
 use tokio::sync::{oneshot, watch};
@@ -143,6 +144,8 @@ pub struct ChunkHolder {
     queued_for_broadcast: AtomicBool,
     /// Monotonic revision for client-visible chunk packet content.
     packet_content_revision: AtomicU64,
+    /// The last encoded chunk packet, shared by every player loading this chunk.
+    cached_packet: SyncMutex<Option<CachedChunkPacket>>,
     /// Per-section sets of changed block positions.
     /// Index is `(block_y - min_y) / 16`.
     changed_blocks_per_section: Box<[SyncMutex<FxHashSet<PackedSectionBlockPos>>]>,
@@ -150,6 +153,13 @@ pub struct ChunkHolder {
     changed_light_sections: SyncMutex<ChangedLightSectionSets>,
 }
 
+/// A fully encoded and compressed chunk packet for one content revision.
+struct CachedChunkPacket {
+    revision: u64,
+    compression: Option<CompressionInfo>,
+    packet: EncodedPacket,
+}
+
 struct StatusWorkClaim {
     holder: Arc<ChunkHolder>,
     status: ChunkStatus,
@@ -230,6 +240,7 @@ impl ChunkHolder {
             has_changed_sections: AtomicBool::new(false),
             queued_for_broadcast: AtomicBool::new(false),
             packet_content_revision: AtomicU64::new(0),
+            cached_packet: SyncMutex::new(None),
             changed_blocks_per_section,
             changed_light_sections: SyncMutex::new(ChangedLightSectionSets::default()),
         }
@@ -409,6 +420,42 @@ impl ChunkHolder {
         self.packet_content_revision.load(Ordering::Acquire)
     }
 
+    /// Returns the cached chunk packet if it was encoded from `revision` with `compression`.
+    pub fn cached_packet(
+        &self,
+        revision: u64,
+        compression: Option<CompressionInfo>,
+    ) -> Option<EncodedPacket> {
+        self.cached_packet
+            .lock()
+            .as_ref()
+            .filter(|cached| cached.revision == revision && cached.compression == compression)
+            .map(|cached| cached.packet.clone())
+    }
+
+    /// Caches `packet` as the chunk packet encoded from `revision` with `compression`.
+    ///
+    /// A packet older than the one already cached is ignored.
+    pub fn cache_packet(
+        &self,
+        revision: u64,
+        compression: Option<CompressionInfo>,
+        packet: EncodedPacket,
+    ) {
+        let mut cached = self.cached_packet.lock();
+        if cached
+            .as_ref()
+            .is_some_and(|cached| cached.revision > revision)
+        {
+            return;
+        }
+        *cached = Some(CachedChunkPacket {
+            revision,
+            compression,
+            packet,
+        });
+    }
+
     /// Returns the number of sections in this chunk.
     pub fn section_count(&self) -> usize {
         self.changed_blocks_per_section.len()
@@ -1067,6 +1114,7 @@ impl ChunkHolder {
                         pending_entities,
                     } = LevelChunk::from_proto(proto, min_y, height, level);
                     let pos = full.pos;
+                    full.register_game_event_listeners();
                     *chunk = ChunkAccess::Full(full);
                     Some((pos, pending_entities))
                 }
@@ -1203,6 +1251,8 @@ mod tests {
     use crate::behavior::init_behaviors;
     use crate::chunk::proto_chunk::ProtoChunk;
     use crate::chunk::section::{ChunkSection, Sections};
+    use steel_protocol::packets::game::CChunkBatchStart;
+    use steel_protocol::utils::ConnectionProtocol;
     use steel_registry::test_support::init_test_registry;
 
     fn init_chunk_test_registry() {
@@ -1346,4 +1396,27 @@ mod tests {
         drop(second);
         assert!(holder.is_ready_for_saving());
     }
+
+    #[test]
+    fn cached_packet_is_invalidated_by_content_and_compression_changes() {
+        let holder = test_holder();
+        let packet = EncodedPacket::from_bare(CChunkBatchStart {}, None, ConnectionProtocol::Play)
+            .expect("packet should encode");
+        let revision = holder.packet_content_revision();
+        holder.cache_packet(revision, None, packet);
+
+        assert!(holder.cached_packet(revision, None).is_some());
+        assert!(
+            holder
+                .cached_packet(revision, Some(CompressionInfo::default()))
+                .is_none()
+        );
+
+        holder.mark_packet_content_changed();
+        assert!(
+            holder
+                .cached_packet(holder.packet_content_revision(), None)
+                .is_none()
+        );
+    }
 }
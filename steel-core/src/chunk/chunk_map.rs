@@ -27,9 +27,9 @@ use crate::behavior::BlockStateBehaviorExt;
 use crate::behavior::{BLOCK_BEHAVIORS, FLUID_BEHAVIORS};
 use crate::chunk::chunk_holder::{ChunkHolder, ChunkSaveDependency};
 use crate::chunk::chunk_ticket_manager::{
-    ChunkTicket, ChunkTicketLevel, ChunkTicketManager, ENDER_PEARL_TICKET_TIMEOUT_TICKS,
-    LevelChange, PersistentChunkTickets, TimedChunkTickets, generation_status, is_block_ticking,
-    is_entity_ticking,
+    ChunkTicket, ChunkTicketLevel, ChunkTicketManager, ChunkTicketType,
+    ENDER_PEARL_TICKET_TIMEOUT_TICKS, LevelChange, PersistentChunkTickets, TimedChunkTickets,
+    generation_status, is_block_ticking, is_entity_ticking,
 };
 use crate::chunk::light::{
     LIGHT_CACHE_RADIUS, LightCacheLayout, LightCacheSetupRadius, LightLayer,
@@ -39,11 +39,12 @@ use crate::chunk::light::{
     propagate_sky_light_changes_with_empty_sections,
 };
 use crate::chunk::player_chunk_view::PlayerChunkView;
+use crate::chunk::tick_region::{partition_regions, tick_in_region};
 use crate::chunk::{
     chunk_access::{ChunkAccess, ChunkStatus},
     chunk_generation_task::ChunkGenerationTask,
 };
-use crate::chunk_saver::ChunkStorage;
+use crate::chunk_saver::{ChunkSaveQueue, ChunkSaveQueueDepth, ChunkStorage};
 use crate::player::connection::NetworkConnection;
 use crate::world::World;
 use crate::world::tick_scheduler::{BlockTick, FluidTick};
@@ -57,6 +58,9 @@ const GENERATION_THREAD_MULTIPLE: usize = 2;
 /// `ENDER_PEARL_TICKET_TIMEOUT - 1` ticks while it flies.
 pub const ENDER_PEARL_TICKET_TIMEOUT: u32 = ENDER_PEARL_TICKET_TIMEOUT_TICKS;
 
+/// Vanilla `TicketType.DRAGON` at radius 9 around chunk `(0, 0)`.
+const DRAGON_TICKET: ChunkTicket = ChunkTicket::simulated_full_chunks(9);
+
 /// Timing information for the game tick portion of chunk map operations.
 #[derive(Debug, Default)]
 pub struct ChunkMapGameTickTimings {
@@ -70,6 +74,8 @@ pub struct ChunkMapGameTickTimings {
     pub tick_block_entities: Duration,
     /// Number of block-ticking chunks.
     pub tickable_count: usize,
+    /// Number of regions random ticks ran in, or 0 if they ran serially.
+    pub tick_regions: usize,
     /// Total number of loaded chunks.
     pub total_chunks: usize,
 }
@@ -79,6 +85,14 @@ struct TickableChunk {
     simulation_level: ChunkTicketLevel,
 }
 
+impl TickableChunk {
+    fn tick_random_blocks(&self, random_tick_speed: u32) {
+        if let Some(chunk_guard) = self.holder.try_chunk(ChunkStatus::Full) {
+            chunk_guard.tick_random_blocks(random_tick_speed);
+        }
+    }
+}
+
 /// Timing information for the chunk scheduling tick operations.
 #[derive(Debug, Default)]
 pub struct ChunkMapSchedulingTimings {
@@ -317,6 +331,10 @@ pub struct ChunkMap {
     pub chunk_runtime: Arc<Runtime>,
     /// Storage backend for chunk saving and loading.
     pub storage: Arc<ChunkStorage>,
+    /// Limits how many chunk saves hold a snapshot at once.
+    save_queue: ChunkSaveQueue,
+    /// Chunk whose random ticks are running serially, for watchdog stall dumps.
+    ticking_chunk: SyncMutex<Option<ChunkPos>>,
     /// Chunk holders with pending block changes to broadcast.
     pub chunks_to_broadcast: SyncMutex<Vec<Arc<ChunkHolder>>>,
     /// Coalesced light changes and drained-but-not-yet-applied light work.
@@ -443,6 +461,8 @@ impl ChunkMap {
             generation_pool,
             chunk_runtime,
             storage,
+            save_queue: ChunkSaveQueue::default(),
+            ticking_chunk: SyncMutex::new(None),
             chunks_to_broadcast: SyncMutex::new(Vec::new()),
             light_updates: SyncMutex::new(LightUpdateState::default()),
             light_updates_progress_notify: Notify::new(),
@@ -538,7 +558,7 @@ impl ChunkMap {
     where
         F: FnOnce() -> R,
     {
-        let ticket = ChunkTicket::full_chunks(radius);
+        let ticket = ChunkTicketType::Start { radius }.ticket();
 
         self.chunk_tickets.lock().add_ticket(center, ticket);
         let radius = i32::from(radius);
@@ -582,6 +602,28 @@ impl ChunkMap {
             .tick(&mut chunk_tickets, |pos| self.can_timed_ticket_expire(pos));
     }
 
+    /// Adds or removes vanilla's persistent `FORCED` ticket at `pos`.
+    ///
+    /// Returns whether the chunk's forced state changed.
+    pub fn set_chunk_forced(&self, pos: ChunkPos, forced: bool) -> bool {
+        let mut chunk_tickets = self.chunk_tickets.lock();
+        self.timed_chunk_tickets
+            .lock()
+            .set_forced(&mut chunk_tickets, pos, forced)
+    }
+
+    /// Returns whether `pos` is force loaded.
+    #[must_use]
+    pub fn is_chunk_forced(&self, pos: ChunkPos) -> bool {
+        self.timed_chunk_tickets.lock().is_forced(pos)
+    }
+
+    /// Returns every force-loaded chunk position.
+    #[must_use]
+    pub fn forced_chunks(&self) -> Vec<ChunkPos> {
+        self.timed_chunk_tickets.lock().forced_chunks().collect()
+    }
+
     pub(crate) fn persistent_chunk_tickets(&self) -> PersistentChunkTickets {
         self.timed_chunk_tickets.lock().to_persistent()
     }
@@ -1304,22 +1346,30 @@ impl ChunkMap {
     /// Game tick: broadcasts block changes, ticks chunks (random + scheduled ticks).
     ///
     /// Runs on the main game tick loop. Does NOT handle chunk generation or unloading.
-    #[instrument(level = "trace", skip(self, world), name = "chunk_map_game_tick")]
+    #[instrument(
+        level = "trace",
+        skip(self, world, random_tick_pool),
+        name = "chunk_map_game_tick"
+    )]
     pub fn tick_game(
         self: &Arc<Self>,
         world: &Arc<World>,
         tick_count: u64,
         random_tick_speed: u32,
         runs_normally: bool,
+        random_tick_pool: Option<&ThreadPool>,
     ) -> ChunkMapGameTickTimings {
         let mut timings = ChunkMapGameTickTimings::default();
         let mut ready_block_ticks = Vec::new();
         let mut ready_fluid_ticks = Vec::new();
 
         if tick_count.is_multiple_of(100) {
+            let save_queue = self.save_queue_depth();
             tracing::debug!(
                 chunks = self.chunks.len(),
                 unloading = self.unloading_chunks.len(),
+                saves_queued = save_queue.queued,
+                saves_in_flight = save_queue.in_flight,
                 "Chunk map status"
             );
         }
@@ -1370,14 +1420,32 @@ impl ChunkMap {
                     }
                 }
                 Self::execute_scheduled_ticks(world, ready_block_ticks, ready_fluid_ticks);
-                for tickable_chunk in &tickable_chunks {
-                    // Vanilla random chunk ticks use the entity-ticking range.
-                    if !tickable_chunk.simulation_level.is_entity_ticking() {
-                        continue;
+                // Vanilla random chunk ticks use the entity-ticking range.
+                let random_ticking: Vec<&TickableChunk> = tickable_chunks
+                    .iter()
+                    .filter(|chunk| chunk.simulation_level.is_entity_ticking())
+                    .collect();
+                let thundering = world.is_raining() && world.is_thundering();
+                for tickable_chunk in &random_ticking {
+                    let chunk_pos = tickable_chunk.holder.get_pos();
+                    if thundering {
+                        world.tick_thunder(chunk_pos);
                     }
-                    if let Some(chunk_guard) = tickable_chunk.holder.try_chunk(ChunkStatus::Full) {
-                        chunk_guard.tick_random_blocks(random_tick_speed);
+                    world.tick_ice_and_snow(chunk_pos, random_tick_speed);
+                }
+                if let Some(pool) = random_tick_pool {
+                    timings.tick_regions = Self::tick_random_blocks_in_regions(
+                        world,
+                        pool,
+                        &random_ticking,
+                        random_tick_speed,
+                    );
+                } else {
+                    for tickable_chunk in random_ticking {
+                        *self.ticking_chunk.lock() = Some(tickable_chunk.holder.get_pos());
+                        tickable_chunk.tick_random_blocks(random_tick_speed);
                     }
+                    *self.ticking_chunk.lock() = None;
                 }
                 timings.tick_chunks = start.elapsed();
             }
@@ -1393,6 +1461,53 @@ impl ChunkMap {
         timings
     }
 
+    /// Runs random ticks with each independent region on its own worker.
+    ///
+    /// Work a region defers, such as scheduling ticks or writing outside its
+    /// own chunks, runs afterwards on this thread in region order, so the
+    /// result does not depend on which worker finished first. Returns the
+    /// region count.
+    fn tick_random_blocks_in_regions(
+        world: &Arc<World>,
+        pool: &ThreadPool,
+        chunks: &[&TickableChunk],
+        random_tick_speed: u32,
+    ) -> usize {
+        let positions: Vec<ChunkPos> = chunks.iter().map(|chunk| chunk.holder.get_pos()).collect();
+        let regions = partition_regions(&positions);
+        if regions.len() <= 1 {
+            for chunk in chunks {
+                chunk.tick_random_blocks(random_tick_speed);
+            }
+            return regions.len();
+        }
+
+        let deferred = SyncMutex::new(Vec::new());
+        pool.scope(|scope| {
+            for (index, region) in regions.iter().enumerate() {
+                let (deferred, positions) = (&deferred, &positions);
+                scope.spawn(move |_| {
+                    let owned = Arc::new(region.iter().map(|&chunk| positions[chunk]).collect());
+                    let actions = tick_in_region(owned, || {
+                        for &chunk in region {
+                            chunks[chunk].tick_random_blocks(random_tick_speed);
+                        }
+                    });
+                    if !actions.is_empty() {
+                        deferred.lock().push((index, actions));
+                    }
+                });
+            }
+        });
+
+        let mut deferred = deferred.into_inner();
+        deferred.sort_unstable_by_key(|(index, _)| *index);
+        for action in deferred.into_iter().flat_map(|(_, actions)| actions) {
+            action(world);
+        }
+        regions.len()
+    }
+
     /// Ticks block entities in tickable full chunks.
     pub fn tick_block_entities(&self, timings: &mut ChunkMapGameTickTimings, runs_normally: bool) {
         if !runs_normally {
@@ -1562,6 +1677,8 @@ impl ChunkMap {
         _save_dependency: ChunkSaveDependency,
     ) {
         let chunk_pos = chunk_holder.get_pos();
+        // Wait for a slot before snapshotting so a save storm stays bounded.
+        let _save_permit = self.save_queue.acquire().await;
         self.flush_queued_light_changes_touching_chunk_for_save(chunk_pos)
             .await;
 
@@ -1616,6 +1733,18 @@ impl ChunkMap {
         }
     }
 
+    /// Returns how many chunk saves are waiting for a slot and running.
+    #[must_use]
+    pub fn save_queue_depth(&self) -> ChunkSaveQueueDepth {
+        self.save_queue.depth()
+    }
+
+    /// Returns the chunk whose random ticks are running serially, if any.
+    #[must_use]
+    pub fn ticking_chunk(&self) -> Option<ChunkPos> {
+        *self.ticking_chunk.lock()
+    }
+
     fn mark_chunk_dirty_for_save_retry(chunk_holder: &ChunkHolder) {
         let Some(chunk) = chunk_holder.try_chunk(ChunkStatus::StructureStarts) else {
             return;
@@ -1667,6 +1796,11 @@ impl ChunkMap {
                     let pos = *pos;
                     let world = self.world_gen_context.world();
                     world.on_entity_chunk_unload_finalized(pos);
+                    if let Some(chunk) = holder.try_chunk(ChunkStatus::Full)
+                        && let ChunkAccess::Full(chunk) = &*chunk
+                    {
+                        chunk.unregister_game_event_listeners();
+                    }
                     let map_clone = self.clone();
                     self.task_tracker.spawn(async move {
                         if let Err(e) = map_clone.storage.release_chunk(pos).await {
@@ -1685,23 +1819,24 @@ impl ChunkMap {
     pub fn update_player_status(&self, player: &Player) {
         let current_chunk_pos = ChunkPos::from_entity_pos(player.position());
         *player.last_chunk_pos.lock() = current_chunk_pos;
-        let view_distance = player.view_distance();
-
-        let new_view = PlayerChunkView::new(current_chunk_pos, view_distance);
+        let new_view = PlayerChunkView::new(current_chunk_pos, player.view_distance())
+            .with_simulation_distance(player.simulation_distance());
         let world = self.world_gen_context.world();
         let mut last_view_guard = player.last_tracking_view.lock();
 
         if last_view_guard.as_ref() != Some(&new_view) {
             let mut chunk_tickets = self.chunk_tickets.lock();
 
-            let new_ticket = ChunkTicket::player(new_view.view_distance, world.simulation_distance);
+            let new_ticket =
+                ChunkTicket::player(new_view.view_distance, new_view.simulation_distance);
 
             if let Some(last_view) = last_view_guard.as_ref() {
                 if last_view.center != new_view.center
                     || last_view.view_distance != new_view.view_distance
+                    || last_view.simulation_distance != new_view.simulation_distance
                 {
                     let old_ticket =
-                        ChunkTicket::player(last_view.view_distance, world.simulation_distance);
+                        ChunkTicket::player(last_view.view_distance, last_view.simulation_distance);
                     chunk_tickets.remove_ticket(last_view.center, old_ticket);
                     chunk_tickets.add_ticket(new_view.center, new_ticket);
 
@@ -1778,8 +1913,8 @@ impl ChunkMap {
         if let Some(last_view) = last_view_guard.take() {
             drop(last_view_guard);
             let mut chunk_tickets = self.chunk_tickets.lock();
-            let world = self.world_gen_context.world();
-            let ticket = ChunkTicket::player(last_view.view_distance, world.simulation_distance);
+            let ticket =
+                ChunkTicket::player(last_view.view_distance, last_view.simulation_distance);
             chunk_tickets.remove_ticket(last_view.center, ticket);
         }
     }
@@ -1800,6 +1935,20 @@ impl ChunkMap {
             .add_ender_pearl_ticket(&mut chunk_tickets, chunk);
     }
 
+    /// Adds vanilla's `DRAGON` ticket, keeping the End's main island simulated.
+    pub(crate) fn add_dragon_ticket(&self) {
+        self.chunk_tickets
+            .lock()
+            .add_ticket(ChunkPos::new(0, 0), DRAGON_TICKET);
+    }
+
+    /// Removes the ticket added by [`Self::add_dragon_ticket`].
+    pub(crate) fn remove_dragon_ticket(&self) {
+        self.chunk_tickets
+            .lock()
+            .remove_ticket(ChunkPos::new(0, 0), DRAGON_TICKET);
+    }
+
     /// Saves all dirty chunks to disk.
     ///
     /// This method should be called during graceful shutdown to ensure all
@@ -1871,6 +2020,7 @@ impl ChunkMap {
             let handled_runtime_entity_ids = mem::take(&mut prepared.handled_runtime_entity_ids);
             let world = self.world_gen_context.world();
             let _save_dependency = holder.add_save_dependency();
+            let _save_permit = self.save_queue.acquire().await;
             match self.storage.save_chunk_data(prepared, status).await {
                 Ok(true) => {
                     world
@@ -3,7 +3,7 @@
 
 use std::mem;
 
-use rustc_hash::{FxBuildHasher, FxHashMap};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 use steel_utils::ChunkPos;
@@ -21,6 +21,7 @@ pub(crate) const PORTAL_TICKET_RADIUS: u8 = 3;
 const PORTAL_TICKET_TIMEOUT_TICKS: i64 = 300;
 pub(crate) const ENDER_PEARL_TICKET_TIMEOUT_TICKS: u32 = 40;
 const ENDER_PEARL_TICKET_RADIUS: u8 = 2;
+const FORCED_TICKET_RADIUS: u8 = 2;
 
 /// A chunk ticket level.
 ///
@@ -202,6 +203,38 @@ impl ChunkTicket {
     }
 }
 
+/// Vanilla ticket categories that plugins and gameplay systems place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkTicketType {
+    /// A player's view, loading `view_distance` and simulating `simulation_distance`.
+    Player {
+        view_distance: u8,
+        simulation_distance: u8,
+    },
+    /// A chunk kept loaded by `/forceload`; persists until removed.
+    Forced,
+    /// The destination of a portal or dimension change.
+    Portal,
+    /// A square of full chunks held while the server starts or prepares spawn.
+    Start { radius: u8 },
+}
+
+impl ChunkTicketType {
+    /// Returns the ticket this category places at its center chunk.
+    #[must_use]
+    pub const fn ticket(self) -> ChunkTicket {
+        match self {
+            Self::Player {
+                view_distance,
+                simulation_distance,
+            } => ChunkTicket::player(view_distance, simulation_distance),
+            Self::Forced => ChunkTicket::simulated_full_chunks(FORCED_TICKET_RADIUS),
+            Self::Portal => ChunkTicket::simulated_full_chunks(PORTAL_TICKET_RADIUS),
+            Self::Start { radius } => ChunkTicket::full_chunks(radius),
+        }
+    }
+}
+
 #[must_use]
 pub const fn is_full(level: ChunkTicketLevel) -> bool {
     level.is_full()
@@ -292,12 +325,14 @@ struct PersistentChunkTicket {
 #[serde(rename_all = "snake_case")]
 enum PersistentChunkTicketKind {
     Portal,
+    Forced,
 }
 
-/// Timed chunk tickets owned by vanilla gameplay systems.
+/// Timed and forced chunk tickets owned by vanilla gameplay systems.
 #[derive(Debug, Default)]
 pub(crate) struct TimedChunkTickets {
     tickets: Vec<TimedChunkTicket>,
+    forced: FxHashSet<ChunkPos>,
 }
 
 impl TimedChunkTickets {
@@ -310,23 +345,67 @@ impl TimedChunkTickets {
         timed_tickets
     }
 
-    /// Converts active timed tickets to persistent saved data.
+    /// Converts active timed and forced tickets to persistent saved data.
     pub(crate) fn to_persistent(&self) -> PersistentChunkTickets {
+        let mut forced: Vec<_> = self.forced.iter().copied().collect();
+        forced.sort_unstable_by_key(|pos| (pos.0.x, pos.0.y));
         PersistentChunkTickets {
             tickets: self
                 .tickets
                 .iter()
                 .copied()
                 .filter_map(TimedChunkTicket::to_persistent)
+                .chain(forced.into_iter().map(|pos| PersistentChunkTicket {
+                    kind: PersistentChunkTicketKind::Forced,
+                    chunk_x: pos.0.x,
+                    chunk_z: pos.0.y,
+                    ticks_left: 0,
+                }))
                 .collect(),
         }
     }
 
-    /// Inserts restored timed ticket sources into the active ticket manager.
+    /// Inserts restored timed and forced ticket sources into the active ticket manager.
     pub(crate) fn activate_all(&self, ticket_manager: &mut ChunkTicketManager) {
         for ticket in &self.tickets {
             ticket_manager.add_ticket(ticket.pos, ticket.ticket);
         }
+        for &pos in &self.forced {
+            ticket_manager.add_ticket(pos, ChunkTicketType::Forced.ticket());
+        }
+    }
+
+    /// Adds or removes vanilla's `FORCED` ticket, returning whether the chunk changed state.
+    pub(crate) fn set_forced(
+        &mut self,
+        ticket_manager: &mut ChunkTicketManager,
+        pos: ChunkPos,
+        forced: bool,
+    ) -> bool {
+        let changed = if forced {
+            self.forced.insert(pos)
+        } else {
+            self.forced.remove(&pos)
+        };
+        if changed {
+            let ticket = ChunkTicketType::Forced.ticket();
+            if forced {
+                ticket_manager.add_ticket(pos, ticket);
+            } else {
+                ticket_manager.remove_ticket(pos, ticket);
+            }
+        }
+        changed
+    }
+
+    /// Returns whether `pos` holds a `FORCED` ticket.
+    pub(crate) fn is_forced(&self, pos: ChunkPos) -> bool {
+        self.forced.contains(&pos)
+    }
+
+    /// Returns every chunk holding a `FORCED` ticket.
+    pub(crate) fn forced_chunks(&self) -> impl Iterator<Item = ChunkPos> + '_ {
+        self.forced.iter().copied()
     }
 
     /// Adds or refreshes vanilla's portal ticket.
@@ -418,6 +497,10 @@ impl TimedChunkTickets {
                     persistent.ticks_left,
                 );
             }
+            PersistentChunkTicketKind::Forced => {
+                self.forced
+                    .insert(ChunkPos::new(persistent.chunk_x, persistent.chunk_z));
+            }
         }
     }
 
@@ -480,7 +563,7 @@ enum TimedChunkTicketKind {
 
 #[must_use]
 const fn portal_ticket() -> ChunkTicket {
-    ChunkTicket::simulated_full_chunks(PORTAL_TICKET_RADIUS)
+    ChunkTicketType::Portal.ticket()
 }
 
 #[must_use]
@@ -1322,4 +1405,35 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn forced_tickets_never_expire_and_survive_a_save_round_trip() {
+        let mut manager = ChunkTicketManager::new();
+        let mut timed_tickets = TimedChunkTickets::default();
+        let pos = ChunkPos::new(-3, 5);
+
+        assert!(timed_tickets.set_forced(&mut manager, pos, true));
+        assert!(!timed_tickets.set_forced(&mut manager, pos, true));
+        for _ in 0..1_000 {
+            timed_tickets.tick(&mut manager, |_| true);
+        }
+        manager.run_all_updates();
+        assert_eq!(
+            manager.get_simulation_level(pos),
+            Some(ChunkTicketLevel::for_full_chunk_radius(
+                FORCED_TICKET_RADIUS
+            ))
+        );
+
+        let restored = TimedChunkTickets::from_persistent(timed_tickets.to_persistent());
+        assert!(restored.is_forced(pos));
+        let mut restored_manager = ChunkTicketManager::new();
+        restored.activate_all(&mut restored_manager);
+        assert_eq!(restored_manager.ticket_count(), 1);
+
+        assert!(timed_tickets.set_forced(&mut manager, pos, false));
+        manager.run_all_updates();
+        assert_eq!(manager.ticket_count(), 0);
+        assert!(timed_tickets.to_persistent().tickets.is_empty());
+    }
 }
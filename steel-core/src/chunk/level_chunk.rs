@@ -505,6 +505,9 @@ impl LevelChunk {
     ///
     /// Marks the entity as removed and removes it from the ticking list.
     pub fn remove_block_entity(&self, pos: BlockPos) {
+        if let Some(block_entity) = self.block_entities.get(pos) {
+            self.remove_game_event_listener(&block_entity);
+        }
         self.block_entities.remove(pos);
         self.mark_unsaved();
     }
@@ -517,10 +520,56 @@ impl LevelChunk {
     ///
     /// Note: The world reference should be passed at block entity construction time.
     pub fn add_and_register_block_entity(&self, block_entity: SharedBlockEntity) {
+        let pos = block_entity.lock().get_block_pos();
+        if let Some(old) = self.block_entities.get(pos) {
+            self.remove_game_event_listener(&old);
+        }
+        self.add_game_event_listener(&block_entity);
         self.block_entities.add_and_register(block_entity);
         self.mark_unsaved();
     }
 
+    /// Registers the game event listeners of every block entity in this chunk.
+    ///
+    /// Called once a promoted proto chunk is published. Vanilla equivalent:
+    /// `LevelChunk.registerAllBlockEntitiesAfterLevelLoad`.
+    pub fn register_game_event_listeners(&self) {
+        for block_entity in self.block_entities.get_all() {
+            self.add_game_event_listener(&block_entity);
+        }
+    }
+
+    /// Unregisters the game event listeners of every block entity in this chunk.
+    ///
+    /// Called when the chunk unloads.
+    pub fn unregister_game_event_listeners(&self) {
+        for block_entity in self.block_entities.get_all() {
+            self.remove_game_event_listener(&block_entity);
+        }
+    }
+
+    /// Vanilla equivalent: `LevelChunk.addGameEventListener`.
+    fn add_game_event_listener(&self, block_entity: &SharedBlockEntity) {
+        let (pos, listener) = {
+            let guard = block_entity.lock();
+            (guard.get_block_pos(), guard.game_event_listener())
+        };
+        if let (Some(listener), Some(world)) = (listener, self.get_level()) {
+            world.register_game_event_listener(SectionPos::from_block_pos(pos), listener);
+        }
+    }
+
+    /// Vanilla equivalent: `LevelChunk.removeGameEventListener`.
+    fn remove_game_event_listener(&self, block_entity: &SharedBlockEntity) {
+        let (pos, listener) = {
+            let guard = block_entity.lock();
+            (guard.get_block_pos(), guard.game_event_listener())
+        };
+        if let (Some(listener), Some(world)) = (listener, self.get_level()) {
+            world.unregister_game_event_listener(SectionPos::from_block_pos(pos), &listener);
+        }
+    }
+
     /// Updates the ticking status of a block entity.
     ///
     /// Call this when a block entity's ticking status may have changed
@@ -545,6 +594,7 @@ impl LevelChunk {
     ///
     /// Marks all entities as removed.
     pub fn clear_all_block_entities(&self) {
+        self.unregister_game_event_listeners();
         self.block_entities.clear();
     }
 
@@ -586,6 +636,7 @@ impl LevelChunk {
                             );
                         }
                     }
+                    BlockEntityTickAction::Callback(callback) => callback(&world),
                 }
             }
         }
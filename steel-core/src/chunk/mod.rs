@@ -19,3 +19,5 @@ pub mod level_chunk;
 pub mod paletted_container;
 pub mod proto_chunk;
 pub mod section;
+/// Groups ticking chunks into regions that can tick in parallel.
+pub mod tick_region;
@@ -9,6 +9,8 @@ pub struct PlayerChunkView {
     pub center: ChunkPos,
     /// The view distance in chunks.
     pub view_distance: u8,
+    /// The simulation distance in chunks.
+    pub simulation_distance: u8,
 }
 
 impl PlayerChunkView {
@@ -18,6 +20,7 @@ impl PlayerChunkView {
         Self {
             center: ChunkPos::new(0, 0),
             view_distance: 0,
+            simulation_distance: 0,
         }
     }
 
@@ -27,9 +30,17 @@ impl PlayerChunkView {
         Self {
             center,
             view_distance,
+            simulation_distance: 0,
         }
     }
 
+    /// Sets the simulation distance the view's chunk ticket was placed with.
+    #[must_use]
+    pub const fn with_simulation_distance(mut self, simulation_distance: u8) -> Self {
+        self.simulation_distance = simulation_distance;
+        self
+    }
+
     fn min_x(&self) -> i32 {
         self.center.0.x - i32::from(self.view_distance) - 1
     }
@@ -0,0 +1,174 @@
+//! Independent tick regions for parallel chunk ticking.
+//!
+//! Ticking chunks are grouped into regions: connected components where each
+//! chunk is within [`REGION_BUFFER_RADIUS`] of another chunk in the same
+//! region. Chunks of different regions are therefore at least
+//! `REGION_BUFFER_RADIUS + 1` chunks apart, so ticks that only reach into
+//! neighbouring chunks never touch another region and regions can run on
+//! separate threads.
+//!
+//! Only random block ticks run in regions. An action that would touch a chunk
+//! outside the region being ticked, such as a far block change or a teleport,
+//! is deferred and run on the tick thread once every region has finished.
+//! Scheduled block and fluid ticks are deferred the same way, so their sub-tick
+//! order follows region order instead of thread timing.
+
+use std::cell::RefCell;
+use std::mem;
+use std::sync::Arc;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use steel_utils::ChunkPos;
+
+use crate::world::World;
+
+/// Chunks this close (Chebyshev distance) to each other share a region.
+pub const REGION_BUFFER_RADIUS: i32 = 2;
+
+/// Work moved out of a region to the synchronization phase.
+pub type DeferredAction = Box<dyn FnOnce(&Arc<World>) + Send>;
+
+/// The region the current thread is ticking.
+struct RegionScope {
+    chunks: Arc<FxHashSet<ChunkPos>>,
+    deferred: Vec<DeferredAction>,
+}
+
+thread_local! {
+    static CURRENT_REGION: RefCell<Option<RegionScope>> = const { RefCell::new(None) };
+}
+
+/// Groups `positions` into regions, returned as indices into `positions`.
+///
+/// Regions and the indices inside them keep the order of `positions`.
+#[must_use]
+pub fn partition_regions(positions: &[ChunkPos]) -> Vec<Vec<usize>> {
+    let index_of: FxHashMap<ChunkPos, usize> = positions
+        .iter()
+        .enumerate()
+        .map(|(index, pos)| (*pos, index))
+        .collect();
+    let mut region_of = vec![usize::MAX; positions.len()];
+    let mut regions = Vec::new();
+    let mut stack = Vec::new();
+
+    for start in 0..positions.len() {
+        if region_of[start] != usize::MAX {
+            continue;
+        }
+        let region = regions.len();
+        let mut members = Vec::new();
+        region_of[start] = region;
+        stack.push(start);
+        while let Some(index) = stack.pop() {
+            members.push(index);
+            let pos = positions[index];
+            for dx in -REGION_BUFFER_RADIUS..=REGION_BUFFER_RADIUS {
+                for dz in -REGION_BUFFER_RADIUS..=REGION_BUFFER_RADIUS {
+                    let neighbor = ChunkPos::new(pos.0.x + dx, pos.0.y + dz);
+                    if let Some(&neighbor) = index_of.get(&neighbor)
+                        && region_of[neighbor] == usize::MAX
+                    {
+                        region_of[neighbor] = region;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+        }
+        members.sort_unstable();
+        regions.push(members);
+    }
+    regions
+}
+
+/// Runs `tick` as the owner of `chunks` on the current thread.
+///
+/// Returns the actions deferred while it ran, in the order they were deferred.
+pub(crate) fn tick_in_region(
+    chunks: Arc<FxHashSet<ChunkPos>>,
+    tick: impl FnOnce(),
+) -> Vec<DeferredAction> {
+    let previous = CURRENT_REGION.with_borrow_mut(|current| {
+        current.replace(RegionScope {
+            chunks,
+            deferred: Vec::new(),
+        })
+    });
+    tick();
+    CURRENT_REGION
+        .with_borrow_mut(|current| mem::replace(current, previous))
+        .map(|scope| scope.deferred)
+        .unwrap_or_default()
+}
+
+/// Defers `action` if the current thread is ticking a region that does not own
+/// `chunk`. Returns `false`, without running `action`, if the caller may touch
+/// `chunk` directly.
+pub fn defer_if_outside_region(
+    chunk: ChunkPos,
+    action: impl FnOnce(&Arc<World>) + Send + 'static,
+) -> bool {
+    CURRENT_REGION.with_borrow_mut(|current| match current {
+        Some(scope) if !scope.chunks.contains(&chunk) => {
+            scope.deferred.push(Box::new(action));
+            true
+        }
+        _ => false,
+    })
+}
+
+/// Defers `action` to the synchronization phase if a region is being ticked on
+/// this thread. Returns `false`, without running `action`, otherwise.
+pub fn defer_if_in_region(action: impl FnOnce(&Arc<World>) + Send + 'static) -> bool {
+    CURRENT_REGION.with_borrow_mut(|current| match current {
+        Some(scope) => {
+            scope.deferred.push(Box::new(action));
+            true
+        }
+        None => false,
+    })
+}
+
+/// Defers `action` to the synchronization phase if a region is being ticked on
+/// this thread, or returns it to be run right away otherwise.
+pub fn defer_to_sync_phase(action: DeferredAction) -> Option<DeferredAction> {
+    CURRENT_REGION.with_borrow_mut(|current| match current {
+        Some(scope) => {
+            scope.deferred.push(action);
+            None
+        }
+        None => Some(action),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regions_split_on_gaps_wider_than_the_buffer() {
+        let positions = [
+            ChunkPos::new(0, 0),
+            ChunkPos::new(10, 10),
+            ChunkPos::new(2, 2),
+            ChunkPos::new(2 + REGION_BUFFER_RADIUS + 1, 2),
+            ChunkPos::new(11, 9),
+        ];
+        assert_eq!(
+            partition_regions(&positions),
+            vec![vec![0, 2], vec![1, 4], vec![3]]
+        );
+
+        let owned = Arc::new([ChunkPos::new(0, 0)].into_iter().collect());
+        let deferred = tick_in_region(owned, || {
+            assert!(!defer_if_outside_region(ChunkPos::new(0, 0), |_| {}));
+            assert!(defer_if_outside_region(ChunkPos::new(5, 0), |_| {}));
+            assert!(defer_to_sync_phase(Box::new(|_| {})).is_none());
+            assert!(defer_if_in_region(|_| {}));
+        });
+        assert_eq!(deferred.len(), 3);
+        assert!(!defer_if_outside_region(ChunkPos::new(5, 0), |_| {}));
+        assert!(defer_to_sync_phase(Box::new(|_| {})).is_some());
+        assert!(!defer_if_in_region(|_| {}));
+    }
+}
@@ -27,6 +27,9 @@
 //!
 //! Block data uses power-of-2 bit packing (1, 2, 4, 8, 16 bits) to avoid entries
 //! spanning u64 boundaries.
+//!
+//! Entities live in a second set of region files beside the terrain ones (like
+//! vanilla's `entities/` folder), so terrain saves never re-encode entity NBT.
 
 use glam::IVec3;
 use steel_utils::{BoundingBox, Identifier, PackedChunkPos};
@@ -363,6 +366,9 @@ pub struct PersistentChunk {
     /// Block entities (chests, signs, etc.).
     pub block_entities: Vec<PersistentBlockEntity>,
     /// Entities in this chunk (excludes players and non-serializable types).
+    ///
+    /// Disk storage moves these into [`PersistentChunkEntities`] before encoding;
+    /// only terrain saved before the entity split still carries them inline.
     pub entities: Vec<PersistentEntity>,
     /// Scheduled block ticks pending in this chunk.
     pub block_ticks: Vec<PersistentTick>,
@@ -445,10 +451,15 @@ pub struct PersistentBlockEntity {
     pub nbt_data: Vec<u8>,
 }
 
+/// The entity payload of one chunk, stored in the entity region files.
+#[derive(Debug, Clone, Default, SchemaWrite, SchemaRead)]
+pub struct PersistentChunkEntities {
+    /// Root entities of the chunk; passengers are nested inside them.
+    pub entities: Vec<PersistentEntity>,
+}
+
 /// An entity stored with a chunk.
 ///
-/// Unlike vanilla which stores entities in separate region files,
-/// Steel stores entities inline with chunk data for simplicity.
 /// Base entity fields are stored directly; type-specific data is in `nbt_data`.
 #[derive(Debug, Clone, SchemaWrite, SchemaRead)]
 pub struct PersistentEntity {
@@ -29,15 +29,18 @@
 //! - **Power-of-2 bit packing** for efficient storage (1, 2, 4, 8, 16 bits)
 //! - **Homogeneous section optimization** (single block type = no bit array)
 //! - **zstd compression** per-chunk for good compression ratios
+//! - **Separate entity regions** in `entities/` beside `region/`, like vanilla
 
 mod bit_pack;
 mod format;
 mod ram_only;
 mod region_manager;
 pub mod registry;
+mod save_queue;
 mod storage;
 
 pub use format::*;
 pub use ram_only::*;
 pub use region_manager::*;
+pub use save_queue::*;
 pub use storage::*;
@@ -73,9 +73,10 @@ impl RamOnlyStorage {
     /// Saves prepared chunk data to storage.
     pub async fn save_chunk_data(
         &self,
-        prepared: PreparedChunkSave,
+        mut prepared: PreparedChunkSave,
         status: ChunkStatus,
     ) -> io::Result<bool> {
+        prepared.pack_sections();
         // Just track that this chunk has been saved
         // The actual data is in the live World/ChunkAccess, not persisted
         self.saved_chunks.write().await.insert(
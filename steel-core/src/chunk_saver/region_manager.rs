@@ -6,6 +6,7 @@
 
 use std::{
     io::{self},
+    mem,
     path::PathBuf,
     sync::Weak,
 };
@@ -15,13 +16,15 @@ use steel_utils::{ChunkPos, locks::AsyncRwLock};
 use tokio::{
     fs::{self, File, OpenOptions},
     io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
+    task,
 };
 
 use crate::chunk::chunk_access::ChunkStatus;
+use crate::chunk::paletted_container::{BiomePalette, BlockPalette};
 use crate::world::World;
 
 use super::{
-    ChunkStorage, LoadedChunk, PersistentChunk,
+    ChunkStorage, LoadedChunk, PersistentChunk, PersistentChunkEntities,
     format::{
         CHUNK_TABLE_SIZE, FILE_HEADER_SIZE, FIRST_DATA_SECTOR, FORMAT_VERSION, MAX_CHUNK_SIZE,
         REGION_MAGIC, RegionHeader, RegionPos, SECTOR_SIZE,
@@ -32,8 +35,18 @@ use super::{
 ///
 /// Only keeps region headers (8KB each) in memory, not chunk data.
 /// Chunks are loaded on-demand and converted directly to runtime format.
+/// Terrain and entities are kept in separate region files, so a terrain save
+/// never re-encodes entity NBT and entity-free chunks write nothing to the entity store.
 pub struct RegionManager {
-    /// Base directory for region files (e.g., "world/region").
+    /// Terrain region files (e.g., "world/region").
+    terrain: RegionFiles,
+    /// Entity region files beside the terrain (e.g., "world/entities").
+    entities: RegionFiles,
+}
+
+/// One directory of region files.
+struct RegionFiles {
+    /// Base directory for region files.
     base_path: PathBuf,
     /// Open region file handles with their headers.
     regions: AsyncRwLock<FxHashMap<RegionPos, RegionHandle>>,
@@ -44,12 +57,22 @@ pub struct RegionManager {
 pub struct PreparedChunkSave {
     /// The chunk position.
     pub pos: ChunkPos,
-    /// The serialized chunk data.
+    /// The serialized chunk data, without sections until `pack_sections` runs.
     pub persistent: PersistentChunk,
+    /// Section palettes copied under the chunk lock, not yet bit-packed.
+    pub sections: Vec<SectionSnapshot>,
     /// Runtime manager entity IDs that were either serialized or explicitly skipped.
     pub handled_runtime_entity_ids: Vec<i32>,
 }
 
+/// Block and biome data of one section, copied while holding the chunk lock.
+pub struct SectionSnapshot {
+    /// The section's block states.
+    pub states: BlockPalette,
+    /// The section's biomes.
+    pub biomes: BiomePalette,
+}
+
 /// An open region file with its header.
 struct RegionHandle {
     /// File handle for reading/writing.
@@ -67,12 +90,176 @@ struct RegionHandle {
 impl RegionManager {
     /// Creates a new region manager.
     ///
+    /// Entity regions are stored in an `entities` directory next to `base_path`.
+    ///
     /// # Arguments
-    /// * `base_path` - Directory where region files are stored.
-    /// * `registry` - The registry for block state and biome conversions.
+    /// * `base_path` - Directory where terrain region files are stored.
     pub fn new(base_path: impl Into<PathBuf>) -> Self {
+        let base_path = base_path.into();
+        let entities_path = base_path.with_file_name("entities");
+        Self {
+            terrain: RegionFiles::new(base_path),
+            entities: RegionFiles::new(entities_path),
+        }
+    }
+
+    /// Saves prepared chunk data to disk. This is the async part that doesn't
+    /// need to hold the chunk lock.
+    pub async fn save_chunk_data(
+        &self,
+        prepared: PreparedChunkSave,
+        status: ChunkStatus,
+    ) -> io::Result<bool> {
+        let pos = prepared.pos;
+
+        // Packing and compression are CPU-bound, so keep them off the async workers
+        let (terrain, entities) = task::spawn_blocking(move || Self::encode_chunk(prepared))
+            .await
+            .map_err(io::Error::other)??;
+
+        self.terrain.write(pos, &terrain, status).await?;
+        match entities {
+            Some(entities) => self.entities.write(pos, &entities, status).await?,
+            None => self.entities.clear(pos).await?,
+        }
+
+        Ok(true)
+    }
+
+    /// Bit-packs, serializes and compresses a prepared chunk.
+    ///
+    /// Returns the terrain data and, if the chunk has any, its entity data.
+    fn encode_chunk(mut prepared: PreparedChunkSave) -> io::Result<(Vec<u8>, Option<Vec<u8>>)> {
+        prepared.pack_sections();
+
+        let entities = mem::take(&mut prepared.persistent.entities);
+        let terrain = wincode::serialize(&prepared.persistent)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        let entities = if entities.is_empty() {
+            None
+        } else {
+            let entities = wincode::serialize(&PersistentChunkEntities { entities })
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+            Some(Self::compress(&entities)?)
+        };
+        Ok((Self::compress(&terrain)?, entities))
+    }
+
+    /// Compresses one serialized region entry.
+    fn compress(data: &[u8]) -> io::Result<Vec<u8>> {
+        let compressed = zstd::encode_all(data, 3)?;
+
+        if compressed.len() > MAX_CHUNK_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Chunk too large: {} bytes (max {})",
+                    compressed.len(),
+                    MAX_CHUNK_SIZE
+                ),
+            ));
+        }
+        Ok(compressed)
+    }
+
+    /// Loads a chunk from the appropriate region.
+    ///
+    /// Automatically opens the region if not already open. The region's reference
+    /// count is incremented, so you must call `release_chunk` when done with the chunk.
+    ///
+    /// Returns `Ok(None)` if the chunk doesn't exist on disk.
+    ///
+    /// # Arguments
+    /// * `pos` - The chunk position
+    /// * `min_y` - The minimum Y coordinate of the world
+    /// * `height` - The total height of the world
+    /// * `level` - Weak reference to the world for `LevelChunk`
+    ///
+    /// The region must already be acquired via `acquire_chunk` before calling this.
+    pub async fn load_chunk(
+        &self,
+        pos: ChunkPos,
+        min_y: i32,
+        height: i32,
+        level: Weak<World>,
+    ) -> io::Result<Option<LoadedChunk>> {
+        let Some((compressed, status)) = self.terrain.read(pos).await? else {
+            return Ok(None);
+        };
+        let entities = self.entities.read(pos).await?;
+
+        // Decompress and deserialize
+        let data = zstd::decode_all(&compressed[..])?;
+        let mut persistent: PersistentChunk = wincode::deserialize(&data)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        // Terrain saved before the entity split keeps its entities inline.
+        if let Some((entities, _)) = entities {
+            let data = zstd::decode_all(&entities[..])?;
+            let entities: PersistentChunkEntities = wincode::deserialize(&data)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+            persistent.entities = entities.entities;
+        }
+
+        // Convert to runtime format (persistent is dropped after this - no duplication!)
+        Ok(Some(ChunkStorage::persistent_to_chunk(
+            &persistent,
+            pos,
+            status,
+            min_y,
+            height,
+            level,
+        )))
+    }
+
+    /// Acquires a chunk, incrementing the region's reference count.
+    ///
+    /// This opens or creates the region file. Call this before loading or
+    /// generating a chunk, and call `release_chunk` when done with the chunk.
+    ///
+    /// Returns `Ok(true)` if the chunk exists on disk, `Ok(false)` if it doesn't.
+    pub async fn acquire_chunk(&self, pos: ChunkPos) -> io::Result<bool> {
+        let exists = self.terrain.acquire(pos).await?;
+        self.entities.acquire(pos).await?;
+        Ok(exists)
+    }
+
+    /// Releases a loaded chunk, decrementing the region's reference count.
+    ///
+    /// When all chunks from a region are released, the header is saved (if dirty)
+    /// and the file handle is closed.
+    ///
+    /// This must be called for each chunk returned by `load_chunk`.
+    pub async fn release_chunk(&self, pos: ChunkPos) -> io::Result<()> {
+        self.terrain.release(pos).await?;
+        self.entities.release(pos).await
+    }
+
+    /// Checks if a chunk exists on disk without loading it.
+    pub async fn chunk_exists(&self, pos: ChunkPos) -> io::Result<bool> {
+        self.terrain.exists(pos).await
+    }
+
+    /// Flushes all dirty headers to disk.
+    pub async fn flush_all(&self) -> io::Result<()> {
+        self.terrain.flush_all().await?;
+        self.entities.flush_all().await
+    }
+
+    /// Flushes all dirty headers and closes all region file handles.
+    ///
+    /// This should be called during graceful shutdown after all chunks have been saved.
+    /// It ensures all data is persisted and file handles are properly closed.
+    pub async fn close_all(&self) -> io::Result<()> {
+        self.terrain.close_all().await?;
+        self.entities.close_all().await
+    }
+}
+
+impl RegionFiles {
+    fn new(base_path: PathBuf) -> Self {
         Self {
-            base_path: base_path.into(),
+            base_path,
             regions: AsyncRwLock::new(FxHashMap::default()),
         }
     }
@@ -225,40 +412,12 @@ impl RegionManager {
         Ok(())
     }
 
-    /// Saves prepared chunk data to disk. This is the async part that doesn't
-    /// need to hold the chunk lock.
-    #[expect(
-        clippy::missing_panics_doc,
-        reason = "panic on `just inserted` is unreachable"
-    )]
-    pub async fn save_chunk_data(
-        &self,
-        prepared: PreparedChunkSave,
-        status: ChunkStatus,
-    ) -> io::Result<bool> {
-        let pos = prepared.pos;
+    /// Writes compressed chunk data into its region, opening the region if needed.
+    async fn write(&self, pos: ChunkPos, compressed: &[u8], status: ChunkStatus) -> io::Result<()> {
         let region_pos = RegionPos::from_chunk(pos.0.x, pos.0.y);
         let (local_x, local_z) = RegionPos::local_chunk_pos(pos.0.x, pos.0.y);
         let index = RegionHeader::chunk_index(local_x, local_z);
 
-        // Serialize the prepared data
-        let data = wincode::serialize(&prepared.persistent)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
-
-        // Compress with zstd
-        let compressed = zstd::encode_all(&data[..], 3)?;
-
-        if compressed.len() > MAX_CHUNK_SIZE {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!(
-                    "Chunk too large: {} bytes (max {})",
-                    compressed.len(),
-                    MAX_CHUNK_SIZE
-                ),
-            ));
-        }
-
         let mut regions = self.regions.write().await;
 
         // Track if we opened the region (so we can close it after)
@@ -290,7 +449,7 @@ impl RegionManager {
         Self::write_chunk_data(
             &mut handle.file,
             sector_offset,
-            &compressed,
+            compressed,
             &mut handle.file_sectors,
         )
         .await?;
@@ -308,85 +467,67 @@ impl RegionManager {
             handle.header_dirty = true;
         }
 
-        Ok(true)
+        Ok(())
     }
 
-    /// Loads a chunk from the appropriate region.
-    ///
-    /// Automatically opens the region if not already open. The region's reference
-    /// count is incremented, so you must call `release_chunk` when done with the chunk.
-    ///
-    /// Returns `Ok(None)` if the chunk doesn't exist on disk.
-    ///
-    /// # Arguments
-    /// * `pos` - The chunk position
-    /// * `min_y` - The minimum Y coordinate of the world
-    /// * `height` - The total height of the world
-    /// * `level` - Weak reference to the world for `LevelChunk`
+    /// Drops a chunk's entry so its sectors can be reused.
     ///
-    /// The region must already be acquired via `acquire_chunk` before calling this.
-    pub async fn load_chunk(
-        &self,
-        pos: ChunkPos,
-        min_y: i32,
-        height: i32,
-        level: Weak<World>,
-    ) -> io::Result<Option<LoadedChunk>> {
+    /// Does nothing, and creates no file, if the chunk has no entry.
+    async fn clear(&self, pos: ChunkPos) -> io::Result<()> {
         let region_pos = RegionPos::from_chunk(pos.0.x, pos.0.y);
         let (local_x, local_z) = RegionPos::local_chunk_pos(pos.0.x, pos.0.y);
         let index = RegionHeader::chunk_index(local_x, local_z);
 
-        let (compressed, status) = {
-            let mut regions = self.regions.write().await;
+        let mut regions = self.regions.write().await;
+        if let Some(handle) = regions.get_mut(&region_pos) {
+            if handle.header.entries[index].exists() {
+                handle.header.entries[index] = super::format::ChunkEntry::empty();
+                handle.header_dirty = true;
+            }
+            return Ok(());
+        }
+
+        if !self.region_path(region_pos).exists() {
+            return Ok(());
+        }
+        let mut handle = self.open_region(region_pos).await?;
+        if handle.header.entries[index].exists() {
+            handle.header.entries[index] = super::format::ChunkEntry::empty();
+            Self::write_header(&mut handle.file, &handle.header).await?;
+        }
+        Ok(())
+    }
 
-            // Get the region (should already be open via acquire_chunk)
-            let Some(handle) = regions.get_mut(&region_pos) else {
-                log::warn!("load_chunk called without acquire_chunk for region {region_pos:?}");
-                return Ok(None);
-            };
+    /// Reads a chunk's compressed data and saved status from an acquired region.
+    ///
+    /// Returns `Ok(None)` if the chunk has no entry.
+    async fn read(&self, pos: ChunkPos) -> io::Result<Option<(Vec<u8>, ChunkStatus)>> {
+        let region_pos = RegionPos::from_chunk(pos.0.x, pos.0.y);
+        let (local_x, local_z) = RegionPos::local_chunk_pos(pos.0.x, pos.0.y);
+        let index = RegionHeader::chunk_index(local_x, local_z);
 
-            // Check if chunk exists
-            let entry = handle.header.entries[index];
-            if !entry.exists() {
-                return Ok(None);
-            }
+        let mut regions = self.regions.write().await;
 
-            // Read chunk data from disk
-            let compressed =
-                Self::read_chunk_data(&mut handle.file, entry.sector_offset, entry.size_bytes)
-                    .await?;
-            (compressed, entry.status)
+        // Get the region (should already be open via acquire_chunk)
+        let Some(handle) = regions.get_mut(&region_pos) else {
+            log::warn!("load_chunk called without acquire_chunk for region {region_pos:?}");
+            return Ok(None);
         };
 
-        // Decompress
-        let data = zstd::decode_all(&compressed[..])?;
-
-        // Deserialize
-        let persistent: PersistentChunk = wincode::deserialize(&data)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        // Check if chunk exists
+        let entry = handle.header.entries[index];
+        if !entry.exists() {
+            return Ok(None);
+        }
 
-        // Convert to runtime format (persistent is dropped after this - no duplication!)
-        Ok(Some(ChunkStorage::persistent_to_chunk(
-            &persistent,
-            pos,
-            status,
-            min_y,
-            height,
-            level,
-        )))
+        // Read chunk data from disk
+        let compressed =
+            Self::read_chunk_data(&mut handle.file, entry.sector_offset, entry.size_bytes).await?;
+        Ok(Some((compressed, entry.status)))
     }
 
-    /// Acquires a chunk, incrementing the region's reference count.
-    ///
-    /// This opens or creates the region file. Call this before loading or
-    /// generating a chunk, and call `release_chunk` when done with the chunk.
-    ///
-    /// Returns `Ok(true)` if the chunk exists on disk, `Ok(false)` if it doesn't.
-    #[expect(
-        clippy::missing_panics_doc,
-        reason = "panic on `just inserted` is unreachable"
-    )]
-    pub async fn acquire_chunk(&self, pos: ChunkPos) -> io::Result<bool> {
+    /// Opens the chunk's region and increments its reference count.
+    async fn acquire(&self, pos: ChunkPos) -> io::Result<bool> {
         let region_pos = RegionPos::from_chunk(pos.0.x, pos.0.y);
         let (local_x, local_z) = RegionPos::local_chunk_pos(pos.0.x, pos.0.y);
         let index = RegionHeader::chunk_index(local_x, local_z);
@@ -412,13 +553,8 @@ impl RegionManager {
         Ok(exists)
     }
 
-    /// Releases a loaded chunk, decrementing the region's reference count.
-    ///
-    /// When all chunks from a region are released, the header is saved (if dirty)
-    /// and the file handle is closed.
-    ///
-    /// This must be called for each chunk returned by `load_chunk`.
-    pub async fn release_chunk(&self, pos: ChunkPos) -> io::Result<()> {
+    /// Decrements the region's reference count, closing it once unused.
+    async fn release(&self, pos: ChunkPos) -> io::Result<()> {
         let region_pos = RegionPos::from_chunk(pos.0.x, pos.0.y);
 
         let mut regions = self.regions.write().await;
@@ -440,8 +576,8 @@ impl RegionManager {
         Ok(())
     }
 
-    /// Checks if a chunk exists on disk without loading it.
-    pub async fn chunk_exists(&self, pos: ChunkPos) -> io::Result<bool> {
+    /// Checks if a chunk has an entry without loading it.
+    async fn exists(&self, pos: ChunkPos) -> io::Result<bool> {
         let region_pos = RegionPos::from_chunk(pos.0.x, pos.0.y);
         let (local_x, local_z) = RegionPos::local_chunk_pos(pos.0.x, pos.0.y);
         let index = RegionHeader::chunk_index(local_x, local_z);
@@ -477,7 +613,7 @@ impl RegionManager {
     }
 
     /// Flushes all dirty headers to disk.
-    pub async fn flush_all(&self) -> io::Result<()> {
+    async fn flush_all(&self) -> io::Result<()> {
         let mut regions = self.regions.write().await;
 
         for handle in regions.values_mut() {
@@ -491,10 +627,7 @@ impl RegionManager {
     }
 
     /// Flushes all dirty headers and closes all region file handles.
-    ///
-    /// This should be called during graceful shutdown after all chunks have been saved.
-    /// It ensures all data is persisted and file handles are properly closed.
-    pub async fn close_all(&self) -> io::Result<()> {
+    async fn close_all(&self) -> io::Result<()> {
         let mut regions = self.regions.write().await;
 
         for (_, mut handle) in regions.drain() {
@@ -507,3 +640,57 @@ impl RegionManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env::temp_dir,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use super::*;
+
+    fn test_region_root(name: &str) -> PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos());
+        temp_dir().join(format!("steel-regions-{name}-{unique}"))
+    }
+
+    #[test]
+    fn entity_regions_sit_beside_terrain_regions() {
+        let manager = RegionManager::new(PathBuf::from("world").join("region"));
+        assert_eq!(
+            manager.entities.base_path,
+            PathBuf::from("world").join("entities")
+        );
+    }
+
+    #[tokio::test]
+    async fn cleared_entries_stop_reading_back() {
+        let root = test_region_root("clear");
+        let files = RegionFiles::new(root.clone());
+        let pos = ChunkPos::new(-1, 33);
+
+        files
+            .clear(pos)
+            .await
+            .expect("clearing a missing region should be a no-op");
+        assert!(!root.exists());
+
+        assert!(!files.acquire(pos).await.expect("region should open"));
+        files
+            .write(pos, b"entities", ChunkStatus::Full)
+            .await
+            .expect("entry should write");
+        let read = files.read(pos).await.expect("entry should read");
+        assert_eq!(read.map(|(data, _)| data), Some(b"entities".to_vec()));
+
+        files.clear(pos).await.expect("entry should clear");
+        assert!(files.read(pos).await.expect("region should read").is_none());
+        files.release(pos).await.expect("region should close");
+        assert!(!files.exists(pos).await.expect("header should read"));
+
+        let _ = fs::remove_dir_all(root).await;
+    }
+}
@@ -0,0 +1,117 @@
+//! Back-pressure for chunk saves.
+//!
+//! Every save holds a copy of its chunk's palettes until it is written, so a
+//! burst of unloads could otherwise snapshot thousands of chunks at once. Saves
+//! wait here for a slot before taking their snapshot.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// Most chunk saves that may be snapshotted or writing at once.
+pub const MAX_IN_FLIGHT_CHUNK_SAVES: usize = 64;
+
+/// Limits concurrent chunk saves and counts the ones waiting.
+pub struct ChunkSaveQueue {
+    slots: Semaphore,
+    queued: AtomicUsize,
+    in_flight: AtomicUsize,
+}
+
+/// How many chunk saves are waiting and running.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChunkSaveQueueDepth {
+    /// Saves waiting for a slot.
+    pub queued: usize,
+    /// Saves holding a slot.
+    pub in_flight: usize,
+}
+
+impl ChunkSaveQueue {
+    /// Creates a queue that lets `max_in_flight` saves run at once.
+    #[must_use]
+    pub fn new(max_in_flight: usize) -> Self {
+        Self {
+            slots: Semaphore::new(max_in_flight.max(1)),
+            queued: AtomicUsize::new(0),
+            in_flight: AtomicUsize::new(0),
+        }
+    }
+
+    /// Waits for a save slot, which is released when the permit drops.
+    pub async fn acquire(&self) -> ChunkSavePermit<'_> {
+        self.queued.fetch_add(1, Ordering::Relaxed);
+        let queued = CountGuard(&self.queued);
+        // The semaphore is never closed, so this only fails if that changes.
+        let slot = self.slots.acquire().await.ok();
+        drop(queued);
+        self.in_flight.fetch_add(1, Ordering::Relaxed);
+        ChunkSavePermit {
+            _slot: slot,
+            _in_flight: CountGuard(&self.in_flight),
+        }
+    }
+
+    /// Returns the current queue depth.
+    #[must_use]
+    pub fn depth(&self) -> ChunkSaveQueueDepth {
+        ChunkSaveQueueDepth {
+            queued: self.queued.load(Ordering::Relaxed),
+            in_flight: self.in_flight.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Default for ChunkSaveQueue {
+    fn default() -> Self {
+        Self::new(MAX_IN_FLIGHT_CHUNK_SAVES)
+    }
+}
+
+/// A held chunk save slot.
+pub struct ChunkSavePermit<'a> {
+    _slot: Option<SemaphorePermit<'a>>,
+    _in_flight: CountGuard<'a>,
+}
+
+/// Decrements a counter when dropped, so cancelled saves are not counted.
+struct CountGuard<'a>(&'a AtomicUsize);
+
+impl Drop for CountGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn saves_past_the_limit_wait_and_are_counted() {
+        let queue = ChunkSaveQueue::new(1);
+        let first = queue.acquire().await;
+
+        let mut second = Box::pin(queue.acquire());
+        assert!(futures::poll!(second.as_mut()).is_pending());
+        assert_eq!(
+            queue.depth(),
+            ChunkSaveQueueDepth {
+                queued: 1,
+                in_flight: 1
+            }
+        );
+
+        drop(first);
+        let second = second.await;
+        assert_eq!(
+            queue.depth(),
+            ChunkSaveQueueDepth {
+                queued: 0,
+                in_flight: 1
+            }
+        );
+        drop(second);
+        assert_eq!(queue.depth(), ChunkSaveQueueDepth::default());
+    }
+}
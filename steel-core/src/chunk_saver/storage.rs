@@ -7,7 +7,7 @@ use crate::chunk::light::{
 };
 use crate::chunk::paletted_container::PalettedContainer;
 use crate::chunk::proto_chunk::ProtoChunk;
-use crate::chunk::section::{ChunkSection, SectionHolder, Sections};
+use crate::chunk::section::{ChunkSection, Sections};
 use crate::chunk_saver::bit_pack::{bits_for_palette_len, pack_indices, unpack_indices};
 use crate::entity::{
     ENTITIES, Entity, EntityBase, EntityBaseSaveData, EntityFireFreezeState, EntityLoadRequest,
@@ -25,7 +25,7 @@ use std::io::Cursor;
 use std::sync::atomic::Ordering;
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::{
-    io,
+    io, mem,
     sync::{Arc, Weak},
 };
 use steel_registry::structure::{
@@ -368,7 +368,7 @@ use super::{
     PersistentStructurePiece, PersistentStructurePiecePayload, PersistentStructureReference,
     PersistentStructureStart, PersistentSwampHutPieceData, PersistentTemplatePieceData,
     PersistentTemplatePlacementAdjustment, PersistentTemplateProcessorList, PersistentTick,
-    PreparedChunkSave,
+    PreparedChunkSave, SectionSnapshot,
 };
 
 /// Builder for creating a persistent chunk with its own palettes.
@@ -433,6 +433,27 @@ impl<'a> ChunkBuilder<'a> {
     }
 }
 
+impl PreparedChunkSave {
+    /// Bit-packs the section snapshots into `persistent`, building the chunk's
+    /// block state and biome palettes.
+    ///
+    /// This is the expensive part of a save, so storage backends call it off
+    /// the tick thread. Calling it again is a no-op.
+    pub fn pack_sections(&mut self) {
+        let sections = mem::take(&mut self.sections);
+        if sections.is_empty() {
+            return;
+        }
+        let mut builder = ChunkBuilder::new(&REGISTRY);
+        self.persistent.sections = sections
+            .iter()
+            .map(|section| ChunkStorage::section_to_persistent(section, &mut builder))
+            .collect();
+        self.persistent.block_states = builder.block_states;
+        self.persistent.biomes = builder.biomes;
+    }
+}
+
 /// Chunk storage backend.
 ///
 /// This enum provides persistence for chunks, either to disk (region files)
@@ -667,8 +688,22 @@ impl ChunkStorage {
             ChunkAccess::Unloaded => unreachable!(),
         };
 
+        // Copy the palettes now and leave bit-packing to `pack_sections`, which
+        // runs on the chunk runtime after the chunk lock is released.
+        let sections = chunk
+            .sections()
+            .sections
+            .iter()
+            .map(|section| {
+                let section = section.read();
+                SectionSnapshot {
+                    states: section.states.clone(),
+                    biomes: section.biomes.clone(),
+                }
+            })
+            .collect();
+
         let persistent = Self::to_persistent(
-            chunk.sections(),
             &block_entities,
             &entities,
             block_ticks,
@@ -686,6 +721,7 @@ impl ChunkStorage {
         Some(PreparedChunkSave {
             pos,
             persistent,
+            sections,
             handled_runtime_entity_ids,
         })
     }
@@ -722,7 +758,6 @@ impl ChunkStorage {
         reason = "chunk serialization requires all fields; `block_ticks`/`fluid_ticks` are distinct"
     )]
     fn to_persistent(
-        sections: &Sections,
         block_entities: &[SharedBlockEntity],
         entities: &[SharedEntity],
         block_ticks: Vec<PersistentTick>,
@@ -736,14 +771,6 @@ impl ChunkStorage {
         pois: Vec<PersistentPoi>,
         chunk_pos: ChunkPos,
     ) -> PersistentChunk {
-        let mut builder = ChunkBuilder::new(&REGISTRY);
-
-        let persistent_sections = sections
-            .sections
-            .iter()
-            .map(|section| Self::section_to_persistent(section, &mut builder))
-            .collect();
-
         // Serialize block entities
         let persistent_block_entities: Vec<PersistentBlockEntity> = block_entities
             .iter()
@@ -773,9 +800,9 @@ impl ChunkStorage {
             last_modified: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .map_or(0, |d| d.as_secs() as u32),
-            block_states: builder.block_states,
-            biomes: builder.biomes,
-            sections: persistent_sections,
+            block_states: Vec::new(),
+            biomes: Vec::new(),
+            sections: Vec::new(),
             block_entities: persistent_block_entities,
             entities: persistent_entities,
             block_ticks,
@@ -1167,10 +1194,9 @@ impl ChunkStorage {
 
     /// Converts a runtime section to persistent format.
     fn section_to_persistent(
-        section: &SectionHolder,
+        section: &SectionSnapshot,
         builder: &mut ChunkBuilder,
     ) -> PersistentSection {
-        let section = section.read();
         let biomes = Self::biomes_to_persistent(&section.biomes, builder);
 
         match &section.states {
@@ -2968,6 +2994,17 @@ mod tests {
         }
     }
 
+    /// Prepares a save with its sections packed, as storage backends see it.
+    fn prepare_packed(
+        chunk: &ChunkAccess,
+        runtime_entities: &[SharedEntity],
+        force: bool,
+    ) -> Option<PreparedChunkSave> {
+        let mut prepared = ChunkStorage::prepare_chunk_save(chunk, runtime_entities, force)?;
+        prepared.pack_sections();
+        Some(prepared)
+    }
+
     fn single_empty_section() -> Sections {
         Sections::from_owned(vec![ChunkSection::new_empty()].into_boxed_slice())
     }
@@ -3117,7 +3154,7 @@ mod tests {
         assert!(chunk.take_dirty());
         chunk.mark_dirty();
 
-        let Some(_prepared) = ChunkStorage::prepare_chunk_save(&chunk, &[], true) else {
+        let Some(_prepared) = prepare_packed(&chunk, &[], true) else {
             panic!("forced save prep should serialize the chunk");
         };
         assert!(chunk.is_dirty());
@@ -3162,7 +3199,7 @@ mod tests {
         drop(proto.get_or_create_carving_mask());
         let chunk = ChunkAccess::Proto(proto);
 
-        let Some(prepared) = ChunkStorage::prepare_chunk_save(&chunk, &[], false) else {
+        let Some(prepared) = prepare_packed(&chunk, &[], false) else {
             panic!("dirty proto chunk should prepare for saving");
         };
         assert_eq!(prepared.persistent.carving_mask, Some(Vec::new()));
@@ -3195,7 +3232,7 @@ mod tests {
         }
         let chunk = ChunkAccess::Proto(proto);
 
-        let Some(prepared) = ChunkStorage::prepare_chunk_save(&chunk, &[], false) else {
+        let Some(prepared) = prepare_packed(&chunk, &[], false) else {
             panic!("dirty proto chunk should prepare for saving");
         };
         assert!(
@@ -3238,7 +3275,7 @@ mod tests {
         let packed = ProtoChunk::pack_postprocessing_offset(marked);
         let chunk = ChunkAccess::Proto(proto);
 
-        let Some(prepared) = ChunkStorage::prepare_chunk_save(&chunk, &[], false) else {
+        let Some(prepared) = prepare_packed(&chunk, &[], false) else {
             panic!("dirty proto chunk should prepare for saving");
         };
 
@@ -3315,7 +3352,7 @@ mod tests {
         assert!(proto.get_block_entity(block_pos).is_some());
 
         let chunk = ChunkAccess::Proto(proto);
-        let Some(prepared) = ChunkStorage::prepare_chunk_save(&chunk, &[], false) else {
+        let Some(prepared) = prepare_packed(&chunk, &[], false) else {
             panic!("dirty proto chunk should prepare for saving");
         };
         assert_eq!(prepared.persistent.block_entities.len(), 1);
@@ -3367,7 +3404,7 @@ mod tests {
         proto.add_entity(crystal);
 
         let chunk = ChunkAccess::Proto(proto);
-        let Some(prepared) = ChunkStorage::prepare_chunk_save(&chunk, &[], false) else {
+        let Some(prepared) = prepare_packed(&chunk, &[], false) else {
             panic!("dirty proto chunk should prepare for saving");
         };
         assert_eq!(prepared.persistent.entities.len(), 1);
@@ -3451,9 +3488,7 @@ mod tests {
             Weak::new(),
         ));
 
-        let Some(prepared) =
-            ChunkStorage::prepare_chunk_save(&chunk, slice::from_ref(&entity), true)
-        else {
+        let Some(prepared) = prepare_packed(&chunk, slice::from_ref(&entity), true) else {
             panic!("forced runtime entity save should prepare a chunk save");
         };
 
@@ -3475,9 +3510,7 @@ mod tests {
             Weak::new(),
         ));
 
-        let Some(prepared) =
-            ChunkStorage::prepare_chunk_save(&chunk, slice::from_ref(&entity), true)
-        else {
+        let Some(prepared) = prepare_packed(&chunk, slice::from_ref(&entity), true) else {
             panic!("forced runtime entity save should prepare a chunk save");
         };
 
@@ -3520,7 +3553,7 @@ mod tests {
         let passenger_uuid = passenger.uuid();
         let entities = [Arc::clone(&vehicle), Arc::clone(&passenger)];
 
-        let Some(prepared) = ChunkStorage::prepare_chunk_save(&chunk, &entities, true) else {
+        let Some(prepared) = prepare_packed(&chunk, &entities, true) else {
             panic!("forced runtime entity save should prepare a chunk save");
         };
 
@@ -3582,9 +3615,7 @@ mod tests {
         EntityBase::restore_passenger_relationship(&vehicle, &passenger);
         let vehicle_uuid = vehicle.uuid();
 
-        let Some(prepared) =
-            ChunkStorage::prepare_chunk_save(&chunk, slice::from_ref(&vehicle), true)
-        else {
+        let Some(prepared) = prepare_packed(&chunk, slice::from_ref(&vehicle), true) else {
             panic!("forced runtime entity save should prepare a chunk save");
         };
 
@@ -3634,7 +3665,7 @@ mod tests {
         proto.add_and_register_block_entity(entity);
 
         let chunk = ChunkAccess::Proto(proto);
-        let Some(prepared) = ChunkStorage::prepare_chunk_save(&chunk, &[], false) else {
+        let Some(prepared) = prepare_packed(&chunk, &[], false) else {
             panic!("dirty proto chunk should prepare for saving");
         };
         assert_eq!(prepared.persistent.block_entities.len(), 1);
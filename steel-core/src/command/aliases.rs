@@ -0,0 +1,160 @@
+//! Command aliases and macros configured in `commands.toml`.
+//!
+//! Every alias becomes a root literal that runs its configured commands as the
+//! invoking source. The dispatcher cannot drop nodes, so aliases removed by a
+//! reload stay in the graph but are hidden and refuse to run.
+
+use std::sync::Arc;
+
+use text_components::TextComponent;
+
+use super::{
+    brigadier::{ArgumentType, CommandNodeBuilder, CommandRequirement},
+    execution::{
+        ChainModifiers, CommandPermissionSource, CommandSource, CustomCommandExecutor,
+        ExecutionCommandSource, ExecutionControl, SteelCommandRuntime, SteelContextChain, argument,
+        literal,
+    },
+};
+use crate::permission::{PermissionExpr, PermissionKey, PermissionKeyError, PermissionState};
+
+/// Builds the root node for alias `name`.
+///
+/// Sources may use every alias unless `steel.alias.<name>` is denied to them.
+pub(crate) fn alias_command(
+    name: &str,
+) -> Result<CommandNodeBuilder<CommandSource, SteelCommandRuntime>, PermissionKeyError> {
+    let name: Arc<str> = Arc::from(name);
+    let permission = PermissionExpr::key(PermissionKey::parse(format!("steel.alias.{name}"))?);
+    let configured = Arc::clone(&name);
+    Ok(literal(&*name)
+        .requires(CommandRequirement::authorization(
+            move |source: &CommandSource| {
+                source.permission_state(&permission) != Some(PermissionState::Deny)
+            },
+        ))
+        .also_requires(CommandRequirement::contextual(
+            move |source: &CommandSource| {
+                source
+                    .server()
+                    .config
+                    .load()
+                    .command_aliases
+                    .aliases
+                    .contains_key(&*configured)
+            },
+        ))
+        .executes_custom(RunAlias {
+            name: Arc::clone(&name),
+        })
+        .then(
+            argument("arguments", ArgumentType::greedy_string()).executes_custom(RunAlias { name }),
+        ))
+}
+
+struct RunAlias {
+    name: Arc<str>,
+}
+
+impl CustomCommandExecutor<CommandSource> for RunAlias {
+    fn run(
+        &self,
+        source: Arc<CommandSource>,
+        chain: &SteelContextChain<CommandSource>,
+        _modifiers: ChainModifiers,
+        control: &mut ExecutionControl<'_, CommandSource>,
+    ) {
+        let arguments = chain.top_context().string("arguments").unwrap_or_default();
+        let server = Arc::clone(source.server());
+        let config = server.config.load();
+        let Some(alias) = config.command_aliases.aliases.get(&*self.name) else {
+            source.send_failure(TextComponent::plain(format!(
+                "Alias '{}' is no longer configured",
+                self.name
+            )));
+            control.return_failure();
+            return;
+        };
+
+        // Parse every command first so a typo doesn't leave a macro half run.
+        let mut chains = Vec::with_capacity(alias.commands().len());
+        for command in alias.commands() {
+            let command = match substitute_arguments(command, arguments) {
+                Ok(command) => command,
+                Err(error) => {
+                    source.send_failure(TextComponent::plain(error));
+                    control.return_failure();
+                    return;
+                }
+            };
+            match server.parse_command(&command, (*source).clone()) {
+                Ok(chain) => chains.push(chain),
+                Err(error) => {
+                    source.handle_error(&error, false);
+                    control.return_failure();
+                    return;
+                }
+            }
+        }
+        for chain in chains {
+            control.queue_contexts(
+                chain,
+                Arc::clone(&source),
+                vec![Arc::clone(&source)],
+                ChainModifiers::default(),
+            );
+        }
+    }
+}
+
+/// Replaces `$1` to `$9` in `command` with the words of `arguments` and `$*`
+/// with all of them. A leading `/` is dropped.
+fn substitute_arguments(command: &str, arguments: &str) -> Result<String, String> {
+    let words: Vec<&str> = arguments.split_whitespace().collect();
+    let command = command.trim().trim_start_matches('/');
+    let mut substituted = String::with_capacity(command.len() + arguments.len());
+    let mut chars = command.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            substituted.push(c);
+            continue;
+        }
+        match chars.peek().copied() {
+            Some('*') => {
+                chars.next();
+                substituted.push_str(arguments.trim());
+            }
+            Some(digit @ '1'..='9') => {
+                chars.next();
+                let index = digit as usize - '1' as usize;
+                let Some(word) = words.get(index) else {
+                    return Err(format!("Missing argument ${digit}"));
+                };
+                substituted.push_str(word);
+            }
+            _ => substituted.push(c),
+        }
+    }
+    Ok(substituted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::substitute_arguments;
+
+    #[test]
+    fn arguments_replace_placeholders() {
+        assert_eq!(
+            substitute_arguments("/tp $1 0 70 0", "Alex"),
+            Ok("tp Alex 0 70 0".to_owned())
+        );
+        assert_eq!(
+            substitute_arguments("say $2 then $* costs $5", "a b"),
+            Err("Missing argument $5".to_owned())
+        );
+        assert_eq!(
+            substitute_arguments("say $* for $$", " hello  world "),
+            Ok("say hello  world for $$".to_owned())
+        );
+    }
+}
@@ -168,6 +168,92 @@ where
         self.node(id).map(|node| node.children.as_slice())
     }
 
+    /// Returns a short usage for each child of `node` that `source` can use.
+    ///
+    /// Brigadier `CommandDispatcher.getSmartUsage`.
+    pub(crate) fn smart_usage(&self, node: NodeId, source: &S) -> Vec<(NodeId, String)> {
+        self.children(node)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|child| {
+                self.smart_usage_of(*child, source, false, false)
+                    .map(|usage| (*child, usage))
+            })
+            .collect()
+    }
+
+    fn smart_usage_of(&self, id: NodeId, source: &S, optional: bool, deep: bool) -> Option<String> {
+        let node = self.node(id)?;
+        if !node.allows(source) {
+            return None;
+        }
+        let usage = if optional {
+            format!("[{}]", node.usage_text())
+        } else {
+            node.usage_text()
+        };
+        if deep {
+            return Some(usage);
+        }
+        if let Some(redirect) = node.redirect() {
+            let target = if redirect == self.root() {
+                "...".to_owned()
+            } else {
+                let target = self.node(redirect)?;
+                format!("-> {}", target.usage_text())
+            };
+            return Some(format!("{usage} {target}"));
+        }
+
+        let child_optional = node.is_executable();
+        let children: Vec<NodeId> = node
+            .children
+            .iter()
+            .copied()
+            .filter(|child| self.node(*child).is_some_and(|child| child.allows(source)))
+            .collect();
+        match children.as_slice() {
+            [] => {}
+            [child] => {
+                if let Some(child) =
+                    self.smart_usage_of(*child, source, child_optional, child_optional)
+                {
+                    return Some(format!("{usage} {child}"));
+                }
+            }
+            children => {
+                let mut child_usages: Vec<String> = Vec::new();
+                for child in children {
+                    if let Some(child) = self.smart_usage_of(*child, source, child_optional, true)
+                        && !child_usages.contains(&child)
+                    {
+                        child_usages.push(child);
+                    }
+                }
+                if let [child] = child_usages.as_slice() {
+                    return Some(if child_optional {
+                        format!("{usage} [{child}]")
+                    } else {
+                        format!("{usage} {child}")
+                    });
+                }
+                if child_usages.len() > 1 {
+                    let (open, close) = if child_optional {
+                        ('[', ']')
+                    } else {
+                        ('(', ')')
+                    };
+                    let alternatives: Vec<String> = children
+                        .iter()
+                        .filter_map(|child| self.node(*child).map(CommandNode::usage_text))
+                        .collect();
+                    return Some(format!("{usage} {open}{}{close}", alternatives.join("|")));
+                }
+            }
+        }
+        Some(usage)
+    }
+
     /// Returns the number of allocated nodes, including the root.
     pub(crate) const fn node_count(&self) -> usize {
         self.nodes.len()
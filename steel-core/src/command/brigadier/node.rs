@@ -308,6 +308,16 @@ where
         self.data.name()
     }
 
+    /// Returns how this node appears in usages: literals by name, arguments
+    /// as `<name>`.
+    pub(crate) fn usage_text(&self) -> String {
+        match &self.data {
+            CommandNodeData::Root => String::new(),
+            CommandNodeData::Literal(name) => name.to_string(),
+            CommandNodeData::Argument { name, .. } => format!("<{name}>"),
+        }
+    }
+
     /// Returns whether this node has a command callback.
     pub(crate) const fn is_executable(&self) -> bool {
         self.executor.is_some()
@@ -331,3 +331,42 @@ fn command_callbacks_receive_the_generic_source_context() {
     );
     assert!(observed.load(Ordering::Relaxed));
 }
+
+#[test]
+fn smart_usage_summarizes_children_the_source_can_use() {
+    let mut dispatcher = CommandDispatcher::<TestSource>::new();
+    let root = dispatcher.root();
+    let optional = register(
+        &mut dispatcher,
+        literal("optional")
+            .executes(|_| Ok(1))
+            .then(argument("value", ArgumentType::bool()).executes(|_| Ok(1))),
+    );
+    let choice = register(
+        &mut dispatcher,
+        literal("choice")
+            .then(literal("first").executes(|_| Ok(1)))
+            .then(literal("second").executes(|_| Ok(1)))
+            .then(
+                literal("hidden")
+                    .requires(CommandRequirement::contextual(|source: &TestSource| {
+                        source.allowed
+                    }))
+                    .executes(|_| Ok(1)),
+            ),
+    );
+
+    assert_eq!(
+        dispatcher.smart_usage(root, &TestSource { allowed: false }),
+        [
+            (optional, "optional [<value>]".to_owned()),
+            (choice, "choice (first|second)".to_owned()),
+        ]
+    );
+    let usages: Vec<String> = dispatcher
+        .smart_usage(choice, &TestSource { allowed: true })
+        .into_iter()
+        .map(|(_, usage)| usage)
+        .collect();
+    assert_eq!(usages, ["first", "second", "hidden"]);
+}
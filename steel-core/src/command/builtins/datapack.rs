@@ -0,0 +1,183 @@
+use steel_utils::{Identifier, translations};
+use text_components::{Modifier as _, TextComponent, format::Color, interactivity::HoverEvent};
+
+use crate::server::datapacks::{DataPackError, DataPackInfo};
+
+use super::super::{
+    brigadier::{ArgumentType, CommandNodeBuilder, CommandSyntaxError},
+    execution::{CommandSource, SteelCommandContext, SteelCommandRuntime, argument, literal},
+    registration::CommandRegistration,
+};
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::vanilla_static("datapack"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("datapack")
+        .then(
+            literal("enable").then(argument("name", ArgumentType::string()).executes(enable_pack)),
+        )
+        .then(
+            literal("disable")
+                .then(argument("name", ArgumentType::string()).executes(disable_pack)),
+        )
+        .then(
+            literal("list")
+                .executes(list_all_packs)
+                .then(literal("available").executes(list_available_packs))
+                .then(literal("enabled").executes(list_enabled_packs)),
+        )
+}
+
+fn enable_pack(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let name = pack_name(context)?;
+    let server = context.source().server();
+    server
+        .datapacks
+        .enable(name)
+        .map_err(|error| selection_error(error, name))?;
+    let message = translations::COMMANDS_DATAPACK_MODIFY_ENABLE
+        .message([pack_link(context, name)])
+        .component();
+    context.source().send_success(&message, true);
+    reload(context)
+}
+
+fn disable_pack(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let name = pack_name(context)?;
+    let message = translations::COMMANDS_DATAPACK_MODIFY_DISABLE
+        .message([pack_link(context, name)])
+        .component();
+    let server = context.source().server();
+    server
+        .datapacks
+        .disable(name)
+        .map_err(|error| selection_error(error, name))?;
+    context.source().send_success(&message, true);
+    reload(context)
+}
+
+fn reload(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let server = context.source().server();
+    if let Err(error) = server.reload_datapacks() {
+        log::error!("Failed to reload data packs: {error}");
+        return Err(CommandSyntaxError::dynamic(TextComponent::from(
+            &translations::COMMANDS_RELOAD_FAILURE,
+        )));
+    }
+    Ok(server.datapacks.enabled().len() as i32)
+}
+
+#[expect(
+    clippy::unnecessary_wraps,
+    reason = "Command executors use a shared fallible callback signature."
+)]
+fn list_all_packs(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    Ok(send_available(context) + send_enabled(context))
+}
+
+#[expect(
+    clippy::unnecessary_wraps,
+    reason = "Command executors use a shared fallible callback signature."
+)]
+fn list_available_packs(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<i32, CommandSyntaxError> {
+    Ok(send_available(context))
+}
+
+#[expect(
+    clippy::unnecessary_wraps,
+    reason = "Command executors use a shared fallible callback signature."
+)]
+fn list_enabled_packs(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<i32, CommandSyntaxError> {
+    Ok(send_enabled(context))
+}
+
+fn send_available(context: &SteelCommandContext<CommandSource>) -> i32 {
+    let packs = context.source().server().datapacks.available();
+    let message = if packs.is_empty() {
+        TextComponent::from(&translations::COMMANDS_DATAPACK_LIST_AVAILABLE_NONE)
+    } else {
+        translations::COMMANDS_DATAPACK_LIST_AVAILABLE_SUCCESS
+            .message([
+                TextComponent::plain(packs.len().to_string()),
+                pack_list(&packs),
+            ])
+            .component()
+    };
+    context.source().send_success(&message, false);
+    packs.len() as i32
+}
+
+fn send_enabled(context: &SteelCommandContext<CommandSource>) -> i32 {
+    let packs = context.source().server().datapacks.enabled();
+    let message = if packs.is_empty() {
+        TextComponent::from(&translations::COMMANDS_DATAPACK_LIST_ENABLED_NONE)
+    } else {
+        translations::COMMANDS_DATAPACK_LIST_ENABLED_SUCCESS
+            .message([
+                TextComponent::plain(packs.len().to_string()),
+                pack_list(&packs),
+            ])
+            .component()
+    };
+    context.source().send_success(&message, false);
+    packs.len() as i32
+}
+
+fn pack_name<'a>(
+    context: &'a SteelCommandContext<CommandSource>,
+) -> Result<&'a str, CommandSyntaxError> {
+    context
+        .string("name")
+        .ok_or_else(|| CommandSyntaxError::dynamic("Missing data pack name"))
+}
+
+fn selection_error(error: DataPackError, name: &str) -> CommandSyntaxError {
+    let message = match error {
+        DataPackError::Unknown => &translations::COMMANDS_DATAPACK_UNKNOWN,
+        DataPackError::AlreadyEnabled => &translations::COMMANDS_DATAPACK_ENABLE_FAILED,
+        DataPackError::NotEnabled | DataPackError::Required => {
+            &translations::COMMANDS_DATAPACK_DISABLE_FAILED
+        }
+    };
+    CommandSyntaxError::dynamic(
+        message
+            .message([TextComponent::plain(name.to_owned())])
+            .component(),
+    )
+}
+
+fn pack_link(context: &SteelCommandContext<CommandSource>, name: &str) -> TextComponent {
+    let description = context
+        .source()
+        .server()
+        .datapacks
+        .pack(name)
+        .map(|pack| pack.description);
+    chat_link(name, description)
+}
+
+/// Vanilla's `Pack.getChatLink`: the id in brackets, with the description on hover.
+fn chat_link(id: &str, description: Option<TextComponent>) -> TextComponent {
+    let link = TextComponent::plain(format!("[{id}]")).color(Color::Green);
+    match description {
+        Some(description) => link.hover_event(HoverEvent::show_text(description)),
+        None => link,
+    }
+}
+
+fn pack_list(packs: &[DataPackInfo]) -> TextComponent {
+    let mut list = TextComponent::plain("");
+    for (index, pack) in packs.iter().enumerate() {
+        if index > 0 {
+            list = list.add_child(TextComponent::plain(", "));
+        }
+        list = list.add_child(chat_link(&pack.id, Some(pack.description.clone())));
+    }
+    list
+}
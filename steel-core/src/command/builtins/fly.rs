@@ -1,9 +1,9 @@
 //! Steel player-flight command.
 
-use std::{slice, sync::Arc};
+use std::{iter, slice, sync::Arc};
 
 use steel_utils::{Identifier, translations};
-use text_components::TextComponent;
+use text_components::{TextComponent, translation::TranslatedMessage};
 
 use super::super::{
     brigadier::{ArgumentType, CommandNodeBuilder, CommandSyntaxError},
@@ -17,6 +17,56 @@ use crate::player::{Abilities, DEFAULT_FLYING_SPEED, Player};
 
 const MAX_FLY_SPEED_MULTIPLIER: f32 = 30.0;
 
+/// A feedback translation key and the English text shown when no translation
+/// is loaded for it.
+type Message = (&'static str, &'static str);
+
+/// The feedback naming a single target and the one counting multiple targets.
+type TargetMessages = (Message, Message);
+
+const FLIGHT_ENABLED: TargetMessages = (
+    ("commands.steel.fly.enabled.single", "Enabled flight for %s"),
+    (
+        "commands.steel.fly.enabled.multiple",
+        "Enabled flight for %s players",
+    ),
+);
+
+const FLIGHT_DISABLED: TargetMessages = (
+    (
+        "commands.steel.fly.disabled.single",
+        "Disabled flight for %s",
+    ),
+    (
+        "commands.steel.fly.disabled.multiple",
+        "Disabled flight for %s players",
+    ),
+);
+
+const FLIGHT_TOGGLED: TargetMessages = (
+    ("commands.steel.fly.toggled.single", "Toggled flight for %s"),
+    (
+        "commands.steel.fly.toggled.multiple",
+        "Toggled flight for %s players",
+    ),
+);
+
+const SPEED_SET: TargetMessages = (
+    (
+        "commands.steel.fly.speed.set.single",
+        "Set flying speed for %s to %s",
+    ),
+    (
+        "commands.steel.fly.speed.set.multiple",
+        "Set flying speed for %s players to %s",
+    ),
+);
+
+const SPEED_QUERY: Message = (
+    "commands.steel.fly.speed.query",
+    "Current flying speed for %s: %s",
+);
+
 pub(super) fn registration() -> CommandRegistration<CommandSource> {
     CommandRegistration::new(Identifier::from_steel("fly"), |_| command())
 }
@@ -51,7 +101,7 @@ fn toggle_sender_flight(
     context: &SteelCommandContext<CommandSource>,
 ) -> Result<i32, CommandSyntaxError> {
     let player = source_player(context)?;
-    toggle_flight(slice::from_ref(player));
+    toggle_flight(context.source(), slice::from_ref(player));
     Ok(1)
 }
 
@@ -59,7 +109,7 @@ fn toggle_target_flight(
     context: &SteelCommandContext<CommandSource>,
 ) -> Result<i32, CommandSyntaxError> {
     let targets = context.players("targets")?;
-    toggle_flight(&targets);
+    toggle_flight(context.source(), &targets);
     Ok(1)
 }
 
@@ -70,7 +120,7 @@ fn set_target_flight(
     let Some(allowed) = context.boolean("value") else {
         return Err(missing_argument("value"));
     };
-    set_flight(&targets, allowed);
+    set_flight(context.source(), &targets, allowed);
     Ok(1)
 }
 
@@ -124,24 +174,47 @@ fn required_speed(context: &SteelCommandContext<CommandSource>) -> Result<f32, C
         .ok_or_else(|| missing_argument("speed"))
 }
 
-fn toggle_flight(targets: &[Arc<Player>]) {
+fn toggle_flight(source: &CommandSource, targets: &[Arc<Player>]) {
+    let states: Vec<_> = targets
+        .iter()
+        .map(|target| {
+            let allowed = {
+                let mut abilities = target.abilities.lock();
+                let allowed = !abilities.may_fly;
+                set_flight_allowed(&mut abilities, allowed);
+                allowed
+            };
+            target.send_abilities();
+            allowed
+        })
+        .collect();
+    source.send_success(
+        &target_feedback(toggle_messages(&states), targets, []),
+        true,
+    );
+}
+
+fn set_flight(source: &CommandSource, targets: &[Arc<Player>], allowed: bool) {
     for target in targets {
-        {
-            let mut abilities = target.abilities.lock();
-            let allowed = !abilities.may_fly;
-            set_flight_allowed(&mut abilities, allowed);
-        }
+        set_flight_allowed(&mut target.abilities.lock(), allowed);
         target.send_abilities();
     }
+    let messages = if allowed {
+        FLIGHT_ENABLED
+    } else {
+        FLIGHT_DISABLED
+    };
+    source.send_success(&target_feedback(messages, targets, []), true);
 }
 
-fn set_flight(targets: &[Arc<Player>], allowed: bool) {
-    for target in targets {
-        {
-            let mut abilities = target.abilities.lock();
-            set_flight_allowed(&mut abilities, allowed);
-        }
-        target.send_abilities();
+/// Picks the toggle feedback from the flight states the targets ended up in.
+fn toggle_messages(allowed: &[bool]) -> TargetMessages {
+    if allowed.iter().all(|&allowed| allowed) {
+        FLIGHT_ENABLED
+    } else if allowed.iter().all(|&allowed| !allowed) {
+        FLIGHT_DISABLED
+    } else {
+        FLIGHT_TOGGLED
     }
 }
 
@@ -157,30 +230,75 @@ fn set_flying_speed(source: &CommandSource, targets: &[Arc<Player>], multiplier:
     for target in targets {
         target.set_flying_speed(speed);
         target.send_abilities();
-        source.send_success(
-            &TextComponent::plain(format!(
-                "Set flying speed for player '{}' to {multiplier:.1}x ({speed:.3})",
-                target.gameprofile.name
-            )),
-            true,
-        );
     }
+    source.send_success(
+        &target_feedback(SPEED_SET, targets, [speed_component(speed)]),
+        true,
+    );
 }
 
 fn query_flying_speed(source: &CommandSource, targets: &[Arc<Player>]) {
     for target in targets {
         let speed = target.get_flying_speed();
-        let multiplier = speed / DEFAULT_FLYING_SPEED;
         source.send_success(
-            &TextComponent::plain(format!(
-                "Current flying speed for player '{}': {multiplier:.1}x ({speed:.3})",
-                target.gameprofile.name
-            )),
+            &translated(
+                SPEED_QUERY,
+                [
+                    TextComponent::plain(target.gameprofile.name.clone()),
+                    speed_component(speed),
+                ],
+            ),
             false,
         );
     }
 }
 
+fn speed_component(speed: f32) -> TextComponent {
+    let multiplier = speed / DEFAULT_FLYING_SPEED;
+    TextComponent::plain(format!("{multiplier:.1}x ({speed:.3})"))
+}
+
+fn target_feedback(
+    messages: TargetMessages,
+    targets: &[Arc<Player>],
+    args: impl IntoIterator<Item = TextComponent>,
+) -> TextComponent {
+    let names: Vec<_> = targets
+        .iter()
+        .map(|target| target.gameprofile.name.as_str())
+        .collect();
+    feedback(messages, &names, args)
+}
+
+/// Builds one feedback message for all targets: it names a single target and
+/// counts multiple ones. `args` follow the target in the message.
+fn feedback(
+    (single, multiple): TargetMessages,
+    target_names: &[&str],
+    args: impl IntoIterator<Item = TextComponent>,
+) -> TextComponent {
+    let (message, target) = if let [name] = target_names {
+        (single, TextComponent::plain((*name).to_owned()))
+    } else {
+        (
+            multiple,
+            TextComponent::plain(target_names.len().to_string()),
+        )
+    };
+    translated(message, iter::once(target).chain(args))
+}
+
+fn translated(
+    (key, fallback): Message,
+    args: impl IntoIterator<Item = TextComponent>,
+) -> TextComponent {
+    TextComponent::translated(TranslatedMessage {
+        key: key.into(),
+        args: Some(args.into_iter().collect()),
+        fallback: Some(fallback.into()),
+    })
+}
+
 fn speed_from_multiplier(multiplier: f32) -> f32 {
     multiplier * DEFAULT_FLYING_SPEED
 }
@@ -194,7 +312,10 @@ fn missing_argument(name: &str) -> CommandSyntaxError {
 #[cfg(test)]
 mod tests {
     use super::super::create_dispatcher;
-    use super::{MAX_FLY_SPEED_MULTIPLIER, set_flight_allowed, speed_from_multiplier};
+    use super::{
+        FLIGHT_DISABLED, FLIGHT_ENABLED, FLIGHT_TOGGLED, MAX_FLY_SPEED_MULTIPLIER, SPEED_SET,
+        feedback, set_flight_allowed, speed_from_multiplier, toggle_messages,
+    };
     use crate::{
         command::{
             brigadier::{ArgumentType, CommandDispatcher, NodeId},
@@ -203,6 +324,7 @@ mod tests {
         player::{Abilities, DEFAULT_FLYING_SPEED},
     };
     use steel_registry::test_support::init_test_registry;
+    use text_components::{TextComponent, content::Content};
 
     type Dispatcher = CommandDispatcher<CommandSource, SteelCommandRuntime>;
 
@@ -286,4 +408,52 @@ mod tests {
         let expected = 30.0 * DEFAULT_FLYING_SPEED;
         assert!((speed - expected).abs() <= f32::EPSILON);
     }
+
+    /// Returns the translation key and argument texts of a feedback message.
+    fn translation(component: &TextComponent) -> (String, Vec<String>) {
+        let Content::Translate(message) = &component.content else {
+            panic!("feedback should be translatable");
+        };
+        let args = message
+            .args
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|arg| match &arg.content {
+                Content::Text { text } => text.to_string(),
+                _ => panic!("feedback arguments should be plain text"),
+            })
+            .collect();
+        (message.key.to_string(), args)
+    }
+
+    #[test]
+    fn feedback_names_one_target_and_counts_several() {
+        assert_eq!(
+            translation(&feedback(FLIGHT_ENABLED, &["Alex"], [])),
+            (FLIGHT_ENABLED.0.0.to_owned(), vec!["Alex".to_owned()])
+        );
+        assert_eq!(
+            translation(&feedback(FLIGHT_DISABLED, &["Alex", "Steve", "Sam"], [])),
+            (FLIGHT_DISABLED.1.0.to_owned(), vec!["3".to_owned()])
+        );
+        assert_eq!(
+            translation(&feedback(
+                SPEED_SET,
+                &["Alex", "Steve"],
+                [TextComponent::plain("2.0x (0.100)")]
+            )),
+            (
+                SPEED_SET.1.0.to_owned(),
+                vec!["2".to_owned(), "2.0x (0.100)".to_owned()]
+            )
+        );
+    }
+
+    #[test]
+    fn toggle_feedback_reports_mixed_results_as_toggled() {
+        assert_eq!(toggle_messages(&[true, true]), FLIGHT_ENABLED);
+        assert_eq!(toggle_messages(&[false]), FLIGHT_DISABLED);
+        assert_eq!(toggle_messages(&[true, false]), FLIGHT_TOGGLED);
+    }
 }
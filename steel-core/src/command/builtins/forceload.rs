@@ -0,0 +1,300 @@
+//! Forced chunk loading command.
+
+use steel_utils::{BlockPos, ChunkPos, Identifier, translations};
+use text_components::TextComponent;
+
+use super::super::{
+    brigadier::{CommandNodeBuilder, CommandSyntaxError},
+    execution::{
+        CommandSource, SteelArgumentType, SteelCommandContext, SteelCommandRuntime, argument,
+        literal,
+    },
+    registration::CommandRegistration,
+};
+use crate::world::World;
+
+/// Vanilla's `MAX_CHUNK_LIMIT`.
+const MAX_CHUNK_LIMIT: i64 = 256;
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::vanilla_static("forceload"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("forceload")
+        .then(
+            literal("add").then(
+                argument("from", SteelArgumentType::column_pos())
+                    .executes(|context| {
+                        let from = column_pos(context, "from")?;
+                        change_force_load(context, from, from, true)
+                    })
+                    .then(
+                        argument("to", SteelArgumentType::column_pos()).executes(|context| {
+                            let from = column_pos(context, "from")?;
+                            let to = column_pos(context, "to")?;
+                            change_force_load(context, from, to, true)
+                        }),
+                    ),
+            ),
+        )
+        .then(
+            literal("remove")
+                .then(
+                    argument("from", SteelArgumentType::column_pos())
+                        .executes(|context| {
+                            let from = column_pos(context, "from")?;
+                            change_force_load(context, from, from, false)
+                        })
+                        .then(argument("to", SteelArgumentType::column_pos()).executes(
+                            |context| {
+                                let from = column_pos(context, "from")?;
+                                let to = column_pos(context, "to")?;
+                                change_force_load(context, from, to, false)
+                            },
+                        )),
+                )
+                .then(literal("all").executes(remove_all)),
+        )
+        .then(literal("query").executes(list_force_load).then(
+            argument("pos", SteelArgumentType::column_pos()).executes(|context| {
+                let pos = column_pos(context, "pos")?;
+                query_force_load(context, pos)
+            }),
+        ))
+}
+
+fn column_pos(
+    context: &SteelCommandContext<CommandSource>,
+    name: &str,
+) -> Result<BlockPos, CommandSyntaxError> {
+    let Some(coordinates) = context.coordinates(name) else {
+        return Err(CommandSyntaxError::dynamic(format!(
+            "Parsed value for {name} is missing from the command context"
+        )));
+    };
+    Ok(coordinates.block_pos(context.source()).at_y(0))
+}
+
+/// Formats a chunk position like vanilla's `ChunkPos.toString`.
+fn chunk_text(pos: ChunkPos) -> String {
+    format!("[{}, {}]", pos.0.x, pos.0.y)
+}
+
+fn list_force_load(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<i32, CommandSyntaxError> {
+    let source = context.source();
+    let world = source.world();
+    let dimension = TextComponent::from(world.key.to_string());
+    let mut forced = world.chunk_map.forced_chunks();
+    forced.sort_unstable_by_key(|pos| (pos.0.x, pos.0.y));
+    let count = forced.len();
+    if count == 0 {
+        source.send_failure(
+            translations::COMMANDS_FORCELOAD_ADDED_NONE
+                .message([dimension])
+                .component(),
+        );
+        return Ok(0);
+    }
+
+    let chunks = TextComponent::from(
+        forced
+            .into_iter()
+            .map(chunk_text)
+            .collect::<Vec<_>>()
+            .join(", "),
+    );
+    let message = if count == 1 {
+        translations::COMMANDS_FORCELOAD_LIST_SINGLE
+            .message([dimension, chunks])
+            .component()
+    } else {
+        translations::COMMANDS_FORCELOAD_LIST_MULTIPLE
+            .message([TextComponent::from(count.to_string()), dimension, chunks])
+            .component()
+    };
+    source.send_success(&message, false);
+    Ok(i32::try_from(count).unwrap_or(i32::MAX))
+}
+
+fn query_force_load(
+    context: &SteelCommandContext<CommandSource>,
+    pos: BlockPos,
+) -> Result<i32, CommandSyntaxError> {
+    let source = context.source();
+    let world = source.world();
+    let chunk = ChunkPos::from_block_pos(pos);
+    let args = [
+        TextComponent::from(chunk_text(chunk)),
+        TextComponent::from(world.key.to_string()),
+    ];
+    if !world.chunk_map.is_chunk_forced(chunk) {
+        return Err(CommandSyntaxError::dynamic(
+            translations::COMMANDS_FORCELOAD_QUERY_FAILURE
+                .message(args)
+                .component(),
+        ));
+    }
+
+    let message = translations::COMMANDS_FORCELOAD_QUERY_SUCCESS
+        .message(args)
+        .component();
+    source.send_success(&message, false);
+    Ok(1)
+}
+
+fn remove_all(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let source = context.source();
+    let world = source.world();
+    for chunk in world.chunk_map.forced_chunks() {
+        world.chunk_map.set_chunk_forced(chunk, false);
+    }
+
+    let message = translations::COMMANDS_FORCELOAD_REMOVED_ALL
+        .message([TextComponent::from(world.key.to_string())])
+        .component();
+    source.send_success(&message, true);
+    Ok(0)
+}
+
+fn change_force_load(
+    context: &SteelCommandContext<CommandSource>,
+    from: BlockPos,
+    to: BlockPos,
+    add: bool,
+) -> Result<i32, CommandSyntaxError> {
+    if !World::is_in_spawnable_bounds(from) || !World::is_in_spawnable_bounds(to) {
+        return Err(CommandSyntaxError::dynamic(TextComponent::from(
+            &translations::ARGUMENT_POS_OUTOFWORLD,
+        )));
+    }
+
+    let (from, to) = (ChunkPos::from_block_pos(from), ChunkPos::from_block_pos(to));
+    let (min_x, max_x) = (from.0.x.min(to.0.x), from.0.x.max(to.0.x));
+    let (min_z, max_z) = (from.0.y.min(to.0.y), from.0.y.max(to.0.y));
+    let count =
+        (i64::from(max_x) - i64::from(min_x) + 1) * (i64::from(max_z) - i64::from(min_z) + 1);
+    if count > MAX_CHUNK_LIMIT {
+        return Err(CommandSyntaxError::dynamic(
+            translations::COMMANDS_FORCELOAD_TOOBIG
+                .message([
+                    TextComponent::from(MAX_CHUNK_LIMIT.to_string()),
+                    TextComponent::from(count.to_string()),
+                ])
+                .component(),
+        ));
+    }
+
+    let source = context.source();
+    let world = source.world();
+    let mut changed = 0;
+    let mut first_changed = None;
+    for x in min_x..=max_x {
+        for z in min_z..=max_z {
+            let chunk = ChunkPos::new(x, z);
+            if world.chunk_map.set_chunk_forced(chunk, add) {
+                changed += 1;
+                first_changed.get_or_insert(chunk);
+            }
+        }
+    }
+
+    let dimension = TextComponent::from(world.key.to_string());
+    let Some(first_changed) = first_changed else {
+        let failure = if add {
+            &translations::COMMANDS_FORCELOAD_ADDED_FAILURE
+        } else {
+            &translations::COMMANDS_FORCELOAD_REMOVED_FAILURE
+        };
+        return Err(CommandSyntaxError::dynamic(TextComponent::from(failure)));
+    };
+
+    let message = if changed == 1 {
+        let translation = if add {
+            &translations::COMMANDS_FORCELOAD_ADDED_SINGLE
+        } else {
+            &translations::COMMANDS_FORCELOAD_REMOVED_SINGLE
+        };
+        translation
+            .message([TextComponent::from(chunk_text(first_changed)), dimension])
+            .component()
+    } else {
+        let translation = if add {
+            &translations::COMMANDS_FORCELOAD_ADDED_MULTIPLE
+        } else {
+            &translations::COMMANDS_FORCELOAD_REMOVED_MULTIPLE
+        };
+        translation
+            .message([
+                TextComponent::from(changed.to_string()),
+                dimension,
+                TextComponent::from(chunk_text(ChunkPos::new(min_x, min_z))),
+                TextComponent::from(chunk_text(ChunkPos::new(max_x, max_z))),
+            ])
+            .component()
+    };
+    source.send_success(&message, true);
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::create_dispatcher;
+    use crate::command::{
+        brigadier::{CommandDispatcher, NodeId},
+        execution::{CommandSource, SteelArgumentType, SteelCommandRuntime},
+    };
+    use steel_registry::test_support::init_test_registry;
+
+    type Dispatcher = CommandDispatcher<CommandSource, SteelCommandRuntime>;
+
+    fn child(dispatcher: &Dispatcher, parent: NodeId, name: &str) -> NodeId {
+        let Some(children) = dispatcher.children(parent) else {
+            panic!("parent node should exist");
+        };
+        let Some(child) = children.iter().copied().find(|child| {
+            dispatcher
+                .node(*child)
+                .is_some_and(|node| node.name() == name)
+        }) else {
+            panic!("child {name} should exist");
+        };
+        child
+    }
+
+    fn executable(dispatcher: &Dispatcher, node: NodeId) -> bool {
+        dispatcher
+            .node(node)
+            .is_some_and(|node| node.is_executable())
+    }
+
+    #[test]
+    fn forceload_graph_matches_vanilla() {
+        init_test_registry();
+        let Ok(dispatcher) = create_dispatcher() else {
+            panic!("built-in commands should register");
+        };
+        let root = child(&dispatcher, dispatcher.root(), "forceload");
+        assert!(!executable(&dispatcher, root));
+
+        for action in ["add", "remove"] {
+            let action = child(&dispatcher, root, action);
+            let from = child(&dispatcher, action, "from");
+            assert_eq!(
+                dispatcher.node(from).and_then(|node| node.argument_type()),
+                Some(&SteelArgumentType::column_pos())
+            );
+            assert!(executable(&dispatcher, from));
+            assert!(executable(&dispatcher, child(&dispatcher, from, "to")));
+        }
+
+        let remove = child(&dispatcher, root, "remove");
+        assert!(executable(&dispatcher, child(&dispatcher, remove, "all")));
+
+        let query = child(&dispatcher, root, "query");
+        assert!(executable(&dispatcher, query));
+        assert!(executable(&dispatcher, child(&dispatcher, query, "pos")));
+    }
+}
@@ -0,0 +1,76 @@
+//! Datapack function command.
+
+use std::sync::atomic::AtomicI32;
+
+use steel_registry::vanilla_game_rules::MAX_COMMAND_SEQUENCE_LENGTH;
+use steel_utils::{Identifier, translations};
+use text_components::TextComponent;
+
+use super::super::{
+    brigadier::{CommandNodeBuilder, CommandSyntaxError},
+    execution::{
+        CommandSource, SteelArgumentType, SteelCommandContext, SteelCommandRuntime, argument,
+        literal,
+    },
+    registration::CommandRegistration,
+};
+use crate::server::Server;
+use crate::server::functions::FunctionRef;
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::vanilla_static("function"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("function").then(argument("name", SteelArgumentType::function()).executes(run))
+}
+
+/// Resolves `function` to the functions it names, or the vanilla unknown
+/// function error.
+pub(super) fn resolve(
+    server: &Server,
+    function: &FunctionRef,
+) -> Result<Vec<Identifier>, CommandSyntaxError> {
+    server.resolve_functions(function).ok_or_else(|| {
+        let message = match function {
+            FunctionRef::Function(id) => translations::ARGUMENTS_FUNCTION_UNKNOWN
+                .message([TextComponent::plain(id.to_string())]),
+            FunctionRef::Tag(id) => translations::ARGUMENTS_FUNCTION_TAG_UNKNOWN
+                .message([TextComponent::plain(id.to_string())]),
+        };
+        CommandSyntaxError::dynamic(message.component())
+    })
+}
+
+fn run(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let Some(function) = context.function("name") else {
+        return Err(CommandSyntaxError::dynamic(
+            "Parsed function is missing from the command context",
+        ));
+    };
+    let source = context.source();
+    let server = source.server();
+    let functions = resolve(server, function)?;
+
+    let budget = AtomicI32::new(
+        source
+            .world()
+            .get_game_rule(&MAX_COMMAND_SEQUENCE_LENGTH)
+            .max(0),
+    );
+    let mut commands = 0;
+    for id in &functions {
+        commands += server.run_function(id, source, &budget).unwrap_or(0);
+    }
+
+    let count = TextComponent::plain(commands.to_string());
+    let message = if let [id] = functions.as_slice() {
+        translations::COMMANDS_FUNCTION_SUCCESS_SINGLE
+            .message([count, TextComponent::plain(id.to_string())])
+    } else {
+        translations::COMMANDS_FUNCTION_SUCCESS_MULTIPLE
+            .message([count, TextComponent::plain(functions.len().to_string())])
+    };
+    source.send_success(&message.component(), true);
+    Ok(commands)
+}
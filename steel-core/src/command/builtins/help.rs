@@ -0,0 +1,111 @@
+//! Vanilla help command with Steel pagination.
+
+use steel_utils::{Identifier, translations};
+use text_components::{
+    Modifier, TextComponent,
+    format::Color,
+    interactivity::{ClickEvent, HoverEvent},
+};
+
+use super::super::{
+    brigadier::{ArgumentType, CommandNodeBuilder, CommandSyntaxError},
+    execution::{CommandSource, SteelCommandContext, SteelCommandRuntime, argument, literal},
+    registration::CommandRegistration,
+};
+
+/// Root commands shown per `/help` page.
+const PAGE_SIZE: usize = 8;
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::vanilla_static("help"), |_| command()).default_access()
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("help")
+        .executes(|context| list_commands(context, 1))
+        .then(
+            argument("command", ArgumentType::greedy_string()).executes(|context| {
+                let command = context.string("command").unwrap_or_default().trim();
+                // A bare number is a page; no command is named like one.
+                match command.parse::<usize>() {
+                    Ok(page) if page > 0 => list_commands(context, page),
+                    _ => describe_command(context, command),
+                }
+            }),
+        )
+}
+
+fn list_commands(
+    context: &SteelCommandContext<CommandSource>,
+    page: usize,
+) -> Result<i32, CommandSyntaxError> {
+    let source = context.source();
+    let mut usages = source
+        .server()
+        .command_usages(None, source.clone())
+        .unwrap_or_default();
+    usages.sort_unstable();
+
+    let pages = usages.len().div_ceil(PAGE_SIZE).max(1);
+    if page > pages {
+        return Err(CommandSyntaxError::dynamic(format!(
+            "Page {page} doesn't exist, there are {pages} page(s)"
+        )));
+    }
+    source.send_success(&page_header(page, pages), false);
+    let shown = &usages[(page - 1) * PAGE_SIZE..usages.len().min(page * PAGE_SIZE)];
+    for usage in shown {
+        let root = usage.split(' ').next().unwrap_or(usage);
+        source.send_success(&usage_line(usage, root), false);
+    }
+    Ok(i32::try_from(usages.len()).unwrap_or(i32::MAX))
+}
+
+fn describe_command(
+    context: &SteelCommandContext<CommandSource>,
+    command: &str,
+) -> Result<i32, CommandSyntaxError> {
+    let source = context.source();
+    let Some(usages) = source
+        .server()
+        .command_usages(Some(command), source.clone())
+    else {
+        return Err(CommandSyntaxError::dynamic(TextComponent::from(
+            &translations::COMMANDS_HELP_FAILED,
+        )));
+    };
+    for usage in &usages {
+        source.send_success(&usage_line(usage, command), false);
+    }
+    Ok(i32::try_from(usages.len()).unwrap_or(i32::MAX))
+}
+
+/// `/usage`, clicking it puts `/prefix ` into the chat box.
+fn usage_line(usage: &str, prefix: &str) -> TextComponent {
+    TextComponent::plain(format!("/{usage}"))
+        .hover_event(HoverEvent::show_text(TextComponent::plain(format!(
+            "Click to type /{prefix}"
+        ))))
+        .click_event(ClickEvent::suggest_command(format!("/{prefix} ")))
+}
+
+/// `Help: page X of Y` with clickable arrows to the neighbouring pages.
+fn page_header(page: usize, pages: usize) -> TextComponent {
+    let arrow = |label: &str, target: usize, enabled: bool| {
+        let arrow = TextComponent::plain(label.to_owned());
+        if enabled {
+            arrow
+                .color(Color::Yellow)
+                .hover_event(HoverEvent::show_text(TextComponent::plain(format!(
+                    "Page {target}"
+                ))))
+                .click_event(ClickEvent::run_command(format!("/help {target}")))
+        } else {
+            arrow.color(Color::DarkGray)
+        }
+    };
+    TextComponent::plain("")
+        .add_child(arrow("<< ", page.saturating_sub(1), page > 1))
+        .add_child(TextComponent::plain(format!("Help: page {page} of {pages}")).color(Color::Gold))
+        .add_child(arrow(" >>", page + 1, page < pages))
+}
@@ -0,0 +1,104 @@
+//! Steel ignore list command.
+
+use std::sync::Arc;
+
+use steel_utils::{Identifier, translations};
+use text_components::{TextComponent, format::Color};
+
+use super::super::{
+    brigadier::{CommandNodeBuilder, CommandSyntaxError},
+    execution::{
+        CommandSource, SteelArgumentType, SteelCommandContext, SteelCommandRuntime, argument,
+        literal,
+    },
+    registration::CommandRegistration,
+};
+use crate::player::Player;
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::from_steel("ignore"), |_| command()).default_access()
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("ignore")
+        .executes(list_ignored)
+        .then(argument("target", SteelArgumentType::player()).executes(toggle_ignored))
+}
+
+fn source_player(source: &CommandSource) -> Result<&Arc<Player>, CommandSyntaxError> {
+    source.player().ok_or_else(|| {
+        CommandSyntaxError::dynamic(TextComponent::from(
+            &translations::PERMISSIONS_REQUIRES_PLAYER,
+        ))
+    })
+}
+
+fn list_ignored(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let source = context.source();
+    let player = source_player(source)?;
+    let ignored = player.ignored_players();
+    if ignored.is_empty() {
+        source.send_success(&TextComponent::plain("You are not ignoring anyone"), false);
+        return Ok(0);
+    }
+
+    let known = source.server().known_players();
+    let names: Vec<String> = ignored
+        .iter()
+        .map(|uuid| {
+            known.by_uuid(*uuid).map_or_else(
+                || uuid.to_string(),
+                |player| player.last_known_name().to_owned(),
+            )
+        })
+        .collect();
+    source.send_success(
+        &TextComponent::plain(format!(
+            "You are ignoring {} player(s): {}",
+            names.len(),
+            names.join(", ")
+        )),
+        false,
+    );
+    Ok(i32::try_from(names.len()).unwrap_or(i32::MAX))
+}
+
+fn toggle_ignored(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let source = context.source();
+    let player = source_player(source)?;
+    let target = context.player("target")?;
+    if target.gameprofile.id == player.gameprofile.id {
+        return Err(CommandSyntaxError::dynamic("You can't ignore yourself"));
+    }
+
+    let ignoring = !player.is_ignoring(target.gameprofile.id);
+    player.set_ignoring(target.gameprofile.id, ignoring);
+    save_ignored(source, player);
+
+    let message = if ignoring {
+        format!(
+            "You are now ignoring {}. Their chat and private messages are hidden",
+            target.gameprofile.name
+        )
+    } else {
+        format!("You are no longer ignoring {}", target.gameprofile.name)
+    };
+    source.send_success(&TextComponent::plain(message).color(Color::Gray), false);
+    Ok(i32::from(ignoring))
+}
+
+/// Saves the player's ignore list in the background, logging failures.
+fn save_ignored(source: &CommandSource, player: &Player) {
+    let server = Arc::clone(source.server());
+    let uuid = player.gameprofile.id;
+    let ignored = player.ignored_players();
+    tokio::spawn(async move {
+        if let Err(e) = server
+            .player_data_storage
+            .save_ignored(uuid, &ignored)
+            .await
+        {
+            log::error!("Failed to save ignore list of {uuid}: {e}");
+        }
+    });
+}
@@ -29,7 +29,7 @@ fn list_players(
             "Online player count exceeds the command result range",
         ));
     };
-    let max_players = context.source().server().config.max_players;
+    let max_players = context.source().server().config.load().max_players;
     let formatted_players = context
         .source()
         .server()
@@ -1,29 +1,46 @@
 //! Steel-owned built-in command declarations.
 
 mod clear;
+mod datapack;
 mod difficulty;
 mod domain;
 mod enchant;
 mod execute;
 mod experience;
 mod fly;
+mod forceload;
+mod function;
 pub(crate) mod gamemode;
 mod gamerule;
 mod give;
+mod help;
+mod ignore;
 mod kill;
 mod list;
 mod locate;
+mod msg;
+mod netstats;
 mod operator;
 mod perms;
+mod ping;
+mod place;
+mod plugins;
+mod profiler;
+mod reload;
 mod return_command;
+mod schedule;
 mod seed;
 mod setworldspawn;
+mod skin;
+mod steel;
 mod stop;
 mod summon;
 mod teleport;
 mod tellraw;
 mod tick;
 mod time;
+mod tps;
+mod viewdistance;
 mod weather;
 
 pub(crate) use difficulty::player_can_change_difficulty;
@@ -34,6 +51,7 @@ use super::{
     registration::{
         CommandDispatcherBuilder, CommandRegistrationError,
         ENTITY_SELECTOR_ADVANCED_PERMISSION_KEY, ENTITY_SELECTOR_PERMISSION_KEY,
+        GAME_MASTER_BLOCKS_PERMISSION_KEY, JOIN_QUEUE_BYPASS_PERMISSION_KEY,
         RegisteredCommandDispatcher,
     },
 };
@@ -52,10 +70,13 @@ pub(crate) fn create_registered_dispatcher(
     let mut builder = CommandDispatcherBuilder::new();
     builder.declare_permission(ENTITY_SELECTOR_PERMISSION_KEY)?;
     builder.declare_permission(ENTITY_SELECTOR_ADVANCED_PERMISSION_KEY)?;
+    builder.declare_permission(GAME_MASTER_BLOCKS_PERMISSION_KEY)?;
+    builder.declare_permission(JOIN_QUEUE_BYPASS_PERMISSION_KEY)?;
     builder.declare_permission(perms::MANAGE_ALL_PERMISSION)?;
     builder.declare_permission(perms::GROUP_ALL_PERMISSION)?;
     builder.declare_permission(perms::METADATA_PERMISSION)?;
     builder.register(clear::registration())?;
+    builder.register(datapack::registration())?;
     builder.register(operator::deop_registration())?;
     builder.register(difficulty::registration())?;
     builder.register(domain::registration())?;
@@ -63,23 +84,41 @@ pub(crate) fn create_registered_dispatcher(
     builder.register(execute::registration())?;
     builder.register(experience::registration())?;
     builder.register(fly::registration())?;
+    builder.register(forceload::registration())?;
+    builder.register(function::registration())?;
     builder.register(gamemode::registration()?)?;
     builder.register(gamerule::registration())?;
     builder.register(give::registration())?;
+    builder.register(help::registration())?;
+    builder.register(ignore::registration())?;
     builder.register(kill::registration())?;
     builder.register(list::registration())?;
     builder.register(locate::registration())?;
+    builder.register(msg::registration())?;
+    builder.register(tps::mspt_registration())?;
+    builder.register(netstats::registration())?;
     builder.register(operator::op_registration())?;
     builder.register(perms::registration())?;
+    builder.register(ping::registration())?;
+    builder.register(place::registration())?;
+    builder.register(plugins::registration())?;
+    builder.register(profiler::registration())?;
+    builder.register(reload::registration())?;
+    builder.register(msg::reply_registration())?;
     builder.register(return_command::registration())?;
+    builder.register(schedule::registration())?;
     builder.register(seed::registration())?;
     builder.register(setworldspawn::registration())?;
+    builder.register(skin::registration())?;
+    builder.register(steel::registration())?;
     builder.register(stop::registration())?;
     builder.register(summon::registration())?;
     builder.register(teleport::registration())?;
     builder.register(tellraw::registration())?;
     builder.register(tick::registration())?;
     builder.register(time::registration())?;
+    builder.register(tps::tps_registration())?;
+    builder.register(viewdistance::registration())?;
     builder.register(weather::registration())?;
     builder.extend(extension_commands.into_inner())?;
     builder.build_with_permissions()
@@ -120,6 +159,7 @@ mod tests {
             names,
             [
                 "clear",
+                "datapack",
                 "deop",
                 "difficulty",
                 "domain",
@@ -128,17 +168,35 @@ mod tests {
                 "experience",
                 "xp",
                 "fly",
+                "function",
                 "gamemode",
                 "gamerule",
                 "give",
+                "help",
+                "ignore",
                 "kill",
                 "list",
                 "locate",
+                "msg",
+                "tell",
+                "w",
+                "mspt",
+                "netstats",
                 "op",
                 "perms",
+                "ping",
+                "place",
+                "plugins",
+                "profiler",
+                "reload",
+                "reply",
+                "r",
                 "return",
+                "schedule",
                 "seed",
                 "setworldspawn",
+                "skin",
+                "steel",
                 "stop",
                 "summon",
                 "teleport",
@@ -146,6 +204,8 @@ mod tests {
                 "tellraw",
                 "tick",
                 "time",
+                "tps",
+                "viewdistance",
                 "weather"
             ]
         );
@@ -0,0 +1,131 @@
+//! Vanilla private message command and Steel's `/reply`.
+
+use std::sync::Arc;
+
+use steel_protocol::packets::game::{CDisguisedChat, ChatTypeBound};
+use steel_registry::{RegistryEntry, vanilla_chat_types};
+use steel_utils::{Identifier, translations};
+use text_components::{Modifier, TextComponent, format::Color};
+
+use super::super::{
+    brigadier::{ArgumentType, CommandNodeBuilder, CommandSyntaxError},
+    execution::{
+        CommandSource, SteelArgumentType, SteelCommandContext, SteelCommandRuntime, argument,
+        literal,
+    },
+    registration::CommandRegistration,
+};
+use crate::entity::Entity;
+use crate::player::Player;
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::vanilla_static("msg"), |_| msg_command())
+        .alias("tell")
+        .alias("w")
+        .default_access()
+}
+
+pub(super) fn reply_registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::from_steel("reply"), |_| reply_command())
+        .alias("r")
+        .default_access()
+}
+
+fn msg_command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("msg").then(
+        argument("targets", SteelArgumentType::players())
+            .then(argument("message", ArgumentType::greedy_string()).executes(send_to_targets)),
+    )
+}
+
+fn reply_command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("reply")
+        .then(argument("message", ArgumentType::greedy_string()).executes(reply_to_correspondent))
+}
+
+fn send_to_targets(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<i32, CommandSyntaxError> {
+    let targets = context.players("targets")?;
+    let message = message_argument(context)?;
+    for target in &targets {
+        send_private_message(context.source(), target, message);
+    }
+    Ok(i32::try_from(targets.len()).unwrap_or(i32::MAX))
+}
+
+fn reply_to_correspondent(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<i32, CommandSyntaxError> {
+    let source = context.source();
+    let Some(player) = source.player() else {
+        return Err(CommandSyntaxError::dynamic(TextComponent::from(
+            &translations::PERMISSIONS_REQUIRES_PLAYER,
+        )));
+    };
+    let Some(correspondent) = player.last_correspondent() else {
+        return Err(CommandSyntaxError::dynamic("There is nobody to reply to"));
+    };
+    let Some(target) = source.server().get_player(&correspondent) else {
+        return Err(CommandSyntaxError::dynamic(
+            "The player you last messaged is no longer online",
+        ));
+    };
+    send_private_message(source, &target, message_argument(context)?);
+    Ok(1)
+}
+
+fn message_argument<'a>(
+    context: &'a SteelCommandContext<CommandSource>,
+) -> Result<&'a str, CommandSyntaxError> {
+    context
+        .string("message")
+        .ok_or_else(|| CommandSyntaxError::dynamic("Missing message"))
+}
+
+/// Shows `message` to the source as outgoing and to `target` as incoming.
+///
+/// Vanilla `MsgCommand.sendMessage`. Targets ignoring the sender silently
+/// miss the message, so the sender can't tell they are ignored.
+fn send_private_message(source: &CommandSource, target: &Arc<Player>, message: &str) {
+    let content = TextComponent::plain(message.to_owned());
+    let sender_name = source.display_name();
+    let target_name = target.display_name();
+
+    match source.player() {
+        Some(player) => {
+            player.send_packet(CDisguisedChat::new(
+                &content,
+                ChatTypeBound {
+                    registry_id: vanilla_chat_types::MSG_COMMAND_OUTGOING.id() as i32,
+                    sender_name: sender_name.clone(),
+                    target_name: Some(target_name),
+                },
+                player.as_ref(),
+            ));
+            player.set_last_correspondent(target.gameprofile.id);
+            if target.is_ignoring(player.gameprofile.id) {
+                return;
+            }
+            target.set_last_correspondent(player.gameprofile.id);
+        }
+        None => source.send_success(
+            &translations::COMMANDS_MESSAGE_DISPLAY_OUTGOING
+                .message([target_name, content.clone()])
+                .component()
+                .color(Color::Gray)
+                .italic(true),
+            false,
+        ),
+    }
+
+    target.send_packet(CDisguisedChat::new(
+        &content,
+        ChatTypeBound {
+            registry_id: vanilla_chat_types::MSG_COMMAND_INCOMING.id() as i32,
+            sender_name,
+            target_name: None,
+        },
+        target.as_ref(),
+    ));
+}
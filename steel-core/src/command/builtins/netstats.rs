@@ -0,0 +1,146 @@
+//! Steel network traffic command.
+
+use std::{cmp::Reverse, sync::Arc};
+
+use steel_protocol::traffic::TrafficStats;
+use steel_utils::Identifier;
+use text_components::{TextComponent, format::Color};
+
+use super::super::{
+    brigadier::{CommandNodeBuilder, CommandSyntaxError},
+    execution::{
+        CommandSource, SteelArgumentType, SteelCommandContext, SteelCommandRuntime, argument,
+        literal,
+    },
+    registration::CommandRegistration,
+};
+use crate::player::Player;
+
+/// Players listed by `/netstats` without targets, busiest first.
+const TOP_PLAYERS: usize = 5;
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::from_steel("netstats"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("netstats")
+        .executes(send_server_stats)
+        .then(argument("targets", SteelArgumentType::players()).executes(send_target_stats))
+}
+
+#[expect(
+    clippy::unnecessary_wraps,
+    reason = "executors share a fallible signature"
+)]
+fn send_server_stats(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<i32, CommandSyntaxError> {
+    let source = context.source();
+    let server = source.server();
+    let total = server.network_traffic.snapshot();
+    source.send_success(&stats_line("Server", &total), false);
+
+    let mut players: Vec<_> = server
+        .get_players()
+        .into_iter()
+        .filter_map(|player| player.traffic().map(|stats| (player, stats)))
+        .collect();
+    players.sort_by_key(|(_, stats)| Reverse(stats.bytes_out + stats.bytes_in));
+    for (player, stats) in players.iter().take(TOP_PLAYERS) {
+        source.send_success(&stats_line(&player.gameprofile.name, stats), false);
+    }
+    Ok(i32::try_from(players.len()).unwrap_or(i32::MAX))
+}
+
+fn send_target_stats(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<i32, CommandSyntaxError> {
+    let targets = context.players("targets")?;
+    let source = context.source();
+    let mut counted = 0;
+    for target in &targets {
+        let Some(stats) = target.traffic() else {
+            continue;
+        };
+        source.send_success(&stats_line(&target.gameprofile.name, &stats), false);
+        counted += 1;
+    }
+    if counted == 0 {
+        return Err(CommandSyntaxError::dynamic(no_traffic_message(&targets)));
+    }
+    Ok(counted)
+}
+
+fn no_traffic_message(targets: &[Arc<Player>]) -> String {
+    match targets {
+        [target] => format!("No traffic is counted for {}", target.gameprofile.name),
+        _ => "No traffic is counted for these players".to_owned(),
+    }
+}
+
+fn stats_line(label: &str, stats: &TrafficStats) -> TextComponent {
+    let direction = |arrow: &str, packets: u64, bytes: u64, data_bytes: u64| {
+        vec![
+            TextComponent::plain(format!(" {arrow} ")).color(Color::Gray),
+            TextComponent::plain(format!("{} ", format_bytes(bytes))).color(Color::Green),
+            TextComponent::plain(format!(
+                "({} uncompressed, {packets} packets, {}/s)",
+                format_bytes(data_bytes),
+                format_bytes(per_second(bytes, stats)),
+            ))
+            .color(Color::Gray),
+        ]
+    };
+    let mut children = direction("in", stats.packets_in, stats.bytes_in, stats.data_bytes_in);
+    children.extend(direction(
+        "out",
+        stats.packets_out,
+        stats.bytes_out,
+        stats.data_bytes_out,
+    ));
+    TextComponent::plain(format!("{label}:"))
+        .color(Color::Gold)
+        .add_children(children)
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss,
+    reason = "a byte rate for display only"
+)]
+fn per_second(bytes: u64, stats: &TrafficStats) -> u64 {
+    let seconds = stats.elapsed.as_secs_f64();
+    if seconds < 1.0 {
+        return bytes;
+    }
+    (bytes as f64 / seconds) as u64
+}
+
+#[expect(clippy::cast_precision_loss, reason = "a byte count for display only")]
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_bytes;
+
+    #[test]
+    fn bytes_use_binary_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
+}
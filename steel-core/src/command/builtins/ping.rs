@@ -0,0 +1,87 @@
+//! Steel player latency command.
+
+use std::{slice, sync::Arc};
+
+use steel_utils::{Identifier, translations};
+use text_components::{TextComponent, format::Color};
+
+use super::super::{
+    brigadier::{CommandNodeBuilder, CommandSyntaxError},
+    execution::{
+        CommandSource, SteelArgumentType, SteelCommandContext, SteelCommandRuntime, argument,
+        literal,
+    },
+    registration::CommandRegistration,
+};
+use crate::player::Player;
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::from_steel("ping"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("ping")
+        .executes(ping_sender)
+        .then(argument("targets", SteelArgumentType::players()).executes(ping_targets))
+}
+
+fn ping_sender(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let Some(player) = context.source().player() else {
+        return Err(CommandSyntaxError::dynamic(TextComponent::from(
+            &translations::PERMISSIONS_REQUIRES_PLAYER,
+        )));
+    };
+    Ok(send_latencies(context.source(), slice::from_ref(player)))
+}
+
+fn ping_targets(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let targets = context.players("targets")?;
+    Ok(send_latencies(context.source(), &targets))
+}
+
+/// Reports each target's latency, returning the last target's average.
+fn send_latencies(source: &CommandSource, targets: &[Arc<Player>]) -> i32 {
+    let mut result = 0;
+    for target in targets {
+        let average = target.latency();
+        source.send_success(
+            &TextComponent::plain(format!("{}'s ping: ", target.gameprofile.name))
+                .color(Color::Gold)
+                .add_children(vec![
+                    latency_text(target.last_round_trip()),
+                    TextComponent::plain(" current, ").color(Color::Gray),
+                    latency_text(average),
+                    TextComponent::plain(" average").color(Color::Gray),
+                ]),
+            false,
+        );
+        result = average;
+    }
+    result
+}
+
+fn latency_text(milliseconds: i32) -> TextComponent {
+    TextComponent::plain(format!("{milliseconds} ms")).color(latency_color(milliseconds))
+}
+
+/// Matches the thresholds of the tab list's connection bars.
+const fn latency_color(milliseconds: i32) -> Color {
+    match milliseconds {
+        ..150 => Color::Green,
+        150..600 => Color::Yellow,
+        _ => Color::Red,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::latency_color;
+    use text_components::format::Color;
+
+    #[test]
+    fn latency_colors_follow_connection_bars() {
+        assert!(matches!(latency_color(40), Color::Green));
+        assert!(matches!(latency_color(300), Color::Yellow));
+        assert!(matches!(latency_color(1200), Color::Red));
+    }
+}
@@ -0,0 +1,280 @@
+//! Structure template, jigsaw and structure placement command.
+
+use steel_registry::{REGISTRY, RegistryExt as _, vanilla_template_pools};
+use steel_utils::{BlockPos, ChunkPos, Identifier, Mirror, Rotation, translations};
+use text_components::TextComponent;
+
+use super::super::{
+    brigadier::{ArgumentType, CommandNodeBuilder, CommandSyntaxError},
+    execution::{
+        CommandSource, SteelArgumentType, SteelCommandContext, SteelCommandRuntime, argument,
+        literal,
+    },
+    registration::CommandRegistration,
+};
+
+/// Vanilla `PlaceCommand` limit on jigsaw generation depth.
+const MAX_JIGSAW_DEPTH: i32 = 20;
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::vanilla_static("place"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    // TODO: Add `place feature` once configured features can be placed into a live world.
+    literal("place")
+        .then(
+            literal("jigsaw").then(
+                argument(
+                    "pool",
+                    SteelArgumentType::resource_key("minecraft:worldgen/template_pool"),
+                )
+                .then(
+                    argument("target", SteelArgumentType::resource_location()).then(
+                        argument("max_depth", ArgumentType::integer(1, MAX_JIGSAW_DEPTH))
+                            .executes(place_jigsaw)
+                            .then(
+                                argument("position", SteelArgumentType::block_pos())
+                                    .executes(place_jigsaw),
+                            ),
+                    ),
+                ),
+            ),
+        )
+        .then(
+            literal("structure").then(
+                argument(
+                    "structure",
+                    SteelArgumentType::resource_key("minecraft:worldgen/structure"),
+                )
+                .executes(place_structure)
+                .then(argument("pos", SteelArgumentType::block_pos()).executes(place_structure)),
+            ),
+        )
+        .then(
+            literal("template").then(
+                argument("template", SteelArgumentType::resource_location())
+                    .executes(place_template)
+                    .then(
+                        argument("pos", SteelArgumentType::block_pos())
+                            .executes(place_template)
+                            .then(
+                                argument("rotation", SteelArgumentType::template_rotation())
+                                    .executes(place_template)
+                                    .then(
+                                        argument("mirror", SteelArgumentType::template_mirror())
+                                            .executes(place_template)
+                                            .then(template_integrity()),
+                                    ),
+                            ),
+                    ),
+            ),
+        )
+}
+
+fn template_integrity() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    argument("integrity", ArgumentType::float(0.0, 1.0))
+        .executes(place_template)
+        .then(
+            argument("seed", ArgumentType::integer(i32::MIN, i32::MAX))
+                .executes(place_template)
+                // TODO: Place with known shapes once live template placement
+                // accepts update flags.
+                .then(literal("strict").executes(place_template)),
+        )
+}
+
+/// Reads an optional block position argument, defaulting to the source's block.
+fn position_or_source(context: &SteelCommandContext<CommandSource>, name: &str) -> BlockPos {
+    context.coordinates(name).map_or_else(
+        || BlockPos::from(context.source().position()),
+        |coordinates| coordinates.block_pos(context.source()),
+    )
+}
+
+/// Vanilla `PlaceCommand.checkLoaded`.
+fn check_loaded(
+    context: &SteelCommandContext<CommandSource>,
+    min: BlockPos,
+    max: BlockPos,
+) -> Result<(), CommandSyntaxError> {
+    let world = context.source().world();
+    let min_chunk = ChunkPos::from_block_pos(min);
+    let max_chunk = ChunkPos::from_block_pos(max);
+    for chunk_x in min_chunk.0.x..=max_chunk.0.x {
+        for chunk_z in min_chunk.0.y..=max_chunk.0.y {
+            let pos = BlockPos::new(chunk_x << 4, min.y(), chunk_z << 4);
+            if !world.is_full_chunk_loaded_at(pos) {
+                return Err(CommandSyntaxError::dynamic(TextComponent::from(
+                    &translations::ARGUMENT_POS_UNLOADED,
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn place_jigsaw(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let Some(pool) = context.identifier("pool") else {
+        return Err(missing_argument("pool"));
+    };
+    if !vanilla_template_pools::vanilla_template_pools()
+        .iter()
+        .any(|template_pool| template_pool.key == *pool)
+    {
+        return Err(CommandSyntaxError::dynamic(
+            translations::COMMANDS_PLACE_JIGSAW_INVALID
+                .message([pool.to_string()])
+                .component(),
+        ));
+    }
+    let position = position_or_source(context, "position");
+    check_loaded(context, position, position)?;
+
+    // TODO: Run `JigsawPlacement.generateJigsaw` once jigsaw assembly can place its
+    // pieces into a live world instead of a worldgen region.
+    Err(CommandSyntaxError::dynamic(TextComponent::from(
+        &translations::COMMANDS_PLACE_JIGSAW_FAILED,
+    )))
+}
+
+fn place_structure(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<i32, CommandSyntaxError> {
+    let Some(structure) = context.identifier("structure") else {
+        return Err(missing_argument("structure"));
+    };
+    if REGISTRY.structures.by_key(structure).is_none() {
+        return Err(CommandSyntaxError::dynamic(
+            translations::COMMANDS_PLACE_STRUCTURE_INVALID
+                .message([structure.to_string()])
+                .component(),
+        ));
+    }
+    let position = position_or_source(context, "pos");
+    check_loaded(context, position, position)?;
+
+    // TODO: Generate the structure start and place its pieces once structure
+    // placement can target a live world instead of a worldgen region.
+    Err(CommandSyntaxError::dynamic(TextComponent::from(
+        &translations::COMMANDS_PLACE_STRUCTURE_FAILED,
+    )))
+}
+
+fn place_template(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let Some(id) = context.identifier("template") else {
+        return Err(missing_argument("template"));
+    };
+    let source = context.source();
+    let position = position_or_source(context, "pos");
+    let rotation = context
+        .template_rotation("rotation")
+        .unwrap_or(Rotation::None);
+    let mirror = context.template_mirror("mirror").unwrap_or(Mirror::None);
+    let seed = context.integer("seed").map_or(0, i64::from);
+
+    let Some(template) = source.server().structure_templates.get(&REGISTRY, id) else {
+        return Err(CommandSyntaxError::dynamic(
+            translations::COMMANDS_PLACE_TEMPLATE_INVALID
+                .message([id.to_string()])
+                .component(),
+        ));
+    };
+    let bounds = template.bounding_box_with_transform(position, rotation, mirror, BlockPos::ZERO);
+    check_loaded(
+        context,
+        BlockPos(bounds.min_corner()),
+        BlockPos(bounds.max_corner()),
+    )?;
+
+    if !template.place_with_transform(
+        source.world(),
+        &REGISTRY,
+        position,
+        mirror,
+        rotation,
+        true,
+        seed,
+    ) {
+        return Err(CommandSyntaxError::dynamic(TextComponent::from(
+            &translations::COMMANDS_PLACE_TEMPLATE_FAILED,
+        )));
+    }
+
+    let message = translations::COMMANDS_PLACE_TEMPLATE_SUCCESS
+        .message([
+            id.to_string(),
+            position.x().to_string(),
+            position.y().to_string(),
+            position.z().to_string(),
+        ])
+        .component();
+    source.send_success(&message, true);
+    Ok(1)
+}
+
+fn missing_argument(name: &str) -> CommandSyntaxError {
+    CommandSyntaxError::dynamic(format!(
+        "Parsed value for {name} is missing from the command context"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::create_dispatcher;
+    use crate::command::{
+        brigadier::{CommandDispatcher, NodeId},
+        execution::{CommandSource, SteelArgumentType, SteelCommandRuntime},
+    };
+    use steel_registry::test_support::init_test_registry;
+
+    type Dispatcher = CommandDispatcher<CommandSource, SteelCommandRuntime>;
+
+    fn child(dispatcher: &Dispatcher, parent: NodeId, name: &str) -> NodeId {
+        let Some(children) = dispatcher.children(parent) else {
+            panic!("parent node should exist");
+        };
+        let Some(child) = children.iter().copied().find(|child| {
+            dispatcher
+                .node(*child)
+                .is_some_and(|node| node.name() == name)
+        }) else {
+            panic!("child {name} should exist");
+        };
+        child
+    }
+
+    #[test]
+    fn place_template_graph_matches_vanilla_optional_chain() {
+        init_test_registry();
+        let Ok(dispatcher) = create_dispatcher() else {
+            panic!("built-in commands should register");
+        };
+        let root = child(&dispatcher, dispatcher.root(), "place");
+        for subcommand in ["jigsaw", "structure", "template"] {
+            child(&dispatcher, root, subcommand);
+        }
+
+        let template = child(
+            &dispatcher,
+            child(&dispatcher, root, "template"),
+            "template",
+        );
+        let position = child(&dispatcher, template, "pos");
+        let rotation = child(&dispatcher, position, "rotation");
+        assert_eq!(
+            dispatcher
+                .node(rotation)
+                .and_then(|node| node.argument_type()),
+            Some(&SteelArgumentType::template_rotation())
+        );
+        let mirror = child(&dispatcher, rotation, "mirror");
+        let integrity = child(&dispatcher, mirror, "integrity");
+        let seed = child(&dispatcher, integrity, "seed");
+        let strict = child(&dispatcher, seed, "strict");
+        let Some(strict_node) = dispatcher.node(strict) else {
+            panic!("place template strict should exist");
+        };
+        assert!(strict_node.is_executable());
+    }
+}
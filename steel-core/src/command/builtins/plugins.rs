@@ -0,0 +1,47 @@
+//! Steel plugin list command.
+
+use steel_utils::Identifier;
+use text_components::{TextComponent, format::Color};
+
+use super::super::{
+    brigadier::{CommandNodeBuilder, CommandSyntaxError},
+    execution::{CommandSource, SteelCommandContext, SteelCommandRuntime, literal},
+    registration::CommandRegistration,
+};
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::from_steel("plugins"), |_| {
+        literal("plugins").executes(list_plugins)
+    })
+}
+
+#[expect(
+    clippy::unnecessary_wraps,
+    reason = "Command executors use a shared fallible callback signature."
+)]
+fn list_plugins(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let plugins = context
+        .source()
+        .server()
+        .plugin_manager()
+        .map(|manager| manager.plugins())
+        .unwrap_or_default();
+
+    let mut children = Vec::with_capacity(plugins.len() * 2);
+    for (index, plugin) in plugins.iter().enumerate() {
+        if index > 0 {
+            children.push(TextComponent::plain(", ").color(Color::White));
+        }
+        let color = if plugin.enabled {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        children
+            .push(TextComponent::plain(format!("{} {}", plugin.name, plugin.version)).color(color));
+    }
+    let message =
+        TextComponent::plain(format!("Plugins ({}): ", plugins.len())).add_children(children);
+    context.source().send_success(&message, false);
+    Ok(plugins.len() as i32)
+}
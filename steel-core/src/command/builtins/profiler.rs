@@ -0,0 +1,121 @@
+//! Steel sampling profiler command.
+
+use std::time::Duration;
+
+use steel_utils::Identifier;
+use text_components::TextComponent;
+
+use super::super::{
+    brigadier::{ArgumentType, CommandNodeBuilder, CommandSyntaxError},
+    execution::{CommandSource, SteelCommandContext, SteelCommandRuntime, argument, literal},
+    registration::CommandRegistration,
+};
+use crate::server::profiler::{
+    self, DEFAULT_SAMPLE_INTERVAL, ProfileSummary, ProfilerError, ReportError, ReportFormat,
+};
+
+/// Longest accepted sample interval in milliseconds.
+const MAX_SAMPLE_INTERVAL_MS: i32 = 1000;
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::from_steel("profiler"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("profiler")
+        .then(
+            literal("start")
+                .executes(|context| start(context, DEFAULT_SAMPLE_INTERVAL))
+                .then(
+                    argument("interval", ArgumentType::integer(1, MAX_SAMPLE_INTERVAL_MS))
+                        .executes(|context| {
+                            let Some(interval) = context.integer("interval") else {
+                                return Err(missing_argument("interval"));
+                            };
+                            start(context, Duration::from_millis(interval as u64))
+                        }),
+                ),
+        )
+        .then(literal("stop").executes(stop))
+        .then(
+            literal("report")
+                .executes(|context| report(context, ReportFormat::Html))
+                .then(literal("html").executes(|context| report(context, ReportFormat::Html)))
+                .then(literal("folded").executes(|context| report(context, ReportFormat::Folded))),
+        )
+}
+
+fn start(
+    context: &SteelCommandContext<CommandSource>,
+    interval: Duration,
+) -> Result<i32, CommandSyntaxError> {
+    profiler::start(interval).map_err(profiler_error)?;
+    context.source().send_success(
+        &TextComponent::plain(format!(
+            "Started profiling, sampling every {}ms",
+            interval.as_millis()
+        )),
+        true,
+    );
+    Ok(1)
+}
+
+fn stop(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let summary = profiler::stop().map_err(profiler_error)?;
+    context.source().send_success(
+        &TextComponent::plain(format!(
+            "Stopped profiling: {}, use /profiler report to save it",
+            describe(summary)
+        )),
+        true,
+    );
+    Ok(sample_result(summary))
+}
+
+fn report(
+    context: &SteelCommandContext<CommandSource>,
+    format: ReportFormat,
+) -> Result<i32, CommandSyntaxError> {
+    let (path, summary) = profiler::write_report(format).map_err(|error| match error {
+        ReportError::Profiler(error) => profiler_error(error),
+        ReportError::Io(error) => {
+            CommandSyntaxError::dynamic(format!("Failed to write the profile report: {error}"))
+        }
+    })?;
+    context.source().send_success(
+        &TextComponent::plain(format!(
+            "Saved profile report ({}) to {}",
+            describe(summary),
+            path.display()
+        )),
+        false,
+    );
+    Ok(sample_result(summary))
+}
+
+fn describe(summary: ProfileSummary) -> String {
+    format!(
+        "{} samples over {:.1}s",
+        summary.samples,
+        summary.duration.as_secs_f64()
+    )
+}
+
+fn sample_result(summary: ProfileSummary) -> i32 {
+    i32::try_from(summary.samples).unwrap_or(i32::MAX)
+}
+
+fn profiler_error(error: ProfilerError) -> CommandSyntaxError {
+    CommandSyntaxError::dynamic(match error {
+        ProfilerError::AlreadyRunning => "The profiler is already running",
+        ProfilerError::NotRunning => "The profiler is not running",
+        ProfilerError::NoProfile => "No profile has been recorded yet",
+        ProfilerError::SamplerUnavailable => "The profiler sampling thread could not be started",
+    })
+}
+
+fn missing_argument(name: &str) -> CommandSyntaxError {
+    CommandSyntaxError::dynamic(format!(
+        "Parsed `{name}` is missing from the profiler command context"
+    ))
+}
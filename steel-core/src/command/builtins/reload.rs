@@ -0,0 +1,34 @@
+use steel_utils::{
+    Identifier,
+    translations::{COMMANDS_RELOAD_FAILURE, COMMANDS_RELOAD_SUCCESS},
+};
+use text_components::TextComponent;
+
+use super::super::{
+    brigadier::{CommandNodeBuilder, CommandSyntaxError},
+    execution::{CommandSource, SteelCommandContext, SteelCommandRuntime, literal},
+    registration::CommandRegistration,
+};
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::vanilla_static("reload"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("reload").executes(reload_datapacks)
+}
+
+fn reload_datapacks(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<i32, CommandSyntaxError> {
+    context
+        .source()
+        .send_success(&TextComponent::from(&COMMANDS_RELOAD_SUCCESS), true);
+    if let Err(error) = context.source().server().reload_datapacks() {
+        log::error!("Failed to reload data packs: {error}");
+        return Err(CommandSyntaxError::dynamic(TextComponent::from(
+            &COMMANDS_RELOAD_FAILURE,
+        )));
+    }
+    Ok(0)
+}
@@ -0,0 +1,103 @@
+//! Delayed datapack function command.
+
+use steel_utils::{Identifier, translations};
+use text_components::TextComponent;
+
+use super::super::{
+    brigadier::{CommandNodeBuilder, CommandSyntaxError},
+    execution::{
+        CommandSource, SteelArgumentType, SteelCommandContext, SteelCommandRuntime, argument,
+        literal,
+    },
+    registration::CommandRegistration,
+};
+use super::function;
+use crate::server::functions::FunctionRef;
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::vanilla_static("schedule"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("schedule")
+        .then(
+            literal("function").then(
+                argument("function", SteelArgumentType::function()).then(
+                    argument("time", SteelArgumentType::time(0))
+                        .executes(|context| schedule(context, true))
+                        .then(literal("append").executes(|context| schedule(context, false)))
+                        .then(literal("replace").executes(|context| schedule(context, true))),
+                ),
+            ),
+        )
+        .then(
+            literal("clear")
+                .then(argument("function", SteelArgumentType::function()).executes(clear)),
+        )
+}
+
+fn schedule(
+    context: &SteelCommandContext<CommandSource>,
+    replace: bool,
+) -> Result<i32, CommandSyntaxError> {
+    let (Some(target), Some(time)) = (context.function("function"), context.time("time")) else {
+        return Err(CommandSyntaxError::dynamic(
+            "Parsed schedule arguments are missing from the command context",
+        ));
+    };
+    let Ok(delay) = u32::try_from(time) else {
+        return Err(CommandSyntaxError::dynamic(
+            "Parsed schedule time is negative",
+        ));
+    };
+    if delay == 0 {
+        return Err(CommandSyntaxError::dynamic(TextComponent::from(
+            &translations::COMMANDS_SCHEDULE_SAME_TICK,
+        )));
+    }
+
+    let source = context.source();
+    let server = source.server();
+    function::resolve(server, target)?;
+    server.schedule_function(target.clone(), delay, replace);
+
+    let game_time = source.world().game_time() + i64::from(delay);
+    let (key, id) = match target {
+        FunctionRef::Function(id) => (&translations::COMMANDS_SCHEDULE_CREATED_FUNCTION, id),
+        FunctionRef::Tag(id) => (&translations::COMMANDS_SCHEDULE_CREATED_TAG, id),
+    };
+    let message = key.message([
+        TextComponent::plain(id.to_string()),
+        TextComponent::plain(delay.to_string()),
+        TextComponent::plain(game_time.to_string()),
+    ]);
+    source.send_success(&message.component(), true);
+    // Vanilla returns the scheduled game time wrapped into the command result range.
+    Ok(i32::try_from(game_time.rem_euclid(i64::from(i32::MAX))).unwrap_or_default())
+}
+
+fn clear(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let Some(target) = context.function("function") else {
+        return Err(CommandSyntaxError::dynamic(
+            "Parsed function is missing from the command context",
+        ));
+    };
+    let source = context.source();
+    let id = TextComponent::plain(target.to_string());
+    let removed = source.server().clear_scheduled_function(target);
+    if removed == 0 {
+        return Err(CommandSyntaxError::dynamic(
+            translations::COMMANDS_SCHEDULE_CLEARED_FAILURE
+                .message([id])
+                .component(),
+        ));
+    }
+    let removed = i32::try_from(removed).unwrap_or(i32::MAX);
+    source.send_success(
+        &translations::COMMANDS_SCHEDULE_CLEARED_SUCCESS
+            .message([TextComponent::plain(removed.to_string()), id])
+            .component(),
+        true,
+    );
+    Ok(removed)
+}
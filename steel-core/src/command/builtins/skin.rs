@@ -0,0 +1,147 @@
+//! Steel player skin refresh command.
+
+use std::sync::Arc;
+
+use futures::future::join_all;
+use steel_protocol::packets::login::GameProfileProperty;
+use steel_utils::{Identifier, translations};
+use text_components::TextComponent;
+use tokio::{sync::oneshot, task::JoinHandle};
+
+use super::super::{
+    brigadier::{CommandNodeBuilder, CommandSyntaxError},
+    execution::{
+        CommandResultSuspension, CommandResultSuspensionPoll, CommandSource, SteelArgumentType,
+        SteelCommandContext, SteelCommandRuntime, argument, literal,
+    },
+    registration::CommandRegistration,
+};
+use crate::entity::Entity as _;
+use crate::player::{Player, ProfileLookupError};
+
+type RefreshResult = (
+    Arc<Player>,
+    Result<Vec<GameProfileProperty>, ProfileLookupError>,
+);
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::from_steel("skin"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("skin").then(literal("refresh").executes_suspended(refresh_sender).then(
+        argument("targets", SteelArgumentType::players()).executes_suspended(refresh_targets),
+    ))
+}
+
+fn refresh_sender(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<SkinRefreshSuspension, CommandSyntaxError> {
+    let Some(player) = context.source().player() else {
+        return Err(CommandSyntaxError::dynamic(TextComponent::from(
+            &translations::PERMISSIONS_REQUIRES_PLAYER,
+        )));
+    };
+    Ok(start(context.source(), vec![Arc::clone(player)]))
+}
+
+fn refresh_targets(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<SkinRefreshSuspension, CommandSyntaxError> {
+    let targets = context.players("targets")?;
+    Ok(start(context.source(), targets))
+}
+
+/// Fetches every target's textures off the tick thread; they are applied when polled.
+fn start(source: &CommandSource, targets: Vec<Arc<Player>>) -> SkinRefreshSuspension {
+    let server = Arc::clone(source.server());
+    let (sender, receiver) = oneshot::channel();
+    let task = tokio::spawn(async move {
+        let results = join_all(targets.into_iter().map(|player| {
+            let server = Arc::clone(&server);
+            async move {
+                let properties = server
+                    .fetch_profile_properties(player.gameprofile.id, &player.gameprofile.name)
+                    .await;
+                (player, properties)
+            }
+        }))
+        .await;
+        let _ = sender.send(results);
+    });
+    SkinRefreshSuspension {
+        source: source.clone(),
+        receiver,
+        task: Some(task),
+    }
+}
+
+struct SkinRefreshSuspension {
+    source: CommandSource,
+    receiver: oneshot::Receiver<Vec<RefreshResult>>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl SkinRefreshSuspension {
+    fn apply(&self, results: Vec<RefreshResult>) -> Result<i32, CommandSyntaxError> {
+        let server = self.source.server();
+        let mut refreshed = 0;
+        let mut last_error = None;
+        for (player, result) in results {
+            match result {
+                Ok(properties) => {
+                    if player.is_removed() {
+                        continue;
+                    }
+                    server.refresh_player_profile(&player, properties);
+                    self.source.send_success(
+                        &TextComponent::plain(format!(
+                            "Refreshed the skin of {}",
+                            player.gameprofile.name
+                        )),
+                        true,
+                    );
+                    refreshed += 1;
+                }
+                Err(error) => {
+                    log::warn!(
+                        "Couldn't refresh the skin of {}: {error}",
+                        player.gameprofile.name
+                    );
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        match last_error {
+            Some(error) if refreshed == 0 => Err(CommandSyntaxError::dynamic(format!(
+                "Couldn't refresh skin: {error}"
+            ))),
+            _ => Ok(refreshed),
+        }
+    }
+}
+
+impl CommandResultSuspension for SkinRefreshSuspension {
+    fn poll(&mut self) -> CommandResultSuspensionPoll {
+        match self.receiver.try_recv() {
+            Ok(results) => {
+                self.task = None;
+                CommandResultSuspensionPoll::Ready(self.apply(results))
+            }
+            Err(oneshot::error::TryRecvError::Empty) => CommandResultSuspensionPoll::Pending,
+            Err(oneshot::error::TryRecvError::Closed) => {
+                self.task = None;
+                CommandResultSuspensionPoll::Ready(Err(CommandSyntaxError::dynamic(
+                    "skin refresh task ended without a result",
+                )))
+            }
+        }
+    }
+
+    fn cancel(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Steel server administration command.
+
+use steel_utils::Identifier;
+use text_components::TextComponent;
+
+use super::super::{
+    brigadier::{CommandNodeBuilder, CommandSyntaxError},
+    execution::{CommandSource, SteelCommandContext, SteelCommandRuntime, literal},
+    registration::CommandRegistration,
+};
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::from_steel("steel"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("steel").then(literal("reload").then(literal("config").executes(reload_config)))
+}
+
+fn reload_config(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let reload = context.source().server().reload_config().map_err(|error| {
+        CommandSyntaxError::dynamic(format!("Failed to reload the configuration: {error}"))
+    })?;
+    let message = if reload.applied.is_empty() {
+        "Reloaded the configuration, nothing changed".to_owned()
+    } else {
+        format!(
+            "Reloaded the configuration, applied: {}",
+            reload.applied.join(", ")
+        )
+    };
+    context
+        .source()
+        .send_success(&TextComponent::plain(message), true);
+    if !reload.requires_restart.is_empty() {
+        context.source().send_success(
+            &TextComponent::plain(format!(
+                "Restart the server to apply: {}",
+                reload.requires_restart.join(", ")
+            )),
+            false,
+        );
+    }
+    Ok(reload.applied.len() as i32)
+}
@@ -0,0 +1,105 @@
+//! Steel server performance commands.
+
+use steel_utils::Identifier;
+use text_components::{TextComponent, format::Color};
+
+use super::super::{
+    brigadier::{CommandNodeBuilder, CommandSyntaxError},
+    execution::{CommandSource, SteelCommandContext, SteelCommandRuntime, literal},
+    registration::CommandRegistration,
+};
+use crate::server::tick_rate_manager::TICK_WINDOW_SECONDS;
+
+pub(super) fn tps_registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::from_steel("tps"), |_| {
+        literal("tps").executes(send_tps)
+    })
+}
+
+pub(super) fn mspt_registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::from_steel("mspt"), |_| {
+        literal("mspt").executes(send_mspt)
+    })
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::unnecessary_wraps,
+    reason = "the command result is the truncated short-window TPS and executors share a fallible signature"
+)]
+fn send_tps(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let (averages, tick_rate) = {
+        let manager = context.source().server().tick_rate_manager.read();
+        (manager.window_averages(), f64::from(manager.tick_rate()))
+    };
+    let values = averages.map(|average| {
+        let color = if average.tps >= tick_rate * 0.9 {
+            Color::Green
+        } else if average.tps >= tick_rate * 0.75 {
+            Color::Yellow
+        } else {
+            Color::Red
+        };
+        TextComponent::plain(format!("{:.1}", average.tps)).color(color)
+    });
+    let message = window_line(format!("TPS from last {}: ", window_names()), values);
+    context.source().send_success(&message, false);
+    Ok(averages[0].tps as i32)
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::unnecessary_wraps,
+    reason = "the command result is the truncated short-window MSPT and executors share a fallible signature"
+)]
+fn send_mspt(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let (averages, budget) = {
+        let manager = context.source().server().tick_rate_manager.read();
+        (
+            manager.window_averages(),
+            f64::from(manager.milliseconds_per_tick()),
+        )
+    };
+    let values = averages.map(|average| {
+        let color = if average.mspt <= budget * 0.8 {
+            Color::Green
+        } else if average.mspt <= budget {
+            Color::Yellow
+        } else {
+            Color::Red
+        };
+        TextComponent::plain(format!("{:.2}", average.mspt)).color(color)
+    });
+    let message = window_line(
+        format!("Tick times (ms) from last {}: ", window_names()),
+        values,
+    );
+    context.source().send_success(&message, false);
+    Ok(averages[0].mspt as i32)
+}
+
+/// Formats the window lengths, such as `5s, 1m, 5m, 15m`.
+fn window_names() -> String {
+    TICK_WINDOW_SECONDS
+        .map(|seconds| {
+            if seconds < 60 {
+                format!("{seconds}s")
+            } else {
+                format!("{}m", seconds / 60)
+            }
+        })
+        .join(", ")
+}
+
+fn window_line(label: String, values: [TextComponent; TICK_WINDOW_SECONDS.len()]) -> TextComponent {
+    let mut children = Vec::with_capacity(values.len() * 2);
+    for (index, value) in values.into_iter().enumerate() {
+        if index > 0 {
+            children.push(TextComponent::plain(", ").color(Color::Gray));
+        }
+        children.push(value);
+    }
+    TextComponent::plain(label)
+        .color(Color::Gold)
+        .add_children(children)
+}
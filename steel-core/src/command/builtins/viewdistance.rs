@@ -0,0 +1,210 @@
+//! Steel per-player view distance command.
+
+use std::{slice, sync::Arc};
+
+use steel_utils::{Identifier, translations};
+use text_components::TextComponent;
+
+use super::super::{
+    brigadier::{ArgumentType, CommandNodeBuilder, CommandSyntaxError},
+    execution::{
+        CommandSource, SteelArgumentType, SteelCommandContext, SteelCommandRuntime, argument,
+        literal,
+    },
+    registration::CommandRegistration,
+};
+use crate::player::{Player, connection::MIN_VIEW_DISTANCE};
+
+/// The largest view distance the vanilla client offers.
+const MAX_VIEW_DISTANCE: u8 = 32;
+
+pub(super) fn registration() -> CommandRegistration<CommandSource> {
+    CommandRegistration::new(Identifier::from_steel("viewdistance"), |_| command())
+}
+
+fn command() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    literal("viewdistance")
+        .executes(query_sender)
+        .then(distance_argument().executes(set_sender))
+        .then(literal("reset").executes(reset_sender))
+        .then(
+            literal("target").then(
+                argument("targets", SteelArgumentType::players())
+                    .executes(query_targets)
+                    .then(distance_argument().executes(set_targets))
+                    .then(literal("reset").executes(reset_targets)),
+            ),
+        )
+}
+
+fn distance_argument() -> CommandNodeBuilder<CommandSource, SteelCommandRuntime> {
+    argument(
+        "distance",
+        ArgumentType::integer(i32::from(MIN_VIEW_DISTANCE), i32::from(MAX_VIEW_DISTANCE)),
+    )
+}
+
+fn query_sender(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let player = source_player(context)?;
+    Ok(query(context.source(), slice::from_ref(player)))
+}
+
+fn set_sender(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let player = source_player(context)?;
+    let distance = required_distance(context)?;
+    Ok(set_limit(
+        context.source(),
+        slice::from_ref(player),
+        Some(distance),
+    ))
+}
+
+fn reset_sender(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let player = source_player(context)?;
+    Ok(set_limit(context.source(), slice::from_ref(player), None))
+}
+
+fn query_targets(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let targets = context.players("targets")?;
+    Ok(query(context.source(), &targets))
+}
+
+fn set_targets(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let targets = context.players("targets")?;
+    let distance = required_distance(context)?;
+    Ok(set_limit(context.source(), &targets, Some(distance)))
+}
+
+fn reset_targets(context: &SteelCommandContext<CommandSource>) -> Result<i32, CommandSyntaxError> {
+    let targets = context.players("targets")?;
+    Ok(set_limit(context.source(), &targets, None))
+}
+
+fn source_player(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<&Arc<Player>, CommandSyntaxError> {
+    context.source().player().ok_or_else(|| {
+        CommandSyntaxError::dynamic(TextComponent::from(
+            &translations::PERMISSIONS_REQUIRES_PLAYER,
+        ))
+    })
+}
+
+fn required_distance(
+    context: &SteelCommandContext<CommandSource>,
+) -> Result<u8, CommandSyntaxError> {
+    context
+        .integer("distance")
+        .and_then(|distance| u8::try_from(distance).ok())
+        .ok_or_else(|| {
+            CommandSyntaxError::dynamic(
+                "Parsed value for distance is missing from the command context",
+            )
+        })
+}
+
+fn query(source: &CommandSource, targets: &[Arc<Player>]) -> i32 {
+    for target in targets {
+        let limit = target
+            .view_distance_limit()
+            .map_or_else(|| "none".to_owned(), |limit| limit.to_string());
+        source.send_success(
+            &TextComponent::plain(format!(
+                "View distance for player '{}': {} chunks, simulation distance {} chunks (limit: {limit})",
+                target.gameprofile.name,
+                target.view_distance(),
+                target.simulation_distance(),
+            )),
+            false,
+        );
+    }
+    targets
+        .first()
+        .map_or(0, |target| i32::from(target.view_distance()))
+}
+
+fn set_limit(source: &CommandSource, targets: &[Arc<Player>], limit: Option<u8>) -> i32 {
+    for target in targets {
+        target.set_view_distance_limit(limit);
+        let message = match limit {
+            Some(limit) => format!(
+                "Limited view distance for player '{}' to {limit} chunks (now {})",
+                target.gameprofile.name,
+                target.view_distance()
+            ),
+            None => format!(
+                "Removed the view distance limit for player '{}' (now {})",
+                target.gameprofile.name,
+                target.view_distance()
+            ),
+        };
+        source.send_success(&TextComponent::plain(message), true);
+    }
+    targets.len() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::create_dispatcher;
+    use super::{MAX_VIEW_DISTANCE, MIN_VIEW_DISTANCE};
+    use crate::command::{
+        brigadier::{ArgumentType, CommandDispatcher, NodeId},
+        execution::{CommandSource, SteelArgumentType, SteelCommandRuntime},
+    };
+    use steel_registry::test_support::init_test_registry;
+
+    type Dispatcher = CommandDispatcher<CommandSource, SteelCommandRuntime>;
+
+    fn child(dispatcher: &Dispatcher, parent: NodeId, name: &str) -> NodeId {
+        let Some(children) = dispatcher.children(parent) else {
+            panic!("parent node should exist");
+        };
+        let Some(child) = children.iter().copied().find(|child| {
+            dispatcher
+                .node(*child)
+                .is_some_and(|node| node.name() == name)
+        }) else {
+            panic!("child {name} should exist");
+        };
+        child
+    }
+
+    #[test]
+    fn viewdistance_graph_bounds_distance_for_sender_and_targets() {
+        init_test_registry();
+        let Ok(dispatcher) = create_dispatcher() else {
+            panic!("built-in commands should register");
+        };
+        let viewdistance = child(&dispatcher, dispatcher.root(), "viewdistance");
+        let targets = child(
+            &dispatcher,
+            child(&dispatcher, viewdistance, "target"),
+            "targets",
+        );
+        assert_eq!(
+            dispatcher
+                .node(targets)
+                .and_then(|node| node.argument_type()),
+            Some(&SteelArgumentType::players())
+        );
+
+        let expected = SteelArgumentType::from(ArgumentType::integer(
+            i32::from(MIN_VIEW_DISTANCE),
+            i32::from(MAX_VIEW_DISTANCE),
+        ));
+        for parent in [viewdistance, targets] {
+            let distance = child(&dispatcher, parent, "distance");
+            assert_eq!(
+                dispatcher
+                    .node(distance)
+                    .and_then(|node| node.argument_type()),
+                Some(&expected)
+            );
+            let reset = child(&dispatcher, parent, "reset");
+            assert!(matches!(
+                dispatcher.node(reset),
+                Some(node) if node.is_executable()
+            ));
+        }
+    }
+}
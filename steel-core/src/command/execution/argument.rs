@@ -15,7 +15,7 @@ use steel_registry::{
     item_stack::ItemStack, timeline::TimelineRef, world_clock::WorldClockRef,
 };
 use steel_utils::{
-    Downcast as _, DowncastType, DowncastTypeKey, ErasedType, Identifier,
+    Downcast as _, DowncastType, DowncastTypeKey, ErasedType, Identifier, Mirror, Rotation,
     nbt::{NbtPath, parse_snbt_argument},
     translations,
     types::GameType,
@@ -27,7 +27,9 @@ use super::{
     ScoreHolderArgument, StructureOrTagKey, WorldArgument,
     biome::{parse_biome_or_tag, suggest_biomes},
     block::{parse_block_predicate, suggest_blocks},
-    coordinates::{parse_block_pos, parse_rotation, parse_vec3, suggest_coordinates},
+    coordinates::{
+        parse_block_pos, parse_column_pos, parse_rotation, parse_vec3, suggest_coordinates,
+    },
     item::{parse_item_stack, suggest_item_stack},
     item_predicate::{parse_item_predicate, suggest_item_predicate},
     nbt::parse_nbt_path,
@@ -42,6 +44,7 @@ use super::{
 use crate::chunk::heightmap::HeightmapType;
 use crate::command::protocol::protocol_argument_type;
 use crate::entity::{ENTITIES, EntityAnchor};
+use crate::server::functions::FunctionRef;
 
 /// Axes selected by vanilla's coordinate swizzle argument.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -177,6 +180,10 @@ impl SteelArgumentType {
         Self::new(RotationParser)
     }
 
+    pub(crate) fn column_pos() -> Self {
+        Self::new(ColumnPosParser)
+    }
+
     pub(crate) fn swizzle() -> Self {
         Self::new(SwizzleParser)
     }
@@ -327,10 +334,30 @@ impl SteelArgumentType {
         Self::new(StorageKeyParser)
     }
 
+    pub(crate) fn function() -> Self {
+        Self::new(FunctionParser)
+    }
+
     pub(crate) fn world_clock() -> Self {
         Self::new(WorldClockParser)
     }
 
+    pub(crate) fn resource_location() -> Self {
+        Self::new(ResourceLocationParser)
+    }
+
+    pub(crate) fn resource_key(registry: &'static str) -> Self {
+        Self::new(ResourceKeyParser { registry })
+    }
+
+    pub(crate) fn template_rotation() -> Self {
+        Self::new(TemplateRotationParser)
+    }
+
+    pub(crate) fn template_mirror() -> Self {
+        Self::new(TemplateMirrorParser)
+    }
+
     pub(crate) fn timeline(clock_argument: Option<&'static str>) -> Self {
         Self::new(TimelineParser { clock_argument })
     }
@@ -480,6 +507,7 @@ impl_downcast_type!(
 impl_downcast_type!(BlockPredicate, "steel:command/value/block_predicate");
 impl_downcast_type!(WorldArgument, "steel:command/value/world");
 impl_downcast_type!(ItemPredicate, "steel:command/value/item_predicate");
+impl_downcast_type!(FunctionRef, "steel:command/value/function");
 
 macro_rules! argument_value_wrapper {
     ($name:ident($value:ty), $key:literal) => {
@@ -517,6 +545,14 @@ argument_value_wrapper!(
     "steel:command/value/world_clock"
 );
 argument_value_wrapper!(TimelineValue(TimelineRef), "steel:command/value/timeline");
+argument_value_wrapper!(
+    TemplateRotationValue(Rotation),
+    "steel:command/value/template_rotation"
+);
+argument_value_wrapper!(
+    TemplateMirrorValue(Mirror),
+    "steel:command/value/template_mirror"
+);
 
 macro_rules! unit_argument_parser {
     (
@@ -670,6 +706,16 @@ unit_argument_parser!(
     _builder | {},
     protocol(ProtocolArgumentType::Rotation, None)
 );
+unit_argument_parser!(
+    ColumnPosParser,
+    "steel:command/parser/column_pos",
+    Coordinates,
+    parse | reader,
+    _source | { parse_column_pos(reader) },
+    suggest | _context,
+    _builder | {},
+    protocol(ProtocolArgumentType::ColumnPos, None)
+);
 unit_argument_parser!(
     SwizzleParser,
     "steel:command/parser/swizzle",
@@ -1025,6 +1071,21 @@ unit_argument_parser!(
         Some(ProtocolSuggestionType::AskServer),
     )
 );
+unit_argument_parser!(
+    FunctionParser,
+    "steel:command/parser/function",
+    FunctionRef,
+    parse | reader,
+    _source | { parse_function_ref(reader) },
+    suggest | context,
+    builder | {
+        suggest_functions(context.source(), builder);
+    },
+    protocol(
+        ProtocolArgumentType::Function,
+        Some(ProtocolSuggestionType::AskServer),
+    )
+);
 unit_argument_parser!(
     WorldClockParser,
     "steel:command/parser/world_clock",
@@ -1052,6 +1113,77 @@ unit_argument_parser!(
     )
 );
 
+unit_argument_parser!(
+    ResourceLocationParser,
+    "steel:command/parser/resource_location",
+    IdentifierValue,
+    parse | reader,
+    _source | { parse_identifier(reader).map(IdentifierValue) },
+    suggest | _context,
+    _builder | {},
+    protocol(ProtocolArgumentType::ResourceLocation, None)
+);
+unit_argument_parser!(
+    TemplateRotationParser,
+    "steel:command/parser/template_rotation",
+    TemplateRotationValue,
+    parse | reader,
+    _source | { parse_template_rotation(reader).map(TemplateRotationValue) },
+    suggest | _context,
+    builder | {
+        suggest_names(TEMPLATE_ROTATIONS.iter().map(|(name, _)| *name), builder);
+    },
+    protocol(ProtocolArgumentType::TemplateRotation, None)
+);
+unit_argument_parser!(
+    TemplateMirrorParser,
+    "steel:command/parser/template_mirror",
+    TemplateMirrorValue,
+    parse | reader,
+    _source | { parse_template_mirror(reader).map(TemplateMirrorValue) },
+    suggest | _context,
+    builder | {
+        suggest_names(TEMPLATE_MIRRORS.iter().map(|(name, _)| *name), builder);
+    },
+    protocol(ProtocolArgumentType::TemplateMirror, None)
+);
+
+/// Vanilla `ResourceKeyArgument`: an identifier checked against its registry at execution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ResourceKeyParser {
+    registry: &'static str,
+}
+
+impl_downcast_type!(ResourceKeyParser, "steel:command/parser/resource_key");
+
+impl SteelArgumentParser for ResourceKeyParser {
+    type Value = IdentifierValue;
+
+    fn parse(
+        &self,
+        reader: &mut StringReader<'_>,
+        _source: &dyn CommandArgumentSource,
+    ) -> Result<Self::Value, CommandSyntaxError> {
+        parse_identifier(reader).map(IdentifierValue)
+    }
+
+    fn list_suggestions(
+        &self,
+        _context: &dyn SteelArgumentSuggestionContext,
+        _builder: &mut SuggestionsBuilder<'_>,
+    ) {
+    }
+
+    fn protocol_argument(&self) -> (ProtocolArgumentType, Option<ProtocolSuggestionType>) {
+        (
+            ProtocolArgumentType::ResourceKey {
+                identifier: self.registry,
+            },
+            None,
+        )
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct TimelineParser {
     clock_argument: Option<&'static str>,
@@ -1253,6 +1385,54 @@ fn suggest_heightmaps(builder: &mut SuggestionsBuilder<'_>) {
     }
 }
 
+/// Vanilla `TemplateRotationArgument` names.
+const TEMPLATE_ROTATIONS: [(&str, Rotation); 4] = [
+    ("none", Rotation::None),
+    ("clockwise_90", Rotation::Clockwise90),
+    ("180", Rotation::Clockwise180),
+    ("counterclockwise_90", Rotation::CounterClockwise90),
+];
+
+/// Vanilla `TemplateMirrorArgument` names.
+const TEMPLATE_MIRRORS: [(&str, Mirror); 3] = [
+    ("none", Mirror::None),
+    ("left_right", Mirror::LeftRight),
+    ("front_back", Mirror::FrontBack),
+];
+
+fn parse_string_representable<T: Copy>(
+    reader: &mut StringReader<'_>,
+    values: &[(&str, T)],
+) -> Result<T, CommandSyntaxError> {
+    let start = reader.checkpoint();
+    let raw = reader.read_unquoted_string();
+    if let Some((_, value)) = values.iter().find(|(name, _)| *name == raw) {
+        return Ok(*value);
+    }
+    let message = translations::ARGUMENT_ENUM_INVALID
+        .message([raw.to_owned()])
+        .component();
+    reader.restore(start);
+    Err(reader.error(CommandSyntaxErrorKind::Dynamic(Box::new(message))))
+}
+
+fn parse_template_rotation(reader: &mut StringReader<'_>) -> Result<Rotation, CommandSyntaxError> {
+    parse_string_representable(reader, &TEMPLATE_ROTATIONS)
+}
+
+fn parse_template_mirror(reader: &mut StringReader<'_>) -> Result<Mirror, CommandSyntaxError> {
+    parse_string_representable(reader, &TEMPLATE_MIRRORS)
+}
+
+fn suggest_names<'a>(names: impl Iterator<Item = &'a str>, builder: &mut SuggestionsBuilder<'_>) {
+    let prefix = builder.remaining_lowercase().to_owned();
+    for name in names {
+        if name.starts_with(&prefix) {
+            builder.suggest(name);
+        }
+    }
+}
+
 fn parse_entity_anchor(reader: &mut StringReader<'_>) -> Result<EntityAnchor, CommandSyntaxError> {
     let start = reader.checkpoint();
     let name = reader.read_unquoted_string();
@@ -1431,6 +1611,59 @@ where
     suggest_resources(keys.iter(), builder);
 }
 
+/// Vanilla `FunctionArgument`: a function id, or a tag id after `#`.
+///
+/// Whether the function exists is checked when the command runs, since
+/// reloads can change it after parsing.
+fn parse_function_ref(reader: &mut StringReader<'_>) -> Result<FunctionRef, CommandSyntaxError> {
+    if reader.peek() != Some('#') {
+        return parse_identifier(reader).map(FunctionRef::Function);
+    }
+    let start = reader.checkpoint();
+    reader.skip();
+    match parse_identifier(reader) {
+        Ok(key) => Ok(FunctionRef::Tag(key)),
+        Err(error) => {
+            reader.restore(start);
+            Err(error)
+        }
+    }
+}
+
+fn suggest_functions<S>(source: &S, builder: &mut SuggestionsBuilder<'_>)
+where
+    S: CommandArgumentSource + ?Sized,
+{
+    let parse = |keys: Vec<String>| {
+        keys.into_iter()
+            .filter_map(|key| key.parse::<Identifier>().ok())
+            .collect::<Vec<_>>()
+    };
+    let tags = parse(source.function_tag_keys());
+    let functions = parse(source.function_keys());
+    let remaining = builder.remaining_lowercase();
+    let suggestions = if let Some(tag_prefix) = remaining.strip_prefix('#') {
+        tags.iter()
+            .filter(|tag| identifier_matches(tag_prefix, tag))
+            .map(|tag| format!("#{tag}"))
+            .collect::<Vec<_>>()
+    } else {
+        tags.iter()
+            .filter(|tag| identifier_matches(remaining, tag))
+            .map(|tag| format!("#{tag}"))
+            .chain(
+                functions
+                    .iter()
+                    .filter(|function| identifier_matches(remaining, function))
+                    .map(ToString::to_string),
+            )
+            .collect()
+    };
+    for suggestion in suggestions {
+        builder.suggest(suggestion);
+    }
+}
+
 pub(super) fn matches_substring(pattern: &str, input: &str) -> bool {
     if input.starts_with(pattern) {
         return true;
@@ -36,6 +36,7 @@ use text_components::{TextComponent, content::Content};
 
 use crate::entity::{EntityAnchor, init_test_entities};
 use crate::permission::{PermissionExpr, PermissionState};
+use crate::server::functions::FunctionRef;
 
 use super::argument::SteelArgumentParser;
 
@@ -96,6 +97,14 @@ impl CommandArgumentSource for TestSource {
         vec!["minecraft:global".to_owned(), "steel:data".to_owned()]
     }
 
+    fn function_keys(&self) -> Vec<String> {
+        vec!["steel:setup".to_owned(), "steel:tick/mobs".to_owned()]
+    }
+
+    fn function_tag_keys(&self) -> Vec<String> {
+        vec!["minecraft:tick".to_owned()]
+    }
+
     fn permission_rule_suggestions(&self) -> Vec<String> {
         vec![
             "minecraft.command.gamemode".to_owned(),
@@ -993,6 +1002,23 @@ fn rotation_argument_retains_yaw_then_pitch_expressions() {
     assert!(parsed_coordinates(&dispatcher, "coordinates ^ ^").is_err());
 }
 
+#[test]
+fn column_pos_argument_retains_x_and_z_with_a_relative_y() {
+    let dispatcher = coordinate_dispatcher(SteelArgumentType::column_pos());
+
+    assert_eq!(
+        parsed_coordinates(&dispatcher, "coordinates -3 ~2"),
+        Ok(Coordinates::World(WorldCoordinates::new(
+            WorldCoordinate::new(false, -3.0),
+            WorldCoordinate::new(true, 0.0),
+            WorldCoordinate::new(true, 2.0),
+        )))
+    );
+    assert!(parsed_coordinates(&dispatcher, "coordinates 5").is_err());
+    assert!(parsed_coordinates(&dispatcher, "coordinates 0.5 1").is_err());
+    assert!(parsed_coordinates(&dispatcher, "coordinates ^ ^").is_err());
+}
+
 #[test]
 fn coordinate_suggestions_include_vanilla_partial_prefixes() {
     let dispatcher = coordinate_dispatcher(SteelArgumentType::block_pos());
@@ -1105,6 +1131,45 @@ fn storage_key_argument_parses_and_suggests_source_domain_keys() {
     );
 }
 
+#[test]
+fn function_argument_parses_functions_and_tags_and_suggests_both() {
+    let dispatcher = resource_dispatcher(SteelArgumentType::function());
+    for (input, expected) in [
+        (
+            "resource steel:setup",
+            FunctionRef::Function(Identifier::from_steel("setup")),
+        ),
+        (
+            "resource #tick",
+            FunctionRef::Tag(Identifier::vanilla_static("tick")),
+        ),
+    ] {
+        let parse = dispatcher.parse(input, TestSource::new());
+        let Ok(chain) = dispatcher.context_chain(parse) else {
+            panic!("function argument should parse: {input}");
+        };
+        assert_eq!(chain.top_context().function("value"), Some(&expected));
+    }
+
+    for (input, expected) in [
+        ("resource ti", vec!["#minecraft:tick", "steel:tick/mobs"]),
+        ("resource #", vec!["#minecraft:tick"]),
+    ] {
+        let parse = dispatcher.parse(input, TestSource::new());
+        let Ok(suggestions) = dispatcher.completion_suggestions(&parse) else {
+            panic!("function suggestions should build");
+        };
+        assert_eq!(
+            suggestions
+                .list()
+                .iter()
+                .map(Suggestion::text)
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+}
+
 #[test]
 fn game_mode_argument_parses_only_vanilla_names() {
     let dispatcher = resource_dispatcher(SteelArgumentType::game_mode());
@@ -12,7 +12,9 @@ use steel_registry::{
     enchantment::EnchantmentRef, entity_type::EntityTypeRef, item_stack::ItemStack,
     timeline::TimelineRef, world_clock::WorldClockRef,
 };
-use steel_utils::{DowncastType, Identifier, nbt::NbtPath, translations, types::GameType};
+use steel_utils::{
+    DowncastType, Identifier, Mirror, Rotation, nbt::NbtPath, translations, types::GameType,
+};
 use text_components::TextComponent;
 
 use crate::command::brigadier::{
@@ -28,7 +30,8 @@ use super::{
     argument::{
         ComponentValue, CoordinateAxes, DomainValue, EnchantmentValue, EntityTypeValue,
         GameModeValue, IdentifierValue, ItemStackValue, NbtPathValue, ObjectiveValue,
-        SteelArgumentValue, TimeValue, TimelineValue, WorldClockValue,
+        SteelArgumentValue, TemplateMirrorValue, TemplateRotationValue, TimeValue, TimelineValue,
+        WorldClockValue,
     },
     selector::EntitySelector,
 };
@@ -38,6 +41,7 @@ use crate::{
     permission::{PermissionMetadataExpression, PermissionRuleExpression},
     player::Player,
     scoreboard::ScoreHolder,
+    server::functions::FunctionRef,
 };
 
 /// Runtime model interpreted by Steel's tick-owned command scheduler.
@@ -327,6 +331,10 @@ where
             .map(|value| &value.0)
     }
 
+    pub(crate) fn function(&self, name: &str) -> Option<&FunctionRef> {
+        self.typed_argument(name)
+    }
+
     pub(crate) fn world_clock(&self, name: &str) -> Option<WorldClockRef> {
         self.typed_argument::<WorldClockValue>(name)
             .map(|value| value.0)
@@ -337,6 +345,16 @@ where
             .map(|value| value.0)
     }
 
+    pub(crate) fn template_rotation(&self, name: &str) -> Option<Rotation> {
+        self.typed_argument::<TemplateRotationValue>(name)
+            .map(|value| value.0)
+    }
+
+    pub(crate) fn template_mirror(&self, name: &str) -> Option<Mirror> {
+        self.typed_argument::<TemplateMirrorValue>(name)
+            .map(|value| value.0)
+    }
+
     pub(crate) fn entity_selector(&self, name: &str) -> Option<&EntitySelector> {
         self.typed_argument(name)
     }
@@ -3,7 +3,8 @@ use std::{collections::BTreeSet, sync::Arc};
 use glam::DVec3;
 use steel_registry::{
     vanilla_game_rules::{
-        LOG_ADMIN_COMMANDS, MAX_COMMAND_FORKS, MAX_COMMAND_SEQUENCE_LENGTH, SEND_COMMAND_FEEDBACK,
+        COMMAND_BLOCK_OUTPUT, LOG_ADMIN_COMMANDS, MAX_COMMAND_FORKS, MAX_COMMAND_SEQUENCE_LENGTH,
+        SEND_COMMAND_FEEDBACK,
     },
     world_clock::WorldClockRef,
 };
@@ -14,7 +15,7 @@ use crate::{
     command::{
         brigadier::CommandSyntaxError,
         registration::{entity_selector_advanced_permission_expr, entity_selector_permission_expr},
-        sender::CommandSender,
+        sender::{CommandBlockSender, CommandSender},
     },
     entity::{Entity, EntityAnchor, SharedEntity},
     permission::{
@@ -94,6 +95,14 @@ pub(crate) trait CommandArgumentSource: Send + Sync {
         Vec::new()
     }
 
+    fn function_keys(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn function_tag_keys(&self) -> Vec<String> {
+        Vec::new()
+    }
+
     fn permission_rule_suggestions(&self) -> Vec<String> {
         Vec::new()
     }
@@ -276,6 +285,27 @@ impl CommandSource {
         }
     }
 
+    /// Vanilla `BaseCommandBlock.createCommandSourceStack`: the command runs
+    /// from the centre of the block with vanilla's zero rotation.
+    pub(crate) fn for_command_block(block: CommandBlockSender, server: Arc<Server>) -> Self {
+        let world = Arc::clone(block.world());
+        let (x, y, z) = block.pos().get_center();
+        let authorization = CommandAuthorizationContext::unrestricted(world.key.clone());
+        Self {
+            sender: CommandSender::CommandBlock(block),
+            player: None,
+            entity: None,
+            world,
+            server,
+            position: DVec3::new(x, y, z),
+            rotation: (0.0, 0.0),
+            anchor: EntityAnchor::default(),
+            authorization,
+            callback: CommandResultCallback::empty(),
+            silent: false,
+        }
+    }
+
     #[expect(
         dead_code,
         reason = "source-aware runtime extensions need access to the original sender"
@@ -292,6 +322,14 @@ impl CommandSource {
         self.entity.as_ref()
     }
 
+    /// Vanilla `CommandSourceStack.getDisplayName`.
+    pub(crate) fn display_name(&self) -> TextComponent {
+        self.entity.as_ref().map_or_else(
+            || TextComponent::plain(self.sender.to_string()),
+            |entity| entity.display_name(),
+        )
+    }
+
     pub(crate) const fn world(&self) -> &Arc<World> {
         &self.world
     }
@@ -375,16 +413,21 @@ impl CommandSource {
         self.position
     }
 
-    #[expect(
-        dead_code,
-        reason = "silent command-source derivation is retained for future vanilla command paths"
-    )]
     pub(crate) fn with_suppressed_output(&self) -> Self {
         let mut source = self.clone();
         source.silent = true;
         source
     }
 
+    /// Vanilla `withPermission(LevelBasedPermissionSet.GAMEMASTER)`. Steel
+    /// has no permission levels, so like command blocks the source runs
+    /// unrestricted.
+    pub(crate) fn with_gamemaster_permissions(&self) -> Self {
+        let mut source = self.clone();
+        source.authorization = CommandAuthorizationContext::unrestricted(self.world.key.clone());
+        source
+    }
+
     #[expect(
         dead_code,
         reason = "custom executors need to inspect silent command-source state"
@@ -398,14 +441,22 @@ impl CommandSource {
             return;
         }
 
-        let accepts_success = self
-            .sender
-            .get_player()
-            .is_none_or(|player| player.get_world().get_game_rule(&SEND_COMMAND_FEEDBACK));
+        let (accepts_success, informs_admins) = match &self.sender {
+            CommandSender::CommandBlock(block) => {
+                let output = block.world().get_game_rule(&COMMAND_BLOCK_OUTPUT);
+                (output, output)
+            }
+            sender => (
+                sender
+                    .get_player()
+                    .is_none_or(|player| player.get_world().get_game_rule(&SEND_COMMAND_FEEDBACK)),
+                true,
+            ),
+        };
         if accepts_success {
             self.sender.send_message(message);
         }
-        if broadcast_to_admins {
+        if broadcast_to_admins && informs_admins {
             self.broadcast_to_admins(message);
         }
     }
@@ -517,6 +568,20 @@ impl CommandArgumentSource for CommandSource {
             })
     }
 
+    fn function_keys(&self) -> Vec<String> {
+        let contents = self.server.datapacks.contents();
+        contents.functions.keys().map(ToString::to_string).collect()
+    }
+
+    fn function_tag_keys(&self) -> Vec<String> {
+        let contents = self.server.datapacks.contents();
+        contents
+            .function_tags
+            .keys()
+            .map(ToString::to_string)
+            .collect()
+    }
+
     fn permission_rule_suggestions(&self) -> Vec<String> {
         self.server.permission_rule_suggestions()
     }
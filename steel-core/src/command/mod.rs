@@ -1,5 +1,6 @@
 //! Brigadier-compatible command parsing, execution, and sender handling.
 
+mod aliases;
 mod api;
 pub(crate) mod brigadier;
 mod builtins;
@@ -19,11 +20,15 @@ pub use api::{
 };
 pub use execution::CommandSuspensionOrder;
 
+pub(crate) use aliases::alias_command;
 pub(crate) use builtins::{
     create_registered_dispatcher, gamemode::handle_client_request, player_can_change_difficulty,
 };
 pub(crate) use pending_execution::{COMMAND_RESUMPTIONS_PER_TICK, PendingCommandExecutionQueue};
 pub(crate) use protocol::{command_suggestions_packet, command_tree_packet};
+pub(crate) use registration::{
+    game_master_blocks_permission_expr, join_queue_bypass_permission_expr,
+};
 pub use request_queue::CommandQueueFull;
 pub(crate) use request_queue::{COMMAND_REQUESTS_PER_TICK, CommandRequest, CommandRequestQueue};
 
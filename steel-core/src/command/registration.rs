@@ -22,6 +22,8 @@ type CommandFactory<S> =
 
 pub(crate) const ENTITY_SELECTOR_PERMISSION_KEY: &str = "minecraft.selector";
 pub(crate) const ENTITY_SELECTOR_ADVANCED_PERMISSION_KEY: &str = "minecraft.selector.advanced";
+pub(crate) const GAME_MASTER_BLOCKS_PERMISSION_KEY: &str = "minecraft.gamemaster_blocks";
+pub(crate) const JOIN_QUEUE_BYPASS_PERMISSION_KEY: &str = "steel.join_queue.bypass";
 
 pub(crate) fn entity_selector_permission_expr() -> Result<PermissionExpr, PermissionKeyError> {
     PermissionKey::parse(ENTITY_SELECTOR_PERMISSION_KEY).map(PermissionExpr::key)
@@ -32,6 +34,14 @@ pub(crate) fn entity_selector_advanced_permission_expr()
     PermissionKey::parse(ENTITY_SELECTOR_ADVANCED_PERMISSION_KEY).map(PermissionExpr::key)
 }
 
+pub(crate) fn game_master_blocks_permission_expr() -> Result<PermissionExpr, PermissionKeyError> {
+    PermissionKey::parse(GAME_MASTER_BLOCKS_PERMISSION_KEY).map(PermissionExpr::key)
+}
+
+pub(crate) fn join_queue_bypass_permission_expr() -> Result<PermissionExpr, PermissionKeyError> {
+    PermissionKey::parse(JOIN_QUEUE_BYPASS_PERMISSION_KEY).map(PermissionExpr::key)
+}
+
 /// One complete command tree and its stable owner identity.
 pub(crate) struct CommandRegistration<S>
 where
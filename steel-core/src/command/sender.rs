@@ -1,9 +1,12 @@
 //! Module defining the sender of a command.
 use std::{fmt, sync::Arc};
+use steel_utils::{BlockPos, text::to_ansi};
 use text_components::TextComponent;
 use uuid::Uuid;
 
+use crate::block_entity::entities::CommandBlockEntity;
 use crate::player::Player;
+use crate::world::World;
 
 /// The sender of a command.
 #[derive(Clone)]
@@ -14,6 +17,39 @@ pub enum CommandSender {
     Console,
     /// The command was sent via Rcon.
     Rcon,
+    /// The command is run by a command block.
+    CommandBlock(CommandBlockSender),
+}
+
+/// The command block a command runs from.
+///
+/// Vanilla `BaseCommandBlock` as a `CommandSource`: messages become the
+/// block's last output.
+#[derive(Clone)]
+pub struct CommandBlockSender {
+    world: Arc<World>,
+    pos: BlockPos,
+    name: String,
+}
+
+impl CommandBlockSender {
+    /// Creates the sender for the command block at `pos`, shown as `name`.
+    #[must_use]
+    pub const fn new(world: Arc<World>, pos: BlockPos, name: String) -> Self {
+        Self { world, pos, name }
+    }
+
+    /// Returns the world the command block is in.
+    #[must_use]
+    pub const fn world(&self) -> &Arc<World> {
+        &self.world
+    }
+
+    /// Returns the position of the command block.
+    #[must_use]
+    pub const fn pos(&self) -> BlockPos {
+        self.pos
+    }
 }
 
 /// Stable identity used to preserve top-level command ordering while work is suspended.
@@ -22,6 +58,7 @@ pub(crate) enum CommandSenderKey {
     Player(Uuid),
     Console,
     Rcon,
+    CommandBlock(BlockPos),
 }
 
 impl CommandSender {
@@ -30,6 +67,7 @@ impl CommandSender {
             Self::Player(player) => CommandSenderKey::Player(player.gameprofile.id),
             Self::Console => CommandSenderKey::Console,
             Self::Rcon => CommandSenderKey::Rcon,
+            Self::CommandBlock(block) => CommandSenderKey::CommandBlock(block.pos),
         }
     }
 
@@ -46,9 +84,12 @@ impl CommandSender {
     pub fn send_message(&self, text: &TextComponent) {
         match self {
             Self::Player(player) => player.send_message(text),
-            Self::Console => log::info!("{text}"),
+            Self::Console => log::info!("{}", to_ansi(text)),
             // TODO: Implement Rcon message sending
             Self::Rcon => log::warn!("Dropping Rcon command message until Rcon output is wired"),
+            Self::CommandBlock(block) => {
+                CommandBlockEntity::record_output(&block.world, block.pos, text);
+            }
         }
     }
 }
@@ -62,6 +103,7 @@ impl fmt::Display for CommandSender {
                 Self::Player(p) => &p.gameprofile.name,
                 Self::Console => "Server",
                 Self::Rcon => "Rcon",
+                Self::CommandBlock(block) => &block.name,
             }
         )
     }
@@ -2,22 +2,45 @@
 //!
 //! The full deserialization struct lives in the `steel` crate. Steel-core only
 //! defines `RuntimeConfig` (the subset kept after startup) and the world/domain
-//! configuration types that both crates share.
+//! and custom item configuration types that both crates share.
 
+use arc_swap::ArcSwap;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Deserializer, de::Error as DeError};
 use std::{
+    borrow::Cow,
     collections::BTreeMap,
+    num::NonZeroU32,
     path::{Component, Path, PathBuf},
+    sync::Arc,
 };
+use steel_protocol::packet_reader::PacketLimits;
 pub use steel_protocol::packet_traits::CompressionInfo;
+use steel_protocol::packets::common::{
+    CCustomReportDetails, CResourcePackPush, MAX_REPORT_DETAIL_KEY_LENGTH,
+    MAX_REPORT_DETAIL_VALUE_LENGTH, MAX_REPORT_DETAILS, ReportDetail,
+};
 use steel_protocol::packets::config::{CServerLinks, Link, ServerLinksType};
-use steel_registry::vanilla_dimension_types;
+use steel_protocol::utils::ConnectionProtocol;
+use steel_registry::data_components::components::{
+    Consumable, CustomModelData, Equippable, FoodProperties, ItemUseAnimation,
+};
+use steel_registry::data_components::vanilla_components::{
+    CONSUMABLE, CUSTOM_MODEL_DATA, EQUIPPABLE, FOOD, ITEM_MODEL, MAX_DAMAGE, MAX_STACK_SIZE,
+};
+use steel_registry::dimension_type::{DimensionType, DimensionTypeRegistry};
+use steel_registry::equipment::EquipmentSlot;
+use steel_registry::items::{Item, ItemRef, ItemRegistry};
+use steel_registry::packets::{config, play};
+use steel_registry::sound_event::SoundEventHolder;
+use steel_registry::{RegistryExt, sound_events, vanilla_dimension_types};
 use steel_utils::Identifier;
 use steel_utils::codec::Or;
 use steel_utils::types::{Difficulty, GameType};
 use text_components::TextComponent;
+use text_components::translation::TranslatedMessage;
 use toml::map::Map;
+use uuid::{Builder, Uuid, Variant, Version};
 
 use crate::chunk_saver::registry::WorldStorageRegistry;
 use crate::worldgen::registry::{ValidatedWorldGeneratorConfig, WorldGeneratorRegistry};
@@ -43,6 +66,12 @@ pub const fn validate_login_security(
     }
 }
 
+/// Runtime configuration shared by the server and its players.
+///
+/// `/steel reload config` swaps in a new snapshot, so read it with `load()` at
+/// the point of use instead of keeping a snapshot around.
+pub type SharedRuntimeConfig = Arc<ArcSwap<RuntimeConfig>>;
+
 /// Runtime server configuration — the subset of settings needed after startup.
 ///
 /// Stored on `Server` and accessed by game logic at runtime.
@@ -54,6 +83,8 @@ pub struct RuntimeConfig {
     pub view_distance: u8,
     /// The simulation distance of the server.
     pub simulation_distance: u8,
+    /// Percentage applied to vanilla entity tracking ranges.
+    pub entity_broadcast_range_percentage: u16,
     /// Whether the server is in online mode.
     pub online_mode: bool,
     /// Optional authentication endpoint for online-mode `hasJoined` checks.
@@ -64,6 +95,8 @@ pub struct RuntimeConfig {
     pub encryption: bool,
     /// Whether vanilla floating/flying movement checks permit unauthorized flight.
     pub allow_flight: bool,
+    /// Whether clients transferred from another server may join.
+    pub accepts_transfers: bool,
     /// The message of the day.
     pub motd: String,
     /// Whether to use a favicon.
@@ -80,8 +113,29 @@ pub struct RuntimeConfig {
     pub compression: Option<CompressionInfo>,
     /// All settings and configurations for server links.
     pub server_links: Option<ServerLinks>,
+    /// Details added to client crash and disconnect reports.
+    pub report_details: ReportDetailsConfig,
     /// Optional worker count for the Rayon chunk generation pool.
     pub chunk_generation_threads: Option<usize>,
+    /// Optional worker count for parallel random block ticks; unset, `0` and
+    /// `1` disable them.
+    pub random_tick_threads: Option<usize>,
+    /// Server resource packs offered to players during configuration.
+    pub resource_packs: Vec<ResourcePackConfig>,
+    /// Player information forwarding from a proxy.
+    pub proxy: ProxyConfig,
+    /// Per-connection limits on what clients may send.
+    pub packet_limits: PacketLimitConfig,
+    /// Main tick stall detection.
+    pub watchdog: WatchdogConfig,
+    /// Chat message formatting.
+    pub chat: ChatConfig,
+    /// Skin lookup for players in offline mode.
+    pub offline_skins: OfflineSkinsConfig,
+    /// Waiting for a free slot when the server is full.
+    pub join_queue: JoinQueueConfig,
+    /// Command aliases and macros from `commands.toml`.
+    pub command_aliases: CommandAliasesConfig,
 }
 
 impl RuntimeConfig {
@@ -108,6 +162,291 @@ impl RuntimeConfig {
 
         Some(CServerLinks { links })
     }
+
+    /// Builds the `CCustomReportDetails` packet, if report details are enabled.
+    #[must_use]
+    pub fn report_details_packet(&self) -> Option<CCustomReportDetails> {
+        self.report_details
+            .enable
+            .then(|| CCustomReportDetails::new(self.report_details.details()))
+    }
+
+    /// Returns whether declining the configured resource pack `id` disconnects the player.
+    #[must_use]
+    pub fn is_resource_pack_required(&self, id: Uuid) -> bool {
+        self.resource_packs
+            .iter()
+            .any(|pack| pack.required && pack.id() == id)
+    }
+}
+
+/// How a proxy in front of the server forwards player information.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProxyForwarding {
+    /// Players connect directly.
+    #[default]
+    None,
+    /// BungeeCord legacy forwarding through the handshake host field.
+    BungeeCord,
+    /// Velocity modern forwarding through a signed login plugin message.
+    Velocity,
+}
+
+/// Player information forwarding from a proxy.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProxyConfig {
+    /// The forwarding mode the proxy uses.
+    #[serde(default)]
+    pub forwarding: ProxyForwarding,
+    /// Velocity's `forwarding-secret`, used to verify forwarded data.
+    #[serde(default)]
+    pub secret: String,
+}
+
+/// Per-connection limits on what clients may send.
+///
+/// A connection exceeding a limit is disconnected.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PacketLimitConfig {
+    /// Packets per second accepted while handshaking. 0 disables the limit.
+    pub handshake_rate: u32,
+    /// Packets per second accepted while answering a status ping. 0 disables the limit.
+    pub status_rate: u32,
+    /// Packets per second accepted while logging in. 0 disables the limit.
+    pub login_rate: u32,
+    /// Packets per second accepted while configuring. 0 disables the limit.
+    pub config_rate: u32,
+    /// Packets per second accepted while playing. 0 disables the limit.
+    pub play_rate: u32,
+    /// Largest accepted custom payload packet in bytes.
+    pub max_custom_payload_size: usize,
+    /// Largest accepted chat message packet in bytes.
+    pub max_chat_size: usize,
+}
+
+impl Default for PacketLimitConfig {
+    fn default() -> Self {
+        Self {
+            handshake_rate: 20,
+            status_rate: 20,
+            login_rate: 50,
+            config_rate: 200,
+            play_rate: 500,
+            // Vanilla `ServerboundCustomPayloadPacket.MAX_PAYLOAD_SIZE`.
+            max_custom_payload_size: 32767,
+            max_chat_size: 2048,
+        }
+    }
+}
+
+impl PacketLimitConfig {
+    /// Returns the limits applied to a connection in `protocol`.
+    #[must_use]
+    pub fn limits(&self, protocol: ConnectionProtocol) -> PacketLimits {
+        let (rate, max_payload_sizes) = match protocol {
+            ConnectionProtocol::Handshake => (self.handshake_rate, FxHashMap::default()),
+            ConnectionProtocol::Status => (self.status_rate, FxHashMap::default()),
+            ConnectionProtocol::Login => (self.login_rate, FxHashMap::default()),
+            ConnectionProtocol::Config => (
+                self.config_rate,
+                FxHashMap::from_iter([(config::S_CUSTOM_PAYLOAD, self.max_custom_payload_size)]),
+            ),
+            ConnectionProtocol::Play => (
+                self.play_rate,
+                FxHashMap::from_iter([
+                    (play::S_CUSTOM_PAYLOAD, self.max_custom_payload_size),
+                    (play::S_CHAT, self.max_chat_size),
+                ]),
+            ),
+        };
+        PacketLimits {
+            max_per_second: NonZeroU32::new(rate),
+            max_payload_sizes,
+        }
+    }
+}
+
+/// Main tick stall detection.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WatchdogConfig {
+    /// Whether to watch the main tick loop at all.
+    pub enable: bool,
+    /// Seconds a single tick may run before it counts as stalled.
+    pub stall_threshold_seconds: u64,
+    /// Whether a stall saves the worlds and shuts the server down.
+    pub shutdown_on_stall: bool,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enable: true,
+            stall_threshold_seconds: 10,
+            shutdown_on_stall: false,
+        }
+    }
+}
+
+/// Chat message formatting and secure chat behaviour.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ChatConfig {
+    /// Format of chat lines, or empty for vanilla signed chat.
+    ///
+    /// Either the format itself or a key of the server translations, resolved
+    /// in each recipient's language. `{prefix}`, `{suffix}`, `{name}` and
+    /// `{message}` are replaced with the sender's `steel:prefix` and
+    /// `steel:suffix` permission metadata, name and message.
+    pub format: String,
+    /// Whether signatures are dropped and chat is sent as disguised messages,
+    /// which clients cannot verify or report.
+    pub strip_signatures: bool,
+    /// Whether deleting a signed message hides it on clients. When off, the
+    /// message stays visible.
+    pub send_deletions: bool,
+    /// The built-in word list filter.
+    pub filter: ChatFilterConfig,
+}
+
+impl Default for ChatConfig {
+    fn default() -> Self {
+        Self {
+            format: String::new(),
+            strip_signatures: false,
+            send_deletions: true,
+            filter: ChatFilterConfig::default(),
+        }
+    }
+}
+
+/// The built-in chat word filter.
+///
+/// Listed words are hidden from players who see filtered chat: players with
+/// text filtering enabled, those chatting with them, or everyone with
+/// `filter_everyone`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ChatFilterConfig {
+    /// Whether the word list is applied.
+    pub enable: bool,
+    /// Words to hide, matched case-insensitively as whole words. A trailing
+    /// `*` matches every word starting with the rest.
+    pub words: Vec<String>,
+    /// Whether every player sees filtered chat, not only those with text
+    /// filtering enabled.
+    pub filter_everyone: bool,
+}
+
+/// Skin lookup for players in offline mode.
+///
+/// Offline-mode profiles carry no textures, so clients would draw the default
+/// skins. When enabled, the joining name is resolved through `providers` and the
+/// result is cached on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OfflineSkinsConfig {
+    /// Whether offline-mode players get skins resolved by name.
+    pub enable: bool,
+    /// Providers tried in order until one knows the name.
+    pub providers: Vec<SkinProvider>,
+    /// Seconds a resolved skin is reused from the disk cache.
+    pub cache_ttl_seconds: u64,
+}
+
+impl Default for OfflineSkinsConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            providers: vec![SkinProvider::Mojang],
+            cache_ttl_seconds: 86_400,
+        }
+    }
+}
+
+/// Waiting for a free slot when the server is full.
+///
+/// Without the queue, players joining a full server are disconnected like in
+/// vanilla. With it, they are held in the configuration state and admitted in
+/// order as slots free up.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct JoinQueueConfig {
+    /// Whether players joining a full server wait instead of being disconnected.
+    pub enable: bool,
+    /// Seconds between queue position updates sent to waiting players.
+    pub update_interval_seconds: u64,
+}
+
+impl Default for JoinQueueConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            update_interval_seconds: 5,
+        }
+    }
+}
+
+/// A source of skins for offline-mode names.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase", deny_unknown_fields)]
+pub enum SkinProvider {
+    /// The skin of the premium account with the same name, from Mojang's
+    /// profile and session services.
+    Mojang,
+    /// Session server profile responses saved as `<name>.json` in `path`.
+    Directory {
+        /// Directory holding the profile files.
+        path: PathBuf,
+    },
+}
+
+/// A server resource pack offered to players while they configure.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResourcePackConfig {
+    /// Download URL of the pack.
+    pub url: String,
+    /// SHA-1 of the pack as 40 hex characters. Empty skips client verification.
+    #[serde(default)]
+    pub sha1: String,
+    /// Pack id. Defaults to vanilla's name-based UUID of the URL.
+    #[serde(default)]
+    pub id: Option<Uuid>,
+    /// Message shown in the download prompt.
+    #[serde(default)]
+    pub prompt: Option<TextComponent>,
+    /// Whether players who decline the pack are disconnected.
+    #[serde(default)]
+    pub required: bool,
+}
+
+impl ResourcePackConfig {
+    /// Returns the pack id. Vanilla `UUID.nameUUIDFromBytes(url)` when unset.
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.id.unwrap_or_else(|| {
+            Builder::from_md5_bytes(md5::compute(self.url.as_bytes()).0)
+                .with_version(Version::Md5)
+                .with_variant(Variant::RFC4122)
+                .into_uuid()
+        })
+    }
+
+    /// Builds the packet asking clients to apply this pack.
+    #[must_use]
+    pub fn push_packet(&self) -> CResourcePackPush {
+        CResourcePackPush {
+            id: self.id(),
+            url: self.url.clone(),
+            hash: self.sha1.clone(),
+            required: self.required,
+            prompt: self.prompt.clone(),
+        }
+    }
 }
 
 /// Label type for server links — either built-in string or custom `TextComponent`.
@@ -144,6 +483,52 @@ pub struct ServerLinks {
     pub links: Vec<ConfigLink>,
 }
 
+/// Details added to client crash and disconnect reports.
+///
+/// The server brand and versions are always included; `extra` adds more,
+/// such as a contact address for bug reports.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReportDetailsConfig {
+    /// Whether clients are sent report details.
+    pub enable: bool,
+    /// Additional details by name.
+    pub extra: BTreeMap<String, String>,
+}
+
+impl ReportDetailsConfig {
+    /// The server build followed by the extra details, with anything past the
+    /// vanilla limits dropped or truncated.
+    fn details(&self) -> Vec<ReportDetail> {
+        let build = [
+            ("Server brand", "Steel"),
+            ("Steel version", env!("CARGO_PKG_VERSION")),
+            ("Minecraft version", steel_utils::MC_VERSION),
+        ];
+        build
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .chain(self.extra.clone())
+            .take(MAX_REPORT_DETAILS)
+            .map(|(key, value)| {
+                ReportDetail::new(
+                    key.chars().take(MAX_REPORT_DETAIL_KEY_LENGTH).collect(),
+                    value.chars().take(MAX_REPORT_DETAIL_VALUE_LENGTH).collect(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Default for ReportDetailsConfig {
+    fn default() -> Self {
+        Self {
+            enable: true,
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
 /// Configuration for world storage.
 #[derive(Debug, Clone)]
 pub enum WorldStorageConfig {
@@ -179,6 +564,9 @@ pub struct WorldsConfig {
     /// Global player data storage selection.
     #[serde(default)]
     pub player_storage: Option<StorageSelection>,
+    /// Custom dimension types keyed by identifier, registered before worlds load.
+    #[serde(default)]
+    pub dimension_types: BTreeMap<String, DimensionTypeConfig>,
     /// Domain declarations keyed by domain name.
     pub domains: BTreeMap<String, DomainConfig>,
 }
@@ -241,6 +629,371 @@ pub struct WorldEntryConfig {
     pub config: Option<toml::Value>,
 }
 
+/// Custom dimension type copied from a base type with selected overrides.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DimensionTypeConfig {
+    /// Dimension type whose remaining properties are copied.
+    #[serde(default = "default_base_dimension_type")]
+    pub base: Identifier,
+    /// Lowest block Y level.
+    #[serde(default)]
+    pub min_y: Option<i32>,
+    /// Total build height.
+    #[serde(default)]
+    pub height: Option<i32>,
+    /// Height portals and chorus fruit are limited to.
+    #[serde(default)]
+    pub logical_height: Option<i32>,
+    /// Horizontal coordinate scale used by portal travel.
+    #[serde(default)]
+    pub coordinate_scale: Option<f64>,
+    /// Whether the dimension has sky light.
+    #[serde(default)]
+    pub has_skylight: Option<bool>,
+    /// Whether the dimension has a bedrock ceiling.
+    #[serde(default)]
+    pub has_ceiling: Option<bool>,
+    /// Ambient light level between 0 and 1.
+    #[serde(default)]
+    pub ambient_light: Option<f32>,
+    /// Fixed time of day, if the sun does not move.
+    #[serde(default)]
+    pub fixed_time: Option<i64>,
+}
+
+impl DimensionTypeConfig {
+    /// Builds the dimension type, applying vanilla `DimensionType.CODEC` bounds.
+    fn build(&self, key: Identifier, base: &DimensionType) -> Result<DimensionType, String> {
+        let mut dimension_type = base.clone();
+        dimension_type.key = key;
+        dimension_type.min_y = self.min_y.unwrap_or(base.min_y);
+        dimension_type.height = self.height.unwrap_or(base.height);
+        dimension_type.logical_height = self.logical_height.unwrap_or(base.logical_height);
+        dimension_type.coordinate_scale = self.coordinate_scale.unwrap_or(base.coordinate_scale);
+        dimension_type.has_skylight = self.has_skylight.unwrap_or(base.has_skylight);
+        dimension_type.has_ceiling = self.has_ceiling.unwrap_or(base.has_ceiling);
+        dimension_type.ambient_light = self.ambient_light.unwrap_or(base.ambient_light);
+        dimension_type.fixed_time = self.fixed_time.or(base.fixed_time);
+
+        let key = &dimension_type.key;
+        if !(16..=DIMENSION_Y_SIZE).contains(&dimension_type.height)
+            || dimension_type.height % 16 != 0
+        {
+            return Err(format!(
+                "dimension type {key} height must be a multiple of 16 between 16 and {DIMENSION_Y_SIZE}"
+            ));
+        }
+        if !(DIMENSION_MIN_Y..=DIMENSION_MAX_Y).contains(&dimension_type.min_y)
+            || dimension_type.min_y % 16 != 0
+        {
+            return Err(format!(
+                "dimension type {key} min_y must be a multiple of 16 between {DIMENSION_MIN_Y} and {DIMENSION_MAX_Y}"
+            ));
+        }
+        if dimension_type.min_y + dimension_type.height > DIMENSION_MAX_Y + 1 {
+            return Err(format!(
+                "dimension type {key} min_y + height cannot exceed {}",
+                DIMENSION_MAX_Y + 1
+            ));
+        }
+        if !(0..=dimension_type.height).contains(&dimension_type.logical_height) {
+            return Err(format!(
+                "dimension type {key} logical_height must be between 0 and height"
+            ));
+        }
+        if !(0.000_01..=30_000_000.0).contains(&dimension_type.coordinate_scale) {
+            return Err(format!(
+                "dimension type {key} coordinate_scale must be between 0.00001 and 30000000"
+            ));
+        }
+        if !(0.0..=1.0).contains(&dimension_type.ambient_light) {
+            return Err(format!(
+                "dimension type {key} ambient_light must be between 0 and 1"
+            ));
+        }
+        Ok(dimension_type)
+    }
+}
+
+/// Parsed `commands.toml` root.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CommandAliasesConfig {
+    /// Aliases keyed by the command name players type.
+    #[serde(default)]
+    pub aliases: BTreeMap<String, CommandAliasConfig>,
+}
+
+/// What a command alias runs: one command, or a macro of several.
+///
+/// Commands may use `$1` to `$9` for the words typed after the alias and `$*`
+/// for all of them.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum CommandAliasConfig {
+    /// A single command.
+    Command(String),
+    /// Commands run in order.
+    Macro {
+        /// The commands, without a leading `/`.
+        run: Vec<String>,
+    },
+}
+
+impl CommandAliasConfig {
+    /// Returns the commands this alias runs, in order.
+    #[must_use]
+    pub fn commands(&self) -> &[String] {
+        match self {
+            Self::Command(command) => std::slice::from_ref(command),
+            Self::Macro { run } => run,
+        }
+    }
+}
+
+impl CommandAliasesConfig {
+    /// Checks alias names and commands, and rejects aliases that end up
+    /// running themselves.
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, alias) in &self.aliases {
+            if name.is_empty()
+                || !name.bytes().all(|byte| {
+                    byte.is_ascii_lowercase()
+                        || byte.is_ascii_digit()
+                        || byte == b'_'
+                        || byte == b'-'
+                })
+            {
+                return Err(format!(
+                    "command alias '{name}' may only contain a-z, 0-9, '_' and '-'"
+                ));
+            }
+            if alias.commands().is_empty() {
+                return Err(format!(
+                    "command alias '{name}' must run at least one command"
+                ));
+            }
+            if alias
+                .commands()
+                .iter()
+                .any(|command| command.trim().is_empty())
+            {
+                return Err(format!("command alias '{name}' has an empty command"));
+            }
+        }
+        for name in self.aliases.keys() {
+            self.check_cycle(name, &mut Vec::new())?;
+        }
+        Ok(())
+    }
+
+    /// Follows the aliases `name` runs, failing when one leads back to an
+    /// alias already on `path`.
+    fn check_cycle<'a>(&'a self, name: &'a str, path: &mut Vec<&'a str>) -> Result<(), String> {
+        if path.contains(&name) {
+            path.push(name);
+            return Err(format!("command alias cycle: {}", path.join(" -> ")));
+        }
+        let Some(alias) = self.aliases.get(name) else {
+            return Ok(());
+        };
+        path.push(name);
+        for command in alias.commands() {
+            let command = command.trim_start().trim_start_matches('/');
+            let root = command.split_whitespace().next().unwrap_or_default();
+            self.check_cycle(root, path)?;
+        }
+        path.pop();
+        Ok(())
+    }
+}
+
+/// Parsed `items.toml` root.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ItemsConfig {
+    /// Custom items keyed by identifier, registered before the registry freezes.
+    #[serde(default)]
+    pub items: BTreeMap<String, CustomItemConfig>,
+}
+
+/// Custom item that vanilla clients see as its base item.
+///
+/// The item starts from the base item's components. Resource packs style it
+/// through `item_model` and `custom_model_data`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomItemConfig {
+    /// Item clients are sent in place of this one.
+    pub base: Identifier,
+    /// Item name. Defaults to the `item.<namespace>.<path>` translation.
+    #[serde(default)]
+    pub name: Option<TextComponent>,
+    /// Item model. Defaults to the item key.
+    #[serde(default)]
+    pub item_model: Option<Identifier>,
+    /// Maximum stack size between 1 and 99.
+    #[serde(default)]
+    pub max_stack_size: Option<i32>,
+    /// Makes the item edible with vanilla's default food consumable.
+    #[serde(default)]
+    pub food: Option<FoodConfig>,
+    /// Makes the item wearable in an equipment slot.
+    #[serde(default)]
+    pub equippable: Option<EquippableConfig>,
+    /// Values resource pack item models can select on.
+    #[serde(default)]
+    pub custom_model_data: Option<CustomModelDataConfig>,
+}
+
+/// `minecraft:food` values of a custom item.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FoodConfig {
+    /// Hunger points restored.
+    pub nutrition: i32,
+    /// Saturation added.
+    pub saturation: f32,
+    /// Whether the item can be eaten on a full hunger bar.
+    #[serde(default)]
+    pub can_always_eat: bool,
+}
+
+/// `minecraft:equippable` values of a custom item.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EquippableConfig {
+    /// Equipment slot name, e.g. `head`.
+    pub slot: String,
+    /// Equipment asset rendered when worn.
+    #[serde(default)]
+    pub asset_id: Option<Identifier>,
+}
+
+/// `minecraft:custom_model_data` values of a custom item.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct CustomModelDataConfig {
+    /// Values read by `custom_model_data` range dispatch properties.
+    pub floats: Vec<f32>,
+    /// Values read by `custom_model_data` condition properties.
+    pub flags: Vec<bool>,
+    /// Values read by `custom_model_data` select properties.
+    pub strings: Vec<String>,
+    /// Values read by `custom_model_data` tint sources.
+    pub colors: Vec<i32>,
+}
+
+impl ItemsConfig {
+    /// Registers the custom items into `registry` before it is frozen.
+    ///
+    /// # Errors
+    /// Returns a startup error for invalid keys, unknown bases or invalid components.
+    pub fn register_items(&self, registry: &mut ItemRegistry) -> Result<(), String> {
+        for (key, config) in &self.items {
+            let Ok(key) = key.parse::<Identifier>() else {
+                return Err(format!("invalid item key {key}"));
+            };
+            if registry.by_key(&key).is_some() {
+                return Err(format!("item {key} is already registered"));
+            }
+            let Some(base) = registry.by_key(&config.base) else {
+                return Err(format!("unknown base item {} for {key}", config.base));
+            };
+            let item = config.build(key, base)?;
+            // Registry entries are `&'static`; custom items live for the whole process.
+            registry.register(Box::leak(Box::new(item)));
+        }
+        Ok(())
+    }
+}
+
+impl CustomItemConfig {
+    /// Builds the item, applying vanilla `Item.Properties` validation.
+    fn build(&self, key: Identifier, base: ItemRef) -> Result<Item, String> {
+        let name = self.name.clone().unwrap_or_else(|| {
+            TextComponent::translated(TranslatedMessage {
+                key: Cow::Owned(format!("item.{}.{}", key.namespace, key.path)),
+                fallback: None,
+                args: None,
+            })
+        });
+        let mut item = Item::custom(key.clone(), name, base);
+        let components = &mut item.components;
+        if let Some(item_model) = &self.item_model {
+            components.set(ITEM_MODEL, Some(item_model.clone()));
+        }
+        if let Some(max_stack_size) = self.max_stack_size {
+            if !(1..=99).contains(&max_stack_size) {
+                return Err(format!(
+                    "item {key} max_stack_size must be between 1 and 99"
+                ));
+            }
+            components.set(MAX_STACK_SIZE, Some(max_stack_size));
+        }
+        if components.get(MAX_STACK_SIZE).unwrap_or(1) > 1 && components.has(MAX_DAMAGE) {
+            return Err(format!(
+                "item {key} cannot have both durability and a max_stack_size above 1"
+            ));
+        }
+        if let Some(food) = &self.food {
+            let food = FoodProperties::new(food.nutrition, food.saturation, food.can_always_eat)
+                .map_err(|error| format!("item {key} has invalid food: {error}"))?;
+            // Vanilla `Item.Properties.food` pairs food with `Consumables.DEFAULT_FOOD`.
+            let consumable = Consumable::new(
+                Consumable::DEFAULT_CONSUME_SECONDS,
+                ItemUseAnimation::Eat,
+                SoundEventHolder::registry(&sound_events::ENTITY_GENERIC_EAT),
+                true,
+                Vec::new(),
+            )
+            .map_err(|error| format!("item {key} has invalid consumable: {error}"))?;
+            components.set(FOOD, Some(food));
+            components.set(CONSUMABLE, Some(consumable));
+        }
+        if let Some(equippable) = &self.equippable {
+            let Some(slot) = EquipmentSlot::by_name(&equippable.slot) else {
+                return Err(format!(
+                    "item {key} has unknown equipment slot {}",
+                    equippable.slot
+                ));
+            };
+            // Vanilla `Equippable.builder` defaults.
+            components.set(
+                EQUIPPABLE,
+                Some(Equippable {
+                    slot,
+                    equip_sound: SoundEventHolder::registry(
+                        &sound_events::ITEM_ARMOR_EQUIP_GENERIC,
+                    ),
+                    asset_id: equippable.asset_id.clone(),
+                    camera_overlay: None,
+                    allowed_entities: None,
+                    dispensable: true,
+                    swappable: true,
+                    damage_on_hurt: true,
+                    equip_on_interact: false,
+                    can_be_sheared: false,
+                    shearing_sound: SoundEventHolder::registry(&sound_events::ITEM_SHEARS_SNIP),
+                }),
+            );
+        }
+        if let Some(custom_model_data) = &self.custom_model_data {
+            components.set(
+                CUSTOM_MODEL_DATA,
+                Some(CustomModelData::new(
+                    custom_model_data.floats.clone(),
+                    custom_model_data.flags.clone(),
+                    custom_model_data.strings.clone(),
+                    custom_model_data.colors.clone(),
+                )),
+            );
+        }
+        Ok(item)
+    }
+}
+
 /// Registry-backed storage selection from config.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -335,6 +1088,34 @@ pub struct ResolvedWorldConfig {
 }
 
 impl WorldsConfig {
+    /// Registers the custom dimension types into `registry` before it is frozen.
+    ///
+    /// # Errors
+    /// Returns a startup error for invalid keys, unknown bases or out-of-range values.
+    pub fn register_dimension_types(
+        &self,
+        registry: &mut DimensionTypeRegistry,
+    ) -> Result<(), String> {
+        for (key, config) in &self.dimension_types {
+            let Ok(key) = key.parse::<Identifier>() else {
+                return Err(format!("invalid dimension type key {key}"));
+            };
+            if registry.by_key(&key).is_some() {
+                return Err(format!("dimension type {key} is already registered"));
+            }
+            let Some(base) = registry.by_key(&config.base) else {
+                return Err(format!(
+                    "unknown base dimension type {} for {key}",
+                    config.base
+                ));
+            };
+            let dimension_type = config.build(key, base)?;
+            // Registry entries are `&'static`; custom types live for the whole process.
+            registry.register(Box::leak(Box::new(dimension_type)));
+        }
+        Ok(())
+    }
+
     /// Validates `worlds.toml` and resolves cascaded defaults.
     ///
     /// # Errors
@@ -682,6 +1463,15 @@ fn default_save_path() -> String {
     "saves".to_owned()
 }
 
+const fn default_base_dimension_type() -> Identifier {
+    Identifier::vanilla_static("overworld")
+}
+
+/// Vanilla `DimensionType.Y_SIZE`, `MIN_Y` and `MAX_Y`.
+const DIMENSION_Y_SIZE: i32 = 4064;
+const DIMENSION_MAX_Y: i32 = 2031;
+const DIMENSION_MIN_Y: i32 = -2032;
+
 fn validate_domain_name(name: &str) -> Result<(), String> {
     if name == "global" {
         return Err("domain name global is reserved".to_owned());
@@ -790,6 +1580,7 @@ fn parse_difficulty(value: &str) -> Result<Difficulty, String> {
 mod tests {
     use super::*;
     use steel_registry::test_support::init_test_registry;
+    use steel_registry::vanilla_items;
 
     #[test]
     fn online_mode_requires_the_authenticated_encryption_flow() {
@@ -802,6 +1593,50 @@ mod tests {
         assert_eq!(validate_login_security(false, false), Ok(()));
     }
 
+    #[test]
+    fn packet_limits_follow_protocol_and_disable_zero_rates() {
+        let config = PacketLimitConfig {
+            status_rate: 0,
+            ..PacketLimitConfig::default()
+        };
+
+        assert!(
+            config
+                .limits(ConnectionProtocol::Status)
+                .max_per_second
+                .is_none()
+        );
+        let play_limits = config.limits(ConnectionProtocol::Play);
+        assert_eq!(play_limits.max_per_second.map(NonZeroU32::get), Some(500));
+        assert_eq!(
+            play_limits.max_payload_sizes.get(&play::S_CHAT),
+            Some(&2048)
+        );
+        assert!(
+            config
+                .limits(ConnectionProtocol::Login)
+                .max_payload_sizes
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn report_details_start_with_the_build_and_respect_vanilla_limits() {
+        let config = ReportDetailsConfig {
+            enable: true,
+            extra: (0..40)
+                .map(|index| (format!("{index:02}"), "x".repeat(5000)))
+                .collect(),
+        };
+
+        let details = config.details();
+        assert_eq!(details.len(), MAX_REPORT_DETAILS);
+        assert_eq!(details[0].key, "Server brand");
+        assert_eq!(details[0].value, "Steel");
+        assert_eq!(details[3].key, "00");
+        assert_eq!(details[3].value.len(), MAX_REPORT_DETAIL_VALUE_LENGTH);
+    }
+
     fn registries() -> (WorldGeneratorRegistry, WorldStorageRegistry) {
         init_test_registry();
         let generators = WorldGeneratorRegistry::new_with_builtins()
@@ -871,6 +1706,100 @@ difficulty = "hard"
         assert_eq!(nether.difficulty, Difficulty::Hard);
     }
 
+    #[test]
+    fn registers_custom_dimension_types_from_a_base() {
+        let config: WorldsConfig = toml::from_str(
+            r#"
+[dimension_types."example:tall"]
+min_y = -128
+height = 512
+logical_height = 512
+
+[domains.minecraft]
+default = true
+"#,
+        )
+        .expect("worlds config should parse");
+        let mut registry = DimensionTypeRegistry::new();
+        registry.register(&vanilla_dimension_types::OVERWORLD);
+        config
+            .register_dimension_types(&mut registry)
+            .expect("custom dimension type should register");
+
+        let tall = registry
+            .by_key(&Identifier::new("example", "tall"))
+            .expect("custom dimension type should exist");
+        assert_eq!(tall.min_y, -128);
+        assert_eq!(tall.height, 512);
+        assert_eq!(
+            tall.coordinate_scale,
+            vanilla_dimension_types::OVERWORLD.coordinate_scale
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_custom_dimension_types() {
+        let config: WorldsConfig = toml::from_str(
+            r#"
+[dimension_types."example:broken"]
+min_y = 2000
+height = 512
+
+[domains.minecraft]
+default = true
+"#,
+        )
+        .expect("worlds config should parse");
+        let mut registry = DimensionTypeRegistry::new();
+        registry.register(&vanilla_dimension_types::OVERWORLD);
+        let error = config
+            .register_dimension_types(&mut registry)
+            .expect_err("dimension type above the build limit should be rejected");
+        assert!(error.contains("min_y + height"));
+    }
+
+    #[test]
+    fn builds_custom_items_from_their_base() {
+        init_test_registry();
+        let config: ItemsConfig = toml::from_str(
+            r#"
+[items."example:ruby_apple"]
+base = "minecraft:apple"
+max_stack_size = 16
+food = { nutrition = 6, saturation = 9.6 }
+custom_model_data = { strings = ["ruby"] }
+
+[items."example:ruby_sword"]
+base = "minecraft:iron_sword"
+max_stack_size = 2
+"#,
+        )
+        .expect("items config should parse");
+
+        let key = Identifier::new("example", "ruby_apple");
+        let apple = config.items["example:ruby_apple"]
+            .build(key.clone(), &vanilla_items::APPLE)
+            .expect("custom item should build");
+        assert_eq!(apple.client_item, Some(&*vanilla_items::APPLE));
+        assert_eq!(apple.components.get(MAX_STACK_SIZE), Some(16));
+        assert_eq!(apple.components.get_ref(ITEM_MODEL), Some(&key));
+        assert_eq!(
+            apple
+                .components
+                .get_ref(FOOD)
+                .map(FoodProperties::nutrition),
+            Some(6)
+        );
+
+        let error = config.items["example:ruby_sword"]
+            .build(
+                Identifier::new("example", "ruby_sword"),
+                &vanilla_items::IRON_SWORD,
+            )
+            .expect_err("stackable items cannot have durability");
+        assert!(error.contains("durability"));
+    }
+
     #[test]
     fn resolves_explicit_portal_targets() {
         let resolved = resolve(
@@ -1175,4 +2104,49 @@ default = true
             Identifier::vanilla_static("flat")
         );
     }
+
+    #[test]
+    fn resource_pack_id_matches_vanilla_name_uuid() {
+        let pack: ResourcePackConfig = toml::from_str(r#"url = "https://example.com/pack.zip""#)
+            .expect("pack config should parse");
+
+        assert_eq!(
+            pack.id().to_string(),
+            "50227fe1-89d1-393b-8884-7d487e29fa5a"
+        );
+    }
+
+    #[test]
+    fn command_aliases_accept_commands_and_macros() {
+        let config: CommandAliasesConfig = toml::from_str(
+            r#"
+[aliases]
+spawn = "tp @s 0 70 0"
+greet = { run = ["say hello $1", "spawn"] }
+"#,
+        )
+        .expect("aliases should parse");
+
+        config.validate().expect("aliases should validate");
+        assert_eq!(config.aliases["spawn"].commands(), ["tp @s 0 70 0"]);
+        assert_eq!(config.aliases["greet"].commands().len(), 2);
+    }
+
+    #[test]
+    fn command_aliases_reject_cycles() {
+        let config: CommandAliasesConfig = toml::from_str(
+            r#"
+[aliases]
+a = "b"
+b = { run = ["say hi", "/c $*"] }
+c = "a"
+"#,
+        )
+        .expect("aliases should parse");
+
+        assert_eq!(
+            config.validate(),
+            Err("command alias cycle: a -> b -> c -> a".to_owned())
+        );
+    }
 }
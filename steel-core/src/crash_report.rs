@@ -0,0 +1,142 @@
+//! Vanilla-style crash reports.
+//!
+//! A report is built from the error, a set of named detail categories filled by
+//! the subsystems that were running, and the system details, then written to
+//! `crash-reports/` like vanilla's `CrashReport.saveToFile`.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io, thread};
+
+use chrono::Local;
+use steel_utils::MC_VERSION;
+
+/// Directory crash reports are written to, relative to the server directory.
+pub const CRASH_REPORT_DIR: &str = "crash-reports";
+
+/// Shown for details that could not be read without blocking.
+pub const UNAVAILABLE: &str = "~~UNAVAILABLE~~";
+
+/// A crash report (vanilla `CrashReport`).
+pub struct CrashReport {
+    title: String,
+    exception: String,
+    details: Vec<CrashReportCategory>,
+}
+
+/// A named group of details in a crash report (vanilla `CrashReportCategory`).
+pub struct CrashReportCategory {
+    title: String,
+    entries: Vec<(String, String)>,
+}
+
+impl CrashReport {
+    /// Creates a report for an error described by `title`.
+    ///
+    /// `exception` holds the error message and its backtrace.
+    #[must_use]
+    pub fn new(title: impl Into<String>, exception: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            exception: exception.into(),
+            details: Vec::new(),
+        }
+    }
+
+    /// Adds a detail category and returns it for filling in.
+    pub fn add_category(&mut self, title: impl Into<String>) -> &mut CrashReportCategory {
+        self.details.push(CrashReportCategory {
+            title: title.into(),
+            entries: Vec::new(),
+        });
+        let index = self.details.len() - 1;
+        &mut self.details[index]
+    }
+
+    /// Renders the full report.
+    #[must_use]
+    pub fn get_friendly_report(&self) -> String {
+        let mut out = String::from("---- Minecraft Crash Report ----\n");
+        out.push_str("// This server is made of Steel, and it still bent.\n\n");
+        let _ = writeln!(out, "Time: {}", Local::now().format("%Y-%m-%d %H:%M:%S"));
+        let _ = writeln!(out, "Description: {}\n", self.title);
+        let _ = writeln!(out, "{}\n", self.exception.trim_end());
+        out.push_str(
+            "A detailed walkthrough of the error, its code path and all known details is as follows:\n",
+        );
+        out.push_str(&"-".repeat(87));
+        out.push_str("\n\n");
+        for category in &self.details {
+            category.write(&mut out);
+        }
+        system_details().write(&mut out);
+        out
+    }
+
+    /// Writes the report to a new file in `dir` and returns its path.
+    pub fn save_to_file(&self, dir: &Path) -> io::Result<PathBuf> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!(
+            "crash-{}-server.txt",
+            Local::now().format("%Y-%m-%d_%H.%M.%S")
+        ));
+        fs::write(&path, self.get_friendly_report())?;
+        Ok(path)
+    }
+}
+
+impl CrashReportCategory {
+    /// Adds a detail line.
+    pub fn set_detail(&mut self, key: impl Into<String>, value: impl ToString) -> &mut Self {
+        self.entries.push((key.into(), value.to_string()));
+        self
+    }
+
+    fn write(&self, out: &mut String) {
+        let _ = writeln!(out, "-- {} --\nDetails:", self.title);
+        for (key, value) in &self.entries {
+            let _ = writeln!(out, "\t{key}: {}", value.replace('\n', "\n\t\t"));
+        }
+        out.push('\n');
+    }
+}
+
+fn system_details() -> CrashReportCategory {
+    let mut system = CrashReportCategory {
+        title: "System Details".to_owned(),
+        entries: Vec::new(),
+    };
+    system
+        .set_detail("Minecraft Version", MC_VERSION)
+        .set_detail("Steel Version", env!("CARGO_PKG_VERSION"))
+        .set_detail(
+            "Operating System",
+            format!("{} ({})", env::consts::OS, env::consts::ARCH),
+        )
+        .set_detail(
+            "CPUs",
+            thread::available_parallelism()
+                .map_or_else(|_| UNAVAILABLE.to_owned(), |n| n.to_string()),
+        );
+    system
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_lists_error_categories_and_system_details() {
+        let mut report = CrashReport::new("Unexpected panic", "boom\nat main.rs:1");
+        report
+            .add_category("Server")
+            .set_detail("Tick", 42)
+            .set_detail("Players", "Alex\nSteve");
+
+        let text = report.get_friendly_report();
+        assert!(text.starts_with("---- Minecraft Crash Report ----\n"));
+        assert!(text.contains("Description: Unexpected panic\n\nboom\nat main.rs:1\n\n"));
+        assert!(text.contains("-- Server --\nDetails:\n\tTick: 42\n\tPlayers: Alex\n\t\tSteve\n"));
+        assert!(text.contains(&format!("\tMinecraft Version: {MC_VERSION}\n")));
+    }
+}
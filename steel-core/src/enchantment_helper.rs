@@ -1,3 +1,5 @@
+use steel_registry::data_components::vanilla_components::ENCHANTABLE;
+use steel_registry::enchantment::{Enchantment, EnchantmentRef};
 use steel_registry::enchantment_effect::{
     DamageSourcePredicate, EnchantmentEffectComponent, EnchantmentEffectRequirements,
     EnchantmentEntityEffect, EnchantmentEntityTarget, EnchantmentTarget, EntityPredicate,
@@ -5,7 +7,8 @@ use steel_registry::enchantment_effect::{
 };
 use steel_registry::entity_type::EntityTypeRef;
 use steel_registry::item_stack::ItemStack;
-use steel_registry::{REGISTRY, RegistryExt, TaggedRegistryExt, vanilla_entities};
+use steel_registry::{REGISTRY, RegistryExt, TaggedRegistryExt, vanilla_entities, vanilla_items};
+use steel_utils::random::Random;
 
 use crate::entity::damage::DamageSource;
 use crate::entity::{Entity, LivingEntity, MobEffectInstance};
@@ -155,6 +158,153 @@ pub(crate) fn modify_smash_damage_per_fallen_block(
     )
 }
 
+/// Vanilla `EnchantmentHelper.processBlockExperience`.
+pub(crate) fn process_block_experience(tool: &ItemStack, experience: i32) -> i32 {
+    let Some(enchantments) = tool.get_enchantments() else {
+        return experience;
+    };
+
+    let mut value = experience as f32;
+    for (key, level) in enchantments.iter() {
+        if *level == 0 {
+            continue;
+        }
+        let Some(enchantment) = REGISTRY.enchantments.by_key(key) else {
+            continue;
+        };
+        for effect in enchantment
+            .effects
+            .value_effects(EnchantmentEffectComponent::BlockExperience)
+        {
+            // Vanilla evaluates these with an item-only loot context, so
+            // entity and damage requirements can never pass.
+            if !effect.is_unconditional() {
+                continue;
+            }
+            if let Some(updated) = effect.effect.process_without_random(*level as i32, value) {
+                value = updated;
+            }
+        }
+    }
+
+    value as i32
+}
+
+/// An enchantment and level picked by the enchanting table.
+///
+/// Vanilla `EnchantmentInstance`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EnchantmentInstance {
+    pub(crate) enchantment: EnchantmentRef,
+    pub(crate) level: u32,
+}
+
+/// Vanilla `EnchantmentHelper.getEnchantmentCost`.
+///
+/// Returns the level cost shown on enchanting table option `slot` (0-2).
+pub(crate) fn get_enchantment_cost(
+    random: &mut impl Random,
+    slot: usize,
+    bookcases: i32,
+    item: &ItemStack,
+) -> i32 {
+    if !item.has(ENCHANTABLE) {
+        return 0;
+    }
+
+    let bookcases = bookcases.min(15);
+    let selected =
+        random.next_i32_bounded(8) + 1 + (bookcases >> 1) + random.next_i32_bounded(bookcases + 1);
+    match slot {
+        0 => (selected / 3).max(1),
+        1 => selected * 2 / 3 + 1,
+        _ => selected.max(bookcases * 2),
+    }
+}
+
+/// Vanilla `EnchantmentHelper.selectEnchantment`.
+pub(crate) fn select_enchantment(
+    random: &mut impl Random,
+    item: &ItemStack,
+    enchantment_cost: i32,
+    source: impl Iterator<Item = EnchantmentRef>,
+) -> Vec<EnchantmentInstance> {
+    let mut results = Vec::new();
+    let Some(enchantable) = item.get(ENCHANTABLE) else {
+        return results;
+    };
+
+    let quarter = enchantable.value() / 4 + 1;
+    let mut cost =
+        enchantment_cost + 1 + random.next_i32_bounded(quarter) + random.next_i32_bounded(quarter);
+    let deviation = (random.next_f32() + random.next_f32() - 1.0) * 0.15;
+    cost = java_round(cost as f32 + cost as f32 * deviation).max(1);
+
+    let mut available = get_available_enchantment_results(cost, item, source);
+    if available.is_empty() {
+        return results;
+    }
+
+    results.extend(get_random_enchantment(random, &available));
+    while random.next_i32_bounded(50) <= cost {
+        if let Some(last) = results.last() {
+            available.retain(|candidate| {
+                Enchantment::are_compatible(last.enchantment, candidate.enchantment)
+            });
+        }
+        if available.is_empty() {
+            break;
+        }
+        results.extend(get_random_enchantment(random, &available));
+        cost /= 2;
+    }
+
+    results
+}
+
+/// Vanilla `EnchantmentHelper.getAvailableEnchantmentResults`.
+///
+/// Picks the highest level of each enchantment whose cost range contains `value`.
+fn get_available_enchantment_results(
+    value: i32,
+    item: &ItemStack,
+    source: impl Iterator<Item = EnchantmentRef>,
+) -> Vec<EnchantmentInstance> {
+    let is_book = item.is(&vanilla_items::BOOK);
+    source
+        .filter(|enchantment| is_book || enchantment.is_primary_item(item.item()))
+        .filter_map(|enchantment| {
+            (1..=enchantment.max_level)
+                .rev()
+                .find(|&level| {
+                    value >= enchantment.get_min_cost(level)
+                        && value <= enchantment.get_max_cost(level)
+                })
+                .map(|level| EnchantmentInstance { enchantment, level })
+        })
+        .collect()
+}
+
+/// Vanilla `WeightedRandom.getRandomItem` weighted by enchantment weight.
+fn get_random_enchantment(
+    random: &mut impl Random,
+    candidates: &[EnchantmentInstance],
+) -> Option<EnchantmentInstance> {
+    let total_weight: i32 = candidates
+        .iter()
+        .map(|candidate| candidate.enchantment.weight as i32)
+        .sum();
+    if total_weight <= 0 {
+        return None;
+    }
+
+    let mut selection = random.next_i32_bounded(total_weight);
+    candidates.iter().copied().find(|candidate| {
+        selection -= candidate.enchantment.weight as i32;
+        selection < 0
+    })
+}
+
 pub(crate) fn is_immune_to_damage<V: LivingEntity + ?Sized>(
     world: &World,
     victim: &V,
@@ -1008,6 +1158,7 @@ mod tests {
     };
     use steel_utils::Identifier;
     use steel_utils::locks::SyncMutex;
+    use steel_utils::random::legacy_random::LegacyRandom;
 
     use super::*;
     use crate::entity::{
@@ -1121,6 +1272,21 @@ mod tests {
         assert!(!requirements_match(Some(&INVERTED), &context));
     }
 
+    #[test]
+    fn silk_touch_removes_block_experience() {
+        init_test_registry();
+
+        let pickaxe = ItemStack::new(&vanilla_items::DIAMOND_PICKAXE);
+        let silk_touch = enchanted_item(
+            &vanilla_items::DIAMOND_PICKAXE,
+            Identifier::vanilla_static("silk_touch"),
+            1,
+        );
+
+        assert_eq!(process_block_experience(&pickaxe, 5), 5);
+        assert_eq!(process_block_experience(&silk_touch, 5), 0);
+    }
+
     #[test]
     fn damage_enchantments_match_target_entity_tags() {
         init_test_registry();
@@ -1528,4 +1694,30 @@ mod tests {
         assert_eq!(slowness.amplifier(), 3);
         assert!(zombie.mob_effect(vanilla_mob_effects::SLOWNESS).is_none());
     }
+
+    #[test]
+    fn enchanting_table_costs_follow_bookcases() {
+        init_test_registry();
+
+        let mut random = LegacyRandom::from_seed(42);
+        let book = ItemStack::new(&vanilla_items::BOOK);
+        assert!(get_enchantment_cost(&mut random, 2, 15, &book) >= 30);
+        assert!(get_enchantment_cost(&mut random, 0, 0, &book) >= 1);
+        assert_eq!(
+            get_enchantment_cost(&mut random, 2, 15, &ItemStack::new(&vanilla_items::STICK)),
+            0
+        );
+
+        let selected = select_enchantment(
+            &mut random,
+            &book,
+            30,
+            REGISTRY
+                .enchantments
+                .iter()
+                .map(|(_, enchantment)| enchantment),
+        );
+        assert!(!selected.is_empty());
+        assert!(selected.iter().all(|instance| instance.level >= 1));
+    }
 }
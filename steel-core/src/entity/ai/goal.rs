@@ -1,14 +1,17 @@
 //! Vanilla-shaped goal selector and movement goals.
 
+mod acquire_poi;
 mod avoid_entity;
 mod breath_air;
 mod breed_goal;
 mod climb_on_top_of_powder_snow;
 mod door_interact;
+mod eat_block;
 mod flee_sun;
 mod float_goal;
 mod follow_mob;
 mod follow_parent;
+mod hurt_by_target;
 mod interact;
 mod leap_at_target;
 mod look_at_player;
@@ -16,28 +19,48 @@ mod melee_attack;
 mod move_to_block;
 mod move_towards_restriction;
 mod move_towards_target;
+mod nearest_attackable_target;
 mod open_door;
 mod panic_goal;
 mod random_look_around;
 mod random_pos;
 mod random_stroll;
 mod random_swimming;
+mod ranged_bow_attack;
 mod restrict_sun;
 mod selector;
+mod swell;
 mod target_goal;
 mod tempt_goal;
+mod trade_with_player;
 mod try_find_water;
 mod water_avoiding_random_stroll;
+mod work_at_poi;
+mod zombie_attack;
 
+pub(crate) use acquire_poi::AcquirePoiGoal;
+pub(crate) use avoid_entity::AvoidEntityGoal;
 pub(crate) use breed_goal::BreedGoal;
+pub(crate) use eat_block::EatBlockGoal;
+pub(crate) use flee_sun::FleeSunGoal;
 pub(crate) use float_goal::FloatGoal;
 pub(crate) use follow_parent::FollowParentGoal;
+pub(crate) use hurt_by_target::HurtByTargetGoal;
+pub(crate) use leap_at_target::LeapAtTargetGoal;
 pub(crate) use look_at_player::LookAtPlayerGoal;
+pub(crate) use melee_attack::MeleeAttackGoal;
+pub(crate) use nearest_attackable_target::NearestAttackableTargetGoal;
 pub(crate) use panic_goal::PanicGoal;
 pub(crate) use random_look_around::RandomLookAroundGoal;
-pub(crate) use selector::{GoalControl, GoalSelector};
+pub(crate) use ranged_bow_attack::RangedBowAttackGoal;
+pub(crate) use restrict_sun::RestrictSunGoal;
+pub(crate) use selector::{Goal, GoalControl, GoalControls, GoalSelector};
+pub(crate) use swell::SwellGoal;
 pub(crate) use tempt_goal::TemptGoal;
+pub(crate) use trade_with_player::TradeWithPlayerGoal;
 pub(crate) use water_avoiding_random_stroll::WaterAvoidingRandomStrollGoal;
+pub(crate) use work_at_poi::WorkAtPoiGoal;
+pub(crate) use zombie_attack::ZombieAttackGoal;
 
 pub(super) const fn reduced_tick_delay(ticks: i32) -> i32 {
     (ticks + 1) / 2
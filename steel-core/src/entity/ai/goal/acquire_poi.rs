@@ -0,0 +1,133 @@
+use glam::DVec3;
+use steel_utils::{BlockPos, Downcast as _};
+
+use super::selector::{Goal, GoalControls};
+use crate::entity::PathfinderMob;
+use crate::entity::entities::{VillagerEntity, VillagerPoiMemory};
+use crate::poi::OccupationStatus;
+
+const SEARCH_RANGE: i32 = 48;
+const ACQUIRE_DISTANCE: f64 = 2.0;
+
+/// Walks a villager to a free POI and claims it.
+///
+/// Simplified vanilla `AcquirePoi` behavior without the brain.
+pub struct AcquirePoiGoal {
+    memory: VillagerPoiMemory,
+    speed_modifier: f64,
+    next_search_delay: i32,
+    target: Option<BlockPos>,
+}
+
+impl AcquirePoiGoal {
+    #[must_use]
+    pub(crate) const fn new(memory: VillagerPoiMemory, speed_modifier: f64) -> Self {
+        Self {
+            memory,
+            speed_modifier,
+            next_search_delay: 0,
+            target: None,
+        }
+    }
+
+    fn try_acquire(&mut self, villager: &VillagerEntity) {
+        let Some(pos) = self.target else {
+            return;
+        };
+        if !block_pos_closer_to_center_than(pos, villager.position(), ACQUIRE_DISTANCE) {
+            return;
+        }
+        let Some(world) = villager.level() else {
+            return;
+        };
+
+        self.target = None;
+        if world.poi_storage.lock().reserve_ticket(pos) {
+            villager.set_poi(self.memory, Some(pos));
+        }
+    }
+}
+
+impl Goal for AcquirePoiGoal {
+    fn controls(&self) -> GoalControls {
+        GoalControls::MOVE
+    }
+
+    fn can_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        let Some(villager) = mob.downcast_ref::<VillagerEntity>() else {
+            return false;
+        };
+        if villager.poi(self.memory).is_some() || !villager.wants_poi(self.memory) {
+            return false;
+        }
+        if self.next_search_delay > 0 {
+            self.next_search_delay -= 1;
+            return false;
+        }
+        self.next_search_delay = 20 + rand::random_range(0..20);
+
+        let Some(world) = mob.level() else {
+            return false;
+        };
+        let memory = self.memory;
+        self.target = world
+            .poi_storage
+            .lock()
+            .get_nearest(
+                &|type_id| villager.is_acceptable_poi(memory, type_id),
+                mob.block_position(),
+                SEARCH_RANGE,
+                OccupationStatus::Free,
+            )
+            .map(|(pos, _)| pos);
+        self.target.is_some()
+    }
+
+    fn can_continue_to_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        let Some(villager) = mob.downcast_ref::<VillagerEntity>() else {
+            return false;
+        };
+        self.try_acquire(villager);
+        self.target.is_some() && !mob.mob_base().navigation().lock().is_done()
+    }
+
+    fn start(&mut self, mob: &dyn PathfinderMob) {
+        if let Some(pos) = self.target {
+            let (x, y, z) = pos.get_center();
+            mob.move_to_pos(DVec3::new(x, y, z), self.speed_modifier);
+        }
+    }
+
+    fn stop(&mut self, mob: &dyn PathfinderMob) {
+        if let Some(villager) = mob.downcast_ref::<VillagerEntity>() {
+            self.try_acquire(villager);
+        }
+        self.target = None;
+    }
+}
+
+fn block_pos_closer_to_center_than(pos: BlockPos, position: DVec3, distance: f64) -> bool {
+    let (x, y, z) = pos.get_center();
+    DVec3::new(x, y, z).distance_squared(position) < distance * distance
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Weak;
+
+    use steel_registry::{test_support::init_test_registry, vanilla_entities};
+
+    use super::*;
+
+    #[test]
+    fn acquire_poi_goal_skips_villagers_with_claimed_poi() {
+        init_test_registry();
+        let villager =
+            VillagerEntity::new(&vanilla_entities::VILLAGER, 1, DVec3::ZERO, Weak::new());
+        villager.set_poi(VillagerPoiMemory::Home, Some(BlockPos::new(0, 64, 0)));
+        let mut goal = AcquirePoiGoal::new(VillagerPoiMemory::Home, 1.0);
+
+        assert_eq!(goal.controls(), GoalControls::MOVE);
+        assert!(!goal.can_use(&villager));
+    }
+}
@@ -0,0 +1,148 @@
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::vanilla_game_rules::MOB_GRIEFING;
+use steel_registry::{REGISTRY, vanilla_blocks, vanilla_game_events};
+use steel_utils::entity_events::EntityStatus;
+use steel_utils::types::UpdateFlags;
+
+use super::reduced_tick_delay;
+use super::selector::{Goal, GoalControls};
+use crate::entity::PathfinderMob;
+
+const EAT_ANIMATION_TICKS: i32 = 40;
+const EAT_BLOCK_TICK: i32 = 4;
+const ADULT_EAT_CHANCE: i32 = 1000;
+const BABY_EAT_CHANCE: i32 = 50;
+
+/// Vanilla `EatBlockGoal`, used by sheep to graze grass.
+pub struct EatBlockGoal {
+    eat_animation_tick: i32,
+}
+
+impl EatBlockGoal {
+    #[must_use]
+    pub(crate) const fn new() -> Self {
+        Self {
+            eat_animation_tick: 0,
+        }
+    }
+}
+
+impl Goal for EatBlockGoal {
+    fn controls(&self) -> GoalControls {
+        GoalControls::MOVE | GoalControls::LOOK | GoalControls::JUMP
+    }
+
+    fn can_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        let chance = if mob.is_baby() {
+            BABY_EAT_CHANCE
+        } else {
+            ADULT_EAT_CHANCE
+        };
+        if rand::random_range(0..chance) != 0 {
+            return false;
+        }
+        let Some(world) = mob.level() else {
+            return false;
+        };
+
+        let pos = mob.block_position();
+        world
+            .get_block_state(pos)
+            .get_block()
+            .has_tag(&BlockTag::EDIBLE_FOR_SHEEP)
+            || world.get_block_state(pos.below()).get_block() == &vanilla_blocks::GRASS_BLOCK
+    }
+
+    fn can_continue_to_use(&mut self, _mob: &dyn PathfinderMob) -> bool {
+        self.eat_animation_tick > 0
+    }
+
+    fn start(&mut self, mob: &dyn PathfinderMob) {
+        self.eat_animation_tick = reduced_tick_delay(EAT_ANIMATION_TICKS);
+        mob.broadcast_entity_event(EntityStatus::EatGrass);
+        mob.mob_base().navigation().lock().stop();
+    }
+
+    fn stop(&mut self, _mob: &dyn PathfinderMob) {
+        self.eat_animation_tick = 0;
+    }
+
+    fn tick(&mut self, mob: &dyn PathfinderMob) {
+        self.eat_animation_tick = (self.eat_animation_tick - 1).max(0);
+        if self.eat_animation_tick != reduced_tick_delay(EAT_BLOCK_TICK) {
+            return;
+        }
+        let Some(world) = mob.level() else {
+            return;
+        };
+
+        let pos = mob.block_position();
+        let mob_griefing = world.get_game_rule(&MOB_GRIEFING);
+        if world
+            .get_block_state(pos)
+            .get_block()
+            .has_tag(&BlockTag::EDIBLE_FOR_SHEEP)
+        {
+            if mob_griefing {
+                world.destroy_block(pos, false);
+            }
+        } else {
+            let below = pos.below();
+            let below_state = world.get_block_state(below);
+            if below_state.get_block() != &vanilla_blocks::GRASS_BLOCK {
+                return;
+            }
+            if mob_griefing {
+                world.destroy_block_effect(below, u32::from(below_state.0), None);
+                world.set_block(
+                    below,
+                    REGISTRY.blocks.get_default_state_id(&vanilla_blocks::DIRT),
+                    UpdateFlags::UPDATE_CLIENTS,
+                );
+            }
+        }
+
+        mob.ate();
+        mob.game_event(&vanilla_game_events::EAT);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Weak;
+
+    use glam::DVec3;
+    use steel_registry::{test_support::init_test_registry, vanilla_entities};
+
+    use super::*;
+    use crate::entity::entities::PigEntity;
+
+    #[test]
+    fn eat_block_goal_uses_move_look_and_jump_controls() {
+        let goal = EatBlockGoal::new();
+
+        assert_eq!(
+            goal.controls(),
+            GoalControls::MOVE | GoalControls::LOOK | GoalControls::JUMP
+        );
+        assert!(!goal.requires_update_every_tick());
+    }
+
+    #[test]
+    fn eat_block_goal_runs_for_reduced_animation_ticks() {
+        init_test_registry();
+        let mut goal = EatBlockGoal::new();
+        let mob = PigEntity::new(&vanilla_entities::PIG, 1, DVec3::ZERO, Weak::new());
+
+        goal.start(&mob);
+        assert_eq!(goal.eat_animation_tick, 20);
+
+        for _ in 0..20 {
+            assert!(goal.can_continue_to_use(&mob));
+            goal.tick(&mob);
+        }
+
+        assert!(!goal.can_continue_to_use(&mob));
+    }
+}
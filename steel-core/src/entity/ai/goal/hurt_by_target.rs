@@ -0,0 +1,182 @@
+use glam::DVec3;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::vanilla_game_rules::UNIVERSAL_ANGER;
+use steel_registry::{vanilla_attributes, vanilla_entities};
+use steel_utils::WorldAabb;
+
+use super::selector::{Goal, GoalControls};
+use super::target_goal::TargetGoalBase;
+use crate::entity::ai::targeting::TargetingConditions;
+use crate::entity::{PathfinderMob, SharedEntity};
+
+const ALERT_RANGE_Y: f64 = 10.0;
+const HURT_BY_UNSEEN_MEMORY_TICKS: i32 = 300;
+
+/// Vanilla `HurtByTargetGoal`: retaliates against the last mob that hurt this one.
+pub struct HurtByTargetGoal {
+    base: TargetGoalBase,
+    hurt_by_targeting: TargetingConditions,
+    alert_same_type: bool,
+    timestamp: i32,
+    to_ignore_damage: Vec<EntityTypeRef>,
+    to_ignore_alert: Vec<EntityTypeRef>,
+}
+
+impl HurtByTargetGoal {
+    /// Creates a goal that ignores damage from `to_ignore_damage` entity types.
+    #[must_use]
+    pub(crate) fn new(to_ignore_damage: Vec<EntityTypeRef>) -> Self {
+        Self {
+            base: TargetGoalBase::new(true, false),
+            hurt_by_targeting: TargetingConditions::for_combat()
+                .ignore_line_of_sight()
+                .ignore_invisibility_testing(),
+            alert_same_type: false,
+            timestamp: 0,
+            to_ignore_damage,
+            to_ignore_alert: Vec::new(),
+        }
+    }
+
+    /// Mirrors vanilla `setAlertOthers`: nearby mobs of the same type join in.
+    #[must_use]
+    pub(crate) fn set_alert_others(mut self, to_ignore_alert: Vec<EntityTypeRef>) -> Self {
+        self.alert_same_type = true;
+        self.to_ignore_alert = to_ignore_alert;
+        self
+    }
+
+    fn alert_others(&self, mob: &dyn PathfinderMob, target: &SharedEntity) {
+        let Some(world) = mob.level() else {
+            return;
+        };
+        let Some(target_living) = target.as_living_entity() else {
+            return;
+        };
+
+        let within = mob
+            .attributes()
+            .lock()
+            .required_value(vanilla_attributes::FOLLOW_RANGE);
+        let position = mob.position();
+        let search_area = WorldAabb::from_min_max(position, position + DVec3::ONE).inflate_xyz(
+            within,
+            ALERT_RANGE_Y,
+            within,
+        );
+        let mob_type = mob.entity_type();
+        let nearby = world.get_entities_in_aabb_matching(&search_area, |entity| {
+            entity.entity_type() == mob_type && !entity.is_spectator()
+        });
+
+        for other in nearby {
+            if other.id() == mob.id() || self.to_ignore_alert.contains(&other.entity_type()) {
+                continue;
+            }
+            let Some(other_mob) = other.as_mob() else {
+                continue;
+            };
+            if other_mob.target().is_some() || other_mob.is_allied_to(target_living) {
+                continue;
+            }
+
+            other_mob.set_target(Some(target));
+        }
+    }
+}
+
+impl Goal for HurtByTargetGoal {
+    fn controls(&self) -> GoalControls {
+        GoalControls::TARGET
+    }
+
+    fn can_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        let timestamp = mob.last_hurt_by_mob_timestamp();
+        if timestamp == self.timestamp {
+            return false;
+        }
+        let Some(last_hurt_by_mob) = mob.last_hurt_by_mob() else {
+            return false;
+        };
+
+        if last_hurt_by_mob.entity_type() == &vanilla_entities::PLAYER
+            && mob
+                .level()
+                .is_some_and(|world| world.get_game_rule(&UNIVERSAL_ANGER))
+        {
+            return false;
+        }
+        if self
+            .to_ignore_damage
+            .contains(&last_hurt_by_mob.entity_type())
+        {
+            return false;
+        }
+
+        self.base.can_attack(
+            mob,
+            last_hurt_by_mob.as_living_entity(),
+            &self.hurt_by_targeting,
+        )
+    }
+
+    fn can_continue_to_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        self.base.can_continue_to_use(mob)
+    }
+
+    fn start(&mut self, mob: &dyn PathfinderMob) {
+        let last_hurt_by_mob = mob.last_hurt_by_mob();
+        mob.set_target(last_hurt_by_mob.as_ref());
+        self.base.set_target_mob(mob.target());
+        self.timestamp = mob.last_hurt_by_mob_timestamp();
+        self.base
+            .set_unseen_memory_ticks(HURT_BY_UNSEEN_MEMORY_TICKS);
+        if self.alert_same_type
+            && let Some(last_hurt_by_mob) = &last_hurt_by_mob
+        {
+            self.alert_others(mob, last_hurt_by_mob);
+        }
+
+        self.base.start();
+    }
+
+    fn stop(&mut self, mob: &dyn PathfinderMob) {
+        self.base.stop(mob);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Weak;
+
+    use steel_registry::test_support::init_test_registry;
+
+    use super::*;
+    use crate::entity::entities::PigEntity;
+
+    #[test]
+    fn hurt_by_target_goal_claims_only_target_control() {
+        let goal = HurtByTargetGoal::new(Vec::new());
+
+        assert_eq!(goal.controls(), GoalControls::TARGET);
+        assert!(!goal.alert_same_type);
+    }
+
+    #[test]
+    fn hurt_by_target_goal_alert_others_records_ignored_types() {
+        let goal = HurtByTargetGoal::new(Vec::new())
+            .set_alert_others(vec![&vanilla_entities::ZOMBIFIED_PIGLIN]);
+
+        assert!(goal.alert_same_type);
+        assert_eq!(goal.to_ignore_alert.len(), 1);
+    }
+
+    #[test]
+    fn hurt_by_target_goal_needs_a_new_hurt_timestamp() {
+        init_test_registry();
+        let mob = PigEntity::new(&vanilla_entities::PIG, 1, DVec3::ZERO, Weak::new());
+        let mut goal = HurtByTargetGoal::new(Vec::new());
+
+        assert!(!goal.can_use(&mob));
+    }
+}
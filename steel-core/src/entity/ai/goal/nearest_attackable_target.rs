@@ -0,0 +1,180 @@
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::vanilla_attributes;
+
+use super::reduced_tick_delay;
+use super::selector::{Goal, GoalControls};
+use super::target_goal::TargetGoalBase;
+use crate::entity::ai::targeting::TargetingConditions;
+use crate::entity::{LivingEntity, PathfinderMob, SharedEntity};
+use crate::world::World;
+
+const DEFAULT_RANDOM_INTERVAL: i32 = 10;
+const TARGET_SEARCH_VERTICAL_RANGE: f64 = 4.0;
+
+enum TargetType {
+    Player,
+    EntityTypes(Vec<EntityTypeRef>),
+}
+
+/// Vanilla `NearestAttackableTargetGoal`.
+pub struct NearestAttackableTargetGoal {
+    base: TargetGoalBase,
+    target_type: TargetType,
+    random_interval: i32,
+    target_conditions: TargetingConditions,
+    target: Option<SharedEntity>,
+}
+
+impl NearestAttackableTargetGoal {
+    /// Targets the nearest attackable player, like vanilla `(mob, Player.class, mustSee)`.
+    #[must_use]
+    pub(crate) const fn new_for_players(must_see: bool) -> Self {
+        Self::new(
+            TargetType::Player,
+            DEFAULT_RANDOM_INTERVAL,
+            must_see,
+            false,
+            TargetingConditions::for_combat(),
+        )
+    }
+
+    /// Targets the nearest attackable entity of one of `entity_types`.
+    #[must_use]
+    pub(crate) const fn new_for_entity_types(
+        entity_types: Vec<EntityTypeRef>,
+        must_see: bool,
+    ) -> Self {
+        Self::new(
+            TargetType::EntityTypes(entity_types),
+            DEFAULT_RANDOM_INTERVAL,
+            must_see,
+            false,
+            TargetingConditions::for_combat(),
+        )
+    }
+
+    /// Targets `entity_types` that also match `selector`.
+    #[must_use]
+    pub(crate) fn new_for_entity_types_with_selector(
+        entity_types: Vec<EntityTypeRef>,
+        random_interval: i32,
+        must_see: bool,
+        must_reach: bool,
+        selector: impl Fn(&dyn LivingEntity, &World) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self::new(
+            TargetType::EntityTypes(entity_types),
+            random_interval,
+            must_see,
+            must_reach,
+            TargetingConditions::for_combat().selector(selector),
+        )
+    }
+
+    const fn new(
+        target_type: TargetType,
+        random_interval: i32,
+        must_see: bool,
+        must_reach: bool,
+        target_conditions: TargetingConditions,
+    ) -> Self {
+        Self {
+            base: TargetGoalBase::new(must_see, must_reach),
+            target_type,
+            random_interval: reduced_tick_delay(random_interval),
+            target_conditions,
+            target: None,
+        }
+    }
+
+    fn find_target(&self, mob: &dyn PathfinderMob) -> Option<SharedEntity> {
+        let world = mob.level()?;
+        let follow_distance = mob
+            .attributes()
+            .lock()
+            .required_value(vanilla_attributes::FOLLOW_RANGE);
+        let conditions = self.target_conditions.clone().range(follow_distance);
+        let position = mob.position();
+        let origin = position.with_y(mob.get_eye_y());
+
+        match &self.target_type {
+            TargetType::Player => world
+                .nearest_player(origin, -1.0, |player| {
+                    conditions.test(world.as_ref(), Some(mob), player)
+                })
+                .map(|player| -> SharedEntity { player }),
+            TargetType::EntityTypes(entity_types) => {
+                let search_area = mob.bounding_box().inflate_xyz(
+                    follow_distance,
+                    TARGET_SEARCH_VERTICAL_RANGE,
+                    follow_distance,
+                );
+                world.nearest_entity_in_aabb_matching(&search_area, origin, |entity| {
+                    entity_types.contains(&entity.entity_type())
+                        && entity.as_living_entity().is_some_and(|living| {
+                            conditions.test(world.as_ref(), Some(mob), living)
+                        })
+                })
+            }
+        }
+    }
+}
+
+impl Goal for NearestAttackableTargetGoal {
+    fn controls(&self) -> GoalControls {
+        GoalControls::TARGET
+    }
+
+    fn can_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        if self.random_interval > 0 && rand::random_range(0..self.random_interval) != 0 {
+            return false;
+        }
+
+        self.target = self.find_target(mob);
+        self.target.is_some()
+    }
+
+    fn can_continue_to_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        self.base.can_continue_to_use(mob)
+    }
+
+    fn start(&mut self, mob: &dyn PathfinderMob) {
+        mob.set_target(self.target.as_ref());
+        self.base.start();
+    }
+
+    fn stop(&mut self, mob: &dyn PathfinderMob) {
+        self.base.stop(mob);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Weak;
+
+    use glam::DVec3;
+    use steel_registry::{test_support::init_test_registry, vanilla_entities};
+
+    use super::*;
+    use crate::entity::entities::PigEntity;
+
+    #[test]
+    fn nearest_attackable_target_goal_claims_only_target_control() {
+        let goal = NearestAttackableTargetGoal::new_for_players(true);
+
+        assert_eq!(goal.controls(), GoalControls::TARGET);
+        assert_eq!(goal.random_interval, reduced_tick_delay(10));
+    }
+
+    #[test]
+    fn nearest_attackable_target_goal_requires_world_to_find_targets() {
+        init_test_registry();
+        let mob = PigEntity::new(&vanilla_entities::PIG, 1, DVec3::ZERO, Weak::new());
+        let mut goal =
+            NearestAttackableTargetGoal::new_for_entity_types(vec![&vanilla_entities::PIG], false);
+        goal.random_interval = 0;
+
+        assert!(!goal.can_use(&mob));
+        assert!(goal.target.is_none());
+    }
+}
@@ -0,0 +1,209 @@
+use steel_registry::vanilla_items;
+
+use super::selector::{Goal, GoalControls};
+use crate::entity::{LivingEntity, PathfinderMob, SharedEntity};
+
+const SEE_TIME_TO_STOP: i32 = 20;
+const STRAFE_DIRECTION_CHANGE_TICKS: i32 = 20;
+const STRAFE_DIRECTION_CHANGE_CHANCE: f32 = 0.3;
+const LOST_SIGHT_GIVE_UP_TICKS: i32 = -60;
+const BOW_FULL_CHARGE_TICKS: i32 = 20;
+const LOOK_MAX_ROTATION: f32 = 30.0;
+
+/// Vanilla `BowItem.getPowerForTime`.
+fn power_for_time(ticks_using: i32) -> f32 {
+    let charge = ticks_using as f32 / 20.0;
+    let power = charge.mul_add(charge, charge * 2.0) / 3.0;
+    power.min(1.0)
+}
+
+/// Vanilla `RangedBowAttackGoal`, used by skeletons holding a bow.
+pub struct RangedBowAttackGoal {
+    speed_modifier: f64,
+    attack_interval_min: i32,
+    attack_radius_sqr: f32,
+    attack_time: i32,
+    see_time: i32,
+    strafing_clockwise: bool,
+    strafing_backwards: bool,
+    strafing_time: i32,
+    // TODO: Move to shared living use-item state once mobs can sync bow drawing.
+    ticks_using_item: Option<i32>,
+}
+
+impl RangedBowAttackGoal {
+    #[must_use]
+    pub(crate) const fn new(
+        speed_modifier: f64,
+        attack_interval_min: i32,
+        attack_radius: f32,
+    ) -> Self {
+        Self {
+            speed_modifier,
+            attack_interval_min,
+            attack_radius_sqr: attack_radius * attack_radius,
+            attack_time: -1,
+            see_time: 0,
+            strafing_clockwise: false,
+            strafing_backwards: false,
+            strafing_time: -1,
+            ticks_using_item: None,
+        }
+    }
+
+    fn is_holding_bow(mob: &dyn PathfinderMob) -> bool {
+        mob.is_holding(&mut |item_stack| item_stack.is(&vanilla_items::BOW))
+    }
+
+    fn tick_strafing(&mut self, mob: &dyn PathfinderMob, target: &SharedEntity, distance_sqr: f64) {
+        if self.strafing_time >= STRAFE_DIRECTION_CHANGE_TICKS {
+            if rand::random::<f32>() < STRAFE_DIRECTION_CHANGE_CHANCE {
+                self.strafing_clockwise = !self.strafing_clockwise;
+            }
+            if rand::random::<f32>() < STRAFE_DIRECTION_CHANGE_CHANCE {
+                self.strafing_backwards = !self.strafing_backwards;
+            }
+            self.strafing_time = 0;
+        }
+
+        let mut controls = mob.mob_base().controls().lock();
+        if self.strafing_time > -1 {
+            let attack_radius_sqr = f64::from(self.attack_radius_sqr);
+            if distance_sqr > attack_radius_sqr * 0.75 {
+                self.strafing_backwards = false;
+            } else if distance_sqr < attack_radius_sqr * 0.25 {
+                self.strafing_backwards = true;
+            }
+
+            controls.move_control.strafe(
+                if self.strafing_backwards { -0.5 } else { 0.5 },
+                if self.strafing_clockwise { 0.5 } else { -0.5 },
+            );
+        }
+
+        let target_position = target.position();
+        controls.look_control.set_look_at(
+            target_position.with_y(target.get_eye_y()),
+            LOOK_MAX_ROTATION,
+            LOOK_MAX_ROTATION,
+        );
+    }
+
+    fn tick_using_item(
+        &mut self,
+        mob: &dyn PathfinderMob,
+        target: &dyn LivingEntity,
+        can_see: bool,
+    ) {
+        let Some(ticks_using_item) = self.ticks_using_item else {
+            self.attack_time -= 1;
+            if self.attack_time <= 0 && self.see_time >= LOST_SIGHT_GIVE_UP_TICKS {
+                self.ticks_using_item = Some(0);
+            }
+            return;
+        };
+
+        if !can_see && self.see_time < LOST_SIGHT_GIVE_UP_TICKS {
+            self.ticks_using_item = None;
+            return;
+        }
+        if !can_see {
+            self.ticks_using_item = Some(ticks_using_item + 1);
+            return;
+        }
+        if ticks_using_item < BOW_FULL_CHARGE_TICKS {
+            self.ticks_using_item = Some(ticks_using_item + 1);
+            return;
+        }
+
+        self.ticks_using_item = None;
+        if let Some(ranged_attack_mob) = mob.as_ranged_attack_mob() {
+            ranged_attack_mob.perform_ranged_attack(target, power_for_time(ticks_using_item));
+        }
+        self.attack_time = self.attack_interval_min;
+    }
+}
+
+impl Goal for RangedBowAttackGoal {
+    fn controls(&self) -> GoalControls {
+        GoalControls::MOVE | GoalControls::LOOK
+    }
+
+    fn can_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        mob.target().is_some() && Self::is_holding_bow(mob)
+    }
+
+    fn can_continue_to_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        (self.can_use(mob) || !mob.mob_base().navigation().lock().is_done())
+            && Self::is_holding_bow(mob)
+    }
+
+    fn start(&mut self, mob: &dyn PathfinderMob) {
+        mob.set_aggressive(true);
+    }
+
+    fn stop(&mut self, mob: &dyn PathfinderMob) {
+        mob.set_aggressive(false);
+        self.see_time = 0;
+        self.attack_time = -1;
+        self.ticks_using_item = None;
+    }
+
+    fn requires_update_every_tick(&self) -> bool {
+        true
+    }
+
+    fn tick(&mut self, mob: &dyn PathfinderMob) {
+        let Some(target) = mob.target() else {
+            return;
+        };
+        let Some(target_living) = target.as_living_entity() else {
+            return;
+        };
+
+        let distance_sqr = mob.position().distance_squared(target.position());
+        let can_see = mob.has_line_of_sight_cached(target_living);
+        let has_seen = self.see_time > 0;
+        if can_see != has_seen {
+            self.see_time = 0;
+        }
+        if can_see {
+            self.see_time += 1;
+        } else {
+            self.see_time -= 1;
+        }
+
+        if distance_sqr <= f64::from(self.attack_radius_sqr) && self.see_time >= SEE_TIME_TO_STOP {
+            mob.mob_base().navigation().lock().stop();
+            self.strafing_time += 1;
+        } else {
+            mob.move_to_pos(target.position(), self.speed_modifier);
+            self.strafing_time = -1;
+        }
+
+        self.tick_strafing(mob, &target, distance_sqr);
+        self.tick_using_item(mob, target_living, can_see);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranged_bow_attack_goal_uses_move_and_look_controls() {
+        let goal = RangedBowAttackGoal::new(1.0, 20, 15.0);
+
+        assert_eq!(goal.controls(), GoalControls::MOVE | GoalControls::LOOK);
+        assert!(goal.requires_update_every_tick());
+        assert!((goal.attack_radius_sqr - 225.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn bow_power_matches_vanilla_charge_curve() {
+        assert!(power_for_time(0).abs() < f32::EPSILON);
+        assert!((power_for_time(10) - 0.416_666_66).abs() < 1.0e-6);
+        assert!((power_for_time(20) - 1.0).abs() < f32::EPSILON);
+        assert!((power_for_time(40) - 1.0).abs() < f32::EPSILON);
+    }
+}
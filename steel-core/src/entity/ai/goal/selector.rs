@@ -188,6 +188,20 @@ impl GoalSelector {
             .push(WrappedGoal::new(priority, Box::new(goal)));
     }
 
+    /// Stops and removes every goal registered at `priority`.
+    ///
+    /// Stands in for vanilla `removeGoal` where goals are swapped by slot,
+    /// such as skeleton weapon reassessment.
+    pub(crate) fn remove_goals_with_priority(&mut self, priority: i32, mob: &dyn PathfinderMob) {
+        for goal in &mut self.available_goals {
+            if goal.priority == priority {
+                goal.stop(mob);
+            }
+        }
+        self.available_goals
+            .retain(|goal| goal.priority != priority);
+    }
+
     pub fn tick(&mut self, mob: &dyn PathfinderMob) {
         for index in 0..self.available_goals.len() {
             let should_stop = {
@@ -557,6 +571,20 @@ mod tests {
         assert_eq!(selector.running_goal_count(), 0);
     }
 
+    #[test]
+    fn remove_goals_with_priority_stops_and_drops_matching_goals() {
+        let mob = TestPathfinderMob::new();
+        let mut selector = GoalSelector::new();
+        selector.add_goal(4, StaticGoal::new(GoalControls::MOVE));
+        selector.add_goal(5, StaticGoal::new(GoalControls::LOOK));
+        selector.tick(&mob);
+
+        selector.remove_goals_with_priority(4, &mob);
+
+        assert_eq!(selector.available_goal_priorities(), vec![5]);
+        assert_eq!(selector.running_goal_count(), 1);
+    }
+
     #[test]
     fn tick_running_goals_respects_requires_update_every_tick() {
         RUNNING_TICK_COUNT.store(0, Ordering::Relaxed);
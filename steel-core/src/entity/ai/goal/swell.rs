@@ -0,0 +1,89 @@
+use steel_utils::Downcast as _;
+
+use super::selector::{Goal, GoalControls};
+use crate::entity::entities::CreeperEntity;
+use crate::entity::{PathfinderMob, SharedEntity};
+
+const START_SWELL_DISTANCE_SQR: f64 = 9.0;
+const STOP_SWELL_DISTANCE_SQR: f64 = 49.0;
+
+/// Vanilla `SwellGoal`: primes a creeper once its target is close.
+pub struct SwellGoal {
+    target: Option<SharedEntity>,
+}
+
+impl SwellGoal {
+    #[must_use]
+    pub(crate) const fn new() -> Self {
+        Self { target: None }
+    }
+}
+
+impl Goal for SwellGoal {
+    fn controls(&self) -> GoalControls {
+        GoalControls::MOVE
+    }
+
+    fn can_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        let Some(creeper) = mob.downcast_ref::<CreeperEntity>() else {
+            return false;
+        };
+
+        creeper.swell_dir() > 0
+            || mob.target().is_some_and(|target| {
+                mob.position().distance_squared(target.position()) < START_SWELL_DISTANCE_SQR
+            })
+    }
+
+    fn start(&mut self, mob: &dyn PathfinderMob) {
+        mob.mob_base().navigation().lock().stop();
+        self.target = mob.target();
+    }
+
+    fn stop(&mut self, _mob: &dyn PathfinderMob) {
+        self.target = None;
+    }
+
+    fn requires_update_every_tick(&self) -> bool {
+        true
+    }
+
+    fn tick(&mut self, mob: &dyn PathfinderMob) {
+        let Some(creeper) = mob.downcast_ref::<CreeperEntity>() else {
+            return;
+        };
+
+        let keeps_swelling = self.target.as_ref().is_some_and(|target| {
+            mob.position().distance_squared(target.position()) <= STOP_SWELL_DISTANCE_SQR
+                && target
+                    .as_living_entity()
+                    .is_some_and(|target| mob.has_line_of_sight_cached(target))
+        });
+        creeper.set_swell_dir(if keeps_swelling { 1 } else { -1 });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Weak;
+
+    use glam::DVec3;
+    use steel_registry::{test_support::init_test_registry, vanilla_entities};
+
+    use super::*;
+
+    #[test]
+    fn swell_goal_unprimes_creeper_without_target() {
+        init_test_registry();
+        let creeper = CreeperEntity::new(&vanilla_entities::CREEPER, 1, DVec3::ZERO, Weak::new());
+        creeper.set_swell_dir(1);
+        let mut goal = SwellGoal::new();
+
+        assert!(goal.can_use(&creeper));
+        goal.start(&creeper);
+        goal.tick(&creeper);
+
+        assert_eq!(creeper.swell_dir(), -1);
+        assert!(!goal.can_use(&creeper));
+    }
+}
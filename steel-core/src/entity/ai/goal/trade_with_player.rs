@@ -0,0 +1,64 @@
+use super::selector::{Goal, GoalControls};
+use crate::entity::{LivingEntity, PathfinderMob};
+
+const MAX_TRADE_DISTANCE_SQR: f64 = 16.0;
+
+/// Vanilla `TradeWithPlayerGoal`: keeps a merchant still while a player trades.
+pub struct TradeWithPlayerGoal;
+
+impl TradeWithPlayerGoal {
+    #[must_use]
+    pub(crate) const fn new() -> Self {
+        Self
+    }
+}
+
+impl Goal for TradeWithPlayerGoal {
+    fn controls(&self) -> GoalControls {
+        GoalControls::JUMP | GoalControls::MOVE
+    }
+
+    fn can_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        if !LivingEntity::is_alive(mob)
+            || mob.is_in_water()
+            || !mob.on_ground()
+            || mob.hurt_marked()
+        {
+            return false;
+        }
+        let Some(uuid) = mob
+            .as_merchant()
+            .and_then(|merchant| merchant.trading_player())
+        else {
+            return false;
+        };
+
+        mob.level()
+            .and_then(|world| world.get_entity_by_uuid(&uuid))
+            .is_some_and(|player| {
+                mob.position().distance_squared(player.position()) <= MAX_TRADE_DISTANCE_SQR
+            })
+    }
+
+    fn start(&mut self, mob: &dyn PathfinderMob) {
+        mob.mob_base().navigation().lock().stop();
+    }
+
+    fn stop(&mut self, mob: &dyn PathfinderMob) {
+        if let Some(merchant) = mob.as_merchant() {
+            merchant.set_trading_player(None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trade_with_player_goal_uses_jump_and_move_controls() {
+        let goal = TradeWithPlayerGoal::new();
+
+        assert_eq!(goal.controls(), GoalControls::JUMP | GoalControls::MOVE);
+    }
+}
@@ -0,0 +1,113 @@
+use glam::DVec3;
+use steel_utils::Downcast as _;
+
+use super::selector::{Goal, GoalControls};
+use crate::entity::PathfinderMob;
+use crate::entity::entities::{VillagerEntity, VillagerPoiMemory};
+
+const CHECK_COOLDOWN: i64 = 300;
+const WORK_DISTANCE: f64 = 1.73;
+
+/// Walks a villager to its job site and works there, restocking its trades.
+///
+/// Simplified vanilla `StrollToPoi` and `WorkAtPoi` behaviors without the brain.
+pub struct WorkAtPoiGoal {
+    speed_modifier: f64,
+    last_check: i64,
+    worked: bool,
+}
+
+impl WorkAtPoiGoal {
+    #[must_use]
+    pub(crate) const fn new(speed_modifier: f64) -> Self {
+        Self {
+            speed_modifier,
+            last_check: 0,
+            worked: false,
+        }
+    }
+
+    fn try_work(&mut self, villager: &VillagerEntity) {
+        if self.worked {
+            return;
+        }
+        let Some(job_site) = villager.poi(VillagerPoiMemory::JobSite) else {
+            return;
+        };
+        let (x, y, z) = job_site.get_center();
+        if DVec3::new(x, y, z).distance_squared(villager.position()) < WORK_DISTANCE * WORK_DISTANCE
+        {
+            self.worked = true;
+            villager.work_at_poi();
+        }
+    }
+}
+
+impl Goal for WorkAtPoiGoal {
+    fn controls(&self) -> GoalControls {
+        GoalControls::MOVE
+    }
+
+    fn can_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        let Some(villager) = mob.downcast_ref::<VillagerEntity>() else {
+            return false;
+        };
+        if villager.poi(VillagerPoiMemory::JobSite).is_none() || villager.is_trading() {
+            return false;
+        }
+        let Some(world) = mob.level() else {
+            return false;
+        };
+
+        let game_time = world.game_time();
+        if game_time - self.last_check < CHECK_COOLDOWN || !rand::random::<bool>() {
+            return false;
+        }
+        self.last_check = game_time;
+        true
+    }
+
+    fn can_continue_to_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        !self.worked && !mob.mob_base().navigation().lock().is_done()
+    }
+
+    fn start(&mut self, mob: &dyn PathfinderMob) {
+        let Some(villager) = mob.downcast_ref::<VillagerEntity>() else {
+            return;
+        };
+        self.worked = false;
+        self.try_work(villager);
+        if !self.worked
+            && let Some(job_site) = villager.poi(VillagerPoiMemory::JobSite)
+        {
+            let (x, y, z) = job_site.get_center();
+            mob.move_to_pos(DVec3::new(x, y, z), self.speed_modifier);
+        }
+    }
+
+    fn stop(&mut self, mob: &dyn PathfinderMob) {
+        if let Some(villager) = mob.downcast_ref::<VillagerEntity>() {
+            self.try_work(villager);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Weak;
+
+    use steel_registry::{test_support::init_test_registry, vanilla_entities};
+
+    use super::*;
+
+    #[test]
+    fn work_at_poi_goal_requires_job_site() {
+        init_test_registry();
+        let villager =
+            VillagerEntity::new(&vanilla_entities::VILLAGER, 1, DVec3::ZERO, Weak::new());
+        let mut goal = WorkAtPoiGoal::new(0.5);
+
+        assert_eq!(goal.controls(), GoalControls::MOVE);
+        assert!(!goal.can_use(&villager));
+    }
+}
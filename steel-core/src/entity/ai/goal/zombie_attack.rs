@@ -0,0 +1,87 @@
+use super::melee_attack::MeleeAttackGoal;
+use super::selector::{Goal, GoalControls};
+use crate::entity::PathfinderMob;
+
+const RAISE_ARM_DELAY_TICKS: i32 = 5;
+
+/// Vanilla `ZombieAttackGoal`: melee attack that raises the zombie's arms
+/// shortly before each swing.
+pub struct ZombieAttackGoal {
+    melee_attack: MeleeAttackGoal,
+    raise_arm_ticks: i32,
+}
+
+impl ZombieAttackGoal {
+    #[must_use]
+    pub(crate) const fn new(speed_modifier: f64, following_target_even_if_not_seen: bool) -> Self {
+        Self {
+            melee_attack: MeleeAttackGoal::new(speed_modifier, following_target_even_if_not_seen),
+            raise_arm_ticks: 0,
+        }
+    }
+}
+
+impl Goal for ZombieAttackGoal {
+    fn controls(&self) -> GoalControls {
+        self.melee_attack.controls()
+    }
+
+    fn can_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        self.melee_attack.can_use(mob)
+    }
+
+    fn can_continue_to_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        self.melee_attack.can_continue_to_use(mob)
+    }
+
+    fn start(&mut self, mob: &dyn PathfinderMob) {
+        self.melee_attack.start(mob);
+        self.raise_arm_ticks = 0;
+    }
+
+    fn stop(&mut self, mob: &dyn PathfinderMob) {
+        self.melee_attack.stop(mob);
+        mob.set_aggressive(false);
+    }
+
+    fn requires_update_every_tick(&self) -> bool {
+        self.melee_attack.requires_update_every_tick()
+    }
+
+    fn tick(&mut self, mob: &dyn PathfinderMob) {
+        self.melee_attack.tick(mob);
+        self.raise_arm_ticks += 1;
+        let arms_raised = self.raise_arm_ticks >= RAISE_ARM_DELAY_TICKS
+            && self.melee_attack.get_ticks_until_next_attack()
+                < MeleeAttackGoal::attack_interval() / 2;
+        mob.set_aggressive(arms_raised);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Weak;
+
+    use glam::DVec3;
+    use steel_registry::{test_support::init_test_registry, vanilla_entities};
+
+    use super::*;
+    use crate::entity::Mob as _;
+    use crate::entity::entities::PigEntity;
+
+    #[test]
+    fn zombie_attack_goal_lowers_arms_until_raise_delay_passes() {
+        init_test_registry();
+        let mut goal = ZombieAttackGoal::new(1.0, false);
+        let mob = PigEntity::new(&vanilla_entities::PIG, 1, DVec3::ZERO, Weak::new());
+
+        goal.start(&mob);
+        assert!(mob.is_aggressive());
+
+        goal.tick(&mob);
+        assert!(!mob.is_aggressive());
+
+        goal.stop(&mob);
+        assert!(!mob.is_aggressive());
+    }
+}
@@ -68,6 +68,8 @@ pub struct PathNavigation {
     can_walk_over_fences: bool,
     avoid_sun: bool,
     can_path_to_targets_below_surface: bool,
+    wall_climber: bool,
+    path_to_position: Option<BlockPos>,
 }
 
 impl PathNavigation {
@@ -99,6 +101,8 @@ impl PathNavigation {
             can_walk_over_fences: false,
             avoid_sun: false,
             can_path_to_targets_below_surface: false,
+            wall_climber: false,
+            path_to_position: None,
         }
     }
 
@@ -217,6 +221,31 @@ impl PathNavigation {
         self.can_path_to_targets_below_surface = can_path_to_targets_below_surface;
     }
 
+    /// Whether this navigation behaves like vanilla `WallClimberNavigation`.
+    #[must_use]
+    pub const fn is_wall_climber(&self) -> bool {
+        self.wall_climber
+    }
+
+    pub const fn set_wall_climber(&mut self, wall_climber: bool) {
+        self.wall_climber = wall_climber;
+        if !wall_climber {
+            self.path_to_position = None;
+        }
+    }
+
+    #[must_use]
+    pub const fn path_to_position(&self) -> Option<BlockPos> {
+        self.path_to_position
+    }
+
+    /// Records the requested path target like vanilla `WallClimberNavigation.createPath`.
+    pub const fn set_path_to_position(&mut self, pos: BlockPos) {
+        if self.wall_climber {
+            self.path_to_position = Some(pos);
+        }
+    }
+
     pub const fn tick(&mut self) {
         self.tick = self.tick.wrapping_add(1);
     }
@@ -435,7 +464,7 @@ impl PathNavigation {
 
     pub fn next_move_target(&mut self, context: NavigationTickContext) -> Option<(DVec3, f64)> {
         if self.done {
-            return None;
+            return self.wall_climber_move_target(context);
         }
 
         if self.path.is_some() {
@@ -457,7 +486,7 @@ impl PathNavigation {
         on_ground: bool,
     ) -> Option<(DVec3, f64)> {
         if self.done {
-            return None;
+            return self.wall_climber_move_target(context);
         }
 
         let path = self.path.as_mut()?;
@@ -600,6 +629,32 @@ impl PathNavigation {
         Some((target, self.speed_modifier))
     }
 
+    /// Vanilla `WallClimberNavigation.tick` once the path is done: keep moving
+    /// straight at the requested position so the mob can climb up to it.
+    fn wall_climber_move_target(&mut self, context: NavigationTickContext) -> Option<(DVec3, f64)> {
+        let pos = self.path_to_position?;
+        let width_sqr = context.mob_bounding_box_width * context.mob_bounding_box_width;
+        let mob_position = context.mob_position;
+        let reached = block_center(pos).distance_squared(mob_position) < width_sqr
+            || (mob_position.y > f64::from(pos.y())
+                && block_center(BlockPos::containing(
+                    f64::from(pos.x()),
+                    mob_position.y,
+                    f64::from(pos.z()),
+                ))
+                .distance_squared(mob_position)
+                    < width_sqr);
+        if reached {
+            self.path_to_position = None;
+            return None;
+        }
+
+        Some((
+            DVec3::new(f64::from(pos.x()), f64::from(pos.y()), f64::from(pos.z())),
+            self.speed_modifier,
+        ))
+    }
+
     fn do_stuck_detection(&mut self, mob_position: DVec3, mob_speed: f32, game_time: i64) {
         if self.tick - self.last_stuck_check > STUCK_CHECK_INTERVAL {
             let effective_speed = if mob_speed >= 1.0 {
@@ -888,6 +943,51 @@ mod tests {
         assert!(navigation.can_path_to_targets_below_surface());
     }
 
+    #[test]
+    fn path_to_position_is_only_recorded_for_wall_climbers() {
+        let mut navigation = PathNavigation::new();
+
+        navigation.set_path_to_position(BlockPos::new(3, 66, 0));
+
+        assert_eq!(navigation.path_to_position(), None);
+
+        navigation.set_wall_climber(true);
+        navigation.set_path_to_position(BlockPos::new(3, 66, 0));
+
+        assert!(navigation.is_wall_climber());
+        assert_eq!(navigation.path_to_position(), Some(BlockPos::new(3, 66, 0)));
+    }
+
+    #[test]
+    fn wall_climber_moves_to_path_to_position_after_path_is_done() {
+        let mut navigation = PathNavigation::new();
+        navigation.set_wall_climber(true);
+        navigation.set_speed_modifier(1.25);
+        navigation.set_path_to_position(BlockPos::new(3, 66, 0));
+
+        let target = navigation.next_move_target(tick_context(DVec3::new(2.5, 64.0, 0.5)));
+
+        let Some((target, speed)) = target else {
+            panic!("wall climber should keep moving to the requested position");
+        };
+        assert_eq!(target, DVec3::new(3.0, 66.0, 0.0));
+        assert_eq!(speed.to_bits(), 1.25_f64.to_bits());
+        assert!(navigation.is_done());
+    }
+
+    #[test]
+    fn wall_climber_clears_path_to_position_when_above_target_column() {
+        let mut navigation = PathNavigation::new();
+        navigation.set_wall_climber(true);
+        navigation.set_path_to_position(BlockPos::new(3, 66, 0));
+
+        assert_eq!(
+            navigation.next_move_target(tick_context(DVec3::new(3.5, 67.0, 0.5))),
+            None
+        );
+        assert_eq!(navigation.path_to_position(), None);
+    }
+
     #[test]
     fn avoid_sun_trims_path_before_first_sky_node() {
         let level = GridLevel::new(BlockStateId(0)).with_sky(BlockPos::new(2, 64, 0));
@@ -6,6 +6,7 @@ use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
 use simdnbt::owned::{NbtCompound, NbtTag};
 use steel_registry::blocks::block_state_ext::BlockStateExt as _;
 use steel_registry::item_stack::ItemStack;
+use steel_registry::stat::{CustomStat, Stat};
 use steel_registry::vanilla_block_tags::BlockTag;
 use steel_registry::vanilla_blocks;
 use steel_registry::vanilla_game_rules::MOB_DROPS;
@@ -22,7 +23,7 @@ use crate::entity::{
     AgeableMob, AgeableMobBase, ENTITIES, EntitySpawnReason, Mob, MobBase, SharedEntity,
     next_entity_id,
 };
-use crate::player::Player;
+use crate::player::{CriteriaTrigger, Player};
 use crate::world::{LevelReader, World};
 
 const PARENT_AGE_AFTER_BREEDING: i32 = 6000;
@@ -333,14 +334,19 @@ pub trait Animal: AgeableMob {
         &self,
         world: &Arc<World>,
         partner: &dyn Animal,
-        _offspring: Option<&dyn Animal>,
+        offspring: Option<&dyn Animal>,
     ) {
-        if self
+        if let Some(cause) = self
             .love_cause_uuid()
             .or_else(|| partner.love_cause_uuid())
-            .is_some()
+            .and_then(|uuid| world.players.get_by_uuid(&uuid))
         {
-            // TODO: Award the animals-bred stat and advancement once those foundations exist.
+            cause.award_stat(Stat::custom(CustomStat::AnimalsBred));
+            cause.trigger_criteria(CriteriaTrigger::BredAnimals {
+                parent: self.entity_type(),
+                partner: partner.entity_type(),
+                child: offspring.map(|offspring| offspring.entity_type()),
+            });
         }
 
         self.set_age(PARENT_AGE_AFTER_BREEDING);
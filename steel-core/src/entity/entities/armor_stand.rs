@@ -0,0 +1,805 @@
+//! Vanilla armor stand entity.
+
+use std::sync::Weak;
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_macros::{entity_behavior, entity_impl};
+use steel_protocol::packets::game::{AttributeSnapshot, EquipmentSlotItem};
+use steel_registry::data_components::vanilla_components::CUSTOM_NAME;
+use steel_registry::entity_data::Rotations;
+use steel_registry::entity_type::{EntityDimensions, EntityTypeRef};
+use steel_registry::equipment::{EquipmentSlot, EquipmentSlotType};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::particle_type::{BlockParticleOption, ParticleData};
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_damage_type_tags::DamageTypeTag;
+use steel_registry::vanilla_entity_data::ArmorStandEntityData;
+use steel_registry::{
+    REGISTRY, sound_events, vanilla_blocks, vanilla_entities, vanilla_game_events, vanilla_items,
+    vanilla_particle_types,
+};
+use steel_utils::entity_events::EntityStatus;
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::InteractionHand;
+use steel_utils::{DowncastType, DowncastTypeKey};
+
+use crate::behavior::InteractionResult;
+use crate::entity::damage::DamageSource;
+use crate::entity::entities::hanging::{
+    game_event_caused_by, is_creative_player_source, is_mob_griefing_blocked,
+};
+use crate::entity::{
+    Entity, EntityBase, EntityBaseLoad, EntityPose, EntitySyncedData, LivingEntity,
+    LivingEntityBase, MobEffectSyncChange, RemovalReason,
+};
+use crate::physics::MoveResult;
+use crate::player::Player;
+use crate::world::World;
+
+const CLIENT_FLAG_SMALL: i8 = 1;
+const CLIENT_FLAG_SHOW_ARMS: i8 = 4;
+const CLIENT_FLAG_NO_BASEPLATE: i8 = 8;
+const CLIENT_FLAG_MARKER: i8 = 16;
+
+/// Vanilla `ArmorStand.MARKER_DIMENSIONS`.
+const MARKER_DIMENSIONS: EntityDimensions = EntityDimensions::new(0.0, 0.0, 0.0);
+
+/// Ticks within which a second punch breaks a survival-mode armor stand.
+const WOBBLE_TIME: i64 = 5;
+
+/// Disabled-slot bit offset that blocks taking items out of a slot.
+const DISABLE_TAKING_OFFSET: i32 = 8;
+/// Disabled-slot bit offset that blocks putting items into a slot.
+const DISABLE_PUTTING_OFFSET: i32 = 16;
+
+/// A posable armor stand body part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArmorStandPart {
+    /// The head.
+    Head,
+    /// The torso.
+    Body,
+    /// The left arm.
+    LeftArm,
+    /// The right arm.
+    RightArm,
+    /// The left leg.
+    LeftLeg,
+    /// The right leg.
+    RightLeg,
+}
+
+impl ArmorStandPart {
+    /// Every posable part in vanilla save order.
+    pub const ALL: [Self; 6] = [
+        Self::Head,
+        Self::Body,
+        Self::LeftArm,
+        Self::RightArm,
+        Self::LeftLeg,
+        Self::RightLeg,
+    ];
+
+    /// Returns vanilla's default rotation for this part.
+    #[must_use]
+    pub const fn default_pose(self) -> Rotations {
+        match self {
+            Self::Head | Self::Body => Rotations::ZERO,
+            Self::LeftArm => Rotations::new(-10.0, 0.0, -10.0),
+            Self::RightArm => Rotations::new(-15.0, 0.0, 10.0),
+            Self::LeftLeg => Rotations::new(-1.0, 0.0, -1.0),
+            Self::RightLeg => Rotations::new(1.0, 0.0, 1.0),
+        }
+    }
+
+    /// Returns the key used for this part in the `Pose` NBT compound.
+    #[must_use]
+    pub const fn nbt_key(self) -> &'static str {
+        match self {
+            Self::Head => "Head",
+            Self::Body => "Body",
+            Self::LeftArm => "LeftArm",
+            Self::RightArm => "RightArm",
+            Self::LeftLeg => "LeftLeg",
+            Self::RightLeg => "RightLeg",
+        }
+    }
+}
+
+/// Server-side armor stand state that is not synced to clients.
+struct ArmorStandState {
+    disabled_slots: i32,
+    last_hit: i64,
+}
+
+/// Vanilla armor stand.
+#[entity_behavior(class = "ArmorStand")]
+pub struct ArmorStandEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    living_base: LivingEntityBase,
+    entity_data: SyncMutex<ArmorStandEntityData>,
+    state: SyncMutex<ArmorStandState>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `ArmorStandEntity`.
+unsafe impl DowncastType for ArmorStandEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/armor_stand");
+}
+
+impl ArmorStandEntity {
+    /// Creates a new armor stand entity.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_with_base(
+            EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+        )
+    }
+
+    /// Creates an armor stand entity from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self::new_with_base(
+            EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+        )
+    }
+
+    fn new_with_base(base: EntityBase, entity_type: EntityTypeRef) -> Self {
+        let living_base = LivingEntityBase::new(entity_type);
+        let mut entity_data = ArmorStandEntityData::new();
+        living_base.initialize_synced_data(&mut entity_data);
+        let entity = Self {
+            base,
+            entity_type,
+            living_base,
+            entity_data: SyncMutex::new(entity_data),
+            state: SyncMutex::new(ArmorStandState {
+                disabled_slots: 0,
+                last_hit: 0,
+            }),
+        };
+        for part in ArmorStandPart::ALL {
+            entity.set_pose(part, part.default_pose());
+        }
+        entity
+    }
+
+    /// Returns the current rotation of `part`.
+    #[must_use]
+    pub fn pose(&self, part: ArmorStandPart) -> Rotations {
+        let entity_data = self.entity_data.lock();
+        *match part {
+            ArmorStandPart::Head => entity_data.head_pose.get(),
+            ArmorStandPart::Body => entity_data.body_pose.get(),
+            ArmorStandPart::LeftArm => entity_data.left_arm_pose.get(),
+            ArmorStandPart::RightArm => entity_data.right_arm_pose.get(),
+            ArmorStandPart::LeftLeg => entity_data.left_leg_pose.get(),
+            ArmorStandPart::RightLeg => entity_data.right_leg_pose.get(),
+        }
+    }
+
+    /// Sets the rotation of `part`.
+    pub fn set_pose(&self, part: ArmorStandPart, pose: Rotations) {
+        let mut entity_data = self.entity_data.lock();
+        match part {
+            ArmorStandPart::Head => entity_data.head_pose.set(pose),
+            ArmorStandPart::Body => entity_data.body_pose.set(pose),
+            ArmorStandPart::LeftArm => entity_data.left_arm_pose.set(pose),
+            ArmorStandPart::RightArm => entity_data.right_arm_pose.set(pose),
+            ArmorStandPart::LeftLeg => entity_data.left_leg_pose.set(pose),
+            ArmorStandPart::RightLeg => entity_data.right_leg_pose.set(pose),
+        }
+    }
+
+    /// Returns whether this is a half-size armor stand.
+    #[must_use]
+    pub fn is_small(&self) -> bool {
+        self.client_flag(CLIENT_FLAG_SMALL)
+    }
+
+    /// Sets whether this is a half-size armor stand.
+    pub fn set_small(&self, small: bool) {
+        self.set_client_flag(CLIENT_FLAG_SMALL, small);
+        self.refresh_dimensions();
+    }
+
+    /// Returns whether the arms are shown and can hold items.
+    #[must_use]
+    pub fn show_arms(&self) -> bool {
+        self.client_flag(CLIENT_FLAG_SHOW_ARMS)
+    }
+
+    /// Sets whether the arms are shown and can hold items.
+    pub fn set_show_arms(&self, show_arms: bool) {
+        self.set_client_flag(CLIENT_FLAG_SHOW_ARMS, show_arms);
+    }
+
+    /// Returns whether the base plate is hidden.
+    #[must_use]
+    pub fn has_no_base_plate(&self) -> bool {
+        self.client_flag(CLIENT_FLAG_NO_BASEPLATE)
+    }
+
+    /// Sets whether the base plate is hidden.
+    pub fn set_no_base_plate(&self, no_base_plate: bool) {
+        self.set_client_flag(CLIENT_FLAG_NO_BASEPLATE, no_base_plate);
+    }
+
+    /// Returns whether this is a marker with no hitbox.
+    #[must_use]
+    pub fn is_marker(&self) -> bool {
+        self.client_flag(CLIENT_FLAG_MARKER)
+    }
+
+    /// Sets whether this is a marker with no hitbox.
+    pub fn set_marker(&self, marker: bool) {
+        self.set_client_flag(CLIENT_FLAG_MARKER, marker);
+        self.refresh_dimensions();
+    }
+
+    fn client_flag(&self, flag: i8) -> bool {
+        *self.entity_data.lock().client_flags.get() & flag != 0
+    }
+
+    fn set_client_flag(&self, flag: i8, value: bool) {
+        let mut entity_data = self.entity_data.lock();
+        let flags = *entity_data.client_flags.get();
+        entity_data
+            .client_flags
+            .set(if value { flags | flag } else { flags & !flag });
+    }
+
+    /// Mirrors vanilla `ArmorStand.isDisabled`.
+    fn is_disabled(&self, slot: EquipmentSlot) -> bool {
+        self.state.lock().disabled_slots & (1 << slot.id()) != 0
+            || slot.slot_type() == EquipmentSlotType::Hand && !self.show_arms()
+    }
+
+    /// Mirrors vanilla `ArmorStand.getClickedSlot`.
+    fn clicked_slot(&self, location: DVec3) -> EquipmentSlot {
+        let small = self.is_small();
+        let y = location.y / f64::from(LivingEntity::get_scale(self) * self.get_age_scale());
+        let (feet_height, chest_min, chest_max, legs_height) = if small {
+            (0.8, 1.2, 1.9, 1.0)
+        } else {
+            (0.45, 0.9, 1.6, 0.8)
+        };
+        if (0.1..0.1 + feet_height).contains(&y) && self.has_item_in_slot(EquipmentSlot::Feet) {
+            EquipmentSlot::Feet
+        } else if (chest_min..chest_max).contains(&y) && self.has_item_in_slot(EquipmentSlot::Chest)
+        {
+            EquipmentSlot::Chest
+        } else if (0.4..0.4 + legs_height).contains(&y)
+            && self.has_item_in_slot(EquipmentSlot::Legs)
+        {
+            EquipmentSlot::Legs
+        } else if y >= 1.6 && self.has_item_in_slot(EquipmentSlot::Head) {
+            EquipmentSlot::Head
+        } else if !self.has_item_in_slot(EquipmentSlot::MainHand)
+            && self.has_item_in_slot(EquipmentSlot::OffHand)
+        {
+            EquipmentSlot::OffHand
+        } else {
+            EquipmentSlot::MainHand
+        }
+    }
+
+    /// Mirrors vanilla `ArmorStand.swapItem`.
+    fn swap_item(&self, player: &Player, slot: EquipmentSlot, hand: InteractionHand) -> bool {
+        let current = self.living_base.equipment().lock().get_ref(slot).clone();
+        let disabled_slots = self.state.lock().disabled_slots;
+        if !current.is_empty() && disabled_slots & (1 << (slot.id() + DISABLE_TAKING_OFFSET)) != 0 {
+            return false;
+        }
+        if current.is_empty() && disabled_slots & (1 << (slot.id() + DISABLE_PUTTING_OFFSET)) != 0 {
+            return false;
+        }
+
+        let placed = {
+            let mut inventory = player.inventory.lock();
+            let held = inventory.get_item_in_hand(hand);
+            if player.has_infinite_materials() && current.is_empty() && !held.is_empty() {
+                held.copy_with_count(1)
+            } else if held.count() <= 1 {
+                let held = held.clone();
+                inventory.set_item_in_hand(hand, current);
+                held
+            } else if current.is_empty() {
+                inventory.split_item_in_hand(hand, 1)
+            } else {
+                return false;
+            }
+        };
+        self.set_item_slot(slot, placed);
+        true
+    }
+
+    /// Replaces the item in `slot`, playing its equip sound like vanilla `onEquipItem`.
+    fn set_item_slot(&self, slot: EquipmentSlot, item: ItemStack) {
+        let sound = (!item.is_empty())
+            .then(|| self.equip_sound(slot, &item))
+            .flatten();
+        self.living_base.equipment().lock().set(slot, item);
+        self.refresh_equipment_attribute_modifiers(slot);
+        if let Some(sound) = sound {
+            self.play_sound(sound, 1.0, 1.0);
+        }
+    }
+
+    fn update_dirty_mob_effect_entity_data(&self) {
+        if !self.living_base.take_effects_dirty() {
+            return;
+        }
+
+        let display = self.living_base.mob_effect_display_state();
+
+        {
+            let mut entity_data = self.entity_data.lock();
+            let living = entity_data.living_entity_mut();
+            living.effect_particles.set(display.particles);
+            living.effect_ambience.set(display.ambient);
+        }
+
+        self.entity_data.set_base_invisible_flag(display.invisible);
+        self.entity_data
+            .set_base_glowing_flag(self.has_glowing_tag() || display.glowing);
+    }
+
+    /// Mirrors vanilla `ArmorStand.showBreakingParticles`.
+    fn show_breaking_particles(&self, world: &World) {
+        let dimensions = self.dimensions_for_pose(EntityPose::Standing);
+        let width = f64::from(dimensions.width) / 4.0;
+        let height = f64::from(dimensions.height) / 4.0;
+        let position = self.position();
+        world.send_particles(
+            ParticleData::new(
+                &vanilla_particle_types::BLOCK,
+                BlockParticleOption::new(
+                    REGISTRY
+                        .blocks
+                        .get_default_state_id(&vanilla_blocks::OAK_PLANKS),
+                ),
+            ),
+            DVec3::new(
+                position.x,
+                position.y + f64::from(dimensions.height) * (2.0 / 3.0),
+                position.z,
+            ),
+            10,
+            DVec3::new(width, height, width),
+            0.05,
+        );
+    }
+
+    /// Mirrors vanilla `ArmorStand.causeDamage`.
+    fn cause_damage(&self, world: &World, source: &DamageSource, damage: f32) {
+        let health = self.get_health() - damage;
+        if health <= 0.5 {
+            self.broken_by_anything(source);
+            self.kill(world);
+        } else {
+            self.set_health(health);
+            game_event_caused_by(
+                self,
+                &vanilla_game_events::ENTITY_DAMAGE,
+                source.causing_entity_id,
+            );
+        }
+    }
+
+    /// Mirrors vanilla `ArmorStand.brokenByPlayer`.
+    fn broken_by_player(&self, source: &DamageSource) {
+        let mut item = ItemStack::new(&vanilla_items::ARMOR_STAND);
+        if let Some(custom_name) = self.custom_name() {
+            item.set(CUSTOM_NAME, custom_name);
+        }
+        if let Some(world) = self.level() {
+            world.pop_resource(self.block_position(), item);
+        }
+        self.broken_by_anything(source);
+    }
+
+    /// Mirrors vanilla `ArmorStand.brokenByAnything`.
+    fn broken_by_anything(&self, source: &DamageSource) {
+        self.play_sound(&sound_events::ENTITY_ARMOR_STAND_BREAK, 1.0, 1.0);
+        self.drop_all_death_loot(source);
+
+        let Some(world) = self.level() else {
+            return;
+        };
+        let drop_pos = self.block_position().above();
+        for slot in EquipmentSlot::ALL {
+            let item = self
+                .living_base
+                .equipment()
+                .lock()
+                .set(slot, ItemStack::empty());
+            if !item.is_empty() {
+                world.pop_resource(drop_pos, item);
+            }
+        }
+    }
+
+    fn save_pose(&self, nbt: &mut NbtCompound) {
+        let mut pose = NbtCompound::new();
+        for part in ArmorStandPart::ALL {
+            let rotations = self.pose(part);
+            if rotations != part.default_pose() {
+                pose.insert(
+                    part.nbt_key(),
+                    NbtTag::List(NbtList::Float(vec![rotations.x, rotations.y, rotations.z])),
+                );
+            }
+        }
+        nbt.insert("Pose", NbtTag::Compound(pose));
+    }
+
+    fn load_pose(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        let pose = nbt.compound("Pose");
+        for part in ArmorStandPart::ALL {
+            let rotations = pose
+                .as_ref()
+                .and_then(|pose| pose.list(part.nbt_key()))
+                .and_then(|list| list.floats())
+                .filter(|values| values.len() == 3)
+                .map_or(part.default_pose(), |values| {
+                    Rotations::new(values[0], values[1], values[2])
+                });
+            self.set_pose(part, rotations);
+        }
+    }
+
+    fn save_equipment(&self, nbt: &mut NbtCompound) {
+        let mut equipment = NbtCompound::new();
+        for slot in EquipmentSlot::ALL {
+            self.with_equipment_slot(slot, &mut |item| {
+                if !item.is_empty() {
+                    equipment.insert(slot.name(), item.to_nbt_tag_ref());
+                }
+            });
+        }
+        if !equipment.is_empty() {
+            nbt.insert("equipment", NbtTag::Compound(equipment));
+        }
+    }
+
+    fn load_equipment(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        let Some(equipment) = nbt.compound("equipment") else {
+            return;
+        };
+        for slot in EquipmentSlot::ALL {
+            if let Some(item_tag) = equipment.compound(slot.name())
+                && let Some(item) = ItemStack::from_borrowed_compound(&item_tag)
+            {
+                self.living_base.equipment().lock().set(slot, item);
+                self.refresh_equipment_attribute_modifiers(slot);
+            }
+        }
+    }
+}
+
+#[entity_impl(class(living))]
+impl Entity for ArmorStandEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn dimensions_for_pose(&self, _pose: EntityPose) -> EntityDimensions {
+        if self.is_marker() {
+            MARKER_DIMENSIONS
+        } else if self.is_small() {
+            self.entity_type.dimensions.scale(0.5)
+        } else {
+            self.entity_type.dimensions
+        }
+        .scale(LivingEntity::get_scale(self))
+    }
+
+    fn tick(&self) {
+        self.default_tick();
+        self.living_base.decrement_invulnerable_time();
+        self.tick_mob_effects();
+
+        if self.is_dead_or_dying() {
+            LivingEntity::tick_death(self);
+            self.tick_living_state();
+            return;
+        }
+
+        if !self.is_removed() {
+            self.ai_step();
+        }
+
+        self.tick_living_state();
+    }
+
+    fn kill(&self, _world: &World) {
+        self.set_removed(RemovalReason::Killed);
+        self.game_event(&vanilla_game_events::ENTITY_DIE);
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.is_removed() && self.get_health() > 0.0
+    }
+
+    fn is_pickable(&self) -> bool {
+        !self.is_removed() && !self.is_marker()
+    }
+
+    fn is_marker_armor_stand(&self) -> bool {
+        self.is_marker()
+    }
+
+    fn skip_attack_interaction(&self, source: &dyn Entity) -> bool {
+        let Some(player) = source.as_player() else {
+            return false;
+        };
+        self.level()
+            .is_some_and(|world| !world.may_interact(player, self.block_position()))
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn update_data_before_sync(&self) {
+        self.update_dirty_mob_effect_entity_data();
+    }
+
+    fn pack_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().syncable_snapshots()
+    }
+
+    fn drain_dirty_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().drain_dirty_sync()
+    }
+
+    fn drain_dirty_mob_effects(&self) -> Vec<MobEffectSyncChange> {
+        self.living_base.drain_dirty_mob_effects()
+    }
+
+    fn pack_all_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.pack_living_equipment()
+    }
+
+    fn drain_dirty_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.drain_dirty_living_equipment()
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, _amount: f32) -> bool {
+        if self.is_removed() || is_mob_griefing_blocked(world, source) {
+            return false;
+        }
+        if source.bypasses_invulnerability() {
+            self.kill(world);
+            return false;
+        }
+        if self.is_invulnerable_to(world, source) || self.is_invisible() || self.is_marker() {
+            return false;
+        }
+        if source.is(&DamageTypeTag::IS_EXPLOSION) {
+            self.broken_by_anything(source);
+            self.kill(world);
+            return false;
+        }
+        if source.is(&DamageTypeTag::IGNITES_ARMOR_STANDS) {
+            if self.is_on_fire() {
+                self.cause_damage(world, source, 0.15);
+            } else {
+                self.ignite_for_ticks(100);
+            }
+            return false;
+        }
+        if source.is(&DamageTypeTag::BURNS_ARMOR_STANDS) && self.get_health() > 0.5 {
+            self.cause_damage(world, source, 4.0);
+            return false;
+        }
+
+        let should_kill = source.is(&DamageTypeTag::ALWAYS_KILLS_ARMOR_STANDS);
+        if !source.is(&DamageTypeTag::CAN_BREAK_ARMOR_STAND) && !should_kill {
+            return false;
+        }
+        let causing_player = source
+            .causing_entity_id
+            .and_then(|id| world.get_entity_by_id(id))
+            .filter(|entity| entity.as_player().is_some());
+        if causing_player
+            .as_ref()
+            .and_then(|entity| entity.as_player())
+            .is_some_and(|player| !player.abilities.lock().may_build)
+        {
+            return false;
+        }
+        if is_creative_player_source(world, source) {
+            self.play_sound(&sound_events::ENTITY_ARMOR_STAND_BREAK, 1.0, 1.0);
+            self.show_breaking_particles(world);
+            self.kill(world);
+            return true;
+        }
+
+        let time = world.game_time();
+        let last_hit = self.state.lock().last_hit;
+        if time - last_hit > WOBBLE_TIME && !should_kill {
+            self.broadcast_entity_event(EntityStatus::ArmorstandWobble);
+            game_event_caused_by(
+                self,
+                &vanilla_game_events::ENTITY_DAMAGE,
+                source.causing_entity_id,
+            );
+            self.state.lock().last_hit = time;
+        } else {
+            self.broken_by_player(source);
+            self.show_breaking_particles(world);
+            self.kill(world);
+        }
+        true
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        let held = player.inventory.lock().get_item_in_hand(hand).clone();
+        if self.is_marker() || held.is(&vanilla_items::NAME_TAG) {
+            return InteractionResult::Pass;
+        }
+        if player.is_spectator() {
+            return InteractionResult::Success;
+        }
+
+        let slot = held
+            .get_equippable()
+            .map_or(EquipmentSlot::MainHand, |equippable| equippable.slot);
+        if held.is_empty() {
+            let clicked_slot = self.clicked_slot(location);
+            let slot = if self.is_disabled(clicked_slot) {
+                slot
+            } else {
+                clicked_slot
+            };
+            if self.has_item_in_slot(slot) && self.swap_item(player, slot, hand) {
+                return InteractionResult::SuccessServer;
+            }
+        } else {
+            if self.is_disabled(slot) {
+                return InteractionResult::Fail;
+            }
+            if slot.slot_type() == EquipmentSlotType::Hand && !self.show_arms() {
+                return InteractionResult::Fail;
+            }
+            if self.swap_item(player, slot, hand) {
+                return InteractionResult::SuccessServer;
+            }
+        }
+        InteractionResult::Pass
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        nbt.insert("Health", self.get_health());
+        self.save_equipment(nbt);
+        nbt.insert("Invisible", i8::from(self.is_invisible()));
+        nbt.insert("Small", i8::from(self.is_small()));
+        nbt.insert("ShowArms", i8::from(self.show_arms()));
+        nbt.insert("DisabledSlots", self.state.lock().disabled_slots);
+        nbt.insert("NoBasePlate", i8::from(self.has_no_base_plate()));
+        if self.is_marker() {
+            nbt.insert("Marker", 1_i8);
+        }
+        self.save_pose(nbt);
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        if let Some(health) = nbt.float("Health") {
+            self.set_health(health);
+        }
+        self.load_equipment(nbt);
+        self.entity_data
+            .set_base_invisible_flag(nbt.byte("Invisible").is_some_and(|value| value != 0));
+        self.set_small(nbt.byte("Small").is_some_and(|value| value != 0));
+        self.set_show_arms(nbt.byte("ShowArms").is_some_and(|value| value != 0));
+        self.state.lock().disabled_slots = nbt.int("DisabledSlots").unwrap_or(0);
+        self.set_no_base_plate(nbt.byte("NoBasePlate").is_some_and(|value| value != 0));
+        self.set_marker(nbt.byte("Marker").is_some_and(|value| value != 0));
+        self.load_pose(nbt);
+    }
+}
+
+impl LivingEntity for ArmorStandEntity {
+    fn living_base(&self) -> &LivingEntityBase {
+        &self.living_base
+    }
+
+    fn get_health(&self) -> f32 {
+        *self.entity_data.lock().living_entity().health.get()
+    }
+
+    fn set_health(&self, health: f32) {
+        let max_health = self.get_max_health();
+        let clamped = health.clamp(0.0, max_health);
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .health
+            .set(clamped);
+    }
+
+    fn is_baby(&self) -> bool {
+        self.is_small()
+    }
+
+    fn can_use_slot(&self, slot: EquipmentSlot) -> bool {
+        slot != EquipmentSlot::Body && slot != EquipmentSlot::Saddle && !self.is_disabled(slot)
+    }
+
+    fn hurt_sound(&self, _source: &DamageSource) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_ARMOR_STAND_HIT)
+    }
+
+    fn death_sound(&self) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_ARMOR_STAND_BREAK)
+    }
+
+    fn ai_step(&self) -> Option<MoveResult> {
+        if self.is_marker() {
+            return None;
+        }
+        self.default_ai_step()
+    }
+
+    fn push_entities(&self) {
+        let Some(world) = self.level() else {
+            return;
+        };
+        let position = self.position();
+        let minecarts = world.get_entities_in_aabb_matching(&self.bounding_box(), |entity| {
+            entity.id() != self.id() && entity.entity_type() == &vanilla_entities::MINECART
+        });
+        for minecart in minecarts {
+            if position.distance_squared(minecart.position()) <= 0.2 {
+                minecart.push_entity(self);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use steel_registry::test_support::init_test_registry;
+
+    #[test]
+    fn armor_stand_persists_flags_and_non_default_poses() {
+        init_test_registry();
+        let armor_stand = ArmorStandEntity::new(
+            &vanilla_entities::ARMOR_STAND,
+            1,
+            DVec3::new(0.5, 64.0, 0.5),
+            Weak::new(),
+        );
+        armor_stand.set_show_arms(true);
+        armor_stand.set_small(true);
+        armor_stand.set_pose(ArmorStandPart::Head, Rotations::new(10.0, 20.0, 30.0));
+
+        let mut nbt = NbtCompound::new();
+        armor_stand.save_additional(&mut nbt);
+
+        assert_eq!(nbt.byte("ShowArms"), Some(1));
+        assert_eq!(nbt.byte("Small"), Some(1));
+        assert_eq!(nbt.byte("Marker"), None);
+        let Some(pose) = nbt.compound("Pose") else {
+            panic!("armor stand should save its pose");
+        };
+        assert!(pose.get("Head").is_some());
+        assert!(pose.get("LeftArm").is_none());
+    }
+}
@@ -0,0 +1,256 @@
+//! Arrow projectile entity (`Arrow`).
+//!
+//! Mirrors vanilla `Arrow` on the Steel `Projectile → AbstractArrow` trait
+//! stack. Skeletons shoot these; tipped-arrow potion effects are not carried yet.
+
+use std::sync::Weak;
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::NbtCompound;
+use steel_macros::{entity_behavior, entity_impl};
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::vanilla_entity_data::ArrowEntityData;
+use steel_registry::vanilla_items;
+use steel_utils::locks::SyncMutex;
+use steel_utils::{DowncastType, DowncastTypeKey};
+
+use crate::entity::damage::DamageSource;
+use crate::entity::{
+    AbstractArrow, AbstractArrowBase, Entity, EntityBase, EntityBaseLoad, EntitySyncedData,
+    Projectile, ProjectileBase, SharedEntity,
+};
+use crate::world::{ClipHitResult, World};
+
+/// Vanilla `AbstractArrow.ID_FLAGS` crit bit.
+const FLAG_CRIT: i8 = 1;
+
+/// A flying or stuck arrow.
+#[entity_behavior(class = "Arrow")]
+pub struct ArrowEntity {
+    /// Common entity fields (id, uuid, position, etc.).
+    base: EntityBase,
+    /// Vanilla entity type registered for this implementation.
+    entity_type: EntityTypeRef,
+    /// Synced data carrying the in-ground and crit flags.
+    entity_data: SyncMutex<ArrowEntityData>,
+    /// Shared `Projectile` state (owner / left-owner / has-been-shot).
+    projectile_base: ProjectileBase,
+    /// Shared `AbstractArrow` state (life, damage, pickup).
+    abstract_arrow_base: AbstractArrowBase,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `ArrowEntity`.
+unsafe impl DowncastType for ArrowEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/arrow");
+}
+
+impl ArrowEntity {
+    /// Creates a new arrow with no owner.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self {
+            base: EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+            entity_data: SyncMutex::new(ArrowEntityData::new()),
+            projectile_base: ProjectileBase::new(),
+            abstract_arrow_base: AbstractArrowBase::new(),
+        }
+    }
+
+    /// Creates an arrow from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self {
+            base: EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+            entity_data: SyncMutex::new(ArrowEntityData::new()),
+            projectile_base: ProjectileBase::new(),
+            abstract_arrow_base: AbstractArrowBase::new(),
+        }
+    }
+}
+
+#[entity_impl(class(projectile))]
+impl Entity for ArrowEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn tick(&self) {
+        self.abstract_arrow_tick();
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        self.abstract_arrow_default_gravity()
+    }
+
+    fn sound_source(&self) -> SoundSource {
+        SoundSource::Neutral
+    }
+
+    fn spawn_data(&self) -> i32 {
+        self.get_owner().map_or(0, |owner| owner.id())
+    }
+
+    fn restore_owner_reference(&self, owner: &SharedEntity) {
+        self.cache_owner_entity(owner);
+    }
+
+    fn projectile_owner_uuid(&self) -> Option<uuid::Uuid> {
+        self.owner_uuid()
+    }
+
+    fn projectile_owner(&self) -> Option<SharedEntity> {
+        self.get_owner()
+    }
+
+    fn attackable(&self) -> bool {
+        false
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn hurt(&self, _world: &World, _source: &DamageSource, _amount: f32) -> bool {
+        // Vanilla `Projectile.hurtServer` marks hurt but never takes damage.
+        false
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        self.save_projectile(nbt);
+        self.save_abstract_arrow(nbt);
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        self.load_projectile(nbt);
+        self.load_abstract_arrow(nbt);
+    }
+}
+
+impl Projectile for ArrowEntity {
+    fn projectile_base(&self) -> &ProjectileBase {
+        &self.projectile_base
+    }
+
+    fn on_hit_entity(&self, entity: &SharedEntity, _location: DVec3) {
+        self.abstract_arrow_on_hit_entity(entity);
+    }
+
+    fn on_hit_block(&self, hit: &ClipHitResult) {
+        self.abstract_arrow_on_hit_block(hit);
+    }
+}
+
+impl AbstractArrow for ArrowEntity {
+    fn abstract_arrow_base(&self) -> &AbstractArrowBase {
+        &self.abstract_arrow_base
+    }
+
+    fn is_in_ground(&self) -> bool {
+        *self.entity_data.lock().abstract_arrow.in_ground.get()
+    }
+
+    fn set_in_ground(&self, in_ground: bool) {
+        self.entity_data
+            .lock()
+            .abstract_arrow
+            .in_ground
+            .set(in_ground);
+    }
+
+    fn is_crit_arrow(&self) -> bool {
+        *self.entity_data.lock().abstract_arrow.id_flags.get() & FLAG_CRIT != 0
+    }
+
+    fn set_crit_arrow(&self, crit: bool) {
+        let mut data = self.entity_data.lock();
+        let flags = *data.abstract_arrow.id_flags.get();
+        let flags = if crit {
+            flags | FLAG_CRIT
+        } else {
+            flags & !FLAG_CRIT
+        };
+        data.abstract_arrow.id_flags.set(flags);
+    }
+
+    fn get_pickup_item(&self) -> ItemStack {
+        ItemStack::new(&vanilla_items::ARROW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::sync::Weak;
+
+    use glam::DVec3;
+    use simdnbt::borrow::read_compound as read_borrowed_compound;
+    use simdnbt::owned::NbtCompound;
+    use steel_registry::{test_support::init_test_registry, vanilla_entities};
+
+    use crate::entity::{AbstractArrow, ArrowPickup, Entity};
+    use crate::world::World;
+
+    use super::ArrowEntity;
+
+    fn arrow() -> ArrowEntity {
+        init_test_registry();
+        ArrowEntity::new(
+            &vanilla_entities::ARROW,
+            1,
+            DVec3::ZERO,
+            Weak::<World>::new(),
+        )
+    }
+
+    #[test]
+    fn crit_flag_round_trips_through_synced_flags() {
+        let arrow = arrow();
+        assert!(!arrow.is_crit_arrow());
+
+        arrow.set_crit_arrow(true);
+        assert!(arrow.is_crit_arrow());
+        arrow.set_crit_arrow(false);
+        assert!(!arrow.is_crit_arrow());
+    }
+
+    #[test]
+    fn mob_damage_scales_with_power_and_difficulty() {
+        let arrow = arrow();
+        for _ in 0..64 {
+            arrow.set_base_damage_from_mob(1.0, 3);
+            let damage = arrow.base_damage();
+            assert!((2.0 + 0.33 - 0.574_25..=2.0 + 0.33 + 0.574_25).contains(&damage));
+        }
+    }
+
+    #[test]
+    fn arrow_state_round_trips_through_nbt() {
+        let arrow = arrow();
+        arrow.set_base_damage(4.5);
+        arrow.set_pickup(ArrowPickup::CreativeOnly);
+        arrow.set_in_ground(true);
+
+        let mut nbt = NbtCompound::new();
+        arrow.save_additional(&mut nbt);
+        assert_eq!(nbt.byte("pickup"), Some(2));
+
+        let mut bytes = Vec::new();
+        nbt.write(&mut bytes);
+        let borrowed = read_borrowed_compound(&mut Cursor::new(&bytes))
+            .unwrap_or_else(|error| panic!("test NBT should reborrow: {error}"));
+        let restored = ArrowEntity::new(&vanilla_entities::ARROW, 2, DVec3::ZERO, Weak::new());
+        restored.load_additional((&borrowed).into());
+        assert!((restored.base_damage() - 4.5).abs() < f64::EPSILON);
+        assert_eq!(restored.pickup(), ArrowPickup::CreativeOnly);
+        assert!(restored.is_in_ground());
+    }
+}
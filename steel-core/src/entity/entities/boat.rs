@@ -0,0 +1,666 @@
+//! Boat entity implementation.
+//!
+//! Steering is client-authoritative while a player controls the boat; the server
+//! accepts the movement through `SMoveVehicle` and only simulates buoyancy and drift
+//! when no player is in control.
+
+use std::f64::consts::SQRT_2;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use steel_macros::entity_behavior;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::entity_type::{EntityAttachment, EntityTypeRef};
+use steel_registry::items::ItemRef;
+use steel_registry::vanilla_blocks;
+use steel_registry::vanilla_entity_data::{AbstractBoatEntityData, VehicleEntityData};
+use steel_registry::vanilla_entity_type_tags::EntityTypeTag;
+use steel_registry::{REGISTRY, TaggedRegistryExt};
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::InteractionHand;
+use steel_utils::{BlockPos, WorldAabb};
+use steel_utils::{DowncastType, DowncastTypeKey};
+
+use crate::behavior::InteractionResult;
+use crate::entity::damage::DamageSource;
+use crate::entity::vehicle::{
+    block_floor_height, can_dismount_to, collision_horizontal_escape_vector, is_block_floor_valid,
+};
+use crate::entity::{
+    Entity, EntityBase, EntityBaseLoad, EntitySyncedData, SharedEntity, VehicleEntity,
+};
+use crate::fluid::{get_fluid_state, get_height, is_water_fluid};
+use crate::physics::{MoverType, WorldCollisionProvider, has_collision};
+use crate::player::Player;
+use crate::world::World;
+
+const DEFAULT_GRAVITY: f64 = 0.04;
+const MAX_PASSENGERS: usize = 2;
+const OUT_OF_CONTROL_EJECT_TICKS: f32 = 60.0;
+
+/// Vanilla `AbstractBoat.Status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BoatStatus {
+    InWater,
+    UnderWater,
+    UnderFlowingWater,
+    OnLand,
+    InAir,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BoatState {
+    status: Option<BoatStatus>,
+    old_status: Option<BoatStatus>,
+    water_level: f64,
+    land_friction: f32,
+    out_of_control_ticks: f32,
+    last_yd: f64,
+}
+
+impl BoatState {
+    const fn new() -> Self {
+        Self {
+            status: None,
+            old_status: None,
+            water_level: 0.0,
+            land_friction: 0.0,
+            out_of_control_ticks: 0.0,
+            last_yd: 0.0,
+        }
+    }
+}
+
+/// Vanilla `Boat`: a rideable vehicle for up to two passengers that floats on water.
+#[entity_behavior(class = "Boat")]
+pub struct BoatEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    #[json_arg(vanilla_items, json = "drop_item")]
+    drop_item: ItemRef,
+    entity_data: SyncMutex<AbstractBoatEntityData>,
+    state: SyncMutex<BoatState>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `BoatEntity`.
+unsafe impl DowncastType for BoatEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/boat");
+}
+
+impl BoatEntity {
+    /// Creates a new boat entity.
+    #[must_use]
+    pub fn new(
+        entity_type: EntityTypeRef,
+        id: i32,
+        position: DVec3,
+        world: Weak<World>,
+        drop_item: ItemRef,
+    ) -> Self {
+        Self {
+            base: EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+            drop_item,
+            entity_data: SyncMutex::new(AbstractBoatEntityData::new()),
+            state: SyncMutex::new(BoatState::new()),
+        }
+    }
+
+    /// Creates a boat entity from saved data.
+    #[must_use]
+    pub fn from_saved(
+        entity_type: EntityTypeRef,
+        load: EntityBaseLoad,
+        drop_item: ItemRef,
+    ) -> Self {
+        Self {
+            base: EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+            drop_item,
+            entity_data: SyncMutex::new(AbstractBoatEntityData::new()),
+            state: SyncMutex::new(BoatState::new()),
+        }
+    }
+
+    /// Sets which paddles are moving, mirroring vanilla `AbstractBoat.setPaddleState`.
+    pub fn set_paddle_state(&self, left: bool, right: bool) {
+        let mut entity_data = self.entity_data.lock();
+        let boat = entity_data.abstract_boat_mut();
+        boat.id_paddle_left.set(left);
+        boat.id_paddle_right.set(right);
+    }
+
+    /// Returns whether the given paddle (`0` = left, `1` = right) is moving.
+    #[must_use]
+    pub fn paddle_state(&self, side: usize) -> bool {
+        let entity_data = self.entity_data.lock();
+        let boat = entity_data.abstract_boat();
+        if side == 0 {
+            *boat.id_paddle_left.get()
+        } else {
+            *boat.id_paddle_right.get()
+        }
+    }
+
+    /// Mirrors vanilla `Entity.isLocalInstanceAuthoritative` on the server: a boat
+    /// steered by a player is simulated by that player's client.
+    fn is_local_instance_authoritative(&self) -> bool {
+        self.controlling_passenger()
+            .is_none_or(|controller| controller.as_player().is_none())
+    }
+
+    fn compute_status(&self, world: &Arc<World>, state: &mut BoatState) -> BoatStatus {
+        if let Some(status) = self.underwater_status(world) {
+            state.water_level = self.bounding_box().max_y();
+            return status;
+        }
+        if self.check_in_water(world, state) {
+            return BoatStatus::InWater;
+        }
+        let friction = self.ground_friction(world);
+        if friction > 0.0 {
+            state.land_friction = friction;
+            return BoatStatus::OnLand;
+        }
+        BoatStatus::InAir
+    }
+
+    /// Mirrors vanilla `AbstractBoat.getWaterLevelAbove`.
+    fn water_level_above(&self, world: &Arc<World>, last_yd: f64) -> f64 {
+        let aabb = self.bounding_box();
+        let min_x = aabb.min_x().floor() as i32;
+        let max_x = aabb.max_x().ceil() as i32;
+        let min_y = aabb.max_y().floor() as i32;
+        let max_y = (aabb.max_y() - last_yd).ceil() as i32;
+        let min_z = aabb.min_z().floor() as i32;
+        let max_z = aabb.max_z().ceil() as i32;
+
+        'layers: for y in min_y..max_y {
+            let mut height = 0.0_f32;
+            for x in min_x..max_x {
+                for z in min_z..max_z {
+                    let pos = BlockPos::new(x, y, z);
+                    let fluid_state = get_fluid_state(world, pos);
+                    if is_water_fluid(fluid_state.fluid_id) {
+                        height = height.max(get_height(world, pos, fluid_state));
+                    }
+                    if height >= 1.0 {
+                        continue 'layers;
+                    }
+                }
+            }
+            return f64::from(y) + f64::from(height);
+        }
+
+        f64::from(max_y + 1)
+    }
+
+    /// Mirrors vanilla `AbstractBoat.getGroundFriction`.
+    fn ground_friction(&self, world: &World) -> f32 {
+        let aabb = self.bounding_box();
+        let below = WorldAabb::new(
+            aabb.min_x(),
+            aabb.min_y() - 0.001,
+            aabb.min_z(),
+            aabb.max_x(),
+            aabb.min_y(),
+            aabb.max_z(),
+        );
+        let min_x = below.min_x().floor() as i32 - 1;
+        let max_x = below.max_x().ceil() as i32 + 1;
+        let min_y = below.min_y().floor() as i32 - 1;
+        let max_y = below.max_y().ceil() as i32 + 1;
+        let min_z = below.min_z().floor() as i32 - 1;
+        let max_z = below.max_z().ceil() as i32 + 1;
+
+        let mut friction = 0.0_f32;
+        let mut count = 0_u32;
+        for x in min_x..max_x {
+            for z in min_z..max_z {
+                let edges = i32::from(x == min_x || x == max_x - 1)
+                    + i32::from(z == min_z || z == max_z - 1);
+                if edges == 2 {
+                    continue;
+                }
+                for y in min_y..max_y {
+                    if edges > 0 && (y == min_y || y == max_y - 1) {
+                        continue;
+                    }
+                    let pos = BlockPos::new(x, y, z);
+                    let state = world.get_block_state(pos);
+                    if state.get_block() == &vanilla_blocks::LILY_PAD {
+                        continue;
+                    }
+                    let touches = state
+                        .get_collision_shape_at(pos)
+                        .iter()
+                        .any(|shape| shape.at_block(pos).intersects(below));
+                    if touches {
+                        friction += state.get_block().config.friction;
+                        count += 1;
+                    }
+                }
+            }
+        }
+
+        if count == 0 {
+            0.0
+        } else {
+            friction / count as f32
+        }
+    }
+
+    /// Mirrors vanilla `AbstractBoat.checkInWater`.
+    fn check_in_water(&self, world: &Arc<World>, state: &mut BoatState) -> bool {
+        let aabb = self.bounding_box();
+        let min_x = aabb.min_x().floor() as i32;
+        let max_x = aabb.max_x().ceil() as i32;
+        let min_y = aabb.min_y().floor() as i32;
+        let max_y = (aabb.min_y() + 0.001).ceil() as i32;
+        let min_z = aabb.min_z().floor() as i32;
+        let max_z = aabb.max_z().ceil() as i32;
+
+        let mut in_water = false;
+        state.water_level = f64::MIN;
+        for x in min_x..max_x {
+            for y in min_y..max_y {
+                for z in min_z..max_z {
+                    let pos = BlockPos::new(x, y, z);
+                    let fluid_state = get_fluid_state(world, pos);
+                    if is_water_fluid(fluid_state.fluid_id) {
+                        let height = f64::from(y) + f64::from(get_height(world, pos, fluid_state));
+                        state.water_level = state.water_level.max(height);
+                        in_water |= aabb.min_y() < height;
+                    }
+                }
+            }
+        }
+        in_water
+    }
+
+    /// Mirrors vanilla `AbstractBoat.isUnderwater`.
+    fn underwater_status(&self, world: &Arc<World>) -> Option<BoatStatus> {
+        let aabb = self.bounding_box();
+        let top = aabb.max_y() + 0.001;
+        let min_x = aabb.min_x().floor() as i32;
+        let max_x = aabb.max_x().ceil() as i32;
+        let min_y = aabb.max_y().floor() as i32;
+        let max_y = top.ceil() as i32;
+        let min_z = aabb.min_z().floor() as i32;
+        let max_z = aabb.max_z().ceil() as i32;
+
+        let mut under_water = false;
+        for x in min_x..max_x {
+            for y in min_y..max_y {
+                for z in min_z..max_z {
+                    let pos = BlockPos::new(x, y, z);
+                    let fluid_state = get_fluid_state(world, pos);
+                    if is_water_fluid(fluid_state.fluid_id)
+                        && top < f64::from(y) + f64::from(get_height(world, pos, fluid_state))
+                    {
+                        if !fluid_state.is_source() {
+                            return Some(BoatStatus::UnderFlowingWater);
+                        }
+                        under_water = true;
+                    }
+                }
+            }
+        }
+        under_water.then_some(BoatStatus::UnderWater)
+    }
+
+    /// Mirrors vanilla `AbstractBoat.floatBoat`.
+    fn float_boat(&self, world: &Arc<World>) {
+        let mut state = self.state.lock();
+        let Some(status) = state.status else {
+            return;
+        };
+        let height = self.bounding_box().height();
+
+        if state.old_status == Some(BoatStatus::InAir)
+            && !matches!(status, BoatStatus::InAir | BoatStatus::OnLand)
+        {
+            let position = self.position();
+            state.water_level = position.y + height;
+            let target_y = self.water_level_above(world, state.last_yd) - height + 0.101;
+            let moved = self
+                .bounding_box()
+                .translate(DVec3::new(0.0, target_y - position.y, 0.0));
+            if !has_collision(&WorldCollisionProvider::for_entity(world, self), moved)
+                && self
+                    .try_set_position(DVec3::new(position.x, target_y, position.z))
+                    .is_ok()
+            {
+                let velocity = self.velocity();
+                self.set_velocity(DVec3::new(velocity.x, 0.0, velocity.z));
+                state.last_yd = 0.0;
+            }
+            state.status = Some(BoatStatus::InWater);
+            return;
+        }
+
+        let mut gravity = -self.get_gravity();
+        let mut buoyancy = 0.0;
+        let inv_friction = match status {
+            BoatStatus::InWater => {
+                buoyancy = (state.water_level - self.position().y) / height;
+                0.9
+            }
+            BoatStatus::UnderFlowingWater => {
+                gravity = -7.0E-4;
+                0.9
+            }
+            BoatStatus::UnderWater => {
+                buoyancy = 0.01;
+                0.45
+            }
+            BoatStatus::InAir => 0.9,
+            BoatStatus::OnLand => {
+                let friction = state.land_friction;
+                if self
+                    .controlling_passenger()
+                    .is_some_and(|controller| controller.as_player().is_some())
+                {
+                    state.land_friction /= 2.0;
+                }
+                friction
+            }
+        };
+        drop(state);
+
+        let inv_friction = f64::from(inv_friction);
+        let velocity = self.velocity();
+        let mut velocity = DVec3::new(
+            velocity.x * inv_friction,
+            velocity.y + gravity,
+            velocity.z * inv_friction,
+        );
+        if buoyancy > 0.0 {
+            velocity.y = (velocity.y + buoyancy * (DEFAULT_GRAVITY / 0.65)) * 0.75;
+        }
+        self.set_velocity(velocity);
+    }
+
+    /// Mirrors the entity-pushing tail of vanilla `AbstractBoat.tick`, including
+    /// picking up small mobs that bump into an uncontrolled boat.
+    fn push_nearby_entities(&self, world: &World) {
+        let aabb = self.bounding_box().inflate_xyz(0.2, -0.01, 0.2);
+        let entities = world.get_pushable_entities(self, &aabb);
+        if entities.is_empty() {
+            return;
+        }
+
+        let can_pick_up = self
+            .controlling_passenger()
+            .is_none_or(|controller| controller.as_player().is_none());
+        let Some(boat) = world.get_entity_by_id(self.id()) else {
+            return;
+        };
+        for entity in entities {
+            if entity.has_passenger(self) {
+                continue;
+            }
+            if can_pick_up
+                && self.passengers().len() < MAX_PASSENGERS
+                && !entity.is_passenger()
+                && entity.bounding_box().width() < self.bounding_box().width()
+                && entity.is_living_entity()
+                && !REGISTRY.entity_types.is_in_tag(
+                    entity.entity_type(),
+                    &EntityTypeTag::CANNOT_BE_PUSHED_ONTO_BOATS,
+                )
+            {
+                entity.start_riding(&boat);
+            } else {
+                self.push_entity(entity.as_ref());
+            }
+        }
+    }
+}
+
+impl VehicleEntity for BoatEntity {
+    fn with_vehicle_data<R>(&self, f: impl FnOnce(&mut VehicleEntityData) -> R) -> R {
+        f(self.entity_data.lock().vehicle_entity_mut())
+    }
+
+    fn drop_item(&self) -> ItemRef {
+        self.drop_item
+    }
+}
+
+impl Entity for BoatEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn tick(&self) {
+        let Some(world) = self.level() else {
+            return;
+        };
+
+        let out_of_control = {
+            let mut state = self.state.lock();
+            state.old_status = state.status;
+            let status = self.compute_status(&world, &mut state);
+            state.status = Some(status);
+            if matches!(
+                status,
+                BoatStatus::UnderWater | BoatStatus::UnderFlowingWater
+            ) {
+                state.out_of_control_ticks += 1.0;
+            } else {
+                state.out_of_control_ticks = 0.0;
+            }
+            state.out_of_control_ticks >= OUT_OF_CONTROL_EJECT_TICKS
+        };
+        if out_of_control {
+            self.eject_passengers();
+        }
+
+        self.tick_vehicle_damage();
+        self.default_tick();
+
+        if self.is_local_instance_authoritative() {
+            if self
+                .first_passenger()
+                .is_none_or(|passenger| passenger.as_player().is_none())
+            {
+                self.set_paddle_state(false, false);
+            }
+            self.float_boat(&world);
+            self.move_entity(MoverType::SelfMovement, self.velocity());
+            self.state.lock().last_yd = self.velocity().y;
+        } else {
+            self.set_velocity(DVec3::ZERO);
+        }
+
+        // TODO: Tick bubble column rocking once bubble column entity effects are shared.
+        self.apply_effects_from_blocks();
+        self.push_nearby_entities(&world);
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        self.hurt_vehicle(world, source, amount)
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        let result = self.interact_entity(player, hand, location);
+        if result != InteractionResult::Pass {
+            return result;
+        }
+        if player.is_secondary_use_active()
+            || self.state.lock().out_of_control_ticks >= OUT_OF_CONTROL_EJECT_TICKS
+        {
+            return InteractionResult::Pass;
+        }
+
+        let Some(world) = self.level() else {
+            return InteractionResult::Pass;
+        };
+        let Some(boat) = world.get_entity_by_id(self.id()) else {
+            return InteractionResult::Pass;
+        };
+        if player.start_riding(&boat) {
+            InteractionResult::Success
+        } else {
+            InteractionResult::Pass
+        }
+    }
+
+    fn controlling_passenger(&self) -> Option<SharedEntity> {
+        self.first_passenger()
+            .filter(|passenger| passenger.is_living_entity())
+    }
+
+    fn can_add_passenger(&self, _passenger: &dyn Entity) -> bool {
+        self.passengers().len() < MAX_PASSENGERS && !self.is_eye_in_water()
+    }
+
+    fn passenger_attachment_point(&self, passenger: &dyn Entity) -> DVec3 {
+        let dimensions = self.base().dimensions();
+        let attachment_y = dimensions
+            .attachments
+            .get_clamped(EntityAttachment::Passenger, 0, 0.0, dimensions)
+            .y;
+        let passengers = self.passengers();
+        let mut z = 0.0_f32;
+        if passengers.len() > 1 {
+            let index = self.passenger_index(passenger).unwrap_or_default();
+            z = if index == 0 { 0.2 } else { -0.6 };
+            if passenger.is_animal() {
+                z += 0.2;
+            }
+        }
+        rotate_y(
+            DVec3::new(0.0, attachment_y, f64::from(z)),
+            -self.rotation().0.to_radians(),
+        )
+    }
+
+    fn dismount_location_for_passenger(&self, passenger: &dyn Entity) -> DVec3 {
+        let width = f64::from(self.base().dimensions().width) * SQRT_2;
+        let passenger_width = f64::from(passenger.base().dimensions().width);
+        let escape =
+            collision_horizontal_escape_vector(width, passenger_width, passenger.rotation().0);
+        let position = self.position();
+        let x = position.x + escape.x;
+        let z = position.z + escape.z;
+        let pos = BlockPos::containing(x, self.bounding_box().max_y(), z);
+        let below = pos.offset(0, -1, 0);
+
+        if let Some(world) = self.level() {
+            let below_fluid = get_fluid_state(&world, below);
+            if !is_water_fluid(below_fluid.fluid_id) {
+                let candidates = [pos, below].into_iter().filter_map(|candidate| {
+                    let floor = block_floor_height(&world, candidate);
+                    is_block_floor_valid(floor)
+                        .then(|| DVec3::new(x, f64::from(candidate.y()) + floor, z))
+                });
+                for candidate in candidates {
+                    if can_dismount_to(&world, passenger, candidate) {
+                        return candidate;
+                    }
+                }
+            }
+        }
+
+        DVec3::new(position.x, self.bounding_box().max_y(), position.z)
+    }
+
+    fn is_pickable(&self) -> bool {
+        !self.is_removed()
+    }
+
+    fn is_pushable(&self) -> bool {
+        true
+    }
+
+    fn can_be_collided_with(&self, _other: Option<&dyn Entity>) -> bool {
+        true
+    }
+
+    fn can_collide_with(&self, other: &dyn Entity) -> bool {
+        (other.can_be_collided_with(Some(self)) || other.is_pushable())
+            && !self.is_passenger_of_same_vehicle(other)
+    }
+
+    fn blocks_building(&self) -> bool {
+        true
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        DEFAULT_GRAVITY
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+}
+
+fn rotate_y(vector: DVec3, radians: f32) -> DVec3 {
+    let cos = f64::from(radians.cos());
+    let sin = f64::from(radians.sin());
+    DVec3::new(
+        vector.x * cos + vector.z * sin,
+        vector.y,
+        vector.z * cos - vector.x * sin,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use steel_registry::{vanilla_entities, vanilla_items};
+
+    fn boat() -> BoatEntity {
+        BoatEntity::new(
+            &vanilla_entities::OAK_BOAT,
+            1,
+            DVec3::new(0.5, 64.0, 0.5),
+            Weak::new(),
+            &vanilla_items::OAK_BOAT,
+        )
+    }
+
+    #[test]
+    fn boat_paddle_state_round_trips() {
+        let boat = boat();
+        boat.set_paddle_state(true, false);
+
+        assert!(boat.paddle_state(0));
+        assert!(!boat.paddle_state(1));
+    }
+
+    #[test]
+    fn boat_damage_decays_each_tick() {
+        let boat = boat();
+        boat.with_vehicle_data(|data| {
+            data.id_hurt.set(2);
+            data.id_damage.set(1.5);
+        });
+
+        boat.tick_vehicle_damage();
+
+        assert_eq!(boat.hurt_time(), 1);
+        assert!((boat.damage() - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn boat_is_solid_for_other_entities() {
+        let boat = boat();
+
+        assert!(boat.can_be_collided_with(None));
+        assert!(boat.is_pickable());
+        assert!((boat.get_default_gravity() - DEFAULT_GRAVITY).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,848 @@
+//! Chicken entity implementation.
+
+use std::str::FromStr;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::NbtCompound;
+use steel_macros::{entity_behavior, entity_impl};
+use steel_protocol::packets::game::{AttributeSnapshot, EquipmentSlotItem, SoundSource};
+use steel_registry::chicken_sound_variant::{ChickenAge, ChickenSoundVariantRef};
+use steel_registry::chicken_variant::ChickenVariantRef;
+use steel_registry::entity_type::{EntityDimensions, EntityTypeRef};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::loot_table::LootContext;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_entity_data::ChickenEntityData;
+use steel_registry::vanilla_item_tags::ItemTag;
+use steel_registry::{
+    REGISTRY, RegistryExt, TaggedRegistryExt, sound_events, vanilla_attributes,
+    vanilla_chicken_sound_variants, vanilla_chicken_variants, vanilla_game_events,
+};
+use steel_utils::locks::SyncMutex;
+use steel_utils::random::legacy_random::LegacyRandom;
+use steel_utils::types::InteractionHand;
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey, Identifier};
+
+use crate::behavior::InteractionResult;
+use crate::entity::ai::goal::{
+    BreedGoal, FloatGoal, FollowParentGoal, LookAtPlayerGoal, PanicGoal, RandomLookAroundGoal,
+    TemptGoal, WaterAvoidingRandomStrollGoal,
+};
+use crate::entity::ai::path::PathType;
+use crate::entity::damage::DamageSource;
+use crate::entity::{
+    AgeableMob, AgeableMobBase, Animal, AnimalBase, Entity, EntityBase, EntityBaseLoad, EntityPose,
+    EntitySpawnReason, EntitySyncedData, LivingEntity, LivingEntityBase, Mob, MobBase,
+    MobEffectSyncChange, PathfinderMob, SpawnGroupData, entity_loot_ref,
+};
+use crate::physics::MoveResult;
+use crate::player::Player;
+use crate::world::World;
+
+const CHICKEN_BABY_DIMENSIONS: EntityDimensions = EntityDimensions::new(0.3, 0.4, 0.28125);
+const EGG_LAY_MIN_TICKS: i32 = 6000;
+const EGG_LAY_RANDOM_TICKS: i32 = 6000;
+const FALL_SLOWDOWN_FACTOR: f64 = 0.6;
+const CHICKEN_JOCKEY_EXPERIENCE_REWARD: i32 = 10;
+
+fn random_egg_time() -> i32 {
+    EGG_LAY_MIN_TICKS + rand::random_range(0..EGG_LAY_RANDOM_TICKS)
+}
+
+struct ChickenState {
+    egg_time: i32,
+    is_chicken_jockey: bool,
+}
+
+/// Vanilla chicken entity.
+#[entity_behavior(class = "Chicken")]
+pub struct ChickenEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    living_base: LivingEntityBase,
+    mob_base: MobBase,
+    ageable_base: AgeableMobBase,
+    animal_base: AnimalBase,
+    entity_data: SyncMutex<ChickenEntityData>,
+    state: SyncMutex<ChickenState>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `ChickenEntity`.
+unsafe impl DowncastType for ChickenEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/chicken");
+}
+
+impl ChickenEntity {
+    /// Creates a new chicken entity.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_with_base(
+            EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+        )
+    }
+
+    /// Creates a chicken entity from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self::new_with_base(
+            EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+        )
+    }
+
+    fn new_with_base(base: EntityBase, entity_type: EntityTypeRef) -> Self {
+        let living_base = LivingEntityBase::new(entity_type);
+        let mob_base = MobBase::new();
+        let ageable_base = AgeableMobBase::new();
+        let animal_base = AnimalBase::new();
+        AnimalBase::initialize_pathfinding_malus(&mob_base);
+        mob_base
+            .pathfinding_malus()
+            .lock()
+            .set(PathType::Water, 0.0);
+        let mut entity_data = ChickenEntityData::new();
+        living_base.initialize_synced_data(&mut entity_data);
+        {
+            let mut goal_selector = mob_base.goal_selector().lock();
+            goal_selector.add_goal(0, FloatGoal::new(&mob_base));
+            goal_selector.add_goal(1, PanicGoal::new(1.4));
+            goal_selector.add_goal(2, BreedGoal::new(1.0));
+            goal_selector.add_goal(3, TemptGoal::new(1.0, ChickenEntity::is_food, false));
+            goal_selector.add_goal(4, FollowParentGoal::new(1.1));
+            goal_selector.add_goal(5, WaterAvoidingRandomStrollGoal::new(1.0));
+            goal_selector.add_goal(6, LookAtPlayerGoal::new(6.0));
+            goal_selector.add_goal(7, RandomLookAroundGoal::new());
+        }
+
+        Self {
+            base,
+            entity_type,
+            living_base,
+            mob_base,
+            ageable_base,
+            animal_base,
+            entity_data: SyncMutex::new(entity_data),
+            state: SyncMutex::new(ChickenState {
+                egg_time: random_egg_time(),
+                is_chicken_jockey: false,
+            }),
+        }
+    }
+
+    /// Returns whether this chicken is a baby.
+    #[must_use]
+    pub fn is_baby(&self) -> bool {
+        AgeableMob::is_baby(self)
+    }
+
+    /// Sets the vanilla baby state using the `AgeableMob` start age.
+    pub fn set_baby(&self, baby: bool) {
+        AgeableMob::set_baby(self, baby);
+    }
+
+    /// Returns the ticks left until this chicken lays its next egg.
+    #[must_use]
+    pub fn egg_time(&self) -> i32 {
+        self.state.lock().egg_time
+    }
+
+    /// Sets the ticks left until this chicken lays its next egg.
+    pub fn set_egg_time(&self, egg_time: i32) {
+        self.state.lock().egg_time = egg_time;
+    }
+
+    /// Returns vanilla `Chicken.isChickenJockey`.
+    #[must_use]
+    pub fn is_chicken_jockey(&self) -> bool {
+        self.state.lock().is_chicken_jockey
+    }
+
+    /// Sets vanilla `Chicken.isChickenJockey`.
+    pub fn set_chicken_jockey(&self, is_chicken_jockey: bool) {
+        self.state.lock().is_chicken_jockey = is_chicken_jockey;
+    }
+
+    /// Returns the current chicken variant registry ID stored in synced data.
+    #[must_use]
+    pub fn variant_id(&self) -> i32 {
+        *self.entity_data.lock().variant.get()
+    }
+
+    /// Sets the current chicken variant by registry entry.
+    pub fn set_variant(&self, variant: ChickenVariantRef) {
+        if !self.set_variant_by_key(&variant.key) {
+            log::error!("chicken variant {} is not registered", variant.key);
+        }
+    }
+
+    /// Returns the current chicken variant, falling back to vanilla's default holder.
+    #[must_use]
+    pub fn variant(&self) -> ChickenVariantRef {
+        let id = self.variant_id();
+        if let Ok(id) = usize::try_from(id)
+            && let Some(variant) = REGISTRY.chicken_variants.by_id(id)
+        {
+            return variant;
+        }
+
+        &vanilla_chicken_variants::TEMPERATE
+    }
+
+    /// Returns the current chicken sound variant registry ID stored in synced data.
+    #[must_use]
+    pub fn sound_variant_id(&self) -> i32 {
+        *self.entity_data.lock().sound_variant.get()
+    }
+
+    /// Sets the current chicken sound variant by registry entry.
+    pub fn set_sound_variant(&self, sound_variant: ChickenSoundVariantRef) {
+        if !self.set_sound_variant_by_key(&sound_variant.key) {
+            log::error!(
+                "chicken sound variant {} is not registered",
+                sound_variant.key
+            );
+        }
+    }
+
+    /// Returns the current chicken sound variant, falling back to vanilla classic.
+    #[must_use]
+    pub fn sound_variant(&self) -> ChickenSoundVariantRef {
+        let id = self.sound_variant_id();
+        if let Ok(id) = usize::try_from(id)
+            && let Some(sound_variant) = REGISTRY.chicken_sound_variants.by_id(id)
+        {
+            return sound_variant;
+        }
+
+        &vanilla_chicken_sound_variants::CLASSIC
+    }
+
+    fn set_variant_by_key(&self, key: &Identifier) -> bool {
+        let Some(id) = REGISTRY.chicken_variants.id_from_key(key) else {
+            return false;
+        };
+        let Ok(id) = i32::try_from(id) else {
+            log::error!("chicken variant id {id} does not fit synced-data i32");
+            return false;
+        };
+        self.entity_data.lock().variant.set(id);
+        true
+    }
+
+    fn set_sound_variant_by_key(&self, key: &Identifier) -> bool {
+        let Some(id) = REGISTRY.chicken_sound_variants.id_from_key(key) else {
+            return false;
+        };
+        let Ok(id) = i32::try_from(id) else {
+            log::error!("chicken sound variant id {id} does not fit synced-data i32");
+            return false;
+        };
+        self.entity_data.lock().sound_variant.set(id);
+        true
+    }
+
+    fn current_sound_set(&self) -> &'static ChickenAge {
+        let sound_variant = self.sound_variant();
+        if self.is_baby() {
+            &sound_variant.baby_sounds
+        } else {
+            &sound_variant.adult_sounds
+        }
+    }
+
+    /// Runs the server-side egg timer from vanilla `Chicken.aiStep`.
+    fn tick_egg_laying(&self) {
+        if !Entity::is_alive(self) || self.is_baby() || self.is_chicken_jockey() {
+            return;
+        }
+        {
+            let mut state = self.state.lock();
+            state.egg_time -= 1;
+            if state.egg_time > 0 {
+                return;
+            }
+        }
+
+        if self.lay_egg() {
+            let pitch = (rand::random::<f32>() - rand::random::<f32>()) * 0.2 + 1.0;
+            self.play_sound(&sound_events::ENTITY_CHICKEN_EGG, 1.0, pitch);
+            self.game_event(&vanilla_game_events::ENTITY_PLACE);
+        }
+        self.set_egg_time(random_egg_time());
+    }
+
+    /// Drops items from the `gameplay/chicken_lay` gift loot table.
+    fn lay_egg(&self) -> bool {
+        let Some(world) = self.level() else {
+            return false;
+        };
+        let Some(loot_table) = REGISTRY
+            .loot_tables
+            .by_key(&Identifier::vanilla_static("gameplay/chicken_lay"))
+        else {
+            return false;
+        };
+
+        let position = self.position();
+        let drops = {
+            let mut rng = rand::rng();
+            let mut ctx = LootContext::new(&mut rng)
+                .with_origin(position.x, position.y, position.z)
+                .with_game_time(world.game_time())
+                .with_this_entity(entity_loot_ref(self));
+            loot_table.get_random_items(&mut ctx)
+        };
+
+        let mut dropped = false;
+        for item_stack in drops {
+            dropped |= self.spawn_at_location(item_stack, 0.0).is_some();
+        }
+        dropped
+    }
+
+    fn update_dirty_mob_effect_entity_data(&self) {
+        if !self.living_base.take_effects_dirty() {
+            return;
+        }
+
+        let display = self.living_base.mob_effect_display_state();
+
+        {
+            let mut entity_data = self.entity_data.lock();
+            let living = entity_data.living_entity_mut();
+            living.effect_particles.set(display.particles);
+            living.effect_ambience.set(display.ambient);
+        }
+
+        self.entity_data.set_base_invisible_flag(display.invisible);
+        self.entity_data
+            .set_base_glowing_flag(self.has_glowing_tag() || display.glowing);
+    }
+
+    /// Returns whether the stack is vanilla chicken food.
+    #[must_use]
+    pub fn is_food(item_stack: &ItemStack) -> bool {
+        REGISTRY
+            .items
+            .is_in_tag(item_stack.item(), &ItemTag::CHICKEN_FOOD)
+    }
+}
+
+#[entity_impl(class(animal))]
+impl Entity for ChickenEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn dimensions_for_pose(&self, _pose: EntityPose) -> EntityDimensions {
+        let scale = LivingEntity::get_scale(self);
+        if self.is_baby() {
+            CHICKEN_BABY_DIMENSIONS.scale(scale)
+        } else if self.entity_type.fixed {
+            self.entity_type.dimensions
+        } else {
+            self.entity_type.dimensions.scale(scale)
+        }
+    }
+
+    fn tick(&self) {
+        self.default_tick();
+        self.living_base.decrement_invulnerable_time();
+        self.tick_mob_effects();
+
+        if self.is_dead_or_dying() {
+            LivingEntity::tick_death(self);
+            self.tick_living_state();
+            return;
+        }
+
+        if !self.is_removed() {
+            self.ai_step();
+        }
+
+        self.tick_living_state();
+    }
+
+    fn check_despawn(&self) {
+        Mob::check_mob_despawn(self);
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.is_removed() && self.get_health() > 0.0
+    }
+
+    fn is_pickable(&self) -> bool {
+        !self.is_removed()
+    }
+
+    fn is_pushable(&self) -> bool {
+        Entity::is_alive(self) && !self.is_spectator() && !self.on_climbable()
+    }
+
+    fn is_effective_ai(&self) -> bool {
+        self.is_server_driven_movement() && !self.is_no_ai()
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        LivingEntity::get_attribute_gravity(self)
+    }
+
+    fn can_freeze(&self) -> bool {
+        self.default_living_can_freeze()
+    }
+
+    fn can_walk_on_powder_snow(&self) -> bool {
+        self.default_living_can_walk_on_powder_snow()
+    }
+
+    fn cause_fall_damage(
+        &self,
+        _fall_distance: f64,
+        _damage_modifier: f32,
+        _source: &DamageSource,
+    ) -> bool {
+        false
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn update_data_before_sync(&self) {
+        self.update_dirty_mob_effect_entity_data();
+    }
+
+    fn pack_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().syncable_snapshots()
+    }
+
+    fn drain_dirty_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().drain_dirty_sync()
+    }
+
+    fn drain_dirty_mob_effects(&self) -> Vec<MobEffectSyncChange> {
+        self.living_base.drain_dirty_mob_effects()
+    }
+
+    fn pack_all_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.pack_living_equipment()
+    }
+
+    fn drain_dirty_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.drain_dirty_living_equipment()
+    }
+
+    fn max_up_step(&self) -> f32 {
+        self.attributes()
+            .lock()
+            .get_value(vanilla_attributes::STEP_HEIGHT)
+            .unwrap_or(0.6) as f32
+    }
+
+    fn sound_source(&self) -> SoundSource {
+        SoundSource::Neutral
+    }
+
+    fn play_step_sound(&self, _pos: BlockPos, _block_state: BlockStateId) {
+        self.play_sound(self.current_sound_set().step_sound, 0.15, 1.0);
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        LivingEntity::hurt_server(self, world, source, amount)
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        Mob::interact_mob(self, player, hand, location)
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        self.save_mob(nbt);
+        self.save_ageable_mob(nbt);
+        self.save_animal(nbt);
+        nbt.insert("IsChickenJockey", i8::from(self.is_chicken_jockey()));
+        nbt.insert("EggLayTime", self.egg_time());
+        nbt.insert("variant", self.variant().key.to_string());
+        nbt.insert("sound_variant", self.sound_variant().key.to_string());
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        self.load_mob(nbt);
+        self.load_ageable_mob(nbt);
+        self.load_animal(nbt);
+
+        self.set_chicken_jockey(
+            nbt.byte("IsChickenJockey")
+                .is_some_and(|is_chicken_jockey| is_chicken_jockey != 0),
+        );
+        if let Some(egg_time) = nbt.int("EggLayTime") {
+            self.set_egg_time(egg_time);
+        }
+        if let Some(variant) = nbt.string("variant")
+            && let Ok(key) = Identifier::from_str(variant.to_str().as_ref())
+        {
+            self.set_variant_by_key(&key);
+        }
+        if let Some(sound_variant) = nbt.string("sound_variant")
+            && let Ok(key) = Identifier::from_str(sound_variant.to_str().as_ref())
+        {
+            self.set_sound_variant_by_key(&key);
+        }
+    }
+}
+
+impl LivingEntity for ChickenEntity {
+    fn living_base(&self) -> &LivingEntityBase {
+        &self.living_base
+    }
+
+    fn get_health(&self) -> f32 {
+        *self.entity_data.lock().living_entity().health.get()
+    }
+
+    fn set_health(&self, health: f32) {
+        let max_health = self.get_max_health();
+        let clamped = health.clamp(0.0, max_health);
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .health
+            .set(clamped);
+    }
+
+    fn is_baby(&self) -> bool {
+        AgeableMob::is_baby(self)
+    }
+
+    fn hurt_sound(&self, _source: &DamageSource) -> Option<SoundEventRef> {
+        Some(self.current_sound_set().hurt_sound)
+    }
+
+    fn death_sound(&self) -> Option<SoundEventRef> {
+        Some(self.current_sound_set().death_sound)
+    }
+
+    fn base_experience_reward(&self) -> i32 {
+        if self.is_chicken_jockey() {
+            return CHICKEN_JOCKEY_EXPERIENCE_REWARD;
+        }
+
+        self.base_experience_reward_animal()
+    }
+
+    fn server_ai_step(&self) {
+        Mob::mob_server_ai_step(self);
+    }
+
+    fn before_actually_hurt(&self, _source: &DamageSource, _amount: f32) {
+        Animal::reset_love(self);
+    }
+
+    fn ai_step(&self) -> Option<MoveResult> {
+        let result = self.default_ai_step();
+
+        AgeableMob::tick_ageable_mob(self);
+        Animal::tick_animal_love(self);
+
+        let velocity = self.velocity();
+        if !self.on_ground() && velocity.y < 0.0 {
+            self.set_velocity(DVec3::new(
+                velocity.x,
+                velocity.y * FALL_SLOWDOWN_FACTOR,
+                velocity.z,
+            ));
+        }
+
+        self.tick_egg_laying();
+        result
+    }
+}
+
+impl AgeableMob for ChickenEntity {
+    fn ageable_base(&self) -> &AgeableMobBase {
+        &self.ageable_base
+    }
+
+    fn is_age_locked(&self) -> bool {
+        *self.entity_data.lock().ageable_mob().age_locked.get()
+    }
+
+    fn set_age_locked(&self, age_locked: bool) {
+        self.entity_data
+            .lock()
+            .ageable_mob_mut()
+            .age_locked
+            .set(age_locked);
+    }
+
+    fn set_synced_baby(&self, baby: bool) {
+        self.entity_data.lock().ageable_mob_mut().baby.set(baby);
+    }
+
+    fn age_boundary_changed(&self, _baby: bool) {
+        self.refresh_dimensions();
+    }
+}
+
+impl Animal for ChickenEntity {
+    fn animal_base(&self) -> &AnimalBase {
+        &self.animal_base
+    }
+
+    fn is_food(&self, item_stack: &ItemStack) -> bool {
+        ChickenEntity::is_food(item_stack)
+    }
+
+    fn breed_variant_key(&self) -> Option<&Identifier> {
+        Some(&self.variant().key)
+    }
+
+    fn set_breed_variant_key(&self, key: &Identifier) -> bool {
+        self.set_variant_by_key(key)
+    }
+
+    fn initialize_breed_offspring(&self, partner: &dyn Animal, offspring: &dyn Animal) {
+        let variant_key = if rand::random::<bool>() {
+            self.breed_variant_key()
+        } else {
+            partner.breed_variant_key()
+        };
+        let Some(variant_key) = variant_key else {
+            return;
+        };
+
+        if !offspring.set_breed_variant_key(variant_key) {
+            log::error!("chicken offspring could not inherit breeding variant {variant_key}");
+        }
+    }
+}
+
+impl Mob for ChickenEntity {
+    fn mob_base(&self) -> &MobBase {
+        &self.mob_base
+    }
+
+    fn tick_goal_selectors(&self) {
+        PathfinderMob::tick_pathfinder_goal_selectors(self);
+    }
+
+    fn tick_path_navigation(&self) {
+        PathfinderMob::tick_pathfinder_path_navigation(self);
+    }
+
+    fn custom_server_ai_step(&self) {
+        Animal::custom_server_ai_step_animal(self);
+    }
+
+    fn ambient_sound(&self) -> Option<SoundEventRef> {
+        Some(self.current_sound_set().ambient_sound)
+    }
+
+    fn remove_when_far_away(&self, _dist_sqr: f64) -> bool {
+        self.is_chicken_jockey()
+    }
+
+    fn finalize_spawn(
+        &self,
+        world: &Arc<World>,
+        spawn_reason: EntitySpawnReason,
+        group_data: Option<SpawnGroupData>,
+    ) -> Option<SpawnGroupData> {
+        let biome = world.biome_at(self.block_position());
+        let (variant, sound_variant) = {
+            let mut random = LegacyRandom::from_seed(rand::random());
+            let variant = biome.and_then(|biome| {
+                REGISTRY
+                    .chicken_variants
+                    .select_spawn_variant(biome, &mut random)
+            });
+            let sound_variant = REGISTRY.chicken_sound_variants.pick_random(&mut random);
+            (variant, sound_variant)
+        };
+
+        if let Some(variant) = variant {
+            self.set_variant(variant);
+        }
+
+        if let Some(sound_variant) = sound_variant {
+            self.set_sound_variant(sound_variant);
+        }
+
+        self.finalize_spawn_ageable_mob(world, spawn_reason, group_data)
+    }
+
+    fn mob_interact(&self, player: &Player, hand: InteractionHand) -> InteractionResult {
+        Animal::mob_interact_animal(self, player, hand)
+    }
+
+    fn mob_flags(&self) -> i8 {
+        *self.entity_data.lock().mob().mob_flags.get()
+    }
+
+    fn set_mob_flags(&self, flags: i8) {
+        self.entity_data.lock().mob_mut().mob_flags.set(flags);
+    }
+}
+
+impl PathfinderMob for ChickenEntity {}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::string::ToString;
+
+    use simdnbt::borrow::read_compound as read_borrowed_compound;
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::{vanilla_damage_types, vanilla_entities, vanilla_items};
+
+    use super::*;
+
+    fn chicken() -> ChickenEntity {
+        ChickenEntity::new(&vanilla_entities::CHICKEN, 1, DVec3::ZERO, Weak::new())
+    }
+
+    #[test]
+    fn chicken_initializes_vanilla_living_attributes_and_health() {
+        init_test_registry();
+
+        let chicken = chicken();
+
+        assert_eq!(chicken.get_health().to_bits(), 4.0_f32.to_bits());
+        assert!(
+            (EGG_LAY_MIN_TICKS..EGG_LAY_MIN_TICKS + EGG_LAY_RANDOM_TICKS)
+                .contains(&chicken.egg_time())
+        );
+        assert_eq!(
+            chicken.get_pathfinding_malus(PathType::Water).to_bits(),
+            0.0_f32.to_bits()
+        );
+    }
+
+    #[test]
+    fn chicken_registers_vanilla_passive_goals() {
+        init_test_registry();
+
+        let chicken = chicken();
+
+        let selector = chicken.mob_base().goal_selector().lock();
+        assert_eq!(
+            selector.available_goal_priorities(),
+            vec![0, 1, 2, 3, 4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn chicken_ignores_fall_damage() {
+        init_test_registry();
+
+        let chicken = chicken();
+        let source = DamageSource::environment(&vanilla_damage_types::FALL);
+
+        assert!(!chicken.cause_fall_damage(20.0, 1.0, &source));
+        assert_eq!(chicken.get_health().to_bits(), 4.0_f32.to_bits());
+    }
+
+    #[test]
+    fn chicken_sounds_follow_baby_sound_set() {
+        init_test_registry();
+
+        let chicken = chicken();
+        assert_eq!(
+            Mob::ambient_sound(&chicken).map(|sound| &sound.key),
+            Some(&sound_events::ENTITY_CHICKEN_AMBIENT.key)
+        );
+
+        chicken.set_baby(true);
+        assert_eq!(
+            Mob::ambient_sound(&chicken).map(|sound| &sound.key),
+            Some(&sound_events::ENTITY_BABY_CHICKEN_AMBIENT.key)
+        );
+    }
+
+    #[test]
+    fn chicken_jockey_despawns_and_rewards_vanilla_experience() {
+        init_test_registry();
+
+        let chicken = chicken();
+        assert!(!chicken.remove_when_far_away(f64::MAX));
+
+        chicken.set_chicken_jockey(true);
+
+        assert!(chicken.remove_when_far_away(f64::MAX));
+        assert_eq!(
+            LivingEntity::base_experience_reward(&chicken),
+            CHICKEN_JOCKEY_EXPERIENCE_REWARD
+        );
+    }
+
+    #[test]
+    fn chicken_egg_timer_only_runs_for_adults() {
+        init_test_registry();
+
+        let chicken = chicken();
+        chicken.set_egg_time(100);
+        chicken.set_baby(true);
+        chicken.tick_egg_laying();
+        assert_eq!(chicken.egg_time(), 100);
+
+        chicken.set_baby(false);
+        chicken.tick_egg_laying();
+        assert_eq!(chicken.egg_time(), 99);
+    }
+
+    #[test]
+    fn chicken_uses_vanilla_chicken_food_tag() {
+        init_test_registry();
+
+        assert!(ChickenEntity::is_food(&ItemStack::new(
+            &vanilla_items::WHEAT_SEEDS
+        )));
+        assert!(!ChickenEntity::is_food(&ItemStack::new(
+            &vanilla_items::WHEAT
+        )));
+    }
+
+    #[test]
+    fn chicken_round_trips_vanilla_save_data() {
+        init_test_registry();
+
+        let chicken = chicken();
+        chicken.set_chicken_jockey(true);
+        chicken.set_egg_time(1234);
+        chicken.set_sound_variant(&vanilla_chicken_sound_variants::PICKY);
+
+        let mut nbt = NbtCompound::new();
+        chicken.save_additional(&mut nbt);
+        assert_eq!(nbt.byte("IsChickenJockey"), Some(1));
+        assert_eq!(nbt.int("EggLayTime"), Some(1234));
+        assert_eq!(
+            nbt.string("sound_variant").map(ToString::to_string),
+            Some("minecraft:picky".to_owned())
+        );
+
+        let mut bytes = Vec::new();
+        nbt.write(&mut bytes);
+        let borrowed = read_borrowed_compound(&mut Cursor::new(&bytes))
+            .unwrap_or_else(|error| panic!("test nbt should reborrow: {error}"));
+        let loaded = ChickenEntity::new(&vanilla_entities::CHICKEN, 2, DVec3::ZERO, Weak::new());
+        loaded.load_additional((&borrowed).into());
+
+        assert!(loaded.is_chicken_jockey());
+        assert_eq!(loaded.egg_time(), 1234);
+        assert_eq!(
+            loaded.sound_variant().key,
+            vanilla_chicken_sound_variants::PICKY.key
+        );
+    }
+}
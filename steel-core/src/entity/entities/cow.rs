@@ -0,0 +1,712 @@
+//! Cow entity implementation.
+
+use std::str::FromStr;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::NbtCompound;
+use steel_macros::{entity_behavior, entity_impl};
+use steel_protocol::packets::game::{AttributeSnapshot, EquipmentSlotItem, SoundSource};
+use steel_registry::cow_sound_variant::CowSoundVariantRef;
+use steel_registry::cow_variant::CowVariantRef;
+use steel_registry::entity_type::{EntityDimensions, EntityTypeRef};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_entity_data::CowEntityData;
+use steel_registry::vanilla_item_tags::ItemTag;
+use steel_registry::{
+    REGISTRY, RegistryExt, TaggedRegistryExt, sound_events, vanilla_attributes,
+    vanilla_cow_sound_variants, vanilla_cow_variants, vanilla_items,
+};
+use steel_utils::locks::SyncMutex;
+use steel_utils::random::legacy_random::LegacyRandom;
+use steel_utils::types::InteractionHand;
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey, Identifier};
+
+use crate::behavior::InteractionResult;
+use crate::entity::ai::goal::{
+    BreedGoal, FloatGoal, FollowParentGoal, LookAtPlayerGoal, PanicGoal, RandomLookAroundGoal,
+    TemptGoal, WaterAvoidingRandomStrollGoal,
+};
+use crate::entity::damage::DamageSource;
+use crate::entity::{
+    AgeableMob, AgeableMobBase, Animal, AnimalBase, Entity, EntityBase, EntityBaseLoad, EntityPose,
+    EntitySpawnReason, EntitySyncedData, LivingEntity, LivingEntityBase, Mob, MobBase,
+    MobEffectSyncChange, PathfinderMob, SpawnGroupData,
+};
+use crate::physics::MoveResult;
+use crate::player::Player;
+use crate::world::World;
+
+const COW_BABY_DIMENSIONS: EntityDimensions = EntityDimensions::new(0.45, 0.7, 0.69);
+
+/// Vanilla cow entity.
+#[entity_behavior(class = "Cow")]
+pub struct CowEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    living_base: LivingEntityBase,
+    mob_base: MobBase,
+    ageable_base: AgeableMobBase,
+    animal_base: AnimalBase,
+    entity_data: SyncMutex<CowEntityData>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `CowEntity`.
+unsafe impl DowncastType for CowEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/cow");
+}
+
+impl CowEntity {
+    /// Creates a new cow entity.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_with_base(
+            EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+        )
+    }
+
+    /// Creates a cow entity from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self::new_with_base(
+            EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+        )
+    }
+
+    fn new_with_base(base: EntityBase, entity_type: EntityTypeRef) -> Self {
+        let living_base = LivingEntityBase::new(entity_type);
+        let mob_base = MobBase::new();
+        let ageable_base = AgeableMobBase::new();
+        let animal_base = AnimalBase::new();
+        AnimalBase::initialize_pathfinding_malus(&mob_base);
+        let mut entity_data = CowEntityData::new();
+        living_base.initialize_synced_data(&mut entity_data);
+        {
+            let mut goal_selector = mob_base.goal_selector().lock();
+            goal_selector.add_goal(0, FloatGoal::new(&mob_base));
+            goal_selector.add_goal(1, PanicGoal::new(2.0));
+            goal_selector.add_goal(2, BreedGoal::new(1.0));
+            goal_selector.add_goal(3, TemptGoal::new(1.25, CowEntity::is_food, false));
+            goal_selector.add_goal(4, FollowParentGoal::new(1.25));
+            goal_selector.add_goal(5, WaterAvoidingRandomStrollGoal::new(1.0));
+            goal_selector.add_goal(6, LookAtPlayerGoal::new(6.0));
+            goal_selector.add_goal(7, RandomLookAroundGoal::new());
+        }
+
+        Self {
+            base,
+            entity_type,
+            living_base,
+            mob_base,
+            ageable_base,
+            animal_base,
+            entity_data: SyncMutex::new(entity_data),
+        }
+    }
+
+    /// Returns whether this cow is a baby.
+    #[must_use]
+    pub fn is_baby(&self) -> bool {
+        AgeableMob::is_baby(self)
+    }
+
+    /// Sets the vanilla baby state using the `AgeableMob` start age.
+    pub fn set_baby(&self, baby: bool) {
+        AgeableMob::set_baby(self, baby);
+    }
+
+    /// Returns the current cow variant registry ID stored in synced data.
+    #[must_use]
+    pub fn variant_id(&self) -> i32 {
+        *self.entity_data.lock().variant.get()
+    }
+
+    /// Sets the current cow variant by registry entry.
+    pub fn set_variant(&self, variant: CowVariantRef) {
+        if !self.set_variant_by_key(&variant.key) {
+            log::error!("cow variant {} is not registered", variant.key);
+        }
+    }
+
+    /// Returns the current cow variant, falling back to vanilla's default holder.
+    #[must_use]
+    pub fn variant(&self) -> CowVariantRef {
+        let id = self.variant_id();
+        if let Ok(id) = usize::try_from(id)
+            && let Some(variant) = REGISTRY.cow_variants.by_id(id)
+        {
+            return variant;
+        }
+
+        &vanilla_cow_variants::TEMPERATE
+    }
+
+    /// Returns the current cow sound variant registry ID stored in synced data.
+    #[must_use]
+    pub fn sound_variant_id(&self) -> i32 {
+        *self.entity_data.lock().sound_variant.get()
+    }
+
+    /// Sets the current cow sound variant by registry entry.
+    pub fn set_sound_variant(&self, sound_variant: CowSoundVariantRef) {
+        if !self.set_sound_variant_by_key(&sound_variant.key) {
+            log::error!("cow sound variant {} is not registered", sound_variant.key);
+        }
+    }
+
+    /// Returns the current cow sound variant, falling back to vanilla classic.
+    #[must_use]
+    pub fn sound_variant(&self) -> CowSoundVariantRef {
+        let id = self.sound_variant_id();
+        if let Ok(id) = usize::try_from(id)
+            && let Some(sound_variant) = REGISTRY.cow_sound_variants.by_id(id)
+        {
+            return sound_variant;
+        }
+
+        &vanilla_cow_sound_variants::CLASSIC
+    }
+
+    fn set_variant_by_key(&self, key: &Identifier) -> bool {
+        let Some(id) = REGISTRY.cow_variants.id_from_key(key) else {
+            return false;
+        };
+        let Ok(id) = i32::try_from(id) else {
+            log::error!("cow variant id {id} does not fit synced-data i32");
+            return false;
+        };
+        self.entity_data.lock().variant.set(id);
+        true
+    }
+
+    fn set_sound_variant_by_key(&self, key: &Identifier) -> bool {
+        let Some(id) = REGISTRY.cow_sound_variants.id_from_key(key) else {
+            return false;
+        };
+        let Ok(id) = i32::try_from(id) else {
+            log::error!("cow sound variant id {id} does not fit synced-data i32");
+            return false;
+        };
+        self.entity_data.lock().sound_variant.set(id);
+        true
+    }
+
+    fn update_dirty_mob_effect_entity_data(&self) {
+        if !self.living_base.take_effects_dirty() {
+            return;
+        }
+
+        let display = self.living_base.mob_effect_display_state();
+
+        {
+            let mut entity_data = self.entity_data.lock();
+            let living = entity_data.living_entity_mut();
+            living.effect_particles.set(display.particles);
+            living.effect_ambience.set(display.ambient);
+        }
+
+        self.entity_data.set_base_invisible_flag(display.invisible);
+        self.entity_data
+            .set_base_glowing_flag(self.has_glowing_tag() || display.glowing);
+    }
+
+    /// Returns whether the stack is vanilla cow food.
+    #[must_use]
+    pub fn is_food(item_stack: &ItemStack) -> bool {
+        REGISTRY
+            .items
+            .is_in_tag(item_stack.item(), &ItemTag::COW_FOOD)
+    }
+}
+
+#[entity_impl(class(animal))]
+impl Entity for CowEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn dimensions_for_pose(&self, _pose: EntityPose) -> EntityDimensions {
+        let scale = LivingEntity::get_scale(self);
+        if self.is_baby() {
+            COW_BABY_DIMENSIONS.scale(scale)
+        } else if self.entity_type.fixed {
+            self.entity_type.dimensions
+        } else {
+            self.entity_type.dimensions.scale(scale)
+        }
+    }
+
+    fn tick(&self) {
+        self.default_tick();
+        self.living_base.decrement_invulnerable_time();
+        self.tick_mob_effects();
+
+        if self.is_dead_or_dying() {
+            LivingEntity::tick_death(self);
+            self.tick_living_state();
+            return;
+        }
+
+        if !self.is_removed() {
+            self.ai_step();
+        }
+
+        self.tick_living_state();
+    }
+
+    fn check_despawn(&self) {
+        Mob::check_mob_despawn(self);
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.is_removed() && self.get_health() > 0.0
+    }
+
+    fn is_pickable(&self) -> bool {
+        !self.is_removed()
+    }
+
+    fn is_pushable(&self) -> bool {
+        Entity::is_alive(self) && !self.is_spectator() && !self.on_climbable()
+    }
+
+    fn is_effective_ai(&self) -> bool {
+        self.is_server_driven_movement() && !self.is_no_ai()
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        LivingEntity::get_attribute_gravity(self)
+    }
+
+    fn can_freeze(&self) -> bool {
+        self.default_living_can_freeze()
+    }
+
+    fn can_walk_on_powder_snow(&self) -> bool {
+        self.default_living_can_walk_on_powder_snow()
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn update_data_before_sync(&self) {
+        self.update_dirty_mob_effect_entity_data();
+    }
+
+    fn pack_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().syncable_snapshots()
+    }
+
+    fn drain_dirty_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().drain_dirty_sync()
+    }
+
+    fn drain_dirty_mob_effects(&self) -> Vec<MobEffectSyncChange> {
+        self.living_base.drain_dirty_mob_effects()
+    }
+
+    fn pack_all_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.pack_living_equipment()
+    }
+
+    fn drain_dirty_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.drain_dirty_living_equipment()
+    }
+
+    fn max_up_step(&self) -> f32 {
+        self.attributes()
+            .lock()
+            .get_value(vanilla_attributes::STEP_HEIGHT)
+            .unwrap_or(0.6) as f32
+    }
+
+    fn sound_source(&self) -> SoundSource {
+        SoundSource::Neutral
+    }
+
+    fn play_step_sound(&self, _pos: BlockPos, _block_state: BlockStateId) {
+        self.play_sound(self.sound_variant().step_sound, 0.15, 1.0);
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        LivingEntity::hurt_server(self, world, source, amount)
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        Mob::interact_mob(self, player, hand, location)
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        self.save_mob(nbt);
+        self.save_ageable_mob(nbt);
+        self.save_animal(nbt);
+        nbt.insert("variant", self.variant().key.to_string());
+        nbt.insert("sound_variant", self.sound_variant().key.to_string());
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        self.load_mob(nbt);
+        self.load_ageable_mob(nbt);
+        self.load_animal(nbt);
+
+        if let Some(variant) = nbt.string("variant")
+            && let Ok(key) = Identifier::from_str(variant.to_str().as_ref())
+        {
+            self.set_variant_by_key(&key);
+        }
+        if let Some(sound_variant) = nbt.string("sound_variant")
+            && let Ok(key) = Identifier::from_str(sound_variant.to_str().as_ref())
+        {
+            self.set_sound_variant_by_key(&key);
+        }
+    }
+}
+
+impl LivingEntity for CowEntity {
+    fn living_base(&self) -> &LivingEntityBase {
+        &self.living_base
+    }
+
+    fn get_health(&self) -> f32 {
+        *self.entity_data.lock().living_entity().health.get()
+    }
+
+    fn set_health(&self, health: f32) {
+        let max_health = self.get_max_health();
+        let clamped = health.clamp(0.0, max_health);
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .health
+            .set(clamped);
+    }
+
+    fn is_baby(&self) -> bool {
+        AgeableMob::is_baby(self)
+    }
+
+    fn hurt_sound(&self, _source: &DamageSource) -> Option<SoundEventRef> {
+        Some(self.sound_variant().hurt_sound)
+    }
+
+    fn death_sound(&self) -> Option<SoundEventRef> {
+        Some(self.sound_variant().death_sound)
+    }
+
+    fn server_ai_step(&self) {
+        Mob::mob_server_ai_step(self);
+    }
+
+    fn before_actually_hurt(&self, _source: &DamageSource, _amount: f32) {
+        Animal::reset_love(self);
+    }
+
+    fn ai_step(&self) -> Option<MoveResult> {
+        let result = self.default_ai_step();
+
+        AgeableMob::tick_ageable_mob(self);
+        Animal::tick_animal_love(self);
+        result
+    }
+}
+
+impl AgeableMob for CowEntity {
+    fn ageable_base(&self) -> &AgeableMobBase {
+        &self.ageable_base
+    }
+
+    fn is_age_locked(&self) -> bool {
+        *self.entity_data.lock().ageable_mob().age_locked.get()
+    }
+
+    fn set_age_locked(&self, age_locked: bool) {
+        self.entity_data
+            .lock()
+            .ageable_mob_mut()
+            .age_locked
+            .set(age_locked);
+    }
+
+    fn set_synced_baby(&self, baby: bool) {
+        self.entity_data.lock().ageable_mob_mut().baby.set(baby);
+    }
+
+    fn age_boundary_changed(&self, _baby: bool) {
+        self.refresh_dimensions();
+    }
+}
+
+impl Animal for CowEntity {
+    fn animal_base(&self) -> &AnimalBase {
+        &self.animal_base
+    }
+
+    fn is_food(&self, item_stack: &ItemStack) -> bool {
+        CowEntity::is_food(item_stack)
+    }
+
+    fn breed_variant_key(&self) -> Option<&Identifier> {
+        Some(&self.variant().key)
+    }
+
+    fn set_breed_variant_key(&self, key: &Identifier) -> bool {
+        self.set_variant_by_key(key)
+    }
+
+    fn initialize_breed_offspring(&self, partner: &dyn Animal, offspring: &dyn Animal) {
+        let variant_key = if rand::random::<bool>() {
+            self.breed_variant_key()
+        } else {
+            partner.breed_variant_key()
+        };
+        let Some(variant_key) = variant_key else {
+            return;
+        };
+
+        if !offspring.set_breed_variant_key(variant_key) {
+            log::error!("cow offspring could not inherit breeding variant {variant_key}");
+        }
+    }
+}
+
+impl Mob for CowEntity {
+    fn mob_base(&self) -> &MobBase {
+        &self.mob_base
+    }
+
+    fn tick_goal_selectors(&self) {
+        PathfinderMob::tick_pathfinder_goal_selectors(self);
+    }
+
+    fn tick_path_navigation(&self) {
+        PathfinderMob::tick_pathfinder_path_navigation(self);
+    }
+
+    fn custom_server_ai_step(&self) {
+        Animal::custom_server_ai_step_animal(self);
+    }
+
+    fn ambient_sound(&self) -> Option<SoundEventRef> {
+        Some(self.sound_variant().ambient_sound)
+    }
+
+    fn remove_when_far_away(&self, dist_sqr: f64) -> bool {
+        Animal::remove_when_far_away_animal(self, dist_sqr)
+    }
+
+    fn finalize_spawn(
+        &self,
+        world: &Arc<World>,
+        spawn_reason: EntitySpawnReason,
+        group_data: Option<SpawnGroupData>,
+    ) -> Option<SpawnGroupData> {
+        let biome = world.biome_at(self.block_position());
+        let (variant, sound_variant) = {
+            let mut random = LegacyRandom::from_seed(rand::random());
+            let variant = biome.and_then(|biome| {
+                REGISTRY
+                    .cow_variants
+                    .select_spawn_variant(biome, &mut random)
+            });
+            let sound_variant = REGISTRY.cow_sound_variants.pick_random(&mut random);
+            (variant, sound_variant)
+        };
+
+        if let Some(variant) = variant {
+            self.set_variant(variant);
+        }
+
+        if let Some(sound_variant) = sound_variant {
+            self.set_sound_variant(sound_variant);
+        }
+
+        self.finalize_spawn_ageable_mob(world, spawn_reason, group_data)
+    }
+
+    fn mob_interact(&self, player: &Player, hand: InteractionHand) -> InteractionResult {
+        let is_bucket = player
+            .inventory
+            .lock()
+            .get_item_in_hand(hand)
+            .is(&vanilla_items::BUCKET);
+        if is_bucket && !self.is_baby() {
+            if let Some(world) = self.level() {
+                world.play_sound_at(
+                    &sound_events::ENTITY_COW_MILK,
+                    SoundSource::Players,
+                    player.position(),
+                    1.0,
+                    1.0,
+                    Some(player.id()),
+                );
+            }
+
+            let overflow = player.inventory.lock().apply_filled_result(
+                hand,
+                ItemStack::new(&vanilla_items::MILK_BUCKET),
+                player.has_infinite_materials(),
+                true,
+            );
+            if !overflow.is_empty() {
+                let _ = player.drop_item(overflow, false, false);
+            }
+            return InteractionResult::Success;
+        }
+
+        Animal::mob_interact_animal(self, player, hand)
+    }
+
+    fn mob_flags(&self) -> i8 {
+        *self.entity_data.lock().mob().mob_flags.get()
+    }
+
+    fn set_mob_flags(&self, flags: i8) {
+        self.entity_data.lock().mob_mut().mob_flags.set(flags);
+    }
+}
+
+impl PathfinderMob for CowEntity {}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::string::ToString;
+
+    use simdnbt::borrow::read_compound as read_borrowed_compound;
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::{vanilla_damage_types, vanilla_entities};
+
+    use super::*;
+
+    #[test]
+    fn cow_initializes_vanilla_living_attributes_and_health() {
+        init_test_registry();
+
+        let cow = CowEntity::new(&vanilla_entities::COW, 1, DVec3::ZERO, Weak::new());
+
+        assert_eq!(cow.get_health().to_bits(), 10.0_f32.to_bits());
+        let attributes = cow.attributes().lock();
+        assert_eq!(
+            attributes
+                .required_value(vanilla_attributes::MOVEMENT_SPEED)
+                .to_bits(),
+            f64::from(0.2_f32).to_bits()
+        );
+    }
+
+    #[test]
+    fn cow_exposes_animal_behavior_without_downcasting() {
+        init_test_registry();
+
+        let cow = CowEntity::new(&vanilla_entities::COW, 1, DVec3::ZERO, Weak::new());
+        let entity = &cow as &dyn Entity;
+
+        assert!(entity.is_pathfinder_mob());
+        let Some(animal) = entity.as_animal() else {
+            panic!("cow should expose animal behavior");
+        };
+        animal.set_in_love_time(5);
+        assert!(animal.is_in_love());
+    }
+
+    #[test]
+    fn cow_registers_vanilla_passive_goals() {
+        init_test_registry();
+
+        let cow = CowEntity::new(&vanilla_entities::COW, 1, DVec3::ZERO, Weak::new());
+
+        let selector = cow.mob_base().goal_selector().lock();
+        assert_eq!(
+            selector.available_goal_priorities(),
+            vec![0, 1, 2, 3, 4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn cow_sounds_use_current_sound_variant() {
+        init_test_registry();
+
+        let cow = CowEntity::new(&vanilla_entities::COW, 1, DVec3::ZERO, Weak::new());
+        let source = DamageSource::environment(&vanilla_damage_types::GENERIC);
+
+        assert_eq!(
+            Mob::ambient_sound(&cow).map(|sound| &sound.key),
+            Some(&sound_events::ENTITY_COW_AMBIENT.key)
+        );
+        assert_eq!(
+            LivingEntity::hurt_sound(&cow, &source).map(|sound| &sound.key),
+            Some(&sound_events::ENTITY_COW_HURT.key)
+        );
+        assert_eq!(
+            LivingEntity::death_sound(&cow).map(|sound| &sound.key),
+            Some(&sound_events::ENTITY_COW_DEATH.key)
+        );
+    }
+
+    #[test]
+    fn cow_uses_vanilla_cow_food_tag() {
+        init_test_registry();
+
+        assert!(CowEntity::is_food(&ItemStack::new(&vanilla_items::WHEAT)));
+        assert!(!CowEntity::is_food(&ItemStack::new(&vanilla_items::CARROT)));
+    }
+
+    #[test]
+    fn cow_baby_uses_vanilla_baby_dimensions() {
+        init_test_registry();
+
+        let cow = CowEntity::new(&vanilla_entities::COW, 1, DVec3::ZERO, Weak::new());
+        cow.set_baby(true);
+
+        let dimensions = cow.dimensions_for_pose(EntityPose::Standing);
+        assert_eq!(dimensions.width.to_bits(), 0.45_f32.to_bits());
+        assert_eq!(dimensions.height.to_bits(), 0.7_f32.to_bits());
+    }
+
+    #[test]
+    fn cow_round_trips_vanilla_variant_data() {
+        init_test_registry();
+
+        let cow = CowEntity::new(&vanilla_entities::COW, 1, DVec3::ZERO, Weak::new());
+        cow.set_variant(&vanilla_cow_variants::WARM);
+        cow.set_sound_variant(&vanilla_cow_sound_variants::MOODY);
+
+        let mut nbt = NbtCompound::new();
+        cow.save_additional(&mut nbt);
+        assert_eq!(
+            nbt.string("variant").map(ToString::to_string),
+            Some("minecraft:warm".to_owned())
+        );
+        assert_eq!(
+            nbt.string("sound_variant").map(ToString::to_string),
+            Some("minecraft:moody".to_owned())
+        );
+
+        let mut bytes = Vec::new();
+        nbt.write(&mut bytes);
+        let borrowed = read_borrowed_compound(&mut Cursor::new(&bytes))
+            .unwrap_or_else(|error| panic!("test nbt should reborrow: {error}"));
+        let loaded = CowEntity::new(&vanilla_entities::COW, 2, DVec3::ZERO, Weak::new());
+        loaded.load_additional((&borrowed).into());
+
+        assert_eq!(loaded.variant().key, vanilla_cow_variants::WARM.key);
+        assert_eq!(
+            loaded.sound_variant().key,
+            vanilla_cow_sound_variants::MOODY.key
+        );
+    }
+}
@@ -0,0 +1,608 @@
+//! Creeper entity implementation.
+
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::NbtCompound;
+use steel_macros::{entity_behavior, entity_impl};
+use steel_protocol::packets::game::{AttributeSnapshot, EquipmentSlotItem, SoundSource};
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_entity_data::CreeperEntityData;
+use steel_registry::vanilla_item_tags::ItemTag;
+use steel_registry::{
+    REGISTRY, TaggedRegistryExt, sound_events, vanilla_attributes, vanilla_entities,
+    vanilla_game_events, vanilla_items,
+};
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::InteractionHand;
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey};
+
+use crate::behavior::InteractionResult;
+use crate::entity::ai::goal::{
+    AvoidEntityGoal, FloatGoal, HurtByTargetGoal, LookAtPlayerGoal, MeleeAttackGoal,
+    NearestAttackableTargetGoal, RandomLookAroundGoal, SwellGoal, WaterAvoidingRandomStrollGoal,
+};
+use crate::entity::damage::DamageSource;
+use crate::entity::entities::LightningBoltEntity;
+use crate::entity::{
+    Entity, EntityBase, EntityBaseLoad, EntitySyncedData, LivingEntity, LivingEntityBase, Mob,
+    MobBase, MobEffectSyncChange, Monster, PathfinderMob, RemovalReason,
+};
+use crate::physics::MoveResult;
+use crate::player::Player;
+use crate::world::{ExplosionInteraction, World};
+
+const MONSTER_EXPERIENCE_REWARD: i32 = 5;
+const DEFAULT_MAX_SWELL: i16 = 30;
+const DEFAULT_EXPLOSION_RADIUS: i8 = 3;
+const FALL_SWELL_MULTIPLIER: f64 = 1.5;
+const FALL_SWELL_MAX_OFFSET: i16 = 5;
+
+struct CreeperState {
+    old_swell: i16,
+    swell: i16,
+    max_swell: i16,
+    explosion_radius: i8,
+}
+
+/// Vanilla creeper entity.
+#[entity_behavior(class = "Creeper")]
+pub struct CreeperEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    living_base: LivingEntityBase,
+    mob_base: MobBase,
+    entity_data: SyncMutex<CreeperEntityData>,
+    state: SyncMutex<CreeperState>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `CreeperEntity`.
+unsafe impl DowncastType for CreeperEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/creeper");
+}
+
+impl CreeperEntity {
+    /// Creates a new creeper entity.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_with_base(
+            EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+        )
+    }
+
+    /// Creates a creeper entity from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self::new_with_base(
+            EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+        )
+    }
+
+    fn new_with_base(base: EntityBase, entity_type: EntityTypeRef) -> Self {
+        let living_base = LivingEntityBase::new(entity_type);
+        let mob_base = MobBase::new();
+        mob_base.set_xp_reward(MONSTER_EXPERIENCE_REWARD);
+        let mut entity_data = CreeperEntityData::new();
+        living_base.initialize_synced_data(&mut entity_data);
+        {
+            let mut goal_selector = mob_base.goal_selector().lock();
+            goal_selector.add_goal(1, FloatGoal::new(&mob_base));
+            goal_selector.add_goal(2, SwellGoal::new());
+            goal_selector.add_goal(
+                3,
+                AvoidEntityGoal::with_selector(6.0, 1.0, 1.2, |target, _| {
+                    target.entity_type() == &vanilla_entities::OCELOT
+                }),
+            );
+            goal_selector.add_goal(
+                3,
+                AvoidEntityGoal::with_selector(6.0, 1.0, 1.2, |target, _| {
+                    target.entity_type() == &vanilla_entities::CAT
+                }),
+            );
+            goal_selector.add_goal(4, MeleeAttackGoal::new(1.0, false));
+            goal_selector.add_goal(5, WaterAvoidingRandomStrollGoal::new(0.8));
+            goal_selector.add_goal(6, LookAtPlayerGoal::new(8.0));
+            goal_selector.add_goal(6, RandomLookAroundGoal::new());
+        }
+        {
+            let mut target_selector = mob_base.target_selector().lock();
+            target_selector.add_goal(1, NearestAttackableTargetGoal::new_for_players(true));
+            target_selector.add_goal(2, HurtByTargetGoal::new(Vec::new()));
+        }
+
+        Self {
+            base,
+            entity_type,
+            living_base,
+            mob_base,
+            entity_data: SyncMutex::new(entity_data),
+            state: SyncMutex::new(CreeperState {
+                old_swell: 0,
+                swell: 0,
+                max_swell: DEFAULT_MAX_SWELL,
+                explosion_radius: DEFAULT_EXPLOSION_RADIUS,
+            }),
+        }
+    }
+
+    /// Returns the synced swell direction: `1` while priming, `-1` otherwise.
+    #[must_use]
+    pub fn swell_dir(&self) -> i32 {
+        *self.entity_data.lock().swell_dir.get()
+    }
+
+    /// Sets the synced swell direction.
+    pub fn set_swell_dir(&self, swell_dir: i32) {
+        self.entity_data.lock().swell_dir.set(swell_dir);
+    }
+
+    /// Returns whether this creeper was struck by lightning.
+    #[must_use]
+    pub fn is_powered(&self) -> bool {
+        *self.entity_data.lock().is_powered.get()
+    }
+
+    /// Charges this creeper, as a lightning strike does.
+    pub fn set_powered(&self) {
+        self.entity_data.lock().is_powered.set(true);
+    }
+
+    /// Returns whether this creeper was ignited by a player.
+    #[must_use]
+    pub fn is_ignited(&self) -> bool {
+        *self.entity_data.lock().is_ignited.get()
+    }
+
+    /// Mirrors vanilla `Creeper.ignite`.
+    pub fn ignite(&self) {
+        self.entity_data.lock().is_ignited.set(true);
+    }
+
+    /// Returns vanilla `Creeper.getSwelling` for the given partial tick.
+    #[must_use]
+    pub fn swelling(&self, partial_tick: f32) -> f32 {
+        let state = self.state.lock();
+        let swell =
+            f32::from(state.old_swell) + f32::from(state.swell - state.old_swell) * partial_tick;
+        swell / f32::from(state.max_swell - 2)
+    }
+
+    fn tick_swell(&self) {
+        let mut state = self.state.lock();
+        state.old_swell = state.swell;
+        if self.is_ignited() {
+            self.set_swell_dir(1);
+        }
+
+        let swell_dir = self.swell_dir();
+        if swell_dir > 0 && state.swell == 0 {
+            self.play_sound(&sound_events::ENTITY_CREEPER_PRIMED, 1.0, 0.5);
+            self.game_event(&vanilla_game_events::PRIME_FUSE);
+        }
+
+        state.swell = (state.swell + swell_dir as i16).max(0);
+        if state.swell >= state.max_swell {
+            state.swell = state.max_swell;
+            drop(state);
+            self.explode_creeper();
+        }
+    }
+
+    /// Explosion radius, doubled for charged creepers.
+    fn explosion_power(&self) -> f32 {
+        let radius = f32::from(self.state.lock().explosion_radius);
+        if self.is_powered() {
+            radius * 2.0
+        } else {
+            radius
+        }
+    }
+
+    /// Vanilla `Creeper.explodeCreeper`.
+    fn explode_creeper(&self) {
+        let Some(world) = self.level() else {
+            return;
+        };
+
+        world.explode(
+            Some(self),
+            None,
+            self.position(),
+            self.explosion_power(),
+            false,
+            ExplosionInteraction::for_mob(&world),
+        );
+        // TODO: Spawn the lingering effect cloud for active mob effects once
+        // area effect clouds exist.
+        self.set_removed(RemovalReason::Discarded);
+    }
+
+    fn update_dirty_mob_effect_entity_data(&self) {
+        if !self.living_base.take_effects_dirty() {
+            return;
+        }
+
+        let display = self.living_base.mob_effect_display_state();
+
+        {
+            let mut entity_data = self.entity_data.lock();
+            let living = entity_data.living_entity_mut();
+            living.effect_particles.set(display.particles);
+            living.effect_ambience.set(display.ambient);
+        }
+
+        self.entity_data.set_base_invisible_flag(display.invisible);
+        self.entity_data
+            .set_base_glowing_flag(self.has_glowing_tag() || display.glowing);
+    }
+}
+
+#[entity_impl(class(pathfinder_mob))]
+impl Entity for CreeperEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn tick(&self) {
+        if Entity::is_alive(self) {
+            self.tick_swell();
+        }
+
+        self.default_tick();
+        self.living_base.decrement_invulnerable_time();
+        self.tick_mob_effects();
+
+        if self.is_dead_or_dying() {
+            LivingEntity::tick_death(self);
+            self.tick_living_state();
+            return;
+        }
+
+        if !self.is_removed() {
+            self.ai_step();
+        }
+
+        self.tick_living_state();
+    }
+
+    fn check_despawn(&self) {
+        Mob::check_mob_despawn(self);
+    }
+
+    fn thunder_hit(&self, world: &Arc<World>, _lightning: &LightningBoltEntity) {
+        self.default_thunder_hit(world);
+        self.set_powered();
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.is_removed() && self.get_health() > 0.0
+    }
+
+    fn is_pickable(&self) -> bool {
+        !self.is_removed()
+    }
+
+    fn is_pushable(&self) -> bool {
+        Entity::is_alive(self) && !self.is_spectator() && !self.on_climbable()
+    }
+
+    fn is_effective_ai(&self) -> bool {
+        self.is_server_driven_movement() && !self.is_no_ai()
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        LivingEntity::get_attribute_gravity(self)
+    }
+
+    fn can_freeze(&self) -> bool {
+        self.default_living_can_freeze()
+    }
+
+    fn can_walk_on_powder_snow(&self) -> bool {
+        self.default_living_can_walk_on_powder_snow()
+    }
+
+    fn cause_fall_damage(
+        &self,
+        fall_distance: f64,
+        damage_modifier: f32,
+        source: &DamageSource,
+    ) -> bool {
+        let result = self.cause_living_fall_damage(fall_distance, damage_modifier, source);
+        let mut state = self.state.lock();
+        let swell = i32::from(state.swell) + (fall_distance * FALL_SWELL_MULTIPLIER) as i32;
+        let max_fall_swell = i32::from(state.max_swell - FALL_SWELL_MAX_OFFSET);
+        state.swell = swell.min(max_fall_swell) as i16;
+        result
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn update_data_before_sync(&self) {
+        self.update_dirty_mob_effect_entity_data();
+    }
+
+    fn pack_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().syncable_snapshots()
+    }
+
+    fn drain_dirty_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().drain_dirty_sync()
+    }
+
+    fn drain_dirty_mob_effects(&self) -> Vec<MobEffectSyncChange> {
+        self.living_base.drain_dirty_mob_effects()
+    }
+
+    fn pack_all_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.pack_living_equipment()
+    }
+
+    fn drain_dirty_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.drain_dirty_living_equipment()
+    }
+
+    fn max_up_step(&self) -> f32 {
+        self.attributes()
+            .lock()
+            .get_value(vanilla_attributes::STEP_HEIGHT)
+            .unwrap_or(0.6) as f32
+    }
+
+    fn sound_source(&self) -> SoundSource {
+        SoundSource::Hostile
+    }
+
+    fn play_step_sound(&self, _pos: BlockPos, _block_state: BlockStateId) {}
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        LivingEntity::hurt_server(self, world, source, amount)
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        Mob::interact_mob(self, player, hand, location)
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        self.save_mob(nbt);
+        if self.is_powered() {
+            nbt.insert("powered", i8::from(true));
+        }
+        let state = self.state.lock();
+        nbt.insert("Fuse", state.max_swell);
+        nbt.insert("ExplosionRadius", state.explosion_radius);
+        drop(state);
+        nbt.insert("ignited", i8::from(self.is_ignited()));
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        self.load_mob(nbt);
+        self.entity_data
+            .lock()
+            .is_powered
+            .set(nbt.byte("powered").is_some_and(|powered| powered != 0));
+        {
+            let mut state = self.state.lock();
+            state.max_swell = nbt.short("Fuse").unwrap_or(DEFAULT_MAX_SWELL);
+            state.explosion_radius = nbt
+                .byte("ExplosionRadius")
+                .unwrap_or(DEFAULT_EXPLOSION_RADIUS);
+        }
+        if nbt.byte("ignited").is_some_and(|ignited| ignited != 0) {
+            self.ignite();
+        }
+    }
+}
+
+impl LivingEntity for CreeperEntity {
+    fn living_base(&self) -> &LivingEntityBase {
+        &self.living_base
+    }
+
+    fn get_health(&self) -> f32 {
+        *self.entity_data.lock().living_entity().health.get()
+    }
+
+    fn set_health(&self, health: f32) {
+        let max_health = self.get_max_health();
+        let clamped = health.clamp(0.0, max_health);
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .health
+            .set(clamped);
+    }
+
+    fn hurt_sound(&self, _source: &DamageSource) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_CREEPER_HURT)
+    }
+
+    fn death_sound(&self) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_CREEPER_DEATH)
+    }
+
+    fn server_ai_step(&self) {
+        Mob::mob_server_ai_step(self);
+    }
+
+    fn ai_step(&self) -> Option<MoveResult> {
+        self.ai_step_monster()
+    }
+}
+
+impl Mob for CreeperEntity {
+    fn mob_base(&self) -> &MobBase {
+        &self.mob_base
+    }
+
+    fn tick_goal_selectors(&self) {
+        PathfinderMob::tick_pathfinder_goal_selectors(self);
+    }
+
+    fn tick_path_navigation(&self) {
+        PathfinderMob::tick_pathfinder_path_navigation(self);
+    }
+
+    fn max_fall_distance(&self) -> i32 {
+        if self.target().is_none() {
+            return 3;
+        }
+
+        3 + (self.get_health() - 1.0) as i32
+    }
+
+    fn mob_interact(&self, player: &Player, hand: InteractionHand) -> InteractionResult {
+        let (is_igniter, is_fire_charge, is_damageable) = {
+            let inventory = player.inventory.lock();
+            let item_stack = inventory.get_item_in_hand(hand);
+            (
+                REGISTRY
+                    .items
+                    .is_in_tag(item_stack.item(), &ItemTag::CREEPER_IGNITERS),
+                item_stack.is(&vanilla_items::FIRE_CHARGE),
+                item_stack.is_damageable_item(),
+            )
+        };
+        if !is_igniter {
+            return InteractionResult::Pass;
+        }
+
+        let sound = if is_fire_charge {
+            &sound_events::ITEM_FIRECHARGE_USE
+        } else {
+            &sound_events::ITEM_FLINTANDSTEEL_USE
+        };
+        if let Some(world) = self.level() {
+            world.play_sound_at(
+                sound,
+                self.sound_source(),
+                self.position(),
+                1.0,
+                rand::random::<f32>().mul_add(0.4, 0.8),
+                Some(player.id()),
+            );
+        }
+
+        self.ignite();
+        if is_damageable {
+            player.hurt_item_in_hand(hand, 1);
+        } else if !player.has_infinite_materials() {
+            player.inventory.lock().shrink_item_in_hand(hand, 1);
+        }
+        InteractionResult::Success
+    }
+
+    fn mob_flags(&self) -> i8 {
+        *self.entity_data.lock().mob().mob_flags.get()
+    }
+
+    fn set_mob_flags(&self, flags: i8) {
+        self.entity_data.lock().mob_mut().mob_flags.set(flags);
+    }
+}
+
+impl PathfinderMob for CreeperEntity {
+    fn get_walk_target_value(&self, pos: BlockPos) -> f32 {
+        self.monster_walk_target_value(pos)
+    }
+}
+
+impl Monster for CreeperEntity {}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+
+    use super::*;
+
+    fn creeper() -> CreeperEntity {
+        init_test_registry();
+        CreeperEntity::new(&vanilla_entities::CREEPER, 1, DVec3::ZERO, Weak::new())
+    }
+
+    #[test]
+    fn creeper_starts_unprimed_with_vanilla_fuse() {
+        let creeper = creeper();
+
+        assert_eq!(creeper.swell_dir(), -1);
+        assert!(!creeper.is_ignited());
+        assert_eq!(creeper.state.lock().max_swell, DEFAULT_MAX_SWELL);
+        assert_eq!(
+            creeper
+                .mob_base()
+                .goal_selector()
+                .lock()
+                .available_goal_priorities(),
+            vec![1, 2, 3, 3, 4, 5, 6, 6]
+        );
+    }
+
+    #[test]
+    fn creeper_ignition_primes_swelling() {
+        let creeper = creeper();
+        creeper.ignite();
+
+        creeper.tick_swell();
+
+        assert_eq!(creeper.swell_dir(), 1);
+        assert_eq!(creeper.state.lock().swell, 1);
+        assert!(creeper.swelling(1.0) > 0.0);
+    }
+
+    #[test]
+    fn creeper_clamps_swell_at_max_fuse() {
+        let creeper = creeper();
+        creeper.set_swell_dir(1);
+        creeper.state.lock().swell = DEFAULT_MAX_SWELL - 1;
+
+        creeper.tick_swell();
+
+        assert_eq!(creeper.state.lock().swell, DEFAULT_MAX_SWELL);
+    }
+
+    #[test]
+    fn creeper_round_trips_fuse_and_ignition() {
+        let creeper = creeper();
+        creeper.ignite();
+        creeper.state.lock().max_swell = 15;
+
+        let mut nbt = NbtCompound::new();
+        creeper.save_additional(&mut nbt);
+
+        assert_eq!(nbt.short("Fuse"), Some(15));
+        assert_eq!(nbt.byte("ExplosionRadius"), Some(DEFAULT_EXPLOSION_RADIUS));
+        assert_eq!(nbt.byte("ignited"), Some(1));
+        assert_eq!(nbt.byte("powered"), None);
+    }
+
+    #[test]
+    fn charged_creeper_doubles_explosion_and_saves_powered() {
+        let creeper = creeper();
+        let radius = f32::from(DEFAULT_EXPLOSION_RADIUS);
+        assert!((creeper.explosion_power() - radius).abs() < f32::EPSILON);
+
+        creeper.set_powered();
+
+        assert!(creeper.is_powered());
+        assert!((creeper.explosion_power() - radius * 2.0).abs() < f32::EPSILON);
+        let mut nbt = NbtCompound::new();
+        creeper.save_additional(&mut nbt);
+        assert_eq!(nbt.byte("powered"), Some(1));
+    }
+}
@@ -1,4 +1,4 @@
-//! Minimal End Crystal entity implementation for End spike worldgen.
+//! End Crystal entity implementation.
 
 use std::sync::Weak;
 
@@ -6,19 +6,27 @@ use glam::DVec3;
 use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
 use simdnbt::owned::{NbtCompound, NbtTag};
 use steel_macros::entity_behavior;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
 use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::vanilla_damage_type_tags::DamageTypeTag;
 use steel_registry::vanilla_entity_data::EndCrystalEntityData;
+use steel_registry::{vanilla_damage_types, vanilla_game_events};
+use steel_utils::types::UpdateFlags;
 use steel_utils::{BlockPos, locks::SyncMutex};
-use steel_utils::{DowncastType, DowncastTypeKey};
+use steel_utils::{Downcast as _, DowncastType, DowncastTypeKey};
 
-use crate::entity::{Entity, EntityBase, EntityBaseLoad, EntitySyncedData};
-use crate::world::World;
+use crate::behavior::blocks::FireBlock;
+use crate::entity::damage::DamageSource;
+use crate::entity::entities::EnderDragonEntity;
+use crate::entity::entities::hanging::is_invulnerable_to_base;
+use crate::entity::{Entity, EntityBase, EntityBaseLoad, EntitySyncedData, RemovalReason};
+use crate::world::{ExplosionInteraction, World};
 
-/// End Crystal entity state needed by worldgen and persistence.
+/// Vanilla End Crystal entity.
 ///
-/// Steel currently implements the synchronized data and saved fields used by generated
-/// End spikes. Portal handling, dragon fight callbacks, and explosion behavior are still
-/// intentionally left to the broader entity/combat foundations.
+/// Crystals keep fire burning under them during a dragon fight, explode when
+/// destroyed and tell the fight about it. Portal handling is still left to the
+/// broader entity foundations.
 #[entity_behavior(class = "EndCrystal")]
 pub struct EndCrystalEntity {
     base: EntityBase,
@@ -106,7 +114,69 @@ impl Entity for EndCrystalEntity {
     }
 
     fn tick(&self) {
-        // TODO: Implement portal handling, fire refresh, dragon fight callbacks, and explosion behavior.
+        // TODO: Implement portal handling.
+        let Some(world) = self.level() else {
+            return;
+        };
+        let pos = self.block_position();
+        if world.dragon_fight.is_some() && world.get_block_state(pos).is_air() {
+            world.set_block(
+                pos,
+                FireBlock::get_state(world.as_ref(), pos),
+                UpdateFlags::UPDATE_ALL,
+            );
+        }
+    }
+
+    fn hurt(&self, _world: &World, source: &DamageSource, _amount: f32) -> bool {
+        if is_invulnerable_to_base(self, source) {
+            return false;
+        }
+        let Some(world) = self.level() else {
+            return false;
+        };
+        if source
+            .causing_entity_id
+            .and_then(|id| world.get_entity_by_id(id))
+            .is_some_and(|entity| entity.is::<EnderDragonEntity>())
+        {
+            return false;
+        }
+        if !self.is_removed() {
+            self.set_removed(RemovalReason::Killed);
+            if !source.is(&DamageTypeTag::IS_EXPLOSION) {
+                let damage_source = match source.causing_entity_id {
+                    Some(causing) => {
+                        DamageSource::environment(&vanilla_damage_types::PLAYER_EXPLOSION)
+                            .with_direct_entity(self.id())
+                            .with_causing_entity(causing)
+                    }
+                    None => DamageSource::environment(&vanilla_damage_types::EXPLOSION)
+                        .with_direct_entity(self.id()),
+                };
+                world.explode(
+                    Some(self),
+                    Some(damage_source),
+                    self.position(),
+                    6.0,
+                    false,
+                    ExplosionInteraction::for_block(&world),
+                );
+            }
+            world.on_end_crystal_destroyed(self, source);
+        }
+        true
+    }
+
+    fn kill(&self, _world: &World) {
+        if let Some(world) = self.level() {
+            world.on_end_crystal_destroyed(
+                self,
+                &DamageSource::environment(&vanilla_damage_types::GENERIC),
+            );
+        }
+        self.set_removed(RemovalReason::Killed);
+        self.game_event(&vanilla_game_events::ENTITY_DIE);
     }
 
     fn is_pickable(&self) -> bool {
@@ -0,0 +1,1779 @@
+//! Ender dragon entity implementation.
+
+use std::f32::consts::PI;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::NbtCompound;
+use steel_macros::{entity_behavior, entity_impl};
+use steel_protocol::packets::game::{AttributeSnapshot, EquipmentSlotItem, SoundSource};
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::vanilla_damage_type_tags::DamageTypeTag;
+use steel_registry::vanilla_entity_data::EnderDragonEntityData;
+use steel_registry::vanilla_game_rules::{MOB_DROPS, MOB_GRIEFING};
+use steel_registry::{level_events, sound_events, vanilla_damage_types, vanilla_game_events};
+use steel_utils::locks::SyncMutex;
+use steel_utils::{BlockPos, Downcast as _, DowncastType, DowncastTypeKey, WorldAabb};
+
+use crate::chunk::heightmap::HeightmapType;
+use crate::entity::damage::DamageSource;
+use crate::entity::entities::{EndCrystalEntity, ExperienceOrbEntity};
+use crate::entity::{
+    Entity, EntityBase, EntityBaseLoad, EntitySyncedData, LivingEntity, LivingEntityBase, Mob,
+    MobBase, MobEffectSyncChange, RemovalReason, next_entity_ids,
+};
+use crate::physics::{MoveResult, MoverType};
+use crate::player::Player;
+use crate::world::World;
+
+/// Vanilla `EndPodiumFeature.getLocation(BlockPos.ZERO)`.
+const END_PODIUM_LOCATION: BlockPos = BlockPos::new(0, 0, 0);
+/// Ticks of the death animation before the dragon is removed.
+const DEATH_ANIMATION_TICKS: i32 = 200;
+/// Experience dropped over the death animation of an already killed dragon.
+const RESPAWNED_DRAGON_XP: i32 = 500;
+/// Experience dropped over the death animation of the first dragon.
+const FIRST_DRAGON_XP: i32 = 12_000;
+/// Flight targets closer than this, squared, are considered reached.
+const TARGET_REACHED_SQR: f64 = 100.0;
+/// Flight targets further than this, squared, are considered lost.
+const TARGET_LOST_SQR: f64 = 22_500.0;
+
+/// Vanilla `EnderDragon.nodeAdjacency`: the edges of the circling path graph.
+const NODE_ADJACENCY: [u32; 24] = [
+    6146, 8197, 8202, 16404, 32808, 32848, 65696, 131_392, 131_712, 263_424, 526_848, 525_313,
+    1_581_057, 3_166_214, 2_138_120, 6_373_424, 4_358_208, 12_910_976, 9_044_480, 9_706_496,
+    15_216_640, 13_688_832, 11_763_712, 8_257_536,
+];
+
+/// Vanilla `EnderDragonPhase`, with its synced ids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnderDragonPhase {
+    /// Circling the outer pillars.
+    HoldingPattern,
+    /// Flying at a player to shoot a fireball.
+    StrafePlayer,
+    /// Following the path down to the exit portal.
+    LandingApproach,
+    /// Descending onto the exit portal.
+    Landing,
+    /// Leaving the exit portal.
+    Takeoff,
+    /// Breathing fire while perched.
+    SittingFlaming,
+    /// Looking for a player while perched.
+    SittingScanning,
+    /// Roaring at a player while perched.
+    SittingAttacking,
+    /// Charging straight at a player.
+    ChargingPlayer,
+    /// Flying to the exit portal to die.
+    Dying,
+    /// Hovering in place.
+    Hovering,
+}
+
+impl EnderDragonPhase {
+    /// Every phase, ordered by id.
+    pub const ALL: [Self; 11] = [
+        Self::HoldingPattern,
+        Self::StrafePlayer,
+        Self::LandingApproach,
+        Self::Landing,
+        Self::Takeoff,
+        Self::SittingFlaming,
+        Self::SittingScanning,
+        Self::SittingAttacking,
+        Self::ChargingPlayer,
+        Self::Dying,
+        Self::Hovering,
+    ];
+
+    /// Returns the id synced to clients and saved as `DragonPhase`.
+    #[must_use]
+    pub const fn id(self) -> i32 {
+        self as i32
+    }
+
+    /// Returns the phase with the given id, or the holding pattern.
+    ///
+    /// Vanilla `EnderDragonPhase.getById`.
+    #[must_use]
+    pub fn by_id(id: i32) -> Self {
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| Self::ALL.get(id).copied())
+            .unwrap_or(Self::HoldingPattern)
+    }
+
+    /// Returns vanilla `DragonPhaseInstance.isSitting`.
+    #[must_use]
+    pub const fn is_sitting(self) -> bool {
+        matches!(
+            self,
+            Self::SittingFlaming | Self::SittingScanning | Self::SittingAttacking | Self::Hovering
+        )
+    }
+
+    const fn fly_speed(self) -> f32 {
+        match self {
+            Self::Landing => 1.5,
+            Self::ChargingPlayer | Self::Dying => 3.0,
+            _ => 0.6,
+        }
+    }
+}
+
+/// One of the dragon's hitboxes, vanilla `EnderDragonPart`.
+///
+/// Clients number the parts after the dragon, in this order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnderDragonPart {
+    /// The head, the only part that takes full damage.
+    Head,
+    /// The neck.
+    Neck,
+    /// The body.
+    Body,
+    /// The first tail segment.
+    Tail1,
+    /// The second tail segment.
+    Tail2,
+    /// The third tail segment.
+    Tail3,
+    /// The right wing.
+    Wing1,
+    /// The left wing.
+    Wing2,
+}
+
+impl EnderDragonPart {
+    /// Every part, in client id order.
+    pub const ALL: [Self; 8] = [
+        Self::Head,
+        Self::Neck,
+        Self::Body,
+        Self::Tail1,
+        Self::Tail2,
+        Self::Tail3,
+        Self::Wing1,
+        Self::Wing2,
+    ];
+
+    /// Returns the part whose entity id is `offset` after the dragon's.
+    #[must_use]
+    pub fn from_id_offset(offset: i32) -> Option<Self> {
+        usize::try_from(offset - 1)
+            .ok()
+            .and_then(|index| Self::ALL.get(index).copied())
+    }
+
+    const fn size(self) -> (f64, f64) {
+        match self {
+            Self::Head => (1.0, 1.0),
+            Self::Neck => (3.0, 3.0),
+            Self::Body => (5.0, 3.0),
+            Self::Tail1 | Self::Tail2 | Self::Tail3 => (2.0, 2.0),
+            Self::Wing1 | Self::Wing2 => (4.0, 2.0),
+        }
+    }
+}
+
+/// Vanilla `Path` over the dragon's node graph.
+struct DragonPath {
+    nodes: Vec<BlockPos>,
+    next: usize,
+}
+
+impl DragonPath {
+    const fn is_done(&self) -> bool {
+        self.next >= self.nodes.len()
+    }
+
+    const fn advance(&mut self) {
+        self.next += 1;
+    }
+
+    fn next_node(&self) -> Option<BlockPos> {
+        self.nodes.get(self.next).copied()
+    }
+}
+
+/// Per-phase state, reset when a phase begins.
+enum PhaseState {
+    HoldingPattern {
+        path: Option<DragonPath>,
+        target: Option<DVec3>,
+    },
+    StrafePlayer {
+        path: Option<DragonPath>,
+        target: Option<DVec3>,
+        attack_target: Option<i32>,
+        fireball_charge: i32,
+    },
+    LandingApproach {
+        path: Option<DragonPath>,
+        target: Option<DVec3>,
+    },
+    Landing {
+        target: Option<DVec3>,
+    },
+    Takeoff {
+        first_tick: bool,
+        path: Option<DragonPath>,
+        target: Option<DVec3>,
+    },
+    SittingFlaming {
+        flame_ticks: i32,
+    },
+    SittingScanning {
+        scanning_time: i32,
+    },
+    SittingAttacking {
+        attacking_ticks: i32,
+    },
+    ChargingPlayer {
+        target: Option<DVec3>,
+        time_since_charge: i32,
+    },
+    Dying {
+        target: Option<DVec3>,
+    },
+    Hovering {
+        target: DVec3,
+    },
+}
+
+impl PhaseState {
+    /// Vanilla `DragonPhaseInstance.begin` for `phase`.
+    const fn begin(phase: EnderDragonPhase, position: DVec3) -> Self {
+        match phase {
+            EnderDragonPhase::HoldingPattern => Self::HoldingPattern {
+                path: None,
+                target: None,
+            },
+            EnderDragonPhase::StrafePlayer => Self::StrafePlayer {
+                path: None,
+                target: None,
+                attack_target: None,
+                fireball_charge: 0,
+            },
+            EnderDragonPhase::LandingApproach => Self::LandingApproach {
+                path: None,
+                target: None,
+            },
+            EnderDragonPhase::Landing => Self::Landing { target: None },
+            EnderDragonPhase::Takeoff => Self::Takeoff {
+                first_tick: true,
+                path: None,
+                target: None,
+            },
+            EnderDragonPhase::SittingFlaming => Self::SittingFlaming { flame_ticks: 0 },
+            EnderDragonPhase::SittingScanning => Self::SittingScanning { scanning_time: 0 },
+            EnderDragonPhase::SittingAttacking => Self::SittingAttacking { attacking_ticks: 0 },
+            EnderDragonPhase::ChargingPlayer => Self::ChargingPlayer {
+                target: None,
+                time_since_charge: 0,
+            },
+            EnderDragonPhase::Dying => Self::Dying { target: None },
+            EnderDragonPhase::Hovering => Self::Hovering { target: position },
+        }
+    }
+
+    const fn fly_target(&self) -> Option<DVec3> {
+        match self {
+            Self::HoldingPattern { target, .. }
+            | Self::StrafePlayer { target, .. }
+            | Self::LandingApproach { target, .. }
+            | Self::Landing { target }
+            | Self::Takeoff { target, .. }
+            | Self::ChargingPlayer { target, .. }
+            | Self::Dying { target } => *target,
+            Self::Hovering { target } => Some(*target),
+            Self::SittingFlaming { .. }
+            | Self::SittingScanning { .. }
+            | Self::SittingAttacking { .. } => None,
+        }
+    }
+}
+
+struct DragonState {
+    phase: EnderDragonPhase,
+    phase_state: PhaseState,
+    /// Vanilla `DragonHoldingPatternPhase.clockwise`, kept across phases.
+    holding_clockwise: bool,
+    /// Vanilla `DragonStrafePlayerPhase.holdingPatternClockwise`.
+    strafe_clockwise: bool,
+    /// Vanilla `DragonSittingFlamingPhase.flameCount`.
+    flame_count: i32,
+    y_rot_a: f32,
+    positions: [[f64; 2]; 64],
+    pos_pointer: i32,
+    in_wall: bool,
+    nearest_crystal: Option<i32>,
+    death_time: i32,
+    sitting_damage_received: i32,
+    nodes: Option<[BlockPos; 24]>,
+    parts: [DVec3; 8],
+    struck_part: Option<EnderDragonPart>,
+}
+
+/// Vanilla ender dragon.
+///
+/// The dragon reserves the eight entity ids after its own for its parts,
+/// which clients create on their own.
+#[entity_behavior(class = "EnderDragon")]
+pub struct EnderDragonEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    living_base: LivingEntityBase,
+    mob_base: MobBase,
+    entity_data: SyncMutex<EnderDragonEntityData>,
+    state: SyncMutex<DragonState>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `EnderDragonEntity`.
+unsafe impl DowncastType for EnderDragonEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/ender_dragon");
+}
+
+fn wrap_degrees(mut degrees: f32) -> f32 {
+    degrees %= 360.0;
+    if degrees >= 180.0 {
+        degrees -= 360.0;
+    }
+    if degrees < -180.0 {
+        degrees += 360.0;
+    }
+    degrees
+}
+
+fn at_bottom_center(pos: BlockPos) -> DVec3 {
+    DVec3::new(
+        f64::from(pos.x()) + 0.5,
+        f64::from(pos.y()),
+        f64::from(pos.z()) + 0.5,
+    )
+}
+
+fn node_distance(a: BlockPos, b: BlockPos) -> f32 {
+    let dx = (b.x() - a.x()) as f32;
+    let dy = (b.y() - a.y()) as f32;
+    let dz = (b.z() - a.z()) as f32;
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+/// Returns the horizontal position of path node `index`, vanilla
+/// `EnderDragon.findClosestNode` node layout.
+fn node_column(index: usize) -> (i32, i32, i32) {
+    let (radius, step, offset, y_offset) = match index {
+        0..12 => (60.0, PI / 12.0, index, 5),
+        12..20 => (40.0, PI / 8.0, index - 12, 15),
+        _ => (20.0, PI / 4.0, index - 20, 5),
+    };
+    let angle = 2.0 * (-PI + step * offset as f32);
+    (
+        (radius * angle.cos()).floor() as i32,
+        y_offset,
+        (radius * angle.sin()).floor() as i32,
+    )
+}
+
+/// Vanilla `EnderDragon.findPath`: A* over the node graph from `start` to
+/// `end`, optionally appending `final_node`.
+fn find_path(
+    nodes: &[BlockPos; 24],
+    start: usize,
+    end: usize,
+    final_node: Option<BlockPos>,
+    has_crystals: bool,
+) -> Option<DragonPath> {
+    let first = if has_crystals { 0 } else { 12 };
+    let mut g = [0.0_f32; 24];
+    let mut f = [0.0_f32; 24];
+    let mut came_from = [None::<usize>; 24];
+    let mut closed = [false; 24];
+    let mut open = Vec::new();
+
+    let end_pos = nodes[end];
+    f[start] = node_distance(nodes[start], end_pos);
+    open.push(start);
+    let mut closest = start;
+
+    let reconstruct = |came_from: &[Option<usize>; 24], to: usize| {
+        let mut path = vec![nodes[to]];
+        let mut node = to;
+        while let Some(previous) = came_from[node] {
+            path.insert(0, nodes[previous]);
+            node = previous;
+        }
+        path
+    };
+
+    while !open.is_empty() {
+        let (open_index, _) = open
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| f[**a].total_cmp(&f[**b]))?;
+        let node = open.swap_remove(open_index);
+        if node == end {
+            let mut path = reconstruct(&came_from, end);
+            path.extend(final_node);
+            return Some(DragonPath {
+                nodes: path,
+                next: 0,
+            });
+        }
+        if node_distance(nodes[node], end_pos) < node_distance(nodes[closest], end_pos) {
+            closest = node;
+        }
+        closed[node] = true;
+
+        for next in first..24 {
+            if NODE_ADJACENCY[node] & (1 << next) == 0 || closed[next] {
+                continue;
+            }
+            let cost = g[node] + node_distance(nodes[node], nodes[next]);
+            let in_open = open.contains(&next);
+            if !in_open || cost < g[next] {
+                came_from[next] = Some(node);
+                g[next] = cost;
+                f[next] = cost + node_distance(nodes[next], end_pos);
+                if !in_open {
+                    open.push(next);
+                }
+            }
+        }
+    }
+
+    if closest == start {
+        return None;
+    }
+    let mut path = reconstruct(&came_from, closest);
+    path.extend(final_node);
+    Some(DragonPath {
+        nodes: path,
+        next: 0,
+    })
+}
+
+/// Vanilla `EnderDragon.findClosestNode(x, y, z)`.
+fn find_closest_node(nodes: &[BlockPos; 24], pos: DVec3, has_crystals: bool) -> usize {
+    let target = BlockPos::new(
+        pos.x.floor() as i32,
+        pos.y.floor() as i32,
+        pos.z.floor() as i32,
+    );
+    let first = if has_crystals { 0 } else { 12 };
+    let mut closest = 0;
+    let mut closest_distance = 10_000.0_f32;
+    for (index, node) in nodes.iter().enumerate().skip(first) {
+        let distance = node_distance(*node, target).powi(2);
+        if distance < closest_distance {
+            closest_distance = distance;
+            closest = index;
+        }
+    }
+    closest
+}
+
+/// Vanilla `navigateToNextPathNode`: pops the next node and aims up to 20
+/// blocks above it.
+fn navigate_to_next_node(path: &mut Option<DragonPath>, target: &mut Option<DVec3>) {
+    let Some(path) = path else {
+        return;
+    };
+    if path.is_done() {
+        return;
+    }
+    let Some(node) = path.next_node() else {
+        return;
+    };
+    path.advance();
+    let y = f64::from(node.y()) + f64::from(rand::random::<f32>() * 20.0);
+    *target = Some(DVec3::new(f64::from(node.x()), y, f64::from(node.z())));
+}
+
+/// Wraps `index` into the ring the dragon circles on.
+const fn wrap_ring_node(index: usize, on_outer_ring: bool) -> usize {
+    if on_outer_ring {
+        index % 12
+    } else {
+        ((index.wrapping_sub(12)) & 7) + 12
+    }
+}
+
+impl EnderDragonEntity {
+    /// Creates a new ender dragon, reserving ids for its parts.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, _id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_with_base(
+            EntityBase::new(Self::reserve_ids(), position, entity_type.dimensions, world),
+            entity_type,
+        )
+    }
+
+    /// Creates an ender dragon from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self::new_with_base(
+            EntityBase::from_load(
+                EntityBaseLoad {
+                    id: Self::reserve_ids(),
+                    ..load
+                },
+                entity_type.dimensions,
+            ),
+            entity_type,
+        )
+    }
+
+    /// Vanilla `EnderDragon` replaces its id with a fresh block of ids so its
+    /// parts follow it.
+    fn reserve_ids() -> i32 {
+        next_entity_ids(EnderDragonPart::ALL.len() as i32 + 1)
+    }
+
+    fn new_with_base(base: EntityBase, entity_type: EntityTypeRef) -> Self {
+        let living_base = LivingEntityBase::new(entity_type);
+        let mob_base = MobBase::new();
+        let mut entity_data = EnderDragonEntityData::new();
+        living_base.initialize_synced_data(&mut entity_data);
+        let position = base.position();
+        base.set_no_physics(true);
+
+        Self {
+            base,
+            entity_type,
+            living_base,
+            mob_base,
+            entity_data: SyncMutex::new(entity_data),
+            state: SyncMutex::new(DragonState {
+                phase: EnderDragonPhase::HoldingPattern,
+                phase_state: PhaseState::begin(EnderDragonPhase::HoldingPattern, position),
+                holding_clockwise: true,
+                strafe_clockwise: false,
+                flame_count: 0,
+                y_rot_a: 0.0,
+                positions: [[0.0; 2]; 64],
+                pos_pointer: -1,
+                in_wall: false,
+                nearest_crystal: None,
+                death_time: 0,
+                sitting_damage_received: 0,
+                nodes: None,
+                parts: [position; 8],
+                struck_part: None,
+            }),
+        }
+    }
+
+    /// Returns the current phase.
+    #[must_use]
+    pub fn phase(&self) -> EnderDragonPhase {
+        self.state.lock().phase
+    }
+
+    /// Switches to `phase`, like vanilla `EnderDragonPhaseManager.setPhase`.
+    pub fn set_phase(&self, phase: EnderDragonPhase) {
+        let mut state = self.state.lock();
+        self.set_phase_locked(&mut state, phase);
+    }
+
+    fn set_phase_locked(&self, state: &mut DragonState, phase: EnderDragonPhase) {
+        if state.phase == phase {
+            return;
+        }
+        state.phase = phase;
+        state.phase_state = PhaseState::begin(phase, self.position());
+        if phase == EnderDragonPhase::SittingFlaming {
+            state.flame_count += 1;
+        }
+        self.entity_data.lock().phase.set(phase.id());
+    }
+
+    /// Remembers which part a player's attack packet targeted, so the next
+    /// hurt applies part damage like vanilla `EnderDragonPart.hurtServer`.
+    pub fn set_struck_part(&self, part: EnderDragonPart) {
+        self.state.lock().struck_part = Some(part);
+    }
+
+    /// Returns the bounding box of `part`.
+    #[must_use]
+    pub fn part_bounding_box(&self, part: EnderDragonPart) -> WorldAabb {
+        let position = self.state.lock().parts[part as usize];
+        let (width, height) = part.size();
+        WorldAabb::entity_box(position.x, position.y, position.z, width / 2.0, height)
+    }
+
+    /// Returns the dragon's death animation progress.
+    #[must_use]
+    pub fn death_time(&self) -> i32 {
+        self.state.lock().death_time
+    }
+
+    fn crystals_alive(world: &World) -> Option<i32> {
+        world
+            .dragon_fight
+            .as_ref()
+            .map(|fight| fight.lock().crystals_alive())
+    }
+
+    /// Vanilla `EnderDragon.findClosestNode()`: builds the path graph on
+    /// first use and returns the node closest to the dragon.
+    fn closest_node(&self, world: &World, state: &mut DragonState, pos: DVec3) -> usize {
+        let nodes = *state.nodes.get_or_insert_with(|| {
+            std::array::from_fn(|index| {
+                let (x, y_offset, z) = node_column(index);
+                let surface = world
+                    .height_at(HeightmapType::MotionBlockingNoLeaves, x, z)
+                    .unwrap_or(world.get_min_y());
+                BlockPos::new(x, (world.sea_level + 10).max(surface + y_offset), z)
+            })
+        });
+        let has_crystals = Self::crystals_alive(world).is_some_and(|count| count > 0);
+        find_closest_node(&nodes, pos, has_crystals)
+    }
+
+    fn find_path(
+        &self,
+        world: &World,
+        state: &mut DragonState,
+        start: usize,
+        end: usize,
+        final_node: Option<BlockPos>,
+    ) -> Option<DragonPath> {
+        self.closest_node(world, state, self.position());
+        let nodes = state.nodes?;
+        let has_crystals = Self::crystals_alive(world).is_some_and(|count| count > 0);
+        find_path(&nodes, start, end, final_node, has_crystals)
+    }
+
+    fn podium_surface(world: &World, heightmap: HeightmapType) -> BlockPos {
+        let y = world
+            .height_at(heightmap, END_PODIUM_LOCATION.x(), END_PODIUM_LOCATION.z())
+            .unwrap_or(world.get_min_y());
+        BlockPos::new(END_PODIUM_LOCATION.x(), y, END_PODIUM_LOCATION.z())
+    }
+
+    fn nearest_target(&self, world: &World, pos: DVec3, range: f64) -> Option<Arc<Player>> {
+        world.nearest_player(pos, range, |player| {
+            player.is_alive() && Mob::can_attack(self, player)
+        })
+    }
+
+    /// Vanilla `EnderDragon.getHeadLookVector`.
+    fn head_look_vector(&self, world: &World, phase: EnderDragonPhase) -> DVec3 {
+        let (yaw, pitch) = self.rotation();
+        let pitch = match phase {
+            EnderDragonPhase::Landing | EnderDragonPhase::Takeoff => {
+                let podium = Self::podium_surface(world, HeightmapType::MotionBlockingNoLeaves);
+                let distance = (at_bottom_center(podium) + DVec3::new(0.0, 0.5, 0.0))
+                    .distance(self.position()) as f32;
+                -(6.0 / (distance / 4.0).max(1.0)) * 1.5 * 5.0
+            }
+            phase if phase.is_sitting() => -45.0,
+            _ => pitch,
+        };
+        self.calculate_view_vector(pitch, yaw)
+    }
+
+    /// Runs the current phase's `doServerTick`.
+    fn tick_phase(&self, world: &World, state: &mut DragonState) {
+        let phase = state.phase;
+        self.do_server_tick(world, state);
+        if state.phase != phase {
+            self.do_server_tick(world, state);
+        }
+    }
+
+    #[expect(
+        clippy::too_many_lines,
+        reason = "mirrors vanilla's per-phase doServerTick implementations"
+    )]
+    fn do_server_tick(&self, world: &World, state: &mut DragonState) {
+        let position = self.position();
+        let collided = self.horizontal_collision() || self.vertical_collision();
+        let target_distance =
+            |target: &Option<DVec3>| target.map_or(0.0, |target| target.distance_squared(position));
+
+        match state.phase {
+            EnderDragonPhase::HoldingPattern => {
+                let PhaseState::HoldingPattern { path, target } = &state.phase_state else {
+                    return;
+                };
+                let distance = target_distance(target);
+                let path_done = path.as_ref().is_some_and(DragonPath::is_done);
+                let has_path = path.as_ref().is_some_and(|path| !path.is_done());
+                if !(distance < TARGET_REACHED_SQR || distance > TARGET_LOST_SQR || collided) {
+                    return;
+                }
+
+                if path_done {
+                    let podium = Self::podium_surface(world, HeightmapType::MotionBlockingNoLeaves);
+                    let crystals = Self::crystals_alive(world).unwrap_or(0);
+                    if rand::random_range(0..crystals + 3) == 0 {
+                        self.set_phase_locked(state, EnderDragonPhase::LandingApproach);
+                        return;
+                    }
+                    let podium_center = at_bottom_center(podium) + DVec3::new(0.0, 0.5, 0.0);
+                    if let Some(player) = self.nearest_target(world, podium_center, -1.0) {
+                        let distance = podium_center.distance_squared(player.position()) / 512.0;
+                        if rand::random_range(0..(distance + 2.0) as i32) == 0
+                            || rand::random_range(0..crystals + 2) == 0
+                        {
+                            self.strafe_player(state, player.id());
+                            return;
+                        }
+                    }
+                }
+
+                if !has_path {
+                    let start = self.closest_node(world, state, position);
+                    let mut end = start;
+                    if rand::random_range(0..8) == 0 {
+                        state.holding_clockwise = !state.holding_clockwise;
+                        end = start + 6;
+                    }
+                    end = if state.holding_clockwise {
+                        end + 1
+                    } else {
+                        end.wrapping_sub(1)
+                    };
+                    let end = if world.dragon_fight.is_some() {
+                        end.wrapping_add(12) % 12
+                    } else {
+                        wrap_ring_node(end, false)
+                    };
+                    let mut new_path = self.find_path(world, state, start, end, None);
+                    if let Some(path) = &mut new_path {
+                        path.advance();
+                    }
+                    if let PhaseState::HoldingPattern { path, .. } = &mut state.phase_state {
+                        *path = new_path;
+                    }
+                }
+                if let PhaseState::HoldingPattern { path, target } = &mut state.phase_state {
+                    navigate_to_next_node(path, target);
+                }
+            }
+            EnderDragonPhase::StrafePlayer => {
+                let PhaseState::StrafePlayer {
+                    attack_target: Some(attack_target),
+                    ..
+                } = &state.phase_state
+                else {
+                    self.set_phase_locked(state, EnderDragonPhase::HoldingPattern);
+                    return;
+                };
+                let Some(attack_target) = world
+                    .get_entity_by_id(*attack_target)
+                    .filter(|target| target.is_alive())
+                else {
+                    self.set_phase_locked(state, EnderDragonPhase::HoldingPattern);
+                    return;
+                };
+                let target_pos = attack_target.position();
+
+                if let PhaseState::StrafePlayer { path, target, .. } = &mut state.phase_state
+                    && path.as_ref().is_some_and(DragonPath::is_done)
+                {
+                    let horizontal = (target_pos - position).with_y(0.0).length();
+                    let lift = (0.4 + horizontal / 80.0 - 1.0).min(10.0);
+                    *target = Some(target_pos + DVec3::new(0.0, lift, 0.0));
+                }
+
+                let PhaseState::StrafePlayer { path, target, .. } = &state.phase_state else {
+                    return;
+                };
+                let distance = target_distance(target);
+                let has_path = path.as_ref().is_some_and(|path| !path.is_done());
+                if distance < TARGET_REACHED_SQR || distance > TARGET_LOST_SQR {
+                    if !has_path {
+                        let start = self.closest_node(world, state, position);
+                        let mut end = start;
+                        if rand::random_range(0..8) == 0 {
+                            state.strafe_clockwise = !state.strafe_clockwise;
+                            end = start + 6;
+                        }
+                        end = if state.strafe_clockwise {
+                            end + 1
+                        } else {
+                            end.wrapping_sub(1)
+                        };
+                        let has_crystals = Self::crystals_alive(world).is_some_and(|n| n > 0);
+                        let end = if has_crystals {
+                            end.wrapping_add(12) % 12
+                        } else {
+                            wrap_ring_node(end, false)
+                        };
+                        let mut new_path = self.find_path(world, state, start, end, None);
+                        if let Some(path) = &mut new_path {
+                            path.advance();
+                        }
+                        if let PhaseState::StrafePlayer { path, .. } = &mut state.phase_state {
+                            *path = new_path;
+                        }
+                    }
+                    if let PhaseState::StrafePlayer { path, target, .. } = &mut state.phase_state {
+                        navigate_to_next_node(path, target);
+                    }
+                }
+
+                let in_range = attack_target.position().distance_squared(position) < 4096.0;
+                let in_sight = in_range && self.has_line_of_sight(attack_target.as_ref());
+                let (yaw, _) = self.rotation();
+                let PhaseState::StrafePlayer {
+                    path,
+                    fireball_charge,
+                    ..
+                } = &mut state.phase_state
+                else {
+                    return;
+                };
+                if !in_sight {
+                    if *fireball_charge > 0 {
+                        *fireball_charge -= 1;
+                    }
+                    return;
+                }
+                *fireball_charge += 1;
+                let to_target = (target_pos - position).with_y(0.0).normalize_or_zero();
+                let facing = DVec3::new(
+                    f64::from((yaw * PI / 180.0).sin()),
+                    0.0,
+                    f64::from(-(yaw * PI / 180.0).cos()),
+                )
+                .normalize_or_zero();
+                let angle = (facing.dot(to_target) as f32).acos().to_degrees() + 0.5;
+                if *fireball_charge >= 5 && (0.0..10.0).contains(&angle) {
+                    // TODO: Shoot a dragon fireball from the head once that
+                    // projectile exists.
+                    if let Some(path) = path {
+                        path.next = path.nodes.len();
+                    }
+                    self.set_phase_locked(state, EnderDragonPhase::HoldingPattern);
+                }
+            }
+            EnderDragonPhase::LandingApproach => {
+                let PhaseState::LandingApproach { path, target } = &state.phase_state else {
+                    return;
+                };
+                let distance = target_distance(target);
+                if !(distance < TARGET_REACHED_SQR || distance > TARGET_LOST_SQR || collided) {
+                    return;
+                }
+                if path.as_ref().is_none_or(DragonPath::is_done) {
+                    let start = self.closest_node(world, state, position);
+                    let podium = Self::podium_surface(world, HeightmapType::MotionBlockingNoLeaves);
+                    let podium_center = at_bottom_center(podium) + DVec3::new(0.0, 0.5, 0.0);
+                    let end = if let Some(player) = self.nearest_target(world, podium_center, 128.0)
+                    {
+                        let away = player.position().with_y(0.0).normalize_or_zero();
+                        self.closest_node(
+                            world,
+                            state,
+                            DVec3::new(-away.x * 40.0, 105.0, -away.z * 40.0),
+                        )
+                    } else {
+                        self.closest_node(
+                            world,
+                            state,
+                            DVec3::new(40.0, f64::from(podium.y()), 0.0),
+                        )
+                    };
+                    let mut new_path = self.find_path(world, state, start, end, Some(podium));
+                    if let Some(path) = &mut new_path {
+                        path.advance();
+                    }
+                    if let PhaseState::LandingApproach { path, .. } = &mut state.phase_state {
+                        *path = new_path;
+                    }
+                }
+                let PhaseState::LandingApproach { path, target } = &mut state.phase_state else {
+                    return;
+                };
+                navigate_to_next_node(path, target);
+                if path.as_ref().is_some_and(DragonPath::is_done) {
+                    self.set_phase_locked(state, EnderDragonPhase::Landing);
+                }
+            }
+            EnderDragonPhase::Landing => {
+                let PhaseState::Landing { target } = &mut state.phase_state else {
+                    return;
+                };
+                let landing = *target.get_or_insert_with(|| {
+                    at_bottom_center(Self::podium_surface(
+                        world,
+                        HeightmapType::MotionBlockingNoLeaves,
+                    ))
+                });
+                if landing.distance_squared(position) < 1.0 {
+                    state.flame_count = 0;
+                    self.set_phase_locked(state, EnderDragonPhase::SittingScanning);
+                }
+            }
+            EnderDragonPhase::Takeoff => {
+                let PhaseState::Takeoff {
+                    first_tick, path, ..
+                } = &mut state.phase_state
+                else {
+                    return;
+                };
+                if !*first_tick && path.is_some() {
+                    let podium = Self::podium_surface(world, HeightmapType::MotionBlockingNoLeaves);
+                    let podium_center = at_bottom_center(podium) + DVec3::new(0.0, 0.5, 0.0);
+                    if podium_center.distance_squared(position) >= 100.0 {
+                        self.set_phase_locked(state, EnderDragonPhase::HoldingPattern);
+                    }
+                    return;
+                }
+                *first_tick = false;
+
+                let start = self.closest_node(world, state, position);
+                let look = self.head_look_vector(world, state.phase);
+                let end = self.closest_node(
+                    world,
+                    state,
+                    DVec3::new(-look.x * 40.0, 105.0, -look.z * 40.0),
+                );
+                let has_crystals = Self::crystals_alive(world).is_some_and(|count| count > 0);
+                let end = if has_crystals {
+                    end % 12
+                } else {
+                    wrap_ring_node(end, false)
+                };
+                let new_path = self.find_path(world, state, start, end, None);
+                let PhaseState::Takeoff { path, target, .. } = &mut state.phase_state else {
+                    return;
+                };
+                *path = new_path;
+                if let Some(current) = path {
+                    current.advance();
+                }
+                navigate_to_next_node(path, target);
+            }
+            EnderDragonPhase::SittingFlaming => {
+                let PhaseState::SittingFlaming { flame_ticks } = &mut state.phase_state else {
+                    return;
+                };
+                *flame_ticks += 1;
+                if *flame_ticks >= 200 {
+                    let next = if state.flame_count >= 4 {
+                        EnderDragonPhase::Takeoff
+                    } else {
+                        EnderDragonPhase::SittingScanning
+                    };
+                    self.set_phase_locked(state, next);
+                } else if *flame_ticks == 10 {
+                    // TODO: Spawn the dragon breath area effect cloud under
+                    // the head once area effect clouds exist.
+                }
+            }
+            EnderDragonPhase::SittingScanning => {
+                let PhaseState::SittingScanning { scanning_time } = &mut state.phase_state else {
+                    return;
+                };
+                *scanning_time += 1;
+                let scanning_time = *scanning_time;
+                let dragon_y = position.y;
+                let nearby = world.nearest_player(position, 20.0, |player| {
+                    player.is_alive()
+                        && Mob::can_attack(self, player)
+                        && (player.position().y - dragon_y).abs() <= 10.0
+                });
+                if let Some(target) = nearby {
+                    if scanning_time > 25 {
+                        self.set_phase_locked(state, EnderDragonPhase::SittingAttacking);
+                        return;
+                    }
+                    let (yaw, pitch) = self.rotation();
+                    let to_target = (target.position() - position)
+                        .with_y(0.0)
+                        .normalize_or_zero();
+                    let facing = DVec3::new(
+                        f64::from((yaw * PI / 180.0).sin()),
+                        0.0,
+                        f64::from(-(yaw * PI / 180.0).cos()),
+                    )
+                    .normalize_or_zero();
+                    let angle = (facing.dot(to_target) as f32).acos().to_degrees() + 0.5;
+                    if !(0.0..=10.0).contains(&angle) {
+                        let head = state.parts[EnderDragonPart::Head as usize];
+                        let dx = target.position().x - head.x;
+                        let dz = target.position().z - head.z;
+                        let turn =
+                            f64::from(wrap_degrees(180.0 - dx.atan2(dz).to_degrees() as f32 - yaw))
+                                .clamp(-100.0, 100.0);
+                        state.y_rot_a *= 0.8;
+                        let distance = (dx * dx + dz * dz).sqrt() as f32 + 1.0;
+                        state.y_rot_a += turn as f32 * (0.7 / distance.min(40.0) / distance);
+                        self.set_rotation((yaw + state.y_rot_a, pitch));
+                    }
+                } else if scanning_time >= 100 {
+                    self.set_phase_locked(state, EnderDragonPhase::Takeoff);
+                    if let Some(target) = self.nearest_target(world, position, 150.0) {
+                        self.set_phase_locked(state, EnderDragonPhase::ChargingPlayer);
+                        if let PhaseState::ChargingPlayer { target: charge, .. } =
+                            &mut state.phase_state
+                        {
+                            *charge = Some(target.position());
+                        }
+                    }
+                }
+            }
+            EnderDragonPhase::SittingAttacking => {
+                let PhaseState::SittingAttacking { attacking_ticks } = &mut state.phase_state
+                else {
+                    return;
+                };
+                *attacking_ticks += 1;
+                if *attacking_ticks > 40 {
+                    self.set_phase_locked(state, EnderDragonPhase::SittingFlaming);
+                }
+            }
+            EnderDragonPhase::ChargingPlayer => {
+                let PhaseState::ChargingPlayer {
+                    target,
+                    time_since_charge,
+                } = &mut state.phase_state
+                else {
+                    return;
+                };
+                let Some(charge) = *target else {
+                    self.set_phase_locked(state, EnderDragonPhase::HoldingPattern);
+                    return;
+                };
+                if *time_since_charge > 0 {
+                    *time_since_charge += 1;
+                    if *time_since_charge > 10 {
+                        self.set_phase_locked(state, EnderDragonPhase::HoldingPattern);
+                        return;
+                    }
+                }
+                let distance = charge.distance_squared(position);
+                if distance < TARGET_REACHED_SQR || distance > TARGET_LOST_SQR || collided {
+                    *time_since_charge += 1;
+                }
+            }
+            EnderDragonPhase::Dying => {
+                let PhaseState::Dying { target } = &mut state.phase_state else {
+                    return;
+                };
+                let grave = *target.get_or_insert_with(|| {
+                    at_bottom_center(Self::podium_surface(world, HeightmapType::MotionBlocking))
+                });
+                let distance = grave.distance_squared(position);
+                let health =
+                    if (TARGET_REACHED_SQR..=TARGET_LOST_SQR).contains(&distance) && !collided {
+                        1.0
+                    } else {
+                        0.0
+                    };
+                self.set_health(health);
+            }
+            EnderDragonPhase::Hovering => {}
+        }
+    }
+
+    fn strafe_player(&self, state: &mut DragonState, player_id: i32) {
+        self.set_phase_locked(state, EnderDragonPhase::StrafePlayer);
+        if let PhaseState::StrafePlayer { attack_target, .. } = &mut state.phase_state {
+            *attack_target = Some(player_id);
+        }
+    }
+
+    /// Vanilla `AbstractDragonPhaseInstance.getTurnSpeed` and the landing
+    /// override.
+    fn turn_speed(&self, phase: EnderDragonPhase) -> f32 {
+        let speed = self.velocity().with_y(0.0).length() as f32 + 1.0;
+        let capped = speed.min(40.0);
+        if phase == EnderDragonPhase::Landing {
+            capped / speed
+        } else {
+            0.7 / capped / speed
+        }
+    }
+
+    /// Vanilla `EnderDragon.checkCrystals`.
+    fn check_crystals(&self, world: &World, state: &mut DragonState) {
+        if let Some(crystal) = state.nearest_crystal {
+            if world
+                .get_entity_by_id(crystal)
+                .is_none_or(|crystal| crystal.is_removed())
+            {
+                state.nearest_crystal = None;
+            } else if self.tick_count() % 10 == 0 && self.get_health() < self.get_max_health() {
+                self.set_health(self.get_health() + 1.0);
+            }
+        }
+
+        if rand::random_range(0..10) == 0 {
+            let position = self.position();
+            state.nearest_crystal = world
+                .get_entities_in_aabb_matching(&self.bounding_box().inflate(32.0), |entity| {
+                    entity.is::<EndCrystalEntity>()
+                })
+                .into_iter()
+                .min_by(|a, b| {
+                    a.position()
+                        .distance_squared(position)
+                        .total_cmp(&b.position().distance_squared(position))
+                })
+                .map(|crystal| crystal.id());
+        }
+    }
+
+    /// Vanilla `EnderDragon.getLatencyPos` at partial tick 1.
+    fn latency_pos(state: &DragonState, delay: i32) -> [f64; 2] {
+        state.positions[((state.pos_pointer - delay - 1) & 63) as usize]
+    }
+
+    /// Moves the dragon toward its phase target, vanilla `EnderDragon.aiStep`.
+    fn dragon_ai_step(&self, world: &Arc<World>) -> Option<MoveResult> {
+        let mut move_result = None;
+        let mut state = self.state.lock();
+        self.check_crystals(world, &mut state);
+        let (yaw, pitch) = self.rotation();
+        self.set_rotation((wrap_degrees(yaw), pitch));
+
+        if !self.is_no_ai() {
+            if state.pos_pointer < 0 {
+                let sample = [f64::from(self.rotation().0), self.position().y];
+                state.positions = [sample; 64];
+            }
+            state.pos_pointer = (state.pos_pointer + 1) % 64;
+            let pointer = state.pos_pointer as usize;
+            state.positions[pointer] = [f64::from(self.rotation().0), self.position().y];
+
+            self.tick_phase(world, &mut state);
+            if let Some(target) = state.phase_state.fly_target() {
+                move_result = self.fly_towards(&mut state, target);
+            }
+        }
+
+        let yaw = self.rotation().0;
+        self.set_y_body_rot(yaw);
+        self.update_parts(&mut state);
+        let phase = state.phase;
+        drop(state);
+
+        let body = self.part_bounding_box(EnderDragonPart::Body);
+        for wing in [EnderDragonPart::Wing1, EnderDragonPart::Wing2] {
+            let area = self
+                .part_bounding_box(wing)
+                .inflate_xyz(4.0, 2.0, 4.0)
+                .translate(DVec3::new(0.0, -2.0, 0.0));
+            self.knock_back(world, &area, &body, phase);
+        }
+        for part in [EnderDragonPart::Head, EnderDragonPart::Neck] {
+            self.hurt_entities(world, &self.part_bounding_box(part).inflate(1.0));
+        }
+
+        let in_wall = self.check_walls(world, &self.part_bounding_box(EnderDragonPart::Head))
+            | self.check_walls(world, &self.part_bounding_box(EnderDragonPart::Neck))
+            | self.check_walls(world, &self.part_bounding_box(EnderDragonPart::Body));
+        self.state.lock().in_wall = in_wall;
+        if let Some(fight) = &world.dragon_fight {
+            fight.lock().update_dragon(self);
+        }
+        move_result
+    }
+
+    fn fly_towards(&self, state: &mut DragonState, target: DVec3) -> Option<MoveResult> {
+        let position = self.position();
+        let delta = target - position;
+        let distance_sqr = delta.length_squared();
+        let max_speed = f64::from(state.phase.fly_speed());
+        let horizontal = delta.with_y(0.0).length();
+        let dy = if horizontal > 0.0 {
+            (delta.y / horizontal).clamp(-max_speed, max_speed)
+        } else {
+            delta.y
+        };
+        self.set_velocity(self.velocity() + DVec3::new(0.0, dy * 0.01, 0.0));
+        let (yaw, pitch) = self.rotation();
+        let yaw = wrap_degrees(yaw);
+
+        let direction = delta.normalize_or_zero();
+        let facing = DVec3::new(
+            f64::from((yaw * PI / 180.0).sin()),
+            self.velocity().y,
+            f64::from(-(yaw * PI / 180.0).cos()),
+        )
+        .normalize_or_zero();
+        let alignment = ((facing.dot(direction) as f32 + 0.5) / 1.5).max(0.0);
+        let mut new_yaw = yaw;
+        if delta.x.abs() > 1.0E-5 || delta.z.abs() > 1.0E-5 {
+            let turn = wrap_degrees(180.0 - delta.x.atan2(delta.z).to_degrees() as f32 - yaw)
+                .clamp(-50.0, 50.0);
+            state.y_rot_a = state.y_rot_a * 0.8 + turn * self.turn_speed(state.phase);
+            new_yaw += state.y_rot_a * 0.1;
+        }
+        self.set_rotation((new_yaw, pitch));
+
+        let closeness = (2.0 / (distance_sqr + 1.0)) as f32;
+        self.move_relative(
+            0.06 * (alignment * closeness + (1.0 - closeness)),
+            DVec3::new(0.0, 0.0, -1.0),
+        );
+        let velocity = self.velocity();
+        let step = if state.in_wall {
+            velocity * 0.8
+        } else {
+            velocity
+        };
+        let result = self.move_entity(MoverType::SelfMovement, step);
+        let moved = self.velocity().normalize_or_zero();
+        let drag = 0.8 + 0.15 * (moved.dot(facing) + 1.0) / 2.0;
+        self.set_velocity(self.velocity() * DVec3::new(drag, 0.91, drag));
+        result
+    }
+
+    /// Places every part relative to the body, vanilla `EnderDragon.tickPart`
+    /// calls in `aiStep`.
+    fn update_parts(&self, state: &mut DragonState) {
+        let position = self.position();
+        let tilt = ((Self::latency_pos(state, 5)[1] - Self::latency_pos(state, 10)[1]) * 10.0)
+            .to_radians() as f32;
+        let (tilt_sin, tilt_cos) = tilt.sin_cos();
+        let yaw = self.rotation().0.to_radians();
+        let (yaw_sin, yaw_cos) = yaw.sin_cos();
+        let place = |x: f32, y: f64, z: f32| position + DVec3::new(f64::from(x), y, f64::from(z));
+
+        state.parts[EnderDragonPart::Body as usize] = place(yaw_sin * 0.5, 0.0, -yaw_cos * 0.5);
+        state.parts[EnderDragonPart::Wing1 as usize] = place(yaw_cos * 4.5, 2.0, yaw_sin * 4.5);
+        state.parts[EnderDragonPart::Wing2 as usize] = place(yaw_cos * -4.5, 2.0, yaw_sin * -4.5);
+
+        let head_yaw = yaw - state.y_rot_a * 0.01;
+        let (head_sin, head_cos) = head_yaw.sin_cos();
+        let head_y = if state.phase.is_sitting() {
+            -1.0
+        } else {
+            Self::latency_pos(state, 5)[1] - Self::latency_pos(state, 0)[1]
+        };
+        state.parts[EnderDragonPart::Head as usize] = place(
+            head_sin * 6.5 * tilt_cos,
+            head_y + f64::from(tilt_sin * 6.5),
+            -head_cos * 6.5 * tilt_cos,
+        );
+        state.parts[EnderDragonPart::Neck as usize] = place(
+            head_sin * 5.5 * tilt_cos,
+            head_y + f64::from(tilt_sin * 5.5),
+            -head_cos * 5.5 * tilt_cos,
+        );
+
+        let base = Self::latency_pos(state, 5);
+        for (index, part) in [
+            EnderDragonPart::Tail1,
+            EnderDragonPart::Tail2,
+            EnderDragonPart::Tail3,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let sample = Self::latency_pos(state, 12 + index as i32 * 2);
+            let tail_yaw = yaw + wrap_degrees((sample[0] - base[0]) as f32).to_radians();
+            let (tail_sin, tail_cos) = tail_yaw.sin_cos();
+            let length = (index + 1) as f32 * 2.0;
+            state.parts[part as usize] = place(
+                -(yaw_sin * 1.5 + tail_sin * length) * tilt_cos,
+                sample[1] - base[1] - f64::from((length + 1.5) * tilt_sin) + 1.5,
+                (yaw_cos * 1.5 + tail_cos * length) * tilt_cos,
+            );
+        }
+    }
+
+    fn melee_source(&self) -> DamageSource {
+        DamageSource::environment(&vanilla_damage_types::MOB_ATTACK)
+            .with_causing_entity(self.id())
+            .with_direct_entity(self.id())
+            .with_source_position(self.position())
+    }
+
+    fn is_melee_target(entity: &dyn Entity) -> bool {
+        entity.as_living_entity().is_some_and(|living| {
+            living
+                .as_player()
+                .is_none_or(|player| !living.is_spectator() && !player.has_infinite_materials())
+        })
+    }
+
+    /// Vanilla `EnderDragon.knockBack`: wings push away everything nearby.
+    fn knock_back(
+        &self,
+        world: &Arc<World>,
+        area: &WorldAabb,
+        body: &WorldAabb,
+        phase: EnderDragonPhase,
+    ) {
+        let center = body.center();
+        let own_id = self.id();
+        for entity in world.get_entities_in_aabb_matching(area, |entity| {
+            entity.id() != own_id && Self::is_melee_target(entity)
+        }) {
+            let Some(living) = entity.as_living_entity() else {
+                continue;
+            };
+            let dx = entity.position().x - center.x;
+            let dz = entity.position().z - center.z;
+            let distance = (dx * dx + dz * dz).max(0.1);
+            entity.push_impulse(DVec3::new(dx / distance * 4.0, 0.2, dz / distance * 4.0));
+            if !phase.is_sitting() && living.last_hurt_by_mob_timestamp() < entity.tick_count() - 2
+            {
+                entity.hurt(world, &self.melee_source(), 5.0);
+            }
+        }
+    }
+
+    /// Vanilla `EnderDragon.hurt(List)`: the head and neck bite.
+    fn hurt_entities(&self, world: &Arc<World>, area: &WorldAabb) {
+        let own_id = self.id();
+        for entity in world.get_entities_in_aabb_matching(area, |entity| {
+            entity.id() != own_id && Self::is_melee_target(entity)
+        }) {
+            entity.hurt(world, &self.melee_source(), 10.0);
+        }
+    }
+
+    /// Vanilla `EnderDragon.checkWalls`: breaks blocks in `area` when mob
+    /// griefing is on, and reports whether anything solid was left.
+    fn check_walls(&self, world: &Arc<World>, area: &WorldAabb) -> bool {
+        let min = BlockPos::new(
+            area.min_x().floor() as i32,
+            area.min_y().floor() as i32,
+            area.min_z().floor() as i32,
+        );
+        let max = BlockPos::new(
+            area.max_x().floor() as i32,
+            area.max_y().floor() as i32,
+            area.max_z().floor() as i32,
+        );
+        let griefing = world.get_game_rule(&MOB_GRIEFING);
+        let mut in_wall = false;
+        let mut destroyed = false;
+        for x in min.x()..=max.x() {
+            for y in min.y()..=max.y() {
+                for z in min.z()..=max.z() {
+                    let pos = BlockPos::new(x, y, z);
+                    let state = world.get_block_state(pos);
+                    let block = state.get_block();
+                    if state.is_air() || block.has_tag(&BlockTag::DRAGON_TRANSPARENT) {
+                        continue;
+                    }
+                    if griefing && !block.has_tag(&BlockTag::DRAGON_IMMUNE) {
+                        destroyed = world.remove_block(pos) || destroyed;
+                    } else {
+                        in_wall = true;
+                    }
+                }
+            }
+        }
+
+        if destroyed {
+            let pos = BlockPos::new(
+                rand::random_range(min.x()..=max.x()),
+                rand::random_range(min.y()..=max.y()),
+                rand::random_range(min.z()..=max.z()),
+            );
+            world.level_event(level_events::PARTICLES_DRAGON_BLOCK_BREAK, pos, 0, None);
+        }
+        in_wall
+    }
+
+    /// Applies damage to one part, vanilla `EnderDragon.hurt(level, part, ...)`.
+    fn hurt_part(
+        &self,
+        world: &World,
+        part: EnderDragonPart,
+        source: &DamageSource,
+        mut amount: f32,
+    ) -> bool {
+        let phase = self.phase();
+        if phase == EnderDragonPhase::Dying {
+            return false;
+        }
+        // TODO: Sitting dragons deflect arrows and set them on fire once arrow
+        // entities exist.
+        if part != EnderDragonPart::Head {
+            amount = amount / 4.0 + amount.min(1.0);
+        }
+        if amount < 0.01 {
+            return false;
+        }
+
+        let by_player = source
+            .causing_entity_id
+            .is_some_and(|id| world.players.get_by_entity_id(id).is_some());
+        if !by_player && !source.is(&DamageTypeTag::ALWAYS_HURTS_ENDER_DRAGONS) {
+            return false;
+        }
+
+        let health_before = self.get_health();
+        LivingEntity::hurt_server(self, world, source, amount);
+        let mut state = self.state.lock();
+        if self.is_dead_or_dying() && !state.phase.is_sitting() {
+            self.set_health(1.0);
+            self.set_phase_locked(&mut state, EnderDragonPhase::Dying);
+        }
+        if state.phase.is_sitting() {
+            state.sitting_damage_received =
+                (state.sitting_damage_received as f32 + health_before - self.get_health()) as i32;
+            if state.sitting_damage_received as f32 > 0.25 * self.get_max_health() {
+                state.sitting_damage_received = 0;
+                self.set_phase_locked(&mut state, EnderDragonPhase::Takeoff);
+            }
+        }
+        true
+    }
+
+    /// Reacts to a destroyed end crystal, vanilla
+    /// `EnderDragon.onCrystalDestroyed`.
+    pub fn on_crystal_destroyed(
+        &self,
+        world: &World,
+        crystal_id: i32,
+        crystal_pos: BlockPos,
+        source: &DamageSource,
+    ) {
+        let player = source
+            .causing_entity_id
+            .and_then(|id| world.players.get_by_entity_id(id))
+            .or_else(|| {
+                let (x, y, z) = crystal_pos.get_center();
+                self.nearest_target(world, DVec3::new(x, y - 0.5, z), 64.0)
+            });
+
+        if self.state.lock().nearest_crystal == Some(crystal_id) {
+            let mut explosion = DamageSource::environment(&vanilla_damage_types::EXPLOSION)
+                .with_direct_entity(crystal_id);
+            if let Some(player) = &player {
+                explosion = explosion.with_causing_entity(player.id());
+            }
+            self.hurt_part(world, EnderDragonPart::Head, &explosion, 10.0);
+        }
+
+        let mut state = self.state.lock();
+        if state.phase == EnderDragonPhase::HoldingPattern
+            && let Some(player) = player
+            && Mob::can_attack(self, player.as_ref())
+        {
+            self.strafe_player(&mut state, player.id());
+        }
+    }
+
+    /// Runs vanilla `EnderDragon.tickDeath`.
+    fn tick_dragon_death(&self) {
+        let Some(world) = self.level() else {
+            return;
+        };
+        if let Some(fight) = &world.dragon_fight {
+            fight.lock().update_dragon(self);
+        }
+        let death_time = {
+            let mut state = self.state.lock();
+            state.death_time += 1;
+            state.death_time
+        };
+
+        let drops = world.get_game_rule(&MOB_DROPS);
+        let reward = if world
+            .dragon_fight
+            .as_ref()
+            .is_some_and(|fight| !fight.lock().has_previously_killed_dragon())
+        {
+            FIRST_DRAGON_XP
+        } else {
+            RESPAWNED_DRAGON_XP
+        };
+        if death_time > 150 && death_time % 5 == 0 && drops {
+            ExperienceOrbEntity::award(
+                &world,
+                self.position(),
+                (reward as f32 * 0.08).floor() as i32,
+            );
+        }
+        if death_time == 1 && !self.is_silent() {
+            world.global_level_event(level_events::SOUND_DRAGON_DEATH, self.block_position(), 0);
+        }
+
+        self.move_entity(MoverType::SelfMovement, DVec3::new(0.0, 0.1, 0.0));
+        if death_time == DEATH_ANIMATION_TICKS {
+            if drops {
+                ExperienceOrbEntity::award(
+                    &world,
+                    self.position(),
+                    (reward as f32 * 0.2).floor() as i32,
+                );
+            }
+            if let Some(fight) = &world.dragon_fight {
+                fight.lock().set_dragon_killed(&world, self);
+            }
+            self.set_removed(RemovalReason::Killed);
+            self.game_event(&vanilla_game_events::ENTITY_DIE);
+        }
+    }
+
+    fn update_dirty_mob_effect_entity_data(&self) {
+        if !self.living_base.take_effects_dirty() {
+            return;
+        }
+
+        let display = self.living_base.mob_effect_display_state();
+
+        {
+            let mut entity_data = self.entity_data.lock();
+            let living = entity_data.living_entity_mut();
+            living.effect_particles.set(display.particles);
+            living.effect_ambience.set(display.ambient);
+        }
+
+        self.entity_data.set_base_invisible_flag(display.invisible);
+        self.entity_data
+            .set_base_glowing_flag(self.has_glowing_tag() || display.glowing);
+    }
+}
+
+#[entity_impl(class(mob))]
+impl Entity for EnderDragonEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn tick(&self) {
+        self.default_tick();
+        self.living_base.decrement_invulnerable_time();
+        self.tick_mob_effects();
+
+        if self.is_dead_or_dying() {
+            LivingEntity::tick_death(self);
+            self.tick_living_state();
+            return;
+        }
+
+        if !self.is_removed() {
+            LivingEntity::ai_step(self);
+        }
+
+        self.tick_living_state();
+    }
+
+    /// The dragon fight owns the dragon, so it never despawns.
+    fn check_despawn(&self) {}
+
+    fn is_alive(&self) -> bool {
+        !self.is_removed() && self.get_health() > 0.0
+    }
+
+    /// Players hit the parts, not the dragon itself.
+    fn is_pickable(&self) -> bool {
+        false
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn update_data_before_sync(&self) {
+        self.update_dirty_mob_effect_entity_data();
+    }
+
+    fn pack_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().syncable_snapshots()
+    }
+
+    fn drain_dirty_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().drain_dirty_sync()
+    }
+
+    fn drain_dirty_mob_effects(&self) -> Vec<MobEffectSyncChange> {
+        self.living_base.drain_dirty_mob_effects()
+    }
+
+    fn pack_all_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.pack_living_equipment()
+    }
+
+    fn drain_dirty_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.drain_dirty_living_equipment()
+    }
+
+    fn sound_source(&self) -> SoundSource {
+        SoundSource::Hostile
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        let part = self
+            .state
+            .lock()
+            .struck_part
+            .take()
+            .unwrap_or(EnderDragonPart::Body);
+        self.hurt_part(world, part, source, amount)
+    }
+
+    fn kill(&self, world: &World) {
+        self.set_removed(RemovalReason::Killed);
+        self.game_event(&vanilla_game_events::ENTITY_DIE);
+        if let Some(fight) = &world.dragon_fight
+            && let Some(world) = self.level()
+        {
+            let mut fight = fight.lock();
+            fight.update_dragon(self);
+            fight.set_dragon_killed(&world, self);
+        }
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        self.save_mob(nbt);
+        let state = self.state.lock();
+        nbt.insert("DragonPhase", state.phase.id());
+        nbt.insert("DragonDeathTime", state.death_time);
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        self.load_mob(nbt);
+        if let Some(phase) = nbt.int("DragonPhase") {
+            self.set_phase(EnderDragonPhase::by_id(phase));
+        }
+        if let Some(death_time) = nbt.int("DragonDeathTime") {
+            self.state.lock().death_time = death_time;
+        }
+    }
+}
+
+impl LivingEntity for EnderDragonEntity {
+    fn living_base(&self) -> &LivingEntityBase {
+        &self.living_base
+    }
+
+    fn get_health(&self) -> f32 {
+        *self.entity_data.lock().living_entity().health.get()
+    }
+
+    fn set_health(&self, health: f32) {
+        let max_health = self.get_max_health();
+        let clamped = health.clamp(0.0, max_health);
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .health
+            .set(clamped);
+    }
+
+    fn sound_volume(&self) -> f32 {
+        5.0
+    }
+
+    fn hurt_sound(&self, _source: &DamageSource) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_ENDER_DRAGON_HURT)
+    }
+
+    /// Experience drops over the death animation instead.
+    fn should_drop_experience(&self) -> bool {
+        false
+    }
+
+    fn tick_death(&self) {
+        self.tick_dragon_death();
+    }
+
+    fn ai_step(&self) -> Option<MoveResult> {
+        let world = self.level()?;
+        self.dragon_ai_step(&world)
+    }
+}
+
+impl Mob for EnderDragonEntity {
+    fn mob_base(&self) -> &MobBase {
+        &self.mob_base
+    }
+
+    fn mob_flags(&self) -> i8 {
+        *self.entity_data.lock().mob().mob_flags.get()
+    }
+
+    fn set_mob_flags(&self, flags: i8) {
+        self.entity_data.lock().mob_mut().mob_flags.set(flags);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::vanilla_entities;
+
+    use super::*;
+
+    fn flat_nodes() -> [BlockPos; 24] {
+        std::array::from_fn(|index| {
+            let (x, y, z) = node_column(index);
+            BlockPos::new(x, 70 + y, z)
+        })
+    }
+
+    #[test]
+    fn dragon_reserves_ids_for_its_parts() {
+        init_test_registry();
+        let first =
+            EnderDragonEntity::new(&vanilla_entities::ENDER_DRAGON, 0, DVec3::ZERO, Weak::new());
+        let second =
+            EnderDragonEntity::new(&vanilla_entities::ENDER_DRAGON, 0, DVec3::ZERO, Weak::new());
+
+        assert!(second.id() - first.id() >= 9);
+        assert_eq!(first.phase(), EnderDragonPhase::HoldingPattern);
+        assert_eq!(
+            EnderDragonPart::from_id_offset(1),
+            Some(EnderDragonPart::Head)
+        );
+        assert_eq!(EnderDragonPart::from_id_offset(9), None);
+    }
+
+    #[test]
+    fn path_graph_matches_vanilla_layout() {
+        let nodes = flat_nodes();
+
+        assert_eq!((nodes[0].x(), nodes[0].z()), (60, 0));
+        assert_eq!((nodes[12].x(), nodes[12].y()), (40, 85));
+        assert_eq!((nodes[20].x(), nodes[20].z()), (20, 0));
+    }
+
+    #[test]
+    fn path_follows_adjacent_nodes() {
+        let nodes = flat_nodes();
+        let path = find_path(&nodes, 0, 3, None, true).expect("path exists");
+
+        assert_eq!(path.nodes.first(), Some(&nodes[0]));
+        assert_eq!(path.nodes.last(), Some(&nodes[3]));
+        for pair in path.nodes.windows(2) {
+            let from = nodes
+                .iter()
+                .position(|node| *node == pair[0])
+                .expect("node");
+            let to = nodes
+                .iter()
+                .position(|node| *node == pair[1])
+                .expect("node");
+            assert_ne!(NODE_ADJACENCY[from] & (1 << to), 0);
+        }
+    }
+
+    #[test]
+    fn closest_node_skips_outer_ring_without_crystals() {
+        let nodes = flat_nodes();
+
+        assert_eq!(
+            find_closest_node(&nodes, DVec3::new(60.0, 75.0, 0.0), true),
+            0
+        );
+        assert!(find_closest_node(&nodes, DVec3::new(60.0, 75.0, 0.0), false) >= 12);
+    }
+}
@@ -0,0 +1,217 @@
+//! Shared vanilla `BlockAttachedEntity` and `HangingEntity` helpers for item
+//! frames and paintings.
+
+use glam::DVec3;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::game_events::GameEventRef;
+use steel_registry::vanilla_game_rules::MOB_GRIEFING;
+use steel_registry::{vanilla_blocks, vanilla_damage_types};
+use steel_utils::{BlockPos, BlockStateId, Direction, WorldAabb};
+
+use crate::entity::Entity;
+use crate::entity::damage::DamageSource;
+use crate::world::World;
+use crate::world::game_event_context::GameEventContext;
+
+/// Ticks between vanilla `BlockAttachedEntity` survival checks.
+pub(crate) const SURVIVAL_CHECK_INTERVAL: i32 = 100;
+
+/// Distance from a block center to the wall a hanging entity is attached to.
+pub(crate) const WALL_OFFSET: f64 = 0.46875;
+
+/// Thickness of item frames and paintings along their facing axis.
+pub(crate) const HANGING_DEPTH: f64 = 0.0625;
+
+/// Returns vanilla `DiodeBlock.isDiode`.
+pub(crate) fn is_diode(state: BlockStateId) -> bool {
+    let block = state.get_block();
+    block == &vanilla_blocks::REPEATER || block == &vanilla_blocks::COMPARATOR
+}
+
+/// Returns the point on the wall behind `block_pos` for a hanging entity facing `direction`.
+pub(crate) fn attached_to_wall(block_pos: BlockPos, direction: Direction) -> DVec3 {
+    let off = direction.offset_vec().as_dvec3() * WALL_OFFSET;
+    block_pos.0.as_dvec3() + DVec3::splat(0.5) - off
+}
+
+/// Returns vanilla `HangingEntity.canCoexist`.
+///
+/// Other hanging entities on the same face block placement unless
+/// `allow_intersecting_same_side` is set, as it is for item frames.
+pub(crate) fn can_coexist(
+    entity: &dyn Entity,
+    direction: Direction,
+    allow_intersecting_same_side: bool,
+) -> bool {
+    if allow_intersecting_same_side {
+        return true;
+    }
+    let Some(world) = entity.level() else {
+        return false;
+    };
+    world
+        .get_entities_in_aabb_matching(&entity.bounding_box(), |other| {
+            other.id() != entity.id() && other.hanging_direction() == Some(direction)
+        })
+        .is_empty()
+}
+
+/// Returns whether every block behind `bounding_box` can hold a hanging entity.
+///
+/// Vanilla equivalent: the support scan in `HangingEntity.survives`.
+pub(crate) fn has_support(world: &World, bounding_box: WorldAabb, direction: Direction) -> bool {
+    let step = direction.offset_vec().as_dvec3() * -0.5;
+    let support = bounding_box.translate(step).deflate(1.0E-7);
+    let min = BlockPos::new(
+        support.min_x().floor() as i32,
+        support.min_y().floor() as i32,
+        support.min_z().floor() as i32,
+    );
+    let max = BlockPos::new(
+        support.max_x().floor() as i32,
+        support.max_y().floor() as i32,
+        support.max_z().floor() as i32,
+    );
+    for x in min.x()..=max.x() {
+        for y in min.y()..=max.y() {
+            for z in min.z()..=max.z() {
+                let state = world.get_block_state(BlockPos::new(x, y, z));
+                if !state.is_solid() && !is_diode(state) {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+/// Returns vanilla `Entity.isInvulnerableToBase` for non-living decorations.
+pub(crate) fn is_invulnerable_to_base(entity: &dyn Entity, source: &DamageSource) -> bool {
+    entity.is_removed() || entity.is_invulnerable() && !source.bypasses_invulnerability()
+}
+
+/// Returns whether the `mobGriefing` rule stops damage caused by a mob.
+pub(crate) fn is_mob_griefing_blocked(world: &World, source: &DamageSource) -> bool {
+    !world.get_game_rule(&MOB_GRIEFING)
+        && source
+            .causing_entity_id
+            .and_then(|id| world.get_entity_by_id(id))
+            .is_some_and(|entity| entity.as_mob().is_some())
+}
+
+/// Returns whether `source` was caused by a player in instant-build mode.
+///
+/// Vanilla equivalent: `DamageSource.isCreativePlayer`.
+pub(crate) fn is_creative_player_source(world: &World, source: &DamageSource) -> bool {
+    source
+        .causing_entity_id
+        .and_then(|id| world.get_entity_by_id(id))
+        .is_some_and(|entity| {
+            entity
+                .as_player()
+                .is_some_and(|player| player.abilities.lock().instabuild)
+        })
+}
+
+/// Returns whether `source` was caused by a player with infinite materials.
+///
+/// Vanilla equivalent: the `causedBy instanceof Player player && player.hasInfiniteMaterials()`
+/// check in `dropItem`.
+pub(crate) fn is_caused_by_infinite_materials_player(
+    world: &World,
+    source: Option<&DamageSource>,
+) -> bool {
+    source
+        .and_then(|source| source.causing_entity_id)
+        .and_then(|id| world.get_entity_by_id(id))
+        .is_some_and(|entity| {
+            entity
+                .as_player()
+                .is_some_and(|player| player.has_infinite_materials())
+        })
+}
+
+/// Returns the vanilla `Direction.get3DDataValue`.
+pub(crate) const fn direction_3d_data_value(direction: Direction) -> i32 {
+    match direction {
+        Direction::Down => 0,
+        Direction::Up => 1,
+        Direction::North => 2,
+        Direction::South => 3,
+        Direction::West => 4,
+        Direction::East => 5,
+    }
+}
+
+/// Returns vanilla `Direction.from3DDataValue` without wrapping.
+pub(crate) const fn direction_from_3d_data_value(value: i32) -> Option<Direction> {
+    match value {
+        0 => Some(Direction::Down),
+        1 => Some(Direction::Up),
+        2 => Some(Direction::North),
+        3 => Some(Direction::South),
+        4 => Some(Direction::West),
+        5 => Some(Direction::East),
+        _ => None,
+    }
+}
+
+/// Returns the vanilla `Direction.get2DDataValue`.
+pub(crate) const fn direction_2d_data_value(direction: Direction) -> u8 {
+    match direction {
+        Direction::South | Direction::Down | Direction::Up => 0,
+        Direction::West => 1,
+        Direction::North => 2,
+        Direction::East => 3,
+    }
+}
+
+/// Returns vanilla `Direction.from2DDataValue` without wrapping.
+pub(crate) const fn direction_from_2d_data_value(value: i32) -> Option<Direction> {
+    match value {
+        0 => Some(Direction::South),
+        1 => Some(Direction::West),
+        2 => Some(Direction::North),
+        3 => Some(Direction::East),
+        _ => None,
+    }
+}
+
+/// Routes player attacks through `hurt`, mirroring vanilla
+/// `BlockAttachedEntity.skipAttackInteraction`.
+pub(crate) fn skip_attack_interaction(
+    entity: &dyn Entity,
+    pos: BlockPos,
+    source: &dyn Entity,
+) -> bool {
+    let Some(player) = source.as_player() else {
+        return false;
+    };
+    let Some(world) = entity.level() else {
+        return false;
+    };
+    if !world.may_interact(player, pos) {
+        return true;
+    }
+    let damage = DamageSource::environment(&vanilla_damage_types::PLAYER_ATTACK)
+        .with_causing_entity(player.id())
+        .with_direct_entity(player.id());
+    entity.hurt(&world, &damage, 0.0)
+}
+
+/// Emits `event` at `entity`, attributing it to the entity with `cause_id`.
+pub(crate) fn game_event_caused_by(
+    entity: &dyn Entity,
+    event: GameEventRef,
+    cause_id: Option<i32>,
+) {
+    let Some(world) = entity.level() else {
+        return;
+    };
+    let cause = cause_id.and_then(|id| world.get_entity_by_id(id));
+    world.game_event_at(
+        event,
+        entity.position(),
+        &GameEventContext::new(cause.as_deref(), None),
+    );
+}
@@ -0,0 +1,367 @@
+//! Hopper minecart entity implementation.
+//!
+//! A non-rideable minecart carrying a 5-slot hopper. It collects items from the
+//! container or item entities above it, and an active activator rail disables it.
+
+use std::mem;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::ToNbtTag;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_macros::entity_behavior;
+use steel_registry::data_components::vanilla_components::CUSTOM_NAME;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::items::ItemRef;
+use steel_registry::vanilla_entity_data::VehicleEntityData;
+use steel_registry::vanilla_game_rules::ENTITY_DROPS;
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::InteractionHand;
+use steel_utils::{DowncastType, DowncastTypeKey};
+
+use crate::behavior::InteractionResult;
+use crate::block_entity::entities::{HOPPER_SLOTS, add_item_entity, suck_in_items};
+use crate::entity::damage::DamageSource;
+use crate::entity::entities::{ItemEntity, MinecartEntity};
+use crate::entity::{Entity, EntityBase, EntityBaseLoad, EntitySyncedData, VehicleEntity};
+use crate::inventory::container::Container;
+use crate::inventory::hopper_menu::HopperMenuProvider;
+use crate::inventory::lock::ContainerRef;
+use crate::player::Player;
+use crate::world::World;
+
+/// The items carried by a hopper minecart.
+pub struct HopperMinecartContainer {
+    entity_id: i32,
+    items: [ItemStack; HOPPER_SLOTS],
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `HopperMinecartContainer`.
+unsafe impl DowncastType for HopperMinecartContainer {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:container/hopper_minecart");
+}
+
+impl HopperMinecartContainer {
+    fn new(entity_id: i32) -> Self {
+        Self {
+            entity_id,
+            items: Default::default(),
+        }
+    }
+}
+
+impl Container for HopperMinecartContainer {
+    fn get_container_size(&self) -> usize {
+        HOPPER_SLOTS
+    }
+
+    fn get_item(&self, slot: usize) -> &ItemStack {
+        &self.items[slot]
+    }
+
+    fn get_item_mut(&mut self, slot: usize) -> &mut ItemStack {
+        &mut self.items[slot]
+    }
+
+    fn set_item(&mut self, slot: usize, stack: ItemStack) {
+        if slot < HOPPER_SLOTS {
+            self.items[slot] = stack;
+        }
+    }
+
+    /// Entity data is saved with the chunk, so there is nothing to mark.
+    fn set_changed(&mut self) {}
+
+    /// Vanilla `ContainerEntity.isChestVehicleStillValid`.
+    fn still_valid(&self, player: &Player) -> bool {
+        player
+            .level()
+            .and_then(|world| world.get_entity_by_id(self.entity_id))
+            .is_some_and(|entity| {
+                !entity.is_removed()
+                    && player.is_within_entity_interaction_range(entity.bounding_box(), 4.0)
+            })
+    }
+}
+
+/// Vanilla `MinecartHopper`.
+#[entity_behavior(class = "MinecartHopper")]
+pub struct HopperMinecartEntity {
+    minecart: MinecartEntity,
+    #[json_arg(vanilla_items, json = "drop_item")]
+    drop_item: ItemRef,
+    container: Arc<SyncMutex<HopperMinecartContainer>>,
+    enabled: SyncMutex<bool>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `HopperMinecartEntity`.
+unsafe impl DowncastType for HopperMinecartEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/hopper_minecart");
+}
+
+impl HopperMinecartEntity {
+    /// Creates a new hopper minecart entity.
+    #[must_use]
+    pub fn new(
+        entity_type: EntityTypeRef,
+        id: i32,
+        position: DVec3,
+        world: Weak<World>,
+        drop_item: ItemRef,
+    ) -> Self {
+        let container = HopperMinecartContainer::new(id);
+        Self::with_minecart(
+            MinecartEntity::new(entity_type, id, position, world, drop_item),
+            drop_item,
+            container,
+        )
+    }
+
+    /// Creates a hopper minecart entity from saved data.
+    #[must_use]
+    pub fn from_saved(
+        entity_type: EntityTypeRef,
+        load: EntityBaseLoad,
+        drop_item: ItemRef,
+    ) -> Self {
+        let minecart = MinecartEntity::from_saved(entity_type, load, drop_item);
+        let container = HopperMinecartContainer::new(minecart.id());
+        Self::with_minecart(minecart, drop_item, container)
+    }
+
+    fn with_minecart(
+        minecart: MinecartEntity,
+        drop_item: ItemRef,
+        container: HopperMinecartContainer,
+    ) -> Self {
+        Self {
+            minecart: minecart.not_rideable(),
+            drop_item,
+            container: Arc::new(SyncMutex::new(container)),
+            enabled: SyncMutex::new(true),
+        }
+    }
+
+    /// Returns the items carried by this minecart.
+    #[must_use]
+    pub const fn container(&self) -> &Arc<SyncMutex<HopperMinecartContainer>> {
+        &self.container
+    }
+
+    /// Returns whether the hopper is collecting items.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        *self.enabled.lock()
+    }
+
+    /// Vanilla `MinecartHopper.suckInItems`.
+    fn suck_in_items(&self, world: &World) -> bool {
+        let mut container = self.container.lock();
+        let position = self.position();
+        let origin = DVec3::new(position.x, position.y + 0.5, position.z);
+        if suck_in_items(world, &mut *container, origin, false) {
+            return true;
+        }
+
+        let aabb = self.bounding_box().inflate_xyz(0.25, 0.0, 0.25);
+        let items = world.get_entities_in_aabb_matching(&aabb, |entity| {
+            entity.is_alive() && entity.is::<ItemEntity>()
+        });
+        items.iter().any(|entity| {
+            entity
+                .downcast_ref::<ItemEntity>()
+                .is_some_and(|item_entity| add_item_entity(&mut *container, item_entity))
+        })
+    }
+
+    const fn nbt_bool(value: bool) -> i8 {
+        if value { 1 } else { 0 }
+    }
+}
+
+impl VehicleEntity for HopperMinecartEntity {
+    fn with_vehicle_data<R>(&self, f: impl FnOnce(&mut VehicleEntityData) -> R) -> R {
+        self.minecart.with_vehicle_data(f)
+    }
+
+    fn drop_item(&self) -> ItemRef {
+        self.drop_item
+    }
+
+    /// Also spills the carried items, like vanilla `AbstractMinecartContainer.destroy`.
+    fn destroy(&self, world: &World) {
+        self.kill(world);
+        if !world.get_game_rule(&ENTITY_DROPS) {
+            return;
+        }
+
+        let mut item = ItemStack::new(self.drop_item);
+        if let Some(custom_name) = self.custom_name() {
+            item.set(CUSTOM_NAME, custom_name);
+        }
+        self.spawn_at_location(item, 0.0);
+
+        let items = mem::take(&mut self.container.lock().items);
+        for item in items {
+            self.spawn_at_location(item, 0.0);
+        }
+    }
+}
+
+impl Entity for HopperMinecartEntity {
+    fn base(&self) -> &EntityBase {
+        self.minecart.base()
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.minecart.entity_type()
+    }
+
+    /// Vanilla `MinecartHopper.tick`.
+    fn tick(&self) {
+        self.minecart.tick();
+        if let Some(powered) = self.minecart.take_activator_power() {
+            *self.enabled.lock() = !powered;
+        }
+
+        if let Some(world) = self.level()
+            && self.is_alive()
+            && self.is_enabled()
+        {
+            self.suck_in_items(&world);
+        }
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        self.hurt_vehicle(world, source, amount)
+    }
+
+    /// Opens the hopper menu, vanilla `AbstractMinecartContainer.interact`.
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        let result = self.interact_entity(player, hand, location);
+        if result.consumes_action() {
+            return result;
+        }
+
+        player.open_menu(&HopperMenuProvider::new(
+            player.inventory.clone(),
+            ContainerRef::from(Arc::clone(&self.container)),
+            self.display_name(),
+        ));
+        InteractionResult::Success
+    }
+
+    fn dismount_location_for_passenger(&self, passenger: &dyn Entity) -> DVec3 {
+        self.minecart.dismount_location_for_passenger(passenger)
+    }
+
+    fn is_on_rails(&self) -> bool {
+        self.minecart.is_on_rails()
+    }
+
+    fn is_pickable(&self) -> bool {
+        self.minecart.is_pickable()
+    }
+
+    fn is_pushable(&self) -> bool {
+        self.minecart.is_pushable()
+    }
+
+    fn can_collide_with(&self, other: &dyn Entity) -> bool {
+        self.minecart.can_collide_with(other)
+    }
+
+    fn blocks_building(&self) -> bool {
+        self.minecart.blocks_building()
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        self.minecart.get_default_gravity()
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        self.minecart.synced_data()
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        self.minecart.save_additional(nbt);
+        nbt.insert("Enabled", Self::nbt_bool(self.is_enabled()));
+
+        let mut items: Vec<NbtCompound> = Vec::new();
+        for (slot, item) in self.container.lock().items.iter().enumerate() {
+            if !item.is_empty()
+                && let NbtTag::Compound(mut item_nbt) = item.clone().to_nbt_tag()
+            {
+                item_nbt.insert("Slot", slot as i8);
+                items.push(item_nbt);
+            }
+        }
+        nbt.insert("Items", NbtList::Compound(items));
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        self.minecart.load_additional(nbt);
+        *self.enabled.lock() = nbt.byte("Enabled").is_none_or(|enabled| enabled != 0);
+
+        let mut container = self.container.lock();
+        if let Some(items_list) = nbt.list("Items")
+            && let Some(compounds) = items_list.compounds()
+        {
+            for compound in compounds {
+                if let Some(slot) = compound.byte("Slot") {
+                    let slot = slot as usize;
+                    if slot < HOPPER_SLOTS
+                        && let Some(item) = ItemStack::from_borrowed_compound(&compound)
+                    {
+                        container.items[slot] = item;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use steel_registry::{vanilla_entities, vanilla_items};
+
+    fn hopper_minecart() -> HopperMinecartEntity {
+        HopperMinecartEntity::new(
+            &vanilla_entities::HOPPER_MINECART,
+            1,
+            DVec3::new(0.5, 64.0, 0.5),
+            Weak::new(),
+            &vanilla_items::HOPPER_MINECART,
+        )
+    }
+
+    #[test]
+    fn hopper_minecart_saves_enabled_and_items() {
+        let minecart = hopper_minecart();
+        *minecart.enabled.lock() = false;
+        minecart
+            .container
+            .lock()
+            .set_item(2, ItemStack::with_count(&vanilla_items::DIAMOND, 3));
+
+        let mut nbt = NbtCompound::new();
+        minecart.save_additional(&mut nbt);
+
+        assert_eq!(nbt.byte("Enabled"), Some(0));
+        let items = nbt.list("Items").and_then(|list| list.compounds());
+        assert_eq!(items.map(|items| items.len()), Some(1));
+    }
+
+    #[test]
+    fn hopper_minecart_starts_enabled() {
+        assert!(hopper_minecart().is_enabled());
+    }
+}
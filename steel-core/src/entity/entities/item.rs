@@ -10,8 +10,10 @@ use glam::DVec3;
 use steel_macros::entity_behavior;
 use steel_registry::entity_type::EntityTypeRef;
 use steel_registry::item_stack::ItemStack;
+use steel_registry::stat::Stat;
 use steel_registry::vanilla_damage_types;
 use steel_registry::vanilla_entity_data::ItemEntityData;
+use steel_registry::vanilla_item_tags::ItemTag;
 use steel_utils::UuidExt;
 use steel_utils::locks::SyncMutex;
 use steel_utils::{DowncastType, DowncastTypeKey};
@@ -343,6 +345,7 @@ impl ItemEntity {
 
         // Calculate how many items were picked up
         let picked_up_count = original_count - item.count();
+        player.award_stat_amount(Stat::picked_up(item.item()), picked_up_count);
 
         // Send the take animation packet to nearby players
         if let Some(world) = self.level() {
@@ -524,6 +527,10 @@ impl Entity for ItemEntity {
         self.entity_type
     }
 
+    fn dampens_vibrations(&self) -> bool {
+        self.get_item().item().has_tag(&ItemTag::DAMPENS_VIBRATIONS)
+    }
+
     fn tick(&self) {
         // Check if item is empty
         if self.get_item().is_empty() {
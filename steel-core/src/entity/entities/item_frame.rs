@@ -1,4 +1,4 @@
-//! Minimal persistent item-frame entity used by structure generation.
+//! Vanilla item frame entity.
 
 use std::sync::Weak;
 
@@ -6,27 +6,56 @@ use glam::DVec3;
 use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
 use simdnbt::owned::{NbtCompound, NbtTag};
 use steel_macros::entity_behavior;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
 use steel_registry::data_components::vanilla_components::MAP_ID;
 use steel_registry::entity_type::EntityTypeRef;
 use steel_registry::item_stack::ItemStack;
+use steel_registry::items::ItemRef;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_damage_type_tags::DamageTypeTag;
 use steel_registry::vanilla_entity_data::ItemFrameEntityData;
+use steel_registry::vanilla_game_rules::ENTITY_DROPS;
+use steel_registry::{sound_events, vanilla_entities, vanilla_game_events, vanilla_items};
 use steel_utils::locks::SyncMutex;
+use steel_utils::types::InteractionHand;
 use steel_utils::{BlockPos, Direction, DowncastType, DowncastTypeKey, WorldAabb, axis::Axis};
 
-use crate::entity::{Entity, EntityBase, EntityBaseLoad, EntityBaseState, EntitySyncedData};
+use crate::behavior::InteractionResult;
+use crate::entity::damage::DamageSource;
+use crate::entity::entities::hanging::{
+    HANGING_DEPTH, SURVIVAL_CHECK_INTERVAL, attached_to_wall, can_coexist, direction_2d_data_value,
+    direction_3d_data_value, direction_from_3d_data_value, game_event_caused_by,
+    is_caused_by_infinite_materials_player, is_creative_player_source, is_diode,
+    is_invulnerable_to_base, is_mob_griefing_blocked, skip_attack_interaction,
+};
+use crate::entity::{
+    Entity, EntityBase, EntityBaseLoad, EntityBaseState, EntitySyncedData, RemovalReason,
+};
+use crate::map::{self, MapFrame};
+use crate::physics::{WorldCollisionProvider, has_block_collision};
+use crate::player::Player;
 use crate::world::World;
 
-/// Item frame state needed by end-city structure markers.
-///
-/// This intentionally implements only placement, synced item/facing data, and
-/// persistence. Interaction, drops, map tracking, and support checks belong to
-/// the full item-frame entity implementation.
+/// Number of distinct framed item rotations.
+const NUM_ROTATIONS: i32 = 8;
+/// Ticks between updates of a framed map, as vanilla `ServerEntity` sends them.
+const MAP_UPDATE_INTERVAL: i32 = 10;
+
+/// Mutable server-side item frame state that is not synced to clients.
+struct ItemFrameState {
+    check_interval: i32,
+    drop_chance: f32,
+    fixed: bool,
+}
+
+/// Vanilla item frame, including glow item frames.
 #[entity_behavior(class = "ItemFrame")]
 pub struct ItemFrameEntity {
     base: EntityBase,
     entity_type: EntityTypeRef,
     entity_data: SyncMutex<ItemFrameEntityData>,
     block_pos: SyncMutex<BlockPos>,
+    state: SyncMutex<ItemFrameState>,
 }
 
 // SAFETY: This key is owned by Steel and uniquely identifies `ItemFrameEntity`.
@@ -64,7 +93,7 @@ impl ItemFrameEntity {
             base: EntityBase::new_with_state(
                 id,
                 EntityBaseState::new_with_bounding_box(
-                    Self::frame_center(block_pos, direction),
+                    attached_to_wall(block_pos, direction),
                     entity_type.dimensions,
                     Self::frame_bounding_box(block_pos, direction, false),
                 )
@@ -74,6 +103,7 @@ impl ItemFrameEntity {
             entity_type,
             entity_data: SyncMutex::new(ItemFrameEntityData::new()),
             block_pos: SyncMutex::new(block_pos),
+            state: SyncMutex::new(ItemFrameState::new()),
         };
         entity
             .entity_data
@@ -97,10 +127,30 @@ impl ItemFrameEntity {
                 position.y.floor() as i32,
                 position.z.floor() as i32,
             )),
+            state: SyncMutex::new(ItemFrameState::new()),
         }
     }
 
-    /// Sets the framed item, matching vanilla by storing a single item.
+    /// Returns the block position this frame hangs in.
+    #[must_use]
+    pub fn block_pos(&self) -> BlockPos {
+        *self.block_pos.lock()
+    }
+
+    /// Returns the face this frame is attached to.
+    #[must_use]
+    pub fn direction(&self) -> Direction {
+        *self.entity_data.lock().hanging_entity.direction.get()
+    }
+
+    /// Returns a copy of the framed item.
+    #[must_use]
+    pub fn item(&self) -> ItemStack {
+        self.entity_data.lock().item.get().clone()
+    }
+
+    /// Sets the framed item without playing a sound, matching vanilla by
+    /// storing a single item.
     pub fn set_item(&self, mut item: ItemStack) {
         if !item.is_empty() {
             item.set_count(1);
@@ -109,6 +159,150 @@ impl ItemFrameEntity {
         self.recalculate_position();
     }
 
+    /// Returns the framed item rotation in eighth turns.
+    #[must_use]
+    pub fn item_rotation(&self) -> i32 {
+        *self.entity_data.lock().rotation.get()
+    }
+
+    /// Sets the framed item rotation, wrapping it to vanilla's eight steps.
+    pub fn set_item_rotation(&self, rotation: i32) {
+        self.entity_data
+            .lock()
+            .rotation
+            .set(rotation.rem_euclid(NUM_ROTATIONS));
+    }
+
+    /// Returns whether this frame is fixed in place and immune to interaction.
+    #[must_use]
+    pub fn is_fixed(&self) -> bool {
+        self.state.lock().fixed
+    }
+
+    /// Returns true when the frame may stay attached at its current position.
+    ///
+    /// Vanilla equivalent: `ItemFrame.survives`.
+    #[must_use]
+    pub fn survives(&self) -> bool {
+        if self.is_fixed() {
+            return true;
+        }
+        let Some(world) = self.level() else {
+            return false;
+        };
+        if has_block_collision(
+            &WorldCollisionProvider::for_entity(&world, self),
+            self.bounding_box(),
+        ) {
+            return false;
+        }
+        let direction = self.direction();
+        let support = world.get_block_state(self.block_pos().relative(direction.opposite()));
+        (support.is_solid() || direction.is_horizontal() && is_diode(support))
+            && can_coexist(self, direction, true)
+    }
+
+    /// Plays the frame's placement sound.
+    pub fn play_placement_sound(&self) {
+        self.play_sound(self.place_sound(), 1.0, 1.0);
+    }
+
+    fn is_glow(&self) -> bool {
+        self.entity_type == &vanilla_entities::GLOW_ITEM_FRAME
+    }
+
+    fn frame_item(&self) -> ItemRef {
+        if self.is_glow() {
+            &vanilla_items::GLOW_ITEM_FRAME
+        } else {
+            &vanilla_items::ITEM_FRAME
+        }
+    }
+
+    fn place_sound(&self) -> SoundEventRef {
+        if self.is_glow() {
+            &sound_events::ENTITY_GLOW_ITEM_FRAME_PLACE
+        } else {
+            &sound_events::ENTITY_ITEM_FRAME_PLACE
+        }
+    }
+
+    fn break_sound(&self) -> SoundEventRef {
+        if self.is_glow() {
+            &sound_events::ENTITY_GLOW_ITEM_FRAME_BREAK
+        } else {
+            &sound_events::ENTITY_ITEM_FRAME_BREAK
+        }
+    }
+
+    fn add_item_sound(&self) -> SoundEventRef {
+        if self.is_glow() {
+            &sound_events::ENTITY_GLOW_ITEM_FRAME_ADD_ITEM
+        } else {
+            &sound_events::ENTITY_ITEM_FRAME_ADD_ITEM
+        }
+    }
+
+    fn remove_item_sound(&self) -> SoundEventRef {
+        if self.is_glow() {
+            &sound_events::ENTITY_GLOW_ITEM_FRAME_REMOVE_ITEM
+        } else {
+            &sound_events::ENTITY_ITEM_FRAME_REMOVE_ITEM
+        }
+    }
+
+    fn rotate_item_sound(&self) -> SoundEventRef {
+        if self.is_glow() {
+            &sound_events::ENTITY_GLOW_ITEM_FRAME_ROTATE_ITEM
+        } else {
+            &sound_events::ENTITY_ITEM_FRAME_ROTATE_ITEM
+        }
+    }
+
+    fn should_check_survival(&self) -> bool {
+        let mut state = self.state.lock();
+        let check = state.check_interval == SURVIVAL_CHECK_INTERVAL;
+        state.check_interval = if check { 0 } else { state.check_interval + 1 };
+        check
+    }
+
+    /// Breaks the frame and drops it with its item.
+    ///
+    /// Vanilla equivalent: `ItemFrame.dropItem(ServerLevel, Entity)`.
+    fn break_frame(&self, world: &World, source: Option<&DamageSource>) {
+        self.play_sound(self.break_sound(), 1.0, 1.0);
+        self.drop_contents(world, source, true);
+        game_event_caused_by(
+            self,
+            &vanilla_game_events::BLOCK_CHANGE,
+            source.and_then(|source| source.causing_entity_id),
+        );
+    }
+
+    /// Empties the frame, dropping its item and optionally the frame itself.
+    ///
+    /// Vanilla equivalent: `ItemFrame.dropItem(ServerLevel, Entity, boolean)`.
+    fn drop_contents(&self, world: &World, source: Option<&DamageSource>, with_frame: bool) {
+        if self.is_fixed() {
+            return;
+        }
+        let item = self.item();
+        self.set_item(ItemStack::empty());
+        map::remove_framed_map(world, &item, self.block_pos(), self.id());
+        if !world.get_game_rule(&ENTITY_DROPS)
+            || is_caused_by_infinite_materials_player(world, source)
+        {
+            return;
+        }
+        if with_frame {
+            self.spawn_at_location(ItemStack::new(self.frame_item()), 0.0);
+        }
+        let drop_chance = self.state.lock().drop_chance;
+        if !item.is_empty() && rand::random::<f32>() < drop_chance {
+            self.spawn_at_location(item, 0.0);
+        }
+    }
+
     fn set_direction(&self, direction: Direction) {
         self.entity_data
             .lock()
@@ -121,9 +315,9 @@ impl ItemFrameEntity {
     }
 
     fn recalculate_position(&self) {
-        let block_pos = *self.block_pos.lock();
-        let direction = *self.entity_data.lock().hanging_entity.direction.get();
-        let position = Self::frame_center(block_pos, direction);
+        let block_pos = self.block_pos();
+        let direction = self.direction();
+        let position = attached_to_wall(block_pos, direction);
         if let Err(error) = self.base.try_set_position(position) {
             panic!(
                 "failed to commit item frame {} position recalculation: {error}",
@@ -141,11 +335,6 @@ impl ItemFrameEntity {
         self.entity_data.lock().item.get().has(MAP_ID)
     }
 
-    fn frame_center(block_pos: BlockPos, direction: Direction) -> DVec3 {
-        let off = direction.offset_vec().as_dvec3() * 0.46875;
-        block_pos.0.as_dvec3() + DVec3::splat(0.5) - off
-    }
-
     fn rotation_for_direction(direction: Direction) -> (f32, f32) {
         if direction.is_horizontal() {
             (f32::from(direction_2d_data_value(direction)) * 90.0, 0.0)
@@ -164,20 +353,20 @@ impl ItemFrameEntity {
         direction: Direction,
         has_framed_map: bool,
     ) -> WorldAabb {
-        let center = Self::frame_center(block_pos, direction);
+        let center = attached_to_wall(block_pos, direction);
         let size = if has_framed_map { 1.0 } else { 0.75 };
         let x_size = if direction.axis() == Axis::X {
-            0.0625
+            HANGING_DEPTH
         } else {
             size
         };
         let y_size = if direction.axis() == Axis::Y {
-            0.0625
+            HANGING_DEPTH
         } else {
             size
         };
         let z_size = if direction.axis() == Axis::Z {
-            0.0625
+            HANGING_DEPTH
         } else {
             size
         };
@@ -192,6 +381,16 @@ impl ItemFrameEntity {
     }
 }
 
+impl ItemFrameState {
+    const fn new() -> Self {
+        Self {
+            check_interval: 0,
+            drop_chance: 1.0,
+            fixed: false,
+        }
+    }
+}
+
 impl Entity for ItemFrameEntity {
     fn base(&self) -> &EntityBase {
         &self.base
@@ -202,11 +401,11 @@ impl Entity for ItemFrameEntity {
     }
 
     fn spawn_data(&self) -> i32 {
-        direction_3d_data_value(*self.entity_data.lock().hanging_entity.direction.get())
+        direction_3d_data_value(self.direction())
     }
 
     fn spawn_position(&self) -> DVec3 {
-        let block_pos = *self.block_pos.lock();
+        let block_pos = self.block_pos();
         DVec3::new(
             f64::from(block_pos.x()),
             f64::from(block_pos.y()),
@@ -214,6 +413,10 @@ impl Entity for ItemFrameEntity {
         )
     }
 
+    fn hanging_direction(&self) -> Option<Direction> {
+        Some(self.direction())
+    }
+
     fn is_pickable(&self) -> bool {
         true
     }
@@ -222,26 +425,123 @@ impl Entity for ItemFrameEntity {
         Some(&self.entity_data)
     }
 
+    fn tick(&self) {
+        let Some(world) = self.level() else {
+            return;
+        };
+        self.check_below_world();
+        if self.should_check_survival() && !self.is_removed() && !self.survives() {
+            self.set_removed(RemovalReason::Discarded);
+            self.break_frame(&world, None);
+        }
+        if self.tick_count() % MAP_UPDATE_INTERVAL == 0 {
+            let item = self.item();
+            if item.has(MAP_ID) {
+                let frame = MapFrame {
+                    pos: self.block_pos(),
+                    rotation: i32::from(direction_2d_data_value(self.direction())) * 90,
+                    entity_id: self.id(),
+                };
+                map::tick_framed_map(&world, &item, frame);
+            }
+        }
+    }
+
+    fn skip_attack_interaction(&self, source: &dyn Entity) -> bool {
+        skip_attack_interaction(self, self.block_pos(), source)
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, _amount: f32) -> bool {
+        if self.is_fixed()
+            && !source.bypasses_invulnerability()
+            && !is_creative_player_source(world, source)
+        {
+            return false;
+        }
+        if is_invulnerable_to_base(self, source) || is_mob_griefing_blocked(world, source) {
+            return false;
+        }
+
+        if self.is_fixed() || source.is(&DamageTypeTag::IS_EXPLOSION) || self.item().is_empty() {
+            if !self.is_removed() {
+                self.kill(world);
+                self.mark_hurt();
+                self.break_frame(world, Some(source));
+            }
+            return true;
+        }
+
+        self.drop_contents(world, Some(source), false);
+        game_event_caused_by(
+            self,
+            &vanilla_game_events::BLOCK_CHANGE,
+            source.causing_entity_id,
+        );
+        self.play_sound(self.remove_item_sound(), 1.0, 1.0);
+        true
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        _location: DVec3,
+    ) -> InteractionResult {
+        if self.is_fixed() {
+            return InteractionResult::Pass;
+        }
+
+        if self.item().is_empty() {
+            let held = player
+                .inventory
+                .lock()
+                .get_item_in_hand(hand)
+                .copy_with_count(1);
+            if held.is_empty() || self.is_removed() {
+                return InteractionResult::Pass;
+            }
+            if let Some(world) = self.level()
+                && held.is(&vanilla_items::FILLED_MAP)
+                && map::is_tracked_count_over_limit(&world, &held)
+            {
+                return InteractionResult::Fail;
+            }
+            self.set_item(held);
+            self.play_sound(self.add_item_sound(), 1.0, 1.0);
+            game_event_caused_by(self, &vanilla_game_events::BLOCK_CHANGE, Some(player.id()));
+            player.inventory.lock().shrink_item_in_hand(hand, 1);
+            return InteractionResult::Success;
+        }
+
+        self.play_sound(self.rotate_item_sound(), 1.0, 1.0);
+        self.set_item_rotation(self.item_rotation() + 1);
+        game_event_caused_by(self, &vanilla_game_events::BLOCK_CHANGE, Some(player.id()));
+        InteractionResult::Success
+    }
+
     fn save_additional(&self, nbt: &mut NbtCompound) {
-        let block_pos = *self.block_pos.lock();
+        let block_pos = self.block_pos();
         nbt.insert(
             "block_pos",
             NbtTag::IntArray(vec![block_pos.x(), block_pos.y(), block_pos.z()]),
         );
 
-        let entity_data = self.entity_data.lock();
-        let item = entity_data.item.get();
-        if !item.is_empty() {
-            nbt.insert("Item", item.to_nbt_tag_ref());
+        {
+            let entity_data = self.entity_data.lock();
+            let item = entity_data.item.get();
+            if !item.is_empty() {
+                nbt.insert("Item", item.to_nbt_tag_ref());
+            }
+            nbt.insert("ItemRotation", *entity_data.rotation.get() as i8);
+            nbt.insert(
+                "Facing",
+                direction_3d_data_value(*entity_data.hanging_entity.direction.get()) as i8,
+            );
         }
-        nbt.insert("ItemRotation", *entity_data.rotation.get() as i8);
-        nbt.insert("ItemDropChance", 1.0_f32);
-        nbt.insert(
-            "Facing",
-            direction_3d_data_value(*entity_data.hanging_entity.direction.get()) as i8,
-        );
-        nbt.insert("Invisible", 0_i8);
-        nbt.insert("Fixed", 0_i8);
+        let state = self.state.lock();
+        nbt.insert("ItemDropChance", state.drop_chance);
+        nbt.insert("Invisible", i8::from(self.is_invisible()));
+        nbt.insert("Fixed", i8::from(state.fixed));
     }
 
     fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
@@ -258,10 +558,7 @@ impl Entity for ItemFrameEntity {
         }
 
         if let Some(item_rotation) = nbt.byte("ItemRotation") {
-            self.entity_data
-                .lock()
-                .rotation
-                .set(i32::from(item_rotation).rem_euclid(8));
+            self.set_item_rotation(i32::from(item_rotation));
         }
 
         let facing = nbt
@@ -272,39 +569,19 @@ impl Entity for ItemFrameEntity {
             self.set_direction(direction);
         }
 
-        self.recalculate_position();
-    }
-}
-
-const fn direction_3d_data_value(direction: Direction) -> i32 {
-    match direction {
-        Direction::Down => 0,
-        Direction::Up => 1,
-        Direction::North => 2,
-        Direction::South => 3,
-        Direction::West => 4,
-        Direction::East => 5,
-    }
-}
-
-const fn direction_from_3d_data_value(value: i32) -> Option<Direction> {
-    match value {
-        0 => Some(Direction::Down),
-        1 => Some(Direction::Up),
-        2 => Some(Direction::North),
-        3 => Some(Direction::South),
-        4 => Some(Direction::West),
-        5 => Some(Direction::East),
-        _ => None,
-    }
-}
+        {
+            let mut state = self.state.lock();
+            if let Some(drop_chance) = nbt.float("ItemDropChance") {
+                state.drop_chance = drop_chance;
+            }
+            state.fixed = nbt.byte("Fixed").is_some_and(|fixed| fixed != 0);
+        }
+        self.entity_data.set_base_invisible_flag(
+            nbt.byte("Invisible")
+                .is_some_and(|invisible| invisible != 0),
+        );
 
-const fn direction_2d_data_value(direction: Direction) -> u8 {
-    match direction {
-        Direction::South | Direction::Down | Direction::Up => 0,
-        Direction::West => 1,
-        Direction::North => 2,
-        Direction::East => 3,
+        self.recalculate_position();
     }
 }
 
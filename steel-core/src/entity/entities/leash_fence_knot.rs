@@ -6,17 +6,20 @@ use glam::DVec3;
 use steel_macros::entity_behavior;
 use steel_registry::blocks::block_state_ext::BlockStateExt as _;
 use steel_registry::entity_type::EntityTypeRef;
-use steel_registry::sound_events;
 use steel_registry::vanilla_block_tags::BlockTag;
-use steel_registry::vanilla_entities;
+use steel_registry::{sound_events, vanilla_entities, vanilla_game_events, vanilla_items};
 use steel_utils::locks::SyncMutex;
+use steel_utils::types::InteractionHand;
 use steel_utils::{BlockPos, DowncastType, DowncastTypeKey, WorldAabb};
 
+use crate::behavior::InteractionResult;
 use crate::entity::{
     Entity, EntityBase, EntityBaseLoad, EntityBaseState, EntityCapabilities, LeashFenceKnot,
     RemovalReason, SharedEntity, next_entity_id,
 };
+use crate::player::Player;
 use crate::world::World;
+use crate::world::game_event_context::GameEventContext;
 
 /// Vanilla leash knot attached to a fence block.
 #[entity_behavior(class = "LeashFenceKnotEntity")]
@@ -229,6 +232,65 @@ impl Entity for LeashFenceKnotEntity {
     fn is_pickable(&self) -> bool {
         true
     }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        _location: DVec3,
+    ) -> InteractionResult {
+        let holding_shears = player
+            .inventory
+            .lock()
+            .get_item_in_hand(hand)
+            .is(&vanilla_items::SHEARS);
+        if holding_shears && self.shear_off_all_leash_connections(Some(player)) {
+            player.hurt_item_in_hand(hand, 1);
+            return InteractionResult::Success;
+        }
+
+        let Some(world) = self.level() else {
+            return InteractionResult::Pass;
+        };
+        let (Some(knot), Some(player_entity)) = (
+            world.get_entity_by_id(self.id()),
+            world.get_entity_by_id(player.id()),
+        ) else {
+            return InteractionResult::Pass;
+        };
+
+        let mut attached_mob = false;
+        for leashable in player.leashables_leashed_to() {
+            if let Some(mob) = leashable.as_mob()
+                && mob.can_have_a_leash_attached_to(self)
+                && mob.set_leashed_to(&knot)
+            {
+                attached_mob = true;
+            }
+        }
+
+        let mut any_dropped = false;
+        if !attached_mob && !player.is_secondary_use_active() {
+            for leashable in self.leashables_leashed_to() {
+                if let Some(mob) = leashable.as_mob()
+                    && mob.can_have_a_leash_attached_to(player)
+                    && mob.set_leashed_to(&player_entity)
+                {
+                    any_dropped = true;
+                }
+            }
+        }
+
+        if !attached_mob && !any_dropped {
+            return InteractionResult::Pass;
+        }
+        world.game_event(
+            &vanilla_game_events::BLOCK_ATTACH,
+            self.block_pos(),
+            &GameEventContext::new(Some(player), None),
+        );
+        InteractionResult::SuccessServer
+    }
 }
 
 impl LeashFenceKnot for LeashFenceKnotEntity {
@@ -0,0 +1,235 @@
+//! Lightning bolt entity implementation.
+
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use steel_macros::entity_behavior;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::vanilla_entity_data::LightningBoltEntityData;
+use steel_registry::vanilla_game_events;
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::{Difficulty, UpdateFlags};
+use steel_utils::{BlockPos, DowncastType, DowncastTypeKey, WorldAabb};
+
+use crate::behavior::blocks::FireBlock;
+use crate::entity::{Entity, EntityBase, EntityBaseLoad, EntitySyncedData, RemovalReason};
+use crate::world::World;
+
+const START_LIFE: i32 = 2;
+const DAMAGE_RADIUS: f64 = 3.0;
+const DAMAGE_BOX_HEIGHT: f64 = 6.0;
+const STRIKE_FIRE_SOURCES: i32 = 4;
+
+struct LightningBoltState {
+    life: i32,
+    flashes: i32,
+    visual_only: bool,
+}
+
+/// Vanilla lightning bolt entity.
+///
+/// A bolt lives for a couple of ticks, flashing a few times. While it lives it
+/// sets fire around its position and strikes every entity close to it.
+#[entity_behavior(class = "LightningBolt")]
+pub struct LightningBoltEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    entity_data: SyncMutex<LightningBoltEntityData>,
+    state: SyncMutex<LightningBoltState>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `LightningBoltEntity`.
+unsafe impl DowncastType for LightningBoltEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/lightning_bolt");
+}
+
+impl LightningBoltEntity {
+    /// Creates a new lightning bolt entity.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_with_base(
+            EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+        )
+    }
+
+    /// Creates a lightning bolt entity from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self::new_with_base(
+            EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+        )
+    }
+
+    fn new_with_base(base: EntityBase, entity_type: EntityTypeRef) -> Self {
+        Self {
+            base,
+            entity_type,
+            entity_data: SyncMutex::new(LightningBoltEntityData::new()),
+            state: SyncMutex::new(LightningBoltState {
+                life: START_LIFE,
+                flashes: rand::random_range(0..3) + 1,
+                visual_only: false,
+            }),
+        }
+    }
+
+    /// Returns whether this bolt only flashes without setting fire or hurting entities.
+    #[must_use]
+    pub fn is_visual_only(&self) -> bool {
+        self.state.lock().visual_only
+    }
+
+    /// Sets whether this bolt only flashes without setting fire or hurting entities.
+    pub fn set_visual_only(&self, visual_only: bool) {
+        self.state.lock().visual_only = visual_only;
+    }
+
+    /// Vanilla `LightningBolt.spawnFire`.
+    fn spawn_fire(&self, world: &Arc<World>, additional_sources: i32) {
+        let pos = self.block_position();
+        if self.is_visual_only() || !world.can_spread_fire_around(pos) {
+            return;
+        }
+
+        Self::try_place_fire(world, pos);
+        for _ in 0..additional_sources {
+            let offset = pos.offset(
+                rand::random_range(-1..=1),
+                rand::random_range(-1..=1),
+                rand::random_range(-1..=1),
+            );
+            Self::try_place_fire(world, offset);
+        }
+    }
+
+    fn try_place_fire(world: &Arc<World>, pos: BlockPos) {
+        if world.get_block_state(pos).is_air()
+            && FireBlock::selected_fire_can_survive_at(world.as_ref(), pos)
+        {
+            world.set_block(
+                pos,
+                FireBlock::get_state(world.as_ref(), pos),
+                UpdateFlags::UPDATE_ALL,
+            );
+        }
+    }
+
+    /// Strikes every living entity in the damage box around the bolt.
+    fn strike_entities(&self, world: &Arc<World>) {
+        let position = self.position();
+        let damage_box = WorldAabb::new(
+            position.x - DAMAGE_RADIUS,
+            position.y - DAMAGE_RADIUS,
+            position.z - DAMAGE_RADIUS,
+            position.x + DAMAGE_RADIUS,
+            position.y + DAMAGE_BOX_HEIGHT + DAMAGE_RADIUS,
+            position.z + DAMAGE_RADIUS,
+        );
+        let id = self.id();
+        for entity in world.get_entities_in_aabb_matching(&damage_box, |entity| {
+            entity.id() != id && entity.is_alive()
+        }) {
+            entity.thunder_hit(world, self);
+        }
+        // TODO: Trigger the channeled_lightning advancement once tridents can cause lightning.
+    }
+}
+
+impl Entity for LightningBoltEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn tick(&self) {
+        self.default_tick();
+        let Some(world) = self.level() else {
+            return;
+        };
+
+        if self.state.lock().life == START_LIFE {
+            // VANILLA CLIENT-LOCAL: The thunder and impact sounds play on clients when the
+            // bolt's first tick arrives.
+            if matches!(world.difficulty(), Difficulty::Normal | Difficulty::Hard) {
+                self.spawn_fire(&world, STRIKE_FIRE_SOURCES);
+            }
+            // TODO: Power the struck lightning rod and clear nearby copper oxidation once
+            // lightning rods have block behavior.
+            self.game_event(&vanilla_game_events::LIGHTNING_STRIKE);
+        }
+
+        let (reflash, strikes) = {
+            let mut state = self.state.lock();
+            state.life -= 1;
+            let mut reflash = false;
+            if state.life < 0 {
+                if state.flashes == 0 {
+                    drop(state);
+                    // TODO: Trigger the lightning_strike advancement for nearby players once
+                    // advancements exist.
+                    self.set_removed(RemovalReason::Discarded);
+                    return;
+                }
+                if state.life < -rand::random_range(0..10) {
+                    state.flashes -= 1;
+                    state.life = 1;
+                    reflash = true;
+                }
+            }
+            (reflash, state.life >= 0 && !state.visual_only)
+        };
+
+        if reflash {
+            self.spawn_fire(&world, 0);
+        }
+        if strikes {
+            self.strike_entities(&world);
+        }
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use steel_registry::vanilla_entities;
+
+    #[test]
+    fn lightning_bolt_starts_with_vanilla_life_and_flashes() {
+        let bolt = LightningBoltEntity::new(
+            &vanilla_entities::LIGHTNING_BOLT,
+            1,
+            DVec3::new(0.5, 64.0, 0.5),
+            Weak::new(),
+        );
+
+        let state = bolt.state.lock();
+        assert_eq!(state.life, START_LIFE);
+        assert!((1..=3).contains(&state.flashes));
+        assert!(!state.visual_only);
+    }
+
+    #[test]
+    fn visual_only_bolts_keep_the_flag() {
+        let bolt = LightningBoltEntity::new(
+            &vanilla_entities::LIGHTNING_BOLT,
+            1,
+            DVec3::ZERO,
+            Weak::new(),
+        );
+
+        bolt.set_visual_only(true);
+
+        assert!(bolt.is_visual_only());
+    }
+}
@@ -0,0 +1,751 @@
+//! Rideable minecart entity implementation.
+//!
+//! Rail movement follows vanilla `OldMinecartBehavior`, which is the default behavior
+//! while the `minecart_improvements` experiment is disabled.
+
+use std::sync::{Arc, Weak};
+
+use glam::{DVec3, IVec3};
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::NbtCompound;
+use steel_macros::entity_behavior;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::{BlockStateProperties, RailShape};
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::items::ItemRef;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::vanilla_entity_data::{AbstractMinecartEntityData, VehicleEntityData};
+use steel_registry::{vanilla_blocks, vanilla_entities};
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::InteractionHand;
+use steel_utils::{BlockPos, BlockStateId, Direction};
+use steel_utils::{DowncastType, DowncastTypeKey};
+
+use crate::behavior::InteractionResult;
+use crate::entity::damage::DamageSource;
+use crate::entity::vehicle::{block_floor_height, can_dismount_to, is_block_floor_valid};
+use crate::entity::{Entity, EntityBase, EntityBaseLoad, EntitySyncedData, VehicleEntity};
+use crate::physics::MoverType;
+use crate::player::Player;
+use crate::player::movement::wrap_degrees;
+use crate::world::World;
+
+const DEFAULT_GRAVITY: f64 = 0.04;
+const RAIL_Y_OFFSET: f64 = 0.0625;
+const SLOPE_ACCELERATION: f64 = 0.0078125;
+const POWERED_RAIL_BOOST: f64 = 0.06;
+const DISMOUNT_HEIGHTS: [i32; 3] = [0, 1, -1];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MinecartState {
+    first_tick: bool,
+    flipped: bool,
+    on_rails: bool,
+    /// `POWERED` of the activator rail crossed this tick, if any.
+    activator_power: Option<bool>,
+}
+
+impl MinecartState {
+    const fn new(first_tick: bool) -> Self {
+        Self {
+            first_tick,
+            flipped: false,
+            on_rails: false,
+            activator_power: None,
+        }
+    }
+}
+
+/// Vanilla `Minecart`: the rideable minecart.
+#[entity_behavior(class = "Minecart")]
+pub struct MinecartEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    #[json_arg(vanilla_items, json = "drop_item")]
+    drop_item: ItemRef,
+    rideable: bool,
+    entity_data: SyncMutex<AbstractMinecartEntityData>,
+    state: SyncMutex<MinecartState>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `MinecartEntity`.
+unsafe impl DowncastType for MinecartEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/minecart");
+}
+
+impl MinecartEntity {
+    /// Creates a new minecart entity.
+    #[must_use]
+    pub fn new(
+        entity_type: EntityTypeRef,
+        id: i32,
+        position: DVec3,
+        world: Weak<World>,
+        drop_item: ItemRef,
+    ) -> Self {
+        Self {
+            base: EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+            drop_item,
+            rideable: true,
+            entity_data: SyncMutex::new(AbstractMinecartEntityData::new()),
+            state: SyncMutex::new(MinecartState::new(true)),
+        }
+    }
+
+    /// Creates a minecart entity from saved data.
+    #[must_use]
+    pub fn from_saved(
+        entity_type: EntityTypeRef,
+        load: EntityBaseLoad,
+        drop_item: ItemRef,
+    ) -> Self {
+        Self {
+            base: EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+            drop_item,
+            rideable: true,
+            entity_data: SyncMutex::new(AbstractMinecartEntityData::new()),
+            state: SyncMutex::new(MinecartState::new(false)),
+        }
+    }
+
+    /// Returns whether the minecart model is rendered facing backwards.
+    #[must_use]
+    pub fn is_flipped(&self) -> bool {
+        self.state.lock().flipped
+    }
+
+    /// Keeps mobs from boarding this minecart, vanilla `isRideable() == false`.
+    #[must_use]
+    pub(crate) const fn not_rideable(mut self) -> Self {
+        self.rideable = false;
+        self
+    }
+
+    /// Returns the `POWERED` value of the activator rail crossed this tick.
+    ///
+    /// Stands in for vanilla `AbstractMinecart.activateMinecart`.
+    pub(crate) fn take_activator_power(&self) -> Option<bool> {
+        self.state.lock().activator_power.take()
+    }
+
+    const fn nbt_bool(value: bool) -> i8 {
+        if value { 1 } else { 0 }
+    }
+
+    fn max_speed(&self) -> f64 {
+        (if self.is_in_water() { 4.0 } else { 8.0 }) / 20.0
+    }
+
+    fn set_pos(&self, position: DVec3) {
+        if let Err(error) = self.try_set_position(position) {
+            log::debug!("Failed to move minecart {} along rail: {error}", self.id());
+        }
+    }
+
+    /// Mirrors vanilla `AbstractMinecart.getCurrentBlockPosOrRailBelow` for the
+    /// legacy movement behavior.
+    fn current_block_pos_or_rail_below(&self, world: &World) -> BlockPos {
+        let position = self.position();
+        let pos = BlockPos::containing(position.x, position.y, position.z);
+        let below = pos.offset(0, -1, 0);
+        if is_rail(world.get_block_state(below)) {
+            below
+        } else {
+            pos
+        }
+    }
+
+    /// Mirrors vanilla `AbstractMinecart.applyNaturalSlowdown`.
+    fn apply_natural_slowdown(&self, velocity: DVec3) -> DVec3 {
+        let friction = if self.is_vehicle() { 0.997 } else { 0.96 };
+        let slowed = DVec3::new(velocity.x * friction, 0.0, velocity.z * friction);
+        if self.is_in_water() {
+            slowed * 0.95
+        } else {
+            slowed
+        }
+    }
+
+    /// Mirrors vanilla `AbstractMinecart.comeOffTrack`.
+    fn come_off_track(&self) {
+        let max_speed = self.max_speed();
+        let velocity = self.velocity();
+        let mut velocity = DVec3::new(
+            velocity.x.clamp(-max_speed, max_speed),
+            velocity.y,
+            velocity.z.clamp(-max_speed, max_speed),
+        );
+        if self.on_ground() {
+            velocity *= 0.5;
+        }
+        self.set_velocity(velocity);
+        self.move_entity(MoverType::SelfMovement, velocity);
+        if !self.on_ground() {
+            self.set_velocity(self.velocity() * 0.95);
+        }
+    }
+
+    /// Mirrors vanilla `OldMinecartBehavior.getPos`: snaps `position` onto the rail
+    /// centerline, or returns `None` when there is no rail there.
+    fn rail_position(world: &World, position: DVec3) -> Option<DVec3> {
+        let mut pos = BlockPos::containing(position.x, position.y, position.z);
+        if is_rail(world.get_block_state(pos.offset(0, -1, 0))) {
+            pos = pos.offset(0, -1, 0);
+        }
+        let state = world.get_block_state(pos);
+        if !is_rail(state) {
+            return None;
+        }
+
+        let [exit0, exit1] = rail_exits(rail_shape(state));
+        let base = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + RAIL_Y_OFFSET,
+            f64::from(pos.z()) + 0.5,
+        );
+        let start = base + exit0.as_dvec3() * 0.5;
+        let end = base + exit1.as_dvec3() * 0.5;
+        let dx = end.x - start.x;
+        let dy = (end.y - start.y) * 2.0;
+        let dz = end.z - start.z;
+        let t = if dx == 0.0 {
+            position.z - f64::from(pos.z())
+        } else if dz == 0.0 {
+            position.x - f64::from(pos.x())
+        } else {
+            ((position.x - start.x) * dx + (position.z - start.z) * dz) * 2.0
+        };
+
+        let mut y = start.y + dy * t;
+        if dy < 0.0 {
+            y += 1.0;
+        } else if dy > 0.0 {
+            y += 0.5;
+        }
+        Some(DVec3::new(start.x + dx * t, y, start.z + dz * t))
+    }
+
+    /// Mirrors vanilla `OldMinecartBehavior.moveAlongTrack`.
+    #[expect(
+        clippy::too_many_lines,
+        reason = "matches vanilla moveAlongTrack; splitting would hurt readability"
+    )]
+    fn move_along_track(&self, world: &World, pos: BlockPos, state: BlockStateId) {
+        self.reset_fall_distance();
+        let position = self.position();
+        let old_rail_position = Self::rail_position(world, position);
+        let mut y = f64::from(pos.y());
+
+        let mut powered = false;
+        let mut brake = false;
+        if state.get_block() == &vanilla_blocks::POWERED_RAIL {
+            powered = state
+                .try_get_value(&BlockStateProperties::POWERED)
+                .unwrap_or(false);
+            brake = !powered;
+        }
+
+        let mut slope = SLOPE_ACCELERATION;
+        if self.is_in_water() {
+            slope *= 0.2;
+        }
+        let shape = rail_shape(state);
+        let slope_push = match shape {
+            RailShape::AscendingEast => Some(DVec3::new(-slope, 0.0, 0.0)),
+            RailShape::AscendingWest => Some(DVec3::new(slope, 0.0, 0.0)),
+            RailShape::AscendingNorth => Some(DVec3::new(0.0, 0.0, slope)),
+            RailShape::AscendingSouth => Some(DVec3::new(0.0, 0.0, -slope)),
+            _ => None,
+        };
+        if let Some(push) = slope_push {
+            self.set_velocity(self.velocity() + push);
+            y += 1.0;
+        }
+
+        let [exit0, exit1] = rail_exits(shape);
+        let mut exit_x = f64::from(exit1.x - exit0.x);
+        let mut exit_z = f64::from(exit1.z - exit0.z);
+        let exit_length = exit_x.hypot(exit_z);
+        let velocity = self.velocity();
+        if velocity.x * exit_x + velocity.z * exit_z < 0.0 {
+            exit_x = -exit_x;
+            exit_z = -exit_z;
+        }
+        let speed = velocity.x.hypot(velocity.z).min(2.0);
+        self.set_velocity(DVec3::new(
+            speed * exit_x / exit_length,
+            velocity.y,
+            speed * exit_z / exit_length,
+        ));
+
+        if let Some(passenger) = self.first_passenger()
+            && let Some(player) = passenger.as_player()
+        {
+            let intent = player.last_client_move_intent();
+            let velocity = self.velocity();
+            if intent.length_squared() > 0.0
+                && velocity.x * velocity.x + velocity.z * velocity.z < 0.01
+            {
+                self.set_velocity(velocity + DVec3::new(intent.x * 0.001, 0.0, intent.z * 0.001));
+                brake = false;
+            }
+        }
+
+        if brake {
+            let velocity = self.velocity();
+            if velocity.x.hypot(velocity.z) < 0.03 {
+                self.set_velocity(DVec3::ZERO);
+            } else {
+                self.set_velocity(DVec3::new(velocity.x * 0.5, 0.0, velocity.z * 0.5));
+            }
+        }
+
+        let center_x = f64::from(pos.x()) + 0.5;
+        let center_z = f64::from(pos.z()) + 0.5;
+        let start_x = center_x + f64::from(exit0.x) * 0.5;
+        let start_z = center_z + f64::from(exit0.z) * 0.5;
+        let end_x = center_x + f64::from(exit1.x) * 0.5;
+        let end_z = center_z + f64::from(exit1.z) * 0.5;
+        let dx = end_x - start_x;
+        let dz = end_z - start_z;
+        let t = if dx == 0.0 {
+            position.z - f64::from(pos.z())
+        } else if dz == 0.0 {
+            position.x - f64::from(pos.x())
+        } else {
+            ((position.x - start_x) * dx + (position.z - start_z) * dz) * 2.0
+        };
+        self.set_pos(DVec3::new(start_x + dx * t, y, start_z + dz * t));
+
+        let passenger_factor = if self.is_vehicle() { 0.75 } else { 1.0 };
+        let max_speed = self.max_speed();
+        let velocity = self.velocity();
+        self.move_entity(
+            MoverType::SelfMovement,
+            DVec3::new(
+                (passenger_factor * velocity.x).clamp(-max_speed, max_speed),
+                0.0,
+                (passenger_factor * velocity.z).clamp(-max_speed, max_speed),
+            ),
+        );
+
+        let position = self.position();
+        let block_x = position.x.floor() as i32 - pos.x();
+        let block_z = position.z.floor() as i32 - pos.z();
+        if exit0.y != 0 && block_x == exit0.x && block_z == exit0.z {
+            self.set_pos(position + DVec3::new(0.0, f64::from(exit0.y), 0.0));
+        } else if exit1.y != 0 && block_x == exit1.x && block_z == exit1.z {
+            self.set_pos(position + DVec3::new(0.0, f64::from(exit1.y), 0.0));
+        }
+
+        self.set_velocity(self.apply_natural_slowdown(self.velocity()));
+
+        let position = self.position();
+        if let Some(new_rail_position) = Self::rail_position(world, position)
+            && let Some(old_rail_position) = old_rail_position
+        {
+            let drop = (old_rail_position.y - new_rail_position.y) * 0.05;
+            let velocity = self.velocity();
+            let horizontal = velocity.x.hypot(velocity.z);
+            if horizontal > 0.0 {
+                let scale = (horizontal + drop) / horizontal;
+                self.set_velocity(DVec3::new(
+                    velocity.x * scale,
+                    velocity.y,
+                    velocity.z * scale,
+                ));
+            }
+            self.set_pos(DVec3::new(position.x, new_rail_position.y, position.z));
+        }
+
+        let position = self.position();
+        let new_x = position.x.floor() as i32;
+        let new_z = position.z.floor() as i32;
+        if new_x != pos.x() || new_z != pos.z() {
+            let velocity = self.velocity();
+            let horizontal = velocity.x.hypot(velocity.z);
+            self.set_velocity(DVec3::new(
+                horizontal * f64::from(new_x - pos.x()),
+                velocity.y,
+                horizontal * f64::from(new_z - pos.z()),
+            ));
+        }
+
+        if powered {
+            self.apply_powered_rail_boost(world, pos, shape);
+        }
+    }
+
+    fn apply_powered_rail_boost(&self, world: &World, pos: BlockPos, shape: RailShape) {
+        let velocity = self.velocity();
+        let horizontal = velocity.x.hypot(velocity.z);
+        if horizontal > 0.01 {
+            self.set_velocity(
+                velocity
+                    + DVec3::new(
+                        velocity.x / horizontal * POWERED_RAIL_BOOST,
+                        0.0,
+                        velocity.z / horizontal * POWERED_RAIL_BOOST,
+                    ),
+            );
+            return;
+        }
+
+        let is_conductor = |direction: Direction| {
+            let neighbor = pos.relative(direction);
+            World::is_redstone_conductor(world.get_block_state(neighbor), neighbor)
+        };
+        let mut velocity = velocity;
+        match shape {
+            RailShape::EastWest => {
+                if is_conductor(Direction::West) {
+                    velocity.x = 0.02;
+                } else if is_conductor(Direction::East) {
+                    velocity.x = -0.02;
+                }
+            }
+            RailShape::NorthSouth => {
+                if is_conductor(Direction::North) {
+                    velocity.z = 0.02;
+                } else if is_conductor(Direction::South) {
+                    velocity.z = -0.02;
+                }
+            }
+            _ => return,
+        }
+        self.set_velocity(velocity);
+    }
+
+    /// Mirrors the server half of vanilla `OldMinecartBehavior.tick`.
+    fn tick_rail_movement(&self, world: &World) {
+        self.apply_gravity();
+        let pos = self.current_block_pos_or_rail_below(world);
+        let state = world.get_block_state(pos);
+        let on_rails = is_rail(state);
+        self.state.lock().on_rails = on_rails;
+        if on_rails {
+            self.move_along_track(world, pos, state);
+            if state.get_block() == &vanilla_blocks::ACTIVATOR_RAIL {
+                let powered = state
+                    .try_get_value(&BlockStateProperties::POWERED)
+                    .unwrap_or(false);
+                self.state.lock().activator_power = Some(powered);
+            }
+        } else {
+            self.come_off_track();
+        }
+        self.apply_effects_from_blocks();
+
+        let position = self.position();
+        let old_position = self.old_position();
+        let (mut yaw, _) = self.rotation();
+        let dx = old_position.x - position.x;
+        let dz = old_position.z - position.z;
+        let mut flipped = self.state.lock().flipped;
+        if dx * dx + dz * dz > 0.001 {
+            yaw = dz.atan2(dx).to_degrees() as f32;
+            if flipped {
+                yaw += 180.0;
+            }
+        }
+        let delta = wrap_degrees(yaw - self.base().old_rotation().0);
+        if !(-170.0..170.0).contains(&delta) {
+            yaw += 180.0;
+            flipped = !flipped;
+        }
+        self.state.lock().flipped = flipped;
+        self.set_rotation((yaw % 360.0, 0.0));
+
+        self.push_and_pick_up_entities(world);
+    }
+
+    /// Mirrors vanilla `OldMinecartBehavior.pushAndPickupEntities`.
+    fn push_and_pick_up_entities(&self, world: &World) {
+        let aabb = self.bounding_box().inflate_xyz(0.2, 0.0, 0.2);
+        let velocity = self.velocity();
+        if velocity.x * velocity.x + velocity.z * velocity.z >= 0.01 {
+            let Some(minecart) = world.get_entity_by_id(self.id()) else {
+                return;
+            };
+            for entity in world.get_pushable_entities(self, &aabb) {
+                if self.rideable
+                    && entity.as_player().is_none()
+                    && entity.entity_type() != &vanilla_entities::IRON_GOLEM
+                    && !entity.entity_type().is_abstract_minecart
+                    && !self.is_vehicle()
+                    && !entity.is_passenger()
+                {
+                    entity.start_riding(&minecart);
+                } else {
+                    entity.push_entity(self);
+                }
+            }
+        } else {
+            for entity in world.get_entities_in_aabb(&aabb) {
+                if entity.id() != self.id()
+                    && !self.has_passenger(entity.as_ref())
+                    && entity.is_pushable()
+                    && entity.entity_type().is_abstract_minecart
+                {
+                    entity.push_entity(self);
+                }
+            }
+        }
+    }
+
+    /// Mirrors vanilla `AbstractMinecart.getMotionDirection`.
+    fn motion_direction(&self) -> Direction {
+        let direction = Direction::from_yaw(self.rotation().0);
+        if self.is_flipped() {
+            direction.opposite().rotate_y_clockwise()
+        } else {
+            direction.rotate_y_clockwise()
+        }
+    }
+}
+
+impl VehicleEntity for MinecartEntity {
+    fn with_vehicle_data<R>(&self, f: impl FnOnce(&mut VehicleEntityData) -> R) -> R {
+        f(self.entity_data.lock().vehicle_entity_mut())
+    }
+
+    fn drop_item(&self) -> ItemRef {
+        self.drop_item
+    }
+}
+
+impl Entity for MinecartEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn tick(&self) {
+        self.tick_vehicle_damage();
+        self.default_tick();
+        if let Some(world) = self.level() {
+            self.tick_rail_movement(&world);
+        }
+        self.state.lock().first_tick = false;
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        self.hurt_vehicle(world, source, amount)
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        let result = self.interact_entity(player, hand, location);
+        if result.consumes_action() {
+            return result;
+        }
+        if player.is_secondary_use_active() || self.is_vehicle() {
+            return InteractionResult::Pass;
+        }
+
+        let Some(world) = self.level() else {
+            return InteractionResult::Pass;
+        };
+        let Some(minecart) = world.get_entity_by_id(self.id()) else {
+            return InteractionResult::Pass;
+        };
+        if player.start_riding(&minecart) {
+            InteractionResult::Consume
+        } else {
+            InteractionResult::Pass
+        }
+    }
+
+    fn dismount_location_for_passenger(&self, passenger: &dyn Entity) -> DVec3 {
+        let fallback = || {
+            let position = self.position();
+            DVec3::new(position.x, self.bounding_box().max_y(), position.z)
+        };
+        let direction = self.motion_direction();
+        let Some(world) = self.level() else {
+            return fallback();
+        };
+
+        let pos = self.block_position();
+        for height in DISMOUNT_HEIGHTS {
+            for (x, z) in dismount_offsets(direction) {
+                let candidate = pos.offset(x, height, z);
+                let floor = block_floor_height(&world, candidate);
+                if !is_block_floor_valid(floor) {
+                    continue;
+                }
+                let location = DVec3::new(
+                    f64::from(candidate.x()) + 0.5,
+                    f64::from(candidate.y()) + floor,
+                    f64::from(candidate.z()) + 0.5,
+                );
+                if can_dismount_to(&world, passenger, location) {
+                    return location;
+                }
+            }
+        }
+
+        fallback()
+    }
+
+    fn is_on_rails(&self) -> bool {
+        self.state.lock().on_rails
+    }
+
+    fn is_pickable(&self) -> bool {
+        !self.is_removed()
+    }
+
+    fn is_pushable(&self) -> bool {
+        true
+    }
+
+    fn can_collide_with(&self, other: &dyn Entity) -> bool {
+        (other.can_be_collided_with(Some(self)) || other.is_pushable())
+            && !self.is_passenger_of_same_vehicle(other)
+    }
+
+    fn blocks_building(&self) -> bool {
+        true
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        if self.is_in_water() {
+            0.005
+        } else {
+            DEFAULT_GRAVITY
+        }
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        let state = self.state.lock();
+        nbt.insert("FlippedRotation", Self::nbt_bool(state.flipped));
+        nbt.insert("HasTicked", Self::nbt_bool(state.first_tick));
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        let mut state = self.state.lock();
+        if let Some(flipped) = nbt.byte("FlippedRotation") {
+            state.flipped = flipped != 0;
+        }
+        if let Some(first_tick) = nbt.byte("HasTicked") {
+            state.first_tick = first_tick != 0;
+        }
+    }
+}
+
+fn is_rail(state: BlockStateId) -> bool {
+    state.get_block().has_tag(&BlockTag::RAILS)
+}
+
+fn rail_shape(state: BlockStateId) -> RailShape {
+    state
+        .try_get_value(&BlockStateProperties::RAIL_SHAPE)
+        .or_else(|| state.try_get_value(&BlockStateProperties::RAIL_SHAPE_STRAIGHT))
+        .unwrap_or(RailShape::NorthSouth)
+}
+
+/// Mirrors vanilla `AbstractMinecart.exits`.
+const fn rail_exits(shape: RailShape) -> [IVec3; 2] {
+    match shape {
+        RailShape::NorthSouth => [IVec3::new(0, 0, -1), IVec3::new(0, 0, 1)],
+        RailShape::EastWest => [IVec3::new(-1, 0, 0), IVec3::new(1, 0, 0)],
+        RailShape::AscendingEast => [IVec3::new(-1, -1, 0), IVec3::new(1, 0, 0)],
+        RailShape::AscendingWest => [IVec3::new(-1, 0, 0), IVec3::new(1, -1, 0)],
+        RailShape::AscendingNorth => [IVec3::new(0, 0, -1), IVec3::new(0, -1, 1)],
+        RailShape::AscendingSouth => [IVec3::new(0, -1, -1), IVec3::new(0, 0, 1)],
+        RailShape::SouthEast => [IVec3::new(0, 0, 1), IVec3::new(1, 0, 0)],
+        RailShape::SouthWest => [IVec3::new(0, 0, 1), IVec3::new(-1, 0, 0)],
+        RailShape::NorthWest => [IVec3::new(0, 0, -1), IVec3::new(-1, 0, 0)],
+        RailShape::NorthEast => [IVec3::new(0, 0, -1), IVec3::new(1, 0, 0)],
+    }
+}
+
+/// Mirrors vanilla `DismountHelper.offsetsForDirection`.
+const fn dismount_offsets(direction: Direction) -> [(i32, i32); 8] {
+    let right = direction.rotate_y_clockwise().offset_xz();
+    let left = direction.rotate_y_counter_clockwise().offset_xz();
+    let back = direction.opposite().offset_xz();
+    let front = direction.offset_xz();
+    [
+        right,
+        left,
+        (back.0 + right.0, back.1 + right.1),
+        (back.0 + left.0, back.1 + left.1),
+        (front.0 + right.0, front.1 + right.1),
+        (front.0 + left.0, front.1 + left.1),
+        back,
+        front,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use steel_registry::vanilla_items;
+
+    fn minecart() -> MinecartEntity {
+        MinecartEntity::new(
+            &vanilla_entities::MINECART,
+            1,
+            DVec3::new(0.5, 64.0, 0.5),
+            Weak::new(),
+            &vanilla_items::MINECART,
+        )
+    }
+
+    #[test]
+    fn ascending_rail_exits_rise_towards_named_side() {
+        let [low, high] = rail_exits(RailShape::AscendingEast);
+
+        assert_eq!(low, IVec3::new(-1, -1, 0));
+        assert_eq!(high, IVec3::new(1, 0, 0));
+    }
+
+    #[test]
+    fn dismount_offsets_prefer_sides_of_motion() {
+        let offsets = dismount_offsets(Direction::North);
+
+        assert_eq!(offsets[0], (1, 0));
+        assert_eq!(offsets[1], (-1, 0));
+        assert_eq!(offsets[6], (0, 1));
+        assert_eq!(offsets[7], (0, -1));
+    }
+
+    #[test]
+    fn minecart_saves_flipped_rotation_and_first_tick() {
+        let minecart = minecart();
+        minecart.state.lock().flipped = true;
+
+        let mut nbt = NbtCompound::new();
+        minecart.save_additional(&mut nbt);
+
+        assert_eq!(nbt.byte("FlippedRotation"), Some(1));
+        assert_eq!(nbt.byte("HasTicked"), Some(1));
+    }
+
+    #[test]
+    fn minecart_natural_slowdown_depends_on_passengers() {
+        let minecart = minecart();
+
+        let slowed = minecart.apply_natural_slowdown(DVec3::new(1.0, 0.5, -1.0));
+
+        assert!((slowed.x - 0.96).abs() < f64::EPSILON);
+        assert!(slowed.y.abs() < f64::EPSILON);
+        assert!((slowed.z + 0.96).abs() < f64::EPSILON);
+    }
+}
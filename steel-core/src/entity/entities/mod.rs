@@ -1,25 +1,59 @@
 //! Concrete entity implementations.
 
+mod armor_stand;
+mod arrow;
 mod block_display;
+mod boat;
 mod chest_minecart;
+mod chicken;
+mod cow;
+mod creeper;
 mod end_crystal;
+mod ender_dragon;
 mod ender_pearl;
 mod experience_orb;
 mod firework_rocket;
+mod hanging;
+mod hopper_minecart;
 mod item;
 mod item_frame;
 mod leash_fence_knot;
+mod lightning_bolt;
+mod minecart;
+mod painting;
 mod pig;
 mod raw;
+mod sheep;
+mod skeleton;
+mod spider;
+mod villager;
+mod villager_trades;
+mod zombie;
 
+pub use armor_stand::{ArmorStandEntity, ArmorStandPart};
+pub use arrow::ArrowEntity;
 pub use block_display::BlockDisplayEntity;
+pub use boat::BoatEntity;
 pub use chest_minecart::ChestMinecartEntity;
+pub use chicken::ChickenEntity;
+pub use cow::CowEntity;
+pub use creeper::CreeperEntity;
 pub use end_crystal::EndCrystalEntity;
+pub use ender_dragon::{EnderDragonEntity, EnderDragonPart, EnderDragonPhase};
 pub use ender_pearl::EnderPearlEntity;
 pub use experience_orb::ExperienceOrbEntity;
 pub use firework_rocket::FireworkRocketEntity;
+pub use hopper_minecart::{HopperMinecartContainer, HopperMinecartEntity};
 pub use item::ItemEntity;
 pub use item_frame::ItemFrameEntity;
 pub use leash_fence_knot::LeashFenceKnotEntity;
+pub use lightning_bolt::LightningBoltEntity;
+pub use minecart::MinecartEntity;
+pub use painting::PaintingEntity;
 pub use pig::PigEntity;
 pub use raw::RawEntity;
+pub use sheep::SheepEntity;
+pub use skeleton::SkeletonEntity;
+pub use spider::SpiderEntity;
+pub use villager::{VillagerEntity, VillagerPoiMemory};
+pub use zombie::ZombieEntity;
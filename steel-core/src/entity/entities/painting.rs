@@ -0,0 +1,415 @@
+//! Vanilla painting entity.
+
+use std::str::FromStr;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::{NbtCompound, NbtTag};
+use steel_macros::entity_behavior;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::painting_variant::PaintingVariantRef;
+use steel_registry::vanilla_entity_data::PaintingEntityData;
+use steel_registry::vanilla_game_rules::ENTITY_DROPS;
+use steel_registry::vanilla_painting_variant_tags::PaintingVariantTag;
+use steel_registry::{
+    REGISTRY, RegistryExt, TaggedRegistryExt, sound_events, vanilla_entities, vanilla_items,
+    vanilla_painting_variants,
+};
+use steel_utils::locks::SyncMutex;
+use steel_utils::{BlockPos, Direction, DowncastType, DowncastTypeKey, Identifier, WorldAabb};
+
+use crate::entity::damage::DamageSource;
+use crate::entity::entities::hanging::{
+    HANGING_DEPTH, SURVIVAL_CHECK_INTERVAL, attached_to_wall, can_coexist, direction_2d_data_value,
+    direction_3d_data_value, direction_from_2d_data_value, has_support,
+    is_caused_by_infinite_materials_player, is_invulnerable_to_base, is_mob_griefing_blocked,
+    skip_attack_interaction,
+};
+use crate::entity::{
+    Entity, EntityBase, EntityBaseLoad, EntityBaseState, EntitySyncedData, RemovalReason,
+    next_entity_id,
+};
+use crate::physics::{WorldCollisionProvider, has_collision};
+use crate::world::World;
+
+/// Vanilla painting hanging on a wall.
+#[entity_behavior(class = "Painting")]
+pub struct PaintingEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    entity_data: SyncMutex<PaintingEntityData>,
+    block_pos: SyncMutex<BlockPos>,
+    check_interval: SyncMutex<i32>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `PaintingEntity`.
+unsafe impl DowncastType for PaintingEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/painting");
+}
+
+impl PaintingEntity {
+    /// Creates a fresh painting from the generic entity factory path.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_attached(
+            entity_type,
+            id,
+            BlockPos::new(
+                position.x.floor() as i32,
+                position.y.floor() as i32,
+                position.z.floor() as i32,
+            ),
+            Direction::South,
+            world,
+        )
+    }
+
+    /// Creates a fresh painting attached to `block_pos`.
+    #[must_use]
+    pub fn new_attached(
+        entity_type: EntityTypeRef,
+        id: i32,
+        block_pos: BlockPos,
+        direction: Direction,
+        world: Weak<World>,
+    ) -> Self {
+        let entity = Self {
+            base: EntityBase::new_with_state(
+                id,
+                EntityBaseState::new(
+                    attached_to_wall(block_pos, direction),
+                    entity_type.dimensions,
+                ),
+                world,
+            ),
+            entity_type,
+            entity_data: SyncMutex::new(PaintingEntityData::new()),
+            block_pos: SyncMutex::new(block_pos),
+            check_interval: SyncMutex::new(0),
+        };
+        entity.set_direction(direction);
+        entity
+    }
+
+    /// Creates a painting from persistent entity data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        let position = load.position;
+        Self {
+            base: EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+            entity_data: SyncMutex::new(PaintingEntityData::new()),
+            block_pos: SyncMutex::new(BlockPos::new(
+                position.x.floor() as i32,
+                position.y.floor() as i32,
+                position.z.floor() as i32,
+            )),
+            check_interval: SyncMutex::new(0),
+        }
+    }
+
+    /// Creates a painting at `pos` with the largest placeable variant that fits.
+    ///
+    /// Vanilla equivalent: `Painting.create`.
+    #[must_use]
+    pub fn create(world: &Arc<World>, pos: BlockPos, direction: Direction) -> Option<Self> {
+        if !direction.is_horizontal() {
+            return None;
+        }
+        let painting = Self::new_attached(
+            &vanilla_entities::PAINTING,
+            next_entity_id(),
+            pos,
+            direction,
+            Arc::downgrade(world),
+        );
+
+        let mut variants: Vec<PaintingVariantRef> = REGISTRY
+            .painting_variants
+            .iter_tag(&PaintingVariantTag::PLACEABLE)
+            .filter(|&variant| {
+                painting.set_variant(variant);
+                painting.survives()
+            })
+            .collect();
+        let max_area = variants.iter().copied().map(variant_area).max()?;
+        variants.retain(|&variant| variant_area(variant) >= max_area);
+
+        let variant = variants[rand::random_range(0..variants.len())];
+        painting.set_variant(variant);
+        Some(painting)
+    }
+
+    /// Returns the block position this painting hangs in.
+    #[must_use]
+    pub fn block_pos(&self) -> BlockPos {
+        *self.block_pos.lock()
+    }
+
+    /// Returns the face this painting is attached to.
+    #[must_use]
+    pub fn direction(&self) -> Direction {
+        *self.entity_data.lock().hanging_entity.direction.get()
+    }
+
+    /// Returns the current painting variant, falling back to vanilla's default.
+    #[must_use]
+    pub fn variant(&self) -> PaintingVariantRef {
+        let id = *self.entity_data.lock().painting_variant.get();
+        if let Ok(id) = usize::try_from(id)
+            && let Some(variant) = REGISTRY.painting_variants.by_id(id)
+        {
+            return variant;
+        }
+
+        &vanilla_painting_variants::KEBAB
+    }
+
+    /// Sets the painting variant and resizes the painting to match.
+    pub fn set_variant(&self, variant: PaintingVariantRef) {
+        if !self.set_variant_by_key(&variant.key) {
+            log::error!("painting variant {} is not registered", variant.key);
+        }
+    }
+
+    /// Returns true when the painting may stay attached at its current position.
+    ///
+    /// Vanilla equivalent: `HangingEntity.survives`.
+    #[must_use]
+    pub fn survives(&self) -> bool {
+        let Some(world) = self.level() else {
+            return false;
+        };
+        let bounding_box = self.bounding_box();
+        if has_collision(
+            &WorldCollisionProvider::for_entity(&world, self),
+            bounding_box,
+        ) {
+            return false;
+        }
+        let direction = self.direction();
+        has_support(&world, bounding_box, direction) && can_coexist(self, direction, false)
+    }
+
+    /// Plays the painting's placement sound.
+    pub fn play_placement_sound(&self) {
+        self.play_sound(&sound_events::ENTITY_PAINTING_PLACE, 1.0, 1.0);
+    }
+
+    fn set_variant_by_key(&self, key: &Identifier) -> bool {
+        let Some(id) = REGISTRY.painting_variants.id_from_key(key) else {
+            return false;
+        };
+        let Ok(id) = i32::try_from(id) else {
+            log::error!("painting variant id {id} does not fit synced-data i32");
+            return false;
+        };
+        self.entity_data.lock().painting_variant.set(id);
+        self.recalculate_bounding_box();
+        true
+    }
+
+    fn set_direction(&self, direction: Direction) {
+        self.entity_data
+            .lock()
+            .hanging_entity
+            .direction
+            .set(direction);
+        let yaw = f32::from(direction_2d_data_value(direction)) * 90.0;
+        self.base.set_rotation((yaw, 0.0));
+        self.base.set_old_rotation_to_current();
+        self.recalculate_bounding_box();
+    }
+
+    fn should_check_survival(&self) -> bool {
+        let mut check_interval = self.check_interval.lock();
+        let check = *check_interval == SURVIVAL_CHECK_INTERVAL;
+        *check_interval = if check { 0 } else { *check_interval + 1 };
+        check
+    }
+
+    /// Drops the painting item.
+    ///
+    /// Vanilla equivalent: `Painting.dropItem`.
+    fn drop_painting(&self, world: &World, source: Option<&DamageSource>) {
+        if !world.get_game_rule(&ENTITY_DROPS) {
+            return;
+        }
+        self.play_sound(&sound_events::ENTITY_PAINTING_BREAK, 1.0, 1.0);
+        if is_caused_by_infinite_materials_player(world, source) {
+            return;
+        }
+        self.spawn_at_location(ItemStack::new(&vanilla_items::PAINTING), 0.0);
+    }
+
+    /// Mirrors vanilla `HangingEntity.recalculateBoundingBox`.
+    fn recalculate_bounding_box(&self) {
+        let bounding_box =
+            Self::calculate_bounding_box(self.block_pos(), self.direction(), self.variant());
+        if let Err(error) = self.base.try_set_position(bounding_box.center()) {
+            panic!(
+                "failed to commit painting {} position recalculation: {error}",
+                self.base.id()
+            );
+        }
+        self.base.set_bounding_box(bounding_box);
+    }
+
+    /// Mirrors vanilla `Painting.calculateBoundingBox`.
+    fn calculate_bounding_box(
+        block_pos: BlockPos,
+        direction: Direction,
+        variant: PaintingVariantRef,
+    ) -> WorldAabb {
+        let width = variant.value().width;
+        let height = variant.value().height;
+        let left = direction
+            .rotate_y_counter_clockwise()
+            .offset_vec()
+            .as_dvec3();
+        let center = attached_to_wall(block_pos, direction)
+            + left * offset_for_painting_size(width)
+            + DVec3::Y * offset_for_painting_size(height);
+        let along_x = direction.offset_vec().x != 0;
+        let x_size = if along_x {
+            HANGING_DEPTH
+        } else {
+            f64::from(width)
+        };
+        let z_size = if along_x {
+            f64::from(width)
+        } else {
+            HANGING_DEPTH
+        };
+        WorldAabb::of_size(center, x_size, f64::from(height), z_size)
+    }
+}
+
+/// Returns vanilla `Painting.variantArea`.
+fn variant_area(variant: PaintingVariantRef) -> i32 {
+    variant.value().width * variant.value().height
+}
+
+/// Returns vanilla `Painting.offsetForPaintingSize`.
+const fn offset_for_painting_size(size: i32) -> f64 {
+    if size % 2 == 0 { 0.5 } else { 0.0 }
+}
+
+impl Entity for PaintingEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn spawn_data(&self) -> i32 {
+        direction_3d_data_value(self.direction())
+    }
+
+    fn spawn_position(&self) -> DVec3 {
+        let block_pos = self.block_pos();
+        DVec3::new(
+            f64::from(block_pos.x()),
+            f64::from(block_pos.y()),
+            f64::from(block_pos.z()),
+        )
+    }
+
+    fn hanging_direction(&self) -> Option<Direction> {
+        Some(self.direction())
+    }
+
+    fn is_pickable(&self) -> bool {
+        true
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn tick(&self) {
+        let Some(world) = self.level() else {
+            return;
+        };
+        self.check_below_world();
+        if self.should_check_survival() && !self.is_removed() && !self.survives() {
+            self.set_removed(RemovalReason::Discarded);
+            self.drop_painting(&world, None);
+        }
+    }
+
+    fn skip_attack_interaction(&self, source: &dyn Entity) -> bool {
+        skip_attack_interaction(self, self.block_pos(), source)
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, _amount: f32) -> bool {
+        if is_invulnerable_to_base(self, source) || is_mob_griefing_blocked(world, source) {
+            return false;
+        }
+        if !self.is_removed() {
+            self.kill(world);
+            self.mark_hurt();
+            self.drop_painting(world, Some(source));
+        }
+        true
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        let block_pos = self.block_pos();
+        nbt.insert(
+            "block_pos",
+            NbtTag::IntArray(vec![block_pos.x(), block_pos.y(), block_pos.z()]),
+        );
+        nbt.insert("facing", direction_2d_data_value(self.direction()) as i8);
+        nbt.insert("variant", self.variant().key.to_string());
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        if let Some(block_pos) = nbt.int_array("block_pos")
+            && block_pos.len() == 3
+        {
+            *self.block_pos.lock() = BlockPos::new(block_pos[0], block_pos[1], block_pos[2]);
+        }
+        if let Some(variant) = nbt.string("variant")
+            && let Ok(key) = Identifier::from_str(variant.to_str().as_ref())
+        {
+            self.set_variant_by_key(&key);
+        }
+        let direction = nbt
+            .byte("facing")
+            .and_then(|value| direction_from_2d_data_value(i32::from(value)))
+            .unwrap_or(Direction::South);
+        self.set_direction(direction);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use steel_registry::test_support::init_test_registry;
+
+    #[test]
+    fn even_sized_paintings_shift_left_and_up() {
+        init_test_registry();
+        let Some(variant) = REGISTRY
+            .painting_variants
+            .by_key(&Identifier::vanilla_static("pointer"))
+        else {
+            panic!("pointer painting variant should be registered");
+        };
+        let bounding_box = PaintingEntity::calculate_bounding_box(
+            BlockPos::new(0, 64, 0),
+            Direction::South,
+            variant,
+        );
+
+        assert!((bounding_box.width() - 4.0).abs() < 1.0E-9);
+        assert!((bounding_box.height() - 4.0).abs() < 1.0E-9);
+        assert!((bounding_box.center().x - 1.0).abs() < 1.0E-9);
+        assert!((bounding_box.center().y - 65.0).abs() < 1.0E-9);
+    }
+}
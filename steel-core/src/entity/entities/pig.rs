@@ -20,12 +20,12 @@ use steel_registry::sound_event::SoundEventRef;
 use steel_registry::vanilla_entity_data::PigEntityData;
 use steel_registry::vanilla_item_tags::ItemTag;
 use steel_registry::{
-    REGISTRY, RegistryExt, TaggedRegistryExt, sound_events, vanilla_attributes, vanilla_items,
-    vanilla_pig_sound_variants, vanilla_pig_variants,
+    REGISTRY, RegistryExt, TaggedRegistryExt, sound_events, vanilla_attributes, vanilla_entities,
+    vanilla_items, vanilla_pig_sound_variants, vanilla_pig_variants,
 };
 use steel_utils::locks::SyncMutex;
 use steel_utils::random::legacy_random::LegacyRandom;
-use steel_utils::types::InteractionHand;
+use steel_utils::types::{Difficulty, InteractionHand};
 use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey, Identifier};
 
 use crate::behavior::InteractionResult;
@@ -34,11 +34,12 @@ use crate::entity::ai::goal::{
     TemptGoal, WaterAvoidingRandomStrollGoal,
 };
 use crate::entity::damage::DamageSource;
+use crate::entity::entities::LightningBoltEntity;
 use crate::entity::{
-    AgeableMob, AgeableMobBase, Animal, AnimalBase, Entity, EntityBase, EntityBaseLoad, EntityPose,
-    EntitySpawnReason, EntitySyncedData, ItemBasedSteering, ItemSteerable, LivingEntity,
-    LivingEntityBase, Mob, MobBase, MobEffectSyncChange, PathfinderMob, SharedEntity,
-    SpawnGroupData,
+    AgeableMob, AgeableMobBase, Animal, AnimalBase, ConversionParams, Entity, EntityBase,
+    EntityBaseLoad, EntityPose, EntitySpawnReason, EntitySyncedData, ItemBasedSteering,
+    ItemSteerable, LivingEntity, LivingEntityBase, Mob, MobBase, MobEffectSyncChange,
+    PathfinderMob, SharedEntity, SpawnGroupData,
 };
 use crate::inventory::equipment::EquipmentSlot;
 use crate::physics::MoveResult;
@@ -406,6 +407,27 @@ impl Entity for PigEntity {
         Mob::check_mob_despawn(self);
     }
 
+    fn thunder_hit(&self, world: &Arc<World>, _lightning: &LightningBoltEntity) {
+        if world.difficulty() == Difficulty::Peaceful
+            || self
+                .convert_to(
+                    world,
+                    &vanilla_entities::ZOMBIFIED_PIGLIN,
+                    ConversionParams::single(false, true),
+                    &mut |zombified_piglin: &dyn Mob| {
+                        zombified_piglin.living_base().equipment().lock().set(
+                            EquipmentSlot::MainHand,
+                            ItemStack::new(&vanilla_items::GOLDEN_SWORD),
+                        );
+                        zombified_piglin.set_persistence_required();
+                    },
+                )
+                .is_none()
+        {
+            self.default_thunder_hit(world);
+        }
+    }
+
     fn is_alive(&self) -> bool {
         !self.is_removed() && self.get_health() > 0.0
     }
@@ -0,0 +1,782 @@
+//! Sheep entity implementation.
+
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::NbtCompound;
+use steel_macros::{entity_behavior, entity_impl};
+use steel_protocol::packets::game::{AttributeSnapshot, EquipmentSlotItem, SoundSource};
+use steel_registry::biome::BiomeRef;
+use steel_registry::dye_color::DyeColor;
+use steel_registry::entity_type::{EntityDimensions, EntityTypeRef};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::loot_table::{LootContext, LootTableRef};
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_biome_tags::BiomeTag;
+use steel_registry::vanilla_entity_data::SheepEntityData;
+use steel_registry::vanilla_item_tags::ItemTag;
+use steel_registry::{
+    REGISTRY, TaggedRegistryExt, sound_events, vanilla_attributes, vanilla_game_events,
+    vanilla_items,
+};
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::InteractionHand;
+use steel_utils::{BlockPos, BlockStateId, Downcast, DowncastType, DowncastTypeKey, Identifier};
+
+use crate::behavior::InteractionResult;
+use crate::entity::ai::goal::{
+    BreedGoal, EatBlockGoal, FloatGoal, FollowParentGoal, LookAtPlayerGoal, PanicGoal,
+    RandomLookAroundGoal, TemptGoal, WaterAvoidingRandomStrollGoal,
+};
+use crate::entity::damage::DamageSource;
+use crate::entity::{
+    AgeableMob, AgeableMobBase, Animal, AnimalBase, Entity, EntityBase, EntityBaseLoad, EntityPose,
+    EntitySpawnReason, EntitySyncedData, LivingEntity, LivingEntityBase, Mob, MobBase,
+    MobEffectSyncChange, PathfinderMob, SpawnGroupData, entity_loot_ref,
+};
+use crate::physics::MoveResult;
+use crate::player::Player;
+use crate::world::World;
+use crate::world::game_event_context::GameEventContext;
+
+const SHEEP_BABY_DIMENSIONS: EntityDimensions = EntityDimensions::new(0.45, 0.65, 0.65625);
+const WOOL_COLOR_MASK: i8 = 0x0F;
+const SHEARED_FLAG: i8 = 0x10;
+const EAT_GRASS_AGE_UP_SECONDS: i32 = 60;
+
+const TEMPERATE_RARE_COLORS: [(DyeColor, i32); 4] = [
+    (DyeColor::Black, 5),
+    (DyeColor::Gray, 5),
+    (DyeColor::LightGray, 5),
+    (DyeColor::Brown, 3),
+];
+const WARM_RARE_COLORS: [(DyeColor, i32); 4] = [
+    (DyeColor::Gray, 5),
+    (DyeColor::LightGray, 5),
+    (DyeColor::White, 5),
+    (DyeColor::Black, 3),
+];
+const COLD_RARE_COLORS: [(DyeColor, i32); 4] = [
+    (DyeColor::LightGray, 5),
+    (DyeColor::Gray, 5),
+    (DyeColor::White, 5),
+    (DyeColor::Brown, 3),
+];
+const COMMON_COLOR_WEIGHT: i32 = 82;
+const COMMON_COLOR_PINK_WEIGHT: i32 = 1;
+const COMMON_COLOR_BASE_WEIGHT: i32 = 499;
+
+/// Vanilla sheep entity.
+#[entity_behavior(class = "Sheep")]
+pub struct SheepEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    living_base: LivingEntityBase,
+    mob_base: MobBase,
+    ageable_base: AgeableMobBase,
+    animal_base: AnimalBase,
+    entity_data: SyncMutex<SheepEntityData>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `SheepEntity`.
+unsafe impl DowncastType for SheepEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/sheep");
+}
+
+impl SheepEntity {
+    /// Creates a new sheep entity.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_with_base(
+            EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+        )
+    }
+
+    /// Creates a sheep entity from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self::new_with_base(
+            EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+        )
+    }
+
+    fn new_with_base(base: EntityBase, entity_type: EntityTypeRef) -> Self {
+        let living_base = LivingEntityBase::new(entity_type);
+        let mob_base = MobBase::new();
+        let ageable_base = AgeableMobBase::new();
+        let animal_base = AnimalBase::new();
+        AnimalBase::initialize_pathfinding_malus(&mob_base);
+        let mut entity_data = SheepEntityData::new();
+        living_base.initialize_synced_data(&mut entity_data);
+        {
+            let mut goal_selector = mob_base.goal_selector().lock();
+            goal_selector.add_goal(0, FloatGoal::new(&mob_base));
+            goal_selector.add_goal(1, PanicGoal::new(1.25));
+            goal_selector.add_goal(2, BreedGoal::new(1.0));
+            goal_selector.add_goal(3, TemptGoal::new(1.1, SheepEntity::is_food, false));
+            goal_selector.add_goal(4, FollowParentGoal::new(1.1));
+            goal_selector.add_goal(5, EatBlockGoal::new());
+            goal_selector.add_goal(6, WaterAvoidingRandomStrollGoal::new(1.0));
+            goal_selector.add_goal(7, LookAtPlayerGoal::new(6.0));
+            goal_selector.add_goal(8, RandomLookAroundGoal::new());
+        }
+
+        Self {
+            base,
+            entity_type,
+            living_base,
+            mob_base,
+            ageable_base,
+            animal_base,
+            entity_data: SyncMutex::new(entity_data),
+        }
+    }
+
+    /// Returns whether this sheep is a baby.
+    #[must_use]
+    pub fn is_baby(&self) -> bool {
+        AgeableMob::is_baby(self)
+    }
+
+    /// Sets the vanilla baby state using the `AgeableMob` start age.
+    pub fn set_baby(&self, baby: bool) {
+        AgeableMob::set_baby(self, baby);
+    }
+
+    /// Returns the wool color stored in the synced wool byte.
+    #[must_use]
+    pub fn color(&self) -> DyeColor {
+        let wool = *self.entity_data.lock().wool.get();
+        DyeColor::by_id(i32::from(wool & WOOL_COLOR_MASK))
+    }
+
+    /// Sets the wool color while preserving the sheared flag.
+    pub fn set_color(&self, color: DyeColor) {
+        let mut entity_data = self.entity_data.lock();
+        let wool = *entity_data.wool.get();
+        let color_id = (color.id() as i8) & WOOL_COLOR_MASK;
+        entity_data.wool.set((wool & !WOOL_COLOR_MASK) | color_id);
+    }
+
+    /// Returns whether this sheep has been sheared.
+    #[must_use]
+    pub fn is_sheared(&self) -> bool {
+        *self.entity_data.lock().wool.get() & SHEARED_FLAG != 0
+    }
+
+    /// Sets the sheared flag while preserving the wool color.
+    pub fn set_sheared(&self, sheared: bool) {
+        let mut entity_data = self.entity_data.lock();
+        let wool = *entity_data.wool.get();
+        if sheared {
+            entity_data.wool.set(wool | SHEARED_FLAG);
+        } else {
+            entity_data.wool.set(wool & !SHEARED_FLAG);
+        }
+    }
+
+    /// Returns vanilla `Sheep.readyForShearing`.
+    #[must_use]
+    pub fn ready_for_shearing(&self) -> bool {
+        Entity::is_alive(self) && !self.is_sheared() && !self.is_baby()
+    }
+
+    /// Runs vanilla `Sheep.shear`, dropping wool from the shearing loot table.
+    pub fn shear(&self, world: &Arc<World>, sound_source: SoundSource, tool: &ItemStack) {
+        world.play_sound_at(
+            &sound_events::ENTITY_SHEEP_SHEAR,
+            sound_source,
+            self.position(),
+            1.0,
+            1.0,
+            None,
+        );
+
+        // Vanilla's `shearing/sheep` table dispatches on the sheep color; roll the
+        // per-color subtable directly since loot entity refs don't carry the color yet.
+        let loot_key =
+            Identifier::vanilla(format!("shearing/sheep/{}", self.color().serialized_name()));
+        if let Some(loot_table) = REGISTRY.loot_tables.by_key(&loot_key) {
+            let position = self.position();
+            let drops = {
+                let mut rng = rand::rng();
+                let mut ctx = LootContext::new(&mut rng)
+                    .with_origin(position.x, position.y, position.z)
+                    .with_game_time(world.game_time())
+                    .with_this_entity(entity_loot_ref(self))
+                    .with_tool(tool);
+                loot_table.get_random_items(&mut ctx)
+            };
+
+            for item_stack in drops {
+                for _ in 0..item_stack.count() {
+                    let Some(item_entity) =
+                        self.spawn_at_location(item_stack.copy_with_count(1), 1.0)
+                    else {
+                        continue;
+                    };
+                    let velocity = item_entity.velocity()
+                        + DVec3::new(
+                            f64::from((rand::random::<f32>() - rand::random::<f32>()) * 0.1),
+                            f64::from(rand::random::<f32>() * 0.05),
+                            f64::from((rand::random::<f32>() - rand::random::<f32>()) * 0.1),
+                        );
+                    item_entity.set_velocity(velocity);
+                }
+            }
+        }
+
+        self.set_sheared(true);
+    }
+
+    fn update_dirty_mob_effect_entity_data(&self) {
+        if !self.living_base.take_effects_dirty() {
+            return;
+        }
+
+        let display = self.living_base.mob_effect_display_state();
+
+        {
+            let mut entity_data = self.entity_data.lock();
+            let living = entity_data.living_entity_mut();
+            living.effect_particles.set(display.particles);
+            living.effect_ambience.set(display.ambient);
+        }
+
+        self.entity_data.set_base_invisible_flag(display.invisible);
+        self.entity_data
+            .set_base_glowing_flag(self.has_glowing_tag() || display.glowing);
+    }
+
+    /// Returns whether the stack is vanilla sheep food.
+    #[must_use]
+    pub fn is_food(item_stack: &ItemStack) -> bool {
+        REGISTRY
+            .items
+            .is_in_tag(item_stack.item(), &ItemTag::SHEEP_FOOD)
+    }
+
+    /// Picks a spawn color using vanilla `SheepColorSpawnRules`.
+    #[must_use]
+    pub fn random_spawn_color(biome: Option<BiomeRef>) -> DyeColor {
+        let (rare_colors, common_color) = match biome {
+            Some(biome) if biome.has_tag(&BiomeTag::SPAWNS_WARM_VARIANT_FARM_ANIMALS) => {
+                (&WARM_RARE_COLORS, DyeColor::Brown)
+            }
+            Some(biome) if biome.has_tag(&BiomeTag::SPAWNS_COLD_VARIANT_FARM_ANIMALS) => {
+                (&COLD_RARE_COLORS, DyeColor::Black)
+            }
+            _ => (&TEMPERATE_RARE_COLORS, DyeColor::White),
+        };
+
+        let total_weight =
+            rare_colors.iter().map(|(_, weight)| weight).sum::<i32>() + COMMON_COLOR_WEIGHT;
+        let mut roll = rand::random_range(0..total_weight);
+        for &(color, weight) in rare_colors {
+            if roll < weight {
+                return color;
+            }
+            roll -= weight;
+        }
+
+        if rand::random_range(0..COMMON_COLOR_BASE_WEIGHT + COMMON_COLOR_PINK_WEIGHT)
+            < COMMON_COLOR_BASE_WEIGHT
+        {
+            common_color
+        } else {
+            DyeColor::Pink
+        }
+    }
+}
+
+#[entity_impl(class(animal))]
+impl Entity for SheepEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn dimensions_for_pose(&self, _pose: EntityPose) -> EntityDimensions {
+        let scale = LivingEntity::get_scale(self);
+        if self.is_baby() {
+            SHEEP_BABY_DIMENSIONS.scale(scale)
+        } else if self.entity_type.fixed {
+            self.entity_type.dimensions
+        } else {
+            self.entity_type.dimensions.scale(scale)
+        }
+    }
+
+    fn tick(&self) {
+        self.default_tick();
+        self.living_base.decrement_invulnerable_time();
+        self.tick_mob_effects();
+
+        if self.is_dead_or_dying() {
+            LivingEntity::tick_death(self);
+            self.tick_living_state();
+            return;
+        }
+
+        if !self.is_removed() {
+            self.ai_step();
+        }
+
+        self.tick_living_state();
+    }
+
+    fn check_despawn(&self) {
+        Mob::check_mob_despawn(self);
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.is_removed() && self.get_health() > 0.0
+    }
+
+    fn is_pickable(&self) -> bool {
+        !self.is_removed()
+    }
+
+    fn is_pushable(&self) -> bool {
+        Entity::is_alive(self) && !self.is_spectator() && !self.on_climbable()
+    }
+
+    fn is_effective_ai(&self) -> bool {
+        self.is_server_driven_movement() && !self.is_no_ai()
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        LivingEntity::get_attribute_gravity(self)
+    }
+
+    fn can_freeze(&self) -> bool {
+        self.default_living_can_freeze()
+    }
+
+    fn can_walk_on_powder_snow(&self) -> bool {
+        self.default_living_can_walk_on_powder_snow()
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn update_data_before_sync(&self) {
+        self.update_dirty_mob_effect_entity_data();
+    }
+
+    fn pack_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().syncable_snapshots()
+    }
+
+    fn drain_dirty_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().drain_dirty_sync()
+    }
+
+    fn drain_dirty_mob_effects(&self) -> Vec<MobEffectSyncChange> {
+        self.living_base.drain_dirty_mob_effects()
+    }
+
+    fn pack_all_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.pack_living_equipment()
+    }
+
+    fn drain_dirty_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.drain_dirty_living_equipment()
+    }
+
+    fn max_up_step(&self) -> f32 {
+        self.attributes()
+            .lock()
+            .get_value(vanilla_attributes::STEP_HEIGHT)
+            .unwrap_or(0.6) as f32
+    }
+
+    fn sound_source(&self) -> SoundSource {
+        SoundSource::Neutral
+    }
+
+    fn play_step_sound(&self, _pos: BlockPos, _block_state: BlockStateId) {
+        self.play_sound(&sound_events::ENTITY_SHEEP_STEP, 0.15, 1.0);
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        LivingEntity::hurt_server(self, world, source, amount)
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        Mob::interact_mob(self, player, hand, location)
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        self.save_mob(nbt);
+        self.save_ageable_mob(nbt);
+        self.save_animal(nbt);
+        nbt.insert("Sheared", i8::from(self.is_sheared()));
+        nbt.insert("Color", self.color().id() as i8);
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        self.load_mob(nbt);
+        self.load_ageable_mob(nbt);
+        self.load_animal(nbt);
+
+        self.set_sheared(nbt.byte("Sheared").is_some_and(|sheared| sheared != 0));
+        self.set_color(DyeColor::by_id(i32::from(nbt.byte("Color").unwrap_or(0))));
+    }
+}
+
+impl LivingEntity for SheepEntity {
+    fn living_base(&self) -> &LivingEntityBase {
+        &self.living_base
+    }
+
+    fn get_health(&self) -> f32 {
+        *self.entity_data.lock().living_entity().health.get()
+    }
+
+    fn set_health(&self, health: f32) {
+        let max_health = self.get_max_health();
+        let clamped = health.clamp(0.0, max_health);
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .health
+            .set(clamped);
+    }
+
+    fn is_baby(&self) -> bool {
+        AgeableMob::is_baby(self)
+    }
+
+    fn hurt_sound(&self, _source: &DamageSource) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_SHEEP_HURT)
+    }
+
+    fn death_sound(&self) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_SHEEP_DEATH)
+    }
+
+    fn death_loot_table(&self) -> Option<LootTableRef> {
+        if self.has_custom_death_loot_table() {
+            return self.custom_death_loot_table();
+        }
+
+        let loot_key = if self.is_sheared() {
+            Identifier::vanilla_static("entities/sheep")
+        } else {
+            Identifier::vanilla(format!("entities/sheep/{}", self.color().serialized_name()))
+        };
+        REGISTRY.loot_tables.by_key(&loot_key)
+    }
+
+    fn server_ai_step(&self) {
+        Mob::mob_server_ai_step(self);
+    }
+
+    fn before_actually_hurt(&self, _source: &DamageSource, _amount: f32) {
+        Animal::reset_love(self);
+    }
+
+    fn ai_step(&self) -> Option<MoveResult> {
+        let result = self.default_ai_step();
+
+        AgeableMob::tick_ageable_mob(self);
+        Animal::tick_animal_love(self);
+        result
+    }
+}
+
+impl AgeableMob for SheepEntity {
+    fn ageable_base(&self) -> &AgeableMobBase {
+        &self.ageable_base
+    }
+
+    fn is_age_locked(&self) -> bool {
+        *self.entity_data.lock().ageable_mob().age_locked.get()
+    }
+
+    fn set_age_locked(&self, age_locked: bool) {
+        self.entity_data
+            .lock()
+            .ageable_mob_mut()
+            .age_locked
+            .set(age_locked);
+    }
+
+    fn set_synced_baby(&self, baby: bool) {
+        self.entity_data.lock().ageable_mob_mut().baby.set(baby);
+    }
+
+    fn age_boundary_changed(&self, _baby: bool) {
+        self.refresh_dimensions();
+    }
+}
+
+impl Animal for SheepEntity {
+    fn animal_base(&self) -> &AnimalBase {
+        &self.animal_base
+    }
+
+    fn is_food(&self, item_stack: &ItemStack) -> bool {
+        SheepEntity::is_food(item_stack)
+    }
+
+    fn initialize_breed_offspring(&self, partner: &dyn Animal, offspring: &dyn Animal) {
+        let Some(offspring) = offspring.downcast_ref::<SheepEntity>() else {
+            return;
+        };
+        let partner_color = partner
+            .downcast_ref::<SheepEntity>()
+            .map_or_else(|| self.color(), SheepEntity::color);
+        // TODO: Mix colors via the dye crafting recipe like vanilla `DyeColor.getMixedColor`.
+        let color = if rand::random::<bool>() {
+            self.color()
+        } else {
+            partner_color
+        };
+        offspring.set_color(color);
+    }
+}
+
+impl Mob for SheepEntity {
+    fn mob_base(&self) -> &MobBase {
+        &self.mob_base
+    }
+
+    fn tick_goal_selectors(&self) {
+        PathfinderMob::tick_pathfinder_goal_selectors(self);
+    }
+
+    fn tick_path_navigation(&self) {
+        PathfinderMob::tick_pathfinder_path_navigation(self);
+    }
+
+    fn custom_server_ai_step(&self) {
+        Animal::custom_server_ai_step_animal(self);
+    }
+
+    fn ambient_sound(&self) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_SHEEP_AMBIENT)
+    }
+
+    fn ate(&self) {
+        self.set_sheared(false);
+        if self.is_baby() {
+            self.age_up(EAT_GRASS_AGE_UP_SECONDS, false);
+        }
+    }
+
+    fn remove_when_far_away(&self, dist_sqr: f64) -> bool {
+        Animal::remove_when_far_away_animal(self, dist_sqr)
+    }
+
+    fn finalize_spawn(
+        &self,
+        world: &Arc<World>,
+        spawn_reason: EntitySpawnReason,
+        group_data: Option<SpawnGroupData>,
+    ) -> Option<SpawnGroupData> {
+        let biome = world.biome_at(self.block_position());
+        self.set_color(SheepEntity::random_spawn_color(biome));
+
+        self.finalize_spawn_ageable_mob(world, spawn_reason, group_data)
+    }
+
+    fn mob_interact(&self, player: &Player, hand: InteractionHand) -> InteractionResult {
+        let tool = {
+            let inventory = player.inventory.lock();
+            let item_stack = inventory.get_item_in_hand(hand);
+            item_stack.copy_with_count(item_stack.count())
+        };
+        if !tool.is(&vanilla_items::SHEARS) {
+            return Animal::mob_interact_animal(self, player, hand);
+        }
+
+        let Some(world) = self.level() else {
+            return InteractionResult::Consume;
+        };
+        if !self.ready_for_shearing() {
+            return InteractionResult::Consume;
+        }
+
+        self.shear(&world, SoundSource::Players, &tool);
+        world.game_event_at(
+            &vanilla_game_events::SHEAR,
+            self.position(),
+            &GameEventContext::new(Some(player), None),
+        );
+        player.hurt_item_in_hand(hand, 1);
+        InteractionResult::SuccessServer
+    }
+
+    fn mob_flags(&self) -> i8 {
+        *self.entity_data.lock().mob().mob_flags.get()
+    }
+
+    fn set_mob_flags(&self, flags: i8) {
+        self.entity_data.lock().mob_mut().mob_flags.set(flags);
+    }
+}
+
+impl PathfinderMob for SheepEntity {}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use simdnbt::borrow::read_compound as read_borrowed_compound;
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::{vanilla_damage_types, vanilla_entities};
+
+    use super::*;
+
+    fn sheep(id: i32) -> SheepEntity {
+        SheepEntity::new(&vanilla_entities::SHEEP, id, DVec3::ZERO, Weak::new())
+    }
+
+    #[test]
+    fn sheep_initializes_vanilla_living_attributes_and_health() {
+        init_test_registry();
+
+        let sheep = sheep(1);
+
+        assert_eq!(sheep.get_health().to_bits(), 8.0_f32.to_bits());
+        let attributes = sheep.attributes().lock();
+        assert_eq!(
+            attributes
+                .required_value(vanilla_attributes::MOVEMENT_SPEED)
+                .to_bits(),
+            f64::from(0.23_f32).to_bits()
+        );
+    }
+
+    #[test]
+    fn sheep_registers_vanilla_passive_goals_with_eat_block() {
+        init_test_registry();
+
+        let sheep = sheep(1);
+
+        let selector = sheep.mob_base().goal_selector().lock();
+        assert_eq!(
+            selector.available_goal_priorities(),
+            vec![0, 1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+
+    #[test]
+    fn sheep_wool_byte_packs_color_and_sheared_flag() {
+        init_test_registry();
+
+        let sheep = sheep(1);
+        sheep.set_color(DyeColor::Black);
+        sheep.set_sheared(true);
+
+        assert_eq!(*sheep.entity_data.lock().wool.get(), 15 | SHEARED_FLAG);
+        assert_eq!(sheep.color(), DyeColor::Black);
+        assert!(sheep.is_sheared());
+
+        sheep.set_color(DyeColor::Orange);
+        assert!(sheep.is_sheared());
+        sheep.set_sheared(false);
+        assert_eq!(sheep.color(), DyeColor::Orange);
+        assert!(!sheep.is_sheared());
+    }
+
+    #[test]
+    fn sheep_shearing_requires_alive_unsheared_adult() {
+        init_test_registry();
+
+        let sheep = sheep(1);
+        assert!(sheep.ready_for_shearing());
+
+        sheep.set_sheared(true);
+        assert!(!sheep.ready_for_shearing());
+
+        sheep.set_sheared(false);
+        sheep.set_baby(true);
+        assert!(!sheep.ready_for_shearing());
+    }
+
+    #[test]
+    fn sheep_eating_regrows_wool_and_ages_up_babies() {
+        init_test_registry();
+
+        let sheep = sheep(1);
+        sheep.set_sheared(true);
+        sheep.set_baby(true);
+        let age = sheep.get_age();
+
+        Mob::ate(&sheep);
+
+        assert!(!sheep.is_sheared());
+        assert_eq!(sheep.get_age(), age + EAT_GRASS_AGE_UP_SECONDS * 20);
+    }
+
+    #[test]
+    fn sheep_sounds_use_vanilla_sheep_sounds() {
+        init_test_registry();
+
+        let sheep = sheep(1);
+        let source = DamageSource::environment(&vanilla_damage_types::GENERIC);
+
+        assert_eq!(
+            Mob::ambient_sound(&sheep).map(|sound| &sound.key),
+            Some(&sound_events::ENTITY_SHEEP_AMBIENT.key)
+        );
+        assert_eq!(
+            LivingEntity::hurt_sound(&sheep, &source).map(|sound| &sound.key),
+            Some(&sound_events::ENTITY_SHEEP_HURT.key)
+        );
+    }
+
+    #[test]
+    fn sheep_breeding_offspring_inherits_a_parent_color() {
+        init_test_registry();
+
+        let sheep_a = sheep(1);
+        let sheep_b = sheep(2);
+        let offspring = sheep(3);
+        sheep_a.set_color(DyeColor::Red);
+        sheep_b.set_color(DyeColor::Blue);
+
+        sheep_a.initialize_breed_offspring(&sheep_b, &offspring);
+
+        assert!(matches!(offspring.color(), DyeColor::Red | DyeColor::Blue));
+    }
+
+    #[test]
+    fn sheep_round_trips_vanilla_wool_data() {
+        init_test_registry();
+
+        let sheep_a = sheep(1);
+        sheep_a.set_color(DyeColor::Lime);
+        sheep_a.set_sheared(true);
+
+        let mut nbt = NbtCompound::new();
+        sheep_a.save_additional(&mut nbt);
+        assert_eq!(nbt.byte("Sheared"), Some(1));
+        assert_eq!(nbt.byte("Color"), Some(5));
+
+        let mut bytes = Vec::new();
+        nbt.write(&mut bytes);
+        let borrowed = read_borrowed_compound(&mut Cursor::new(&bytes))
+            .unwrap_or_else(|error| panic!("test nbt should reborrow: {error}"));
+        let loaded = sheep(2);
+        loaded.load_additional((&borrowed).into());
+
+        assert_eq!(loaded.color(), DyeColor::Lime);
+        assert!(loaded.is_sheared());
+    }
+}
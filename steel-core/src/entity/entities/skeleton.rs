@@ -0,0 +1,495 @@
+//! Skeleton entity implementation.
+
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::NbtCompound;
+use steel_macros::{entity_behavior, entity_impl};
+use steel_protocol::packets::game::{AttributeSnapshot, EquipmentSlotItem, SoundSource};
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_entity_data::SkeletonEntityData;
+use steel_registry::{sound_events, vanilla_attributes, vanilla_entities, vanilla_items};
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::{Difficulty, InteractionHand};
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey};
+
+use crate::behavior::InteractionResult;
+use crate::entity::ai::goal::{
+    AvoidEntityGoal, FleeSunGoal, HurtByTargetGoal, LookAtPlayerGoal, MeleeAttackGoal,
+    NearestAttackableTargetGoal, RandomLookAroundGoal, RangedBowAttackGoal, RestrictSunGoal,
+    WaterAvoidingRandomStrollGoal,
+};
+use crate::entity::damage::DamageSource;
+use crate::entity::entities::ArrowEntity;
+use crate::entity::{
+    AbstractArrow as _, Entity, EntityBase, EntityBaseLoad, EntitySpawnReason, EntitySyncedData,
+    LivingEntity, LivingEntityBase, Mob, MobBase, MobEffectSyncChange, Monster, PathfinderMob,
+    Projectile as _, RangedAttackMob, SharedEntity, SpawnGroupData, next_entity_id,
+};
+use crate::inventory::equipment::EquipmentSlot;
+use crate::physics::MoveResult;
+use crate::player::Player;
+use crate::world::World;
+
+const MONSTER_EXPERIENCE_REWARD: i32 = 5;
+const WEAPON_GOAL_PRIORITY: i32 = 4;
+const BOW_ATTACK_SPEED_MODIFIER: f64 = 1.0;
+const BOW_ATTACK_RADIUS: f32 = 15.0;
+const BOW_ATTACK_INTERVAL: i32 = 40;
+const HARD_BOW_ATTACK_INTERVAL: i32 = 20;
+const MELEE_ATTACK_SPEED_MODIFIER: f64 = 1.2;
+/// Vanilla `AbstractSkeleton.performRangedAttack` arrow velocity.
+const ARROW_SHOOT_POWER: f32 = 1.6;
+const TURTLE_TARGET_INTERVAL: i32 = 10;
+
+/// Vanilla skeleton entity.
+#[entity_behavior(class = "Skeleton")]
+pub struct SkeletonEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    living_base: LivingEntityBase,
+    mob_base: MobBase,
+    entity_data: SyncMutex<SkeletonEntityData>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `SkeletonEntity`.
+unsafe impl DowncastType for SkeletonEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/skeleton");
+}
+
+impl SkeletonEntity {
+    /// Creates a new skeleton entity.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_with_base(
+            EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+        )
+    }
+
+    /// Creates a skeleton entity from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self::new_with_base(
+            EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+        )
+    }
+
+    fn new_with_base(base: EntityBase, entity_type: EntityTypeRef) -> Self {
+        let living_base = LivingEntityBase::new(entity_type);
+        let mob_base = MobBase::new();
+        mob_base.set_xp_reward(MONSTER_EXPERIENCE_REWARD);
+        let mut entity_data = SkeletonEntityData::new();
+        living_base.initialize_synced_data(&mut entity_data);
+        {
+            let mut goal_selector = mob_base.goal_selector().lock();
+            goal_selector.add_goal(2, RestrictSunGoal::new());
+            goal_selector.add_goal(3, FleeSunGoal::new(1.0));
+            goal_selector.add_goal(
+                3,
+                AvoidEntityGoal::with_selector(6.0, 1.0, 1.2, |target, _| {
+                    target.entity_type() == &vanilla_entities::WOLF
+                }),
+            );
+            goal_selector.add_goal(5, WaterAvoidingRandomStrollGoal::new(1.0));
+            goal_selector.add_goal(6, LookAtPlayerGoal::new(8.0));
+            goal_selector.add_goal(6, RandomLookAroundGoal::new());
+        }
+        {
+            let mut target_selector = mob_base.target_selector().lock();
+            target_selector.add_goal(1, HurtByTargetGoal::new(Vec::new()));
+            target_selector.add_goal(2, NearestAttackableTargetGoal::new_for_players(true));
+            target_selector.add_goal(
+                3,
+                NearestAttackableTargetGoal::new_for_entity_types(
+                    vec![&vanilla_entities::IRON_GOLEM],
+                    true,
+                ),
+            );
+            target_selector.add_goal(
+                3,
+                NearestAttackableTargetGoal::new_for_entity_types_with_selector(
+                    vec![&vanilla_entities::TURTLE],
+                    TURTLE_TARGET_INTERVAL,
+                    true,
+                    false,
+                    |target, _| target.is_baby() && !target.is_in_water(),
+                ),
+            );
+        }
+
+        Self {
+            base,
+            entity_type,
+            living_base,
+            mob_base,
+            entity_data: SyncMutex::new(entity_data),
+        }
+    }
+
+    /// Mirrors vanilla `AbstractSkeleton.reassessWeaponGoal`: swaps between
+    /// bow and melee attacks based on the held item.
+    pub fn reassess_weapon_goal(&self) {
+        let Some(world) = self.level() else {
+            return;
+        };
+
+        let holding_bow = self.is_holding(&mut |item_stack| item_stack.is(&vanilla_items::BOW));
+        let mut goal_selector = self.mob_base.goal_selector().lock();
+        goal_selector.remove_goals_with_priority(WEAPON_GOAL_PRIORITY, self);
+        if holding_bow {
+            let attack_interval = if world.difficulty() == Difficulty::Hard {
+                HARD_BOW_ATTACK_INTERVAL
+            } else {
+                BOW_ATTACK_INTERVAL
+            };
+            goal_selector.add_goal(
+                WEAPON_GOAL_PRIORITY,
+                RangedBowAttackGoal::new(
+                    BOW_ATTACK_SPEED_MODIFIER,
+                    attack_interval,
+                    BOW_ATTACK_RADIUS,
+                ),
+            );
+        } else {
+            goal_selector.add_goal(
+                WEAPON_GOAL_PRIORITY,
+                MeleeAttackGoal::new(MELEE_ATTACK_SPEED_MODIFIER, false),
+            );
+        }
+    }
+
+    fn update_dirty_mob_effect_entity_data(&self) {
+        if !self.living_base.take_effects_dirty() {
+            return;
+        }
+
+        let display = self.living_base.mob_effect_display_state();
+
+        {
+            let mut entity_data = self.entity_data.lock();
+            let living = entity_data.living_entity_mut();
+            living.effect_particles.set(display.particles);
+            living.effect_ambience.set(display.ambient);
+        }
+
+        self.entity_data.set_base_invisible_flag(display.invisible);
+        self.entity_data
+            .set_base_glowing_flag(self.has_glowing_tag() || display.glowing);
+    }
+}
+
+#[entity_impl(class(pathfinder_mob), interfaces(ranged_attack_mob))]
+impl Entity for SkeletonEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn tick(&self) {
+        self.default_tick();
+        self.living_base.decrement_invulnerable_time();
+        self.tick_mob_effects();
+
+        if self.is_dead_or_dying() {
+            LivingEntity::tick_death(self);
+            self.tick_living_state();
+            return;
+        }
+
+        if !self.is_removed() {
+            self.ai_step();
+        }
+
+        self.tick_living_state();
+    }
+
+    fn check_despawn(&self) {
+        Mob::check_mob_despawn(self);
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.is_removed() && self.get_health() > 0.0
+    }
+
+    fn is_pickable(&self) -> bool {
+        !self.is_removed()
+    }
+
+    fn is_pushable(&self) -> bool {
+        Entity::is_alive(self) && !self.is_spectator() && !self.on_climbable()
+    }
+
+    fn is_effective_ai(&self) -> bool {
+        self.is_server_driven_movement() && !self.is_no_ai()
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        LivingEntity::get_attribute_gravity(self)
+    }
+
+    fn can_freeze(&self) -> bool {
+        false
+    }
+
+    fn can_walk_on_powder_snow(&self) -> bool {
+        self.default_living_can_walk_on_powder_snow()
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn update_data_before_sync(&self) {
+        self.update_dirty_mob_effect_entity_data();
+    }
+
+    fn pack_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().syncable_snapshots()
+    }
+
+    fn drain_dirty_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().drain_dirty_sync()
+    }
+
+    fn drain_dirty_mob_effects(&self) -> Vec<MobEffectSyncChange> {
+        self.living_base.drain_dirty_mob_effects()
+    }
+
+    fn pack_all_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.pack_living_equipment()
+    }
+
+    fn drain_dirty_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.drain_dirty_living_equipment()
+    }
+
+    fn max_up_step(&self) -> f32 {
+        self.attributes()
+            .lock()
+            .get_value(vanilla_attributes::STEP_HEIGHT)
+            .unwrap_or(0.6) as f32
+    }
+
+    fn sound_source(&self) -> SoundSource {
+        SoundSource::Hostile
+    }
+
+    fn play_step_sound(&self, _pos: BlockPos, _block_state: BlockStateId) {
+        self.play_sound(&sound_events::ENTITY_SKELETON_STEP, 0.15, 1.0);
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        LivingEntity::hurt_server(self, world, source, amount)
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        Mob::interact_mob(self, player, hand, location)
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        self.save_mob(nbt);
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        self.load_mob(nbt);
+        self.reassess_weapon_goal();
+    }
+}
+
+impl LivingEntity for SkeletonEntity {
+    fn living_base(&self) -> &LivingEntityBase {
+        &self.living_base
+    }
+
+    fn get_health(&self) -> f32 {
+        *self.entity_data.lock().living_entity().health.get()
+    }
+
+    fn set_health(&self, health: f32) {
+        let max_health = self.get_max_health();
+        let clamped = health.clamp(0.0, max_health);
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .health
+            .set(clamped);
+    }
+
+    fn hurt_sound(&self, _source: &DamageSource) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_SKELETON_HURT)
+    }
+
+    fn death_sound(&self) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_SKELETON_DEATH)
+    }
+
+    fn server_ai_step(&self) {
+        Mob::mob_server_ai_step(self);
+    }
+
+    fn ai_step(&self) -> Option<MoveResult> {
+        self.ai_step_monster()
+    }
+}
+
+impl Mob for SkeletonEntity {
+    fn mob_base(&self) -> &MobBase {
+        &self.mob_base
+    }
+
+    fn tick_goal_selectors(&self) {
+        PathfinderMob::tick_pathfinder_goal_selectors(self);
+    }
+
+    fn tick_path_navigation(&self) {
+        PathfinderMob::tick_pathfinder_path_navigation(self);
+    }
+
+    fn ambient_sound(&self) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_SKELETON_AMBIENT)
+    }
+
+    fn finalize_spawn(
+        &self,
+        world: &Arc<World>,
+        spawn_reason: EntitySpawnReason,
+        group_data: Option<SpawnGroupData>,
+    ) -> Option<SpawnGroupData> {
+        let group_data = self.finalize_spawn_mob_base(world, spawn_reason, group_data);
+        // TODO: Roll armor, loot pickup and halloween pumpkins once difficulty instances exist.
+        self.living_base
+            .equipment()
+            .lock()
+            .set(EquipmentSlot::MainHand, ItemStack::new(&vanilla_items::BOW));
+        self.reassess_weapon_goal();
+        group_data
+    }
+
+    fn mob_flags(&self) -> i8 {
+        *self.entity_data.lock().mob().mob_flags.get()
+    }
+
+    fn set_mob_flags(&self, flags: i8) {
+        self.entity_data.lock().mob_mut().mob_flags.set(flags);
+    }
+}
+
+impl PathfinderMob for SkeletonEntity {
+    fn get_walk_target_value(&self, pos: BlockPos) -> f32 {
+        self.monster_walk_target_value(pos)
+    }
+}
+
+impl Monster for SkeletonEntity {}
+
+impl RangedAttackMob for SkeletonEntity {
+    /// Vanilla `AbstractSkeleton.performRangedAttack`.
+    fn perform_ranged_attack(&self, target: &dyn LivingEntity, power: f32) {
+        let Some(world) = self.level() else {
+            return;
+        };
+        let position = self.position();
+        let arrow = Arc::new(ArrowEntity::new(
+            &vanilla_entities::ARROW,
+            next_entity_id(),
+            DVec3::new(position.x, self.get_eye_y() - 0.1, position.z),
+            Arc::downgrade(&world),
+        ));
+        arrow.set_owner_uuid(Some(self.uuid()));
+        arrow.set_base_damage_from_mob(power, world.difficulty() as i32);
+
+        let target_position = target.position();
+        let dx = target_position.x - position.x;
+        let dy = target_position.y + target.bounding_box().height() / 3.0 - arrow.position().y;
+        let dz = target_position.z - position.z;
+        let horizontal_distance = dx.hypot(dz);
+        arrow.shoot(
+            DVec3::new(dx, horizontal_distance.mul_add(0.2, dy), dz),
+            ARROW_SHOOT_POWER,
+            (14 - world.difficulty() as i32 * 4) as f32,
+        );
+        let entity: SharedEntity = arrow;
+        if let Err(error) = world.try_add_entity(entity) {
+            log::debug!("failed to spawn skeleton arrow: {error}");
+            return;
+        }
+
+        self.play_sound(
+            &sound_events::ENTITY_SKELETON_SHOOT,
+            1.0,
+            1.0 / rand::random::<f32>().mul_add(0.4, 0.8),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+
+    use super::*;
+
+    fn skeleton() -> SkeletonEntity {
+        init_test_registry();
+        SkeletonEntity::new(&vanilla_entities::SKELETON, 1, DVec3::ZERO, Weak::new())
+    }
+
+    #[test]
+    fn skeleton_initializes_vanilla_monster_attributes() {
+        let skeleton = skeleton();
+
+        assert_eq!(skeleton.get_health().to_bits(), 20.0_f32.to_bits());
+        assert_eq!(
+            skeleton
+                .attributes()
+                .lock()
+                .required_value(vanilla_attributes::MOVEMENT_SPEED)
+                .to_bits(),
+            0.25_f64.to_bits()
+        );
+    }
+
+    #[test]
+    fn skeleton_registers_vanilla_goals_and_targets() {
+        let skeleton = skeleton();
+
+        assert_eq!(
+            skeleton
+                .mob_base()
+                .goal_selector()
+                .lock()
+                .available_goal_priorities(),
+            vec![2, 3, 3, 5, 6, 6]
+        );
+        assert_eq!(
+            skeleton
+                .mob_base()
+                .target_selector()
+                .lock()
+                .available_goal_priorities(),
+            vec![1, 2, 3, 3]
+        );
+    }
+
+    #[test]
+    fn skeleton_exposes_ranged_attack_and_does_not_freeze() {
+        let skeleton = skeleton();
+        let entity = &skeleton as &dyn Entity;
+
+        assert!(entity.as_ranged_attack_mob().is_some());
+        assert!(!entity.can_freeze());
+        assert_eq!(entity.sound_source(), SoundSource::Hostile);
+    }
+}
@@ -0,0 +1,518 @@
+//! Spider entity implementation.
+
+use std::sync::Weak;
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::NbtCompound;
+use steel_macros::{entity_behavior, entity_impl};
+use steel_protocol::packets::game::{AttributeSnapshot, EquipmentSlotItem, SoundSource};
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_entity_data::SpiderEntityData;
+use steel_registry::{sound_events, vanilla_attributes, vanilla_blocks, vanilla_entities};
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::InteractionHand;
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey};
+
+use crate::behavior::InteractionResult;
+use crate::entity::ai::goal::{
+    AvoidEntityGoal, FloatGoal, Goal, GoalControls, HurtByTargetGoal, LeapAtTargetGoal,
+    LookAtPlayerGoal, MeleeAttackGoal, NearestAttackableTargetGoal, RandomLookAroundGoal,
+    WaterAvoidingRandomStrollGoal,
+};
+use crate::entity::damage::DamageSource;
+use crate::entity::{
+    Entity, EntityBase, EntityBaseLoad, EntitySyncedData, LivingEntity, LivingEntityBase, Mob,
+    MobBase, MobEffectSyncChange, Monster, PathfinderMob,
+};
+use crate::physics::MoveResult;
+use crate::player::Player;
+use crate::world::World;
+
+const MONSTER_EXPERIENCE_REWARD: i32 = 5;
+const CLIMBING_FLAG: i8 = 0x01;
+const SPIDER_BRIGHTNESS_THRESHOLD: f32 = 0.5;
+const DAYLIGHT_FORGET_TARGET_CHANCE: i32 = 100;
+
+/// Vanilla `Spider.SpiderAttackGoal`: melee attack that gives up in daylight.
+struct SpiderAttackGoal {
+    melee_attack: MeleeAttackGoal,
+}
+
+impl SpiderAttackGoal {
+    const fn new() -> Self {
+        Self {
+            melee_attack: MeleeAttackGoal::new(1.0, true),
+        }
+    }
+}
+
+impl Goal for SpiderAttackGoal {
+    fn controls(&self) -> GoalControls {
+        self.melee_attack.controls()
+    }
+
+    fn can_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        self.melee_attack.can_use(mob) && !mob.is_vehicle()
+    }
+
+    fn can_continue_to_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        if mob.light_level_dependent_magic_value() >= SPIDER_BRIGHTNESS_THRESHOLD
+            && rand::random_range(0..DAYLIGHT_FORGET_TARGET_CHANCE) == 0
+        {
+            mob.set_target(None);
+            return false;
+        }
+
+        self.melee_attack.can_continue_to_use(mob)
+    }
+
+    fn start(&mut self, mob: &dyn PathfinderMob) {
+        self.melee_attack.start(mob);
+    }
+
+    fn stop(&mut self, mob: &dyn PathfinderMob) {
+        self.melee_attack.stop(mob);
+    }
+
+    fn requires_update_every_tick(&self) -> bool {
+        self.melee_attack.requires_update_every_tick()
+    }
+
+    fn tick(&mut self, mob: &dyn PathfinderMob) {
+        self.melee_attack.tick(mob);
+    }
+}
+
+/// Vanilla `Spider.SpiderTargetGoal`: only picks new targets in the dark.
+struct SpiderTargetGoal {
+    nearest_attackable_target: NearestAttackableTargetGoal,
+}
+
+impl SpiderTargetGoal {
+    const fn new(nearest_attackable_target: NearestAttackableTargetGoal) -> Self {
+        Self {
+            nearest_attackable_target,
+        }
+    }
+}
+
+impl Goal for SpiderTargetGoal {
+    fn controls(&self) -> GoalControls {
+        self.nearest_attackable_target.controls()
+    }
+
+    fn can_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        mob.light_level_dependent_magic_value() < SPIDER_BRIGHTNESS_THRESHOLD
+            && self.nearest_attackable_target.can_use(mob)
+    }
+
+    fn can_continue_to_use(&mut self, mob: &dyn PathfinderMob) -> bool {
+        self.nearest_attackable_target.can_continue_to_use(mob)
+    }
+
+    fn start(&mut self, mob: &dyn PathfinderMob) {
+        self.nearest_attackable_target.start(mob);
+    }
+
+    fn stop(&mut self, mob: &dyn PathfinderMob) {
+        self.nearest_attackable_target.stop(mob);
+    }
+}
+
+/// Vanilla spider entity.
+#[entity_behavior(class = "Spider")]
+pub struct SpiderEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    living_base: LivingEntityBase,
+    mob_base: MobBase,
+    entity_data: SyncMutex<SpiderEntityData>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `SpiderEntity`.
+unsafe impl DowncastType for SpiderEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/spider");
+}
+
+impl SpiderEntity {
+    /// Creates a new spider entity.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_with_base(
+            EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+        )
+    }
+
+    /// Creates a spider entity from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self::new_with_base(
+            EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+        )
+    }
+
+    fn new_with_base(base: EntityBase, entity_type: EntityTypeRef) -> Self {
+        let living_base = LivingEntityBase::new(entity_type);
+        let mob_base = MobBase::new();
+        mob_base.set_xp_reward(MONSTER_EXPERIENCE_REWARD);
+        mob_base.navigation().lock().set_wall_climber(true);
+        let mut entity_data = SpiderEntityData::new();
+        living_base.initialize_synced_data(&mut entity_data);
+        {
+            let mut goal_selector = mob_base.goal_selector().lock();
+            goal_selector.add_goal(1, FloatGoal::new(&mob_base));
+            // TODO: Skip scared armadillos once armadillo state exists.
+            goal_selector.add_goal(
+                2,
+                AvoidEntityGoal::with_selector(6.0, 1.0, 1.2, |target, _| {
+                    target.entity_type() == &vanilla_entities::ARMADILLO
+                }),
+            );
+            goal_selector.add_goal(3, LeapAtTargetGoal::new(0.4));
+            goal_selector.add_goal(4, SpiderAttackGoal::new());
+            goal_selector.add_goal(5, WaterAvoidingRandomStrollGoal::new(0.8));
+            goal_selector.add_goal(6, LookAtPlayerGoal::new(8.0));
+            goal_selector.add_goal(6, RandomLookAroundGoal::new());
+        }
+        {
+            let mut target_selector = mob_base.target_selector().lock();
+            target_selector.add_goal(1, HurtByTargetGoal::new(Vec::new()));
+            target_selector.add_goal(
+                2,
+                SpiderTargetGoal::new(NearestAttackableTargetGoal::new_for_players(true)),
+            );
+            target_selector.add_goal(
+                3,
+                SpiderTargetGoal::new(NearestAttackableTargetGoal::new_for_entity_types(
+                    vec![&vanilla_entities::IRON_GOLEM],
+                    true,
+                )),
+            );
+        }
+
+        Self {
+            base,
+            entity_type,
+            living_base,
+            mob_base,
+            entity_data: SyncMutex::new(entity_data),
+        }
+    }
+
+    /// Returns whether this spider is climbing a wall.
+    #[must_use]
+    pub fn is_climbing(&self) -> bool {
+        *self.entity_data.lock().flags.get() & CLIMBING_FLAG != 0
+    }
+
+    /// Sets the synced wall-climbing flag.
+    pub fn set_climbing(&self, climbing: bool) {
+        let mut entity_data = self.entity_data.lock();
+        let flags = *entity_data.flags.get();
+        let flags = if climbing {
+            flags | CLIMBING_FLAG
+        } else {
+            flags & !CLIMBING_FLAG
+        };
+        entity_data.flags.set(flags);
+    }
+
+    fn update_dirty_mob_effect_entity_data(&self) {
+        if !self.living_base.take_effects_dirty() {
+            return;
+        }
+
+        let display = self.living_base.mob_effect_display_state();
+
+        {
+            let mut entity_data = self.entity_data.lock();
+            let living = entity_data.living_entity_mut();
+            living.effect_particles.set(display.particles);
+            living.effect_ambience.set(display.ambient);
+        }
+
+        self.entity_data.set_base_invisible_flag(display.invisible);
+        self.entity_data
+            .set_base_glowing_flag(self.has_glowing_tag() || display.glowing);
+    }
+}
+
+#[entity_impl(class(pathfinder_mob))]
+impl Entity for SpiderEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn tick(&self) {
+        self.default_tick();
+        self.living_base.decrement_invulnerable_time();
+        self.tick_mob_effects();
+
+        if self.is_dead_or_dying() {
+            LivingEntity::tick_death(self);
+            self.tick_living_state();
+            return;
+        }
+
+        if !self.is_removed() {
+            self.ai_step();
+        }
+
+        self.tick_living_state();
+        self.set_climbing(self.horizontal_collision());
+    }
+
+    fn check_despawn(&self) {
+        Mob::check_mob_despawn(self);
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.is_removed() && self.get_health() > 0.0
+    }
+
+    fn is_pickable(&self) -> bool {
+        !self.is_removed()
+    }
+
+    fn is_pushable(&self) -> bool {
+        Entity::is_alive(self) && !self.is_spectator() && !self.on_climbable()
+    }
+
+    fn is_effective_ai(&self) -> bool {
+        self.is_server_driven_movement() && !self.is_no_ai()
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        LivingEntity::get_attribute_gravity(self)
+    }
+
+    fn can_freeze(&self) -> bool {
+        self.default_living_can_freeze()
+    }
+
+    fn can_walk_on_powder_snow(&self) -> bool {
+        self.default_living_can_walk_on_powder_snow()
+    }
+
+    fn on_climbable(&self) -> bool {
+        self.is_climbing()
+    }
+
+    fn make_stuck_in_block(&self, state: BlockStateId, speed_multiplier: DVec3) {
+        if state.get_block() != &vanilla_blocks::COBWEB {
+            self.default_make_stuck_in_block(state, speed_multiplier);
+        }
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn update_data_before_sync(&self) {
+        self.update_dirty_mob_effect_entity_data();
+    }
+
+    fn pack_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().syncable_snapshots()
+    }
+
+    fn drain_dirty_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().drain_dirty_sync()
+    }
+
+    fn drain_dirty_mob_effects(&self) -> Vec<MobEffectSyncChange> {
+        self.living_base.drain_dirty_mob_effects()
+    }
+
+    fn pack_all_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.pack_living_equipment()
+    }
+
+    fn drain_dirty_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.drain_dirty_living_equipment()
+    }
+
+    fn max_up_step(&self) -> f32 {
+        self.attributes()
+            .lock()
+            .get_value(vanilla_attributes::STEP_HEIGHT)
+            .unwrap_or(0.6) as f32
+    }
+
+    fn sound_source(&self) -> SoundSource {
+        SoundSource::Hostile
+    }
+
+    fn play_step_sound(&self, _pos: BlockPos, _block_state: BlockStateId) {
+        self.play_sound(&sound_events::ENTITY_SPIDER_STEP, 0.15, 1.0);
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        LivingEntity::hurt_server(self, world, source, amount)
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        Mob::interact_mob(self, player, hand, location)
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        self.save_mob(nbt);
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        self.load_mob(nbt);
+    }
+}
+
+impl LivingEntity for SpiderEntity {
+    fn living_base(&self) -> &LivingEntityBase {
+        &self.living_base
+    }
+
+    fn get_health(&self) -> f32 {
+        *self.entity_data.lock().living_entity().health.get()
+    }
+
+    fn set_health(&self, health: f32) {
+        let max_health = self.get_max_health();
+        let clamped = health.clamp(0.0, max_health);
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .health
+            .set(clamped);
+    }
+
+    fn hurt_sound(&self, _source: &DamageSource) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_SPIDER_HURT)
+    }
+
+    fn death_sound(&self) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_SPIDER_DEATH)
+    }
+
+    fn server_ai_step(&self) {
+        Mob::mob_server_ai_step(self);
+    }
+
+    fn ai_step(&self) -> Option<MoveResult> {
+        self.ai_step_monster()
+    }
+}
+
+impl Mob for SpiderEntity {
+    fn mob_base(&self) -> &MobBase {
+        &self.mob_base
+    }
+
+    fn tick_goal_selectors(&self) {
+        PathfinderMob::tick_pathfinder_goal_selectors(self);
+    }
+
+    fn tick_path_navigation(&self) {
+        PathfinderMob::tick_pathfinder_path_navigation(self);
+    }
+
+    fn ambient_sound(&self) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_SPIDER_AMBIENT)
+    }
+
+    fn mob_flags(&self) -> i8 {
+        *self.entity_data.lock().mob().mob_flags.get()
+    }
+
+    fn set_mob_flags(&self, flags: i8) {
+        self.entity_data.lock().mob_mut().mob_flags.set(flags);
+    }
+}
+
+impl PathfinderMob for SpiderEntity {
+    fn get_walk_target_value(&self, pos: BlockPos) -> f32 {
+        self.monster_walk_target_value(pos)
+    }
+}
+
+impl Monster for SpiderEntity {}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+
+    use super::*;
+
+    fn spider() -> SpiderEntity {
+        init_test_registry();
+        SpiderEntity::new(&vanilla_entities::SPIDER, 1, DVec3::ZERO, Weak::new())
+    }
+
+    #[test]
+    fn spider_initializes_vanilla_monster_attributes() {
+        let spider = spider();
+
+        assert_eq!(spider.get_health().to_bits(), 16.0_f32.to_bits());
+        assert_eq!(
+            spider
+                .attributes()
+                .lock()
+                .required_value(vanilla_attributes::MOVEMENT_SPEED)
+                .to_bits(),
+            0.3_f64.to_bits()
+        );
+    }
+
+    #[test]
+    fn spider_climbing_flag_drives_on_climbable() {
+        let spider = spider();
+
+        assert!(!spider.on_climbable());
+        spider.set_climbing(true);
+        assert!(spider.is_climbing());
+        assert!(spider.on_climbable());
+        spider.set_climbing(false);
+        assert!(!spider.on_climbable());
+    }
+
+    #[test]
+    fn spider_registers_vanilla_goals_and_targets() {
+        let spider = spider();
+
+        assert_eq!(
+            spider
+                .mob_base()
+                .goal_selector()
+                .lock()
+                .available_goal_priorities(),
+            vec![1, 2, 3, 4, 5, 6, 6]
+        );
+        assert_eq!(
+            spider
+                .mob_base()
+                .target_selector()
+                .lock()
+                .available_goal_priorities(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn spider_target_goal_requires_world_to_find_targets() {
+        let spider = spider();
+        let mut goal = SpiderTargetGoal::new(NearestAttackableTargetGoal::new_for_players(true));
+
+        assert!(!goal.can_use(&spider));
+    }
+}
@@ -0,0 +1,1425 @@
+//! Villager entity implementation.
+
+use std::mem;
+use std::str::FromStr;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use parking_lot::{MappedMutexGuard, MutexGuard};
+use rustc_hash::FxHashMap;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_macros::{entity_behavior, entity_impl};
+use steel_protocol::packets::game::{AttributeSnapshot, EquipmentSlotItem, SoundSource};
+use steel_registry::entity_data::VillagerData;
+use steel_registry::entity_type::{EntityDimensions, EntityTypeRef};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::trading::{MerchantOffer, MerchantOffers};
+use steel_registry::vanilla_entity_data::VillagerEntityData;
+use steel_registry::vanilla_poi_type_tags::PoiTag;
+use steel_registry::villager_profession::VillagerProfessionRef;
+use steel_registry::villager_type::VillagerTypeRef;
+use steel_registry::{
+    REGISTRY, RegistryExt, TaggedRegistryExt, sound_events, vanilla_attributes, vanilla_entities,
+    vanilla_mob_effects, vanilla_poi_types, vanilla_villager_professions, vanilla_villager_types,
+    vanilla_world_clocks,
+};
+use steel_utils::entity_events::EntityStatus;
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::{Difficulty, InteractionHand};
+use steel_utils::{BlockPos, DowncastType, DowncastTypeKey, GlobalPos, Identifier, UuidExt};
+use text_components::TextComponent;
+use text_components::translation::TranslatedMessage;
+use uuid::Uuid;
+
+use super::villager_trades;
+use crate::behavior::InteractionResult;
+use crate::entity::ai::goal::{
+    AcquirePoiGoal, FloatGoal, LookAtPlayerGoal, PanicGoal, RandomLookAroundGoal,
+    TradeWithPlayerGoal, WaterAvoidingRandomStrollGoal, WorkAtPoiGoal,
+};
+use crate::entity::damage::DamageSource;
+use crate::entity::entities::{ExperienceOrbEntity, LightningBoltEntity};
+use crate::entity::{
+    AgeableMob, AgeableMobBase, ConversionParams, Entity, EntityBase, EntityBaseLoad, EntityPose,
+    EntitySpawnReason, EntitySyncedData, LivingEntity, LivingEntityBase, Merchant, Mob, MobBase,
+    MobEffectInstance, MobEffectSyncChange, PathfinderMob, SharedEntity, SpawnGroupData,
+    open_trading_screen,
+};
+use crate::physics::MoveResult;
+use crate::player::Player;
+use crate::world::World;
+
+const VILLAGER_BABY_DIMENSIONS: EntityDimensions = EntityDimensions::new(0.49, 0.98, 0.63);
+
+/// Vanilla `VillagerData.NEXT_LEVEL_XP_THRESHOLDS`.
+const NEXT_LEVEL_XP_THRESHOLDS: [i32; 5] = [0, 10, 70, 150, 250];
+const MIN_VILLAGER_LEVEL: i32 = 1;
+const MAX_VILLAGER_LEVEL: i32 = 5;
+const UNHAPPY_TICKS: i32 = 40;
+const LEVEL_UP_DELAY: i32 = 40;
+const RESTOCK_COOLDOWN: i64 = 2400;
+const RESTOCK_DEMAND_RESET: i64 = 12000;
+const MAX_RESTOCKS_PER_DAY: i32 = 2;
+const TICKS_PER_DAY: i64 = 24000;
+const POI_VALIDATION_INTERVAL: i32 = 20;
+const TRADING_REPUTATION_GAIN: i32 = 2;
+const MAX_TRADING_REPUTATION: i32 = 25;
+const HURT_REPUTATION_LOSS: i32 = 25;
+const MAX_MINOR_NEGATIVE_REPUTATION: i32 = 200;
+
+/// A POI claimed by a villager, mirroring vanilla brain memories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VillagerPoiMemory {
+    /// Vanilla `MemoryModuleType.HOME`: a claimed bed.
+    Home,
+    /// Vanilla `MemoryModuleType.JOB_SITE`: a claimed workstation.
+    JobSite,
+}
+
+impl VillagerPoiMemory {
+    const fn key(self) -> &'static str {
+        match self {
+            Self::Home => "minecraft:home",
+            Self::JobSite => "minecraft:job_site",
+        }
+    }
+}
+
+/// Per-player reputation, tracking the two vanilla gossip types a villager
+/// records firsthand. Gossip is not shared between villagers and never decays.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Reputation {
+    trading: i32,
+    minor_negative: i32,
+}
+
+impl Reputation {
+    /// Mirrors vanilla `GossipContainer.getReputation` with the gossip type weights.
+    const fn value(self) -> i32 {
+        self.trading - self.minor_negative
+    }
+}
+
+/// Trading progression state from vanilla `Villager`.
+#[derive(Debug, Default)]
+struct TradeState {
+    villager_xp: i32,
+    update_merchant_timer: i32,
+    increase_profession_level_on_update: bool,
+    last_traded_player: Option<Uuid>,
+    last_restock_game_time: i64,
+    last_restock_check_day_time: i64,
+    number_of_restocks_today: i32,
+}
+
+/// Vanilla villager entity.
+#[entity_behavior(class = "Villager")]
+pub struct VillagerEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    living_base: LivingEntityBase,
+    mob_base: MobBase,
+    ageable_base: AgeableMobBase,
+    entity_data: SyncMutex<VillagerEntityData>,
+    offers: SyncMutex<Option<MerchantOffers>>,
+    trading_player: SyncMutex<Option<Uuid>>,
+    trade_state: SyncMutex<TradeState>,
+    reputation: SyncMutex<FxHashMap<Uuid, Reputation>>,
+    home: SyncMutex<Option<GlobalPos>>,
+    job_site: SyncMutex<Option<GlobalPos>>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `VillagerEntity`.
+unsafe impl DowncastType for VillagerEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/villager");
+}
+
+impl VillagerEntity {
+    /// Creates a new villager entity.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_with_base(
+            EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+        )
+    }
+
+    /// Creates a villager entity from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self::new_with_base(
+            EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+        )
+    }
+
+    fn new_with_base(base: EntityBase, entity_type: EntityTypeRef) -> Self {
+        let living_base = LivingEntityBase::new(entity_type);
+        let mob_base = MobBase::new();
+        let ageable_base = AgeableMobBase::new();
+        let mut entity_data = VillagerEntityData::new();
+        living_base.initialize_synced_data(&mut entity_data);
+        {
+            let mut goal_selector = mob_base.goal_selector().lock();
+            goal_selector.add_goal(0, FloatGoal::new(&mob_base));
+            goal_selector.add_goal(1, TradeWithPlayerGoal::new());
+            goal_selector.add_goal(1, PanicGoal::new(0.5));
+            goal_selector.add_goal(2, AcquirePoiGoal::new(VillagerPoiMemory::JobSite, 0.5));
+            goal_selector.add_goal(3, WorkAtPoiGoal::new(0.5));
+            goal_selector.add_goal(4, AcquirePoiGoal::new(VillagerPoiMemory::Home, 0.5));
+            goal_selector.add_goal(5, WaterAvoidingRandomStrollGoal::new(0.35));
+            goal_selector.add_goal(6, LookAtPlayerGoal::new(8.0));
+            goal_selector.add_goal(7, RandomLookAroundGoal::new());
+        }
+
+        Self {
+            base,
+            entity_type,
+            living_base,
+            mob_base,
+            ageable_base,
+            entity_data: SyncMutex::new(entity_data),
+            offers: SyncMutex::new(None),
+            trading_player: SyncMutex::new(None),
+            trade_state: SyncMutex::new(TradeState::default()),
+            reputation: SyncMutex::new(FxHashMap::default()),
+            home: SyncMutex::new(None),
+            job_site: SyncMutex::new(None),
+        }
+    }
+
+    /// Returns whether this villager is a baby.
+    #[must_use]
+    pub fn is_baby(&self) -> bool {
+        AgeableMob::is_baby(self)
+    }
+
+    /// Sets the vanilla baby state using the `AgeableMob` start age.
+    pub fn set_baby(&self, baby: bool) {
+        AgeableMob::set_baby(self, baby);
+    }
+
+    /// Returns the synced villager data.
+    #[must_use]
+    pub fn villager_data(&self) -> VillagerData {
+        *self.entity_data.lock().villager_data.get()
+    }
+
+    /// Sets the synced villager data, clearing offers when the profession changes.
+    ///
+    /// Based on Java's `Villager::setVillagerData`.
+    pub fn set_villager_data(&self, data: VillagerData) {
+        let old_profession = self.villager_data().profession;
+        if old_profession != data.profession {
+            *self.offers.lock() = None;
+        }
+        self.entity_data.lock().villager_data.set(data);
+    }
+
+    /// Returns the villager's profession, falling back to `minecraft:none`.
+    #[must_use]
+    pub fn profession(&self) -> VillagerProfessionRef {
+        let id = self.villager_data().profession;
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| REGISTRY.villager_professions.by_id(id))
+            .unwrap_or(&vanilla_villager_professions::NONE)
+    }
+
+    /// Sets the villager's profession.
+    pub fn set_profession(&self, profession: VillagerProfessionRef) {
+        let Some(id) = REGISTRY
+            .villager_professions
+            .id_from_key(&profession.key)
+            .and_then(|id| i32::try_from(id).ok())
+        else {
+            log::error!("villager profession {} is not registered", profession.key);
+            return;
+        };
+        let mut data = self.villager_data();
+        data.profession = id;
+        self.set_villager_data(data);
+    }
+
+    /// Returns the villager's biome type, falling back to `minecraft:plains`.
+    #[must_use]
+    pub fn villager_type(&self) -> VillagerTypeRef {
+        let id = self.villager_data().villager_type;
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| REGISTRY.villager_types.by_id(id))
+            .unwrap_or(&vanilla_villager_types::PLAINS)
+    }
+
+    /// Sets the villager's biome type.
+    pub fn set_villager_type(&self, villager_type: VillagerTypeRef) {
+        let Some(id) = REGISTRY
+            .villager_types
+            .id_from_key(&villager_type.key)
+            .and_then(|id| i32::try_from(id).ok())
+        else {
+            log::error!("villager type {} is not registered", villager_type.key);
+            return;
+        };
+        let mut data = self.villager_data();
+        data.villager_type = id;
+        self.set_villager_data(data);
+    }
+
+    /// Returns the villager's trading level.
+    #[must_use]
+    pub fn villager_level(&self) -> i32 {
+        self.villager_data().level
+    }
+
+    /// Returns whether a player is currently trading with this villager.
+    #[must_use]
+    pub fn is_trading(&self) -> bool {
+        self.trading_player.lock().is_some()
+    }
+
+    /// Returns the position stored in a POI memory, if it is in this villager's world.
+    #[must_use]
+    pub fn poi(&self, memory: VillagerPoiMemory) -> Option<BlockPos> {
+        let pos = self.poi_memory(memory).lock().clone()?;
+        self.level()
+            .is_none_or(|world| world.key == pos.dimension)
+            .then_some(pos.pos)
+    }
+
+    /// Sets a POI memory, assigning a profession when an unemployed villager
+    /// claims a job site.
+    pub fn set_poi(&self, memory: VillagerPoiMemory, pos: Option<BlockPos>) {
+        let pos = pos.map(|pos| {
+            let dimension = self.level().map_or_else(
+                || Identifier::vanilla_static("overworld"),
+                |world| world.key.clone(),
+            );
+            GlobalPos::new(dimension, pos)
+        });
+
+        if memory == VillagerPoiMemory::JobSite
+            && let Some(pos) = &pos
+            && self.profession().key == vanilla_villager_professions::NONE.key
+            && let Some(profession) = self.profession_for_poi(pos.pos)
+        {
+            self.set_profession(profession);
+        }
+        *self.poi_memory(memory).lock() = pos;
+    }
+
+    /// Returns whether this villager is still looking for a POI of this kind.
+    #[must_use]
+    pub fn wants_poi(&self, memory: VillagerPoiMemory) -> bool {
+        match memory {
+            VillagerPoiMemory::Home => true,
+            VillagerPoiMemory::JobSite => {
+                !self.is_baby() && self.profession().key != vanilla_villager_professions::NITWIT.key
+            }
+        }
+    }
+
+    /// Returns whether a POI type satisfies the given memory for this villager.
+    ///
+    /// Unemployed villagers accept any acquirable job site; employed villagers
+    /// only accept their profession's workstation.
+    #[must_use]
+    pub fn is_acceptable_poi(&self, memory: VillagerPoiMemory, type_id: usize) -> bool {
+        let Some(poi_type) = REGISTRY.poi_types.by_id(type_id) else {
+            return false;
+        };
+        match memory {
+            VillagerPoiMemory::Home => poi_type.key == vanilla_poi_types::HOME.key,
+            VillagerPoiMemory::JobSite => {
+                let profession = self.profession();
+                if profession.key == vanilla_villager_professions::NONE.key {
+                    REGISTRY
+                        .poi_types
+                        .is_in_tag(poi_type, &PoiTag::ACQUIRABLE_JOB_SITE)
+                } else {
+                    poi_type.key == profession.key
+                }
+            }
+        }
+    }
+
+    /// Plays the profession's work sound and restocks when allowed.
+    ///
+    /// Based on Java's `WorkAtPoi::useWorkstation`.
+    pub fn work_at_poi(&self) {
+        let Some(world) = self.level() else {
+            return;
+        };
+        if let Some(sound) = self.profession().work_sound {
+            self.make_sound(Some(sound));
+        }
+        if self.should_restock(&world) {
+            self.restock();
+        }
+    }
+
+    /// Returns the reputation a player has with this villager.
+    #[must_use]
+    pub fn player_reputation(&self, player: Uuid) -> i32 {
+        self.reputation
+            .lock()
+            .get(&player)
+            .copied()
+            .unwrap_or_default()
+            .value()
+    }
+
+    const fn poi_memory(&self, memory: VillagerPoiMemory) -> &SyncMutex<Option<GlobalPos>> {
+        match memory {
+            VillagerPoiMemory::Home => &self.home,
+            VillagerPoiMemory::JobSite => &self.job_site,
+        }
+    }
+
+    fn profession_for_poi(&self, pos: BlockPos) -> Option<VillagerProfessionRef> {
+        let world = self.level()?;
+        let type_id = world.poi_storage.lock().get_type(pos)?;
+        let poi_type = REGISTRY.poi_types.by_id(type_id)?;
+        REGISTRY.villager_professions.by_key(&poi_type.key)
+    }
+
+    /// Releases claimed POIs and forgets invalid ones.
+    ///
+    /// Based on Java's `ValidateNearbyPoi` and `ResetProfession` behaviors.
+    fn validate_pois(&self, world: &World) {
+        for memory in [VillagerPoiMemory::Home, VillagerPoiMemory::JobSite] {
+            let Some(pos) = self.poi(memory) else {
+                continue;
+            };
+            let valid =
+                world
+                    .poi_storage
+                    .lock()
+                    .get_type(pos)
+                    .is_some_and(|type_id| match memory {
+                        VillagerPoiMemory::Home => self.is_acceptable_poi(memory, type_id),
+                        VillagerPoiMemory::JobSite => REGISTRY
+                            .poi_types
+                            .by_id(type_id)
+                            .is_some_and(|poi_type| poi_type.key == self.profession().key),
+                    });
+            if !valid {
+                *self.poi_memory(memory).lock() = None;
+            }
+        }
+
+        let profession = self.profession();
+        if self.job_site.lock().is_none()
+            && profession.key != vanilla_villager_professions::NONE.key
+            && profession.key != vanilla_villager_professions::NITWIT.key
+            && self.trade_state.lock().villager_xp == 0
+            && self.villager_level() <= MIN_VILLAGER_LEVEL
+        {
+            self.set_profession(&vanilla_villager_professions::NONE);
+        }
+    }
+
+    /// Based on Java's `Villager::releaseAllPois`.
+    fn release_all_pois(&self) {
+        let Some(world) = self.level() else {
+            return;
+        };
+        for memory in [VillagerPoiMemory::Home, VillagerPoiMemory::JobSite] {
+            let Some(pos) = self.poi(memory) else {
+                continue;
+            };
+            *self.poi_memory(memory).lock() = None;
+            if !world.poi_storage.lock().release_ticket(pos) {
+                log::debug!("villager {} had no ticket at {pos:?}", self.id());
+            }
+        }
+    }
+
+    /// Based on Java's `Villager::setUnhappy`.
+    fn set_unhappy(&self) {
+        self.set_unhappy_counter(UNHAPPY_TICKS);
+        self.make_sound(Some(&sound_events::ENTITY_VILLAGER_NO));
+    }
+
+    fn unhappy_counter(&self) -> i32 {
+        *self
+            .entity_data
+            .lock()
+            .abstract_villager()
+            .unhappy_counter
+            .get()
+    }
+
+    fn set_unhappy_counter(&self, counter: i32) {
+        self.entity_data
+            .lock()
+            .abstract_villager_mut()
+            .unhappy_counter
+            .set(counter);
+    }
+
+    /// Based on Java's `Villager::startTrading`.
+    fn start_trading(&self, player: &Player) {
+        let Some(entity) = self
+            .level()
+            .and_then(|world| world.get_entity_by_id(self.id()))
+        else {
+            return;
+        };
+        self.update_special_prices(player.uuid());
+        self.set_trading_player(Some(player.uuid()));
+        open_trading_screen(entity, player, self.display_name(), self.villager_level());
+    }
+
+    /// Based on Java's `Villager::updateSpecialPrices`, without Hero of the Village.
+    fn update_special_prices(&self, player: Uuid) {
+        let reputation = self.player_reputation(player);
+        if reputation == 0 {
+            return;
+        }
+        for offer in self.offers().iter_mut() {
+            offer.add_to_special_price_diff(
+                -(reputation as f32 * offer.price_multiplier).floor() as i32
+            );
+        }
+    }
+
+    fn reset_special_prices(&self) {
+        if let Some(offers) = self.offers.lock().as_mut() {
+            offers
+                .iter_mut()
+                .for_each(MerchantOffer::reset_special_price_diff);
+        }
+    }
+
+    /// Based on Java's `Villager::shouldIncreaseLevel`.
+    fn should_increase_level(&self, villager_xp: i32) -> bool {
+        let level = self.villager_level();
+        can_level_up(level) && villager_xp >= max_xp_per_level(level)
+    }
+
+    /// Based on Java's `Villager::increaseMerchantCareer`.
+    fn increase_merchant_career(&self) {
+        let mut data = self.villager_data();
+        data.level += 1;
+        self.set_villager_data(data);
+        let profession = self.profession();
+        villager_trades::add_offers_for_level(&mut self.offers(), profession, data.level);
+    }
+
+    /// Based on Java's `Villager::shouldRestock`.
+    fn should_restock(&self, world: &World) -> bool {
+        let game_time = world.game_time();
+        let day_time = world
+            .clock_total_ticks(&vanilla_world_clocks::OVERWORLD)
+            .unwrap_or(game_time);
+        let reset = {
+            let mut state = self.trade_state.lock();
+            let mut reset = game_time > state.last_restock_game_time + RESTOCK_DEMAND_RESET;
+            if state.last_restock_check_day_time > 0 {
+                reset |=
+                    day_time / TICKS_PER_DAY > state.last_restock_check_day_time / TICKS_PER_DAY;
+            }
+            state.last_restock_check_day_time = day_time;
+            if reset {
+                state.last_restock_game_time = game_time;
+            }
+            reset
+        };
+        if reset {
+            self.reset_number_of_restocks();
+        }
+
+        self.allowed_to_restock(game_time) && self.offers().iter().any(MerchantOffer::needs_restock)
+    }
+
+    /// Based on Java's `Villager::allowedToRestock`.
+    fn allowed_to_restock(&self, game_time: i64) -> bool {
+        let state = self.trade_state.lock();
+        state.number_of_restocks_today == 0
+            || state.number_of_restocks_today < MAX_RESTOCKS_PER_DAY
+                && game_time > state.last_restock_game_time + RESTOCK_COOLDOWN
+    }
+
+    /// Based on Java's `Villager::restock`.
+    fn restock(&self) {
+        {
+            let mut offers = self.offers();
+            for offer in offers.iter_mut() {
+                offer.update_demand();
+                offer.reset_uses();
+            }
+        }
+        self.resend_offers_to_trading_player();
+        let game_time = self.level().map_or(0, |world| world.game_time());
+        let mut state = self.trade_state.lock();
+        state.last_restock_game_time = game_time;
+        state.number_of_restocks_today += 1;
+    }
+
+    /// Based on Java's `Villager::resetNumberOfRestocks` and `catchUpDemand`.
+    fn reset_number_of_restocks(&self) {
+        let missed_restocks =
+            MAX_RESTOCKS_PER_DAY - self.trade_state.lock().number_of_restocks_today;
+        if missed_restocks > 0 {
+            let mut offers = self.offers();
+            offers.iter_mut().for_each(MerchantOffer::reset_uses);
+            for _ in 0..missed_restocks {
+                offers.iter_mut().for_each(MerchantOffer::update_demand);
+            }
+        }
+        self.resend_offers_to_trading_player();
+        self.trade_state.lock().number_of_restocks_today = 0;
+    }
+
+    fn trading_player_entity(&self) -> Option<SharedEntity> {
+        let uuid = (*self.trading_player.lock())?;
+        self.level()?.get_entity_by_uuid(&uuid)
+    }
+
+    /// Based on Java's `Villager::resendOffersToTradingPlayer`.
+    fn resend_offers_to_trading_player(&self) {
+        let Some(entity) = self.trading_player_entity() else {
+            return;
+        };
+        let Some(player) = entity.as_player() else {
+            return;
+        };
+        let offers = self.offers().to_vec();
+        player.send_merchant_offers(
+            offers,
+            self.villager_level(),
+            self.villager_xp(),
+            self.show_progress_bar(),
+            self.can_restock(),
+        );
+    }
+
+    fn add_reputation(&self, player: Uuid, update: impl FnOnce(&mut Reputation)) {
+        let mut reputation = self.reputation.lock();
+        let entry = reputation.entry(player).or_default();
+        update(entry);
+        if *entry == Reputation::default() {
+            reputation.remove(&player);
+        }
+    }
+
+    fn update_dirty_mob_effect_entity_data(&self) {
+        if !self.living_base.take_effects_dirty() {
+            return;
+        }
+
+        let display = self.living_base.mob_effect_display_state();
+
+        {
+            let mut entity_data = self.entity_data.lock();
+            let living = entity_data.living_entity_mut();
+            living.effect_particles.set(display.particles);
+            living.effect_ambience.set(display.ambient);
+        }
+
+        self.entity_data.set_base_invisible_flag(display.invisible);
+        self.entity_data
+            .set_base_glowing_flag(self.has_glowing_tag() || display.glowing);
+    }
+
+    fn save_villager(&self, nbt: &mut NbtCompound) {
+        if let Some(offers) = self.offers.lock().as_ref() {
+            nbt.insert("Offers", offers.to_nbt());
+        }
+
+        let mut villager_data = NbtCompound::new();
+        villager_data.insert("level", self.villager_level());
+        villager_data.insert("profession", self.profession().key.to_string());
+        villager_data.insert("type", self.villager_type().key.to_string());
+        nbt.insert("VillagerData", villager_data);
+
+        let gossips = self
+            .reputation
+            .lock()
+            .iter()
+            .flat_map(|(uuid, reputation)| {
+                [
+                    ("trading", reputation.trading),
+                    ("minor_negative", reputation.minor_negative),
+                ]
+                .into_iter()
+                .filter(|(_, value)| *value > 0)
+                .map(|(gossip_type, value)| {
+                    let mut gossip = NbtCompound::new();
+                    gossip.insert("Target", NbtTag::IntArray(uuid.to_int_array().to_vec()));
+                    gossip.insert("Type", gossip_type);
+                    gossip.insert("Value", value);
+                    gossip
+                })
+            })
+            .collect();
+        nbt.insert("Gossips", NbtList::Compound(gossips));
+
+        let state = self.trade_state.lock();
+        nbt.insert("Xp", state.villager_xp);
+        nbt.insert("LastRestock", state.last_restock_game_time);
+        nbt.insert("RestocksToday", state.number_of_restocks_today);
+        drop(state);
+
+        let mut memories = NbtCompound::new();
+        for memory in [VillagerPoiMemory::Home, VillagerPoiMemory::JobSite] {
+            let Some(pos) = self.poi_memory(memory).lock().clone() else {
+                continue;
+            };
+            let mut value = NbtCompound::new();
+            value.insert("dimension", pos.dimension.to_string());
+            value.insert(
+                "pos",
+                NbtTag::IntArray(vec![pos.pos.x(), pos.pos.y(), pos.pos.z()]),
+            );
+            let mut entry = NbtCompound::new();
+            entry.insert("value", value);
+            memories.insert(memory.key(), entry);
+        }
+        let mut brain = NbtCompound::new();
+        brain.insert("memories", memories);
+        nbt.insert("Brain", brain);
+    }
+
+    fn load_villager(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        if let Some(villager_data) = nbt.compound("VillagerData") {
+            if let Some(villager_type) = villager_data
+                .string("type")
+                .and_then(|key| Identifier::from_str(key.to_str().as_ref()).ok())
+                .and_then(|key| REGISTRY.villager_types.by_key(&key))
+            {
+                self.set_villager_type(villager_type);
+            }
+            if let Some(profession) = villager_data
+                .string("profession")
+                .and_then(|key| Identifier::from_str(key.to_str().as_ref()).ok())
+                .and_then(|key| REGISTRY.villager_professions.by_key(&key))
+            {
+                self.set_profession(profession);
+            }
+            let mut data = self.villager_data();
+            data.level = villager_data
+                .int("level")
+                .unwrap_or(MIN_VILLAGER_LEVEL)
+                .clamp(MIN_VILLAGER_LEVEL, MAX_VILLAGER_LEVEL);
+            self.entity_data.lock().villager_data.set(data);
+        }
+
+        // Loaded after `VillagerData`, whose profession change clears offers.
+        if let Some(offers) = nbt.list("Offers").and_then(|list| list.compounds()) {
+            *self.offers.lock() = Some(
+                offers
+                    .iter()
+                    .filter_map(MerchantOffer::from_borrowed_compound)
+                    .collect(),
+            );
+        }
+
+        if let Some(gossips) = nbt.list("Gossips").and_then(|list| list.compounds()) {
+            let mut reputation = self.reputation.lock();
+            reputation.clear();
+            for gossip in &gossips {
+                let Some(uuid) = gossip
+                    .int_array("Target")
+                    .and_then(|target| Uuid::from_int_array(&target))
+                else {
+                    continue;
+                };
+                let value = gossip.int("Value").unwrap_or(0);
+                let entry = reputation.entry(uuid).or_default();
+                match gossip.string("Type").map(|kind| kind.to_str()).as_deref() {
+                    Some("trading") => entry.trading = value.min(MAX_TRADING_REPUTATION),
+                    Some("minor_negative") => {
+                        entry.minor_negative = value.min(MAX_MINOR_NEGATIVE_REPUTATION);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        {
+            let mut state = self.trade_state.lock();
+            state.villager_xp = nbt.int("Xp").unwrap_or(0);
+            state.last_restock_game_time = nbt.long("LastRestock").unwrap_or(0);
+            state.number_of_restocks_today = nbt.int("RestocksToday").unwrap_or(0);
+        }
+
+        if let Some(memories) = nbt
+            .compound("Brain")
+            .and_then(|brain| brain.compound("memories"))
+        {
+            for memory in [VillagerPoiMemory::Home, VillagerPoiMemory::JobSite] {
+                let pos = memories
+                    .compound(memory.key())
+                    .and_then(|entry| entry.compound("value"))
+                    .and_then(|value| {
+                        let dimension = value
+                            .string("dimension")
+                            .and_then(|key| Identifier::from_str(key.to_str().as_ref()).ok())?;
+                        let pos = value.int_array("pos").filter(|pos| pos.len() == 3)?;
+                        Some(GlobalPos::new(
+                            dimension,
+                            BlockPos::new(pos[0], pos[1], pos[2]),
+                        ))
+                    });
+                *self.poi_memory(memory).lock() = pos;
+            }
+        }
+    }
+}
+
+/// Based on Java's `VillagerData::canLevelUp`.
+const fn can_level_up(level: i32) -> bool {
+    level >= MIN_VILLAGER_LEVEL && level < MAX_VILLAGER_LEVEL
+}
+
+/// Based on Java's `VillagerData::getMaxXpPerLevel`.
+const fn max_xp_per_level(level: i32) -> i32 {
+    if can_level_up(level) {
+        NEXT_LEVEL_XP_THRESHOLDS[level as usize]
+    } else {
+        0
+    }
+}
+
+/// Based on Java's `VillagerType.BY_BIOME`.
+fn villager_type_for_biome(biome: &Identifier) -> VillagerTypeRef {
+    match biome.path.as_ref() {
+        "badlands" | "desert" | "eroded_badlands" | "wooded_badlands" => {
+            &vanilla_villager_types::DESERT
+        }
+        "bamboo_jungle" | "jungle" | "sparse_jungle" => &vanilla_villager_types::JUNGLE,
+        "savanna_plateau" | "savanna" | "windswept_savanna" => &vanilla_villager_types::SAVANNA,
+        "deep_frozen_ocean" | "frozen_ocean" | "frozen_river" | "ice_spikes" | "snowy_beach"
+        | "snowy_taiga" | "snowy_plains" | "grove" | "snowy_slopes" | "frozen_peaks"
+        | "jagged_peaks" => &vanilla_villager_types::SNOW,
+        "mangrove_swamp" | "swamp" => &vanilla_villager_types::SWAMP,
+        "old_growth_spruce_taiga"
+        | "old_growth_pine_taiga"
+        | "windswept_gravelly_hills"
+        | "windswept_hills"
+        | "taiga"
+        | "windswept_forest" => &vanilla_villager_types::TAIGA,
+        _ => &vanilla_villager_types::PLAINS,
+    }
+}
+
+#[entity_impl(class(ageable_mob), interfaces(merchant))]
+impl Entity for VillagerEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    /// Based on Java's `Villager::getTypeName`, which names the profession.
+    fn type_name(&self) -> TextComponent {
+        let profession = &self.profession().key;
+        TextComponent::translated(TranslatedMessage {
+            key: format!(
+                "entity.{}.villager.{}",
+                profession.namespace, profession.path
+            )
+            .into(),
+            fallback: None,
+            args: None,
+        })
+    }
+
+    fn dimensions_for_pose(&self, _pose: EntityPose) -> EntityDimensions {
+        let scale = LivingEntity::get_scale(self);
+        if self.is_baby() {
+            VILLAGER_BABY_DIMENSIONS.scale(scale)
+        } else if self.entity_type.fixed {
+            self.entity_type.dimensions
+        } else {
+            self.entity_type.dimensions.scale(scale)
+        }
+    }
+
+    fn tick(&self) {
+        self.default_tick();
+        self.living_base.decrement_invulnerable_time();
+        self.tick_mob_effects();
+
+        if self.is_dead_or_dying() {
+            self.release_all_pois();
+            LivingEntity::tick_death(self);
+            self.tick_living_state();
+            return;
+        }
+
+        if !self.is_removed() {
+            self.ai_step();
+        }
+
+        let unhappy_counter = self.unhappy_counter();
+        if unhappy_counter > 0 {
+            self.set_unhappy_counter(unhappy_counter - 1);
+        }
+
+        self.tick_living_state();
+    }
+
+    fn check_despawn(&self) {
+        Mob::check_mob_despawn(self);
+    }
+
+    fn thunder_hit(&self, world: &Arc<World>, _lightning: &LightningBoltEntity) {
+        if world.difficulty() == Difficulty::Peaceful {
+            self.default_thunder_hit(world);
+            return;
+        }
+
+        log::info!("Villager {} was struck by lightning.", self.id());
+        let witch = self.convert_to(
+            world,
+            &vanilla_entities::WITCH,
+            ConversionParams::single(false, false),
+            &mut |witch: &dyn Mob| {
+                let _ = witch.finalize_spawn(world, EntitySpawnReason::Conversion, None);
+                witch.set_persistence_required();
+                self.release_all_pois();
+            },
+        );
+        if witch.is_none() {
+            self.default_thunder_hit(world);
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.is_removed() && self.get_health() > 0.0
+    }
+
+    fn is_pickable(&self) -> bool {
+        !self.is_removed()
+    }
+
+    fn is_pushable(&self) -> bool {
+        Entity::is_alive(self) && !self.is_spectator() && !self.on_climbable()
+    }
+
+    fn is_effective_ai(&self) -> bool {
+        self.is_server_driven_movement() && !self.is_no_ai()
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        LivingEntity::get_attribute_gravity(self)
+    }
+
+    fn can_freeze(&self) -> bool {
+        self.default_living_can_freeze()
+    }
+
+    fn can_walk_on_powder_snow(&self) -> bool {
+        self.default_living_can_walk_on_powder_snow()
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn update_data_before_sync(&self) {
+        self.update_dirty_mob_effect_entity_data();
+    }
+
+    fn pack_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().syncable_snapshots()
+    }
+
+    fn drain_dirty_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().drain_dirty_sync()
+    }
+
+    fn drain_dirty_mob_effects(&self) -> Vec<MobEffectSyncChange> {
+        self.living_base.drain_dirty_mob_effects()
+    }
+
+    fn pack_all_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.pack_living_equipment()
+    }
+
+    fn drain_dirty_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.drain_dirty_living_equipment()
+    }
+
+    fn max_up_step(&self) -> f32 {
+        self.attributes()
+            .lock()
+            .get_value(vanilla_attributes::STEP_HEIGHT)
+            .unwrap_or(0.6) as f32
+    }
+
+    fn sound_source(&self) -> SoundSource {
+        SoundSource::Neutral
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        LivingEntity::hurt_server(self, world, source, amount)
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        Mob::interact_mob(self, player, hand, location)
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        self.save_mob(nbt);
+        self.save_ageable_mob(nbt);
+        self.save_villager(nbt);
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        self.load_mob(nbt);
+        self.load_ageable_mob(nbt);
+        self.load_villager(nbt);
+    }
+}
+
+impl LivingEntity for VillagerEntity {
+    fn living_base(&self) -> &LivingEntityBase {
+        &self.living_base
+    }
+
+    fn get_health(&self) -> f32 {
+        *self.entity_data.lock().living_entity().health.get()
+    }
+
+    fn set_health(&self, health: f32) {
+        let max_health = self.get_max_health();
+        let clamped = health.clamp(0.0, max_health);
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .health
+            .set(clamped);
+    }
+
+    fn is_baby(&self) -> bool {
+        AgeableMob::is_baby(self)
+    }
+
+    fn hurt_sound(&self, _source: &DamageSource) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_VILLAGER_HURT)
+    }
+
+    fn death_sound(&self) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_VILLAGER_DEATH)
+    }
+
+    fn server_ai_step(&self) {
+        Mob::mob_server_ai_step(self);
+    }
+
+    /// Based on Java's `Villager::setLastHurtByMob`, recording player attacks.
+    fn before_actually_hurt(&self, source: &DamageSource, _amount: f32) {
+        let Some(world) = self.level() else {
+            return;
+        };
+        let Some(attacker) = source
+            .causing_entity_id
+            .and_then(|id| world.get_entity_by_id(id))
+        else {
+            return;
+        };
+        if attacker.as_player().is_none() {
+            return;
+        }
+
+        self.add_reputation(attacker.uuid(), |reputation| {
+            reputation.minor_negative = (reputation.minor_negative + HURT_REPUTATION_LOSS)
+                .min(MAX_MINOR_NEGATIVE_REPUTATION);
+        });
+        if LivingEntity::is_alive(self) {
+            self.broadcast_entity_event(EntityStatus::VillagerAngry);
+        }
+    }
+
+    fn ai_step(&self) -> Option<MoveResult> {
+        let result = self.default_ai_step();
+
+        AgeableMob::tick_ageable_mob(self);
+        result
+    }
+}
+
+impl AgeableMob for VillagerEntity {
+    fn ageable_base(&self) -> &AgeableMobBase {
+        &self.ageable_base
+    }
+
+    fn is_age_locked(&self) -> bool {
+        *self.entity_data.lock().ageable_mob().age_locked.get()
+    }
+
+    fn set_age_locked(&self, age_locked: bool) {
+        self.entity_data
+            .lock()
+            .ageable_mob_mut()
+            .age_locked
+            .set(age_locked);
+    }
+
+    fn set_synced_baby(&self, baby: bool) {
+        self.entity_data.lock().ageable_mob_mut().baby.set(baby);
+    }
+
+    fn age_boundary_changed(&self, _baby: bool) {
+        self.refresh_dimensions();
+    }
+}
+
+impl Mob for VillagerEntity {
+    fn mob_base(&self) -> &MobBase {
+        &self.mob_base
+    }
+
+    fn tick_goal_selectors(&self) {
+        PathfinderMob::tick_pathfinder_goal_selectors(self);
+    }
+
+    fn tick_path_navigation(&self) {
+        PathfinderMob::tick_pathfinder_path_navigation(self);
+    }
+
+    /// Based on Java's `Villager::customServerAiStep`.
+    fn custom_server_ai_step(&self) {
+        let level_up = {
+            let mut state = self.trade_state.lock();
+            if !self.is_trading() && state.update_merchant_timer > 0 {
+                state.update_merchant_timer -= 1;
+                (state.update_merchant_timer <= 0)
+                    .then(|| mem::take(&mut state.increase_profession_level_on_update))
+            } else {
+                None
+            }
+        };
+        if let Some(increase_level) = level_up {
+            if increase_level {
+                self.increase_merchant_career();
+            }
+            self.add_mob_effect(MobEffectInstance::with_duration(
+                vanilla_mob_effects::REGENERATION,
+                200,
+                0,
+            ));
+        }
+
+        let last_traded_player = self.trade_state.lock().last_traded_player.take();
+        if let Some(player) = last_traded_player {
+            self.add_reputation(player, |reputation| {
+                reputation.trading =
+                    (reputation.trading + TRADING_REPUTATION_GAIN).min(MAX_TRADING_REPUTATION);
+            });
+            self.broadcast_entity_event(EntityStatus::VillagerHappy);
+        }
+
+        if self.profession().key == vanilla_villager_professions::NONE.key && self.is_trading() {
+            self.set_trading_player(None);
+        }
+
+        if self.tick_count() % POI_VALIDATION_INTERVAL == 0
+            && let Some(world) = self.level()
+        {
+            self.validate_pois(&world);
+        }
+    }
+
+    fn ambient_sound(&self) -> Option<SoundEventRef> {
+        if self.is_trading() {
+            Some(&sound_events::ENTITY_VILLAGER_TRADE)
+        } else {
+            Some(&sound_events::ENTITY_VILLAGER_AMBIENT)
+        }
+    }
+
+    fn remove_when_far_away(&self, _dist_sqr: f64) -> bool {
+        false
+    }
+
+    fn finalize_spawn(
+        &self,
+        world: &Arc<World>,
+        spawn_reason: EntitySpawnReason,
+        group_data: Option<SpawnGroupData>,
+    ) -> Option<SpawnGroupData> {
+        if let Some(biome) = world.biome_at(self.block_position()) {
+            self.set_villager_type(villager_type_for_biome(&biome.key));
+        }
+
+        self.finalize_spawn_ageable_mob(world, spawn_reason, group_data)
+    }
+
+    /// Based on Java's `Villager::mobInteract`.
+    fn mob_interact(&self, player: &Player, hand: InteractionHand) -> InteractionResult {
+        if !LivingEntity::is_alive(self) || self.is_trading() {
+            return InteractionResult::Pass;
+        }
+        if self.is_baby() {
+            self.set_unhappy();
+            return InteractionResult::Success;
+        }
+
+        let no_offers = self.offers().is_empty();
+        if hand == InteractionHand::MainHand && no_offers {
+            self.set_unhappy();
+        }
+        if no_offers {
+            return InteractionResult::Consume;
+        }
+
+        self.start_trading(player);
+        InteractionResult::Success
+    }
+
+    fn mob_flags(&self) -> i8 {
+        *self.entity_data.lock().mob().mob_flags.get()
+    }
+
+    fn set_mob_flags(&self, flags: i8) {
+        self.entity_data.lock().mob_mut().mob_flags.set(flags);
+    }
+}
+
+impl PathfinderMob for VillagerEntity {}
+
+impl Merchant for VillagerEntity {
+    fn trading_player(&self) -> Option<Uuid> {
+        *self.trading_player.lock()
+    }
+
+    /// Based on Java's `Villager::setTradingPlayer`, which resets prices when trading stops.
+    fn set_trading_player(&self, player: Option<Uuid>) {
+        let stopped_trading = {
+            let mut trading_player = self.trading_player.lock();
+            let stopped_trading = trading_player.is_some() && player.is_none();
+            *trading_player = player;
+            stopped_trading
+        };
+        if stopped_trading {
+            self.reset_special_prices();
+        }
+    }
+
+    fn offers(&self) -> MappedMutexGuard<'_, MerchantOffers> {
+        let data = self.villager_data();
+        let profession = self.profession();
+        MutexGuard::map(self.offers.lock(), |offers| {
+            offers.get_or_insert_with(|| {
+                let mut offers = MerchantOffers::new();
+                villager_trades::add_offers_for_level(&mut offers, profession, data.level);
+                offers
+            })
+        })
+    }
+
+    /// Based on Java's `AbstractVillager::notifyTrade` and `Villager::rewardTradeXp`.
+    fn notify_trade(&self, offer: usize) {
+        let Some((xp, reward_exp)) = self.offers().get_mut(offer).map(|offer| {
+            offer.increase_uses();
+            (offer.xp, offer.reward_exp)
+        }) else {
+            return;
+        };
+        self.reset_ambient_sound_time();
+
+        let mut reward = 3 + rand::random_range(0..4);
+        {
+            let mut state = self.trade_state.lock();
+            state.villager_xp += xp;
+            state.last_traded_player = *self.trading_player.lock();
+            if self.should_increase_level(state.villager_xp) {
+                state.update_merchant_timer = LEVEL_UP_DELAY;
+                state.increase_profession_level_on_update = true;
+                reward += 5;
+            }
+        }
+
+        if reward_exp && let Some(world) = self.level() {
+            ExperienceOrbEntity::award(&world, self.position() + DVec3::new(0.0, 0.5, 0.0), reward);
+        }
+    }
+
+    /// Based on Java's `AbstractVillager::notifyTradeUpdated`.
+    fn notify_trade_updated(&self, item: &ItemStack) {
+        let interval = self.ambient_sound_interval();
+        if self.mob_base().ambient_sound_time() > -interval + 20 {
+            self.mob_base().set_ambient_sound_time(-interval);
+            self.make_sound(Some(if item.is_empty() {
+                &sound_events::ENTITY_VILLAGER_NO
+            } else {
+                &sound_events::ENTITY_VILLAGER_YES
+            }));
+        }
+    }
+
+    fn villager_xp(&self) -> i32 {
+        self.trade_state.lock().villager_xp
+    }
+
+    fn show_progress_bar(&self) -> bool {
+        true
+    }
+
+    fn can_restock(&self) -> bool {
+        true
+    }
+
+    /// Based on Java's `Merchant::stillValid`.
+    fn still_valid(&self, player: &Player) -> bool {
+        self.trading_player() == Some(player.uuid())
+            && LivingEntity::is_alive(self)
+            && player.is_within_entity_interaction_range(self.bounding_box(), 4.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use simdnbt::borrow::read_compound as read_borrowed_compound;
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::vanilla_entities;
+
+    use super::*;
+
+    fn villager() -> VillagerEntity {
+        VillagerEntity::new(&vanilla_entities::VILLAGER, 1, DVec3::ZERO, Weak::new())
+    }
+
+    #[test]
+    fn villager_initializes_vanilla_living_attributes_and_health() {
+        init_test_registry();
+
+        let villager = villager();
+
+        assert_eq!(villager.get_health().to_bits(), 20.0_f32.to_bits());
+        let attributes = villager.attributes().lock();
+        assert_eq!(
+            attributes
+                .required_value(vanilla_attributes::MOVEMENT_SPEED)
+                .to_bits(),
+            f64::from(0.5_f32).to_bits()
+        );
+    }
+
+    #[test]
+    fn villager_registers_trading_and_poi_goals() {
+        init_test_registry();
+
+        let villager = villager();
+
+        let selector = villager.mob_base().goal_selector().lock();
+        assert_eq!(
+            selector.available_goal_priorities(),
+            vec![0, 1, 1, 2, 3, 4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn villager_exposes_merchant_without_downcasting() {
+        init_test_registry();
+
+        let villager = villager();
+        let entity = &villager as &dyn Entity;
+
+        assert!(entity.is_pathfinder_mob());
+        let Some(merchant) = entity.as_merchant() else {
+            panic!("villager should expose merchant behavior");
+        };
+        assert!(merchant.trading_player().is_none());
+        assert!(merchant.show_progress_bar());
+    }
+
+    #[test]
+    fn unemployed_villager_has_no_offers() {
+        init_test_registry();
+
+        let villager = villager();
+
+        assert_eq!(
+            villager.profession().key,
+            vanilla_villager_professions::NONE.key
+        );
+        assert!(villager.offers().is_empty());
+    }
+
+    #[test]
+    fn villager_profession_change_regenerates_offers() {
+        init_test_registry();
+
+        let villager = villager();
+        assert!(villager.offers().is_empty());
+        villager.set_profession(&vanilla_villager_professions::FARMER);
+
+        assert_eq!(villager.offers().len(), 2);
+    }
+
+    #[test]
+    fn villager_levels_up_at_vanilla_xp_thresholds() {
+        assert_eq!(max_xp_per_level(1), 10);
+        assert_eq!(max_xp_per_level(4), 250);
+        assert_eq!(max_xp_per_level(5), 0);
+        assert!(!can_level_up(5));
+    }
+
+    #[test]
+    fn villager_baby_uses_vanilla_baby_dimensions() {
+        init_test_registry();
+
+        let villager = villager();
+        villager.set_baby(true);
+
+        let dimensions = villager.dimensions_for_pose(EntityPose::Standing);
+        assert_eq!(dimensions.width.to_bits(), 0.49_f32.to_bits());
+        assert_eq!(dimensions.height.to_bits(), 0.98_f32.to_bits());
+    }
+
+    #[test]
+    fn villager_round_trips_vanilla_data() {
+        init_test_registry();
+
+        let villager = villager();
+        villager.set_profession(&vanilla_villager_professions::LIBRARIAN);
+        villager.set_villager_type(&vanilla_villager_types::SNOW);
+        let offer_count = villager.offers().len();
+        let player = Uuid::from_u128(7);
+        villager.add_reputation(player, |reputation| reputation.trading = 4);
+        villager.trade_state.lock().villager_xp = 12;
+        *villager.job_site.lock() = Some(GlobalPos::new(
+            Identifier::vanilla_static("overworld"),
+            BlockPos::new(1, 64, 2),
+        ));
+
+        let mut nbt = NbtCompound::new();
+        villager.save_additional(&mut nbt);
+        let mut bytes = Vec::new();
+        nbt.write(&mut bytes);
+        let borrowed = read_borrowed_compound(&mut Cursor::new(&bytes))
+            .unwrap_or_else(|error| panic!("test nbt should reborrow: {error}"));
+        let loaded = VillagerEntity::new(&vanilla_entities::VILLAGER, 2, DVec3::ZERO, Weak::new());
+        loaded.load_additional((&borrowed).into());
+
+        assert_eq!(
+            loaded.profession().key,
+            vanilla_villager_professions::LIBRARIAN.key
+        );
+        assert_eq!(loaded.villager_type().key, vanilla_villager_types::SNOW.key);
+        assert_eq!(loaded.offers().len(), offer_count);
+        assert_eq!(loaded.player_reputation(player), 4);
+        assert_eq!(loaded.villager_xp(), 12);
+        assert_eq!(
+            loaded.poi(VillagerPoiMemory::JobSite),
+            Some(BlockPos::new(1, 64, 2))
+        );
+    }
+}
@@ -0,0 +1,468 @@
+//! Vanilla villager trade listings (`VillagerTrades.TRADES`).
+//!
+//! Listings that need enchantments, maps, potions or dyed armor are not
+//! generated yet.
+
+use rand::seq::SliceRandom as _;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::items::ItemRef;
+use steel_registry::trading::{ItemCost, MerchantOffer, MerchantOffers};
+use steel_registry::vanilla_items;
+use steel_registry::villager_profession::VillagerProfessionRef;
+
+/// Number of listings a villager gains per profession level.
+const OFFERS_PER_LEVEL: usize = 2;
+
+/// Vanilla `VillagerTrades.ItemListing` variants without randomized components.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum ItemListing {
+    /// Vanilla `EmeraldForItems`: buys `cost` items for one emerald.
+    EmeraldForItems {
+        item: ItemRef,
+        cost: i32,
+        max_uses: i32,
+        villager_xp: i32,
+    },
+    /// Vanilla `ItemsForEmeralds`: sells `number_of_items` items for emeralds.
+    ItemsForEmeralds {
+        item: ItemRef,
+        emerald_cost: i32,
+        number_of_items: i32,
+        max_uses: i32,
+        villager_xp: i32,
+        price_multiplier: f32,
+    },
+    /// Vanilla `ItemsAndEmeraldsToItems`: converts items plus emeralds into other items.
+    ItemsAndEmeraldsToItems {
+        from_item: ItemRef,
+        from_count: i32,
+        emerald_cost: i32,
+        to_item: ItemRef,
+        to_count: i32,
+        max_uses: i32,
+        villager_xp: i32,
+    },
+}
+
+impl ItemListing {
+    const DEFAULT_PRICE_MULTIPLIER: f32 = 0.05;
+
+    const fn emerald_for_items(item: ItemRef, cost: i32, max_uses: i32, villager_xp: i32) -> Self {
+        Self::EmeraldForItems {
+            item,
+            cost,
+            max_uses,
+            villager_xp,
+        }
+    }
+
+    const fn items_for_emeralds(
+        item: ItemRef,
+        emerald_cost: i32,
+        number_of_items: i32,
+        max_uses: i32,
+        villager_xp: i32,
+    ) -> Self {
+        Self::ItemsForEmeralds {
+            item,
+            emerald_cost,
+            number_of_items,
+            max_uses,
+            villager_xp,
+            price_multiplier: Self::DEFAULT_PRICE_MULTIPLIER,
+        }
+    }
+
+    /// Armor and tool listings use a steeper price multiplier.
+    const fn gear_for_emeralds(
+        item: ItemRef,
+        emerald_cost: i32,
+        max_uses: i32,
+        villager_xp: i32,
+    ) -> Self {
+        Self::ItemsForEmeralds {
+            item,
+            emerald_cost,
+            number_of_items: 1,
+            max_uses,
+            villager_xp,
+            price_multiplier: 0.2,
+        }
+    }
+
+    const fn items_and_emeralds_to_items(
+        from_item: ItemRef,
+        from_count: i32,
+        emerald_cost: i32,
+        to_item: ItemRef,
+        to_count: i32,
+        max_uses: i32,
+        villager_xp: i32,
+    ) -> Self {
+        Self::ItemsAndEmeraldsToItems {
+            from_item,
+            from_count,
+            emerald_cost,
+            to_item,
+            to_count,
+            max_uses,
+            villager_xp,
+        }
+    }
+
+    /// Mirrors vanilla `ItemListing.getOffer`.
+    pub(super) fn get_offer(&self) -> MerchantOffer {
+        match *self {
+            Self::EmeraldForItems {
+                item,
+                cost,
+                max_uses,
+                villager_xp,
+            } => MerchantOffer::new(
+                ItemCost::new(item, cost),
+                None,
+                ItemStack::new(&vanilla_items::EMERALD),
+                max_uses,
+                villager_xp,
+                Self::DEFAULT_PRICE_MULTIPLIER,
+            ),
+            Self::ItemsForEmeralds {
+                item,
+                emerald_cost,
+                number_of_items,
+                max_uses,
+                villager_xp,
+                price_multiplier,
+            } => MerchantOffer::new(
+                ItemCost::new(&vanilla_items::EMERALD, emerald_cost),
+                None,
+                ItemStack::with_count(item, number_of_items),
+                max_uses,
+                villager_xp,
+                price_multiplier,
+            ),
+            Self::ItemsAndEmeraldsToItems {
+                from_item,
+                from_count,
+                emerald_cost,
+                to_item,
+                to_count,
+                max_uses,
+                villager_xp,
+            } => MerchantOffer::new(
+                ItemCost::new(&vanilla_items::EMERALD, emerald_cost),
+                Some(ItemCost::new(from_item, from_count)),
+                ItemStack::with_count(to_item, to_count),
+                max_uses,
+                villager_xp,
+                Self::DEFAULT_PRICE_MULTIPLIER,
+            ),
+        }
+    }
+}
+
+/// Adds up to two random listings for `profession` at `level`.
+///
+/// Based on Java's `Villager::updateTrades` and
+/// `AbstractVillager::addOffersFromItemListings`.
+pub(super) fn add_offers_for_level(
+    offers: &mut MerchantOffers,
+    profession: VillagerProfessionRef,
+    level: i32,
+) {
+    let mut listings = listings(profession, level);
+    listings.shuffle(&mut rand::rng());
+    offers.extend(
+        listings
+            .iter()
+            .take(OFFERS_PER_LEVEL)
+            .map(ItemListing::get_offer),
+    );
+}
+
+/// Returns the vanilla listings a profession unlocks at `level`.
+#[must_use]
+pub(super) fn listings(profession: VillagerProfessionRef, level: i32) -> Vec<ItemListing> {
+    use ItemListing as L;
+    use vanilla_items as i;
+
+    if profession.key.namespace != "minecraft" {
+        return Vec::new();
+    }
+
+    match (profession.key.path.as_ref(), level) {
+        ("farmer", 1) => vec![
+            L::emerald_for_items(&i::WHEAT, 20, 16, 2),
+            L::emerald_for_items(&i::POTATO, 26, 16, 2),
+            L::emerald_for_items(&i::CARROT, 22, 16, 2),
+            L::emerald_for_items(&i::BEETROOT, 15, 16, 2),
+            L::items_for_emeralds(&i::BREAD, 1, 6, 16, 1),
+        ],
+        ("farmer", 2) => vec![
+            L::emerald_for_items(&i::PUMPKIN, 6, 12, 10),
+            L::items_for_emeralds(&i::PUMPKIN_PIE, 1, 4, 12, 5),
+            L::items_for_emeralds(&i::APPLE, 1, 4, 16, 5),
+        ],
+        ("farmer", 3) => vec![
+            L::items_for_emeralds(&i::COOKIE, 3, 18, 12, 10),
+            L::emerald_for_items(&i::MELON, 4, 12, 20),
+        ],
+        ("farmer", 4) => vec![L::items_for_emeralds(&i::CAKE, 1, 1, 12, 15)],
+        ("farmer", 5) => vec![
+            L::items_for_emeralds(&i::GOLDEN_CARROT, 3, 3, 12, 30),
+            L::items_for_emeralds(&i::GLISTERING_MELON_SLICE, 4, 3, 12, 30),
+        ],
+        ("fisherman", 1) => vec![
+            L::emerald_for_items(&i::STRING, 20, 16, 2),
+            L::emerald_for_items(&i::COAL, 10, 16, 2),
+            L::items_and_emeralds_to_items(&i::COD, 6, 1, &i::COOKED_COD, 6, 16, 1),
+            L::items_for_emeralds(&i::COD_BUCKET, 3, 1, 16, 1),
+        ],
+        ("fisherman", 2) => vec![
+            L::emerald_for_items(&i::COD, 15, 16, 10),
+            L::items_and_emeralds_to_items(&i::SALMON, 6, 1, &i::COOKED_SALMON, 6, 16, 5),
+            L::items_for_emeralds(&i::CAMPFIRE, 2, 1, 12, 5),
+        ],
+        ("fisherman", 3) => vec![L::emerald_for_items(&i::SALMON, 13, 16, 20)],
+        ("fisherman", 4) => vec![L::emerald_for_items(&i::TROPICAL_FISH, 6, 12, 30)],
+        ("fisherman", 5) => vec![L::emerald_for_items(&i::PUFFERFISH, 4, 12, 30)],
+        ("shepherd", 1) => vec![
+            L::emerald_for_items(&i::WHITE_WOOL, 18, 16, 2),
+            L::emerald_for_items(&i::BROWN_WOOL, 18, 16, 2),
+            L::emerald_for_items(&i::BLACK_WOOL, 18, 16, 2),
+            L::emerald_for_items(&i::GRAY_WOOL, 18, 16, 2),
+            L::items_for_emeralds(&i::SHEARS, 2, 1, 12, 1),
+        ],
+        ("shepherd", 2) => vec![
+            L::emerald_for_items(&i::WHITE_DYE, 12, 16, 10),
+            L::emerald_for_items(&i::GRAY_DYE, 12, 16, 10),
+            L::emerald_for_items(&i::BLACK_DYE, 12, 16, 10),
+            L::emerald_for_items(&i::LIGHT_BLUE_DYE, 12, 16, 10),
+            L::emerald_for_items(&i::LIME_DYE, 12, 16, 10),
+            L::items_for_emeralds(&i::WHITE_WOOL, 1, 1, 16, 5),
+            L::items_for_emeralds(&i::WHITE_CARPET, 1, 4, 16, 5),
+        ],
+        ("shepherd", 3) => vec![
+            L::emerald_for_items(&i::YELLOW_DYE, 12, 16, 20),
+            L::emerald_for_items(&i::LIGHT_GRAY_DYE, 12, 16, 20),
+            L::emerald_for_items(&i::ORANGE_DYE, 12, 16, 20),
+            L::emerald_for_items(&i::RED_DYE, 12, 16, 20),
+            L::emerald_for_items(&i::PINK_DYE, 12, 16, 20),
+            L::items_for_emeralds(&i::WHITE_BED, 3, 1, 12, 10),
+        ],
+        ("shepherd", 4) => vec![
+            L::emerald_for_items(&i::BROWN_DYE, 12, 16, 30),
+            L::emerald_for_items(&i::PURPLE_DYE, 12, 16, 30),
+            L::emerald_for_items(&i::BLUE_DYE, 12, 16, 30),
+            L::emerald_for_items(&i::GREEN_DYE, 12, 16, 30),
+            L::emerald_for_items(&i::MAGENTA_DYE, 12, 16, 30),
+            L::emerald_for_items(&i::CYAN_DYE, 12, 16, 30),
+            L::items_for_emeralds(&i::WHITE_BANNER, 3, 1, 12, 15),
+        ],
+        ("shepherd", 5) => vec![L::items_for_emeralds(&i::PAINTING, 2, 3, 12, 30)],
+        ("fletcher", 1) => vec![
+            L::emerald_for_items(&i::STICK, 32, 16, 2),
+            L::items_for_emeralds(&i::ARROW, 1, 16, 12, 1),
+            L::items_and_emeralds_to_items(&i::GRAVEL, 10, 1, &i::FLINT, 10, 12, 1),
+        ],
+        ("fletcher", 2) => vec![
+            L::emerald_for_items(&i::FLINT, 26, 12, 10),
+            L::items_for_emeralds(&i::BOW, 2, 1, 12, 5),
+        ],
+        ("fletcher", 3) => vec![
+            L::emerald_for_items(&i::STRING, 14, 16, 20),
+            L::items_for_emeralds(&i::CROSSBOW, 3, 1, 12, 10),
+        ],
+        ("fletcher", 4) => vec![L::emerald_for_items(&i::FEATHER, 24, 16, 30)],
+        ("fletcher", 5) => vec![L::emerald_for_items(&i::TRIPWIRE_HOOK, 8, 12, 30)],
+        ("librarian", 1) => vec![
+            L::emerald_for_items(&i::PAPER, 24, 16, 2),
+            L::items_for_emeralds(&i::BOOKSHELF, 9, 1, 12, 1),
+        ],
+        ("librarian", 2) => vec![
+            L::emerald_for_items(&i::BOOK, 4, 12, 10),
+            L::items_for_emeralds(&i::LANTERN, 1, 1, 12, 5),
+        ],
+        ("librarian", 3) => vec![
+            L::emerald_for_items(&i::INK_SAC, 5, 12, 20),
+            L::items_for_emeralds(&i::GLASS, 1, 4, 12, 10),
+        ],
+        ("librarian", 4) => vec![
+            L::emerald_for_items(&i::WRITABLE_BOOK, 2, 12, 30),
+            L::items_for_emeralds(&i::CLOCK, 5, 1, 12, 15),
+            L::items_for_emeralds(&i::COMPASS, 4, 1, 12, 15),
+        ],
+        ("librarian", 5) => vec![L::items_for_emeralds(&i::NAME_TAG, 20, 1, 12, 30)],
+        ("cartographer", 1) => vec![
+            L::emerald_for_items(&i::PAPER, 24, 16, 2),
+            L::items_for_emeralds(&i::MAP, 7, 1, 12, 1),
+        ],
+        ("cartographer", 2) => vec![L::emerald_for_items(&i::GLASS_PANE, 11, 16, 10)],
+        ("cartographer", 3) => vec![L::emerald_for_items(&i::COMPASS, 1, 12, 20)],
+        ("cartographer", 4) => vec![
+            L::items_for_emeralds(&i::ITEM_FRAME, 7, 1, 12, 15),
+            L::items_for_emeralds(&i::WHITE_BANNER, 3, 1, 12, 15),
+        ],
+        ("cartographer", 5) => vec![L::items_for_emeralds(
+            &i::GLOBE_BANNER_PATTERN,
+            8,
+            1,
+            12,
+            30,
+        )],
+        ("cleric", 1) => vec![
+            L::emerald_for_items(&i::ROTTEN_FLESH, 32, 16, 2),
+            L::items_for_emeralds(&i::REDSTONE, 1, 2, 12, 1),
+        ],
+        ("cleric", 2) => vec![
+            L::emerald_for_items(&i::GOLD_INGOT, 3, 12, 10),
+            L::items_for_emeralds(&i::LAPIS_LAZULI, 1, 1, 12, 5),
+        ],
+        ("cleric", 3) => vec![
+            L::emerald_for_items(&i::RABBIT_FOOT, 2, 12, 20),
+            L::items_for_emeralds(&i::GLOWSTONE, 4, 1, 12, 10),
+        ],
+        ("cleric", 4) => vec![
+            L::emerald_for_items(&i::TURTLE_SCUTE, 4, 12, 30),
+            L::emerald_for_items(&i::GLASS_BOTTLE, 9, 12, 30),
+            L::items_for_emeralds(&i::ENDER_PEARL, 5, 1, 12, 15),
+        ],
+        ("cleric", 5) => vec![
+            L::emerald_for_items(&i::NETHER_WART, 22, 12, 30),
+            L::items_for_emeralds(&i::EXPERIENCE_BOTTLE, 3, 1, 12, 30),
+        ],
+        ("armorer", 1) => vec![
+            L::emerald_for_items(&i::COAL, 15, 16, 2),
+            L::gear_for_emeralds(&i::IRON_LEGGINGS, 7, 12, 1),
+            L::gear_for_emeralds(&i::IRON_BOOTS, 4, 12, 1),
+            L::gear_for_emeralds(&i::IRON_HELMET, 5, 12, 1),
+            L::gear_for_emeralds(&i::IRON_CHESTPLATE, 9, 12, 1),
+        ],
+        ("armorer", 2) => vec![
+            L::emerald_for_items(&i::IRON_INGOT, 4, 12, 10),
+            L::gear_for_emeralds(&i::BELL, 36, 12, 5),
+            L::gear_for_emeralds(&i::CHAINMAIL_BOOTS, 1, 12, 5),
+            L::gear_for_emeralds(&i::CHAINMAIL_LEGGINGS, 3, 12, 5),
+        ],
+        ("armorer", 3) => vec![
+            L::emerald_for_items(&i::LAVA_BUCKET, 1, 12, 20),
+            L::emerald_for_items(&i::DIAMOND, 1, 12, 20),
+            L::gear_for_emeralds(&i::CHAINMAIL_HELMET, 1, 12, 10),
+            L::gear_for_emeralds(&i::CHAINMAIL_CHESTPLATE, 4, 12, 10),
+            L::gear_for_emeralds(&i::SHIELD, 5, 12, 10),
+        ],
+        ("weaponsmith", 1) => vec![
+            L::emerald_for_items(&i::COAL, 15, 16, 2),
+            L::gear_for_emeralds(&i::IRON_AXE, 3, 12, 1),
+        ],
+        ("weaponsmith", 2) => vec![
+            L::emerald_for_items(&i::IRON_INGOT, 4, 12, 10),
+            L::gear_for_emeralds(&i::BELL, 36, 12, 5),
+        ],
+        ("weaponsmith", 3) => vec![L::emerald_for_items(&i::FLINT, 24, 12, 20)],
+        ("weaponsmith", 4) => vec![L::emerald_for_items(&i::DIAMOND, 1, 12, 30)],
+        ("toolsmith", 1) => vec![
+            L::emerald_for_items(&i::COAL, 15, 16, 2),
+            L::gear_for_emeralds(&i::STONE_AXE, 1, 12, 1),
+            L::gear_for_emeralds(&i::STONE_SHOVEL, 1, 12, 1),
+            L::gear_for_emeralds(&i::STONE_PICKAXE, 1, 12, 1),
+            L::gear_for_emeralds(&i::STONE_HOE, 1, 12, 1),
+        ],
+        ("toolsmith", 2) => vec![
+            L::emerald_for_items(&i::IRON_INGOT, 4, 12, 10),
+            L::gear_for_emeralds(&i::BELL, 36, 12, 5),
+        ],
+        ("toolsmith", 3) => vec![L::emerald_for_items(&i::FLINT, 30, 12, 20)],
+        ("toolsmith", 4) => vec![L::emerald_for_items(&i::DIAMOND, 1, 12, 30)],
+        ("butcher", 1) => vec![
+            L::emerald_for_items(&i::CHICKEN, 14, 16, 2),
+            L::emerald_for_items(&i::PORKCHOP, 7, 16, 2),
+            L::emerald_for_items(&i::RABBIT, 4, 16, 2),
+            L::items_for_emeralds(&i::RABBIT_STEW, 1, 1, 12, 1),
+        ],
+        ("butcher", 2) => vec![
+            L::emerald_for_items(&i::COAL, 15, 16, 2),
+            L::items_for_emeralds(&i::COOKED_PORKCHOP, 1, 5, 16, 5),
+            L::items_for_emeralds(&i::COOKED_CHICKEN, 1, 8, 16, 5),
+        ],
+        ("butcher", 3) => vec![
+            L::emerald_for_items(&i::MUTTON, 7, 16, 20),
+            L::emerald_for_items(&i::BEEF, 10, 16, 20),
+        ],
+        ("butcher", 4) => vec![L::emerald_for_items(&i::DRIED_KELP_BLOCK, 10, 12, 30)],
+        ("butcher", 5) => vec![L::emerald_for_items(&i::SWEET_BERRIES, 10, 12, 30)],
+        ("leatherworker", 1) => vec![L::emerald_for_items(&i::LEATHER, 6, 16, 2)],
+        ("leatherworker", 2) => vec![L::emerald_for_items(&i::FLINT, 26, 12, 10)],
+        ("leatherworker", 3) => vec![L::emerald_for_items(&i::RABBIT_HIDE, 9, 12, 20)],
+        ("leatherworker", 4) => vec![L::emerald_for_items(&i::TURTLE_SCUTE, 4, 12, 30)],
+        ("leatherworker", 5) => vec![L::items_for_emeralds(&i::SADDLE, 6, 1, 12, 30)],
+        ("mason", 1) => vec![
+            L::emerald_for_items(&i::CLAY_BALL, 10, 16, 2),
+            L::items_for_emeralds(&i::BRICK, 1, 10, 16, 1),
+        ],
+        ("mason", 2) => vec![
+            L::emerald_for_items(&i::STONE, 20, 16, 10),
+            L::items_for_emeralds(&i::CHISELED_STONE_BRICKS, 1, 4, 16, 5),
+        ],
+        ("mason", 3) => vec![
+            L::emerald_for_items(&i::GRANITE, 16, 16, 20),
+            L::emerald_for_items(&i::ANDESITE, 16, 16, 20),
+            L::emerald_for_items(&i::DIORITE, 16, 16, 20),
+            L::items_for_emeralds(&i::DRIPSTONE_BLOCK, 1, 4, 16, 10),
+            L::items_for_emeralds(&i::POLISHED_ANDESITE, 1, 4, 16, 10),
+            L::items_for_emeralds(&i::POLISHED_DIORITE, 1, 4, 16, 10),
+            L::items_for_emeralds(&i::POLISHED_GRANITE, 1, 4, 16, 10),
+        ],
+        ("mason", 4) => vec![
+            L::emerald_for_items(&i::QUARTZ, 12, 12, 30),
+            L::items_for_emeralds(&i::ORANGE_TERRACOTTA, 1, 1, 12, 15),
+            L::items_for_emeralds(&i::WHITE_TERRACOTTA, 1, 1, 12, 15),
+            L::items_for_emeralds(&i::ORANGE_GLAZED_TERRACOTTA, 1, 1, 12, 15),
+            L::items_for_emeralds(&i::WHITE_GLAZED_TERRACOTTA, 1, 1, 12, 15),
+        ],
+        ("mason", 5) => vec![
+            L::items_for_emeralds(&i::QUARTZ_PILLAR, 1, 1, 12, 30),
+            L::items_for_emeralds(&i::QUARTZ_BLOCK, 1, 1, 12, 30),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::vanilla_villager_professions;
+
+    use super::*;
+
+    #[test]
+    fn emerald_for_items_buys_items_for_one_emerald() {
+        init_test_registry();
+
+        let offer = ItemListing::emerald_for_items(&vanilla_items::WHEAT, 20, 16, 2).get_offer();
+
+        assert_eq!(offer.base_cost_a.item.key, vanilla_items::WHEAT.key);
+        assert_eq!(offer.base_cost_a.count, 20);
+        assert!(offer.result.is(&vanilla_items::EMERALD));
+        assert_eq!(offer.max_uses, 16);
+        assert_eq!(offer.xp, 2);
+    }
+
+    #[test]
+    fn new_level_adds_at_most_two_offers() {
+        init_test_registry();
+
+        let mut offers = MerchantOffers::new();
+        add_offers_for_level(&mut offers, &vanilla_villager_professions::FARMER, 1);
+        assert_eq!(offers.len(), 2);
+
+        add_offers_for_level(&mut offers, &vanilla_villager_professions::FARMER, 4);
+        assert_eq!(offers.len(), 3);
+    }
+
+    #[test]
+    fn unemployed_villagers_have_no_listings() {
+        assert!(listings(&vanilla_villager_professions::NONE, 1).is_empty());
+        assert!(listings(&vanilla_villager_professions::NITWIT, 1).is_empty());
+    }
+}
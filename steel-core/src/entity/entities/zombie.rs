@@ -0,0 +1,535 @@
+//! Zombie entity implementation.
+
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::NbtCompound;
+use steel_macros::{entity_behavior, entity_impl};
+use steel_protocol::packets::game::{AttributeSnapshot, EquipmentSlotItem, SoundSource};
+use steel_registry::entity_type::{EntityDimensions, EntityTypeRef};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::sound_event::SoundEventRef;
+use steel_registry::vanilla_entity_data::ZombieEntityData;
+use steel_registry::{sound_events, vanilla_attributes, vanilla_entities, vanilla_items};
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::{Difficulty, InteractionHand};
+use steel_utils::{BlockPos, BlockStateId, DowncastType, DowncastTypeKey, Identifier};
+
+use crate::behavior::InteractionResult;
+use crate::entity::ai::goal::{
+    HurtByTargetGoal, LookAtPlayerGoal, NearestAttackableTargetGoal, RandomLookAroundGoal,
+    WaterAvoidingRandomStrollGoal, ZombieAttackGoal,
+};
+use crate::entity::attribute::{AttributeModifier, AttributeModifierOperation};
+use crate::entity::damage::DamageSource;
+use crate::entity::{
+    Entity, EntityBase, EntityBaseLoad, EntityPose, EntitySpawnReason, EntitySyncedData,
+    LivingEntity, LivingEntityBase, Mob, MobBase, MobEffectSyncChange, Monster, PathfinderMob,
+    SpawnGroupData,
+};
+use crate::inventory::equipment::EquipmentSlot;
+use crate::physics::MoveResult;
+use crate::player::Player;
+use crate::world::World;
+
+const ZOMBIE_BABY_DIMENSIONS: EntityDimensions = EntityDimensions::new(0.3, 0.975, 0.93);
+const SPEED_MODIFIER_BABY_ID: Identifier = Identifier::vanilla_static("baby");
+const SPEED_MODIFIER_BABY_AMOUNT: f64 = 0.5;
+const MONSTER_EXPERIENCE_REWARD: i32 = 5;
+const BABY_EXPERIENCE_MULTIPLIER: f32 = 2.5;
+const BABY_SPAWN_CHANCE: f32 = 0.05;
+const WEAPON_SPAWN_CHANCE: f32 = 0.01;
+const HARD_WEAPON_SPAWN_CHANCE: f32 = 0.05;
+const TURTLE_TARGET_INTERVAL: i32 = 10;
+
+/// Vanilla zombie entity.
+#[entity_behavior(class = "Zombie")]
+pub struct ZombieEntity {
+    base: EntityBase,
+    entity_type: EntityTypeRef,
+    living_base: LivingEntityBase,
+    mob_base: MobBase,
+    entity_data: SyncMutex<ZombieEntityData>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `ZombieEntity`.
+unsafe impl DowncastType for ZombieEntity {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:entity/zombie");
+}
+
+impl ZombieEntity {
+    /// Creates a new zombie entity.
+    #[must_use]
+    pub fn new(entity_type: EntityTypeRef, id: i32, position: DVec3, world: Weak<World>) -> Self {
+        Self::new_with_base(
+            EntityBase::new(id, position, entity_type.dimensions, world),
+            entity_type,
+        )
+    }
+
+    /// Creates a zombie entity from saved base data.
+    #[must_use]
+    pub fn from_saved(entity_type: EntityTypeRef, load: EntityBaseLoad) -> Self {
+        Self::new_with_base(
+            EntityBase::from_load(load, entity_type.dimensions),
+            entity_type,
+        )
+    }
+
+    fn new_with_base(base: EntityBase, entity_type: EntityTypeRef) -> Self {
+        let living_base = LivingEntityBase::new(entity_type);
+        let mob_base = MobBase::new();
+        mob_base.set_xp_reward(MONSTER_EXPERIENCE_REWARD);
+        let mut entity_data = ZombieEntityData::new();
+        living_base.initialize_synced_data(&mut entity_data);
+        {
+            let mut goal_selector = mob_base.goal_selector().lock();
+            goal_selector.add_goal(2, ZombieAttackGoal::new(1.0, false));
+            // TODO: Add MoveThroughVillageGoal and RemoveBlockGoal for turtle eggs.
+            goal_selector.add_goal(7, WaterAvoidingRandomStrollGoal::new(1.0));
+            goal_selector.add_goal(8, LookAtPlayerGoal::new(8.0));
+            goal_selector.add_goal(8, RandomLookAroundGoal::new());
+        }
+        {
+            let mut target_selector = mob_base.target_selector().lock();
+            target_selector.add_goal(
+                1,
+                HurtByTargetGoal::new(Vec::new())
+                    .set_alert_others(vec![&vanilla_entities::ZOMBIFIED_PIGLIN]),
+            );
+            target_selector.add_goal(2, NearestAttackableTargetGoal::new_for_players(true));
+            target_selector.add_goal(
+                3,
+                NearestAttackableTargetGoal::new_for_entity_types(
+                    vec![
+                        &vanilla_entities::VILLAGER,
+                        &vanilla_entities::WANDERING_TRADER,
+                    ],
+                    false,
+                ),
+            );
+            target_selector.add_goal(
+                3,
+                NearestAttackableTargetGoal::new_for_entity_types(
+                    vec![&vanilla_entities::IRON_GOLEM],
+                    true,
+                ),
+            );
+            target_selector.add_goal(
+                5,
+                NearestAttackableTargetGoal::new_for_entity_types_with_selector(
+                    vec![&vanilla_entities::TURTLE],
+                    TURTLE_TARGET_INTERVAL,
+                    true,
+                    false,
+                    |target, _| target.is_baby() && !target.is_in_water(),
+                ),
+            );
+        }
+
+        Self {
+            base,
+            entity_type,
+            living_base,
+            mob_base,
+            entity_data: SyncMutex::new(entity_data),
+        }
+    }
+
+    /// Returns whether this zombie is a baby.
+    #[must_use]
+    pub fn is_baby(&self) -> bool {
+        *self.entity_data.lock().baby.get()
+    }
+
+    /// Mirrors vanilla `Zombie.setBaby`, including the baby speed modifier.
+    pub fn set_baby(&self, baby: bool) {
+        self.entity_data.lock().baby.set(baby);
+        {
+            let mut attributes = self.attributes().lock();
+            attributes.remove_modifier(vanilla_attributes::MOVEMENT_SPEED, &SPEED_MODIFIER_BABY_ID);
+            if baby {
+                attributes.add_modifier(
+                    vanilla_attributes::MOVEMENT_SPEED,
+                    AttributeModifier {
+                        id: SPEED_MODIFIER_BABY_ID,
+                        amount: SPEED_MODIFIER_BABY_AMOUNT,
+                        operation: AttributeModifierOperation::AddMultipliedBase,
+                    },
+                    false,
+                );
+            }
+        }
+        self.refresh_dimensions();
+    }
+
+    fn populate_default_equipment_slots(&self, difficulty: Difficulty) {
+        let chance = if difficulty == Difficulty::Hard {
+            HARD_WEAPON_SPAWN_CHANCE
+        } else {
+            WEAPON_SPAWN_CHANCE
+        };
+        if rand::random::<f32>() >= chance {
+            return;
+        }
+
+        let weapon = if rand::random_range(0..3) == 0 {
+            ItemStack::new(&vanilla_items::IRON_SWORD)
+        } else {
+            ItemStack::new(&vanilla_items::IRON_SHOVEL)
+        };
+        self.living_base
+            .equipment()
+            .lock()
+            .set(EquipmentSlot::MainHand, weapon);
+    }
+
+    fn update_dirty_mob_effect_entity_data(&self) {
+        if !self.living_base.take_effects_dirty() {
+            return;
+        }
+
+        let display = self.living_base.mob_effect_display_state();
+
+        {
+            let mut entity_data = self.entity_data.lock();
+            let living = entity_data.living_entity_mut();
+            living.effect_particles.set(display.particles);
+            living.effect_ambience.set(display.ambient);
+        }
+
+        self.entity_data.set_base_invisible_flag(display.invisible);
+        self.entity_data
+            .set_base_glowing_flag(self.has_glowing_tag() || display.glowing);
+    }
+}
+
+#[entity_impl(class(pathfinder_mob))]
+impl Entity for ZombieEntity {
+    fn base(&self) -> &EntityBase {
+        &self.base
+    }
+
+    fn entity_type(&self) -> EntityTypeRef {
+        self.entity_type
+    }
+
+    fn dimensions_for_pose(&self, _pose: EntityPose) -> EntityDimensions {
+        let scale = LivingEntity::get_scale(self);
+        if self.is_baby() {
+            ZOMBIE_BABY_DIMENSIONS.scale(scale)
+        } else if self.entity_type.fixed {
+            self.entity_type.dimensions
+        } else {
+            self.entity_type.dimensions.scale(scale)
+        }
+    }
+
+    fn tick(&self) {
+        self.default_tick();
+        self.living_base.decrement_invulnerable_time();
+        self.tick_mob_effects();
+
+        if self.is_dead_or_dying() {
+            LivingEntity::tick_death(self);
+            self.tick_living_state();
+            return;
+        }
+
+        if !self.is_removed() {
+            self.ai_step();
+        }
+
+        self.tick_living_state();
+    }
+
+    fn check_despawn(&self) {
+        Mob::check_mob_despawn(self);
+    }
+
+    fn is_alive(&self) -> bool {
+        !self.is_removed() && self.get_health() > 0.0
+    }
+
+    fn is_pickable(&self) -> bool {
+        !self.is_removed()
+    }
+
+    fn is_pushable(&self) -> bool {
+        Entity::is_alive(self) && !self.is_spectator() && !self.on_climbable()
+    }
+
+    fn is_effective_ai(&self) -> bool {
+        self.is_server_driven_movement() && !self.is_no_ai()
+    }
+
+    fn get_default_gravity(&self) -> f64 {
+        LivingEntity::get_attribute_gravity(self)
+    }
+
+    fn can_freeze(&self) -> bool {
+        self.default_living_can_freeze()
+    }
+
+    fn can_walk_on_powder_snow(&self) -> bool {
+        self.default_living_can_walk_on_powder_snow()
+    }
+
+    fn synced_data(&self) -> Option<&dyn EntitySyncedData> {
+        Some(&self.entity_data)
+    }
+
+    fn update_data_before_sync(&self) {
+        self.update_dirty_mob_effect_entity_data();
+    }
+
+    fn pack_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().syncable_snapshots()
+    }
+
+    fn drain_dirty_syncable_attributes(&self) -> Vec<AttributeSnapshot> {
+        self.attributes().lock().drain_dirty_sync()
+    }
+
+    fn drain_dirty_mob_effects(&self) -> Vec<MobEffectSyncChange> {
+        self.living_base.drain_dirty_mob_effects()
+    }
+
+    fn pack_all_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.pack_living_equipment()
+    }
+
+    fn drain_dirty_equipment(&self) -> Vec<EquipmentSlotItem> {
+        self.drain_dirty_living_equipment()
+    }
+
+    fn max_up_step(&self) -> f32 {
+        self.attributes()
+            .lock()
+            .get_value(vanilla_attributes::STEP_HEIGHT)
+            .unwrap_or(0.6) as f32
+    }
+
+    fn sound_source(&self) -> SoundSource {
+        SoundSource::Hostile
+    }
+
+    fn play_step_sound(&self, _pos: BlockPos, _block_state: BlockStateId) {
+        self.play_sound(&sound_events::ENTITY_ZOMBIE_STEP, 0.15, 1.0);
+    }
+
+    fn hurt(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        LivingEntity::hurt_server(self, world, source, amount)
+    }
+
+    fn interact(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+        location: DVec3,
+    ) -> InteractionResult {
+        Mob::interact_mob(self, player, hand, location)
+    }
+
+    fn save_additional(&self, nbt: &mut NbtCompound) {
+        self.save_mob(nbt);
+        nbt.insert("IsBaby", i8::from(self.is_baby()));
+    }
+
+    fn load_additional(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        self.load_mob(nbt);
+        self.set_baby(nbt.byte("IsBaby").is_some_and(|baby| baby != 0));
+    }
+}
+
+impl LivingEntity for ZombieEntity {
+    fn living_base(&self) -> &LivingEntityBase {
+        &self.living_base
+    }
+
+    fn get_health(&self) -> f32 {
+        *self.entity_data.lock().living_entity().health.get()
+    }
+
+    fn set_health(&self, health: f32) {
+        let max_health = self.get_max_health();
+        let clamped = health.clamp(0.0, max_health);
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .health
+            .set(clamped);
+    }
+
+    fn is_baby(&self) -> bool {
+        ZombieEntity::is_baby(self)
+    }
+
+    fn hurt_sound(&self, _source: &DamageSource) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_ZOMBIE_HURT)
+    }
+
+    fn death_sound(&self) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_ZOMBIE_DEATH)
+    }
+
+    fn base_experience_reward(&self) -> i32 {
+        if self.is_baby() {
+            self.set_xp_reward((self.xp_reward() as f32 * BABY_EXPERIENCE_MULTIPLIER) as i32);
+        }
+
+        self.base_experience_reward_mob()
+    }
+
+    fn server_ai_step(&self) {
+        Mob::mob_server_ai_step(self);
+    }
+
+    fn ai_step(&self) -> Option<MoveResult> {
+        self.ai_step_monster()
+    }
+}
+
+impl Mob for ZombieEntity {
+    fn mob_base(&self) -> &MobBase {
+        &self.mob_base
+    }
+
+    fn tick_goal_selectors(&self) {
+        PathfinderMob::tick_pathfinder_goal_selectors(self);
+    }
+
+    fn tick_path_navigation(&self) {
+        PathfinderMob::tick_pathfinder_path_navigation(self);
+    }
+
+    fn ambient_sound(&self) -> Option<SoundEventRef> {
+        Some(&sound_events::ENTITY_ZOMBIE_AMBIENT)
+    }
+
+    fn finalize_spawn(
+        &self,
+        world: &Arc<World>,
+        spawn_reason: EntitySpawnReason,
+        group_data: Option<SpawnGroupData>,
+    ) -> Option<SpawnGroupData> {
+        let group_data = self.finalize_spawn_mob_base(world, spawn_reason, group_data);
+        if rand::random::<f32>() < BABY_SPAWN_CHANCE {
+            self.set_baby(true);
+        }
+        // TODO: Roll chicken jockeys, loot pickup and armor once difficulty instances exist.
+        self.populate_default_equipment_slots(world.difficulty());
+        group_data
+    }
+
+    fn mob_flags(&self) -> i8 {
+        *self.entity_data.lock().mob().mob_flags.get()
+    }
+
+    fn set_mob_flags(&self, flags: i8) {
+        self.entity_data.lock().mob_mut().mob_flags.set(flags);
+    }
+}
+
+impl PathfinderMob for ZombieEntity {
+    fn get_walk_target_value(&self, pos: BlockPos) -> f32 {
+        self.monster_walk_target_value(pos)
+    }
+}
+
+impl Monster for ZombieEntity {}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+
+    use super::*;
+
+    fn zombie() -> ZombieEntity {
+        init_test_registry();
+        ZombieEntity::new(&vanilla_entities::ZOMBIE, 1, DVec3::ZERO, Weak::new())
+    }
+
+    #[test]
+    fn zombie_initializes_vanilla_monster_attributes() {
+        let zombie = zombie();
+
+        assert_eq!(zombie.get_health().to_bits(), 20.0_f32.to_bits());
+        let attributes = zombie.attributes().lock();
+        assert_eq!(
+            attributes
+                .required_value(vanilla_attributes::FOLLOW_RANGE)
+                .to_bits(),
+            35.0_f64.to_bits()
+        );
+        assert_eq!(
+            attributes
+                .required_value(vanilla_attributes::ATTACK_DAMAGE)
+                .to_bits(),
+            3.0_f64.to_bits()
+        );
+    }
+
+    #[test]
+    fn zombie_registers_vanilla_goals_and_targets() {
+        let zombie = zombie();
+
+        assert_eq!(
+            zombie
+                .mob_base()
+                .goal_selector()
+                .lock()
+                .available_goal_priorities(),
+            vec![2, 7, 8, 8]
+        );
+        assert_eq!(
+            zombie
+                .mob_base()
+                .target_selector()
+                .lock()
+                .available_goal_priorities(),
+            vec![1, 2, 3, 3, 5]
+        );
+    }
+
+    #[test]
+    fn zombie_baby_uses_vanilla_dimensions_and_speed_bonus() {
+        let zombie = zombie();
+        let adult_speed = zombie
+            .attributes()
+            .lock()
+            .required_value(vanilla_attributes::MOVEMENT_SPEED);
+
+        zombie.set_baby(true);
+
+        let dimensions = zombie.dimensions_for_pose(EntityPose::Standing);
+        assert_eq!(dimensions.width.to_bits(), 0.3_f32.to_bits());
+        assert_eq!(dimensions.height.to_bits(), 0.975_f32.to_bits());
+        let baby_speed = zombie
+            .attributes()
+            .lock()
+            .required_value(vanilla_attributes::MOVEMENT_SPEED);
+        assert!((baby_speed - adult_speed * 1.5).abs() < 1.0e-9);
+
+        zombie.set_baby(false);
+        assert_eq!(
+            zombie
+                .attributes()
+                .lock()
+                .required_value(vanilla_attributes::MOVEMENT_SPEED)
+                .to_bits(),
+            adult_speed.to_bits()
+        );
+    }
+
+    #[test]
+    fn zombie_is_hostile_monster() {
+        let zombie = zombie();
+        let entity = &zombie as &dyn Entity;
+
+        assert_eq!(entity.sound_source(), SoundSource::Hostile);
+        assert!(!zombie.entity_type().allowed_in_peaceful);
+        assert!(entity.is_pathfinder_mob());
+    }
+}
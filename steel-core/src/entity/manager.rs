@@ -10,7 +10,7 @@ use std::{collections::BTreeMap, error::Error, fmt, slice, sync::Arc};
 use glam::DVec3;
 use rustc_hash::{FxHashMap, FxHashSet};
 use steel_registry::vanilla_entities;
-use steel_utils::locks::SyncRwLock;
+use steel_utils::locks::{SyncMutex, SyncRwLock};
 use steel_utils::{ChunkPos, PackedSectionPos, SectionPos, WorldAabb};
 use uuid::Uuid;
 
@@ -395,6 +395,8 @@ impl EntityTickList {
 /// Central world entity manager.
 pub struct WorldEntityManager {
     state: SyncRwLock<ManagerState>,
+    /// Entity whose tick is running, for watchdog stall dumps.
+    ticking_entity: SyncMutex<Option<i32>>,
 }
 
 impl fmt::Debug for WorldEntityManager {
@@ -414,9 +416,16 @@ impl WorldEntityManager {
     pub fn new() -> Self {
         Self {
             state: SyncRwLock::new(ManagerState::default()),
+            ticking_entity: SyncMutex::new(None),
         }
     }
 
+    /// Returns the id of the entity currently being ticked, if any.
+    #[must_use]
+    pub fn ticking_entity(&self) -> Option<i32> {
+        *self.ticking_entity.lock()
+    }
+
     /// Marks a chunk as loaded and reactivates retained unloading entities.
     pub fn on_chunk_loaded(&self, pos: ChunkPos) -> ChunkEntityLoadResult {
         let mut state = self.state.write();
@@ -1269,8 +1278,10 @@ impl WorldEntityManager {
                 continue;
             }
 
+            *self.ticking_entity.lock() = Some(entity.id());
             self.tick_non_passenger(&entity, &mut ticked_entities, &mut dirty_chunks);
         }
+        *self.ticking_entity.lock() = None;
         dirty_chunks
     }
 
@@ -0,0 +1,69 @@
+//! Vanilla `Merchant`: entities players can trade with.
+
+use parking_lot::MappedMutexGuard;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::trading::MerchantOffers;
+use text_components::TextComponent;
+use uuid::Uuid;
+
+use crate::entity::{Entity as _, SharedEntity};
+use crate::inventory::merchant_menu::MerchantMenuProvider;
+use crate::player::Player;
+
+/// Vanilla `Merchant`.
+pub trait Merchant: Send + Sync {
+    /// Returns the UUID of the player currently trading, if any.
+    fn trading_player(&self) -> Option<Uuid>;
+
+    /// Sets or clears the player currently trading.
+    fn set_trading_player(&self, player: Option<Uuid>);
+
+    /// Returns the merchant's offers, generating them on first access.
+    fn offers(&self) -> MappedMutexGuard<'_, MerchantOffers>;
+
+    /// Called after a player completes the trade at `offer` in [`Self::offers`].
+    fn notify_trade(&self, offer: usize);
+
+    /// Called when the trade result preview changes.
+    fn notify_trade_updated(&self, item: &ItemStack);
+
+    /// Returns the merchant's trading experience.
+    fn villager_xp(&self) -> i32;
+
+    /// Whether the client shows the level progress bar.
+    fn show_progress_bar(&self) -> bool;
+
+    /// Whether out-of-stock offers are shown as restockable.
+    fn can_restock(&self) -> bool;
+
+    /// Mirrors vanilla `Merchant.stillValid`.
+    fn still_valid(&self, player: &Player) -> bool;
+}
+
+/// Mirrors vanilla `Merchant.openTradingScreen`.
+pub(crate) fn open_trading_screen(
+    merchant: SharedEntity,
+    player: &Player,
+    title: TextComponent,
+    level: i32,
+) {
+    player.open_menu(&MerchantMenuProvider::new(
+        player.inventory.clone(),
+        merchant.clone(),
+        title,
+    ));
+    let Some(merchant) = merchant.as_merchant() else {
+        return;
+    };
+
+    let offers = merchant.offers().to_vec();
+    if !offers.is_empty() {
+        player.send_merchant_offers(
+            offers,
+            level,
+            merchant.villager_xp(),
+            merchant.show_progress_bar(),
+            merchant.can_restock(),
+        );
+    }
+}
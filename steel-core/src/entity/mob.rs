@@ -10,6 +10,7 @@ use steel_math::fast_floor;
 use steel_protocol::packets::game::SoundSource;
 use steel_registry::blocks::block_state_ext::BlockStateExt as _;
 use steel_registry::enchantment_effect::EnchantmentEffectComponent;
+use steel_registry::entity_type::EntityTypeRef;
 use steel_registry::item_stack::ItemStack;
 use steel_registry::loot_table::LootTableRef;
 use steel_registry::sound_event::SoundEventRef;
@@ -20,6 +21,7 @@ use steel_registry::{
     vanilla_entities, vanilla_game_events, vanilla_items,
 };
 use steel_utils::UuidExt;
+use steel_utils::entity_events::EntityStatus;
 use steel_utils::locks::SyncMutex;
 use steel_utils::types::{Difficulty, InteractionHand};
 use steel_utils::{BlockPos, ChunkPos, Identifier, WorldAabb, axis::Axis};
@@ -41,8 +43,8 @@ use crate::entity::attribute::{AttributeModifier, AttributeModifierOperation};
 use crate::entity::damage::DamageSource;
 use crate::entity::entities::LeashFenceKnotEntity;
 use crate::entity::{
-    Entity, EntitySpawnReason, LivingEntity, LivingTravelInput, RemovalReason, SharedEntity,
-    SpawnGroupData, WeakEntity,
+    ENTITIES, Entity, EntitySpawnReason, LivingEntity, LivingTravelInput, RemovalReason,
+    SharedEntity, SpawnGroupData, WeakEntity, is_valid_spawn, next_entity_id,
 };
 use crate::inventory::equipment::EquipmentSlot;
 use crate::physics::WorldCollisionProvider;
@@ -56,6 +58,7 @@ const MOB_FLAG_AGGRESSIVE: i8 = 4;
 const MOVE_CONTROL_MIN_SPEED_SQR: f64 = 2.500_000_3e-7;
 const MOVE_CONTROL_MAX_TURN: f32 = 90.0;
 const DEFAULT_EQUIPMENT_DROP_CHANCE: f32 = 0.085;
+const SUN_BURN_IGNITE_TICKS: i32 = 160;
 const PRESERVE_ITEM_DROP_CHANCE_THRESHOLD: f32 = 1.0;
 const PRESERVE_ITEM_DROP_CHANCE: f32 = 2.0;
 const LEASH_SNAP_DISTANCE: f64 = 12.0;
@@ -524,6 +527,26 @@ impl Default for MobBase {
     }
 }
 
+/// Vanilla `ConversionParams` for a `ConversionType.SINGLE` mob conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ConversionParams {
+    /// Whether the converted mob takes over the original's equipment.
+    pub keep_equipment: bool,
+    /// Whether the converted mob inherits the original's `CanPickUpLoot`.
+    pub preserve_can_pick_up_loot: bool,
+}
+
+impl ConversionParams {
+    /// Vanilla `ConversionParams.single`.
+    #[must_use]
+    pub const fn single(keep_equipment: bool, preserve_can_pick_up_loot: bool) -> Self {
+        Self {
+            keep_equipment,
+            preserve_can_pick_up_loot,
+        }
+    }
+}
+
 pub trait Mob: LivingEntity {
     fn mob_base(&self) -> &MobBase;
 
@@ -612,6 +635,60 @@ pub trait Mob: LivingEntity {
         self.make_sound(self.ambient_sound());
     }
 
+    /// Handles vanilla `Mob.ate`, called after `EatBlockGoal` consumes a block.
+    fn ate(&self) {}
+
+    /// Returns vanilla `Mob.isSunBurnTick`.
+    fn is_sun_burn_tick(&self) -> bool {
+        let Some(world) = self.level() else {
+            return false;
+        };
+        if !world.is_bright_outside() {
+            return false;
+        }
+
+        let brightness = self.light_level_dependent_magic_value();
+        let position = self.position();
+        let eye_pos = BlockPos::containing(position.x, self.get_eye_y(), position.z);
+        let is_in_water_rain_or_snow =
+            self.is_in_water_or_rain() || self.is_in_powder_snow() || self.was_in_powder_snow();
+        brightness > 0.5
+            && rand::random::<f32>() * 30.0 < (brightness - 0.4) * 2.0
+            && !is_in_water_rain_or_snow
+            && world.can_see_sky(eye_pos)
+    }
+
+    /// Runs vanilla `Mob.burnUndead`; helmets take the sunlight instead of the mob.
+    fn burn_undead(&self) {
+        if !Entity::is_alive(self) || !self.is_sun_burn_tick() {
+            return;
+        }
+
+        let helmet_broke = {
+            let mut equipment = self.living_base().equipment().lock();
+            let helmet = equipment.get_ref(EquipmentSlot::Head);
+            if helmet.is_empty() {
+                None
+            } else if !helmet.is_damageable_item() {
+                Some(false)
+            } else {
+                let helmet = equipment.get_mut(EquipmentSlot::Head);
+                helmet.set_damage_value(helmet.get_damage_value() + rand::random_range(0..2));
+                let broke = helmet.get_damage_value() >= helmet.get_max_damage();
+                if broke {
+                    equipment.set(EquipmentSlot::Head, ItemStack::empty());
+                }
+                Some(broke)
+            }
+        };
+
+        match helmet_broke {
+            None => self.ignite_for_ticks(SUN_BURN_IGNITE_TICKS),
+            Some(true) => self.on_equipped_item_broken(EquipmentSlot::Head),
+            Some(false) => {}
+        }
+    }
+
     fn reset_ambient_sound_time(&self) {
         self.mob_base()
             .set_ambient_sound_time(-self.ambient_sound_interval());
@@ -685,7 +762,18 @@ pub trait Mob: LivingEntity {
             return InteractionResult::Pass;
         }
 
-        // TODO: Handle name tags and spawn eggs once item-on-entity behavior exists.
+        let interaction_result = self.check_and_handle_important_interactions(player, hand);
+        if interaction_result.consumes_action() {
+            if let Some(world) = self.level() {
+                world.game_event(
+                    &vanilla_game_events::ENTITY_INTERACT,
+                    self.block_position(),
+                    &GameEventContext::new(Some(player), None),
+                );
+            }
+            return interaction_result;
+        }
+
         let interaction_result = self.interact_entity(player, hand, location);
         if interaction_result != InteractionResult::Pass {
             return interaction_result;
@@ -705,6 +793,30 @@ pub trait Mob: LivingEntity {
         interaction_result
     }
 
+    /// Handles vanilla `Mob.checkAndHandleImportantInteractions`.
+    fn check_and_handle_important_interactions(
+        &self,
+        player: &Player,
+        hand: InteractionHand,
+    ) -> InteractionResult {
+        let Some(target) = self.as_entity_event_source().as_living_entity() else {
+            return InteractionResult::Pass;
+        };
+        let mut inventory = player.inventory.lock();
+        if !inventory
+            .get_item_in_hand(hand)
+            .is(&vanilla_items::NAME_TAG)
+        {
+            // TODO: Handle spawn eggs once spawn egg items exist.
+            return InteractionResult::Pass;
+        }
+        inventory.mutate_item_in_hand(hand, |stack| {
+            ITEM_BEHAVIORS
+                .get_behavior(stack.item())
+                .interact_living_entity(stack, player, target, hand)
+        })
+    }
+
     /// Handles vanilla `Mob.mobInteract`.
     fn mob_interact(&self, _player: &Player, _hand: InteractionHand) -> InteractionResult {
         InteractionResult::Pass
@@ -737,6 +849,121 @@ pub trait Mob: LivingEntity {
         *self.mob_base().persistence_required().lock() = true;
     }
 
+    /// Replaces this mob with a new mob of `entity_type` that inherits its state.
+    ///
+    /// Vanilla `Mob.convertTo` with `ConversionType.SINGLE`. Returns `None` and leaves this
+    /// mob in place when it is already removed or `entity_type` has no implementation yet.
+    fn convert_to(
+        &self,
+        world: &Arc<World>,
+        entity_type: EntityTypeRef,
+        params: ConversionParams,
+        finalize_conversion: &mut dyn FnMut(&dyn Mob),
+    ) -> Option<SharedEntity> {
+        if self.is_removed() {
+            return None;
+        }
+
+        let converted = ENTITIES.create(
+            entity_type,
+            next_entity_id(),
+            self.position(),
+            Arc::downgrade(world),
+        )?;
+        {
+            let Some(mob) = converted.as_mob() else {
+                log::error!(
+                    "converting entity {} created non-mob type {}",
+                    self.id(),
+                    entity_type.key
+                );
+                return None;
+            };
+            mob.set_rotation(self.rotation());
+            mob.set_y_head_rot(self.y_head_rot());
+            mob.set_velocity(self.velocity());
+            mob.set_old_position_to_current();
+            mob.set_custom_name(self.custom_name());
+            mob.set_custom_name_visible(self.is_custom_name_visible());
+            mob.set_silent(self.is_silent());
+            mob.set_invulnerable(self.is_invulnerable());
+            mob.set_no_ai(self.is_no_ai());
+            mob.set_left_handed(self.is_left_handed());
+            if self.is_persistence_required() {
+                mob.set_persistence_required();
+            }
+            if params.preserve_can_pick_up_loot {
+                mob.set_can_pick_up_loot(self.can_pick_up_loot());
+            }
+            if params.keep_equipment {
+                for slot in EquipmentSlot::ALL {
+                    let item = self.living_base().equipment().lock().get_ref(slot).clone();
+                    if !item.is_empty() {
+                        mob.living_base().equipment().lock().set(slot, item);
+                    }
+                }
+            }
+            // TODO: Carry over passengers, vehicle, leash, mob effects and baby state with the
+            // rest of vanilla `ConversionType.SINGLE`.
+            finalize_conversion(mob);
+        }
+
+        if let Err(error) = world.try_add_entity(Arc::clone(&converted)) {
+            log::error!(
+                "failed to add conversion of entity {} to world: {error}",
+                self.id()
+            );
+            return None;
+        }
+        self.set_removed(RemovalReason::Discarded);
+        Some(converted)
+    }
+
+    /// Returns vanilla `Mob.checkSpawnRules`, including the `PathfinderMob` override.
+    fn check_spawn_rules(&self, _world: &World, _spawn_reason: EntitySpawnReason) -> bool {
+        Entity::as_pathfinder_mob(self)
+            .is_none_or(|mob| mob.get_walk_target_value(self.block_position()) >= 0.0)
+    }
+
+    /// Returns vanilla `Mob.checkSpawnObstruction`.
+    fn check_spawn_obstruction(&self, world: &World) -> bool {
+        let bounding_box = self.bounding_box();
+        !world.contains_any_liquid(&bounding_box)
+            && !world.has_entity_in_aabb_matching(&bounding_box, |entity| {
+                entity.id() != self.id() && !entity.is_removed() && entity.blocks_building()
+            })
+    }
+
+    /// Returns vanilla `Mob.getMaxSpawnClusterSize`.
+    fn max_spawn_cluster_size(&self) -> i32 {
+        4
+    }
+
+    /// Returns vanilla `Mob.isMaxGroupSizeReached`.
+    fn is_max_group_size_reached(&self, _group_size: i32) -> bool {
+        false
+    }
+
+    /// Vanilla `Mob.spawnAnim`: shows the spawn poof particles on clients.
+    fn spawn_anim(&self) {
+        self.broadcast_entity_event(EntityStatus::SilverfishMergeAnim);
+    }
+
+    /// Returns vanilla `Mob.checkMobSpawnRules`.
+    fn check_mob_spawn_rules(
+        entity_type: EntityTypeRef,
+        level: &dyn LevelReader,
+        spawn_reason: EntitySpawnReason,
+        pos: BlockPos,
+    ) -> bool
+    where
+        Self: Sized,
+    {
+        let below = pos.below();
+        spawn_reason.is_spawner()
+            || is_valid_spawn(level.get_block_state(below), below, entity_type)
+    }
+
     /// Returns vanilla `Mob.canPickUpLoot`.
     fn can_pick_up_loot(&self) -> bool {
         *self.mob_base().can_pick_up_loot().lock()
@@ -1977,6 +2204,10 @@ pub trait PathfinderMob: Mob {
             return pathfinder.create_path_to(target, reach_range);
         }
 
+        self.mob_base()
+            .navigation()
+            .lock()
+            .set_path_to_position(target);
         let world = self.level()?;
         if !world.has_full_chunk(ChunkPos::from_block_pos(target)) {
             return None;
@@ -2014,6 +2245,10 @@ pub trait PathfinderMob: Mob {
         }
 
         let target_pos = BlockPos::containing(target.x, target.y, target.z);
+        self.mob_base()
+            .navigation()
+            .lock()
+            .set_path_to_position(target_pos);
         let Some(world) = self.level() else {
             self.mob_base().navigation().lock().stop();
             return false;
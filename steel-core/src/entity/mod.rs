@@ -73,7 +73,7 @@ use crate::world::game_event_context::GameEventContext;
 use crate::world::{ClipBlockShape, ClipFluid, LevelReader, World};
 use crate::{enchantment_helper, entity::damage::DamageSource, player::Player};
 
-use entities::ExperienceOrbEntity;
+use entities::{ExperienceOrbEntity, LightningBoltEntity};
 
 fn nbt_bool(value: bool) -> NbtTag {
     NbtTag::Byte(i8::from(value))
@@ -141,6 +141,8 @@ const fn should_apply_entity_cramming_damage(
     let threshold = (max_cramming - 1) as usize;
     pushable_count > threshold && non_passenger_count > threshold
 }
+const LIGHTNING_IGNITE_TICKS: i32 = 160;
+const LIGHTNING_DAMAGE: f32 = 5.0;
 const LEASH_SCAN_SIZE: f64 = 32.0;
 const LEASH_SCAN_HALF_SIZE: f64 = LEASH_SCAN_SIZE / 2.0;
 const SPEED_MODIFIER_POWDER_SNOW_ID: Identifier = Identifier::vanilla_static("powder_snow");
@@ -193,6 +195,23 @@ fn leash_scan_area(center: DVec3) -> WorldAabb {
     )
 }
 
+/// Finds leashable mobs around `center` whose leash holder is `holder`.
+///
+/// Vanilla equivalent: `Leashable.leashableInArea` filtered by holder.
+pub(crate) fn leashables_leashed_to_holder_near(
+    world: &World,
+    center: DVec3,
+    holder: &dyn Entity,
+) -> Vec<SharedEntity> {
+    let holder_id = holder.id();
+    world.get_entities_in_aabb_matching(&leash_scan_area(center), |entity| {
+        entity.as_mob().is_some_and(|mob| {
+            mob.leash_holder()
+                .is_some_and(|holder| holder.id() == holder_id)
+        })
+    })
+}
+
 fn transfer_leashables_to_holder(leashables: Vec<SharedEntity>, new_holder: &SharedEntity) -> bool {
     let mut transferred = false;
     for leashable in leashables {
@@ -302,7 +321,7 @@ fn finish_inside_block_effects(
         return;
     }
 
-    if is_in_rain(entity) {
+    if entity.is_in_rain() {
         entity.clear_fire();
     }
 
@@ -321,20 +340,6 @@ fn finish_inside_block_effects(
     }
 }
 
-fn is_in_rain(entity: &dyn Entity) -> bool {
-    let Some(world) = entity.level() else {
-        return false;
-    };
-
-    let pos = entity.block_position();
-    world.is_raining_at(pos)
-        || world.is_raining_at(BlockPos::new(
-            pos.x(),
-            entity.bounding_box().max_y().floor() as i32,
-            pos.z(),
-        ))
-}
-
 fn closest_open_space_direction(
     block_pos: BlockPos,
     fractional_position: DVec3,
@@ -478,6 +483,18 @@ pub fn next_entity_id() -> i32 {
     id
 }
 
+/// Allocates `count` consecutive entity IDs and returns the first.
+///
+/// Multipart entities use this so the client can derive part IDs from the
+/// parent's, like vanilla `EnderDragon` does.
+#[must_use]
+pub fn next_entity_ids(count: i32) -> i32 {
+    let mut counter = ENTITY_COUNTER.lock();
+    let id = *counter;
+    *counter = counter.wrapping_add(count);
+    id
+}
+
 fn apply_block_effect_segment(
     entity: &dyn Entity,
     world: &Arc<World>,
@@ -742,16 +759,20 @@ mod inside_block_effects;
 mod item_based_steering;
 mod living_base;
 mod manager;
+mod merchant;
 mod mob;
+mod monster;
 mod movement_sync;
 pub mod projectile;
 mod registry;
 mod shared_flags;
 mod spawn;
+mod spawn_placements;
 mod storage;
 mod synced_data;
 mod ticking;
 mod tracker;
+mod vehicle;
 
 use crate::portal::{
     PortalKind, PortalProcessResult, PortalProcessor, PortalTicketTarget, TeleportPostAction,
@@ -784,7 +805,9 @@ pub use manager::{
     AddEntityError, ChunkEntityLoadResult, EntityLifecycleChanges, EntityMoveError,
     EntityMoveUpdate, EntityOwnership, EntityVisibility, WorldEntityManager,
 };
-pub(crate) use mob::{Mob, MobBase, PathfinderMob};
+pub(crate) use merchant::{Merchant, open_trading_screen};
+pub(crate) use mob::{ConversionParams, Mob, MobBase, PathfinderMob};
+pub(crate) use monster::{Monster, RangedAttackMob};
 pub use movement_sync::{
     EntityMovementSyncPacket, EntityMovementSyncPackets, EntityMovementSyncState,
     EntityMovementSyncUpdate, EntityPositionRotSyncPacket, EntityPositionSyncDecision,
@@ -793,20 +816,26 @@ pub use movement_sync::{
     PackedEntityRotation, ServerEntityMovementSyncState, ServerEntityMovementSyncUpdate,
 };
 pub use projectile::{
-    EntityHitResult, Projectile, ProjectileBase, ProjectileDeflection, ProjectileEventSource,
-    ProjectileHit, ThrowableItemProjectile, ThrowableProjectile, compute_margin,
+    AbstractArrow, AbstractArrowBase, ArrowPickup, EntityHitResult, Projectile, ProjectileBase,
+    ProjectileDeflection, ProjectileEventSource, ProjectileHit, ThrowableItemProjectile,
+    ThrowableProjectile, compute_margin,
 };
 #[cfg(test)]
 pub(crate) use registry::init_test_entities;
 pub use registry::{ENTITIES, EntityLoadRequest, EntityRegistry, init_entities};
 pub(crate) use shared_flags::EntitySharedFlags;
 pub(crate) use spawn::{AgeableMobGroupData, EntitySpawnReason, SpawnGroupData};
+pub(crate) use spawn_placements::{
+    SpawnPlacementType, is_block_dangerous, is_valid_empty_spawn_block, is_valid_spawn,
+    spawn_placement,
+};
 pub(crate) use storage::EntityStorage;
 pub use synced_data::EntitySyncedData;
 pub(crate) use ticking::{
     snapshot_old_pos_and_rot_for_tick, tick_vehicle_passengers_with_ticked_if,
 };
-pub use tracker::{EntityChangeSenders, EntityTracker};
+pub use tracker::{DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE, EntityChangeSenders, EntityTracker};
+pub use vehicle::VehicleEntity;
 
 #[cfg(test)]
 macro_rules! impl_test_downcast_type {
@@ -1407,6 +1436,8 @@ pub struct EntityCapabilities<'a> {
     pathfinder_mob: Option<&'a dyn PathfinderMob>,
     animal: Option<&'a dyn Animal>,
     item_steerable: Option<&'a dyn ItemSteerable>,
+    ranged_attack_mob: Option<&'a dyn RangedAttackMob>,
+    merchant: Option<&'a dyn Merchant>,
     item_merge_entity: Option<&'a dyn ItemMergeEntity>,
     experience_orb_merge_entity: Option<&'a dyn ExperienceOrbMergeEntity>,
     leash_fence_knot: Option<&'a dyn LeashFenceKnot>,
@@ -1424,6 +1455,8 @@ impl<'a> EntityCapabilities<'a> {
             pathfinder_mob: None,
             animal: None,
             item_steerable: None,
+            ranged_attack_mob: None,
+            merchant: None,
             item_merge_entity: None,
             experience_orb_merge_entity: None,
             leash_fence_knot: None,
@@ -1479,6 +1512,23 @@ impl<'a> EntityCapabilities<'a> {
         self
     }
 
+    /// Exposes ranged-attack behavior for this entity.
+    #[must_use]
+    pub const fn with_ranged_attack_mob(
+        mut self,
+        ranged_attack_mob: &'a dyn RangedAttackMob,
+    ) -> Self {
+        self.ranged_attack_mob = Some(ranged_attack_mob);
+        self
+    }
+
+    /// Exposes merchant trading behavior for this entity.
+    #[must_use]
+    pub const fn with_merchant(mut self, merchant: &'a dyn Merchant) -> Self {
+        self.merchant = Some(merchant);
+        self
+    }
+
     /// Exposes dropped-item merge behavior for this entity.
     #[must_use]
     pub const fn with_item_merge_entity(
@@ -1572,12 +1622,15 @@ pub trait Entity: EntityEventSource + ErasedType + Send + Sync {
         self.uuid().to_string()
     }
 
+    /// Mirrors vanilla `Entity.getTypeName`.
+    fn type_name(&self) -> TextComponent {
+        entity_type_name(self.entity_type())
+    }
+
     /// Returns this entity's vanilla name component before team decoration.
     fn name(&self) -> TextComponent {
-        self.custom_name().map_or_else(
-            || entity_type_name(self.entity_type()),
-            remove_entity_name_actions,
-        )
+        self.custom_name()
+            .map_or_else(|| self.type_name(), remove_entity_name_actions)
     }
 
     /// Returns this entity's vanilla display name.
@@ -1876,6 +1929,12 @@ pub trait Entity: EntityEventSource + ErasedType + Send + Sync {
             .is_some_and(EntitySyncedData::is_shift_key_down)
     }
 
+    /// Returns vanilla `Entity.dampensVibrations()`: whether vibrations this
+    /// entity causes are muffled, like those of dropped wool.
+    fn dampens_vibrations(&self) -> bool {
+        false
+    }
+
     /// Returns whether this entity is allied to `other`.
     fn is_allied_to(&self, _other: &dyn Entity) -> bool {
         false
@@ -1886,6 +1945,11 @@ pub trait Entity: EntityEventSource + ErasedType + Send + Sync {
         false
     }
 
+    /// Returns the attached face of a vanilla `HangingEntity`, if this is one.
+    fn hanging_direction(&self) -> Option<Direction> {
+        None
+    }
+
     /// Returns whether this entity is a tameable animal owned by `owner`.
     fn is_tame_owned_by(&self, _owner: &dyn LivingEntity) -> bool {
         false
@@ -2032,6 +2096,15 @@ pub trait Entity: EntityEventSource + ErasedType + Send + Sync {
         self.base().first_passenger()
     }
 
+    /// Dismounts every direct passenger, last passenger first.
+    ///
+    /// Mirrors vanilla `Entity.ejectPassengers`.
+    fn eject_passengers(&self) {
+        for passenger in self.passengers().into_iter().rev() {
+            passenger.stop_riding();
+        }
+    }
+
     /// Returns the living passenger currently controlling this entity, if any.
     ///
     /// Mirrors vanilla `Entity.getControllingPassenger`. Base entities have no
@@ -2151,6 +2224,14 @@ pub trait Entity: EntityEventSource + ErasedType + Send + Sync {
         }
     }
 
+    /// Returns where `passenger` should be placed after dismounting this vehicle.
+    ///
+    /// Mirrors vanilla `Entity.getDismountLocationForPassenger`.
+    fn dismount_location_for_passenger(&self, _passenger: &dyn Entity) -> DVec3 {
+        let position = self.position();
+        DVec3::new(position.x, self.bounding_box().max_y(), position.z)
+    }
+
     /// Returns this entity's root vehicle ID, or this entity's ID when it is not riding.
     ///
     /// Mirrors vanilla `Entity.getRootVehicle` using session IDs for object identity.
@@ -2644,14 +2725,7 @@ pub trait Entity: EntityEventSource + ErasedType + Send + Sync {
         let Some(world) = self.level() else {
             return Vec::new();
         };
-        let holder_id = holder.id();
-        let scan_area = leash_scan_area(world_aabb_center(self.bounding_box()));
-        world.get_entities_in_aabb_matching(&scan_area, |entity| {
-            entity.as_mob().is_some_and(|mob| {
-                mob.leash_holder()
-                    .is_some_and(|holder| holder.id() == holder_id)
-            })
-        })
+        leashables_leashed_to_holder_near(&world, world_aabb_center(self.bounding_box()), holder)
     }
 
     /// Transfers leashables currently held by `old_holder` to this entity.
@@ -2755,10 +2829,7 @@ pub trait Entity: EntityEventSource + ErasedType + Send + Sync {
                 continue;
             }
 
-            player
-                .inventory
-                .lock()
-                .hurt_item_in_hand(hand, 1, has_infinite_materials);
+            player.hurt_item_in_hand(hand, 1);
             mob.refresh_equipment_attribute_modifiers(slot);
             mob.set_guaranteed_drop(slot);
             mob.set_persistence_required();
@@ -2834,11 +2905,7 @@ pub trait Entity: EntityEventSource + ErasedType + Send + Sync {
             inventory.get_item_in_hand(hand).is(&vanilla_items::SHEARS)
         };
         if holding_shears && self.shear_off_all_leash_connections(Some(player)) {
-            let has_infinite_materials = player.has_infinite_materials();
-            player
-                .inventory
-                .lock()
-                .hurt_item_in_hand(hand, 1, has_infinite_materials);
+            player.hurt_item_in_hand(hand, 1);
             return InteractionResult::Success;
         }
         if holding_shears && self.attempt_to_shear_equipment(player, hand) {
@@ -2983,6 +3050,16 @@ pub trait Entity: EntityEventSource + ErasedType + Send + Sync {
         self.capabilities().item_steerable
     }
 
+    /// Returns this entity as a vanilla `RangedAttackMob` when it can shoot.
+    fn as_ranged_attack_mob(&self) -> Option<&dyn RangedAttackMob> {
+        self.capabilities().ranged_attack_mob
+    }
+
+    /// Returns this entity as a vanilla `Merchant` when players can trade with it.
+    fn as_merchant(&self) -> Option<&dyn Merchant> {
+        self.capabilities().merchant
+    }
+
     /// Returns dropped-item merge behavior when this entity exposes it.
     fn as_item_merge_entity(&self) -> Option<&dyn ItemMergeEntity> {
         self.capabilities().item_merge_entity
@@ -3447,6 +3524,24 @@ pub trait Entity: EntityEventSource + ErasedType + Send + Sync {
         }
     }
 
+    /// Handles being struck by `lightning`.
+    fn thunder_hit(&self, world: &Arc<World>, _lightning: &LightningBoltEntity) {
+        self.default_thunder_hit(world);
+    }
+
+    /// Vanilla `Entity.thunderHit`: extends or starts burning, then deals lightning damage.
+    fn default_thunder_hit(&self, world: &Arc<World>) {
+        self.set_remaining_fire_ticks(self.remaining_fire_ticks() + 1);
+        if self.remaining_fire_ticks() == 0 {
+            self.ignite_for_ticks(LIGHTNING_IGNITE_TICKS);
+        }
+        self.hurt(
+            world,
+            &DamageSource::environment(&vanilla_damage_types::LIGHTNING_BOLT),
+            LIGHTNING_DAMAGE,
+        );
+    }
+
     /// Maximum vanilla `remainingFireTicks` this entity can store.
     fn remaining_fire_ticks_cap(&self) -> Option<i32> {
         None
@@ -3552,6 +3647,40 @@ pub trait Entity: EntityEventSource + ErasedType + Send + Sync {
         self.fluid_contact().water_height() > 0.0
     }
 
+    /// Returns vanilla `Entity.isInRain`.
+    fn is_in_rain(&self) -> bool {
+        let Some(world) = self.level() else {
+            return false;
+        };
+
+        let pos = self.block_position();
+        world.is_raining_at(pos)
+            || world.is_raining_at(BlockPos::new(
+                pos.x(),
+                self.bounding_box().max_y().floor() as i32,
+                pos.z(),
+            ))
+    }
+
+    /// Returns vanilla `Entity.isInWaterOrRain`.
+    fn is_in_water_or_rain(&self) -> bool {
+        self.is_in_water() || self.is_in_rain()
+    }
+
+    /// Returns vanilla `Entity.getLightLevelDependentMagicValue` at eye height.
+    fn light_level_dependent_magic_value(&self) -> f32 {
+        let Some(world) = self.level() else {
+            return 0.0;
+        };
+
+        let position = self.position();
+        world.light_level_dependent_magic_value(BlockPos::containing(
+            position.x,
+            self.get_eye_y(),
+            position.z,
+        ))
+    }
+
     /// Returns true if this entity is currently touching lava.
     fn is_in_lava(&self) -> bool {
         self.fluid_contact().lava_height() > 0.0
@@ -5400,6 +5529,13 @@ pub trait LivingEntity: Entity {
         self.living_base().last_hurt_by_mob()
     }
 
+    /// Returns vanilla `LivingEntity.getKillCredit`.
+    fn kill_credit(&self, world: &World) -> Option<SharedEntity> {
+        self.last_hurt_by_player_uuid()
+            .and_then(|uuid| world.get_entity_by_uuid(&uuid))
+            .or_else(|| self.last_hurt_by_mob())
+    }
+
     /// Returns vanilla `LivingEntity.lastHurtByMobTimestamp`.
     fn last_hurt_by_mob_timestamp(&self) -> i32 {
         self.living_base().last_hurt_by_mob_timestamp()
@@ -5812,6 +5948,21 @@ pub trait LivingEntity: Entity {
         }
 
         self.game_event(&vanilla_game_events::ENTITY_DIE);
+        if let Some(world) = self.level() {
+            if let Some(killer) = self.kill_credit(&world)
+                && let Some(player) = world.players.get_by_entity_id(killer.id())
+            {
+                player.award_kill_score(self.entity_type());
+            }
+            // Vanilla `Raider.die`: players that kill a raider become heroes.
+            let hero = source
+                .causing_entity_id
+                .and_then(|id| world.players.get_by_entity_id(id));
+            world
+                .raids
+                .lock()
+                .on_raider_death(&world, self.uuid(), hero.as_deref());
+        }
         self.drop_all_death_loot(source);
         self.broadcast_entity_event(EntityStatus::Death);
         self.set_pose(EntityPose::Dying);
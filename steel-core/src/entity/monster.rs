@@ -0,0 +1,99 @@
+//! Shared vanilla `Monster` hooks.
+
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::vanilla_entity_type_tags::EntityTypeTag;
+use steel_registry::{REGISTRY, TaggedRegistryExt};
+use steel_utils::BlockPos;
+use steel_utils::types::Difficulty;
+
+use crate::chunk::light::LightLayer;
+use crate::entity::{EntitySpawnReason, LivingEntity, Mob, PathfinderMob};
+use crate::physics::MoveResult;
+use crate::world::{LevelReader, World};
+
+const NO_ACTION_BRIGHTNESS_THRESHOLD: f32 = 0.5;
+const MAX_BLOCK_LIGHT_LIMIT: i32 = 15;
+const THUNDER_SKY_DARKENING: u8 = 10;
+
+/// Vanilla `Monster`: hostile pathfinder mobs that despawn in peaceful and
+/// age their idle timer faster in light.
+pub trait Monster: PathfinderMob {
+    /// Mirrors vanilla `Monster.updateNoActionTime`.
+    fn update_no_action_time(&self) {
+        if self.light_level_dependent_magic_value() > NO_ACTION_BRIGHTNESS_THRESHOLD {
+            self.set_no_action_time(self.no_action_time() + 2);
+        }
+    }
+
+    /// Mirrors vanilla `Monster.aiStep` plus the `Mob.aiStep` daylight check.
+    fn ai_step_monster(&self) -> Option<MoveResult> {
+        self.update_no_action_time();
+        if REGISTRY
+            .entity_types
+            .is_in_tag(self.entity_type(), &EntityTypeTag::BURN_IN_DAYLIGHT)
+        {
+            self.burn_undead();
+        }
+
+        self.default_ai_step()
+    }
+
+    /// Mirrors vanilla `Monster.getWalkTargetValue`.
+    fn monster_walk_target_value(&self, pos: BlockPos) -> f32 {
+        let Some(world) = self.level() else {
+            return 0.0;
+        };
+
+        -world.pathfinding_cost_from_light_levels(pos)
+    }
+
+    /// Mirrors vanilla `Monster.isDarkEnoughToSpawn`.
+    fn is_dark_enough_to_spawn(world: &World, pos: BlockPos) -> bool
+    where
+        Self: Sized,
+    {
+        if i32::from(world.light_value_at(LightLayer::Sky, pos)) > rand::random_range(0..32) {
+            return false;
+        }
+
+        let dimension_type = world.dimension_type;
+        let block_light_limit = dimension_type.monster_spawn_block_light_limit;
+        if block_light_limit < MAX_BLOCK_LIGHT_LIMIT
+            && i32::from(world.light_value_at(LightLayer::Block, pos)) > block_light_limit
+        {
+            return false;
+        }
+
+        let sky_darkening = if world.is_thundering() {
+            THUNDER_SKY_DARKENING
+        } else {
+            world.sky_darkening()
+        };
+        i32::from(world.max_local_raw_brightness(pos, sky_darkening))
+            <= dimension_type
+                .monster_spawn_light_level
+                .sample(&mut rand::rng())
+    }
+
+    /// Mirrors vanilla `Monster.checkMonsterSpawnRules`.
+    fn check_monster_spawn_rules(
+        entity_type: EntityTypeRef,
+        world: &World,
+        spawn_reason: EntitySpawnReason,
+        pos: BlockPos,
+    ) -> bool
+    where
+        Self: Sized,
+    {
+        world.difficulty() != Difficulty::Peaceful
+            && (spawn_reason.ignores_light_requirements()
+                || Self::is_dark_enough_to_spawn(world, pos))
+            && Self::check_mob_spawn_rules(entity_type, world, spawn_reason, pos)
+    }
+}
+
+/// Vanilla `RangedAttackMob`: mobs driven by ranged attack goals.
+pub trait RangedAttackMob: Mob {
+    /// Mirrors vanilla `RangedAttackMob.performRangedAttack`.
+    fn perform_ranged_attack(&self, target: &dyn LivingEntity, power: f32);
+}
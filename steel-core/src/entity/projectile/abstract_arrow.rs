@@ -0,0 +1,442 @@
+//! Vanilla `AbstractArrow` — arrows that fly under gravity, stick into blocks
+//! and despawn after a minute in the ground.
+//!
+//! Vanilla keeps the in-ground and crit flags in synced entity data. Steel keeps
+//! them in the generated `*EntityData` layer, so the concrete entity implements
+//! the flag accessors and this trait holds the rest in [`AbstractArrowBase`].
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as BorrowedNbtCompoundView;
+use simdnbt::owned::NbtCompound;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::{sound_events, vanilla_damage_types, vanilla_entities};
+use steel_utils::locks::SyncMutex;
+use steel_utils::{BlockStateId, WorldAabb};
+
+use crate::entity::damage::DamageSource;
+use crate::entity::projectile::{
+    Projectile, ProjectileDeflection, ProjectileHit, compute_margin, get_entity_hit_result,
+    triangle_random,
+};
+use crate::entity::{Entity, RemovalReason, SharedEntity};
+use crate::physics::{CollisionWorld as _, WorldCollisionProvider};
+use crate::world::{ClipBlockShape, ClipFluid, ClipHitResult, World};
+
+/// Vanilla `AbstractArrow.getDefaultGravity`.
+const DEFAULT_GRAVITY: f64 = 0.05;
+
+/// Vanilla `AbstractArrow.getWaterInertia`.
+const WATER_INERTIA: f64 = 0.6;
+
+/// Vanilla air drag applied after each flight step.
+const AIR_INERTIA: f64 = 0.99;
+
+/// Vanilla `AbstractArrow.baseDamage` default.
+const DEFAULT_BASE_DAMAGE: f64 = 2.0;
+
+/// Ticks an arrow may stay in the ground before it despawns.
+const DESPAWN_LIFE: i32 = 1200;
+
+/// Ticks a burning arrow sets its target on fire for (vanilla `igniteForSeconds(5)`).
+const IGNITE_TICKS: i32 = 100;
+
+/// Whether and by whom an arrow may be picked up (vanilla `AbstractArrow.Pickup`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ArrowPickup {
+    /// Nobody may pick the arrow up.
+    Disallowed,
+    /// Anyone may pick the arrow up.
+    Allowed,
+    /// Only players in creative mode may pick the arrow up.
+    CreativeOnly,
+}
+
+impl ArrowPickup {
+    /// Vanilla `Pickup.byOrdinal`, which falls back to `DISALLOWED`.
+    #[must_use]
+    pub const fn from_ordinal(ordinal: i8) -> Self {
+        match ordinal {
+            1 => Self::Allowed,
+            2 => Self::CreativeOnly,
+            _ => Self::Disallowed,
+        }
+    }
+
+    /// The ordinal stored in the `pickup` save tag.
+    #[must_use]
+    pub const fn ordinal(self) -> i8 {
+        match self {
+            Self::Disallowed => 0,
+            Self::Allowed => 1,
+            Self::CreativeOnly => 2,
+        }
+    }
+}
+
+struct AbstractArrowState {
+    life: i32,
+    base_damage: f64,
+    pickup: ArrowPickup,
+    last_state: Option<BlockStateId>,
+}
+
+/// Runtime fields shared by vanilla arrows (vanilla `AbstractArrow` fields).
+pub struct AbstractArrowBase {
+    state: SyncMutex<AbstractArrowState>,
+}
+
+impl AbstractArrowBase {
+    /// Creates default arrow runtime state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: SyncMutex::new(AbstractArrowState {
+                life: 0,
+                base_damage: DEFAULT_BASE_DAMAGE,
+                pickup: ArrowPickup::Disallowed,
+                last_state: None,
+            }),
+        }
+    }
+}
+
+impl Default for AbstractArrowBase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Vanilla-shaped behavior shared by entities that extend `AbstractArrow`.
+pub trait AbstractArrow: Projectile {
+    /// Returns shared arrow runtime state.
+    fn abstract_arrow_base(&self) -> &AbstractArrowBase;
+
+    /// Vanilla `AbstractArrow.isInGround` (synced `IN_GROUND`).
+    fn is_in_ground(&self) -> bool;
+
+    /// Vanilla `AbstractArrow.setInGround`.
+    fn set_in_ground(&self, in_ground: bool);
+
+    /// Vanilla `AbstractArrow.isCritArrow` (synced `ID_FLAGS` bit 0).
+    fn is_crit_arrow(&self) -> bool;
+
+    /// Vanilla `AbstractArrow.setCritArrow`.
+    fn set_crit_arrow(&self, crit: bool);
+
+    /// Vanilla `AbstractArrow.getDefaultPickupItem`.
+    fn get_pickup_item(&self) -> ItemStack;
+
+    /// Vanilla `AbstractArrow.getDefaultGravity` (0.05).
+    fn abstract_arrow_default_gravity(&self) -> f64 {
+        DEFAULT_GRAVITY
+    }
+
+    /// Vanilla `AbstractArrow.getBaseDamage`.
+    fn base_damage(&self) -> f64 {
+        self.abstract_arrow_base().state.lock().base_damage
+    }
+
+    /// Vanilla `AbstractArrow.setBaseDamage`.
+    fn set_base_damage(&self, damage: f64) {
+        self.abstract_arrow_base().state.lock().base_damage = damage;
+    }
+
+    /// Vanilla `AbstractArrow.setBaseDamageFromMob`: scales the damage of a mob
+    /// shot with the bow `power` and the world difficulty id.
+    fn set_base_damage_from_mob(&self, power: f32, difficulty_id: i32) {
+        self.set_base_damage(
+            f64::from(power) * 2.0 + triangle_random(f64::from(difficulty_id) * 0.11, 0.574_25),
+        );
+    }
+
+    /// Returns who may pick this arrow up.
+    fn pickup(&self) -> ArrowPickup {
+        self.abstract_arrow_base().state.lock().pickup
+    }
+
+    /// Sets who may pick this arrow up.
+    fn set_pickup(&self, pickup: ArrowPickup) {
+        self.abstract_arrow_base().state.lock().pickup = pickup;
+    }
+
+    /// Vanilla `AbstractArrow.shouldFall`: the block holding the arrow is gone.
+    fn should_fall(&self) -> bool {
+        let Some(world) = self.level() else {
+            return false;
+        };
+        let position = self.position();
+        self.is_in_ground()
+            && !WorldCollisionProvider::for_entity(&world, self.as_entity_event_source())
+                .has_block_collision(&WorldAabb::from_min_max(position, position).inflate(0.06))
+    }
+
+    /// Vanilla `AbstractArrow.startFalling`.
+    fn start_falling(&self) {
+        self.set_in_ground(false);
+        let jitter = DVec3::new(
+            f64::from(rand::random::<f32>() * 0.2),
+            f64::from(rand::random::<f32>() * 0.2),
+            f64::from(rand::random::<f32>() * 0.2),
+        );
+        self.set_velocity(self.velocity() * jitter);
+        self.mark_velocity_sync();
+        self.abstract_arrow_base().state.lock().life = 0;
+    }
+
+    /// Vanilla `AbstractArrow.tickDespawn`.
+    fn tick_despawn(&self) {
+        let mut state = self.abstract_arrow_base().state.lock();
+        state.life += 1;
+        if state.life >= DESPAWN_LIFE {
+            drop(state);
+            self.set_removed(RemovalReason::Discarded);
+        }
+    }
+
+    /// Vanilla `AbstractArrow.tick`.
+    ///
+    /// Runs the `Projectile`/`Entity` base tick, then either ticks the despawn
+    /// timer while stuck in the ground or steps the flight: raycast the move
+    /// vector, move to the nearest hit, resolve it, then apply drag and gravity.
+    fn abstract_arrow_tick(&self) {
+        self.set_old_position_to_current();
+        self.base().set_old_rotation_to_current();
+        self.projectile_base_tick();
+        let Some(world) = self.level() else {
+            return;
+        };
+        if !self.is_alive() {
+            return;
+        }
+
+        let physics = !self.no_physics();
+        let pos = self.block_pos();
+        let state = world.get_block_state(pos);
+        if !state.is_air() && physics && self.should_be_in_ground_at(&world) {
+            self.set_velocity(DVec3::ZERO);
+            self.set_in_ground(true);
+        }
+
+        if self.is_in_water_or_rain() {
+            self.clear_fire();
+        }
+
+        if self.is_in_ground() && physics {
+            let last_state = self.abstract_arrow_base().state.lock().last_state;
+            if last_state != Some(state) && self.should_fall() {
+                self.start_falling();
+            } else {
+                self.tick_despawn();
+            }
+            if self.is_alive() {
+                self.apply_effects_from_blocks();
+            }
+            return;
+        }
+
+        if self.is_in_water() {
+            // VANILLA CLIENT-LOCAL: `AbstractArrow.tick` adds the bubble particles.
+            self.set_velocity(self.velocity() * WATER_INERTIA);
+        }
+        // VANILLA CLIENT-LOCAL: crit arrows trail crit particles.
+        self.update_rotation();
+
+        let from = self.position();
+        let block_hit = world.clip_including_border(
+            from,
+            from + self.velocity(),
+            ClipBlockShape::Collider,
+            ClipFluid::None,
+        );
+        self.step_move_and_hit(from, block_hit);
+
+        if !self.is_in_water() {
+            self.set_velocity(self.velocity() * AIR_INERTIA);
+        }
+        if physics && !self.is_in_ground() {
+            self.apply_gravity();
+        }
+    }
+
+    /// Vanilla `AbstractArrow.tick` in-ground check: the arrow's position lies
+    /// inside a collision box of the block it occupies.
+    fn should_be_in_ground_at(&self, world: &Arc<World>) -> bool {
+        let position = self.position();
+        WorldCollisionProvider::for_entity(world, self.as_entity_event_source())
+            .get_block_collisions(&WorldAabb::from_min_max(position, position))
+            .iter()
+            .any(|collision| collision.contains(position))
+    }
+
+    /// Vanilla `AbstractArrow.stepMoveAndHit` for non-piercing arrows: moves to
+    /// the nearest entity or block hit along the move vector and resolves it.
+    fn step_move_and_hit(&self, from: DVec3, block_hit: ClipHitResult) {
+        let Some(world) = self.level() else {
+            return;
+        };
+        let to = if block_hit.is_miss() {
+            from + self.velocity()
+        } else {
+            block_hit.location
+        };
+        let search_box = self
+            .bounding_box()
+            .expand_towards(self.velocity())
+            .inflate(1.0);
+        let self_id = self.id();
+        let entity_hit = get_entity_hit_result(
+            &world,
+            from,
+            to,
+            search_box,
+            compute_margin(self.tick_count()),
+            |entity| entity.id() != self_id && self.can_hit_entity(entity),
+        );
+
+        let location = entity_hit.as_ref().map_or(to, |hit| hit.location);
+        if let Err(error) = self.try_set_position(location) {
+            log::debug!("failed to advance arrow {}: {error}", self.id());
+            self.set_removed(RemovalReason::Discarded);
+            return;
+        }
+        self.apply_effects_from_blocks();
+
+        if !self.is_alive() || self.is_world_change_pending() {
+            return;
+        }
+        match entity_hit {
+            Some(hit) if !self.no_physics() => {
+                self.hit_target_or_deflect_self(&ProjectileHit::Entity(hit));
+            }
+            Some(_) => {}
+            None if !block_hit.is_miss() => {
+                self.hit_target_or_deflect_self(&ProjectileHit::Block {
+                    location: block_hit.location,
+                    hit: block_hit,
+                });
+            }
+            None => {}
+        }
+        self.mark_velocity_sync();
+    }
+
+    /// Vanilla `AbstractArrow.onHitEntity`: hurts `entity` by the arrow's speed
+    /// times its base damage, or bounces off if the hit is blocked.
+    fn abstract_arrow_on_hit_entity(&self, entity: &SharedEntity) {
+        let Some(world) = entity.level() else {
+            return;
+        };
+        let speed = self.velocity().length();
+        let mut damage = (speed * self.base_damage())
+            .clamp(0.0, f64::from(i32::MAX))
+            .ceil() as i32;
+        if self.is_crit_arrow() {
+            let bonus = rand::random_range(0..damage / 2 + 2);
+            damage = damage.saturating_add(bonus);
+        }
+
+        let owner = self.get_owner();
+        let source = DamageSource::environment(&vanilla_damage_types::ARROW)
+            .with_direct_entity(self.id())
+            .with_causing_entity(owner.as_ref().map_or(self.id(), |owner| owner.id()));
+        if let Some(owner) = owner.as_ref()
+            && let Some(living) = owner.as_living_entity()
+        {
+            living.set_last_hurt_mob(Some(entity));
+        }
+
+        let is_enderman = entity.entity_type() == &vanilla_entities::ENDERMAN;
+        let fire_ticks = entity.remaining_fire_ticks();
+        if self.is_on_fire() && !is_enderman {
+            entity.ignite_for_ticks(IGNITE_TICKS);
+        }
+
+        if entity.hurt(&world, &source, damage as f32) {
+            if is_enderman {
+                return;
+            }
+            // TODO: Increment the target's arrow count once living entities expose it.
+            self.play_sound(
+                &sound_events::ENTITY_ARROW_HIT,
+                1.0,
+                1.2 / rand::random::<f32>().mul_add(0.2, 0.9),
+            );
+            self.set_removed(RemovalReason::Discarded);
+            return;
+        }
+
+        entity.set_remaining_fire_ticks(fire_ticks);
+        let owner_uuid = self.owner_uuid();
+        self.deflect(
+            ProjectileDeflection::Reverse,
+            Some(entity.as_ref()),
+            owner_uuid,
+            owner.as_ref(),
+            false,
+        );
+        self.set_velocity(self.velocity() * 0.2);
+        if self.velocity().length_squared() < 1.0e-7 {
+            if self.pickup() == ArrowPickup::Allowed {
+                self.spawn_at_location(self.get_pickup_item(), 0.1);
+            }
+            self.set_removed(RemovalReason::Discarded);
+        }
+    }
+
+    /// Vanilla `AbstractArrow.onHitBlock`: backs the arrow out of the block face
+    /// and sticks it in the ground.
+    fn abstract_arrow_on_hit_block(&self, hit: &ClipHitResult) {
+        if let Some(world) = self.level() {
+            self.abstract_arrow_base().state.lock().last_state =
+                Some(world.get_block_state(hit.block_pos));
+        }
+        self.projectile_on_hit_block(hit);
+
+        let movement = self.velocity();
+        let offset = DVec3::new(
+            java_signum(movement.x),
+            java_signum(movement.y),
+            java_signum(movement.z),
+        ) * 0.05;
+        if let Err(error) = self.try_set_position(self.position() - offset) {
+            log::debug!("failed to back arrow {} out of block: {error}", self.id());
+        }
+        self.set_velocity(DVec3::ZERO);
+        self.play_sound(
+            &sound_events::ENTITY_ARROW_HIT,
+            1.0,
+            1.2 / rand::random::<f32>().mul_add(0.2, 0.9),
+        );
+        self.set_in_ground(true);
+        self.set_crit_arrow(false);
+    }
+
+    /// Saves vanilla `AbstractArrow` fields.
+    fn save_abstract_arrow(&self, nbt: &mut NbtCompound) {
+        let state = self.abstract_arrow_base().state.lock();
+        nbt.insert("life", state.life as i16);
+        nbt.insert("inGround", i8::from(self.is_in_ground()));
+        nbt.insert("pickup", state.pickup.ordinal());
+        nbt.insert("damage", state.base_damage);
+        nbt.insert("crit", i8::from(self.is_crit_arrow()));
+    }
+
+    /// Loads vanilla `AbstractArrow` fields.
+    fn load_abstract_arrow(&self, nbt: BorrowedNbtCompoundView<'_, '_>) {
+        let mut state = self.abstract_arrow_base().state.lock();
+        state.life = nbt.short("life").map_or(0, i32::from);
+        state.pickup = ArrowPickup::from_ordinal(nbt.byte("pickup").unwrap_or(0));
+        state.base_damage = nbt.double("damage").unwrap_or(DEFAULT_BASE_DAMAGE);
+        drop(state);
+        self.set_in_ground(nbt.byte("inGround").is_some_and(|value| value != 0));
+        self.set_crit_arrow(nbt.byte("crit").is_some_and(|value| value != 0));
+    }
+}
+
+/// Java `Math.signum`, which keeps zero components at zero.
+fn java_signum(value: f64) -> f64 {
+    if value == 0.0 { 0.0 } else { value.signum() }
+}
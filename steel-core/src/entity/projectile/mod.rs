@@ -1,7 +1,8 @@
 //! Vanilla `Projectile` class hierarchy, mirrored as Rust traits + base structs.
 //!
 //! Mirrors `net.minecraft.world.entity.projectile`:
-//! `Entity → Projectile → ThrowableProjectile → ThrowableItemProjectile`.
+//! `Entity → Projectile → ThrowableProjectile → ThrowableItemProjectile` and
+//! `Entity → Projectile → AbstractArrow`.
 //! Concrete projectiles embed [`ProjectileBase`] (owner / left-owner / shot state)
 //! and implement the trait stack; the per-layer tick logic chains explicitly via
 //! [`Projectile::projectile_base_tick`] and
@@ -9,6 +10,7 @@
 //!
 //! The block + entity move-vector raycast mirrors `ProjectileUtil`.
 
+mod abstract_arrow;
 mod throwable;
 mod throwable_item;
 
@@ -33,6 +35,7 @@ use crate::entity::{Entity, LivingEntity, SharedEntity};
 use crate::world::game_event_context::GameEventContext;
 use crate::world::{ClipBlockShape, ClipFluid, ClipHitResult, World};
 
+pub use abstract_arrow::{AbstractArrow, AbstractArrowBase, ArrowPickup};
 pub use throwable::ThrowableProjectile;
 pub use throwable_item::ThrowableItemProjectile;
 
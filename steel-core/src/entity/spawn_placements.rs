@@ -0,0 +1,161 @@
+//! Vanilla `SpawnPlacements`: where each entity type may be placed by spawners.
+
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::{vanilla_blocks, vanilla_entities};
+use steel_utils::{BlockPos, BlockStateId, Direction};
+
+use crate::behavior::BlockStateBehaviorExt as _;
+use crate::entity::ai::walk::WalkPathEvaluator;
+use crate::entity::entities::{
+    ChickenEntity, CowEntity, CreeperEntity, PigEntity, SheepEntity, SkeletonEntity, SpiderEntity,
+    ZombieEntity,
+};
+use crate::entity::{Animal, EntitySpawnReason, Monster};
+use crate::world::World;
+
+const MAX_SPAWN_BLOCK_LIGHT_EMISSION: u8 = 14;
+
+type SpawnPredicate = fn(EntityTypeRef, &World, EntitySpawnReason, BlockPos) -> bool;
+
+/// Vanilla `SpawnPlacementType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SpawnPlacementType {
+    OnGround,
+}
+
+impl SpawnPlacementType {
+    /// Mirrors vanilla `SpawnPlacementType.isSpawnPositionOk`.
+    pub(crate) fn is_spawn_position_ok(
+        self,
+        world: &World,
+        pos: BlockPos,
+        entity_type: EntityTypeRef,
+    ) -> bool {
+        if !world.is_block_within_world_border(pos) {
+            return false;
+        }
+
+        match self {
+            Self::OnGround => {
+                let below = pos.below();
+                is_valid_spawn(world.get_block_state(below), below, entity_type)
+                    && is_valid_empty_spawn_block(world, pos, entity_type)
+                    && is_valid_empty_spawn_block(world, pos.above(), entity_type)
+            }
+        }
+    }
+}
+
+/// Vanilla `SpawnPlacements.Data` for one entity type.
+#[derive(Clone, Copy)]
+pub(crate) struct SpawnPlacement {
+    pub(crate) placement_type: SpawnPlacementType,
+    predicate: SpawnPredicate,
+}
+
+impl SpawnPlacement {
+    /// Mirrors vanilla `SpawnPlacements.checkSpawnRules`.
+    pub(crate) fn check_spawn_rules(
+        self,
+        entity_type: EntityTypeRef,
+        world: &World,
+        spawn_reason: EntitySpawnReason,
+        pos: BlockPos,
+    ) -> bool {
+        (self.predicate)(entity_type, world, spawn_reason, pos)
+    }
+}
+
+/// Returns the vanilla spawn placement registered for `entity_type`.
+///
+/// Only entity types Steel can construct are registered; everything else is
+/// skipped by natural spawning.
+pub(crate) fn spawn_placement(entity_type: EntityTypeRef) -> Option<SpawnPlacement> {
+    let predicate: SpawnPredicate = if entity_type == &vanilla_entities::CHICKEN {
+        |_, world, spawn_reason, pos| {
+            ChickenEntity::check_animal_spawn_rules(world, spawn_reason, pos)
+        }
+    } else if entity_type == &vanilla_entities::COW {
+        |_, world, spawn_reason, pos| CowEntity::check_animal_spawn_rules(world, spawn_reason, pos)
+    } else if entity_type == &vanilla_entities::PIG {
+        |_, world, spawn_reason, pos| PigEntity::check_animal_spawn_rules(world, spawn_reason, pos)
+    } else if entity_type == &vanilla_entities::SHEEP {
+        |_, world, spawn_reason, pos| {
+            SheepEntity::check_animal_spawn_rules(world, spawn_reason, pos)
+        }
+    } else if entity_type == &vanilla_entities::CREEPER {
+        CreeperEntity::check_monster_spawn_rules
+    } else if entity_type == &vanilla_entities::SKELETON {
+        SkeletonEntity::check_monster_spawn_rules
+    } else if entity_type == &vanilla_entities::SPIDER {
+        SpiderEntity::check_monster_spawn_rules
+    } else if entity_type == &vanilla_entities::ZOMBIE {
+        ZombieEntity::check_monster_spawn_rules
+    } else {
+        return None;
+    };
+
+    Some(SpawnPlacement {
+        placement_type: SpawnPlacementType::OnGround,
+        predicate,
+    })
+}
+
+/// Mirrors vanilla `BlockState.isValidSpawn`.
+pub(crate) fn is_valid_spawn(
+    state: BlockStateId,
+    pos: BlockPos,
+    entity_type: EntityTypeRef,
+) -> bool {
+    let block = state.get_block();
+    if block.has_tag(&BlockTag::LEAVES) {
+        return entity_type == &vanilla_entities::OCELOT
+            || entity_type == &vanilla_entities::PARROT;
+    }
+    if block == &vanilla_blocks::ICE || block == &vanilla_blocks::FROSTED_ICE {
+        return entity_type == &vanilla_entities::POLAR_BEAR;
+    }
+    if block == &vanilla_blocks::MAGMA_BLOCK {
+        return entity_type.fire_immune;
+    }
+    if block.has_tag(&BlockTag::IMPERMEABLE)
+        || block == &vanilla_blocks::BARRIER
+        || block == &vanilla_blocks::LIGHT
+    {
+        return false;
+    }
+
+    state.is_face_sturdy_at(pos, Direction::Up)
+        && state.get_light_emission() < MAX_SPAWN_BLOCK_LIGHT_EMISSION
+}
+
+/// Mirrors vanilla `NaturalSpawner.isValidEmptySpawnBlock`.
+pub(crate) fn is_valid_empty_spawn_block(
+    world: &World,
+    pos: BlockPos,
+    entity_type: EntityTypeRef,
+) -> bool {
+    let state = world.get_block_state(pos);
+    // TODO: Reject signal sources once block behaviors expose vanilla `isSignalSource`.
+    !world.is_collision_shape_full_block_at(pos, state)
+        && !state.has_fluid()
+        && !state
+            .get_block()
+            .has_tag(&BlockTag::PREVENT_MOB_SPAWNING_INSIDE)
+        && !is_block_dangerous(entity_type, state)
+}
+
+/// Mirrors vanilla `EntityType.isBlockDangerous` without per-type immunities.
+pub(crate) fn is_block_dangerous(entity_type: EntityTypeRef, state: BlockStateId) -> bool {
+    if !entity_type.fire_immune && WalkPathEvaluator::is_burning_block(state) {
+        return true;
+    }
+
+    let block = state.get_block();
+    block == &vanilla_blocks::WITHER_ROSE
+        || block == &vanilla_blocks::SWEET_BERRY_BUSH
+        || block == &vanilla_blocks::CACTUS
+        || block == &vanilla_blocks::POWDER_SNOW
+}
@@ -2,7 +2,9 @@
 //!
 //! Keeps the vanilla visibility predicate in block space. Vanilla stores an
 //! entity tracking range as client chunks, multiplies it by 16, caps it by the
-//! player's view distance, and then checks horizontal squared distance.
+//! player's view distance, and then checks horizontal squared distance. The
+//! effective range is scaled by the server's entity broadcast range percentage
+//! like vanilla `MinecraftServer.getScaledTrackingDistance`.
 
 use std::sync::Arc;
 
@@ -27,10 +29,15 @@ use crate::player::Player;
 
 const BLOCKS_PER_CHUNK: f64 = 16.0;
 
+/// Vanilla default for `entity-broadcast-range-percentage`.
+pub const DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE: u16 = 100;
+
 /// World-level entity tracker.
 pub struct EntityTracker {
     /// Maps entity ID to its tracking data.
     entities: scc::HashMap<i32, TrackedEntity>,
+    /// Percentage applied to every effective tracking range.
+    broadcast_range_percentage: u16,
 }
 
 /// Packet sinks used by [`EntityTracker::send_changes`].
@@ -96,6 +103,13 @@ impl EntityTrackingRange {
         self.block_radius <= 0.0
     }
 
+    /// Applies vanilla `getScaledTrackingDistance` integer scaling.
+    fn scaled(self, percentage: u16) -> Self {
+        Self {
+            block_radius: (self.block_radius * f64::from(percentage) / 100.0).floor(),
+        }
+    }
+
     fn visible_radius(self, player_view_distance: u8) -> f64 {
         self.block_radius
             .min(f64::from(player_view_distance) * BLOCKS_PER_CHUNK)
@@ -104,16 +118,20 @@ impl EntityTrackingRange {
 
 impl Default for EntityTracker {
     fn default() -> Self {
-        Self::new()
+        Self::new(DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE)
     }
 }
 
 impl EntityTracker {
     /// Creates a new empty entity tracker.
+    ///
+    /// `broadcast_range_percentage` scales every entity tracking range like
+    /// vanilla `entity-broadcast-range-percentage`.
     #[must_use]
-    pub fn new() -> Self {
+    pub fn new(broadcast_range_percentage: u16) -> Self {
         Self {
             entities: scc::HashMap::new(),
+            broadcast_range_percentage,
         }
     }
 
@@ -151,7 +169,7 @@ impl EntityTracker {
         let pos = entity.position();
         let registered_chunk = ChunkPos::from_entity_pos(pos);
 
-        let players_to_notify = Self::visible_players_for_entity(
+        let players_to_notify = self.visible_players_for_entity(
             entity_id,
             entity.as_ref(),
             registered_chunk,
@@ -245,7 +263,8 @@ impl EntityTracker {
                 && is_within_tracking_distance(
                     entity.position(),
                     player_pos,
-                    effective_tracking_range(entity.as_ref(), tracked.tracking_range),
+                    effective_tracking_range(entity.as_ref(), tracked.tracking_range)
+                        .scaled(self.broadcast_range_percentage),
                     player_view_distance,
                 );
 
@@ -555,7 +574,7 @@ impl EntityTracker {
                 return;
             };
 
-            let new_seen_by = Self::visible_players_for_entity(
+            let new_seen_by = self.visible_players_for_entity(
                 entity_id,
                 entity.as_ref(),
                 new_chunk,
@@ -608,7 +627,7 @@ impl EntityTracker {
                 return;
             };
 
-            let new_seen_by = Self::visible_players_for_entity(
+            let new_seen_by = self.visible_players_for_entity(
                 entity_id,
                 entity.as_ref(),
                 tracked.registered_chunk,
@@ -662,6 +681,24 @@ impl EntityTracker {
             .unwrap_or_default()
     }
 
+    /// Despawns and respawns an entity for every player tracking it.
+    ///
+    /// Clients only read some state when an entity is spawned, such as the skin
+    /// of a player, so this is how that state is refreshed.
+    pub fn resend_spawn_packets(
+        &self,
+        entity: &SharedEntity,
+        get_player: impl Fn(i32) -> Option<Arc<Player>>,
+    ) {
+        let entity_id = entity.id();
+        for player_id in self.tracking_player_ids(entity_id) {
+            if let Some(player) = get_player(player_id) {
+                player.send_packet(CRemoveEntities::single(entity_id));
+                self.send_spawn_packets(entity, &player);
+            }
+        }
+    }
+
     fn remove_dead_entity(&self, entity_id: i32) {
         // Note: We don't send despawn packets here because the players
         // will get updated via player view changes or explicit removals.
@@ -669,6 +706,7 @@ impl EntityTracker {
     }
 
     fn visible_players_for_entity(
+        &self,
         entity_id: i32,
         entity: &dyn Entity,
         entity_chunk: ChunkPos,
@@ -677,7 +715,8 @@ impl EntityTracker {
         get_player: &impl Fn(i32) -> Option<Arc<Player>>,
     ) -> FxHashSet<i32> {
         let entity_pos = entity.position();
-        let tracking_range = effective_tracking_range(entity, tracking_range);
+        let tracking_range = effective_tracking_range(entity, tracking_range)
+            .scaled(self.broadcast_range_percentage);
         let mut players = FxHashSet::default();
         if entity.is_removed() {
             return players;
@@ -1119,6 +1158,16 @@ mod tests {
         assert!((range.visible_radius(10) - 64.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn broadcast_range_percentage_scales_block_radius() {
+        let range = EntityTrackingRange::from_client_chunk_range(5);
+
+        assert!((range.scaled(50).block_radius - 40.0).abs() < f64::EPSILON);
+        assert!((range.scaled(10).block_radius - 8.0).abs() < f64::EPSILON);
+        assert!((range.scaled(33).block_radius - 26.0).abs() < f64::EPSILON);
+        assert!((range.scaled(500).visible_radius(32) - 400.0).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn zero_client_tracking_range_disables_tracking() {
         let range = EntityTrackingRange::from_client_chunk_range(0);
@@ -1264,7 +1313,7 @@ mod tests {
     fn send_changes_broadcasts_dirty_attributes_once() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let entity_typed = PairingTestEntity::new(1, Vec::new());
         let entity: SharedEntity = entity_typed.clone();
         tracker.add(&entity, |_| Vec::new(), |_| None);
@@ -1319,7 +1368,7 @@ mod tests {
     fn send_changes_broadcasts_dirty_equipment_once() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let entity_typed = PairingTestEntity::new(1, Vec::new());
         let entity: SharedEntity = entity_typed.clone();
         tracker.add(&entity, |_| Vec::new(), |_| None);
@@ -1375,7 +1424,7 @@ mod tests {
     fn send_changes_syncs_hurt_marked_player_motion_to_self() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let entity_typed =
             PairingTestEntity::new_with_type(1, &vanilla_entities::PLAYER, Vec::new());
         let entity: SharedEntity = entity_typed.clone();
@@ -1422,7 +1471,7 @@ mod tests {
     fn send_changes_broadcasts_hurt_marked_non_player_motion() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let entity_typed = PairingTestEntity::new(1, Vec::new());
         let entity: SharedEntity = entity_typed.clone();
         track_entity_for_player(&tracker, &entity, 99);
@@ -1456,7 +1505,7 @@ mod tests {
     fn spawn_pairing_omits_untracked_passenger_for_vehicle() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let vehicle_typed = PairingTestEntity::new(1, Vec::new());
         let passenger_typed = PairingTestEntity::new(2, Vec::new());
         let passenger: SharedEntity = passenger_typed;
@@ -1472,7 +1521,7 @@ mod tests {
     fn spawn_pairing_includes_tracked_passenger_packet_for_vehicle() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let vehicle_typed = PairingTestEntity::new(1, Vec::new());
         let passenger_typed = PairingTestEntity::new(2, Vec::new());
         let passenger: SharedEntity = passenger_typed;
@@ -1491,7 +1540,7 @@ mod tests {
     fn spawn_pairing_for_passenger_omits_untracked_vehicle_packet() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let vehicle_typed = PairingTestEntity::new(1, Vec::new());
         let passenger_typed = PairingTestEntity::new(2, Vec::new());
         let passenger: SharedEntity = passenger_typed.clone();
@@ -1508,7 +1557,7 @@ mod tests {
     fn spawn_pairing_for_passenger_includes_tracked_vehicle_passenger_packet() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let vehicle_typed = PairingTestEntity::new(1, Vec::new());
         let passenger_typed = PairingTestEntity::new(2, Vec::new());
         let passenger: SharedEntity = passenger_typed.clone();
@@ -1529,7 +1578,7 @@ mod tests {
     fn spawn_pairing_includes_live_mob_leash_link_packet() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let pig_typed = Arc::new(PigEntity::new(
             &vanilla_entities::PIG,
             1,
@@ -1549,7 +1598,7 @@ mod tests {
     fn send_changes_broadcasts_leash_link_changes_once() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let pig: SharedEntity = Arc::new(PigEntity::new(
             &vanilla_entities::PIG,
             1,
@@ -1635,7 +1684,7 @@ mod tests {
     fn send_changes_broadcasts_passenger_changes_once() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let vehicle_typed = PairingTestEntity::new(1, Vec::new());
         let vehicle: SharedEntity = vehicle_typed.clone();
         let passenger_typed = PairingTestEntity::new(2, Vec::new());
@@ -1731,7 +1780,7 @@ mod tests {
     fn send_changes_removes_untracked_passenger_from_vehicle_packet() {
         test_support::init_test_registry();
 
-        let tracker = EntityTracker::new();
+        let tracker = EntityTracker::default();
         let vehicle_typed = PairingTestEntity::new(1, Vec::new());
         let passenger_typed = PairingTestEntity::new(2, Vec::new());
         let passenger: SharedEntity = passenger_typed;
@@ -0,0 +1,147 @@
+//! Shared vanilla `VehicleEntity` behavior for boats and minecarts.
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::data_components::vanilla_components::CUSTOM_NAME;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::items::ItemRef;
+use steel_registry::vanilla_entity_data::VehicleEntityData;
+use steel_registry::vanilla_game_events;
+use steel_registry::vanilla_game_rules::ENTITY_DROPS;
+use steel_utils::BlockPos;
+use steel_utils::axis::Axis;
+
+use crate::entity::damage::DamageSource;
+use crate::entity::{Entity, RemovalReason};
+use crate::physics::{WorldCollisionProvider, has_collision};
+use crate::world::World;
+
+const HURT_TIME: i32 = 10;
+const MAX_DAMAGE: f32 = 40.0;
+
+/// Vanilla `VehicleEntity`: damage wobble, destruction and item drops shared by boats
+/// and minecarts.
+pub trait VehicleEntity: Entity {
+    /// Runs `f` with the vanilla `VehicleEntity` synchronized data layer.
+    fn with_vehicle_data<R>(&self, f: impl FnOnce(&mut VehicleEntityData) -> R) -> R;
+
+    /// Returns the item dropped when this vehicle is destroyed.
+    fn drop_item(&self) -> ItemRef;
+
+    /// Returns the remaining hurt wobble ticks.
+    fn hurt_time(&self) -> i32 {
+        self.with_vehicle_data(|data| *data.id_hurt.get())
+    }
+
+    /// Returns the accumulated damage.
+    fn damage(&self) -> f32 {
+        self.with_vehicle_data(|data| *data.id_damage.get())
+    }
+
+    /// Decays the hurt wobble and accumulated damage by one tick.
+    fn tick_vehicle_damage(&self) {
+        self.with_vehicle_data(|data| {
+            let hurt_time = *data.id_hurt.get();
+            if hurt_time > 0 {
+                data.id_hurt.set(hurt_time - 1);
+            }
+            let damage = *data.id_damage.get();
+            if damage > 0.0 {
+                data.id_damage.set(damage - 1.0);
+            }
+        });
+    }
+
+    /// Mirrors vanilla `VehicleEntity.hurtServer`.
+    fn hurt_vehicle(&self, world: &World, source: &DamageSource, amount: f32) -> bool {
+        if self.is_removed() {
+            return true;
+        }
+        if self.is_invulnerable() && !source.bypasses_invulnerability() {
+            return false;
+        }
+
+        let damage = self.with_vehicle_data(|data| {
+            let hurt_dir = *data.id_hurtdir.get();
+            data.id_hurtdir.set(-hurt_dir);
+            data.id_hurt.set(HURT_TIME);
+            let damage = *data.id_damage.get() + amount * 10.0;
+            data.id_damage.set(damage);
+            damage
+        });
+        self.mark_hurt();
+        self.game_event(&vanilla_game_events::ENTITY_DAMAGE);
+
+        let instabuild = source
+            .causing_entity_id
+            .and_then(|id| world.get_entity_by_id(id))
+            .is_some_and(|entity| {
+                entity
+                    .as_player()
+                    .is_some_and(|player| player.abilities.lock().instabuild)
+            });
+        if instabuild {
+            self.set_removed(RemovalReason::Discarded);
+        } else if damage > MAX_DAMAGE {
+            self.destroy(world);
+        }
+        true
+    }
+
+    /// Kills this vehicle and drops its item, mirroring vanilla `VehicleEntity.destroy`.
+    fn destroy(&self, world: &World) {
+        self.kill(world);
+        if !world.get_game_rule(&ENTITY_DROPS) {
+            return;
+        }
+
+        let mut item = ItemStack::new(self.drop_item());
+        if let Some(custom_name) = self.custom_name() {
+            item.set(CUSTOM_NAME, custom_name);
+        }
+        self.spawn_at_location(item, 0.0);
+    }
+}
+
+/// Mirrors vanilla `Entity.getCollisionHorizontalEscapeVector`.
+pub(crate) fn collision_horizontal_escape_vector(
+    vehicle_width: f64,
+    passenger_width: f64,
+    yaw: f32,
+) -> DVec3 {
+    let distance = (vehicle_width + passenger_width + 1.0E-5) / 2.0;
+    let (sin, cos) = yaw.to_radians().sin_cos();
+    let (x, z) = (f64::from(-sin), f64::from(cos));
+    let max = x.abs().max(z.abs());
+    DVec3::new(x * distance / max, 0.0, z * distance / max)
+}
+
+/// Mirrors vanilla `BlockGetter.getBlockFloorHeight`.
+pub(crate) fn block_floor_height(world: &World, pos: BlockPos) -> f64 {
+    let shape = world.get_block_state(pos).get_collision_shape_at(pos);
+    if !shape.is_empty() {
+        return shape.max(Axis::Y);
+    }
+    let below = pos.offset(0, -1, 0);
+    let below_shape = world.get_block_state(below).get_collision_shape_at(below);
+    if below_shape.is_empty() {
+        f64::NEG_INFINITY
+    } else {
+        below_shape.max(Axis::Y) - 1.0
+    }
+}
+
+/// Mirrors vanilla `DismountHelper.isBlockFloorValid`.
+pub(crate) const fn is_block_floor_valid(floor_height: f64) -> bool {
+    floor_height.is_finite() && floor_height < 1.0
+}
+
+/// Returns whether `passenger` fits at `position`, mirroring vanilla
+/// `DismountHelper.canDismountTo` for the passenger's current pose.
+pub(crate) fn can_dismount_to(world: &Arc<World>, passenger: &dyn Entity, position: DVec3) -> bool {
+    let aabb = passenger.make_bounding_box_at(position);
+    world.is_block_within_world_border(BlockPos::containing(position.x, position.y, position.z))
+        && !has_collision(&WorldCollisionProvider::for_entity(world, passenger), aabb)
+}
@@ -0,0 +1,406 @@
+//! The beacon menu.
+//!
+//! Slot layout (37 total):
+//! - Slot 0: Payment item
+//! - Slots 1-27: Main inventory (27 slots)
+//! - Slots 28-36: Hotbar (9 slots)
+//!
+//! Data slot 0 holds the pyramid levels, 1 the primary and 2 the secondary
+//! power, encoded with [`encode_beacon_effect`].
+
+use std::{mem, sync::Arc};
+
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::menu_type::MenuTypeRef;
+use steel_registry::mob_effect::MobEffectRef;
+use steel_registry::vanilla_item_tags::ItemTag;
+use steel_registry::{REGISTRY, RegistryExt, vanilla_blocks, vanilla_menu_types};
+use steel_utils::locks::SyncMutex;
+use steel_utils::{BlockPos, DowncastType, DowncastTypeKey};
+use text_components::TextComponent;
+
+use crate::block_entity::entities::{BeaconBlockEntity, encode_beacon_effect};
+use crate::block_entity::{BlockEntity as _, SharedBlockEntity};
+use crate::inventory::{
+    SyncPlayerInv,
+    container::Container,
+    lock::{ContainerLockGuard, ContainerRef},
+    menu::{Menu, MenuBehavior},
+    menu_provider::{MenuInstance, MenuProvider},
+    slot::{BeaconPaymentSlot, Slot, SlotType, add_standard_inventory_slots},
+};
+use crate::player::Player;
+
+/// Slot indices for the beacon menu.
+pub mod slots {
+    /// Slot index for the payment item (slot 0).
+    pub const PAYMENT_SLOT: usize = 0;
+    /// Start of main inventory (slot 1).
+    pub const INV_SLOT_START: usize = 1;
+    /// End of main inventory / start of hotbar (slot 28).
+    pub const INV_SLOT_END: usize = 28;
+    /// Start of hotbar (slot 28).
+    pub const USE_ROW_SLOT_START: usize = 28;
+    /// End of hotbar (slot 37, exclusive).
+    pub const USE_ROW_SLOT_END: usize = 37;
+    /// Total number of slots in the beacon menu.
+    pub const TOTAL_SLOTS: usize = 37;
+}
+
+/// Data slot holding the pyramid levels.
+const LEVELS_DATA_SLOT: usize = 0;
+/// Data slot holding the encoded primary power.
+const PRIMARY_DATA_SLOT: usize = 1;
+/// Data slot holding the encoded secondary power.
+const SECONDARY_DATA_SLOT: usize = 2;
+/// Number of data slots in the beacon menu.
+const DATA_COUNT: usize = 3;
+
+/// Returns whether `stack` can pay for beacon powers.
+#[must_use]
+pub fn is_payment_item(stack: &ItemStack) -> bool {
+    stack.item().has_tag(&ItemTag::BEACON_PAYMENT_ITEMS)
+}
+
+/// A synchronized beacon payment container.
+pub type SyncBeaconContainer = Arc<SyncMutex<BeaconContainer>>;
+
+/// The payment slot of a beacon menu.
+///
+/// Based on the anonymous `SimpleContainer` in Java's `BeaconMenu`.
+pub struct BeaconContainer {
+    item: ItemStack,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `BeaconContainer`.
+unsafe impl DowncastType for BeaconContainer {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:container/beacon");
+}
+
+impl BeaconContainer {
+    /// Creates an empty beacon payment container.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            item: ItemStack::empty(),
+        }
+    }
+}
+
+impl Default for BeaconContainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Container for BeaconContainer {
+    fn get_container_size(&self) -> usize {
+        1
+    }
+
+    fn get_item(&self, _slot: usize) -> &ItemStack {
+        &self.item
+    }
+
+    fn get_item_mut(&mut self, _slot: usize) -> &mut ItemStack {
+        &mut self.item
+    }
+
+    fn set_item(&mut self, _slot: usize, stack: ItemStack) {
+        self.item = stack;
+    }
+
+    fn get_max_stack_size(&self) -> i32 {
+        1
+    }
+
+    fn set_changed(&mut self) {}
+
+    fn can_place_item(&self, _slot: usize, stack: &ItemStack) -> bool {
+        is_payment_item(stack)
+    }
+}
+
+/// The beacon menu.
+///
+/// Based on Java's `BeaconMenu`.
+pub struct BeaconMenu {
+    behavior: MenuBehavior,
+    /// The payment slot.
+    payment: SyncBeaconContainer,
+    /// The position of the beacon block.
+    block_pos: BlockPos,
+    /// The beacon the data slots mirror.
+    beacon: Option<SharedBlockEntity>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `BeaconMenu`.
+unsafe impl DowncastType for BeaconMenu {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:menu/beacon");
+}
+
+impl BeaconMenu {
+    /// Creates a new beacon menu.
+    #[must_use]
+    pub fn new(
+        inventory: SyncPlayerInv,
+        container_id: u8,
+        block_pos: BlockPos,
+        beacon: Option<SharedBlockEntity>,
+    ) -> Self {
+        let mut menu_slots = Vec::with_capacity(slots::TOTAL_SLOTS);
+
+        let payment: SyncBeaconContainer = Arc::new(SyncMutex::new(BeaconContainer::new()));
+
+        menu_slots.push(SlotType::BeaconPayment(BeaconPaymentSlot::new(
+            ContainerRef::from(Arc::clone(&payment)),
+            slots::PAYMENT_SLOT,
+        )));
+
+        add_standard_inventory_slots(&mut menu_slots, &inventory);
+
+        let mut behavior =
+            MenuBehavior::new(menu_slots, container_id, Some(&vanilla_menu_types::BEACON));
+        behavior.add_data_slots(DATA_COUNT);
+
+        let mut menu = Self {
+            behavior,
+            payment,
+            block_pos,
+            beacon,
+        };
+        menu.update_data_slots();
+        menu
+    }
+
+    /// Returns a reference to the payment container.
+    #[must_use]
+    pub const fn payment(&self) -> &SyncBeaconContainer {
+        &self.payment
+    }
+
+    /// Returns the position of the beacon block.
+    #[must_use]
+    pub const fn block_pos(&self) -> BlockPos {
+        self.block_pos
+    }
+
+    /// Sets the beacon powers, consuming the payment item.
+    ///
+    /// The effects are mob effect registry ids. Nothing happens without a
+    /// payment item.
+    ///
+    /// Based on Java's `BeaconMenu::updateEffects`.
+    pub fn update_effects(&self, player: &Player, primary: Option<i32>, secondary: Option<i32>) {
+        let mut guard = self.behavior.lock_all_containers();
+        let payment_slot = &self.behavior.slots[slots::PAYMENT_SLOT];
+        if !payment_slot.has_item(&guard) {
+            return;
+        }
+        let Some(beacon) = &self.beacon else {
+            return;
+        };
+
+        {
+            let mut beacon = beacon.lock();
+            let Some(beacon) = beacon.downcast_mut::<BeaconBlockEntity>() else {
+                return;
+            };
+            beacon.set_primary_power(&player.get_world(), decode_effect(primary));
+            beacon.set_secondary_power(decode_effect(secondary));
+            beacon.set_changed();
+        }
+        payment_slot.remove(&mut guard, 1);
+    }
+}
+
+/// Looks up a mob effect by registry id.
+fn decode_effect(id: Option<i32>) -> Option<MobEffectRef> {
+    let id = usize::try_from(id?).ok()?;
+    REGISTRY.mob_effects.by_id(id)
+}
+
+impl Menu for BeaconMenu {
+    fn behavior(&self) -> &MenuBehavior {
+        &self.behavior
+    }
+
+    fn behavior_mut(&mut self) -> &mut MenuBehavior {
+        &mut self.behavior
+    }
+
+    /// Handles shift-click (quick move) for a slot.
+    ///
+    /// Based on Java's `BeaconMenu::quickMoveStack`:
+    /// - Payment slot (0) -> inventory (1-37), hotbar first
+    /// - A single payment item -> payment slot (0) if it is empty
+    /// - Otherwise between main inventory and hotbar
+    fn quick_move_stack(
+        &mut self,
+        guard: &mut ContainerLockGuard,
+        slot_index: usize,
+        player: &Player,
+    ) -> ItemStack {
+        if slot_index >= self.behavior.slots.len() {
+            return ItemStack::empty();
+        }
+
+        let mut stack_mut = self.behavior.slots[slot_index].get_item(guard).clone();
+        if stack_mut.is_empty() {
+            return ItemStack::empty();
+        }
+
+        let clicked = stack_mut.clone();
+        let payment_slot = &self.behavior.slots[slots::PAYMENT_SLOT];
+
+        let moved = if slot_index == slots::PAYMENT_SLOT {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::INV_SLOT_START,
+                slots::USE_ROW_SLOT_END,
+                true,
+            )
+        } else if !payment_slot.has_item(guard)
+            && payment_slot.may_place(&stack_mut)
+            && stack_mut.count() == 1
+        {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::PAYMENT_SLOT,
+                slots::PAYMENT_SLOT + 1,
+                false,
+            )
+        } else if slot_index < slots::INV_SLOT_END {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::USE_ROW_SLOT_START,
+                slots::USE_ROW_SLOT_END,
+                false,
+            )
+        } else {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::INV_SLOT_START,
+                slots::INV_SLOT_END,
+                false,
+            )
+        };
+
+        if !moved {
+            return ItemStack::empty();
+        }
+
+        let slot = &self.behavior.slots[slot_index];
+        if stack_mut.is_empty() {
+            slot.set_by_player(guard, ItemStack::empty(), &clicked);
+        } else {
+            slot.set_item(guard, stack_mut.clone());
+        }
+        slot.set_changed(guard);
+
+        if stack_mut.count() == clicked.count() {
+            return ItemStack::empty();
+        }
+
+        if let Some(remainder) = slot.on_take(guard, &stack_mut, player) {
+            player.add_item_or_drop_with_guard(guard, remainder);
+        }
+
+        clicked
+    }
+
+    /// Returns true if the beacon is still there and the player is within range.
+    ///
+    /// Based on Java's `BeaconMenu::stillValid`.
+    fn still_valid(&self, player: &Player) -> bool {
+        let world = player.get_world();
+        world.get_block_state(self.block_pos).get_block() == &vanilla_blocks::BEACON
+            && player.is_within_block_interaction_range_with_buffer(self.block_pos, 4.0)
+    }
+
+    /// Returns the carried item and drops the payment item.
+    ///
+    /// Based on Java's `BeaconMenu::removed`.
+    fn removed(&mut self, player: &Player) {
+        let carried = mem::take(&mut self.behavior.carried);
+        if !carried.is_empty() {
+            player.add_item_or_drop(carried);
+        }
+
+        let payment = self
+            .payment
+            .lock()
+            .remove_item_no_update(slots::PAYMENT_SLOT);
+        let _ = player.drop_item(payment, false, false);
+    }
+
+    fn update_data_slots(&mut self) {
+        let Some(beacon) = &self.beacon else {
+            return;
+        };
+        let (levels, primary, secondary) = {
+            let mut beacon = beacon.lock();
+            let Some(beacon) = beacon.downcast_mut::<BeaconBlockEntity>() else {
+                return;
+            };
+            (
+                beacon.levels(),
+                encode_beacon_effect(beacon.primary_power()),
+                encode_beacon_effect(beacon.secondary_power()),
+            )
+        };
+        self.behavior.set_data(LEVELS_DATA_SLOT, levels as i16);
+        self.behavior.set_data(PRIMARY_DATA_SLOT, primary as i16);
+        self.behavior
+            .set_data(SECONDARY_DATA_SLOT, secondary as i16);
+    }
+}
+
+impl MenuInstance for BeaconMenu {
+    fn menu_type(&self) -> MenuTypeRef {
+        &vanilla_menu_types::BEACON
+    }
+
+    fn container_id(&self) -> u8 {
+        self.behavior.container_id
+    }
+}
+
+/// Provider for creating a beacon menu.
+pub struct BeaconMenuProvider {
+    inventory: SyncPlayerInv,
+    pos: BlockPos,
+    title: TextComponent,
+}
+
+impl BeaconMenuProvider {
+    /// Creates a new beacon menu provider.
+    #[must_use]
+    pub const fn new(inventory: SyncPlayerInv, pos: BlockPos, title: TextComponent) -> Self {
+        Self {
+            inventory,
+            pos,
+            title,
+        }
+    }
+}
+
+impl MenuProvider for BeaconMenuProvider {
+    fn title(&self) -> TextComponent {
+        self.title.clone()
+    }
+
+    fn create(&self, container_id: u8, player: &Player) -> Box<dyn MenuInstance> {
+        Box::new(BeaconMenu::new(
+            self.inventory.clone(),
+            container_id,
+            self.pos,
+            player.get_world().get_block_entity(self.pos),
+        ))
+    }
+}
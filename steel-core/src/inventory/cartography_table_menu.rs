@@ -0,0 +1,454 @@
+//! The cartography table menu.
+//!
+//! Slot layout (39 total):
+//! - Slot 0: Filled map
+//! - Slot 1: Paper, empty map or glass pane
+//! - Slot 2: Result
+//! - Slots 3-29: Main inventory (27 slots)
+//! - Slots 30-38: Hotbar (9 slots)
+
+use std::{mem, sync::Arc};
+
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::data_components::components::MapPostProcessing;
+use steel_registry::data_components::vanilla_components::{MAP_ID, MAP_POST_PROCESSING};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::menu_type::MenuTypeRef;
+use steel_registry::{sound_events, vanilla_blocks, vanilla_items, vanilla_menu_types};
+use steel_utils::locks::SyncMutex;
+use steel_utils::{BlockPos, DowncastType, DowncastTypeKey, translations};
+use text_components::TextComponent;
+
+use crate::inventory::{
+    SyncPlayerInv,
+    container::Container,
+    lock::{ContainerId, ContainerLockGuard, ContainerRef},
+    menu::{Menu, MenuBehavior},
+    menu_provider::{MenuInstance, MenuProvider},
+    slot::{CartographyResultSlot, CartographySlot, Slot, SlotType, add_standard_inventory_slots},
+};
+use crate::map::{MAX_SCALE, MapStorage, apply_post_processing};
+use crate::player::Player;
+
+/// Slot indices for the cartography table menu.
+pub mod slots {
+    /// Slot index for the filled map (slot 0).
+    pub const MAP_SLOT: usize = 0;
+    /// Slot index for the paper, empty map or glass pane (slot 1).
+    pub const ADDITIONAL_SLOT: usize = 1;
+    /// Slot index for the result (slot 2).
+    pub const RESULT_SLOT: usize = 2;
+    /// Start of main inventory (slot 3).
+    pub const INV_SLOT_START: usize = 3;
+    /// End of main inventory / start of hotbar (slot 30).
+    pub const INV_SLOT_END: usize = 30;
+    /// Start of hotbar (slot 30).
+    pub const USE_ROW_SLOT_START: usize = 30;
+    /// End of hotbar (slot 39, exclusive).
+    pub const USE_ROW_SLOT_END: usize = 39;
+    /// Total number of slots in the cartography table menu.
+    pub const TOTAL_SLOTS: usize = 39;
+}
+
+/// A synchronized cartography table container.
+pub type SyncCartographyContainer = Arc<SyncMutex<CartographyContainer>>;
+
+/// The input and result slots of a cartography table menu.
+///
+/// Based on the anonymous containers in Java's `CartographyTableMenu`; the
+/// result is recomputed whenever an input changes.
+pub struct CartographyContainer {
+    items: [ItemStack; 3],
+    /// The maps of the domain the table is in.
+    maps: Option<Arc<MapStorage>>,
+    /// Game time the take sound last played, so shift-clicking plays it once.
+    last_sound_time: i64,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `CartographyContainer`.
+unsafe impl DowncastType for CartographyContainer {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:container/cartography");
+}
+
+impl CartographyContainer {
+    /// Creates an empty cartography table container.
+    #[must_use]
+    pub fn new(maps: Option<Arc<MapStorage>>) -> Self {
+        Self {
+            items: Default::default(),
+            maps,
+            last_sound_time: 0,
+        }
+    }
+
+    const fn is_input_slot(slot: usize) -> bool {
+        slot == slots::MAP_SLOT || slot == slots::ADDITIONAL_SLOT
+    }
+
+    /// Applies the pending operation of `stack` as it leaves the result slot.
+    pub fn finish_result(&self, stack: &mut ItemStack) {
+        if let Some(maps) = &self.maps {
+            apply_post_processing(maps, stack);
+        }
+    }
+
+    /// Returns whether the take sound should play at `game_time`, recording it.
+    pub const fn take_sound_due(&mut self, game_time: i64) -> bool {
+        if self.last_sound_time == game_time {
+            return false;
+        }
+        self.last_sound_time = game_time;
+        true
+    }
+
+    /// Recomputes the result from the inputs.
+    ///
+    /// Based on Java's `CartographyTableMenu::slotsChanged` and `setupResultSlot`.
+    fn update_result(&mut self) {
+        let [map, additional, result] = &mut self.items;
+        if map.is_empty() || additional.is_empty() {
+            *result = ItemStack::empty();
+            return;
+        }
+        let Some((_, data)) = self.maps.as_ref().and_then(|maps| maps.get_for_item(map)) else {
+            return;
+        };
+        let (locked, scale) = {
+            let data = data.lock();
+            (data.locked, data.scale)
+        };
+        let new_result = if additional.is(&vanilla_items::PAPER) && !locked && scale < MAX_SCALE {
+            let mut scaled = map.copy_with_count(1);
+            scaled.set(MAP_POST_PROCESSING, MapPostProcessing::Scale);
+            scaled
+        } else if additional.is(&vanilla_items::GLASS_PANE) && !locked {
+            let mut locked = map.copy_with_count(1);
+            locked.set(MAP_POST_PROCESSING, MapPostProcessing::Lock);
+            locked
+        } else if additional.is(&vanilla_items::MAP) {
+            map.copy_with_count(2)
+        } else {
+            *result = ItemStack::empty();
+            return;
+        };
+        if !ItemStack::matches(&new_result, result) {
+            *result = new_result;
+        }
+    }
+}
+
+impl Container for CartographyContainer {
+    fn get_container_size(&self) -> usize {
+        self.items.len()
+    }
+
+    fn get_item(&self, slot: usize) -> &ItemStack {
+        &self.items[slot]
+    }
+
+    fn get_item_mut(&mut self, slot: usize) -> &mut ItemStack {
+        &mut self.items[slot]
+    }
+
+    fn set_item(&mut self, slot: usize, stack: ItemStack) {
+        self.items[slot] = stack;
+        if Self::is_input_slot(slot) {
+            self.update_result();
+        }
+    }
+
+    /// The result slot always hands out its whole stack.
+    fn remove_item(&mut self, slot: usize, count: i32) -> ItemStack {
+        let item = &mut self.items[slot];
+        if slot == slots::RESULT_SLOT && !item.is_empty() {
+            return mem::take(item);
+        }
+        if item.is_empty() || count <= 0 {
+            return ItemStack::empty();
+        }
+
+        let removed = item.split(count);
+        if !removed.is_empty() && Self::is_input_slot(slot) {
+            self.update_result();
+        }
+        removed
+    }
+
+    fn set_changed(&mut self) {
+        self.update_result();
+    }
+}
+
+/// The cartography table menu.
+///
+/// Based on Java's `CartographyTableMenu`.
+pub struct CartographyTableMenu {
+    behavior: MenuBehavior,
+    /// The input and result slots.
+    container: SyncCartographyContainer,
+    /// The position of the cartography table block.
+    block_pos: BlockPos,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `CartographyTableMenu`.
+unsafe impl DowncastType for CartographyTableMenu {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:menu/cartography_table");
+}
+
+impl CartographyTableMenu {
+    /// Creates a new cartography table menu.
+    #[must_use]
+    pub fn new(
+        inventory: SyncPlayerInv,
+        container_id: u8,
+        block_pos: BlockPos,
+        maps: Option<Arc<MapStorage>>,
+    ) -> Self {
+        let mut menu_slots = Vec::with_capacity(slots::TOTAL_SLOTS);
+
+        let container: SyncCartographyContainer =
+            Arc::new(SyncMutex::new(CartographyContainer::new(maps)));
+
+        menu_slots.push(SlotType::Cartography(CartographySlot::new(
+            ContainerRef::from(Arc::clone(&container)),
+            slots::MAP_SLOT,
+        )));
+        menu_slots.push(SlotType::Cartography(CartographySlot::new(
+            ContainerRef::from(Arc::clone(&container)),
+            slots::ADDITIONAL_SLOT,
+        )));
+        menu_slots.push(SlotType::CartographyResult(CartographyResultSlot::new(
+            Arc::clone(&container),
+            block_pos,
+        )));
+
+        add_standard_inventory_slots(&mut menu_slots, &inventory);
+
+        Self {
+            behavior: MenuBehavior::new(
+                menu_slots,
+                container_id,
+                Some(&vanilla_menu_types::CARTOGRAPHY_TABLE),
+            ),
+            container,
+            block_pos,
+        }
+    }
+
+    /// Returns a reference to the input and result container.
+    #[must_use]
+    pub const fn container(&self) -> &SyncCartographyContainer {
+        &self.container
+    }
+
+    /// Returns the position of the cartography table block.
+    #[must_use]
+    pub const fn block_pos(&self) -> BlockPos {
+        self.block_pos
+    }
+}
+
+impl Menu for CartographyTableMenu {
+    fn behavior(&self) -> &MenuBehavior {
+        &self.behavior
+    }
+
+    fn behavior_mut(&mut self) -> &mut MenuBehavior {
+        &mut self.behavior
+    }
+
+    /// Handles shift-click (quick move) for a slot.
+    ///
+    /// Based on Java's `CartographyTableMenu::quickMoveStack`:
+    /// - Result slot (2) -> inventory (3-39), hotbar first
+    /// - Input slots (0-1) -> inventory (3-39)
+    /// - Filled maps -> map slot (0)
+    /// - Paper, empty maps and glass panes -> additional slot (1)
+    /// - Otherwise between main inventory and hotbar
+    fn quick_move_stack(
+        &mut self,
+        guard: &mut ContainerLockGuard,
+        slot_index: usize,
+        player: &Player,
+    ) -> ItemStack {
+        if slot_index >= self.behavior.slots.len() {
+            return ItemStack::empty();
+        }
+
+        let mut stack_mut = self.behavior.slots[slot_index].get_item(guard).clone();
+        if stack_mut.is_empty() {
+            return ItemStack::empty();
+        }
+
+        let clicked = stack_mut.clone();
+
+        let moved = if slot_index == slots::RESULT_SLOT {
+            if let Some(container) =
+                guard.get_typed_mut::<CartographyContainer>(ContainerId::from_arc(&self.container))
+            {
+                container.finish_result(&mut stack_mut);
+            }
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::INV_SLOT_START,
+                slots::USE_ROW_SLOT_END,
+                true,
+            )
+        } else if slot_index == slots::MAP_SLOT || slot_index == slots::ADDITIONAL_SLOT {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::INV_SLOT_START,
+                slots::USE_ROW_SLOT_END,
+                false,
+            )
+        } else if stack_mut.has(MAP_ID) {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::MAP_SLOT,
+                slots::MAP_SLOT + 1,
+                false,
+            )
+        } else if is_additional_item(&stack_mut) {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::ADDITIONAL_SLOT,
+                slots::ADDITIONAL_SLOT + 1,
+                false,
+            )
+        } else if slot_index < slots::INV_SLOT_END {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::USE_ROW_SLOT_START,
+                slots::USE_ROW_SLOT_END,
+                false,
+            )
+        } else {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::INV_SLOT_START,
+                slots::INV_SLOT_END,
+                false,
+            )
+        };
+
+        if !moved {
+            return ItemStack::empty();
+        }
+
+        let slot = &self.behavior.slots[slot_index];
+        if stack_mut.is_empty() {
+            slot.set_by_player(guard, ItemStack::empty(), &clicked);
+        } else {
+            slot.set_item(guard, stack_mut.clone());
+        }
+        slot.set_changed(guard);
+
+        if stack_mut.count() == clicked.count() {
+            return ItemStack::empty();
+        }
+
+        if let Some(remainder) = slot.on_take(guard, &stack_mut, player) {
+            player.add_item_or_drop_with_guard(guard, remainder);
+        }
+
+        clicked
+    }
+
+    /// Returns true if the table is still there and the player is within range.
+    ///
+    /// Based on Java's `CartographyTableMenu::stillValid`.
+    fn still_valid(&self, player: &Player) -> bool {
+        let world = player.get_world();
+        world.get_block_state(self.block_pos).get_block() == &vanilla_blocks::CARTOGRAPHY_TABLE
+            && player.is_within_block_interaction_range_with_buffer(self.block_pos, 4.0)
+    }
+
+    /// Returns the carried item and both inputs to the player.
+    ///
+    /// Based on Java's `CartographyTableMenu::removed`, which calls `clearContainer`.
+    fn removed(&mut self, player: &Player) {
+        let carried = mem::take(&mut self.behavior.carried);
+        if !carried.is_empty() {
+            player.add_item_or_drop(carried);
+        }
+
+        let items: Vec<ItemStack> = {
+            let mut container = self.container.lock();
+            container.remove_item_no_update(slots::RESULT_SLOT);
+            [slots::MAP_SLOT, slots::ADDITIONAL_SLOT]
+                .into_iter()
+                .map(|i| container.remove_item_no_update(i))
+                .filter(|item| !item.is_empty())
+                .collect()
+        };
+        for item in items {
+            player.add_item_or_drop(item);
+        }
+    }
+}
+
+impl MenuInstance for CartographyTableMenu {
+    fn menu_type(&self) -> MenuTypeRef {
+        &vanilla_menu_types::CARTOGRAPHY_TABLE
+    }
+
+    fn container_id(&self) -> u8 {
+        self.behavior.container_id
+    }
+}
+
+/// Returns whether `stack` goes in the additional slot.
+#[must_use]
+pub fn is_additional_item(stack: &ItemStack) -> bool {
+    stack.is(&vanilla_items::PAPER)
+        || stack.is(&vanilla_items::MAP)
+        || stack.is(&vanilla_items::GLASS_PANE)
+}
+
+/// Plays the take sound at the table.
+pub(crate) fn play_take_sound(player: &Player, pos: BlockPos) {
+    player.get_world().play_sound(
+        &sound_events::UI_CARTOGRAPHY_TABLE_TAKE_RESULT,
+        SoundSource::Blocks,
+        pos,
+        1.0,
+        1.0,
+        None,
+    );
+}
+
+/// Provider for creating a cartography table menu.
+pub struct CartographyTableMenuProvider {
+    inventory: SyncPlayerInv,
+    pos: BlockPos,
+}
+
+impl CartographyTableMenuProvider {
+    /// Creates a new cartography table menu provider.
+    #[must_use]
+    pub const fn new(inventory: SyncPlayerInv, pos: BlockPos) -> Self {
+        Self { inventory, pos }
+    }
+}
+
+impl MenuProvider for CartographyTableMenuProvider {
+    fn title(&self) -> TextComponent {
+        TextComponent::translated(translations::CONTAINER_CARTOGRAPHY_TABLE.msg())
+    }
+
+    fn create(&self, container_id: u8, player: &Player) -> Box<dyn MenuInstance> {
+        Box::new(CartographyTableMenu::new(
+            self.inventory.clone(),
+            container_id,
+            self.pos,
+            player.get_world().maps().cloned(),
+        ))
+    }
+}
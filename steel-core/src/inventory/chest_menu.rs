@@ -10,6 +10,7 @@ use std::mem;
 use steel_registry::item_stack::ItemStack;
 use steel_registry::menu_type::MenuTypeRef;
 use steel_registry::vanilla_menu_types;
+use steel_utils::{DowncastType, DowncastTypeKey};
 use text_components::TextComponent;
 
 use crate::inventory::{
@@ -73,12 +74,17 @@ pub mod slots {
 /// Based on Java's `ChestMenu`.
 pub struct ChestMenu {
     behavior: MenuBehavior,
-    /// Reference to the container (chest, barrel, etc.).
-    container: ContainerRef,
+    /// The backing containers, in slot order. Double chests have two halves.
+    containers: Vec<ContainerRef>,
     /// Number of rows in the container (1-6).
     rows: usize,
 }
 
+// SAFETY: This key is owned by Steel and uniquely identifies `ChestMenu`.
+unsafe impl DowncastType for ChestMenu {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:menu/chest");
+}
+
 impl ChestMenu {
     /// Creates a new chest menu with the specified number of rows.
     ///
@@ -120,11 +126,43 @@ impl ChestMenu {
                 container_id,
                 Some(Self::menu_type_for_rows(rows)),
             ),
-            container,
+            containers: vec![container],
             rows,
         }
     }
 
+    /// Creates a 6-row menu over the two halves of a double chest.
+    ///
+    /// `first` backs the top three rows, matching Java's `CompoundContainer`.
+    #[must_use]
+    pub fn double(
+        inventory: SyncPlayerInv,
+        container_id: u8,
+        first: ContainerRef,
+        second: ContainerRef,
+    ) -> Self {
+        let half_slots = slots::container_slot_count(3);
+        let mut menu_slots = Vec::with_capacity(slots::total_slots(6));
+
+        for container in [&first, &second] {
+            for i in 0..half_slots {
+                menu_slots.push(SlotType::Normal(NormalSlot::new(container.clone(), i)));
+            }
+        }
+
+        add_standard_inventory_slots(&mut menu_slots, &inventory);
+
+        Self {
+            behavior: MenuBehavior::new(
+                menu_slots,
+                container_id,
+                Some(Self::menu_type_for_rows(6)),
+            ),
+            containers: vec![first, second],
+            rows: 6,
+        }
+    }
+
     /// Creates a 1-row chest menu.
     #[must_use]
     pub fn one_row(inventory: SyncPlayerInv, container_id: u8, container: ContainerRef) -> Self {
@@ -184,10 +222,22 @@ impl ChestMenu {
         self.rows
     }
 
-    /// Returns a reference to the container.
+    /// Returns the backing containers in slot order.
     #[must_use]
-    pub const fn container(&self) -> &ContainerRef {
-        &self.container
+    pub fn containers(&self) -> &[ContainerRef] {
+        &self.containers
+    }
+
+    /// Notifies the backing containers that `player` opened this menu.
+    ///
+    /// Java's `ChestMenu` constructor calls `container.startOpen(player)`.
+    pub fn start_open(&self, player: &Player) {
+        let mut guard = self.behavior.lock_all_containers();
+        for container in &self.containers {
+            if let Some(container) = guard.get_mut(container.container_id()) {
+                container.start_open(player);
+            }
+        }
     }
 }
 
@@ -263,24 +313,32 @@ impl Menu for ChestMenu {
 
     /// Returns true if the container is still valid for interaction.
     ///
-    /// Delegates to the container's `still_valid` method.
+    /// Delegates to each container's `still_valid` method.
     fn still_valid(&self, player: &Player) -> bool {
         let guard = self.behavior.lock_all_containers();
-        guard
-            .get(self.container.container_id())
-            .is_some_and(|container| container.still_valid(player))
+        self.containers.iter().all(|container| {
+            guard
+                .get(container.container_id())
+                .is_some_and(|container| container.still_valid(player))
+        })
     }
 
     /// Called when the menu is closed.
     ///
-    /// Returns the carried item to the player inventory.
-    /// Note: Java's `ChestMenu::removed` also calls `container.stopOpen(player)`,
-    /// but we don't have that callback implemented yet.
+    /// Returns the carried item to the player inventory and calls
+    /// `stop_open` on the containers, like Java's `ChestMenu::removed`.
     fn removed(&mut self, player: &Player) {
         let carried = mem::take(&mut self.behavior.carried);
         if !carried.is_empty() {
             player.add_item_or_drop(carried);
         }
+
+        let mut guard = self.behavior.lock_all_containers();
+        for container in &self.containers {
+            if let Some(container) = guard.get_mut(container.container_id()) {
+                container.stop_open(player);
+            }
+        }
     }
 }
 
@@ -298,6 +356,8 @@ impl MenuInstance for ChestMenu {
 pub struct ChestMenuProvider {
     inventory: SyncPlayerInv,
     container: ContainerRef,
+    /// The second half of a double chest, if any.
+    second: Option<ContainerRef>,
     rows: usize,
     title: TextComponent,
 }
@@ -320,6 +380,7 @@ impl ChestMenuProvider {
         Self {
             inventory,
             container,
+            second: None,
             rows,
             title,
         }
@@ -344,6 +405,23 @@ impl ChestMenuProvider {
     ) -> Self {
         Self::new(inventory, container, 6, title)
     }
+
+    /// Creates a provider for a double chest made of two 27-slot halves.
+    #[must_use]
+    pub const fn double(
+        inventory: SyncPlayerInv,
+        first: ContainerRef,
+        second: ContainerRef,
+        title: TextComponent,
+    ) -> Self {
+        Self {
+            inventory,
+            container: first,
+            second: Some(second),
+            rows: 6,
+            title,
+        }
+    }
 }
 
 impl MenuProvider for ChestMenuProvider {
@@ -351,12 +429,23 @@ impl MenuProvider for ChestMenuProvider {
         self.title.clone()
     }
 
-    fn create(&self, container_id: u8) -> Box<dyn MenuInstance> {
-        Box::new(ChestMenu::new(
-            self.inventory.clone(),
-            container_id,
-            self.container.clone(),
-            self.rows,
-        ))
+    fn create(&self, container_id: u8, player: &Player) -> Box<dyn MenuInstance> {
+        let menu = if let Some(second) = &self.second {
+            ChestMenu::double(
+                self.inventory.clone(),
+                container_id,
+                self.container.clone(),
+                second.clone(),
+            )
+        } else {
+            ChestMenu::new(
+                self.inventory.clone(),
+                container_id,
+                self.container.clone(),
+                self.rows,
+            )
+        };
+        menu.start_open(player);
+        Box::new(menu)
     }
 }
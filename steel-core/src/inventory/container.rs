@@ -111,6 +111,16 @@ pub trait Container: ErasedType + Send + Sync {
         true
     }
 
+    /// Called when a player opens a menu backed by this container.
+    ///
+    /// Based on Java's `Container.startOpen(Player)`.
+    fn start_open(&mut self, _player: &Player) {}
+
+    /// Called when a player closes a menu backed by this container.
+    ///
+    /// Based on Java's `Container.stopOpen(Player)`.
+    fn stop_open(&mut self, _player: &Player) {}
+
     /// Clears all items from this container.
     fn clear_content(&mut self) -> i32 {
         let mut count = 0;
@@ -333,18 +343,31 @@ pub fn with_indices<const N: usize>(
 /// Signal strength from 0 to 15
 #[must_use]
 pub fn calculate_redstone_signal_from_container(container: &dyn Container) -> i32 {
-    let size = container.get_container_size();
+    calculate_redstone_signal_from_containers(&[container])
+}
+
+/// Calculates the comparator signal for containers read as one, like a double chest.
+///
+/// Mirrors vanilla passing a `CompoundContainer` to `getRedstoneSignalFromContainer`.
+#[must_use]
+pub fn calculate_redstone_signal_from_containers(containers: &[&dyn Container]) -> i32 {
+    let size: usize = containers
+        .iter()
+        .map(|container| container.get_container_size())
+        .sum();
     if size == 0 {
         return 0;
     }
 
     let mut total_percent: f32 = 0.0;
 
-    for i in 0..size {
-        let item = container.get_item(i);
-        if !item.is_empty() {
-            let max_stack = container.get_max_stack_size_for_item(item);
-            total_percent += item.count() as f32 / max_stack as f32;
+    for container in containers {
+        for i in 0..container.get_container_size() {
+            let item = container.get_item(i);
+            if !item.is_empty() {
+                let max_stack = container.get_max_stack_size_for_item(item);
+                total_percent += item.count() as f32 / max_stack as f32;
+            }
         }
     }
 
@@ -14,7 +14,7 @@ use steel_registry::menu_type::MenuTypeRef;
 use steel_registry::vanilla_blocks;
 use steel_registry::vanilla_menu_types;
 use steel_utils::locks::SyncMutex;
-use steel_utils::{BlockPos, translations};
+use steel_utils::{BlockPos, DowncastType, DowncastTypeKey, translations};
 use text_components::TextComponent;
 
 use crate::inventory::{
@@ -64,6 +64,11 @@ pub struct CraftingMenu {
     block_pos: BlockPos,
 }
 
+// SAFETY: This key is owned by Steel and uniquely identifies `CraftingMenu`.
+unsafe impl DowncastType for CraftingMenu {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:menu/crafting");
+}
+
 impl CraftingMenu {
     /// Creates a new crafting menu for a player.
     ///
@@ -365,7 +370,7 @@ impl MenuProvider for CraftingMenuProvider {
         TextComponent::translated(translations::CONTAINER_CRAFTING.msg())
     }
 
-    fn create(&self, container_id: u8) -> Box<dyn MenuInstance> {
+    fn create(&self, container_id: u8, _player: &Player) -> Box<dyn MenuInstance> {
         Box::new(CraftingMenu::new(
             self.inventory.clone(),
             container_id,
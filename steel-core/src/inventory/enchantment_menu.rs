@@ -0,0 +1,554 @@
+//! The enchanting table menu.
+//!
+//! Slot layout (38 total):
+//! - Slot 0: Item to enchant
+//! - Slot 1: Lapis lazuli
+//! - Slots 2-28: Main inventory (27 slots)
+//! - Slots 29-37: Hotbar (9 slots)
+//!
+//! Data slots 0-2 hold the level costs, 3 the enchantment seed, 4-6 the hinted
+//! enchantment ids and 7-9 the hinted levels.
+
+use std::{mem, sync::Arc};
+
+use steel_protocol::packets::game::SoundSource;
+use steel_registry::blocks::block_state_ext::BlockStateExt;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::menu_type::MenuTypeRef;
+use steel_registry::stat::{CustomStat, Stat};
+use steel_registry::vanilla_enchantment_tags::EnchantmentTag;
+use steel_registry::{
+    REGISTRY, RegistryEntry, TaggedRegistryExt, sound_events, vanilla_blocks, vanilla_items,
+    vanilla_menu_types,
+};
+use steel_utils::locks::SyncMutex;
+use steel_utils::random::{Random, legacy_random::LegacyRandom};
+use steel_utils::{BlockPos, DowncastType, DowncastTypeKey};
+use text_components::TextComponent;
+
+use crate::behavior::blocks::{bookshelf_offsets, is_valid_book_shelf};
+use crate::enchantment_helper::{EnchantmentInstance, get_enchantment_cost, select_enchantment};
+use crate::inventory::{
+    SyncPlayerInv,
+    container::Container,
+    lock::{ContainerLockGuard, ContainerRef},
+    menu::{Menu, MenuBehavior},
+    menu_provider::{MenuInstance, MenuProvider},
+    slot::{EnchantmentSlot, Slot, SlotType, add_standard_inventory_slots},
+};
+use crate::player::Player;
+
+/// Slot indices for the enchanting table menu.
+pub mod slots {
+    /// Slot index for the item being enchanted (slot 0).
+    pub const ITEM_SLOT: usize = 0;
+    /// Slot index for the lapis lazuli (slot 1).
+    pub const LAPIS_SLOT: usize = 1;
+    /// Start of main inventory (slot 2).
+    pub const INV_SLOT_START: usize = 2;
+    /// End of hotbar (slot 38, exclusive).
+    pub const HOTBAR_SLOT_END: usize = 38;
+    /// Total number of slots in the enchanting table menu.
+    pub const TOTAL_SLOTS: usize = 38;
+}
+
+/// Number of enchanting options offered at once.
+const OPTION_COUNT: usize = 3;
+/// Data slot holding the enchantment seed, right after the three costs.
+const SEED_DATA_SLOT: usize = 3;
+/// First data slot of the hinted enchantment ids.
+const ENCHANT_CLUE_DATA_SLOT: usize = 4;
+/// First data slot of the hinted enchantment levels.
+const LEVEL_CLUE_DATA_SLOT: usize = 7;
+
+/// A synchronized enchanting table input container.
+pub type SyncEnchantmentContainer = Arc<SyncMutex<EnchantmentContainer>>;
+
+/// The item and lapis slots of an enchanting table menu.
+///
+/// Based on the anonymous `SimpleContainer` in Java's `EnchantmentMenu`, which
+/// calls `slotsChanged` whenever it changes.
+pub struct EnchantmentContainer {
+    items: [ItemStack; 2],
+    /// Whether the inputs changed since the offers were last computed.
+    changed: bool,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `EnchantmentContainer`.
+unsafe impl DowncastType for EnchantmentContainer {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:container/enchantment");
+}
+
+impl EnchantmentContainer {
+    /// Creates an empty enchanting table input container.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            items: Default::default(),
+            changed: false,
+        }
+    }
+
+    /// Returns whether the inputs changed, clearing the flag.
+    pub const fn take_changed(&mut self) -> bool {
+        mem::replace(&mut self.changed, false)
+    }
+}
+
+impl Default for EnchantmentContainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Container for EnchantmentContainer {
+    fn get_container_size(&self) -> usize {
+        self.items.len()
+    }
+
+    fn get_item(&self, slot: usize) -> &ItemStack {
+        &self.items[slot]
+    }
+
+    fn get_item_mut(&mut self, slot: usize) -> &mut ItemStack {
+        &mut self.items[slot]
+    }
+
+    fn set_item(&mut self, slot: usize, stack: ItemStack) {
+        self.items[slot] = stack;
+        self.set_changed();
+    }
+
+    fn remove_item(&mut self, slot: usize, count: i32) -> ItemStack {
+        let item = &mut self.items[slot];
+        if item.is_empty() || count <= 0 {
+            return ItemStack::empty();
+        }
+        let removed = item.split(count);
+        if !removed.is_empty() {
+            self.set_changed();
+        }
+        removed
+    }
+
+    fn set_changed(&mut self) {
+        self.changed = true;
+    }
+}
+
+/// The enchanting table menu.
+///
+/// Based on Java's `EnchantmentMenu`.
+pub struct EnchantmentMenu {
+    behavior: MenuBehavior,
+    /// The item and lapis slots.
+    enchant_slots: SyncEnchantmentContainer,
+    /// The position of the enchanting table block.
+    block_pos: BlockPos,
+    /// Reseeded from `enchantment_seed` before every offer computation.
+    random: LegacyRandom,
+    /// The opening player's enchantment seed.
+    enchantment_seed: i32,
+    /// Level cost of each option, or 0 if the option is unavailable.
+    costs: [i32; OPTION_COUNT],
+    /// Registry id of the enchantment hinted for each option, or -1.
+    enchant_clue: [i32; OPTION_COUNT],
+    /// Level of the enchantment hinted for each option, or -1.
+    level_clue: [i32; OPTION_COUNT],
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `EnchantmentMenu`.
+unsafe impl DowncastType for EnchantmentMenu {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:menu/enchantment");
+}
+
+impl EnchantmentMenu {
+    /// Creates a new enchanting table menu.
+    #[must_use]
+    pub fn new(
+        inventory: SyncPlayerInv,
+        container_id: u8,
+        block_pos: BlockPos,
+        enchantment_seed: i32,
+    ) -> Self {
+        let mut menu_slots = Vec::with_capacity(slots::TOTAL_SLOTS);
+
+        let enchant_slots: SyncEnchantmentContainer =
+            Arc::new(SyncMutex::new(EnchantmentContainer::new()));
+
+        menu_slots.push(SlotType::Enchantment(EnchantmentSlot::new(
+            ContainerRef::from(Arc::clone(&enchant_slots)),
+            slots::ITEM_SLOT,
+        )));
+        menu_slots.push(SlotType::Enchantment(EnchantmentSlot::new(
+            ContainerRef::from(Arc::clone(&enchant_slots)),
+            slots::LAPIS_SLOT,
+        )));
+
+        add_standard_inventory_slots(&mut menu_slots, &inventory);
+
+        let mut behavior = MenuBehavior::new(
+            menu_slots,
+            container_id,
+            Some(&vanilla_menu_types::ENCHANTMENT),
+        );
+        behavior.add_data_slots(LEVEL_CLUE_DATA_SLOT + OPTION_COUNT);
+
+        let mut menu = Self {
+            behavior,
+            enchant_slots,
+            block_pos,
+            random: LegacyRandom::from_seed(rand::random()),
+            enchantment_seed,
+            costs: [0; OPTION_COUNT],
+            enchant_clue: [-1; OPTION_COUNT],
+            level_clue: [-1; OPTION_COUNT],
+        };
+        menu.sync_data_slots();
+        menu
+    }
+
+    /// Returns a reference to the item and lapis container.
+    #[must_use]
+    pub const fn enchant_slots(&self) -> &SyncEnchantmentContainer {
+        &self.enchant_slots
+    }
+
+    /// Returns the position of the enchanting table block.
+    #[must_use]
+    pub const fn block_pos(&self) -> BlockPos {
+        self.block_pos
+    }
+
+    /// Returns the level cost of each option, 0 if unavailable.
+    #[must_use]
+    pub const fn costs(&self) -> [i32; OPTION_COUNT] {
+        self.costs
+    }
+
+    /// Copies the offer state into the synced data slots.
+    fn sync_data_slots(&mut self) {
+        for i in 0..OPTION_COUNT {
+            self.behavior.set_data(i, self.costs[i] as i16);
+            self.behavior
+                .set_data(ENCHANT_CLUE_DATA_SLOT + i, self.enchant_clue[i] as i16);
+            self.behavior
+                .set_data(LEVEL_CLUE_DATA_SLOT + i, self.level_clue[i] as i16);
+        }
+        // Vanilla syncs the seed through a short data slot too; the client only
+        // uses it for the glyph text.
+        self.behavior
+            .set_data(SEED_DATA_SLOT, self.enchantment_seed as i16);
+    }
+
+    /// Recomputes the three offers for the item in the item slot.
+    ///
+    /// Based on Java's `EnchantmentMenu::slotsChanged`.
+    fn update_offers(&mut self, player: &Player) {
+        let item = self.enchant_slots.lock().get_item(slots::ITEM_SLOT).clone();
+
+        self.enchant_clue = [-1; OPTION_COUNT];
+        self.level_clue = [-1; OPTION_COUNT];
+        if item.is_empty() || !item.is_enchantable() {
+            self.costs = [0; OPTION_COUNT];
+            return;
+        }
+
+        let world = player.get_world();
+        let bookcases = bookshelf_offsets()
+            .filter(|&offset| is_valid_book_shelf(&world, self.block_pos, offset))
+            .count() as i32;
+
+        self.random.set_seed(i64::from(self.enchantment_seed));
+        for i in 0..OPTION_COUNT {
+            self.costs[i] = get_enchantment_cost(&mut self.random, i, bookcases, &item);
+            if self.costs[i] < i as i32 + 1 {
+                self.costs[i] = 0;
+            }
+        }
+
+        for i in 0..OPTION_COUNT {
+            if self.costs[i] <= 0 {
+                continue;
+            }
+            let enchantments = self.get_enchantment_list(&item, i, self.costs[i]);
+            if enchantments.is_empty() {
+                continue;
+            }
+            let index = self.random.next_i32_bounded(enchantments.len() as i32) as usize;
+            let clue = enchantments[index];
+            self.enchant_clue[i] = clue.enchantment.try_id().map_or(-1, |id| id as i32);
+            self.level_clue[i] = clue.level as i32;
+        }
+    }
+
+    /// Rolls the enchantments option `slot` would apply.
+    ///
+    /// Based on Java's `EnchantmentMenu::getEnchantmentList`.
+    fn get_enchantment_list(
+        &mut self,
+        item: &ItemStack,
+        slot: usize,
+        enchantment_cost: i32,
+    ) -> Vec<EnchantmentInstance> {
+        self.random
+            .set_seed(i64::from(self.enchantment_seed.wrapping_add(slot as i32)));
+        let mut enchantments = select_enchantment(
+            &mut self.random,
+            item,
+            enchantment_cost,
+            REGISTRY
+                .enchantments
+                .iter_tag(&EnchantmentTag::IN_ENCHANTING_TABLE),
+        );
+        if item.is(&vanilla_items::BOOK) && enchantments.len() > 1 {
+            let index = self.random.next_i32_bounded(enchantments.len() as i32) as usize;
+            enchantments.remove(index);
+        }
+        enchantments
+    }
+}
+
+impl Menu for EnchantmentMenu {
+    fn behavior(&self) -> &MenuBehavior {
+        &self.behavior
+    }
+
+    fn behavior_mut(&mut self) -> &mut MenuBehavior {
+        &mut self.behavior
+    }
+
+    /// Handles shift-click (quick move) for a slot.
+    ///
+    /// Based on Java's `EnchantmentMenu::quickMoveStack`:
+    /// - Item and lapis slots (0-1) -> inventory (2-38), hotbar first
+    /// - Lapis from the inventory -> lapis slot (1)
+    /// - Anything else -> a single item into the empty item slot (0)
+    fn quick_move_stack(
+        &mut self,
+        guard: &mut ContainerLockGuard,
+        slot_index: usize,
+        _player: &Player,
+    ) -> ItemStack {
+        if slot_index >= self.behavior.slots.len() {
+            return ItemStack::empty();
+        }
+
+        let stack = self.behavior.slots[slot_index].get_item(guard).clone();
+        if stack.is_empty() {
+            return ItemStack::empty();
+        }
+
+        let clicked = stack.clone();
+        let mut stack_mut = stack;
+
+        let moved = if slot_index == slots::ITEM_SLOT || slot_index == slots::LAPIS_SLOT {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::INV_SLOT_START,
+                slots::HOTBAR_SLOT_END,
+                true,
+            )
+        } else if stack_mut.is(&vanilla_items::LAPIS_LAZULI) {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::LAPIS_SLOT,
+                slots::LAPIS_SLOT + 1,
+                true,
+            )
+        } else {
+            let item_slot = &self.behavior.slots[slots::ITEM_SLOT];
+            if item_slot.has_item(guard) || !item_slot.may_place(&stack_mut) {
+                return ItemStack::empty();
+            }
+            let single = stack_mut.copy_with_count(1);
+            stack_mut.shrink(1);
+            item_slot.set_by_player(guard, single, &ItemStack::empty());
+            true
+        };
+
+        if !moved {
+            return ItemStack::empty();
+        }
+
+        self.behavior.slots[slot_index].set_item(guard, stack_mut.clone());
+
+        if stack_mut.count == clicked.count {
+            return ItemStack::empty();
+        }
+
+        self.behavior.slots[slot_index].set_changed(guard);
+
+        clicked
+    }
+
+    /// Returns true if the table is still there and the player is within range.
+    ///
+    /// Based on Java's `EnchantmentMenu::stillValid`.
+    fn still_valid(&self, player: &Player) -> bool {
+        let world = player.get_world();
+        world.get_block_state(self.block_pos).get_block() == &vanilla_blocks::ENCHANTING_TABLE
+            && player.is_within_block_interaction_range_with_buffer(self.block_pos, 4.0)
+    }
+
+    /// Returns the carried item and both input slots to the player.
+    ///
+    /// Based on Java's `EnchantmentMenu::removed`, which calls `clearContainer`.
+    fn removed(&mut self, player: &Player) {
+        let carried = mem::take(&mut self.behavior.carried);
+        if !carried.is_empty() {
+            player.add_item_or_drop(carried);
+        }
+
+        let items: Vec<ItemStack> = {
+            let mut enchant_slots = self.enchant_slots.lock();
+            (0..enchant_slots.get_container_size())
+                .map(|i| enchant_slots.remove_item_no_update(i))
+                .filter(|item| !item.is_empty())
+                .collect()
+        };
+        for item in items {
+            player.add_item_or_drop(item);
+        }
+    }
+
+    /// Enchants the item with option `button_id`, spending levels and lapis.
+    ///
+    /// Based on Java's `EnchantmentMenu::clickMenuButton`.
+    fn click_menu_button(&mut self, player: &Player, button_id: i32) -> bool {
+        let Some(button) = usize::try_from(button_id)
+            .ok()
+            .filter(|&button| button < OPTION_COUNT)
+        else {
+            log::error!(
+                "{} pressed invalid button id: {button_id}",
+                player.gameprofile.name
+            );
+            return false;
+        };
+
+        let (item, mut currency) = {
+            let enchant_slots = self.enchant_slots.lock();
+            (
+                enchant_slots.get_item(slots::ITEM_SLOT).clone(),
+                enchant_slots.get_item(slots::LAPIS_SLOT).clone(),
+            )
+        };
+        let cost = button_id + 1;
+        let has_infinite_materials = player.has_infinite_materials();
+        if (currency.is_empty() || currency.count() < cost) && !has_infinite_materials {
+            return false;
+        }
+
+        let level = player.experience.lock().level();
+        if self.costs[button] <= 0
+            || item.is_empty()
+            || ((level < cost || level < self.costs[button]) && !has_infinite_materials)
+        {
+            return false;
+        }
+
+        let enchantments = self.get_enchantment_list(&item, button, self.costs[button]);
+        if enchantments.is_empty() {
+            return true;
+        }
+
+        player.on_enchantment_performed(cost);
+        let mut enchanted = if item.is(&vanilla_items::BOOK) {
+            item.transmute_copy(&vanilla_items::ENCHANTED_BOOK)
+        } else {
+            item
+        };
+        for enchantment in enchantments {
+            enchanted.upgrade_enchantment(enchantment.enchantment.key.clone(), enchantment.level);
+        }
+        if !has_infinite_materials {
+            currency.shrink(cost);
+        }
+
+        {
+            let mut enchant_slots = self.enchant_slots.lock();
+            enchant_slots.set_item(slots::ITEM_SLOT, enchanted);
+            enchant_slots.set_item(
+                slots::LAPIS_SLOT,
+                if currency.is_empty() {
+                    ItemStack::empty()
+                } else {
+                    currency
+                },
+            );
+        }
+        player.award_stat(Stat::custom(CustomStat::EnchantItem));
+        // TODO: Trigger the enchanted_item criterion
+
+        self.enchantment_seed = player.enchantment_seed();
+        self.slots_changed(player);
+
+        let pitch = rand::random::<f32>() * 0.1 + 0.9;
+        player.get_world().play_sound(
+            &sound_events::BLOCK_ENCHANTMENT_TABLE_USE,
+            SoundSource::Blocks,
+            self.block_pos,
+            1.0,
+            pitch,
+            None,
+        );
+
+        true
+    }
+
+    /// Recomputes the offers when the item or lapis slot changed.
+    fn slots_changed(&mut self, player: &Player) {
+        if !self.enchant_slots.lock().take_changed() {
+            return;
+        }
+        self.update_offers(player);
+        self.sync_data_slots();
+    }
+}
+
+impl MenuInstance for EnchantmentMenu {
+    fn menu_type(&self) -> MenuTypeRef {
+        &vanilla_menu_types::ENCHANTMENT
+    }
+
+    fn container_id(&self) -> u8 {
+        self.behavior.container_id
+    }
+}
+
+/// Provider for creating an enchanting table menu.
+pub struct EnchantmentMenuProvider {
+    inventory: SyncPlayerInv,
+    pos: BlockPos,
+    title: TextComponent,
+}
+
+impl EnchantmentMenuProvider {
+    /// Creates a new enchanting table menu provider.
+    #[must_use]
+    pub const fn new(inventory: SyncPlayerInv, pos: BlockPos, title: TextComponent) -> Self {
+        Self {
+            inventory,
+            pos,
+            title,
+        }
+    }
+}
+
+impl MenuProvider for EnchantmentMenuProvider {
+    fn title(&self) -> TextComponent {
+        self.title.clone()
+    }
+
+    fn create(&self, container_id: u8, player: &Player) -> Box<dyn MenuInstance> {
+        Box::new(EnchantmentMenu::new(
+            self.inventory.clone(),
+            container_id,
+            self.pos,
+            player.enchantment_seed(),
+        ))
+    }
+}
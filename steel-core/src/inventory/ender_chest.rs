@@ -0,0 +1,116 @@
+//! The per-player ender chest inventory.
+//!
+//! Every ender chest block opens the same 27 slots for a given player. The
+//! block the player used is kept as the active chest so its lid animates and
+//! the menu closes when the player walks away.
+
+use simdnbt::owned::{NbtList, NbtTag};
+use steel_registry::item_stack::ItemStack;
+use steel_utils::{Downcast as _, DowncastType, DowncastTypeKey};
+
+use crate::block_entity::SharedBlockEntity;
+use crate::block_entity::entities::EnderChestBlockEntity;
+use crate::inventory::container::Container;
+use crate::player::Player;
+
+/// Number of slots in an ender chest (3 rows of 9).
+pub const ENDER_CHEST_SLOTS: usize = 27;
+
+/// A player's ender chest items.
+///
+/// Based on Java's `PlayerEnderChestContainer`.
+pub struct PlayerEnderChestContainer {
+    items: Vec<ItemStack>,
+    /// The ender chest block the player is viewing this through, if any.
+    active_chest: Option<SharedBlockEntity>,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `PlayerEnderChestContainer`.
+unsafe impl DowncastType for PlayerEnderChestContainer {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:container/ender_chest");
+}
+
+impl Default for PlayerEnderChestContainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlayerEnderChestContainer {
+    /// Creates an empty ender chest inventory.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            items: vec![ItemStack::empty(); ENDER_CHEST_SLOTS],
+            active_chest: None,
+        }
+    }
+
+    /// Sets the ender chest block used to open this inventory.
+    pub fn set_active_chest(&mut self, chest: SharedBlockEntity) {
+        self.active_chest = Some(chest);
+    }
+
+    /// Writes the non-empty slots like Java's `PlayerEnderChestContainer.createTag`.
+    pub(crate) fn to_vanilla_nbt(&self) -> NbtList {
+        let items = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, item)| {
+                if item.is_empty() {
+                    return None;
+                }
+                let NbtTag::Compound(mut nbt) = item.to_nbt_tag_ref() else {
+                    return None;
+                };
+                nbt.insert("Slot", NbtTag::Byte(slot as i8));
+                Some(nbt)
+            })
+            .collect();
+        NbtList::Compound(items)
+    }
+
+    fn with_active_chest<R>(&self, f: impl FnOnce(&mut EnderChestBlockEntity) -> R) -> Option<R> {
+        let chest = self.active_chest.as_ref()?;
+        let mut guard = chest.lock();
+        guard.downcast_mut::<EnderChestBlockEntity>().map(f)
+    }
+}
+
+impl Container for PlayerEnderChestContainer {
+    fn get_container_size(&self) -> usize {
+        ENDER_CHEST_SLOTS
+    }
+
+    fn get_item(&self, slot: usize) -> &ItemStack {
+        &self.items[slot]
+    }
+
+    fn get_item_mut(&mut self, slot: usize) -> &mut ItemStack {
+        &mut self.items[slot]
+    }
+
+    fn set_item(&mut self, slot: usize, stack: ItemStack) {
+        if slot < ENDER_CHEST_SLOTS {
+            self.items[slot] = stack;
+        }
+    }
+
+    /// Saved with the player data, so there is nothing to mark.
+    fn set_changed(&mut self) {}
+
+    fn still_valid(&self, player: &Player) -> bool {
+        self.with_active_chest(|chest| chest.still_valid(player))
+            .unwrap_or(true)
+    }
+
+    fn start_open(&mut self, player: &Player) {
+        self.with_active_chest(|chest| chest.start_open(player));
+    }
+
+    fn stop_open(&mut self, player: &Player) {
+        self.with_active_chest(|chest| chest.stop_open(player));
+        self.active_chest = None;
+    }
+}
@@ -0,0 +1,185 @@
+//! The hopper menu, shared by hopper blocks and hopper minecarts.
+//!
+//! The slot layout is:
+//! - Slots 0-4: Hopper slots
+//! - Slots 5-31: Main inventory
+//! - Slots 32-40: Hotbar
+
+use std::mem;
+
+use steel_registry::item_stack::ItemStack;
+use steel_registry::menu_type::MenuTypeRef;
+use steel_registry::vanilla_menu_types;
+use steel_utils::{DowncastType, DowncastTypeKey};
+use text_components::TextComponent;
+
+use crate::block_entity::entities::HOPPER_SLOTS;
+use crate::inventory::{
+    SyncPlayerInv,
+    lock::{ContainerLockGuard, ContainerRef},
+    menu::{Menu, MenuBehavior},
+    menu_provider::{MenuInstance, MenuProvider},
+    slot::{NormalSlot, Slot, SlotType, add_standard_inventory_slots},
+};
+use crate::player::Player;
+
+/// A menu for hoppers.
+///
+/// Based on Java's `HopperMenu`.
+pub struct HopperMenu {
+    behavior: MenuBehavior,
+    /// Reference to the hopper container.
+    container: ContainerRef,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `HopperMenu`.
+unsafe impl DowncastType for HopperMenu {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:menu/hopper");
+}
+
+impl HopperMenu {
+    /// Creates a new hopper menu.
+    #[must_use]
+    pub fn new(inventory: SyncPlayerInv, container_id: u8, container: ContainerRef) -> Self {
+        let mut menu_slots = Vec::with_capacity(HOPPER_SLOTS + 36);
+
+        for i in 0..HOPPER_SLOTS {
+            menu_slots.push(SlotType::Normal(NormalSlot::new(container.clone(), i)));
+        }
+
+        add_standard_inventory_slots(&mut menu_slots, &inventory);
+
+        Self {
+            behavior: MenuBehavior::new(
+                menu_slots,
+                container_id,
+                Some(&vanilla_menu_types::HOPPER),
+            ),
+            container,
+        }
+    }
+
+    /// Returns a reference to the container.
+    #[must_use]
+    pub const fn container(&self) -> &ContainerRef {
+        &self.container
+    }
+}
+
+impl Menu for HopperMenu {
+    fn behavior(&self) -> &MenuBehavior {
+        &self.behavior
+    }
+
+    fn behavior_mut(&mut self) -> &mut MenuBehavior {
+        &mut self.behavior
+    }
+
+    /// Handles shift-click (quick move) for a slot.
+    ///
+    /// Based on Java's `HopperMenu::quickMoveStack`.
+    fn quick_move_stack(
+        &mut self,
+        guard: &mut ContainerLockGuard,
+        slot_index: usize,
+        _player: &Player,
+    ) -> ItemStack {
+        if slot_index >= self.behavior.slots.len() {
+            return ItemStack::empty();
+        }
+
+        let stack = self.behavior.slots[slot_index].get_item(guard).clone();
+        if stack.is_empty() {
+            return ItemStack::empty();
+        }
+
+        let clicked = stack.clone();
+        let mut stack_mut = stack;
+        let total_slots = self.behavior.slots.len();
+
+        let moved = if slot_index < HOPPER_SLOTS {
+            self.behavior
+                .move_item_stack_to(guard, &mut stack_mut, HOPPER_SLOTS, total_slots, true)
+        } else {
+            self.behavior
+                .move_item_stack_to(guard, &mut stack_mut, 0, HOPPER_SLOTS, false)
+        };
+
+        if !moved {
+            return ItemStack::empty();
+        }
+
+        self.behavior.slots[slot_index].set_item(guard, stack_mut.clone());
+
+        if stack_mut.count == clicked.count {
+            return ItemStack::empty();
+        }
+
+        self.behavior.slots[slot_index].set_changed(guard);
+
+        clicked
+    }
+
+    /// Delegates to the hopper's `still_valid` method.
+    fn still_valid(&self, player: &Player) -> bool {
+        let guard = self.behavior.lock_all_containers();
+        guard
+            .get(self.container.container_id())
+            .is_some_and(|container| container.still_valid(player))
+    }
+
+    /// Returns the carried item, like Java's `HopperMenu::removed`.
+    fn removed(&mut self, player: &Player) {
+        let carried = mem::take(&mut self.behavior.carried);
+        if !carried.is_empty() {
+            player.add_item_or_drop(carried);
+        }
+    }
+}
+
+impl MenuInstance for HopperMenu {
+    fn menu_type(&self) -> MenuTypeRef {
+        &vanilla_menu_types::HOPPER
+    }
+
+    fn container_id(&self) -> u8 {
+        self.behavior.container_id
+    }
+}
+
+/// Provider for creating hopper menus.
+pub struct HopperMenuProvider {
+    inventory: SyncPlayerInv,
+    container: ContainerRef,
+    title: TextComponent,
+}
+
+impl HopperMenuProvider {
+    /// Creates a new hopper menu provider.
+    #[must_use]
+    pub const fn new(
+        inventory: SyncPlayerInv,
+        container: ContainerRef,
+        title: TextComponent,
+    ) -> Self {
+        Self {
+            inventory,
+            container,
+            title,
+        }
+    }
+}
+
+impl MenuProvider for HopperMenuProvider {
+    fn title(&self) -> TextComponent {
+        self.title.clone()
+    }
+
+    fn create(&self, container_id: u8, _player: &Player) -> Box<dyn MenuInstance> {
+        Box::new(HopperMenu::new(
+            self.inventory.clone(),
+            container_id,
+            self.container.clone(),
+        ))
+    }
+}
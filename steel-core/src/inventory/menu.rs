@@ -30,6 +30,7 @@ use steel_registry::{
 };
 
 use crate::{
+    behavior::ITEM_BEHAVIORS,
     inventory::{
         lock::{ContainerId, ContainerLockGuard, ContainerRef},
         slot::{Slot, SlotType},
@@ -38,6 +39,17 @@ use crate::{
 };
 use std::sync::Arc;
 
+/// Which mouse button a pickup click used.
+///
+/// Based on Java's `ClickAction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClickAction {
+    /// Left click.
+    Primary,
+    /// Right click.
+    Secondary,
+}
+
 /// Represents the server's perception of what the client knows about a slot.
 ///
 /// This can be either:
@@ -107,8 +119,15 @@ fn hashed_stack_matches(hash: &HashedStack, item: &ItemStack) -> bool {
                 return false;
             }
 
+            // Clients hash custom items as the vanilla item they were sent
+            let client_view = item.item.client_view(item.patch());
+            let (local_item, local_patch) = match &client_view {
+                Some((client_item, client_patch)) => (*client_item, client_patch),
+                None => (item.item, item.patch()),
+            };
+
             // Check item type and count match
-            let local_id = item.item.id() as i32;
+            let local_id = local_item.id() as i32;
             if local_id != *item_id {
                 log::info!(
                     "HashedStack mismatch: item_id client={item_id} server={local_id} ({})",
@@ -126,7 +145,7 @@ fn hashed_stack_matches(hash: &HashedStack, item: &ItemStack) -> bool {
             }
 
             // Validate component hashes
-            validate_component_hashes(components, item.patch())
+            validate_component_hashes(components, local_patch)
         }
     }
 }
@@ -969,7 +988,21 @@ impl MenuBehavior {
         let slot_item = slot.get_item(&guard).clone();
         let mut carried = mem::take(&mut self.carried);
 
-        if slot_item.is_empty() {
+        let action = if button == 0 {
+            ClickAction::Primary
+        } else {
+            ClickAction::Secondary
+        };
+        if Self::try_item_click_behavior_override(
+            slot,
+            &mut guard,
+            &slot_item,
+            &mut carried,
+            action,
+            player,
+        ) {
+            self.carried = carried;
+        } else if slot_item.is_empty() {
             // Slot is empty - place carried items (if allowed)
             if !carried.is_empty() && slot.may_place(&carried) {
                 let max_for_slot = slot.get_max_stack_size_for_item(&guard, &carried);
@@ -1084,6 +1117,39 @@ impl MenuBehavior {
         slot.set_changed(&mut guard);
     }
 
+    /// Lets the carried or clicked item handle the click itself, like bundles do.
+    ///
+    /// Based on Java's `AbstractContainerMenu::tryItemClickBehaviourOverride`.
+    fn try_item_click_behavior_override(
+        slot: &SlotType,
+        guard: &mut ContainerLockGuard,
+        clicked: &ItemStack,
+        carried: &mut ItemStack,
+        action: ClickAction,
+        player: &Player,
+    ) -> bool {
+        if !carried.is_empty()
+            && ITEM_BEHAVIORS
+                .get_behavior(carried.item())
+                .override_stacked_on_other(carried, slot, guard, action, player)
+        {
+            return true;
+        }
+        if clicked.is_empty() {
+            return false;
+        }
+
+        let mut clicked = clicked.clone();
+        if ITEM_BEHAVIORS
+            .get_behavior(clicked.item())
+            .override_other_stacked_on_me(&mut clicked, carried, slot, guard, action, player)
+        {
+            slot.set_item(guard, clicked);
+            return true;
+        }
+        false
+    }
+
     /// Handles clone (middle-click in creative).
     pub fn do_clone(&mut self, slot_num: i16, has_infinite_materials: bool) {
         if !has_infinite_materials || !self.carried.is_empty() || slot_num < 0 {
@@ -1214,12 +1280,30 @@ pub trait Menu {
         }
     }
 
+    /// Handles a menu button press, such as an enchanting table option.
+    ///
+    /// Returns true if the button was accepted. Based on Java's
+    /// `AbstractContainerMenu::clickMenuButton`.
+    fn click_menu_button(&mut self, _player: &Player, _button_id: i32) -> bool {
+        false
+    }
+
+    /// Called after a click may have changed this menu's slots.
+    ///
+    /// Menus that derive data from their inputs recompute it here. Based on
+    /// Java's `AbstractContainerMenu::slotsChanged`.
+    fn slots_changed(&mut self, _player: &Player) {}
+
+    /// Refreshes data slots that mirror state kept outside the menu.
+    ///
+    /// Called every tick before changes are broadcast. Based on the live
+    /// `ContainerData` views that menus like Java's `BeaconMenu` read from.
+    fn update_data_slots(&mut self) {}
+
     /// Handles a click action in this menu.
     /// Based on Java's `AbstractContainerMenu::clicked` and doClick.
     ///
     /// `has_infinite_materials` should be true if the player is in creative mode.
-    ///
-    /// TODO: Add `tryItemClickBehaviorOverride` for bundle item support.
     fn clicked(
         &mut self,
         slot_num: i16,
@@ -1,15 +1,18 @@
 //! Menu provider for opening menus.
 
 use steel_registry::menu_type::MenuTypeRef;
+use steel_utils::ErasedType;
 use text_components::TextComponent;
 
 use crate::inventory::menu::Menu;
+use crate::player::Player;
 
 /// Trait for menu instances that can be opened by players.
 ///
 /// This extends `Menu` with the additional information needed to send
-/// the open screen packet: menu type and container ID.
-pub trait MenuInstance: Menu + Send + Sync {
+/// the open screen packet: menu type and container ID. Instances can be
+/// downcast to their concrete menu via [`steel_utils::Downcast`].
+pub trait MenuInstance: Menu + ErasedType + Send + Sync {
     /// Returns the menu type for the open screen packet.
     fn menu_type(&self) -> MenuTypeRef;
 
@@ -24,6 +27,8 @@ pub trait MenuProvider {
     /// Returns the display title for this menu.
     fn title(&self) -> TextComponent;
 
-    /// Creates a menu with the given container ID.
-    fn create(&self, container_id: u8) -> Box<dyn MenuInstance>;
+    /// Creates a menu with the given container ID for the opening player.
+    ///
+    /// Based on Java's `MenuConstructor.createMenu`.
+    fn create(&self, container_id: u8, player: &Player) -> Box<dyn MenuInstance>;
 }
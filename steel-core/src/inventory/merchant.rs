@@ -0,0 +1,172 @@
+//! Merchant trading container.
+//!
+//! Holds the two payment slots and the trade result shown in a merchant menu.
+
+use std::mem;
+
+use steel_registry::item_stack::ItemStack;
+use steel_utils::{DowncastType, DowncastTypeKey};
+
+use crate::entity::{Entity as _, SharedEntity};
+use crate::inventory::container::Container;
+use crate::player::Player;
+
+/// Slot index of the result in a [`MerchantContainer`].
+pub const RESULT_SLOT: usize = 2;
+
+/// Based on Java's `MerchantContainer`.
+pub struct MerchantContainer {
+    merchant: SharedEntity,
+    items: [ItemStack; 3],
+    /// Index into the merchant's offers of the trade currently previewed.
+    active_offer: Option<usize>,
+    selection_hint: usize,
+    future_xp: i32,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `MerchantContainer`.
+unsafe impl DowncastType for MerchantContainer {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:container/merchant");
+}
+
+impl MerchantContainer {
+    /// Creates an empty trade container for `merchant`.
+    #[must_use]
+    pub fn new(merchant: SharedEntity) -> Self {
+        Self {
+            merchant,
+            items: Default::default(),
+            active_offer: None,
+            selection_hint: 0,
+            future_xp: 0,
+        }
+    }
+
+    /// Returns the trading entity.
+    #[must_use]
+    pub const fn merchant(&self) -> &SharedEntity {
+        &self.merchant
+    }
+
+    /// Returns the index of the offer currently previewed in the result slot.
+    #[must_use]
+    pub const fn active_offer(&self) -> Option<usize> {
+        self.active_offer
+    }
+
+    /// Returns the experience the previewed trade would award.
+    #[must_use]
+    pub const fn future_xp(&self) -> i32 {
+        self.future_xp
+    }
+
+    /// Sets the offer the player selected, preferring it when matching payments.
+    pub fn set_selection_hint(&mut self, selection_hint: usize) {
+        self.selection_hint = selection_hint;
+        self.update_sell_item();
+    }
+
+    const fn is_payment_slot(slot: usize) -> bool {
+        slot == 0 || slot == 1
+    }
+
+    /// Recomputes the previewed trade result from the payment slots.
+    ///
+    /// Based on Java's `MerchantContainer::updateSellItem`.
+    pub fn update_sell_item(&mut self) {
+        self.active_offer = None;
+        let empty = ItemStack::empty();
+        let (buy_a, buy_b) = if self.items[0].is_empty() {
+            (&self.items[1], &empty)
+        } else {
+            (&self.items[0], &self.items[1])
+        };
+
+        if buy_a.is_empty() {
+            self.items[RESULT_SLOT] = ItemStack::empty();
+            self.future_xp = 0;
+            return;
+        }
+
+        let Some(merchant) = self.merchant.as_merchant() else {
+            return;
+        };
+
+        let sell = {
+            let offers = merchant.offers();
+            (!offers.is_empty()).then(|| {
+                let mut offer = offers.get_recipe_for(buy_a, buy_b, self.selection_hint);
+                if offer.is_none_or(|index| offers[index].is_out_of_stock()) {
+                    self.active_offer = offer;
+                    offer = offers.get_recipe_for(buy_b, buy_a, self.selection_hint);
+                }
+
+                match offer {
+                    Some(index) if !offers[index].is_out_of_stock() => {
+                        self.active_offer = Some(index);
+                        (offers[index].assemble(), offers[index].xp)
+                    }
+                    _ => (ItemStack::empty(), 0),
+                }
+            })
+        };
+
+        if let Some((result, future_xp)) = sell {
+            self.items[RESULT_SLOT] = result;
+            self.future_xp = future_xp;
+        }
+        merchant.notify_trade_updated(&self.items[RESULT_SLOT]);
+    }
+}
+
+impl Container for MerchantContainer {
+    fn get_container_size(&self) -> usize {
+        self.items.len()
+    }
+
+    fn get_item(&self, slot: usize) -> &ItemStack {
+        &self.items[slot]
+    }
+
+    fn get_item_mut(&mut self, slot: usize) -> &mut ItemStack {
+        &mut self.items[slot]
+    }
+
+    fn set_item(&mut self, slot: usize, mut stack: ItemStack) {
+        let max_stack_size = self.get_max_stack_size_for_item(&stack);
+        if stack.count() > max_stack_size {
+            stack.set_count(max_stack_size);
+        }
+        self.items[slot] = stack;
+        if Self::is_payment_slot(slot) {
+            self.update_sell_item();
+        }
+    }
+
+    /// The result slot always hands out its whole stack.
+    fn remove_item(&mut self, slot: usize, count: i32) -> ItemStack {
+        let item = &mut self.items[slot];
+        if slot == RESULT_SLOT && !item.is_empty() {
+            return mem::take(item);
+        }
+        if item.is_empty() || count <= 0 {
+            return ItemStack::empty();
+        }
+
+        let removed = item.split(count);
+        if !removed.is_empty() && Self::is_payment_slot(slot) {
+            self.update_sell_item();
+        }
+        removed
+    }
+
+    fn set_changed(&mut self) {
+        self.update_sell_item();
+    }
+
+    fn still_valid(&self, player: &Player) -> bool {
+        self.merchant
+            .as_merchant()
+            .is_some_and(|merchant| merchant.trading_player() == Some(player.uuid()))
+    }
+}
@@ -0,0 +1,379 @@
+//! The merchant trading menu.
+//!
+//! Slot layout (39 total):
+//! - Slots 0-1: Payment slots
+//! - Slot 2: Trade result
+//! - Slots 3-29: Main inventory (27 slots)
+//! - Slots 30-38: Hotbar (9 slots)
+
+use std::{mem, sync::Arc};
+
+use steel_registry::item_stack::ItemStack;
+use steel_registry::menu_type::MenuTypeRef;
+use steel_registry::trading::ItemCost;
+use steel_registry::vanilla_menu_types;
+use steel_utils::locks::SyncMutex;
+use steel_utils::{DowncastType, DowncastTypeKey};
+use text_components::TextComponent;
+
+use crate::entity::{Entity as _, SharedEntity};
+use crate::inventory::{
+    SyncPlayerInv,
+    container::Container,
+    lock::ContainerLockGuard,
+    menu::{Menu, MenuBehavior},
+    menu_provider::{MenuInstance, MenuProvider},
+    merchant::MerchantContainer,
+    slot::{
+        MerchantResultSlot, NormalSlot, Slot, SlotType, SyncMerchantContainer,
+        add_standard_inventory_slots,
+    },
+};
+use crate::player::Player;
+
+/// Slot indices for the merchant menu.
+pub mod slots {
+    /// First payment slot (slot 0).
+    pub const PAYMENT1_SLOT: usize = 0;
+    /// Second payment slot (slot 1).
+    pub const PAYMENT2_SLOT: usize = 1;
+    /// Slot index for the trade result (slot 2).
+    pub const RESULT_SLOT: usize = 2;
+    /// Start of main inventory (slot 3).
+    pub const INV_SLOT_START: usize = 3;
+    /// End of main inventory (slot 30, exclusive).
+    pub const INV_SLOT_END: usize = 30;
+    /// Start of hotbar (slot 30).
+    pub const HOTBAR_SLOT_START: usize = 30;
+    /// End of hotbar (slot 39, exclusive).
+    pub const HOTBAR_SLOT_END: usize = 39;
+    /// Total number of slots in the merchant menu.
+    pub const TOTAL_SLOTS: usize = 39;
+}
+
+/// The menu shown while trading with a merchant.
+///
+/// Based on Java's `MerchantMenu`.
+pub struct MerchantMenu {
+    behavior: MenuBehavior,
+    /// The payment and result container.
+    trade_container: SyncMerchantContainer,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `MerchantMenu`.
+unsafe impl DowncastType for MerchantMenu {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:menu/merchant");
+}
+
+impl MerchantMenu {
+    /// Creates a new merchant menu for a player trading with `merchant`.
+    #[must_use]
+    pub fn new(inventory: SyncPlayerInv, container_id: u8, merchant: SharedEntity) -> Self {
+        let mut menu_slots = Vec::with_capacity(slots::TOTAL_SLOTS);
+
+        let trade_container: SyncMerchantContainer =
+            Arc::new(SyncMutex::new(MerchantContainer::new(merchant)));
+
+        // Slots 0-1: Payment
+        menu_slots.push(SlotType::Normal(NormalSlot::new(
+            trade_container.clone(),
+            slots::PAYMENT1_SLOT,
+        )));
+        menu_slots.push(SlotType::Normal(NormalSlot::new(
+            trade_container.clone(),
+            slots::PAYMENT2_SLOT,
+        )));
+
+        // Slot 2: Result
+        menu_slots.push(SlotType::MerchantResult(MerchantResultSlot::new(
+            trade_container.clone(),
+        )));
+
+        // Slots 3-38: Standard inventory (main inventory + hotbar)
+        add_standard_inventory_slots(&mut menu_slots, &inventory);
+
+        Self {
+            behavior: MenuBehavior::new(
+                menu_slots,
+                container_id,
+                Some(&vanilla_menu_types::MERCHANT),
+            ),
+            trade_container,
+        }
+    }
+
+    /// Returns a reference to the trade container.
+    #[must_use]
+    pub const fn trade_container(&self) -> &SyncMerchantContainer {
+        &self.trade_container
+    }
+
+    /// Sets which offer the player has selected in the trade list.
+    pub fn set_selection_hint(&self, selection_hint: usize) {
+        self.trade_container
+            .lock()
+            .set_selection_hint(selection_hint);
+    }
+
+    /// Moves the payment for the selected offer from the inventory into the
+    /// payment slots, returning whatever was there before.
+    ///
+    /// Based on Java's `MerchantMenu::tryMoveItems`.
+    pub fn try_move_items(&self, selected_trade_index: usize) {
+        let merchant = Arc::clone(self.trade_container.lock().merchant());
+        let Some(merchant) = merchant.as_merchant() else {
+            return;
+        };
+        let Some((cost_a, cost_b)) = merchant
+            .offers()
+            .get(selected_trade_index)
+            .map(|offer| (offer.base_cost_a.clone(), offer.cost_b.clone()))
+        else {
+            return;
+        };
+
+        let mut guard = self.behavior.lock_all_containers();
+        for payment_slot in [slots::PAYMENT1_SLOT, slots::PAYMENT2_SLOT] {
+            let mut payment = self.behavior.slots[payment_slot].get_item(&guard).clone();
+            if payment.is_empty() {
+                continue;
+            }
+            if !self.behavior.move_item_stack_to(
+                &mut guard,
+                &mut payment,
+                slots::INV_SLOT_START,
+                slots::HOTBAR_SLOT_END,
+                true,
+            ) {
+                return;
+            }
+            self.behavior.slots[payment_slot].set_item(&mut guard, payment);
+        }
+
+        if self.behavior.slots[slots::PAYMENT1_SLOT]
+            .get_item(&guard)
+            .is_empty()
+            && self.behavior.slots[slots::PAYMENT2_SLOT]
+                .get_item(&guard)
+                .is_empty()
+        {
+            self.move_from_inventory_to_payment_slot(&mut guard, slots::PAYMENT1_SLOT, &cost_a);
+            if let Some(cost_b) = cost_b {
+                self.move_from_inventory_to_payment_slot(&mut guard, slots::PAYMENT2_SLOT, &cost_b);
+            }
+        }
+    }
+
+    /// Based on Java's `MerchantMenu::moveFromInventoryToPaymentSlot`.
+    fn move_from_inventory_to_payment_slot(
+        &self,
+        guard: &mut ContainerLockGuard,
+        payment_slot: usize,
+        cost: &ItemCost,
+    ) {
+        for slot in slots::INV_SLOT_START..slots::HOTBAR_SLOT_END {
+            let inventory_item = self.behavior.slots[slot].get_item(guard).clone();
+            if inventory_item.is_empty() || !cost.test(&inventory_item) {
+                continue;
+            }
+
+            let payment_item = self.behavior.slots[payment_slot].get_item(guard).clone();
+            if !payment_item.is_empty()
+                && !ItemStack::is_same_item_same_components(&inventory_item, &payment_item)
+            {
+                continue;
+            }
+
+            let max_stack_size = inventory_item.max_stack_size();
+            let move_count = (max_stack_size - payment_item.count()).min(inventory_item.count());
+            let new_payment_item =
+                inventory_item.copy_with_count(payment_item.count() + move_count);
+            self.behavior.slots[slot]
+                .get_item_mut(guard)
+                .shrink(move_count);
+            self.behavior.slots[slot].set_changed(guard);
+            self.behavior.slots[payment_slot].set_item(guard, new_payment_item.clone());
+            if new_payment_item.count() >= max_stack_size {
+                break;
+            }
+        }
+    }
+}
+
+impl Menu for MerchantMenu {
+    fn behavior(&self) -> &MenuBehavior {
+        &self.behavior
+    }
+
+    fn behavior_mut(&mut self) -> &mut MenuBehavior {
+        &mut self.behavior
+    }
+
+    /// Handles shift-click (quick move) for a slot.
+    ///
+    /// Based on Java's `MerchantMenu::quickMoveStack`:
+    /// - Result slot (2) -> inventory (3-39), prefer existing stacks
+    /// - Payment slots (0-1) -> inventory (3-39)
+    /// - Inventory (3-29) -> hotbar (30-38)
+    /// - Hotbar (30-38) -> inventory (3-29)
+    fn quick_move_stack(
+        &mut self,
+        guard: &mut ContainerLockGuard,
+        slot_index: usize,
+        player: &Player,
+    ) -> ItemStack {
+        if slot_index >= self.behavior.slots.len() {
+            return ItemStack::empty();
+        }
+
+        let stack = self.behavior.slots[slot_index].get_item(guard).clone();
+        if stack.is_empty() {
+            return ItemStack::empty();
+        }
+
+        let clicked = stack.clone();
+        let mut stack_mut = stack;
+
+        let moved = if slot_index == slots::RESULT_SLOT {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::INV_SLOT_START,
+                slots::HOTBAR_SLOT_END,
+                true,
+            )
+        } else if (slots::INV_SLOT_START..slots::INV_SLOT_END).contains(&slot_index) {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::HOTBAR_SLOT_START,
+                slots::HOTBAR_SLOT_END,
+                false,
+            )
+        } else if (slots::HOTBAR_SLOT_START..slots::HOTBAR_SLOT_END).contains(&slot_index) {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::INV_SLOT_START,
+                slots::INV_SLOT_END,
+                false,
+            )
+        } else {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                slots::INV_SLOT_START,
+                slots::HOTBAR_SLOT_END,
+                false,
+            )
+        };
+
+        if !moved {
+            return ItemStack::empty();
+        }
+
+        self.behavior.slots[slot_index].set_item(guard, stack_mut.clone());
+
+        if stack_mut.count() == clicked.count() {
+            return ItemStack::empty();
+        }
+
+        self.behavior.slots[slot_index].set_changed(guard);
+
+        if let Some(remainder) = self.behavior.slots[slot_index].on_take(guard, &stack_mut, player)
+        {
+            player.add_item_or_drop_with_guard(guard, remainder);
+        }
+
+        clicked
+    }
+
+    /// Prevents pickup-all from pulling items out of the result slot.
+    fn can_take_item_for_pick_all(&self, _carried: &ItemStack, slot_index: usize) -> bool {
+        slot_index != slots::RESULT_SLOT
+    }
+
+    /// Based on Java's `MerchantMenu::stillValid`, which defers to the merchant.
+    fn still_valid(&self, player: &Player) -> bool {
+        let merchant = Arc::clone(self.trade_container.lock().merchant());
+        merchant
+            .as_merchant()
+            .is_some_and(|merchant| merchant.still_valid(player))
+    }
+
+    /// Ends the trade and returns the payment items to the player.
+    ///
+    /// Based on Java's `MerchantMenu::removed`.
+    fn removed(&mut self, player: &Player) {
+        let carried = mem::take(&mut self.behavior.carried);
+        if !carried.is_empty() {
+            player.add_item_or_drop(carried);
+        }
+
+        let (merchant, payments) = {
+            let mut container = self.trade_container.lock();
+            let payments: Vec<ItemStack> = [slots::PAYMENT1_SLOT, slots::PAYMENT2_SLOT]
+                .into_iter()
+                .map(|slot| mem::take(container.get_item_mut(slot)))
+                .filter(|item| !item.is_empty())
+                .collect();
+            container.set_item(slots::RESULT_SLOT, ItemStack::empty());
+            (Arc::clone(container.merchant()), payments)
+        };
+
+        if let Some(merchant) = merchant.as_merchant() {
+            merchant.set_trading_player(None);
+        }
+
+        for item in payments {
+            player.add_item_or_drop(item);
+        }
+    }
+}
+
+impl MenuInstance for MerchantMenu {
+    fn menu_type(&self) -> MenuTypeRef {
+        &vanilla_menu_types::MERCHANT
+    }
+
+    fn container_id(&self) -> u8 {
+        self.behavior.container_id
+    }
+}
+
+/// Provider for creating a merchant menu.
+pub struct MerchantMenuProvider {
+    inventory: SyncPlayerInv,
+    merchant: SharedEntity,
+    title: TextComponent,
+}
+
+impl MerchantMenuProvider {
+    /// Creates a new merchant menu provider.
+    #[must_use]
+    pub const fn new(
+        inventory: SyncPlayerInv,
+        merchant: SharedEntity,
+        title: TextComponent,
+    ) -> Self {
+        Self {
+            inventory,
+            merchant,
+            title,
+        }
+    }
+}
+
+impl MenuProvider for MerchantMenuProvider {
+    fn title(&self) -> TextComponent {
+        self.title.clone()
+    }
+
+    fn create(&self, container_id: u8, _player: &Player) -> Box<dyn MenuInstance> {
+        Box::new(MerchantMenu::new(
+            self.inventory.clone(),
+            container_id,
+            Arc::clone(&self.merchant),
+        ))
+    }
+}
@@ -3,19 +3,34 @@
 //! This module provides the core inventory system including containers,
 //! menus, crafting, equipment, and recipes.
 
+pub mod beacon_menu;
+pub mod cartography_table_menu;
 pub mod chest_menu;
 pub mod container;
 pub mod crafting;
 pub mod crafting_menu;
+pub mod enchantment_menu;
+pub mod ender_chest;
 pub mod equipment;
+pub mod hopper_menu;
 pub mod inventory_menu;
 pub mod lock;
 pub mod menu;
 pub mod menu_provider;
+pub mod merchant;
+pub mod merchant_menu;
 pub mod recipe_manager;
+pub mod shulker_box_menu;
 pub mod slot;
 
+pub use beacon_menu::{BeaconMenu, BeaconMenuProvider};
+pub use cartography_table_menu::{CartographyTableMenu, CartographyTableMenuProvider};
 pub use chest_menu::{ChestMenu, ChestMenuProvider};
 pub use crafting_menu::{CraftingMenu, CraftingMenuProvider};
+pub use enchantment_menu::{EnchantmentMenu, EnchantmentMenuProvider};
+pub use ender_chest::PlayerEnderChestContainer;
+pub use hopper_menu::{HopperMenu, HopperMenuProvider};
 pub use lock::SyncPlayerInv;
 pub use menu_provider::{MenuInstance, MenuProvider};
+pub use merchant_menu::{MerchantMenu, MerchantMenuProvider};
+pub use shulker_box_menu::{ShulkerBoxMenu, ShulkerBoxMenuProvider};
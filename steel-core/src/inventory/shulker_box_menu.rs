@@ -0,0 +1,209 @@
+//! The shulker box menu.
+//!
+//! The slot layout is:
+//! - Slots 0-26: Shulker box slots
+//! - Slots 27-53: Main inventory
+//! - Slots 54-62: Hotbar
+
+use std::mem;
+
+use steel_registry::item_stack::ItemStack;
+use steel_registry::menu_type::MenuTypeRef;
+use steel_registry::vanilla_menu_types;
+use steel_utils::{DowncastType, DowncastTypeKey};
+use text_components::TextComponent;
+
+use crate::block_entity::entities::SHULKER_BOX_SLOTS;
+use crate::inventory::{
+    SyncPlayerInv,
+    lock::{ContainerLockGuard, ContainerRef},
+    menu::{Menu, MenuBehavior},
+    menu_provider::{MenuInstance, MenuProvider},
+    slot::{ShulkerBoxSlot, Slot, SlotType, add_standard_inventory_slots},
+};
+use crate::player::Player;
+
+/// A menu for shulker boxes.
+///
+/// Unlike a chest menu, its container slots reject other shulker boxes.
+///
+/// Based on Java's `ShulkerBoxMenu`.
+pub struct ShulkerBoxMenu {
+    behavior: MenuBehavior,
+    /// Reference to the shulker box container.
+    container: ContainerRef,
+}
+
+// SAFETY: This key is owned by Steel and uniquely identifies `ShulkerBoxMenu`.
+unsafe impl DowncastType for ShulkerBoxMenu {
+    const TYPE_KEY: DowncastTypeKey = DowncastTypeKey::new("steel:menu/shulker_box");
+}
+
+impl ShulkerBoxMenu {
+    /// Creates a new shulker box menu.
+    #[must_use]
+    pub fn new(inventory: SyncPlayerInv, container_id: u8, container: ContainerRef) -> Self {
+        let mut menu_slots = Vec::with_capacity(SHULKER_BOX_SLOTS + 36);
+
+        for i in 0..SHULKER_BOX_SLOTS {
+            menu_slots.push(SlotType::ShulkerBox(ShulkerBoxSlot::new(
+                container.clone(),
+                i,
+            )));
+        }
+
+        add_standard_inventory_slots(&mut menu_slots, &inventory);
+
+        Self {
+            behavior: MenuBehavior::new(
+                menu_slots,
+                container_id,
+                Some(&vanilla_menu_types::SHULKER_BOX),
+            ),
+            container,
+        }
+    }
+
+    /// Returns a reference to the container.
+    #[must_use]
+    pub const fn container(&self) -> &ContainerRef {
+        &self.container
+    }
+
+    /// Notifies the shulker box that `player` opened this menu.
+    ///
+    /// Java's `ShulkerBoxMenu` constructor calls `container.startOpen(player)`.
+    pub fn start_open(&self, player: &Player) {
+        let mut guard = self.behavior.lock_all_containers();
+        if let Some(container) = guard.get_mut(self.container.container_id()) {
+            container.start_open(player);
+        }
+    }
+}
+
+impl Menu for ShulkerBoxMenu {
+    fn behavior(&self) -> &MenuBehavior {
+        &self.behavior
+    }
+
+    fn behavior_mut(&mut self) -> &mut MenuBehavior {
+        &mut self.behavior
+    }
+
+    /// Handles shift-click (quick move) for a slot.
+    ///
+    /// Based on Java's `ShulkerBoxMenu::quickMoveStack`.
+    fn quick_move_stack(
+        &mut self,
+        guard: &mut ContainerLockGuard,
+        slot_index: usize,
+        _player: &Player,
+    ) -> ItemStack {
+        if slot_index >= self.behavior.slots.len() {
+            return ItemStack::empty();
+        }
+
+        let stack = self.behavior.slots[slot_index].get_item(guard).clone();
+        if stack.is_empty() {
+            return ItemStack::empty();
+        }
+
+        let clicked = stack.clone();
+        let mut stack_mut = stack;
+        let total_slots = self.behavior.slots.len();
+
+        let moved = if slot_index < SHULKER_BOX_SLOTS {
+            self.behavior.move_item_stack_to(
+                guard,
+                &mut stack_mut,
+                SHULKER_BOX_SLOTS,
+                total_slots,
+                true,
+            )
+        } else {
+            self.behavior
+                .move_item_stack_to(guard, &mut stack_mut, 0, SHULKER_BOX_SLOTS, false)
+        };
+
+        if !moved {
+            return ItemStack::empty();
+        }
+
+        self.behavior.slots[slot_index].set_item(guard, stack_mut.clone());
+
+        if stack_mut.count == clicked.count {
+            return ItemStack::empty();
+        }
+
+        self.behavior.slots[slot_index].set_changed(guard);
+
+        clicked
+    }
+
+    /// Delegates to the shulker box's `still_valid` method.
+    fn still_valid(&self, player: &Player) -> bool {
+        let guard = self.behavior.lock_all_containers();
+        guard
+            .get(self.container.container_id())
+            .is_some_and(|container| container.still_valid(player))
+    }
+
+    /// Returns the carried item and calls `stop_open`, like Java's `ShulkerBoxMenu::removed`.
+    fn removed(&mut self, player: &Player) {
+        let carried = mem::take(&mut self.behavior.carried);
+        if !carried.is_empty() {
+            player.add_item_or_drop(carried);
+        }
+
+        let mut guard = self.behavior.lock_all_containers();
+        if let Some(container) = guard.get_mut(self.container.container_id()) {
+            container.stop_open(player);
+        }
+    }
+}
+
+impl MenuInstance for ShulkerBoxMenu {
+    fn menu_type(&self) -> MenuTypeRef {
+        &vanilla_menu_types::SHULKER_BOX
+    }
+
+    fn container_id(&self) -> u8 {
+        self.behavior.container_id
+    }
+}
+
+/// Provider for creating shulker box menus.
+pub struct ShulkerBoxMenuProvider {
+    inventory: SyncPlayerInv,
+    container: ContainerRef,
+    title: TextComponent,
+}
+
+impl ShulkerBoxMenuProvider {
+    /// Creates a new shulker box menu provider.
+    #[must_use]
+    pub const fn new(
+        inventory: SyncPlayerInv,
+        container: ContainerRef,
+        title: TextComponent,
+    ) -> Self {
+        Self {
+            inventory,
+            container,
+            title,
+        }
+    }
+}
+
+impl MenuProvider for ShulkerBoxMenuProvider {
+    fn title(&self) -> TextComponent {
+        self.title.clone()
+    }
+
+    fn create(&self, container_id: u8, player: &Player) -> Box<dyn MenuInstance> {
+        let menu =
+            ShulkerBoxMenu::new(self.inventory.clone(), container_id, self.container.clone());
+        menu.start_open(player);
+        Box::new(menu)
+    }
+}
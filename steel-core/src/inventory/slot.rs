@@ -9,17 +9,30 @@
 use std::{mem, sync::Arc};
 
 use enum_dispatch::enum_dispatch;
+use steel_registry::data_components::vanilla_components::MAP_ID;
 use steel_registry::enchantment_effect::EnchantmentEffectComponent;
 use steel_registry::item_stack::ItemStack;
+use steel_registry::recipe::CraftingRecipe;
+use steel_registry::stat::{CustomStat, Stat};
+use steel_registry::vanilla_game_rules::LIMITED_CRAFTING;
+use steel_registry::vanilla_items;
+use steel_utils::BlockPos;
 use steel_utils::locks::SyncMutex;
 
+use crate::entity::Entity as _;
 use crate::inventory::SyncPlayerInv;
+use crate::inventory::beacon_menu;
+use crate::inventory::cartography_table_menu::{
+    self, CartographyContainer, SyncCartographyContainer,
+};
 use crate::inventory::container::Container;
 use crate::inventory::crafting::{CraftingContainer, ResultContainer};
+use crate::inventory::enchantment_menu;
 use crate::inventory::equipment::EquipmentSlot;
 use crate::inventory::lock::{ContainerId, ContainerLockGuard, ContainerRef};
+use crate::inventory::merchant::{self, MerchantContainer};
 use crate::inventory::recipe_manager;
-use crate::player::Player;
+use crate::player::{CriteriaTrigger, Player};
 
 /// A synchronized crafting container.
 pub type SyncCraftingContainer = Arc<SyncMutex<CraftingContainer>>;
@@ -27,6 +40,9 @@ pub type SyncCraftingContainer = Arc<SyncMutex<CraftingContainer>>;
 /// A synchronized result container.
 pub type SyncResultContainer = Arc<SyncMutex<ResultContainer>>;
 
+/// A synchronized merchant container.
+pub type SyncMerchantContainer = Arc<SyncMutex<MerchantContainer>>;
+
 /// A slot is a view into a single position in a container.
 /// Slots require a `ContainerLockGuard` to access items, ensuring proper locking.
 #[enum_dispatch]
@@ -185,6 +201,33 @@ pub trait Slot {
         }
     }
 
+    /// Places as much of `stack` into this slot as fits, returning the rest.
+    ///
+    /// Based on Java's `Slot::safeInsert`.
+    fn safe_insert(&self, guard: &mut ContainerLockGuard, mut stack: ItemStack) -> ItemStack {
+        if stack.is_empty() || !self.may_place(&stack) {
+            return stack;
+        }
+
+        let current = self.get_item(guard).clone();
+        let count = stack
+            .count()
+            .min(self.get_max_stack_size_for_item(guard, &stack) - current.count());
+        if count <= 0 {
+            return stack;
+        }
+
+        if current.is_empty() {
+            self.set_by_player(guard, stack.split(count), &current);
+        } else if ItemStack::is_same_item_same_components(&current, &stack) {
+            stack.shrink(count);
+            let mut grown = current.clone();
+            grown.grow(count);
+            self.set_by_player(guard, grown, &current);
+        }
+        stack
+    }
+
     /// Marks the slot's container as changed.
     fn set_changed(&self, guard: &mut ContainerLockGuard);
 
@@ -261,6 +304,116 @@ impl Slot for NormalSlot {
     }
 }
 
+/// A shulker box slot that rejects items which cannot be nested in container items.
+///
+/// Based on Java's `ShulkerBoxSlot`.
+pub struct ShulkerBoxSlot {
+    slot: NormalSlot,
+}
+
+impl ShulkerBoxSlot {
+    /// Creates a new shulker box slot from a `ContainerRef`.
+    pub fn new(container: impl Into<ContainerRef>, index: usize) -> Self {
+        Self {
+            slot: NormalSlot::new(container, index),
+        }
+    }
+
+    /// Returns a reference to the container.
+    #[must_use]
+    pub fn container_ref(&self) -> ContainerRef {
+        self.slot.container_ref()
+    }
+}
+
+impl Slot for ShulkerBoxSlot {
+    fn get_item<'a>(&self, guard: &'a ContainerLockGuard) -> &'a ItemStack {
+        self.slot.get_item(guard)
+    }
+
+    fn get_item_mut<'a>(&self, guard: &'a mut ContainerLockGuard) -> &'a mut ItemStack {
+        self.slot.get_item_mut(guard)
+    }
+
+    fn set_item(&self, guard: &mut ContainerLockGuard, stack: ItemStack) {
+        self.slot.set_item(guard, stack);
+    }
+
+    fn set_changed(&self, guard: &mut ContainerLockGuard) {
+        self.slot.set_changed(guard);
+    }
+
+    fn get_container_slot(&self) -> usize {
+        self.slot.get_container_slot()
+    }
+
+    fn get_max_stack_size(&self, guard: &ContainerLockGuard) -> i32 {
+        self.slot.get_max_stack_size(guard)
+    }
+
+    fn may_place(&self, stack: &ItemStack) -> bool {
+        stack.can_fit_inside_container_items()
+    }
+}
+
+/// One of the two input slots of an enchanting table.
+///
+/// Based on the anonymous slots in Java's `EnchantmentMenu`: the item slot holds a
+/// single item and the lapis slot only accepts lapis lazuli.
+pub struct EnchantmentSlot {
+    slot: NormalSlot,
+}
+
+impl EnchantmentSlot {
+    /// Creates a new enchantment slot from a `ContainerRef`.
+    pub fn new(container: impl Into<ContainerRef>, index: usize) -> Self {
+        Self {
+            slot: NormalSlot::new(container, index),
+        }
+    }
+
+    /// Returns a reference to the container.
+    #[must_use]
+    pub fn container_ref(&self) -> ContainerRef {
+        self.slot.container_ref()
+    }
+}
+
+impl Slot for EnchantmentSlot {
+    fn get_item<'a>(&self, guard: &'a ContainerLockGuard) -> &'a ItemStack {
+        self.slot.get_item(guard)
+    }
+
+    fn get_item_mut<'a>(&self, guard: &'a mut ContainerLockGuard) -> &'a mut ItemStack {
+        self.slot.get_item_mut(guard)
+    }
+
+    fn set_item(&self, guard: &mut ContainerLockGuard, stack: ItemStack) {
+        self.slot.set_item(guard, stack);
+    }
+
+    fn set_changed(&self, guard: &mut ContainerLockGuard) {
+        self.slot.set_changed(guard);
+    }
+
+    fn get_container_slot(&self) -> usize {
+        self.slot.get_container_slot()
+    }
+
+    fn get_max_stack_size(&self, guard: &ContainerLockGuard) -> i32 {
+        if self.get_container_slot() == enchantment_menu::slots::ITEM_SLOT {
+            1
+        } else {
+            self.slot.get_max_stack_size(guard)
+        }
+    }
+
+    fn may_place(&self, stack: &ItemStack) -> bool {
+        self.get_container_slot() == enchantment_menu::slots::ITEM_SLOT
+            || stack.is(&vanilla_items::LAPIS_LAZULI)
+    }
+}
+
 /// An armor slot that only accepts items equippable in the corresponding slot.
 pub struct ArmorSlot {
     container: SyncPlayerInv,
@@ -554,22 +707,18 @@ impl CraftingResultSlot {
         ContainerRef::from(Arc::clone(&self.crafting_container))
     }
 
-    fn has_valid_recipe_result(&self, guard: &ContainerLockGuard) -> bool {
+    /// Returns the recipe producing the current result, if the result is still valid.
+    fn valid_recipe(&self, guard: &ContainerLockGuard) -> Option<CraftingRecipe> {
         let result = self.get_item(guard);
         if result.is_empty() {
-            return false;
+            return None;
         }
 
         let crafting_id = ContainerId::from_arc(&self.crafting_container);
-        let Some(crafting) = guard.get_typed::<CraftingContainer>(crafting_id) else {
-            return false;
-        };
-
-        let Some(recipe) = recipe_manager::find_recipe(crafting, self.grid_size == 2) else {
-            return false;
-        };
+        let crafting = guard.get_typed::<CraftingContainer>(crafting_id)?;
+        let recipe = recipe_manager::find_recipe(crafting, self.grid_size == 2)?;
 
-        ItemStack::matches(result, &recipe.assemble())
+        ItemStack::matches(result, &recipe.assemble()).then_some(recipe)
     }
 }
 
@@ -600,8 +749,13 @@ impl Slot for CraftingResultSlot {
         false
     }
 
-    fn may_pickup(&self, guard: &ContainerLockGuard, _player: &Player) -> bool {
-        self.has_valid_recipe_result(guard)
+    /// With `limited_crafting` only recipes the player has unlocked can be crafted.
+    ///
+    /// Based on Java's `ResultContainer.setRecipeUsed`.
+    fn may_pickup(&self, guard: &ContainerLockGuard, player: &Player) -> bool {
+        self.valid_recipe(guard).is_some_and(|recipe| {
+            !player.get_world().get_game_rule(&LIMITED_CRAFTING) || player.knows_recipe(recipe.id())
+        })
     }
 
     /// Result slots don't allow partial removal.
@@ -644,19 +798,34 @@ impl Slot for CraftingResultSlot {
     fn on_take(
         &self,
         guard: &mut ContainerLockGuard,
-        _stack: &ItemStack,
+        stack: &ItemStack,
         player: &Player,
     ) -> Option<ItemStack> {
-        // TODO: Add statistics/achievement tracking here.
-        // Java calls checkTakeAchievements(carried) which triggers:
-        // - carried.onCraftedBy(player, removeCount) for achievements
-        // - recipeCraftingHolder.awardUsedRecipes(player, items) for recipe unlocks
-
-        let mut remainder_overflow: Vec<ItemStack> = Vec::new();
+        // Java's checkTakeAchievements -> carried.onCraftedBy(player, removeCount).
+        if !stack.is_empty() {
+            player.award_stat_amount(Stat::crafted(stack.item()), stack.count());
+        }
         let crafting_id = ContainerId::from_arc(&self.crafting_container);
         let result_id = ContainerId::from_arc(&self.result_container);
         let is_2x2 = self.grid_size == 2;
 
+        // Java's recipeCraftingHolder.awardUsedRecipes
+        if let Some((recipe, ingredients)) = guard
+            .get_typed::<CraftingContainer>(crafting_id)
+            .and_then(|crafting| {
+                let recipe = recipe_manager::find_recipe(crafting, is_2x2)?;
+                Some((recipe, crafting.items().to_vec()))
+            })
+        {
+            player.trigger_criteria(CriteriaTrigger::RecipeCrafted {
+                recipe: recipe.id(),
+                ingredients: &ingredients,
+            });
+            player.award_recipes(&[recipe]);
+        }
+
+        let mut remainder_overflow: Vec<ItemStack> = Vec::new();
+
         // Get remainders and positioned input from recipe_manager
         let remainders_and_positioned = {
             let crafting = guard
@@ -748,6 +917,341 @@ impl Slot for CraftingResultSlot {
     }
 }
 
+/// The result slot of a merchant menu.
+///
+/// Taking the result pays for the active offer from the payment slots.
+///
+/// Based on Java's `MerchantResultSlot`.
+pub struct MerchantResultSlot {
+    container: SyncMerchantContainer,
+}
+
+impl MerchantResultSlot {
+    /// Creates a new merchant result slot.
+    #[must_use]
+    pub const fn new(container: SyncMerchantContainer) -> Self {
+        Self { container }
+    }
+
+    /// Returns a reference to the merchant container.
+    #[must_use]
+    pub fn container_ref(&self) -> ContainerRef {
+        ContainerRef::from(Arc::clone(&self.container))
+    }
+}
+
+impl Slot for MerchantResultSlot {
+    fn get_item<'a>(&self, guard: &'a ContainerLockGuard) -> &'a ItemStack {
+        guard
+            .get(ContainerId::from_arc(&self.container))
+            .expect("container not locked")
+            .get_item(merchant::RESULT_SLOT)
+    }
+
+    fn get_item_mut<'a>(&self, guard: &'a mut ContainerLockGuard) -> &'a mut ItemStack {
+        guard
+            .get_mut(ContainerId::from_arc(&self.container))
+            .expect("container not locked")
+            .get_item_mut(merchant::RESULT_SLOT)
+    }
+
+    fn set_item(&self, guard: &mut ContainerLockGuard, stack: ItemStack) {
+        guard
+            .get_mut(ContainerId::from_arc(&self.container))
+            .expect("container not locked")
+            .set_item(merchant::RESULT_SLOT, stack);
+    }
+
+    /// Cannot place items directly in the result slot.
+    fn may_place(&self, _stack: &ItemStack) -> bool {
+        false
+    }
+
+    /// Result slots don't allow partial removal.
+    fn allow_modification(&self, _guard: &ContainerLockGuard, _player: &Player) -> bool {
+        false
+    }
+
+    /// Always takes the entire stack regardless of `amount`.
+    fn remove(&self, guard: &mut ContainerLockGuard, _amount: i32) -> ItemStack {
+        mem::take(self.get_item_mut(guard))
+    }
+
+    fn set_changed(&self, guard: &mut ContainerLockGuard) {
+        guard
+            .get_mut(ContainerId::from_arc(&self.container))
+            .expect("container not locked")
+            .set_changed();
+    }
+
+    fn get_container_slot(&self) -> usize {
+        merchant::RESULT_SLOT
+    }
+
+    fn get_max_stack_size(&self, guard: &ContainerLockGuard) -> i32 {
+        guard
+            .get(ContainerId::from_arc(&self.container))
+            .expect("container not locked")
+            .get_max_stack_size()
+    }
+
+    /// Consumes the payment for the active offer and notifies the merchant.
+    ///
+    /// Based on Java's `MerchantResultSlot::onTake`.
+    fn on_take(
+        &self,
+        guard: &mut ContainerLockGuard,
+        _stack: &ItemStack,
+        player: &Player,
+    ) -> Option<ItemStack> {
+        let container = guard
+            .get_typed_mut::<MerchantContainer>(ContainerId::from_arc(&self.container))
+            .expect("merchant container not locked");
+        let index = container.active_offer()?;
+        let merchant = Arc::clone(container.merchant());
+        let merchant = merchant.as_merchant()?;
+        let offer = merchant.offers().get(index).cloned()?;
+
+        let mut buy_a = container.get_item(0).clone();
+        let mut buy_b = container.get_item(1).clone();
+        if offer.take(&mut buy_a, &mut buy_b) || offer.take(&mut buy_b, &mut buy_a) {
+            merchant.notify_trade(index);
+            player.award_stat(Stat::custom(CustomStat::TradedWithVillager));
+            container.set_item(0, buy_a);
+            container.set_item(1, buy_b);
+        }
+        None
+    }
+
+    /// Merchant result slots are "fake" - they don't persist items.
+    fn is_fake(&self) -> bool {
+        true
+    }
+}
+
+/// One of the two input slots of a cartography table.
+///
+/// Based on the anonymous slots in Java's `CartographyTableMenu`: the map slot
+/// only accepts filled maps and the additional slot paper, empty maps and
+/// glass panes.
+pub struct CartographySlot {
+    slot: NormalSlot,
+}
+
+impl CartographySlot {
+    /// Creates a new cartography slot from a `ContainerRef`.
+    pub fn new(container: impl Into<ContainerRef>, index: usize) -> Self {
+        Self {
+            slot: NormalSlot::new(container, index),
+        }
+    }
+
+    /// Returns a reference to the container.
+    #[must_use]
+    pub fn container_ref(&self) -> ContainerRef {
+        self.slot.container_ref()
+    }
+}
+
+impl Slot for CartographySlot {
+    fn get_item<'a>(&self, guard: &'a ContainerLockGuard) -> &'a ItemStack {
+        self.slot.get_item(guard)
+    }
+
+    fn get_item_mut<'a>(&self, guard: &'a mut ContainerLockGuard) -> &'a mut ItemStack {
+        self.slot.get_item_mut(guard)
+    }
+
+    fn set_item(&self, guard: &mut ContainerLockGuard, stack: ItemStack) {
+        self.slot.set_item(guard, stack);
+    }
+
+    fn set_changed(&self, guard: &mut ContainerLockGuard) {
+        self.slot.set_changed(guard);
+    }
+
+    fn get_container_slot(&self) -> usize {
+        self.slot.get_container_slot()
+    }
+
+    fn get_max_stack_size(&self, guard: &ContainerLockGuard) -> i32 {
+        self.slot.get_max_stack_size(guard)
+    }
+
+    fn may_place(&self, stack: &ItemStack) -> bool {
+        if self.get_container_slot() == cartography_table_menu::slots::MAP_SLOT {
+            stack.has(MAP_ID)
+        } else {
+            cartography_table_menu::is_additional_item(stack)
+        }
+    }
+}
+
+/// The payment slot of a beacon, holding one mineral item.
+///
+/// Based on Java's `BeaconMenu.PaymentSlot`.
+pub struct BeaconPaymentSlot {
+    slot: NormalSlot,
+}
+
+impl BeaconPaymentSlot {
+    /// Creates a new beacon payment slot from a `ContainerRef`.
+    pub fn new(container: impl Into<ContainerRef>, index: usize) -> Self {
+        Self {
+            slot: NormalSlot::new(container, index),
+        }
+    }
+
+    /// Returns a reference to the container.
+    #[must_use]
+    pub fn container_ref(&self) -> ContainerRef {
+        self.slot.container_ref()
+    }
+}
+
+impl Slot for BeaconPaymentSlot {
+    fn get_item<'a>(&self, guard: &'a ContainerLockGuard) -> &'a ItemStack {
+        self.slot.get_item(guard)
+    }
+
+    fn get_item_mut<'a>(&self, guard: &'a mut ContainerLockGuard) -> &'a mut ItemStack {
+        self.slot.get_item_mut(guard)
+    }
+
+    fn set_item(&self, guard: &mut ContainerLockGuard, stack: ItemStack) {
+        self.slot.set_item(guard, stack);
+    }
+
+    fn set_changed(&self, guard: &mut ContainerLockGuard) {
+        self.slot.set_changed(guard);
+    }
+
+    fn get_container_slot(&self) -> usize {
+        self.slot.get_container_slot()
+    }
+
+    fn get_max_stack_size(&self, _guard: &ContainerLockGuard) -> i32 {
+        1
+    }
+
+    fn may_place(&self, stack: &ItemStack) -> bool {
+        beacon_menu::is_payment_item(stack)
+    }
+}
+
+/// The result slot of a cartography table.
+///
+/// Taking the result consumes one of each input.
+pub struct CartographyResultSlot {
+    container: SyncCartographyContainer,
+    /// The position of the cartography table, where the take sound plays.
+    block_pos: BlockPos,
+}
+
+impl CartographyResultSlot {
+    /// Creates a new cartography result slot.
+    #[must_use]
+    pub const fn new(container: SyncCartographyContainer, block_pos: BlockPos) -> Self {
+        Self {
+            container,
+            block_pos,
+        }
+    }
+
+    /// Returns a reference to the cartography container.
+    #[must_use]
+    pub fn container_ref(&self) -> ContainerRef {
+        ContainerRef::from(Arc::clone(&self.container))
+    }
+}
+
+impl Slot for CartographyResultSlot {
+    fn get_item<'a>(&self, guard: &'a ContainerLockGuard) -> &'a ItemStack {
+        guard
+            .get(ContainerId::from_arc(&self.container))
+            .expect("container not locked")
+            .get_item(cartography_table_menu::slots::RESULT_SLOT)
+    }
+
+    fn get_item_mut<'a>(&self, guard: &'a mut ContainerLockGuard) -> &'a mut ItemStack {
+        guard
+            .get_mut(ContainerId::from_arc(&self.container))
+            .expect("container not locked")
+            .get_item_mut(cartography_table_menu::slots::RESULT_SLOT)
+    }
+
+    fn set_item(&self, guard: &mut ContainerLockGuard, stack: ItemStack) {
+        guard
+            .get_mut(ContainerId::from_arc(&self.container))
+            .expect("container not locked")
+            .set_item(cartography_table_menu::slots::RESULT_SLOT, stack);
+    }
+
+    /// Cannot place items directly in the result slot.
+    fn may_place(&self, _stack: &ItemStack) -> bool {
+        false
+    }
+
+    /// Result slots don't allow partial removal.
+    fn allow_modification(&self, _guard: &ContainerLockGuard, _player: &Player) -> bool {
+        false
+    }
+
+    /// Takes the entire stack, applying its pending lock or zoom.
+    fn remove(&self, guard: &mut ContainerLockGuard, _amount: i32) -> ItemStack {
+        let container = guard
+            .get_typed_mut::<CartographyContainer>(ContainerId::from_arc(&self.container))
+            .expect("cartography container not locked");
+        let mut taken =
+            mem::take(container.get_item_mut(cartography_table_menu::slots::RESULT_SLOT));
+        container.finish_result(&mut taken);
+        taken
+    }
+
+    fn set_changed(&self, guard: &mut ContainerLockGuard) {
+        guard
+            .get_mut(ContainerId::from_arc(&self.container))
+            .expect("container not locked")
+            .set_changed();
+    }
+
+    fn get_container_slot(&self) -> usize {
+        cartography_table_menu::slots::RESULT_SLOT
+    }
+
+    fn get_max_stack_size(&self, guard: &ContainerLockGuard) -> i32 {
+        guard
+            .get(ContainerId::from_arc(&self.container))
+            .expect("container not locked")
+            .get_max_stack_size()
+    }
+
+    /// Consumes one of each input and plays the take sound once per tick.
+    ///
+    /// Based on the result slot's `onTake` in Java's `CartographyTableMenu`.
+    fn on_take(
+        &self,
+        guard: &mut ContainerLockGuard,
+        _stack: &ItemStack,
+        player: &Player,
+    ) -> Option<ItemStack> {
+        let container = guard
+            .get_typed_mut::<CartographyContainer>(ContainerId::from_arc(&self.container))
+            .expect("cartography container not locked");
+        container.remove_item(cartography_table_menu::slots::MAP_SLOT, 1);
+        container.remove_item(cartography_table_menu::slots::ADDITIONAL_SLOT, 1);
+        if container.take_sound_due(player.get_world().game_time()) {
+            cartography_table_menu::play_take_sound(player, self.block_pos);
+        }
+        None
+    }
+
+    /// Cartography result slots are "fake" - they don't persist items.
+    fn is_fake(&self) -> bool {
+        true
+    }
+}
+
 /// Enum of all slot types that implement the Slot trait.
 #[enum_dispatch(Slot)]
 pub enum SlotType {
@@ -755,10 +1259,22 @@ pub enum SlotType {
     Normal(NormalSlot),
     /// Armor slot that only accepts armor items.
     Armor(ArmorSlot),
+    /// Shulker box slot that rejects nested shulker boxes.
+    ShulkerBox(ShulkerBoxSlot),
+    /// Enchanting table item or lapis slot.
+    Enchantment(EnchantmentSlot),
     /// Crafting grid slot for crafting input.
     CraftingGrid(CraftingGridSlot),
     /// Crafting result slot (fake, doesn't persist items).
     CraftingResult(CraftingResultSlot),
+    /// Merchant trade result slot (fake, doesn't persist items).
+    MerchantResult(MerchantResultSlot),
+    /// Cartography table map or additional slot.
+    Cartography(CartographySlot),
+    /// Cartography table result slot (fake, doesn't persist items).
+    CartographyResult(CartographyResultSlot),
+    /// Beacon payment slot.
+    BeaconPayment(BeaconPaymentSlot),
 }
 
 impl SlotType {
@@ -770,10 +1286,16 @@ impl SlotType {
         match self {
             SlotType::Normal(s) => vec![s.container_ref()],
             SlotType::Armor(s) => vec![s.container_ref()],
+            SlotType::ShulkerBox(s) => vec![s.container_ref()],
+            SlotType::Enchantment(s) => vec![s.container_ref()],
             SlotType::CraftingGrid(s) => vec![s.container_ref(), s.result_container_ref()],
             SlotType::CraftingResult(s) => {
                 vec![s.result_container_ref(), s.crafting_container_ref()]
             }
+            SlotType::MerchantResult(s) => vec![s.container_ref()],
+            SlotType::Cartography(s) => vec![s.container_ref()],
+            SlotType::CartographyResult(s) => vec![s.container_ref()],
+            SlotType::BeaconPayment(s) => vec![s.container_ref()],
         }
     }
 
@@ -787,6 +1309,9 @@ impl SlotType {
         match self {
             SlotType::Normal(s) => Some((s.container_ref().container_id(), s.get_container_slot())),
             SlotType::Armor(s) => Some((s.container_ref().container_id(), s.get_container_slot())),
+            SlotType::ShulkerBox(s) => {
+                Some((s.container_ref().container_id(), s.get_container_slot()))
+            }
             _ => None,
         }
     }
@@ -901,15 +1426,15 @@ mod tests {
             .get_typed_mut::<ResultContainer>(result_id)
             .expect("result container not locked")
             .set_item(0, ItemStack::with_count(&vanilla_items::OAK_PLANKS, 4));
-        assert!(!result_slot.has_valid_recipe_result(&guard));
+        assert!(result_slot.valid_recipe(&guard).is_none());
 
         input_slot.set_item(&mut guard, ItemStack::new(&vanilla_items::OAK_LOG));
-        assert!(result_slot.has_valid_recipe_result(&guard));
+        assert!(result_slot.valid_recipe(&guard).is_some());
 
         guard
             .get_typed_mut::<ResultContainer>(result_id)
             .expect("result container not locked")
             .set_item(0, ItemStack::new(&vanilla_items::STICK));
-        assert!(!result_slot.has_valid_recipe_result(&guard));
+        assert!(result_slot.valid_recipe(&guard).is_none());
     }
 }
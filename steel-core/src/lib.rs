@@ -10,14 +10,17 @@ pub mod chunk;
 pub mod chunk_saver;
 pub mod command;
 pub mod config;
+pub mod crash_report;
 pub(crate) mod enchantment_helper;
 pub mod entity;
 pub mod fluid;
 pub mod inventory;
 pub mod level_data;
+pub mod map;
 pub mod permission;
 pub mod physics;
 pub mod player;
+pub mod plugin;
 pub mod poi;
 pub(crate) mod portal;
 pub mod scoreboard;
@@ -0,0 +1,153 @@
+//! Filled maps: per-id map data, terrain rendering, and holder updates.
+//!
+//! Map data lives in a [`MapStorage`] shared by every world of a domain and
+//! is saved through the domain default world.
+
+pub mod saved_data;
+pub mod storage;
+mod update;
+
+pub use saved_data::{
+    MAP_SIZE, MAX_SCALE, MapBanner, MapDecoration, MapFrame, MapItemSavedData,
+    TRACKED_DECORATION_LIMIT,
+};
+pub use storage::{MapStorage, SharedMapData};
+
+use steel_registry::data_components::components::MapPostProcessing;
+use steel_registry::data_components::vanilla_components::{MAP_ID, MAP_POST_PROCESSING};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::vanilla_items;
+use steel_utils::BlockPos;
+
+use crate::inventory::container::Container as _;
+use crate::player::Player;
+use crate::player::player_inventory::PlayerInventory;
+use crate::world::World;
+
+/// Creates a filled map centered on the map grid cell around `(x, z)`.
+///
+/// Returns `None` while the world has no map storage.
+///
+/// Vanilla equivalent: `MapItem.create`.
+#[must_use]
+pub fn create_map(
+    world: &World,
+    x: f64,
+    z: f64,
+    scale: u8,
+    tracking_position: bool,
+    unlimited_tracking: bool,
+) -> Option<ItemStack> {
+    let data = MapItemSavedData::create_fresh(
+        x,
+        z,
+        scale,
+        tracking_position,
+        unlimited_tracking,
+        world.key.clone(),
+    );
+    let id = world.maps()?.create(data);
+    let mut stack = ItemStack::new(&vanilla_items::FILLED_MAP);
+    stack.set(MAP_ID, id);
+    Some(stack)
+}
+
+/// Applies and removes a pending cartography table operation on `stack`.
+///
+/// Vanilla equivalent: `MapItem.onCraftedBy`.
+pub fn apply_post_processing(maps: &MapStorage, stack: &mut ItemStack) {
+    let Some(&post_processing) = stack.get(MAP_POST_PROCESSING) else {
+        return;
+    };
+    stack.remove(MAP_POST_PROCESSING);
+    let Some((_, data)) = maps.get_for_item(stack) else {
+        return;
+    };
+    let copy = match post_processing {
+        MapPostProcessing::Lock => data.lock().locked(),
+        MapPostProcessing::Scale => data.lock().scaled(),
+    };
+    stack.set(MAP_ID, maps.create(copy));
+}
+
+/// Ticks every map in `player`'s inventory, redrawing held maps and sending
+/// their changes.
+///
+/// Vanilla equivalent: `MapItem.inventoryTick` together with
+/// `ServerPlayer.synchronizeSpecialItemUpdates`.
+pub(crate) fn tick_player_maps(player: &Player) {
+    let world = player.get_world();
+    let Some(maps) = world.maps() else {
+        return;
+    };
+    // Holders are checked by locking their inventories, so collect the maps
+    // before ticking them.
+    let held: Vec<(ItemStack, bool)> = {
+        let inventory = player.inventory.lock();
+        let selected = usize::from(inventory.get_selected_slot());
+        (0..inventory.get_container_size())
+            .filter_map(|slot| {
+                let stack = inventory.get_item(slot);
+                stack.has(MAP_ID).then(|| {
+                    let in_hand = slot == selected || slot == PlayerInventory::SLOT_OFFHAND;
+                    (stack.clone(), in_hand)
+                })
+            })
+            .collect()
+    };
+    if held.is_empty() {
+        return;
+    }
+    let server = player.server();
+    for (stack, in_hand) in held {
+        let Some((id, data)) = maps.get_for_item(&stack) else {
+            continue;
+        };
+        let mut data = data.lock();
+        data.tick_carried_by(&server, player, &stack, None);
+        if in_hand && !data.locked {
+            update::update(&world, player, &mut data);
+        }
+        if let Some(packet) = data.get_update_packet(id, player.uuid()) {
+            player.send_packet(packet);
+        }
+    }
+}
+
+/// Ticks the map in an item frame for every player in `world` and sends them
+/// its changes.
+///
+/// Vanilla equivalent: the item frame branch of `ServerEntity.sendChanges`.
+pub(crate) fn tick_framed_map(world: &World, stack: &ItemStack, frame: MapFrame) {
+    let Some((id, data)) = world.maps().and_then(|maps| maps.get_for_item(stack)) else {
+        return;
+    };
+    world.players.iter_players(|_, player| {
+        let mut data = data.lock();
+        data.tick_carried_by(&player.server(), player, stack, Some(frame));
+        if let Some(packet) = data.get_update_packet(id, player.uuid()) {
+            player.send_packet(packet);
+        }
+        true
+    });
+}
+
+/// Forgets the frame at `pos` on the map shown by `stack`.
+pub(crate) fn remove_framed_map(world: &World, stack: &ItemStack, pos: BlockPos, entity_id: i32) {
+    if let Some((_, data)) = world.maps().and_then(|maps| maps.get_for_item(stack)) {
+        data.lock().removed_from_frame(pos, entity_id);
+    }
+}
+
+/// Returns whether the map shown by `stack` already tracks too many banners
+/// and frames to be framed.
+#[must_use]
+pub(crate) fn is_tracked_count_over_limit(world: &World, stack: &ItemStack) -> bool {
+    world
+        .maps()
+        .and_then(|maps| maps.get_for_item(stack))
+        .is_some_and(|(_, data)| {
+            data.lock()
+                .is_tracked_count_over_limit(TRACKED_DECORATION_LIMIT)
+        })
+}
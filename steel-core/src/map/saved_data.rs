@@ -0,0 +1,827 @@
+//! The colors and decorations of a single map.
+
+use std::collections::BTreeMap;
+
+use steel_protocol::packets::game::{CMapItemData, MapDecoration as PacketMapDecoration, MapPatch};
+use steel_registry::data_components::components::MapId;
+use steel_registry::data_components::vanilla_components::{MAP_DECORATIONS, MAP_ID};
+use steel_registry::dye_color::DyeColor;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::map_decoration_type::MapDecorationTypeRef;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::vanilla_item_tags::ItemTag;
+use steel_registry::{
+    REGISTRY, RegistryEntry as _, vanilla_dimension_types, vanilla_map_decoration_types,
+};
+use steel_utils::{BlockPos, Identifier};
+use text_components::TextComponent;
+use uuid::Uuid;
+
+use crate::entity::{Entity as _, LivingEntity as _};
+use crate::inventory::container::Container as _;
+use crate::inventory::equipment::EquipmentSlot;
+use crate::player::Player;
+use crate::server::Server;
+use crate::world::World;
+
+/// Width and height of a map in pixels.
+pub const MAP_SIZE: usize = 128;
+/// Highest scale a map can be zoomed out to.
+pub const MAX_SCALE: u8 = 4;
+/// Most decorations that count towards the tracking limit, such as banners and
+/// frames, a map can hold.
+pub const TRACKED_DECORATION_LIMIT: i32 = 256;
+
+const HALF_MAP_SIZE: i32 = 64;
+/// Distance in map pixels within which an off-map player is still shown.
+const MAX_OFF_MAP_DISTANCE: f32 = 320.0;
+/// Ticks between decoration updates sent to a holder.
+const DECORATION_UPDATE_INTERVAL: i32 = 5;
+
+/// A decoration drawn on a map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapDecoration {
+    pub decoration_type: MapDecorationTypeRef,
+    pub x: i8,
+    pub y: i8,
+    pub rot: u8,
+    pub name: Option<TextComponent>,
+}
+
+impl MapDecoration {
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap,
+        reason = "there are far fewer than 2^31 decoration types"
+    )]
+    fn to_packet(&self) -> PacketMapDecoration {
+        PacketMapDecoration {
+            decoration_type: self.decoration_type.id() as i32,
+            x: self.x,
+            y: self.y,
+            rot: self.rot,
+            name: self.name.clone(),
+        }
+    }
+}
+
+/// A banner marked on a map with a filled map.
+///
+/// Vanilla equivalent: `MapBanner`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapBanner {
+    pub pos: BlockPos,
+    pub color: DyeColor,
+    pub name: Option<TextComponent>,
+}
+
+impl MapBanner {
+    /// Reads the banner at `pos`, or `None` when there is no banner.
+    ///
+    /// Vanilla equivalent: `MapBanner.fromWorld`.
+    #[must_use]
+    pub fn from_world(world: &World, pos: BlockPos) -> Option<Self> {
+        let block = world.get_block_state(pos).get_block();
+        if !block.has_tag(&BlockTag::BANNERS) {
+            return None;
+        }
+        let path = block.key.path.as_ref();
+        let color = path
+            .strip_suffix("_wall_banner")
+            .or_else(|| path.strip_suffix("_banner"))
+            .and_then(DyeColor::from_serialized_name)?;
+        let block_entity = world.get_block_entity(pos)?;
+        let nbt = block_entity.lock().save_custom_only();
+        let name = nbt
+            .get("CustomName")
+            .and_then(|tag| TextComponent::try_from_nbt(tag).ok());
+        Some(Self { pos, color, name })
+    }
+
+    /// Key of the banner's decoration.
+    #[must_use]
+    pub fn id(&self) -> String {
+        format!("banner-{},{},{}", self.pos.x(), self.pos.y(), self.pos.z())
+    }
+
+    /// The decoration type matching the banner color.
+    #[must_use]
+    pub const fn decoration_type(&self) -> MapDecorationTypeRef {
+        match self.color {
+            DyeColor::White => &vanilla_map_decoration_types::BANNER_WHITE,
+            DyeColor::Orange => &vanilla_map_decoration_types::BANNER_ORANGE,
+            DyeColor::Magenta => &vanilla_map_decoration_types::BANNER_MAGENTA,
+            DyeColor::LightBlue => &vanilla_map_decoration_types::BANNER_LIGHT_BLUE,
+            DyeColor::Yellow => &vanilla_map_decoration_types::BANNER_YELLOW,
+            DyeColor::Lime => &vanilla_map_decoration_types::BANNER_LIME,
+            DyeColor::Pink => &vanilla_map_decoration_types::BANNER_PINK,
+            DyeColor::Gray => &vanilla_map_decoration_types::BANNER_GRAY,
+            DyeColor::LightGray => &vanilla_map_decoration_types::BANNER_LIGHT_GRAY,
+            DyeColor::Cyan => &vanilla_map_decoration_types::BANNER_CYAN,
+            DyeColor::Purple => &vanilla_map_decoration_types::BANNER_PURPLE,
+            DyeColor::Blue => &vanilla_map_decoration_types::BANNER_BLUE,
+            DyeColor::Brown => &vanilla_map_decoration_types::BANNER_BROWN,
+            DyeColor::Green => &vanilla_map_decoration_types::BANNER_GREEN,
+            DyeColor::Red => &vanilla_map_decoration_types::BANNER_RED,
+            DyeColor::Black => &vanilla_map_decoration_types::BANNER_BLACK,
+        }
+    }
+}
+
+/// An item frame holding a copy of a map, with the frame's rotation in
+/// degrees.
+///
+/// Vanilla equivalent: `MapFrame`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapFrame {
+    pub pos: BlockPos,
+    pub rotation: i32,
+    pub entity_id: i32,
+}
+
+impl MapFrame {
+    fn id(&self) -> String {
+        frame_id(self.pos)
+    }
+}
+
+fn frame_id(pos: BlockPos) -> String {
+    format!("frame-{},{},{}", pos.x(), pos.y(), pos.z())
+}
+
+fn frame_decoration_id(entity_id: i32) -> String {
+    format!("frame-{entity_id}")
+}
+
+/// Tracks which parts of a map a holder has not been sent yet.
+///
+/// Vanilla equivalent: `MapItemSavedData.HoldingPlayer`.
+#[derive(Debug)]
+struct HoldingPlayer {
+    uuid: Uuid,
+    name: String,
+    dirty_data: bool,
+    min_dirty_x: usize,
+    min_dirty_y: usize,
+    max_dirty_x: usize,
+    max_dirty_y: usize,
+    dirty_decorations: bool,
+    tick: i32,
+    /// Which column group the next terrain update samples.
+    step: i32,
+}
+
+impl HoldingPlayer {
+    fn new(player: &Player) -> Self {
+        Self {
+            uuid: player.uuid(),
+            name: player.gameprofile.name.clone(),
+            dirty_data: true,
+            min_dirty_x: 0,
+            min_dirty_y: 0,
+            max_dirty_x: MAP_SIZE - 1,
+            max_dirty_y: MAP_SIZE - 1,
+            dirty_decorations: true,
+            tick: 0,
+            step: 0,
+        }
+    }
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "map coordinates are below 128"
+    )]
+    fn create_patch(&self, colors: &[u8]) -> MapPatch {
+        let width = self.max_dirty_x + 1 - self.min_dirty_x;
+        let height = self.max_dirty_y + 1 - self.min_dirty_y;
+        let mut patch = Vec::with_capacity(width * height);
+        for y in self.min_dirty_y..=self.max_dirty_y {
+            let row = self.min_dirty_x + y * MAP_SIZE;
+            patch.extend_from_slice(&colors[row..row + width]);
+        }
+        MapPatch {
+            start_x: self.min_dirty_x as u8,
+            start_y: self.min_dirty_y as u8,
+            width: width as u8,
+            height: height as u8,
+            colors: patch,
+        }
+    }
+
+    fn mark_colors_dirty(&mut self, x: usize, y: usize) {
+        if self.dirty_data {
+            self.min_dirty_x = self.min_dirty_x.min(x);
+            self.min_dirty_y = self.min_dirty_y.min(y);
+            self.max_dirty_x = self.max_dirty_x.max(x);
+            self.max_dirty_y = self.max_dirty_y.max(y);
+        } else {
+            self.dirty_data = true;
+            self.min_dirty_x = x;
+            self.min_dirty_y = y;
+            self.max_dirty_x = x;
+            self.max_dirty_y = y;
+        }
+    }
+}
+
+/// The saved state of one filled map.
+///
+/// Vanilla equivalent: `MapItemSavedData`.
+#[derive(Debug)]
+pub struct MapItemSavedData {
+    pub center_x: i32,
+    pub center_z: i32,
+    /// Key of the world the map shows.
+    pub dimension: Identifier,
+    pub tracking_position: bool,
+    pub unlimited_tracking: bool,
+    pub scale: u8,
+    pub locked: bool,
+    /// Packed colors, row by row.
+    pub colors: Box<[u8]>,
+    carried_by: Vec<HoldingPlayer>,
+    banners: BTreeMap<String, MapBanner>,
+    decorations: BTreeMap<String, MapDecoration>,
+    frame_markers: BTreeMap<String, MapFrame>,
+    tracked_decoration_count: i32,
+    dirty: bool,
+}
+
+impl MapItemSavedData {
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "mirrors vanilla's MapItemSavedData constructor"
+    )]
+    pub(crate) fn new(
+        center_x: i32,
+        center_z: i32,
+        scale: u8,
+        tracking_position: bool,
+        unlimited_tracking: bool,
+        locked: bool,
+        dimension: Identifier,
+        colors: Box<[u8]>,
+    ) -> Self {
+        Self {
+            center_x,
+            center_z,
+            dimension,
+            tracking_position,
+            unlimited_tracking,
+            scale,
+            locked,
+            colors,
+            carried_by: Vec::new(),
+            banners: BTreeMap::new(),
+            decorations: BTreeMap::new(),
+            frame_markers: BTreeMap::new(),
+            tracked_decoration_count: 0,
+            dirty: false,
+        }
+    }
+
+    /// Creates an empty map covering the 128 << `scale` wide square that
+    /// contains `(x, z)`.
+    ///
+    /// Vanilla equivalent: `MapItemSavedData.createFresh`.
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "world coordinates fit in i32"
+    )]
+    pub fn create_fresh(
+        x: f64,
+        z: f64,
+        scale: u8,
+        tracking_position: bool,
+        unlimited_tracking: bool,
+        dimension: Identifier,
+    ) -> Self {
+        let size = 128 << scale;
+        let center = |coordinate: f64| {
+            let cell = ((coordinate + 64.0) / f64::from(size)).floor() as i32;
+            cell * size + size / 2 - HALF_MAP_SIZE
+        };
+        let mut data = Self::new(
+            center(x),
+            center(z),
+            scale,
+            tracking_position,
+            unlimited_tracking,
+            false,
+            dimension,
+            vec![0; MAP_SIZE * MAP_SIZE].into_boxed_slice(),
+        );
+        data.dirty = true;
+        data
+    }
+
+    /// Returns a locked copy with the same colors and banners.
+    ///
+    /// Vanilla equivalent: `MapItemSavedData.locked`.
+    #[must_use]
+    pub fn locked(&self) -> Self {
+        let mut data = Self::new(
+            self.center_x,
+            self.center_z,
+            self.scale,
+            self.tracking_position,
+            self.unlimited_tracking,
+            true,
+            self.dimension.clone(),
+            self.colors.clone(),
+        );
+        data.banners.clone_from(&self.banners);
+        data.decorations.clone_from(&self.decorations);
+        data.tracked_decoration_count = self.tracked_decoration_count;
+        data.dirty = true;
+        data
+    }
+
+    /// Returns an empty copy zoomed out by one step.
+    ///
+    /// Vanilla equivalent: `MapItemSavedData.scaled`.
+    #[must_use]
+    pub fn scaled(&self) -> Self {
+        Self::create_fresh(
+            f64::from(self.center_x),
+            f64::from(self.center_z),
+            (self.scale + 1).min(MAX_SCALE),
+            self.tracking_position,
+            self.unlimited_tracking,
+            self.dimension.clone(),
+        )
+    }
+
+    /// Returns whether the map changed since it was last saved.
+    #[must_use]
+    pub const fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Marks the map as needing to be saved.
+    pub const fn set_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    pub(crate) const fn clear_dirty(&mut self) {
+        self.dirty = false;
+    }
+
+    /// Returns the banners marked on this map.
+    #[must_use]
+    pub fn banners(&self) -> impl Iterator<Item = &MapBanner> {
+        self.banners.values()
+    }
+
+    /// Returns the item frames holding this map.
+    #[must_use]
+    pub fn frames(&self) -> impl Iterator<Item = &MapFrame> {
+        self.frame_markers.values()
+    }
+
+    pub(crate) fn insert_banner(&mut self, banner: MapBanner) {
+        self.banners.insert(banner.id(), banner);
+    }
+
+    pub(crate) fn insert_frame(&mut self, frame: MapFrame) {
+        self.frame_markers.insert(frame.id(), frame);
+    }
+
+    /// Returns whether the map tracks at least `limit` decorations.
+    #[must_use]
+    pub const fn is_tracked_count_over_limit(&self, limit: i32) -> bool {
+        self.tracked_decoration_count >= limit
+    }
+
+    /// Updates the holders and decorations of this map for `player`, who
+    /// carries `stack` or sees it in the item frame `frame`.
+    ///
+    /// Vanilla equivalent: `MapItemSavedData.tickCarriedBy`.
+    pub fn tick_carried_by(
+        &mut self,
+        server: &Server,
+        player: &Player,
+        stack: &ItemStack,
+        frame: Option<MapFrame>,
+    ) {
+        let uuid = player.uuid();
+        if !self.carried_by.iter().any(|holder| holder.uuid == uuid) {
+            self.carried_by.push(HoldingPlayer::new(player));
+        }
+
+        let map_id = stack.get(MAP_ID).copied();
+        let carries_map = |holder: &Player| {
+            let inventory = holder.inventory.lock();
+            (0..inventory.get_container_size())
+                .any(|slot| inventory.get_item(slot).get(MAP_ID).copied() == map_id)
+        };
+        if !carries_map(player) {
+            self.remove_decoration(&player.gameprofile.name);
+        }
+
+        let mut index = 0;
+        while index < self.carried_by.len() {
+            let holder_uuid = self.carried_by[index].uuid;
+            let name = self.carried_by[index].name.clone();
+            let holder = server.get_player(&holder_uuid);
+            match &holder {
+                Some(holder)
+                    if !holder.is_removed() && (frame.is_some() || carries_map(holder)) =>
+                {
+                    let world = holder.get_world();
+                    if frame.is_none() && world.key == self.dimension && self.tracking_position {
+                        let position = holder.position();
+                        self.add_decoration(
+                            &vanilla_map_decoration_types::PLAYER,
+                            Some(&world),
+                            name.clone(),
+                            position.x,
+                            position.z,
+                            f64::from(holder.rotation().0),
+                            None,
+                        );
+                    }
+                    index += 1;
+                }
+                _ => {
+                    self.carried_by.remove(index);
+                    self.remove_decoration(&name);
+                }
+            }
+            if let Some(holder) = holder
+                && holder_uuid != uuid
+                && has_map_invisibility_item_equipped(&holder)
+            {
+                self.remove_decoration(&name);
+            }
+        }
+
+        if let Some(frame) = frame
+            && self.tracking_position
+        {
+            if let Some(existing) = self.frame_markers.get(&frame.id())
+                && existing.entity_id != frame.entity_id
+            {
+                let stale = frame_decoration_id(existing.entity_id);
+                self.remove_decoration(&stale);
+            }
+            self.add_decoration(
+                &vanilla_map_decoration_types::FRAME,
+                Some(&player.get_world()),
+                frame_decoration_id(frame.entity_id),
+                f64::from(frame.pos.x()),
+                f64::from(frame.pos.z()),
+                f64::from(frame.rotation),
+                None,
+            );
+            if self.frame_markers.insert(frame.id(), frame) != Some(frame) {
+                self.set_dirty();
+            }
+        }
+
+        if let Some(decorations) = stack.get(MAP_DECORATIONS) {
+            let world = player.get_world();
+            for (id, entry) in decorations.decorations() {
+                if !self.decorations.contains_key(id) {
+                    self.add_decoration(
+                        entry.decoration_type().value(),
+                        Some(&world),
+                        id.clone(),
+                        entry.x(),
+                        entry.z(),
+                        f64::from(entry.rotation()),
+                        None,
+                    );
+                }
+            }
+        }
+    }
+
+    fn remove_decoration(&mut self, id: &str) {
+        if let Some(decoration) = self.decorations.remove(id) {
+            if decoration.decoration_type.track_count {
+                self.tracked_decoration_count -= 1;
+            }
+            self.set_decorations_dirty();
+        }
+    }
+
+    /// Vanilla equivalent: `MapItemSavedData.addDecoration`.
+    #[expect(clippy::too_many_arguments, reason = "mirrors vanilla addDecoration")]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "vanilla computes map offsets as floats"
+    )]
+    fn add_decoration(
+        &mut self,
+        decoration_type: MapDecorationTypeRef,
+        world: Option<&World>,
+        id: String,
+        x: f64,
+        z: f64,
+        y_rot: f64,
+        name: Option<TextComponent>,
+    ) {
+        let scale = f64::from(1 << self.scale);
+        let x = ((x - f64::from(self.center_x)) / scale) as f32;
+        let z = ((z - f64::from(self.center_z)) / scale) as f32;
+        let Some((decoration_type, x, y, rot)) =
+            self.decoration_location_and_type(decoration_type, world, y_rot, x, z)
+        else {
+            self.remove_decoration(&id);
+            return;
+        };
+        let decoration = MapDecoration {
+            decoration_type,
+            x,
+            y,
+            rot,
+            name,
+        };
+        let old = self.decorations.insert(id, decoration.clone());
+        if old.as_ref() != Some(&decoration) {
+            if old.is_some_and(|old| old.decoration_type.track_count) {
+                self.tracked_decoration_count -= 1;
+            }
+            if decoration_type.track_count {
+                self.tracked_decoration_count += 1;
+            }
+            self.set_decorations_dirty();
+        }
+    }
+
+    /// Vanilla equivalent: `MapItemSavedData.calculateDecorationLocationAndType`.
+    fn decoration_location_and_type(
+        &self,
+        decoration_type: MapDecorationTypeRef,
+        world: Option<&World>,
+        y_rot: f64,
+        x: f32,
+        z: f32,
+    ) -> Option<(MapDecorationTypeRef, i8, i8, u8)> {
+        let clamped_x = clamp_map_coordinate(x);
+        let clamped_z = clamp_map_coordinate(z);
+        let inside = is_inside_map(x, z);
+        if decoration_type == &vanilla_map_decoration_types::PLAYER {
+            if inside {
+                return Some((
+                    decoration_type,
+                    clamped_x,
+                    clamped_z,
+                    self.rotation(world, y_rot),
+                ));
+            }
+            let off_map = if x.abs() < MAX_OFF_MAP_DISTANCE && z.abs() < MAX_OFF_MAP_DISTANCE {
+                &vanilla_map_decoration_types::PLAYER_OFF_MAP
+            } else if self.unlimited_tracking {
+                &vanilla_map_decoration_types::PLAYER_OFF_LIMITS
+            } else {
+                return None;
+            };
+            return Some((off_map, clamped_x, clamped_z, 0));
+        }
+        (inside || self.unlimited_tracking).then(|| {
+            (
+                decoration_type,
+                clamped_x,
+                clamped_z,
+                self.rotation(world, y_rot),
+            )
+        })
+    }
+
+    /// Spins decorations randomly in the nether, otherwise points them along
+    /// `y_rot` in sixteenths of a turn.
+    ///
+    /// Vanilla equivalent: `MapItemSavedData.calculateRotation`.
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "vanilla narrows the rotation to a byte"
+    )]
+    fn rotation(&self, world: Option<&World>, y_rot: f64) -> u8 {
+        if let Some(world) = world
+            && world.key == self.dimension
+            && world.dimension_type == &vanilla_dimension_types::THE_NETHER
+        {
+            let step = (world.game_time() / 10) as i32;
+            let spin = step
+                .wrapping_mul(step)
+                .wrapping_mul(34_187_121)
+                .wrapping_add(step.wrapping_mul(121));
+            return ((spin >> 15) & 15) as u8;
+        }
+        let adjusted = if y_rot < 0.0 {
+            y_rot - 8.0
+        } else {
+            y_rot + 8.0
+        };
+        (adjusted * 16.0 / 360.0) as i32 as u8
+    }
+
+    /// Adds or removes the banner at `pos`, returning whether anything changed.
+    ///
+    /// Vanilla equivalent: `MapItemSavedData.toggleBanner`.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "vanilla computes map offsets as floats"
+    )]
+    pub fn toggle_banner(&mut self, world: &World, pos: BlockPos) -> bool {
+        let x = f64::from(pos.x()) + 0.5;
+        let z = f64::from(pos.z()) + 0.5;
+        let scale = f64::from(1 << self.scale);
+        let dx = ((x - f64::from(self.center_x)) / scale) as f32;
+        let dz = ((z - f64::from(self.center_z)) / scale) as f32;
+        if !is_inside_map(dx, dz) {
+            return false;
+        }
+        let Some(banner) = MapBanner::from_world(world, pos) else {
+            return false;
+        };
+        let id = banner.id();
+        if self.banners.get(&id) == Some(&banner) {
+            self.banners.remove(&id);
+            self.remove_decoration(&id);
+            self.set_dirty();
+            return true;
+        }
+        if self.is_tracked_count_over_limit(TRACKED_DECORATION_LIMIT) {
+            return false;
+        }
+        self.banners.insert(id.clone(), banner.clone());
+        self.add_decoration(
+            banner.decoration_type(),
+            Some(world),
+            id,
+            x,
+            z,
+            180.0,
+            banner.name,
+        );
+        self.set_dirty();
+        true
+    }
+
+    /// Drops banners in the column at `(x, z)` that were broken or changed.
+    ///
+    /// Vanilla equivalent: `MapItemSavedData.checkBanners`.
+    pub fn check_banners(&mut self, world: &World, x: i32, z: i32) {
+        let stale: Vec<String> = self
+            .banners
+            .iter()
+            .filter(|(_, banner)| banner.pos.x() == x && banner.pos.z() == z)
+            .filter(|(_, banner)| MapBanner::from_world(world, banner.pos).as_ref() != Some(banner))
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in stale {
+            self.banners.remove(&id);
+            self.remove_decoration(&id);
+            self.set_dirty();
+        }
+    }
+
+    /// Forgets the frame at `pos` once the map was taken out of it.
+    ///
+    /// Vanilla equivalent: `MapItemSavedData.removedFromFrame`.
+    pub fn removed_from_frame(&mut self, pos: BlockPos, entity_id: i32) {
+        self.remove_decoration(&frame_decoration_id(entity_id));
+        self.frame_markers.remove(&frame_id(pos));
+        self.set_dirty();
+    }
+
+    /// Sets a pixel, returning whether it changed.
+    ///
+    /// Vanilla equivalent: `MapItemSavedData.updateColor`.
+    pub fn update_color(&mut self, x: usize, y: usize, color: u8) -> bool {
+        if self.colors[x + y * MAP_SIZE] == color {
+            return false;
+        }
+        self.set_color(x, y, color);
+        true
+    }
+
+    /// Vanilla equivalent: `MapItemSavedData.setColor`.
+    pub fn set_color(&mut self, x: usize, y: usize, color: u8) {
+        self.colors[x + y * MAP_SIZE] = color;
+        self.set_dirty();
+        for holder in &mut self.carried_by {
+            holder.mark_colors_dirty(x, y);
+        }
+    }
+
+    fn set_decorations_dirty(&mut self) {
+        for holder in &mut self.carried_by {
+            holder.dirty_decorations = true;
+        }
+    }
+
+    /// Advances the terrain update step of `player`, returning the new step,
+    /// or `None` when they do not hold this map.
+    pub(crate) fn next_step(&mut self, player: Uuid) -> Option<i32> {
+        let holder = self
+            .carried_by
+            .iter_mut()
+            .find(|holder| holder.uuid == player)?;
+        holder.step += 1;
+        Some(holder.step)
+    }
+
+    /// Returns the changes `player` has not been sent yet, if any.
+    ///
+    /// Vanilla equivalent: `MapItemSavedData.getUpdatePacket`.
+    pub fn get_update_packet(&mut self, map_id: MapId, player: Uuid) -> Option<CMapItemData> {
+        let holder = self
+            .carried_by
+            .iter_mut()
+            .find(|holder| holder.uuid == player)?;
+        let color_patch = holder.dirty_data.then(|| {
+            holder.dirty_data = false;
+            holder.create_patch(&self.colors)
+        });
+        let decorations = if holder.dirty_decorations && {
+            let tick = holder.tick;
+            holder.tick += 1;
+            tick % DECORATION_UPDATE_INTERVAL == 0
+        } {
+            holder.dirty_decorations = false;
+            Some(
+                self.decorations
+                    .values()
+                    .map(MapDecoration::to_packet)
+                    .collect(),
+            )
+        } else {
+            None
+        };
+        if decorations.is_none() && color_patch.is_none() {
+            return None;
+        }
+        Some(CMapItemData {
+            map_id: map_id.id(),
+            scale: self.scale,
+            locked: self.locked,
+            decorations,
+            color_patch,
+        })
+    }
+}
+
+/// Vanilla equivalent: `MapItemSavedData.clampMapCoordinate`.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "the value is between -126 and 126"
+)]
+fn clamp_map_coordinate(delta: f32) -> i8 {
+    if delta <= -63.0 {
+        -128
+    } else if delta >= 63.0 {
+        127
+    } else {
+        (delta * 2.0 + 0.5) as i8
+    }
+}
+
+fn is_inside_map(x: f32, z: f32) -> bool {
+    (-63.0..=63.0).contains(&x) && (-63.0..=63.0).contains(&z)
+}
+
+/// Vanilla equivalent: `MapItemSavedData.hasMapInvisibilityItemEquipped`.
+fn has_map_invisibility_item_equipped(player: &Player) -> bool {
+    EquipmentSlot::ALL.into_iter().any(|slot| {
+        let mut invisible = false;
+        if slot.is_armor() {
+            player.with_equipment_slot(slot, &mut |stack| {
+                invisible = REGISTRY
+                    .items
+                    .is_in_tag(stack.item(), &ItemTag::MAP_INVISIBILITY_EQUIPMENT);
+            });
+        }
+        invisible
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamps_and_centers_like_vanilla() {
+        assert_eq!(clamp_map_coordinate(-63.0), -128);
+        assert_eq!(clamp_map_coordinate(63.0), 127);
+        assert_eq!(clamp_map_coordinate(10.25), 21);
+        assert_eq!(clamp_map_coordinate(-10.0), -19);
+
+        let map = MapItemSavedData::create_fresh(
+            100.0,
+            -300.0,
+            1,
+            true,
+            false,
+            Identifier::vanilla_static("overworld"),
+        );
+        assert_eq!((map.center_x, map.center_z), (64, -192));
+        assert_eq!(map.colors.len(), MAP_SIZE * MAP_SIZE);
+        assert_eq!(map.scaled().scale, 2);
+    }
+}
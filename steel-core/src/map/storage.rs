@@ -0,0 +1,358 @@
+//! Domain-scoped storage of map data by map id.
+
+use std::{
+    collections::BTreeMap,
+    io,
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicI32, Ordering},
+    },
+};
+
+use rustc_hash::FxHashMap;
+use steel_registry::data_components::components::MapId;
+use steel_registry::data_components::vanilla_components::MAP_ID;
+use steel_registry::dye_color::DyeColor;
+use steel_registry::item_stack::ItemStack;
+use steel_utils::locks::{AsyncMutex, SyncMutex, SyncRwLock};
+use steel_utils::saved_data::{SavedDataManager, names as saved_data_names};
+use steel_utils::text::component_from_json;
+use steel_utils::{BlockPos, Identifier};
+use tokio::task::spawn_blocking;
+use wincode::{SchemaRead, SchemaWrite};
+
+use super::saved_data::{MAP_SIZE, MapBanner, MapFrame, MapItemSavedData};
+use crate::{server::worlds::WorldMap, world::World};
+
+/// Map data shared by every holder and frame of one map id.
+pub type SharedMapData = Arc<SyncMutex<MapItemSavedData>>;
+
+/// Every map of one domain, keyed by map id.
+///
+/// Vanilla keeps maps in the overworld's saved data; Steel keeps them in the
+/// domain default world so map ids are shared across the domain's worlds.
+pub struct MapStorage {
+    maps: SyncRwLock<FxHashMap<i32, SharedMapData>>,
+    /// The last map id handed out, or -1 before the first map.
+    last_id: AtomicI32,
+    /// Set when a map was added since the last save.
+    index_dirty: AtomicBool,
+}
+
+impl MapStorage {
+    fn from_persistent(persistent: PersistentMaps) -> io::Result<Self> {
+        let maps = persistent
+            .maps
+            .into_iter()
+            .map(|map| Ok((map.id, Arc::new(SyncMutex::new(map.into_data()?)))))
+            .collect::<io::Result<_>>()?;
+        Ok(Self {
+            maps: SyncRwLock::new(maps),
+            last_id: AtomicI32::new(persistent.last_id),
+            index_dirty: AtomicBool::new(false),
+        })
+    }
+
+    fn empty() -> Self {
+        Self {
+            maps: SyncRwLock::new(FxHashMap::default()),
+            last_id: AtomicI32::new(-1),
+            index_dirty: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the data of map `id`, if it exists.
+    #[must_use]
+    pub fn get(&self, id: MapId) -> Option<SharedMapData> {
+        self.maps.read().get(&id.id()).cloned()
+    }
+
+    /// Returns the id and data of the map shown by `stack`.
+    ///
+    /// Vanilla equivalent: `MapItem.getSavedData(ItemStack, Level)`.
+    #[must_use]
+    pub fn get_for_item(&self, stack: &ItemStack) -> Option<(MapId, SharedMapData)> {
+        let id = *stack.get(MAP_ID)?;
+        Some((id, self.get(id)?))
+    }
+
+    /// Stores `data` under a new map id and returns the id.
+    ///
+    /// Vanilla equivalent: `ServerLevel.getFreeMapId` followed by `setMapData`.
+    pub fn create(&self, data: MapItemSavedData) -> MapId {
+        let id = self.last_id.fetch_add(1, Ordering::Relaxed) + 1;
+        self.maps.write().insert(id, Arc::new(SyncMutex::new(data)));
+        self.index_dirty.store(true, Ordering::Relaxed);
+        MapId::new(id)
+    }
+
+    /// Snapshots every map when any of them changed, clearing their dirty
+    /// flags.
+    fn pending_save(&self) -> Option<PersistentMaps> {
+        let maps = self.maps.read();
+        let index_dirty = self.index_dirty.swap(false, Ordering::Relaxed);
+        let any_dirty = maps.values().any(|data| data.lock().is_dirty());
+        if !index_dirty && !any_dirty {
+            return None;
+        }
+        let mut persistent: Vec<PersistentMap> = maps
+            .iter()
+            .map(|(&id, data)| {
+                let mut data = data.lock();
+                data.clear_dirty();
+                PersistentMap::from_data(id, &data)
+            })
+            .collect();
+        persistent.sort_unstable_by_key(|map| map.id);
+        Some(PersistentMaps {
+            last_id: self.last_id.load(Ordering::Relaxed),
+            maps: persistent,
+        })
+    }
+}
+
+/// Loaded map storages keyed by Steel domain.
+pub(crate) struct DomainMaps {
+    storages: BTreeMap<String, Arc<MapStorage>>,
+    save_lock: AsyncMutex<()>,
+}
+
+impl DomainMaps {
+    /// Loads the maps of each domain from its default world and hands them to
+    /// every world of the domain.
+    pub(crate) fn load(worlds: &WorldMap) -> io::Result<Self> {
+        let mut domains = worlds.domain_names().collect::<Vec<_>>();
+        domains.sort_unstable();
+        let mut storages = BTreeMap::new();
+        for domain in domains {
+            let world = domain_default_world(worlds, domain)?;
+            let storage = match world
+                .saved_data
+                .sync_load_wincode::<PersistentMaps>(saved_data_names::MAPS)
+                .map_err(|error| maps_io_error(domain, error))?
+            {
+                Some(persistent) => MapStorage::from_persistent(persistent)
+                    .map_err(|error| maps_io_error(domain, error))?,
+                None => MapStorage::empty(),
+            };
+            let storage = Arc::new(storage);
+            for world in worlds.worlds_in_domain(domain) {
+                world.set_maps(Arc::clone(&storage));
+            }
+            storages.insert(domain.to_owned(), storage);
+        }
+        Ok(Self {
+            storages,
+            save_lock: AsyncMutex::new(()),
+        })
+    }
+
+    /// Returns the maps of a domain.
+    #[must_use]
+    pub(crate) fn get(&self, domain: &str) -> Option<&Arc<MapStorage>> {
+        self.storages.get(domain)
+    }
+
+    /// Saves the maps of every domain where a map changed and returns the
+    /// number of domains written.
+    pub(crate) async fn save(&self, worlds: &WorldMap) -> io::Result<usize> {
+        let _save_guard = self.save_lock.lock().await;
+        let mut saved = 0;
+        for (domain, storage) in &self.storages {
+            let Some(snapshot) = storage.pending_save() else {
+                continue;
+            };
+            let saved_data: SavedDataManager =
+                domain_default_world(worlds, domain)?.saved_data.clone();
+            spawn_blocking(move || saved_data.sync_save_wincode(saved_data_names::MAPS, &snapshot))
+                .await
+                .map_err(io::Error::other)?
+                .map_err(|error| maps_io_error(domain, error))?;
+            saved += 1;
+        }
+        Ok(saved)
+    }
+}
+
+#[derive(SchemaWrite, SchemaRead)]
+struct PersistentMaps {
+    last_id: i32,
+    maps: Vec<PersistentMap>,
+}
+
+#[derive(SchemaWrite, SchemaRead)]
+struct PersistentMap {
+    id: i32,
+    dimension: String,
+    center_x: i32,
+    center_z: i32,
+    scale: u8,
+    tracking_position: bool,
+    unlimited_tracking: bool,
+    locked: bool,
+    colors: Vec<u8>,
+    banners: Vec<PersistentBanner>,
+    frames: Vec<PersistentFrame>,
+}
+
+#[derive(SchemaWrite, SchemaRead)]
+struct PersistentBanner {
+    pos: [i32; 3],
+    color: i32,
+    /// The custom name as JSON text.
+    name: Option<String>,
+}
+
+#[derive(SchemaWrite, SchemaRead)]
+struct PersistentFrame {
+    pos: [i32; 3],
+    rotation: i32,
+    entity_id: i32,
+}
+
+impl PersistentMap {
+    fn from_data(id: i32, data: &MapItemSavedData) -> Self {
+        Self {
+            id,
+            dimension: data.dimension.to_string(),
+            center_x: data.center_x,
+            center_z: data.center_z,
+            scale: data.scale,
+            tracking_position: data.tracking_position,
+            unlimited_tracking: data.unlimited_tracking,
+            locked: data.locked,
+            colors: data.colors.to_vec(),
+            banners: data
+                .banners()
+                .map(|banner| PersistentBanner {
+                    pos: [banner.pos.x(), banner.pos.y(), banner.pos.z()],
+                    color: banner.color.id(),
+                    name: banner
+                        .name
+                        .as_ref()
+                        .and_then(|name| serde_json::to_string(name).ok()),
+                })
+                .collect(),
+            frames: data
+                .frames()
+                .map(|frame| PersistentFrame {
+                    pos: [frame.pos.x(), frame.pos.y(), frame.pos.z()],
+                    rotation: frame.rotation,
+                    entity_id: frame.entity_id,
+                })
+                .collect(),
+        }
+    }
+
+    fn into_data(self) -> io::Result<MapItemSavedData> {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+        let dimension = Identifier::from_str(&self.dimension)
+            .map_err(|_| invalid(format!("map {} has invalid dimension", self.id)))?;
+        if self.colors.len() != MAP_SIZE * MAP_SIZE {
+            return Err(invalid(format!(
+                "map {} has a malformed color array",
+                self.id
+            )));
+        }
+        let mut data = MapItemSavedData::new(
+            self.center_x,
+            self.center_z,
+            self.scale,
+            self.tracking_position,
+            self.unlimited_tracking,
+            self.locked,
+            dimension,
+            self.colors.into_boxed_slice(),
+        );
+        for banner in self.banners {
+            let [x, y, z] = banner.pos;
+            data.insert_banner(MapBanner {
+                pos: BlockPos::new(x, y, z),
+                color: DyeColor::by_id(banner.color),
+                name: banner.name.and_then(|name| component_from_json(&name).ok()),
+            });
+        }
+        for frame in self.frames {
+            let [x, y, z] = frame.pos;
+            data.insert_frame(MapFrame {
+                pos: BlockPos::new(x, y, z),
+                rotation: frame.rotation,
+                entity_id: frame.entity_id,
+            });
+        }
+        Ok(data)
+    }
+}
+
+fn domain_default_world<'a>(worlds: &'a WorldMap, domain: &str) -> io::Result<&'a World> {
+    worlds
+        .default_world(domain)
+        .map(AsRef::as_ref)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("domain '{domain}' has no loaded default world"),
+            )
+        })
+}
+
+fn maps_io_error(domain: &str, error: io::Error) -> io::Error {
+    io::Error::new(
+        error.kind(),
+        format!("map data I/O failed for domain '{domain}': {error}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env::temp_dir,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use super::*;
+
+    #[test]
+    fn persisted_maps_round_trip_and_become_clean_after_save() {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time should be after Unix epoch")
+            .as_nanos();
+        let path = temp_dir().join(format!("steel-maps-{unique}"));
+        let manager = SavedDataManager::new(Some(&path));
+
+        let storage = MapStorage::empty();
+        let mut data = MapItemSavedData::create_fresh(
+            10.0,
+            10.0,
+            0,
+            true,
+            false,
+            Identifier::vanilla_static("overworld"),
+        );
+        data.set_color(3, 4, 42);
+        let id = storage.create(data);
+        assert_eq!(id.id(), 0);
+
+        let snapshot = storage.pending_save().expect("new map should be dirty");
+        assert!(storage.pending_save().is_none());
+        manager
+            .sync_save_wincode(saved_data_names::MAPS, &snapshot)
+            .expect("maps should save");
+
+        let loaded = manager
+            .sync_load_wincode::<PersistentMaps>(saved_data_names::MAPS)
+            .expect("maps should load")
+            .expect("maps file should exist");
+        let storage = MapStorage::from_persistent(loaded).expect("maps should decode");
+        let data = storage.get(id).expect("map should be restored");
+        assert_eq!(data.lock().colors[3 + 4 * MAP_SIZE], 42);
+        assert_eq!(
+            storage.create(MapItemSavedData::scaled(&data.lock())).id(),
+            1
+        );
+
+        let _ = std::fs::remove_dir_all(path);
+    }
+}
@@ -0,0 +1,233 @@
+//! Renders the terrain around a player onto a map they hold.
+
+use steel_registry::REGISTRY;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::map_color::{MapColor, MapColorBrightness};
+use steel_utils::{BlockPos, BlockStateId, ChunkPos, Direction};
+
+use super::saved_data::{MAP_SIZE, MapItemSavedData};
+use crate::behavior::BlockStateBehaviorExt as _;
+use crate::chunk::chunk_access::ChunkAccess;
+use crate::chunk::heightmap::HeightmapType;
+use crate::entity::Entity as _;
+use crate::player::Player;
+use crate::world::World;
+
+/// Colors around `player` that changed in `world`, refreshing one in sixteen
+/// columns each tick and following changed columns further.
+///
+/// Vanilla equivalent: `MapItem.update`.
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    reason = "pixel indices are checked to lie within the map"
+)]
+pub(super) fn update(world: &World, player: &Player, data: &mut MapItemSavedData) {
+    if world.key != data.dimension {
+        return;
+    }
+    let Some(step) = data.next_step(player.uuid()) else {
+        return;
+    };
+    let scale = 1 << data.scale;
+    let position = player.position();
+    let player_x = (position.x - f64::from(data.center_x)).floor() as i32 / scale + 64;
+    let player_z = (position.z - f64::from(data.center_z)).floor() as i32 / scale + 64;
+    let has_ceiling = world.dimension_type.has_ceiling;
+    let mut radius = 128 / scale;
+    if has_ceiling {
+        radius /= 2;
+    }
+
+    let mut found_change = false;
+    for img_x in player_x - radius + 1..player_x + radius {
+        if (img_x & 15) != (step & 15) && !found_change {
+            continue;
+        }
+        found_change = false;
+        let mut previous_height = 0.0;
+        for img_y in player_z - radius - 1..player_z + radius {
+            if !(0..MAP_SIZE as i32).contains(&img_x) || !(-1..MAP_SIZE as i32).contains(&img_y) {
+                continue;
+            }
+            let dist2 = (img_x - player_x).pow(2) + (img_y - player_z).pow(2);
+            let dither_black = dist2 > (radius - 2) * (radius - 2);
+            let x_center = (data.center_x / scale + img_x - 64) * scale;
+            let z_center = (data.center_z / scale + img_y - 64) * scale;
+
+            let Some(sample) = world.chunk_map.with_full_chunk(
+                ChunkPos::new(x_center >> 4, z_center >> 4),
+                |chunk| {
+                    if has_ceiling {
+                        Some(ceiling_sample(x_center, z_center))
+                    } else {
+                        surface_sample(world, chunk, x_center, z_center, scale)
+                    }
+                },
+            ) else {
+                continue;
+            };
+            let Some(sample) = sample else {
+                continue;
+            };
+            for &(x, z) in &sample.columns {
+                data.check_banners(world, x, z);
+            }
+
+            let parity = (img_x + img_y) & 1;
+            let brightness = if sample.color == MapColor::WATER {
+                let diff = f64::from(sample.water_depth) * 0.1 + f64::from(parity) * 0.2;
+                if diff < 0.5 {
+                    MapColorBrightness::High
+                } else if diff > 0.9 {
+                    MapColorBrightness::Low
+                } else {
+                    MapColorBrightness::Normal
+                }
+            } else {
+                let diff = (sample.height - previous_height) * 4.0 / f64::from(scale + 4)
+                    + (f64::from(parity) - 0.5) * 0.4;
+                if diff > 0.6 {
+                    MapColorBrightness::High
+                } else if diff < -0.6 {
+                    MapColorBrightness::Low
+                } else {
+                    MapColorBrightness::Normal
+                }
+            };
+            previous_height = sample.height;
+
+            if img_y >= 0 && dist2 < radius * radius && (!dither_black || parity != 0) {
+                found_change |= data.update_color(
+                    img_x as usize,
+                    img_y as usize,
+                    sample.color.packed_id(brightness),
+                );
+            }
+        }
+    }
+}
+
+/// The dominant color and average height of the blocks behind one pixel.
+struct PixelSample {
+    color: MapColor,
+    height: f64,
+    water_depth: i32,
+    /// Block columns whose banners must be checked.
+    columns: Vec<(i32, i32)>,
+}
+
+/// Vanilla draws dimensions with a ceiling as dirt and stone noise.
+fn ceiling_sample(x: i32, z: i32) -> PixelSample {
+    let seed = x.wrapping_add(z.wrapping_mul(231_871));
+    let seed = seed
+        .wrapping_mul(seed)
+        .wrapping_mul(31_287_121)
+        .wrapping_add(seed.wrapping_mul(11));
+    PixelSample {
+        color: if (seed >> 20) & 1 == 0 {
+            MapColor::DIRT
+        } else {
+            MapColor::STONE
+        },
+        height: 100.0,
+        water_depth: 0,
+        columns: Vec::new(),
+    }
+}
+
+/// Samples the top non-transparent block of every column behind a pixel.
+#[expect(
+    clippy::cast_sign_loss,
+    reason = "local coordinates are masked to 0..16"
+)]
+fn surface_sample(
+    world: &World,
+    chunk: &ChunkAccess,
+    x_center: i32,
+    z_center: i32,
+    scale: i32,
+) -> Option<PixelSample> {
+    chunk.as_full()?;
+    let min_y = world.get_min_y();
+    // Vanilla's LinkedHashMultiset: the first color reached wins ties.
+    let mut counts: Vec<(MapColor, i32)> = Vec::new();
+    let mut height = 0.0;
+    let mut water_depth = 0;
+    let mut columns = Vec::with_capacity((scale * scale) as usize);
+    for dx in 0..scale {
+        for dz in 0..scale {
+            let (x, z) = (x_center + dx, z_center + dz);
+            let mut y = chunk.height_at(
+                HeightmapType::WorldSurface,
+                (x & 15) as usize,
+                (z & 15) as usize,
+            );
+            let color = if y <= min_y {
+                MapColor::STONE
+            } else {
+                let mut state;
+                loop {
+                    y -= 1;
+                    state = chunk.get_block_state(BlockPos::new(x, y, z));
+                    if state.get_map_color() != MapColor::NONE || y <= min_y {
+                        break;
+                    }
+                }
+                if y > min_y && state.has_fluid() {
+                    let mut below_y = y - 1;
+                    loop {
+                        let below = chunk.get_block_state(BlockPos::new(x, below_y, z));
+                        below_y -= 1;
+                        water_depth += 1;
+                        if below_y <= min_y || !below.has_fluid() {
+                            break;
+                        }
+                    }
+                    correct_color_for_fluid_block(state, BlockPos::new(x, y, z))
+                } else {
+                    state.get_map_color()
+                }
+            };
+            columns.push((x, z));
+            height += f64::from(y) / f64::from(scale * scale);
+            match counts.iter_mut().find(|(counted, _)| *counted == color) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((color, 1)),
+            }
+        }
+    }
+    let color = counts
+        .iter()
+        .fold(
+            None,
+            |best: Option<(MapColor, i32)>, &(color, count)| match best {
+                Some((_, best_count)) if best_count >= count => best,
+                _ => Some((color, count)),
+            },
+        )
+        .map_or(MapColor::NONE, |(color, _)| color);
+    Some(PixelSample {
+        color,
+        height,
+        water_depth: water_depth / (scale * scale),
+        columns,
+    })
+}
+
+/// Shows the fluid of a waterlogged block unless its top face is solid.
+///
+/// Vanilla equivalent: `MapItem.getCorrectStateForFluidBlock`.
+fn correct_color_for_fluid_block(state: BlockStateId, pos: BlockPos) -> MapColor {
+    let fluid = state.get_fluid_state();
+    if fluid.is_empty() || state.is_face_sturdy_at(pos, Direction::Up) {
+        return state.get_map_color();
+    }
+    REGISTRY
+        .blocks
+        .by_key(&fluid.fluid_id.block)
+        .map_or(MapColor::NONE, |block| {
+            block.default_state().get_map_color()
+        })
+}
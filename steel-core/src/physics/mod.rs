@@ -24,7 +24,8 @@ pub(crate) use entity_move::move_entity;
 pub use entity_move::{MoveResult, MoverType};
 pub(crate) use movement_validation::ClientAuthoredMovementState;
 pub use movement_validation::{
-    MOVEMENT_ERROR_THRESHOLD, MovementCollisionValidation, movement_error_delta,
+    MOVEMENT_ERROR_THRESHOLD, MovementCollisionValidation, PhaseThroughValidation, ground_probe,
+    movement_error_delta,
 };
 pub(crate) use physics_state::EntityPhysicsState;
 pub use shapes::{collide, join_is_not_empty, merged_face_occludes, translate_shape};
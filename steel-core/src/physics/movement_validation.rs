@@ -1,6 +1,7 @@
 //! Shared vanilla movement-validation helpers for client-authored movement.
 
 use glam::DVec3;
+use steel_utils::WorldAabb;
 
 /// Movement error threshold for anti-cheat validation (squared distance).
 /// Vanilla uses 0.0625 (1/16 block squared).
@@ -12,6 +13,12 @@ pub const MOVEMENT_ERROR_THRESHOLD: f64 = 0.0625;
 /// finite Y residual before the moved-wrongly check.
 pub const Y_TOLERANCE: f64 = 0.5;
 
+/// Horizontal slack of the box searched for blocks around a client-authored entity.
+pub const GROUND_PROBE_INFLATE: f64 = 0.0625;
+
+/// Depth below the feet of the box searched for blocks around a client-authored entity.
+pub const GROUND_PROBE_DEPTH: f64 = 0.55;
+
 /// Collision state used to decide whether a client-authored movement is accepted.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MovementCollisionValidation {
@@ -33,6 +40,45 @@ impl MovementCollisionValidation {
     }
 }
 
+/// Replay state used to reject client movement that passes through collision.
+///
+/// Vanilla zeroes the Y residual before its moved-wrongly check and skips that
+/// check for creative players, so a client can claim to drop through a floor
+/// or clip through a thin wall. This rejects targets the server replay could
+/// only reach by crossing collision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhaseThroughValidation {
+    /// Whether the entity bypasses collision physics.
+    pub no_physics: bool,
+    /// Whether a recent server impulse makes the replay unreliable.
+    pub in_impulse_grace: bool,
+    /// Whether the entity already intersected collision before the movement.
+    pub old_collision: bool,
+    /// Unfiltered residual between the client target and the replayed position.
+    pub residual: DVec3,
+    /// Whether the box swept from the replayed position to the target hits collision.
+    pub swept_collision: bool,
+}
+
+impl PhaseThroughValidation {
+    /// Returns true when the claimed target lies behind collision the replay stopped at.
+    #[must_use]
+    pub fn rejects(self) -> bool {
+        !self.no_physics
+            && !self.in_impulse_grace
+            && !self.old_collision
+            && self.residual.length_squared() > MOVEMENT_ERROR_THRESHOLD
+            && self.swept_collision
+    }
+}
+
+/// Returns the box vanilla's `noBlocksAround` searches for support under an entity.
+#[must_use]
+pub fn ground_probe(aabb: WorldAabb) -> WorldAabb {
+    aabb.inflate(GROUND_PROBE_INFLATE)
+        .expand_towards(DVec3::new(0.0, -GROUND_PROBE_DEPTH, 0.0))
+}
+
 /// State shared by vanilla client-authored movement validation.
 ///
 /// Vanilla stores parallel copies of this state in `ServerGamePacketListenerImpl`
@@ -206,6 +252,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn phase_validation_rejects_targets_behind_collision() {
+        let validation = PhaseThroughValidation {
+            no_physics: false,
+            in_impulse_grace: false,
+            old_collision: false,
+            residual: DVec3::new(0.0, -2.0, 0.0),
+            swept_collision: true,
+        };
+
+        assert!(validation.rejects());
+        assert!(
+            !PhaseThroughValidation {
+                swept_collision: false,
+                ..validation
+            }
+            .rejects()
+        );
+        assert!(
+            !PhaseThroughValidation {
+                residual: DVec3::new(0.0, -0.1, 0.0),
+                ..validation
+            }
+            .rejects()
+        );
+        assert!(
+            !PhaseThroughValidation {
+                old_collision: true,
+                ..validation
+            }
+            .rejects()
+        );
+    }
+
     #[test]
     fn client_movement_tick_reset_updates_good_positions_and_packet_base() {
         let mut state = ClientAuthoredMovementState::new();
@@ -0,0 +1,941 @@
+//! Vanilla `PlayerAdvancements`: per-player advancement progress.
+//!
+//! Progress is stored in vanilla's `advancements/<uuid>.json` layout. Only a
+//! subset of the criteria triggers and their conditions is evaluated; criteria
+//! the build script compiled to `CriterionConditions::Unsupported` are never
+//! granted automatically, and each is logged the first time its trigger fires.
+
+use std::io;
+use std::sync::{Arc, LazyLock};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::{Map, Value};
+use steel_protocol::packets::game::{
+    AdvancementHolder, AdvancementProgressEntry, CSelectAdvancementsTab, CSystemChat,
+    CUpdateAdvancements, CriterionProgressEntry, SSeenAdvancements, SeenAdvancementsAction,
+};
+use steel_registry::advancement::{
+    AdvancementCriterion, AdvancementRef, AdvancementType, CriterionConditions, ItemPredicate,
+    LootConditionPredicate, SlotsPredicate,
+};
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::item_stack::ItemStack;
+use steel_registry::loot_table::LootContext;
+use steel_registry::vanilla_game_rules::SHOW_ADVANCEMENT_MESSAGES;
+use steel_registry::{REGISTRY, RegistryExt, vanilla_attributes};
+use steel_utils::locks::SyncMutex;
+use steel_utils::{BlockStateId, Identifier, translations};
+use text_components::{Modifier as _, TextComponent, format::Color, interactivity::HoverEvent};
+
+use crate::command::execution::CommandSource;
+use crate::command::sender::CommandSender;
+use crate::entity::{Entity, LivingEntity, entity_loot_ref};
+use crate::inventory::container::Container;
+use crate::player::Player;
+
+/// Vanilla `AdvancementProgress.DATE_FORMAT`.
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S %z";
+
+/// How many ancestors vanilla `AdvancementVisibilityEvaluator` looks at.
+const VISIBILITY_DEPTH: usize = 2;
+
+/// Unsupported criteria already logged, as advancement key and criterion name.
+static LOGGED_UNSUPPORTED: LazyLock<SyncMutex<FxHashSet<(Identifier, &'static str)>>> =
+    LazyLock::new(|| SyncMutex::new(FxHashSet::default()));
+
+/// Vanilla `PlayerAdvancements`.
+#[derive(Debug)]
+pub struct PlayerAdvancements {
+    /// When each obtained criterion was obtained, per advancement.
+    progress: FxHashMap<Identifier, FxHashMap<&'static str, DateTime<Utc>>>,
+    /// Advancements currently shown to the client.
+    visible: FxHashSet<Identifier>,
+    /// Trees whose visibility must be recomputed on the next flush.
+    roots_to_update: FxHashSet<Identifier>,
+    progress_changed: FxHashSet<Identifier>,
+    is_first_packet: bool,
+    last_selected_tab: Option<Identifier>,
+    /// Inventory change counter seen by the last `inventory_changed` scan.
+    scanned_inventory_changes: Option<u32>,
+}
+
+impl Default for PlayerAdvancements {
+    fn default() -> Self {
+        Self {
+            progress: FxHashMap::default(),
+            visible: FxHashSet::default(),
+            roots_to_update: FxHashSet::default(),
+            progress_changed: FxHashSet::default(),
+            is_first_packet: true,
+            last_selected_tab: None,
+            scanned_inventory_changes: None,
+        }
+    }
+}
+
+impl PlayerAdvancements {
+    /// Returns whether `criterion` of `advancement` has been obtained.
+    #[must_use]
+    pub fn is_obtained(&self, advancement: AdvancementRef, criterion: &str) -> bool {
+        self.progress
+            .get(&advancement.key)
+            .is_some_and(|criteria| criteria.contains_key(criterion))
+    }
+
+    /// Vanilla `AdvancementProgress.isDone`.
+    #[must_use]
+    pub fn is_done(&self, advancement: AdvancementRef) -> bool {
+        advancement.requirements_met(|criterion| self.is_obtained(advancement, criterion))
+    }
+
+    /// Vanilla `AdvancementProgress.grantProgress`.
+    fn grant(&mut self, advancement: AdvancementRef, criterion: &'static str) -> bool {
+        let criteria = self.progress.entry(advancement.key.clone()).or_default();
+        if criteria.contains_key(criterion) {
+            return false;
+        }
+        criteria.insert(criterion, Utc::now());
+        self.mark_changed(advancement);
+        true
+    }
+
+    /// Vanilla `AdvancementProgress.revokeProgress`.
+    fn revoke(&mut self, advancement: AdvancementRef, criterion: &str) -> bool {
+        let Some(criteria) = self.progress.get_mut(&advancement.key) else {
+            return false;
+        };
+        if criteria.remove(criterion).is_none() {
+            return false;
+        }
+        self.mark_changed(advancement);
+        true
+    }
+
+    fn mark_changed(&mut self, advancement: AdvancementRef) {
+        self.progress_changed.insert(advancement.key.clone());
+        self.roots_to_update
+            .insert(REGISTRY.advancements.root(advancement).key.clone());
+    }
+
+    /// Vanilla `PlayerAdvancements.flushDirty`: the update packet for everything
+    /// changed since the last flush, if anything visible changed.
+    fn flush_dirty(&mut self) -> Option<CUpdateAdvancements> {
+        let is_first_packet = std::mem::replace(&mut self.is_first_packet, false);
+        if is_first_packet {
+            self.roots_to_update.extend(
+                REGISTRY
+                    .advancements
+                    .iter()
+                    .filter(|(_, advancement)| advancement.parent.is_none())
+                    .map(|(_, advancement)| advancement.key.clone()),
+            );
+        }
+
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        for root in std::mem::take(&mut self.roots_to_update) {
+            let Some(root) = REGISTRY.advancements.by_key(&root) else {
+                continue;
+            };
+            let mut rules = Vec::new();
+            self.update_visibility(root, &mut rules, &mut added, &mut removed);
+        }
+
+        let progress: Vec<_> = std::mem::take(&mut self.progress_changed)
+            .into_iter()
+            .filter(|key| self.visible.contains(key))
+            .filter_map(|key| REGISTRY.advancements.by_key(&key))
+            .map(|advancement| self.progress_entry(advancement))
+            .collect();
+
+        if !is_first_packet && progress.is_empty() && added.is_empty() && removed.is_empty() {
+            return None;
+        }
+        Some(CUpdateAdvancements {
+            reset: is_first_packet,
+            added,
+            removed,
+            progress,
+            show_advancements: !is_first_packet,
+        })
+    }
+
+    /// Vanilla `AdvancementVisibilityEvaluator.evaluateVisibility`, applying
+    /// the result to the visible set. Returns whether the subtree has a done
+    /// advancement.
+    fn update_visibility(
+        &mut self,
+        advancement: AdvancementRef,
+        rules: &mut Vec<VisibilityRule>,
+        added: &mut Vec<AdvancementHolder>,
+        removed: &mut Vec<Identifier>,
+    ) -> bool {
+        let done = self.is_done(advancement);
+        rules.push(VisibilityRule::of(advancement, done));
+        let mut subtree_done = done;
+        for child in REGISTRY.advancements.children(advancement) {
+            subtree_done |= self.update_visibility(child, rules, added, removed);
+        }
+        let visible = subtree_done || VisibilityRule::unfinished_visible(rules);
+        rules.pop();
+
+        if visible {
+            if self.visible.insert(advancement.key.clone()) {
+                added.push(AdvancementHolder(advancement));
+                if self.progress.contains_key(&advancement.key) {
+                    self.progress_changed.insert(advancement.key.clone());
+                }
+            }
+        } else if self.visible.remove(&advancement.key) {
+            removed.push(advancement.key.clone());
+        }
+        subtree_done
+    }
+
+    fn progress_entry(&self, advancement: AdvancementRef) -> AdvancementProgressEntry {
+        let obtained = self.progress.get(&advancement.key);
+        AdvancementProgressEntry {
+            advancement: advancement.key.clone(),
+            criteria: advancement
+                .criteria
+                .iter()
+                .map(|criterion| CriterionProgressEntry {
+                    criterion: criterion.name.to_owned(),
+                    obtained: obtained
+                        .and_then(|criteria| criteria.get(criterion.name))
+                        .map(DateTime::timestamp_millis),
+                })
+                .collect(),
+        }
+    }
+
+    /// Vanilla `PlayerAdvancements.save`.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut root = Map::new();
+        for (key, criteria) in &self.progress {
+            let Some(advancement) = REGISTRY.advancements.by_key(key) else {
+                continue;
+            };
+            if criteria.is_empty() {
+                continue;
+            }
+            let criteria: Map<String, Value> = criteria
+                .iter()
+                .map(|(name, obtained)| {
+                    (
+                        (*name).to_owned(),
+                        Value::from(obtained.format(DATE_FORMAT).to_string()),
+                    )
+                })
+                .collect();
+            let mut entry = Map::new();
+            entry.insert("criteria".to_owned(), Value::Object(criteria));
+            entry.insert("done".to_owned(), Value::from(self.is_done(advancement)));
+            root.insert(key.to_string(), Value::Object(entry));
+        }
+        Value::Object(root).to_string()
+    }
+
+    /// Vanilla `PlayerAdvancements.load`. Unknown advancements and criteria are skipped.
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        let root: Value = serde_json::from_str(json)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        let mut advancements = Self::default();
+        let Some(root) = root.as_object() else {
+            return Ok(advancements);
+        };
+
+        for (key, entry) in root {
+            let Some(advancement) = key
+                .parse::<Identifier>()
+                .ok()
+                .and_then(|key| REGISTRY.advancements.by_key(&key))
+            else {
+                continue;
+            };
+            let Some(criteria) = entry.get("criteria").and_then(Value::as_object) else {
+                continue;
+            };
+            for (name, obtained) in criteria {
+                let Some(criterion) = advancement.criterion(name) else {
+                    log::warn!("Ignored unknown criterion {name} of advancement {key}");
+                    continue;
+                };
+                let Some(obtained) = obtained.as_str().and_then(parse_date) else {
+                    log::warn!("Skipping invalid date of criterion {name} in {key}");
+                    continue;
+                };
+                advancements
+                    .progress
+                    .entry(advancement.key.clone())
+                    .or_default()
+                    .insert(criterion.name, obtained);
+            }
+        }
+        Ok(advancements)
+    }
+}
+
+fn parse_date(date: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_str(date, DATE_FORMAT)
+        .map(|date| date.with_timezone(&Utc))
+        .or_else(|_| NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S").map(|d| d.and_utc()))
+        .ok()
+}
+
+/// Vanilla `AdvancementVisibilityEvaluator.VisibilityRule`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VisibilityRule {
+    Show,
+    Hide,
+    NoChange,
+}
+
+impl VisibilityRule {
+    /// Vanilla `AdvancementVisibilityEvaluator.evaluateVisibilityRule`.
+    fn of(advancement: AdvancementRef, done: bool) -> Self {
+        match &advancement.display {
+            None => Self::Hide,
+            Some(_) if done => Self::Show,
+            Some(display) if display.hidden => Self::Hide,
+            Some(_) => Self::NoChange,
+        }
+    }
+
+    /// Vanilla `AdvancementVisibilityEvaluator.evaluateVisiblityForUnfinishedNode`.
+    fn unfinished_visible(rules: &[Self]) -> bool {
+        for rule in rules.iter().rev().take(VISIBILITY_DEPTH + 1) {
+            match rule {
+                Self::Show => return true,
+                Self::Hide => return false,
+                Self::NoChange => {}
+            }
+        }
+        false
+    }
+}
+
+/// A criteria trigger firing for a player.
+#[derive(Debug, Clone, Copy)]
+pub enum CriteriaTrigger<'a> {
+    /// Vanilla `InventoryChangeTrigger`, with the player's whole inventory.
+    InventoryChanged(&'a [ItemStack]),
+    /// Vanilla `ItemUsedOnLocationTrigger` for `placed_block`.
+    PlacedBlock(BlockStateId),
+    /// Vanilla `KilledTrigger` for `player_killed_entity`.
+    PlayerKilledEntity(EntityTypeRef),
+    /// Vanilla `PlayerTrigger` for `slept_in_bed`.
+    SleptInBed,
+    /// Vanilla `PlayerTrigger` for `avoid_vibration`.
+    AvoidVibration,
+    /// Vanilla `PlayerTrigger` for `voluntary_exile`.
+    RaidOmen,
+    /// Vanilla `PlayerTrigger` for `hero_of_the_village`.
+    RaidWin,
+    /// Vanilla `ConsumeItemTrigger`, with the stack before it was consumed.
+    ConsumeItem(&'a ItemStack),
+    /// Vanilla `RecipeCraftedTrigger` for `recipe_crafted`.
+    RecipeCrafted {
+        recipe: &'a Identifier,
+        ingredients: &'a [ItemStack],
+    },
+    /// Vanilla `BredAnimalsTrigger`.
+    BredAnimals {
+        parent: EntityTypeRef,
+        partner: EntityTypeRef,
+        child: Option<EntityTypeRef>,
+    },
+    /// Vanilla `RecipeUnlockedTrigger`.
+    RecipeUnlocked(&'a Identifier),
+    /// Vanilla `SlideDownBlockTrigger`.
+    SlideDownBlock(BlockStateId),
+}
+
+impl CriteriaTrigger<'_> {
+    fn key(&self) -> Identifier {
+        Identifier::vanilla_static(match self {
+            Self::InventoryChanged(_) => "inventory_changed",
+            Self::PlacedBlock(_) => "placed_block",
+            Self::PlayerKilledEntity(_) => "player_killed_entity",
+            Self::SleptInBed => "slept_in_bed",
+            Self::AvoidVibration => "avoid_vibration",
+            Self::RaidOmen => "voluntary_exile",
+            Self::RaidWin => "hero_of_the_village",
+            Self::ConsumeItem(_) => "consume_item",
+            Self::RecipeCrafted { .. } => "recipe_crafted",
+            Self::BredAnimals { .. } => "bred_animals",
+            Self::RecipeUnlocked(_) => "recipe_unlocked",
+            Self::SlideDownBlock(_) => "slide_down_block",
+        })
+    }
+
+    /// Tests the compiled trigger instance `conditions` against this event.
+    fn matches(&self, conditions: &CriterionConditions) -> bool {
+        match (self, conditions) {
+            (
+                Self::SleptInBed | Self::AvoidVibration | Self::RaidOmen | Self::RaidWin,
+                CriterionConditions::Player,
+            ) => true,
+            (
+                Self::InventoryChanged(stacks),
+                CriterionConditions::InventoryChanged { slots, items },
+            ) => inventory_matches(slots, items, stacks),
+            (Self::PlacedBlock(state), CriterionConditions::PlacedBlock { location }) => location
+                .iter()
+                .all(|condition| condition.matches_block(*state)),
+            (
+                Self::PlayerKilledEntity(entity_type),
+                CriterionConditions::PlayerKilledEntity { entity },
+            ) => entity_matches(entity, *entity_type),
+            (Self::ConsumeItem(stack), CriterionConditions::ConsumeItem { item }) => {
+                item.as_ref().is_none_or(|item| item.matches(stack))
+            }
+            (
+                Self::RecipeCrafted {
+                    recipe,
+                    ingredients,
+                },
+                CriterionConditions::RecipeCrafted {
+                    recipe_id,
+                    ingredients: predicates,
+                },
+            ) => *recipe == recipe_id && ingredients_match(predicates, ingredients),
+            (
+                Self::BredAnimals {
+                    parent,
+                    partner,
+                    child,
+                },
+                CriterionConditions::BredAnimals {
+                    parent: parent_predicate,
+                    partner: partner_predicate,
+                    child: child_predicate,
+                },
+            ) => {
+                // Vanilla `BredAnimalsTrigger.TriggerInstance.matches` accepts
+                // the parents either way round.
+                child_predicate.is_none_or(|predicate| {
+                    child.is_some_and(|child| entity_matches(predicate, child))
+                }) && ((entity_matches(parent_predicate, *parent)
+                    && entity_matches(partner_predicate, *partner))
+                    || (entity_matches(parent_predicate, *partner)
+                        && entity_matches(partner_predicate, *parent)))
+            }
+            (Self::RecipeUnlocked(unlocked), CriterionConditions::RecipeUnlocked { recipe }) => {
+                *unlocked == recipe
+            }
+            (
+                Self::SlideDownBlock(state),
+                CriterionConditions::SlideDownBlock {
+                    block,
+                    state: properties,
+                },
+            ) => {
+                block
+                    .as_ref()
+                    .is_none_or(|block| *block == state.get_block().key)
+                    && properties.matches(*state)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Logs once per criterion that its trigger fired but its conditions are not
+/// evaluated, so it can only be granted by commands.
+fn log_unsupported_criterion(
+    advancement: AdvancementRef,
+    criterion: &'static AdvancementCriterion,
+) {
+    if LOGGED_UNSUPPORTED
+        .lock()
+        .insert((advancement.key.clone(), criterion.name))
+    {
+        log::warn!(
+            "Criterion {} of advancement {} uses conditions that are not evaluated; it can only be granted by commands",
+            criterion.name,
+            advancement.key
+        );
+    }
+}
+
+fn entity_matches(predicate: &[LootConditionPredicate], entity_type: EntityTypeRef) -> bool {
+    predicate
+        .iter()
+        .all(|condition| condition.matches_entity(entity_type))
+}
+
+/// Vanilla `InventoryChangeTrigger.TriggerInstance.matches`.
+fn inventory_matches(
+    slots: &SlotsPredicate,
+    items: &[ItemPredicate],
+    stacks: &[ItemStack],
+) -> bool {
+    let occupied = stacks.iter().filter(|stack| !stack.is_empty()).count();
+    let full = stacks
+        .iter()
+        .filter(|stack| !stack.is_empty() && stack.count() >= stack.max_stack_size())
+        .count();
+    let empty = stacks.len() - occupied;
+    let counts_match = [
+        (slots.occupied, occupied),
+        (slots.full, full),
+        (slots.empty, empty),
+    ]
+    .into_iter()
+    .all(|(bounds, count)| i32::try_from(count).is_ok_and(|count| bounds.matches(count)));
+
+    counts_match
+        && items.iter().all(|predicate| {
+            stacks
+                .iter()
+                .filter(|stack| !stack.is_empty())
+                .any(|stack| predicate.matches(stack))
+        })
+}
+
+/// Vanilla `RecipeCraftedTrigger.TriggerInstance.matches`: every predicate
+/// needs its own ingredient.
+fn ingredients_match(predicates: &[ItemPredicate], ingredients: &[ItemStack]) -> bool {
+    let mut remaining: Vec<_> = ingredients.iter().collect();
+    predicates.iter().all(|predicate| {
+        let Some(index) = remaining.iter().position(|stack| predicate.matches(stack)) else {
+            return false;
+        };
+        remaining.remove(index);
+        true
+    })
+}
+
+impl Player {
+    /// Vanilla `PlayerAdvancements.award`: grants one criterion, handing out
+    /// the rewards if this completes the advancement.
+    pub fn award_advancement(&self, advancement: AdvancementRef, criterion: &str) -> bool {
+        let Some(criterion) = advancement.criterion(criterion) else {
+            return false;
+        };
+        let completed = {
+            let mut advancements = self.advancements.lock();
+            let was_done = advancements.is_done(advancement);
+            if !advancements.grant(advancement, criterion.name) {
+                return false;
+            }
+            !was_done && advancements.is_done(advancement)
+        };
+        if completed {
+            self.grant_advancement_rewards(advancement);
+            self.announce_advancement(advancement);
+        }
+        true
+    }
+
+    /// Vanilla `PlayerAdvancements.revoke`.
+    pub fn revoke_advancement(&self, advancement: AdvancementRef, criterion: &str) -> bool {
+        self.advancements.lock().revoke(advancement, criterion)
+    }
+
+    /// Returns whether the player has completed `advancement`.
+    #[must_use]
+    pub fn has_advancement(&self, advancement: AdvancementRef) -> bool {
+        self.advancements.lock().is_done(advancement)
+    }
+
+    /// Fires `trigger`, awarding every unfinished criterion whose conditions match.
+    pub fn trigger_criteria(&self, trigger: CriteriaTrigger<'_>) {
+        let key = trigger.key();
+        let matched: Vec<_> = {
+            let advancements = self.advancements.lock();
+            REGISTRY
+                .advancements
+                .iter()
+                .filter(|&(_, advancement)| !advancements.is_done(advancement))
+                .flat_map(|(_, advancement)| {
+                    advancement
+                        .criteria
+                        .iter()
+                        .map(move |criterion| (advancement, criterion))
+                })
+                .filter(|&(advancement, criterion)| {
+                    criterion.trigger == key
+                        && !advancements.is_obtained(advancement, criterion.name)
+                })
+                .collect()
+        };
+
+        for (advancement, criterion) in matched {
+            if matches!(criterion.conditions, CriterionConditions::Unsupported) {
+                log_unsupported_criterion(advancement, criterion);
+            } else if trigger.matches(&criterion.conditions) {
+                self.award_advancement(advancement, criterion.name);
+            }
+        }
+    }
+
+    /// Vanilla `AdvancementRewards.grant`.
+    fn grant_advancement_rewards(&self, advancement: AdvancementRef) {
+        let rewards = &advancement.rewards;
+        if rewards.experience != 0 {
+            self.give_experience_points(rewards.experience);
+        }
+
+        if !rewards.loot.is_empty() {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "vanilla reads the luck attribute as a float"
+            )]
+            let luck = self
+                .attributes()
+                .lock()
+                .get_value(vanilla_attributes::LUCK)
+                .unwrap_or(0.0) as f32;
+            let position = self.position();
+            let mut rng = rand::rng();
+            for key in rewards.loot {
+                let Some(loot_table) = REGISTRY.loot_tables.by_key(key) else {
+                    log::warn!(
+                        "Advancement {} rewards unknown loot table {key}",
+                        advancement.key
+                    );
+                    continue;
+                };
+                let items = {
+                    let mut ctx = LootContext::new(&mut rng)
+                        .with_luck(luck)
+                        .with_origin(position.x, position.y, position.z)
+                        .with_this_entity(entity_loot_ref(self));
+                    loot_table.get_random_items(&mut ctx)
+                };
+                for item in items {
+                    self.add_item_or_drop(item);
+                }
+            }
+        }
+
+        if !rewards.recipes.is_empty() {
+            let recipes: Vec<_> = rewards
+                .recipes
+                .iter()
+                .filter_map(|key| REGISTRY.recipes.by_key(key))
+                .copied()
+                .collect();
+            self.award_recipes(&recipes);
+        }
+
+        if let Some(function) = &rewards.function {
+            self.run_reward_function(advancement, function);
+        }
+    }
+
+    /// Vanilla `AdvancementRewards.grant`: runs the reward function as this
+    /// player with gamemaster permissions and no command feedback.
+    fn run_reward_function(&self, advancement: AdvancementRef, function: &Identifier) {
+        let server = self.server();
+        let Some(player) = server.get_player(&self.gameprofile.id) else {
+            return;
+        };
+        let source = CommandSource::new(CommandSender::Player(player), Arc::clone(&server))
+            .with_suppressed_output()
+            .with_gamemaster_permissions();
+        if server.execute_function(function, &source).is_none() {
+            log::warn!(
+                "Advancement {} rewards unknown function {function}",
+                advancement.key
+            );
+        }
+    }
+
+    /// Broadcasts vanilla `AdvancementType.createAnnouncement` if enabled.
+    fn announce_advancement(&self, advancement: AdvancementRef) {
+        let Some(display) = &advancement.display else {
+            return;
+        };
+        let world = self.get_world();
+        if !display.announce_to_chat || !world.get_game_rule(&SHOW_ADVANCEMENT_MESSAGES) {
+            return;
+        }
+
+        let color = match display.frame {
+            AdvancementType::Task | AdvancementType::Goal => Color::Green,
+            AdvancementType::Challenge => Color::DarkPurple,
+        };
+        let name = TextComponent::plain("[")
+            .add_child(display.title.clone())
+            .add_child(TextComponent::plain("]"))
+            .color(color)
+            .hover_event(HoverEvent::show_text(
+                display
+                    .title
+                    .clone()
+                    .color(color)
+                    .add_child(TextComponent::plain("\n"))
+                    .add_child(display.description.clone()),
+            ));
+        let player_name = TextComponent::plain(self.gameprofile.name.clone());
+        let announcement = match display.frame {
+            AdvancementType::Task => &translations::CHAT_TYPE_ADVANCEMENT_TASK,
+            AdvancementType::Challenge => &translations::CHAT_TYPE_ADVANCEMENT_CHALLENGE,
+            AdvancementType::Goal => &translations::CHAT_TYPE_ADVANCEMENT_GOAL,
+        };
+        world.broadcast_system_chat(CSystemChat {
+            content: announcement.message([player_name, name]).component(),
+            overlay: false,
+        });
+    }
+
+    /// Fires `inventory_changed` and sends pending advancement updates.
+    pub(super) fn tick_advancements(&self) {
+        let stacks = {
+            let inventory = self.inventory.lock();
+            let times_changed = inventory.get_times_changed();
+            let mut advancements = self.advancements.lock();
+            if advancements.scanned_inventory_changes == Some(times_changed) {
+                None
+            } else {
+                advancements.scanned_inventory_changes = Some(times_changed);
+                Some(
+                    (0..inventory.get_container_size())
+                        .map(|slot| inventory.get_item(slot).clone())
+                        .collect::<Vec<_>>(),
+                )
+            }
+        };
+        if let Some(stacks) = stacks {
+            self.trigger_criteria(CriteriaTrigger::InventoryChanged(&stacks));
+        }
+
+        let packet = self.advancements.lock().flush_dirty();
+        if let Some(packet) = packet {
+            self.send_packet(packet);
+        }
+    }
+
+    /// Vanilla `ServerGamePacketListenerImpl.handleSeenAdvancements`.
+    pub fn handle_seen_advancements(&self, packet: SSeenAdvancements) {
+        let SeenAdvancementsAction::OpenedTab(tab) = packet.action else {
+            return;
+        };
+        let Some(advancement) = REGISTRY.advancements.by_key(&tab) else {
+            return;
+        };
+        self.set_selected_advancement_tab(Some(advancement));
+    }
+
+    /// Vanilla `PlayerAdvancements.setSelectedTab`.
+    pub fn set_selected_advancement_tab(&self, advancement: Option<AdvancementRef>) {
+        let tab = advancement
+            .filter(|advancement| advancement.parent.is_none() && advancement.display.is_some())
+            .map(|advancement| advancement.key.clone());
+        let previous =
+            std::mem::replace(&mut self.advancements.lock().last_selected_tab, tab.clone());
+        if previous != tab {
+            self.send_packet(CSelectAdvancementsTab { tab });
+        }
+    }
+
+    /// Replaces the player's advancement progress with one loaded from storage.
+    pub(crate) fn load_advancements(&self, advancements: PlayerAdvancements) {
+        *self.advancements.lock() = advancements;
+    }
+
+    /// Serializes the player's advancement progress for storage.
+    #[must_use]
+    pub(crate) fn advancements_json(&self) -> String {
+        self.advancements.lock().to_json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::advancement::{HolderSetPredicate, IntBounds, StatePropertiesPredicate};
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::{vanilla_blocks, vanilla_entities, vanilla_items};
+
+    use super::*;
+
+    const ANY_SLOTS: SlotsPredicate = SlotsPredicate {
+        occupied: IntBounds::ANY,
+        full: IntBounds::ANY,
+        empty: IntBounds::ANY,
+    };
+
+    static CRAFTING_TABLE: [Identifier; 1] = [Identifier::vanilla_static("crafting_table")];
+
+    static TABLE: [ItemPredicate; 1] = [ItemPredicate {
+        items: Some(HolderSetPredicate::Keys(&CRAFTING_TABLE)),
+        count: IntBounds::ANY,
+    }];
+
+    static TABLE_AND_LOG: [ItemPredicate; 2] = [
+        ItemPredicate {
+            items: Some(HolderSetPredicate::Keys(&CRAFTING_TABLE)),
+            count: IntBounds::ANY,
+        },
+        ItemPredicate {
+            items: Some(HolderSetPredicate::Tag(Identifier::vanilla_static("logs"))),
+            count: IntBounds::ANY,
+        },
+    ];
+
+    static STONE: [LootConditionPredicate; 1] = [LootConditionPredicate::AnyOf(&[
+        LootConditionPredicate::BlockStateProperty {
+            block: Identifier::vanilla_static("stone"),
+            properties: StatePropertiesPredicate(&[]),
+        },
+    ])];
+
+    static DIRT: [LootConditionPredicate; 1] = [LootConditionPredicate::LocationBlock {
+        blocks: Some(HolderSetPredicate::Keys(&[Identifier::vanilla_static(
+            "dirt",
+        )])),
+        state: StatePropertiesPredicate(&[]),
+    }];
+
+    static UNDEAD: [LootConditionPredicate; 1] = [LootConditionPredicate::EntityType(Some(
+        HolderSetPredicate::Keys(&[
+            Identifier::vanilla_static("skeleton"),
+            Identifier::vanilla_static("zombie"),
+        ]),
+    ))];
+
+    static COW: [LootConditionPredicate; 1] = [LootConditionPredicate::EntityType(Some(
+        HolderSetPredicate::Keys(&[Identifier::vanilla_static("cow")]),
+    ))];
+
+    static TWO_TABLES: [ItemPredicate; 2] = [
+        ItemPredicate {
+            items: Some(HolderSetPredicate::Keys(&CRAFTING_TABLE)),
+            count: IntBounds::ANY,
+        },
+        ItemPredicate {
+            items: Some(HolderSetPredicate::Keys(&CRAFTING_TABLE)),
+            count: IntBounds::ANY,
+        },
+    ];
+
+    #[test]
+    fn inventory_changed_matches_items_and_tags() {
+        init_test_registry();
+        let stacks = [
+            ItemStack::new(&vanilla_items::CRAFTING_TABLE),
+            ItemStack::empty(),
+        ];
+        let trigger = CriteriaTrigger::InventoryChanged(&stacks);
+
+        assert!(trigger.matches(&CriterionConditions::InventoryChanged {
+            slots: ANY_SLOTS,
+            items: &TABLE,
+        }));
+        assert!(!trigger.matches(&CriterionConditions::InventoryChanged {
+            slots: ANY_SLOTS,
+            items: &TABLE_AND_LOG,
+        }));
+
+        assert!(trigger.matches(&CriterionConditions::InventoryChanged {
+            slots: SlotsPredicate {
+                occupied: IntBounds::ANY,
+                full: IntBounds::ANY,
+                empty: IntBounds {
+                    min: Some(1),
+                    max: None,
+                },
+            },
+            items: &[],
+        }));
+        assert!(!trigger.matches(&CriterionConditions::Unsupported));
+    }
+
+    #[test]
+    fn placed_block_and_kill_conditions() {
+        init_test_registry();
+        let placed = CriteriaTrigger::PlacedBlock(vanilla_blocks::STONE.default_state());
+        assert!(placed.matches(&CriterionConditions::PlacedBlock { location: &STONE }));
+        assert!(!placed.matches(&CriterionConditions::PlacedBlock { location: &DIRT }));
+
+        let killed = CriteriaTrigger::PlayerKilledEntity(&vanilla_entities::ZOMBIE);
+        assert!(killed.matches(&CriterionConditions::PlayerKilledEntity { entity: &UNDEAD }));
+        assert!(killed.matches(&CriterionConditions::PlayerKilledEntity { entity: &[] }));
+        assert!(!killed.matches(&CriterionConditions::PlacedBlock { location: &[] }));
+    }
+
+    #[test]
+    fn bred_animals_accepts_parents_either_way_round() {
+        init_test_registry();
+        let conditions = CriterionConditions::BredAnimals {
+            parent: &COW,
+            partner: &[],
+            child: None,
+        };
+        let bred = |parent, partner| CriteriaTrigger::BredAnimals {
+            parent,
+            partner,
+            child: None,
+        };
+        assert!(bred(&vanilla_entities::PIG, &vanilla_entities::COW).matches(&conditions));
+        assert!(!bred(&vanilla_entities::PIG, &vanilla_entities::PIG).matches(&conditions));
+        assert!(
+            !bred(&vanilla_entities::COW, &vanilla_entities::COW).matches(
+                &CriterionConditions::BredAnimals {
+                    parent: &[],
+                    partner: &[],
+                    child: Some(&[]),
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn recipe_crafted_needs_an_ingredient_per_predicate() {
+        init_test_registry();
+        let recipe = Identifier::vanilla_static("crafting_table");
+        let ingredients = [ItemStack::new(&vanilla_items::CRAFTING_TABLE)];
+        let trigger = CriteriaTrigger::RecipeCrafted {
+            recipe: &recipe,
+            ingredients: &ingredients,
+        };
+        assert!(trigger.matches(&CriterionConditions::RecipeCrafted {
+            recipe_id: Identifier::vanilla_static("crafting_table"),
+            ingredients: &TWO_TABLES[..1],
+        }));
+        assert!(!trigger.matches(&CriterionConditions::RecipeCrafted {
+            recipe_id: Identifier::vanilla_static("crafting_table"),
+            ingredients: &TWO_TABLES,
+        }));
+    }
+
+    #[test]
+    fn visibility_shows_two_levels_below_visible_ancestors() {
+        use VisibilityRule::{Hide, NoChange, Show};
+
+        assert!(VisibilityRule::unfinished_visible(&[
+            Show, NoChange, NoChange
+        ]));
+        assert!(!VisibilityRule::unfinished_visible(&[
+            Show, NoChange, NoChange, NoChange
+        ]));
+        assert!(!VisibilityRule::unfinished_visible(&[Show, Hide, NoChange]));
+    }
+
+    #[test]
+    fn progress_json_roundtrips_in_vanilla_layout() {
+        init_test_registry();
+        let Some(root) = REGISTRY
+            .advancements
+            .by_key(&Identifier::vanilla_static("story/root"))
+        else {
+            panic!("story root should be registered");
+        };
+        let mut advancements = PlayerAdvancements::default();
+        let criterion = root.criteria[0].name;
+        assert!(advancements.grant(root, criterion));
+
+        let json = advancements.to_json();
+        let parsed: Value = serde_json::from_str(&json).expect("advancements json should parse");
+        assert_eq!(parsed["minecraft:story/root"]["done"], Value::Bool(true));
+
+        let loaded = PlayerAdvancements::from_json(&json).expect("advancements json should load");
+        assert!(loaded.is_done(root));
+    }
+}
@@ -8,6 +8,7 @@ use std::sync::Arc;
 use steel_protocol::packets::game::CBlockUpdate;
 use steel_registry::blocks::block_state_ext::BlockStateExt;
 use steel_registry::loot_table::LootContext;
+use steel_registry::stat::Stat;
 use steel_registry::vanilla_attributes;
 use steel_registry::{
     REGISTRY, RegistryExt, blocks::properties::Direction, vanilla_blocks, vanilla_game_events,
@@ -20,6 +21,7 @@ use steel_utils::{
 
 use super::food_data::food_constants;
 use crate::behavior::{BLOCK_BEHAVIORS, BlockStateBehaviorExt};
+use crate::block_entity::BlockEntityLootData;
 use crate::entity::{Entity, LivingEntity};
 use crate::fluid::fluid_state_to_block;
 use crate::player::Player;
@@ -294,6 +296,9 @@ impl BlockBreakingManager {
             &GameEventContext::new(Some(player), Some(adjusted_state)),
         );
         let changed_by_player_will_destroy = world.get_block_state(pos) != state;
+        let block_entity = world
+            .get_block_entity(pos)
+            .map(|block_entity| BlockEntityLootData::capture(&*block_entity.lock()));
 
         // Vanilla parity: fluidState.createLegacyBlock() — breaking a waterlogged
         // block leaves water behind instead of air.
@@ -328,20 +333,14 @@ impl BlockBreakingManager {
                 .map_or(0.0, |b| b.config.destroy_time);
 
             if block_destroy_time != 0.0 {
-                let mut inv = player.inventory.lock();
-                let damage_per_block = inv.get_selected_item().get_tool_damage_per_block();
+                let damage_per_block = player
+                    .inventory
+                    .lock()
+                    .get_selected_item()
+                    .get_tool_damage_per_block();
 
                 if damage_per_block > 0 {
-                    // Use with_selected_item_mut to ensure set_changed() is called
-                    // Skip damage if player has infinite materials (creative mode)
-                    let has_infinite_materials = player.has_infinite_materials();
-                    let broke = inv.with_selected_item_mut(|main_hand| {
-                        main_hand.hurt_and_break(damage_per_block, has_infinite_materials)
-                    });
-                    if broke {
-                        // TODO: Play item break sound/particles
-                        log::debug!("Tool broke while mining block at {pos:?}");
-                    }
+                    player.hurt_item_in_hand(InteractionHand::MainHand, damage_per_block);
                 }
             }
 
@@ -354,7 +353,8 @@ impl BlockBreakingManager {
                 && has_correct_tool
             {
                 // TODO: Call playerDestroy to spawn drops
-                drop_block_loot(player, world, pos, adjusted_state);
+                player.award_stat(Stat::mined(adjusted_state.get_block()));
+                drop_block_loot(player, world, pos, adjusted_state, block_entity.as_ref());
             }
         }
 
@@ -449,41 +449,52 @@ fn get_destroy_progress(player: &Player, block_state: BlockStateId) -> f32 {
     speed / destroy_time / divisor
 }
 
-/// Drops loot for a destroyed block using its loot table.
-fn drop_block_loot(player: &Player, _world: &Arc<World>, pos: BlockPos, state: BlockStateId) {
+/// Drops loot for a destroyed block using its loot table, then runs the
+/// block's `spawn_after_break` hook (experience orbs for ores).
+fn drop_block_loot(
+    player: &Player,
+    world: &Arc<World>,
+    pos: BlockPos,
+    state: BlockStateId,
+    block_entity: Option<&BlockEntityLootData>,
+) {
     let block = state.get_block();
+    let tool = player.inventory.lock().get_selected_item().clone();
 
     // Build the loot table key: "blocks/{block_name}"
     let loot_table_key = Identifier::vanilla(format!("blocks/{}", block.key.path));
 
-    let Some(loot_table) = REGISTRY.loot_tables.by_key(&loot_table_key) else {
-        // No loot table for this block (e.g., air, bedrock)
-        return;
-    };
+    // Blocks without a loot table (e.g., air, bedrock) drop nothing.
+    if let Some(loot_table) = REGISTRY.loot_tables.by_key(&loot_table_key) {
+        let mut rng = rand::rng();
+        let luck = player
+            .attributes()
+            .lock()
+            .get_value(vanilla_attributes::LUCK)
+            .unwrap_or(0.0) as f32;
+
+        let drops = {
+            let mut ctx = LootContext::new(&mut rng)
+                .with_luck(luck)
+                .with_block_state(state)
+                .with_tool(&tool)
+                .with_origin(f64::from(pos.x()), f64::from(pos.y()), f64::from(pos.z()));
+            if let Some(block_entity) = block_entity {
+                ctx = ctx.with_block_entity(block_entity.as_loot_ref());
+            }
 
-    let mut rng = rand::rng();
-    let luck = player
-        .attributes()
-        .lock()
-        .get_value(vanilla_attributes::LUCK)
-        .unwrap_or(0.0) as f32;
-
-    let drops = {
-        let inventory = player.inventory.lock();
-        let tool = inventory.get_selected_item();
-        let mut ctx = LootContext::new(&mut rng)
-            .with_luck(luck)
-            .with_block_state(state)
-            .with_tool(tool)
-            .with_origin(f64::from(pos.x()), f64::from(pos.y()), f64::from(pos.z()));
-
-        loot_table.get_random_items(&mut ctx)
-    };
+            loot_table.get_random_items(&mut ctx)
+        };
 
-    // Spawn each dropped item using the player's world reference (Arc<World>)
-    for item in drops {
-        if !item.is_empty() {
-            player.get_world().pop_resource(pos, item);
+        // Spawn each dropped item using the player's world reference (Arc<World>)
+        for item in drops {
+            if !item.is_empty() {
+                player.get_world().pop_resource(pos, item);
+            }
         }
     }
+
+    BLOCK_BEHAVIORS
+        .get_behavior(block)
+        .spawn_after_break(state, world, pos, &tool, true);
 }
@@ -1,11 +1,13 @@
 //! Chat and messaging state for a player.
 //!
 //! Groups the fields related to secure chat: message counters, signature cache,
-//! message validator, chat session, and message chain.
+//! message validator, chat session, and message chain, plus the ignore list
+//! and `/reply` target used by private messages.
 
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use rustc_hash::FxHashSet;
 use steel_crypto::{SignatureValidator, public_key_from_bytes, signature::NoValidation};
 use steel_protocol::packets::game::CSystemChatMessage;
 use steel_protocol::packets::game::{
@@ -16,6 +18,8 @@ use steel_utils::translations;
 use text_components::Modifier;
 use text_components::TextComponent;
 use text_components::interactivity::{ClickEvent, HoverEvent};
+use tokio::sync::oneshot::{self, error::TryRecvError};
+use uuid::Uuid;
 
 use super::LastSeenMessagesValidator;
 use super::message_chain::SignedMessageChain;
@@ -24,6 +28,8 @@ use super::spam_throttler::TickThrottler;
 use super::{LastSeen, MessageCache};
 use crate::entity::Entity;
 use crate::player::{Player, message_chain, profile_key};
+use crate::server::chat::OutgoingChat;
+use crate::server::chat_filter::{ChatFilterMask, filter_chat};
 
 /// All chat-related state for a player.
 ///
@@ -43,6 +49,13 @@ pub struct ChatState {
     pub chat_session: Option<RemoteChatSession>,
     /// Message chain state for tracking signed message sequence.
     pub message_chain: Option<SignedMessageChain>,
+    /// Completes once the player's last chat message left the filters and
+    /// was broadcast.
+    pub pending_broadcast: Option<oneshot::Receiver<()>>,
+    /// Players whose chat and private messages this player doesn't receive.
+    ignored: FxHashSet<Uuid>,
+    /// The player this player last sent a private message to or received one from.
+    last_correspondent: Option<Uuid>,
     chat_spam_throttler: TickThrottler,
     command_spam_throttler: TickThrottler,
 }
@@ -56,6 +69,9 @@ impl ChatState {
             message_validator: LastSeenMessagesValidator::new(),
             chat_session: None,
             message_chain: None,
+            pending_broadcast: None,
+            ignored: FxHashSet::default(),
+            last_correspondent: None,
             chat_spam_throttler: TickThrottler::new(
                 20,
                 chat_spam_threshold_seconds.wrapping_mul(20),
@@ -69,11 +85,15 @@ impl ChatState {
 }
 
 impl Player {
-    /// Decays the per player chat and command spam counters once per server tick
+    /// Decays the per player chat, command and creative drop spam counters once per server tick
     pub fn tick_spam_throttlers(&self) {
-        let mut chat = self.chat.lock();
-        chat.chat_spam_throttler.tick();
-        chat.command_spam_throttler.tick();
+        {
+            let mut chat = self.chat.lock();
+            chat.chat_spam_throttler.tick();
+            chat.command_spam_throttler.tick();
+        }
+        self.drop_spam_throttler.lock().tick();
+        self.interaction_spam_throttler.lock().tick();
     }
 
     const fn should_disconnect_for_rate_spam(
@@ -194,8 +214,6 @@ impl Player {
 
     /// Handles a chat message from the player.
     pub fn handle_chat(&self, packet: SChat, player: Arc<Player>) {
-        let chat_message = packet.message.clone();
-
         let verification_result = if let Some(_signature) = &packet.signature {
             match self.verify_chat_signature(&packet) {
                 Ok((link, last_seen)) => Some(Ok((link, last_seen))),
@@ -211,7 +229,7 @@ impl Player {
             None
         };
 
-        if self.config.enforce_secure_chat {
+        if self.config.load().enforce_secure_chat {
             match &verification_result {
                 Some(Ok(_)) => {}
                 Some(Err(err)) => {
@@ -227,12 +245,106 @@ impl Player {
             }
         }
 
-        let signature = if matches!(verification_result, Some(Ok(_))) {
-            packet.signature.map(|sig| Box::new(sig) as Box<[u8]>)
+        let server = self.server();
+        let mut outgoing = OutgoingChat::new(packet.message.clone());
+        let strip_signatures = self.config.load().chat.strip_signatures;
+        if !strip_signatures && matches!(verification_result, Some(Ok(_))) {
+            outgoing.signature = packet.signature;
+        }
+        if let Some(plugins) = server.plugin_manager() {
+            plugins.chat(&player, &mut outgoing);
+        }
+        if outgoing.cancelled {
+            self.detect_chat_rate_spam();
+            return;
+        }
+
+        let last_seen = match verification_result {
+            Some(Ok((_, last_seen))) => last_seen,
+            _ => LastSeen::default(),
+        };
+        let filters = server.chat_filters();
+        let previous = self.take_pending_broadcast();
+        if filters.is_empty() && previous.is_none() {
+            self.broadcast_chat_message(
+                &player,
+                packet,
+                &outgoing,
+                &last_seen,
+                &ChatFilterMask::pass_through(),
+            );
         } else {
-            None
+            // Filters may take a while, but messages still go out in the order
+            // they were sent, like vanilla's chat message chain.
+            let (done, pending) = oneshot::channel();
+            self.chat.lock().pending_broadcast = Some(pending);
+            let filter_everyone = self.config.load().chat.filter.filter_everyone;
+            tokio::spawn(async move {
+                let filter =
+                    filter_chat(&filters, &player, &outgoing.message, filter_everyone).await;
+                if let Some(previous) = previous {
+                    let _ = previous.await;
+                }
+                player.broadcast_chat_message(&player, packet, &outgoing, &last_seen, &filter);
+                let _ = done.send(());
+            });
+        }
+
+        self.detect_chat_rate_spam();
+    }
+
+    /// Returns the broadcast of this player's previous message if it is still
+    /// waiting for filters.
+    fn take_pending_broadcast(&self) -> Option<oneshot::Receiver<()>> {
+        let mut previous = self.chat.lock().pending_broadcast.take()?;
+        matches!(previous.try_recv(), Err(TryRecvError::Empty)).then_some(previous)
+    }
+
+    /// Sends a chat message that passed validation, plugins and filters to
+    /// every player.
+    fn broadcast_chat_message(
+        &self,
+        player: &Arc<Player>,
+        packet: SChat,
+        outgoing: &OutgoingChat,
+        last_seen: &LastSeen,
+        filter: &ChatFilterMask,
+    ) {
+        let server = self.server();
+        steel_utils::chat!(player.gameprofile.name.clone(), "{}", outgoing.message);
+
+        let config = self.config.load();
+        if !config.chat.format.is_empty() {
+            server.broadcast_formatted_chat(player, &config.chat.format, &outgoing.message, filter);
+            return;
+        }
+
+        let registry_id = vanilla_chat_types::CHAT.id() as i32;
+        let chat_type = ChatTypeBound {
+            registry_id,
+            sender_name: TextComponent::plain(player.gameprofile.name.clone())
+                .insertion(player.gameprofile.name.clone())
+                .click_event(ClickEvent::suggest_command(format!(
+                    "/tell {} ",
+                    player.gameprofile.name
+                )))
+                .hover_event(HoverEvent::show_entity(
+                    "minecraft:player",
+                    self.uuid(),
+                    Some(player.gameprofile.name.clone()),
+                )),
+            target_name: None,
         };
 
+        if config.chat.strip_signatures {
+            for world in server.worlds.values() {
+                world.broadcast_disguised_chat(player, &outgoing.message, &chat_type, filter);
+            }
+            return;
+        }
+
+        let signature = outgoing.signature.map(|sig| Box::new(sig) as Box<[u8]>);
+
         let sender_index = {
             let mut chat = player.chat.lock();
             let idx = chat.messages_sent;
@@ -240,64 +352,35 @@ impl Player {
             idx
         };
 
-        let registry_id = vanilla_chat_types::CHAT.id() as i32;
-
         let chat_packet = CPlayerChat::new(
             0,
             player.gameprofile.id,
             sender_index,
-            signature.clone(),
-            chat_message.clone(),
+            signature,
+            packet.message,
             packet.timestamp,
             packet.salt,
             Box::new([]),
-            Some(TextComponent::plain(chat_message.clone())),
+            Some(TextComponent::plain(outgoing.message.clone())),
             FilterType::PassThrough,
-            ChatTypeBound {
-                registry_id,
-                sender_name: TextComponent::plain(player.gameprofile.name.clone())
-                    .insertion(player.gameprofile.name.clone())
-                    .click_event(ClickEvent::suggest_command(format!(
-                        "/tell {} ",
-                        player.gameprofile.name
-                    )))
-                    .hover_event(HoverEvent::show_entity(
-                        "minecraft:player",
-                        self.uuid(),
-                        Some(player.gameprofile.name.clone()),
-                    )),
-                target_name: None,
-            },
+            chat_type,
         );
 
-        steel_utils::chat!(player.gameprofile.name.clone(), "{}", chat_message);
-        if let Some(sig_box) = &signature
-            && sig_box.len() == 256
-        {
-            let mut sig_array = [0u8; 256];
-            sig_array.copy_from_slice(&sig_box[..]);
-
-            let last_seen = if let Some(Ok((_, ref last_seen))) = verification_result {
-                last_seen.clone()
-            } else {
-                LastSeen::default()
-            };
-
-            for world in self.server().worlds.values() {
+        if let Some(signature) = &outgoing.signature {
+            for world in server.worlds.values() {
                 world.broadcast_chat(
                     chat_packet.clone(),
-                    Arc::clone(&player),
+                    player,
                     last_seen.clone(),
-                    Some(&sig_array),
+                    Some(signature),
+                    filter,
                 );
             }
         } else {
-            for world in self.server().worlds.values() {
-                world.broadcast_unsigned_chat(chat_packet.clone());
+            for world in server.worlds.values() {
+                world.broadcast_unsigned_chat(chat_packet.clone(), player, filter);
             }
         }
-
-        self.detect_chat_rate_spam();
     }
 
     /// Sends a system message to the player.
@@ -305,6 +388,53 @@ impl Player {
         self.send_packet(CSystemChatMessage::new(text, self, false));
     }
 
+    /// Shows a system message in chat, or above the hotbar when `overlay` is set.
+    ///
+    /// Vanilla `ServerPlayer.displayClientMessage`.
+    pub fn display_client_message(&self, text: &TextComponent, overlay: bool) {
+        self.send_packet(CSystemChatMessage::new(text, self, overlay));
+    }
+
+    /// Whether this player ignores chat and private messages from `uuid`.
+    #[must_use]
+    pub fn is_ignoring(&self, uuid: Uuid) -> bool {
+        self.chat.lock().ignored.contains(&uuid)
+    }
+
+    /// Starts or stops ignoring `uuid`. Returns whether the ignore list changed.
+    pub fn set_ignoring(&self, uuid: Uuid, ignoring: bool) -> bool {
+        let mut chat = self.chat.lock();
+        if ignoring {
+            chat.ignored.insert(uuid)
+        } else {
+            chat.ignored.remove(&uuid)
+        }
+    }
+
+    /// Returns the players this player ignores, sorted for stable saving.
+    #[must_use]
+    pub fn ignored_players(&self) -> Vec<Uuid> {
+        let mut ignored: Vec<Uuid> = self.chat.lock().ignored.iter().copied().collect();
+        ignored.sort_unstable();
+        ignored
+    }
+
+    /// Replaces the ignore list with the one loaded from storage.
+    pub fn load_ignored(&self, ignored: Vec<Uuid>) {
+        self.chat.lock().ignored = ignored.into_iter().collect();
+    }
+
+    /// Returns who `/reply` answers: the player last messaged privately.
+    #[must_use]
+    pub fn last_correspondent(&self) -> Option<Uuid> {
+        self.chat.lock().last_correspondent
+    }
+
+    /// Remembers `uuid` as the player `/reply` answers.
+    pub fn set_last_correspondent(&self, uuid: Uuid) {
+        self.chat.lock().last_correspondent = Some(uuid);
+    }
+
     /// Updates the player's chat session and initializes the message chain.
     ///
     /// This should be called when receiving a `ChatSessionUpdate` packet from the client.
@@ -368,7 +498,7 @@ impl Player {
                     "Player {} sent invalid public key: {err}",
                     self.gameprofile.name
                 );
-                if self.config.enforce_secure_chat {
+                if self.config.load().enforce_secure_chat {
                     log::error!(
                         "Player {} kicked for invalid public key",
                         self.gameprofile.name
@@ -398,7 +528,7 @@ impl Player {
                     "Player {} sent invalid chat session: {err}",
                     self.gameprofile.name
                 );
-                if self.config.enforce_secure_chat {
+                if self.config.load().enforce_secure_chat {
                     self.disconnect(format!("Chat session validation failed: {err}"));
                 }
             }
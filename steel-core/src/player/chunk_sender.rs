@@ -4,6 +4,7 @@
 //! tick. The three-phase design (prepare → encode → commit) minimizes lock hold
 //! time on the per-player `ChunkSender` mutex so that game-tick operations like
 //! `mark_chunk_pending_to_send` and `drop_chunk` are never blocked for long.
+use glam::DVec2;
 use rustc_hash::FxHashSet;
 use std::sync::Arc;
 
@@ -33,6 +34,9 @@ const MAX_CHUNKS_PER_TICK: f32 = 500.0;
 const START_CHUNKS_PER_TICK: f32 = 9.0;
 /// Maximum unacknowledged batches after first ack (vanilla: 10)
 const MAX_UNACKNOWLEDGED_BATCHES: u16 = 10;
+/// How much looking at a chunk shortens its effective distance: a chunk straight
+/// ahead is treated as half as far away, one directly behind as half again as far.
+const LOOK_PRIORITY_WEIGHT: f64 = 0.5;
 
 /// One chunk selected during the prepare phase.
 pub struct PreparedChunk {
@@ -109,11 +113,15 @@ impl ChunkSender {
 
     /// Phase 1: Lock briefly to drain pending chunks and snapshot state.
     ///
+    /// `look` is the horizontal part of the player's view vector; chunks in that
+    /// direction are sent before equally distant chunks behind the player.
+    ///
     /// Returns `None` if there is nothing to send this tick.
     pub fn prepare_batch(
         &mut self,
         world: &Arc<World>,
         player_chunk_pos: ChunkPos,
+        look: DVec2,
         chunk_send_epoch: &SyncMutex<u32>,
     ) -> Option<PreparedBatch> {
         if self.unacknowledged_batches >= self.max_unacknowledged_batches {
@@ -127,7 +135,7 @@ impl ChunkSender {
             return None;
         }
 
-        let holders = self.collect_candidates(world, player_chunk_pos);
+        let holders = self.collect_candidates(world, player_chunk_pos, look);
         if holders.is_empty() {
             return None;
         }
@@ -143,14 +151,13 @@ impl ChunkSender {
 
     /// Phase 2: Encode chunks without holding any lock. Called between prepare and commit.
     ///
-    /// Uses a per-tick local cache so multiple players sharing the same chunks
-    /// don't re-encode them within the same sending tick. No mutex needed.
+    /// Each holder caches its last encoded packet, so players loading the same
+    /// chunks share one serialization and compression pass until the chunk changes.
     ///
     /// # Panics
     /// Panics if a chunk packet fails to encode.
     pub fn encode_batch(
         batch: &PreparedBatch,
-        cache: &mut rustc_hash::FxHashMap<ChunkPos, EncodedChunk>,
         compression: Option<CompressionInfo>,
     ) -> Vec<EncodedChunk> {
         let mut encoded_chunks = Vec::with_capacity(batch.chunks.len());
@@ -159,14 +166,16 @@ impl ChunkSender {
             let holder = &prepared.holder;
             let pos = prepared.pos;
 
-            if let Some(cached) = cache.get(&pos)
-                && cached.content_revision == holder.packet_content_revision()
-            {
-                encoded_chunks.push(cached.clone());
+            let revision_before = holder.packet_content_revision();
+            if let Some(packet) = holder.cached_packet(revision_before, compression) {
+                encoded_chunks.push(EncodedChunk {
+                    pos,
+                    packet,
+                    content_revision: revision_before,
+                });
                 continue;
             }
 
-            let revision_before = holder.packet_content_revision();
             let Some(chunk_guard) = holder.try_chunk(ChunkStatus::Full) else {
                 continue;
             };
@@ -185,18 +194,18 @@ impl ChunkSender {
                 ConnectionProtocol::Play,
             )
             .expect("Failed to encode chunk packet");
+            drop(chunk_guard);
             let revision_after = holder.packet_content_revision();
             if revision_before != revision_after {
                 continue;
             }
 
-            let encoded = EncodedChunk {
+            holder.cache_packet(revision_after, compression, encoded.clone());
+            encoded_chunks.push(EncodedChunk {
                 pos,
                 packet: encoded,
                 content_revision: revision_after,
-            };
-            cache.insert(pos, encoded.clone());
-            encoded_chunks.push(encoded);
+            });
         }
 
         encoded_chunks
@@ -267,16 +276,21 @@ impl ChunkSender {
         &mut self,
         world: &Arc<World>,
         player_chunk_pos: ChunkPos,
+        look: DVec2,
     ) -> Vec<PreparedChunk> {
         let max_batch_size = self.batch_quota.floor() as usize;
-        let mut candidates: Vec<ChunkPos> = self.pending_chunks.iter().copied().collect();
+        let mut candidates: Vec<(f64, ChunkPos)> = self
+            .pending_chunks
+            .iter()
+            .map(|&pos| (Self::chunk_priority(pos, player_chunk_pos, look), pos))
+            .collect();
 
-        // Sort by distance to player
-        candidates.sort_by_key(|pos| Self::chunk_distance_squared(*pos, player_chunk_pos));
+        // Lowest priority value first
+        candidates.sort_unstable_by(|(a, _), (b, _)| a.total_cmp(b));
 
         let mut chunks_to_send = Vec::new();
 
-        for pos in candidates {
+        for (_, pos) in candidates {
             if chunks_to_send.len() >= max_batch_size {
                 break;
             }
@@ -293,6 +307,21 @@ impl ChunkSender {
         chunks_to_send
     }
 
+    /// Distance to the player, shortened for chunks the player is facing.
+    fn chunk_priority(pos: ChunkPos, player_chunk_pos: ChunkPos, look: DVec2) -> f64 {
+        if pos == player_chunk_pos {
+            return 0.0;
+        }
+        let distance = (Self::chunk_distance_squared(pos, player_chunk_pos) as f64).sqrt();
+        let offset = DVec2::new(
+            f64::from(pos.0.x) - f64::from(player_chunk_pos.0.x),
+            f64::from(pos.0.y) - f64::from(player_chunk_pos.0.y),
+        );
+        // Looking up or down shortens `look`, so direction matters less.
+        let alignment = (offset.dot(look) / distance).clamp(-1.0, 1.0);
+        distance * (1.0 - LOOK_PRIORITY_WEIGHT * alignment)
+    }
+
     fn chunk_distance_squared(pos: ChunkPos, player_chunk_pos: ChunkPos) -> u64 {
         let dx = u64::from(pos.0.x.abs_diff(player_chunk_pos.0.x));
         let dz = u64::from(pos.0.y.abs_diff(player_chunk_pos.0.y));
@@ -407,6 +436,26 @@ mod tests {
         assert!(!sender.is_chunk_sent(pos));
     }
 
+    #[test]
+    fn chunks_in_look_direction_are_sent_first() {
+        let center = ChunkPos::new(0, 0);
+        let east = DVec2::new(1.0, 0.0);
+        let ahead = ChunkSender::chunk_priority(ChunkPos::new(4, 0), center, east);
+        let behind = ChunkSender::chunk_priority(ChunkPos::new(-3, 0), center, east);
+        let side = ChunkSender::chunk_priority(ChunkPos::new(0, 4), center, east);
+
+        assert!(ahead < behind);
+        assert!(ahead < side);
+        assert_eq!(
+            ChunkSender::chunk_priority(center, center, east).to_bits(),
+            0.0_f64.to_bits()
+        );
+        assert_eq!(
+            ChunkSender::chunk_priority(ChunkPos::new(0, 4), center, DVec2::ZERO).to_bits(),
+            4.0_f64.to_bits()
+        );
+    }
+
     #[test]
     fn chunk_distance_squared_handles_far_chunk_coordinates() {
         let distance = ChunkSender::chunk_distance_squared(
@@ -6,12 +6,16 @@
 use enum_dispatch::enum_dispatch;
 use steel_protocol::packet_traits::{ClientPacket, CompressionInfo, EncodedPacket};
 use steel_protocol::packets::common::SClientInformation;
-use steel_protocol::packets::game::CSetChunkCacheRadius;
+use steel_protocol::packets::game::{CSetChunkCacheRadius, CSetSimulationDistance};
+use steel_protocol::traffic::TrafficStats;
 use steel_protocol::utils::ConnectionProtocol;
 use text_components::TextComponent;
 
 use crate::player::{ClientInformation, Player, networking};
 
+/// Vanilla clamps requested view distances to at least this many chunks.
+pub const MIN_VIEW_DISTANCE: u8 = 2;
+
 /// An object-safe trait for player connections.
 ///
 /// This abstracts the connection layer so that:
@@ -52,6 +56,16 @@ pub trait NetworkConnection: Send + Sync {
     /// Returns the current latency in milliseconds.
     fn latency(&self) -> i32;
 
+    /// Returns the most recent keep-alive round trip in milliseconds, unsmoothed.
+    fn last_round_trip(&self) -> i32 {
+        self.latency()
+    }
+
+    /// Returns the bytes and packets this connection has sent and received, if counted.
+    fn traffic(&self) -> Option<TrafficStats> {
+        None
+    }
+
     /// Closes the connection.
     fn close(&self);
 
@@ -84,6 +98,14 @@ impl NetworkConnection for Box<dyn NetworkConnection> {
         (**self).latency()
     }
 
+    fn last_round_trip(&self) -> i32 {
+        (**self).last_round_trip()
+    }
+
+    fn traffic(&self) -> Option<TrafficStats> {
+        (**self).traffic()
+    }
+
     fn close(&self) {
         (**self).close();
     }
@@ -94,6 +116,25 @@ impl NetworkConnection for Box<dyn NetworkConnection> {
 }
 
 impl Player {
+    /// Returns the player's smoothed keep-alive latency in milliseconds, as
+    /// shown in the tab list.
+    #[must_use]
+    pub fn latency(&self) -> i32 {
+        self.connection.latency()
+    }
+
+    /// Returns the player's most recent keep-alive round trip in milliseconds.
+    #[must_use]
+    pub fn last_round_trip(&self) -> i32 {
+        self.connection.last_round_trip()
+    }
+
+    /// Returns the bytes and packets exchanged with the player since they connected.
+    #[must_use]
+    pub fn traffic(&self) -> Option<TrafficStats> {
+        self.connection.traffic()
+    }
+
     /// Sends a packet to the player's connection.
     ///
     /// This is a generic helper that encodes the packet and delegates to the
@@ -136,13 +177,11 @@ impl Player {
 
     /// Handles client information updates during play phase.
     pub fn handle_client_information(&self, packet: SClientInformation) {
-        let old_view_distance = self.view_distance();
-
         let info = ClientInformation {
             language: packet.language,
             view_distance: packet
                 .view_distance
-                .clamp(2, i32::from(self.config.view_distance).max(2))
+                .clamp(i32::from(MIN_VIEW_DISTANCE), i32::from(u8::MAX))
                 as u8,
             chat_visibility: packet.chat_visibility,
             chat_colors: packet.chat_colors,
@@ -152,13 +191,37 @@ impl Player {
             allows_listing: packet.allows_listing,
             particle_status: packet.particle_status,
         };
-        self.set_client_information(info);
+        self.update_view_distance(|| self.set_client_information(info));
+    }
 
+    /// Runs `update` and tells the client and chunk map if the effective view or
+    /// simulation distance changed.
+    fn update_view_distance(&self, update: impl FnOnce()) {
+        let old_view_distance = self.view_distance();
+        let old_simulation_distance = self.simulation_distance();
+        update();
+        self.view_distance_changed(old_view_distance, old_simulation_distance);
+    }
+
+    /// Tells the client and chunk map if the effective view or simulation
+    /// distance differs from the previous values.
+    pub(crate) fn view_distance_changed(&self, old_view_distance: u8, old_simulation_distance: u8) {
         let new_view_distance = self.view_distance();
+        let new_simulation_distance = self.simulation_distance();
+
         if old_view_distance != new_view_distance {
             self.send_packet(CSetChunkCacheRadius {
                 radius: i32::from(new_view_distance),
             });
+        }
+        if old_simulation_distance != new_simulation_distance {
+            self.send_packet(CSetSimulationDistance {
+                simulation_distance: i32::from(new_simulation_distance),
+            });
+        }
+        if old_view_distance != new_view_distance
+            || old_simulation_distance != new_simulation_distance
+        {
             self.get_world().chunk_map.update_player_status(self);
         }
     }
@@ -169,6 +232,12 @@ impl Player {
         self.client_information.lock().clone()
     }
 
+    /// Whether the client asked for filtered sign and book text.
+    #[must_use]
+    pub fn is_text_filtering_enabled(&self) -> bool {
+        self.client_information.lock().text_filtering_enabled
+    }
+
     /// Updates the player's client information settings.
     pub fn set_client_information(&self, info: ClientInformation) {
         *self.client_information.lock() = info;
@@ -176,11 +245,39 @@ impl Player {
 
     /// Returns the effective view distance for this player.
     ///
-    /// This is the minimum of the client's requested view distance and
-    /// the server's configured maximum view distance.
+    /// This is the minimum of the client's requested view distance, the
+    /// server's configured maximum view distance and any `/viewdistance` limit.
     #[must_use]
     pub fn view_distance(&self) -> u8 {
         let client_view_distance = self.client_information.lock().view_distance;
-        client_view_distance.min(self.world.load().view_distance)
+        let limit = self.view_distance_limit.lock().unwrap_or(u8::MAX);
+        client_view_distance
+            .min(self.world.load().view_distance())
+            .min(limit)
+    }
+
+    /// Returns the effective simulation distance for this player.
+    ///
+    /// Chunks outside the view distance are not loaded, so this never exceeds it.
+    #[must_use]
+    pub fn simulation_distance(&self) -> u8 {
+        self.world
+            .load()
+            .simulation_distance()
+            .min(self.view_distance())
+    }
+
+    /// Returns the view distance limit set with `/viewdistance`, if any.
+    #[must_use]
+    pub fn view_distance_limit(&self) -> Option<u8> {
+        *self.view_distance_limit.lock()
+    }
+
+    /// Limits the player's view distance below the server maximum, or removes the
+    /// limit with `None`, resending chunks as needed.
+    pub fn set_view_distance_limit(&self, limit: Option<u8>) {
+        self.update_view_distance(|| {
+            *self.view_distance_limit.lock() = limit.map(|limit| limit.max(MIN_VIEW_DISTANCE));
+        });
     }
 }
@@ -3,6 +3,7 @@
 //! Manages food level, saturation, and exhaustion — the three values that
 //! control natural health regeneration and starvation damage.
 
+use steel_registry::data_components::components::FoodProperties;
 use steel_registry::vanilla_damage_types;
 use steel_registry::vanilla_game_rules::NATURAL_HEALTH_REGENERATION;
 use steel_utils::types::Difficulty;
@@ -149,6 +150,15 @@ impl FoodData {
         self.food_level > SPRINT_LEVEL
     }
 
+    /// Returns `true` if hunger lets a player sprint. Players who may fly or
+    /// ride a vehicle sprint regardless of their food level.
+    ///
+    /// Vanilla `LocalPlayer.hasEnoughFoodToSprint`.
+    #[must_use]
+    pub const fn allows_sprinting(&self, may_fly: bool, is_passenger: bool) -> bool {
+        is_passenger || self.has_enough_food() || may_fly
+    }
+
     /// Adds exhaustion from an action (sprinting, jumping, taking damage, …).
     /// The value is clamped so `exhaustion_level` never exceeds 40.
     pub fn add_exhaustion(&mut self, amount: f32) {
@@ -170,6 +180,11 @@ impl FoodData {
         );
     }
 
+    /// Applies the nutrition and saturation of a food component.
+    pub fn eat_food(&mut self, food: &FoodProperties) {
+        self.add(food.nutrition(), food.saturation());
+    }
+
     /// Runs one tick of the hunger system.
     /// Returns a [`FoodTickResult`] describing what happened this tick so the
     /// caller (`Player::tick`) can apply healing or starvation damage
@@ -259,6 +274,17 @@ pub enum FoodTickResult {
 }
 
 impl Player {
+    /// Returns whether hunger allows this player to sprint.
+    ///
+    /// See [`FoodData::allows_sprinting`].
+    #[must_use]
+    pub fn has_enough_food_to_sprint(&self) -> bool {
+        let may_fly = self.abilities.lock().may_fly;
+        self.food_data
+            .lock()
+            .allows_sprinting(may_fly, self.is_passenger())
+    }
+
     /// Ticks food/hunger regeneration and starvation.
     pub(super) fn tick_regeneration(&self) {
         let world = self.get_world();
@@ -310,6 +336,12 @@ impl Player {
             }
             FoodTickResult::None => {}
         }
+
+        // Vanilla `LocalPlayer.aiStep` stops sprinting as soon as the food
+        // level drops to the sprint threshold.
+        if self.is_sprinting() && !self.has_enough_food_to_sprint() {
+            self.set_sprinting(false);
+        }
     }
 
     /// Adds food exhaustion, gated by invulnerability.
@@ -437,4 +469,28 @@ mod tests {
 
         assert_eq!(food.food_level, MAX_FOOD_LEVEL);
     }
+
+    #[test]
+    fn sprinting_needs_more_than_six_food_unless_flying_or_riding() {
+        let mut food = FoodData::new();
+        food.food_level = SPRINT_LEVEL + 1;
+        assert!(food.allows_sprinting(false, false));
+
+        food.food_level = SPRINT_LEVEL;
+        assert!(!food.allows_sprinting(false, false));
+        assert!(food.allows_sprinting(true, false));
+        assert!(food.allows_sprinting(false, true));
+    }
+
+    #[test]
+    fn draining_food_to_the_sprint_level_stops_sprinting() {
+        let mut food = FoodData::new();
+        food.food_level = SPRINT_LEVEL + 1;
+        food.saturation_level = 0.0;
+        food.add_exhaustion(EXHAUSTION_DROP + 0.1);
+
+        let _ = food.tick(Difficulty::Normal, false, 20.0, 20.0);
+        assert_eq!(food.food_level, SPRINT_LEVEL);
+        assert!(!food.allows_sprinting(false, false));
+    }
 }
@@ -0,0 +1,295 @@
+//! Structure block, jigsaw block and command block screens.
+
+use steel_protocol::packets::game::{
+    CBlockEntityData, CommandBlockMode, SJigsawGenerate, SSetCommandBlock, SSetJigsawBlock,
+    SSetStructureBlock, StructureBlockUpdateType,
+};
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::BlockStateProperties;
+use steel_registry::vanilla_game_rules::COMMAND_BLOCKS_WORK;
+use steel_utils::serial::OptionalNbt;
+use steel_utils::types::UpdateFlags;
+use steel_utils::{BlockPos, Downcast as _, translations};
+use text_components::TextComponent;
+
+use crate::block_entity::BlockEntity as _;
+use crate::block_entity::entities::{
+    CommandBlockEntity, JigsawBlockEntity, StructureBlockEntity, StructureBlockFlags,
+    command_block_for_mode, is_command_block,
+};
+use crate::command::game_master_blocks_permission_expr;
+use crate::permission::PermissionContext;
+use crate::world::World;
+
+use super::Player;
+
+impl Player {
+    /// Vanilla `Player.canUseGameMasterBlocks`: creative players with the
+    /// game master blocks permission may edit structure, jigsaw and command blocks.
+    #[must_use]
+    pub fn can_use_game_master_blocks(&self) -> bool {
+        if !self.abilities.lock().instabuild {
+            return false;
+        }
+        let permission = match game_master_blocks_permission_expr() {
+            Ok(permission) => permission,
+            Err(error) => {
+                log::error!("invalid built-in game master blocks permission key: {error}");
+                return false;
+            }
+        };
+        let context = PermissionContext::for_world(self.get_world().key.clone());
+        self.has_permission_in(&permission, &context)
+    }
+
+    /// Handles the structure block screen.
+    ///
+    /// Based on Java's `ServerGamePacketListenerImpl::handleSetStructureBlock`.
+    pub fn handle_set_structure_block(&self, packet: SSetStructureBlock) {
+        if !self.can_use_game_master_blocks() {
+            return;
+        }
+
+        let world = self.get_world();
+        let pos = packet.pos;
+        let name = StructureBlockEntity::with_at(&world, pos, |structure| {
+            structure.set_mode(packet.mode);
+            structure.set_structure_name(&packet.name);
+            structure.apply_settings(
+                packet.offset,
+                packet.size,
+                packet.mirror,
+                packet.rotation,
+                packet.data,
+                packet.integrity,
+                packet.seed,
+                StructureBlockFlags {
+                    ignore_entities: packet.ignore_entities,
+                    strict: packet.strict,
+                    show_air: packet.show_air,
+                    show_bounding_box: packet.show_bounding_box,
+                },
+            );
+            structure.structure_name().map(ToString::to_string)
+        });
+        let Some(name) = name else {
+            return;
+        };
+        StructureBlockEntity::update_block_state(&world, pos);
+
+        let message: Option<TextComponent> = match name {
+            Some(name) => {
+                let structure = || [TextComponent::from(name.clone())];
+                match packet.update_type {
+                    StructureBlockUpdateType::UpdateData => None,
+                    StructureBlockUpdateType::SaveArea => {
+                        if StructureBlockEntity::save_structure(&world, pos, true) {
+                            Some(
+                                translations::STRUCTURE_BLOCK_SAVE_SUCCESS
+                                    .message(structure())
+                                    .into(),
+                            )
+                        } else {
+                            Some(
+                                translations::STRUCTURE_BLOCK_SAVE_FAILURE
+                                    .message(structure())
+                                    .into(),
+                            )
+                        }
+                    }
+                    StructureBlockUpdateType::LoadArea => {
+                        if !StructureBlockEntity::is_structure_loadable(&world, pos) {
+                            Some(
+                                translations::STRUCTURE_BLOCK_LOAD_NOT_FOUND
+                                    .message(structure())
+                                    .into(),
+                            )
+                        } else if StructureBlockEntity::place_structure_if_same_size(&world, pos) {
+                            Some(
+                                translations::STRUCTURE_BLOCK_LOAD_SUCCESS
+                                    .message(structure())
+                                    .into(),
+                            )
+                        } else {
+                            Some(
+                                translations::STRUCTURE_BLOCK_LOAD_PREPARE
+                                    .message(structure())
+                                    .into(),
+                            )
+                        }
+                    }
+                    StructureBlockUpdateType::ScanArea => {
+                        if StructureBlockEntity::detect_size(&world, pos) {
+                            Some(
+                                translations::STRUCTURE_BLOCK_SIZE_SUCCESS
+                                    .message(structure())
+                                    .into(),
+                            )
+                        } else {
+                            Some(TextComponent::translated(
+                                translations::STRUCTURE_BLOCK_SIZE_FAILURE.msg(),
+                            ))
+                        }
+                    }
+                }
+            }
+            None => Some(
+                translations::STRUCTURE_BLOCK_INVALID_STRUCTURE_NAME
+                    .message([TextComponent::from(packet.name)])
+                    .into(),
+            ),
+        };
+        if let Some(message) = message {
+            self.display_client_message(&message, false);
+        }
+
+        StructureBlockEntity::with_at(&world, pos, |structure| structure.set_changed());
+        world.send_block_updated(pos);
+    }
+
+    /// Handles the jigsaw block screen.
+    ///
+    /// Based on Java's `ServerGamePacketListenerImpl::handleSetJigsawBlock`.
+    pub fn handle_set_jigsaw_block(&self, packet: SSetJigsawBlock) {
+        if !self.can_use_game_master_blocks() {
+            return;
+        }
+
+        let world = self.get_world();
+        let Some(block_entity) = world.get_block_entity(packet.pos) else {
+            return;
+        };
+        let mut guard = block_entity.lock();
+        let Some(jigsaw) = guard.downcast_mut::<JigsawBlockEntity>() else {
+            return;
+        };
+        jigsaw.apply_settings(
+            packet.name,
+            packet.target,
+            packet.pool,
+            packet.final_state,
+            packet.joint,
+            packet.placement_priority,
+            packet.selection_priority,
+        );
+        jigsaw.set_changed();
+        drop(guard);
+        world.send_block_updated(packet.pos);
+    }
+
+    /// Handles the jigsaw block "Generate" button.
+    ///
+    /// Based on Java's `ServerGamePacketListenerImpl::handleJigsawGenerate`.
+    pub fn handle_jigsaw_generate(&self, packet: SJigsawGenerate) {
+        if !self.can_use_game_master_blocks() {
+            return;
+        }
+
+        let world = self.get_world();
+        let Some(block_entity) = world.get_block_entity(packet.pos) else {
+            return;
+        };
+        let guard = block_entity.lock();
+        let Some(jigsaw) = guard.downcast_ref::<JigsawBlockEntity>() else {
+            return;
+        };
+        // TODO: Run `JigsawPlacement.generateJigsaw` once jigsaw assembly can place
+        // its pieces into a live world instead of a worldgen region.
+        log::debug!(
+            "Player {} asked to generate pool {} from jigsaw at {:?} ({} levels, keep jigsaws: {})",
+            self.gameprofile.name,
+            jigsaw.pool(),
+            packet.pos,
+            packet.levels,
+            packet.keep_jigsaws
+        );
+    }
+
+    /// Sends the command block at `pos` to this player so the client can fill
+    /// in its screen.
+    ///
+    /// Based on Java's `ServerPlayer::openCommandBlock`. Returns `false` if
+    /// there is no command block entity at `pos`.
+    pub fn open_command_block(&self, world: &World, pos: BlockPos) -> bool {
+        let Some(block_entity) = world.get_block_entity(pos) else {
+            return false;
+        };
+        let guard = block_entity.lock();
+        if guard.downcast_ref::<CommandBlockEntity>().is_none() {
+            return false;
+        }
+        let packet = CBlockEntityData {
+            pos,
+            block_entity_type: guard.get_type().id() as i32,
+            nbt: OptionalNbt(Some(guard.save_custom_only())),
+        };
+        drop(guard);
+        self.send_packet(packet);
+        true
+    }
+
+    /// Handles the command block screen.
+    ///
+    /// Based on Java's `ServerGamePacketListenerImpl::handleSetCommandBlock`.
+    pub fn handle_set_command_block(&self, packet: SSetCommandBlock) {
+        let world = self.get_world();
+        if !world.get_game_rule(&COMMAND_BLOCKS_WORK) {
+            self.send_message(&TextComponent::translated(
+                translations::ADV_MODE_NOT_ENABLED.msg(),
+            ));
+            return;
+        }
+        if !self.can_use_game_master_blocks() {
+            self.send_message(&TextComponent::translated(
+                translations::ADV_MODE_NOT_ALLOWED.msg(),
+            ));
+            return;
+        }
+
+        let pos = packet.pos;
+        let current = world.get_block_state(pos);
+        let Some(old_mode) =
+            CommandBlockEntity::with_at(&world, pos, |command_block| command_block.mode())
+        else {
+            return;
+        };
+        if is_command_block(current.get_block()) {
+            let state = command_block_for_mode(packet.mode)
+                .default_state()
+                .set_value(
+                    &BlockStateProperties::FACING,
+                    current.get_value(&BlockStateProperties::FACING),
+                )
+                .set_value(&BlockStateProperties::CONDITIONAL, packet.conditional);
+            if state != current {
+                world.set_block(pos, state, UpdateFlags::UPDATE_CLIENTS);
+            }
+        }
+
+        let Some(schedule) = CommandBlockEntity::with_at(&world, pos, |command_block| {
+            command_block.set_command(packet.command.clone());
+            command_block.set_track_output(packet.track_output);
+            let activated = command_block.set_automatic(packet.automatic);
+            // Vanilla `CommandBlockEntity.onModeSwitch`.
+            let switched_to_auto = old_mode != packet.mode
+                && packet.mode == CommandBlockMode::Auto
+                && (command_block.is_powered() || command_block.is_automatic());
+            command_block.set_changed();
+            activated || switched_to_auto
+        }) else {
+            return;
+        };
+        if schedule {
+            CommandBlockEntity::schedule_tick(&world, pos);
+        }
+        world.send_block_updated(pos);
+
+        if !packet.command.is_empty() {
+            self.send_message(
+                &translations::ADV_MODE_SET_COMMAND_SUCCESS
+                    .message([TextComponent::from(packet.command)])
+                    .into(),
+            );
+        }
+    }
+}
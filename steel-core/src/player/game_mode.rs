@@ -10,17 +10,24 @@ use glam::DVec3;
 use steel_protocol::packets::game::{
     CBlockChangedAck, CBlockUpdate, CChangeDifficulty, CGameEvent, COpenSignEditor,
     CPlayerInfoUpdate, CSetCamera, CSetEntityMotion, CSetHeldSlot, GameEventType, PlayerAction,
-    SAttack, SInteract, SPickItemFromBlock, SPlayerAction, SSignUpdate, SSpectatorAction, SUseItem,
-    SUseItemOn,
+    SAttack, SEditBook, SInteract, SPickItemFromBlock, SPlayerAction, SSignUpdate,
+    SSpectatorAction, SUseItem, SUseItemOn,
 };
 use steel_registry::blocks::block_state_ext::BlockStateExt;
 use steel_registry::blocks::properties::Direction;
 use steel_registry::damage_type::DamageType;
-use steel_registry::data_components::components::PiercingWeapon;
+use steel_registry::data_components::components::{
+    Filterable, PiercingWeapon, WritableBookContent, WrittenBookContent,
+};
+use steel_registry::data_components::vanilla_components::{
+    WRITABLE_BOOK_CONTENT, WRITTEN_BOOK_CONTENT,
+};
 use steel_registry::entity_type::EntityTypeRef;
 use steel_registry::item_stack::ItemStack;
 use steel_registry::sound_event::{SoundEventHolder, SoundEventRef};
-use steel_registry::{REGISTRY, vanilla_attributes, vanilla_damage_types, vanilla_entities};
+use steel_registry::{
+    REGISTRY, vanilla_attributes, vanilla_damage_types, vanilla_entities, vanilla_items,
+};
 use steel_utils::entity_events::EntityStatus;
 use steel_utils::types::{Difficulty, GameType, InteractionHand};
 use steel_utils::{BlockPos, Downcast as _, Identifier, WorldAabb};
@@ -28,8 +35,8 @@ use text_components::TextComponent;
 use text_components::translation::TranslatedMessage;
 
 use crate::behavior::{
-    BLOCK_BEHAVIORS, BlockCollisionContext, BlockHitResult, ITEM_BEHAVIORS, InteractionResult,
-    InventoryAccess, UseOnContext,
+    BLOCK_BEHAVIORS, BlockCollisionContext, BlockHitResult, BlockInteraction, ITEM_BEHAVIORS,
+    InteractionResult, InventoryAccess, UseOnContext,
 };
 use crate::block_entity::BlockEntity;
 use crate::block_entity::entities::SignBlockEntity;
@@ -37,7 +44,9 @@ use crate::command::player_can_change_difficulty;
 use crate::enchantment_helper::{self, EnchantmentDamageContext, EnchantmentPostAttackContext};
 use crate::entity::attribute::{AttributeModifier, AttributeModifierOperation};
 use crate::entity::damage::DamageSource;
+use crate::entity::entities::EnderDragonEntity;
 use crate::entity::{Entity, LivingEntity, SharedEntity};
+use crate::inventory::container::Container;
 use crate::inventory::equipment::EquipmentSlot;
 use crate::inventory::menu::Menu;
 use crate::physics::collision::{CollisionWorld, WorldCollisionProvider};
@@ -45,7 +54,7 @@ use crate::physics::shapes;
 use crate::player::Player;
 use crate::player::block_breaking::BlockBreakAction;
 use crate::player::movement::wrap_degrees;
-use crate::player::player_inventory::PlayerInventory;
+use crate::player::player_inventory::{PlayerInventory, hand_to_equipment_slot};
 use crate::world::{ClipBlockShape, ClipFluid, World};
 use steel_utils::axis::Axis;
 
@@ -195,6 +204,8 @@ pub fn use_item(player: &Player, world: &Arc<World>, hand: InteractionHand) -> I
         let item_behaviors = &*ITEM_BEHAVIORS;
         let item_behavior = item_behaviors.get_behavior(item_ref);
 
+        // Items used over time apply their after-use effects when finished instead.
+        let not_using = item_behavior.get_use_duration(&stack_before_use) <= 0;
         let result = item_behavior.use_item(&mut context);
 
         // Restore count for creative mode (infinite materials)
@@ -206,8 +217,12 @@ pub fn use_item(player: &Player, world: &Arc<World>, hand: InteractionHand) -> I
             });
         }
 
-        if result.should_apply_item_use_side_effects() {
-            player.apply_item_use_cooldown(&stack_before_use);
+        if not_using && result.should_apply_item_use_side_effects() {
+            let held = context.inv.with_item(|item| item.clone());
+            let held = player.apply_after_use_component_side_effects(held, &stack_before_use);
+            context
+                .inv
+                .with_inventory(|inventory| inventory.set_item_in_hand(hand, held));
         }
 
         return result;
@@ -795,17 +810,22 @@ impl Player {
             return;
         };
         let has_infinite_materials = self.has_infinite_materials();
-        let mut inventory = self.inventory.lock();
-        inventory.mutate_item_in_hand(InteractionHand::MainHand, |stack| {
-            if stack.is_empty() {
-                return;
-            }
-            let behavior = ITEM_BEHAVIORS.get_behavior(stack.item());
-            behavior.post_hurt_enemy(stack, living_target, self);
-            if let Some(damage) = behavior.item_damage_per_attack(stack) {
-                stack.hurt_and_break(damage, has_infinite_materials);
-            }
-        });
+        let broke = self
+            .inventory
+            .lock()
+            .mutate_item_in_hand(InteractionHand::MainHand, |stack| {
+                if stack.is_empty() {
+                    return false;
+                }
+                let behavior = ITEM_BEHAVIORS.get_behavior(stack.item());
+                behavior.post_hurt_enemy(stack, living_target, self);
+                behavior
+                    .item_damage_per_attack(stack)
+                    .is_some_and(|damage| stack.hurt_and_break(damage, has_infinite_materials))
+            });
+        if broke {
+            self.on_equipped_item_broken(EquipmentSlot::MainHand);
+        }
     }
 
     /// Interacts with an entity using the held item.
@@ -876,9 +896,15 @@ impl Player {
         }
 
         let world = self.get_world();
-        let Some(target) = world.get_accessible_entity_by_id(packet.entity_id) else {
+        let Some((target, part)) = world.get_accessible_entity_or_part_by_id(packet.entity_id)
+        else {
             return;
         };
+        let dragon = target.downcast_ref::<EnderDragonEntity>();
+        let target_box = match (dragon, part) {
+            (Some(dragon), Some(part)) => dragon.part_bounding_box(part),
+            _ => target.bounding_box(),
+        };
 
         let target_pos = target.block_position();
         if !world.world_border_snapshot().is_within_bounds_with_margin(
@@ -897,12 +923,26 @@ impl Player {
 
         if !self.is_within_attack_range_with_buffer(
             &main_hand_item,
-            target.bounding_box(),
+            target_box,
             ATTACK_RANGE_BUFFER,
         ) {
             return;
         }
 
+        if !self.try_record_interaction() {
+            log::warn!("Player {} is attacking too fast", self.gameprofile.name);
+            return;
+        }
+
+        if !self.can_see_entity_box(&world, &target_box) {
+            log::debug!(
+                "Player {} attacked entity {} through blocks",
+                self.gameprofile.name,
+                target.id()
+            );
+            return;
+        }
+
         if main_hand_item.get_piercing_weapon().is_some() {
             return;
         }
@@ -920,6 +960,11 @@ impl Player {
             return;
         }
 
+        if let Some(dragon) = dragon
+            && let Some(part) = part
+        {
+            dragon.set_struck_part(part);
+        }
         let _ = self.attack(&target);
     }
 
@@ -975,6 +1020,24 @@ impl Player {
             return;
         }
 
+        if !self.try_record_interaction() {
+            log::warn!(
+                "Player {} is interacting with entities too fast",
+                self.gameprofile.name
+            );
+            return;
+        }
+
+        if !self.can_see_entity_box(&world, &target.bounding_box()) {
+            log::debug!(
+                "Player {} interacted with entity {} through blocks",
+                self.gameprofile.name,
+                target.id()
+            );
+            self.resync_inventory();
+            return;
+        }
+
         let result = self.interact_on(target.as_ref(), packet.hand, packet.location);
         if result.should_swing_server() {
             self.swing(packet.hand, true);
@@ -1010,7 +1073,7 @@ impl Player {
             CPlayerInfoUpdate::update_game_mode(self.gameprofile.id, gamemode as i32);
         self.server().broadcast_to_online(update_packet);
 
-        // TODO: Refresh sleeping-player aggregation once world sleep tracking is implemented.
+        self.get_world().update_sleeping_player_list();
 
         if gamemode == GameType::Creative {
             self.reset_current_impulse_context();
@@ -1024,7 +1087,7 @@ impl Player {
         if gamemode == GameType::Spectator {
             self.stop_riding();
             // TODO: Remove shoulder entities once player shoulder storage is implemented.
-            // TODO: Stop item use once living item-use state is implemented.
+            self.stop_using_item();
             // TODO: Stop location-based enchantment effects once those effects are implemented.
         } else if was_spectator {
             self.send_packet(CSetCamera {
@@ -1163,6 +1226,19 @@ impl Player {
         self.game_mode() == GameType::Creative
     }
 
+    /// Damages the item in `hand`, broadcasting the break effect if it breaks.
+    ///
+    /// Mirrors vanilla `ItemStack.hurtAndBreak(int, LivingEntity, InteractionHand)`.
+    pub fn hurt_item_in_hand(&self, hand: InteractionHand, amount: i32) {
+        let broke =
+            self.inventory
+                .lock()
+                .hurt_item_in_hand(hand, amount, self.has_infinite_materials());
+        if broke {
+            self.on_equipped_item_broken(hand_to_equipment_slot(hand));
+        }
+    }
+
     /// Acknowledges block changes up to the given sequence number.
     ///
     /// The ack is batched and sent once per tick (in `tick_ack_block_changes`),
@@ -1304,6 +1380,8 @@ impl Player {
     /// Handles the use of an item on a block.
     ///
     /// Implements the logic from Java's `ServerGamePacketListenerImpl.handleUseItemOn()`.
+    /// The client predicted the interaction, so the blocks around the hit are
+    /// always resent, and the held item as well when the interaction failed.
     pub fn handle_use_item_on(&self, packet: SUseItemOn) {
         if !self.has_client_loaded() {
             return;
@@ -1311,18 +1389,36 @@ impl Player {
 
         self.ack_block_changes_up_to(packet.sequence);
 
-        let pos = packet.block_hit.block_pos;
-        let direction = packet.block_hit.direction;
+        let result = self.interact_with_block(packet.hand, &packet.block_hit);
+        if result.should_swing_server() {
+            self.swing(packet.hand, true);
+        }
 
+        self.send_block_updates(packet.block_hit.block_pos, packet.block_hit.direction);
+        if result == InteractionResult::Fail {
+            self.resync_inventory();
+        } else {
+            self.broadcast_inventory_changes();
+        }
+    }
+
+    /// Runs the use-item-on pipeline: packet validation (reach, rate and line of
+    /// sight), protections, plugins, then block and item behaviors. Each stage either passes or ends the
+    /// interaction with its result; stages that deny it return `Fail`.
+    fn interact_with_block(
+        &self,
+        hand: InteractionHand,
+        hit_result: &BlockHitResult,
+    ) -> InteractionResult {
+        let pos = hit_result.block_pos;
         if !self.is_within_block_interaction_range(pos) {
-            self.send_block_updates(pos, direction);
-            return;
+            return InteractionResult::Fail;
         }
 
         let center_x = f64::from(pos.x()) + 0.5;
         let center_y = f64::from(pos.y()) + 0.5;
         let center_z = f64::from(pos.z()) + 0.5;
-        let location = &packet.block_hit.location;
+        let location = &hit_result.location;
         let limit = 1.000_000_1;
 
         if (location.x - center_x).abs() >= limit
@@ -1335,36 +1431,54 @@ impl Player {
                 location,
                 pos
             );
-            self.send_block_updates(pos, direction);
-            return;
+            return InteractionResult::Fail;
+        }
+
+        if !self.try_record_interaction() {
+            log::warn!(
+                "Player {} is using items on blocks too fast",
+                self.gameprofile.name
+            );
+            return InteractionResult::Fail;
         }
 
         let world = self.get_world();
 
+        if !self.can_see_block_hit(&world, hit_result) {
+            log::debug!(
+                "Rejecting UseItemOnPacket from {}: block {:?} is not visible",
+                self.gameprofile.name,
+                pos
+            );
+            return InteractionResult::Fail;
+        }
+
         if pos.y() >= world.max_build_height() {
             // TODO: Send "build.tooHigh" message to player
-            self.send_block_updates(pos, direction);
-            return;
+            return InteractionResult::Fail;
         }
 
         if self.is_awaiting_teleport() {
-            self.send_block_updates(pos, direction);
-            return;
+            return InteractionResult::Fail;
         }
 
         if !world.may_interact(self, pos) {
-            self.send_block_updates(pos, direction);
-            return;
+            return InteractionResult::Fail;
         }
 
-        let result = use_item_on(self, &world, packet.hand, &packet.block_hit);
-
-        if result.should_swing_server() {
-            self.swing(packet.hand, true);
+        if let Some(plugins) = self.server().plugin_manager() {
+            let result = plugins.use_item_on(&BlockInteraction {
+                player: self,
+                hand,
+                hit_result,
+                world: &world,
+            });
+            if result.consumes_action() {
+                return result;
+            }
         }
 
-        self.send_block_updates(pos, direction);
-        self.broadcast_inventory_changes();
+        use_item_on(self, &world, hand, hit_result)
     }
 
     /// Handles a player action packet (block breaking, item dropping, etc.).
@@ -1412,8 +1526,7 @@ impl Player {
                 self.drop_from_selected(false);
             }
             PlayerAction::ReleaseUseItem => {
-                // TODO: Implement release use item (releasing bow, etc.)
-                log::debug!("Player {} released use item", self.gameprofile.name);
+                self.release_using_item();
             }
             PlayerAction::SwapItemWithOffhand => {
                 if self.game_mode() == GameType::Spectator {
@@ -1425,7 +1538,7 @@ impl Player {
                     self.broadcast_entity_event(EntityStatus::SwapHands);
                     self.broadcast_inventory_changes();
                 }
-                // TODO: Stop active item use once the using-item foundation exists.
+                self.stop_using_item();
             }
             PlayerAction::Stab => {
                 if self.game_mode() == GameType::Spectator {
@@ -1553,6 +1666,10 @@ impl Player {
             return;
         }
 
+        let lines = packet
+            .lines
+            .map(|line| self.filter_text(strip_formatting_codes(&line)));
+
         let world = self.get_world();
 
         let Some(block_entity) = world.get_block_entity(packet.pos) else {
@@ -1576,11 +1693,25 @@ impl Player {
             return;
         }
 
+        // Vanilla `SignBlockEntity.setMessages`: lines keep the style they had
+        // and filtering clients only ever store the filtered text.
+        let filtering = self.is_text_filtering_enabled();
         let text = sign.get_text_mut(packet.is_front_text);
-        for (i, line) in packet.lines.iter().enumerate() {
-            if i < 4 {
-                let stripped = strip_formatting_codes(line);
-                text.set_message(i, TextComponent::plain(stripped));
+        for (i, line) in lines.iter().enumerate() {
+            let Some(current) = text.get_message(i, filtering) else {
+                continue;
+            };
+            let format = current.format.clone();
+            let styled = |text: &str| {
+                let mut component = TextComponent::plain(text.to_owned());
+                component.format = format.clone();
+                component
+            };
+            let filtered = styled(line.get(true));
+            if filtering {
+                text.set_message(i, filtered);
+            } else {
+                text.set_filtered_message(i, styled(line.raw()), filtered);
             }
         }
 
@@ -1598,6 +1729,96 @@ impl Player {
         }
     }
 
+    /// Handles a book edit packet: saves the pages of a writable book, or
+    /// signs it into a written book when a title is sent.
+    pub fn handle_edit_book(&self, packet: SEditBook) {
+        let Ok(slot) = usize::try_from(packet.slot) else {
+            return;
+        };
+        if !PlayerInventory::is_hotbar_slot(slot) && slot != PlayerInventory::SLOT_OFFHAND {
+            return;
+        }
+
+        // Filtered before locking the inventory, which plugins may read.
+        let pages: Vec<_> = packet
+            .pages
+            .into_iter()
+            .map(|page| self.filterable_from_outgoing(self.filter_text(page)))
+            .collect();
+        let title = packet
+            .title
+            .map(|title| self.filterable_from_outgoing(self.filter_text(title)));
+
+        let mut inventory = self.inventory.lock();
+        let carried = inventory.get_item(slot);
+        if !carried.has(WRITABLE_BOOK_CONTENT) {
+            return;
+        }
+
+        if let Some(title) = title {
+            let pages = pages
+                .into_iter()
+                .map(|page| {
+                    Filterable::new(
+                        TextComponent::plain(page.raw().clone()),
+                        page.filtered().cloned().map(TextComponent::plain),
+                    )
+                })
+                .collect();
+            let content =
+                match WrittenBookContent::new(title, self.gameprofile.name.clone(), 0, pages, true)
+                {
+                    Ok(content) => content,
+                    Err(error) => {
+                        log::warn!(
+                            "Player {} sent an invalid book: {error}",
+                            self.gameprofile.name
+                        );
+                        return;
+                    }
+                };
+            let mut book = carried.transmute_copy(&vanilla_items::WRITTEN_BOOK);
+            book.remove(WRITABLE_BOOK_CONTENT);
+            book.set(WRITTEN_BOOK_CONTENT, content);
+            inventory.set_item(slot, book);
+        } else {
+            match WritableBookContent::new(pages) {
+                Ok(content) => inventory
+                    .get_item_mut(slot)
+                    .set(WRITABLE_BOOK_CONTENT, content),
+                Err(error) => {
+                    log::warn!(
+                        "Player {} sent an invalid book: {error}",
+                        self.gameprofile.name
+                    );
+                    return;
+                }
+            }
+        }
+
+        drop(inventory);
+        self.broadcast_inventory_changes();
+    }
+
+    /// Runs sign or book text through the plugin filters.
+    fn filter_text(&self, text: String) -> Filterable<String> {
+        let filtered = self
+            .server()
+            .plugin_manager()
+            .and_then(|plugins| plugins.filter_text(self, &text));
+        Filterable::new(text, filtered)
+    }
+
+    /// Vanilla `ServerGamePacketListenerImpl.filterableFromOutgoing`: players
+    /// with text filtering enabled only ever store the filtered text.
+    fn filterable_from_outgoing(&self, text: Filterable<String>) -> Filterable<String> {
+        if !self.is_text_filtering_enabled() {
+            return text;
+        }
+        let filtered = text.get(true).clone();
+        Filterable::pass_through(filtered)
+    }
+
     /// Opens the sign editor for the player.
     ///
     /// # Arguments
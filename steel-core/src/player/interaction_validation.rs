@@ -0,0 +1,130 @@
+//! Line-of-sight and rate checks for client block and entity interactions.
+//!
+//! Vanilla only bounds interactions by reach. These checks additionally reject
+//! clicks on faces turned away from the player, targets behind blocks and
+//! interaction packets sent faster than a client can click.
+
+use glam::DVec3;
+use steel_utils::WorldAabb;
+
+use crate::behavior::BlockHitResult;
+use crate::player::Player;
+use crate::world::{ClipBlockShape, ClipFluid, World};
+
+/// Interactions added to the throttler per accepted packet; it decays by one each tick.
+pub(super) const INTERACTION_SPAM_INCREMENT: i32 = 1;
+
+/// Throttler level at which further interactions are dropped.
+pub(super) const INTERACTION_SPAM_THRESHOLD: i32 = 80;
+
+/// Distance, in blocks, the eye may sit behind a clicked face before it counts as turned away.
+///
+/// Covers the eye position lagging a tick of movement behind the client.
+const FACE_ANGLE_TOLERANCE: f64 = 0.5;
+
+/// Returns whether the clicked face points towards `eye`.
+///
+/// A ray can only enter a shape through a face whose normal points back at the ray origin.
+#[must_use]
+fn face_faces_eye(eye: DVec3, hit: &BlockHitResult) -> bool {
+    let normal = hit.direction.offset_vec().as_dvec3();
+    (eye - hit.location).dot(normal) >= -FACE_ANGLE_TOLERANCE
+}
+
+/// Returns the point of `aabb` closest to `point`.
+fn closest_point(aabb: &WorldAabb, point: DVec3) -> DVec3 {
+    DVec3::new(
+        point.x.clamp(aabb.min_x(), aabb.max_x()),
+        point.y.clamp(aabb.min_y(), aabb.max_y()),
+        point.z.clamp(aabb.min_z(), aabb.max_z()),
+    )
+}
+
+impl Player {
+    fn interaction_eye_position(&self) -> DVec3 {
+        let position = self.position();
+        DVec3::new(position.x, self.get_eye_y(), position.z)
+    }
+
+    /// Records an interaction packet and returns whether it is under the spam threshold.
+    pub(super) fn try_record_interaction(&self) -> bool {
+        let mut throttler = self.interaction_spam_throttler.lock();
+        if !throttler.is_under_threshold() {
+            return false;
+        }
+        throttler.increment();
+        true
+    }
+
+    /// Returns whether the player can see the clicked face of `hit`.
+    ///
+    /// The face must point towards the eye, and a ray from the eye to either the
+    /// hit location or the block center must reach the block before any other.
+    pub(super) fn can_see_block_hit(&self, world: &World, hit: &BlockHitResult) -> bool {
+        let eye = self.interaction_eye_position();
+        if hit.inside {
+            return true;
+        }
+        if !face_faces_eye(eye, hit) {
+            return false;
+        }
+
+        let (center_x, center_y, center_z) = hit.block_pos.get_center();
+        let center = DVec3::new(center_x, center_y, center_z);
+        [hit.location, center].into_iter().any(|target| {
+            let clip = world.clip(eye, target, ClipBlockShape::Outline, ClipFluid::None);
+            clip.is_miss() || clip.inside || clip.block_pos == hit.block_pos
+        })
+    }
+
+    /// Returns whether any of a few points on `target` can be reached from the eye
+    /// without passing through block collision.
+    pub(super) fn can_see_entity_box(&self, world: &World, target: &WorldAabb) -> bool {
+        let eye = self.interaction_eye_position();
+        if target.contains(eye) {
+            return true;
+        }
+
+        let center = target.center();
+        [
+            closest_point(target, eye),
+            center,
+            DVec3::new(center.x, target.max_y(), center.z),
+        ]
+        .into_iter()
+        .any(|point| {
+            let clip = world.clip(eye, point, ClipBlockShape::Collider, ClipFluid::None);
+            clip.is_miss() || clip.inside
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::{BlockPos, Direction};
+
+    use super::*;
+
+    fn hit(direction: Direction, location: DVec3) -> BlockHitResult {
+        BlockHitResult {
+            location,
+            direction,
+            block_pos: BlockPos::new(0, 0, 0),
+            miss: false,
+            inside: false,
+            world_border_hit: false,
+        }
+    }
+
+    #[test]
+    fn faces_turned_away_from_the_eye_are_rejected() {
+        let top = hit(Direction::Up, DVec3::new(0.5, 1.0, 0.5));
+        assert!(face_faces_eye(DVec3::new(0.5, 2.6, 2.0), &top));
+        assert!(face_faces_eye(DVec3::new(0.5, 0.7, 2.0), &top));
+        assert!(!face_faces_eye(DVec3::new(0.5, -1.0, 2.0), &top));
+
+        let north = hit(Direction::North, DVec3::new(0.5, 0.5, 0.0));
+        assert!(face_faces_eye(DVec3::new(0.5, 1.6, -3.0), &north));
+        assert!(!face_faces_eye(DVec3::new(0.5, 1.6, 3.0), &north));
+    }
+}
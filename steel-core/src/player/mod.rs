@@ -1,5 +1,6 @@
 //! This module contains all things player-related.
 mod abilities;
+mod advancements;
 pub mod block_breaking;
 mod chat_state;
 pub mod chunk_sender;
@@ -10,12 +11,14 @@ mod entity_state;
 /// Experience System
 pub mod experience;
 pub mod food_data;
+mod game_master_blocks;
 /// Game mode specific logic for player interactions.
 pub mod game_mode;
 mod game_mode_state;
 mod game_profile;
 mod health_sync;
 mod input_state;
+mod interaction_validation;
 mod item_cooldowns;
 mod known_players;
 mod lifecycle_state;
@@ -25,17 +28,28 @@ pub mod movement;
 mod movement_state;
 /// This module contains the networking implementation for the player.
 pub mod networking;
+mod offline_skins;
 pub mod player_data;
 pub mod player_data_storage;
 pub mod player_inventory;
+pub mod plugin_messages;
 pub mod profile_key;
 mod profile_lookup;
+mod raid_omen;
+mod recipe_book;
+mod resource_packs;
 mod signature_cache;
+mod skin;
+mod sleeping;
 mod spam_throttler;
+mod stats;
 mod teleport_state;
 mod tick_state;
+mod using_item;
+mod warden_spawn_tracker;
 
 pub use abilities::{Abilities, DEFAULT_FLYING_SPEED};
+pub use advancements::{CriteriaTrigger, PlayerAdvancements};
 use chat_state::ChatState;
 use container_counter::ContainerCounter;
 use food_data::FoodData;
@@ -46,14 +60,22 @@ use item_cooldowns::ItemCooldowns;
 use lifecycle_state::PlayerLifecycleState;
 pub use message_validator::LastSeenMessagesValidator;
 use movement_state::MovementState;
+pub use plugin_messages::ClientChannels;
+use recipe_book::ServerRecipeBook;
+pub use resource_packs::{ResourcePackStatuses, declines_required_pack};
 pub use signature_cache::{LastSeen, MessageCache};
 use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+pub use sleeping::{BedSleepingProblem, RespawnConfig};
+use spam_throttler::TickThrottler;
+pub use stats::StatsCounter;
 use steel_protocol::{
     packet_traits::{CompressionInfo, EncodedPacket},
     packets::game::{CCooldown, CLevelEvent, CSetEntityData, CSetExperience},
 };
 use teleport_state::TeleportState;
 use tick_state::PlayerTickState;
+use using_item::UsingItem;
+pub use warden_spawn_tracker::{MAX_WARNING_LEVEL, WardenSpawnTracker};
 
 use block_breaking::BlockBreakingManager;
 use enum_dispatch::enum_dispatch;
@@ -61,8 +83,9 @@ use game_mode_state::PlayerGameModeState;
 pub use game_profile::{GameProfile, GameProfileAction, is_valid_player_name, offline_uuid};
 pub(crate) use known_players::KnownPlayerNameLookup;
 pub use known_players::{KnownPlayer, KnownPlayers};
+pub use offline_skins::OfflineSkinCache;
 pub use profile_lookup::ProfileLookupError;
-pub(crate) use profile_lookup::lookup_online_profile;
+pub(crate) use profile_lookup::{fetch_profile_properties, lookup_online_profile};
 use std::sync::{Arc, Weak};
 use steel_macros::entity_impl;
 use steel_protocol::packets::game::{
@@ -70,11 +93,13 @@ use steel_protocol::packets::game::{
     CSetDefaultSpawnPosition, CSetHealth, CSetHeldSlot, CSetPassengers, ClientCommandAction,
     EquipmentSlotItem, LookAtAnchor, RelativeMovement, SoundSource,
 };
+use steel_protocol::packets::login::GameProfileProperty;
 use steel_registry::blocks::block_state_ext::BlockStateExt as _;
 use steel_registry::entity_data::{EntityPose, ParticleList};
 use steel_registry::entity_type::{EntityDimensions, EntityTypeRef};
 use steel_registry::game_rules::GameRuleRef;
 use steel_registry::sound_event::SoundEventRef;
+use steel_registry::stat::{CustomStat, Stat};
 use steel_registry::vanilla_block_tags::BlockTag;
 use steel_registry::vanilla_entity_data::PlayerEntityData;
 use steel_registry::vanilla_game_rules::{
@@ -90,8 +115,9 @@ use uuid::Uuid;
 
 use arc_swap::ArcSwap;
 use steel_utils::locks::SyncMutex;
+use steel_utils::translations_registry::TRANSLATIONS;
 use steel_utils::types::{Difficulty, GameType};
-use text_components::resolving::TextResolutor;
+use text_components::resolving::{TextResolutor, TryTextResolutor as _};
 use text_components::translation::TranslatedMessage;
 use text_components::{
     Modifier as _, TextComponent,
@@ -99,8 +125,11 @@ use text_components::{
 };
 use text_components::{content::Resolvable, custom::CustomData};
 
+use crate::behavior::items::CompassItem;
 use crate::chunk::chunk_request::{ChunkRequestHandle, ChunkRequestState};
-use crate::config::RuntimeConfig;
+use crate::command::execution::{CommandSource, CommandTextResolver};
+use crate::command::sender::CommandSender;
+use crate::config::SharedRuntimeConfig;
 use crate::enchantment_helper;
 use crate::entity::damage::DamageSource;
 use crate::entity::{
@@ -110,8 +139,9 @@ use crate::entity::{
     start_riding_entities,
 };
 use crate::fluid::get_fluid_state;
-use crate::inventory::{SyncPlayerInv, equipment::EquipmentSlot};
+use crate::inventory::{PlayerEnderChestContainer, SyncPlayerInv, equipment::EquipmentSlot};
 use crate::level_data::RespawnData;
+use crate::map::tick_player_maps;
 use crate::permission::{
     PermissionContext, PermissionExpr, PermissionMetadataSet, PermissionMetadataValue,
     PermissionSet, PermissionState,
@@ -127,15 +157,15 @@ use crate::server::{
 use crate::world::player_spawn_finder::{PlayerSpawnSearch, PlayerSpawnSearchPoll};
 use steel_registry::vanilla_damage_types;
 
-use steel_protocol::packets::{
-    common::SCustomPayload,
-    game::{CContainerClose, CGameEvent, CSystemChat, GameEventType, PreviousMessage},
+use steel_protocol::packets::game::{
+    CContainerClose, CGameEvent, CSystemChat, GameEventType, PreviousMessage,
 };
 use steel_registry::RegistryEntry;
 use steel_registry::item_stack::ItemStack;
 
 use steel_utils::{
-    BlockPos, BlockStateId, ChunkPos, DowncastType, DowncastTypeKey, Identifier, UuidExt as _,
+    BlockPos, BlockStateId, ChunkPos, DowncastType, DowncastTypeKey, GlobalPos, Identifier,
+    UuidExt as _,
 };
 
 use crate::inventory::{MenuInstance, container::Container, inventory_menu::InventoryMenu};
@@ -201,6 +231,17 @@ pub enum PlayerConnection {
     Other(Box<dyn NetworkConnection>),
 }
 
+impl PlayerConnection {
+    /// Returns the ids of the last play packets this connection processed, oldest first.
+    #[must_use]
+    pub fn recent_packet_ids(&self) -> Vec<i32> {
+        match self {
+            Self::Java(connection) => connection.recent_packet_ids(),
+            Self::Other(_) => Vec::new(),
+        }
+    }
+}
+
 use crate::chunk::player_chunk_view::PlayerChunkView;
 use crate::player::chunk_sender::ChunkSender;
 use crate::player::networking::JavaConnection;
@@ -213,6 +254,8 @@ use crate::world::World;
 pub struct Player {
     /// The player's game profile.
     pub gameprofile: GameProfile,
+    /// The profile properties (skin and cape textures) sent to clients, refreshed by `/skin refresh`.
+    profile_properties: SyncMutex<Vec<GameProfileProperty>>,
     /// The player's connection (abstracted for testing).
     pub connection: Arc<PlayerConnection>,
 
@@ -222,7 +265,7 @@ pub struct Player {
     /// Reference to the server (for entity ID generation, etc.).
     pub(crate) server: Weak<Server>,
     /// Runtime configuration shared with the server.
-    pub(crate) config: Arc<RuntimeConfig>,
+    pub(crate) config: SharedRuntimeConfig,
 
     /// Common entity fields (id, uuid, position, rotation, removal, callback).
     base: EntityBase,
@@ -246,6 +289,8 @@ pub struct Player {
     /// The client's settings/information (language, view distance, chat visibility, etc.).
     /// Updated when the client sends `SClientInformation` during config or play phase.
     client_information: SyncMutex<ClientInformation>,
+    /// Cap on the view distance set with `/viewdistance`, below the server maximum.
+    view_distance_limit: SyncMutex<Option<u8>>,
 
     /// Chat state: message counters, signature cache, validator, session, chain.
     pub chat: SyncMutex<ChatState>,
@@ -256,6 +301,9 @@ pub struct Player {
     /// The player's inventory container (shared with `inventory_menu`).
     pub inventory: SyncPlayerInv,
 
+    /// Vanilla `Player.enderChestInventory`, the same 27 slots behind every ender chest.
+    pub ender_chest_inventory: Arc<SyncMutex<PlayerEnderChestContainer>>,
+
     /// Last main-hand stack used for vanilla attack-strength reset checks.
     last_item_in_main_hand: SyncMutex<ItemStack>,
 
@@ -269,10 +317,27 @@ pub struct Player {
     /// Counter for generating container IDs (1-100, wraps around).
     container_counter: SyncMutex<ContainerCounter>,
 
+    /// Vanilla `dropSpamThrottler`, limiting items dropped from the creative inventory.
+    drop_spam_throttler: SyncMutex<TickThrottler>,
+    /// Limits block and entity interaction packets to what a client can click.
+    interaction_spam_throttler: SyncMutex<TickThrottler>,
+
     /// Pending server-initiated teleport state (ID, position, timeout).
     teleport_state: SyncMutex<TeleportState>,
     /// Vanilla item use cooldown groups.
     item_cooldowns: SyncMutex<ItemCooldowns>,
+    /// The item being eaten, drunk or otherwise held in use.
+    using_item: SyncMutex<Option<UsingItem>>,
+    /// Unlocked recipes and recipe book settings.
+    recipe_book: SyncMutex<ServerRecipeBook>,
+    /// Vanilla statistics.
+    stats: SyncMutex<StatsCounter>,
+    /// Vanilla advancement progress.
+    advancements: SyncMutex<PlayerAdvancements>,
+    /// Latest responses to the server resource packs.
+    resource_packs: SyncMutex<ResourcePackStatuses>,
+    /// The brand and plugin channels the client announced.
+    client_channels: SyncMutex<ClientChannels>,
 
     /// Local tick and once-per-tick packet state.
     tick_state: SyncMutex<PlayerTickState>,
@@ -296,12 +361,27 @@ pub struct Player {
     /// The Player's Experience
     pub experience: SyncMutex<Experience>,
 
+    /// Vanilla `Player.enchantmentSeed`, rerolled after every enchanting table use.
+    enchantment_seed: SyncMutex<i32>,
+
     /// Assigned groups, direct overrides, and the effective permission set.
     permissions: SyncMutex<PlayerPermissionState>,
 
     /// Whether the player has completed the vanilla End credits flow.
     seen_credits: SyncMutex<bool>,
 
+    /// Personal respawn point set by a bed, or `None` for the world spawn.
+    respawn_config: SyncMutex<Option<RespawnConfig>>,
+
+    /// Warning level raised by sculk shriekers.
+    warden_spawn_tracker: SyncMutex<WardenSpawnTracker>,
+
+    /// Where the player last died, which recovery compasses point to.
+    last_death_location: SyncMutex<Option<GlobalPos>>,
+
+    /// Where the raid starts once raid omen runs out.
+    raid_omen_position: SyncMutex<Option<BlockPos>>,
+
     /// Vanilla `ServerPlayer.wonGame`; transient while the End credits screen is open.
     won_game: SyncMutex<bool>,
 
@@ -539,7 +619,7 @@ impl Player {
         connection: Arc<PlayerConnection>,
         world: Arc<World>,
         server: Weak<Server>,
-        config: Arc<RuntimeConfig>,
+        config: SharedRuntimeConfig,
         entity_id: i32,
         player: &Weak<Player>,
         client_information: ClientInformation,
@@ -552,10 +632,16 @@ impl Player {
         let living_base = LivingEntityBase::new(&vanilla_entities::PLAYER);
         let player_uuid = gameprofile.id;
         let world_ref = Arc::downgrade(&world);
-        let chat_spam_threshold_seconds = config.chat_spam_threshold_seconds;
-        let command_spam_threshold_seconds = config.command_spam_threshold_seconds;
+        let (chat_spam_threshold_seconds, command_spam_threshold_seconds) = {
+            let config = config.load();
+            (
+                config.chat_spam_threshold_seconds,
+                config.command_spam_threshold_seconds,
+            )
+        };
 
         Self {
+            profile_properties: SyncMutex::new(gameprofile.properties.clone()),
             gameprofile,
             connection,
 
@@ -580,18 +666,31 @@ impl Player {
             last_tracking_view: SyncMutex::new(None),
             chunk_sender: SyncMutex::new(ChunkSender::default()),
             client_information: SyncMutex::new(client_information),
+            view_distance_limit: SyncMutex::new(None),
             chat: SyncMutex::new(ChatState::new(
                 chat_spam_threshold_seconds,
                 command_spam_threshold_seconds,
             )),
             game_modes: SyncMutex::new(PlayerGameModeState::new(GameType::Survival)),
             inventory: inventory.clone(),
+            ender_chest_inventory: Arc::new(SyncMutex::new(PlayerEnderChestContainer::new())),
             last_item_in_main_hand: SyncMutex::new(ItemStack::empty()),
             inventory_menu: SyncMutex::new(InventoryMenu::new(inventory)),
             open_menu: SyncMutex::new(None),
             container_counter: SyncMutex::new(ContainerCounter::new()),
+            drop_spam_throttler: SyncMutex::new(TickThrottler::new(20, 1480)),
+            interaction_spam_throttler: SyncMutex::new(TickThrottler::new(
+                interaction_validation::INTERACTION_SPAM_INCREMENT,
+                interaction_validation::INTERACTION_SPAM_THRESHOLD,
+            )),
             teleport_state: SyncMutex::new(TeleportState::new()),
             item_cooldowns: SyncMutex::new(ItemCooldowns::default()),
+            using_item: SyncMutex::new(None),
+            recipe_book: SyncMutex::new(ServerRecipeBook::default()),
+            stats: SyncMutex::new(StatsCounter::default()),
+            advancements: SyncMutex::new(PlayerAdvancements::default()),
+            resource_packs: SyncMutex::new(ResourcePackStatuses::default()),
+            client_channels: SyncMutex::new(ClientChannels::default()),
             tick_state: SyncMutex::new(PlayerTickState::new()),
             abilities: SyncMutex::new(Abilities::default()),
             block_breaking: SyncMutex::new(BlockBreakingManager::new()),
@@ -599,8 +698,13 @@ impl Player {
             food_data: SyncMutex::new(FoodData::new()),
             health_sync: SyncMutex::new(HealthSyncState::new()),
             experience: SyncMutex::new(Experience::default()),
+            enchantment_seed: SyncMutex::new(rand::random()),
             permissions: SyncMutex::new(PlayerPermissionState::default()),
             seen_credits: SyncMutex::new(false),
+            respawn_config: SyncMutex::new(None),
+            warden_spawn_tracker: SyncMutex::new(WardenSpawnTracker::default()),
+            last_death_location: SyncMutex::new(None),
+            raid_omen_position: SyncMutex::new(None),
             won_game: SyncMutex::new(false),
             chunk_send_epoch: SyncMutex::new(0),
             pending_root_vehicle: SyncMutex::new(None),
@@ -621,6 +725,8 @@ impl Player {
         self.tick_attack_strength();
         self.tick_spam_throttlers();
         self.tick_client_load_timeout();
+        self.tick_sleeping();
+        self.warden_spawn_tracker.lock().tick();
 
         self.set_no_physics(self.is_spectator());
         if self.is_spectator() || self.is_passenger() {
@@ -635,6 +741,7 @@ impl Player {
         self.reset_vehicle_movement_for_tick();
 
         self.default_tick();
+        self.tick_using_item();
         self.ai_step();
 
         // Vanilla snaps the player back to firstGood after ServerPlayer.doTick().
@@ -653,7 +760,9 @@ impl Player {
         }
 
         self.living_base.decrement_invulnerable_time();
+        self.tick_raid_omen();
         self.tick_mob_effects();
+        self.tick_stats();
 
         if self.get_health() <= 0.0 {
             self.tick_death();
@@ -661,19 +770,16 @@ impl Player {
             let world = self.get_world();
             self.touch_nearby_items();
             self.block_breaking.lock().tick(self, &world);
+            self.tick_recipe_unlocks();
+            self.tick_advancements();
 
             // TODO: Implement remaining player ticking logic here
             // - Managing game mode specific logic
-            // - Updating advancements
             // - Handling falling
 
             self.update_player_attributes();
             self.living_base.refresh_speed_from_attributes();
             self.tick_regeneration();
-
-            if self.is_sprinting() && !self.food_data.lock().has_enough_food() {
-                self.set_sprinting(false);
-            }
         }
 
         if self.disconnect_if_floating_too_long() {
@@ -685,6 +791,11 @@ impl Player {
 
         self.tick_living_state();
 
+        // Vanilla: MapItem.inventoryTick and ServerPlayer.synchronizeSpecialItemUpdates.
+        tick_player_maps(self);
+        // Vanilla: CompassItem.inventoryTick.
+        CompassItem::inventory_tick(self);
+
         self.broadcast_inventory_changes();
         self.update_pose();
 
@@ -795,10 +906,6 @@ impl Player {
             .set_base_glowing_flag(self.has_glowing_tag() || display.glowing);
     }
 
-    /// Handles a custom payload packet.
-    #[expect(clippy::unused_self, reason = "this is an api function")]
-    pub fn handle_custom_payload(&self, _packet: SCustomPayload) {}
-
     /// Handles the end of a client tick.
     pub fn handle_client_tick_end(&self) {
         self.movement.lock().finish_client_tick();
@@ -967,15 +1074,28 @@ impl Player {
             }
         }
 
+        if let Some(killer) = self.kill_credit(&world) {
+            self.award_stat(Stat::killed_by(killer.entity_type()));
+            if let Some(player) = world.players.get_by_entity_id(killer.id()) {
+                player.award_kill_score(self.entity_type());
+            }
+        }
+        self.award_stat(Stat::custom(CustomStat::Deaths));
+        self.reset_stat(Stat::custom(CustomStat::TimeSinceDeath));
+        self.reset_stat(Stat::custom(CustomStat::TimeSinceRest));
         self.clear_fire();
         self.set_ticks_frozen(0);
+        self.set_last_death_location(Some(GlobalPos::new(
+            world.key.clone(),
+            self.block_position(),
+        )));
 
         if world.get_game_rule(&IMMEDIATE_RESPAWN) {
             self.respawn();
         }
     }
 
-    /// TODO: personal respawn blocks/anchors and noRespawnBlockAvailable.
+    /// TODO: respawn at the `respawn_config` bed or anchor, and noRespawnBlockAvailable.
     pub fn respawn(&self) {
         let health = self.get_health();
         if !Self::should_process_respawn(health) {
@@ -1143,7 +1263,7 @@ impl Player {
         self.base.set_boarding_cooldown(0);
     }
 
-    /// Handles client commands, requestStats and `RequestGameRuleValues` are still todo
+    /// Handles client commands, `RequestGameRuleValues` is still todo
     pub fn handle_client_command(self: &Arc<Self>, action: ClientCommandAction) {
         match action {
             ClientCommandAction::PerformRespawn => {
@@ -1153,8 +1273,9 @@ impl Player {
                     self.respawn();
                 }
             }
-            ClientCommandAction::RequestStats | ClientCommandAction::RequestGameRuleValues => {
-                // TODO: implement stats
+            ClientCommandAction::RequestStats => self.send_stats(),
+            ClientCommandAction::RequestGameRuleValues => {
+                // TODO: implement game rule value requests
             }
         }
     }
@@ -1184,6 +1305,29 @@ impl Player {
         *self.seen_credits.lock() = seen_credits;
     }
 
+    /// Returns vanilla `Player.wardenSpawnTracker`.
+    #[must_use]
+    pub fn warden_spawn_tracker(&self) -> WardenSpawnTracker {
+        *self.warden_spawn_tracker.lock()
+    }
+
+    /// Replaces the player's warden spawn tracker.
+    pub fn set_warden_spawn_tracker(&self, tracker: WardenSpawnTracker) {
+        *self.warden_spawn_tracker.lock() = tracker;
+    }
+
+    /// Returns vanilla `Player.lastDeathLocation`.
+    #[must_use]
+    pub fn last_death_location(&self) -> Option<GlobalPos> {
+        self.last_death_location.lock().clone()
+    }
+
+    /// Sets vanilla `Player.lastDeathLocation`. The client learns about it on
+    /// the next login or respawn.
+    pub fn set_last_death_location(&self, location: Option<GlobalPos>) {
+        *self.last_death_location.lock() = location;
+    }
+
     /// Returns vanilla `ServerPlayer.wonGame`.
     #[must_use]
     pub(crate) fn has_won_game(&self) -> bool {
@@ -1559,6 +1703,11 @@ impl Player {
             nbt.insert("Inventory", inventory.to_vanilla_inventory_nbt());
             nbt.insert("SelectedItemSlot", i32::from(inventory.get_selected_slot()));
         }
+        nbt.insert(
+            "EnderItems",
+            self.ender_chest_inventory.lock().to_vanilla_nbt(),
+        );
+        nbt.insert("recipeBook", self.recipe_book.lock().to_vanilla_nbt());
 
         {
             let experience = self.experience.lock();
@@ -1566,6 +1715,7 @@ impl Player {
             nbt.insert("XpLevel", experience.level());
             nbt.insert("XpTotal", experience.total_points());
         }
+        nbt.insert("XpSeed", self.enchantment_seed());
         nbt.insert("Score", self.score());
 
         {
@@ -1601,6 +1751,35 @@ impl Player {
             NbtTag::Byte(i8::from(self.has_seen_credits())),
         );
         nbt.insert("Dimension", self.get_world().key.to_string());
+        nbt.insert("SleepTimer", self.sleep_timer() as i16);
+        if let Some(config) = self.respawn_config() {
+            let data = &config.respawn_data;
+            let mut respawn_nbt = NbtCompound::new();
+            respawn_nbt.insert("dimension", data.dimension().to_string());
+            respawn_nbt.insert(
+                "pos",
+                NbtTag::IntArray(vec![data.pos().x(), data.pos().y(), data.pos().z()]),
+            );
+            respawn_nbt.insert("yaw", data.yaw);
+            respawn_nbt.insert("pitch", data.pitch);
+            respawn_nbt.insert("forced", NbtTag::Byte(i8::from(config.forced)));
+            nbt.insert("respawn", NbtTag::Compound(respawn_nbt));
+        }
+        if let Some(location) = self.last_death_location() {
+            let mut location_nbt = NbtCompound::new();
+            location_nbt.insert("dimension", location.dimension.to_string());
+            location_nbt.insert(
+                "pos",
+                NbtTag::IntArray(vec![location.pos.x(), location.pos.y(), location.pos.z()]),
+            );
+            nbt.insert("LastDeathLocation", NbtTag::Compound(location_nbt));
+        }
+        let tracker = self.warden_spawn_tracker();
+        let mut tracker_nbt = NbtCompound::new();
+        tracker_nbt.insert("ticks_since_last_warning", tracker.ticks_since_last_warning);
+        tracker_nbt.insert("warning_level", tracker.warning_level);
+        tracker_nbt.insert("cooldown_ticks", tracker.cooldown_ticks);
+        nbt.insert("warden_spawn_tracker", NbtTag::Compound(tracker_nbt));
 
         if let Some(vehicle) = self.vehicle()
             && let Some(root_vehicle) = self.root_vehicle()
@@ -1734,6 +1913,23 @@ impl Player {
         }
     }
 
+    /// Returns vanilla `Player.getEnchantmentSeed`.
+    #[must_use]
+    pub fn enchantment_seed(&self) -> i32 {
+        *self.enchantment_seed.lock()
+    }
+
+    /// Sets the enchantment seed, e.g. when restoring saved player data.
+    pub fn set_enchantment_seed(&self, seed: i32) {
+        *self.enchantment_seed.lock() = seed;
+    }
+
+    /// Vanilla `Player.onEnchantmentPerformed`: spends levels and rerolls the seed.
+    pub(crate) fn on_enchantment_performed(&self, enchantment_cost: i32) {
+        self.experience.lock().add_levels(-enchantment_cost);
+        *self.enchantment_seed.lock() = rand::random();
+    }
+
     fn play_experience_level_up_sound(&self, level: i32) {
         if !self.tick_state.lock().mark_level_up_sound_if_due() {
             return;
@@ -1839,6 +2035,7 @@ impl Player {
         if reason != ResetReason::InitialJoin {
             // 0x01 = keep attributes, 0x02 = keep entity data
             let data_kept = reason.respawn_data_kept();
+            let death_location = self.last_death_location();
 
             self.send_packet(CRespawn {
                 dimension_type: new_world.dimension_type.id() as i32,
@@ -1848,9 +2045,11 @@ impl Player {
                 previous_gamemode: nullable_game_mode_id(self.previous_game_mode()),
                 is_debug: false,
                 is_flat: new_world.is_flat,
-                has_death_location: false,
-                death_dimension_name: None,
-                death_location: None,
+                has_death_location: death_location.is_some(),
+                death_dimension_name: death_location
+                    .as_ref()
+                    .map(|location| location.dimension.clone()),
+                death_location: death_location.map(|location| location.pos),
                 portal_cooldown_ticks: self.portal_cooldown(),
                 sea_level: new_world.sea_level,
                 data_kept,
@@ -2101,6 +2300,30 @@ impl Player {
         }
     }
 
+    /// Moves this player off `vehicle`, mirroring vanilla `LivingEntity.dismountVehicle`.
+    fn dismount_vehicle(&self, vehicle: &dyn Entity) {
+        if self.is_removed() {
+            return;
+        }
+        let in_portal = vehicle.level().is_some_and(|world| {
+            world
+                .get_block_state(vehicle.block_position())
+                .get_block()
+                .has_tag(&BlockTag::PORTALS)
+        });
+        let position = if vehicle.is_removed() || in_portal {
+            let position = self.position();
+            DVec3::new(position.x, position.y.max(vehicle.position().y), position.z)
+        } else {
+            vehicle.dismount_location_for_passenger(self)
+        };
+
+        let (yaw, pitch) = self.rotation();
+        if let Err(error) = self.teleport(position, yaw, pitch) {
+            log::warn!("Failed to dismount player {}: {error}", self.id());
+        }
+    }
+
     fn apply_post_teleport_transition(&self, post_transition: &TeleportPostTransition) {
         for action in post_transition.actions() {
             match *action {
@@ -2314,6 +2537,22 @@ impl Entity for Player {
             old_vehicle.id(),
             Self::passenger_ids_for_packet(old_vehicle.as_ref()),
         ));
+        self.dismount_vehicle(old_vehicle.as_ref());
+    }
+
+    fn ride_tick(&self) {
+        // Vanilla `Player.rideTick`: sneaking dismounts before the passenger tick.
+        if self.last_client_input().shift() && self.is_passenger() {
+            self.stop_riding();
+            self.set_shared_shift_key_down(false);
+            return;
+        }
+
+        self.set_velocity(DVec3::ZERO);
+        self.tick();
+        if let Some(vehicle) = self.vehicle() {
+            vehicle.position_rider(self.as_entity_event_source());
+        }
     }
 
     fn start_riding(&self, entity_to_ride: &SharedEntity) -> bool {
@@ -2481,7 +2720,12 @@ impl Entity for Player {
             return false;
         }
 
-        // TODO: Award `Stats.FALL_ONE_CM` once player statistics are implemented.
+        if fall_distance >= 2.0 {
+            self.award_stat_amount(
+                Stat::custom(CustomStat::FallOneCm),
+                stats::centimeters(fall_distance),
+            );
+        }
         LivingEntity::cause_living_fall_damage(self, fall_distance, damage_modifier, source)
     }
 
@@ -2634,6 +2878,32 @@ impl LivingEntity for Player {
         &self.living_base
     }
 
+    fn set_sleeping_pos(&self, bed_position: BlockPos) {
+        self.living_base.set_sleeping_pos(bed_position);
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .sleeping_pos
+            .set(Some(bed_position));
+    }
+
+    fn clear_sleeping_pos(&self) {
+        self.living_base.clear_sleeping_pos();
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .sleeping_pos
+            .set(None);
+    }
+
+    fn stop_sleeping(&self) {
+        self.leave_bed();
+    }
+
+    fn is_using_item(&self) -> bool {
+        self.used_item_hand().is_some()
+    }
+
     fn can_be_seen_as_enemy(&self) -> bool {
         !self.abilities.lock().invulnerable
             && !self.is_invulnerable()
@@ -2735,7 +3005,7 @@ impl LivingEntity for Player {
 
     fn jump_from_ground(&self) {
         self.default_jump_from_ground();
-        // TODO: Award Stats.JUMP once player statistics exist.
+        self.award_stat(Stat::custom(CustomStat::Jump));
         if self.is_sprinting() {
             self.cause_food_exhaustion(0.2);
         } else {
@@ -2804,16 +3074,41 @@ impl LivingEntity for Player {
 }
 
 impl TextResolutor for Player {
-    fn resolve_content(&self, _resolvable: &Resolvable) -> TextComponent {
-        TextComponent::new()
+    /// Resolves scores, selectors and NBT as vanilla `ComponentUtils.updateForEntity`
+    /// does, with this player as the command source. Content that fails to
+    /// resolve is left out.
+    fn resolve_content(&self, resolvable: &Resolvable) -> TextComponent {
+        let Some(server) = self.server.upgrade() else {
+            return TextComponent::new();
+        };
+        let Some(player) = server.online_players.get_by_uuid(&self.gameprofile.id) else {
+            return TextComponent::new();
+        };
+        let source = CommandSource::new(CommandSender::Player(player), server);
+        CommandTextResolver::with_entity_override(&source, self)
+            .try_resolve_content(resolvable, 0)
+            .unwrap_or_else(|error| {
+                log::debug!(
+                    "Could not resolve text for {}: {error}",
+                    self.gameprofile.name
+                );
+                TextComponent::new()
+            })
     }
 
     fn resolve_custom(&self, _data: &CustomData) -> Option<TextComponent> {
         None
     }
 
-    fn translate(&self, _key: &str) -> Option<String> {
-        None
+    /// Translates keys the client does not know through the server
+    /// translations, in the player's language.
+    fn translate(&self, key: &str) -> Option<String> {
+        if TRANSLATIONS.contains_key(key) {
+            return None;
+        }
+        let server = self.server.upgrade()?;
+        let language = self.client_information.lock().language.clone();
+        server.translations.translate(&language, key)
     }
 }
 
@@ -2821,6 +3116,7 @@ impl TextResolutor for Player {
 mod tests {
     use std::sync::{Arc, Weak};
 
+    use arc_swap::ArcSwap;
     use steel_protocol::packet_traits::{CompressionInfo, EncodedPacket};
     use steel_registry::{
         item_stack::ItemStack, test_support::init_test_registry, vanilla_attributes,
@@ -2830,7 +3126,10 @@ mod tests {
     use text_components::TextComponent;
     use uuid::Uuid;
 
-    use crate::config::RuntimeConfig;
+    use crate::config::{
+        ChatConfig, CommandAliasesConfig, JoinQueueConfig, OfflineSkinsConfig, PacketLimitConfig,
+        ProxyConfig, ReportDetailsConfig, RuntimeConfig, SharedRuntimeConfig, WatchdogConfig,
+    };
     use crate::entity::{EntitySyncedData, LivingEntity, damage::DamageSource};
     use crate::inventory::{container::Container as _, equipment::EquipmentSlot, menu::Menu as _};
     use crate::permission::{PermissionEntry, PermissionKey, PermissionMetadataSet, PermissionSet};
@@ -2871,16 +3170,19 @@ mod tests {
         }
     }
 
-    fn test_runtime_config() -> Arc<RuntimeConfig> {
-        Arc::new(RuntimeConfig {
+    fn test_runtime_config() -> SharedRuntimeConfig {
+        Arc::new(ArcSwap::from_pointee(RuntimeConfig {
             max_players: 1,
             view_distance: 2,
             simulation_distance: 2,
+            entity_broadcast_range_percentage:
+                crate::entity::DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE,
             online_mode: false,
             auth_server: None,
             profile_server: None,
             encryption: false,
             allow_flight: false,
+            accepts_transfers: false,
             motd: String::new(),
             use_favicon: false,
             favicon: String::new(),
@@ -2889,8 +3191,18 @@ mod tests {
             command_spam_threshold_seconds: 10,
             compression: None,
             server_links: None,
+            report_details: ReportDetailsConfig::default(),
             chunk_generation_threads: Some(1),
-        })
+            random_tick_threads: Some(1),
+            resource_packs: Vec::new(),
+            proxy: ProxyConfig::default(),
+            packet_limits: PacketLimitConfig::default(),
+            watchdog: WatchdogConfig::default(),
+            chat: ChatConfig::default(),
+            offline_skins: OfflineSkinsConfig::default(),
+            join_queue: JoinQueueConfig::default(),
+            command_aliases: CommandAliasesConfig::default(),
+        }))
     }
 
     fn test_player(world: Arc<World>) -> Arc<Player> {
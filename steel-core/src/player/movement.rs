@@ -6,20 +6,22 @@
 use glam::DVec3;
 use steel_protocol::packets::game::{
     CMoveVehicle, CPlayerPosition, PlayerCommandAction, RelativeMovement, SAcceptTeleportation,
-    SMovePlayer, SMoveVehicle, SPlayerCommand, SPlayerInput,
+    SMovePlayer, SMoveVehicle, SPaddleBoat, SPlayerCommand, SPlayerInput,
 };
 use steel_registry::vanilla_game_rules::{ELYTRA_MOVEMENT_CHECK, PLAYER_MOVEMENT_CHECK};
 use steel_registry::vanilla_mob_effects;
+use steel_utils::Downcast as _;
 use steel_utils::translations;
 use steel_utils::types::GameType;
 
+use crate::entity::entities::BoatEntity;
 use crate::entity::{
     AcceptedClientMovement, AcceptedClientMovementOutcome, Entity, EntityMoveError, LivingEntity,
     get_input_vector,
 };
 use crate::physics::{
-    MOVEMENT_ERROR_THRESHOLD, MovementCollisionValidation, MoverType, WorldCollisionProvider,
-    is_colliding_with_new_shapes, movement_error_delta,
+    MOVEMENT_ERROR_THRESHOLD, MovementCollisionValidation, MoverType, PhaseThroughValidation,
+    WorldCollisionProvider, ground_probe, is_colliding_with_new_shapes, movement_error_delta,
 };
 use crate::player::food_data::food_constants;
 use crate::player::{Player, PlayerInput};
@@ -182,7 +184,8 @@ impl Player {
 
     /// Handles a move player packet.
     ///
-    /// Matches vanilla `ServerGamePacketListenerImpl.handleMovePlayer()`.
+    /// Matches vanilla `ServerGamePacketListenerImpl.handleMovePlayer()`, and additionally
+    /// rejects targets behind collision and ignores on-ground claims with nothing below.
     ///
     /// # Panics
     ///
@@ -295,6 +298,13 @@ impl Player {
                 } * f64::from(delta_packets);
 
                 if moved_dist_sq - self.velocity().length_squared() > threshold {
+                    log::warn!(
+                        "{} moved too quickly! {},{},{}",
+                        self.gameprofile.name,
+                        dx,
+                        dy,
+                        dz
+                    );
                     if let Err(error) =
                         self.teleport(start_pos, current_rotation.0, current_rotation.1)
                     {
@@ -334,6 +344,9 @@ impl Player {
             && !is_creative
             && !is_spectator
             && !in_impulse_grace;
+        if fail {
+            log::warn!("{} moved wrongly!", self.gameprofile.name);
+        }
 
         let new_aabb = self.bounding_box().translate(target_pos - self.position());
         let collision_world = WorldCollisionProvider::for_entity(&world, self);
@@ -345,13 +358,56 @@ impl Player {
         let new_collision =
             is_colliding_with_new_shapes(&collision_world, old_aabb, new_aabb, self.is_crouching());
 
-        if (MovementCollisionValidation {
+        // The replay stops at the first collision; a target beyond it means the
+        // client claims to have moved through blocks.
+        let residual = target_pos - self.position();
+        let swept_collision = residual.length_squared() > MOVEMENT_ERROR_THRESHOLD
+            && collision_world.has_entity_context_collision(
+                self.bounding_box().expand_towards(residual),
+                self.position().y,
+                self.is_descending(),
+            );
+        let phased = (PhaseThroughValidation {
             no_physics: self.no_physics(),
-            moved_wrongly: fail,
+            in_impulse_grace,
             old_collision,
-            new_collision,
+            residual,
+            swept_collision,
         })
-        .rejects()
+        .rejects();
+        if phased {
+            log::warn!(
+                "{} moved through collision! {},{},{}",
+                self.gameprofile.name,
+                residual.x,
+                residual.y,
+                residual.z
+            );
+        }
+
+        // A client claiming ground with nothing below is spoofing it to avoid fall damage.
+        let on_ground = packet.on_ground
+            && (self.no_physics()
+                || collision_world.has_entity_context_collision(
+                    ground_probe(new_aabb),
+                    target_pos.y,
+                    self.is_descending(),
+                ));
+        if packet.on_ground && !on_ground {
+            log::debug!(
+                "{} claimed to be on ground without support",
+                self.gameprofile.name
+            );
+        }
+
+        if phased
+            || (MovementCollisionValidation {
+                no_physics: self.no_physics(),
+                moved_wrongly: fail,
+                old_collision,
+                new_collision,
+            })
+            .rejects()
         {
             if let Err(error) = self.teleport(start_pos, target_yaw, target_pitch) {
                 log::warn!(
@@ -359,8 +415,8 @@ impl Player {
                     self.id()
                 );
             }
-            self.refresh_supporting_block_for_fall_damage(DVec3::ZERO, packet.on_ground);
-            self.do_check_fall_damage(DVec3::ZERO, packet.on_ground, &world);
+            self.refresh_supporting_block_for_fall_damage(DVec3::ZERO, on_ground);
+            self.do_check_fall_damage(DVec3::ZERO, on_ground, &world);
             self.remove_latest_movement_recording();
             return;
         }
@@ -369,7 +425,7 @@ impl Player {
         // post-move residual used by moved-wrongly validation.
         let floating_check = Some((player_stands_on_something, move_delta.y));
 
-        if packet.on_ground && self.is_sprinting() {
+        if on_ground && self.is_sprinting() {
             let dx = move_delta.x;
             let dz = move_delta.z;
 
@@ -385,7 +441,7 @@ impl Player {
             AcceptedClientMovement {
                 position: Some(target_pos),
                 rotation: (target_yaw, target_pitch),
-                on_ground: packet.on_ground,
+                on_ground,
                 horizontal_collision: packet.horizontal_collision,
                 movement: client_delta,
                 reset_fall_distance: moved_upwards,
@@ -409,6 +465,7 @@ impl Player {
             }
         }
         world.chunk_map.update_player_status(self);
+        self.check_movement_statistics(client_delta);
 
         if let Some((player_stands_on_something, y_dist)) = floating_check {
             self.record_client_floating(
@@ -619,7 +676,7 @@ impl Player {
             y_dist,
             player_stands_on_something,
             is_spectator,
-            server_allows_flight: self.config.allow_flight,
+            server_allows_flight: self.config.load().allow_flight,
             may_fly: self.abilities.lock().may_fly,
             has_levitation: self.has_mob_effect(vanilla_mob_effects::LEVITATION),
             is_fall_flying,
@@ -641,7 +698,7 @@ impl Player {
     ) {
         let client_is_floating = y_dist >= -0.03125
             && !vehicle_rests_on_something
-            && !self.config.allow_flight
+            && !self.config.load().allow_flight
             && !vehicle.is_flying_vehicle()
             && !vehicle.is_no_gravity()
             && Self::no_blocks_around_entity(world, vehicle);
@@ -651,11 +708,7 @@ impl Player {
     }
 
     fn no_blocks_around_entity(world: &World, entity: &dyn Entity) -> bool {
-        let block_query = entity
-            .bounding_box()
-            .inflate(0.0625)
-            .expand_towards(DVec3::new(0.0, -0.55, 0.0));
-        world.block_states_in_aabb_are_air(block_query)
+        world.block_states_in_aabb_are_air(ground_probe(entity.bounding_box()))
     }
 
     /// Returns how long vanilla permits unsupported floating for this player's gravity.
@@ -859,6 +912,15 @@ impl Player {
         )
     }
 
+    /// Handles a boat paddle packet from the boat's controlling passenger.
+    pub fn handle_paddle_boat(&self, packet: SPaddleBoat) {
+        if let Some(vehicle) = self.controlled_vehicle()
+            && let Some(boat) = vehicle.downcast_ref::<BoatEntity>()
+        {
+            boat.set_paddle_state(packet.left, packet.right);
+        }
+    }
+
     /// Handles a player input packet (movement keys, sneaking, sprinting).
     pub fn handle_player_input(&self, packet: SPlayerInput) {
         // Vanilla stores the input unconditionally before the guard check.
@@ -896,7 +958,9 @@ impl Player {
 
         match packet.action {
             PlayerCommandAction::StartSprinting => {
-                self.set_sprinting(true);
+                if self.has_enough_food_to_sprint() {
+                    self.set_sprinting(true);
+                }
             }
             PlayerCommandAction::StopSprinting => {
                 self.set_sprinting(false);
@@ -908,16 +972,7 @@ impl Player {
             }
             PlayerCommandAction::LeaveBed => {
                 if self.is_sleeping() {
-                    self.stop_sleeping();
-                    // TODO: Full bed wake-up logic:
-                    //   - set bed block OCCUPIED property to false
-                    //   - compute stand-up position via BedBlock::findStandUpPosition
-                    //   - teleport player + set rotation toward bed
-                    //   - set pose to Standing, clear sleeping pos entity data
-                    //   - update server sleeping player list (for sleep-skip)
-                    //   - set sleepCounter = 100
-                    //   - set awaiting_position_from_client
-                    // Blocked on: bed block properties, sleeping pos entity data
+                    self.stop_sleep_in_bed(false, true);
                 }
             }
             PlayerCommandAction::StartRidingJump
@@ -1,29 +1,36 @@
 //! This module contains the `JavaConnection` struct, which is used to represent a connection to a Java client.
-use std::io::Cursor;
+use std::collections::VecDeque;
+use std::slice;
 use std::sync::{Arc, Weak};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use rustc_hash::FxHashMap;
+
 use steel_protocol::packet_reader::TCPNetworkDecoder;
 use steel_protocol::packet_traits::{ClientPacket, CompressionInfo, EncodedPacket, ServerPacket};
-use steel_protocol::packet_writer::TCPNetworkEncoder;
+use steel_protocol::packet_writer::{TCPNetworkEncoder, encode_bundle};
 use steel_protocol::packets::common::{
-    CDisconnect, CKeepAlive, CPongResponse, SClientInformation, SCustomPayload, SKeepAlive,
-    SPingRequest,
+    CCookieRequest, CDisconnect, CKeepAlive, CPongResponse, CStoreCookie, CTransfer,
+    MAX_COOKIE_SIZE, SClientInformation, SCookieResponse, SCustomPayload, SKeepAlive, SPingRequest,
+    SResourcePack,
 };
 use steel_protocol::packets::game::{
-    CBundleDelimiter, CCommandSuggestions, SAcceptTeleportation, SAttack, SChangeDifficulty,
-    SChangeGameMode, SChat, SChatAck, SChatCommand, SChatSessionUpdate, SChunkBatchReceived,
-    SClientCommand, SClientTickEnd, SCommandSuggestion, SContainerButtonClick, SContainerClick,
-    SContainerClose, SContainerSlotStateChanged, SInteract, SMovePlayerPos, SMovePlayerPosRot,
-    SMovePlayerRot, SMovePlayerStatusOnly, SMoveVehicle, SPickItemFromBlock, SPlayerAbilities,
-    SPlayerAction, SPlayerCommand, SPlayerInput, SPlayerLoad, SSetCarriedItem,
-    SSetCreativeModeSlot, SSignUpdate, SSpectatorAction, SSwing, SUseItem, SUseItemOn,
+    CCommandSuggestions, SAcceptTeleportation, SAttack, SChangeDifficulty, SChangeGameMode, SChat,
+    SChatAck, SChatCommand, SChatSessionUpdate, SChunkBatchReceived, SClientCommand,
+    SClientTickEnd, SCommandSuggestion, SContainerButtonClick, SContainerClick, SContainerClose,
+    SContainerSlotStateChanged, SEditBook, SInteract, SJigsawGenerate, SMovePlayerPos,
+    SMovePlayerPosRot, SMovePlayerRot, SMovePlayerStatusOnly, SMoveVehicle, SPaddleBoat,
+    SPickItemFromBlock, SPlayerAbilities, SPlayerAction, SPlayerCommand, SPlayerInput, SPlayerLoad,
+    SRecipeBookChangeSettings, SRecipeBookSeenRecipe, SSeenAdvancements, SSelectTrade, SSetBeacon,
+    SSetCarriedItem, SSetCommandBlock, SSetCreativeModeSlot, SSetJigsawBlock, SSetStructureBlock,
+    SSignUpdate, SSpectatorAction, SSwing, SUseItem, SUseItemOn,
 };
 
+use steel_protocol::traffic::{TrafficCounters, TrafficStats};
 use steel_protocol::utils::{ConnectionProtocol, PacketError, RawPacket};
 use steel_registry::packets::play;
 use steel_utils::locks::{AsyncMutex, SyncMutex};
-use steel_utils::translations;
+use steel_utils::{Identifier, translations};
 use text_components::content::Resolvable;
 use text_components::custom::CustomData;
 use text_components::resolving::TextResolutor;
@@ -32,6 +39,7 @@ use tokio::io::{BufReader, BufWriter};
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::select;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, error::TryRecvError};
+use tokio::sync::oneshot;
 use tokio_util::sync::CancellationToken;
 
 use crate::command::{handle_client_request, sender::CommandSender};
@@ -39,6 +47,11 @@ use crate::player::Player;
 use crate::player::connection::NetworkConnection;
 use crate::server::Server;
 
+/// Most queued packets written before the socket is flushed.
+const MAX_BATCH_PACKETS: usize = 512;
+/// Play packets remembered per connection for watchdog stall dumps.
+const RECENT_PACKETS: usize = 8;
+
 /// Shared Java socket writer.
 pub type JavaNetworkWriter = Arc<AsyncMutex<Option<TCPNetworkEncoder<BufWriter<OwnedWriteHalf>>>>>;
 
@@ -50,6 +63,9 @@ pub enum OutboundPacket {
     Disconnect(EncodedPacket),
 }
 
+/// Resolves a pending cookie request with the client's payload.
+type CookieSender = oneshot::Sender<Option<Vec<u8>>>;
+
 /// Builder for creating packet bundles.
 ///
 /// Used with [`JavaConnection::send_bundle`] to send multiple packets atomically.
@@ -93,6 +109,8 @@ struct KeepAliveTracker {
     alive_time: u64,
     alive_pending: bool,
     alive_id: u64,
+    /// The most recent keep-alive round trip in milliseconds.
+    last_round_trip: u32,
 }
 
 /// A connection to a Java client.
@@ -102,21 +120,35 @@ pub struct JavaConnection {
     compression: Option<CompressionInfo>,
     network_writer: JavaNetworkWriter,
     id: u64,
+    transferred: bool,
 
     player: Weak<Player>,
     keep_alive_tracker: SyncMutex<KeepAliveTracker>,
     latency: SyncMutex<u32>,
+    /// Cookie requests awaiting a response, oldest first per key.
+    cookie_requests: SyncMutex<FxHashMap<Identifier, VecDeque<CookieSender>>>,
+    /// Ids of the last play packets processed, oldest first, for stall dumps.
+    recent_packets: SyncMutex<VecDeque<i32>>,
+    /// Bytes and packets counted by the socket reader and writer.
+    traffic: Arc<TrafficCounters>,
 }
 
 impl JavaConnection {
     /// Creates a new `JavaConnection`.
-    pub const fn new(
+    #[must_use]
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "the connection takes over every piece of state the login connection set up"
+    )]
+    pub fn new(
         outgoing_packets: UnboundedSender<OutboundPacket>,
         cancel_token: CancellationToken,
         compression: Option<CompressionInfo>,
         network_writer: JavaNetworkWriter,
         id: u64,
+        transferred: bool,
         player: Weak<Player>,
+        traffic: Arc<TrafficCounters>,
     ) -> Self {
         Self {
             outgoing_packets,
@@ -124,22 +156,37 @@ impl JavaConnection {
             compression,
             network_writer,
             id,
+            transferred,
             player,
             keep_alive_tracker: SyncMutex::new(KeepAliveTracker {
                 alive_time: 0,
                 alive_pending: false,
                 alive_id: 0,
+                last_round_trip: 0,
             }),
             latency: SyncMutex::new(0),
+            cookie_requests: SyncMutex::new(FxHashMap::default()),
+            recent_packets: SyncMutex::new(VecDeque::with_capacity(RECENT_PACKETS)),
+            traffic,
         }
     }
 
+    /// Returns the ids of the last play packets processed, oldest first.
+    #[must_use]
+    pub fn recent_packet_ids(&self) -> Vec<i32> {
+        self.recent_packets.lock().iter().copied().collect()
+    }
+
     async fn write_packet_now(&self, packet: &EncodedPacket) -> Result<(), PacketError> {
+        self.write_packets_now(slice::from_ref(packet)).await
+    }
+
+    async fn write_packets_now(&self, packets: &[EncodedPacket]) -> Result<(), PacketError> {
         let mut network_writer = self.network_writer.lock().await;
         let Some(network_writer) = network_writer.as_mut() else {
             return Err(PacketError::ConnectionClosed);
         };
-        network_writer.write_packet(packet).await
+        network_writer.write_packets(packets).await
     }
 
     async fn release_network_writer(&self) {
@@ -185,6 +232,7 @@ impl JavaConnection {
 
             let time = now.saturating_sub(tracker.alive_time) as u32;
             tracker.alive_pending = false;
+            tracker.last_round_trip = time;
             drop(tracker);
             let mut latency = self.latency.lock();
             *latency = (*latency * 3 + time) / 4;
@@ -245,6 +293,16 @@ impl JavaConnection {
         }
     }
 
+    /// Sends the packets added by `f` as one atomic bundle.
+    pub fn send_bundle(&self, f: impl FnOnce(&mut BundleBuilder)) {
+        let mut builder = BundleBuilder::new(self.compression);
+        f(&mut builder);
+        let packets = builder.into_packets();
+        if !packets.is_empty() {
+            self.send_encoded_bundle(packets);
+        }
+    }
+
     /// Sends an encoded packet to the client.
     ///
     /// # Panics
@@ -259,6 +317,62 @@ impl JavaConnection {
         }
     }
 
+    /// Returns whether the client joined through a transfer from another server.
+    #[must_use]
+    pub const fn transferred(&self) -> bool {
+        self.transferred
+    }
+
+    /// Stores a cookie on the client. Cookies survive transfers, so another
+    /// server can read them back with [`Self::request_cookie`].
+    pub fn store_cookie(&self, key: Identifier, payload: Vec<u8>) {
+        if payload.len() > MAX_COOKIE_SIZE {
+            log::warn!(
+                "Not storing cookie {key} for client {}: {} bytes exceeds {MAX_COOKIE_SIZE}",
+                self.id,
+                payload.len()
+            );
+            return;
+        }
+        self.send_packet(CStoreCookie::new(key, payload));
+    }
+
+    /// Requests the cookie stored under `key`. The receiver resolves with the
+    /// payload, or `None` if the client has no such cookie.
+    pub fn request_cookie(&self, key: Identifier) -> oneshot::Receiver<Option<Vec<u8>>> {
+        let (sender, receiver) = oneshot::channel();
+        self.cookie_requests
+            .lock()
+            .entry(key.clone())
+            .or_default()
+            .push_back(sender);
+        self.send_packet(CCookieRequest::new(key));
+        receiver
+    }
+
+    /// Sends the client to another server. It reconnects with the transfer
+    /// intent and keeps its cookies.
+    pub fn transfer(&self, host: String, port: u16) {
+        self.send_packet(CTransfer::new(host, i32::from(port)));
+    }
+
+    /// Vanilla `ServerCommonPacketListenerImpl.handleCookieResponse`: responses
+    /// nothing asked for disconnect the client.
+    fn handle_cookie_response(&self, packet: SCookieResponse) {
+        let mut requests = self.cookie_requests.lock();
+        let sender = requests.get_mut(&packet.key).and_then(VecDeque::pop_front);
+        if requests.get(&packet.key).is_some_and(VecDeque::is_empty) {
+            requests.remove(&packet.key);
+        }
+        drop(requests);
+        match sender {
+            Some(sender) => {
+                let _ = sender.send(packet.payload);
+            }
+            None => self.disconnect(translations::MULTIPLAYER_DISCONNECT_UNEXPECTED_QUERY.msg()),
+        }
+    }
+
     /// Closes the connection.
     pub fn close(&self) {
         self.cancel_token.cancel();
@@ -288,6 +402,8 @@ impl JavaConnection {
                 | play::S_CHAT_ACK
                 | play::S_CLIENT_TICK_END
                 | play::S_PLAYER_LOADED
+                | play::S_RESOURCE_PACK
+                | play::S_COOKIE_RESPONSE
         )
     }
 
@@ -298,11 +414,18 @@ impl JavaConnection {
     )]
     pub fn process_packet(
         &self,
-        packet: RawPacket,
+        mut packet: RawPacket,
         player: Arc<Player>,
         server: Arc<Server>,
     ) -> Result<(), PacketError> {
-        let data = &mut Cursor::new(packet.payload.as_slice());
+        let data = &mut packet.payload;
+        {
+            let mut recent_packets = self.recent_packets.lock();
+            if recent_packets.len() == RECENT_PACKETS {
+                recent_packets.pop_front();
+            }
+            recent_packets.push_back(packet.id);
+        }
 
         if !player.has_joined_world() && !Self::can_process_before_join(packet.id) {
             return Ok(());
@@ -327,6 +450,9 @@ impl JavaConnection {
             play::S_CUSTOM_PAYLOAD => {
                 player.handle_custom_payload(SCustomPayload::read_packet(data)?);
             }
+            play::S_RESOURCE_PACK => {
+                player.handle_resource_pack(SResourcePack::read_packet(data)?);
+            }
             play::S_CHAT => {
                 player.handle_chat(SChat::read_packet(data)?, Arc::clone(&player));
             }
@@ -353,6 +479,9 @@ impl JavaConnection {
             play::S_KEEP_ALIVE => {
                 self.handle_keep_alive(SKeepAlive::read_packet(data)?);
             }
+            play::S_COOKIE_RESPONSE => {
+                self.handle_cookie_response(SCookieResponse::read_packet(data)?);
+            }
             play::S_MOVE_PLAYER_POS => {
                 player.handle_move_player(SMovePlayerPos::read_packet(data)?.into());
             }
@@ -368,6 +497,9 @@ impl JavaConnection {
             play::S_MOVE_VEHICLE => {
                 player.handle_move_vehicle(SMoveVehicle::read_packet(data)?);
             }
+            play::S_PADDLE_BOAT => {
+                player.handle_paddle_boat(SPaddleBoat::read_packet(data)?);
+            }
             play::S_PLAYER_LOADED => {
                 let _ = SPlayerLoad::read_packet(data)?;
                 if player.mark_client_loaded_from_network() {
@@ -405,6 +537,35 @@ impl JavaConnection {
             play::S_CONTAINER_CLOSE => {
                 player.handle_container_close(SContainerClose::read_packet(data)?);
             }
+            play::S_SELECT_TRADE => {
+                player.handle_select_trade(SSelectTrade::read_packet(data)?);
+            }
+            play::S_SET_BEACON => {
+                player.handle_set_beacon(SSetBeacon::read_packet(data)?);
+            }
+            play::S_SET_STRUCTURE_BLOCK => {
+                player.handle_set_structure_block(SSetStructureBlock::read_packet(data)?);
+            }
+            play::S_SET_COMMAND_BLOCK => {
+                player.handle_set_command_block(SSetCommandBlock::read_packet(data)?);
+            }
+            play::S_SET_JIGSAW_BLOCK => {
+                player.handle_set_jigsaw_block(SSetJigsawBlock::read_packet(data)?);
+            }
+            play::S_JIGSAW_GENERATE => {
+                player.handle_jigsaw_generate(SJigsawGenerate::read_packet(data)?);
+            }
+            play::S_RECIPE_BOOK_CHANGE_SETTINGS => {
+                player.handle_recipe_book_change_settings(SRecipeBookChangeSettings::read_packet(
+                    data,
+                )?);
+            }
+            play::S_RECIPE_BOOK_SEEN_RECIPE => {
+                player.handle_recipe_book_seen_recipe(SRecipeBookSeenRecipe::read_packet(data)?);
+            }
+            play::S_SEEN_ADVANCEMENTS => {
+                player.handle_seen_advancements(SSeenAdvancements::read_packet(data)?);
+            }
             play::S_CONTAINER_SLOT_STATE_CHANGED => {
                 player.handle_container_slot_state_changed(
                     SContainerSlotStateChanged::read_packet(data)?,
@@ -447,6 +608,9 @@ impl JavaConnection {
                 let packet = SSignUpdate::read_packet(data)?;
                 player.handle_sign_update(packet);
             }
+            play::S_EDIT_BOOK => {
+                player.handle_edit_book(SEditBook::read_packet(data)?);
+            }
             play::S_SPECTATOR_ACTION => {
                 let packet = SSpectatorAction::read_packet(data)?;
                 player.handle_spectator_action(packet);
@@ -478,6 +642,13 @@ impl JavaConnection {
         mut reader: TCPNetworkDecoder<BufReader<OwnedReadHalf>>,
         server: Arc<Server>,
     ) {
+        reader.set_limits(
+            server
+                .config
+                .load()
+                .packet_limits
+                .limits(ConnectionProtocol::Play),
+        );
         loop {
             select! {
                 () = self.wait_for_close() => {
@@ -494,6 +665,15 @@ impl JavaConnection {
                                 );
                             }
                         }
+                        Err(err) if err.is_limit_exceeded() => {
+                            log::warn!("Client {} exceeded packet limits: {err}", self.id);
+                            if matches!(err, PacketError::RateLimited(_)) {
+                                self.disconnect(translations::DISCONNECT_EXCEEDED_PACKET_RATE.msg());
+                            } else {
+                                self.disconnect(translations::DISCONNECT_PACKET_ERROR.msg());
+                            }
+                            break;
+                        }
                         Err(err) => {
                             log::debug!("Failed to get raw packet from client {}: {err}", self.id);
                             self.close();
@@ -506,7 +686,9 @@ impl JavaConnection {
 
     /// Sends packets to the client.
     ///
+    /// Everything already queued is written as one batch with a single flush.
     pub async fn sender(&self, mut sender_recv: UnboundedReceiver<OutboundPacket>) {
+        let mut batch = Vec::new();
         loop {
             select! {
                 biased;
@@ -516,33 +698,44 @@ impl JavaConnection {
                 }
                 outbound = sender_recv.recv() => {
                     if let Some(outbound) = outbound {
-                        let (packet, close_after_write) = match outbound {
-                            OutboundPacket::Packet(packet) => (packet, false),
-                            OutboundPacket::Disconnect(packet) => (packet, true),
-                        };
-
-                        if close_after_write {
-                            if let Err(err) = self.write_packet_now(&packet).await {
-                                log::warn!("Failed to send disconnect packet to client {}: {err}", self.id);
+                        let mut disconnect = None;
+                        match outbound {
+                            OutboundPacket::Packet(packet) => batch.push(packet),
+                            OutboundPacket::Disconnect(packet) => disconnect = Some(packet),
+                        }
+                        while disconnect.is_none() && batch.len() < MAX_BATCH_PACKETS {
+                            match sender_recv.try_recv() {
+                                Ok(OutboundPacket::Packet(packet)) => batch.push(packet),
+                                Ok(OutboundPacket::Disconnect(packet)) => disconnect = Some(packet),
+                                Err(TryRecvError::Empty | TryRecvError::Disconnected) => break,
                             }
-                            self.close();
-                            break;
                         }
 
-                        let write_result = self.write_packet_now(&packet);
-                        select! {
-                            biased;
-                            () = self.wait_for_close() => {
-                                self.write_queued_disconnect(&mut sender_recv).await;
-                                break;
-                            },
-                            result = write_result => {
-                                if let Err(err) = result {
-                                    log::warn!("Failed to send packet to client {}: {err}", self.id);
-                                    self.close();
+                        if !batch.is_empty() {
+                            let write_result = self.write_packets_now(&batch);
+                            select! {
+                                biased;
+                                () = self.wait_for_close() => {
+                                    self.write_queued_disconnect(&mut sender_recv).await;
                                     break;
+                                },
+                                result = write_result => {
+                                    if let Err(err) = result {
+                                        log::warn!("Failed to send packet to client {}: {err}", self.id);
+                                        self.close();
+                                        break;
+                                    }
                                 }
                             }
+                            batch.clear();
+                        }
+
+                        if let Some(packet) = disconnect {
+                            if let Err(err) = self.write_packet_now(&packet).await {
+                                log::warn!("Failed to send disconnect packet to client {}: {err}", self.id);
+                            }
+                            self.close();
+                            break;
                         }
                     } else {
                         //log::warn!(
@@ -613,11 +806,10 @@ impl NetworkConnection for JavaConnection {
     }
 
     fn send_encoded_bundle(&self, packets: Vec<EncodedPacket>) {
-        self.send_packet(CBundleDelimiter);
-        for packet in packets {
-            self.send_encoded_packet(packet);
+        match encode_bundle(&packets, self.compression) {
+            Ok(bundle) => self.send_encoded_packet(bundle),
+            Err(err) => log::warn!("Failed to encode bundle for client {}: {err}", self.id),
         }
-        self.send_packet(CBundleDelimiter);
     }
 
     fn disconnect_with_reason(&self, reason: TextComponent) {
@@ -632,6 +824,14 @@ impl NetworkConnection for JavaConnection {
         *self.latency.lock() as i32
     }
 
+    fn last_round_trip(&self) -> i32 {
+        self.keep_alive_tracker.lock().last_round_trip as i32
+    }
+
+    fn traffic(&self) -> Option<TrafficStats> {
+        Some(self.traffic.snapshot())
+    }
+
     fn close(&self) {
         self.cancel_token.cancel();
     }
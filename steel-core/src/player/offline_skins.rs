@@ -0,0 +1,221 @@
+//! Skins for offline-mode players, resolved by name.
+//!
+//! Offline-mode profiles have no textures, so the joining name is looked up
+//! through the configured providers instead. Results, including names no
+//! provider knows, are cached as `<save>/cache/skins/<name>.json` and reused
+//! until they are older than the configured TTL.
+
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use steel_protocol::packets::login::GameProfileProperty;
+use tokio::fs;
+
+use super::profile_lookup::{fetch_profile_properties, lookup_online_profile};
+use super::{ProfileLookupError, is_valid_player_name};
+use crate::config::{OfflineSkinsConfig, SkinProvider};
+
+const CACHE_DIRECTORY: &str = "cache";
+const SKIN_CACHE_DIRECTORY: &str = "skins";
+const TEXTURES_PROPERTY: &str = "textures";
+
+/// A session server profile response, as saved by the directory provider.
+#[derive(Deserialize)]
+struct SavedProfile {
+    properties: Vec<GameProfileProperty>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedSkin {
+    /// Unix time in seconds the skin was resolved at.
+    fetched_at: u64,
+    properties: Vec<GameProfileProperty>,
+}
+
+/// Resolves and caches skins for offline-mode names.
+pub struct OfflineSkinCache {
+    directory: PathBuf,
+}
+
+impl OfflineSkinCache {
+    /// Creates a cache storing resolved skins under `save_path`, without touching disk.
+    #[must_use]
+    pub fn new(save_path: &Path) -> Self {
+        Self {
+            directory: save_path.join(CACHE_DIRECTORY).join(SKIN_CACHE_DIRECTORY),
+        }
+    }
+
+    /// Returns the texture properties for `name`, or none when skins are
+    /// disabled or no provider knows the name.
+    ///
+    /// Mojang's signed textures are kept signed. Directory textures without a
+    /// signature are sent unsigned, which clients accept for textures hosted by
+    /// Mojang.
+    pub async fn resolve(
+        &self,
+        client: &reqwest::Client,
+        profile_server: Option<&str>,
+        config: &OfflineSkinsConfig,
+        name: &str,
+    ) -> Vec<GameProfileProperty> {
+        if !config.enable {
+            return Vec::new();
+        }
+        let Some(path) = self.cache_path(name) else {
+            return Vec::new();
+        };
+        let now = unix_time();
+        if let Some(cached) = read_cached(&path).await
+            && now.saturating_sub(cached.fetched_at) < config.cache_ttl_seconds
+        {
+            return cached.properties;
+        }
+
+        let mut properties = Vec::new();
+        for provider in &config.providers {
+            match resolve_with(provider, client, profile_server, name).await {
+                Ok(Some(resolved)) => {
+                    properties = resolved;
+                    break;
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    log::warn!("Couldn't resolve the skin of {name}: {error}");
+                    // A provider failure is not a miss, so don't cache it.
+                    return Vec::new();
+                }
+            }
+        }
+
+        let cached = CachedSkin {
+            fetched_at: now,
+            properties,
+        };
+        if let Err(error) = write_cached(&path, &cached).await {
+            log::warn!("Couldn't cache the skin of {name}: {error}");
+        }
+        cached.properties
+    }
+
+    fn cache_path(&self, name: &str) -> Option<PathBuf> {
+        skin_file_name(name).map(|file| self.directory.join(file))
+    }
+}
+
+/// Returns `Ok(None)` when the provider doesn't know `name`.
+async fn resolve_with(
+    provider: &SkinProvider,
+    client: &reqwest::Client,
+    profile_server: Option<&str>,
+    name: &str,
+) -> Result<Option<Vec<GameProfileProperty>>, String> {
+    let properties = match provider {
+        SkinProvider::Mojang => {
+            let profile = match lookup_online_profile(client, profile_server, name).await {
+                Ok(profile) => profile,
+                Err(ProfileLookupError::UnknownPlayer(_)) => return Ok(None),
+                Err(error) => return Err(error.to_string()),
+            };
+            match fetch_profile_properties(client, profile.uuid(), name).await {
+                Ok(properties) => properties,
+                Err(ProfileLookupError::UnknownPlayer(_)) => return Ok(None),
+                Err(error) => return Err(error.to_string()),
+            }
+        }
+        SkinProvider::Directory { path } => {
+            let Some(file) = skin_file_name(name) else {
+                return Ok(None);
+            };
+            let path = path.join(file);
+            let bytes = match fs::read(&path).await {
+                Ok(bytes) => bytes,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(error) => return Err(format!("failed to read {}: {error}", path.display())),
+            };
+            serde_json::from_slice::<SavedProfile>(&bytes)
+                .map_err(|error| format!("failed to parse {}: {error}", path.display()))?
+                .properties
+        }
+    };
+
+    let textures: Vec<_> = properties
+        .into_iter()
+        .filter(|property| property.name == TEXTURES_PROPERTY)
+        .collect();
+    Ok((!textures.is_empty()).then_some(textures))
+}
+
+/// File name for a player name, or `None` for names that aren't valid player names.
+fn skin_file_name(name: &str) -> Option<String> {
+    is_valid_player_name(name).then(|| format!("{}.json", name.to_ascii_lowercase()))
+}
+
+async fn read_cached(path: &Path) -> Option<CachedSkin> {
+    let bytes = fs::read(path).await.ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+async fn write_cached(path: &Path, cached: &CachedSkin) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|error| format!("failed to create {}: {error}", parent.display()))?;
+    }
+    let bytes = serde_json::to_vec(cached).map_err(|error| error.to_string())?;
+    fs::write(path, bytes)
+        .await
+        .map_err(|error| format!("failed to write {}: {error}", path.display()))
+}
+
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skin_file_name_normalizes_and_rejects_paths() {
+        assert_eq!(skin_file_name("Steve").as_deref(), Some("steve.json"));
+        assert_eq!(skin_file_name("../etc"), None);
+    }
+
+    #[tokio::test]
+    async fn directory_provider_keeps_only_textures() {
+        let directory =
+            std::env::temp_dir().join(format!("steel-offline-skins-{}", std::process::id()));
+        fs::create_dir_all(&directory)
+            .await
+            .expect("test directory should be creatable");
+        fs::write(
+            directory.join("alex.json"),
+            r#"{"properties":[{"name":"textures","value":"e30="},{"name":"other","value":"x"}]}"#,
+        )
+        .await
+        .expect("test profile should be writable");
+
+        let provider = SkinProvider::Directory {
+            path: directory.clone(),
+        };
+        let client = reqwest::Client::new();
+        let found = resolve_with(&provider, &client, None, "Alex").await;
+        let missing = resolve_with(&provider, &client, None, "Steve").await;
+        let _ = fs::remove_dir_all(&directory).await;
+
+        let Ok(Some(found)) = found else {
+            panic!("saved profile should resolve");
+        };
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].value, "e30=");
+        assert!(found[0].signature.is_none());
+        assert!(matches!(missing, Ok(None)));
+    }
+}
@@ -5,14 +5,18 @@
 use rustc_hash::FxHashSet;
 use steel_registry::item_stack::ItemStack;
 use steel_utils::types::GameType;
+use steel_utils::{BlockPos, GlobalPos};
 
 use crate::{
     chunk_saver::{ChunkStorage, PersistentEntity},
     entity::{Entity, EntityFireFreezeState, LivingEntity},
     inventory::container::Container,
+    level_data::RespawnData,
 };
 
-use super::{Player, abilities::Abilities, experience::Experience};
+use super::{
+    Player, RespawnConfig, WardenSpawnTracker, abilities::Abilities, experience::Experience,
+};
 
 /// Current data version for player saves.
 /// Increment when making breaking changes to the format.
@@ -72,6 +76,12 @@ pub struct PersistentPlayerData {
     /// Currently selected hotbar slot (0-8).
     pub selected_slot: i32,
 
+    /// Vanilla `EnderItems`, the player's ender chest contents.
+    pub ender_items: Vec<PersistentSlot>,
+
+    /// Vanilla `recipeBook`, the unlocked recipes and recipe book settings.
+    pub recipe_book: PersistentRecipeBook,
+
     /// Loaded world identifier (e.g., "minecraft:overworld").
     pub world: String,
 
@@ -102,6 +112,9 @@ pub struct PersistentPlayerData {
     /// Vanilla death-screen score. Point grants change it with Java `int` wrapping.
     pub score: i32,
 
+    /// Vanilla `XpSeed`, the seed for enchanting table offers.
+    pub enchantment_seed: i32,
+
     /// Vanilla `ServerPlayer.seenCredits`.
     pub seen_credits: bool,
 
@@ -110,6 +123,15 @@ pub struct PersistentPlayerData {
 
     /// Vanilla in-flight ender pearls stored with the player (`ServerPlayer.enderPearls`).
     pub ender_pearls: Vec<PersistentEnderPearl>,
+
+    /// Vanilla `respawn`, the personal respawn point set by a bed.
+    pub respawn: Option<PersistentRespawnConfig>,
+
+    /// Vanilla `LastDeathLocation`, where the player last died.
+    pub last_death_location: Option<PersistentGlobalPos>,
+
+    /// Vanilla `warden_spawn_tracker`, the sculk shrieker warning level.
+    pub warden_spawn_tracker: WardenSpawnTracker,
 }
 
 /// A vanilla `RootVehicle` tree persisted with player data.
@@ -133,6 +155,30 @@ pub struct PersistentEnderPearl {
     pub entity: PersistentEntity,
 }
 
+/// A personal respawn point persisted with the player.
+///
+/// Mirrors vanilla `ServerPlayer.RespawnConfig`.
+#[derive(Debug, Clone)]
+pub struct PersistentRespawnConfig {
+    /// Key of the respawn world.
+    pub world: String,
+    /// Respawn block position.
+    pub pos: [i32; 3],
+    /// Respawn yaw and pitch in degrees.
+    pub rotation: [f32; 2],
+    /// Whether the point is used without a bed.
+    pub forced: bool,
+}
+
+/// A block position in a world, mirroring vanilla `GlobalPos`.
+#[derive(Debug, Clone)]
+pub struct PersistentGlobalPos {
+    /// Key of the world.
+    pub world: String,
+    /// Block position.
+    pub pos: [i32; 3],
+}
+
 /// Persistent abilities data.
 #[derive(Debug, Clone)]
 pub struct PersistentAbilities {
@@ -161,6 +207,17 @@ pub struct PersistentSlot {
     pub item: ItemStack,
 }
 
+/// Saved recipe book state.
+#[derive(Debug, Clone, Default)]
+pub struct PersistentRecipeBook {
+    /// Keys of the unlocked recipes.
+    pub recipes: Vec<String>,
+    /// Keys of the unlocked recipes the player has not looked at yet.
+    pub to_be_displayed: Vec<String>,
+    /// Open and filtering flags per recipe book, in vanilla stream order.
+    pub settings: [bool; 8],
+}
+
 impl PersistentPlayerData {
     /// Extracts persistent data from a live player.
     #[must_use]
@@ -224,6 +281,8 @@ impl PersistentPlayerData {
             },
             inventory: slots,
             selected_slot: i32::from(inventory.get_selected_slot()),
+            ender_items: Self::ender_items_from_player(player),
+            recipe_book: player.recipe_book.lock().to_persistent(),
             world: player.get_world().key.to_string(),
             food_level: food_data.food_level,
             food_saturation_level: food_data.saturation_level,
@@ -234,12 +293,43 @@ impl PersistentPlayerData {
             experience_progress,
             experience_total,
             score,
+            enchantment_seed: player.enchantment_seed(),
             seen_credits: player.has_seen_credits(),
             root_vehicle,
             ender_pearls,
+            respawn: player.respawn_config().map(|config| {
+                let pos = config.respawn_data.pos();
+                PersistentRespawnConfig {
+                    world: config.respawn_data.dimension().to_string(),
+                    pos: [pos.x(), pos.y(), pos.z()],
+                    rotation: [config.respawn_data.yaw, config.respawn_data.pitch],
+                    forced: config.forced,
+                }
+            }),
+            last_death_location: player
+                .last_death_location()
+                .map(|location| PersistentGlobalPos {
+                    world: location.dimension.to_string(),
+                    pos: [location.pos.x(), location.pos.y(), location.pos.z()],
+                }),
+            warden_spawn_tracker: player.warden_spawn_tracker(),
         }
     }
 
+    /// Collects the non-empty ender chest slots.
+    fn ender_items_from_player(player: &Player) -> Vec<PersistentSlot> {
+        let ender_chest = player.ender_chest_inventory.lock();
+        (0..ender_chest.get_container_size())
+            .filter_map(|slot| {
+                let item = ender_chest.get_item(slot);
+                (!item.is_empty()).then(|| PersistentSlot {
+                    slot: slot as i8,
+                    item: item.clone(),
+                })
+            })
+            .collect()
+    }
+
     /// Snapshots the player's live in-flight ender pearls for persistence.
     fn ender_pearls_from_player(player: &Player) -> Vec<PersistentEnderPearl> {
         let mut seen = FxHashSet::default();
@@ -397,6 +487,20 @@ impl PersistentPlayerData {
             inventory.set_selected_slot(selected);
         }
 
+        // Ender chest
+        {
+            let mut ender_chest = player.ender_chest_inventory.lock();
+            ender_chest.clear_content();
+            for slot_data in &self.ender_items {
+                let slot_index = slot_data.slot as usize;
+                if slot_index < ender_chest.get_container_size() {
+                    ender_chest.set_item(slot_index, slot_data.item.clone());
+                }
+            }
+        }
+
+        player.recipe_book.lock().load_persistent(&self.recipe_book);
+
         // Food data
         {
             let mut food = player.food_data.lock();
@@ -415,6 +519,29 @@ impl PersistentPlayerData {
             );
         }
         player.set_score(self.score);
+        player.set_enchantment_seed(self.enchantment_seed);
         player.set_seen_credits(self.seen_credits);
+        player.set_respawn_position(
+            self.respawn.as_ref().and_then(|respawn| {
+                let dimension = respawn.world.parse().ok()?;
+                Some(RespawnConfig {
+                    respawn_data: RespawnData::of(
+                        dimension,
+                        BlockPos::new(respawn.pos[0], respawn.pos[1], respawn.pos[2]),
+                        respawn.rotation[0],
+                        respawn.rotation[1],
+                    ),
+                    forced: respawn.forced,
+                })
+            }),
+            false,
+        );
+        player.set_last_death_location(self.last_death_location.as_ref().and_then(|location| {
+            Some(GlobalPos::new(
+                location.world.parse().ok()?,
+                BlockPos::new(location.pos[0], location.pos[1], location.pos[2]),
+            ))
+        }));
+        player.set_warden_spawn_tracker(self.warden_spawn_tracker);
     }
 }
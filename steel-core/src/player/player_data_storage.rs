@@ -25,23 +25,24 @@ use self::{
     permissions::{PlayerPermissionsFile, serialize_player_permissions_file},
 };
 use super::player_data::{
-    PLAYER_DATA_VERSION, PersistentAbilities, PersistentEnderPearl, PersistentPlayerData,
-    PersistentRootVehicle, PersistentSlot,
+    PLAYER_DATA_VERSION, PersistentAbilities, PersistentEnderPearl, PersistentGlobalPos,
+    PersistentPlayerData, PersistentRecipeBook, PersistentRespawnConfig, PersistentRootVehicle,
+    PersistentSlot,
 };
 use crate::chunk_saver::PersistentEntity;
 use crate::config::StorageSelection;
 use crate::permission::PermissionSubjectIndex;
 #[cfg(test)]
 use crate::permission::PermissionSubjectState;
-use crate::player::Player;
 use crate::player::known_players::KnownPlayers;
+use crate::player::{Player, PlayerAdvancements, StatsCounter, WardenSpawnTracker};
 use steel_registry::item_stack::ItemStack;
 use steel_utils::Identifier;
 use steel_utils::locks::{AsyncMutex, SyncMutex};
 
 const PLAYER_MAGIC: [u8; 4] = *b"STLP";
 const GLOBAL_MAGIC: [u8; 4] = *b"STLG";
-const PLAYER_STORAGE_VERSION: u16 = 7;
+const PLAYER_STORAGE_VERSION: u16 = 13;
 const GLOBAL_STORAGE_VERSION: u16 = 1;
 const GLOBAL_PLAYER_DATA_VERSION: i32 = 1;
 
@@ -85,6 +86,8 @@ struct PlayerDataFile {
     abilities: AbilitiesFile,
     inventory: Vec<SlotFile>,
     selected_slot: i32,
+    ender_items: Vec<SlotFile>,
+    recipe_book: RecipeBookFile,
     world: String,
     food_level: i32,
     food_saturation_level: f32,
@@ -94,9 +97,13 @@ struct PlayerDataFile {
     experience_progress: f32,
     experience_total: i32,
     score: i32,
+    enchantment_seed: i32,
     seen_credits: bool,
     root_vehicle: Option<RootVehicleFile>,
     ender_pearls: Vec<EnderPearlFile>,
+    respawn: Option<RespawnConfigFile>,
+    last_death_location: Option<GlobalPosFile>,
+    warden_spawn_tracker: WardenSpawnTrackerFile,
 }
 
 #[derive(SchemaWrite, SchemaRead)]
@@ -111,6 +118,27 @@ struct EnderPearlFile {
     entity: PersistentEntity,
 }
 
+#[derive(SchemaWrite, SchemaRead)]
+struct RespawnConfigFile {
+    world: String,
+    pos: [i32; 3],
+    rotation: [f32; 2],
+    forced: bool,
+}
+
+#[derive(SchemaWrite, SchemaRead)]
+struct GlobalPosFile {
+    world: String,
+    pos: [i32; 3],
+}
+
+#[derive(SchemaWrite, SchemaRead)]
+struct WardenSpawnTrackerFile {
+    ticks_since_last_warning: i32,
+    warning_level: i32,
+    cooldown_ticks: i32,
+}
+
 #[derive(SchemaWrite, SchemaRead)]
 struct AbilitiesFile {
     invulnerable: bool,
@@ -122,6 +150,13 @@ struct AbilitiesFile {
     walking_speed: f32,
 }
 
+#[derive(SchemaWrite, SchemaRead)]
+struct RecipeBookFile {
+    recipes: Vec<String>,
+    to_be_displayed: Vec<String>,
+    settings: [bool; 8],
+}
+
 #[derive(SchemaWrite, SchemaRead)]
 struct SlotFile {
     slot: i8,
@@ -150,7 +185,12 @@ impl PlayerDataStorage {
     /// Saves a player's current domain data and global last-active-domain.
     pub async fn save(&self, player: &Player) -> io::Result<()> {
         let domain = player.get_world().domain().to_owned();
+        let stats = player.stats_json();
+        let advancements = player.advancements_json();
         self.save_domain(&domain, player).await?;
+        self.save_stats(player.gameprofile.id, stats).await?;
+        self.save_advancements(player.gameprofile.id, advancements)
+            .await?;
         self.save_global(
             player.gameprofile.id,
             &GlobalPlayerData {
@@ -199,6 +239,48 @@ impl PlayerDataStorage {
         }
     }
 
+    /// Loads a player's statistics.
+    pub async fn load_stats(&self, uuid: Uuid) -> io::Result<Option<StatsCounter>> {
+        match &self.backend {
+            PlayerDataStorageBackend::File(storage) => storage.load_stats(uuid).await,
+        }
+    }
+
+    /// Saves a player's statistics, already serialized as vanilla stats JSON.
+    pub async fn save_stats(&self, uuid: Uuid, json: String) -> io::Result<()> {
+        match &self.backend {
+            PlayerDataStorageBackend::File(storage) => storage.save_stats(uuid, json).await,
+        }
+    }
+
+    /// Loads a player's advancement progress.
+    pub async fn load_advancements(&self, uuid: Uuid) -> io::Result<Option<PlayerAdvancements>> {
+        match &self.backend {
+            PlayerDataStorageBackend::File(storage) => storage.load_advancements(uuid).await,
+        }
+    }
+
+    /// Saves a player's advancement progress, already serialized as vanilla JSON.
+    pub async fn save_advancements(&self, uuid: Uuid, json: String) -> io::Result<()> {
+        match &self.backend {
+            PlayerDataStorageBackend::File(storage) => storage.save_advancements(uuid, json).await,
+        }
+    }
+
+    /// Loads the players a player ignores.
+    pub async fn load_ignored(&self, uuid: Uuid) -> io::Result<Option<Vec<Uuid>>> {
+        match &self.backend {
+            PlayerDataStorageBackend::File(storage) => storage.load_ignored(uuid).await,
+        }
+    }
+
+    /// Saves the players a player ignores.
+    pub async fn save_ignored(&self, uuid: Uuid, ignored: &[Uuid]) -> io::Result<()> {
+        match &self.backend {
+            PlayerDataStorageBackend::File(storage) => storage.save_ignored(uuid, ignored).await,
+        }
+    }
+
     /// Loads all persisted player permission snapshots.
     pub async fn load_permission_subjects(&self) -> io::Result<PermissionSubjectIndex> {
         match &self.backend {
@@ -379,6 +461,66 @@ impl FilePlayerDataStorage {
             .await
     }
 
+    async fn load_stats(&self, uuid: Uuid) -> io::Result<Option<StatsCounter>> {
+        let path = self.stats_file(uuid);
+        let lock = self.file_lock(&path);
+        let _guard = lock.lock().await;
+        if !Self::recover_missing_atomic_path_locked(&path).await? {
+            return Ok(None);
+        }
+        let contents = fs::read_to_string(&path).await?;
+        StatsCounter::from_json(&contents).map(Some)
+    }
+
+    async fn save_stats(&self, uuid: Uuid, json: String) -> io::Result<()> {
+        let path = self.stats_file(uuid);
+        let lock = self.file_lock(&path);
+        let _guard = lock.lock().await;
+        Self::write_atomic_path_locked(&path, json.into_bytes()).await
+    }
+
+    async fn load_advancements(&self, uuid: Uuid) -> io::Result<Option<PlayerAdvancements>> {
+        let path = self.advancements_file(uuid);
+        let lock = self.file_lock(&path);
+        let _guard = lock.lock().await;
+        if !Self::recover_missing_atomic_path_locked(&path).await? {
+            return Ok(None);
+        }
+        let contents = fs::read_to_string(&path).await?;
+        PlayerAdvancements::from_json(&contents).map(Some)
+    }
+
+    async fn save_advancements(&self, uuid: Uuid, json: String) -> io::Result<()> {
+        let path = self.advancements_file(uuid);
+        let lock = self.file_lock(&path);
+        let _guard = lock.lock().await;
+        Self::write_atomic_path_locked(&path, json.into_bytes()).await
+    }
+
+    async fn load_ignored(&self, uuid: Uuid) -> io::Result<Option<Vec<Uuid>>> {
+        let path = self.ignored_file(uuid);
+        let lock = self.file_lock(&path);
+        let _guard = lock.lock().await;
+        if !Self::recover_missing_atomic_path_locked(&path).await? {
+            return Ok(None);
+        }
+        let contents = fs::read_to_string(&path).await?;
+        serde_json::from_str(&contents).map(Some).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid ignore list in {}: {error}", path.display()),
+            )
+        })
+    }
+
+    async fn save_ignored(&self, uuid: Uuid, ignored: &[Uuid]) -> io::Result<()> {
+        let path = self.ignored_file(uuid);
+        let lock = self.file_lock(&path);
+        let _guard = lock.lock().await;
+        let json = serde_json::to_vec(ignored).map_err(io::Error::other)?;
+        Self::write_atomic_path_locked(&path, json).await
+    }
+
     async fn save_permission_subjects(&self, subjects: &PermissionSubjectIndex) -> io::Result<()> {
         let path = self.player_permissions_file();
         let lock = self.file_lock(&path);
@@ -438,6 +580,25 @@ impl FilePlayerDataStorage {
         self.global_dir().join("players")
     }
 
+    /// Vanilla keeps one `stats/<uuid>.json` per player, shared by every domain.
+    fn stats_file(&self, uuid: Uuid) -> PathBuf {
+        self.global_dir().join("stats").join(format!("{uuid}.json"))
+    }
+
+    /// Like stats, advancements are kept in vanilla's `advancements/<uuid>.json`.
+    fn advancements_file(&self, uuid: Uuid) -> PathBuf {
+        self.global_dir()
+            .join("advancements")
+            .join(format!("{uuid}.json"))
+    }
+
+    /// Ignore lists are a Steel addition, kept next to stats as `ignored/<uuid>.json`.
+    fn ignored_file(&self, uuid: Uuid) -> PathBuf {
+        self.global_dir()
+            .join("ignored")
+            .join(format!("{uuid}.json"))
+    }
+
     fn player_permissions_file(&self) -> PathBuf {
         self.global_dir().join("player_permissions.toml")
     }
@@ -588,15 +749,29 @@ impl FilePlayerDataStorage {
 }
 
 impl PlayerDataFile {
-    fn from_persistent(data: &PersistentPlayerData) -> io::Result<Self> {
-        let mut inventory = Vec::with_capacity(data.inventory.len());
-        for slot in &data.inventory {
-            inventory.push(SlotFile {
+    fn slots_to_file(slots: &[PersistentSlot]) -> io::Result<Vec<SlotFile>> {
+        let mut files = Vec::with_capacity(slots.len());
+        for slot in slots {
+            files.push(SlotFile {
                 slot: slot.slot,
                 item_nbt: item_to_nbt_bytes(&slot.item)?,
             });
         }
+        Ok(files)
+    }
+
+    fn slots_from_file(files: Vec<SlotFile>) -> io::Result<Vec<PersistentSlot>> {
+        let mut slots = Vec::with_capacity(files.len());
+        for slot in files {
+            slots.push(PersistentSlot {
+                slot: slot.slot,
+                item: item_from_nbt_bytes(&slot.item_nbt)?,
+            });
+        }
+        Ok(slots)
+    }
 
+    fn from_persistent(data: &PersistentPlayerData) -> io::Result<Self> {
         Ok(Self {
             data_version: data.data_version,
             pos: data.pos,
@@ -621,8 +796,14 @@ impl PlayerDataFile {
                 flying_speed: data.abilities.flying_speed,
                 walking_speed: data.abilities.walking_speed,
             },
-            inventory,
+            inventory: Self::slots_to_file(&data.inventory)?,
             selected_slot: data.selected_slot,
+            ender_items: Self::slots_to_file(&data.ender_items)?,
+            recipe_book: RecipeBookFile {
+                recipes: data.recipe_book.recipes.clone(),
+                to_be_displayed: data.recipe_book.to_be_displayed.clone(),
+                settings: data.recipe_book.settings,
+            },
             world: data.world.clone(),
             food_level: data.food_level,
             food_saturation_level: data.food_saturation_level,
@@ -632,6 +813,7 @@ impl PlayerDataFile {
             experience_progress: data.experience_progress,
             experience_total: data.experience_total,
             score: data.score,
+            enchantment_seed: data.enchantment_seed,
             seen_credits: data.seen_credits,
             root_vehicle: data
                 .root_vehicle
@@ -648,6 +830,24 @@ impl PlayerDataFile {
                     entity: pearl.entity.clone(),
                 })
                 .collect(),
+            respawn: data.respawn.as_ref().map(|respawn| RespawnConfigFile {
+                world: respawn.world.clone(),
+                pos: respawn.pos,
+                rotation: respawn.rotation,
+                forced: respawn.forced,
+            }),
+            last_death_location: data
+                .last_death_location
+                .as_ref()
+                .map(|location| GlobalPosFile {
+                    world: location.world.clone(),
+                    pos: location.pos,
+                }),
+            warden_spawn_tracker: WardenSpawnTrackerFile {
+                ticks_since_last_warning: data.warden_spawn_tracker.ticks_since_last_warning,
+                warning_level: data.warden_spawn_tracker.warning_level,
+                cooldown_ticks: data.warden_spawn_tracker.cooldown_ticks,
+            },
         })
     }
 
@@ -662,14 +862,6 @@ impl PlayerDataFile {
             ));
         }
 
-        let mut inventory = Vec::with_capacity(self.inventory.len());
-        for slot in self.inventory {
-            inventory.push(PersistentSlot {
-                slot: slot.slot,
-                item: item_from_nbt_bytes(&slot.item_nbt)?,
-            });
-        }
-
         Ok(PersistentPlayerData {
             pos: self.pos,
             motion: self.motion,
@@ -693,8 +885,14 @@ impl PlayerDataFile {
                 flying_speed: self.abilities.flying_speed,
                 walking_speed: self.abilities.walking_speed,
             },
-            inventory,
+            inventory: Self::slots_from_file(self.inventory)?,
             selected_slot: self.selected_slot,
+            ender_items: Self::slots_from_file(self.ender_items)?,
+            recipe_book: PersistentRecipeBook {
+                recipes: self.recipe_book.recipes,
+                to_be_displayed: self.recipe_book.to_be_displayed,
+                settings: self.recipe_book.settings,
+            },
             world: self.world,
             food_level: self.food_level,
             food_saturation_level: self.food_saturation_level,
@@ -705,6 +903,7 @@ impl PlayerDataFile {
             experience_progress: self.experience_progress,
             experience_total: self.experience_total,
             score: self.score,
+            enchantment_seed: self.enchantment_seed,
             seen_credits: self.seen_credits,
             root_vehicle: self.root_vehicle.map(|root_vehicle| PersistentRootVehicle {
                 attach: root_vehicle.attach,
@@ -718,6 +917,23 @@ impl PlayerDataFile {
                     entity: pearl.entity,
                 })
                 .collect(),
+            respawn: self.respawn.map(|respawn| PersistentRespawnConfig {
+                world: respawn.world,
+                pos: respawn.pos,
+                rotation: respawn.rotation,
+                forced: respawn.forced,
+            }),
+            last_death_location: self
+                .last_death_location
+                .map(|location| PersistentGlobalPos {
+                    world: location.world,
+                    pos: location.pos,
+                }),
+            warden_spawn_tracker: WardenSpawnTracker {
+                ticks_since_last_warning: self.warden_spawn_tracker.ticks_since_last_warning,
+                warning_level: self.warden_spawn_tracker.warning_level,
+                cooldown_ticks: self.warden_spawn_tracker.cooldown_ticks,
+            },
         })
     }
 }
@@ -862,6 +1078,12 @@ mod tests {
             },
             inventory: Vec::new(),
             selected_slot: 4,
+            ender_items: Vec::new(),
+            recipe_book: RecipeBookFile {
+                recipes: vec!["minecraft:crafting_table".to_owned()],
+                to_be_displayed: Vec::new(),
+                settings: [false; 8],
+            },
             world: "lobby:void".to_owned(),
             food_level: 20,
             food_saturation_level: 5.0,
@@ -871,9 +1093,25 @@ mod tests {
             experience_progress: 0.5,
             experience_total: 32,
             score: 9,
+            enchantment_seed: 1234,
             seen_credits: true,
             root_vehicle: None,
             ender_pearls: Vec::new(),
+            respawn: Some(RespawnConfigFile {
+                world: "lobby:void".to_owned(),
+                pos: [0, 64, 0],
+                rotation: [0.0, 0.0],
+                forced: false,
+            }),
+            last_death_location: Some(GlobalPosFile {
+                world: "lobby:void".to_owned(),
+                pos: [3, -12, 8],
+            }),
+            warden_spawn_tracker: WardenSpawnTrackerFile {
+                ticks_since_last_warning: 40,
+                warning_level: 2,
+                cooldown_ticks: 0,
+            },
         }
     }
 
@@ -1243,7 +1481,12 @@ mod tests {
         assert_eq!(decoded.experience_progress.to_bits(), 0.5_f32.to_bits());
         assert_eq!(decoded.experience_total, 32);
         assert_eq!(decoded.score, 9);
+        assert_eq!(decoded.enchantment_seed, 1234);
         assert!(decoded.seen_credits);
+        assert_eq!(
+            decoded.recipe_book.recipes,
+            vec!["minecraft:crafting_table".to_owned()]
+        );
     }
 
     #[test]
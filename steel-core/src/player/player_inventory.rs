@@ -10,24 +10,29 @@ use std::{
 use glam::DVec3;
 use simdnbt::owned::{NbtList, NbtTag};
 use steel_protocol::packets::game::{
-    CContainerClose, COpenScreen, SContainerButtonClick, SContainerClick, SContainerClose,
-    SContainerSlotStateChanged, SSetCarriedItem, SSetCreativeModeSlot,
+    CContainerClose, CMerchantOffers, COpenScreen, SContainerButtonClick, SContainerClick,
+    SContainerClose, SContainerSlotStateChanged, SSelectTrade, SSetBeacon, SSetCarriedItem,
+    SSetCreativeModeSlot,
 };
 use steel_registry::enchantment_effect::EnchantmentEffectComponent;
 use steel_registry::item_stack::ItemStack;
+use steel_registry::stat::{CustomStat, Stat};
+use steel_registry::trading::MerchantOffer;
 use steel_registry::{REGISTRY, RegistryExt, items::ItemRef};
 use steel_utils::types::{GameType, InteractionHand};
-use steel_utils::{DowncastType, DowncastTypeKey};
+use steel_utils::{Downcast as _, DowncastType, DowncastTypeKey};
 
 use crate::{
     entity::{Entity, entities::ItemEntity},
     inventory::{
         MenuProvider,
+        beacon_menu::BeaconMenu,
         container::{Container, clear_or_count_matching_stack},
         equipment::{EntityEquipment, EquipmentSlot},
         inventory_menu::InventoryMenu,
         lock::{ContainerId, ContainerLockGuard},
         menu::Menu,
+        merchant_menu::MerchantMenu,
         slot::Slot,
     },
     player::Player,
@@ -60,7 +65,7 @@ const fn slot_to_equipment(slot: usize) -> Option<EquipmentSlot> {
     }
 }
 
-const fn hand_to_equipment_slot(hand: InteractionHand) -> EquipmentSlot {
+pub(crate) const fn hand_to_equipment_slot(hand: InteractionHand) -> EquipmentSlot {
     match hand {
         InteractionHand::MainHand => EquipmentSlot::MainHand,
         InteractionHand::OffHand => EquipmentSlot::OffHand,
@@ -417,34 +422,38 @@ impl PlayerInventory {
     }
 
     /// Damages the held item and records inventory/equipment changes.
+    ///
+    /// Returns true if the item broke.
     pub fn hurt_item_in_hand(
         &mut self,
         hand: InteractionHand,
         amount: i32,
         has_infinite_materials: bool,
-    ) {
+    ) -> bool {
         if amount <= 0 || self.get_item_in_hand(hand).is_empty() {
-            return;
+            return false;
         }
 
         let slot = hand_to_equipment_slot(hand);
-        let changed = {
+        let (broke, changed) = {
             let item = self.get_item_in_hand_mut(hand);
             let previous_item = item.item();
             let previous_count = item.count();
             let previous_damage = item.get_damage_value();
 
-            let _ = item.hurt_and_break(amount, has_infinite_materials);
+            let broke = item.hurt_and_break(amount, has_infinite_materials);
 
-            item.item() != previous_item
+            let changed = item.item() != previous_item
                 || item.count() != previous_count
-                || item.get_damage_value() != previous_damage
+                || item.get_damage_value() != previous_damage;
+            (broke, changed)
         };
 
         if changed {
             self.refresh_player_equipment_attribute_modifiers(slot);
             self.set_changed();
         }
+        broke
     }
 
     /// Mutates the held item and records inventory/equipment changes if its stack state changed.
@@ -767,19 +776,32 @@ impl Player {
     }
 
     /// Handles a container button click packet (e.g., enchanting table buttons).
+    ///
+    /// Based on Java's `ServerGamePacketListenerImpl::handleContainerButtonClick`.
     pub fn handle_container_button_click(&self, packet: SContainerButtonClick) {
-        log::debug!(
-            "Player {} clicked button {} in container {}",
-            self.gameprofile.name,
-            packet.button_id,
-            packet.container_id
-        );
-        // TODO: Implement container button click handling
-        // This is used for things like:
-        // - Enchanting table level selection
-        // - Stonecutter recipe selection
-        // - Loom pattern selection
-        // - Lectern page turning
+        if self.game_mode() == GameType::Spectator {
+            return;
+        }
+
+        let mut open_menu_guard = self.open_menu.lock();
+        let Some(menu) = open_menu_guard.as_mut() else {
+            return;
+        };
+        if i32::from(menu.container_id()) != packet.container_id {
+            return;
+        }
+
+        if !menu.still_valid(self) {
+            log::debug!(
+                "Player {} interacted with invalid menu",
+                self.gameprofile.name
+            );
+            return;
+        }
+
+        if menu.click_menu_button(self, packet.button_id) {
+            menu.behavior_mut().broadcast_changes(&self.connection);
+        }
     }
 
     /// Handles a container click packet (slot interaction).
@@ -845,6 +867,7 @@ impl Player {
             has_infinite_materials,
             self,
         );
+        menu.slots_changed(self);
 
         for (slot, hash) in packet.changed_slots {
             menu.behavior_mut().set_remote_slot(slot as usize, hash);
@@ -900,15 +923,34 @@ impl Player {
     }
 
     /// Handles a creative mode slot set packet.
+    ///
+    /// Based on Java's `ServerGamePacketListenerImpl::handleSetCreativeModeSlot`.
     pub fn handle_set_creative_mode_slot(&self, packet: SSetCreativeModeSlot) {
-        if self.game_mode() != GameType::Creative {
+        if !self.has_infinite_materials() {
             return;
         }
 
         let drop = packet.slot_num < 0;
         let item_stack = packet.item_stack;
+        let valid_slot = (1..=45).contains(&packet.slot_num);
+
+        // Vanilla reads this stack through `ItemStack.validatedStreamCodec`, so the
+        // client cannot create stacks that break container, bundle or stack limits.
+        if let Err(error) = item_stack.validate_strict() {
+            log::warn!(
+                "Player {} sent an invalid creative mode item: {error}",
+                self.gameprofile.name
+            );
+            if valid_slot {
+                // The client already shows the rejected stack, so resend the real one.
+                let mut menu = self.inventory_menu.lock();
+                menu.behavior_mut()
+                    .set_remote_slot_known(packet.slot_num as usize, &item_stack);
+                menu.behavior_mut().broadcast_changes(&self.connection);
+            }
+            return;
+        }
 
-        let valid_slot = packet.slot_num >= 1 && packet.slot_num <= 45;
         let valid_data = item_stack.is_empty() || item_stack.count <= item_stack.max_stack_size();
 
         if valid_slot && valid_data {
@@ -926,14 +968,21 @@ impl Player {
                 .set_remote_slot_known(slot_index, &item_stack);
             menu.behavior_mut().broadcast_changes(&self.connection);
         } else if drop && valid_data {
-            // TODO: Implement drop spam throttling
-            // For now, just drop the item
-            if !item_stack.is_empty() {
-                // TODO: Actually drop the item into the world
-                log::debug!(
-                    "Player {} would drop {:?} in creative mode",
-                    self.gameprofile.name,
-                    item_stack
+            let under_threshold = {
+                let mut throttler = self.drop_spam_throttler.lock();
+                let under_threshold = throttler.is_under_threshold();
+                if under_threshold {
+                    throttler.increment();
+                }
+                under_threshold
+            };
+
+            if under_threshold {
+                let _ = self.drop_item(item_stack, true, false);
+            } else {
+                log::warn!(
+                    "Player {} was dropping items too fast in creative mode, ignoring.",
+                    self.gameprofile.name
                 );
             }
         }
@@ -941,16 +990,26 @@ impl Player {
 
     /// Sets selected slot
     pub fn handle_set_carried_item(&self, packet: SSetCarriedItem) {
-        if self
-            .inventory
-            .lock()
-            .try_set_selected_slot_from_packet(packet.slot)
-            .is_err()
-        {
+        let (previous_slot, result) = {
+            let mut inventory = self.inventory.lock();
+            let previous_slot = inventory.get_selected_slot();
+            (
+                previous_slot,
+                inventory.try_set_selected_slot_from_packet(packet.slot),
+            )
+        };
+        if result.is_err() {
             log::warn!(
                 "{} tried to set an invalid carried item",
                 self.gameprofile.name
             );
+            return;
+        }
+
+        if i16::from(previous_slot) != packet.slot
+            && self.used_item_hand() == Some(InteractionHand::MainHand)
+        {
+            self.stop_using_item();
         }
     }
 
@@ -980,7 +1039,7 @@ impl Player {
         self.do_close_container();
 
         let container_id = self.next_container_counter();
-        let mut menu = provider.create(container_id);
+        let mut menu = provider.create(container_id, self);
 
         self.send_packet(COpenScreen {
             container_id: i32::from(menu.container_id()),
@@ -994,6 +1053,88 @@ impl Player {
         *self.open_menu.lock() = Some(menu);
     }
 
+    /// Sends the offers of the merchant whose menu is currently open.
+    ///
+    /// Based on Java's `ServerPlayer::sendMerchantOffers`.
+    pub fn send_merchant_offers(
+        &self,
+        offers: Vec<MerchantOffer>,
+        villager_level: i32,
+        villager_xp: i32,
+        show_progress: bool,
+        can_restock: bool,
+    ) {
+        let Some(container_id) = self
+            .open_menu
+            .lock()
+            .as_ref()
+            .map(|menu| menu.container_id())
+        else {
+            return;
+        };
+
+        self.send_packet(CMerchantOffers {
+            container_id: i32::from(container_id),
+            offers,
+            villager_level,
+            villager_xp,
+            show_progress,
+            can_restock,
+        });
+    }
+
+    /// Handles the player selecting an offer in the merchant menu.
+    ///
+    /// Based on Java's `ServerGamePacketListenerImpl::handleSelectTrade`.
+    pub fn handle_select_trade(&self, packet: SSelectTrade) {
+        let Ok(selected) = usize::try_from(packet.item) else {
+            return;
+        };
+
+        let mut open_menu = self.open_menu.lock();
+        let Some(menu) = open_menu
+            .as_mut()
+            .and_then(|menu| menu.downcast_mut::<MerchantMenu>())
+        else {
+            return;
+        };
+
+        if !menu.still_valid(self) {
+            log::debug!(
+                "Player {} interacted with invalid menu",
+                self.gameprofile.name
+            );
+            return;
+        }
+
+        menu.set_selection_hint(selected);
+        menu.try_move_items(selected);
+        menu.behavior_mut().broadcast_changes(&self.connection);
+    }
+
+    /// Handles the beacon power selection packet.
+    ///
+    /// Based on Java's `ServerGamePacketListenerImpl::handleSetBeaconPacket`.
+    pub fn handle_set_beacon(&self, packet: SSetBeacon) {
+        let mut open_menu = self.open_menu.lock();
+        let Some(menu) = open_menu
+            .as_mut()
+            .and_then(|menu| menu.downcast_mut::<BeaconMenu>())
+        else {
+            return;
+        };
+
+        if !menu.still_valid(self) {
+            log::debug!(
+                "Player {} interacted with invalid menu",
+                self.gameprofile.name
+            );
+            return;
+        }
+
+        menu.update_effects(self, packet.primary, packet.secondary);
+    }
+
     /// Closes the currently open container and returns to the inventory menu.
     ///
     /// Based on Java's `ServerPlayer::closeContainer`.
@@ -1036,6 +1177,7 @@ impl Player {
     pub fn broadcast_inventory_changes(&self) {
         let mut open_menu = self.open_menu.lock();
         if let Some(ref mut menu) = *open_menu {
+            menu.update_data_slots();
             menu.behavior_mut().broadcast_changes(&self.connection);
         } else {
             drop(open_menu);
@@ -1046,6 +1188,23 @@ impl Player {
         }
     }
 
+    /// Resends the open menu, or the inventory, in full. Used when the server
+    /// rejected a change the client already predicted, such as a denied
+    /// placement.
+    pub fn resync_inventory(&self) {
+        let mut open_menu = self.open_menu.lock();
+        if let Some(ref mut menu) = *open_menu {
+            menu.behavior_mut()
+                .send_all_data_to_remote(&self.connection);
+        } else {
+            drop(open_menu);
+            self.inventory_menu
+                .lock()
+                .behavior_mut()
+                .send_all_data_to_remote(&self.connection);
+        }
+    }
+
     /// Removes or counts matching stacks across every location used by vanilla `/clear`.
     pub(crate) fn clear_or_count_matching_items(
         &self,
@@ -1183,6 +1342,8 @@ impl Player {
         };
 
         let spawn_pos = DVec3::new(pos.x, spawn_y, pos.z);
+        let dropped = Stat::dropped(item.item());
+        let count = item.count();
 
         let entity = self
             .get_world()
@@ -1190,6 +1351,8 @@ impl Player {
         entity.set_pickup_delay(40);
         if thrown_from_hand {
             entity.set_thrower(self.gameprofile.id);
+            self.award_stat_amount(dropped, count);
+            self.award_stat(Stat::custom(CustomStat::Drop));
         }
         Some(entity)
     }
@@ -1877,7 +2040,7 @@ mod tests {
         inventory.drain_dirty_equipment_items();
 
         let before = inventory.get_times_changed();
-        inventory.hurt_item_in_hand(InteractionHand::MainHand, 1, false);
+        assert!(!inventory.hurt_item_in_hand(InteractionHand::MainHand, 1, false));
 
         let main_hand = inventory.get_selected_item();
         assert!(main_hand.is(&vanilla_items::SHEARS));
@@ -1890,6 +2053,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hurt_item_in_hand_reports_break() {
+        init_test_registry();
+
+        let mut inventory = PlayerInventory::new(Weak::new());
+        let mut shears = ItemStack::new(&vanilla_items::SHEARS);
+        shears.set_damage_value(shears.get_max_damage() - 1);
+        inventory.set_selected_item(shears);
+
+        assert!(inventory.hurt_item_in_hand(InteractionHand::MainHand, 1, false));
+        assert!(inventory.get_selected_item().is_empty());
+    }
+
     #[test]
     fn hurt_and_convert_item_in_hand_damages_without_breaking() {
         init_test_registry();
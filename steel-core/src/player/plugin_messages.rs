@@ -0,0 +1,168 @@
+//! Plugin messages (custom payloads).
+//!
+//! The client's brand and the channels it announced through
+//! `minecraft:register` are recorded during configuration by the login handler
+//! and during play by the player. Other channels are routed through the
+//! server's [`PluginChannelRegistry`](crate::server::plugin_channels::PluginChannelRegistry).
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use rustc_hash::FxHashSet;
+use steel_protocol::packets::common::{CCustomPayload, SCustomPayload};
+use steel_utils::Identifier;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::{PrefixedRead, PrefixedWrite};
+
+use crate::player::Player;
+
+/// Vanilla `BrandPayload` channel.
+pub const BRAND: Identifier = Identifier::vanilla_static("brand");
+/// Channel clients and servers announce the channels they listen on with.
+pub const REGISTER: Identifier = Identifier::vanilla_static("register");
+/// Channel clients and servers withdraw announced channels with.
+pub const UNREGISTER: Identifier = Identifier::vanilla_static("unregister");
+
+/// Vanilla `BrandPayload` max length.
+const MAX_BRAND_LENGTH: usize = 32767;
+
+/// The brand and channels a client announced.
+#[derive(Debug, Default, Clone)]
+pub struct ClientChannels {
+    brand: Option<String>,
+    channels: FxHashSet<Identifier>,
+}
+
+impl ClientChannels {
+    /// Records a payload on one of the vanilla channels. Returns `false` for
+    /// any other channel.
+    pub fn handle(&mut self, channel: &Identifier, payload: &[u8]) -> bool {
+        if *channel == BRAND {
+            match String::read_prefixed_bound::<VarInt>(&mut Cursor::new(payload), MAX_BRAND_LENGTH)
+            {
+                Ok(brand) => self.brand = Some(brand),
+                Err(error) => log::debug!("Invalid client brand payload: {error}"),
+            }
+        } else if *channel == REGISTER {
+            self.channels.extend(parse_channel_list(payload));
+        } else if *channel == UNREGISTER {
+            for channel in parse_channel_list(payload) {
+                self.channels.remove(&channel);
+            }
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Returns the client brand, such as `vanilla` or `fabric`.
+    #[must_use]
+    pub fn brand(&self) -> Option<&str> {
+        self.brand.as_deref()
+    }
+
+    /// Returns whether the client announced `channel`.
+    #[must_use]
+    pub fn is_registered(&self, channel: &Identifier) -> bool {
+        self.channels.contains(channel)
+    }
+}
+
+/// Parses the NUL-separated channel list of a register or unregister payload,
+/// skipping invalid entries.
+fn parse_channel_list(payload: &[u8]) -> impl Iterator<Item = Identifier> + '_ {
+    payload
+        .split(|&byte| byte == 0)
+        .filter_map(|channel| str::from_utf8(channel).ok()?.parse().ok())
+}
+
+/// Encodes `channels` as a register or unregister payload.
+#[must_use]
+pub fn channel_list_payload(channels: &[Identifier]) -> Box<[u8]> {
+    channels
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\0")
+        .into_bytes()
+        .into_boxed_slice()
+}
+
+/// Encodes `brand` as a vanilla `BrandPayload`.
+#[must_use]
+pub fn brand_payload(brand: &str) -> Box<[u8]> {
+    let mut payload = Vec::with_capacity(brand.len() + 1);
+    if let Err(error) = brand.write_prefixed_bound::<VarInt>(&mut payload, MAX_BRAND_LENGTH) {
+        log::warn!("Could not encode server brand: {error}");
+    }
+    payload.into_boxed_slice()
+}
+
+impl Player {
+    /// Returns the brand the client announced, if any.
+    #[must_use]
+    pub fn client_brand(&self) -> Option<String> {
+        self.client_channels.lock().brand().map(str::to_owned)
+    }
+
+    /// Returns whether the client announced it listens on `channel`.
+    #[must_use]
+    pub fn listens_on(&self, channel: &Identifier) -> bool {
+        self.client_channels.lock().is_registered(channel)
+    }
+
+    /// Carries over the brand and channels recorded while the client was configuring.
+    pub fn set_client_channels(&self, channels: ClientChannels) {
+        *self.client_channels.lock() = channels;
+    }
+
+    /// Sends a plugin message on `channel`.
+    pub fn send_plugin_message(&self, channel: Identifier, payload: impl Into<Box<[u8]>>) {
+        self.send_packet(CCustomPayload::new(channel, payload.into()));
+    }
+
+    /// Vanilla `ServerCommonPacketListenerImpl.handleCustomPayload`, plus
+    /// routing to the server's registered channels.
+    pub fn handle_custom_payload(self: &Arc<Self>, packet: SCustomPayload) {
+        let payload = &packet.payload.0;
+        if self
+            .client_channels
+            .lock()
+            .handle(&packet.identifier, payload)
+        {
+            return;
+        }
+        match self.server().plugin_channels.handler(&packet.identifier) {
+            Some(handler) => handler(self, payload),
+            None => log::debug!(
+                "{} sent a plugin message on unhandled channel {}",
+                self.gameprofile.name,
+                packet.identifier
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brand_round_trips_and_register_tracks_channels() {
+        let mut client = ClientChannels::default();
+        assert!(client.handle(&BRAND, &brand_payload("fabric")));
+        assert_eq!(client.brand(), Some("fabric"));
+
+        let sync = Identifier::new("example", "sync");
+        let chat = Identifier::new("example", "chat");
+        let payload = channel_list_payload(&[sync.clone(), chat.clone()]);
+        assert!(client.handle(&REGISTER, &payload));
+        assert!(client.is_registered(&sync) && client.is_registered(&chat));
+
+        assert!(client.handle(&UNREGISTER, b"example:sync"));
+        assert!(!client.is_registered(&sync));
+        assert!(client.is_registered(&chat));
+
+        assert!(!client.handle(&sync, b""));
+    }
+}
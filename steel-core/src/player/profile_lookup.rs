@@ -2,6 +2,7 @@
 
 use reqwest::{StatusCode, Url};
 use serde::Deserialize;
+use steel_protocol::packets::login::GameProfileProperty;
 use thiserror::Error;
 use tokio::time::{Duration, sleep};
 use uuid::Uuid;
@@ -10,6 +11,8 @@ use super::known_players::KnownPlayer;
 
 const DEFAULT_PROFILE_SERVER: &str =
     "https://api.minecraftservices.com/minecraft/profile/lookup/name";
+const DEFAULT_SESSION_PROFILE_SERVER: &str =
+    "https://sessionserver.mojang.com/session/minecraft/profile";
 const MAX_PROFILE_LOOKUP_ATTEMPTS: usize = 3;
 const PROFILE_LOOKUP_RETRY_DELAY: Duration = Duration::from_millis(750);
 /// Bounds every attempt so suspended administrative commands always release their ordering barrier.
@@ -56,6 +59,11 @@ struct ProfileLookupResponse {
     name: String,
 }
 
+#[derive(Deserialize)]
+struct SessionProfileResponse {
+    properties: Vec<GameProfileProperty>,
+}
+
 /// Resolves one online-mode profile through the configured service.
 ///
 /// The caller handles local caches, offline mode, and name validation first.
@@ -108,6 +116,71 @@ async fn lookup_online_profile_once(
     }
 }
 
+/// Fetches the signed profile properties (skin and cape textures) of an online profile.
+///
+/// Mirrors vanilla `MinecraftSessionService.fetchProfile` with signatures requested,
+/// so the properties can be forwarded to clients unchanged.
+pub async fn fetch_profile_properties(
+    client: &reqwest::Client,
+    uuid: Uuid,
+    name: &str,
+) -> Result<Vec<GameProfileProperty>, ProfileLookupError> {
+    let url = session_profile_url(DEFAULT_SESSION_PROFILE_SERVER, uuid)?;
+    for attempt in 1..=MAX_PROFILE_LOOKUP_ATTEMPTS {
+        let result = fetch_profile_properties_once(client, url.as_str(), name).await;
+        match result {
+            Ok(properties) => return Ok(properties),
+            Err(error @ ProfileLookupError::UnknownPlayer(_)) => return Err(error),
+            Err(error) if attempt == MAX_PROFILE_LOOKUP_ATTEMPTS => return Err(error),
+            Err(_) => sleep(PROFILE_LOOKUP_RETRY_DELAY).await,
+        }
+    }
+    unreachable!("the profile lookup attempt range is non-empty")
+}
+
+async fn fetch_profile_properties_once(
+    client: &reqwest::Client,
+    url: &str,
+    name: &str,
+) -> Result<Vec<GameProfileProperty>, ProfileLookupError> {
+    let response = client
+        .get(url)
+        .timeout(PROFILE_LOOKUP_REQUEST_TIMEOUT)
+        .send()
+        .await
+        .map_err(|source| ProfileLookupError::Request {
+            name: name.to_owned(),
+            source,
+        })?;
+
+    match response.status() {
+        StatusCode::OK => response
+            .json::<SessionProfileResponse>()
+            .await
+            .map(|profile| profile.properties)
+            .map_err(|source| ProfileLookupError::InvalidResponse {
+                name: name.to_owned(),
+                reason: source.to_string(),
+            }),
+        StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => {
+            Err(ProfileLookupError::UnknownPlayer(name.to_owned()))
+        }
+        status => Err(ProfileLookupError::ServiceResponse {
+            name: name.to_owned(),
+            status,
+        }),
+    }
+}
+
+fn session_profile_url(server: &str, uuid: Uuid) -> Result<Url, ProfileLookupError> {
+    let endpoint = format!(
+        "{}/{}?unsigned=false",
+        server.trim_end_matches('/'),
+        uuid.simple()
+    );
+    Url::parse(&endpoint).map_err(|_| ProfileLookupError::InvalidProfileServer(endpoint))
+}
+
 fn profile_lookup_url(
     profile_server: Option<&str>,
     normalized_name: &str,
@@ -142,7 +215,12 @@ mod tests {
 
     use tokio::net::TcpListener;
 
-    use super::{ProfileLookupError, lookup_online_profile_once, profile_lookup_url};
+    use uuid::Uuid;
+
+    use super::{
+        DEFAULT_SESSION_PROFILE_SERVER, ProfileLookupError, lookup_online_profile_once,
+        profile_lookup_url, session_profile_url,
+    };
 
     #[test]
     fn profile_lookup_url_uses_mojangs_default_endpoint() {
@@ -165,6 +243,18 @@ mod tests {
         assert_eq!(url.as_str(), "https://profiles.example.com/lookup/steve");
     }
 
+    #[test]
+    fn session_profile_url_requests_signed_properties() {
+        let uuid = Uuid::from_u128(0x069a_79f4_44e9_4726_a5be_fca9_0e38_aaf5);
+        let Ok(url) = session_profile_url(DEFAULT_SESSION_PROFILE_SERVER, uuid) else {
+            panic!("session profile URL should build");
+        };
+        assert_eq!(
+            url.as_str(),
+            "https://sessionserver.mojang.com/session/minecraft/profile/069a79f444e94726a5befca90e38aaf5?unsigned=false"
+        );
+    }
+
     #[tokio::test]
     async fn nonresponding_profile_service_is_bounded_by_request_timeout() {
         let Ok(listener) = TcpListener::bind("127.0.0.1:0").await else {
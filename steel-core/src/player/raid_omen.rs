@@ -0,0 +1,59 @@
+//! Bad omen turning into raid omen inside villages, and raid omen starting a raid.
+
+use steel_registry::vanilla_mob_effects;
+use steel_utils::BlockPos;
+use steel_utils::types::Difficulty;
+
+use crate::entity::{Entity as _, LivingEntity as _, MobEffectInstance};
+use crate::world::MAX_RAID_OMEN_LEVEL;
+
+use super::Player;
+
+/// Ticks raid omen lasts before the raid starts.
+const RAID_OMEN_DURATION: i32 = 600;
+
+impl Player {
+    /// Returns the position the next raid starts at, vanilla
+    /// `ServerPlayer.getRaidOmenPosition`.
+    #[must_use]
+    pub fn raid_omen_position(&self) -> Option<BlockPos> {
+        *self.raid_omen_position.lock()
+    }
+
+    /// Runs the effect ticks of vanilla `BadOmenMobEffect` and
+    /// `RaidOmenMobEffect`, before effect durations count down.
+    pub(super) fn tick_raid_omen(&self) {
+        if self.is_spectator() {
+            return;
+        }
+        let world = self.get_world();
+
+        if let Some(raid_omen) = self.mob_effect(vanilla_mob_effects::RAID_OMEN)
+            && raid_omen.duration() == 1
+            && let Some(pos) = self.raid_omen_position.lock().take()
+        {
+            world.raids.lock().create_or_extend_raid(&world, self, pos);
+        }
+
+        let Some(bad_omen) = self.mob_effect(vanilla_mob_effects::BAD_OMEN) else {
+            return;
+        };
+        let pos = self.block_position();
+        if world.difficulty() == Difficulty::Peaceful || !world.is_village(pos) {
+            return;
+        }
+        if world
+            .raid_omen_level_at(pos)
+            .is_some_and(|level| level >= MAX_RAID_OMEN_LEVEL)
+        {
+            return;
+        }
+        self.add_mob_effect(MobEffectInstance::with_duration(
+            vanilla_mob_effects::RAID_OMEN,
+            RAID_OMEN_DURATION,
+            bad_omen.amplifier(),
+        ));
+        *self.raid_omen_position.lock() = Some(pos);
+        self.remove_mob_effect(vanilla_mob_effects::BAD_OMEN);
+    }
+}
@@ -0,0 +1,361 @@
+//! Vanilla `ServerRecipeBook`: the recipes a player has unlocked.
+//!
+//! Recipes are unlocked the first time the player holds one of their
+//! ingredients, or when they are crafted.
+
+use rustc_hash::FxHashSet;
+use simdnbt::owned::{NbtCompound, NbtList};
+use steel_protocol::packets::game::{
+    CRecipeBookAdd, CRecipeBookRemove, CRecipeBookSettings, RecipeBookAddEntry, RecipeBookType,
+    SRecipeBookChangeSettings, SRecipeBookSeenRecipe,
+};
+use steel_registry::recipe::CraftingRecipe;
+use steel_registry::{REGISTRY, RegistryEntry as _, RegistryExt};
+use steel_utils::Identifier;
+
+use crate::inventory::container::Container;
+use crate::player::player_data::PersistentRecipeBook;
+use crate::player::{CriteriaTrigger, Player};
+
+/// Vanilla `RecipeBookSettings.TypeSettings`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RecipeBookTypeSettings {
+    pub open: bool,
+    pub filtering: bool,
+}
+
+/// Vanilla `RecipeBookSettings`: open and filtering state per recipe book.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RecipeBookSettings {
+    pub crafting: RecipeBookTypeSettings,
+    pub furnace: RecipeBookTypeSettings,
+    pub blast_furnace: RecipeBookTypeSettings,
+    pub smoker: RecipeBookTypeSettings,
+}
+
+impl RecipeBookSettings {
+    fn get_mut(&mut self, book_type: RecipeBookType) -> &mut RecipeBookTypeSettings {
+        match book_type {
+            RecipeBookType::Crafting => &mut self.crafting,
+            RecipeBookType::Furnace => &mut self.furnace,
+            RecipeBookType::BlastFurnace => &mut self.blast_furnace,
+            RecipeBookType::Smoker => &mut self.smoker,
+        }
+    }
+
+    /// Returns the settings in vanilla stream order.
+    #[must_use]
+    pub const fn to_array(self) -> [bool; 8] {
+        [
+            self.crafting.open,
+            self.crafting.filtering,
+            self.furnace.open,
+            self.furnace.filtering,
+            self.blast_furnace.open,
+            self.blast_furnace.filtering,
+            self.smoker.open,
+            self.smoker.filtering,
+        ]
+    }
+
+    /// Reads settings written by [`Self::to_array`].
+    #[must_use]
+    pub const fn from_array(settings: [bool; 8]) -> Self {
+        let [
+            crafting_open,
+            crafting_filtering,
+            furnace_open,
+            furnace_filtering,
+            blast_furnace_open,
+            blast_furnace_filtering,
+            smoker_open,
+            smoker_filtering,
+        ] = settings;
+        Self {
+            crafting: RecipeBookTypeSettings {
+                open: crafting_open,
+                filtering: crafting_filtering,
+            },
+            furnace: RecipeBookTypeSettings {
+                open: furnace_open,
+                filtering: furnace_filtering,
+            },
+            blast_furnace: RecipeBookTypeSettings {
+                open: blast_furnace_open,
+                filtering: blast_furnace_filtering,
+            },
+            smoker: RecipeBookTypeSettings {
+                open: smoker_open,
+                filtering: smoker_filtering,
+            },
+        }
+    }
+
+    const fn to_packet(self) -> CRecipeBookSettings {
+        CRecipeBookSettings {
+            crafting_open: self.crafting.open,
+            crafting_filtering: self.crafting.filtering,
+            furnace_open: self.furnace.open,
+            furnace_filtering: self.furnace.filtering,
+            blast_furnace_open: self.blast_furnace.open,
+            blast_furnace_filtering: self.blast_furnace.filtering,
+            smoker_open: self.smoker.open,
+            smoker_filtering: self.smoker.filtering,
+        }
+    }
+}
+
+/// Vanilla `ServerRecipeBook`.
+#[derive(Debug, Default)]
+pub struct ServerRecipeBook {
+    known: FxHashSet<Identifier>,
+    highlight: FxHashSet<Identifier>,
+    settings: RecipeBookSettings,
+    /// Items whose recipes have already been unlocked this session.
+    obtained_items: FxHashSet<usize>,
+    /// Inventory change counter at the last unlock scan.
+    scanned_inventory_changes: Option<u32>,
+}
+
+impl ServerRecipeBook {
+    /// Returns whether the recipe with `key` is unlocked.
+    #[must_use]
+    pub fn contains(&self, key: &Identifier) -> bool {
+        self.known.contains(key)
+    }
+
+    /// Snapshots the recipe book for persistence.
+    #[must_use]
+    pub fn to_persistent(&self) -> PersistentRecipeBook {
+        PersistentRecipeBook {
+            recipes: self.known.iter().map(ToString::to_string).collect(),
+            to_be_displayed: self.highlight.iter().map(ToString::to_string).collect(),
+            settings: self.settings.to_array(),
+        }
+    }
+
+    /// Replaces the recipe book with saved state, dropping recipes that no longer exist.
+    pub fn load_persistent(&mut self, data: &PersistentRecipeBook) {
+        let parse = |keys: &[String]| -> FxHashSet<Identifier> {
+            keys.iter()
+                .filter_map(|key| key.parse::<Identifier>().ok())
+                .filter(|key| REGISTRY.recipes.by_key(key).is_some())
+                .collect()
+        };
+        self.known = parse(&data.recipes);
+        self.highlight = parse(&data.to_be_displayed);
+        self.highlight.retain(|key| self.known.contains(key));
+        self.settings = RecipeBookSettings::from_array(data.settings);
+        self.obtained_items.clear();
+        self.scanned_inventory_changes = None;
+    }
+
+    /// Vanilla `ServerRecipeBook.pack`, used for command NBT.
+    #[must_use]
+    pub fn to_vanilla_nbt(&self) -> NbtCompound {
+        let keys = |set: &FxHashSet<Identifier>| {
+            NbtList::from(set.iter().map(ToString::to_string).collect::<Vec<_>>())
+        };
+        let mut nbt = NbtCompound::new();
+        nbt.insert("recipes", keys(&self.known));
+        nbt.insert("toBeDisplayed", keys(&self.highlight));
+        let settings = self.settings;
+        for (prefix, book) in [
+            ("isGui", settings.crafting),
+            ("isFurnaceGui", settings.furnace),
+            ("isBlastingFurnaceGui", settings.blast_furnace),
+            ("isSmokerGui", settings.smoker),
+        ] {
+            nbt.insert(format!("{prefix}Open"), i8::from(book.open));
+            nbt.insert(
+                format!("{prefix}FilteringCraftable"),
+                i8::from(book.filtering),
+            );
+        }
+        nbt
+    }
+}
+
+impl Player {
+    /// Returns whether the player has unlocked the recipe with `key`.
+    #[must_use]
+    pub fn knows_recipe(&self, key: &Identifier) -> bool {
+        self.recipe_book.lock().contains(key)
+    }
+
+    /// Vanilla `ServerPlayer.awardRecipes`.
+    ///
+    /// Returns how many of the recipes were newly unlocked.
+    pub fn award_recipes(&self, recipes: &[CraftingRecipe]) -> usize {
+        let mut entries = Vec::new();
+        let mut unlocked = Vec::new();
+        {
+            let mut book = self.recipe_book.lock();
+            for recipe in recipes {
+                if !book.known.insert(recipe.id().clone()) {
+                    continue;
+                }
+                book.highlight.insert(recipe.id().clone());
+                if let Some(contents) = recipe.display_entry() {
+                    entries.push(RecipeBookAddEntry {
+                        contents,
+                        notification: recipe.show_notification(),
+                        highlight: true,
+                    });
+                }
+                unlocked.push(recipe.id());
+            }
+        }
+        for recipe in unlocked {
+            self.trigger_criteria(CriteriaTrigger::RecipeUnlocked(recipe));
+        }
+
+        let count = entries.len();
+        if count > 0 {
+            self.send_packet(CRecipeBookAdd {
+                entries,
+                replace: false,
+            });
+        }
+        count
+    }
+
+    /// Vanilla `ServerPlayer.resetRecipes`.
+    ///
+    /// Returns how many of the recipes were known and removed.
+    pub fn reset_recipes(&self, recipes: &[CraftingRecipe]) -> usize {
+        let mut removed = Vec::new();
+        {
+            let mut book = self.recipe_book.lock();
+            for recipe in recipes {
+                if !book.known.remove(recipe.id()) {
+                    continue;
+                }
+                book.highlight.remove(recipe.id());
+                if let Some(id) = recipe.display_entry().map(|entry| entry.id) {
+                    removed.push(id);
+                }
+            }
+        }
+
+        let count = removed.len();
+        if count > 0 {
+            self.send_packet(CRecipeBookRemove { recipes: removed });
+        }
+        count
+    }
+
+    /// Vanilla `ServerRecipeBook.sendInitialRecipeBook`.
+    pub fn send_initial_recipe_book(&self) {
+        let (settings, entries) = {
+            let book = self.recipe_book.lock();
+            let entries = book
+                .known
+                .iter()
+                .filter_map(|key| REGISTRY.recipes.by_key(key))
+                .filter_map(|recipe| {
+                    Some(RecipeBookAddEntry {
+                        contents: recipe.display_entry()?,
+                        notification: false,
+                        highlight: book.highlight.contains(recipe.id()),
+                    })
+                })
+                .collect();
+            (book.settings, entries)
+        };
+
+        self.send_packet(settings.to_packet());
+        self.send_packet(CRecipeBookAdd {
+            entries,
+            replace: true,
+        });
+    }
+
+    /// Vanilla `ServerGamePacketListenerImpl.handleRecipeBookSeenRecipePacket`.
+    pub fn handle_recipe_book_seen_recipe(&self, packet: SRecipeBookSeenRecipe) {
+        let Ok(id) = usize::try_from(packet.recipe) else {
+            return;
+        };
+        let Some(recipe) = REGISTRY.recipes.by_id(id) else {
+            return;
+        };
+        self.recipe_book.lock().highlight.remove(recipe.id());
+    }
+
+    /// Vanilla `ServerGamePacketListenerImpl.handleRecipeBookChangeSettingsPacket`.
+    pub fn handle_recipe_book_change_settings(&self, packet: SRecipeBookChangeSettings) {
+        let mut book = self.recipe_book.lock();
+        *book.settings.get_mut(packet.book_type) = RecipeBookTypeSettings {
+            open: packet.is_open,
+            filtering: packet.is_filtering,
+        };
+    }
+
+    /// Unlocks the recipes using items the player obtained since the last check.
+    ///
+    /// Stands in for the vanilla recipe advancements, which unlock a recipe
+    /// through an `inventory_changed` trigger on its ingredients.
+    pub(super) fn tick_recipe_unlocks(&self) {
+        let (times_changed, stacks) = {
+            let inventory = self.inventory.lock();
+            let times_changed = inventory.get_times_changed();
+            if self.recipe_book.lock().scanned_inventory_changes == Some(times_changed) {
+                return;
+            }
+            let stacks: Vec<_> = (0..inventory.get_container_size())
+                .map(|slot| inventory.get_item(slot))
+                .filter(|stack| !stack.is_empty())
+                .cloned()
+                .collect();
+            (times_changed, stacks)
+        };
+
+        let new_stacks: Vec<_> = {
+            let mut book = self.recipe_book.lock();
+            book.scanned_inventory_changes = Some(times_changed);
+            stacks
+                .into_iter()
+                .filter(|stack| book.obtained_items.insert(stack.item().id()))
+                .collect()
+        };
+        if new_stacks.is_empty() {
+            return;
+        }
+
+        let recipes: Vec<_> = (0..REGISTRY.recipes.len())
+            .filter_map(|id| REGISTRY.recipes.by_id(id))
+            .filter(|recipe| !self.knows_recipe(recipe.id()))
+            .filter(|recipe| {
+                recipe
+                    .ingredients()
+                    .iter()
+                    .any(|ingredient| new_stacks.iter().any(|stack| ingredient.test(stack)))
+            })
+            .copied()
+            .collect();
+        self.award_recipes(&recipes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recipe_book_settings_roundtrip_in_vanilla_order() {
+        let mut settings = RecipeBookSettings::default();
+        *settings.get_mut(RecipeBookType::Furnace) = RecipeBookTypeSettings {
+            open: true,
+            filtering: false,
+        };
+        settings.smoker.filtering = true;
+
+        let array = settings.to_array();
+
+        assert_eq!(
+            array,
+            [false, false, true, false, false, false, false, true]
+        );
+        assert_eq!(RecipeBookSettings::from_array(array), settings);
+    }
+}
@@ -0,0 +1,72 @@
+//! Server resource pack responses.
+//!
+//! The latest action each client reported for the configured packs, recorded
+//! during configuration by the login handler and during play by the player.
+
+use rustc_hash::FxHashMap;
+use steel_protocol::packets::common::{ResourcePackAction, SResourcePack};
+use steel_utils::translations;
+use uuid::Uuid;
+
+use crate::config::RuntimeConfig;
+use crate::player::Player;
+
+/// The latest action a client reported for each server resource pack.
+#[derive(Debug, Default, Clone)]
+pub struct ResourcePackStatuses {
+    statuses: FxHashMap<Uuid, ResourcePackAction>,
+}
+
+impl ResourcePackStatuses {
+    /// Records the client's latest action for pack `id`.
+    pub fn record(&mut self, id: Uuid, action: ResourcePackAction) {
+        self.statuses.insert(id, action);
+    }
+
+    /// Returns the latest action reported for pack `id`.
+    #[must_use]
+    pub fn get(&self, id: Uuid) -> Option<ResourcePackAction> {
+        self.statuses.get(&id).copied()
+    }
+
+    /// Returns whether the client finished handling every configured pack.
+    #[must_use]
+    pub fn all_terminal(&self, config: &RuntimeConfig) -> bool {
+        config.resource_packs.iter().all(|pack| {
+            self.get(pack.id())
+                .is_some_and(ResourcePackAction::is_terminal)
+        })
+    }
+}
+
+/// Returns whether `packet` declines a required pack, which disconnects the client.
+#[must_use]
+pub fn declines_required_pack(config: &RuntimeConfig, packet: &SResourcePack) -> bool {
+    packet.action == ResourcePackAction::Declined && config.is_resource_pack_required(packet.id)
+}
+
+impl Player {
+    /// Returns the latest action the client reported for resource pack `id`.
+    #[must_use]
+    pub fn resource_pack_status(&self, id: Uuid) -> Option<ResourcePackAction> {
+        self.resource_packs.lock().get(id)
+    }
+
+    /// Carries over the responses recorded while the client was configuring.
+    pub fn set_resource_pack_statuses(&self, statuses: ResourcePackStatuses) {
+        *self.resource_packs.lock() = statuses;
+    }
+
+    /// Vanilla `ServerCommonPacketListenerImpl.handleResourcePackResponse`.
+    pub fn handle_resource_pack(&self, packet: SResourcePack) {
+        if declines_required_pack(&self.config.load(), &packet) {
+            log::info!(
+                "Disconnecting {} due to resource pack {} rejection",
+                self.gameprofile.name,
+                packet.id
+            );
+            self.disconnect(translations::MULTIPLAYER_REQUIRED_TEXTURE_PROMPT_DISCONNECT.msg());
+        }
+        self.resource_packs.lock().record(packet.id, packet.action);
+    }
+}
@@ -269,4 +269,22 @@ impl MessageCache {
         }
         indexed.into_boxed_slice()
     }
+
+    /// Packs a signature for the recipient, as an id into this cache when the
+    /// client has it and as the full signature otherwise.
+    ///
+    /// Vanilla `MessageSignatureCache.pack`.
+    #[must_use]
+    pub fn pack(&self, signature: &[u8]) -> PreviousMessageEntry {
+        match self.full_cache.iter().position(|s| **s == *signature) {
+            Some(index) => PreviousMessageEntry {
+                id: 1 + index as i32,
+                signature: None,
+            },
+            None => PreviousMessageEntry {
+                id: 0,
+                signature: Some(signature.into()),
+            },
+        }
+    }
 }
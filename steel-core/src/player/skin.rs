@@ -0,0 +1,21 @@
+//! Skin and cape textures sent with the player's profile.
+
+use steel_protocol::packets::login::GameProfileProperty;
+
+use super::Player;
+
+impl Player {
+    /// Returns the profile properties (such as the signed `textures` property)
+    /// sent to clients in player info updates.
+    #[must_use]
+    pub fn profile_properties(&self) -> Vec<GameProfileProperty> {
+        self.profile_properties.lock().clone()
+    }
+
+    /// Replaces the profile properties without notifying clients.
+    ///
+    /// Use `Server::refresh_player_profile` to also resend the player to everyone.
+    pub(crate) fn set_profile_properties(&self, properties: Vec<GameProfileProperty>) {
+        *self.profile_properties.lock() = properties;
+    }
+}
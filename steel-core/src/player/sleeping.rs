@@ -0,0 +1,346 @@
+//! Bed sleeping and personal respawn points.
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use steel_protocol::packets::game::{AnimateAction, CAnimate};
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::{BlockStateProperties, Direction};
+use steel_registry::entity_data::EntityPose;
+use steel_registry::entity_type::MobCategory;
+use steel_registry::stat::{CustomStat, Stat};
+use steel_utils::types::{GameType, UpdateFlags};
+use steel_utils::{BlockPos, BlockStateId, WorldAabb, translations};
+use text_components::TextComponent;
+
+use crate::entity::{Entity as _, LivingEntity as _};
+use crate::level_data::RespawnData;
+use crate::world::World;
+
+use super::movement::wrap_degrees;
+use super::{CriteriaTrigger, Player};
+
+/// Ticks a player must sleep before counting toward the night skip.
+const SLEEP_DURATION: i32 = 100;
+
+/// Vanilla `ServerPlayer.RespawnConfig`: a personal respawn point set by a bed
+/// or respawn anchor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RespawnConfig {
+    /// Dimension, block position and facing of the respawn point.
+    pub respawn_data: RespawnData,
+    /// Whether the point was set by a command and is used without a bed.
+    pub forced: bool,
+}
+
+impl RespawnConfig {
+    /// Returns whether both configs point at the same block in the same dimension.
+    #[must_use]
+    pub fn is_same_position(&self, other: Option<&Self>) -> bool {
+        other.is_some_and(|other| self.respawn_data.global_pos == other.respawn_data.global_pos)
+    }
+}
+
+/// Vanilla `Player.BedSleepingProblem`: why a player could not lie down.
+#[derive(Debug, Clone)]
+pub enum BedSleepingProblem {
+    /// The dimension's bed rule refuses sleeping right now.
+    NotPossibleHere(Option<TextComponent>),
+    /// The player is too far from the bed.
+    TooFarAway,
+    /// A solid block sits on top of the bed.
+    Obstructed,
+    /// Monsters are nearby.
+    NotSafe,
+    /// The player is already asleep or dead.
+    Other,
+}
+
+impl BedSleepingProblem {
+    /// Returns the overlay message shown for this problem, if any.
+    #[must_use]
+    pub fn message(self) -> Option<TextComponent> {
+        match self {
+            Self::NotPossibleHere(message) => message,
+            Self::TooFarAway => Some(TextComponent::translated(
+                translations::BLOCK_MINECRAFT_BED_TOO_FAR_AWAY.msg(),
+            )),
+            Self::Obstructed => Some(TextComponent::translated(
+                translations::BLOCK_MINECRAFT_BED_OBSTRUCTED.msg(),
+            )),
+            Self::NotSafe => Some(TextComponent::translated(
+                translations::BLOCK_MINECRAFT_BED_NOT_SAFE.msg(),
+            )),
+            Self::Other => None,
+        }
+    }
+}
+
+fn is_bed(state: BlockStateId) -> bool {
+    state
+        .try_get_value(&BlockStateProperties::BED_PART)
+        .is_some()
+}
+
+fn bottom_center(pos: BlockPos) -> DVec3 {
+    DVec3::new(
+        f64::from(pos.x()) + 0.5,
+        f64::from(pos.y()),
+        f64::from(pos.z()) + 0.5,
+    )
+}
+
+impl Player {
+    /// Returns the player's personal respawn point, if one is set.
+    #[must_use]
+    pub fn respawn_config(&self) -> Option<RespawnConfig> {
+        self.respawn_config.lock().clone()
+    }
+
+    /// Sets the personal respawn point, telling the player when it moved.
+    ///
+    /// Vanilla `ServerPlayer.setRespawnPosition`.
+    pub fn set_respawn_position(&self, config: Option<RespawnConfig>, show_message: bool) {
+        let mut current = self.respawn_config.lock();
+        if show_message
+            && let Some(config) = &config
+            && !config.is_same_position(current.as_ref())
+        {
+            self.send_message(&TextComponent::translated(
+                translations::BLOCK_MINECRAFT_SET_SPAWN.msg(),
+            ));
+        }
+        *current = config;
+    }
+
+    /// Returns how many ticks the player has been asleep, or counts down after waking.
+    #[must_use]
+    pub fn sleep_timer(&self) -> i32 {
+        self.tick_state.lock().sleep_counter()
+    }
+
+    /// Returns whether the player has slept long enough to count toward the night skip.
+    ///
+    /// Vanilla `Player.isSleepingLongEnough`.
+    #[must_use]
+    pub fn is_sleeping_long_enough(&self) -> bool {
+        self.is_sleeping() && self.sleep_timer() >= SLEEP_DURATION
+    }
+
+    /// Tries to lie down in the bed whose head is at `pos`.
+    ///
+    /// Vanilla `ServerPlayer.startSleepInBed`.
+    pub fn start_sleep_in_bed(&self, pos: BlockPos) -> Result<(), BedSleepingProblem> {
+        if self.is_sleeping() || !self.is_alive() {
+            return Err(BedSleepingProblem::Other);
+        }
+
+        let world = self.get_world();
+        let facing = world
+            .get_block_state(pos)
+            .get_value(&BlockStateProperties::HORIZONTAL_FACING);
+        let can_sleep = world.bed_rule_can_sleep();
+        let can_set_spawn = world.bed_rule_can_set_spawn();
+        if !can_sleep && !can_set_spawn {
+            return Err(BedSleepingProblem::NotPossibleHere(
+                world.bed_rule_error_message(),
+            ));
+        }
+        if !self.bed_in_range(pos, facing) {
+            return Err(BedSleepingProblem::TooFarAway);
+        }
+        if Self::bed_blocked(&world, pos, facing) {
+            return Err(BedSleepingProblem::Obstructed);
+        }
+
+        if can_set_spawn {
+            let (yaw, pitch) = self.rotation();
+            self.set_respawn_position(
+                Some(RespawnConfig {
+                    respawn_data: RespawnData::of(world.key.clone(), pos, yaw, pitch),
+                    forced: false,
+                }),
+                true,
+            );
+        }
+        if !can_sleep {
+            return Err(BedSleepingProblem::NotPossibleHere(
+                world.bed_rule_error_message(),
+            ));
+        }
+
+        if self.game_mode() != GameType::Creative && Self::monsters_near_bed(&world, pos) {
+            return Err(BedSleepingProblem::NotSafe);
+        }
+
+        self.start_sleeping(&world, pos);
+        self.tick_state.lock().set_sleep_counter(0);
+        self.award_stat(Stat::custom(CustomStat::SleepInBed));
+        self.trigger_criteria(CriteriaTrigger::SleptInBed);
+
+        if !world.can_sleep_through_nights() {
+            self.display_client_message(
+                &TextComponent::translated(translations::SLEEP_NOT_POSSIBLE.msg()),
+                true,
+            );
+        }
+        world.update_sleeping_player_list();
+        Ok(())
+    }
+
+    /// Vanilla `Player.startSleeping`, which resets the time since rest before
+    /// `LivingEntity.startSleeping`.
+    fn start_sleeping(&self, world: &Arc<World>, pos: BlockPos) {
+        self.reset_stat(Stat::custom(CustomStat::TimeSinceRest));
+        if self.is_passenger() {
+            self.stop_riding();
+        }
+
+        let state = world.get_block_state(pos);
+        if is_bed(state) {
+            world.set_block(
+                pos,
+                state.set_value(&BlockStateProperties::OCCUPIED, true),
+                UpdateFlags::UPDATE_ALL,
+            );
+        }
+
+        self.set_pose(EntityPose::Sleeping);
+        let bed_pos = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 0.6875,
+            f64::from(pos.z()) + 0.5,
+        );
+        if let Err(error) = self.try_set_position(bed_pos) {
+            log::debug!("Failed to move player {} into bed: {error}", self.id());
+        }
+        self.set_sleeping_pos(pos);
+        self.set_velocity(DVec3::ZERO);
+    }
+
+    /// Wakes the player and frees the bed.
+    ///
+    /// `forceful` skips the short wake-up fade, and `update_level_list`
+    /// recounts the world's sleepers. Vanilla `ServerPlayer.stopSleepInBed`.
+    pub fn stop_sleep_in_bed(&self, forceful: bool, update_level_list: bool) {
+        let world = self.get_world();
+        if self.is_sleeping() {
+            world.broadcast_to_entity_trackers(
+                self.id(),
+                CAnimate::new(self.id(), AnimateAction::WakeUp),
+                None,
+            );
+            self.send_packet(CAnimate::new(self.id(), AnimateAction::WakeUp));
+        }
+
+        self.stop_sleeping();
+        if update_level_list {
+            world.update_sleeping_player_list();
+        }
+        self.tick_state
+            .lock()
+            .set_sleep_counter(if forceful { 0 } else { SLEEP_DURATION });
+
+        let (yaw, pitch) = self.rotation();
+        if let Err(error) = self.teleport(self.position(), yaw, pitch) {
+            log::debug!("Failed to sync player {} after waking: {error}", self.id());
+        }
+    }
+
+    /// Frees the bed and stands the player up beside it.
+    ///
+    /// Vanilla `LivingEntity.stopSleeping`.
+    pub(super) fn leave_bed(&self) {
+        if let Some(bed_pos) = self.sleeping_pos() {
+            let world = self.get_world();
+            let state = world.get_block_state(bed_pos);
+            if is_bed(state) {
+                world.set_block(
+                    bed_pos,
+                    state.set_value(&BlockStateProperties::OCCUPIED, false),
+                    UpdateFlags::UPDATE_ALL,
+                );
+                // TODO: Search for a free stand-up spot like vanilla `BedBlock.findStandUpPosition`.
+                let above = bed_pos.above();
+                let stand_up = DVec3::new(
+                    f64::from(above.x()) + 0.5,
+                    f64::from(above.y()) + 0.1,
+                    f64::from(above.z()) + 0.5,
+                );
+                let look = (bottom_center(bed_pos) - stand_up).normalize_or_zero();
+                let yaw = wrap_degrees(look.z.atan2(look.x).to_degrees() as f32 - 90.0);
+                if let Err(error) = self.try_set_position(stand_up) {
+                    log::debug!("Failed to stand player {} up from bed: {error}", self.id());
+                }
+                self.set_rotation((yaw, 0.0));
+            }
+        }
+
+        self.set_pose(EntityPose::Standing);
+        self.clear_sleeping_pos();
+    }
+
+    /// Advances the sleep counter and wakes the player when the bed is gone
+    /// or sleeping stops being allowed.
+    ///
+    /// The sleeping part of vanilla `Player.tick`.
+    pub(super) fn tick_sleeping(&self) {
+        let sleeping = self.is_sleeping();
+        self.tick_state.lock().advance_sleep_counter(sleeping);
+        if !sleeping {
+            return;
+        }
+
+        let world = self.get_world();
+        let bed_exists = self
+            .sleeping_pos()
+            .is_some_and(|pos| is_bed(world.get_block_state(pos)));
+        if !bed_exists || !world.bed_rule_can_sleep() {
+            self.stop_sleep_in_bed(false, true);
+        }
+    }
+
+    /// Vanilla `ServerPlayer.bedInRange`.
+    fn bed_in_range(&self, pos: BlockPos, facing: Direction) -> bool {
+        self.is_reachable_bed_block(pos)
+            || self.is_reachable_bed_block(facing.opposite().relative(pos))
+    }
+
+    /// Vanilla `ServerPlayer.isReachableBedBlock`.
+    fn is_reachable_bed_block(&self, pos: BlockPos) -> bool {
+        let bed = bottom_center(pos);
+        let position = self.position();
+        (position.x - bed.x).abs() <= 3.0
+            && (position.y - bed.y).abs() <= 2.0
+            && (position.z - bed.z).abs() <= 3.0
+    }
+
+    /// Vanilla `ServerPlayer.bedBlocked`.
+    fn bed_blocked(world: &World, pos: BlockPos, facing: Direction) -> bool {
+        let above = pos.above();
+        world.get_block_state(above).is_suffocating()
+            || world
+                .get_block_state(facing.opposite().relative(above))
+                .is_suffocating()
+    }
+
+    /// Returns whether monsters near the bed keep the player awake.
+    fn monsters_near_bed(world: &World, pos: BlockPos) -> bool {
+        let center = bottom_center(pos);
+        let area = WorldAabb::new(
+            center.x - 8.0,
+            center.y - 5.0,
+            center.z - 8.0,
+            center.x + 8.0,
+            center.y + 5.0,
+            center.z + 8.0,
+        );
+        // TODO: Let neutral monsters like zombified piglins only count while angry
+        // (vanilla `Monster.isPreventingPlayerRest`).
+        !world
+            .get_entities_in_aabb_matching(&area, |entity| {
+                entity.entity_type().mob_category == MobCategory::Monster
+            })
+            .is_empty()
+    }
+}
@@ -0,0 +1,278 @@
+//! Vanilla `ServerStatsCounter`: per-player statistics.
+//!
+//! Stats are stored in vanilla's `stats/<uuid>.json` layout and only the
+//! values changed since the last request are sent to the client.
+
+use std::io;
+
+use glam::DVec3;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::{Map, Value};
+use steel_protocol::packets::game::{AwardedStat, CAwardStats};
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::stat::{CustomStat, Stat};
+use steel_registry::vanilla_entities;
+use steel_utils::Identifier;
+
+use crate::entity::{Entity, LivingEntity};
+use crate::player::{CriteriaTrigger, Player};
+
+/// Vanilla `ServerStatsCounter`.
+#[derive(Debug, Default)]
+pub struct StatsCounter {
+    stats: FxHashMap<Stat, i32>,
+    /// Stats changed since they were last sent to the client.
+    dirty: FxHashSet<Stat>,
+}
+
+impl StatsCounter {
+    /// Returns the current value of `stat`.
+    #[must_use]
+    pub fn get_value(&self, stat: Stat) -> i32 {
+        self.stats.get(&stat).copied().unwrap_or(0)
+    }
+
+    /// Vanilla `ServerStatsCounter.setValue`.
+    pub fn set_value(&mut self, stat: Stat, value: i32) {
+        self.stats.insert(stat, value);
+        self.dirty.insert(stat);
+    }
+
+    /// Vanilla `StatsCounter.increment`, saturating at `i32::MAX`.
+    pub fn increment(&mut self, stat: Stat, amount: i32) {
+        let value = self.get_value(stat).saturating_add(amount);
+        self.set_value(stat, value);
+    }
+
+    /// Marks every stat as changed so the next request sends all of them.
+    pub fn mark_all_dirty(&mut self) {
+        self.dirty.extend(self.stats.keys().copied());
+    }
+
+    /// Vanilla `ServerStatsCounter.getDirty`, clearing the changed set.
+    fn take_dirty(&mut self) -> Vec<AwardedStat> {
+        self.dirty
+            .drain()
+            .map(|stat| AwardedStat {
+                stat,
+                value: self.stats.get(&stat).copied().unwrap_or(0),
+            })
+            .collect()
+    }
+
+    /// Vanilla `ServerStatsCounter.toJson`.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut types: FxHashMap<Identifier, Map<String, Value>> = FxHashMap::default();
+        for (stat, value) in &self.stats {
+            let Some(value_key) = stat.value_key() else {
+                continue;
+            };
+            types
+                .entry(Identifier::vanilla_static(stat.stat_type.key()))
+                .or_default()
+                .insert(value_key.to_string(), Value::from(*value));
+        }
+
+        let stats: Map<String, Value> = types
+            .into_iter()
+            .map(|(stat_type, values)| (stat_type.to_string(), Value::Object(values)))
+            .collect();
+        let mut root = Map::new();
+        root.insert("stats".to_owned(), Value::Object(stats));
+        Value::Object(root).to_string()
+    }
+
+    /// Vanilla `ServerStatsCounter.parseLocal`. Unknown stats are skipped.
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        let root: Value = serde_json::from_str(json)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        let mut counter = Self::default();
+        let Some(types) = root.get("stats").and_then(Value::as_object) else {
+            return Ok(counter);
+        };
+
+        for (stat_type, values) in types {
+            let Ok(stat_type) = stat_type.parse::<Identifier>() else {
+                continue;
+            };
+            let Some(values) = values.as_object() else {
+                continue;
+            };
+            for (value_key, value) in values {
+                let Ok(value_key) = value_key.parse::<Identifier>() else {
+                    continue;
+                };
+                let (Some(stat), Some(value)) = (
+                    Stat::from_keys(&stat_type, &value_key),
+                    value.as_i64().and_then(|value| i32::try_from(value).ok()),
+                ) else {
+                    log::warn!("Skipping invalid statistic {stat_type} {value_key}");
+                    continue;
+                };
+                counter.stats.insert(stat, value);
+            }
+        }
+        counter.mark_all_dirty();
+        Ok(counter)
+    }
+}
+
+impl Player {
+    /// Vanilla `Player.awardStat`.
+    pub fn award_stat(&self, stat: Stat) {
+        self.award_stat_amount(stat, 1);
+    }
+
+    /// Vanilla `Player.awardStat(Stat, int)`.
+    pub fn award_stat_amount(&self, stat: Stat, amount: i32) {
+        self.stats.lock().increment(stat, amount);
+    }
+
+    /// Vanilla `Player.resetStat`.
+    pub fn reset_stat(&self, stat: Stat) {
+        self.stats.lock().set_value(stat, 0);
+    }
+
+    /// Returns the current value of `stat`.
+    #[must_use]
+    pub fn stat_value(&self, stat: Stat) -> i32 {
+        self.stats.lock().get_value(stat)
+    }
+
+    /// Replaces the player's stats with ones loaded from storage.
+    pub(crate) fn load_stats(&self, stats: StatsCounter) {
+        *self.stats.lock() = stats;
+    }
+
+    /// Serializes the player's stats for storage.
+    #[must_use]
+    pub(crate) fn stats_json(&self) -> String {
+        self.stats.lock().to_json()
+    }
+
+    /// Vanilla `ServerStatsCounter.sendStats`, replying to a stats request.
+    pub fn send_stats(&self) {
+        let mut stats = self.stats.lock().take_dirty();
+        stats.retain(|awarded| awarded.stat.is_synced());
+        self.send_packet(CAwardStats { stats });
+    }
+
+    /// Vanilla `Player.awardKillScore`.
+    pub fn award_kill_score(&self, killed: EntityTypeRef) {
+        self.trigger_criteria(CriteriaTrigger::PlayerKilledEntity(killed));
+        self.award_stat(Stat::killed(killed));
+        self.award_stat(Stat::custom(if killed == &vanilla_entities::PLAYER {
+            CustomStat::PlayerKills
+        } else {
+            CustomStat::MobKills
+        }));
+    }
+
+    /// The per-tick stats of vanilla `Player.tick`.
+    pub(super) fn tick_stats(&self) {
+        let mut stats = self.stats.lock();
+        stats.increment(Stat::custom(CustomStat::PlayTime), 1);
+        stats.increment(Stat::custom(CustomStat::TotalWorldTime), 1);
+        if self.is_alive() {
+            stats.increment(Stat::custom(CustomStat::TimeSinceDeath), 1);
+        }
+        if self.is_discrete() {
+            stats.increment(Stat::custom(CustomStat::CrouchTime), 1);
+        }
+        if !self.is_sleeping() {
+            stats.increment(Stat::custom(CustomStat::TimeSinceRest), 1);
+        }
+    }
+
+    /// Vanilla `ServerPlayer.checkMovementStatistics`.
+    pub(super) fn check_movement_statistics(&self, delta: DVec3) {
+        const MIN_DELTA: f64 = 1.0E-5;
+
+        if self.is_passenger()
+            || (delta.x.abs() < MIN_DELTA && delta.y.abs() < MIN_DELTA && delta.z.abs() < MIN_DELTA)
+        {
+            return;
+        }
+
+        let distance = centimeters(delta.length());
+        let horizontal = centimeters(delta.x.hypot(delta.z));
+        let (stat, amount) = if self.is_swimming() {
+            (CustomStat::SwimOneCm, distance)
+        } else if self.is_eye_in_water() {
+            (CustomStat::WalkUnderWaterOneCm, distance)
+        } else if self.is_in_water() {
+            (CustomStat::WalkOnWaterOneCm, horizontal)
+        } else if self.on_climbable() {
+            if delta.y <= 0.0 {
+                return;
+            }
+            (CustomStat::ClimbOneCm, centimeters(delta.y))
+        } else if self.on_ground() {
+            let stat = if self.is_sprinting() {
+                CustomStat::SprintOneCm
+            } else if self.is_crouching() {
+                CustomStat::CrouchOneCm
+            } else {
+                CustomStat::WalkOneCm
+            };
+            (stat, horizontal)
+        } else if self.is_fall_flying() {
+            (CustomStat::AviateOneCm, distance)
+        } else {
+            if horizontal <= 25 {
+                return;
+            }
+            (CustomStat::FlyOneCm, horizontal)
+        };
+
+        if amount > 0 {
+            self.award_stat_amount(Stat::custom(stat), amount);
+        }
+    }
+}
+
+/// Vanilla `Math.round(distance * 100.0F)` for the `*_one_cm` stats.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "movement per packet is far below i32::MAX centimeters"
+)]
+pub(super) fn centimeters(distance: f64) -> i32 {
+    (distance * 100.0).round() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_registry::test_support::init_test_registry;
+    use steel_registry::vanilla_blocks;
+
+    use super::*;
+
+    #[test]
+    fn stats_json_roundtrips_in_vanilla_layout() {
+        init_test_registry();
+        let mut counter = StatsCounter::default();
+        counter.increment(Stat::custom(CustomStat::Jump), 3);
+        counter.increment(Stat::mined(&vanilla_blocks::STONE), 2);
+
+        let json = counter.to_json();
+        let parsed: Value = serde_json::from_str(&json).expect("stats json should parse");
+        assert_eq!(
+            parsed["stats"]["minecraft:custom"]["minecraft:jump"],
+            Value::from(3)
+        );
+
+        let loaded = StatsCounter::from_json(&json).expect("stats json should load");
+        assert_eq!(loaded.get_value(Stat::custom(CustomStat::Jump)), 3);
+        assert_eq!(loaded.get_value(Stat::mined(&vanilla_blocks::STONE)), 2);
+    }
+
+    #[test]
+    fn sending_stats_clears_dirty_set() {
+        let mut counter = StatsCounter::default();
+        counter.increment(Stat::custom(CustomStat::PlayTime), 1);
+
+        assert_eq!(counter.take_dirty().len(), 1);
+        assert!(counter.take_dirty().is_empty());
+    }
+}
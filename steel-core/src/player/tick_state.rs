@@ -6,6 +6,7 @@ pub(super) struct PlayerTickState {
     take_xp_delay: i32,
     last_level_up_time: i32,
     ack_block_changes_up_to: i32,
+    sleep_counter: i32,
 }
 
 impl PlayerTickState {
@@ -17,6 +18,7 @@ impl PlayerTickState {
             take_xp_delay: 0,
             last_level_up_time: 0,
             ack_block_changes_up_to: -1,
+            sleep_counter: 0,
         }
     }
 
@@ -73,6 +75,29 @@ impl PlayerTickState {
         self.ack_block_changes_up_to = -1;
         sequence
     }
+
+    #[must_use]
+    pub(super) const fn sleep_counter(self) -> i32 {
+        self.sleep_counter
+    }
+
+    pub(super) const fn set_sleep_counter(&mut self, counter: i32) {
+        self.sleep_counter = counter;
+    }
+
+    /// Counts up to 100 while asleep, then for 10 more ticks after waking.
+    pub(super) const fn advance_sleep_counter(&mut self, sleeping: bool) {
+        if sleeping {
+            if self.sleep_counter < 100 {
+                self.sleep_counter += 1;
+            }
+        } else if self.sleep_counter > 0 {
+            self.sleep_counter += 1;
+            if self.sleep_counter >= 110 {
+                self.sleep_counter = 0;
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -146,4 +171,24 @@ mod tests {
         assert_eq!(state.take_ack_block_changes_up_to(), 5);
         assert_eq!(state.take_ack_block_changes_up_to(), -1);
     }
+
+    #[test]
+    fn sleep_counter_caps_while_sleeping_and_resets_after_waking() {
+        let mut state = PlayerTickState::new();
+        for _ in 0..150 {
+            state.advance_sleep_counter(true);
+        }
+        assert_eq!(state.sleep_counter(), 100);
+
+        for _ in 0..9 {
+            state.advance_sleep_counter(false);
+        }
+        assert_eq!(state.sleep_counter(), 109);
+
+        state.advance_sleep_counter(false);
+        assert_eq!(state.sleep_counter(), 0);
+
+        state.advance_sleep_counter(false);
+        assert_eq!(state.sleep_counter(), 0);
+    }
 }
@@ -0,0 +1,201 @@
+//! Item use over time: eating, drinking and other held-down right clicks.
+//!
+//! Mirrors the `useItem` half of vanilla `LivingEntity` for players.
+
+use steel_protocol::packets::game::CEntityEvent;
+use steel_registry::data_components::vanilla_components::{CONSUMABLE, USE_REMAINDER};
+use steel_registry::item_stack::ItemStack;
+use steel_registry::vanilla_game_events;
+use steel_utils::entity_events::EntityStatus;
+use steel_utils::types::InteractionHand;
+
+use crate::behavior::{ITEM_BEHAVIORS, consumable};
+use crate::entity::Entity;
+use crate::player::Player;
+
+/// Vanilla `LivingEntity.LIVING_ENTITY_FLAG_IS_USING`.
+const FLAG_IS_USING: i8 = 1;
+/// Vanilla `LivingEntity.LIVING_ENTITY_FLAG_OFF_HAND`.
+const FLAG_OFF_HAND: i8 = 2;
+
+/// The item a player is currently using.
+pub(super) struct UsingItem {
+    hand: InteractionHand,
+    stack: ItemStack,
+    remaining_ticks: i32,
+}
+
+impl Player {
+    /// Returns the hand holding the item being used, if any.
+    #[must_use]
+    pub fn used_item_hand(&self) -> Option<InteractionHand> {
+        self.using_item.lock().as_ref().map(|using| using.hand)
+    }
+
+    /// Returns vanilla `LivingEntity.getUseItemRemainingTicks`.
+    #[must_use]
+    pub fn use_item_remaining_ticks(&self) -> i32 {
+        self.using_item
+            .lock()
+            .as_ref()
+            .map_or(0, |using| using.remaining_ticks)
+    }
+
+    /// Vanilla `LivingEntity.startUsingItem`.
+    pub fn start_using_item(&self, hand: InteractionHand) {
+        let stack = self.inventory.lock().get_item_in_hand(hand).clone();
+        if stack.is_empty() || self.used_item_hand().is_some() {
+            return;
+        }
+
+        let remaining_ticks = ITEM_BEHAVIORS
+            .get_behavior(stack.item())
+            .get_use_duration(&stack);
+        *self.using_item.lock() = Some(UsingItem {
+            hand,
+            stack,
+            remaining_ticks,
+        });
+
+        let flags = match hand {
+            InteractionHand::MainHand => FLAG_IS_USING,
+            InteractionHand::OffHand => FLAG_IS_USING | FLAG_OFF_HAND,
+        };
+        self.set_living_entity_flags(flags);
+        self.game_event(&vanilla_game_events::ITEM_INTERACT_START);
+    }
+
+    /// Vanilla `LivingEntity.stopUsingItem`.
+    pub fn stop_using_item(&self) {
+        let was_using = self.using_item.lock().take().is_some();
+        self.set_living_entity_flags(0);
+        if was_using {
+            self.game_event(&vanilla_game_events::ITEM_INTERACT_FINISH);
+        }
+    }
+
+    /// Vanilla `LivingEntity.releaseUsingItem`, sent when the player lets go of use.
+    pub fn release_using_item(&self) {
+        let using = self
+            .using_item
+            .lock()
+            .as_ref()
+            .map(|using| (using.stack.clone(), using.remaining_ticks));
+        if let Some((stack, remaining_ticks)) = using {
+            ITEM_BEHAVIORS
+                .get_behavior(stack.item())
+                .release_using(&stack, self, remaining_ticks);
+        }
+        self.stop_using_item();
+    }
+
+    /// Vanilla `LivingEntity.updatingUsingItem`.
+    pub(super) fn tick_using_item(&self) {
+        let Some(hand) = self.used_item_hand() else {
+            return;
+        };
+
+        let held = self.inventory.lock().get_item_in_hand(hand).clone();
+        let remaining_ticks = {
+            let mut using = self.using_item.lock();
+            let Some(using) = using.as_mut() else {
+                return;
+            };
+            if ItemStack::is_same_item(&held, &using.stack) {
+                using.stack = held;
+                Some(using.remaining_ticks)
+            } else {
+                None
+            }
+        };
+        let Some(remaining_ticks) = remaining_ticks else {
+            self.stop_using_item();
+            return;
+        };
+
+        // Vanilla `LivingEntity.triggerItemUseEffects`
+        if let Some(consumable) = self
+            .using_item
+            .lock()
+            .as_ref()
+            .and_then(|using| using.stack.get(CONSUMABLE).cloned())
+            && consumable::should_emit_particles_and_sounds(&consumable, remaining_ticks)
+        {
+            consumable::emit_sounds(&consumable, self);
+        }
+
+        let finished = {
+            let mut using = self.using_item.lock();
+            using.as_mut().is_some_and(|using| {
+                using.remaining_ticks -= 1;
+                using.remaining_ticks == 0
+            })
+        };
+        if finished {
+            self.complete_using_item();
+        }
+    }
+
+    /// Vanilla `ServerPlayer.completeUsingItem`.
+    fn complete_using_item(&self) {
+        let Some((hand, stack)) = self
+            .using_item
+            .lock()
+            .as_ref()
+            .map(|using| (using.hand, using.stack.clone()))
+        else {
+            return;
+        };
+
+        let held = self.inventory.lock().get_item_in_hand(hand).clone();
+        if !ItemStack::matches(&held, &stack) {
+            self.release_using_item();
+            return;
+        }
+
+        self.send_packet(CEntityEvent {
+            entity_id: self.id(),
+            event: EntityStatus::UseItemComplete,
+        });
+
+        let result = ITEM_BEHAVIORS
+            .get_behavior(stack.item())
+            .finish_using_item(stack.clone(), self);
+        let result = self.apply_after_use_component_side_effects(result, &stack);
+        self.inventory.lock().set_item_in_hand(hand, result);
+        self.stop_using_item();
+    }
+
+    /// Vanilla `ItemStack.applyAfterUseComponentSideEffects`.
+    ///
+    /// Converts a used-up stack into its `use_remainder` and starts its `use_cooldown`.
+    #[must_use]
+    pub fn apply_after_use_component_side_effects(
+        &self,
+        stack: ItemStack,
+        before_use: &ItemStack,
+    ) -> ItemStack {
+        let mut result = stack;
+        if let Some(use_remainder) = before_use.get(USE_REMAINDER)
+            && !self.has_infinite_materials()
+            && result.count() < before_use.count()
+        {
+            let remainder = use_remainder.convert_into().create();
+            if result.is_empty() {
+                result = remainder;
+            } else {
+                self.add_item_or_drop(remainder);
+            }
+        }
+        self.apply_item_use_cooldown(before_use);
+        result
+    }
+
+    fn set_living_entity_flags(&self, flags: i8) {
+        self.entity_data
+            .lock()
+            .living_entity_mut()
+            .living_entity_flags
+            .set(flags);
+    }
+}
@@ -0,0 +1,184 @@
+//! Per-player warning level raised by sculk shriekers.
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use steel_registry::vanilla_entities;
+use steel_utils::{BlockPos, WorldAabb};
+
+use crate::entity::{Entity as _, LivingEntity as _};
+use crate::world::World;
+
+use super::Player;
+
+/// Ticks between automatic warning level decreases.
+const DECREASE_WARNING_LEVEL_EVERY_INTERVAL: i32 = 12000;
+/// Warning level at which a shrieker summons a warden.
+pub const MAX_WARNING_LEVEL: i32 = 4;
+/// Block distance within which players share a shrieker warning.
+const PLAYER_SEARCH_RADIUS: f64 = 16.0;
+/// Edge length of the box in which an existing warden suppresses warnings.
+const WARNING_CHECK_DIAMETER: f64 = 48.0;
+/// Ticks after a warning before the level can rise again.
+const DEFAULT_WARNING_LEVEL_COOLDOWN: i32 = 200;
+
+/// Vanilla `WardenSpawnTracker`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WardenSpawnTracker {
+    /// Ticks since the warning level last rose.
+    pub ticks_since_last_warning: i32,
+    /// Current warning level, `0..=MAX_WARNING_LEVEL`.
+    pub warning_level: i32,
+    /// Ticks until the warning level can rise again.
+    pub cooldown_ticks: i32,
+}
+
+impl WardenSpawnTracker {
+    /// Decays the warning level and counts down the cooldown.
+    pub fn tick(&mut self) {
+        if self.ticks_since_last_warning >= DECREASE_WARNING_LEVEL_EVERY_INTERVAL {
+            self.decrease_warning_level();
+            self.ticks_since_last_warning = 0;
+        } else {
+            self.ticks_since_last_warning += 1;
+        }
+        if self.cooldown_ticks > 0 {
+            self.cooldown_ticks -= 1;
+        }
+    }
+
+    /// Returns whether a recent warning blocks the level from rising.
+    #[must_use]
+    pub const fn on_cooldown(&self) -> bool {
+        self.cooldown_ticks > 0
+    }
+
+    /// Raises the warning level unless the tracker is on cooldown.
+    pub fn increase_warning_level(&mut self) {
+        if !self.on_cooldown() {
+            self.ticks_since_last_warning = 0;
+            self.cooldown_ticks = DEFAULT_WARNING_LEVEL_COOLDOWN;
+            self.set_warning_level(self.warning_level + 1);
+        }
+    }
+
+    /// Lowers the warning level by one.
+    pub fn decrease_warning_level(&mut self) {
+        self.set_warning_level(self.warning_level - 1);
+    }
+
+    fn set_warning_level(&mut self, warning_level: i32) {
+        self.warning_level = warning_level.clamp(0, MAX_WARNING_LEVEL);
+    }
+
+    /// Raises the shared warning level of `player` and everyone near `pos`.
+    ///
+    /// Returns the new level, or `None` when a warden is already nearby or
+    /// any affected player is on cooldown. Vanilla `WardenSpawnTracker.tryWarn`.
+    pub fn try_warn(world: &World, pos: BlockPos, player: &Arc<Player>) -> Option<i32> {
+        let center = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 0.5,
+            f64::from(pos.z()) + 0.5,
+        );
+        if Self::has_nearby_warden(world, center) {
+            return None;
+        }
+
+        let mut players = Vec::new();
+        world.players.iter_players(|_, nearby| {
+            if !nearby.is_spectator()
+                && nearby.is_alive()
+                && nearby.position().distance_squared(center)
+                    < PLAYER_SEARCH_RADIUS * PLAYER_SEARCH_RADIUS
+            {
+                players.push(Arc::clone(nearby));
+            }
+            true
+        });
+        if !players.iter().any(|nearby| Arc::ptr_eq(nearby, player)) {
+            players.push(Arc::clone(player));
+        }
+
+        let trackers: Vec<_> = players
+            .iter()
+            .map(|nearby| nearby.warden_spawn_tracker())
+            .collect();
+        if trackers.iter().any(Self::on_cooldown) {
+            return None;
+        }
+        let mut tracker = trackers
+            .into_iter()
+            .max_by_key(|tracker| tracker.warning_level)?;
+        tracker.increase_warning_level();
+        for nearby in &players {
+            nearby.set_warden_spawn_tracker(tracker);
+        }
+        Some(tracker.warning_level)
+    }
+
+    fn has_nearby_warden(world: &World, center: DVec3) -> bool {
+        let half = WARNING_CHECK_DIAMETER / 2.0;
+        let area = WorldAabb::new(
+            center.x - half,
+            center.y - half,
+            center.z - half,
+            center.x + half,
+            center.y + half,
+            center.z + half,
+        );
+        !world
+            .get_entities_in_aabb_matching(&area, |entity| {
+                entity.entity_type() == &vanilla_entities::WARDEN
+            })
+            .is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warning_level_rises_once_per_cooldown() {
+        let mut tracker = WardenSpawnTracker::default();
+        tracker.increase_warning_level();
+        tracker.increase_warning_level();
+        assert_eq!(tracker.warning_level, 1);
+        assert!(tracker.on_cooldown());
+
+        for _ in 0..DEFAULT_WARNING_LEVEL_COOLDOWN {
+            tracker.tick();
+        }
+        assert!(!tracker.on_cooldown());
+        tracker.increase_warning_level();
+        assert_eq!(tracker.warning_level, 2);
+    }
+
+    #[test]
+    fn warning_level_is_clamped() {
+        let mut tracker = WardenSpawnTracker::default();
+        tracker.decrease_warning_level();
+        assert_eq!(tracker.warning_level, 0);
+        for _ in 0..10 {
+            tracker.cooldown_ticks = 0;
+            tracker.increase_warning_level();
+        }
+        assert_eq!(tracker.warning_level, MAX_WARNING_LEVEL);
+    }
+
+    #[test]
+    fn warning_level_decays_after_interval() {
+        let mut tracker = WardenSpawnTracker {
+            warning_level: 2,
+            ..WardenSpawnTracker::default()
+        };
+        for _ in 0..DECREASE_WARNING_LEVEL_EVERY_INTERVAL {
+            tracker.tick();
+        }
+        assert_eq!(tracker.warning_level, 2);
+        tracker.tick();
+        assert_eq!(tracker.warning_level, 1);
+        assert_eq!(tracker.ticks_since_last_warning, 0);
+    }
+}
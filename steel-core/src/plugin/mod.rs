@@ -0,0 +1,779 @@
+//! Plugins loaded from the plugin directory.
+//!
+//! With the `native-plugins` feature, a plugin can be a `cdylib` built against
+//! the same Steel version and toolchain as the server. It exports a
+//! [`PluginDeclaration`] named `STEEL_PLUGIN`, usually through
+//! [`declare_plugin!`](crate::declare_plugin). Trait objects only have a stable
+//! layout within one compiler build, so the ABI, Steel and rustc versions are
+//! checked before anything else in the library is touched.
+//!
+//! With the `wasm` feature, plugins can also be WebAssembly modules run in a
+//! sandbox, see [`wasm`].
+//!
+//! [`Plugin::on_load`] runs before the server is built and is the only place
+//! commands can be registered. [`Plugin::on_enable`] runs once the worlds are
+//! ready; the plugin can keep the server and spawn [`jobs`](crate::server::jobs)
+//! on it. [`Plugin::on_disable`] runs first during shutdown. Hooks run on a
+//! snapshot of the plugin list, so they may call back into the
+//! [`PluginManager`].
+//!
+//! With the `scripting` feature, Rhai scripts run as plugins too, see [`script`].
+
+#[cfg(feature = "scripting")]
+pub mod script;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+use std::collections::BTreeMap;
+use std::env::consts::DLL_EXTENSION;
+use std::ffi::{CStr, c_char};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::{fs, io, mem};
+
+#[cfg(feature = "native-plugins")]
+use libloading::Library;
+use serde::Deserialize;
+use steel_utils::locks::SyncMutex;
+use text_components::TextComponent;
+use thiserror::Error;
+
+use crate::behavior::{BlockInteraction, InteractionResult};
+use crate::command::CommandRegistry;
+use crate::player::Player;
+use crate::server::Server;
+use crate::server::chat::OutgoingChat;
+
+/// Version of the [`PluginDeclaration`] layout and entry point contract.
+pub const PLUGIN_ABI_VERSION: u32 = 2;
+
+/// The Steel version plugins must be built against.
+pub const STEEL_VERSION: &CStr =
+    match CStr::from_bytes_with_nul(concat!(env!("CARGO_PKG_VERSION"), "\0").as_bytes()) {
+        Ok(version) => version,
+        Err(_) => panic!("the package version must not contain a nul byte"),
+    };
+
+/// The `rustc --version` of the compiler that built Steel, which plugins must
+/// be built with too.
+pub const RUSTC_VERSION: &CStr =
+    match CStr::from_bytes_with_nul(concat!(env!("STEEL_RUSTC_VERSION"), "\0").as_bytes()) {
+        Ok(version) => version,
+        Err(_) => panic!("the rustc version must not contain a nul byte"),
+    };
+
+/// Symbol name of the exported [`PluginDeclaration`].
+#[cfg(feature = "native-plugins")]
+const DECLARATION_SYMBOL: &[u8] = b"STEEL_PLUGIN\0";
+
+/// File extension of WebAssembly plugins.
+const WASM_EXTENSION: &str = "wasm";
+
+/// File extension of script plugins.
+const SCRIPT_EXTENSION: &str = "rhai";
+
+/// How a plugin is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginRuntime {
+    /// A shared library loaded into the server process.
+    Native,
+    /// A WebAssembly module run in a sandbox.
+    Wasm,
+    /// A Rhai script run with an operation budget.
+    Script,
+}
+
+/// Plugin loading settings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PluginsConfig {
+    /// Runtime per plugin, keyed by file name without extension.
+    pub runtimes: BTreeMap<String, PluginRuntime>,
+    /// Script operations each script may run per tick, across all its hooks.
+    pub script_operations_per_tick: u64,
+}
+
+impl Default for PluginsConfig {
+    fn default() -> Self {
+        Self {
+            runtimes: BTreeMap::new(),
+            script_operations_per_tick: 100_000,
+        }
+    }
+}
+
+/// A native plugin.
+pub trait Plugin: Send + Sync {
+    /// Name shown in `/plugins` and the log.
+    fn name(&self) -> &str;
+
+    /// Version shown in `/plugins`.
+    fn version(&self) -> &str;
+
+    /// Called before the server is built. Commands can only be registered here.
+    ///
+    /// A plugin that fails here is not enabled, but commands it registered
+    /// before failing stay registered.
+    fn on_load(&mut self, _commands: &mut CommandRegistry) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Called once the worlds are loaded, before players can join.
+    fn on_enable(&self, _server: &Arc<Server>) {}
+
+    /// Called at the start of shutdown, before players are kicked and the
+    /// worlds are saved.
+    fn on_disable(&self, _server: &Arc<Server>) {}
+
+    /// Called every server tick while enabled.
+    fn on_tick(&self, _server: &Arc<Server>, _tick_count: u64) {}
+
+    /// Called when a player has joined. Returning a message replaces the
+    /// vanilla join message.
+    fn on_player_join(&self, _player: &Arc<Player>) -> Option<TextComponent> {
+        None
+    }
+
+    /// Called when a player is leaving, before the leave message is sent.
+    fn on_player_leave(&self, _player: &Arc<Player>) {}
+
+    /// Called when a player sends a chat message, before it is broadcast.
+    /// Plugins run in load order and see the changes of earlier plugins.
+    fn on_chat(&self, _player: &Arc<Player>, _chat: &mut OutgoingChat) {}
+
+    /// Called for text a player writes on a sign or in a book. Returning text
+    /// stores it as the filtered version, which players with text filtering
+    /// enabled see instead. Plugins run in load order and each gets the text
+    /// filtered by the ones before it.
+    fn on_filter_text(&self, _player: &Player, _text: &str) -> Option<String> {
+        None
+    }
+
+    /// Called when a player uses an item on a block, after the protections and
+    /// before block and item behaviors. A result that consumes the action ends
+    /// the interaction; `Fail` denies it and resyncs the client.
+    fn on_use_item_on(&self, _interaction: &BlockInteraction<'_>) -> InteractionResult {
+        InteractionResult::Pass
+    }
+}
+
+/// The entry point a plugin library exports as `STEEL_PLUGIN`.
+#[repr(C)]
+pub struct PluginDeclaration {
+    /// Must equal [`PLUGIN_ABI_VERSION`].
+    pub abi_version: u32,
+    /// Must equal [`STEEL_VERSION`].
+    pub steel_version: *const c_char,
+    /// Must equal [`RUSTC_VERSION`].
+    pub rustc_version: *const c_char,
+    /// Creates the plugin as a leaked `Box<Box<dyn Plugin>>`.
+    pub create: unsafe extern "C" fn() -> *mut Box<dyn Plugin>,
+}
+
+// SAFETY: The declaration is immutable and only points to static strings and a function.
+unsafe impl Sync for PluginDeclaration {}
+
+impl PluginDeclaration {
+    /// Checks that the plugin was built for this server, before `create` is
+    /// called and any of the plugin's trait objects are touched.
+    ///
+    /// # Safety
+    ///
+    /// If `abi_version` matches, `steel_version` and `rustc_version` must point
+    /// to nul-terminated strings.
+    #[cfg(feature = "native-plugins")]
+    unsafe fn check(&self) -> Result<(), PluginError> {
+        if self.abi_version != PLUGIN_ABI_VERSION {
+            return Err(PluginError::AbiVersion {
+                found: self.abi_version,
+            });
+        }
+        // SAFETY: The ABI versions match, so the caller guarantees the string.
+        let steel_version = unsafe { CStr::from_ptr(self.steel_version) };
+        if steel_version != STEEL_VERSION {
+            return Err(PluginError::SteelVersion {
+                found: steel_version.to_string_lossy().into_owned(),
+            });
+        }
+        // SAFETY: The ABI versions match, so the caller guarantees the string.
+        let rustc_version = unsafe { CStr::from_ptr(self.rustc_version) };
+        if rustc_version != RUSTC_VERSION {
+            return Err(PluginError::RustcVersion {
+                found: rustc_version.to_string_lossy().into_owned(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Exports a plugin type from a `cdylib`.
+///
+/// `$constructor` is called once when the library is loaded.
+///
+/// ```ignore
+/// steel_core::declare_plugin!(MyPlugin::default);
+/// ```
+#[macro_export]
+macro_rules! declare_plugin {
+    ($constructor:expr) => {
+        #[unsafe(no_mangle)]
+        pub static STEEL_PLUGIN: $crate::plugin::PluginDeclaration =
+            $crate::plugin::PluginDeclaration {
+                abi_version: $crate::plugin::PLUGIN_ABI_VERSION,
+                steel_version: $crate::plugin::STEEL_VERSION.as_ptr(),
+                rustc_version: $crate::plugin::RUSTC_VERSION.as_ptr(),
+                create: {
+                    unsafe extern "C" fn create()
+                    -> *mut ::std::boxed::Box<dyn $crate::plugin::Plugin> {
+                        let plugin: ::std::boxed::Box<dyn $crate::plugin::Plugin> =
+                            ::std::boxed::Box::new($constructor());
+                        ::std::boxed::Box::into_raw(::std::boxed::Box::new(plugin))
+                    }
+                    create
+                },
+            };
+    };
+}
+
+/// Failure to load a plugin library.
+#[derive(Debug, Error)]
+pub enum PluginError {
+    /// The library could not be opened or has no `STEEL_PLUGIN` export.
+    #[cfg(feature = "native-plugins")]
+    #[error("failed to load library: {0}")]
+    Library(#[from] libloading::Error),
+    /// The plugin was built for a different entry point contract.
+    #[error("plugin ABI version {found} does not match {PLUGIN_ABI_VERSION}")]
+    AbiVersion {
+        /// The ABI version the plugin declares.
+        found: u32,
+    },
+    /// The plugin was built against a different Steel version.
+    #[error("plugin was built for Steel {found}, this server runs {}", STEEL_VERSION.to_string_lossy())]
+    SteelVersion {
+        /// The Steel version the plugin declares.
+        found: String,
+    },
+    /// The plugin was built with a different compiler.
+    #[error("plugin was built with {found}, this server was built with {}", RUSTC_VERSION.to_string_lossy())]
+    RustcVersion {
+        /// The rustc version the plugin declares.
+        found: String,
+    },
+    /// The plugin constructor returned null.
+    #[error("plugin constructor returned no plugin")]
+    Constructor,
+    /// The configured runtime has no plugin file.
+    #[error("no {0:?} plugin file found")]
+    MissingFile(PluginRuntime),
+    /// The server was built without the feature for this runtime.
+    #[error("this server was built without {0:?} plugin support")]
+    Unsupported(PluginRuntime),
+    /// The WebAssembly module could not be compiled or initialized.
+    #[cfg(feature = "wasm")]
+    #[error("invalid WebAssembly plugin: {0:#}")]
+    Wasm(wasmtime::Error),
+    /// The script could not be compiled or its top level failed.
+    #[cfg(feature = "scripting")]
+    #[error("invalid script: {0}")]
+    Script(String),
+}
+
+/// A plugin as listed by `/plugins`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginInfo {
+    /// The plugin name.
+    pub name: String,
+    /// The plugin version.
+    pub version: String,
+    /// Whether the plugin is running.
+    pub enabled: bool,
+}
+
+#[derive(Default)]
+struct PluginFiles {
+    native: Option<PathBuf>,
+    wasm: Option<PathBuf>,
+    script: Option<PathBuf>,
+}
+
+impl PluginFiles {
+    const fn default_runtime(&self) -> PluginRuntime {
+        if self.wasm.is_some() {
+            PluginRuntime::Wasm
+        } else if self.script.is_some() {
+            PluginRuntime::Script
+        } else {
+            PluginRuntime::Native
+        }
+    }
+}
+
+/// A plugin waiting for [`PluginManager::register_commands`].
+struct PendingPlugin {
+    plugin: Box<dyn Plugin>,
+    path: PathBuf,
+}
+
+struct LoadedPlugin {
+    plugin: Arc<dyn Plugin>,
+    loaded: bool,
+    enabled: bool,
+}
+
+/// Loads plugins and drives their lifecycle.
+pub struct PluginManager {
+    pending: Vec<PendingPlugin>,
+    plugins: SyncMutex<Vec<LoadedPlugin>>,
+    /// Only unloaded with the manager, which the server keeps until the process
+    /// exits: registered commands and spawned jobs can still run plugin code
+    /// after the plugin is disabled. Declared after `plugins` so plugin
+    /// instances are dropped before their code is unmapped.
+    #[cfg(feature = "native-plugins")]
+    libraries: Vec<Library>,
+}
+
+impl PluginManager {
+    /// Creates a manager without plugins.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pending: Vec::new(),
+            plugins: SyncMutex::new(Vec::new()),
+            #[cfg(feature = "native-plugins")]
+            libraries: Vec::new(),
+        }
+    }
+
+    /// Loads every plugin in `dir`, logging and skipping the ones that fail. A
+    /// missing directory means no plugins.
+    ///
+    /// Plugins are named by their file stem. [`PluginsConfig::runtimes`] picks
+    /// the runtime per plugin; otherwise the plugin runs in whichever runtime it
+    /// has a file for, preferring the sandboxed ones.
+    pub fn load_dir(&mut self, dir: &Path, config: &PluginsConfig) -> io::Result<()> {
+        if !dir.exists() {
+            return Ok(());
+        }
+        let mut files = BTreeMap::<String, PluginFiles>::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let (Some(stem), Some(extension)) = (path.file_stem(), path.extension()) else {
+                continue;
+            };
+            let stem = stem.to_string_lossy().into_owned();
+            if extension == DLL_EXTENSION {
+                files.entry(stem).or_default().native = Some(path);
+            } else if extension == WASM_EXTENSION {
+                files.entry(stem).or_default().wasm = Some(path);
+            } else if extension == SCRIPT_EXTENSION {
+                files.entry(stem).or_default().script = Some(path);
+            }
+        }
+        for (name, files) in files {
+            let runtime = config
+                .runtimes
+                .get(&name)
+                .copied()
+                .unwrap_or_else(|| files.default_runtime());
+            if let Err(error) = self.load_with(&name, &files, runtime, config) {
+                log::error!("Could not load plugin {name}: {error}");
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(
+        not(feature = "scripting"),
+        expect(
+            unused_variables,
+            reason = "Only the sandboxed runtimes use the plugin name and script settings."
+        )
+    )]
+    fn load_with(
+        &mut self,
+        name: &str,
+        files: &PluginFiles,
+        runtime: PluginRuntime,
+        config: &PluginsConfig,
+    ) -> Result<(), PluginError> {
+        let path = match runtime {
+            PluginRuntime::Native => files.native.as_deref(),
+            PluginRuntime::Wasm => files.wasm.as_deref(),
+            PluginRuntime::Script => files.script.as_deref(),
+        }
+        .ok_or(PluginError::MissingFile(runtime))?;
+        match runtime {
+            #[cfg(feature = "native-plugins")]
+            PluginRuntime::Native => self.load(path),
+            #[cfg(feature = "wasm")]
+            PluginRuntime::Wasm => {
+                self.push(Box::new(wasm::WasmPlugin::load(name, path)?), path);
+                Ok(())
+            }
+            #[cfg(feature = "scripting")]
+            PluginRuntime::Script => {
+                let plugin =
+                    script::ScriptPlugin::load(name, path, config.script_operations_per_tick)?;
+                self.push(Box::new(plugin), path);
+                Ok(())
+            }
+            #[cfg(not(all(feature = "native-plugins", feature = "wasm", feature = "scripting")))]
+            unsupported => Err(PluginError::Unsupported(unsupported)),
+        }
+    }
+
+    #[cfg(any(
+        feature = "native-plugins",
+        feature = "wasm",
+        feature = "scripting",
+        test
+    ))]
+    fn push(&mut self, plugin: Box<dyn Plugin>, path: &Path) {
+        log::info!("Loaded plugin {} {}", plugin.name(), plugin.version());
+        self.pending.push(PendingPlugin {
+            plugin,
+            path: path.to_owned(),
+        });
+    }
+
+    /// Loads the native plugin library at `path`.
+    #[cfg(feature = "native-plugins")]
+    pub fn load(&mut self, path: &Path) -> Result<(), PluginError> {
+        // SAFETY: Loading runs the library's initializers. Plugins are trusted
+        // native code placed in the plugin directory by the server operator.
+        let library = unsafe { Library::new(path) }?;
+        // SAFETY: `STEEL_PLUGIN` is declared by `declare_plugin!` as a
+        // `PluginDeclaration` static, and the library outlives the pointer.
+        let declaration = unsafe {
+            let symbol = library.get::<*const PluginDeclaration>(DECLARATION_SYMBOL)?;
+            &**symbol
+        };
+        // SAFETY: This ABI version declares both versions as nul-terminated
+        // static strings.
+        unsafe { declaration.check() }?;
+        // SAFETY: The versions match, so `create` returns a leaked
+        // `Box<Box<dyn Plugin>>` built by the same compiler as the server.
+        let plugin = unsafe {
+            let plugin = (declaration.create)();
+            if plugin.is_null() {
+                return Err(PluginError::Constructor);
+            }
+            *Box::from_raw(plugin)
+        };
+
+        self.libraries.push(library);
+        self.push(plugin, path);
+        Ok(())
+    }
+
+    /// Runs [`Plugin::on_load`] for every plugin, collecting their commands.
+    pub fn register_commands(&mut self, commands: &mut CommandRegistry) {
+        for PendingPlugin { mut plugin, path } in mem::take(&mut self.pending) {
+            let loaded = match plugin.on_load(commands) {
+                Ok(()) => true,
+                Err(error) => {
+                    log::error!(
+                        "Plugin {} ({}) failed to load: {error}",
+                        plugin.name(),
+                        path.display()
+                    );
+                    false
+                }
+            };
+            self.plugins.get_mut().push(LoadedPlugin {
+                plugin: Arc::from(plugin),
+                loaded,
+                enabled: false,
+            });
+        }
+    }
+
+    /// Runs [`Plugin::on_enable`] for every plugin that loaded.
+    pub fn enable_all(&self, server: &Arc<Server>) {
+        self.enable_with(|plugin| plugin.on_enable(server));
+    }
+
+    fn enable_with(&self, mut enable: impl FnMut(&dyn Plugin)) {
+        let to_enable: Vec<_> = self
+            .plugins
+            .lock()
+            .iter()
+            .enumerate()
+            .filter(|(_, loaded)| loaded.loaded && !loaded.enabled)
+            .map(|(index, loaded)| (index, Arc::clone(&loaded.plugin)))
+            .collect();
+        for (index, plugin) in to_enable {
+            log::info!("Enabling plugin {}", plugin.name());
+            enable(&*plugin);
+            if let Some(loaded) = self.plugins.lock().get_mut(index) {
+                loaded.enabled = true;
+            }
+        }
+    }
+
+    /// Runs [`Plugin::on_disable`] for every enabled plugin in reverse load
+    /// order, then drops the plugins. Their libraries stay loaded.
+    pub fn disable_all(&self, server: &Arc<Server>) {
+        self.disable_with(|plugin| plugin.on_disable(server));
+    }
+
+    fn disable_with(&self, mut disable: impl FnMut(&dyn Plugin)) {
+        let plugins = mem::take(&mut *self.plugins.lock());
+        for loaded in plugins.into_iter().rev() {
+            if loaded.enabled {
+                log::info!("Disabling plugin {}", loaded.plugin.name());
+                disable(&*loaded.plugin);
+            }
+        }
+    }
+
+    /// The enabled plugins in load order. Hooks run on this snapshot rather
+    /// than under the lock, so they can call back into the manager.
+    fn enabled(&self) -> Vec<Arc<dyn Plugin>> {
+        self.plugins
+            .lock()
+            .iter()
+            .filter(|loaded| loaded.enabled)
+            .map(|loaded| Arc::clone(&loaded.plugin))
+            .collect()
+    }
+
+    /// Runs [`Plugin::on_tick`] for every enabled plugin.
+    pub fn tick(&self, server: &Arc<Server>, tick_count: u64) {
+        for plugin in self.enabled() {
+            plugin.on_tick(server, tick_count);
+        }
+    }
+
+    /// Runs [`Plugin::on_player_join`] for every enabled plugin and returns
+    /// the last replacement join message.
+    pub fn player_join(&self, player: &Arc<Player>) -> Option<TextComponent> {
+        self.enabled().iter().fold(None, |message, plugin| {
+            plugin.on_player_join(player).or(message)
+        })
+    }
+
+    /// Runs [`Plugin::on_player_leave`] for every enabled plugin.
+    pub fn player_leave(&self, player: &Arc<Player>) {
+        for plugin in self.enabled() {
+            plugin.on_player_leave(player);
+        }
+    }
+
+    /// Runs [`Plugin::on_chat`] for every enabled plugin until one cancels
+    /// the message.
+    pub fn chat(&self, player: &Arc<Player>, chat: &mut OutgoingChat) {
+        for plugin in self.enabled() {
+            plugin.on_chat(player, chat);
+            if chat.cancelled {
+                return;
+            }
+        }
+    }
+
+    /// Runs [`Plugin::on_filter_text`] for every enabled plugin. Returns the
+    /// filtered text, or `None` when no plugin changed it.
+    pub fn filter_text(&self, player: &Player, text: &str) -> Option<String> {
+        let filtered = self
+            .enabled()
+            .iter()
+            .fold(None, |filtered: Option<String>, plugin| {
+                let current = filtered.as_deref().unwrap_or(text);
+                plugin.on_filter_text(player, current).or(filtered)
+            });
+        filtered.filter(|filtered| filtered != text)
+    }
+
+    /// Runs [`Plugin::on_use_item_on`] for every enabled plugin until one
+    /// consumes the action.
+    pub fn use_item_on(&self, interaction: &BlockInteraction<'_>) -> InteractionResult {
+        self.enabled()
+            .iter()
+            .map(|plugin| plugin.on_use_item_on(interaction))
+            .find(|result| result.consumes_action())
+            .unwrap_or(InteractionResult::Pass)
+    }
+
+    /// Returns the loaded plugins in load order.
+    #[must_use]
+    pub fn plugins(&self) -> Vec<PluginInfo> {
+        self.plugins
+            .lock()
+            .iter()
+            .map(|loaded| PluginInfo {
+                name: loaded.plugin.name().to_owned(),
+                version: loaded.plugin.version().to_owned(),
+                enabled: loaded.enabled,
+            })
+            .collect()
+    }
+}
+
+impl Default for PluginManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Makes a plugin name usable as a command namespace.
+#[cfg(any(feature = "wasm", feature = "scripting"))]
+fn command_namespace(name: &str) -> String {
+    name.chars()
+        .map(|c| match c.to_ascii_lowercase() {
+            c @ ('a'..='z' | '0'..='9' | '_' | '-' | '.') => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+
+    #[test]
+    fn default_runtime_prefers_sandboxed_files() {
+        let mut files = PluginFiles {
+            native: Some(PathBuf::from("warps.so")),
+            ..PluginFiles::default()
+        };
+        assert_eq!(files.default_runtime(), PluginRuntime::Native);
+        files.script = Some(PathBuf::from("warps.rhai"));
+        assert_eq!(files.default_runtime(), PluginRuntime::Script);
+        files.wasm = Some(PathBuf::from("warps.wasm"));
+        assert_eq!(files.default_runtime(), PluginRuntime::Wasm);
+    }
+
+    struct TestPlugin {
+        name: &'static str,
+        fails_to_load: bool,
+        events: Arc<SyncMutex<Vec<String>>>,
+    }
+
+    impl Plugin for TestPlugin {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn on_load(&mut self, _commands: &mut CommandRegistry) -> Result<(), String> {
+            self.events.lock().push(format!("load {}", self.name));
+            if self.fails_to_load {
+                Err("broken".to_owned())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn plugins_load_enable_and_disable_in_order() {
+        let events = Arc::new(SyncMutex::new(Vec::new()));
+        let mut manager = PluginManager::new();
+        for (name, fails_to_load) in [("first", false), ("broken", true), ("second", false)] {
+            let plugin = TestPlugin {
+                name,
+                fails_to_load,
+                events: Arc::clone(&events),
+            };
+            manager.push(Box::new(plugin), Path::new(name));
+        }
+        manager.register_commands(&mut CommandRegistry::new());
+        assert!(manager.plugins().iter().all(|plugin| !plugin.enabled));
+
+        // Hooks may call back into the manager.
+        manager.enable_with(|plugin| {
+            let listed = manager.plugins().len();
+            events
+                .lock()
+                .push(format!("enable {} of {listed}", plugin.name()));
+        });
+        let enabled: Vec<_> = manager
+            .plugins()
+            .into_iter()
+            .map(|plugin| (plugin.name, plugin.enabled))
+            .collect();
+        assert_eq!(
+            enabled,
+            [
+                ("first".to_owned(), true),
+                ("broken".to_owned(), false),
+                ("second".to_owned(), true),
+            ]
+        );
+
+        manager.disable_with(|plugin| events.lock().push(format!("disable {}", plugin.name())));
+        assert!(manager.plugins().is_empty());
+        assert_eq!(
+            *events.lock(),
+            [
+                "load first",
+                "load broken",
+                "load second",
+                "enable first of 3",
+                "enable second of 3",
+                "disable second",
+                "disable first",
+            ]
+        );
+    }
+
+    #[cfg(feature = "native-plugins")]
+    #[test]
+    fn declaration_check_rejects_other_builds() {
+        unsafe extern "C" fn no_plugin() -> *mut Box<dyn Plugin> {
+            ptr::null_mut()
+        }
+        let declaration = || PluginDeclaration {
+            abi_version: PLUGIN_ABI_VERSION,
+            steel_version: STEEL_VERSION.as_ptr(),
+            rustc_version: RUSTC_VERSION.as_ptr(),
+            create: no_plugin,
+        };
+
+        // SAFETY: Every declaration below points to static C strings or has a
+        // mismatched ABI version.
+        unsafe {
+            assert!(declaration().check().is_ok());
+            assert!(matches!(
+                PluginDeclaration {
+                    abi_version: PLUGIN_ABI_VERSION + 1,
+                    steel_version: ptr::null(),
+                    rustc_version: ptr::null(),
+                    ..declaration()
+                }
+                .check(),
+                Err(PluginError::AbiVersion { .. })
+            ));
+            assert!(matches!(
+                PluginDeclaration {
+                    steel_version: c"0.0.0".as_ptr(),
+                    ..declaration()
+                }
+                .check(),
+                Err(PluginError::SteelVersion { found }) if found == "0.0.0"
+            ));
+            assert!(matches!(
+                PluginDeclaration {
+                    rustc_version: c"rustc 1.0.0".as_ptr(),
+                    ..declaration()
+                }
+                .check(),
+                Err(PluginError::RustcVersion { found }) if found == "rustc 1.0.0"
+            ));
+        }
+    }
+
+    #[cfg(any(feature = "wasm", feature = "scripting"))]
+    #[test]
+    fn command_namespace_keeps_only_identifier_characters() {
+        assert_eq!(command_namespace("My Plugin-2"), "my_plugin-2");
+        assert_eq!(command_namespace("warps"), "warps");
+    }
+}
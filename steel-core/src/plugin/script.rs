@@ -0,0 +1,394 @@
+//! Rhai gameplay scripts.
+//!
+//! Each `.rhai` file in the plugin directory runs as its own plugin. The top
+//! level runs once when the script is loaded, after which the server calls the
+//! hook functions the script defines:
+//!
+//! - `on_enable()` and `on_disable()`.
+//! - `on_tick(tick)` every server tick.
+//! - `on_join(player)` when a player joins. Returning a string replaces the
+//!   join message.
+//! - `on_leave(player)` when a player leaves.
+//! - `on_chat(player, message)` before a chat message is sent. Returning a
+//!   string replaces the message, returning `false` cancels it.
+//! - `on_filter_text(player, text)` for sign and book text. Returning a string
+//!   sets the filtered text shown to players with text filtering enabled.
+//! - `on_use_item_on(player, x, y, z, item)` when a player uses an item on a
+//!   block. Returning `false` denies the interaction.
+//! - `on_command(name, sender, args)` for commands the script registered. The
+//!   sender is the player name, or empty for other sources. Returning an
+//!   integer sets the command result.
+//!
+//! Scripts can call:
+//!
+//! - `register_command(name)`, only at the top level, to add `/<name> [args]`.
+//! - `broadcast(text)` and `send_message(player, text)`.
+//! - `reply(text)` to answer the sender of the current command.
+//! - `online_players()`, the names of all online players.
+//! - `run_command(command)` to run a command as the console on the next tick.
+//!
+//! Messages sent or returned by scripts are [text markup](steel_utils::text::parse_markup).
+//!
+//! `print` goes to the server log. Every script has an operation budget per
+//! tick shared by all its hooks; a hook that exceeds it is stopped.
+
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+
+use rhai::{
+    AST, Array, CallFnOptions, Dynamic, Engine, EvalAltResult, FuncArgs, ImmutableString, Scope,
+};
+use steel_utils::Identifier;
+use steel_utils::locks::SyncMutex;
+use steel_utils::text::parse_markup;
+use text_components::TextComponent;
+
+use super::{Plugin, PluginError, command_namespace};
+use crate::behavior::{BlockInteraction, InteractionResult};
+use crate::command::sender::CommandSender;
+use crate::command::{
+    CommandArgument, CommandContext, CommandError, CommandNode, CommandRegistration,
+    CommandRegistry, argument, literal,
+};
+use crate::player::Player;
+use crate::server::Server;
+use crate::server::chat::OutgoingChat;
+
+/// Host state shared with the functions registered on the script engine.
+struct ScriptHost {
+    name: String,
+    loading: AtomicBool,
+    commands: SyncMutex<Vec<String>>,
+    server: SyncMutex<Weak<Server>>,
+    replies: SyncMutex<Vec<String>>,
+    /// Operations left in the current tick.
+    remaining: AtomicU64,
+    /// Operations used by the running call.
+    used: AtomicU64,
+}
+
+impl ScriptHost {
+    fn server(&self) -> Option<Arc<Server>> {
+        self.server.lock().upgrade()
+    }
+}
+
+struct Script {
+    engine: Engine,
+    ast: AST,
+    scope: SyncMutex<Scope<'static>>,
+    host: Arc<ScriptHost>,
+    budget: u64,
+}
+
+/// A Rhai script running as a plugin.
+pub struct ScriptPlugin {
+    namespace: String,
+    commands: Vec<String>,
+    script: Arc<Script>,
+}
+
+impl ScriptPlugin {
+    /// Compiles the script at `path` and runs its top level.
+    ///
+    /// `budget` is the number of operations the script may run per tick.
+    pub fn load(name: &str, path: &Path, budget: u64) -> Result<Self, PluginError> {
+        let source =
+            fs::read_to_string(path).map_err(|error| PluginError::Script(error.to_string()))?;
+        let host = Arc::new(ScriptHost {
+            name: name.to_owned(),
+            loading: AtomicBool::new(true),
+            commands: SyncMutex::new(Vec::new()),
+            server: SyncMutex::new(Weak::new()),
+            replies: SyncMutex::new(Vec::new()),
+            remaining: AtomicU64::new(budget),
+            used: AtomicU64::new(0),
+        });
+        let engine = create_engine(&host);
+        let ast = engine
+            .compile(source)
+            .map_err(|error| PluginError::Script(error.to_string()))?;
+
+        let mut scope = Scope::new();
+        let result = engine.run_ast_with_scope(&mut scope, &ast);
+        host.loading.store(false, Ordering::Relaxed);
+        result.map_err(|error| PluginError::Script(error.to_string()))?;
+
+        let commands = host.commands.lock().clone();
+        Ok(Self {
+            namespace: command_namespace(name),
+            commands,
+            script: Arc::new(Script {
+                engine,
+                ast,
+                scope: SyncMutex::new(scope),
+                host,
+                budget,
+            }),
+        })
+    }
+}
+
+impl Plugin for ScriptPlugin {
+    fn name(&self) -> &str {
+        &self.script.host.name
+    }
+
+    fn version(&self) -> &str {
+        "script"
+    }
+
+    fn on_load(&mut self, commands: &mut CommandRegistry) -> Result<(), String> {
+        for command in &self.commands {
+            commands
+                .register(CommandRegistration::new(
+                    Identifier::new(self.namespace.clone(), command.clone()),
+                    command_node(Arc::clone(&self.script), command.clone()),
+                ))
+                .map_err(|error| format!("could not register /{command}: {error}"))?;
+        }
+        Ok(())
+    }
+
+    fn on_enable(&self, server: &Arc<Server>) {
+        *self.script.host.server.lock() = Arc::downgrade(server);
+        self.script.call("on_enable", ());
+    }
+
+    fn on_disable(&self, _server: &Arc<Server>) {
+        self.script.call("on_disable", ());
+        *self.script.host.server.lock() = Weak::new();
+    }
+
+    fn on_tick(&self, _server: &Arc<Server>, tick_count: u64) {
+        self.script
+            .host
+            .remaining
+            .store(self.script.budget, Ordering::Relaxed);
+        self.script.call("on_tick", (tick_count as i64,));
+    }
+
+    fn on_player_join(&self, player: &Arc<Player>) -> Option<TextComponent> {
+        let message = self
+            .script
+            .call("on_join", (player.gameprofile.name.clone(),))?;
+        message
+            .into_string()
+            .ok()
+            .map(|message| parse_markup(&message))
+    }
+
+    fn on_player_leave(&self, player: &Arc<Player>) {
+        self.script
+            .call("on_leave", (player.gameprofile.name.clone(),));
+    }
+
+    fn on_chat(&self, player: &Arc<Player>, chat: &mut OutgoingChat) {
+        let Some(result) = self.script.call(
+            "on_chat",
+            (player.gameprofile.name.clone(), chat.message.clone()),
+        ) else {
+            return;
+        };
+        if result.as_bool() == Ok(false) {
+            chat.cancelled = true;
+        } else if let Ok(message) = result.into_string() {
+            chat.message = message;
+        }
+    }
+
+    fn on_filter_text(&self, player: &Player, text: &str) -> Option<String> {
+        self.script
+            .call(
+                "on_filter_text",
+                (player.gameprofile.name.clone(), text.to_owned()),
+            )?
+            .into_string()
+            .ok()
+    }
+
+    fn on_use_item_on(&self, interaction: &BlockInteraction<'_>) -> InteractionResult {
+        let pos = interaction.hit_result.block_pos;
+        let allowed = self.script.call(
+            "on_use_item_on",
+            (
+                interaction.player.gameprofile.name.clone(),
+                i64::from(pos.x()),
+                i64::from(pos.y()),
+                i64::from(pos.z()),
+                interaction.held_item().item.key.to_string(),
+            ),
+        );
+        if allowed.and_then(|allowed| allowed.as_bool().ok()) == Some(false) {
+            InteractionResult::Fail
+        } else {
+            InteractionResult::Pass
+        }
+    }
+}
+
+impl Script {
+    /// Calls a hook if the script defines it, within the remaining tick budget.
+    fn call(&self, function: &str, args: impl FuncArgs) -> Option<Dynamic> {
+        let mut args_list = Vec::new();
+        args.parse(&mut args_list);
+        if !self
+            .ast
+            .iter_functions()
+            .any(|f| f.name == function && f.params.len() == args_list.len())
+        {
+            return None;
+        }
+
+        let mut scope = self.scope.lock();
+        self.host.used.store(0, Ordering::Relaxed);
+        let result = self.engine.call_fn_with_options::<Dynamic>(
+            CallFnOptions::new().eval_ast(false),
+            &mut scope,
+            &self.ast,
+            function,
+            args_list,
+        );
+        let used = self.host.used.load(Ordering::Relaxed);
+        let _ =
+            self.host
+                .remaining
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |remaining| {
+                    Some(remaining.saturating_sub(used))
+                });
+
+        match result {
+            Ok(value) => Some(value),
+            Err(error) if matches!(*error, EvalAltResult::ErrorTerminated(..)) => {
+                log::warn!(
+                    "Script {} ran out of its operation budget in {function}",
+                    self.host.name
+                );
+                None
+            }
+            Err(error) => {
+                log::error!("Script {} failed in {function}: {error}", self.host.name);
+                None
+            }
+        }
+    }
+
+    fn run_command(
+        &self,
+        command: &str,
+        context: &CommandContext<'_>,
+        args: &str,
+    ) -> Result<i32, CommandError> {
+        let source = context.source();
+        let sender = source
+            .player()
+            .map_or_else(String::new, |player| player.gameprofile.name.clone());
+        let result = self.call("on_command", (command.to_owned(), sender, args.to_owned()));
+        for reply in self.host.replies.lock().drain(..) {
+            source.send_success(&parse_markup(&reply), false);
+        }
+        let Some(result) = result else {
+            return Err(CommandError::new(format!(
+                "Script {} could not run /{command}",
+                self.host.name
+            )));
+        };
+        Ok(result.as_int().map_or(1, |result| result as i32))
+    }
+}
+
+/// Builds `/<command>` with an optional greedy `args` argument.
+fn command_node(
+    script: Arc<Script>,
+    command: String,
+) -> impl FnOnce() -> CommandNode + Send + 'static {
+    move || {
+        let with_args = Arc::clone(&script);
+        let args_command = command.clone();
+        literal(command.clone())
+            .executes(move |context| script.run_command(&command, context, ""))
+            .then(
+                argument("args", CommandArgument::greedy_string()).executes(move |context| {
+                    let args = context.string("args").unwrap_or_default();
+                    with_args.run_command(&args_command, context, args)
+                }),
+            )
+    }
+}
+
+fn create_engine(host: &Arc<ScriptHost>) -> Engine {
+    let mut engine = Engine::new();
+
+    let progress = Arc::clone(host);
+    engine.on_progress(move |operations| {
+        progress.used.store(operations, Ordering::Relaxed);
+        (operations > progress.remaining.load(Ordering::Relaxed)).then_some(Dynamic::UNIT)
+    });
+    let print = Arc::clone(host);
+    engine.on_print(move |text| log::info!("[{}] {text}", print.name));
+
+    let register = Arc::clone(host);
+    engine.register_fn("register_command", move |name: &str| -> bool {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if valid && register.loading.load(Ordering::Relaxed) {
+            register.commands.lock().push(name.to_owned());
+            return true;
+        }
+        false
+    });
+
+    let broadcast = Arc::clone(host);
+    engine.register_fn("broadcast", move |text: &str| {
+        if let Some(server) = broadcast.server() {
+            server.broadcast_system_chat(&parse_markup(text), None);
+        }
+    });
+
+    let send = Arc::clone(host);
+    engine.register_fn("send_message", move |player: &str, text: &str| -> bool {
+        let Some(player) = send.server().and_then(|server| {
+            server
+                .get_players()
+                .into_iter()
+                .find(|online| online.gameprofile.name.eq_ignore_ascii_case(player))
+        }) else {
+            return false;
+        };
+        player.send_message(&parse_markup(text));
+        true
+    });
+
+    let reply = Arc::clone(host);
+    engine.register_fn("reply", move |text: &str| {
+        reply.replies.lock().push(text.to_owned());
+    });
+
+    let players = Arc::clone(host);
+    engine.register_fn("online_players", move || -> Array {
+        players.server().map_or_else(Array::new, |server| {
+            server
+                .get_players()
+                .iter()
+                .map(|player| {
+                    Dynamic::from(ImmutableString::from(player.gameprofile.name.as_str()))
+                })
+                .collect()
+        })
+    });
+
+    let commands = Arc::clone(host);
+    engine.register_fn("run_command", move |command: &str| -> bool {
+        commands.server().is_some_and(|server| {
+            server
+                .submit_command(CommandSender::Console, command.to_owned())
+                .is_ok()
+        })
+    });
+
+    engine
+}
@@ -0,0 +1,454 @@
+//! Sandboxed WebAssembly plugins.
+//!
+//! A WebAssembly plugin has no WASI and no other access to the host than the
+//! functions imported from the `steel` module below. Every call into the guest
+//! runs on a fixed fuel budget and the guest memory is capped, so a broken
+//! plugin fails its call instead of stalling the server.
+//!
+//! Strings are passed as UTF-8 pointer and length pairs in the guest memory.
+//! Coordinates refer to the server's default world.
+//!
+//! Imports (`steel` module):
+//! - `log(ptr, len)` logs a message under the plugin name.
+//! - `set_plugin_info(name_ptr, name_len, version_ptr, version_len)` sets the
+//!   name and version shown in `/plugins`. Only valid in `steel_init`.
+//! - `register_command(ptr, len, default_access) -> i32` registers
+//!   `/<name> [args]`. A non-zero `default_access` lets everyone run it unless
+//!   denied. Only valid in `steel_init`. Returns 0 on success.
+//! - `send_message(target_ptr, target_len, message_ptr, message_len) -> i32`
+//!   sends a message to the named online player, or to the command sender when
+//!   the target is empty. Returns 1 if it was delivered.
+//! - `get_block(x, y, z) -> i32` returns the block state ID at the position.
+//! - `set_block(x, y, z, state) -> i32` returns 1 if the block changed.
+//! - `spawn_entity(type_ptr, type_len, x, y, z) -> i32` spawns an entity by
+//!   type ID and returns its network ID.
+//!
+//! All functions returning `i32` return -1 for invalid arguments, and world
+//! functions return -1 before the plugin is enabled.
+//!
+//! Exports:
+//! - `memory`, and `steel_alloc(len) -> ptr` to pass strings to the guest.
+//! - `steel_init()`, `steel_on_enable()` and `steel_on_disable()`, all optional.
+//! - `steel_on_command(name_ptr, name_len, sender_ptr, sender_len, args_ptr,
+//!   args_len) -> i32` for registered commands. The sender is the player name,
+//!   or empty for other sources. The return value is the command result.
+
+use std::mem;
+use std::path::Path;
+use std::sync::{Arc, Weak};
+
+use glam::DVec3;
+use steel_registry::{REGISTRY, RegistryExt};
+use steel_utils::locks::SyncMutex;
+use steel_utils::types::{Difficulty, UpdateFlags};
+use steel_utils::{BlockPos, BlockStateId, Identifier};
+use text_components::TextComponent;
+use wasmtime::{
+    Caller, Config, Engine, Extern, Instance, Linker, Module, Store, StoreLimits,
+    StoreLimitsBuilder,
+};
+
+use super::{Plugin, PluginError, command_namespace};
+use crate::command::{
+    CommandArgument, CommandContext, CommandError, CommandNode, CommandRegistration,
+    CommandRegistry, argument, literal,
+};
+use crate::entity::{ENTITIES, EntitySpawnReason, next_entity_id};
+use crate::server::Server;
+use crate::world::World;
+
+/// Fuel available to each call into the guest, roughly one unit per instruction.
+const FUEL_PER_CALL: u64 = 50_000_000;
+
+/// Largest linear memory a guest may grow to.
+const MAX_MEMORY_BYTES: usize = 64 * 1024 * 1024;
+
+/// Name of the host import module.
+const HOST_MODULE: &str = "steel";
+
+struct HostState {
+    name: String,
+    version: String,
+    initializing: bool,
+    commands: Vec<(String, bool)>,
+    server: Weak<Server>,
+    replies: Vec<String>,
+    limits: StoreLimits,
+}
+
+struct WasmInstance {
+    store: SyncMutex<Store<HostState>>,
+    instance: Instance,
+}
+
+/// A WebAssembly module running as a plugin.
+pub struct WasmPlugin {
+    name: String,
+    version: String,
+    namespace: String,
+    commands: Vec<(String, bool)>,
+    instance: Arc<WasmInstance>,
+}
+
+impl WasmPlugin {
+    /// Compiles the module at `path` and runs its `steel_init` export.
+    ///
+    /// `name` is used until the guest sets its own and namespaces the guest's
+    /// commands.
+    pub fn load(name: &str, path: &Path) -> Result<Self, PluginError> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(PluginError::Wasm)?;
+        let module = Module::from_file(&engine, path).map_err(PluginError::Wasm)?;
+
+        let mut linker = Linker::new(&engine);
+        define_host_functions(&mut linker).map_err(PluginError::Wasm)?;
+
+        let mut store = Store::new(
+            &engine,
+            HostState {
+                name: name.to_owned(),
+                version: "unknown".to_owned(),
+                initializing: true,
+                commands: Vec::new(),
+                server: Weak::new(),
+                replies: Vec::new(),
+                limits: StoreLimitsBuilder::new()
+                    .memory_size(MAX_MEMORY_BYTES)
+                    .instances(1)
+                    .build(),
+            },
+        );
+        store.limiter(|state| &mut state.limits);
+        store.set_fuel(FUEL_PER_CALL).map_err(PluginError::Wasm)?;
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .map_err(PluginError::Wasm)?;
+        call_hook(&mut store, instance, "steel_init").map_err(PluginError::Wasm)?;
+
+        let state = store.data_mut();
+        state.initializing = false;
+        let plugin_name = state.name.clone();
+        let version = state.version.clone();
+        let commands = mem::take(&mut state.commands);
+        Ok(Self {
+            name: plugin_name,
+            version,
+            namespace: command_namespace(name),
+            commands,
+            instance: Arc::new(WasmInstance {
+                store: SyncMutex::new(store),
+                instance,
+            }),
+        })
+    }
+}
+
+impl Plugin for WasmPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> &str {
+        &self.version
+    }
+
+    fn on_load(&mut self, commands: &mut CommandRegistry) -> Result<(), String> {
+        for (command, default_access) in &self.commands {
+            let mut registration = CommandRegistration::new(
+                Identifier::new(self.namespace.clone(), command.clone()),
+                command_node(Arc::clone(&self.instance), command.clone()),
+            );
+            if *default_access {
+                registration = registration.default_access();
+            }
+            commands
+                .register(registration)
+                .map_err(|error| format!("could not register /{command}: {error}"))?;
+        }
+        Ok(())
+    }
+
+    fn on_enable(&self, server: &Arc<Server>) {
+        let mut store = self.instance.store.lock();
+        store.data_mut().server = Arc::downgrade(server);
+        if let Err(error) = call_hook(&mut store, self.instance.instance, "steel_on_enable") {
+            log::error!("Plugin {} failed to enable: {error:#}", self.name);
+        }
+    }
+
+    fn on_disable(&self, _server: &Arc<Server>) {
+        let mut store = self.instance.store.lock();
+        if let Err(error) = call_hook(&mut store, self.instance.instance, "steel_on_disable") {
+            log::error!("Plugin {} failed to disable: {error:#}", self.name);
+        }
+        store.data_mut().server = Weak::new();
+    }
+}
+
+impl WasmInstance {
+    fn run_command(
+        &self,
+        command: &str,
+        context: &CommandContext<'_>,
+        args: &str,
+    ) -> Result<i32, CommandError> {
+        let source = context.source();
+        let sender = source
+            .player()
+            .map_or("", |player| player.gameprofile.name.as_str());
+
+        let mut store = self.store.lock();
+        let result = self.call_command(&mut store, command, sender, args);
+        let replies = mem::take(&mut store.data_mut().replies);
+        drop(store);
+
+        for reply in replies {
+            source.send_success(&TextComponent::plain(reply), false);
+        }
+        result.map_err(|error| CommandError::new(format!("Plugin command failed: {error:#}")))
+    }
+
+    fn call_command(
+        &self,
+        store: &mut Store<HostState>,
+        command: &str,
+        sender: &str,
+        args: &str,
+    ) -> wasmtime::Result<i32> {
+        store.set_fuel(FUEL_PER_CALL)?;
+        let on_command = self
+            .instance
+            .get_typed_func::<(i32, i32, i32, i32, i32, i32), i32>(
+                &mut *store,
+                "steel_on_command",
+            )?;
+        let (command_ptr, command_len) = self.write_string(store, command)?;
+        let (sender_ptr, sender_len) = self.write_string(store, sender)?;
+        let (args_ptr, args_len) = self.write_string(store, args)?;
+        on_command.call(
+            &mut *store,
+            (
+                command_ptr,
+                command_len,
+                sender_ptr,
+                sender_len,
+                args_ptr,
+                args_len,
+            ),
+        )
+    }
+
+    fn write_string(
+        &self,
+        store: &mut Store<HostState>,
+        text: &str,
+    ) -> wasmtime::Result<(i32, i32)> {
+        let alloc = self
+            .instance
+            .get_typed_func::<i32, i32>(&mut *store, "steel_alloc")?;
+        let len = i32::try_from(text.len())?;
+        let ptr = alloc.call(&mut *store, len)?;
+        let Some(memory) = self.instance.get_memory(&mut *store, "memory") else {
+            return Err(wasmtime::Error::msg("plugin does not export its memory"));
+        };
+        memory.write(&mut *store, usize::try_from(ptr)?, text.as_bytes())?;
+        Ok((ptr, len))
+    }
+}
+
+/// Builds `/<command>` with an optional greedy `args` argument.
+fn command_node(
+    instance: Arc<WasmInstance>,
+    command: String,
+) -> impl FnOnce() -> CommandNode + Send + 'static {
+    move || {
+        let with_args = Arc::clone(&instance);
+        let args_command = command.clone();
+        literal(command.clone())
+            .executes(move |context| instance.run_command(&command, context, ""))
+            .then(
+                argument("args", CommandArgument::greedy_string()).executes(move |context| {
+                    let args = context.string("args").unwrap_or_default();
+                    with_args.run_command(&args_command, context, args)
+                }),
+            )
+    }
+}
+
+/// Calls an optional `() -> ()` export with a fresh fuel budget.
+fn call_hook(
+    store: &mut Store<HostState>,
+    instance: Instance,
+    export: &str,
+) -> wasmtime::Result<()> {
+    let Some(hook) = instance.get_func(&mut *store, export) else {
+        return Ok(());
+    };
+    store.set_fuel(FUEL_PER_CALL)?;
+    hook.typed::<(), ()>(&*store)?.call(&mut *store, ())
+}
+
+fn read_string(caller: &mut Caller<'_, HostState>, ptr: i32, len: i32) -> Option<String> {
+    let Some(Extern::Memory(memory)) = caller.get_export("memory") else {
+        return None;
+    };
+    let start = usize::try_from(ptr).ok()?;
+    let end = start.checked_add(usize::try_from(len).ok()?)?;
+    let bytes = memory.data(&*caller).get(start..end)?;
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+fn world(caller: &Caller<'_, HostState>) -> Option<Arc<World>> {
+    let server = caller.data().server.upgrade()?;
+    Some(Arc::clone(server.overworld()))
+}
+
+fn define_host_functions(linker: &mut Linker<HostState>) -> wasmtime::Result<()> {
+    linker.func_wrap(HOST_MODULE, "log", log)?;
+    linker.func_wrap(HOST_MODULE, "set_plugin_info", set_plugin_info)?;
+    linker.func_wrap(HOST_MODULE, "register_command", register_command)?;
+    linker.func_wrap(HOST_MODULE, "send_message", send_message)?;
+    linker.func_wrap(HOST_MODULE, "get_block", get_block)?;
+    linker.func_wrap(HOST_MODULE, "set_block", set_block)?;
+    linker.func_wrap(HOST_MODULE, "spawn_entity", spawn_entity)?;
+    Ok(())
+}
+
+fn log(mut caller: Caller<'_, HostState>, ptr: i32, len: i32) {
+    if let Some(message) = read_string(&mut caller, ptr, len) {
+        log::info!("[{}] {message}", caller.data().name);
+    }
+}
+
+fn set_plugin_info(
+    mut caller: Caller<'_, HostState>,
+    name_ptr: i32,
+    name_len: i32,
+    version_ptr: i32,
+    version_len: i32,
+) {
+    if !caller.data().initializing {
+        return;
+    }
+    let name = read_string(&mut caller, name_ptr, name_len);
+    let version = read_string(&mut caller, version_ptr, version_len);
+    let state = caller.data_mut();
+    if let Some(name) = name.filter(|name| !name.is_empty()) {
+        state.name = name;
+    }
+    if let Some(version) = version.filter(|version| !version.is_empty()) {
+        state.version = version;
+    }
+}
+
+fn register_command(
+    mut caller: Caller<'_, HostState>,
+    ptr: i32,
+    len: i32,
+    default_access: i32,
+) -> i32 {
+    if !caller.data().initializing {
+        return -1;
+    }
+    let Some(command) = read_string(&mut caller, ptr, len).filter(|command| {
+        !command.is_empty()
+            && command
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    }) else {
+        return -1;
+    };
+    caller
+        .data_mut()
+        .commands
+        .push((command, default_access != 0));
+    0
+}
+
+fn send_message(
+    mut caller: Caller<'_, HostState>,
+    target_ptr: i32,
+    target_len: i32,
+    message_ptr: i32,
+    message_len: i32,
+) -> i32 {
+    let (Some(target), Some(message)) = (
+        read_string(&mut caller, target_ptr, target_len),
+        read_string(&mut caller, message_ptr, message_len),
+    ) else {
+        return -1;
+    };
+    if target.is_empty() {
+        caller.data_mut().replies.push(message);
+        return 1;
+    }
+    let Some(server) = caller.data().server.upgrade() else {
+        return -1;
+    };
+    let Some(player) = server
+        .get_players()
+        .into_iter()
+        .find(|player| player.gameprofile.name.eq_ignore_ascii_case(&target))
+    else {
+        return 0;
+    };
+    player.send_message(&TextComponent::plain(message));
+    1
+}
+
+fn get_block(caller: Caller<'_, HostState>, x: i32, y: i32, z: i32) -> i32 {
+    let Some(world) = world(&caller) else {
+        return -1;
+    };
+    i32::from(world.get_block_state(BlockPos::new(x, y, z)).0)
+}
+
+fn set_block(caller: Caller<'_, HostState>, x: i32, y: i32, z: i32, state: i32) -> i32 {
+    let Some(world) = world(&caller) else {
+        return -1;
+    };
+    let Ok(state) = u16::try_from(state).map(BlockStateId) else {
+        return -1;
+    };
+    if REGISTRY.blocks.by_state_id(state).is_none() {
+        return -1;
+    }
+    i32::from(world.set_block(BlockPos::new(x, y, z), state, UpdateFlags::UPDATE_ALL))
+}
+
+fn spawn_entity(
+    mut caller: Caller<'_, HostState>,
+    type_ptr: i32,
+    type_len: i32,
+    x: f64,
+    y: f64,
+    z: f64,
+) -> i32 {
+    let Some(entity_type) = read_string(&mut caller, type_ptr, type_len)
+        .and_then(|key| key.parse::<Identifier>().ok())
+        .and_then(|key| REGISTRY.entity_types.by_key(&key))
+    else {
+        return -1;
+    };
+    let Some(world) = world(&caller) else {
+        return -1;
+    };
+    let position = DVec3::new(x, y, z);
+    if !World::is_in_spawnable_bounds(BlockPos::from(position))
+        || (world.difficulty() == Difficulty::Peaceful && !entity_type.allowed_in_peaceful)
+    {
+        return -1;
+    }
+    let id = next_entity_id();
+    let Some(entity) = ENTITIES.create(entity_type, id, position, Arc::downgrade(&world)) else {
+        return -1;
+    };
+    if let Some(mob) = entity.as_mob() {
+        let _ = mob.finalize_spawn(&world, EntitySpawnReason::Command, None);
+    }
+    if world.try_add_entity(entity).is_err() {
+        return -1;
+    }
+    id
+}
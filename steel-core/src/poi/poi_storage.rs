@@ -134,6 +134,31 @@ impl PointOfInterestStorage {
             .map(|poi| poi.poi_type_id)
     }
 
+    /// Returns `true` if a POI matching `type_predicate` sits at the given position.
+    ///
+    /// Vanilla equivalent: `PoiManager.existsAtPosition`.
+    #[must_use]
+    pub fn exists_at_position(
+        &self,
+        type_predicate: &impl Fn(usize) -> bool,
+        pos: BlockPos,
+    ) -> bool {
+        self.get_type(pos).is_some_and(type_predicate)
+    }
+
+    /// Returns the number of unreserved tickets at the given position, or 0 without a POI.
+    ///
+    /// Vanilla equivalent: `PoiManager.getFreeTickets`.
+    #[must_use]
+    pub fn get_free_tickets(&self, pos: BlockPos) -> u32 {
+        let (chunk_pos, section_y, packed) = resolve_pos(pos);
+        self.columns
+            .get(&chunk_pos)
+            .and_then(|column| column.get(&section_y))
+            .and_then(|set| set.get(packed))
+            .map_or(0, |poi| poi.free_tickets)
+    }
+
     /// Returns `true` if the POI at the given position has all tickets reserved.
     #[must_use]
     pub fn is_occupied(&self, pos: BlockPos) -> bool {
@@ -542,4 +567,21 @@ mod tests {
             vec![BlockPos::new(0, -64, 0), BlockPos::new(0, 320, 0)]
         );
     }
+
+    #[test]
+    fn point_lookups_report_type_and_free_tickets() {
+        init_test_registry();
+        let mut storage = PointOfInterestStorage::new();
+        let pos = BlockPos::new(3, 70, -5);
+        storage.add(pos, 7, 2);
+
+        assert!(storage.exists_at_position(&|type_id| type_id == 7, pos));
+        assert!(!storage.exists_at_position(&|type_id| type_id == 8, pos));
+        assert!(!storage.exists_at_position(&|_| true, BlockPos::new(3, 71, -5)));
+
+        assert_eq!(storage.get_free_tickets(pos), 2);
+        assert!(storage.reserve_ticket(pos));
+        assert_eq!(storage.get_free_tickets(pos), 1);
+        assert_eq!(storage.get_free_tickets(BlockPos::new(0, 0, 0)), 0);
+    }
 }
@@ -0,0 +1,77 @@
+//! Encode-once packet fan-out.
+//!
+//! A broadcast packet is serialized and compressed once, and every recipient is
+//! queued the same shared buffer. Packets that must differ per viewer map each
+//! viewer to a variant key; viewers with the same key share one encoding.
+
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+use steel_protocol::packet_traits::{ClientPacket, CompressionInfo, EncodedPacket};
+use steel_protocol::utils::ConnectionProtocol;
+
+use crate::player::Player;
+
+/// A packet sent to many players, encoded once per distinct variant.
+pub struct BroadcastPacket<K, F> {
+    compression: Option<CompressionInfo>,
+    build: F,
+    encoded: FxHashMap<K, Option<EncodedPacket>>,
+}
+
+impl<K, P, F> BroadcastPacket<K, F>
+where
+    K: Eq + Hash,
+    P: ClientPacket,
+    F: FnMut(&K, &Player) -> P,
+{
+    /// Creates a broadcast that builds the packet for a variant from the first
+    /// viewer with that key.
+    pub fn new(compression: Option<CompressionInfo>, build: F) -> Self {
+        Self {
+            compression,
+            build,
+            encoded: FxHashMap::default(),
+        }
+    }
+
+    /// Returns the encoded packet for `key`, encoding it for `viewer` on first use.
+    ///
+    /// Returns `None` if the variant failed to encode.
+    pub fn encoded(&mut self, key: K, viewer: &Player) -> Option<EncodedPacket> {
+        let Self {
+            compression,
+            build,
+            encoded,
+        } = self;
+        encoded
+            .entry(key)
+            .or_insert_with_key(|key| {
+                match EncodedPacket::from_bare(
+                    build(key, viewer),
+                    *compression,
+                    ConnectionProtocol::Play,
+                ) {
+                    Ok(packet) => Some(packet),
+                    Err(err) => {
+                        log::warn!("Failed to encode broadcast packet: {err}");
+                        None
+                    }
+                }
+            })
+            .clone()
+    }
+
+    /// Queues the `key` variant to `viewer`.
+    pub fn send(&mut self, key: K, viewer: &Player) {
+        if let Some(packet) = self.encoded(key, viewer) {
+            viewer.connection.send_encoded(packet);
+        }
+    }
+
+    /// Returns how many distinct variants were encoded.
+    #[must_use]
+    pub fn variants(&self) -> usize {
+        self.encoded.len()
+    }
+}
@@ -0,0 +1,202 @@
+//! Chat formatting.
+//!
+//! Plugins see every chat message first and can change or cancel it. With an
+//! empty [`ChatConfig::format`](crate::config::ChatConfig::format) the message
+//! then goes out as vanilla signed chat. Any other format is rendered on the
+//! server, in each recipient's language, and sent as a system message.
+//! Signed messages can later be hidden on clients with
+//! [`Server::delete_chat_message`].
+
+use rustc_hash::FxHashMap;
+use steel_protocol::packets::game::CSystemChat;
+use steel_utils::Identifier;
+use steel_utils::text::{escape_markup, parse_markup_with};
+use text_components::TextComponent;
+
+use crate::entity::Entity;
+use crate::player::Player;
+use crate::server::Server;
+use crate::server::chat_filter::ChatFilterMask;
+
+/// Permission metadata shown before the sender's name.
+pub const PREFIX_METADATA: &str = "prefix";
+/// Permission metadata shown after the sender's name.
+pub const SUFFIX_METADATA: &str = "suffix";
+
+/// A chat message on its way to the other players.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutgoingChat {
+    /// The message text. Changing it drops the client's signature, so the
+    /// message is shown as modified.
+    pub message: String,
+    /// Whether the message is dropped instead of sent.
+    pub cancelled: bool,
+    /// The client's verified signature, `None` for unsigned chat. Keep it to
+    /// delete the message later with [`Server::delete_chat_message`].
+    pub signature: Option<[u8; 256]>,
+}
+
+impl OutgoingChat {
+    /// Creates an uncancelled message.
+    #[must_use]
+    pub const fn new(message: String) -> Self {
+        Self {
+            message,
+            cancelled: false,
+            signature: None,
+        }
+    }
+}
+
+/// A piece of a chat format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormatPart<'a> {
+    Text(&'a str),
+    Prefix,
+    Suffix,
+    Name,
+    Message,
+}
+
+/// Splits `format` into text and placeholders. Unknown placeholders stay text.
+fn parse_format(format: &str) -> Vec<FormatPart<'_>> {
+    let mut parts = Vec::new();
+    let mut text_start = 0;
+    let mut search = 0;
+    while let Some(open) = format[search..].find('{').map(|index| search + index) {
+        let Some(close) = format[open..].find('}').map(|index| open + index) else {
+            break;
+        };
+        let placeholder = match &format[open + 1..close] {
+            "prefix" => FormatPart::Prefix,
+            "suffix" => FormatPart::Suffix,
+            "name" => FormatPart::Name,
+            "message" => FormatPart::Message,
+            _ => {
+                search = open + 1;
+                continue;
+            }
+        };
+        if open > text_start {
+            parts.push(FormatPart::Text(&format[text_start..open]));
+        }
+        parts.push(placeholder);
+        text_start = close + 1;
+        search = text_start;
+    }
+    if text_start < format.len() {
+        parts.push(FormatPart::Text(&format[text_start..]));
+    }
+    parts
+}
+
+/// Renders one chat line from `format`.
+///
+/// The format, prefix and suffix are [markup](steel_utils::text::parse_markup).
+/// `name` is the decorated sender name and the message is inserted as plain
+/// text.
+#[must_use]
+pub fn render_chat(
+    format: &str,
+    prefix: &str,
+    suffix: &str,
+    name: &TextComponent,
+    message: &str,
+) -> TextComponent {
+    let mut markup = String::with_capacity(format.len() + message.len());
+    for part in parse_format(format) {
+        match part {
+            FormatPart::Text(text) => markup.push_str(text),
+            FormatPart::Prefix => markup.push_str(prefix),
+            FormatPart::Suffix => markup.push_str(suffix),
+            FormatPart::Name => markup.push_str("<name>"),
+            FormatPart::Message => markup.push_str(&escape_markup(message)),
+        }
+    }
+    parse_markup_with(&markup, &[("name", name)])
+}
+
+impl Server {
+    /// Sends a chat line rendered from `format` to every online player.
+    ///
+    /// When `format` is a key of the server translations, each player gets the
+    /// translation for their language. Players who see filtered chat get the
+    /// message with `filter` applied.
+    pub(crate) fn broadcast_formatted_chat(
+        &self,
+        sender: &Player,
+        format: &str,
+        message: &str,
+        filter: &ChatFilterMask,
+    ) {
+        let metadata = |key: &'static str| {
+            sender
+                .permission_metadata(&Identifier::from_steel(key))
+                .and_then(|value| value.as_str().map(str::to_owned))
+                .unwrap_or_default()
+        };
+        let prefix = metadata(PREFIX_METADATA);
+        let suffix = metadata(SUFFIX_METADATA);
+        let name = sender.display_name();
+
+        // Rendered once per language, and again for filtered chat.
+        let mut lines = FxHashMap::<(String, bool), TextComponent>::default();
+        self.online_players.iter_players(|_, recipient| {
+            let filtered = filter.filters_for(sender, recipient);
+            let Some(message) = filter.apply_for(sender, recipient, message) else {
+                return true;
+            };
+            let language = recipient.client_information().language;
+            let line = lines
+                .entry((language, filtered))
+                .or_insert_with_key(|(language, _)| {
+                    let format = self
+                        .translations
+                        .translate(language, format)
+                        .unwrap_or_else(|| format.to_owned());
+                    render_chat(&format, &prefix, &suffix, &name, &message)
+                });
+            recipient.send_packet(CSystemChat::new(line, false, recipient.as_ref()));
+            true
+        });
+    }
+
+    /// Hides a signed chat message on every client, if
+    /// [`ChatConfig::send_deletions`](crate::config::ChatConfig::send_deletions)
+    /// is on. Returns whether deletions were sent.
+    pub fn delete_chat_message(&self, signature: &[u8; 256]) -> bool {
+        if !self.config.load().chat.send_deletions {
+            return false;
+        }
+        for world in self.worlds.values() {
+            world.delete_chat_message(signature);
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_format_splits_placeholders_and_keeps_unknown_ones() {
+        assert_eq!(
+            parse_format("{prefix}<{name}>{suffix}: {message} {world}"),
+            vec![
+                FormatPart::Prefix,
+                FormatPart::Text("<"),
+                FormatPart::Name,
+                FormatPart::Text(">"),
+                FormatPart::Suffix,
+                FormatPart::Text(": "),
+                FormatPart::Message,
+                FormatPart::Text(" {world}"),
+            ]
+        );
+        assert_eq!(
+            parse_format("{ {name}"),
+            vec![FormatPart::Text("{ "), FormatPart::Name]
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! Chat filtering.
+//!
+//! Every chat message runs through the registered [`ChatFilter`]s and the
+//! built-in word list before it is broadcast. Their masks are merged and sent
+//! like vanilla text filtering: players who see filtered chat get the masked
+//! characters replaced by `#`, and fully filtered messages are not sent to
+//! them at all.
+
+use std::borrow::Cow;
+use std::iter;
+use std::sync::Arc;
+
+use futures::future::{BoxFuture, join_all};
+use steel_protocol::packets::game::FilterType;
+use steel_utils::codec::BitSet;
+
+use crate::player::Player;
+use crate::server::Server;
+
+/// Decides which parts of a chat message to hide.
+///
+/// Filters may be slow, for example when they ask a web service, so they
+/// return a future. Messages of one player are still broadcast in order.
+pub trait ChatFilter: Send + Sync {
+    /// Returns the mask for `message`. Bit `i` of a partial mask hides the
+    /// UTF-16 code unit at index `i`, like vanilla `FilterMask`.
+    fn filter<'a>(&'a self, sender: &'a Arc<Player>, message: &'a str)
+    -> BoxFuture<'a, FilterType>;
+}
+
+/// The built-in filter hiding words from
+/// [`ChatFilterConfig::words`](crate::config::ChatFilterConfig::words).
+///
+/// Words match case-insensitively and only as whole words. A trailing `*`
+/// matches every word starting with the rest.
+pub struct WordListFilter {
+    words: Vec<String>,
+}
+
+impl WordListFilter {
+    /// Creates a filter for the configured words.
+    #[must_use]
+    pub fn new(words: &[String]) -> Self {
+        Self {
+            words: words
+                .iter()
+                .map(|word| word.trim().to_lowercase())
+                .filter(|word| !word.is_empty() && word != "*")
+                .collect(),
+        }
+    }
+
+    fn matches(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        self.words
+            .iter()
+            .any(|entry| match entry.strip_suffix('*') {
+                Some(prefix) => word.starts_with(prefix),
+                None => word == *entry,
+            })
+    }
+
+    /// Returns the mask hiding every listed word in `message`.
+    #[must_use]
+    pub fn mask(&self, message: &str) -> FilterType {
+        let mut mask: Option<BitSet> = None;
+        let mut word = String::new();
+        let mut word_start = 0;
+        let mut index = 0;
+        // The trailing separator ends the last word.
+        for c in message.chars().chain(iter::once(' ')) {
+            if c.is_alphanumeric() {
+                if word.is_empty() {
+                    word_start = index;
+                }
+                word.push(c);
+            } else if !word.is_empty() {
+                if self.matches(&word) {
+                    let mask = mask.get_or_insert_with(|| {
+                        BitSet::with_capacity(message.encode_utf16().count())
+                    });
+                    for hidden in word_start..index {
+                        mask.set(hidden, true);
+                    }
+                }
+                word.clear();
+            }
+            index += c.len_utf16();
+        }
+        mask.map_or(FilterType::PassThrough, FilterType::PartiallyFiltered)
+    }
+}
+
+impl ChatFilter for WordListFilter {
+    fn filter<'a>(
+        &'a self,
+        _sender: &'a Arc<Player>,
+        message: &'a str,
+    ) -> BoxFuture<'a, FilterType> {
+        let mask = self.mask(message);
+        Box::pin(async move { mask })
+    }
+}
+
+/// Merges two masks, hiding everything either of them hides.
+#[must_use]
+pub fn merge_masks(first: FilterType, second: FilterType) -> FilterType {
+    match (first, second) {
+        (FilterType::FullyFiltered, _) | (_, FilterType::FullyFiltered) => {
+            FilterType::FullyFiltered
+        }
+        (FilterType::PassThrough, mask) | (mask, FilterType::PassThrough) => mask,
+        (FilterType::PartiallyFiltered(first), FilterType::PartiallyFiltered(second)) => {
+            let (mut longer, shorter) = if first.0.len() >= second.0.len() {
+                (first, second)
+            } else {
+                (second, first)
+            };
+            for (word, other) in longer.0.iter_mut().zip(shorter.0.iter()) {
+                *word |= other;
+            }
+            FilterType::PartiallyFiltered(longer)
+        }
+    }
+}
+
+/// Applies `mask` to `message`, or returns `None` when it is fully filtered.
+///
+/// Vanilla `FilterMask.apply`.
+#[must_use]
+pub fn apply_mask<'a>(mask: &FilterType, message: &'a str) -> Option<Cow<'a, str>> {
+    match mask {
+        FilterType::PassThrough => Some(Cow::Borrowed(message)),
+        FilterType::FullyFiltered => None,
+        FilterType::PartiallyFiltered(mask) => {
+            let units: Vec<u16> = message
+                .encode_utf16()
+                .enumerate()
+                .map(|(index, unit)| {
+                    if mask.get(index) {
+                        u16::from(b'#')
+                    } else {
+                        unit
+                    }
+                })
+                .collect();
+            Some(Cow::Owned(String::from_utf16_lossy(&units)))
+        }
+    }
+}
+
+/// The merged mask of a chat message and who sees it applied.
+pub struct ChatFilterMask {
+    mask: FilterType,
+    filter_everyone: bool,
+}
+
+impl ChatFilterMask {
+    /// A mask that hides nothing.
+    #[must_use]
+    pub const fn pass_through() -> Self {
+        Self {
+            mask: FilterType::PassThrough,
+            filter_everyone: false,
+        }
+    }
+
+    /// Whether `recipient` sees the message filtered. Senders always see their
+    /// own message. Otherwise the message is filtered when either player has
+    /// text filtering enabled, or for everyone if configured.
+    ///
+    /// Vanilla `ServerPlayer.shouldFilterMessageTo`.
+    #[must_use]
+    pub fn filters_for(&self, sender: &Player, recipient: &Player) -> bool {
+        if matches!(self.mask, FilterType::PassThrough)
+            || sender.gameprofile.id == recipient.gameprofile.id
+        {
+            return false;
+        }
+        self.filter_everyone
+            || sender.is_text_filtering_enabled()
+            || recipient.is_text_filtering_enabled()
+    }
+
+    /// Returns the mask to send to `recipient`, or `None` when the message
+    /// should not reach them because it is fully filtered or they ignore the
+    /// sender.
+    #[must_use]
+    pub fn mask_for(&self, sender: &Player, recipient: &Player) -> Option<FilterType> {
+        if recipient.is_ignoring(sender.gameprofile.id) {
+            return None;
+        }
+        if !self.filters_for(sender, recipient) {
+            return Some(FilterType::PassThrough);
+        }
+        match &self.mask {
+            FilterType::FullyFiltered => None,
+            mask => Some(mask.clone()),
+        }
+    }
+
+    /// Returns `message` as `recipient` should see it, or `None` when the
+    /// message should not reach them because it is fully filtered or they
+    /// ignore the sender.
+    #[must_use]
+    pub fn apply_for<'a>(
+        &self,
+        sender: &Player,
+        recipient: &Player,
+        message: &'a str,
+    ) -> Option<Cow<'a, str>> {
+        if recipient.is_ignoring(sender.gameprofile.id) {
+            return None;
+        }
+        if self.filters_for(sender, recipient) {
+            apply_mask(&self.mask, message)
+        } else {
+            Some(Cow::Borrowed(message))
+        }
+    }
+}
+
+/// Runs every filter on `message` and merges their masks.
+pub async fn filter_chat(
+    filters: &[Arc<dyn ChatFilter>],
+    sender: &Arc<Player>,
+    message: &str,
+    filter_everyone: bool,
+) -> ChatFilterMask {
+    let masks = join_all(filters.iter().map(|filter| filter.filter(sender, message))).await;
+    ChatFilterMask {
+        mask: masks.into_iter().fold(FilterType::PassThrough, merge_masks),
+        filter_everyone,
+    }
+}
+
+impl Server {
+    /// Adds a filter that every chat message runs through before broadcast.
+    pub fn register_chat_filter(&self, filter: Arc<dyn ChatFilter>) {
+        self.chat_filters.write().push(filter);
+    }
+
+    /// Returns the registered filters, with the built-in word list first when
+    /// it is enabled.
+    #[must_use]
+    pub fn chat_filters(&self) -> Vec<Arc<dyn ChatFilter>> {
+        let config = self.config.load();
+        let filter = &config.chat.filter;
+        let mut filters: Vec<Arc<dyn ChatFilter>> = Vec::new();
+        if filter.enable && !filter.words.is_empty() {
+            filters.push(Arc::new(WordListFilter::new(&filter.words)));
+        }
+        filters.extend(self.chat_filters.read().iter().cloned());
+        filters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn masked(words: &[&str], message: &str) -> Option<String> {
+        let words: Vec<String> = words.iter().map(|word| (*word).to_owned()).collect();
+        let mask = WordListFilter::new(&words).mask(message);
+        apply_mask(&mask, message).map(Cow::into_owned)
+    }
+
+    #[test]
+    fn word_list_hides_whole_words_only() {
+        assert_eq!(
+            masked(&["heck"], "Heck, what the heck? checks"),
+            Some("####, what the ####? checks".to_owned())
+        );
+        assert_eq!(
+            masked(&["dar*"], "darn it, darnation"),
+            Some("#### it, #########".to_owned())
+        );
+        assert!(matches!(
+            WordListFilter::new(&["heck".to_owned()]).mask("hello there"),
+            FilterType::PassThrough
+        ));
+    }
+
+    #[test]
+    fn word_list_counts_utf16_units() {
+        assert_eq!(masked(&["heck"], "😀 heck"), Some("😀 ####".to_owned()));
+    }
+
+    #[test]
+    fn merged_masks_hide_both() {
+        let first = WordListFilter::new(&["a".to_owned()]).mask("a b");
+        let second = WordListFilter::new(&["b".to_owned()]).mask("a b");
+        let merged = merge_masks(first, second);
+        assert_eq!(apply_mask(&merged, "a b").as_deref(), Some("# #"));
+        assert!(matches!(
+            merge_masks(merged, FilterType::FullyFiltered),
+            FilterType::FullyFiltered
+        ));
+    }
+}
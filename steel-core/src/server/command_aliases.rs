@@ -0,0 +1,59 @@
+//! Registers `commands.toml` aliases in the command dispatcher.
+
+use crate::command::alias_command;
+use crate::command::brigadier::CommandSyntaxError;
+use crate::command::execution::{CommandSource, SteelContextChain};
+use crate::server::Server;
+
+impl Server {
+    /// Parses `command` into a chain that can be queued for `source`.
+    pub(crate) fn parse_command(
+        &self,
+        command: &str,
+        source: CommandSource,
+    ) -> Result<SteelContextChain<CommandSource>, CommandSyntaxError> {
+        let dispatcher = self.command_dispatcher.read();
+        let parse = dispatcher.parse(command, source);
+        dispatcher.context_chain(parse)
+    }
+
+    /// Adds a root for every configured alias that doesn't have one yet.
+    ///
+    /// Aliases never replace commands: an alias named like an existing command
+    /// is skipped with a warning.
+    pub(crate) fn register_command_aliases(&self) {
+        let config = self.config.load();
+        let mut dispatcher = self.command_dispatcher.write();
+        let mut alias_roots = self.command_alias_roots.lock();
+        for name in config.command_aliases.aliases.keys() {
+            if alias_roots.contains(name) {
+                continue;
+            }
+            let root = dispatcher.root();
+            let taken = dispatcher.children(root).is_some_and(|children| {
+                children.iter().any(|child| {
+                    dispatcher
+                        .node(*child)
+                        .is_some_and(|node| node.name() == name)
+                })
+            });
+            if taken {
+                log::warn!("Skipping command alias '{name}': a command with that name exists");
+                continue;
+            }
+            let registered = alias_command(name)
+                .map_err(|error| error.to_string())
+                .and_then(|command| {
+                    dispatcher
+                        .register(command)
+                        .map_err(|error| error.to_string())
+                });
+            match registered {
+                Ok(_) => {
+                    alias_roots.insert(name.clone());
+                }
+                Err(error) => log::warn!("Skipping command alias '{name}': {error}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! Runtime configuration reloading for `/steel reload config`.
+//!
+//! The configuration file belongs to the embedding binary, so it installs a
+//! [`ConfigReloader`] that re-reads it. The server then swaps in the settings
+//! that can change while running and keeps the rest until a restart.
+
+use std::sync::Arc;
+
+use crate::config::RuntimeConfig;
+use crate::server::Server;
+
+/// Re-reads the server configuration.
+pub trait ConfigReloader: Send + Sync {
+    /// Reads and validates the configuration file.
+    ///
+    /// Settings the embedding binary owns, such as log formatting, are applied
+    /// here directly.
+    fn reload(&self) -> Result<RuntimeConfig, String>;
+}
+
+/// What a configuration reload changed.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ConfigReload {
+    /// Settings that changed and are now in effect.
+    pub applied: Vec<&'static str>,
+    /// Settings that changed but only take effect after a restart.
+    pub requires_restart: Vec<&'static str>,
+}
+
+impl Server {
+    /// Installs the reloader used by [`Server::reload_config`].
+    ///
+    /// Only the first reloader is kept.
+    pub fn set_config_reloader(&self, reloader: Box<dyn ConfigReloader>) {
+        if self.config_reloader.set(reloader).is_err() {
+            log::warn!("A config reloader is already installed");
+        }
+    }
+
+    /// Re-reads the configuration and applies the settings that can change at
+    /// runtime.
+    pub fn reload_config(self: &Arc<Self>) -> Result<ConfigReload, String> {
+        let Some(reloader) = self.config_reloader.get() else {
+            return Err("this server cannot reload its configuration".to_owned());
+        };
+        let current = self.config.load_full();
+        let (config, reload) = merge_reloadable(&current, reloader.reload()?);
+
+        let view_distance = config.view_distance;
+        let simulation_distance = config.simulation_distance;
+        let aliases_changed = config.command_aliases != current.command_aliases;
+        self.config.store(Arc::new(config));
+        if aliases_changed {
+            self.register_command_aliases();
+            for player in self.get_players() {
+                self.resend_player_permission_context(&player);
+            }
+        }
+        if view_distance != current.view_distance
+            || simulation_distance != current.simulation_distance
+        {
+            for world in self.worlds.values() {
+                world.set_view_distances(view_distance, simulation_distance);
+            }
+        }
+        Ok(reload)
+    }
+}
+
+/// Takes the reloadable settings from `new` and keeps everything else from
+/// `current`.
+///
+/// Compression is negotiated per connection at login and baked into broadcast
+/// packets, so it cannot change while players are connected.
+fn merge_reloadable(current: &RuntimeConfig, new: RuntimeConfig) -> (RuntimeConfig, ConfigReload) {
+    let mut reload = ConfigReload::default();
+    if new.motd != current.motd {
+        reload.applied.push("motd");
+    }
+    if new.max_players != current.max_players {
+        reload.applied.push("max_players");
+    }
+    if new.view_distance != current.view_distance {
+        reload.applied.push("view_distance");
+    }
+    if new.simulation_distance != current.simulation_distance {
+        reload.applied.push("simulation_distance");
+    }
+    if new.enforce_secure_chat != current.enforce_secure_chat {
+        reload.applied.push("enforce_secure_chat");
+    }
+    if new.chat != current.chat {
+        reload.applied.push("chat");
+    }
+    if new.offline_skins != current.offline_skins {
+        reload.applied.push("offline_skins");
+    }
+    if new.join_queue != current.join_queue {
+        reload.applied.push("join_queue");
+    }
+    if new.report_details != current.report_details {
+        reload.applied.push("report_details");
+    }
+    if new.command_aliases != current.command_aliases {
+        reload.applied.push("command_aliases");
+    }
+    if new.compression != current.compression {
+        reload.requires_restart.push("compression");
+    }
+
+    let config = RuntimeConfig {
+        motd: new.motd,
+        max_players: new.max_players,
+        view_distance: new.view_distance,
+        simulation_distance: new.simulation_distance,
+        enforce_secure_chat: new.enforce_secure_chat,
+        chat: new.chat,
+        offline_skins: new.offline_skins,
+        join_queue: new.join_queue,
+        report_details: new.report_details,
+        command_aliases: new.command_aliases,
+        ..current.clone()
+    };
+    (config, reload)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use crate::config::{
+        ChatConfig, CommandAliasesConfig, CompressionInfo, JoinQueueConfig, OfflineSkinsConfig,
+        PacketLimitConfig, ProxyConfig, ReportDetailsConfig, WatchdogConfig,
+    };
+
+    use super::*;
+
+    fn runtime_config() -> RuntimeConfig {
+        RuntimeConfig {
+            max_players: 20,
+            view_distance: 10,
+            simulation_distance: 10,
+            entity_broadcast_range_percentage: 100,
+            online_mode: true,
+            auth_server: None,
+            profile_server: None,
+            encryption: true,
+            allow_flight: false,
+            accepts_transfers: false,
+            motd: "A Steel server".to_owned(),
+            use_favicon: false,
+            favicon: String::new(),
+            enforce_secure_chat: true,
+            chat_spam_threshold_seconds: 10,
+            command_spam_threshold_seconds: 10,
+            compression: None,
+            server_links: None,
+            report_details: ReportDetailsConfig::default(),
+            chunk_generation_threads: None,
+            random_tick_threads: None,
+            resource_packs: Vec::new(),
+            proxy: ProxyConfig::default(),
+            packet_limits: PacketLimitConfig::default(),
+            watchdog: WatchdogConfig::default(),
+            chat: ChatConfig::default(),
+            offline_skins: OfflineSkinsConfig::default(),
+            join_queue: JoinQueueConfig::default(),
+            command_aliases: CommandAliasesConfig::default(),
+        }
+    }
+
+    #[test]
+    fn merge_applies_reloadable_settings_and_keeps_the_rest() {
+        let current = runtime_config();
+        let mut new = runtime_config();
+        new.motd = "Reloaded".to_owned();
+        new.view_distance = 12;
+        new.allow_flight = true;
+        new.compression = NonZeroU32::new(256).map(|threshold| CompressionInfo {
+            threshold,
+            level: 4,
+        });
+
+        let (config, reload) = merge_reloadable(&current, new);
+
+        assert_eq!(config.motd, "Reloaded");
+        assert_eq!(config.view_distance, 12);
+        assert!(!config.allow_flight);
+        assert_eq!(config.compression, None);
+        assert_eq!(
+            reload,
+            ConfigReload {
+                applied: vec!["motd", "view_distance"],
+                requires_restart: vec!["compression"],
+            }
+        );
+    }
+}
@@ -0,0 +1,975 @@
+//! Datapack discovery, selection and loading.
+//!
+//! Packs are directories in `<save>/datapacks` with a valid `pack.mcmeta`.
+//! Steel's registries are generated at build time, so datapacks change them
+//! through overlays: tags are resolved into a [`TagOverlay`], and entries of the
+//! synced registries in [`OVERLAY_REGISTRIES`] go into a [`RegistryOverlay`].
+//! Recipes, loot tables, advancements and functions are merged by id, later
+//! packs overriding earlier ones, and kept as documents for lookup. Function
+//! tags are resolved against the loaded functions.
+//!
+//! Built-in tags are flattened at build time, so a built-in tag that includes a
+//! datapack-modified tag keeps its built-in contents.
+//!
+//! Plugins define synced registry entries such as custom biomes through
+//! [`DataPackManager::define_biome`]. They are loaded before any pack, so
+//! datapacks can still override them.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use arc_swap::ArcSwap;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use simdnbt::{ToNbtTag, owned::NbtTag};
+use steel_registry::{
+    BIOMES_REGISTRY, BLOCKS_REGISTRY, DAMAGE_TYPE_REGISTRY, DIALOG_REGISTRY, ENTITY_TYPE_REGISTRY,
+    FLUID_REGISTRY, ITEMS_REGISTRY, REGISTRY, RegistryEntry, RegistryExt, TaggedRegistryExt,
+    biome::Biome,
+    registry_overlay::{OVERLAY_REGISTRIES, RegistryOverlay, json_to_nbt, set_registry_overlay},
+    tag_overlay::{TagOverlay, set_tag_overlay},
+};
+use steel_utils::{Identifier, locks::SyncMutex, text::component_from_json_value};
+use text_components::TextComponent;
+
+/// Id of the built-in pack holding Steel's generated vanilla data.
+pub const VANILLA_PACK_ID: &str = "vanilla";
+const FILE_PACK_PREFIX: &str = "file/";
+const DATAPACKS_DIRECTORY: &str = "datapacks";
+const SELECTION_FILE: &str = "datapacks.json";
+const FUNCTION_TAGS_DIRECTORY: &str = "tags/function";
+
+type TagMap = FxHashMap<Identifier, Vec<Identifier>>;
+
+/// A registry whose tags datapacks can change.
+struct TagRegistry {
+    directory: &'static str,
+    key: Identifier,
+    contains: fn(&Identifier) -> bool,
+}
+
+fn contains_block(key: &Identifier) -> bool {
+    REGISTRY.blocks.by_key(key).is_some()
+}
+
+fn contains_item(key: &Identifier) -> bool {
+    REGISTRY.items.by_key(key).is_some()
+}
+
+fn contains_entity_type(key: &Identifier) -> bool {
+    REGISTRY.entity_types.by_key(key).is_some()
+}
+
+fn contains_fluid(key: &Identifier) -> bool {
+    REGISTRY.fluids.by_key(key).is_some()
+}
+
+fn contains_biome(key: &Identifier) -> bool {
+    REGISTRY.biomes.by_key(key).is_some()
+}
+
+fn contains_damage_type(key: &Identifier) -> bool {
+    REGISTRY.damage_types.by_key(key).is_some()
+}
+
+fn contains_dialog(key: &Identifier) -> bool {
+    REGISTRY.dialogs.by_key(key).is_some()
+}
+
+const TAG_REGISTRIES: [TagRegistry; 7] = [
+    TagRegistry {
+        directory: "tags/block",
+        key: BLOCKS_REGISTRY,
+        contains: contains_block,
+    },
+    TagRegistry {
+        directory: "tags/item",
+        key: ITEMS_REGISTRY,
+        contains: contains_item,
+    },
+    TagRegistry {
+        directory: "tags/entity_type",
+        key: ENTITY_TYPE_REGISTRY,
+        contains: contains_entity_type,
+    },
+    TagRegistry {
+        directory: "tags/fluid",
+        key: FLUID_REGISTRY,
+        contains: contains_fluid,
+    },
+    TagRegistry {
+        directory: "tags/worldgen/biome",
+        key: BIOMES_REGISTRY,
+        contains: contains_biome,
+    },
+    TagRegistry {
+        directory: "tags/damage_type",
+        key: DAMAGE_TYPE_REGISTRY,
+        contains: contains_damage_type,
+    },
+    TagRegistry {
+        directory: "tags/dialog",
+        key: DIALOG_REGISTRY,
+        contains: contains_dialog,
+    },
+];
+
+/// Why a pack selection change was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataPackError {
+    /// No available pack has this id.
+    Unknown,
+    /// The pack is already enabled.
+    AlreadyEnabled,
+    /// The pack is not enabled.
+    NotEnabled,
+    /// The pack is built in and cannot be disabled.
+    Required,
+}
+
+/// A discovered pack.
+#[derive(Debug, Clone)]
+pub struct DataPackInfo {
+    /// Pack id, `vanilla` or `file/<directory>`.
+    pub id: String,
+    /// Description from `pack.mcmeta`.
+    pub description: TextComponent,
+    path: Option<PathBuf>,
+}
+
+impl DataPackInfo {
+    fn vanilla() -> Self {
+        Self {
+            id: VANILLA_PACK_ID.to_owned(),
+            description: TextComponent::plain("The default data for Minecraft"),
+            path: None,
+        }
+    }
+}
+
+/// Merged non-tag contents of the enabled packs.
+#[derive(Debug, Default)]
+pub struct DataPackContents {
+    /// Recipe documents by id.
+    pub recipes: FxHashMap<Identifier, Value>,
+    /// Loot table documents by id.
+    pub loot_tables: FxHashMap<Identifier, Value>,
+    /// Advancement documents by id.
+    pub advancements: FxHashMap<Identifier, Value>,
+    /// Function command lines by id, without comments and blank lines.
+    pub functions: FxHashMap<Identifier, Arc<[String]>>,
+    /// Functions of each function tag, in tag order.
+    pub function_tags: FxHashMap<Identifier, Vec<Identifier>>,
+    /// Number of tags the enabled packs replace or add.
+    pub tag_count: usize,
+    /// Number of synced registry entries the enabled packs replace or add.
+    pub registry_entry_count: usize,
+}
+
+#[derive(Deserialize)]
+struct PackMcmeta {
+    pack: PackSection,
+}
+
+#[derive(Deserialize)]
+struct PackSection {
+    description: Value,
+    #[serde(default)]
+    pack_format: Option<i64>,
+    #[serde(default)]
+    min_format: Option<Value>,
+    #[serde(default)]
+    max_format: Option<Value>,
+}
+
+#[derive(Deserialize)]
+struct TagFile {
+    #[serde(default)]
+    replace: bool,
+    values: Vec<TagValue>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TagValue {
+    Id(String),
+    Entry { id: String, required: bool },
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct SelectionFile {
+    #[serde(default)]
+    enabled: Vec<String>,
+    #[serde(default)]
+    disabled: Vec<String>,
+}
+
+struct Selection {
+    available: Vec<DataPackInfo>,
+    enabled: Vec<String>,
+    disabled: FxHashSet<String>,
+}
+
+#[derive(Default)]
+struct TagBuilder {
+    replace: bool,
+    entries: Vec<(String, bool)>,
+}
+
+/// Discovers, selects and loads the server's datapacks.
+pub struct DataPackManager {
+    directory: PathBuf,
+    selection_path: PathBuf,
+    base_tags: FxHashMap<Identifier, TagMap>,
+    selection: SyncMutex<Selection>,
+    contents: ArcSwap<DataPackContents>,
+    /// Entries defined from code, as `(registry, key, data)` in definition order.
+    defined_entries: SyncMutex<Vec<(Identifier, Identifier, NbtTag)>>,
+}
+
+impl DataPackManager {
+    /// Creates a manager with only the vanilla pack enabled, without touching disk.
+    ///
+    /// Must run before any tag overlay is installed, as it captures the
+    /// built-in tag contents.
+    #[must_use]
+    pub fn new(save_path: &Path) -> Self {
+        Self {
+            directory: save_path.join(DATAPACKS_DIRECTORY),
+            selection_path: save_path.join(SELECTION_FILE),
+            base_tags: capture_base_tags(),
+            selection: SyncMutex::new(Selection {
+                available: vec![DataPackInfo::vanilla()],
+                enabled: vec![VANILLA_PACK_ID.to_owned()],
+                disabled: FxHashSet::default(),
+            }),
+            contents: ArcSwap::from_pointee(DataPackContents::default()),
+            defined_entries: SyncMutex::new(Vec::new()),
+        }
+    }
+
+    /// Creates a manager, restores the saved selection and loads enabled packs.
+    pub fn load(save_path: &Path) -> Result<Self, String> {
+        let manager = Self::new(save_path);
+        let saved = manager.read_selection()?;
+        {
+            let mut selection = manager.selection.lock();
+            selection.enabled = saved.enabled;
+            selection.disabled = saved.disabled.into_iter().collect();
+        }
+        manager.reload()?;
+        Ok(manager)
+    }
+
+    /// Returns the enabled packs, in load order.
+    #[must_use]
+    pub fn enabled(&self) -> Vec<DataPackInfo> {
+        let selection = self.selection.lock();
+        selection
+            .enabled
+            .iter()
+            .filter_map(|id| selection.available.iter().find(|pack| &pack.id == id))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the packs that are available but not enabled.
+    #[must_use]
+    pub fn available(&self) -> Vec<DataPackInfo> {
+        let selection = self.selection.lock();
+        selection
+            .available
+            .iter()
+            .filter(|pack| !selection.enabled.contains(&pack.id))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the discovered pack with `id`.
+    #[must_use]
+    pub fn pack(&self, id: &str) -> Option<DataPackInfo> {
+        self.selection
+            .lock()
+            .available
+            .iter()
+            .find(|pack| pack.id == id)
+            .cloned()
+    }
+
+    /// Marks `id` as enabled, loading last. Takes effect on the next reload.
+    pub fn enable(&self, id: &str) -> Result<(), DataPackError> {
+        let mut selection = self.selection.lock();
+        if !selection.available.iter().any(|pack| pack.id == id) {
+            return Err(DataPackError::Unknown);
+        }
+        if selection.enabled.iter().any(|enabled| enabled == id) {
+            return Err(DataPackError::AlreadyEnabled);
+        }
+        selection.enabled.push(id.to_owned());
+        selection.disabled.remove(id);
+        Ok(())
+    }
+
+    /// Marks `id` as disabled. Takes effect on the next reload.
+    pub fn disable(&self, id: &str) -> Result<(), DataPackError> {
+        let mut selection = self.selection.lock();
+        if !selection.available.iter().any(|pack| pack.id == id) {
+            return Err(DataPackError::Unknown);
+        }
+        if id == VANILLA_PACK_ID {
+            return Err(DataPackError::Required);
+        }
+        let Some(index) = selection.enabled.iter().position(|enabled| enabled == id) else {
+            return Err(DataPackError::NotEnabled);
+        };
+        selection.enabled.remove(index);
+        selection.disabled.insert(id.to_owned());
+        Ok(())
+    }
+
+    /// Returns the merged contents of the enabled packs.
+    #[must_use]
+    pub fn contents(&self) -> Arc<DataPackContents> {
+        self.contents.load_full()
+    }
+
+    /// Returns the command lines of function `id`.
+    #[must_use]
+    pub fn function(&self, id: &Identifier) -> Option<Arc<[String]>> {
+        self.contents.load().functions.get(id).cloned()
+    }
+
+    /// Returns the functions of function tag `id`, or `None` if the tag is unknown.
+    #[must_use]
+    pub fn function_tag(&self, id: &Identifier) -> Option<Vec<Identifier>> {
+        self.contents.load().function_tags.get(id).cloned()
+    }
+
+    /// Defines entry `key` of the synced registry `registry` from its network
+    /// NBT, as if a datapack loaded before all others declared it.
+    ///
+    /// Redefining a key replaces its data. Takes effect on the next
+    /// [`reload`](Self::reload); only registries in [`OVERLAY_REGISTRIES`] are
+    /// synced.
+    pub fn define_registry_entry(&self, registry: Identifier, key: Identifier, data: NbtTag) {
+        let mut defined = self.defined_entries.lock();
+        match defined.iter_mut().find(|(existing_registry, existing, _)| {
+            *existing_registry == registry && *existing == key
+        }) {
+            Some((_, _, existing)) => *existing = data,
+            None => defined.push((registry, key, data)),
+        }
+    }
+
+    /// Defines a custom biome, usually made with [`Biome::derive`], so clients
+    /// receive its colors, sounds and particles during configuration.
+    ///
+    /// A new key is only sent to clients; chunk biome palettes hold built-in
+    /// biomes, so it cannot be placed in the world yet. Redefining a vanilla
+    /// key changes how that biome looks everywhere.
+    pub fn define_biome(&self, biome: &Biome) {
+        self.define_registry_entry(BIOMES_REGISTRY, biome.key.clone(), biome.to_nbt_tag());
+    }
+
+    /// Rediscovers packs and loads the enabled ones, installing their tags.
+    ///
+    /// Newly discovered packs are enabled unless they were disabled before.
+    /// On failure the previously loaded data stays active.
+    pub fn reload(&self) -> Result<(), String> {
+        let available = self.discover()?;
+        let enabled = {
+            let mut selection = self.selection.lock();
+            selection.available = available;
+            let Selection {
+                available,
+                enabled,
+                disabled,
+            } = &mut *selection;
+            enabled.retain(|id| available.iter().any(|pack| &pack.id == id));
+            if let Some(index) = enabled.iter().position(|id| id == VANILLA_PACK_ID) {
+                enabled.remove(index);
+            }
+            enabled.insert(0, VANILLA_PACK_ID.to_owned());
+            for pack in &*available {
+                if !enabled.contains(&pack.id) && !disabled.contains(&pack.id) {
+                    enabled.push(pack.id.clone());
+                }
+            }
+            enabled
+                .iter()
+                .filter_map(|id| available.iter().find(|pack| &pack.id == id))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        let mut contents = DataPackContents::default();
+        let mut registries = RegistryOverlay::default();
+        for (registry, key, data) in self.defined_entries.lock().iter() {
+            registries.insert(registry.clone(), key.clone(), data.clone());
+        }
+        let mut tags: Vec<FxHashMap<Identifier, TagBuilder>> = TAG_REGISTRIES
+            .iter()
+            .map(|_| FxHashMap::default())
+            .collect();
+        let mut function_tags = FxHashMap::default();
+        for pack in &enabled {
+            let Some(path) = &pack.path else {
+                continue;
+            };
+            load_pack(
+                &path.join("data"),
+                &mut contents,
+                &mut registries,
+                &mut tags,
+                &mut function_tags,
+            )
+            .map_err(|error| format!("failed to load data pack {}: {error}", pack.id))?;
+        }
+
+        let mut overlay = TagOverlay::default();
+        for (registry, builders) in TAG_REGISTRIES.iter().zip(&tags) {
+            self.resolve_tags(registry, builders, &registries, &mut overlay);
+        }
+        let functions = &contents.functions;
+        contents.function_tags = resolve_tag_builders(
+            &|key: &Identifier| functions.contains_key(key),
+            &function_tags,
+            None,
+        );
+        contents.tag_count = overlay.len() + contents.function_tags.len();
+        contents.registry_entry_count = registries.len();
+
+        self.save_selection()?;
+        log::info!(
+            "Loaded {} data pack(s): {} registry entries, {} tags, {} recipes, {} loot tables, {} advancements, {} functions",
+            enabled.len(),
+            contents.registry_entry_count,
+            contents.tag_count,
+            contents.recipes.len(),
+            contents.loot_tables.len(),
+            contents.advancements.len(),
+            contents.functions.len()
+        );
+        set_registry_overlay(registries);
+        set_tag_overlay(overlay);
+        self.contents.store(Arc::new(contents));
+        Ok(())
+    }
+
+    fn discover(&self) -> Result<Vec<DataPackInfo>, String> {
+        let mut packs = vec![DataPackInfo::vanilla()];
+        if !self.directory.exists() {
+            fs::create_dir_all(&self.directory).map_err(|error| {
+                format!("failed to create {}: {error}", self.directory.display())
+            })?;
+            return Ok(packs);
+        }
+        let entries = fs::read_dir(&self.directory)
+            .map_err(|error| format!("failed to read {}: {error}", self.directory.display()))?;
+        let mut discovered = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            // TODO: support zipped packs
+            if !path.is_dir() {
+                continue;
+            }
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            match read_pack_description(&path) {
+                Ok(description) => discovered.push(DataPackInfo {
+                    id: format!("{FILE_PACK_PREFIX}{name}"),
+                    description,
+                    path: Some(path.clone()),
+                }),
+                Err(error) => log::warn!("Skipping data pack {name}: {error}"),
+            }
+        }
+        discovered.sort_by(|a, b| a.id.cmp(&b.id));
+        packs.extend(discovered);
+        Ok(packs)
+    }
+
+    fn read_selection(&self) -> Result<SelectionFile, String> {
+        match fs::read_to_string(&self.selection_path) {
+            Ok(content) => serde_json::from_str(&content).map_err(|error| {
+                format!("failed to parse {}: {error}", self.selection_path.display())
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(SelectionFile::default()),
+            Err(error) => Err(format!(
+                "failed to read {}: {error}",
+                self.selection_path.display()
+            )),
+        }
+    }
+
+    fn save_selection(&self) -> Result<(), String> {
+        let file = {
+            let selection = self.selection.lock();
+            let mut disabled: Vec<String> = selection.disabled.iter().cloned().collect();
+            disabled.sort();
+            SelectionFile {
+                enabled: selection.enabled.clone(),
+                disabled,
+            }
+        };
+        let content = serde_json::to_string_pretty(&file)
+            .map_err(|error| format!("failed to serialize data pack selection: {error}"))?;
+        fs::write(&self.selection_path, content)
+            .map_err(|error| format!("failed to write {}: {error}", self.selection_path.display()))
+    }
+
+    fn resolve_tags(
+        &self,
+        registry: &TagRegistry,
+        builders: &FxHashMap<Identifier, TagBuilder>,
+        registries: &RegistryOverlay,
+        overlay: &mut TagOverlay,
+    ) {
+        let contains = |key: &Identifier| {
+            (registry.contains)(key) || registries.get(&registry.key, key).is_some()
+        };
+        let resolved = resolve_tag_builders(&contains, builders, self.base_tags.get(&registry.key));
+        for (tag, entries) in resolved {
+            overlay.insert(registry.key.clone(), tag, entries);
+        }
+    }
+}
+
+/// Resolves every tag in `builders`, logging the ones with missing references.
+fn resolve_tag_builders(
+    contains: &dyn Fn(&Identifier) -> bool,
+    builders: &FxHashMap<Identifier, TagBuilder>,
+    base: Option<&TagMap>,
+) -> TagMap {
+    let mut resolver = TagResolver {
+        contains,
+        builders,
+        base,
+        resolved: FxHashMap::default(),
+        failed: FxHashSet::default(),
+        stack: Vec::new(),
+    };
+    for tag in builders.keys() {
+        if let Err(missing) = resolver.resolve(tag) {
+            log::error!(
+                "Couldn't load tag {tag} as it is missing following references: {}",
+                missing.join(", ")
+            );
+        }
+    }
+    resolver.resolved
+}
+
+/// Resolves datapack tags of one registry, following `#tag` references.
+struct TagResolver<'a> {
+    contains: &'a dyn Fn(&Identifier) -> bool,
+    builders: &'a FxHashMap<Identifier, TagBuilder>,
+    base: Option<&'a TagMap>,
+    resolved: TagMap,
+    failed: FxHashSet<Identifier>,
+    stack: Vec<Identifier>,
+}
+
+impl TagResolver<'_> {
+    fn contains(&self, key: &Identifier) -> bool {
+        (self.contains)(key)
+    }
+
+    fn base_tag(&self, tag: &Identifier) -> Option<Vec<Identifier>> {
+        self.base.and_then(|base| base.get(tag)).cloned()
+    }
+
+    fn resolve(&mut self, tag: &Identifier) -> Result<(), Vec<String>> {
+        if self.resolved.contains_key(tag) {
+            return Ok(());
+        }
+        if self.failed.contains(tag) || self.stack.contains(tag) {
+            return Err(vec![format!("#{tag}")]);
+        }
+        let builders = self.builders;
+        let Some(builder) = builders.get(tag) else {
+            return Ok(());
+        };
+        self.stack.push(tag.clone());
+
+        let mut entries = if builder.replace {
+            Vec::new()
+        } else {
+            self.base_tag(tag).unwrap_or_default()
+        };
+        let mut missing = Vec::new();
+        for (value, required) in &builder.entries {
+            if let Some(reference) = value.strip_prefix('#') {
+                let Ok(reference) = reference.parse::<Identifier>() else {
+                    missing.push(value.clone());
+                    continue;
+                };
+                let nested = if builders.contains_key(&reference) {
+                    self.resolve(&reference)
+                        .ok()
+                        .and_then(|()| self.resolved.get(&reference).cloned())
+                } else {
+                    self.base_tag(&reference)
+                };
+                match nested {
+                    Some(nested) => entries.extend(nested),
+                    None if *required => missing.push(value.clone()),
+                    None => {}
+                }
+            } else {
+                match value.parse::<Identifier>() {
+                    Ok(key) if self.contains(&key) => entries.push(key),
+                    _ if *required => missing.push(value.clone()),
+                    _ => {}
+                }
+            }
+        }
+
+        self.stack.pop();
+        if !missing.is_empty() {
+            self.failed.insert(tag.clone());
+            return Err(missing);
+        }
+        let mut seen = FxHashSet::default();
+        entries.retain(|key| seen.insert(key.clone()));
+        self.resolved.insert(tag.clone(), entries);
+        Ok(())
+    }
+}
+
+fn capture_base_tags() -> FxHashMap<Identifier, TagMap> {
+    let mut base_tags = FxHashMap::default();
+    macro_rules! capture {
+        ($reg_key:expr, $field:ident) => {
+            let tags: TagMap = REGISTRY
+                .$field
+                .tag_keys()
+                .map(|tag| {
+                    let entries = REGISTRY
+                        .$field
+                        .iter_tag(tag)
+                        .map(|entry| entry.key().clone())
+                        .collect();
+                    (tag.clone(), entries)
+                })
+                .collect();
+            base_tags.insert($reg_key, tags);
+        };
+    }
+    capture!(BLOCKS_REGISTRY, blocks);
+    capture!(ITEMS_REGISTRY, items);
+    capture!(ENTITY_TYPE_REGISTRY, entity_types);
+    capture!(FLUID_REGISTRY, fluids);
+    capture!(BIOMES_REGISTRY, biomes);
+    capture!(DAMAGE_TYPE_REGISTRY, damage_types);
+    capture!(DIALOG_REGISTRY, dialogs);
+    base_tags
+}
+
+fn read_pack_description(path: &Path) -> Result<TextComponent, String> {
+    let content = fs::read_to_string(path.join("pack.mcmeta"))
+        .map_err(|error| format!("failed to read pack.mcmeta: {error}"))?;
+    let mcmeta: PackMcmeta =
+        serde_json::from_str(&content).map_err(|error| format!("invalid pack.mcmeta: {error}"))?;
+    let pack = mcmeta.pack;
+    if pack.pack_format.is_none() && (pack.min_format.is_none() || pack.max_format.is_none()) {
+        return Err("pack.mcmeta declares neither pack_format nor min_format/max_format".into());
+    }
+    component_from_json_value(&pack.description)
+        .map_err(|error| format!("invalid pack description: {error}"))
+}
+
+fn load_pack(
+    data: &Path,
+    contents: &mut DataPackContents,
+    registries: &mut RegistryOverlay,
+    tags: &mut [FxHashMap<Identifier, TagBuilder>],
+    function_tags: &mut FxHashMap<Identifier, TagBuilder>,
+) -> io::Result<()> {
+    if !data.is_dir() {
+        return Ok(());
+    }
+    for namespace in fs::read_dir(data)? {
+        let namespace = namespace?.path();
+        let Some(name) = namespace.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !namespace.is_dir() {
+            continue;
+        }
+
+        let tag_directories = TAG_REGISTRIES
+            .iter()
+            .map(|registry| registry.directory)
+            .zip(tags.iter_mut())
+            .chain([(FUNCTION_TAGS_DIRECTORY, &mut *function_tags)]);
+        for (directory, builders) in tag_directories {
+            visit_files(&namespace.join(directory), "json", &mut |path, file| {
+                let Some(file) = read_json::<TagFile>(file) else {
+                    return;
+                };
+                let builder = builders
+                    .entry(Identifier::new(name.to_owned(), path))
+                    .or_default();
+                if file.replace {
+                    builder.replace = true;
+                    builder.entries.clear();
+                }
+                builder
+                    .entries
+                    .extend(file.values.into_iter().map(|value| match value {
+                        TagValue::Id(id) => (id, true),
+                        TagValue::Entry { id, required } => (id, required),
+                    }));
+            })?;
+        }
+
+        for (registry, directory) in &OVERLAY_REGISTRIES {
+            visit_files(&namespace.join(directory), "json", &mut |path, file| {
+                let Some(document) = read_json::<Value>(file) else {
+                    return;
+                };
+                match json_to_nbt(&document) {
+                    Some(data) => registries.insert(
+                        registry.clone(),
+                        Identifier::new(name.to_owned(), path),
+                        data,
+                    ),
+                    None => log::error!("Failed to convert {} to NBT", file.display()),
+                }
+            })?;
+        }
+
+        for (directory, documents) in [
+            ("recipe", &mut contents.recipes),
+            ("loot_table", &mut contents.loot_tables),
+            ("advancement", &mut contents.advancements),
+        ] {
+            visit_files(&namespace.join(directory), "json", &mut |path, file| {
+                if let Some(document) = read_json::<Value>(file) {
+                    documents.insert(Identifier::new(name.to_owned(), path), document);
+                }
+            })?;
+        }
+
+        visit_files(
+            &namespace.join("function"),
+            "mcfunction",
+            &mut |path, file| match fs::read_to_string(file) {
+                Ok(source) => {
+                    let lines: Arc<[String]> = source
+                        .lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                        .map(str::to_owned)
+                        .collect();
+                    contents
+                        .functions
+                        .insert(Identifier::new(name.to_owned(), path), lines);
+                }
+                Err(error) => log::error!("Failed to read {}: {error}", file.display()),
+            },
+        )?;
+    }
+    Ok(())
+}
+
+fn read_json<T: DeserializeOwned>(file: &Path) -> Option<T> {
+    let parsed = fs::read_to_string(file)
+        .map_err(|error| error.to_string())
+        .and_then(|content| serde_json::from_str(&content).map_err(|error| error.to_string()));
+    match parsed {
+        Ok(value) => Some(value),
+        Err(error) => {
+            log::error!("Failed to load {}: {error}", file.display());
+            None
+        }
+    }
+}
+
+/// Calls `f` with the slash-separated path, without extension, of every file
+/// below `root` with `extension`.
+fn visit_files(root: &Path, extension: &str, f: &mut impl FnMut(String, &Path)) -> io::Result<()> {
+    fn walk(
+        root: &Path,
+        dir: &Path,
+        extension: &str,
+        f: &mut impl FnMut(String, &Path),
+    ) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                walk(root, &path, extension, f)?;
+            } else if path.extension().and_then(|ext| ext.to_str()) == Some(extension) {
+                let Ok(relative) = path
+                    .with_extension("")
+                    .strip_prefix(root)
+                    .map(Path::to_owned)
+                else {
+                    continue;
+                };
+                let key = relative
+                    .components()
+                    .filter_map(|component| component.as_os_str().to_str())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                f(key, &path);
+            }
+        }
+        Ok(())
+    }
+
+    if !root.is_dir() {
+        return Ok(());
+    }
+    walk(root, root, extension, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env::temp_dir,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use steel_registry::{
+        registry_overlay::registry_overlay, tag_overlay::tag_overlay,
+        test_support::init_test_registry,
+    };
+
+    use super::*;
+
+    fn temp_save(name: &str) -> PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos());
+        temp_dir().join(format!("steel-datapacks-{name}-{unique}"))
+    }
+
+    fn write(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            assert!(fs::create_dir_all(parent).is_ok());
+        }
+        assert!(fs::write(path, content).is_ok());
+    }
+
+    #[test]
+    fn tags_resolve_against_base_and_other_pack_tags() {
+        init_test_registry();
+        let save = temp_save("tags");
+        let pack = save.join(DATAPACKS_DIRECTORY).join("test");
+        write(
+            &pack.join("pack.mcmeta"),
+            r#"{"pack": {"description": "Test", "pack_format": 88}}"#,
+        );
+        let tags = pack.join("data/test/tags/item");
+        write(
+            &tags.join("stones.json"),
+            r#"{"values": ["minecraft:stone", {"id": "test:missing", "required": false}]}"#,
+        );
+        write(
+            &tags.join("combined.json"),
+            r##"{"replace": true, "values": ["#test:stones", "#minecraft:logs"]}"##,
+        );
+        write(&tags.join("broken.json"), r#"{"values": ["test:missing"]}"#);
+        write(
+            &pack.join("data/test/damage_type/custom.json"),
+            r#"{"message_id": "custom", "scaling": "never", "exhaustion": 0.1}"#,
+        );
+        write(
+            &pack.join("data/test/tags/damage_type/customs.json"),
+            r#"{"values": ["test:custom"]}"#,
+        );
+        write(
+            &pack.join("data/test/function/hello.mcfunction"),
+            "# comment\nsay hello\n\n",
+        );
+        write(
+            &pack.join("data/minecraft/tags/function/load.json"),
+            r##"{"values": ["test:hello", "#test:greetings"]}"##,
+        );
+        write(
+            &pack.join("data/test/tags/function/greetings.json"),
+            r#"{"values": ["test:hello", {"id": "test:missing", "required": false}]}"#,
+        );
+
+        let Ok(manager) = DataPackManager::load(&save) else {
+            panic!("data packs should load");
+        };
+        let overlay = tag_overlay();
+        let registries = registry_overlay();
+        set_tag_overlay(TagOverlay::default());
+        set_registry_overlay(RegistryOverlay::default());
+
+        let custom = Identifier::new("test".to_owned(), "custom".to_owned());
+        assert!(
+            registries
+                .is_some_and(|registries| registries.get(&DAMAGE_TYPE_REGISTRY, &custom).is_some())
+        );
+
+        let Some(overlay) = overlay else {
+            panic!("overlay should be installed");
+        };
+        let tags: FxHashMap<&Identifier, &[Identifier]> = overlay.tags(&ITEMS_REGISTRY).collect();
+        let stones = Identifier::new("test".to_owned(), "stones".to_owned());
+        let combined = Identifier::new("test".to_owned(), "combined".to_owned());
+        let broken = Identifier::new("test".to_owned(), "broken".to_owned());
+        assert_eq!(
+            tags.get(&stones).copied(),
+            Some([Identifier::vanilla_static("stone")].as_slice())
+        );
+        let Some(combined) = tags.get(&combined) else {
+            panic!("combined tag should resolve");
+        };
+        assert_eq!(combined.first(), Some(&Identifier::vanilla_static("stone")));
+        assert!(combined.contains(&Identifier::vanilla_static("oak_log")));
+        assert!(!tags.contains_key(&broken));
+        let customs = Identifier::new("test".to_owned(), "customs".to_owned());
+        assert!(
+            overlay
+                .tags(&DAMAGE_TYPE_REGISTRY)
+                .any(|(tag, entries)| *tag == customs && entries == [custom.clone()])
+        );
+
+        let hello = Identifier::new("test".to_owned(), "hello".to_owned());
+        assert_eq!(
+            manager.function(&hello).as_deref(),
+            Some(["say hello".to_owned()].as_slice())
+        );
+        assert_eq!(
+            manager.function_tag(&Identifier::vanilla_static("load")),
+            Some(vec![hello.clone()])
+        );
+        assert_eq!(
+            manager
+                .enabled()
+                .iter()
+                .map(|pack| pack.id.as_str())
+                .collect::<Vec<_>>(),
+            [VANILLA_PACK_ID, "file/test"]
+        );
+
+        let _ = fs::remove_dir_all(save);
+    }
+
+    #[test]
+    fn selection_changes_validate_pack_state() {
+        init_test_registry();
+        let save = temp_save("selection");
+        let manager = DataPackManager::new(&save);
+        assert_eq!(manager.enable("file/nope"), Err(DataPackError::Unknown));
+        assert_eq!(
+            manager.enable(VANILLA_PACK_ID),
+            Err(DataPackError::AlreadyEnabled)
+        );
+        assert_eq!(
+            manager.disable(VANILLA_PACK_ID),
+            Err(DataPackError::Required)
+        );
+    }
+}
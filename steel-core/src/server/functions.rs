@@ -0,0 +1,252 @@
+//! Datapack functions run by the game loop, `/function` and `/schedule`.
+//!
+//! Functions tagged `#minecraft:load` run on the first tick after startup and
+//! after every datapack reload. Functions tagged `#minecraft:tick` run at the
+//! start of every tick. Together with scheduled functions they share a budget
+//! of `maxCommandSequenceLength` commands per tick; once it is spent, the
+//! remaining function commands of that tick are skipped. `/function` gets a
+//! budget of its own for each run.
+//!
+//! Scheduled functions are tasks on the server [`Scheduler`](super::scheduler::Scheduler)
+//! and are not persisted.
+
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+
+use rustc_hash::FxHashMap;
+use steel_registry::vanilla_game_rules::MAX_COMMAND_SEQUENCE_LENGTH;
+use steel_utils::Identifier;
+use steel_utils::locks::SyncMutex;
+
+use crate::command::execution::CommandSource;
+use crate::command::sender::CommandSender;
+use crate::server::Server;
+use crate::server::scheduler::TaskHandle;
+
+/// Functions run after startup and every reload.
+pub const LOAD_FUNCTION_TAG: Identifier = Identifier::vanilla_static("load");
+/// Functions run at the start of every tick.
+pub const TICK_FUNCTION_TAG: Identifier = Identifier::vanilla_static("tick");
+
+/// A function or a function tag, as `/function` and `/schedule` name them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FunctionRef {
+    /// A single function.
+    Function(Identifier),
+    /// Every function of a function tag.
+    Tag(Identifier),
+}
+
+impl fmt::Display for FunctionRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Function(id) => write!(f, "{id}"),
+            Self::Tag(id) => write!(f, "#{id}"),
+        }
+    }
+}
+
+/// Per-tick state of the function runner.
+pub struct ServerFunctions {
+    /// Whether the load functions still have to run.
+    post_reload: AtomicBool,
+    /// Game loop function commands that may still run this tick.
+    budget: AtomicI32,
+    /// Pending `/schedule` tasks by the function or tag they run.
+    scheduled: SyncMutex<FxHashMap<FunctionRef, Vec<TaskHandle>>>,
+}
+
+impl Default for ServerFunctions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ServerFunctions {
+    /// Creates a runner that runs the load functions on its first tick.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            post_reload: AtomicBool::new(true),
+            budget: AtomicI32::new(0),
+            scheduled: SyncMutex::new(FxHashMap::default()),
+        }
+    }
+
+    /// Runs the load functions again on the next tick.
+    pub fn mark_reloaded(&self) {
+        self.post_reload.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Takes one command from `budget`. Returns `false` once it is spent.
+fn take_budget(budget: &AtomicI32) -> bool {
+    budget
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |budget| {
+            (budget > 0).then(|| budget - 1)
+        })
+        .is_ok()
+}
+
+impl Server {
+    /// Vanilla `ServerFunctionManager.tick`: runs the load functions after a
+    /// reload, then the tick functions.
+    pub(crate) fn tick_functions(self: &Arc<Self>) {
+        let _span = tracing::trace_span!("functions").entered();
+        let limit = self.overworld().get_game_rule(&MAX_COMMAND_SEQUENCE_LENGTH);
+        self.functions.budget.store(limit.max(0), Ordering::Relaxed);
+
+        if self.functions.post_reload.swap(false, Ordering::Relaxed) {
+            self.run_function_tag(&LOAD_FUNCTION_TAG);
+        }
+        self.run_function_tag(&TICK_FUNCTION_TAG);
+    }
+
+    /// Vanilla `ServerFunctionManager.getGameLoopSender`: the silent source
+    /// tagged and scheduled functions run as.
+    pub(crate) fn game_loop_source(self: &Arc<Self>) -> CommandSource {
+        CommandSource::new(CommandSender::Console, Arc::clone(self)).with_suppressed_output()
+    }
+
+    /// Returns the functions `function` names, or `None` if it names an
+    /// unknown function or tag.
+    pub(crate) fn resolve_functions(&self, function: &FunctionRef) -> Option<Vec<Identifier>> {
+        match function {
+            FunctionRef::Function(id) => self.datapacks.function(id).map(|_| vec![id.clone()]),
+            FunctionRef::Tag(id) => self.datapacks.function_tag(id),
+        }
+    }
+
+    /// Runs function `id` as `source`, one command per line.
+    ///
+    /// Returns how many of its commands succeeded, or `None` if there is no
+    /// such function. Stops early once `budget` is spent.
+    pub(crate) fn run_function(
+        &self,
+        id: &Identifier,
+        source: &CommandSource,
+        budget: &AtomicI32,
+    ) -> Option<i32> {
+        let lines = self.datapacks.function(id)?;
+        let mut successes = 0;
+        for (index, line) in lines.iter().enumerate() {
+            if !take_budget(budget) {
+                // Only the function that spent the budget reports it.
+                if index > 0 {
+                    tracing::warn!(
+                        "Function {id} reached the command limit, skipping the remaining commands"
+                    );
+                }
+                break;
+            }
+            successes += self.perform_command(source.clone(), line);
+        }
+        Some(successes)
+    }
+
+    /// Vanilla `ServerFunctionManager.execute`: runs function `id` as `source`
+    /// with a fresh `maxCommandSequenceLength` budget.
+    pub(crate) fn execute_function(&self, id: &Identifier, source: &CommandSource) -> Option<i32> {
+        let limit = source.world().get_game_rule(&MAX_COMMAND_SEQUENCE_LENGTH);
+        self.run_function(id, source, &AtomicI32::new(limit.max(0)))
+    }
+
+    fn run_game_loop_functions(self: &Arc<Self>, functions: &[Identifier]) {
+        let source = self.game_loop_source();
+        for function in functions {
+            self.run_function(function, &source, &self.functions.budget);
+        }
+    }
+
+    fn run_function_tag(self: &Arc<Self>, tag: &Identifier) {
+        if let Some(functions) = self.datapacks.function_tag(tag) {
+            self.run_game_loop_functions(&functions);
+        }
+    }
+
+    /// Vanilla `/schedule function`: runs `function` after `delay` ticks.
+    ///
+    /// With `replace`, earlier schedules of the same function are cancelled.
+    pub(crate) fn schedule_function(
+        self: &Arc<Self>,
+        function: FunctionRef,
+        delay: u32,
+        replace: bool,
+    ) {
+        let mut scheduled = self.functions.scheduled.lock();
+        let handles = scheduled.entry(function.clone()).or_default();
+        handles.retain(|handle| !handle.is_cancelled());
+        if replace {
+            for handle in handles.drain(..) {
+                handle.cancel();
+            }
+        }
+
+        let name = format!("schedule {function}");
+        let task_function = function.clone();
+        let handle = self
+            .scheduler
+            .run_later(name, u64::from(delay), move |server| {
+                server.run_scheduled_function(&task_function);
+            });
+        handles.push(handle);
+    }
+
+    /// Vanilla `/schedule clear`: cancels every pending schedule of `function`
+    /// and returns how many there were.
+    pub(crate) fn clear_scheduled_function(&self, function: &FunctionRef) -> usize {
+        let Some(handles) = self.functions.scheduled.lock().remove(function) else {
+            return 0;
+        };
+        handles
+            .into_iter()
+            .filter(|handle| !handle.is_cancelled())
+            .inspect(TaskHandle::cancel)
+            .count()
+    }
+
+    fn run_scheduled_function(self: &Arc<Self>, function: &FunctionRef) {
+        {
+            let mut scheduled = self.functions.scheduled.lock();
+            if let Some(handles) = scheduled.get_mut(function) {
+                handles.retain(|handle| !handle.is_cancelled());
+                // The task that is running now is the earliest one still pending.
+                if !handles.is_empty() {
+                    handles.remove(0);
+                }
+                if handles.is_empty() {
+                    scheduled.remove(function);
+                }
+            }
+        }
+
+        let Some(functions) = self.resolve_functions(function) else {
+            tracing::warn!("Scheduled function {function} no longer exists");
+            return;
+        };
+        self.run_game_loop_functions(&functions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_runs_out_and_function_refs_display_like_vanilla() {
+        let budget = AtomicI32::new(2);
+        assert!(take_budget(&budget));
+        assert!(take_budget(&budget));
+        assert!(!take_budget(&budget));
+
+        let functions = ServerFunctions::new();
+        assert!(functions.post_reload.swap(false, Ordering::Relaxed));
+        functions.mark_reloaded();
+        assert!(functions.post_reload.load(Ordering::Relaxed));
+
+        let id = Identifier::new_static("test", "hello");
+        assert_eq!(FunctionRef::Function(id.clone()).to_string(), "test:hello");
+        assert_eq!(FunctionRef::Tag(id).to_string(), "#test:hello");
+    }
+}
@@ -0,0 +1,215 @@
+//! Players waiting for a free slot on a full server.
+//!
+//! Joining players hold a [`JoinTicket`] from the end of configuration until
+//! they are reserved as joining. While the server is full, tickets line up in
+//! arrival order and are admitted as slots free; with the queue disabled they
+//! are turned away like in vanilla.
+
+use std::{collections::VecDeque, sync::Arc};
+
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_utils::locks::SyncMutex;
+use text_components::{EmbeddedNbtCodec as _, TextComponent};
+use uuid::Uuid;
+
+/// The outcome of asking for a slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinQueuePoll {
+    /// The player has a slot and may finish configuration.
+    Admitted,
+    /// The player waits at this 1-based position.
+    Waiting(usize),
+    /// The server is full and the queue is disabled.
+    Full,
+}
+
+/// Slot accounting for players that have not been reserved as joining yet.
+#[derive(Default)]
+pub struct JoinQueue {
+    state: SyncMutex<JoinQueueState>,
+}
+
+#[derive(Default)]
+struct JoinQueueState {
+    waiting: VecDeque<Uuid>,
+    /// Admitted tickets still finishing configuration.
+    admitting: usize,
+}
+
+/// A joining player's claim on a slot. Dropping it gives up the queue
+/// position or the admitted slot.
+pub struct JoinTicket {
+    queue: Arc<JoinQueue>,
+    uuid: Uuid,
+    admitted: bool,
+}
+
+impl JoinQueue {
+    /// Creates a ticket for `uuid`, which is not queued until first polled.
+    #[must_use]
+    pub fn ticket(self: &Arc<Self>, uuid: Uuid) -> JoinTicket {
+        JoinTicket {
+            queue: Arc::clone(self),
+            uuid,
+            admitted: false,
+        }
+    }
+
+    /// Admits the ticket if a slot is free for its position, counting
+    /// `occupied` players plus tickets admitted earlier against `max_players`.
+    ///
+    /// Players ahead in the queue keep their slot until they poll, so a
+    /// newcomer never takes a slot freed for someone already waiting.
+    pub(crate) fn poll(
+        &self,
+        ticket: &mut JoinTicket,
+        occupied: usize,
+        max_players: usize,
+        bypass: bool,
+        enabled: bool,
+    ) -> JoinQueuePoll {
+        if ticket.admitted {
+            return JoinQueuePoll::Admitted;
+        }
+        let mut state = self.state.lock();
+        let queued = state.waiting.iter().position(|uuid| *uuid == ticket.uuid);
+        let position = queued.unwrap_or(state.waiting.len());
+        let free = max_players.saturating_sub(occupied + state.admitting);
+
+        if bypass || position < free {
+            if let Some(index) = queued {
+                let _ = state.waiting.remove(index);
+            }
+            state.admitting += 1;
+            ticket.admitted = true;
+            return JoinQueuePoll::Admitted;
+        }
+        if !enabled {
+            if let Some(index) = queued {
+                let _ = state.waiting.remove(index);
+            }
+            return JoinQueuePoll::Full;
+        }
+        if queued.is_none() {
+            state.waiting.push_back(ticket.uuid);
+        }
+        JoinQueuePoll::Waiting(position + 1)
+    }
+
+    /// Returns how many players are waiting.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.state.lock().waiting.len()
+    }
+
+    /// Returns whether no player is waiting.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().waiting.is_empty()
+    }
+}
+
+impl Drop for JoinTicket {
+    fn drop(&mut self) {
+        let mut state = self.queue.state.lock();
+        if self.admitted {
+            state.admitting = state.admitting.saturating_sub(1);
+        } else if let Some(index) = state.waiting.iter().position(|uuid| *uuid == self.uuid) {
+            let _ = state.waiting.remove(index);
+        }
+    }
+}
+
+/// The notice shown to a waiting player, replaced on every position update.
+#[must_use]
+pub fn queue_position_dialog(position: usize, waiting: usize) -> NbtTag {
+    let mut body = NbtCompound::new();
+    body.insert("type", "minecraft:plain_message");
+    body.insert(
+        "contents",
+        TextComponent::plain(format!(
+            "You are number {position} of {waiting} in the queue. You will join when a slot frees up."
+        ))
+        .to_codec_nbt(),
+    );
+
+    let mut dialog = NbtCompound::new();
+    dialog.insert("type", "minecraft:notice");
+    dialog.insert(
+        "title",
+        TextComponent::plain("The server is full").to_codec_nbt(),
+    );
+    dialog.insert("body", NbtTag::List(NbtList::Compound(vec![body])));
+    dialog.insert("can_close_with_escape", i8::from(false));
+    NbtTag::Compound(dialog)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue() -> Arc<JoinQueue> {
+        Arc::new(JoinQueue::default())
+    }
+
+    #[test]
+    fn waits_in_arrival_order_until_a_slot_frees() {
+        let queue = queue();
+        let mut first = queue.ticket(Uuid::from_u128(1));
+        let mut second = queue.ticket(Uuid::from_u128(2));
+
+        assert_eq!(
+            queue.poll(&mut first, 2, 2, false, true),
+            JoinQueuePoll::Waiting(1)
+        );
+        assert_eq!(
+            queue.poll(&mut second, 2, 2, false, true),
+            JoinQueuePoll::Waiting(2)
+        );
+        // One slot frees: only the head of the queue may take it.
+        assert_eq!(
+            queue.poll(&mut second, 1, 2, false, true),
+            JoinQueuePoll::Waiting(2)
+        );
+        assert_eq!(
+            queue.poll(&mut first, 1, 2, false, true),
+            JoinQueuePoll::Admitted
+        );
+        assert_eq!(
+            queue.poll(&mut second, 1, 2, false, true),
+            JoinQueuePoll::Waiting(1)
+        );
+
+        drop(first);
+        assert_eq!(
+            queue.poll(&mut second, 1, 2, false, true),
+            JoinQueuePoll::Admitted
+        );
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn bypass_skips_the_queue_and_disabled_queue_turns_away() {
+        let queue = queue();
+        let mut waiting = queue.ticket(Uuid::from_u128(1));
+        let mut priority = queue.ticket(Uuid::from_u128(2));
+        let mut turned_away = queue.ticket(Uuid::from_u128(3));
+
+        assert_eq!(
+            queue.poll(&mut waiting, 1, 1, false, true),
+            JoinQueuePoll::Waiting(1)
+        );
+        assert_eq!(
+            queue.poll(&mut priority, 1, 1, true, true),
+            JoinQueuePoll::Admitted
+        );
+        assert_eq!(
+            queue.poll(&mut turned_away, 1, 1, false, false),
+            JoinQueuePoll::Full
+        );
+        assert_eq!(queue.len(), 1);
+
+        drop(waiting);
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+//! Server-side translations.
+//!
+//! Clients translate vanilla keys themselves. Keys added by the server or by
+//! plugins are loaded from `<locale>.json` files, such as `de_de.json`, and
+//! resolved in each player's language before components are sent.
+
+use std::path::Path;
+use std::{fs, io};
+
+use rustc_hash::FxHashMap;
+use steel_utils::locks::SyncRwLock;
+
+/// Locale used when a player's language has no translation for a key.
+pub const DEFAULT_LOCALE: &str = "en_us";
+
+/// Translations by locale, then key.
+#[derive(Default)]
+pub struct ServerTranslations {
+    locales: SyncRwLock<FxHashMap<String, FxHashMap<String, String>>>,
+}
+
+impl ServerTranslations {
+    /// Creates an empty translation table.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads every `<locale>.json` file in `dir`, merging into the keys already
+    /// loaded. A missing directory loads nothing.
+    ///
+    /// Returns the number of files loaded. Invalid files are skipped with a
+    /// warning.
+    pub fn load_dir(&self, dir: &Path) -> io::Result<usize> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(error) => return Err(error),
+        };
+        let mut loaded = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+            let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let translations = match fs::read_to_string(&path)
+                .map_err(|error| error.to_string())
+                .and_then(|json| {
+                    serde_json::from_str::<FxHashMap<String, String>>(&json)
+                        .map_err(|error| error.to_string())
+                }) {
+                Ok(translations) => translations,
+                Err(error) => {
+                    log::warn!("Skipping translations {}: {error}", path.display());
+                    continue;
+                }
+            };
+            self.locales
+                .write()
+                .entry(locale.to_ascii_lowercase())
+                .or_default()
+                .extend(translations);
+            loaded += 1;
+        }
+        Ok(loaded)
+    }
+
+    /// Adds or replaces the translation of `key` in `locale`.
+    pub fn insert(&self, locale: &str, key: impl Into<String>, value: impl Into<String>) {
+        self.locales
+            .write()
+            .entry(locale.to_ascii_lowercase())
+            .or_default()
+            .insert(key.into(), value.into());
+    }
+
+    /// Translates `key` into `locale`, falling back to [`DEFAULT_LOCALE`].
+    #[must_use]
+    pub fn translate(&self, locale: &str, key: &str) -> Option<String> {
+        let locales = self.locales.read();
+        [locale.to_ascii_lowercase().as_str(), DEFAULT_LOCALE]
+            .into_iter()
+            .find_map(|locale| locales.get(locale)?.get(key).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translate_falls_back_to_the_default_locale() {
+        let translations = ServerTranslations::new();
+        translations.insert("en_us", "steel.greeting", "Hello");
+        translations.insert("de_DE", "steel.greeting", "Hallo");
+        translations.insert("en_us", "steel.farewell", "Bye");
+
+        assert_eq!(
+            translations.translate("de_de", "steel.greeting").as_deref(),
+            Some("Hallo")
+        );
+        assert_eq!(
+            translations.translate("de_de", "steel.farewell").as_deref(),
+            Some("Bye")
+        );
+        assert_eq!(translations.translate("fr_fr", "steel.unknown"), None);
+    }
+}
@@ -1,11 +1,41 @@
 //! This module contains the `Server` struct, which is the main entry point for the server.
+/// Encode-once packet fan-out.
+pub mod broadcast;
+/// Chat formatting.
+pub mod chat;
+/// Chat filters run before broadcast.
+pub mod chat_filter;
+/// Command aliases from `commands.toml`.
+mod command_aliases;
+/// Runtime configuration reloading.
+pub mod config_reload;
+/// Datapack loading and reloading.
+pub mod datapacks;
+/// Datapack functions run by the game loop and `/schedule`.
+pub mod functions;
 /// Tick-polled server jobs.
 pub mod jobs;
+/// Players waiting for a free slot on a full server.
+pub mod join_queue;
+/// Server-side translations.
+pub mod localization;
+/// Plugin message channel routing.
+pub mod plugin_channels;
 mod pregen;
+/// Sampling profiler over tracing spans.
+pub mod profiler;
 /// The registry cache for the server.
 pub mod registry_cache;
+/// Task scheduling for subsystems and plugins.
+pub mod scheduler;
+/// Structure templates saved by structure blocks.
+pub mod structure_templates;
+/// Tab list headers, footers and entry presentation.
+pub mod tab_list;
 /// The tick rate manager for the server.
 pub mod tick_rate_manager;
+/// Main tick stall detection.
+pub mod watchdog;
 /// Domain-aware loaded world map.
 pub mod worlds;
 
@@ -26,9 +56,13 @@ use crate::command::{
     COMMAND_REQUESTS_PER_TICK, COMMAND_RESUMPTIONS_PER_TICK, CommandCompletion, CommandDispatcher,
     CommandQueueFull, CommandRegistry, CommandRequest, CommandRequestQueue,
     PendingCommandExecutionQueue, client_permission_event, command_suggestions_packet,
-    command_tree_packet, create_registered_dispatcher,
+    command_tree_packet, create_registered_dispatcher, join_queue_bypass_permission_expr,
 };
-use crate::config::{ResolvedWorldConfig, RuntimeConfig, WorldsConfig, validate_login_security};
+use crate::config::{
+    ItemsConfig, ResolvedWorldConfig, RuntimeConfig, SharedRuntimeConfig, WorldsConfig,
+    validate_login_security,
+};
+use crate::crash_report::{CrashReport, UNAVAILABLE};
 use crate::entity::{
     Entity, EntityBase, PendingWorldChangeToken, RemovalReason, SharedEntity, change_entity_world,
     init_entities,
@@ -36,42 +70,63 @@ use crate::entity::{
 
 use crate::chunk_saver::{ChunkStorage, PersistentEntity, registry::WorldStorageRegistry};
 use crate::level_data::{LevelDataManager, RespawnData, WorldGenerationSettings};
+use crate::map::storage::DomainMaps;
 use crate::permission::{
     OP_GROUP, PermissionGroupManager, PermissionGroupManagerError, PermissionGroupUpdateError,
     PermissionGroupsConfig, PermissionMetadataExpression, PermissionRuleExpression, PermissionSet,
     PermissionSubjectIndex, PermissionSubjectState,
 };
-use crate::player::chunk_sender::{ChunkSender, EncodedChunk};
+use crate::player::chunk_sender::ChunkSender;
 use crate::player::connection::NetworkConnection;
 use crate::player::player_data::{
     PersistentEnderPearl, PersistentPlayerData, PersistentRootVehicle,
 };
 use crate::player::player_data_storage::{GlobalPlayerData, PlayerDataStorage};
 use crate::player::{
-    GameProfile, KnownPlayer, KnownPlayerNameLookup, KnownPlayers, Player, ProfileLookupError,
-    ResetReason, is_valid_player_name, lookup_online_profile, offline_uuid,
+    GameProfile, KnownPlayer, KnownPlayerNameLookup, KnownPlayers, OfflineSkinCache, Player,
+    ProfileLookupError, ResetReason, fetch_profile_properties, is_valid_player_name,
+    lookup_online_profile, offline_uuid,
 };
+use crate::plugin::PluginManager;
 use crate::portal::{
     PortalKind, TeleportPostTransition, TeleportTransition, WorldChangeRequest, end_gateway,
     end_portal, nether_portal,
 };
 use crate::scoreboard::DomainScoreboards;
+use crate::server::broadcast::BroadcastPacket;
+use crate::server::chat_filter::ChatFilter;
+use crate::server::config_reload::ConfigReloader;
+use crate::server::datapacks::DataPackManager;
+use crate::server::functions::ServerFunctions;
 use crate::server::jobs::{FnServerJob, JobPoll, ServerJob, ServerJobContext, ServerJobQueue};
+use crate::server::join_queue::{JoinQueue, JoinQueuePoll, JoinTicket};
+use crate::server::localization::ServerTranslations;
+use crate::server::plugin_channels::PluginChannelRegistry;
 use crate::server::registry_cache::RegistryCache;
+use crate::server::scheduler::Scheduler;
+use crate::server::structure_templates::StructureTemplateManager;
+use crate::server::tab_list::TabList;
+use crate::server::watchdog::Watchdog;
 use crate::server::worlds::WorldMap;
 use crate::world::player_spawn_finder::{PlayerSpawnSearch, PlayerSpawnSearchPoll};
 use crate::world::{PlayerMap, World, WorldConfig, WorldGameTickTimings};
 use crate::worldgen::WorldGeneratorRegistry;
 use crate::worldgen::registry::GeneratorOutput;
-use glam::DVec3;
+use arc_swap::ArcSwap;
+use glam::{DVec2, DVec3};
 use rayon::{ThreadPool, ThreadPoolBuilder};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::{
     collections::BTreeSet,
+    hash::Hash,
     io, mem,
     num::NonZero,
     path::Path,
-    sync::{Arc, mpsc},
+    sync::{
+        Arc, OnceLock,
+        atomic::{AtomicI32, Ordering},
+        mpsc,
+    },
     thread,
     time::{Duration, Instant},
 };
@@ -79,9 +134,11 @@ use steel_crypto::key_store::KeyStore;
 use steel_protocol::packet_traits::{ClientPacket, EncodedPacket};
 use steel_protocol::packets::game::{
     CCommandSuggestions, CEntityEvent, CGameEvent, CLogin, CPlayerInfoUpdate, CRemovePlayerInfo,
-    CSetDefaultSpawnPosition, CSystemChat, CTabList, CTickingState, CTickingStep,
-    CommonPlayerSpawnInfo, GameEventType, RelativeMovement,
+    CSetDefaultSpawnPosition, CSystemChat, CTickingState, CTickingStep, CommonPlayerSpawnInfo,
+    GameEventType, RelativeMovement,
 };
+use steel_protocol::packets::login::GameProfileProperty;
+use steel_protocol::traffic::TrafficCounters;
 use steel_protocol::utils::ConnectionProtocol;
 use steel_registry::vanilla_game_rules::{
     ALLOW_ENTERING_NETHER_USING_PORTALS, IMMEDIATE_RESPAWN, LIMITED_CRAFTING, REDUCED_DEBUG_INFO,
@@ -93,12 +150,17 @@ use steel_registry::{
 use steel_utils::{
     BlockPos, ChunkPos, Identifier,
     locks::{AsyncMutex, SyncMutex, SyncRwLock},
-    text::DisplayResolutor,
+    text::{has_resolvable_content, to_ansi},
     translations,
 };
 use text_components::{Modifier, TextComponent, format::Color};
 use tick_rate_manager::{SprintReport, TickRateManager};
-use tokio::{runtime::Runtime, sync::Notify, task::spawn_blocking, time::sleep};
+use tokio::{
+    runtime::{Handle, Runtime},
+    sync::Notify,
+    task::spawn_blocking,
+    time::sleep,
+};
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
@@ -220,6 +282,26 @@ fn configured_chunk_generation_threads(configured_threads: Option<usize>) -> Opt
     cap_positive_thread_count(configured_threads, available_worker_threads())
 }
 
+/// Builds the pool for parallel random block ticks. Unset, `0` and `1` keep
+/// random ticks on the world thread.
+fn build_random_tick_pool(
+    configured_threads: Option<usize>,
+) -> Result<Option<Arc<ThreadPool>>, String> {
+    let Some(threads) = cap_positive_thread_count(configured_threads, available_worker_threads())
+    else {
+        return Ok(None);
+    };
+    if threads <= 1 {
+        return Ok(None);
+    }
+    ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .thread_name(|i| format!("rayon-random-tick-{i}"))
+        .build()
+        .map(|pool| Some(Arc::new(pool)))
+        .map_err(|e| format!("failed to create random tick thread pool: {e}"))
+}
+
 fn available_worker_threads() -> usize {
     thread::available_parallelism().map_or(4, NonZero::get)
 }
@@ -239,10 +321,11 @@ mod tests {
         io::Cursor,
         path::{Path, PathBuf},
         slice,
-        sync::{Arc, Weak},
+        sync::{Arc, OnceLock, Weak},
         time::{SystemTime, UNIX_EPOCH},
     };
 
+    use arc_swap::ArcSwap;
     use glam::DVec3;
     use steel_protocol::packet_traits::{CompressionInfo, EncodedPacket};
     use steel_registry::entity_type::EntityTypeRef;
@@ -255,7 +338,11 @@ mod tests {
 
     use crate::command::execution::{CommandPermissionSource, CommandSource};
     use crate::command::sender::CommandSender;
-    use crate::config::{ResolvedDomainConfig, RuntimeConfig, StorageSelection};
+    use crate::config::{
+        ChatConfig, CommandAliasesConfig, JoinQueueConfig, OfflineSkinsConfig, PacketLimitConfig,
+        ProxyConfig, ReportDetailsConfig, ResolvedDomainConfig, RuntimeConfig, SharedRuntimeConfig,
+        StorageSelection, WatchdogConfig,
+    };
     use crate::entity::{Entity, EntityBase};
     use crate::permission::{
         OP_GROUP, PermissionEntry, PermissionExpr, PermissionGroupConfig, PermissionGroupManager,
@@ -269,9 +356,10 @@ mod tests {
 
     use super::{
         AsyncMutex, CancellationToken, CommandRegistry, CommandRequestQueue, DomainCommandStorage,
-        DomainScoreboards, FxHashMap, KeyStore, KnownPlayerCacheState, KnownPlayerSaveStep,
-        KnownPlayers, Notify, PlayerDataStorage, PlayerJoinQueue, PlayerMap, RegistryCache, Server,
-        ServerJobQueue, SyncMutex, SyncRwLock, TickRateManager, UncachedPlayerTarget, WorldMap,
+        DomainScoreboards, FxHashMap, FxHashSet, KeyStore, KnownPlayerCacheState,
+        KnownPlayerSaveStep, KnownPlayers, Notify, PlayerDataStorage, PlayerJoinQueue, PlayerMap,
+        RegistryCache, Server, ServerJobQueue, SyncMutex, SyncRwLock, TabList, TickRateManager,
+        UncachedPlayerTarget, WorldMap, build_random_tick_pool,
         can_entity_return_from_end_to_overworld, cap_positive_thread_count,
         classify_uncached_player_target, create_registered_dispatcher, direct_uuid_profile,
         is_allowed_to_enter_portal_target, is_end_return_transition, offline_uuid,
@@ -310,16 +398,19 @@ mod tests {
         }
     }
 
-    fn test_runtime_config() -> Arc<RuntimeConfig> {
-        Arc::new(RuntimeConfig {
+    fn test_runtime_config() -> SharedRuntimeConfig {
+        Arc::new(ArcSwap::from_pointee(RuntimeConfig {
             max_players: 1,
             view_distance: 2,
             simulation_distance: 2,
+            entity_broadcast_range_percentage:
+                crate::entity::DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE,
             online_mode: false,
             auth_server: None,
             profile_server: None,
             encryption: false,
             allow_flight: false,
+            accepts_transfers: false,
             motd: String::new(),
             use_favicon: false,
             favicon: String::new(),
@@ -328,8 +419,18 @@ mod tests {
             command_spam_threshold_seconds: 10,
             compression: None,
             server_links: None,
+            report_details: ReportDetailsConfig::default(),
             chunk_generation_threads: Some(1),
-        })
+            random_tick_threads: Some(1),
+            resource_packs: Vec::new(),
+            proxy: ProxyConfig::default(),
+            packet_limits: PacketLimitConfig::default(),
+            watchdog: WatchdogConfig::default(),
+            chat: ChatConfig::default(),
+            offline_skins: OfflineSkinsConfig::default(),
+            join_queue: JoinQueueConfig::default(),
+            command_aliases: CommandAliasesConfig::default(),
+        }))
     }
 
     fn test_storage_root(name: &str) -> PathBuf {
@@ -358,6 +459,9 @@ mod tests {
         let command_storage = DomainCommandStorage::load(&worlds)
             .await
             .map_err(|error| format!("test command storage should load: {error}"))?;
+        let maps =
+            DomainMaps::load(&worlds).map_err(|error| format!("test maps should load: {error}"))?;
+        let structure_templates = StructureTemplateManager::install(storage_root, &worlds);
         let player_data_storage = PlayerDataStorage::new(
             storage_root.to_owned(),
             StorageSelection::default_player_file(),
@@ -375,22 +479,28 @@ mod tests {
             PermissionGroupManager::transient(PermissionGroupsConfig::default())
                 .map_err(|error| format!("test permission groups should resolve: {error}"))?;
         let config = test_runtime_config();
-        let registry_cache = RegistryCache::new(config.compression);
+        let registry_cache = RegistryCache::new(config.load().compression);
 
         Ok(Arc::new(Server {
             config,
             permission_groups,
+            scheduler: Scheduler::new(CancellationToken::new()),
+            functions: ServerFunctions::new(),
             cancel_token: CancellationToken::new(),
             key_store: KeyStore::create(),
             registry_cache,
+            datapacks: DataPackManager::new(storage_root),
+            structure_templates,
             worlds,
             online_players: PlayerMap::new(),
             player_admissions: SyncMutex::new(FxHashMap::default()),
             tick_rate_manager: SyncRwLock::new(TickRateManager::new()),
             scoreboards,
             command_storage,
+            maps,
             command_dispatcher: SyncRwLock::new(registered_commands.dispatcher),
             command_permission_keys,
+            command_alias_roots: SyncMutex::new(FxHashSet::default()),
             command_requests: CommandRequestQueue::new(),
             jobs: ServerJobQueue::new(),
             player_data_storage,
@@ -399,9 +509,20 @@ mod tests {
             known_players: SyncMutex::new(KnownPlayerCacheState::new(KnownPlayers::new())),
             known_player_save_idle: Notify::new(),
             profile_lookup_client: reqwest::Client::new(),
+            offline_skins: OfflineSkinCache::new(storage_root),
             pending_player_joins: PlayerJoinQueue::new(),
+            join_queue: Arc::default(),
+            chat_filters: SyncRwLock::new(Vec::new()),
+            network_traffic: Arc::default(),
             pending_world_changes: SyncMutex::new(Vec::new()),
             pending_domain_switches: SyncMutex::new(Vec::new()),
+            tab_list: TabList::default(),
+            random_tick_pool: None,
+            watchdog: Watchdog::new(),
+            config_reloader: OnceLock::new(),
+            plugin_manager: OnceLock::new(),
+            plugin_channels: PluginChannelRegistry::new(),
+            translations: ServerTranslations::new(),
         }))
     }
 
@@ -507,6 +628,14 @@ mod tests {
         assert_eq!(cap_positive_thread_count(None, 8), None);
     }
 
+    #[test]
+    fn random_ticks_stay_on_the_world_thread_by_default() {
+        for threads in [None, Some(0), Some(1)] {
+            assert!(matches!(build_random_tick_pool(threads), Ok(None)));
+        }
+        assert!(matches!(build_random_tick_pool(Some(2)), Ok(Some(_))));
+    }
+
     #[test]
     fn uncached_uuid_target_is_preserved_in_online_mode() {
         let uuid = Uuid::from_u128(0x1234_5678_90ab_cdef_1234_5678_90ab_cdef);
@@ -733,7 +862,7 @@ mod tests {
             assert!(server.online_players.insert(existing_player));
             assert!(server.online_players.insert(Arc::clone(&joining_player)));
 
-            server.broadcast_player_join_message(&joining_player, Some("OldName"));
+            server.broadcast_player_join_message(&joining_player, Some("OldName"), None);
 
             {
                 let existing_packets = existing_packets.lock();
@@ -754,6 +883,55 @@ mod tests {
         });
     }
 
+    #[test]
+    fn system_chat_broadcast_shares_one_encoding() {
+        let world = Arc::clone(test_world());
+        let runtime = Builder::new_current_thread().enable_all().build();
+        let Ok(runtime) = runtime else {
+            panic!("test runtime should initialize");
+        };
+        runtime.block_on(async {
+            let storage_root = test_storage_root("broadcast-shared-encoding");
+            let server = test_server(
+                Arc::clone(&world),
+                PermissionSubjectIndex::new(),
+                &storage_root,
+            )
+            .await;
+            let Ok(server) = server else {
+                panic!("test server should initialize");
+            };
+            let (first, first_packets) =
+                test_player_with_packets(&server, Arc::clone(&world), Uuid::from_u128(1), "A", 1);
+            let (second, second_packets) =
+                test_player_with_packets(&server, world, Uuid::from_u128(2), "B", 2);
+            assert!(server.online_players.insert(first));
+            assert!(server.online_players.insert(second));
+
+            server.broadcast_system_chat(&TextComponent::plain("hello"), None);
+
+            {
+                let first_packets = first_packets.lock();
+                let second_packets = second_packets.lock();
+                assert_eq!(first_packets.len(), 1);
+                assert_eq!(second_packets.len(), 1);
+                assert!(Arc::ptr_eq(
+                    &first_packets[0].encoded_data,
+                    &second_packets[0].encoded_data
+                ));
+                assert_eq!(
+                    decode_system_chat(&second_packets[0]).to_plain(&DisplayResolutor),
+                    "hello"
+                );
+            }
+
+            drop(server);
+            if let Err(error) = fs::remove_dir_all(&storage_root).await {
+                panic!("test storage should be removed: {error}");
+            }
+        });
+    }
+
     #[tokio::test]
     async fn effective_permissions_reflect_published_group_revocation() {
         let mut config = PermissionGroupsConfig::default();
@@ -1871,7 +2049,7 @@ fn restore_ender_pearl_for_player(
 /// The main server struct.
 pub struct Server {
     /// Runtime configuration (view distance, compression, etc.).
-    pub config: Arc<RuntimeConfig>,
+    pub config: SharedRuntimeConfig,
     /// Runtime permission groups and their persistence boundary.
     pub permission_groups: PermissionGroupManager,
     /// The cancellation token for graceful shutdown.
@@ -1880,6 +2058,10 @@ pub struct Server {
     pub key_store: KeyStore,
     /// The registry cache for the server.
     pub registry_cache: RegistryCache,
+    /// Discovered datapacks and the contents of the enabled ones.
+    pub datapacks: DataPackManager,
+    /// Structure templates saved by structure blocks, plus the bundled vanilla ones.
+    pub structure_templates: Arc<StructureTemplateManager>,
     /// A list of all the worlds on the server.
     pub worlds: WorldMap,
     /// Players currently connected to the server, independent of world membership.
@@ -1892,14 +2074,22 @@ pub struct Server {
     pub scoreboards: DomainScoreboards,
     /// Command NBT storage isolated by Steel domain.
     pub(crate) command_storage: DomainCommandStorage,
+    /// Filled-map data isolated by Steel domain.
+    pub(crate) maps: DomainMaps,
     /// Saves and dispatches commands to appropriate handlers.
     command_dispatcher: SyncRwLock<CommandDispatcher>,
     /// Steel-owned permission keys exposed for command autocomplete.
     command_permission_keys: Vec<String>,
+    /// Roots registered for `commands.toml` aliases.
+    command_alias_roots: SyncMutex<FxHashSet<String>>,
     /// Command work submitted from connection and console tasks.
     command_requests: CommandRequestQueue,
     /// Jobs resumed from a known point in the server game tick.
     pub jobs: ServerJobQueue,
+    /// Tasks scheduled on the game tick or the async runtime.
+    pub scheduler: Scheduler,
+    /// Tagged and scheduled datapack functions.
+    pub functions: ServerFunctions,
     /// Player data storage for saving/loading player state.
     pub player_data_storage: PlayerDataStorage,
     /// Persisted permission state indexed by player UUID.
@@ -1912,12 +2102,34 @@ pub struct Server {
     known_player_save_idle: Notify,
     /// HTTP client used by online-mode name-to-profile lookups.
     profile_lookup_client: reqwest::Client,
+    offline_skins: OfflineSkinCache,
     /// Player joins prepared by async I/O and finalized at the game tick safe point.
     pending_player_joins: PlayerJoinQueue,
+    /// Players waiting for a slot while the server is full.
+    join_queue: Arc<JoinQueue>,
+    /// Filters registered by plugins, run on every chat message.
+    chat_filters: SyncRwLock<Vec<Arc<dyn ChatFilter>>>,
+    /// Bytes and packets of every connection, including status pings.
+    pub network_traffic: Arc<TrafficCounters>,
     /// Queued world changes to process after the tick.
     pub pending_world_changes: SyncMutex<Vec<(SharedEntity, WorldChangeRequest)>>,
     /// Queued domain switches to process after world ticks.
     pending_domain_switches: SyncMutex<Vec<DomainSwitchRequest>>,
+    /// Tab list headers, footers and entry presentation.
+    pub tab_list: TabList,
+    /// Workers for random block ticks of independent chunk regions, or `None`
+    /// to run them on the world thread.
+    random_tick_pool: Option<Arc<ThreadPool>>,
+    /// Progress of the running game tick, checked for stalls.
+    pub watchdog: Watchdog,
+    /// Re-reads the configuration for `/steel reload config`.
+    config_reloader: OnceLock<Box<dyn ConfigReloader>>,
+    /// Plugins, installed once they registered their commands.
+    plugin_manager: OnceLock<PluginManager>,
+    /// Handlers for plugin message channels.
+    pub plugin_channels: PluginChannelRegistry,
+    /// Translations of keys the client does not know.
+    pub translations: ServerTranslations,
 }
 
 impl Server {
@@ -1962,6 +2174,7 @@ impl Server {
         cancel_token: CancellationToken,
         config: RuntimeConfig,
         worlds_config: WorldsConfig,
+        items_config: ItemsConfig,
         permission_groups: PermissionGroupManager,
     ) -> Result<Self, String> {
         Self::new_with_commands(
@@ -1969,6 +2182,7 @@ impl Server {
             cancel_token,
             config,
             worlds_config,
+            items_config,
             permission_groups,
             CommandRegistry::new(),
         )
@@ -1985,6 +2199,7 @@ impl Server {
         cancel_token: CancellationToken,
         config: RuntimeConfig,
         worlds_config: WorldsConfig,
+        items_config: ItemsConfig,
         permission_groups: PermissionGroupManager,
         command_registry: CommandRegistry,
     ) -> Result<Self, String> {
@@ -1992,6 +2207,8 @@ impl Server {
         let config = Arc::new(config);
         let start = Instant::now();
         let mut registry = Registry::new_vanilla();
+        worlds_config.register_dimension_types(&mut registry.dimension_types)?;
+        items_config.register_items(&mut registry.items)?;
         registry.freeze();
         log::info!("Vanilla registry loaded in {:?}", start.elapsed());
 
@@ -2008,13 +2225,15 @@ impl Server {
             "SteelMC is not affiliated with Mojang or Microsoft. Use is subject to the Minecraft EULA: https://aka.ms/MinecraftEULA"
         );
 
-        let registry_cache = RegistryCache::new(config.compression);
-
         let (generator_registry, storage_registry) = world_config_registries()?;
         let resolved_worlds = worlds_config
             .validate_and_resolve(&generator_registry, &storage_registry)
             .map_err(|e| format!("failed to validate worlds.toml: {e}"))?;
 
+        // Datapack tags must be installed before the tags packet is cached.
+        let datapacks = DataPackManager::load(&resolved_worlds.save_path)?;
+        let registry_cache = RegistryCache::new(config.compression);
+
         let generation_pool: Arc<ThreadPool> = Arc::new({
             let mut builder = ThreadPoolBuilder::new().thread_name(|i| format!("rayon-gen-{i}"));
             if let Some(chunk_generation_threads) =
@@ -2031,6 +2250,8 @@ impl Server {
                 .map_err(|e| format!("failed to create generation thread pool: {e}"))?
         });
 
+        let random_tick_pool = build_random_tick_pool(config.random_tick_threads)?;
+
         let player_data_storage = PlayerDataStorage::new(
             resolved_worlds.save_path.clone(),
             resolved_worlds.player_storage.clone(),
@@ -2098,6 +2319,7 @@ impl Server {
                     generation_settings,
                     view_distance: config.view_distance,
                     simulation_distance: config.simulation_distance,
+                    entity_broadcast_range_percentage: config.entity_broadcast_range_percentage,
                     compression: config.compression,
                     is_flat: generator_output.is_flat,
                     sea_level: generator_output.sea_level,
@@ -2121,6 +2343,10 @@ impl Server {
         let command_storage = DomainCommandStorage::load(&worlds)
             .await
             .map_err(|error| format!("failed to load domain command storage: {error}"))?;
+        let maps = DomainMaps::load(&worlds)
+            .map_err(|error| format!("failed to load domain maps: {error}"))?;
+        let structure_templates =
+            StructureTemplateManager::install(&resolved_worlds.save_path, &worlds);
         let registered_commands = create_registered_dispatcher(command_registry)
             .map_err(|error| format!("failed to register commands: {error}"))?;
         let command_permission_keys = registered_commands
@@ -2129,20 +2355,26 @@ impl Server {
             .map(|permission| permission.as_str().to_owned())
             .collect();
 
-        Ok(Server {
-            config,
+        let server = Server {
+            config: Arc::new(ArcSwap::new(config)),
             permission_groups,
+            scheduler: Scheduler::new(cancel_token.child_token()),
+            functions: ServerFunctions::new(),
             cancel_token,
             key_store: KeyStore::create(),
             worlds,
             online_players: PlayerMap::new(),
             player_admissions: SyncMutex::new(FxHashMap::default()),
             registry_cache,
+            datapacks,
+            structure_templates,
             tick_rate_manager: SyncRwLock::new(TickRateManager::new()),
             scoreboards,
             command_storage,
+            maps,
             command_dispatcher: SyncRwLock::new(registered_commands.dispatcher),
             command_permission_keys,
+            command_alias_roots: SyncMutex::new(FxHashSet::default()),
             command_requests: CommandRequestQueue::new(),
             jobs: ServerJobQueue::new(),
             player_data_storage,
@@ -2151,10 +2383,35 @@ impl Server {
             known_players: SyncMutex::new(KnownPlayerCacheState::new(known_players)),
             known_player_save_idle: Notify::new(),
             profile_lookup_client: reqwest::Client::new(),
+            offline_skins: OfflineSkinCache::new(&resolved_worlds.save_path),
             pending_player_joins: PlayerJoinQueue::new(),
+            join_queue: Arc::default(),
+            chat_filters: SyncRwLock::new(Vec::new()),
+            network_traffic: Arc::default(),
             pending_world_changes: SyncMutex::new(vec![]),
             pending_domain_switches: SyncMutex::new(vec![]),
-        })
+            tab_list: TabList::default(),
+            random_tick_pool,
+            watchdog: Watchdog::new(),
+            config_reloader: OnceLock::new(),
+            plugin_manager: OnceLock::new(),
+            plugin_channels: PluginChannelRegistry::new(),
+            translations: ServerTranslations::new(),
+        };
+        server.register_command_aliases();
+        Ok(server)
+    }
+
+    /// Reloads the enabled datapacks and resends tags to online players.
+    ///
+    /// Registry entries only reach players on their next configuration phase.
+    /// On failure the previously loaded data stays active.
+    pub fn reload_datapacks(&self) -> Result<(), String> {
+        self.datapacks.reload()?;
+        self.functions.mark_reloaded();
+        self.registry_cache.refresh();
+        self.broadcast_to_online(RegistryCache::build_tags_packet(&REGISTRY));
+        Ok(())
     }
 
     /// Saves all dirty domain command storage through domain default worlds.
@@ -2162,6 +2419,11 @@ impl Server {
         self.command_storage.save(&self.worlds).await
     }
 
+    /// Saves all dirty domain maps through domain default worlds.
+    pub async fn save_maps(&self) -> io::Result<usize> {
+        self.maps.save(&self.worlds).await
+    }
+
     /// Saves all command-owned persistent data while allowing each data set to fail independently.
     pub async fn save_command_data(&self) -> CommandDataSaveResults {
         CommandDataSaveResults {
@@ -2245,6 +2507,9 @@ impl Server {
 
     async fn prepare_player_join(&self, player: &Player) -> Result<DomainPlayerState, String> {
         let target_domain = self.load_join_domain(player).await?;
+        self.load_join_stats(player).await?;
+        self.load_join_advancements(player).await?;
+        self.load_join_ignored(player).await?;
         self.load_domain_player_state(player, &target_domain, None, true)
             .await
     }
@@ -2295,8 +2560,12 @@ impl Server {
             return;
         }
         let previous_name = self.record_known_player(&player.gameprofile);
-        self.broadcast_player_join_message(&player, previous_name.as_deref());
+        let join_message = self
+            .plugin_manager()
+            .and_then(|plugins| plugins.player_join(&player));
+        self.broadcast_player_join_message(&player, previous_name.as_deref(), join_message);
         self.sync_tab_list(&player);
+        self.plugin_channels.announce_to(&player);
         if player.mark_joined_world() {
             player.send_inventory_to_remote();
         }
@@ -2309,6 +2578,46 @@ impl Server {
         }
     }
 
+    /// Creates the slot claim a joining player holds until it is queued to join.
+    #[must_use]
+    pub fn join_ticket(&self, uuid: Uuid) -> JoinTicket {
+        self.join_queue.ticket(uuid)
+    }
+
+    /// Asks for a slot for a player finishing configuration.
+    ///
+    /// Online and joining players count against `max_players`. Players with
+    /// the join queue bypass permission are admitted even when the server is full.
+    pub fn poll_join_queue(&self, ticket: &mut JoinTicket, uuid: Uuid) -> JoinQueuePoll {
+        let config = self.config.load();
+        let bypass = match join_queue_bypass_permission_expr() {
+            Ok(permission) => self.command_permission_snapshot(uuid).allows(&permission),
+            Err(error) => {
+                log::error!("invalid built-in join queue bypass permission key: {error}");
+                false
+            }
+        };
+        let joining = self
+            .player_admissions
+            .lock()
+            .values()
+            .filter(|state| **state == PlayerAdmissionState::Joining)
+            .count();
+        self.join_queue.poll(
+            ticket,
+            self.online_players.len() + joining,
+            config.max_players as usize,
+            bypass,
+            config.join_queue.enable,
+        )
+    }
+
+    /// Returns how many players are waiting in the join queue.
+    #[must_use]
+    pub fn join_queue_len(&self) -> usize {
+        self.join_queue.len()
+    }
+
     fn reserve_player_join(&self, player: &Player) -> bool {
         let uuid = player.gameprofile.id;
         let mut admissions = self.player_admissions.lock();
@@ -2375,9 +2684,13 @@ impl Server {
             return;
         }
 
+        if let Some(plugins) = self.plugin_manager() {
+            plugins.player_leave(&player);
+        }
         // Vanilla broadcasts before removing the player from its global player list.
         self.broadcast_player_leave_message(&player);
         self.broadcast_to_online(CRemovePlayerInfo::single(uuid));
+        self.tab_list.remove_player(uuid);
         let player = self.online_players.remove_player_sync(&player);
 
         let Some(player) = player else {
@@ -2404,6 +2717,20 @@ impl Server {
         {
             log::error!("Failed to save global player data for {uuid}: {e}");
         }
+        if let Err(e) = self
+            .player_data_storage
+            .save_stats(uuid, player.stats_json())
+            .await
+        {
+            log::error!("Failed to save player stats for {uuid}: {e}");
+        }
+        if let Err(e) = self
+            .player_data_storage
+            .save_advancements(uuid, player.advancements_json())
+            .await
+        {
+            log::error!("Failed to save player advancements for {uuid}: {e}");
+        }
 
         player.cleanup();
         self.release_player_admission(uuid, PlayerAdmissionState::Disconnecting);
@@ -2411,9 +2738,11 @@ impl Server {
 
     /// Broadcasts a packet to every online player, regardless of world membership.
     pub fn broadcast_to_online<P: ClientPacket>(&self, packet: P) {
-        let Ok(encoded) =
-            EncodedPacket::from_bare(packet, self.config.compression, ConnectionProtocol::Play)
-        else {
+        let Ok(encoded) = EncodedPacket::from_bare(
+            packet,
+            self.config.load().compression,
+            ConnectionProtocol::Play,
+        ) else {
             return;
         };
         self.online_players.iter_players(|_, player| {
@@ -2422,9 +2751,15 @@ impl Server {
         });
     }
 
-    fn broadcast_to_online_with<P: ClientPacket, F: Fn(&Player) -> P>(&self, packet: F) {
+    /// Broadcasts a packet that differs per viewer, encoding each distinct `variant` once.
+    fn broadcast_to_online_variants<K: Eq + Hash, P: ClientPacket>(
+        &self,
+        variant: impl Fn(&Player) -> K,
+        packet: impl FnMut(&K, &Player) -> P,
+    ) {
+        let mut broadcast = BroadcastPacket::new(self.config.load().compression, packet);
         self.online_players.iter_players(|_, player| {
-            player.send_packet(packet(player));
+            broadcast.send(variant(player), player);
             true
         });
     }
@@ -2439,16 +2774,7 @@ impl Server {
                 return true;
             }
 
-            let add_existing = CPlayerInfoUpdate::create_player_initializing(
-                existing_player.gameprofile.id,
-                existing_player.gameprofile.name.clone(),
-                existing_player.gameprofile.properties.clone(),
-                existing_player.game_mode().into(),
-                existing_player.connection.latency(),
-                None,
-                true,
-            );
-            player.send_packet(add_existing);
+            player.send_packet(self.player_info_initializing(existing_player));
 
             if let Some(session) = existing_player.chat_session()
                 && let Ok(protocol_data) = session.as_data().to_protocol_data()
@@ -2462,22 +2788,13 @@ impl Server {
             true
         });
 
-        let player_info_packet = CPlayerInfoUpdate::create_player_initializing(
-            player.gameprofile.id,
-            player.gameprofile.name.clone(),
-            player.gameprofile.properties.clone(),
-            player.game_mode().into(),
-            player.connection.latency(),
-            None,
-            true,
-        );
-        self.broadcast_to_online(player_info_packet);
+        self.broadcast_to_online(self.player_info_initializing(player));
     }
 
     fn broadcast_player_latency_updates(&self) {
         let mut latency_entries = Vec::new();
         self.online_players.iter_players(|uuid, player| {
-            latency_entries.push((*uuid, player.connection.latency()));
+            latency_entries.push((*uuid, player.latency()));
             true
         });
 
@@ -2508,6 +2825,66 @@ impl Server {
         }
     }
 
+    /// Vanilla `ServerStatsCounter` load; unreadable stats are reset like vanilla.
+    async fn load_join_stats(&self, player: &Player) -> Result<(), String> {
+        match self
+            .player_data_storage
+            .load_stats(player.gameprofile.id)
+            .await
+        {
+            Ok(Some(stats)) => player.load_stats(stats),
+            Ok(None) => {}
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                log::error!(
+                    "Couldn't parse stats of player {}, resetting: {e}",
+                    player.gameprofile.name
+                );
+            }
+            Err(e) => return Err(format!("failed to load player stats: {e}")),
+        }
+        Ok(())
+    }
+
+    /// Vanilla `PlayerAdvancements.load`; unreadable progress is reset like vanilla.
+    async fn load_join_advancements(&self, player: &Player) -> Result<(), String> {
+        match self
+            .player_data_storage
+            .load_advancements(player.gameprofile.id)
+            .await
+        {
+            Ok(Some(advancements)) => player.load_advancements(advancements),
+            Ok(None) => {}
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                log::error!(
+                    "Couldn't parse player advancements of {}, resetting: {e}",
+                    player.gameprofile.name
+                );
+            }
+            Err(e) => return Err(format!("failed to load player advancements: {e}")),
+        }
+        Ok(())
+    }
+
+    /// Loads the player's ignore list; an unreadable list is reset.
+    async fn load_join_ignored(&self, player: &Player) -> Result<(), String> {
+        match self
+            .player_data_storage
+            .load_ignored(player.gameprofile.id)
+            .await
+        {
+            Ok(Some(ignored)) => player.load_ignored(ignored),
+            Ok(None) => {}
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                log::error!(
+                    "Couldn't parse ignore list of {}, resetting: {e}",
+                    player.gameprofile.name
+                );
+            }
+            Err(e) => return Err(format!("failed to load player ignore list: {e}")),
+        }
+        Ok(())
+    }
+
     fn apply_cached_or_default_permission_state(&self, player: &Player) -> u64 {
         let state = self
             .player_permission_states
@@ -2704,7 +3081,7 @@ impl Server {
             return Ok(profile);
         }
 
-        match classify_uncached_player_target(name, self.config.online_mode) {
+        match classify_uncached_player_target(name, self.config.load().online_mode) {
             UncachedPlayerTarget::DirectUuid(uuid) => {
                 // No verified name is available, so use the canonical UUID for
                 // feedback without adding a synthetic identity-cache entry.
@@ -2721,16 +3098,45 @@ impl Server {
             return Err(ProfileLookupError::UnknownPlayer(name.to_owned()));
         }
 
-        let profile = lookup_online_profile(
-            &self.profile_lookup_client,
-            self.config.profile_server.as_deref(),
-            name,
-        )
-        .await?;
+        let profile_server = self.config.load().profile_server.clone();
+        let profile =
+            lookup_online_profile(&self.profile_lookup_client, profile_server.as_deref(), name)
+                .await?;
         self.record_known_profile(profile.uuid(), profile.last_known_name().to_owned());
         Ok(profile)
     }
 
+    /// Fetches the current signed skin and cape textures of an online-mode profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in offline mode, or when the session service fails or does
+    /// not know the profile.
+    pub async fn fetch_profile_properties(
+        self: &Arc<Self>,
+        uuid: Uuid,
+        name: &str,
+    ) -> Result<Vec<GameProfileProperty>, ProfileLookupError> {
+        if !self.config.load().online_mode {
+            return Err(ProfileLookupError::UnknownPlayer(name.to_owned()));
+        }
+        fetch_profile_properties(&self.profile_lookup_client, uuid, name).await
+    }
+
+    /// Resolves the skin of an offline-mode player through the configured skin
+    /// providers. Returns no properties when offline skins are disabled.
+    pub async fn resolve_offline_skin(&self, name: &str) -> Vec<GameProfileProperty> {
+        let config = self.config.load_full();
+        self.offline_skins
+            .resolve(
+                &self.profile_lookup_client,
+                config.profile_server.as_deref(),
+                &config.offline_skins,
+                name,
+            )
+            .await
+    }
+
     fn cached_player_profile(self: &Arc<Self>, name: &str) -> Option<KnownPlayer> {
         let uuid = Uuid::parse_str(name).ok();
         if let Some(player) = self.get_players().into_iter().find(|player| {
@@ -3031,6 +3437,7 @@ impl Server {
                 apply_default_spawn(player, &state.world, *default_spawn);
             }
         }
+        player.send_initial_recipe_book();
     }
 
     fn schedule_root_vehicle_restore(&self, player: &Arc<Player>, state: &DomainPlayerState) {
@@ -3118,9 +3525,9 @@ impl Server {
             player_id: player.id(),
             hardcore: false,
             levels: self.worlds.keys().cloned().collect(),
-            max_players: self.config.max_players as i32,
+            max_players: self.config.load().max_players as i32,
             chunk_radius: player.view_distance().into(),
-            simulation_distance: self.config.simulation_distance.into(),
+            simulation_distance: player.simulation_distance().into(),
             reduced_debug_info,
             show_death_screen: !immediate_respawn,
             do_limited_crafting,
@@ -3132,12 +3539,12 @@ impl Server {
                 previous_game_type: player.previous_game_mode(),
                 is_debug: false,
                 is_flat: world.is_flat,
-                last_death_location: None,
+                last_death_location: player.last_death_location(),
                 portal_cooldown: 0,
                 sea_level: world.sea_level,
             },
-            online_mode: self.config.online_mode,
-            enforces_secure_chat: self.config.enforce_secure_chat,
+            online_mode: self.config.load().online_mode,
+            enforces_secure_chat: self.config.load().enforce_secure_chat,
         });
     }
 
@@ -3151,12 +3558,92 @@ impl Server {
         players
     }
 
+    /// Gets an online player by UUID, whatever world they are in.
+    #[must_use]
+    pub fn get_player(&self, uuid: &Uuid) -> Option<Arc<Player>> {
+        self.online_players.get_by_uuid(uuid)
+    }
+
+    /// Installs the plugin manager once its plugins registered their commands.
+    ///
+    /// Only the first manager is kept.
+    pub fn set_plugin_manager(&self, plugin_manager: PluginManager) {
+        if self.plugin_manager.set(plugin_manager).is_err() {
+            log::warn!("A plugin manager is already installed");
+        }
+    }
+
+    /// Returns the plugin manager, if one is installed.
+    #[must_use]
+    pub fn plugin_manager(&self) -> Option<&PluginManager> {
+        self.plugin_manager.get()
+    }
+
     /// Returns the total number of players currently online across all worlds.
     #[must_use]
     pub fn player_count(&self) -> usize {
         self.online_players.len()
     }
 
+    /// Adds the server state to a crash report (vanilla
+    /// `MinecraftServer.fillSystemReport`).
+    ///
+    /// Reads nothing that could block, since the crashing thread may hold a lock.
+    pub fn fill_crash_report(&self, report: &mut CrashReport) {
+        let tick = self.tick_rate_manager.try_read().map_or_else(
+            || UNAVAILABLE.to_owned(),
+            |manager| {
+                format!(
+                    "{} ({:.2} ms average)",
+                    manager.tick_count,
+                    manager.get_average_mspt()
+                )
+            },
+        );
+        let config = self.config.load();
+        report
+            .add_category("Server")
+            .set_detail("Tick", tick)
+            .set_detail("Watchdog phase", self.watchdog.phase())
+            .set_detail("Online mode", config.online_mode)
+            .set_detail("Max players", config.max_players)
+            .set_detail("View distance", config.view_distance)
+            .set_detail("Simulation distance", config.simulation_distance)
+            .set_detail(
+                "Compression threshold",
+                config
+                    .compression
+                    .map_or_else(|| "disabled".to_owned(), |info| info.threshold.to_string()),
+            );
+
+        let worlds = report.add_category("Worlds");
+        for (key, world) in self.worlds.iter() {
+            worlds.set_detail(
+                key.to_string(),
+                format!("{} chunks loaded", world.chunk_map.chunks.len()),
+            );
+        }
+
+        let players = self.online_players.try_players().map_or_else(
+            || UNAVAILABLE.to_owned(),
+            |players| {
+                let names: Vec<_> = players
+                    .iter()
+                    .map(|player| player.gameprofile.name.as_str())
+                    .collect();
+                format!(
+                    "{} / {}; {}",
+                    names.len(),
+                    config.max_players,
+                    names.join(", ")
+                )
+            },
+        );
+        report
+            .add_category("Players")
+            .set_detail("Player Count", players);
+    }
+
     /// Returns a sample of up to 12 online players for the server list ping.
     #[must_use]
     pub fn player_sample(&self) -> Vec<(String, String)> {
@@ -3311,6 +3798,10 @@ impl Server {
 
     /// Runs the three independent tick loops concurrently.
     pub async fn run(self: Arc<Self>, cancel_token: CancellationToken) {
+        for world in self.worlds.values() {
+            world.set_server(Arc::downgrade(&self));
+        }
+        watchdog::spawn(&self, &self.config.load().watchdog, Handle::current());
         let game_handle = {
             let s = self.clone();
             let t = cancel_token.clone();
@@ -3373,6 +3864,7 @@ impl Server {
             }
 
             let tick_start = Instant::now();
+            self.watchdog.begin_tick();
 
             let (tick_count, runs_normally) = {
                 let mut tick_manager = self.tick_rate_manager.write();
@@ -3384,18 +3876,32 @@ impl Server {
                 (tick_manager.tick_count, runs_normally)
             };
 
+            self.watchdog.enter_phase("commands");
             Self::tick_pending_command_executions(&mut pending_command_executions);
             self.tick_command_requests(&mut pending_command_executions);
+            self.watchdog.enter_phase("functions");
+            self.tick_functions();
+            self.watchdog.enter_phase("worlds");
             self.tick_worlds_game(tick_count, runs_normally).await;
+            self.watchdog.enter_phase("players");
             player_info_ticks += 1;
             if player_info_ticks > SEND_PLAYER_INFO_INTERVAL {
                 let _span = tracing::trace_span!("broadcast_latency").entered();
                 self.broadcast_player_latency_updates();
                 player_info_ticks = 0;
             }
+            self.watchdog.enter_phase("jobs");
             self.tick_jobs(tick_count, runs_normally);
+            self.watchdog.enter_phase("scheduler");
+            self.scheduler.tick(&self);
+            if let Some(plugins) = self.plugin_manager() {
+                self.watchdog.enter_phase("plugins");
+                plugins.tick(&self, tick_count);
+            }
+            self.watchdog.enter_phase("player joins");
             self.process_player_joins();
 
+            self.watchdog.enter_phase("world changes");
             {
                 let server = self.clone();
                 let _ =
@@ -3403,22 +3909,39 @@ impl Server {
                         .await;
             }
 
+            self.watchdog.enter_phase("domain switches");
             self.process_domain_switches().await;
 
             if Instant::now() >= next_command_data_autosave {
+                self.watchdog.enter_phase("command data autosave");
                 self.autosave_command_data().await;
+                self.watchdog.enter_phase("map data autosave");
+                match self.save_maps().await {
+                    Ok(saved) => tracing::debug!(saved, "Domain map autosave completed"),
+                    Err(error) => tracing::error!(%error, "Domain map autosave failed"),
+                }
                 next_command_data_autosave = Instant::now() + COMMAND_DATA_AUTOSAVE_INTERVAL;
             }
 
-            let (tps, mspt) = {
-                let tick_duration_nanos = tick_start.elapsed().as_nanos() as u64;
-                let mut tick_manager = self.tick_rate_manager.write();
-                tick_manager.record_tick_time(tick_duration_nanos);
-                (tick_manager.get_tps(), tick_manager.get_average_mspt())
-            };
+            let tick_duration_nanos = tick_start.elapsed().as_nanos() as u64;
+            let overload = self
+                .tick_rate_manager
+                .write()
+                .record_tick_time(tick_duration_nanos);
+            if let Some(report) = overload {
+                tracing::warn!(
+                    seconds = report.seconds,
+                    tps_5s = format!("{:.1}", report.recent.tps),
+                    mspt_5s = format!("{:.2}", report.recent.mspt),
+                    tps_1m = format!("{:.1}", report.minute.tps),
+                    mspt_1m = format!("{:.2}", report.minute.mspt),
+                    "Can't keep up! Ticks have been running over budget"
+                );
+            }
+            self.watchdog.end_tick();
 
             if tick_count % TAB_LIST_UPDATE_INTERVAL == 0 {
-                self.broadcast_tab_list(tps, mspt);
+                self.broadcast_tab_list();
             }
 
             if should_sprint_this_tick {
@@ -3530,6 +4053,40 @@ impl Server {
         }
     }
 
+    /// Runs `command` for `source` to completion and returns how many of its
+    /// executions succeeded.
+    ///
+    /// Vanilla `Commands.performPrefixedCommand` with a counting callback, as
+    /// command blocks use it. Commands that suspend are cancelled.
+    pub(crate) fn perform_command(&self, source: CommandSource, command: &str) -> i32 {
+        let command = command.strip_prefix('/').unwrap_or(command);
+        let chain = match self.parse_command(command, source.clone()) {
+            Ok(chain) => chain,
+            Err(error) => {
+                source.handle_error(&error, false);
+                return 0;
+            }
+        };
+
+        let successes = Arc::new(AtomicI32::new(0));
+        let counter = Arc::clone(&successes);
+        let source = source.with_callback(CommandResultCallback::new(move |success, _| {
+            if success {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+        }));
+        let mut execution = CommandExecutionContext::for_source(&source);
+        execution.queue_initial_command(chain, source, CommandResultCallback::empty());
+        if execution.run() == ExecutionStop::Suspended {
+            tracing::warn!(
+                command,
+                "cancelled a command that suspended outside the command queue"
+            );
+            execution.cancel();
+        }
+        successes.load(Ordering::Relaxed)
+    }
+
     fn send_command_suggestions(
         self: &Arc<Self>,
         player: &Arc<Player>,
@@ -3564,6 +4121,43 @@ impl Server {
         dispatcher.completion_suggestions(&parse)
     }
 
+    /// Returns the usage of every root command `source` can use, or of the
+    /// subcommands of `command` when given, without the leading `/`.
+    ///
+    /// Returns `None` if `command` isn't a complete command path for `source`.
+    pub(crate) fn command_usages(
+        &self,
+        command: Option<&str>,
+        source: CommandSource,
+    ) -> Option<Vec<String>> {
+        let dispatcher = self.command_dispatcher.read();
+        let Some(command) = command else {
+            let root = dispatcher.root();
+            return Some(
+                dispatcher
+                    .smart_usage(root, &source)
+                    .into_iter()
+                    .map(|(_, usage)| usage)
+                    .collect(),
+            );
+        };
+        let parse = dispatcher.parse(command, source.clone());
+        if parse.reader().can_read() {
+            return None;
+        }
+        let last = parse.context().nodes().last()?.node();
+        let usages = dispatcher.smart_usage(last, &source);
+        if usages.is_empty() {
+            return Some(vec![command.to_owned()]);
+        }
+        Some(
+            usages
+                .into_iter()
+                .map(|(_, usage)| format!("{command} {usage}"))
+                .collect(),
+        )
+    }
+
     /// Chunk sending tick loop — encodes and sends chunks to players independently.
     async fn run_chunk_sending_tick(self: Arc<Self>, cancel_token: CancellationToken) {
         let nanos_per_tick = 1_000_000_000 / CHUNK_SENDING_TPS;
@@ -3628,13 +4222,12 @@ impl Server {
 
     /// Executes one chunk sending tick across all worlds and players.
     ///
-    /// A per-world per-tick encode cache is used so overlapping view areas
-    /// don't re-encode the same chunk within a single tick.
+    /// Chunk holders cache their encoded packet, so overlapping view areas
+    /// don't re-encode the same chunk.
     fn tick_chunk_sending(&self) {
         for world in self.worlds.values() {
-            let mut encode_cache = rustc_hash::FxHashMap::default();
             world.players.iter_players(|_uuid, player| {
-                Self::send_chunks_for_player(player, world, &mut encode_cache);
+                Self::send_chunks_for_player(player, world);
                 true
             });
         }
@@ -3642,27 +4235,29 @@ impl Server {
 
     /// Three-phase chunk send for a single player: prepare (lock briefly),
     /// encode (no lock), commit (lock briefly + generation check).
-    fn send_chunks_for_player(
-        player: &Arc<Player>,
-        world: &Arc<World>,
-        encode_cache: &mut rustc_hash::FxHashMap<ChunkPos, EncodedChunk>,
-    ) {
+    fn send_chunks_for_player(player: &Arc<Player>, world: &Arc<World>) {
         let chunk_pos = *player.last_chunk_pos.lock();
+        let look = player.look_angle();
         let connection = &player.connection;
 
         // Phase 1: prepare (brief lock)
         let prepared = {
             let mut sender = player.chunk_sender.lock();
-            sender.prepare_batch(world, chunk_pos, &player.chunk_send_epoch)
+            sender.prepare_batch(
+                world,
+                chunk_pos,
+                DVec2::new(look.x, look.z),
+                &player.chunk_send_epoch,
+            )
         };
 
         let Some(batch) = prepared else {
             return;
         };
 
-        // Phase 2: encode (no lock held — uses per-tick local cache)
+        // Phase 2: encode (no lock held — reuses each holder's cached packet)
         let compression = connection.compression();
-        let encoded = ChunkSender::encode_batch(&batch, encode_cache, compression);
+        let encoded = ChunkSender::encode_batch(&batch, compression);
 
         // Phase 3: commit (brief lock + generation check)
         let sent_chunks = {
@@ -4228,11 +4823,12 @@ impl Server {
         let mut tasks = Vec::with_capacity(self.worlds.len());
         for world in self.worlds.values() {
             let world_clone = world.clone();
+            let random_tick_pool = self.random_tick_pool.clone();
             tasks.push(spawn_blocking(move || {
                 if runs_normally {
                     world_clone.chunk_map.tick_timed_tickets();
                 }
-                world_clone.tick_game(tick_count, runs_normally)
+                world_clone.tick_game(tick_count, runs_normally, random_tick_pool.as_deref())
             }));
         }
         let mut all_timings: Vec<WorldGameTickTimings> = Vec::with_capacity(tasks.len());
@@ -4256,6 +4852,7 @@ impl Server {
                 tick_chunks = ?cm.tick_chunks,
                 tick_block_entities = ?cm.tick_block_entities,
                 tickable_count = cm.tickable_count,
+                tick_regions = cm.tick_regions,
                 total_chunks = cm.total_chunks,
                 "Game tick slow"
             );
@@ -4277,50 +4874,28 @@ impl Server {
     }
 
     /// Logs and broadcasts a system chat message to online players.
-    fn broadcast_system_chat(&self, message: &TextComponent, excluded_player: Option<Uuid>) {
-        log::info!("{}", message.to_plain(&DisplayResolutor));
+    pub(crate) fn broadcast_system_chat(
+        &self,
+        message: &TextComponent,
+        excluded_player: Option<Uuid>,
+    ) {
+        log::info!("{}", to_ansi(message));
+        // Shared by players with the same language unless the message resolves
+        // per viewer.
+        let per_viewer = has_resolvable_content(message);
+        let mut broadcast = BroadcastPacket::new(
+            self.config.load().compression,
+            |_: &(String, Option<Uuid>), player: &Player| CSystemChat::new(message, false, player),
+        );
         self.online_players.iter_players(|uuid, player| {
             if Some(*uuid) != excluded_player {
-                player.send_packet(CSystemChat::new(message, false, player));
+                let language = player.client_information().language;
+                broadcast.send((language, per_viewer.then_some(*uuid)), player);
             }
             true
         });
     }
 
-    /// Broadcasts the tab list header/footer with current TPS and MSPT values.
-    fn broadcast_tab_list(&self, tps: f32, mspt: f32) {
-        // Color TPS based on value
-        let tps_color = if tps >= 19.5 {
-            Color::Green
-        } else if tps >= 15.0 {
-            Color::Yellow
-        } else {
-            Color::Red
-        };
-
-        // Color MSPT based on value (under 50ms is good)
-        let mspt_color = if mspt <= 50.0 {
-            Color::Aqua
-        } else {
-            Color::Red
-        };
-
-        let header = TextComponent::plain("\n").add_children(vec![
-            TextComponent::plain("Steel Dev Build").color(Color::Yellow),
-            TextComponent::plain("\n"),
-        ]);
-        let footer = TextComponent::plain("\n").add_children(vec![
-            TextComponent::plain("TPS: ").color(Color::Gray),
-            TextComponent::plain(format!("{tps:.1}")).color(tps_color),
-            TextComponent::plain(" | ").color(Color::DarkGray),
-            TextComponent::plain("MSPT: ").color(Color::Gray),
-            TextComponent::plain(format!("{mspt:.2}")).color(mspt_color),
-            TextComponent::plain("\n"),
-        ]);
-
-        self.broadcast_to_online_with(|player| CTabList::new(&header, &footer, player));
-    }
-
     /// Broadcasts a sprint completion report to all players.
     pub(crate) fn broadcast_sprint_report(&self, report: &SprintReport) {
         let message: TextComponent = translations::COMMANDS_TICK_SPRINT_REPORT
@@ -4333,7 +4908,16 @@ impl Server {
         self.broadcast_system_chat(&message, None);
     }
 
-    fn broadcast_player_join_message(&self, player: &Player, previous_name: Option<&str>) {
+    fn broadcast_player_join_message(
+        &self,
+        player: &Player,
+        previous_name: Option<&str>,
+        plugin_message: Option<TextComponent>,
+    ) {
+        if let Some(message) = plugin_message {
+            self.broadcast_system_chat(&message, Some(player.gameprofile.id));
+            return;
+        }
         let display_name = player.display_name();
         // Fallback to the current name when the cache has no prior entry.
         let old_name = previous_name.unwrap_or(player.gameprofile.name.as_str());
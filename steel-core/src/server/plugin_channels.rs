@@ -0,0 +1,102 @@
+//! Plugin message channel routing.
+//!
+//! Custom payloads on the channels vanilla defines are handled by the player
+//! itself. Every other channel is routed to the handler registered for it here,
+//! and the registered channels are announced to clients through
+//! `minecraft:register` when they join.
+
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+use steel_utils::Identifier;
+use steel_utils::locks::SyncRwLock;
+
+use crate::player::Player;
+use crate::player::plugin_messages::{BRAND, REGISTER, UNREGISTER, channel_list_payload};
+
+/// Handles the payload of a plugin message sent by a player.
+pub type PluginMessageHandler = Arc<dyn Fn(&Arc<Player>, &[u8]) + Send + Sync>;
+
+/// Server-side plugin message handlers by channel.
+#[derive(Default)]
+pub struct PluginChannelRegistry {
+    handlers: SyncRwLock<FxHashMap<Identifier, PluginMessageHandler>>,
+}
+
+impl PluginChannelRegistry {
+    /// Creates a registry without channels.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the handler for `channel`.
+    ///
+    /// Returns `false` if the channel already has a handler or is one of the
+    /// vanilla channels handled by the server.
+    pub fn register(
+        &self,
+        channel: Identifier,
+        handler: impl Fn(&Arc<Player>, &[u8]) + Send + Sync + 'static,
+    ) -> bool {
+        if is_vanilla_channel(&channel) {
+            return false;
+        }
+        let mut handlers = self.handlers.write();
+        if handlers.contains_key(&channel) {
+            return false;
+        }
+        handlers.insert(channel, Arc::new(handler));
+        true
+    }
+
+    /// Removes the handler for `channel`. Returns whether one was registered.
+    pub fn unregister(&self, channel: &Identifier) -> bool {
+        self.handlers.write().remove(channel).is_some()
+    }
+
+    /// Returns the registered channels, sorted.
+    #[must_use]
+    pub fn channels(&self) -> Vec<Identifier> {
+        let mut channels: Vec<_> = self.handlers.read().keys().cloned().collect();
+        channels.sort_by_cached_key(ToString::to_string);
+        channels
+    }
+
+    /// Returns the handler registered for `channel`.
+    #[must_use]
+    pub fn handler(&self, channel: &Identifier) -> Option<PluginMessageHandler> {
+        self.handlers.read().get(channel).cloned()
+    }
+
+    /// Announces the registered channels to a player that just joined.
+    pub(crate) fn announce_to(&self, player: &Player) {
+        let channels = self.channels();
+        if !channels.is_empty() {
+            player.send_plugin_message(REGISTER, channel_list_payload(&channels));
+        }
+    }
+}
+
+fn is_vanilla_channel(channel: &Identifier) -> bool {
+    *channel == BRAND || *channel == REGISTER || *channel == UNREGISTER
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_rejects_duplicate_and_vanilla_channels() {
+        let registry = PluginChannelRegistry::new();
+        let channel = Identifier::new("example", "sync");
+
+        assert!(registry.register(channel.clone(), |_, _| {}));
+        assert!(!registry.register(channel.clone(), |_, _| {}));
+        assert!(!registry.register(BRAND, |_, _| {}));
+        assert_eq!(registry.channels(), vec![channel.clone()]);
+
+        assert!(registry.unregister(&channel));
+        assert!(registry.handler(&channel).is_none());
+    }
+}
@@ -0,0 +1,487 @@
+//! Sampling profiler over tracing spans.
+//!
+//! While a profile runs, [`profiler_layer`] keeps the stack of spans each thread
+//! is inside. A sampler thread reads every stack at a fixed interval and counts
+//! identical stacks, which gives a flame graph of where the tick loop, chunk
+//! workers and network tasks spend their time without external tooling. Spans
+//! are only created for the profiler while a profile runs.
+
+use std::cell::RefCell;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Weak};
+use std::time::{Duration, Instant};
+use std::{fs, io, thread};
+
+use chrono::Local;
+use rustc_hash::FxHashMap;
+use steel_utils::locks::SyncMutex;
+use tracing::span::Id;
+use tracing::subscriber::Interest;
+use tracing::{Metadata, Subscriber};
+use tracing_subscriber::Layer;
+use tracing_subscriber::filter::DynFilterFn;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Default time between two samples.
+pub const DEFAULT_SAMPLE_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Directory profile reports are written to.
+const REPORT_DIR: &str = "profiler";
+
+static PROFILER: LazyLock<Profiler> = LazyLock::new(Profiler::default);
+
+thread_local! {
+    static THREAD_STACK: RefCell<Option<Arc<ThreadStack>>> = const { RefCell::new(None) };
+}
+
+/// Spans one thread is currently inside, outermost first.
+struct ThreadStack {
+    name: String,
+    frames: SyncMutex<Vec<&'static str>>,
+}
+
+#[derive(Default)]
+struct Profiler {
+    /// Id of the running profile, or 0 while stopped.
+    session: AtomicU64,
+    next_session: AtomicU64,
+    threads: SyncMutex<Vec<Weak<ThreadStack>>>,
+    profile: SyncMutex<Profile>,
+}
+
+/// Samples collected by the last or running profile.
+#[derive(Default)]
+struct Profile {
+    /// Sample counts by `;`-joined stack, thread name first.
+    stacks: FxHashMap<String, u64>,
+    samples: u64,
+    interval: Duration,
+    started: Option<Instant>,
+    /// Length of the profile once it stopped.
+    duration: Option<Duration>,
+    /// Measured runtime of scheduled tasks by name.
+    tasks: FxHashMap<String, TaskTotals>,
+}
+
+/// Runs and runtime of the scheduled tasks sharing a name.
+#[derive(Debug, Default, Clone, Copy)]
+struct TaskTotals {
+    runs: u64,
+    total: Duration,
+    max: Duration,
+}
+
+/// Why a profiler command could not run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfilerError {
+    /// `start` while a profile is running.
+    AlreadyRunning,
+    /// `stop` while no profile is running.
+    NotRunning,
+    /// `report` before any profile was started.
+    NoProfile,
+    /// The sampler thread could not be started.
+    SamplerUnavailable,
+}
+
+/// Summary of a profile.
+#[derive(Debug, Clone, Copy)]
+pub struct ProfileSummary {
+    /// Stack samples taken.
+    pub samples: u64,
+    /// Time spent profiling.
+    pub duration: Duration,
+}
+
+/// Profile report formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// A self-contained page with a collapsible call tree.
+    Html,
+    /// Folded stacks, one `frame;frame count` line per stack.
+    Folded,
+}
+
+/// Returns the layer that tracks span stacks for the profiler.
+///
+/// It only enables spans while a profile runs and never sees events.
+pub fn profiler_layer<S>() -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let filter = DynFilterFn::new(|metadata: &Metadata<'_>, _| metadata.is_span() && is_running())
+        .with_callsite(|metadata| {
+            if metadata.is_span() {
+                Interest::sometimes()
+            } else {
+                Interest::never()
+            }
+        });
+    ProfilerLayer.with_filter(filter)
+}
+
+/// Records span enters and exits on the current thread's stack.
+pub struct ProfilerLayer;
+
+impl<S> Layer<S> for ProfilerLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            with_thread_stack(|stack| stack.frames.lock().push(span.name()));
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            with_thread_stack(|stack| {
+                let mut frames = stack.frames.lock();
+                // Spans entered before the profile started were never pushed.
+                if frames.last() == Some(&span.name()) {
+                    frames.pop();
+                }
+            });
+        }
+    }
+}
+
+fn with_thread_stack(f: impl FnOnce(&ThreadStack)) {
+    // Spans can still close while the thread's locals are being destroyed.
+    let _ = THREAD_STACK.try_with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let stack = stack.get_or_insert_with(|| {
+            let current = thread::current();
+            let stack = Arc::new(ThreadStack {
+                name: current.name().map_or_else(
+                    || format!("{:?}", current.id()),
+                    |name| {
+                        name.trim_end_matches(|c: char| c.is_ascii_digit() || c == '-')
+                            .to_owned()
+                    },
+                ),
+                frames: SyncMutex::new(Vec::new()),
+            });
+            PROFILER.threads.lock().push(Arc::downgrade(&stack));
+            stack
+        });
+        f(stack);
+    });
+}
+
+/// Returns whether a profile is running.
+#[must_use]
+pub fn is_running() -> bool {
+    PROFILER.session.load(Ordering::Relaxed) != 0
+}
+
+/// Starts a profile that samples every `interval`, discarding the last one.
+pub fn start(interval: Duration) -> Result<(), ProfilerError> {
+    let session = PROFILER.next_session.fetch_add(1, Ordering::Relaxed) + 1;
+    if PROFILER
+        .session
+        .compare_exchange(0, session, Ordering::AcqRel, Ordering::Relaxed)
+        .is_err()
+    {
+        return Err(ProfilerError::AlreadyRunning);
+    }
+    *PROFILER.profile.lock() = Profile {
+        interval,
+        started: Some(Instant::now()),
+        ..Profile::default()
+    };
+
+    let spawned = thread::Builder::new()
+        .name("steel-profiler".to_owned())
+        .spawn(move || {
+            loop {
+                thread::sleep(interval);
+                if PROFILER.session.load(Ordering::Relaxed) != session {
+                    break;
+                }
+                sample();
+            }
+        });
+    if let Err(error) = spawned {
+        tracing::error!(%error, "Failed to start the profiler thread");
+        PROFILER.session.store(0, Ordering::Release);
+        return Err(ProfilerError::SamplerUnavailable);
+    }
+    Ok(())
+}
+
+/// Stops the running profile and returns its summary.
+pub fn stop() -> Result<ProfileSummary, ProfilerError> {
+    if PROFILER.session.swap(0, Ordering::AcqRel) == 0 {
+        return Err(ProfilerError::NotRunning);
+    }
+    let mut profile = PROFILER.profile.lock();
+    let duration = profile.elapsed();
+    profile.duration = Some(duration);
+    Ok(ProfileSummary {
+        samples: profile.samples,
+        duration,
+    })
+}
+
+/// Takes one sample of every thread that is inside a span.
+fn sample() {
+    let mut stacks = Vec::new();
+    PROFILER.threads.lock().retain(|stack| {
+        let Some(stack) = stack.upgrade() else {
+            return false;
+        };
+        let frames = stack.frames.lock();
+        if !frames.is_empty() {
+            let mut folded = stack.name.clone();
+            for frame in frames.iter() {
+                folded.push(';');
+                folded.push_str(frame);
+            }
+            stacks.push(folded);
+        }
+        true
+    });
+
+    let mut profile = PROFILER.profile.lock();
+    profile.samples += 1;
+    for stack in stacks {
+        *profile.stacks.entry(stack).or_default() += 1;
+    }
+}
+
+/// Records one run of a scheduled task while a profile runs.
+pub fn record_task(name: &str, elapsed: Duration) {
+    if !is_running() {
+        return;
+    }
+    let mut profile = PROFILER.profile.lock();
+    let tasks = &mut profile.tasks;
+    if !tasks.contains_key(name) {
+        tasks.insert(name.to_owned(), TaskTotals::default());
+    }
+    let Some(totals) = tasks.get_mut(name) else {
+        return;
+    };
+    totals.runs += 1;
+    totals.total += elapsed;
+    totals.max = totals.max.max(elapsed);
+}
+
+/// Writes a report of the last or running profile to the report directory.
+///
+/// Returns the written file and the profile summary.
+pub fn write_report(format: ReportFormat) -> Result<(PathBuf, ProfileSummary), ReportError> {
+    let (contents, summary) = {
+        let profile = PROFILER.profile.lock();
+        if profile.started.is_none() {
+            return Err(ReportError::Profiler(ProfilerError::NoProfile));
+        }
+        let summary = ProfileSummary {
+            samples: profile.samples,
+            duration: profile.duration.unwrap_or_else(|| profile.elapsed()),
+        };
+        let contents = match format {
+            ReportFormat::Html => profile.html(summary),
+            ReportFormat::Folded => profile.folded(),
+        };
+        (contents, summary)
+    };
+
+    let extension = match format {
+        ReportFormat::Html => "html",
+        ReportFormat::Folded => "txt",
+    };
+    let path = Path::new(REPORT_DIR).join(format!(
+        "profile-{}.{extension}",
+        Local::now().format("%Y-%m-%d_%H.%M.%S")
+    ));
+    fs::create_dir_all(REPORT_DIR)?;
+    fs::write(&path, contents)?;
+    Ok((path, summary))
+}
+
+/// Failure while writing a profile report.
+#[derive(Debug)]
+pub enum ReportError {
+    /// There was nothing to report.
+    Profiler(ProfilerError),
+    /// The report file could not be written.
+    Io(io::Error),
+}
+
+impl From<io::Error> for ReportError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl Profile {
+    fn elapsed(&self) -> Duration {
+        self.started
+            .map_or(Duration::ZERO, |started| started.elapsed())
+    }
+
+    /// Stacks sorted by sample count, most sampled first.
+    fn sorted_stacks(&self) -> Vec<(&str, u64)> {
+        let mut stacks: Vec<_> = self
+            .stacks
+            .iter()
+            .map(|(stack, count)| (stack.as_str(), *count))
+            .collect();
+        stacks.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        stacks
+    }
+
+    fn folded(&self) -> String {
+        let mut out = String::new();
+        for (stack, count) in self.sorted_stacks() {
+            let _ = writeln!(out, "{stack} {count}");
+        }
+        out
+    }
+
+    fn html(&self, summary: ProfileSummary) -> String {
+        let mut root = CallNode::default();
+        for (stack, count) in self.sorted_stacks() {
+            root.insert(stack.split(';'), count);
+        }
+        let mut out = String::from(
+            "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>Steel profile</title>\
+             <style>body{font-family:monospace}details{margin-left:1.5em}\
+             summary{cursor:pointer;white-space:nowrap}</style></head><body>\n",
+        );
+        let _ = writeln!(
+            out,
+            "<h1>Steel profile</h1><p>{} samples every {:?} over {:.1}s</p>",
+            summary.samples,
+            self.interval,
+            summary.duration.as_secs_f64()
+        );
+        root.write_html(&mut out, summary.samples.max(1));
+        self.write_task_table(&mut out);
+        out.push_str("</body></html>\n");
+        out
+    }
+
+    /// Writes the scheduled tasks, longest total runtime first.
+    fn write_task_table(&self, out: &mut String) {
+        if self.tasks.is_empty() {
+            return;
+        }
+        let mut tasks: Vec<_> = self.tasks.iter().collect();
+        tasks.sort_unstable_by(|a, b| b.1.total.cmp(&a.1.total).then_with(|| a.0.cmp(b.0)));
+        out.push_str(
+            "<h2>Scheduled tasks</h2><table><tr><th>Task</th><th>Runs</th>\
+             <th>Total ms</th><th>Mean ms</th><th>Max ms</th></tr>\n",
+        );
+        for (name, totals) in tasks {
+            let total = totals.total.as_secs_f64() * 1000.0;
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td>{total:.3}</td><td>{:.3}</td><td>{:.3}</td></tr>",
+                escape_html(name),
+                totals.runs,
+                total / totals.runs.max(1) as f64,
+                totals.max.as_secs_f64() * 1000.0
+            );
+        }
+        out.push_str("</table>\n");
+    }
+}
+
+/// A frame in the aggregated call tree.
+#[derive(Default)]
+struct CallNode {
+    samples: u64,
+    children: Vec<(String, CallNode)>,
+}
+
+impl CallNode {
+    fn insert<'a>(&mut self, mut frames: impl Iterator<Item = &'a str>, count: u64) {
+        let Some(frame) = frames.next() else {
+            return;
+        };
+        let index = if let Some(index) = self.children.iter().position(|(name, _)| name == frame) {
+            index
+        } else {
+            self.children.push((frame.to_owned(), Self::default()));
+            self.children.len() - 1
+        };
+        let child = &mut self.children[index].1;
+        child.samples += count;
+        child.insert(frames, count);
+    }
+
+    fn write_html(&self, out: &mut String, total: u64) {
+        let mut children: Vec<_> = self.children.iter().collect();
+        children.sort_by(|a, b| b.1.samples.cmp(&a.1.samples));
+        for (name, child) in children {
+            let _ = write!(
+                out,
+                "<details><summary>{:.2}% {} ({})</summary>",
+                child.samples as f64 * 100.0 / total as f64,
+                escape_html(name),
+                child.samples
+            );
+            child.write_html(out, total);
+            out.push_str("</details>\n");
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_aggregate_stacks_by_sample_count() {
+        let profile = Profile {
+            stacks: [
+                ("tick;worlds;entities".to_owned(), 3),
+                ("tick;worlds".to_owned(), 1),
+                ("chunk-worker;generate".to_owned(), 2),
+            ]
+            .into_iter()
+            .collect(),
+            samples: 6,
+            interval: DEFAULT_SAMPLE_INTERVAL,
+            started: Some(Instant::now()),
+            duration: Some(Duration::from_secs(1)),
+            tasks: [(
+                "example:<heal>".to_owned(),
+                TaskTotals {
+                    runs: 4,
+                    total: Duration::from_millis(2),
+                    max: Duration::from_millis(1),
+                },
+            )]
+            .into_iter()
+            .collect(),
+        };
+
+        assert_eq!(
+            profile.folded(),
+            "tick;worlds;entities 3\nchunk-worker;generate 2\ntick;worlds 1\n"
+        );
+
+        let html = profile.html(ProfileSummary {
+            samples: 6,
+            duration: Duration::from_secs(1),
+        });
+        assert!(html.contains("<summary>66.67% tick (4)</summary>"));
+        assert!(html.contains("<summary>50.00% entities (3)</summary>"));
+        assert!(html.contains(
+            "<tr><td>example:&lt;heal&gt;</td><td>4</td><td>2.000</td><td>0.500</td><td>1.000</td></tr>"
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+use arc_swap::ArcSwap;
 use simdnbt::ToNbtTag;
 use std::sync::Arc;
 
@@ -18,9 +19,11 @@ use steel_registry::{
     DAMAGE_TYPE_REGISTRY, DIALOG_REGISTRY, DIMENSION_TYPE_REGISTRY, ENCHANTMENT_REGISTRY,
     ENTITY_TYPE_REGISTRY, FLUID_REGISTRY, FROG_VARIANT_REGISTRY, INSTRUMENT_REGISTRY,
     ITEMS_REGISTRY, JUKEBOX_SONG_REGISTRY, PAINTING_VARIANT_REGISTRY, PIG_SOUND_VARIANT_REGISTRY,
-    PIG_VARIANT_REGISTRY, REGISTRY, Registry, RegistryEntry as _, TIMELINE_REGISTRY,
+    PIG_VARIANT_REGISTRY, REGISTRY, Registry, RegistryEntry as _, RegistryExt, TIMELINE_REGISTRY,
     TRIM_MATERIAL_REGISTRY, TRIM_PATTERN_REGISTRY, TaggedRegistryExt, WOLF_SOUND_VARIANT_REGISTRY,
     WOLF_VARIANT_REGISTRY, WORLD_CLOCK_REGISTRY, ZOMBIE_NAUTILUS_VARIANT_REGISTRY,
+    registry_overlay::registry_overlay,
+    tag_overlay::{tag_overlay, with_overlaid_tag},
 };
 use steel_utils::Identifier;
 use steel_utils::codec::VarInt;
@@ -29,10 +32,11 @@ use steel_protocol::packet_traits::CompressionInfo;
 
 /// Caches compressed registry packets to avoid re-compressing them for every player.
 pub struct RegistryCache {
-    /// The cached registry data packets.
-    pub registry_packets: Arc<[EncodedPacket]>,
-    /// The cached tags packet.
-    pub tags_packet: Arc<EncodedPacket>,
+    /// The cached registry data packets, replaced when datapacks change registries.
+    registry_packets: ArcSwap<Vec<EncodedPacket>>,
+    /// The cached tags packet, replaced when datapacks change tag contents.
+    tags_packet: ArcSwap<EncodedPacket>,
+    compression: Option<CompressionInfo>,
 }
 
 impl RegistryCache {
@@ -46,26 +50,69 @@ impl RegistryCache {
             build_compressed_packets(registry_packets, tags_by_registry_packet, compression);
 
         Self {
-            registry_packets,
-            tags_packet: Arc::new(tags_packet),
+            registry_packets: ArcSwap::from_pointee(registry_packets),
+            tags_packet: ArcSwap::from_pointee(tags_packet),
+            compression,
+        }
+    }
+
+    /// Returns the cached configuration-phase registry data packets.
+    #[must_use]
+    pub fn registry_packets(&self) -> Arc<Vec<EncodedPacket>> {
+        self.registry_packets.load_full()
+    }
+
+    /// Returns the cached configuration-phase tags packet.
+    #[must_use]
+    pub fn tags_packet(&self) -> Arc<EncodedPacket> {
+        self.tags_packet.load_full()
+    }
+
+    /// Rebuilds the cached packets from the current registry and tag overlays.
+    ///
+    /// Registry data can only be sent during configuration, so players already
+    /// in game keep their registries until they reconnect.
+    pub fn refresh(&self) {
+        let registry_packets = Self::build_registry_packets(&REGISTRY)
+            .into_iter()
+            .map(|packet| compress_packet(packet, self.compression))
+            .collect::<Option<Vec<_>>>();
+        if let Some(registry_packets) = registry_packets {
+            self.registry_packets.store(Arc::new(registry_packets));
+        }
+        if let Some(packet) = compress_packet(Self::build_tags_packet(&REGISTRY), self.compression)
+        {
+            self.tags_packet.store(Arc::new(packet));
         }
     }
 
     fn build_registry_packets(registry: &Registry) -> Vec<CRegistryData> {
+        let overlay = registry_overlay();
         let mut packets = Vec::with_capacity(9);
 
+        // Datapack entries replace compiled data in place and append new keys.
         macro_rules! add_registry {
             ($reg_key:expr, $field:ident) => {
-                packets.push(CRegistryData::new(
-                    $reg_key,
-                    registry
-                        .$field
-                        .iter()
-                        .map(|(_, entry)| {
-                            RegistryEntry::new(entry.key.clone(), Some(entry.to_nbt_tag()))
-                        })
-                        .collect(),
-                ));
+                let mut entries: Vec<RegistryEntry> = registry
+                    .$field
+                    .iter()
+                    .map(|(_, entry)| {
+                        let data = overlay
+                            .as_ref()
+                            .and_then(|overlay| overlay.get(&$reg_key, &entry.key))
+                            .cloned()
+                            .unwrap_or_else(|| entry.to_nbt_tag());
+                        RegistryEntry::new(entry.key.clone(), Some(data))
+                    })
+                    .collect();
+                if let Some(overlay) = &overlay {
+                    entries.extend(
+                        overlay
+                            .additions(&$reg_key, |key| registry.$field.by_key(key).is_some())
+                            .map(|(key, data)| RegistryEntry::new(key.clone(), Some(data.clone()))),
+                    );
+                }
+                packets.push(CRegistryData::new($reg_key, entries));
             };
         }
 
@@ -104,17 +151,54 @@ impl RegistryCache {
         packets
     }
 
-    fn build_tags_packet(registry: &Registry) -> CUpdateTags {
+    /// Builds the tags packet, including tags only defined by datapacks.
+    pub(crate) fn build_tags_packet(registry: &Registry) -> CUpdateTags {
+        let overlay = tag_overlay();
+        let registries = registry_overlay();
         let mut tags_by_registry: TagCollection = Vec::with_capacity(10);
         macro_rules! add_tags {
             ($reg_key:expr, $field:ident) => {
-                let mut tags: Vec<(Identifier, Vec<VarInt>)> =
-                    Vec::with_capacity(registry.$field.tag_keys().count());
-                for tag_key in registry.$field.tag_keys() {
-                    let mut ids = Vec::with_capacity(registry.$field.iter_tag(tag_key).count());
-                    for entry in registry.$field.iter_tag(tag_key) {
-                        ids.push(VarInt::from(entry.id()));
+                // Datapack entries follow the compiled ones, as in the registry data.
+                let additions: Vec<&Identifier> = registries
+                    .as_ref()
+                    .map(|registries| {
+                        registries
+                            .additions(&$reg_key, |key| registry.$field.by_key(key).is_some())
+                            .map(|(key, _)| key)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let mut tag_keys: Vec<&Identifier> = registry.$field.tag_keys().collect();
+                if let Some(overlay) = &overlay {
+                    for (tag_key, _) in overlay.tags(&$reg_key) {
+                        if !tag_keys.contains(&tag_key) {
+                            tag_keys.push(tag_key);
+                        }
                     }
+                }
+                let mut tags: Vec<(Identifier, Vec<VarInt>)> = Vec::with_capacity(tag_keys.len());
+                for tag_key in tag_keys {
+                    let ids = with_overlaid_tag(&$reg_key, tag_key, |entries| {
+                        entries
+                            .iter()
+                            .filter_map(|key| {
+                                registry.$field.id_from_key(key).or_else(|| {
+                                    additions
+                                        .iter()
+                                        .position(|addition| *addition == key)
+                                        .map(|index| registry.$field.len() + index)
+                                })
+                            })
+                            .map(VarInt::from)
+                            .collect()
+                    })
+                    .unwrap_or_else(|| {
+                        registry
+                            .$field
+                            .iter_tag(tag_key)
+                            .map(|entry| VarInt::from(entry.id()))
+                            .collect()
+                    });
                     tags.push((tag_key.clone(), ids));
                 }
                 tags_by_registry.push(($reg_key, tags));
@@ -131,20 +215,7 @@ impl RegistryCache {
         add_tags!(INSTRUMENT_REGISTRY, instruments);
         add_tags!(PAINTING_VARIANT_REGISTRY, painting_variants);
         add_tags!(ENCHANTMENT_REGISTRY, enchantments);
-
-        // Build fluid tags
-        let mut fluid_tags: Vec<(Identifier, Vec<VarInt>)> =
-            Vec::with_capacity(registry.fluids.tag_keys().count());
-        for tag_key in registry.fluids.tag_keys() {
-            let mut fluid_ids = Vec::with_capacity(registry.fluids.iter_tag(tag_key).count());
-
-            for fluid in registry.fluids.iter_tag(tag_key) {
-                fluid_ids.push(VarInt::from(fluid.id() as i32));
-            }
-
-            fluid_tags.push((tag_key.clone(), fluid_ids));
-        }
-        tags_by_registry.push((FLUID_REGISTRY, fluid_tags));
+        add_tags!(FLUID_REGISTRY, fluids);
 
         // Build and return a CUpdateTagsPacket based on the registry data
         CUpdateTags::new(tags_by_registry)
@@ -172,7 +243,7 @@ pub fn build_compressed_packets(
     registry_packets: Vec<CRegistryData>,
     tags_packet: CUpdateTags,
     compression: Option<CompressionInfo>,
-) -> (Arc<[EncodedPacket]>, EncodedPacket) {
+) -> (Vec<EncodedPacket>, EncodedPacket) {
     let mut compressed_packets = Vec::with_capacity(registry_packets.len());
 
     for packet in registry_packets {
@@ -183,5 +254,5 @@ pub fn build_compressed_packets(
     let compressed_tags_packet =
         compress_packet(tags_packet, compression).expect("Failed to compress tags packet");
 
-    (compressed_packets.into(), compressed_tags_packet)
+    (compressed_packets, compressed_tags_packet)
 }
@@ -0,0 +1,365 @@
+//! Task scheduling for subsystems and plugins.
+//!
+//! Sync tasks run on the game tick thread after server jobs, in the order they
+//! become due. The scheduler counts its own ticks, so delays keep running while
+//! the tick rate manager freezes the game. Async tasks run on the Tokio runtime
+//! and are cancelled when the server shuts down.
+//!
+//! Every run is timed. The totals per task are available through
+//! [`Scheduler::tasks`] and are added to the running profile, if any.
+
+use std::future::Future;
+use std::mem;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use rustc_hash::FxHashMap;
+use steel_utils::locks::SyncMutex;
+use tokio_util::sync::CancellationToken;
+
+use crate::server::{Server, profiler};
+
+/// Identifies a scheduled task.
+pub type TaskId = u64;
+
+/// Cancels a scheduled task.
+#[derive(Debug, Clone)]
+pub struct TaskHandle {
+    id: TaskId,
+    token: CancellationToken,
+}
+
+impl TaskHandle {
+    /// Returns the id of the task.
+    #[must_use]
+    pub const fn id(&self) -> TaskId {
+        self.id
+    }
+
+    /// Cancels the task. A sync task that is due is skipped, an async task is
+    /// dropped at its next await point.
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Returns whether the task was cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+}
+
+/// Runtime accounting of a scheduled task.
+#[derive(Debug, Clone)]
+pub struct TaskInfo {
+    /// Id of the task.
+    pub id: TaskId,
+    /// Name the task was scheduled with.
+    pub name: Arc<str>,
+    /// Whether the task runs on the async runtime.
+    pub is_async: bool,
+    /// Completed runs, or polls for async tasks.
+    pub runs: u64,
+    /// Time spent in all runs.
+    pub total: Duration,
+    /// Longest single run.
+    pub max: Duration,
+}
+
+/// Live task with its runtime counters.
+struct TaskRecord {
+    id: TaskId,
+    name: Arc<str>,
+    is_async: bool,
+    token: CancellationToken,
+    runs: AtomicU64,
+    total_nanos: AtomicU64,
+    max_nanos: AtomicU64,
+}
+
+impl TaskRecord {
+    fn record(&self, elapsed: Duration) {
+        let nanos = elapsed.as_nanos() as u64;
+        self.runs.fetch_add(1, Ordering::Relaxed);
+        self.total_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.max_nanos.fetch_max(nanos, Ordering::Relaxed);
+        profiler::record_task(&self.name, elapsed);
+    }
+
+    fn info(&self) -> TaskInfo {
+        TaskInfo {
+            id: self.id,
+            name: Arc::clone(&self.name),
+            is_async: self.is_async,
+            runs: self.runs.load(Ordering::Relaxed),
+            total: Duration::from_nanos(self.total_nanos.load(Ordering::Relaxed)),
+            max: Duration::from_nanos(self.max_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+type LiveTasks = Arc<SyncMutex<FxHashMap<TaskId, Arc<TaskRecord>>>>;
+
+enum TaskAction {
+    Once(Box<dyn FnOnce(&Arc<Server>) + Send>),
+    Repeating {
+        period: u64,
+        action: Box<dyn FnMut(&Arc<Server>) + Send>,
+    },
+}
+
+struct SyncTask {
+    /// Scheduler tick the task runs on next.
+    due: u64,
+    record: Arc<TaskRecord>,
+    action: TaskAction,
+}
+
+/// Runs closures on the game tick thread or the async runtime.
+pub struct Scheduler {
+    /// Ticks run so far.
+    tick: AtomicU64,
+    next_id: AtomicU64,
+    pending: SyncMutex<Vec<SyncTask>>,
+    live: LiveTasks,
+    /// Cancelled on shutdown, parent of every task token.
+    shutdown: CancellationToken,
+}
+
+impl Scheduler {
+    /// Creates a scheduler whose tasks are cancelled with `shutdown`.
+    #[must_use]
+    pub fn new(shutdown: CancellationToken) -> Self {
+        Self {
+            tick: AtomicU64::new(0),
+            next_id: AtomicU64::new(1),
+            pending: SyncMutex::new(Vec::new()),
+            live: Arc::default(),
+            shutdown,
+        }
+    }
+
+    /// Runs `action` on the next tick.
+    pub fn run_next_tick(
+        &self,
+        name: impl Into<Arc<str>>,
+        action: impl FnOnce(&Arc<Server>) + Send + 'static,
+    ) -> TaskHandle {
+        self.run_later(name, 1, action)
+    }
+
+    /// Runs `action` once `delay` ticks have passed. A delay of 0 runs on the
+    /// next tick.
+    pub fn run_later(
+        &self,
+        name: impl Into<Arc<str>>,
+        delay: u64,
+        action: impl FnOnce(&Arc<Server>) + Send + 'static,
+    ) -> TaskHandle {
+        self.schedule(name.into(), delay, TaskAction::Once(Box::new(action)))
+    }
+
+    /// Runs `action` once `delay` ticks have passed and then every `period`
+    /// ticks until cancelled. A period of 0 runs every tick.
+    pub fn run_repeating(
+        &self,
+        name: impl Into<Arc<str>>,
+        delay: u64,
+        period: u64,
+        action: impl FnMut(&Arc<Server>) + Send + 'static,
+    ) -> TaskHandle {
+        self.schedule(
+            name.into(),
+            delay,
+            TaskAction::Repeating {
+                period: period.max(1),
+                action: Box::new(action),
+            },
+        )
+    }
+
+    /// Spawns `future` on the async runtime.
+    ///
+    /// Must be called from within the runtime, which includes the tick thread.
+    pub fn run_async(
+        &self,
+        name: impl Into<Arc<str>>,
+        future: impl Future<Output = ()> + Send + 'static,
+    ) -> TaskHandle {
+        let record = self.register(name.into(), true);
+        let handle = TaskHandle {
+            id: record.id,
+            token: record.token.clone(),
+        };
+        let live = Arc::clone(&self.live);
+        tokio::spawn(async move {
+            let token = record.token.clone();
+            let timed = Timed {
+                future: Box::pin(future),
+                record: Arc::clone(&record),
+            };
+            tokio::select! {
+                () = token.cancelled() => {}
+                () = timed => {}
+            }
+            live.lock().remove(&record.id);
+        });
+        handle
+    }
+
+    /// Returns the runtime accounting of all live tasks, by id.
+    #[must_use]
+    pub fn tasks(&self) -> Vec<TaskInfo> {
+        let mut tasks: Vec<_> = self.live.lock().values().map(|task| task.info()).collect();
+        tasks.sort_unstable_by_key(|task| task.id);
+        tasks
+    }
+
+    /// Cancels every task, including those scheduled afterwards, as shutdown
+    /// does.
+    pub fn cancel_all(&self) {
+        self.shutdown.cancel();
+    }
+
+    fn register(&self, name: Arc<str>, is_async: bool) -> Arc<TaskRecord> {
+        let record = Arc::new(TaskRecord {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            name,
+            is_async,
+            token: self.shutdown.child_token(),
+            runs: AtomicU64::new(0),
+            total_nanos: AtomicU64::new(0),
+            max_nanos: AtomicU64::new(0),
+        });
+        self.live.lock().insert(record.id, Arc::clone(&record));
+        record
+    }
+
+    fn schedule(&self, name: Arc<str>, delay: u64, action: TaskAction) -> TaskHandle {
+        let record = self.register(name, false);
+        let handle = TaskHandle {
+            id: record.id,
+            token: record.token.clone(),
+        };
+        let due = self.tick.load(Ordering::Relaxed) + delay.max(1);
+        self.pending.lock().push(SyncTask {
+            due,
+            record,
+            action,
+        });
+        handle
+    }
+
+    /// Advances one tick and removes the tasks that are due, in the order they
+    /// run. Cancelled tasks are dropped.
+    fn take_due(&self) -> (u64, Vec<SyncTask>) {
+        let tick = self.tick.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut pending = self.pending.lock();
+        let (mut due, waiting): (Vec<_>, Vec<_>) = mem::take(&mut *pending)
+            .into_iter()
+            .filter(|task| {
+                let cancelled = task.record.token.is_cancelled();
+                if cancelled {
+                    self.live.lock().remove(&task.record.id);
+                }
+                !cancelled
+            })
+            .partition(|task| task.due <= tick);
+        *pending = waiting;
+        drop(pending);
+        due.sort_unstable_by_key(|task| (task.due, task.record.id));
+        (tick, due)
+    }
+
+    /// Runs the sync tasks that are due.
+    pub(crate) fn tick(&self, server: &Arc<Server>) {
+        let _span = tracing::trace_span!("scheduler").entered();
+        let (tick, due) = self.take_due();
+        let mut repeating = Vec::new();
+        for mut task in due {
+            // Cancelled by a task that ran earlier in this tick.
+            if task.record.token.is_cancelled() {
+                self.live.lock().remove(&task.record.id);
+                continue;
+            }
+            let start = Instant::now();
+            match task.action {
+                TaskAction::Once(action) => {
+                    action(server);
+                    task.record.record(start.elapsed());
+                    self.live.lock().remove(&task.record.id);
+                }
+                TaskAction::Repeating {
+                    period,
+                    ref mut action,
+                } => {
+                    action(server);
+                    task.record.record(start.elapsed());
+                    task.due = tick + period;
+                    repeating.push(task);
+                }
+            }
+        }
+        if !repeating.is_empty() {
+            self.pending.lock().extend(repeating);
+        }
+    }
+}
+
+/// Times every poll of an async task.
+struct Timed<F> {
+    future: Pin<Box<F>>,
+    record: Arc<TaskRecord>,
+}
+
+impl<F: Future<Output = ()>> Future for Timed<F> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let _span = tracing::trace_span!("async_task").entered();
+        let start = Instant::now();
+        let poll = self.future.as_mut().poll(cx);
+        self.record.record(start.elapsed());
+        poll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn due_tasks_run_in_order_and_cancelled_tasks_are_dropped() {
+        let scheduler = Scheduler::new(CancellationToken::new());
+        let later = scheduler.run_later("later", 2, |_| {});
+        let next = scheduler.run_next_tick("next", |_| {});
+        let cancelled = scheduler.run_next_tick("cancelled", |_| {});
+        let repeating = scheduler.run_repeating("repeating", 0, 5, |_| {});
+        cancelled.cancel();
+
+        let (tick, due) = scheduler.take_due();
+        assert_eq!(tick, 1);
+        let ids: Vec<_> = due.iter().map(|task| task.record.id).collect();
+        assert_eq!(ids, vec![next.id(), repeating.id()]);
+
+        let (_, due) = scheduler.take_due();
+        let ids: Vec<_> = due.iter().map(|task| task.record.id).collect();
+        assert_eq!(ids, vec![later.id()]);
+
+        let names: Vec<_> = scheduler
+            .tasks()
+            .into_iter()
+            .map(|task| task.name)
+            .collect();
+        assert_eq!(
+            names,
+            vec![Arc::from("later"), "next".into(), "repeating".into()]
+        );
+
+        scheduler.cancel_all();
+        assert!(later.is_cancelled() && repeating.is_cancelled());
+    }
+}
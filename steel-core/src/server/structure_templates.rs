@@ -0,0 +1,161 @@
+//! Structure templates saved by structure blocks.
+//!
+//! Mirrors vanilla's `StructureTemplateManager`: templates are looked up in
+//! `<save>/generated/<namespace>/structures/<path>.nbt` first and then in the
+//! bundled vanilla templates. Loaded templates are cached by id.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustc_hash::FxHashMap;
+use steel_registry::Registry;
+use steel_utils::{Identifier, locks::SyncMutex};
+
+use crate::server::worlds::WorldMap;
+use crate::worldgen::template::StructureTemplate;
+
+const GENERATED_DIRECTORY: &str = "generated";
+const STRUCTURE_DIRECTORY: &str = "structures";
+const STRUCTURE_FILE_EXTENSION: &str = "nbt";
+
+/// Caches structure templates and persists the ones structure blocks save.
+pub struct StructureTemplateManager {
+    generated_directory: PathBuf,
+    templates: SyncMutex<FxHashMap<Identifier, Arc<StructureTemplate>>>,
+}
+
+impl StructureTemplateManager {
+    /// Creates a manager storing generated templates under `save_path`, without touching disk.
+    #[must_use]
+    pub fn new(save_path: &Path) -> Self {
+        Self {
+            generated_directory: save_path.join(GENERATED_DIRECTORY),
+            templates: SyncMutex::new(FxHashMap::default()),
+        }
+    }
+
+    /// Creates a manager for `save_path` and hands it to every loaded world.
+    pub(crate) fn install(save_path: &Path, worlds: &WorldMap) -> Arc<Self> {
+        let manager = Arc::new(Self::new(save_path));
+        for (_, world) in worlds.iter() {
+            world.set_structure_templates(Arc::clone(&manager));
+        }
+        manager
+    }
+
+    /// Vanilla `StructureTemplateManager.get`: the cached template, else the saved
+    /// one from the `generated` directory, else the bundled vanilla template.
+    pub(crate) fn get(
+        &self,
+        registry: &Registry,
+        id: &Identifier,
+    ) -> Option<Arc<StructureTemplate>> {
+        if let Some(template) = self.templates.lock().get(id) {
+            return Some(Arc::clone(template));
+        }
+
+        let template = match self.load_generated(registry, id) {
+            Some(template) => template,
+            None => StructureTemplate::load_vanilla(registry, id).ok()?,
+        };
+        let template = Arc::new(template);
+        self.templates
+            .lock()
+            .insert(id.clone(), Arc::clone(&template));
+        Some(template)
+    }
+
+    /// Vanilla `StructureTemplateManager.save`: writes `template` to the
+    /// `generated` directory and makes it the cached template for `id`.
+    pub(crate) fn save(
+        &self,
+        registry: &Registry,
+        id: &Identifier,
+        template: StructureTemplate,
+    ) -> Result<(), String> {
+        let path = self.generated_path(id)?;
+        let bytes = template.to_gzip_nbt(registry)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("failed to create directory {}: {err}", parent.display()))?;
+        }
+        fs::write(&path, bytes)
+            .map_err(|err| format!("failed to write structure {}: {err}", path.display()))?;
+
+        self.templates.lock().insert(id.clone(), Arc::new(template));
+        Ok(())
+    }
+
+    /// Caches `template` under `id` without writing it to disk.
+    pub(crate) fn put(&self, id: Identifier, template: StructureTemplate) {
+        self.templates.lock().insert(id, Arc::new(template));
+    }
+
+    /// Vanilla `StructureTemplateManager.remove`: drops the cached template for `id`.
+    pub(crate) fn remove(&self, id: &Identifier) {
+        self.templates.lock().remove(id);
+    }
+
+    fn load_generated(&self, registry: &Registry, id: &Identifier) -> Option<StructureTemplate> {
+        let path = self.generated_path(id).ok()?;
+        if !path.is_file() {
+            return None;
+        }
+        match StructureTemplate::load_file(registry, &path) {
+            Ok(template) => Some(template),
+            Err(error) => {
+                log::error!("Couldn't load structure {id}: {error}");
+                None
+            }
+        }
+    }
+
+    /// Vanilla `createAndValidatePathToGeneratedStructure`: rejects paths that would
+    /// escape the namespace's `structures` directory.
+    fn generated_path(&self, id: &Identifier) -> Result<PathBuf, String> {
+        let mut path = self
+            .generated_directory
+            .join(id.namespace.as_ref())
+            .join(STRUCTURE_DIRECTORY);
+        for segment in id.path.split('/') {
+            if segment.is_empty() || segment == "." || segment == ".." {
+                return Err(format!("invalid structure path {id}"));
+            }
+            path.push(segment);
+        }
+        path.set_extension(STRUCTURE_FILE_EXTENSION);
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_path_nests_namespace_and_path() {
+        let manager = StructureTemplateManager::new(Path::new("saves"));
+        let path = manager
+            .generated_path(&Identifier::new_static("steel", "houses/small"))
+            .expect("valid identifier should map to a path");
+
+        assert_eq!(
+            path,
+            Path::new("saves/generated/steel/structures/houses/small.nbt")
+        );
+    }
+
+    #[test]
+    fn generated_path_rejects_parent_segments() {
+        let manager = StructureTemplateManager::new(Path::new("saves"));
+
+        assert!(
+            manager
+                .generated_path(&Identifier::new_static("steel", "../escape"))
+                .is_err()
+        );
+    }
+}
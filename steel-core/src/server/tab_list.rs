@@ -0,0 +1,324 @@
+//! Tab list header, footer and entry presentation.
+//!
+//! Headers and footers are templates: `{online}`, `{max_players}`, `{tps}` and
+//! `{mspt}` are substituted each time the tab list is refreshed. Legacy `§`
+//! formatting codes in a template are rendered by the client.
+
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+use steel_protocol::packets::game::{
+    CPlayerInfoUpdate, CRemovePlayerInfo, CTabList, PLAYER_INFO_INIT_ACTIONS, PlayerInfoEntry,
+};
+use steel_protocol::packets::login::GameProfileProperty;
+use steel_utils::locks::SyncMutex;
+use text_components::{Modifier, TextComponent, format::Color};
+use uuid::Uuid;
+
+use crate::entity::SharedEntity;
+use crate::player::Player;
+use crate::server::Server;
+
+/// Server state substituted into header and footer templates.
+#[derive(Debug, Clone, Copy)]
+pub struct TabListPlaceholders {
+    /// Players currently online.
+    pub online: usize,
+    /// The player limit.
+    pub max_players: u32,
+    /// Average ticks per second.
+    pub tps: f32,
+    /// Average milliseconds per tick.
+    pub mspt: f32,
+}
+
+impl TabListPlaceholders {
+    /// Replaces the placeholders in `template`.
+    #[must_use]
+    pub fn apply(&self, template: &str) -> String {
+        template
+            .replace("{online}", &self.online.to_string())
+            .replace("{max_players}", &self.max_players.to_string())
+            .replace("{tps}", &format!("{:.1}", self.tps))
+            .replace("{mspt}", &format!("{:.2}", self.mspt))
+    }
+}
+
+/// A tab list header and footer template.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TabListText {
+    /// Text shown above the player list.
+    pub header: String,
+    /// Text shown below the player list.
+    pub footer: String,
+}
+
+impl TabListText {
+    /// Renders the templates into a header/footer packet.
+    #[must_use]
+    pub fn packet(&self, placeholders: &TabListPlaceholders, player: &Player) -> CTabList {
+        CTabList::new(
+            &TextComponent::plain(placeholders.apply(&self.header)),
+            &TextComponent::plain(placeholders.apply(&self.footer)),
+            player,
+        )
+    }
+}
+
+/// How a player appears in everyone's tab list.
+#[derive(Debug, Clone)]
+pub struct TabListEntry {
+    /// Name shown instead of the profile name, if any.
+    pub display_name: Option<TextComponent>,
+    /// Sort position; entries with a higher order are listed first.
+    pub list_order: i32,
+    /// Whether the player is shown at all.
+    pub listed: bool,
+}
+
+impl Default for TabListEntry {
+    fn default() -> Self {
+        Self {
+            display_name: None,
+            list_order: 0,
+            listed: true,
+        }
+    }
+}
+
+/// Tab list headers, footers and per-player entry presentation.
+#[derive(Default)]
+pub struct TabList {
+    global: SyncMutex<Option<TabListText>>,
+    per_player: SyncMutex<FxHashMap<Uuid, TabListText>>,
+    entries: SyncMutex<FxHashMap<Uuid, TabListEntry>>,
+}
+
+impl TabList {
+    /// Sets the header and footer shown to players without their own.
+    ///
+    /// `None` restores Steel's built-in TPS and MSPT display.
+    pub fn set_global(&self, text: Option<TabListText>) {
+        *self.global.lock() = text;
+    }
+
+    /// Sets a header and footer for one player, overriding the global one.
+    pub fn set_player(&self, id: Uuid, text: Option<TabListText>) {
+        let mut per_player = self.per_player.lock();
+        match text {
+            Some(text) => per_player.insert(id, text),
+            None => per_player.remove(&id),
+        };
+    }
+
+    /// Returns the header and footer template shown to player `id`.
+    #[must_use]
+    pub fn text_for(&self, id: Uuid) -> Option<TabListText> {
+        if let Some(text) = self.per_player.lock().get(&id) {
+            return Some(text.clone());
+        }
+        self.global.lock().clone()
+    }
+
+    /// Returns how player `id` appears in the tab list.
+    #[must_use]
+    pub fn entry(&self, id: Uuid) -> TabListEntry {
+        self.entries.lock().get(&id).cloned().unwrap_or_default()
+    }
+
+    fn update_entry(&self, id: Uuid, update: impl FnOnce(&mut TabListEntry)) {
+        update(self.entries.lock().entry(id).or_default());
+    }
+
+    /// Forgets everything stored for player `id`.
+    pub fn remove_player(&self, id: Uuid) {
+        self.per_player.lock().remove(&id);
+        self.entries.lock().remove(&id);
+    }
+}
+
+impl Server {
+    /// Sets the tab list header and footer for every player without their own.
+    pub fn set_tab_list_text(&self, text: Option<TabListText>) {
+        self.tab_list.set_global(text);
+        self.broadcast_tab_list();
+    }
+
+    /// Sets the tab list header and footer for one player.
+    pub fn set_player_tab_list_text(&self, player: &Player, text: Option<TabListText>) {
+        self.tab_list.set_player(player.gameprofile.id, text);
+        let text = self.tab_list.text_for(player.gameprofile.id);
+        player.send_packet(Self::tab_list_packet(
+            text.as_ref(),
+            &self.tab_list_placeholders(),
+            player,
+        ));
+    }
+
+    /// Sets the name shown for `player` in everyone's tab list.
+    pub fn set_tab_list_display_name(&self, player: &Player, display_name: Option<TextComponent>) {
+        let id = player.gameprofile.id;
+        self.tab_list
+            .update_entry(id, |entry| entry.display_name.clone_from(&display_name));
+        self.broadcast_to_online(CPlayerInfoUpdate::update_display_name(id, display_name));
+    }
+
+    /// Sets where `player` is sorted in everyone's tab list.
+    pub fn set_tab_list_order(&self, player: &Player, list_order: i32) {
+        let id = player.gameprofile.id;
+        self.tab_list
+            .update_entry(id, |entry| entry.list_order = list_order);
+        self.broadcast_to_online(CPlayerInfoUpdate::update_list_order(id, list_order));
+    }
+
+    /// Sets whether `player` is shown in everyone's tab list.
+    pub fn set_tab_list_listed(&self, player: &Player, listed: bool) {
+        let id = player.gameprofile.id;
+        self.tab_list
+            .update_entry(id, |entry| entry.listed = listed);
+        self.broadcast_to_online(CPlayerInfoUpdate::update_listed(id, listed));
+    }
+
+    /// Replaces `player`'s profile properties and resends them to every client.
+    ///
+    /// Clients only read skins when a player info entry is added and when the
+    /// player entity spawns, so the entry is re-added and the entity respawned for
+    /// the players tracking it. The player's own model keeps its old skin until
+    /// they respawn.
+    pub fn refresh_player_profile(
+        &self,
+        player: &Arc<Player>,
+        properties: Vec<GameProfileProperty>,
+    ) {
+        let id = player.gameprofile.id;
+        player.set_profile_properties(properties);
+        self.broadcast_to_online(CRemovePlayerInfo::single(id));
+        self.broadcast_to_online(self.player_info_initializing(player));
+        if let Some(session) = player.chat_session()
+            && let Ok(protocol_data) = session.as_data().to_protocol_data()
+        {
+            self.broadcast_to_online(CPlayerInfoUpdate::update_chat_session(id, protocol_data));
+        }
+
+        let entity: SharedEntity = Arc::clone(player) as SharedEntity;
+        player.get_world().resend_entity_to_trackers(&entity);
+    }
+
+    /// Returns the current values substituted into tab list templates.
+    #[must_use]
+    pub fn tab_list_placeholders(&self) -> TabListPlaceholders {
+        let tick_manager = self.tick_rate_manager.read();
+        TabListPlaceholders {
+            online: self.player_count(),
+            max_players: self.config.load().max_players,
+            tps: tick_manager.get_tps(),
+            mspt: tick_manager.get_average_mspt(),
+        }
+    }
+
+    /// Broadcasts each player's tab list header and footer.
+    ///
+    /// Players shown the same template share one encoded packet.
+    pub(crate) fn broadcast_tab_list(&self) {
+        let placeholders = self.tab_list_placeholders();
+        self.broadcast_to_online_variants(
+            |player| self.tab_list.text_for(player.gameprofile.id),
+            |text, player| Self::tab_list_packet(text.as_ref(), &placeholders, player),
+        );
+    }
+
+    /// Renders `text`, or the built-in TPS and MSPT display without one.
+    fn tab_list_packet(
+        text: Option<&TabListText>,
+        placeholders: &TabListPlaceholders,
+        player: &Player,
+    ) -> CTabList {
+        if let Some(text) = text {
+            return text.packet(placeholders, player);
+        }
+
+        // Color TPS based on value
+        let tps_color = if placeholders.tps >= 19.5 {
+            Color::Green
+        } else if placeholders.tps >= 15.0 {
+            Color::Yellow
+        } else {
+            Color::Red
+        };
+
+        // Color MSPT based on value (under 50ms is good)
+        let mspt_color = if placeholders.mspt <= 50.0 {
+            Color::Aqua
+        } else {
+            Color::Red
+        };
+
+        let header = TextComponent::plain("\n").add_children(vec![
+            TextComponent::plain("Steel Dev Build").color(Color::Yellow),
+            TextComponent::plain("\n"),
+        ]);
+        let footer = TextComponent::plain("\n").add_children(vec![
+            TextComponent::plain("TPS: ").color(Color::Gray),
+            TextComponent::plain(format!("{:.1}", placeholders.tps)).color(tps_color),
+            TextComponent::plain(" | ").color(Color::DarkGray),
+            TextComponent::plain("MSPT: ").color(Color::Gray),
+            TextComponent::plain(format!("{:.2}", placeholders.mspt)).color(mspt_color),
+            TextComponent::plain("\n"),
+        ]);
+        CTabList::new(&header, &footer, player)
+    }
+
+    /// Vanilla `ClientboundPlayerInfoUpdatePacket.createPlayerInitializing` with the
+    /// player's tab list presentation applied.
+    pub(crate) fn player_info_initializing(&self, player: &Player) -> CPlayerInfoUpdate {
+        let entry = self.tab_list.entry(player.gameprofile.id);
+        let mut info = PlayerInfoEntry::new(player.gameprofile.id)
+            .with_game_mode(player.game_mode().into())
+            .with_listed(entry.listed)
+            .with_latency(player.latency())
+            .with_display_name(entry.display_name)
+            .with_list_order(entry.list_order)
+            .with_show_hat(true);
+        info.name = Some(player.gameprofile.name.clone());
+        info.properties = player.profile_properties();
+        CPlayerInfoUpdate {
+            actions: PLAYER_INFO_INIT_ACTIONS,
+            entries: vec![info],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn player_text_overrides_global_and_placeholders_apply() {
+        let tab_list = TabList::default();
+        let id = Uuid::from_u128(1);
+        let global = TabListText {
+            header: "§e{online}/{max_players}".to_owned(),
+            footer: "TPS {tps}, MSPT {mspt}".to_owned(),
+        };
+        let own = TabListText {
+            header: "Welcome".to_owned(),
+            footer: String::new(),
+        };
+
+        assert_eq!(tab_list.text_for(id), None);
+        tab_list.set_global(Some(global.clone()));
+        tab_list.set_player(id, Some(own.clone()));
+        assert_eq!(tab_list.text_for(id), Some(own));
+        tab_list.remove_player(id);
+        assert_eq!(tab_list.text_for(id), Some(global.clone()));
+
+        let placeholders = TabListPlaceholders {
+            online: 3,
+            max_players: 20,
+            tps: 19.96,
+            mspt: 4.5,
+        };
+        assert_eq!(placeholders.apply(&global.header), "§e3/20");
+        assert_eq!(placeholders.apply(&global.footer), "TPS 20.0, MSPT 4.50");
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::time::Instant;
 
 /// Number of tick samples to keep for averaging (matches vanilla).
@@ -15,6 +16,49 @@ const MS_PER_SEC: f64 = 1000.0;
 /// Smoothing factor for exponential moving average (matches vanilla's 0.8).
 const TICK_TIME_SMOOTHING: f32 = 0.8;
 
+/// Rolling windows, in seconds, reported by `/tps` and `/mspt`.
+pub const TICK_WINDOW_SECONDS: [u64; 4] = [5, 60, 5 * 60, 15 * 60];
+
+/// Seconds of per-second tick history kept for the longest window.
+const TICK_HISTORY_SECONDS: u64 = TICK_WINDOW_SECONDS[TICK_WINDOW_SECONDS.len() - 1];
+
+/// Consecutive overloaded seconds before an overload is reported.
+const OVERLOAD_REPORT_AFTER_SECONDS: u64 = 10;
+
+/// Minimum seconds between two overload reports.
+const OVERLOAD_REPORT_INTERVAL_SECONDS: u64 = 60;
+
+/// Ticks run and time spent ticking during one wall-clock second.
+#[derive(Debug, Clone, Copy)]
+struct TickSecond {
+    /// Seconds since the first recorded tick.
+    second: u64,
+    ticks: u32,
+    busy_nanos: u64,
+}
+
+/// Average tick performance over a rolling window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TickWindowAverage {
+    /// Length of the window in seconds.
+    pub seconds: u64,
+    /// Ticks completed per second.
+    pub tps: f64,
+    /// Milliseconds spent per tick.
+    pub mspt: f64,
+}
+
+/// Report data returned when the server has been overloaded for a while.
+#[derive(Debug, Clone, Copy)]
+pub struct OverloadReport {
+    /// Consecutive seconds the average tick took longer than its budget.
+    pub seconds: u64,
+    /// Averages over the shortest window.
+    pub recent: TickWindowAverage,
+    /// Averages over the one minute window.
+    pub minute: TickWindowAverage,
+}
+
 /// Report data returned when a sprint finishes.
 #[derive(Debug, Clone)]
 pub struct SprintReport {
@@ -58,6 +102,16 @@ pub struct TickRateManager {
     aggregated_tick_times_nanos: u64,
     /// Exponentially smoothed tick time in milliseconds.
     smoothed_tick_time_ms: f32,
+
+    // Rolling windows
+    /// When the first tick was recorded.
+    history_start: Option<Instant>,
+    /// Per-second tick history, oldest first.
+    tick_history: VecDeque<TickSecond>,
+    /// First second of the current overload, if overloaded.
+    overloaded_since: Option<u64>,
+    /// Second of the last overload report.
+    last_overload_report: Option<u64>,
 }
 
 impl TickRateManager {
@@ -79,6 +133,10 @@ impl TickRateManager {
             tick_times_nanos: [0; TICK_STATS_SPAN],
             aggregated_tick_times_nanos: 0,
             smoothed_tick_time_ms: 0.0,
+            history_start: None,
+            tick_history: VecDeque::new(),
+            overloaded_since: None,
+            last_overload_report: None,
         }
     }
 
@@ -261,7 +319,17 @@ impl TickRateManager {
 
     /// Records the duration of a tick in nanoseconds.
     /// This should be called at the end of each server tick.
-    pub fn record_tick_time(&mut self, tick_time_nanos: u64) {
+    /// Returns a report when the server has been overloaded for a while.
+    pub fn record_tick_time(&mut self, tick_time_nanos: u64) -> Option<OverloadReport> {
+        self.record_tick_time_at(Instant::now(), tick_time_nanos)
+    }
+
+    /// Records the duration of a tick that ended at `now`.
+    fn record_tick_time_at(
+        &mut self,
+        now: Instant,
+        tick_time_nanos: u64,
+    ) -> Option<OverloadReport> {
         let tick_index = (self.tick_count as usize) % TICK_STATS_SPAN;
 
         // Remove old value from aggregated sum, add new value
@@ -273,6 +341,98 @@ impl TickRateManager {
         let tick_time_ms = tick_time_nanos as f32 / NANOS_PER_MS as f32;
         self.smoothed_tick_time_ms = self.smoothed_tick_time_ms * TICK_TIME_SMOOTHING
             + tick_time_ms * (1.0 - TICK_TIME_SMOOTHING);
+
+        let second = now
+            .saturating_duration_since(*self.history_start.get_or_insert(now))
+            .as_secs();
+        if let Some(current) = self
+            .tick_history
+            .back_mut()
+            .filter(|current| current.second == second)
+        {
+            current.ticks += 1;
+            current.busy_nanos += tick_time_nanos;
+            return None;
+        }
+
+        let report = self.check_overload(second);
+        self.tick_history.push_back(TickSecond {
+            second,
+            ticks: 1,
+            busy_nanos: tick_time_nanos,
+        });
+        while self
+            .tick_history
+            .front()
+            .is_some_and(|oldest| oldest.second + TICK_HISTORY_SECONDS < second)
+        {
+            self.tick_history.pop_front();
+        }
+        report
+    }
+
+    /// Tracks overload once the last recorded second is complete.
+    fn check_overload(&mut self, second: u64) -> Option<OverloadReport> {
+        let finished = self.tick_history.back()?;
+        let budget = self.nanoseconds_per_tick * u64::from(finished.ticks);
+        if self.is_sprinting() || finished.busy_nanos <= budget {
+            self.overloaded_since = None;
+            return None;
+        }
+
+        let since = *self.overloaded_since.get_or_insert(finished.second);
+        let seconds = second - since;
+        let reported_recently = self
+            .last_overload_report
+            .is_some_and(|last| second < last + OVERLOAD_REPORT_INTERVAL_SECONDS);
+        if seconds < OVERLOAD_REPORT_AFTER_SECONDS || reported_recently {
+            return None;
+        }
+        self.last_overload_report = Some(second);
+        Some(OverloadReport {
+            seconds,
+            recent: self.window_average_until(second, TICK_WINDOW_SECONDS[0]),
+            minute: self.window_average_until(second, TICK_WINDOW_SECONDS[1]),
+        })
+    }
+
+    /// Returns the averages over each of [`TICK_WINDOW_SECONDS`].
+    #[must_use]
+    pub fn window_averages(&self) -> [TickWindowAverage; TICK_WINDOW_SECONDS.len()] {
+        let now = self
+            .history_start
+            .map_or(0, |start| start.elapsed().as_secs());
+        TICK_WINDOW_SECONDS.map(|seconds| self.window_average_until(now, seconds))
+    }
+
+    /// Averages the complete seconds in the `seconds` before `now`.
+    ///
+    /// Falls back to the last 100 ticks until a second has completed.
+    fn window_average_until(&self, now: u64, seconds: u64) -> TickWindowAverage {
+        let first_second = self
+            .tick_history
+            .front()
+            .map_or(now, |oldest| oldest.second);
+        let covered = seconds.min(now.saturating_sub(first_second));
+        let (ticks, busy_nanos) = self
+            .tick_history
+            .iter()
+            .filter(|bucket| bucket.second < now && bucket.second + covered >= now)
+            .fold((0_u64, 0_u64), |(ticks, busy), bucket| {
+                (ticks + u64::from(bucket.ticks), busy + bucket.busy_nanos)
+            });
+        if covered == 0 || ticks == 0 {
+            return TickWindowAverage {
+                seconds,
+                tps: f64::from(self.get_tps()),
+                mspt: f64::from(self.get_average_mspt()),
+            };
+        }
+        TickWindowAverage {
+            seconds,
+            tps: (ticks as f64 / covered as f64).min(f64::from(self.tick_rate)),
+            mspt: busy_nanos as f64 / ticks as f64 / NANOS_PER_MS,
+        }
     }
 
     /// Returns the average tick time in nanoseconds over the last 100 ticks.
@@ -359,3 +519,88 @@ impl Default for TickRateManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Records `seconds` seconds of 20 ticks that each took `tick_ms`.
+    fn run_seconds(
+        manager: &mut TickRateManager,
+        start: Instant,
+        seconds: Range<u64>,
+        tick_ms: u64,
+    ) -> Vec<OverloadReport> {
+        let mut reports = Vec::new();
+        for second in seconds {
+            for tick in 0..20 {
+                let at = start + Duration::from_millis(second * 1000 + tick * 50);
+                manager.increment_tick_count();
+                reports.extend(manager.record_tick_time_at(at, tick_ms * 1_000_000));
+            }
+        }
+        reports
+    }
+
+    #[test]
+    fn windows_average_complete_seconds_and_report_sustained_overload() {
+        let mut manager = TickRateManager::new();
+        let start = Instant::now();
+        assert!(run_seconds(&mut manager, start, 0..10, 10).is_empty());
+
+        let recent = manager.window_average_until(10, TICK_WINDOW_SECONDS[0]);
+        assert!((recent.tps - 20.0).abs() < 1e-9);
+        assert!((recent.mspt - 10.0).abs() < 1e-9);
+
+        let reports = run_seconds(&mut manager, start, 10..30, 80);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].seconds, OVERLOAD_REPORT_AFTER_SECONDS);
+        assert!((reports[0].recent.mspt - 80.0).abs() < 1e-9);
+        let minute = manager.window_average_until(30, TICK_WINDOW_SECONDS[1]);
+        assert!((minute.mspt - (10.0 * 10.0 + 80.0 * 20.0) / 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stepping_runs_frozen_ticks_then_pauses_again() {
+        let mut manager = TickRateManager::new();
+        assert!(!manager.step_game_if_paused(3));
+
+        manager.set_frozen(true);
+        manager.tick();
+        assert!(!manager.runs_normally());
+
+        assert!(manager.step_game_if_paused(2));
+        assert!(manager.is_stepping_forward());
+        for _ in 0..2 {
+            manager.tick();
+            assert!(manager.runs_normally());
+        }
+        manager.tick();
+        assert!(!manager.runs_normally());
+        assert!(!manager.stop_stepping());
+    }
+
+    #[test]
+    fn sprint_unfreezes_and_restores_the_previous_frozen_state() {
+        let mut manager = TickRateManager::new();
+        manager.set_frozen(true);
+        assert!(!manager.request_game_to_sprint(2));
+        assert!(!manager.is_frozen());
+        assert!(manager.is_sprinting());
+
+        for _ in 0..2 {
+            manager.tick();
+            assert!(manager.check_should_sprint_this_tick().0);
+            manager.end_tick_work();
+        }
+        manager.tick();
+        let (sprinting, report) = manager.check_should_sprint_this_tick();
+        assert!(!sprinting);
+        assert!(report.is_some());
+        assert!(manager.is_frozen());
+        assert!(!manager.is_sprinting());
+    }
+}
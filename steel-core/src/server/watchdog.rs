@@ -0,0 +1,207 @@
+//! Main tick stall detection.
+//!
+//! The game loop marks the start, phases and end of every tick. A dedicated OS
+//! thread checks the running tick once a second, so it keeps working when every
+//! runtime worker is blocked. Once a tick runs past the configured threshold it
+//! logs the tick phase, runtime load, the chunk and entity each world is ticking
+//! and the last packets of every player, and can then shut the server down.
+
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use steel_utils::locks::SyncMutex;
+use tokio::runtime::Handle;
+
+use crate::config::WatchdogConfig;
+use crate::server::Server;
+
+/// How often the watchdog thread checks the running tick.
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+/// How long a shutdown requested after a stall may take before the process exits.
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(60);
+/// `tick_started` while no tick is running.
+const IDLE: u64 = u64::MAX;
+
+/// Progress of the game tick currently running.
+pub struct Watchdog {
+    epoch: Instant,
+    /// Nanoseconds after `epoch` the running tick started, or [`IDLE`].
+    tick_started: AtomicU64,
+    /// Ticks started so far, including frozen ones.
+    ticks: AtomicU64,
+    phase: SyncMutex<&'static str>,
+}
+
+impl Watchdog {
+    /// Creates a watchdog with no tick running.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            epoch: Instant::now(),
+            tick_started: AtomicU64::new(IDLE),
+            ticks: AtomicU64::new(0),
+            phase: SyncMutex::new("idle"),
+        }
+    }
+
+    /// Marks the start of a tick.
+    pub fn begin_tick(&self) {
+        *self.phase.lock() = "start";
+        self.ticks.fetch_add(1, Ordering::Relaxed);
+        let started = self.epoch.elapsed().as_nanos() as u64;
+        self.tick_started.store(started, Ordering::Release);
+    }
+
+    /// Records which part of the tick is running.
+    pub fn enter_phase(&self, phase: &'static str) {
+        *self.phase.lock() = phase;
+    }
+
+    /// Marks the end of the running tick.
+    pub fn end_tick(&self) {
+        self.tick_started.store(IDLE, Ordering::Release);
+        *self.phase.lock() = "idle";
+    }
+
+    /// Returns how long the running tick has taken at `now`, or `None` between ticks.
+    #[must_use]
+    pub fn tick_elapsed(&self, now: Instant) -> Option<Duration> {
+        let started = self.tick_started.load(Ordering::Acquire);
+        if started == IDLE {
+            return None;
+        }
+        Some(
+            now.saturating_duration_since(self.epoch)
+                .saturating_sub(Duration::from_nanos(started)),
+        )
+    }
+
+    /// Returns the number of ticks started so far.
+    #[must_use]
+    pub fn ticks(&self) -> u64 {
+        self.ticks.load(Ordering::Relaxed)
+    }
+
+    /// Returns the part of the tick that is running.
+    #[must_use]
+    pub fn phase(&self) -> &'static str {
+        *self.phase.lock()
+    }
+}
+
+impl Default for Watchdog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Starts the watchdog thread for `server` if it is enabled.
+///
+/// `runtime` is the runtime the game loop runs on; its load is part of the dump.
+pub fn spawn(server: &Arc<Server>, config: &WatchdogConfig, runtime: Handle) {
+    if !config.enable {
+        return;
+    }
+    let server = Arc::downgrade(server);
+    let threshold = Duration::from_secs(config.stall_threshold_seconds);
+    let shutdown_on_stall = config.shutdown_on_stall;
+    let spawned = thread::Builder::new()
+        .name("steel-watchdog".to_owned())
+        .spawn(move || watch(&server, threshold, shutdown_on_stall, &runtime));
+    if let Err(error) = spawned {
+        tracing::error!(%error, "Failed to start the watchdog thread");
+    }
+}
+
+fn watch(server: &Weak<Server>, threshold: Duration, shutdown_on_stall: bool, runtime: &Handle) {
+    let mut reported_tick = None;
+    let mut exit_deadline = None;
+    loop {
+        thread::sleep(CHECK_INTERVAL);
+        if let Some(deadline) = exit_deadline
+            && Instant::now() >= deadline
+        {
+            tracing::error!(grace = ?SHUTDOWN_GRACE, "Server did not stop after a stalled tick, exiting");
+            process::exit(1);
+        }
+        let Some(server) = server.upgrade() else {
+            return;
+        };
+        if exit_deadline.is_none() && server.cancel_token.is_cancelled() {
+            return;
+        }
+
+        let watchdog = &server.watchdog;
+        let Some(elapsed) = watchdog.tick_elapsed(Instant::now()) else {
+            continue;
+        };
+        let tick = watchdog.ticks();
+        if elapsed < threshold || reported_tick == Some(tick) {
+            continue;
+        }
+        reported_tick = Some(tick);
+        log_stall(&server, elapsed, runtime);
+
+        if shutdown_on_stall && exit_deadline.is_none() {
+            tracing::error!("Shutting down after a stalled tick");
+            server.cancel_token.cancel();
+            exit_deadline = Some(Instant::now() + SHUTDOWN_GRACE);
+        }
+    }
+}
+
+fn log_stall(server: &Server, elapsed: Duration, runtime: &Handle) {
+    let metrics = runtime.metrics();
+    tracing::error!(
+        tick = server.watchdog.ticks(),
+        phase = server.watchdog.phase(),
+        ?elapsed,
+        workers = metrics.num_workers(),
+        alive_tasks = metrics.num_alive_tasks(),
+        queued_tasks = metrics.global_queue_depth(),
+        "Game tick stalled"
+    );
+    for (key, world) in server.worlds.iter() {
+        tracing::error!(
+            world = %key,
+            ticking_chunk = ?world.chunk_map.ticking_chunk(),
+            ticking_entity = ?world.entity_manager().ticking_entity(),
+            "Stalled tick world state"
+        );
+    }
+    server.online_players.iter_players(|_, player| {
+        tracing::error!(
+            player = %player.gameprofile.name,
+            recent_packets = format!("{:02x?}", player.connection.recent_packet_ids()),
+            "Stalled tick player packets"
+        );
+        true
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elapsed_is_only_reported_while_a_tick_runs() {
+        let watchdog = Watchdog::new();
+        assert_eq!(watchdog.tick_elapsed(Instant::now()), None);
+
+        watchdog.begin_tick();
+        watchdog.enter_phase("worlds");
+        let later = Instant::now() + Duration::from_secs(5);
+        assert!(
+            watchdog
+                .tick_elapsed(later)
+                .is_some_and(|elapsed| elapsed >= Duration::from_secs(5))
+        );
+        assert_eq!((watchdog.ticks(), watchdog.phase()), (1, "worlds"));
+
+        watchdog.end_tick();
+        assert_eq!(watchdog.tick_elapsed(later), None);
+    }
+}
@@ -0,0 +1,235 @@
+//! Server-side boss bars shown to a set of players.
+
+use std::sync::{Arc, Weak};
+
+use rustc_hash::FxHashMap;
+use steel_protocol::packets::game::{
+    BOSS_BAR_FLAG_CREATE_WORLD_FOG, BOSS_BAR_FLAG_DARKEN_SCREEN, BOSS_BAR_FLAG_PLAY_MUSIC,
+    BossBarColor, BossBarOverlay, BossEventOperation, CBossEvent,
+};
+use text_components::TextComponent;
+use uuid::Uuid;
+
+use crate::entity::Entity as _;
+use crate::player::Player;
+
+/// Vanilla `ServerBossEvent`.
+///
+/// Every change is sent to the players currently watching the bar, and only
+/// while the bar is visible.
+pub struct ServerBossEvent {
+    id: Uuid,
+    name: TextComponent,
+    progress: f32,
+    color: BossBarColor,
+    overlay: BossBarOverlay,
+    darken_screen: bool,
+    play_boss_music: bool,
+    create_world_fog: bool,
+    visible: bool,
+    players: FxHashMap<Uuid, Weak<Player>>,
+}
+
+impl ServerBossEvent {
+    /// Creates a visible, empty bar with full progress.
+    #[must_use]
+    pub fn new(name: TextComponent, color: BossBarColor, overlay: BossBarOverlay) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            progress: 1.0,
+            color,
+            overlay,
+            darken_screen: false,
+            play_boss_music: false,
+            create_world_fog: false,
+            visible: true,
+            players: FxHashMap::default(),
+        }
+    }
+
+    /// Returns the client-side id of this bar.
+    #[must_use]
+    pub const fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Returns the fill of this bar, from `0.0` to `1.0`.
+    #[must_use]
+    pub const fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Returns whether the bar is shown to its players.
+    #[must_use]
+    pub const fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.darken_screen {
+            flags |= BOSS_BAR_FLAG_DARKEN_SCREEN;
+        }
+        if self.play_boss_music {
+            flags |= BOSS_BAR_FLAG_PLAY_MUSIC;
+        }
+        if self.create_world_fog {
+            flags |= BOSS_BAR_FLAG_CREATE_WORLD_FOG;
+        }
+        flags
+    }
+
+    fn add_operation(&self) -> BossEventOperation {
+        BossEventOperation::Add {
+            name: self.name.clone(),
+            progress: self.progress,
+            color: self.color,
+            overlay: self.overlay,
+            flags: self.flags(),
+        }
+    }
+
+    fn broadcast(&self, operation: &BossEventOperation) {
+        if !self.visible {
+            return;
+        }
+        for player in self.players.values().filter_map(Weak::upgrade) {
+            player.send_packet(CBossEvent {
+                id: self.id,
+                operation: operation.clone(),
+            });
+        }
+    }
+
+    /// Sets the fill of this bar.
+    pub fn set_progress(&mut self, progress: f32) {
+        if (progress - self.progress).abs() > f32::EPSILON {
+            self.progress = progress;
+            self.broadcast(&BossEventOperation::UpdateProgress(progress));
+        }
+    }
+
+    /// Sets the title of this bar.
+    pub fn set_name(&mut self, name: TextComponent) {
+        if name != self.name {
+            self.name = name.clone();
+            self.broadcast(&BossEventOperation::UpdateName(name));
+        }
+    }
+
+    /// Sets the color of this bar.
+    pub fn set_color(&mut self, color: BossBarColor) {
+        if color != self.color {
+            self.color = color;
+            self.broadcast(&BossEventOperation::UpdateStyle {
+                color,
+                overlay: self.overlay,
+            });
+        }
+    }
+
+    /// Sets how this bar is divided into segments.
+    pub fn set_overlay(&mut self, overlay: BossBarOverlay) {
+        if overlay != self.overlay {
+            self.overlay = overlay;
+            self.broadcast(&BossEventOperation::UpdateStyle {
+                color: self.color,
+                overlay,
+            });
+        }
+    }
+
+    /// Sets whether the bar darkens the sky.
+    pub fn set_darken_screen(&mut self, darken_screen: bool) {
+        if darken_screen != self.darken_screen {
+            self.darken_screen = darken_screen;
+            self.broadcast(&BossEventOperation::UpdateProperties(self.flags()));
+        }
+    }
+
+    /// Sets whether the bar plays boss music.
+    pub fn set_play_boss_music(&mut self, play_boss_music: bool) {
+        if play_boss_music != self.play_boss_music {
+            self.play_boss_music = play_boss_music;
+            self.broadcast(&BossEventOperation::UpdateProperties(self.flags()));
+        }
+    }
+
+    /// Sets whether the bar creates world fog.
+    pub fn set_create_world_fog(&mut self, create_world_fog: bool) {
+        if create_world_fog != self.create_world_fog {
+            self.create_world_fog = create_world_fog;
+            self.broadcast(&BossEventOperation::UpdateProperties(self.flags()));
+        }
+    }
+
+    /// Shows or hides the bar for every player at once.
+    pub fn set_visible(&mut self, visible: bool) {
+        if visible == self.visible {
+            return;
+        }
+        self.visible = visible;
+        for player in self.players.values().filter_map(Weak::upgrade) {
+            player.send_packet(CBossEvent {
+                id: self.id,
+                operation: if visible {
+                    self.add_operation()
+                } else {
+                    BossEventOperation::Remove
+                },
+            });
+        }
+    }
+
+    /// Returns whether `player` is watching this bar.
+    #[must_use]
+    pub fn has_player(&self, player: &Player) -> bool {
+        self.players.contains_key(&player.uuid())
+    }
+
+    /// Starts showing the bar to `player`.
+    pub fn add_player(&mut self, player: &Arc<Player>) {
+        if self
+            .players
+            .insert(player.uuid(), Arc::downgrade(player))
+            .is_none()
+            && self.visible
+        {
+            player.send_packet(CBossEvent {
+                id: self.id,
+                operation: self.add_operation(),
+            });
+        }
+    }
+
+    /// Stops showing the bar to `player`.
+    pub fn remove_player(&mut self, player: &Player) {
+        if self.players.remove(&player.uuid()).is_some() && self.visible {
+            player.send_packet(CBossEvent {
+                id: self.id,
+                operation: BossEventOperation::Remove,
+            });
+        }
+    }
+
+    /// Stops showing the bar to everyone.
+    pub fn remove_all_players(&mut self) {
+        for (_, player) in self.players.drain() {
+            if self.visible
+                && let Some(player) = player.upgrade()
+            {
+                player.send_packet(CBossEvent {
+                    id: self.id,
+                    operation: BossEventOperation::Remove,
+                });
+            }
+        }
+    }
+
+    /// Returns the players currently watching the bar.
+    #[must_use]
+    pub fn players(&self) -> Vec<Arc<Player>> {
+        self.players.values().filter_map(Weak::upgrade).collect()
+    }
+}
@@ -0,0 +1,148 @@
+//! Local difficulty, scaled by world age and moon phase.
+
+use steel_utils::BlockPos;
+use steel_utils::types::Difficulty;
+
+use super::World;
+
+/// Vanilla `DimensionType.MOON_BRIGHTNESS_PER_PHASE`.
+const MOON_BRIGHTNESS_PER_PHASE: [f32; 8] = [1.0, 0.75, 0.5, 0.25, 0.0, 0.25, 0.5, 0.75];
+
+/// Vanilla `DifficultyInstance`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifficultyInstance {
+    base: Difficulty,
+    effective_difficulty: f32,
+}
+
+impl DifficultyInstance {
+    /// Computes the local difficulty for the given world age, chunk inhabited
+    /// time and moon brightness.
+    #[must_use]
+    pub fn new(
+        base: Difficulty,
+        level_time: i64,
+        chunk_inhabited_time: i64,
+        moon_brightness: f32,
+    ) -> Self {
+        Self {
+            base,
+            effective_difficulty: Self::calculate_difficulty(
+                base,
+                level_time,
+                chunk_inhabited_time,
+                moon_brightness,
+            ),
+        }
+    }
+
+    /// Returns the world difficulty this instance was computed from.
+    #[must_use]
+    pub const fn difficulty(&self) -> Difficulty {
+        self.base
+    }
+
+    /// Returns the local difficulty, from `0.0` to `6.75`.
+    #[must_use]
+    pub const fn effective_difficulty(&self) -> f32 {
+        self.effective_difficulty
+    }
+
+    /// Returns whether the local difficulty is above `threshold`.
+    #[must_use]
+    pub fn is_harder_than(&self, threshold: f32) -> bool {
+        self.effective_difficulty > threshold
+    }
+
+    /// Returns the "clamped regional difficulty", from `0.0` to `1.0`.
+    #[must_use]
+    pub fn special_multiplier(&self) -> f32 {
+        if self.effective_difficulty < 2.0 {
+            0.0
+        } else if self.effective_difficulty > 4.0 {
+            1.0
+        } else {
+            (self.effective_difficulty - 2.0) / 2.0
+        }
+    }
+
+    fn calculate_difficulty(
+        base: Difficulty,
+        level_time: i64,
+        chunk_inhabited_time: i64,
+        moon_brightness: f32,
+    ) -> f32 {
+        if base == Difficulty::Peaceful {
+            return 0.0;
+        }
+        let hard = base == Difficulty::Hard;
+        let mut scale = 0.75;
+        let time_factor = ((level_time as f32 - 72_000.0) / 1_440_000.0).clamp(0.0, 1.0) * 0.25;
+        scale += time_factor;
+
+        let mut local = 0.0;
+        local += (chunk_inhabited_time as f32 / 3_600_000.0).clamp(0.0, 1.0)
+            * if hard { 1.0 } else { 0.75 };
+        local += (moon_brightness * 0.25).clamp(0.0, time_factor);
+        if base == Difficulty::Easy {
+            local *= 0.5;
+        }
+        scale += local;
+        f32::from(u8::from(base)) * scale
+    }
+}
+
+impl World {
+    /// Returns the total ticks of this world's default clock, or `0` for
+    /// worlds without one.
+    pub(crate) fn default_clock_time(&self) -> i64 {
+        self.dimension_type
+            .default_clock
+            .and_then(|clock| self.clock_total_ticks(clock))
+            .unwrap_or(0)
+    }
+
+    /// Returns vanilla `DimensionType.moonBrightness` for the current day.
+    fn moon_brightness(&self) -> f32 {
+        let phase = self.default_clock_time().div_euclid(24_000).rem_euclid(8) as usize;
+        MOON_BRIGHTNESS_PER_PHASE[phase]
+    }
+
+    /// Returns vanilla `Level.getCurrentDifficultyAt`.
+    #[must_use]
+    pub fn current_difficulty_at(&self, _pos: BlockPos) -> DifficultyInstance {
+        // TODO: Use the chunk's inhabited time once chunks track it.
+        let inhabited_time = 0;
+        DifficultyInstance::new(
+            self.difficulty(),
+            self.default_clock_time(),
+            inhabited_time,
+            self.moon_brightness(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peaceful_is_always_zero() {
+        let instance = DifficultyInstance::new(Difficulty::Peaceful, 10_000_000, 10_000_000, 1.0);
+        assert!(instance.effective_difficulty().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn new_worlds_use_the_base_scale() {
+        let instance = DifficultyInstance::new(Difficulty::Normal, 0, 0, 1.0);
+        assert!((instance.effective_difficulty() - 1.5).abs() < f32::EPSILON);
+        assert!(instance.special_multiplier().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn hard_worlds_cap_at_six_and_three_quarters() {
+        let instance = DifficultyInstance::new(Difficulty::Hard, i64::MAX / 2, i64::MAX / 2, 1.0);
+        assert!((instance.effective_difficulty() - 6.75).abs() < f32::EPSILON);
+        assert!((instance.special_multiplier() - 1.0).abs() < f32::EPSILON);
+    }
+}
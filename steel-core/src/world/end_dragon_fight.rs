@@ -0,0 +1,964 @@
+//! The End's dragon fight: the dragon boss bar, the exit portal, gateways and
+//! the crystal respawn ritual.
+
+use std::f64::consts::PI;
+use std::mem;
+use std::sync::Arc;
+
+use glam::DVec3;
+use serde::{Deserialize, Serialize};
+use steel_protocol::packets::game::{BossBarColor, BossBarOverlay};
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::{BlockStateProperties, Direction};
+use steel_registry::feature::EndSpike;
+use steel_registry::{REGISTRY, level_events, vanilla_blocks, vanilla_entities};
+use steel_utils::random::{Random as _, legacy_random::LegacyRandom};
+use steel_utils::types::UpdateFlags;
+use steel_utils::{BlockPos, Downcast as _, WorldAabb, translations};
+use text_components::TextComponent;
+use uuid::Uuid;
+
+use super::World;
+use super::boss_event::ServerBossEvent;
+use super::explosion::ExplosionInteraction;
+use crate::behavior::blocks::FireBlock;
+use crate::chunk::heightmap::HeightmapType;
+use crate::entity::damage::DamageSource;
+use crate::entity::entities::{EndCrystalEntity, EnderDragonEntity, EnderDragonPhase};
+use crate::entity::{Entity, LivingEntity as _, RemovalReason, SharedEntity, next_entity_id};
+use crate::worldgen::feature::FeatureDecorationRunner;
+
+/// Vanilla `EndDragonFight.ARENA_TICKET_LEVEL` area, in chunks around `(0, 0)`.
+const ARENA_CHUNK_RADIUS: i32 = 8;
+/// Ticks between boss bar player scans.
+const TIME_BETWEEN_PLAYER_SCANS: i32 = 20;
+/// Ticks without hearing from the dragon before looking for it again.
+const TIME_BETWEEN_DRAGON_SEARCHES: i32 = 1200;
+/// Ticks between spike crystal counts.
+const TIME_BETWEEN_CRYSTAL_SCANS: i32 = 100;
+/// Players further than this from the arena center do not see the boss bar.
+const ARENA_PLAYER_RANGE: f64 = 192.0;
+/// Number of end gateways the fight can open.
+const GATEWAY_COUNT: i32 = 20;
+/// Distance of the end gateways from the arena center.
+const GATEWAY_DISTANCE: f64 = 96.0;
+/// Height the end gateways are placed at.
+const GATEWAY_HEIGHT: i32 = 75;
+/// Vanilla `EndPodiumFeature.PODIUM_RADIUS`.
+const PODIUM_RADIUS: f64 = 4.0;
+/// Vanilla `EndPodiumFeature.PODIUM_PILLAR_HEIGHT`.
+const PODIUM_PILLAR_HEIGHT: i32 = 4;
+/// Vanilla `EndPodiumFeature.RIM_RADIUS`.
+const RIM_RADIUS: f64 = 1.0;
+/// Where new dragons appear and respawn beams converge.
+const DRAGON_SPAWN_POS: BlockPos = BlockPos::new(0, 128, 0);
+/// Ticks each spike takes to be rebuilt while respawning.
+const PILLAR_DURATION: i32 = 40;
+const END_SPIKE_CLEAR_AIR_MIN_Y: i32 = 65;
+const END_SPIKE_CAGE_RADIUS: i32 = 2;
+const END_SPIKE_CAGE_HEIGHT: i32 = 3;
+
+/// Vanilla `DragonRespawnAnimation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragonRespawnStage {
+    /// Points the placed crystals at the sky.
+    Start,
+    /// Roars while the beams gather.
+    PreparingToSummonPillars,
+    /// Rebuilds the spikes one by one.
+    SummoningPillars,
+    /// Summons the dragon from the gathered beams.
+    SummoningDragon,
+    /// The dragon is back.
+    End,
+}
+
+fn new_dragon_event() -> ServerBossEvent {
+    let mut event = ServerBossEvent::new(
+        TextComponent::translated(translations::ENTITY_MINECRAFT_ENDER_DRAGON.msg()),
+        BossBarColor::Pink,
+        BossBarOverlay::Progress,
+    );
+    event.set_play_boss_music(true);
+    event.set_create_world_fog(true);
+    event
+}
+
+const fn default_needs_state_scanning() -> bool {
+    true
+}
+
+/// Squared distance between two block positions, vanilla `Vec3i.distSqr`.
+fn dist_sqr(a: BlockPos, b: BlockPos) -> f64 {
+    let dx = f64::from(a.x() - b.x());
+    let dy = f64::from(a.y() - b.y());
+    let dz = f64::from(a.z() - b.z());
+    dx * dx + dy * dy + dz * dz
+}
+
+fn block_aabb(pos: BlockPos) -> WorldAabb {
+    let (x, y, z) = (f64::from(pos.x()), f64::from(pos.y()), f64::from(pos.z()));
+    WorldAabb::new(x, y, z, x + 1.0, y + 1.0, z + 1.0)
+}
+
+/// World changes the fight queues while it is locked.
+type DeferredAction = Box<dyn FnOnce(&Arc<World>) + Send>;
+
+/// Vanilla `EndDragonFight`, persisted as the End's `ender_dragon_fight`
+/// saved data.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct EndDragonFight {
+    #[serde(default = "default_needs_state_scanning")]
+    needs_state_scanning: bool,
+    dragon_killed: bool,
+    previously_killed: bool,
+    is_respawning: bool,
+    dragon_uuid: Option<Uuid>,
+    exit_portal_location: Option<[i32; 3]>,
+    gateways: Option<Vec<i32>>,
+    #[serde(skip, default = "new_dragon_event")]
+    dragon_event: ServerBossEvent,
+    #[serde(skip)]
+    respawn_stage: Option<DragonRespawnStage>,
+    #[serde(skip)]
+    respawn_crystals: Option<Vec<Uuid>>,
+    #[serde(skip)]
+    respawn_time: i32,
+    #[serde(skip)]
+    ticks_since_last_player_scan: i32,
+    #[serde(skip)]
+    ticks_since_dragon_seen: i32,
+    #[serde(skip)]
+    ticks_since_crystals_scanned: i32,
+    #[serde(skip)]
+    crystals_alive: i32,
+    #[serde(skip)]
+    ticket_active: bool,
+    /// Explosions of the respawn animation, run once the fight is unlocked
+    /// because the crystals they hurt report back to the fight.
+    #[serde(skip)]
+    deferred: Vec<DeferredAction>,
+}
+
+impl Default for EndDragonFight {
+    fn default() -> Self {
+        Self {
+            needs_state_scanning: true,
+            dragon_killed: false,
+            previously_killed: false,
+            is_respawning: false,
+            dragon_uuid: None,
+            exit_portal_location: None,
+            gateways: None,
+            dragon_event: new_dragon_event(),
+            respawn_stage: None,
+            respawn_crystals: None,
+            respawn_time: 0,
+            ticks_since_last_player_scan: 0,
+            ticks_since_dragon_seen: 0,
+            ticks_since_crystals_scanned: 0,
+            crystals_alive: 0,
+            ticket_active: false,
+            deferred: Vec::new(),
+        }
+    }
+}
+
+impl EndDragonFight {
+    /// Finishes loading saved fight data for a world with `seed`.
+    ///
+    /// Fills in the gateway order vanilla derives from the seed and resumes an
+    /// interrupted respawn from its start.
+    pub(super) fn init(&mut self, seed: i64) {
+        if self.gateways.is_none() {
+            let mut gateways: Vec<i32> = (0..GATEWAY_COUNT).collect();
+            let mut random = LegacyRandom::from_seed(seed as u64);
+            for i in (2..=GATEWAY_COUNT).rev() {
+                let swap_to = random.next_i32_bounded(i) as usize;
+                gateways.swap(i as usize - 1, swap_to);
+            }
+            self.gateways = Some(gateways);
+        }
+        if self.is_respawning {
+            self.respawn_stage = Some(DragonRespawnStage::Start);
+        }
+    }
+
+    /// Returns how many spike crystals are still standing.
+    #[must_use]
+    pub const fn crystals_alive(&self) -> i32 {
+        self.crystals_alive
+    }
+
+    /// Returns whether a dragon of this fight has ever been killed.
+    #[must_use]
+    pub const fn has_previously_killed_dragon(&self) -> bool {
+        self.previously_killed
+    }
+
+    /// Returns the current respawn stage, if a respawn is running.
+    #[must_use]
+    pub const fn respawn_stage(&self) -> Option<DragonRespawnStage> {
+        self.respawn_stage
+    }
+
+    fn exit_portal_location(&self) -> Option<BlockPos> {
+        self.exit_portal_location
+            .map(|[x, y, z]| BlockPos::new(x, y, z))
+    }
+
+    fn set_exit_portal_location(&mut self, pos: BlockPos) {
+        self.exit_portal_location = Some([pos.x(), pos.y(), pos.z()]);
+    }
+
+    /// Ticks the fight, vanilla `EndDragonFight.tick`.
+    pub fn tick(&mut self, world: &Arc<World>) {
+        self.dragon_event.set_visible(!self.dragon_killed);
+        self.ticks_since_last_player_scan += 1;
+        if self.ticks_since_last_player_scan >= TIME_BETWEEN_PLAYER_SCANS {
+            self.update_players(world);
+            self.ticks_since_last_player_scan = 0;
+        }
+
+        if self.dragon_event.players().is_empty() {
+            if self.ticket_active {
+                world.chunk_map.remove_dragon_ticket();
+                self.ticket_active = false;
+            }
+            return;
+        }
+
+        if !self.ticket_active {
+            world.chunk_map.add_dragon_ticket();
+            self.ticket_active = true;
+        }
+        let arena_loaded = Self::is_arena_loaded(world);
+        if self.needs_state_scanning && arena_loaded {
+            self.scan_state(world);
+        }
+
+        if self.respawn_stage.is_some() {
+            if self.respawn_crystals.is_none() && arena_loaded {
+                log::warn!("Haven't seen our respawn crystals yet, so we'll try to restart");
+                self.respawn_stage = None;
+                self.is_respawning = false;
+                self.try_respawn(world);
+            }
+            if let Some(stage) = self.respawn_stage {
+                let time = self.respawn_time;
+                self.respawn_time += 1;
+                self.tick_respawn(world, stage, time);
+            }
+        }
+
+        if !self.dragon_killed {
+            self.ticks_since_dragon_seen += 1;
+            if (self.dragon_uuid.is_none()
+                || self.ticks_since_dragon_seen >= TIME_BETWEEN_DRAGON_SEARCHES)
+                && arena_loaded
+            {
+                self.find_or_create_dragon(world);
+                self.ticks_since_dragon_seen = 0;
+            }
+            self.ticks_since_crystals_scanned += 1;
+            if self.ticks_since_crystals_scanned >= TIME_BETWEEN_CRYSTAL_SCANS && arena_loaded {
+                self.update_crystal_count(world);
+                self.ticks_since_crystals_scanned = 0;
+            }
+        }
+    }
+
+    fn is_arena_loaded(world: &World) -> bool {
+        (-ARENA_CHUNK_RADIUS..=ARENA_CHUNK_RADIUS).all(|x| {
+            (-ARENA_CHUNK_RADIUS..=ARENA_CHUNK_RADIUS)
+                .all(|z| world.is_entity_ticking_chunk_loaded(BlockPos::new(x * 16, 0, z * 16)))
+        })
+    }
+
+    /// Shows the boss bar to living players near the arena.
+    fn update_players(&mut self, world: &World) {
+        let center = DVec3::new(0.0, 128.0, 0.0);
+        let mut inside = Vec::new();
+        world.players.iter_players(|_, player| {
+            if player.is_alive()
+                && player.position().distance_squared(center) < ARENA_PLAYER_RANGE.powi(2)
+            {
+                inside.push(Arc::clone(player));
+            }
+            true
+        });
+        for player in self.dragon_event.players() {
+            if !inside.iter().any(|other| Arc::ptr_eq(other, &player)) {
+                self.dragon_event.remove_player(&player);
+            }
+        }
+        for player in &inside {
+            self.dragon_event.add_player(player);
+        }
+    }
+
+    /// Works out the fight state from the world, for fights without saved
+    /// data.
+    fn scan_state(&mut self, world: &Arc<World>) {
+        log::info!("Scanning for legacy world dragon fight...");
+        self.needs_state_scanning = false;
+        let active_portal = self.has_active_exit_portal(world);
+        if active_portal {
+            log::info!("Found that the dragon has been killed in this world already.");
+            self.previously_killed = true;
+        } else {
+            log::info!("Found that the dragon has not yet been killed in this world.");
+            self.previously_killed = false;
+            if self.find_exit_portal(world).is_none() {
+                self.spawn_exit_portal(world, false);
+            }
+        }
+
+        if let Some(dragon) = Self::dragons(world).into_iter().next() {
+            log::info!("Found that there's a dragon still alive");
+            self.dragon_uuid = Some(dragon.uuid());
+            self.dragon_killed = false;
+            if !active_portal {
+                log::info!("But we didn't have a portal, let's remove it.");
+                dragon.set_removed(RemovalReason::Discarded);
+                self.dragon_uuid = None;
+            }
+        } else {
+            self.dragon_killed = true;
+        }
+
+        if !self.previously_killed && self.dragon_killed {
+            self.dragon_killed = false;
+        }
+    }
+
+    fn dragons(world: &World) -> Vec<SharedEntity> {
+        world
+            .entity_manager
+            .get_accessible_entities()
+            .into_iter()
+            .filter(|entity| entity.is::<EnderDragonEntity>() && entity.is_alive())
+            .collect()
+    }
+
+    fn find_or_create_dragon(&mut self, world: &Arc<World>) {
+        if let Some(dragon) = Self::dragons(world).into_iter().next() {
+            log::debug!("Haven't seen our dragon, but found another one to use.");
+            self.dragon_uuid = Some(dragon.uuid());
+        } else {
+            log::debug!("Haven't seen the dragon, respawning it");
+            self.create_new_dragon(world);
+        }
+    }
+
+    fn create_new_dragon(&mut self, world: &Arc<World>) -> Option<Arc<EnderDragonEntity>> {
+        let position = DVec3::new(
+            f64::from(DRAGON_SPAWN_POS.x()),
+            f64::from(DRAGON_SPAWN_POS.y()),
+            f64::from(DRAGON_SPAWN_POS.z()),
+        );
+        let dragon = Arc::new(EnderDragonEntity::new(
+            &vanilla_entities::ENDER_DRAGON,
+            0,
+            position,
+            Arc::downgrade(world),
+        ));
+        dragon.set_phase(EnderDragonPhase::HoldingPattern);
+        dragon.set_rotation((rand::random::<f32>() * 360.0, 0.0));
+        world
+            .try_add_entity(Arc::clone(&dragon) as SharedEntity)
+            .ok()?;
+        self.dragon_uuid = Some(dragon.uuid());
+        Some(dragon)
+    }
+
+    /// Mirrors the dragon's health and name onto the boss bar.
+    pub fn update_dragon(&mut self, dragon: &EnderDragonEntity) {
+        if self.dragon_uuid != Some(dragon.uuid()) {
+            return;
+        }
+        self.dragon_event
+            .set_progress(dragon.get_health() / dragon.get_max_health());
+        self.ticks_since_dragon_seen = 0;
+        if dragon.custom_name().is_some() {
+            self.dragon_event.set_name(dragon.display_name());
+        }
+    }
+
+    /// Opens the exit portal and a new gateway once the fight's dragon died.
+    pub fn set_dragon_killed(&mut self, world: &Arc<World>, dragon: &EnderDragonEntity) {
+        if self.dragon_uuid != Some(dragon.uuid()) {
+            return;
+        }
+        self.dragon_event.set_progress(0.0);
+        self.dragon_event.set_visible(false);
+        self.spawn_exit_portal(world, true);
+        self.spawn_new_gateway(world);
+        if !self.previously_killed {
+            let y = world
+                .height_at(HeightmapType::MotionBlocking, 0, 0)
+                .unwrap_or(world.get_min_y());
+            world.set_block(
+                BlockPos::new(0, y, 0),
+                REGISTRY
+                    .blocks
+                    .get_default_state_id(&vanilla_blocks::DRAGON_EGG),
+                UpdateFlags::UPDATE_ALL,
+            );
+        }
+        self.previously_killed = true;
+        self.dragon_killed = true;
+    }
+
+    fn spawn_new_gateway(&mut self, world: &Arc<World>) {
+        let Some(index) = self.gateways.as_mut().and_then(Vec::pop) else {
+            return;
+        };
+        let angle = 2.0 * (-PI + PI / f64::from(GATEWAY_COUNT) * f64::from(index));
+        let pos = BlockPos::new(
+            (GATEWAY_DISTANCE * angle.cos()).floor() as i32,
+            GATEWAY_HEIGHT,
+            (GATEWAY_DISTANCE * angle.sin()).floor() as i32,
+        );
+        world.level_event(level_events::ANIMATION_END_GATEWAY_SPAWN, pos, 0, None);
+        world.create_end_gateway_portal(pos, None, false);
+    }
+
+    /// Returns whether the podium at `pos` is intact, the shape vanilla's
+    /// `exitPortalPattern` matches.
+    fn is_exit_portal_at(world: &World, pos: BlockPos) -> bool {
+        let is_bedrock =
+            |pos: BlockPos| world.get_block_state(pos).get_block() == &vanilla_blocks::BEDROCK;
+        (0..PODIUM_PILLAR_HEIGHT).all(|dy| is_bedrock(pos.above_n(dy)))
+            && Direction::HORIZONTAL
+                .into_iter()
+                .all(|direction| is_bedrock(pos.relative_n(direction, 3)))
+    }
+
+    fn find_exit_portal(&mut self, world: &World) -> Option<BlockPos> {
+        if let Some(pos) = self.exit_portal_location()
+            && Self::is_exit_portal_at(world, pos)
+        {
+            return Some(pos);
+        }
+        let top = world.height_at(HeightmapType::MotionBlocking, 0, 0)?;
+        let pos = (world.get_min_y()..=top)
+            .rev()
+            .map(|y| BlockPos::new(0, y, 0))
+            .find(|pos| Self::is_exit_portal_at(world, *pos))?;
+        self.set_exit_portal_location(pos);
+        Some(pos)
+    }
+
+    fn has_active_exit_portal(&mut self, world: &World) -> bool {
+        self.find_exit_portal(world).is_some_and(|pos| {
+            world.get_block_state(pos.offset(1, 0, 0)).get_block() == &vanilla_blocks::END_PORTAL
+        })
+    }
+
+    /// Builds the exit podium, vanilla `EndDragonFight.spawnExitPortal`.
+    fn spawn_exit_portal(&mut self, world: &Arc<World>, active: bool) {
+        let origin = if let Some(pos) = self.exit_portal_location() {
+            pos
+        } else {
+            let y = world
+                .height_at(HeightmapType::MotionBlockingNoLeaves, 0, 0)
+                .unwrap_or(world.get_min_y());
+            let mut pos = BlockPos::new(0, y - 1, 0);
+            while world.get_block_state(pos).get_block() == &vanilla_blocks::BEDROCK
+                && pos.y() > world.sea_level
+            {
+                pos = pos.below();
+            }
+            self.set_exit_portal_location(pos);
+            pos
+        };
+        Self::place_end_podium(world, origin, active);
+    }
+
+    /// Vanilla `EndPodiumFeature.place`.
+    fn place_end_podium(world: &Arc<World>, origin: BlockPos, active: bool) {
+        let radius = PODIUM_RADIUS as i32;
+        let inner = (PODIUM_RADIUS - RIM_RADIUS - 0.5).powi(2);
+        let outer = (PODIUM_RADIUS - 0.5).powi(2);
+        for x in -radius..=radius {
+            for y in -1..=32 {
+                for z in -radius..=radius {
+                    let pos = origin.offset(x, y, z);
+                    let distance = dist_sqr(pos, origin);
+                    let inside_inner = distance < inner;
+                    if !inside_inner && distance >= outer {
+                        continue;
+                    }
+                    let block = if y < 0 {
+                        if inside_inner {
+                            &vanilla_blocks::BEDROCK
+                        } else {
+                            &vanilla_blocks::END_STONE
+                        }
+                    } else if y > 0 {
+                        &vanilla_blocks::AIR
+                    } else if !inside_inner {
+                        &vanilla_blocks::BEDROCK
+                    } else if active {
+                        &vanilla_blocks::END_PORTAL
+                    } else {
+                        &vanilla_blocks::AIR
+                    };
+                    world.set_block(pos, block.default_state(), UpdateFlags::UPDATE_ALL);
+                }
+            }
+        }
+
+        for dy in 0..PODIUM_PILLAR_HEIGHT {
+            world.set_block(
+                origin.above_n(dy),
+                vanilla_blocks::BEDROCK.default_state(),
+                UpdateFlags::UPDATE_ALL,
+            );
+        }
+        let torch_pos = origin.above_n(2);
+        for direction in Direction::HORIZONTAL {
+            world.set_block(
+                torch_pos.relative(direction),
+                vanilla_blocks::WALL_TORCH
+                    .default_state()
+                    .set_value(&BlockStateProperties::HORIZONTAL_FACING, direction),
+                UpdateFlags::UPDATE_ALL,
+            );
+        }
+    }
+
+    /// Starts a respawn if all four crystals stand around the exit portal,
+    /// vanilla `EndDragonFight.tryRespawn`.
+    pub fn try_respawn(&mut self, world: &Arc<World>) {
+        if !self.dragon_killed || self.respawn_stage.is_some() {
+            return;
+        }
+        let portal = if let Some(pos) = self.find_exit_portal(world) {
+            pos
+        } else {
+            log::debug!("Tried to respawn, but need to find the portal first.");
+            self.spawn_exit_portal(world, true);
+            let Some(pos) = self.exit_portal_location() else {
+                return;
+            };
+            pos
+        };
+
+        let center = portal.above();
+        let mut crystals = Vec::new();
+        for direction in Direction::HORIZONTAL {
+            let found = world.get_entities_in_aabb_matching(
+                &block_aabb(center.relative_n(direction, 2)),
+                |entity| entity.is::<EndCrystalEntity>(),
+            );
+            if found.is_empty() {
+                return;
+            }
+            crystals.extend(found);
+        }
+        log::debug!("Found all crystals, respawning dragon.");
+        self.respawn_dragon(world, &crystals);
+    }
+
+    fn respawn_dragon(&mut self, world: &Arc<World>, crystals: &[SharedEntity]) {
+        if !self.dragon_killed || self.respawn_stage.is_some() {
+            return;
+        }
+        if let Some(portal) = self.find_exit_portal(world) {
+            let end_stone = REGISTRY
+                .blocks
+                .get_default_state_id(&vanilla_blocks::END_STONE);
+            for x in -3..=3 {
+                for y in -1..PODIUM_PILLAR_HEIGHT {
+                    for z in -3..=3 {
+                        let pos = portal.offset(x, y, z);
+                        let block = world.get_block_state(pos).get_block();
+                        if block == &vanilla_blocks::BEDROCK || block == &vanilla_blocks::END_PORTAL
+                        {
+                            world.set_block(pos, end_stone, UpdateFlags::UPDATE_ALL);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.respawn_stage = Some(DragonRespawnStage::Start);
+        self.is_respawning = true;
+        self.respawn_time = 0;
+        self.spawn_exit_portal(world, false);
+        self.respawn_crystals = Some(crystals.iter().map(|crystal| crystal.uuid()).collect());
+    }
+
+    fn set_respawn_stage(&mut self, world: &Arc<World>, stage: DragonRespawnStage) {
+        if self.respawn_stage.is_none() {
+            return;
+        }
+        self.respawn_time = 0;
+        if stage == DragonRespawnStage::End {
+            self.respawn_stage = None;
+            self.is_respawning = false;
+            self.dragon_killed = false;
+            // TODO: Trigger `SUMMONED_ENTITY` for the boss bar's players once
+            // that criteria trigger exists.
+            self.create_new_dragon(world);
+        } else {
+            self.respawn_stage = Some(stage);
+        }
+    }
+
+    fn respawn_crystals(&self, world: &World) -> Vec<SharedEntity> {
+        self.respawn_crystals
+            .iter()
+            .flatten()
+            .filter_map(|uuid| world.get_entity_by_uuid(uuid))
+            .filter(|entity| entity.is::<EndCrystalEntity>())
+            .collect()
+    }
+
+    /// Runs one tick of the respawn animation, vanilla
+    /// `DragonRespawnAnimation.tick`.
+    fn tick_respawn(&mut self, world: &Arc<World>, stage: DragonRespawnStage, time: i32) {
+        let entities = self.respawn_crystals(world);
+        let crystals: Vec<_> = entities
+            .iter()
+            .filter_map(|entity| entity.downcast_ref::<EndCrystalEntity>())
+            .collect();
+        match stage {
+            DragonRespawnStage::Start => {
+                for crystal in &crystals {
+                    crystal.set_beam_target(Some(DRAGON_SPAWN_POS));
+                }
+                self.set_respawn_stage(world, DragonRespawnStage::PreparingToSummonPillars);
+            }
+            DragonRespawnStage::PreparingToSummonPillars => {
+                if time < 100 {
+                    if matches!(time, 0 | 50 | 51 | 52) || time >= 95 {
+                        world.level_event(
+                            level_events::ANIMATION_DRAGON_SUMMON_ROAR,
+                            DRAGON_SPAWN_POS,
+                            0,
+                            None,
+                        );
+                    }
+                } else {
+                    self.set_respawn_stage(world, DragonRespawnStage::SummoningPillars);
+                }
+            }
+            DragonRespawnStage::SummoningPillars => {
+                let is_start = time % PILLAR_DURATION == 0;
+                let is_end = time % PILLAR_DURATION == PILLAR_DURATION - 1;
+                if !is_start && !is_end {
+                    return;
+                }
+                let spikes = FeatureDecorationRunner::end_spikes_for_level(world.seed());
+                let Some(spike) = spikes.get((time / PILLAR_DURATION) as usize) else {
+                    if is_start {
+                        self.set_respawn_stage(world, DragonRespawnStage::SummoningDragon);
+                    }
+                    return;
+                };
+                if is_start {
+                    for crystal in &crystals {
+                        crystal.set_beam_target(Some(BlockPos::new(
+                            spike.center_x,
+                            spike.height + 1,
+                            spike.center_z,
+                        )));
+                    }
+                } else {
+                    let spike = spike.clone();
+                    self.deferred.push(Box::new(move |world| {
+                        for x in -10..=10 {
+                            for y in -10..=10 {
+                                for z in -10..=10 {
+                                    world.remove_block(BlockPos::new(
+                                        spike.center_x + x,
+                                        spike.height + y,
+                                        spike.center_z + z,
+                                    ));
+                                }
+                            }
+                        }
+                        world.explode(
+                            None,
+                            None,
+                            DVec3::new(
+                                f64::from(spike.center_x) + 0.5,
+                                f64::from(spike.height),
+                                f64::from(spike.center_z) + 0.5,
+                            ),
+                            5.0,
+                            false,
+                            ExplosionInteraction::for_block(world),
+                        );
+                        Self::place_spike(world, &spike);
+                    }));
+                }
+            }
+            DragonRespawnStage::SummoningDragon => {
+                if time >= 100 {
+                    self.set_respawn_stage(world, DragonRespawnStage::End);
+                    self.reset_spike_crystals(world);
+                    for crystal in &crystals {
+                        crystal.set_beam_target(None);
+                    }
+                    self.deferred.push(Box::new(move |world| {
+                        for crystal in entities {
+                            world.explode(
+                                Some(crystal.as_ref()),
+                                None,
+                                crystal.position(),
+                                6.0,
+                                false,
+                                ExplosionInteraction::Keep,
+                            );
+                            crystal.set_removed(RemovalReason::Discarded);
+                        }
+                    }));
+                } else if time >= 80 || (time > 0 && time < 5) {
+                    world.level_event(
+                        level_events::ANIMATION_DRAGON_SUMMON_ROAR,
+                        DRAGON_SPAWN_POS,
+                        0,
+                        None,
+                    );
+                } else if time == 0 {
+                    for crystal in &crystals {
+                        crystal.set_beam_target(Some(DRAGON_SPAWN_POS));
+                    }
+                }
+            }
+            DragonRespawnStage::End => {}
+        }
+    }
+
+    /// Rebuilds one spike with an invulnerable crystal beaming at the dragon
+    /// spawn, like vanilla placing `END_SPIKE` during a respawn.
+    fn place_spike(world: &Arc<World>, spike: &EndSpike) {
+        let obsidian = REGISTRY
+            .blocks
+            .get_default_state_id(&vanilla_blocks::OBSIDIAN);
+        let air = REGISTRY.blocks.get_default_state_id(&vanilla_blocks::AIR);
+        let radius_squared_plus_one = spike.radius * spike.radius + 1;
+        for dx in -spike.radius..=spike.radius {
+            for dz in -spike.radius..=spike.radius {
+                let inside = dx * dx + dz * dz <= radius_squared_plus_one;
+                for y in world.get_min_y()..=spike.height + 10 {
+                    let pos = BlockPos::new(spike.center_x + dx, y, spike.center_z + dz);
+                    if inside && y < spike.height {
+                        world.set_block(pos, obsidian, UpdateFlags::UPDATE_ALL);
+                    } else if y > END_SPIKE_CLEAR_AIR_MIN_Y {
+                        world.set_block(pos, air, UpdateFlags::UPDATE_ALL);
+                    }
+                }
+            }
+        }
+
+        if spike.guarded {
+            for dx in -END_SPIKE_CAGE_RADIUS..=END_SPIKE_CAGE_RADIUS {
+                for dz in -END_SPIKE_CAGE_RADIUS..=END_SPIKE_CAGE_RADIUS {
+                    for dy in 0..=END_SPIKE_CAGE_HEIGHT {
+                        let x_limit = dx.abs() == END_SPIKE_CAGE_RADIUS;
+                        let z_limit = dz.abs() == END_SPIKE_CAGE_RADIUS;
+                        let top = dy == END_SPIKE_CAGE_HEIGHT;
+                        if !x_limit && !z_limit && !top {
+                            continue;
+                        }
+                        let state = FeatureDecorationRunner::end_spike_iron_bars_state(
+                            x_limit || top,
+                            z_limit || top,
+                            dx,
+                            dz,
+                        );
+                        let pos = BlockPos::new(
+                            spike.center_x + dx,
+                            spike.height + dy,
+                            spike.center_z + dz,
+                        );
+                        world.set_block(pos, state, UpdateFlags::UPDATE_ALL);
+                    }
+                }
+            }
+        }
+
+        let position = DVec3::new(
+            f64::from(spike.center_x) + 0.5,
+            f64::from(spike.height + 1),
+            f64::from(spike.center_z) + 0.5,
+        );
+        let crystal = Arc::new(EndCrystalEntity::new(
+            &vanilla_entities::END_CRYSTAL,
+            next_entity_id(),
+            position,
+            Arc::downgrade(world),
+        ));
+        crystal.set_beam_target(Some(DRAGON_SPAWN_POS));
+        crystal.set_invulnerable(true);
+        crystal.snap_to(position, rand::random::<f32>() * 360.0, 0.0);
+        let _ = world.try_add_entity(crystal);
+
+        let crystal_pos = BlockPos::new(spike.center_x, spike.height + 1, spike.center_z);
+        world.set_block(
+            crystal_pos.below(),
+            REGISTRY
+                .blocks
+                .get_default_state_id(&vanilla_blocks::BEDROCK),
+            UpdateFlags::UPDATE_ALL,
+        );
+        world.set_block(
+            crystal_pos,
+            FireBlock::get_state(world.as_ref(), crystal_pos),
+            UpdateFlags::UPDATE_ALL,
+        );
+    }
+
+    /// Vanilla `EndSpike.getTopBoundingBox`.
+    fn spike_column(world: &World, spike: &EndSpike) -> WorldAabb {
+        let min_y = f64::from(world.get_min_y());
+        WorldAabb::new(
+            f64::from(spike.center_x - spike.radius),
+            min_y,
+            f64::from(spike.center_z - spike.radius),
+            f64::from(spike.center_x + spike.radius),
+            min_y + f64::from(world.dimension_type.height),
+            f64::from(spike.center_z + spike.radius),
+        )
+    }
+
+    fn spike_crystals(world: &World) -> Vec<SharedEntity> {
+        FeatureDecorationRunner::end_spikes_for_level(world.seed())
+            .iter()
+            .flat_map(|spike| {
+                world.get_entities_in_aabb_matching(&Self::spike_column(world, spike), |entity| {
+                    entity.is::<EndCrystalEntity>()
+                })
+            })
+            .collect()
+    }
+
+    fn update_crystal_count(&mut self, world: &World) {
+        self.ticks_since_crystals_scanned = 0;
+        self.crystals_alive = Self::spike_crystals(world).len() as i32;
+        log::debug!("Found {} end crystals still alive", self.crystals_alive);
+    }
+
+    /// Makes the spike crystals breakable again after a respawn.
+    fn reset_spike_crystals(&self, world: &World) {
+        for entity in Self::spike_crystals(world) {
+            let Some(crystal) = entity.downcast_ref::<EndCrystalEntity>() else {
+                continue;
+            };
+            crystal.set_invulnerable(false);
+            crystal.set_beam_target(None);
+        }
+    }
+
+    /// Handles a destroyed crystal, returning the dragon to notify if the
+    /// crystal was not part of a respawn.
+    fn on_crystal_destroyed(&mut self, world: &Arc<World>, crystal: Uuid) -> Option<Uuid> {
+        if self.respawn_stage.is_some()
+            && self
+                .respawn_crystals
+                .as_ref()
+                .is_some_and(|crystals| crystals.contains(&crystal))
+        {
+            log::debug!("Aborting respawn sequence");
+            self.respawn_stage = None;
+            self.is_respawning = false;
+            self.respawn_time = 0;
+            self.reset_spike_crystals(world);
+            self.spawn_exit_portal(world, true);
+            return None;
+        }
+        self.update_crystal_count(world);
+        self.dragon_uuid
+    }
+}
+
+impl World {
+    /// Ticks the End's dragon fight, if this world has one.
+    pub(super) fn tick_dragon_fight(self: &Arc<Self>) {
+        if let Some(fight) = &self.dragon_fight {
+            let deferred = {
+                let mut fight = fight.lock();
+                fight.tick(self);
+                mem::take(&mut fight.deferred)
+            };
+            for action in deferred {
+                action(self);
+            }
+        }
+    }
+
+    /// Tells the dragon fight an end crystal was destroyed, vanilla
+    /// `EndDragonFight.onCrystalDestroyed`.
+    pub(crate) fn on_end_crystal_destroyed(
+        self: &Arc<Self>,
+        crystal: &EndCrystalEntity,
+        source: &DamageSource,
+    ) {
+        let Some(fight) = &self.dragon_fight else {
+            return;
+        };
+        let dragon = fight.lock().on_crystal_destroyed(self, crystal.uuid());
+        if let Some(dragon) = dragon.and_then(|uuid| self.get_entity_by_uuid(&uuid))
+            && let Some(dragon) = dragon.downcast_ref::<EnderDragonEntity>()
+        {
+            dragon.on_crystal_destroyed(self, crystal.id(), crystal.block_position(), source);
+        }
+    }
+
+    /// Starts a dragon respawn if the crystals are in place.
+    pub(crate) fn try_respawn_dragon(self: &Arc<Self>) {
+        if let Some(fight) = &self.dragon_fight {
+            fight.lock().try_respawn(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gateways_are_a_seeded_permutation() {
+        let mut first = EndDragonFight::default();
+        first.init(42);
+        let mut second = EndDragonFight::default();
+        second.init(42);
+
+        let gateways = first.gateways.clone().expect("gateways filled in");
+        assert_eq!(Some(gateways.clone()), second.gateways);
+        let mut sorted = gateways;
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..GATEWAY_COUNT).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn saved_fight_round_trips_through_toml() {
+        let mut fight = EndDragonFight::default();
+        fight.init(7);
+        fight.dragon_killed = true;
+        fight.previously_killed = true;
+        fight.set_exit_portal_location(BlockPos::new(0, 63, 0));
+
+        let content = toml::to_string_pretty(&fight).expect("fight serializes");
+        let loaded: EndDragonFight = toml::from_str(&content).expect("fight deserializes");
+
+        assert!(loaded.dragon_killed);
+        assert!(loaded.previously_killed);
+        assert_eq!(loaded.exit_portal_location(), Some(BlockPos::new(0, 63, 0)));
+        assert_eq!(loaded.gateways, fight.gateways);
+    }
+
+    #[test]
+    fn missing_data_scans_the_world_first() {
+        let fight: EndDragonFight = toml::from_str("").expect("empty data deserializes");
+
+        assert!(fight.needs_state_scanning);
+        assert_eq!(fight.respawn_stage(), None);
+    }
+}
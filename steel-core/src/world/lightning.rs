@@ -0,0 +1,139 @@
+//! Thunderstorm lightning strikes in ticking chunks.
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::vanilla_game_rules::SPAWN_MOBS;
+use steel_registry::{RegistryEntry as _, vanilla_entities, vanilla_poi_types};
+use steel_utils::{BlockPos, ChunkPos, WorldAabb};
+
+use super::World;
+use crate::chunk::heightmap::HeightmapType;
+use crate::entity::entities::LightningBoltEntity;
+use crate::entity::{ENTITIES, next_entity_id};
+use crate::poi::OccupationStatus;
+
+/// One in this many thundering chunk ticks strikes lightning.
+const THUNDER_CHANCE: i32 = 100_000;
+
+/// Radius searched for a lightning rod to redirect a strike to.
+const LIGHTNING_ROD_SEARCH_RADIUS: i32 = 128;
+
+/// Horizontal reach around a strike column in which living entities draw the bolt.
+const LIGHTNING_ENTITY_SEARCH_INFLATE: f64 = 3.0;
+
+impl World {
+    /// Vanilla `ServerLevel.tickThunder`: occasionally strikes lightning in `chunk`.
+    ///
+    /// The caller checks that the world is raining and thundering, so this only
+    /// rolls the per-chunk chance.
+    pub(crate) fn tick_thunder(self: &Arc<Self>, chunk: ChunkPos) {
+        if rand::random_range(0..THUNDER_CHANCE) != 0 {
+            return;
+        }
+
+        let random_pos = BlockPos::new(
+            chunk.0.x * 16 + rand::random_range(0..16),
+            0,
+            chunk.0.y * 16 + rand::random_range(0..16),
+        );
+        let pos = self.find_lightning_target_around(random_pos);
+        if !self.is_raining_at(pos) {
+            return;
+        }
+
+        let difficulty = self.current_difficulty_at(pos);
+        let is_trap = self.get_game_rule(&SPAWN_MOBS)
+            && rand::random::<f64>() < f64::from(difficulty.effective_difficulty()) * 0.01
+            && !self
+                .get_block_state(pos.below())
+                .get_block()
+                .has_tag(&BlockTag::LIGHTNING_RODS);
+        let position = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()),
+            f64::from(pos.z()) + 0.5,
+        );
+
+        if is_trap {
+            // TODO: Mark the horse as a skeleton trap with age 0 once skeleton horses have an
+            // entity implementation; until then the factory lookup finds nothing to spawn.
+            if let Some(horse) = ENTITIES.create(
+                &vanilla_entities::SKELETON_HORSE,
+                next_entity_id(),
+                DVec3::new(f64::from(pos.x()), f64::from(pos.y()), f64::from(pos.z())),
+                Arc::downgrade(self),
+            ) && let Err(error) = self.try_add_entity(horse)
+            {
+                log::warn!("failed to add skeleton trap horse at {pos:?}: {error}");
+            }
+        }
+
+        let bolt = Arc::new(LightningBoltEntity::new(
+            &vanilla_entities::LIGHTNING_BOLT,
+            next_entity_id(),
+            position,
+            Arc::downgrade(self),
+        ));
+        bolt.set_visual_only(is_trap);
+        if let Err(error) = self.try_add_entity(bolt) {
+            log::warn!("failed to add lightning bolt at {pos:?}: {error}");
+        }
+    }
+
+    /// Vanilla `ServerLevel.findLightningTargetAround`.
+    ///
+    /// Prefers a nearby exposed lightning rod, then a random living entity under
+    /// open sky in the strike column, and finally the top of the column itself.
+    pub(crate) fn find_lightning_target_around(&self, pos: BlockPos) -> BlockPos {
+        let mut center = self.heightmap_pos(HeightmapType::MotionBlocking, pos);
+        if let Some(rod) = self.find_lightning_rod(center) {
+            return rod;
+        }
+
+        let search = WorldAabb::new(
+            f64::from(center.x()),
+            f64::from(center.y()),
+            f64::from(center.z()),
+            f64::from(center.x() + 1),
+            f64::from(self.get_max_y() + 2),
+            f64::from(center.z() + 1),
+        )
+        .inflate(LIGHTNING_ENTITY_SEARCH_INFLATE);
+        let targets = self.get_entities_in_aabb_matching(&search, |entity| {
+            entity.as_living_entity().is_some()
+                && entity.is_alive()
+                && self.can_see_sky(entity.block_position())
+        });
+        if !targets.is_empty() {
+            return targets[rand::random_range(0..targets.len())].block_position();
+        }
+
+        if center.y() == self.get_min_y() - 1 {
+            center = center.above_n(2);
+        }
+        center
+    }
+
+    /// Vanilla `ServerLevel.findLightningRod`: the closest rod that tops its column.
+    fn find_lightning_rod(&self, center: BlockPos) -> Option<BlockPos> {
+        let lightning_rod = vanilla_poi_types::LIGHTNING_ROD.try_id()?;
+        let candidates = self.poi_storage.lock().get_sorted_by_distance(
+            &|type_id| type_id == lightning_rod,
+            center,
+            LIGHTNING_ROD_SEARCH_RADIUS,
+            OccupationStatus::Any,
+        );
+
+        candidates
+            .into_iter()
+            .map(|(rod, _)| rod)
+            .find(|rod| {
+                self.height_at(HeightmapType::MotionBlocking, rod.x(), rod.z())
+                    .is_some_and(|height| rod.y() == height - 1)
+            })
+            .map(|rod| rod.above())
+    }
+}
@@ -1,11 +1,12 @@
 //! This module contains the `World` struct, which represents a world.
 
 use std::{
+    hash::Hash,
     io, mem,
     path::Path,
     sync::{
-        Arc, LazyLock, Weak,
-        atomic::{AtomicBool, AtomicI64, Ordering},
+        Arc, LazyLock, OnceLock, Weak,
+        atomic::{AtomicBool, AtomicI64, AtomicU8, Ordering},
     },
     time::Duration,
 };
@@ -15,8 +16,13 @@ use crate::chunk::chunk_ticket_manager::{PersistentChunkTickets, TimedChunkTicke
 use crate::chunk::light::{
     LightLayer, LightSectionEmptinessChange, MAX_LIGHT_LEVEL, has_different_light_properties,
 };
+use crate::map::storage::MapStorage;
 use crate::poi::OccupationStatus;
 use crate::portal::WorldChangeRequest;
+use crate::server::Server;
+use crate::server::broadcast::BroadcastPacket;
+use crate::server::chat_filter::ChatFilterMask;
+use crate::server::structure_templates::StructureTemplateManager;
 use crate::world::game_event_context::GameEventContext;
 use crate::world::game_event_listener::{GameEventListenerStorage, SharedGameEventListener};
 use crate::{chunk::chunk_map::ChunkMapGameTickTimings, world::weather::Weather};
@@ -25,10 +31,11 @@ use steel_utils::saved_data::{SavedDataManager, names as saved_data_names};
 use glam::DVec3;
 use sha2::{Digest, Sha256};
 use steel_protocol::packets::game::{
-    CBlockDestruction, CBlockEvent, CChangeDifficulty, CGameEvent, CInitializeBorder, CLevelEvent,
-    CLevelParticles, CPlayerChat, CSetBorderCenter, CSetBorderLerpSize, CSetBorderSize,
-    CSetBorderWarningDelay, CSetBorderWarningDistance, CSetEntityData, CSetEntityLink,
-    CSetEquipment, CSound, CSystemChat, CUpdateAttributes, GameEventType, SoundSource,
+    CBlockDestruction, CBlockEvent, CChangeDifficulty, CDeleteChat, CDisguisedChat, CGameEvent,
+    CInitializeBorder, CLevelEvent, CLevelParticles, CPlayerChat, CSetBorderCenter,
+    CSetBorderLerpSize, CSetBorderSize, CSetBorderWarningDelay, CSetBorderWarningDistance,
+    CSetEntityData, CSetEntityLink, CSetEquipment, CSound, CSystemChat, CUpdateAttributes,
+    ChatTypeBound, GameEventType, SoundSource,
 };
 use steel_protocol::utils::ConnectionProtocol;
 use steel_protocol::{
@@ -55,8 +62,10 @@ use steel_registry::particle_type::ParticleData;
 use steel_registry::sound_event::SoundEventRef;
 use steel_registry::vanilla_block_tags::BlockTag;
 use steel_registry::vanilla_game_rules::{
-    BLOCK_DROPS, GLOBAL_SOUND_EVENTS, PLAYERS_NETHER_PORTAL_DEFAULT_DELAY, RANDOM_TICK_SPEED,
+    BLOCK_DROPS, FIRE_SPREAD_RADIUS_AROUND_PLAYER, GLOBAL_SOUND_EVENTS,
+    PLAYERS_NETHER_PORTAL_DEFAULT_DELAY, RANDOM_TICK_SPEED,
 };
+use steel_registry::vanilla_poi_type_tags::PoiTag;
 use steel_registry::{
     REGISTRY, RegistryEntry, RegistryExt, dimension_type::DimensionTypeRef,
     world_clock::WorldClockRef,
@@ -67,6 +76,7 @@ use steel_registry::{
 };
 use steel_registry::{vanilla_blocks, vanilla_entities, vanilla_game_events, vanilla_poi_types};
 use steel_utils::block_util::FoundRectangle;
+use steel_utils::value_providers::IntProvider;
 use steel_utils::{
     Downcast as _,
     locks::{SyncMutex, SyncRwLock},
@@ -92,20 +102,27 @@ use steel_utils::{
     WorldAabb,
     types::{Difficulty, GameType, UpdateFlags},
 };
+use text_components::TextComponent;
 use tokio::{runtime::Runtime, time::Instant};
 
 use crate::{
     ChunkMap,
     behavior::BlockStateBehaviorExt,
     behavior::{BLOCK_BEHAVIORS, BlockCollisionContext, FLUID_BEHAVIORS},
-    block_entity::{SharedBlockEntity, entities::EndGatewayBlockEntity},
-    chunk::{heightmap::HeightmapType, player_chunk_view::PlayerChunkView},
+    block_entity::{
+        BlockEntityLootData, SharedBlockEntity,
+        entities::{BeehiveBlockEntity, EndGatewayBlockEntity},
+    },
+    chunk::{heightmap::HeightmapType, player_chunk_view::PlayerChunkView, tick_region},
     chunk_saver::{ChunkStorage, RamOnlyStorage, RegionManager},
+    enchantment_helper,
     entity::{
         AddEntityError, Entity, EntityChangeSenders, EntityChunkCallback, EntityLifecycleChanges,
         EntityMovementSyncPacket, EntityOwnership, EntityTracker, EntityVisibility,
         InactiveEntityCallback, MobEffectSyncPacket, RemovalReason, SharedEntity,
-        WorldEntityManager, entities::ItemEntity, entity_loot_ref,
+        WorldEntityManager,
+        entities::{EnderDragonEntity, EnderDragonPart, ExperienceOrbEntity, ItemEntity},
+        entity_loot_ref,
     },
     fluid::{FluidStateExt as _, fluid_state_to_block},
     level_data::{LevelDataManager, RespawnData, WorldBorderData, WorldGenerationSettings},
@@ -183,15 +200,28 @@ impl ClipHitResult {
 }
 
 mod border;
+mod boss_event;
 pub(crate) mod clock;
+mod difficulty_instance;
+mod end_dragon_fight;
 mod environment;
+mod explosion;
 pub mod game_event_context;
 pub mod game_event_listener;
 mod level_reader;
+mod lightning;
+mod natural_spawner;
+mod patrol_spawner;
 mod player_area_map;
 mod player_map;
 pub(crate) mod player_spawn_finder;
+mod precipitation;
+mod raid;
+mod raids;
+mod signal_getter;
+mod sleep_status;
 pub mod tick_scheduler;
+pub mod vibration;
 mod weather;
 mod world_entities;
 
@@ -200,9 +230,18 @@ use crate::worldgen::generators::vanilla::fuzzed_biome_at_block;
 use crate::worldgen::{ChunkGenerator, ChunkGeneratorType};
 pub use border::WorldBorderError;
 use border::{WorldBorder, WorldBorderSnapshot};
+pub use boss_event::ServerBossEvent;
+pub use difficulty_instance::DifficultyInstance;
+pub use end_dragon_fight::{DragonRespawnStage, EndDragonFight};
+pub use explosion::ExplosionInteraction;
 pub use level_reader::{LevelAccessor, LevelReader, ScheduledTickAccess};
+use patrol_spawner::PatrolSpawner;
 pub use player_area_map::PlayerAreaMap;
 pub use player_map::PlayerMap;
+pub use raid::{MAX_RAID_OMEN_LEVEL, Raid, RaidStatus};
+pub use raids::Raids;
+pub use signal_getter::SignalGetter;
+use sleep_status::SleepStatus;
 pub use tick_scheduler::ScheduledTick;
 
 /// Generates a random value using triangle distribution.
@@ -240,6 +279,16 @@ fn dist_to_origin_center_sqr(pos: BlockPos) -> f64 {
     x * x + y * y + z * z
 }
 
+/// Vanilla `Bee.BeeLocateHiveGoal` search radius.
+const BEE_HIVE_SEARCH_RADIUS: i32 = 20;
+
+fn is_bee_home(type_id: usize) -> bool {
+    REGISTRY
+        .poi_types
+        .by_id(type_id)
+        .is_some_and(|poi_type| REGISTRY.poi_types.is_in_tag(poi_type, &PoiTag::BEE_HOME))
+}
+
 fn closest_portal_candidate(
     candidates: impl IntoIterator<Item = BlockPos>,
     approximate_exit_pos: BlockPos,
@@ -330,6 +379,8 @@ pub struct WorldConfig {
     pub view_distance: u8,
     /// Server simulation distance.
     pub simulation_distance: u8,
+    /// Percentage applied to vanilla entity tracking ranges.
+    pub entity_broadcast_range_percentage: u16,
     /// Compression settings for encoding broadcast packets.
     pub compression: Option<CompressionInfo>,
     /// Whether the world should be marked as flat in login/respawn packets.
@@ -391,9 +442,9 @@ pub struct World {
     /// Runtime world border state.
     world_border: SyncMutex<WorldBorder>,
     /// Server view distance (maximum chunk radius).
-    pub view_distance: u8,
+    view_distance: AtomicU8,
     /// Server simulation distance.
-    pub simulation_distance: u8,
+    simulation_distance: AtomicU8,
     /// Compression settings for encoding broadcast packets.
     pub compression: Option<CompressionInfo>,
     /// Whether the world should be marked as flat in login/respawn packets.
@@ -413,6 +464,14 @@ pub struct World {
     navigating_mobs: NavigatingMobTracker,
     /// Weather Data needed for animating starting and stopping of rain clientside
     pub weather: SyncMutex<Weather>,
+    /// Player counts for the night skip vote.
+    sleep_status: SyncMutex<SleepStatus>,
+    /// Village raids in progress.
+    pub(crate) raids: SyncMutex<Raids>,
+    /// Countdown to the next pillager patrol attempt.
+    patrol_spawner: SyncMutex<PatrolSpawner>,
+    /// The ender dragon fight, in dimensions that have one.
+    pub(crate) dragon_fight: Option<SyncMutex<EndDragonFight>>,
     /// Monotonic counter for `sub_tick_order` on scheduled ticks.
     /// Provides stable ordering when multiple ticks fire on the same game tick
     /// with the same priority.
@@ -423,6 +482,12 @@ pub struct World {
     game_event_listeners: GameEventListenerStorage,
     /// World-change requests queued by world-local ticks for server safe-point processing.
     pending_world_changes: SyncMutex<Vec<(SharedEntity, WorldChangeRequest)>>,
+    /// Filled-map data shared by every world of this world's domain.
+    maps: OnceLock<Arc<MapStorage>>,
+    /// Structure templates shared by every world of the server.
+    structure_templates: OnceLock<Arc<StructureTemplateManager>>,
+    /// The server running this world, set once it starts.
+    server: OnceLock<Weak<Server>>,
 }
 
 impl World {
@@ -447,6 +512,7 @@ impl World {
     ) -> io::Result<Arc<Self>> {
         let view_distance = config.view_distance;
         let simulation_distance = config.simulation_distance;
+        let entity_broadcast_range_percentage = config.entity_broadcast_range_percentage;
         let compression = config.compression;
         let is_flat = config.is_flat;
         let sea_level = config.sea_level;
@@ -475,6 +541,16 @@ impl World {
             .load_or_default(saved_data_names::CHUNK_TICKETS)
             .await?;
         let timed_chunk_tickets = TimedChunkTickets::from_persistent(persistent_chunk_tickets);
+        let raids: Raids = saved_data.load_or_default(saved_data_names::RAIDS).await?;
+        let dragon_fight = if dimension_type.has_ender_dragon_fight {
+            let mut fight: EndDragonFight = saved_data
+                .load_or_default(saved_data_names::ENDER_DRAGON_FIGHT)
+                .await?;
+            fight.init(seed);
+            Some(SyncMutex::new(fight))
+        } else {
+            None
+        };
         let world_border = WorldBorder::new(level_data.data().world_border)
             .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
         // let generator = Arc::new(ChunkGeneratorType::Flat(FlatChunkGenerator::new(
@@ -517,21 +593,28 @@ impl World {
                 level_data: SyncRwLock::new(level_data),
                 saved_data,
                 world_border: SyncMutex::new(world_border),
-                view_distance,
-                simulation_distance,
+                view_distance: AtomicU8::new(view_distance),
+                simulation_distance: AtomicU8::new(simulation_distance),
                 compression,
                 is_flat,
                 sea_level,
                 default_gamemode,
                 tick_runs_normally: AtomicBool::new(true),
                 entity_manager: WorldEntityManager::new(),
-                entity_tracker: EntityTracker::new(),
+                entity_tracker: EntityTracker::new(entity_broadcast_range_percentage),
                 navigating_mobs: NavigatingMobTracker::new(),
                 weather: SyncMutex::new(weather),
+                sleep_status: SyncMutex::new(SleepStatus::default()),
+                raids: SyncMutex::new(raids),
+                patrol_spawner: SyncMutex::new(PatrolSpawner::default()),
+                dragon_fight,
                 sub_tick_count: AtomicI64::new(0),
                 poi_storage: SyncMutex::new(PointOfInterestStorage::new()),
                 game_event_listeners: GameEventListenerStorage::new(),
                 pending_world_changes: SyncMutex::new(Vec::new()),
+                maps: OnceLock::new(),
+                structure_templates: OnceLock::new(),
+                server: OnceLock::new(),
             }
         }))
     }
@@ -558,6 +641,26 @@ impl World {
             Err(e) => log::error!("Failed to save world chunk ticket data: {e}"),
         }
 
+        match self
+            .saved_data
+            .save(saved_data_names::RAIDS, &*self.raids.lock())
+            .await
+        {
+            Ok(()) => log::info!("World {} saved raid data successfully", self.key),
+            Err(e) => log::error!("Failed to save world raid data: {e}"),
+        }
+
+        if let Some(fight) = &self.dragon_fight {
+            match self
+                .saved_data
+                .save(saved_data_names::ENDER_DRAGON_FIGHT, &*fight.lock())
+                .await
+            {
+                Ok(()) => log::info!("World {} saved dragon fight data successfully", self.key),
+                Err(e) => log::error!("Failed to save world dragon fight data: {e}"),
+            }
+        }
+
         match self.save_all_chunks().await {
             Ok(count) => *total_saved += count,
             Err(e) => log::error!("Failed to save world chunks: {e}"),
@@ -624,6 +727,32 @@ impl World {
         )
     }
 
+    /// Returns bee homes near `pos` that can take another bee, nearest first.
+    ///
+    /// Vanilla `Bee.BeeLocateHiveGoal.findNearbyHivesWithSpace`.
+    #[must_use]
+    pub fn find_nearby_hives_with_space(&self, pos: BlockPos) -> Vec<BlockPos> {
+        let candidates = self.poi_storage.lock().get_sorted_by_distance(
+            &is_bee_home,
+            pos,
+            BEE_HIVE_SEARCH_RADIUS,
+            OccupationStatus::Any,
+        );
+
+        candidates
+            .into_iter()
+            .map(|(hive, _)| hive)
+            .filter(|&hive| {
+                self.get_block_entity(hive).is_some_and(|block_entity| {
+                    block_entity
+                        .lock()
+                        .downcast_ref::<BeehiveBlockEntity>()
+                        .is_some_and(|beehive| !beehive.is_full())
+                })
+            })
+            .collect()
+    }
+
     /// Creates a Nether portal using vanilla `PortalForcer.createPortal` placement rules.
     ///
     /// The caller must keep the target search area loaded as full chunks before calling. Steel
@@ -988,7 +1117,12 @@ impl World {
         tallest.unwrap_or(around)
     }
 
-    fn is_collision_shape_full_block_at(&self, pos: BlockPos, state: BlockStateId) -> bool {
+    /// Returns vanilla `BlockState.isCollisionShapeFullBlock` for `state` placed at `pos`.
+    pub(crate) fn is_collision_shape_full_block_at(
+        &self,
+        pos: BlockPos,
+        state: BlockStateId,
+    ) -> bool {
         is_shape_full_block(self.block_collision_shape(pos, state))
     }
 
@@ -1024,10 +1158,13 @@ impl World {
     }
 
     /// Mirrors vanilla `EndGatewayFeature.place` for runtime End gateway creation.
+    ///
+    /// Without an `exit` the gateway searches for one on first use, like
+    /// vanilla's delayed exit search.
     pub(crate) fn create_end_gateway_portal(
         self: &Arc<Self>,
         origin: BlockPos,
-        exit: BlockPos,
+        exit: Option<BlockPos>,
         exact: bool,
     ) -> bool {
         for dy in -2_i32..=2 {
@@ -1054,6 +1191,9 @@ impl World {
             }
         }
 
+        let Some(exit) = exit else {
+            return true;
+        };
         let Some(block_entity) = self.get_block_entity(origin) else {
             return false;
         };
@@ -1270,6 +1410,35 @@ impl World {
         }
     }
 
+    /// Returns the filled maps of this world's domain, once loaded.
+    #[must_use]
+    pub fn maps(&self) -> Option<&Arc<MapStorage>> {
+        self.maps.get()
+    }
+
+    pub(crate) fn set_maps(&self, maps: Arc<MapStorage>) {
+        let _ = self.maps.set(maps);
+    }
+
+    /// Returns the server's structure template manager, once installed.
+    #[must_use]
+    pub fn structure_templates(&self) -> Option<&Arc<StructureTemplateManager>> {
+        self.structure_templates.get()
+    }
+
+    pub(crate) fn set_structure_templates(&self, templates: Arc<StructureTemplateManager>) {
+        let _ = self.structure_templates.set(templates);
+    }
+
+    /// Returns the server running this world, once it has started.
+    pub(crate) fn server(&self) -> Option<Arc<Server>> {
+        self.server.get().and_then(Weak::upgrade)
+    }
+
+    pub(crate) fn set_server(&self, server: Weak<Server>) {
+        let _ = self.server.set(server);
+    }
+
     /// Returns vanilla level game time.
     pub fn game_time(&self) -> i64 {
         self.level_data.read().game_time()
@@ -1638,6 +1807,31 @@ impl World {
         true
     }
 
+    /// Returns whether any fluid touches the given box.
+    ///
+    /// This is the Rust equivalent of vanilla's `BlockGetter.containsAnyLiquid(AABB)`.
+    #[must_use]
+    pub fn contains_any_liquid(&self, aabb: &WorldAabb) -> bool {
+        let min_x = aabb.min_x().floor() as i32;
+        let max_x = aabb.max_x().ceil() as i32;
+        let min_y = aabb.min_y().floor() as i32;
+        let max_y = aabb.max_y().ceil() as i32;
+        let min_z = aabb.min_z().floor() as i32;
+        let max_z = aabb.max_z().ceil() as i32;
+
+        for x in min_x..max_x {
+            for y in min_y..max_y {
+                for z in min_z..max_z {
+                    if self.get_block_state(BlockPos::new(x, y, z)).has_fluid() {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
     /// Returns whether the tick rate is running normally.
     ///
     /// When false (frozen/paused), movement validation checks should be skipped.
@@ -1655,6 +1849,40 @@ impl World {
             .store(runs_normally, Ordering::Relaxed);
     }
 
+    /// Returns the server view distance (maximum chunk radius).
+    #[must_use]
+    pub fn view_distance(&self) -> u8 {
+        self.view_distance.load(Ordering::Relaxed)
+    }
+
+    /// Returns the server simulation distance.
+    #[must_use]
+    pub fn simulation_distance(&self) -> u8 {
+        self.simulation_distance.load(Ordering::Relaxed)
+    }
+
+    /// Changes the server view and simulation distance and resends chunks to
+    /// every player in the world whose effective distance changed.
+    ///
+    /// Matches vanilla's `PlayerList.setViewDistance` and `setSimulationDistance`.
+    pub fn set_view_distances(&self, view_distance: u8, simulation_distance: u8) {
+        let mut players = Vec::new();
+        self.players.iter_players(|_, player| {
+            players.push((
+                Arc::clone(player),
+                player.view_distance(),
+                player.simulation_distance(),
+            ));
+            true
+        });
+        self.view_distance.store(view_distance, Ordering::Relaxed);
+        self.simulation_distance
+            .store(simulation_distance, Ordering::Relaxed);
+        for (player, old_view_distance, old_simulation_distance) in players {
+            player.view_distance_changed(old_view_distance, old_simulation_distance);
+        }
+    }
+
     /// Gets the value of a game rule.
     /// WARNING: this function acquires a read lock on the level data.
     /// if you already have a write lock on level data, this will DEADLOCK
@@ -1802,7 +2030,8 @@ impl World {
             .queue_light_change(pos, light_properties_changed, empty_section_change);
     }
 
-    fn light_value_at(&self, layer: LightLayer, pos: BlockPos) -> u8 {
+    /// Returns vanilla `Level.getBrightness(LightLayer, BlockPos)`.
+    pub(crate) fn light_value_at(&self, layer: LightLayer, pos: BlockPos) -> u8 {
         if layer == LightLayer::Sky && !self.dimension_type.has_skylight {
             return 0;
         }
@@ -1895,6 +2124,9 @@ impl World {
     /// further block changes. Each recursive call decrements the limit.
     ///
     /// Returns `true` if the block was successfully set, `false` otherwise.
+    /// A write a parallel random tick makes outside its own region is queued
+    /// until every region has ticked and returns `false`, as the block is not
+    /// set yet.
     pub fn set_block_with_limit(
         self: &Arc<Self>,
         pos: BlockPos,
@@ -1911,6 +2143,12 @@ impl World {
         }
 
         let chunk_pos = Self::chunk_pos_for_block(pos);
+        // A region ticking in parallel only writes to its own chunks.
+        if tick_region::defer_if_outside_region(chunk_pos, move |world| {
+            world.set_block_with_limit(pos, block_state, flags, update_limit);
+        }) {
+            return false;
+        }
         let Some(old_state) = self
             .chunk_map
             .with_full_chunk(chunk_pos, |chunk| {
@@ -2080,7 +2318,8 @@ impl World {
         }
     }
 
-    fn is_redstone_conductor(state: BlockStateId, pos: BlockPos) -> bool {
+    /// Approximates vanilla `BlockState.isRedstoneConductor` with a full-block collision check.
+    pub(crate) fn is_redstone_conductor(state: BlockStateId, pos: BlockPos) -> bool {
         is_offset_shape_full_block(state.get_collision_shape_at(pos))
     }
 
@@ -2217,13 +2456,30 @@ impl World {
             .with_chunk_at_status(chunk_pos, ChunkStatus::Empty, ChunkAccess::mark_dirty);
     }
 
-    /// Game tick: weather, time, chunk game tick (broadcasts + random/scheduled ticks),
-    /// and player logic (without chunk sending).
+    /// Runs `action` now, or after every region has ticked if called while a
+    /// region is ticking in parallel.
+    ///
+    /// Use this for work that reaches far outside the caller's chunk, such as
+    /// teleporting an entity.
+    pub fn run_at_sync_point(self: &Arc<Self>, action: impl FnOnce(&Arc<World>) + Send + 'static) {
+        if let Some(action) = tick_region::defer_to_sync_phase(Box::new(action)) {
+            action(self);
+        }
+    }
+
+    /// Game tick: weather, time, natural spawning, chunk game tick (broadcasts + random/scheduled
+    /// ticks), and player logic (without chunk sending).
     ///
     /// * `tick_count` - The current tick number
     /// * `runs_normally` - Whether game elements (random ticks, entities) should run.
     ///   When false (frozen), only essential operations like chunk loading run.
-    #[tracing::instrument(level = "trace", skip(self), name = "world_game_tick")]
+    /// * `random_tick_pool` - Workers for running random block ticks of independent chunk
+    ///   regions in parallel. `None` runs them on the calling thread.
+    #[tracing::instrument(
+        level = "trace",
+        skip(self, random_tick_pool),
+        name = "world_game_tick"
+    )]
     #[expect(
         clippy::too_many_lines,
         reason = "world tick orchestration keeps vanilla subsystem order explicit"
@@ -2232,20 +2488,42 @@ impl World {
         self: &Arc<Self>,
         tick_count: u64,
         runs_normally: bool,
+        random_tick_pool: Option<&rayon::ThreadPool>,
     ) -> WorldGameTickTimings {
         let world_start = Instant::now();
         self.set_tick_runs_normally(runs_normally);
         if runs_normally {
             self.tick_world_border();
             self.tick_weather();
+            self.tick_sleep();
             self.tick_time();
         }
 
+        if runs_normally {
+            let _span = tracing::trace_span!("natural_spawning").entered();
+            self.tick_natural_spawning();
+            self.tick_patrol_spawner();
+        }
+
         let random_tick_speed = self.get_game_rule(&RANDOM_TICK_SPEED) as u32;
 
-        let mut chunk_map_timings =
-            self.chunk_map
-                .tick_game(self, tick_count, random_tick_speed, runs_normally);
+        let mut chunk_map_timings = self.chunk_map.tick_game(
+            self,
+            tick_count,
+            random_tick_speed,
+            runs_normally,
+            random_tick_pool,
+        );
+
+        if runs_normally {
+            let _span = tracing::trace_span!("raid").entered();
+            self.tick_raids();
+        }
+
+        if runs_normally {
+            let _span = tracing::trace_span!("dragon_fight").entered();
+            self.tick_dragon_fight();
+        }
 
         let entity_tick = {
             let _span = tracing::trace_span!("entity_tick").entered();
@@ -2600,6 +2878,30 @@ impl World {
             .is_some_and(|height| height <= pos.y())
     }
 
+    /// Returns whether fire may spread or be started by lightning at `pos`.
+    ///
+    /// Vanilla `ServerLevel.canSpreadFireAround`: a radius of `-1` allows fire
+    /// everywhere, otherwise a player must be within the radius.
+    pub fn can_spread_fire_around(&self, pos: BlockPos) -> bool {
+        let radius = self.get_game_rule(&FIRE_SPREAD_RADIUS_AROUND_PLAYER);
+        if radius == -1 {
+            return true;
+        }
+
+        let center = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 0.5,
+            f64::from(pos.z()) + 0.5,
+        );
+        let radius_sq = f64::from(radius) * f64::from(radius);
+        let mut player_nearby = false;
+        self.players.iter_players(|_, player| {
+            player_nearby = player.position().distance_squared(center) <= radius_sq;
+            !player_nearby
+        });
+        player_nearby
+    }
+
     fn can_see_sky_for_precipitation(&self, pos: BlockPos) -> bool {
         self.can_see_sky(pos)
     }
@@ -2717,6 +3019,13 @@ impl World {
         delay: i32,
         priority: tick_scheduler::TickPriority,
     ) {
+        // Regions ticking in parallel queue their ticks so sub-tick order
+        // does not depend on thread timing.
+        if tick_region::defer_if_in_region(move |world| {
+            world.schedule_block_tick(pos, block, delay, priority);
+        }) {
+            return;
+        }
         let chunk_pos = Self::chunk_pos_for_block(pos);
         self.chunk_map.with_full_chunk(chunk_pos, |chunk_access| {
             if let Some(chunk) = chunk_access.as_full() {
@@ -2749,6 +3058,12 @@ impl World {
         delay: i32,
         priority: tick_scheduler::TickPriority,
     ) {
+        // See `schedule_block_tick`.
+        if tick_region::defer_if_in_region(move |world| {
+            world.schedule_fluid_tick(pos, fluid, delay, priority);
+        }) {
+            return;
+        }
         let chunk_pos = Self::chunk_pos_for_block(pos);
         self.chunk_map.with_full_chunk(chunk_pos, |chunk_access| {
             if let Some(chunk) = chunk_access.as_full() {
@@ -2817,9 +3132,10 @@ impl World {
     pub fn broadcast_chat(
         &self,
         mut packet: CPlayerChat,
-        _sender: Arc<Player>,
+        sender: &Player,
         sender_last_seen: LastSeen,
         message_signature: Option<&[u8; 256]>,
+        filter: &ChatFilterMask,
     ) {
         log::debug!(
             "broadcast_chat: sender_last_seen has {} signatures, message_signature present: {}",
@@ -2828,6 +3144,10 @@ impl World {
         );
 
         self.players.iter_players(|_, recipient| {
+            let Some(filter_type) = filter.mask_for(sender, recipient) else {
+                return true;
+            };
+            packet.filter_type = filter_type;
             let messages_received = recipient.get_and_increment_messages_received();
             packet.global_index = messages_received;
 
@@ -2880,6 +3200,41 @@ impl World {
         });
     }
 
+    /// Hides a signed chat message on the clients of every player in the world.
+    ///
+    /// The signature is packed against each recipient's signature cache, so
+    /// clients that still have it cached only get its id.
+    pub fn delete_chat_message(&self, signature: &[u8; 256]) {
+        self.players.iter_players(|_, recipient| {
+            let message_signature = recipient.chat.lock().signature_cache.pack(signature);
+            recipient.send_packet(CDeleteChat { message_signature });
+            true
+        });
+    }
+
+    /// Broadcasts player chat without a signature or sender, so clients
+    /// neither verify nor allow reporting it. Filtered words are replaced
+    /// before sending.
+    pub fn broadcast_disguised_chat(
+        &self,
+        sender: &Player,
+        message: &str,
+        chat_type: &ChatTypeBound,
+        filter: &ChatFilterMask,
+    ) {
+        self.players.iter_players(|_, recipient| {
+            if let Some(message) = filter.apply_for(sender, recipient, message) {
+                let content = TextComponent::plain(message.into_owned());
+                recipient.send_packet(CDisguisedChat::new(
+                    &content,
+                    chat_type.clone(),
+                    recipient.as_ref(),
+                ));
+            }
+            true
+        });
+    }
+
     /// Broadcasts a system chat message to all players.
     pub fn broadcast_system_chat(&self, packet: CSystemChat) {
         self.broadcast_to_all(packet);
@@ -2922,6 +3277,20 @@ impl World {
         });
     }
 
+    /// Broadcasts a packet that differs per viewer, such as one naming the viewer's
+    /// own entity. Each distinct `variant` is built and encoded once.
+    pub fn broadcast_to_all_variants<K: Eq + Hash, P: ClientPacket>(
+        &self,
+        variant: impl Fn(&Player) -> K,
+        packet: impl FnMut(&K, &Player) -> P,
+    ) {
+        let mut broadcast = BroadcastPacket::new(self.compression, packet);
+        self.players.iter_players(|_, player| {
+            broadcast.send(variant(player), player);
+            true
+        });
+    }
+
     /// Broadcasts an already-encoded packet to all players in the world.
     pub fn broadcast_to_all_encoded(&self, packet: EncodedPacket) {
         self.players.iter_players(|_, player| {
@@ -2941,8 +3310,17 @@ impl World {
     }
 
     /// Broadcasts an unsigned player chat message to all players.
-    pub fn broadcast_unsigned_chat(&self, mut packet: CPlayerChat) {
+    pub fn broadcast_unsigned_chat(
+        &self,
+        mut packet: CPlayerChat,
+        sender: &Player,
+        filter: &ChatFilterMask,
+    ) {
         self.players.iter_players(|_, recipient| {
+            let Some(filter_type) = filter.mask_for(sender, recipient) else {
+                return true;
+            };
+            packet.filter_type = filter_type;
             let messages_received = recipient.get_and_increment_messages_received();
             packet.global_index = messages_received;
 
@@ -4117,11 +4495,7 @@ impl World {
             return;
         }
 
-        let packet = CLevelEvent::new(event_type, pos, data, true);
-        self.players.iter_players(|_, player| {
-            player.send_packet(packet.clone());
-            true
-        });
+        self.broadcast_to_all(CLevelEvent::new(event_type, pos, data, true));
     }
 
     /// Broadcasts block destruction particles and sound for a destroyed block.
@@ -4151,6 +4525,14 @@ impl World {
         self.destroy_block_with_limit(pos, drop_items, 512)
     }
 
+    /// Removes a block without drops or effects, leaving its fluid behind.
+    ///
+    /// Vanilla `Level.removeBlock(pos, false)`.
+    pub fn remove_block(self: &Arc<Self>, pos: BlockPos) -> bool {
+        let replacement = fluid_state_to_block(self.get_block_state(pos).get_fluid_state());
+        self.set_block(pos, replacement, UpdateFlags::UPDATE_ALL)
+    }
+
     /// Destroys a block with an entity source for game-event context.
     pub fn destroy_block_by_entity(
         self: &Arc<Self>,
@@ -4193,8 +4575,10 @@ impl World {
         }
 
         if drop_items {
-            self.drop_resources_with_entity(state, pos, entity);
-            // TODO: block entity drops
+            let block_entity = self
+                .get_block_entity(pos)
+                .map(|block_entity| BlockEntityLootData::capture(&*block_entity.lock()));
+            self.drop_resources_with_entity(state, pos, block_entity.as_ref(), entity);
         }
 
         // Vanilla parity: fluidState.createLegacyBlock() — breaking a waterlogged
@@ -4217,29 +4601,36 @@ impl World {
     /// This is the no-tool/no-entity overload. Player block breaking uses
     /// `block_breaking::drop_block_loot` which includes tool context for
     /// fortune/silk touch.
-    // TODO: `spawnAfterBreak` (XP orbs for ores) not called yet.
-    // TODO: block entity and entity drops
     pub fn drop_resources(self: &Arc<Self>, state: BlockStateId, pos: BlockPos) {
-        self.drop_resources_with_entity(state, pos, None);
+        let block_entity = self
+            .get_block_entity(pos)
+            .map(|block_entity| BlockEntityLootData::capture(&*block_entity.lock()));
+        self.drop_resources_with_entity(state, pos, block_entity.as_ref(), None);
     }
 
     fn drop_resources_with_entity(
         self: &Arc<Self>,
         state: BlockStateId,
         pos: BlockPos,
+        block_entity: Option<&BlockEntityLootData>,
         entity: Option<&dyn Entity>,
     ) {
-        for item in Self::block_drops(state, pos, entity) {
+        for item in Self::block_drops(state, pos, block_entity, entity, None) {
             if !item.is_empty() {
                 self.pop_resource(pos, item);
             }
         }
+        BLOCK_BEHAVIORS
+            .get_behavior(state.get_block())
+            .spawn_after_break(state, self, pos, &ItemStack::empty(), true);
     }
 
     fn block_drops(
         state: BlockStateId,
         pos: BlockPos,
+        block_entity: Option<&BlockEntityLootData>,
         entity: Option<&dyn Entity>,
+        explosion_radius: Option<f32>,
     ) -> Vec<ItemStack> {
         let block = state.get_block();
         let loot_key = steel_utils::Identifier::vanilla(format!("blocks/{}", block.key.path));
@@ -4252,9 +4643,15 @@ impl World {
         let mut ctx = LootContext::new(&mut rng)
             .with_block_state(state)
             .with_origin(f64::from(pos.x()), f64::from(pos.y()), f64::from(pos.z()));
+        if let Some(block_entity) = block_entity {
+            ctx = ctx.with_block_entity(block_entity.as_loot_ref());
+        }
         if let Some(entity) = entity {
             ctx = ctx.with_this_entity(entity_loot_ref(entity));
         }
+        if let Some(radius) = explosion_radius {
+            ctx = ctx.with_explosion(radius);
+        }
 
         loot_table.get_random_items(&mut ctx)
     }
@@ -4437,6 +4834,12 @@ impl World {
         self.track_navigating_mob(entity);
     }
 
+    /// Respawns an entity for every player tracking it.
+    pub(crate) fn resend_entity_to_trackers(&self, entity: &SharedEntity) {
+        self.entity_tracker
+            .resend_spawn_packets(entity, |player_id| self.players.get_by_entity_id(player_id));
+    }
+
     pub(crate) fn remove_entity_from_tracker(&self, entity_id: i32) {
         self.entity_tracker.remove(entity_id, |player_id| {
             self.players.get_by_entity_id(player_id)
@@ -4733,6 +5136,37 @@ impl World {
         Some(entity)
     }
 
+    /// Spawns experience orbs worth `amount` at the center of `pos`.
+    ///
+    /// Mirrors vanilla's `Block.popExperience()`. Respects `doTileDrops`.
+    pub fn pop_experience(self: &Arc<Self>, pos: BlockPos, amount: i32) {
+        if !self.get_game_rule(&BLOCK_DROPS) {
+            return;
+        }
+        let center = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 0.5,
+            f64::from(pos.z()) + 0.5,
+        );
+        ExperienceOrbEntity::award(self, center, amount);
+    }
+
+    /// Samples `range`, lets `tool`'s enchantments adjust it and pops the result.
+    ///
+    /// Mirrors vanilla's `Block.tryDropExperience()`.
+    pub fn try_drop_experience(
+        self: &Arc<Self>,
+        pos: BlockPos,
+        tool: &ItemStack,
+        range: &IntProvider,
+    ) {
+        let mut random = LegacyRandom::from_seed(rand::random());
+        let amount = enchantment_helper::process_block_experience(tool, range.sample(&mut random));
+        if amount > 0 {
+            self.pop_experience(pos, amount);
+        }
+    }
+
     /// Drops an item from a block face with directional velocity.
     ///
     /// Mirrors vanilla's `Block.popResourceFromFace()`. Used for items ejected
@@ -4835,6 +5269,26 @@ impl World {
         self.entity_manager.get_accessible_by_id(id)
     }
 
+    /// Gets an entity by its network ID, resolving ender dragon part IDs to
+    /// the dragon and the part that was targeted.
+    ///
+    /// Vanilla `ServerLevel.getEntityOrPart`.
+    #[must_use]
+    pub fn get_accessible_entity_or_part_by_id(
+        &self,
+        id: i32,
+    ) -> Option<(SharedEntity, Option<EnderDragonPart>)> {
+        if let Some(entity) = self.get_accessible_entity_by_id(id) {
+            return Some((entity, None));
+        }
+        (1..=EnderDragonPart::ALL.len() as i32).find_map(|offset| {
+            let dragon = self
+                .get_accessible_entity_by_id(id - offset)
+                .filter(|entity| entity.is::<EnderDragonEntity>())?;
+            Some((dragon, EnderDragonPart::from_id_offset(offset)))
+        })
+    }
+
     /// Gets an entity by its UUID.
     ///
     /// Returns `None` if the entity is not live in the world.
@@ -4930,6 +5384,15 @@ impl World {
         nearest.map(|(player, _)| player)
     }
 
+    /// Returns vanilla `EntityGetter.hasNearbyAlivePlayer`.
+    #[must_use]
+    pub fn has_nearby_alive_player(&self, position: DVec3, range: f64) -> bool {
+        self.nearest_player(position, range, |player| {
+            !player.is_spectator() && player.is_alive()
+        })
+        .is_some()
+    }
+
     /// Gets the squared distance to the nearest player, if any player is present.
     #[must_use]
     pub fn nearest_player_distance_sqr(&self, position: DVec3) -> Option<f64> {
@@ -5252,12 +5715,12 @@ mod tests {
         let state = vanilla_blocks::CHORUS_FLOWER.default_state();
         let pos = BlockPos::new(1_312, 64, 1_312);
         let breaker = TrackerTestEntity::shared(987_654);
-        let drops = World::block_drops(state, pos, Some(breaker.as_ref()));
+        let drops = World::block_drops(state, pos, None, Some(breaker.as_ref()), None);
 
         assert_eq!(drops.len(), 1);
         assert_eq!(drops[0].item(), &*vanilla_items::CHORUS_FLOWER);
         assert_eq!(drops[0].count(), 1);
-        assert!(World::block_drops(state, pos, None).is_empty());
+        assert!(World::block_drops(state, pos, None, None, None).is_empty());
     }
 
     fn assert_vec3_close(left: DVec3, right: DVec3) {
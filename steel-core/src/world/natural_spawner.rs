@@ -0,0 +1,494 @@
+//! Vanilla `NaturalSpawner`: per-category mob caps and natural spawn cycles.
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use rand::seq::SliceRandom as _;
+use rustc_hash::{FxHashMap, FxHashSet};
+use steel_registry::biome::SpawnerData;
+use steel_registry::entity_type::{EntityTypeRef, MobCategory};
+use steel_registry::vanilla_biome_tags::BiomeTag;
+use steel_registry::vanilla_game_rules::{SPAWN_MOBS, SPAWN_MONSTERS};
+use steel_registry::{REGISTRY, RegistryExt as _};
+use steel_utils::types::Difficulty;
+use steel_utils::{BlockPos, ChunkPos, WorldAabb};
+
+use crate::behavior::BlockCollisionContext;
+use crate::chunk::heightmap::HeightmapType;
+use crate::entity::{
+    ENTITIES, EntitySpawnReason, Mob, SpawnGroupData, next_entity_id, spawn_placement,
+};
+use crate::physics::{CollisionWorld as _, WorldCollisionProvider};
+use crate::world::World;
+
+/// Number of chunks in a single player's spawning area (17x17).
+const MAGIC_NUMBER: i32 = 17 * 17;
+const SPAWN_DISTANCE_CHUNK: i32 = 8;
+const SPAWN_DISTANCE_BLOCK_SQR: f64 = 128.0 * 128.0;
+const MIN_PLAYER_DISTANCE_SQR: f64 = 24.0 * 24.0;
+const MIN_SPAWN_POINT_DISTANCE_SQR: f64 = 24.0 * 24.0;
+const PERSISTENT_SPAWN_INTERVAL: i64 = 400;
+const SPAWN_GROUP_ATTEMPTS: i32 = 3;
+const SPAWN_SPREAD: i32 = 6;
+const REDUCED_WATER_AMBIENT_SPAWN_CHANCE: f32 = 0.98;
+
+/// Vanilla `NaturalSpawner.SpawnState`: mob counts gathered once per tick.
+#[derive(Debug)]
+pub(crate) struct SpawnState {
+    spawnable_chunk_count: i32,
+    mob_category_counts: FxHashMap<MobCategory, i32>,
+}
+
+impl SpawnState {
+    pub(crate) fn new(spawnable_chunk_count: i32) -> Self {
+        Self {
+            spawnable_chunk_count,
+            mob_category_counts: FxHashMap::default(),
+        }
+    }
+
+    /// Returns how many mobs of `category` currently count towards the cap.
+    pub(crate) fn mob_count(&self, category: MobCategory) -> i32 {
+        self.mob_category_counts
+            .get(&category)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Mirrors vanilla `SpawnState.canSpawnForCategoryGlobal`.
+    pub(crate) fn can_spawn_for_category_global(&self, category: MobCategory) -> bool {
+        let max_count =
+            category.max_instances_per_chunk() * self.spawnable_chunk_count / MAGIC_NUMBER;
+        self.mob_count(category) < max_count
+    }
+
+    /// Mirrors vanilla `SpawnState.afterSpawn`.
+    pub(crate) fn after_spawn(&mut self, category: MobCategory) {
+        *self.mob_category_counts.entry(category).or_insert(0) += 1;
+    }
+
+    /// Mirrors vanilla `NaturalSpawner.getFilteredSpawningCategories`.
+    pub(crate) fn filtered_spawning_categories(
+        &self,
+        spawn_friendlies: bool,
+        spawn_enemies: bool,
+        spawn_persistent: bool,
+    ) -> Vec<MobCategory> {
+        MobCategory::SPAWNING_CATEGORIES
+            .into_iter()
+            .filter(|category| {
+                (spawn_friendlies || !category.is_friendly())
+                    && (spawn_enemies || category.is_friendly())
+                    && (spawn_persistent || !category.is_persistent())
+                    && self.can_spawn_for_category_global(*category)
+            })
+            .collect()
+    }
+}
+
+impl World {
+    /// Runs the natural spawning part of vanilla `ServerChunkCache.tickChunks`.
+    pub(crate) fn tick_natural_spawning(self: &Arc<Self>) {
+        if !self.get_game_rule(&SPAWN_MOBS) {
+            return;
+        }
+
+        let player_positions = self.spawning_player_positions();
+        if player_positions.is_empty() {
+            return;
+        }
+
+        let mut state = SpawnState::new(natural_spawn_chunk_count(&player_positions));
+        for entity in self.entity_manager.get_accessible_entities() {
+            if entity.as_mob().is_some_and(|mob| {
+                mob.is_persistence_required() || mob.requires_custom_persistence()
+            }) {
+                continue;
+            }
+            let category = entity.entity_type().mob_category;
+            if category != MobCategory::Misc
+                && self.is_full_chunk_loaded_at(entity.block_position())
+            {
+                state.after_spawn(category);
+            }
+        }
+
+        let spawn_enemies =
+            self.difficulty() != Difficulty::Peaceful && self.get_game_rule(&SPAWN_MONSTERS);
+        let spawn_persistent = self.game_time() % PERSISTENT_SPAWN_INTERVAL == 0;
+        let categories = state.filtered_spawning_categories(true, spawn_enemies, spawn_persistent);
+        if categories.is_empty() {
+            return;
+        }
+
+        let mut chunks: Vec<_> = self
+            .chunk_map
+            .tickable_full_chunk_positions()
+            .into_iter()
+            .filter(|chunk| {
+                any_player_close_enough_for_spawning(&player_positions, *chunk)
+                    && self.is_chunk_within_world_border(*chunk)
+            })
+            .collect();
+        chunks.shuffle(&mut rand::rng());
+
+        for chunk in chunks {
+            for &category in &categories {
+                // Vanilla re-checks the cap through the per-player local counter here.
+                if state.can_spawn_for_category_global(category) {
+                    self.spawn_category_for_chunk(category, chunk, &mut state);
+                }
+            }
+        }
+    }
+
+    fn spawning_player_positions(&self) -> Vec<DVec3> {
+        let mut positions = Vec::new();
+        self.players.iter_players(|_, player| {
+            if !player.is_spectator() {
+                positions.push(player.position());
+            }
+            true
+        });
+        positions
+    }
+
+    fn is_chunk_within_world_border(&self, chunk: ChunkPos) -> bool {
+        let min_x = chunk.0.x * 16;
+        let min_z = chunk.0.y * 16;
+        self.is_block_within_world_border(BlockPos::new(min_x, 0, min_z))
+            && self.is_block_within_world_border(BlockPos::new(min_x + 15, 0, min_z + 15))
+    }
+
+    /// Mirrors vanilla `NaturalSpawner.spawnCategoryForChunk`.
+    fn spawn_category_for_chunk(
+        self: &Arc<Self>,
+        category: MobCategory,
+        chunk: ChunkPos,
+        state: &mut SpawnState,
+    ) {
+        let Some(pos) = self.random_pos_within(chunk) else {
+            return;
+        };
+        if pos.y() < self.get_min_y() + 1 {
+            return;
+        }
+
+        self.spawn_category_for_position(category, chunk, pos, state);
+    }
+
+    /// Mirrors vanilla `NaturalSpawner.getRandomPosWithin`.
+    fn random_pos_within(&self, chunk: ChunkPos) -> Option<BlockPos> {
+        let x = chunk.0.x * 16 + rand::random_range(0..16);
+        let z = chunk.0.y * 16 + rand::random_range(0..16);
+        let top_y = self.vanilla_chunk_height_at(HeightmapType::WorldSurface, x, z)? + 1;
+        let y = rand::random_range(self.get_min_y()..=top_y);
+        Some(BlockPos::new(x, y, z))
+    }
+
+    /// Mirrors vanilla `NaturalSpawner.spawnCategoryForPosition`.
+    fn spawn_category_for_position(
+        self: &Arc<Self>,
+        category: MobCategory,
+        chunk: ChunkPos,
+        start: BlockPos,
+        state: &mut SpawnState,
+    ) {
+        if Self::is_redstone_conductor(self.get_block_state(start), start) {
+            return;
+        }
+
+        let y = start.y();
+        let mut groups_spawned = 0;
+        for _ in 0..SPAWN_GROUP_ATTEMPTS {
+            let mut x = start.x();
+            let mut z = start.z();
+            let mut spawner_data: Option<&SpawnerData> = None;
+            let mut group_data: Option<SpawnGroupData> = None;
+            let mut max = (rand::random::<f32>() * 4.0).ceil() as i32;
+            let mut group_size = 0;
+
+            let mut attempt = 0;
+            while attempt < max {
+                attempt += 1;
+                x += rand::random_range(0..SPAWN_SPREAD) - rand::random_range(0..SPAWN_SPREAD);
+                z += rand::random_range(0..SPAWN_SPREAD) - rand::random_range(0..SPAWN_SPREAD);
+                let pos = BlockPos::new(x, y, z);
+                let spawn_pos = DVec3::new(f64::from(x) + 0.5, f64::from(y), f64::from(z) + 0.5);
+
+                let Some(nearest_player_dist_sqr) = self.nearest_player_distance_sqr(spawn_pos)
+                else {
+                    continue;
+                };
+                if !self.is_right_distance_to_player_and_spawn_point(
+                    chunk,
+                    pos,
+                    nearest_player_dist_sqr,
+                ) {
+                    continue;
+                }
+
+                let data = match spawner_data {
+                    Some(data) => data,
+                    None => {
+                        let Some(data) = self.random_spawn_mob_at(category, pos) else {
+                            break;
+                        };
+                        max = data.min_count
+                            + rand::random_range(0..=data.max_count - data.min_count);
+                        spawner_data = Some(data);
+                        data
+                    }
+                };
+
+                let Some(entity_type) = REGISTRY.entity_types.by_key(&data.entity_type) else {
+                    continue;
+                };
+                if !self.is_valid_spawn_position_for_type(entity_type, pos, nearest_player_dist_sqr)
+                {
+                    continue;
+                }
+
+                let Some(entity) = ENTITIES.create(
+                    entity_type,
+                    next_entity_id(),
+                    spawn_pos,
+                    Arc::downgrade(self),
+                ) else {
+                    return;
+                };
+                let Some(mob) = entity.as_mob() else {
+                    return;
+                };
+                mob.set_rotation((rand::random::<f32>() * 360.0, 0.0));
+                if !self.is_valid_position_for_mob(mob, nearest_player_dist_sqr) {
+                    continue;
+                }
+
+                group_data = mob.finalize_spawn(self, EntitySpawnReason::Natural, group_data);
+                groups_spawned += 1;
+                group_size += 1;
+                let max_spawn_cluster_size = mob.max_spawn_cluster_size();
+                let max_group_size_reached = mob.is_max_group_size_reached(group_size);
+                if self.try_add_entity(Arc::clone(&entity)).is_ok() {
+                    state.after_spawn(category);
+                }
+
+                if groups_spawned >= max_spawn_cluster_size {
+                    return;
+                }
+                if max_group_size_reached {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Mirrors vanilla `NaturalSpawner.isRightDistanceToPlayerAndSpawnPoint`.
+    fn is_right_distance_to_player_and_spawn_point(
+        &self,
+        chunk: ChunkPos,
+        pos: BlockPos,
+        nearest_player_dist_sqr: f64,
+    ) -> bool {
+        if nearest_player_dist_sqr <= MIN_PLAYER_DISTANCE_SQR {
+            return false;
+        }
+
+        let spawn_pos = self
+            .level_data
+            .read()
+            .data()
+            .respawn_data_or_local(&self.key)
+            .pos();
+        let spawn_center = DVec3::new(
+            f64::from(spawn_pos.x()) + 0.5,
+            f64::from(spawn_pos.y()) + 0.5,
+            f64::from(spawn_pos.z()) + 0.5,
+        );
+        let candidate = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()),
+            f64::from(pos.z()) + 0.5,
+        );
+        if spawn_center.distance_squared(candidate) < MIN_SPAWN_POINT_DISTANCE_SQR {
+            return false;
+        }
+
+        ChunkPos::from_block_pos(pos) == chunk || self.is_entity_ticking_chunk_loaded(pos)
+    }
+
+    /// Mirrors vanilla `NaturalSpawner.getRandomSpawnMobAt`.
+    ///
+    /// Structure spawn overrides are not applied yet; only the biome list is used.
+    fn random_spawn_mob_at(
+        &self,
+        category: MobCategory,
+        pos: BlockPos,
+    ) -> Option<&'static SpawnerData> {
+        let biome = self.biome_at(pos)?;
+        if category == MobCategory::WaterAmbient
+            && biome.has_tag(&BiomeTag::REDUCED_WATER_AMBIENT_SPAWNS)
+            && rand::random::<f32>() < REDUCED_WATER_AMBIENT_SPAWN_CHANCE
+        {
+            return None;
+        }
+
+        random_spawner_data(biome.spawners.get(category.name())?)
+    }
+
+    /// Mirrors vanilla `NaturalSpawner.isValidSpawnPostitionForType`.
+    fn is_valid_spawn_position_for_type(
+        self: &Arc<Self>,
+        entity_type: EntityTypeRef,
+        pos: BlockPos,
+        nearest_player_dist_sqr: f64,
+    ) -> bool {
+        let category = entity_type.mob_category;
+        if category == MobCategory::Misc {
+            return false;
+        }
+
+        let despawn_distance = f64::from(category.despawn_distance());
+        if !entity_type.can_spawn_far_from_player
+            && nearest_player_dist_sqr > despawn_distance * despawn_distance
+        {
+            return false;
+        }
+
+        let Some(placement) = spawn_placement(entity_type) else {
+            return false;
+        };
+        if !entity_type.summonable
+            || !placement
+                .placement_type
+                .is_spawn_position_ok(self, pos, entity_type)
+            || !placement.check_spawn_rules(entity_type, self, EntitySpawnReason::Natural, pos)
+        {
+            return false;
+        }
+
+        let dimensions = entity_type.dimensions;
+        let spawn_box = WorldAabb::entity_box(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()),
+            f64::from(pos.z()) + 0.5,
+            f64::from(dimensions.half_width()),
+            f64::from(dimensions.height),
+        );
+        let collision_world = WorldCollisionProvider::new(self);
+        !collision_world.has_entity_collision(&spawn_box)
+            && !collision_world
+                .has_block_collision_with_context(&spawn_box, BlockCollisionContext::empty())
+    }
+
+    /// Mirrors vanilla `NaturalSpawner.isValidPositionForMob`.
+    fn is_valid_position_for_mob(&self, mob: &dyn Mob, nearest_player_dist_sqr: f64) -> bool {
+        let despawn_distance = f64::from(mob.entity_type().mob_category.despawn_distance());
+        if nearest_player_dist_sqr > despawn_distance * despawn_distance
+            && mob.remove_when_far_away(nearest_player_dist_sqr)
+        {
+            return false;
+        }
+
+        mob.check_spawn_rules(self, EntitySpawnReason::Natural) && mob.check_spawn_obstruction(self)
+    }
+}
+
+/// Mirrors vanilla `DistanceManager.getNaturalSpawnChunkCount`.
+fn natural_spawn_chunk_count(player_positions: &[DVec3]) -> i32 {
+    let mut chunks = FxHashSet::default();
+    for position in player_positions {
+        let center = ChunkPos::from_entity_pos(*position);
+        for dx in -SPAWN_DISTANCE_CHUNK..=SPAWN_DISTANCE_CHUNK {
+            for dz in -SPAWN_DISTANCE_CHUNK..=SPAWN_DISTANCE_CHUNK {
+                chunks.insert(ChunkPos::new(center.0.x + dx, center.0.y + dz));
+            }
+        }
+    }
+    chunks.len() as i32
+}
+
+/// Mirrors vanilla `ChunkMap.anyPlayerCloseEnoughForSpawning`.
+fn any_player_close_enough_for_spawning(player_positions: &[DVec3], chunk: ChunkPos) -> bool {
+    let center_x = f64::from((chunk.0.x * 16) + 8);
+    let center_z = f64::from((chunk.0.y * 16) + 8);
+    player_positions.iter().any(|position| {
+        let dx = center_x - position.x;
+        let dz = center_z - position.z;
+        dx * dx + dz * dz < SPAWN_DISTANCE_BLOCK_SQR
+    })
+}
+
+/// Mirrors vanilla `WeightedList.getRandom`.
+fn random_spawner_data(spawners: &[SpawnerData]) -> Option<&SpawnerData> {
+    let total_weight: i32 = spawners.iter().map(|data| data.weight).sum();
+    if total_weight <= 0 {
+        return None;
+    }
+
+    let mut roll = rand::random_range(0..total_weight);
+    spawners.iter().find(|data| {
+        roll -= data.weight;
+        roll < 0
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::DVec3;
+    use steel_registry::entity_type::MobCategory;
+    use steel_utils::ChunkPos;
+
+    use super::{
+        MAGIC_NUMBER, SpawnState, any_player_close_enough_for_spawning, natural_spawn_chunk_count,
+    };
+
+    #[test]
+    fn single_player_area_uses_the_full_category_cap() {
+        let players = [DVec3::new(0.5, 64.0, 0.5)];
+        assert_eq!(natural_spawn_chunk_count(&players), MAGIC_NUMBER);
+
+        let mut state = SpawnState::new(MAGIC_NUMBER);
+        for _ in 0..69 {
+            state.after_spawn(MobCategory::Monster);
+        }
+        assert!(state.can_spawn_for_category_global(MobCategory::Monster));
+        state.after_spawn(MobCategory::Monster);
+        assert!(!state.can_spawn_for_category_global(MobCategory::Monster));
+    }
+
+    #[test]
+    fn overlapping_players_share_spawnable_chunks() {
+        let players = [DVec3::new(0.5, 64.0, 0.5), DVec3::new(16.5, 64.0, 0.5)];
+        assert_eq!(natural_spawn_chunk_count(&players), 17 * 18);
+    }
+
+    #[test]
+    fn filtered_categories_follow_difficulty_and_persistent_cycle() {
+        let state = SpawnState::new(MAGIC_NUMBER);
+
+        let peaceful = state.filtered_spawning_categories(true, false, false);
+        assert!(!peaceful.contains(&MobCategory::Monster));
+        assert!(!peaceful.contains(&MobCategory::Creature));
+        assert!(peaceful.contains(&MobCategory::Ambient));
+
+        let persistent_tick = state.filtered_spawning_categories(true, true, true);
+        assert!(persistent_tick.contains(&MobCategory::Monster));
+        assert!(persistent_tick.contains(&MobCategory::Creature));
+        assert!(!persistent_tick.contains(&MobCategory::Misc));
+    }
+
+    #[test]
+    fn spawning_chunks_must_be_within_128_blocks_of_a_player() {
+        let players = [DVec3::new(8.0, 64.0, 8.0)];
+        assert!(any_player_close_enough_for_spawning(
+            &players,
+            ChunkPos::new(7, 0)
+        ));
+        assert!(!any_player_close_enough_for_spawning(
+            &players,
+            ChunkPos::new(8, 0)
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+//! Pillager patrols spawning near players outside villages.
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use steel_registry::vanilla_biome_tags::BiomeTag;
+use steel_registry::vanilla_game_rules::{SPAWN_MONSTERS, SPAWN_PATROLS};
+use steel_registry::{vanilla_dimension_types, vanilla_entities};
+use steel_utils::BlockPos;
+use steel_utils::types::Difficulty;
+
+use super::World;
+use crate::chunk::heightmap::HeightmapType;
+use crate::chunk::light::LightLayer;
+use crate::entity::{
+    ENTITIES, Entity as _, EntitySpawnReason, is_valid_empty_spawn_block, is_valid_spawn,
+    next_entity_id,
+};
+
+/// Highest block light a patrol member may spawn in.
+const MAX_PATROL_BLOCK_LIGHT: u8 = 8;
+
+/// Vanilla `PatrolSpawner` state.
+#[derive(Debug, Default)]
+pub(crate) struct PatrolSpawner {
+    next_tick: i32,
+}
+
+impl World {
+    /// Runs vanilla `PatrolSpawner.tick`.
+    pub(super) fn tick_patrol_spawner(self: &Arc<Self>) {
+        // Vanilla only registers custom spawners for the overworld.
+        if self.dimension_type.key != vanilla_dimension_types::OVERWORLD.key
+            || self.difficulty() == Difficulty::Peaceful
+            || !self.get_game_rule(&SPAWN_MONSTERS)
+            || !self.get_game_rule(&SPAWN_PATROLS)
+        {
+            return;
+        }
+
+        {
+            let mut spawner = self.patrol_spawner.lock();
+            spawner.next_tick -= 1;
+            if spawner.next_tick > 0 {
+                return;
+            }
+            spawner.next_tick += 12_000 + rand::random_range(0..1200);
+        }
+
+        let day = self.default_clock_time() / 24_000;
+        if day < 5 || !self.is_bright_outside() || rand::random_range(0..5) != 0 {
+            return;
+        }
+
+        let mut players = Vec::new();
+        self.players.iter_players(|_, player| {
+            players.push(Arc::clone(player));
+            true
+        });
+        if players.is_empty() {
+            return;
+        }
+        let player = &players[rand::random_range(0..players.len())];
+        if player.is_spectator() || self.is_close_to_village(player.block_position(), 2) {
+            return;
+        }
+
+        let offset_x = rand::random_range(24..48) * if rand::random() { -1 } else { 1 };
+        let offset_z = rand::random_range(24..48) * if rand::random() { -1 } else { 1 };
+        let origin = player.block_position().offset(offset_x, 0, offset_z);
+        let (mut x, mut z) = (origin.x(), origin.z());
+        if !self.has_chunks_at(x - 10, z - 10, x + 10, z + 10) {
+            return;
+        }
+        if self
+            .biome_at(origin)
+            .is_none_or(|biome| biome.has_tag(&BiomeTag::WITHOUT_PATROL_SPAWNS))
+        {
+            return;
+        }
+
+        let group_size = self
+            .current_difficulty_at(origin)
+            .effective_difficulty()
+            .ceil() as i32
+            + 1;
+        for member in 0..group_size {
+            let Some(y) = self.height_at(HeightmapType::MotionBlockingNoLeaves, x, z) else {
+                break;
+            };
+            let pos = BlockPos::new(x, y, z);
+            let leader = member == 0;
+            if !self.spawn_patrol_member(pos, leader) && leader {
+                break;
+            }
+            x += rand::random_range(0..5) - rand::random_range(0..5);
+            z += rand::random_range(0..5) - rand::random_range(0..5);
+        }
+    }
+
+    /// Vanilla `PatrolSpawner.spawnPatrolMember`.
+    fn spawn_patrol_member(self: &Arc<Self>, pos: BlockPos, _leader: bool) -> bool {
+        let entity_type = &vanilla_entities::PILLAGER;
+        if !is_valid_empty_spawn_block(self, pos, entity_type)
+            || self.light_value_at(LightLayer::Block, pos) > MAX_PATROL_BLOCK_LIGHT
+            || !is_valid_spawn(self.get_block_state(pos.below()), pos.below(), entity_type)
+        {
+            return false;
+        }
+
+        // TODO: Make the leader a patrol captain heading for a patrol target
+        // once pillagers have an entity implementation.
+        let Some(entity) = ENTITIES.create(
+            entity_type,
+            next_entity_id(),
+            DVec3::new(f64::from(pos.x()), f64::from(pos.y()), f64::from(pos.z())),
+            Arc::downgrade(self),
+        ) else {
+            return false;
+        };
+        if let Some(mob) = entity.as_mob() {
+            mob.finalize_spawn(self, EntitySpawnReason::Patrol, None);
+        }
+        self.try_add_entity(entity).is_ok()
+    }
+}
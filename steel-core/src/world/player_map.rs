@@ -138,6 +138,20 @@ impl PlayerMap {
         self.by_entity_id.read_sync(&entity_id, |_, p| p.clone())
     }
 
+    /// Returns all players in insertion order, or `None` if the order is locked.
+    ///
+    /// Unlike [`Self::iter_players`] this never waits, so it is safe in crash reports.
+    #[must_use]
+    pub fn try_players(&self) -> Option<Vec<Arc<Player>>> {
+        let order = self.order.try_lock()?.clone();
+        Some(
+            order
+                .iter()
+                .filter_map(|uuid| self.get_by_uuid(uuid))
+                .collect(),
+        )
+    }
+
     /// Iterates over all players.
     ///
     /// The callback returns `true` to continue iteration, `false` to stop.
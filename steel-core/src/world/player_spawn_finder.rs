@@ -10,7 +10,7 @@ use tokio::time::sleep;
 use crate::behavior::BlockCollisionContext;
 use crate::chunk::chunk_access::ChunkStatus;
 use crate::chunk::chunk_request::{ChunkRequestHandle, ChunkRequestState, ChunkTicketKind};
-use crate::fluid::get_fluid_state;
+use crate::entity::is_block_dangerous;
 use crate::physics::{CollisionWorld as _, WorldCollisionProvider};
 use crate::world::World;
 
@@ -136,7 +136,9 @@ impl PlayerSpawnSearch {
                             }
                             continue;
                         };
-                        if world.no_collision_no_liquid(spawn_pos) {
+                        if world.no_collision_no_liquid(spawn_pos)
+                            && !world.is_dangerous_spawn(spawn_pos)
+                        {
                             return PlayerSpawnSearchPoll::Ready(block_bottom_center(spawn_pos));
                         }
                         if ready_candidates_checked >= ready_candidate_budget {
@@ -286,29 +288,16 @@ impl World {
         !collision_world.has_entity_collision(&aabb)
             && !collision_world
                 .has_block_collision_with_context(&aabb, BlockCollisionContext::empty())
-            && !aabb_contains_any_liquid(self, aabb)
+            && !self.contains_any_liquid(&aabb)
     }
-}
 
-fn aabb_contains_any_liquid(world: &Arc<World>, aabb: WorldAabb) -> bool {
-    let min_x = aabb.min_x().floor() as i32;
-    let max_x = aabb.max_x().ceil() as i32;
-    let min_y = aabb.min_y().floor() as i32;
-    let max_y = aabb.max_y().ceil() as i32;
-    let min_z = aabb.min_z().floor() as i32;
-    let max_z = aabb.max_z().ceil() as i32;
-
-    for x in min_x..max_x {
-        for y in min_y..max_y {
-            for z in min_z..max_z {
-                if !get_fluid_state(world, BlockPos::new(x, y, z)).is_empty() {
-                    return true;
-                }
-            }
-        }
+    /// Whether a player standing at `pos` would touch a block that hurts them,
+    /// such as magma or fire underfoot or a berry bush around their legs.
+    fn is_dangerous_spawn(&self, pos: BlockPos) -> bool {
+        [pos.below(), pos, pos.above()]
+            .into_iter()
+            .any(|pos| is_block_dangerous(&vanilla_entities::PLAYER, self.get_block_state(pos)))
     }
-
-    false
 }
 
 const fn get_coprime(possible_origins: i32) -> i32 {
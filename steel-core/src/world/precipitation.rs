@@ -0,0 +1,152 @@
+//! Snow accumulation and water freezing in ticking chunks.
+
+use std::sync::Arc;
+
+use steel_registry::biome::BiomeRef;
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::BlockStateProperties;
+use steel_registry::vanilla_blocks;
+use steel_registry::vanilla_game_rules::MAX_SNOW_ACCUMULATION_HEIGHT;
+use steel_utils::types::UpdateFlags;
+use steel_utils::{BlockPos, ChunkPos};
+
+use super::World;
+use crate::behavior::BLOCK_BEHAVIORS;
+use crate::chunk::heightmap::HeightmapType;
+use crate::chunk::light::LightLayer;
+use crate::fluid::state::get_fluid_state_from_block;
+
+/// One in this many random tick rounds runs a precipitation pass for the chunk.
+const PRECIPITATION_CHANCE: u32 = 48;
+
+/// Block light at or above which water no longer freezes and snow no longer settles.
+const PRECIPITATION_MAX_BLOCK_LIGHT: u8 = 10;
+
+/// Biome temperature from which precipitation falls as rain instead of snow.
+const RAIN_MIN_TEMPERATURE: f32 = 0.15;
+
+/// Layer count of a full snow block.
+const MAX_SNOW_LAYERS: u8 = 8;
+
+impl World {
+    /// Vanilla `ServerLevel.tickChunk` precipitation rolls: one chance per random
+    /// tick round to freeze or snow on a random column of `chunk`.
+    pub(crate) fn tick_ice_and_snow(self: &Arc<Self>, chunk: ChunkPos, random_tick_speed: u32) {
+        for _ in 0..random_tick_speed {
+            if rand::random_range(0..PRECIPITATION_CHANCE) != 0 {
+                continue;
+            }
+
+            let random_pos = BlockPos::new(
+                chunk.0.x * 16 + rand::random_range(0..16),
+                0,
+                chunk.0.y * 16 + rand::random_range(0..16),
+            );
+            self.tick_precipitation(random_pos);
+        }
+    }
+
+    /// Vanilla `ServerLevel.tickPrecipitation`.
+    ///
+    /// Freezes exposed water just below the motion-blocking surface and, while it
+    /// rains, piles snow layers on top of it up to `max_snow_accumulation_height`.
+    fn tick_precipitation(self: &Arc<Self>, pos: BlockPos) {
+        let top = self.heightmap_pos(HeightmapType::MotionBlocking, pos);
+        let below = top.below();
+        let Some(biome) = self.biome_at(top) else {
+            return;
+        };
+
+        if self.should_freeze(biome, below) {
+            self.set_block(
+                below,
+                vanilla_blocks::ICE.default_state(),
+                UpdateFlags::UPDATE_ALL,
+            );
+        }
+
+        if !self.is_raining() {
+            return;
+        }
+
+        let max_height = self.get_game_rule(&MAX_SNOW_ACCUMULATION_HEIGHT);
+        if max_height > 0 && self.should_snow(biome, top) {
+            let state = self.get_block_state(top);
+            if state.get_block() == &vanilla_blocks::SNOW {
+                let layers = state.get_value(&BlockStateProperties::LAYERS);
+                let max_layers = u8::try_from(max_height)
+                    .unwrap_or(MAX_SNOW_LAYERS)
+                    .min(MAX_SNOW_LAYERS);
+                if layers < max_layers {
+                    let snow = state.set_value(&BlockStateProperties::LAYERS, layers + 1);
+                    // TODO: Push entities standing in the snow up once
+                    // `Block.pushEntitiesUp` exists.
+                    self.set_block(top, snow, UpdateFlags::UPDATE_ALL);
+                }
+            } else {
+                self.set_block(
+                    top,
+                    vanilla_blocks::SNOW.default_state(),
+                    UpdateFlags::UPDATE_ALL,
+                );
+            }
+        }
+
+        // TODO: Let the block below handle precipitation (cauldrons filling with
+        // water or powder snow) once blocks have a precipitation hook.
+    }
+
+    /// Vanilla `Biome.warmEnoughToRain`.
+    fn warm_enough_to_rain(&self, biome: BiomeRef, pos: BlockPos) -> bool {
+        self.biome_temperature(biome, pos) >= RAIN_MIN_TEMPERATURE
+    }
+
+    /// Vanilla `Biome.shouldFreeze`: source water in a cold biome away from bright
+    /// block light turns into ice unless water surrounds it on all four sides.
+    fn should_freeze(&self, biome: BiomeRef, pos: BlockPos) -> bool {
+        if self.warm_enough_to_rain(biome, pos)
+            || self.is_outside_build_height(pos.y())
+            || self.light_value_at(LightLayer::Block, pos) >= PRECIPITATION_MAX_BLOCK_LIGHT
+        {
+            return false;
+        }
+
+        let state = self.get_block_state(pos);
+        if state.get_block() != &vanilla_blocks::WATER
+            || !get_fluid_state_from_block(state).is_water()
+        {
+            return false;
+        }
+
+        !(self.is_water_at(pos.west())
+            && self.is_water_at(pos.east())
+            && self.is_water_at(pos.north())
+            && self.is_water_at(pos.south()))
+    }
+
+    fn is_water_at(&self, pos: BlockPos) -> bool {
+        get_fluid_state_from_block(self.get_block_state(pos)).is_water()
+    }
+
+    /// Vanilla `Biome.shouldSnow`: snow settles on air or existing snow in a cold,
+    /// precipitating biome wherever a snow layer could survive.
+    fn should_snow(&self, biome: BiomeRef, pos: BlockPos) -> bool {
+        if !biome.has_precipitation
+            || self.warm_enough_to_rain(biome, pos)
+            || self.is_outside_build_height(pos.y())
+            || self.light_value_at(LightLayer::Block, pos) >= PRECIPITATION_MAX_BLOCK_LIGHT
+        {
+            return false;
+        }
+
+        let state = self.get_block_state(pos);
+        if !state.is_air() && state.get_block() != &vanilla_blocks::SNOW {
+            return false;
+        }
+
+        let snow = vanilla_blocks::SNOW.default_state();
+        BLOCK_BEHAVIORS
+            .get_behavior(snow.get_block())
+            .can_survive(snow, self.as_ref(), pos)
+    }
+}
@@ -0,0 +1,792 @@
+//! A single village raid: waves of raiders, the raid boss bar and the
+//! hero-of-the-village reward.
+
+use std::f32::consts::PI;
+use std::sync::Arc;
+
+use glam::DVec3;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use steel_protocol::packets::game::{BossBarColor, BossBarOverlay, CSound, SoundSource};
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::entity_type::EntityTypeRef;
+use steel_registry::stat::{CustomStat, Stat};
+use steel_registry::{sound_events, vanilla_blocks, vanilla_entities, vanilla_mob_effects};
+use steel_utils::types::Difficulty;
+use steel_utils::{BlockPos, SectionPos, translations};
+use text_components::TextComponent;
+use uuid::Uuid;
+
+use super::World;
+use super::boss_event::ServerBossEvent;
+use crate::chunk::heightmap::HeightmapType;
+use crate::entity::{
+    ENTITIES, Entity as _, EntitySpawnReason, LivingEntity as _, MobEffectInstance, SharedEntity,
+    SpawnPlacementType, next_entity_id,
+};
+use crate::player::{CriteriaTrigger, Player};
+
+/// Vanilla `Raid.DEFAULT_MAX_RAID_OMEN_LEVEL`.
+pub const MAX_RAID_OMEN_LEVEL: i32 = 5;
+/// Ticks before the first wave, and between waves.
+const DEFAULT_PRE_RAID_TICKS: i32 = 300;
+/// Ticks a raid runs before it is abandoned.
+const RAID_TIMEOUT_TICKS: i64 = 48_000;
+/// Ticks between the last raider dying and the raid being won.
+const POST_RAID_TICK_LIMIT: i32 = 40;
+/// Ticks the victory or defeat bar stays up.
+const MAX_CELEBRATION_TICKS: i32 = 600;
+/// Squared distance past which a raider no longer counts for the raid.
+const VALID_RAID_RADIUS_SQR: i64 = 12_544;
+/// Raider count at or below which the bar shows the remaining count.
+const LOW_MOB_THRESHOLD: usize = 2;
+/// Failed wave spawns tolerated in one tick before the raid stops.
+const MAX_WAVE_SPAWN_FAILURES: i32 = 5;
+/// Duration of the hero-of-the-village reward.
+const HERO_OF_THE_VILLAGE_DURATION: i32 = 48_000;
+/// Horizontal distance at which players hear the raid horn.
+const RAID_HORN_RANGE: f64 = 64.0;
+
+/// Vanilla `Raid.RaiderType`: how many of each raider join each wave.
+struct RaiderType {
+    entity_type: EntityTypeRef,
+    spawns_per_wave_before_bonus: [i32; 8],
+}
+
+static RAIDER_TYPES: [RaiderType; 5] = [
+    RaiderType {
+        entity_type: &vanilla_entities::VINDICATOR,
+        spawns_per_wave_before_bonus: [0, 0, 2, 0, 1, 4, 2, 5],
+    },
+    RaiderType {
+        entity_type: &vanilla_entities::EVOKER,
+        spawns_per_wave_before_bonus: [0, 0, 0, 0, 0, 1, 1, 2],
+    },
+    RaiderType {
+        entity_type: &vanilla_entities::PILLAGER,
+        spawns_per_wave_before_bonus: [0, 4, 3, 3, 4, 4, 4, 2],
+    },
+    RaiderType {
+        entity_type: &vanilla_entities::WITCH,
+        spawns_per_wave_before_bonus: [0, 0, 0, 0, 3, 0, 0, 1],
+    },
+    RaiderType {
+        entity_type: &vanilla_entities::RAVAGER,
+        spawns_per_wave_before_bonus: [0, 0, 0, 1, 0, 1, 0, 2],
+    },
+];
+
+/// Vanilla `Raid.RaidStatus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RaidStatus {
+    /// Waves are still coming.
+    #[default]
+    Ongoing,
+    /// Every wave was defeated.
+    Victory,
+    /// The village was lost.
+    Loss,
+    /// The raid ended and is about to be removed.
+    Stopped,
+}
+
+/// A raider that joined a wave of a raid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct RaidMember {
+    uuid: Uuid,
+    wave: i32,
+}
+
+/// Squared distance between two block positions, vanilla `Vec3i.distSqr`.
+pub(super) fn dist_sqr(a: BlockPos, b: BlockPos) -> i64 {
+    let dx = i64::from(a.x()) - i64::from(b.x());
+    let dy = i64::from(a.y()) - i64::from(b.y());
+    let dz = i64::from(a.z()) - i64::from(b.z());
+    dx * dx + dy * dy + dz * dz
+}
+
+fn new_raid_event() -> ServerBossEvent {
+    let mut event = ServerBossEvent::new(raid_name(), BossBarColor::Red, BossBarOverlay::Notched10);
+    event.set_progress(0.0);
+    event
+}
+
+fn raid_name() -> TextComponent {
+    TextComponent::translated(translations::EVENT_MINECRAFT_RAID.msg())
+}
+
+/// Vanilla `Raid`.
+#[derive(Serialize, Deserialize)]
+pub struct Raid {
+    id: i32,
+    center_x: i32,
+    center_y: i32,
+    center_z: i32,
+    started: bool,
+    active: bool,
+    ticks_active: i64,
+    raid_omen_level: i32,
+    groups_spawned: i32,
+    pre_raid_ticks: i32,
+    post_raid_ticks: i32,
+    celebration_ticks: i32,
+    total_health: f32,
+    num_groups: i32,
+    status: RaidStatus,
+    #[serde(default)]
+    heroes_of_the_village: FxHashSet<Uuid>,
+    #[serde(default)]
+    raiders: Vec<RaidMember>,
+    #[serde(skip)]
+    wave_spawn_pos: Option<BlockPos>,
+    #[serde(skip, default = "new_raid_event")]
+    raid_event: ServerBossEvent,
+}
+
+impl Raid {
+    /// Creates a raid around `center` that has not spawned any wave yet.
+    #[must_use]
+    pub fn new(id: i32, center: BlockPos, difficulty: Difficulty) -> Self {
+        Self {
+            id,
+            center_x: center.x(),
+            center_y: center.y(),
+            center_z: center.z(),
+            started: false,
+            active: true,
+            ticks_active: 0,
+            raid_omen_level: 0,
+            groups_spawned: 0,
+            pre_raid_ticks: DEFAULT_PRE_RAID_TICKS,
+            post_raid_ticks: 0,
+            celebration_ticks: 0,
+            total_health: 0.0,
+            num_groups: Self::num_groups(difficulty),
+            status: RaidStatus::Ongoing,
+            heroes_of_the_village: FxHashSet::default(),
+            raiders: Vec::new(),
+            wave_spawn_pos: None,
+            raid_event: new_raid_event(),
+        }
+    }
+
+    /// Returns the id of this raid within its world.
+    #[must_use]
+    pub const fn id(&self) -> i32 {
+        self.id
+    }
+
+    /// Returns the block the raid is centered on.
+    #[must_use]
+    pub const fn center(&self) -> BlockPos {
+        BlockPos::new(self.center_x, self.center_y, self.center_z)
+    }
+
+    fn set_center(&mut self, center: BlockPos) {
+        self.center_x = center.x();
+        self.center_y = center.y();
+        self.center_z = center.z();
+    }
+
+    /// Returns whether the first wave has spawned.
+    #[must_use]
+    pub const fn is_started(&self) -> bool {
+        self.started
+    }
+
+    /// Returns whether the raid's center chunk is loaded.
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Returns whether the raid ended and will be removed.
+    #[must_use]
+    pub fn is_stopped(&self) -> bool {
+        self.status == RaidStatus::Stopped
+    }
+
+    /// Returns whether the raid was won or lost.
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        self.is_victory() || self.is_loss()
+    }
+
+    /// Returns whether the village defended itself.
+    #[must_use]
+    pub fn is_victory(&self) -> bool {
+        self.status == RaidStatus::Victory
+    }
+
+    /// Returns whether the raiders won.
+    #[must_use]
+    pub fn is_loss(&self) -> bool {
+        self.status == RaidStatus::Loss
+    }
+
+    /// Returns the combined raid omen level absorbed so far.
+    #[must_use]
+    pub const fn raid_omen_level(&self) -> i32 {
+        self.raid_omen_level
+    }
+
+    /// Returns the number of regular waves for `difficulty`.
+    #[must_use]
+    pub const fn num_groups(difficulty: Difficulty) -> i32 {
+        match difficulty {
+            Difficulty::Peaceful => 0,
+            Difficulty::Easy => 3,
+            Difficulty::Normal => 5,
+            Difficulty::Hard => 7,
+        }
+    }
+
+    fn has_first_wave_spawned(&self) -> bool {
+        self.groups_spawned > 0
+    }
+
+    fn is_final_wave(&self) -> bool {
+        self.groups_spawned == self.num_groups
+    }
+
+    fn has_bonus_wave(&self) -> bool {
+        self.raid_omen_level > 1
+    }
+
+    fn has_spawned_bonus_wave(&self) -> bool {
+        self.groups_spawned > self.num_groups
+    }
+
+    fn has_more_waves(&self) -> bool {
+        if self.has_bonus_wave() {
+            !self.has_spawned_bonus_wave()
+        } else {
+            !self.is_final_wave()
+        }
+    }
+
+    fn should_spawn_bonus_group(&self) -> bool {
+        self.is_final_wave() && self.raiders.is_empty() && self.has_bonus_wave()
+    }
+
+    fn should_spawn_group(&self) -> bool {
+        self.pre_raid_ticks == 0
+            && (self.groups_spawned < self.num_groups || self.should_spawn_bonus_group())
+            && self.raiders.is_empty()
+    }
+
+    /// Ends the raid and hides its boss bar.
+    pub fn stop(&mut self) {
+        self.active = false;
+        self.raid_event.remove_all_players();
+        self.status = RaidStatus::Stopped;
+    }
+
+    /// Raises the omen level by the player's `RAID_OMEN` amplifier plus one.
+    ///
+    /// Returns whether the player had raid omen.
+    pub fn absorb_raid_omen(&mut self, player: &Player) -> bool {
+        let Some(effect) = player.mob_effect(vanilla_mob_effects::RAID_OMEN) else {
+            return false;
+        };
+        self.raid_omen_level =
+            (self.raid_omen_level + effect.amplifier() + 1).clamp(0, MAX_RAID_OMEN_LEVEL);
+        if !self.has_first_wave_spawned() {
+            player.award_stat(Stat::custom(CustomStat::RaidTrigger));
+            player.trigger_criteria(CriteriaTrigger::RaidOmen);
+        }
+        true
+    }
+
+    /// Records a player that killed a raider.
+    pub fn add_hero_of_the_village(&mut self, player: &Player) {
+        self.heroes_of_the_village.insert(player.uuid());
+    }
+
+    /// Returns whether `uuid` is a raider of this raid.
+    #[must_use]
+    pub fn has_raider(&self, uuid: Uuid) -> bool {
+        self.raiders.iter().any(|member| member.uuid == uuid)
+    }
+
+    /// Drops a raider from the raid, for example when it died.
+    pub fn remove_raider(&mut self, world: &World, uuid: Uuid) {
+        self.raiders.retain(|member| member.uuid != uuid);
+        self.update_bossbar(world);
+    }
+
+    /// Runs one tick of vanilla `Raid.tick`.
+    ///
+    /// `raid_at` returns the id of the raid vanilla `ServerLevel.getRaidAt`
+    /// would pick for a position.
+    pub fn tick(&mut self, world: &Arc<World>, raid_at: &impl Fn(BlockPos) -> Option<i32>) {
+        if self.is_stopped() {
+            return;
+        }
+        if self.status == RaidStatus::Ongoing {
+            self.tick_ongoing(world, raid_at);
+        } else if self.is_over() {
+            self.celebration_ticks += 1;
+            if self.celebration_ticks >= MAX_CELEBRATION_TICKS {
+                self.stop();
+                return;
+            }
+            if self.celebration_ticks % 20 == 0 {
+                self.update_players(world, raid_at);
+                self.raid_event.set_visible(true);
+                if self.is_victory() {
+                    self.raid_event.set_progress(0.0);
+                    self.raid_event.set_name(TextComponent::translated(
+                        translations::EVENT_MINECRAFT_RAID_VICTORY_FULL.msg(),
+                    ));
+                } else {
+                    self.raid_event.set_name(TextComponent::translated(
+                        translations::EVENT_MINECRAFT_RAID_DEFEAT_FULL.msg(),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn tick_ongoing(&mut self, world: &Arc<World>, raid_at: &impl Fn(BlockPos) -> Option<i32>) {
+        let was_active = self.active;
+        self.active = world.is_full_chunk_loaded_at(self.center());
+        if world.difficulty() == Difficulty::Peaceful {
+            self.stop();
+            return;
+        }
+        if was_active != self.active {
+            self.raid_event.set_visible(self.active);
+        }
+        if !self.active {
+            return;
+        }
+
+        if !world.is_village(self.center()) {
+            self.move_raid_center_to_nearby_village_section(world);
+        }
+        if !world.is_village(self.center()) {
+            if self.groups_spawned > 0 {
+                self.status = RaidStatus::Loss;
+            } else {
+                self.stop();
+            }
+        }
+
+        self.ticks_active += 1;
+        if self.ticks_active >= RAID_TIMEOUT_TICKS {
+            self.stop();
+            return;
+        }
+
+        let raiders_alive = self.raiders.len();
+        if raiders_alive == 0 && self.has_more_waves() {
+            if self.pre_raid_ticks <= 0 {
+                if self.pre_raid_ticks == 0 && self.groups_spawned > 0 {
+                    self.pre_raid_ticks = DEFAULT_PRE_RAID_TICKS;
+                    self.raid_event.set_name(raid_name());
+                    return;
+                }
+            } else {
+                let has_spawn_pos = self.wave_spawn_pos.is_some();
+                let mut find_spawn_pos = !has_spawn_pos && self.pre_raid_ticks % 5 == 0;
+                if let Some(pos) = self.wave_spawn_pos
+                    && !world.is_entity_ticking_chunk_loaded(pos)
+                {
+                    find_spawn_pos = true;
+                }
+                if find_spawn_pos {
+                    self.wave_spawn_pos = self.find_random_spawn_pos(world, 8);
+                }
+                if self.pre_raid_ticks == DEFAULT_PRE_RAID_TICKS || self.pre_raid_ticks % 20 == 0 {
+                    self.update_players(world, raid_at);
+                }
+                self.pre_raid_ticks -= 1;
+                self.raid_event.set_progress(
+                    ((DEFAULT_PRE_RAID_TICKS - self.pre_raid_ticks) as f32
+                        / DEFAULT_PRE_RAID_TICKS as f32)
+                        .clamp(0.0, 1.0),
+                );
+            }
+        }
+
+        if self.ticks_active % 20 == 0 {
+            self.update_players(world, raid_at);
+            self.update_raiders(world);
+            let remaining = self.raiders.len();
+            if remaining > 0 && remaining <= LOW_MOB_THRESHOLD {
+                self.raid_event.set_name(raid_name().add_children(vec![
+                        TextComponent::plain(" - "),
+                        translations::EVENT_MINECRAFT_RAID_RAIDERS_REMAINING
+                            .message([TextComponent::from(remaining.to_string())])
+                            .into(),
+                    ]));
+            } else {
+                self.raid_event.set_name(raid_name());
+            }
+        }
+
+        let mut played_sound = false;
+        let mut failures = 0;
+        while self.should_spawn_group() {
+            let spawn_pos = self
+                .wave_spawn_pos
+                .or_else(|| self.find_random_spawn_pos(world, 20));
+            // Vanilla only counts a missing position as a failure; raiders
+            // without an entity implementation fail here as well so the wave
+            // loop cannot run away.
+            if let Some(pos) = spawn_pos
+                && self.spawn_group(world, pos)
+            {
+                self.started = true;
+                if !played_sound {
+                    self.play_sound(world, pos);
+                    played_sound = true;
+                }
+            } else {
+                failures += 1;
+            }
+            if failures > MAX_WAVE_SPAWN_FAILURES {
+                self.stop();
+                break;
+            }
+        }
+
+        if self.is_started() && !self.has_more_waves() && raiders_alive == 0 {
+            if self.post_raid_ticks < POST_RAID_TICK_LIMIT {
+                self.post_raid_ticks += 1;
+            } else {
+                self.status = RaidStatus::Victory;
+                self.reward_heroes(world);
+            }
+        }
+    }
+
+    fn reward_heroes(&self, world: &World) {
+        for uuid in &self.heroes_of_the_village {
+            let Some(player) = world.players.get_by_uuid(uuid) else {
+                // TODO: Reward non-player heroes once other entities can kill raiders.
+                continue;
+            };
+            if player.is_spectator() {
+                continue;
+            }
+            player.add_mob_effect(
+                MobEffectInstance::with_duration(
+                    vanilla_mob_effects::HERO_OF_THE_VILLAGE,
+                    HERO_OF_THE_VILLAGE_DURATION,
+                    self.raid_omen_level - 1,
+                )
+                .with_ambient(false)
+                .with_visible(false)
+                .with_show_icon(true),
+            );
+            player.award_stat(Stat::custom(CustomStat::RaidWin));
+            player.trigger_criteria(CriteriaTrigger::RaidWin);
+        }
+    }
+
+    /// Shows the boss bar to living players inside this raid and hides it from
+    /// everyone else.
+    fn update_players(&mut self, world: &World, raid_at: &impl Fn(BlockPos) -> Option<i32>) {
+        let mut inside = Vec::new();
+        world.players.iter_players(|_, player| {
+            if player.is_alive() && raid_at(player.block_position()) == Some(self.id) {
+                inside.push(Arc::clone(player));
+            }
+            true
+        });
+        for player in self.raid_event.players() {
+            if !inside.iter().any(|other| Arc::ptr_eq(other, &player)) {
+                self.raid_event.remove_player(&player);
+            }
+        }
+        for player in &inside {
+            self.raid_event.add_player(player);
+        }
+    }
+
+    /// Drops raiders that are gone or wandered too far from the center.
+    fn update_raiders(&mut self, world: &World) {
+        let center = self.center();
+        self.raiders.retain(|member| {
+            world
+                .get_entity_by_uuid(&member.uuid)
+                .is_some_and(|raider| {
+                    !raider.is_removed()
+                        && dist_sqr(raider.block_position(), center) < VALID_RAID_RADIUS_SQR
+                })
+        });
+        self.update_bossbar(world);
+    }
+
+    fn health_of_living_raiders(&self, world: &World) -> f32 {
+        self.raiders
+            .iter()
+            .filter_map(|member| world.get_entity_by_uuid(&member.uuid))
+            .filter_map(|raider| raider.as_living_entity().map(|living| living.get_health()))
+            .sum()
+    }
+
+    fn update_bossbar(&mut self, world: &World) {
+        if self.total_health <= 0.0 {
+            return;
+        }
+        let progress = self.health_of_living_raiders(world) / self.total_health;
+        self.raid_event.set_progress(progress.clamp(0.0, 1.0));
+    }
+
+    fn move_raid_center_to_nearby_village_section(&mut self, world: &World) {
+        let center = self.center();
+        let section = SectionPos::from_block_pos(center);
+        let mut best: Option<(BlockPos, i64)> = None;
+        for dx in -2..=2 {
+            for dy in -2..=2 {
+                for dz in -2..=2 {
+                    let candidate = BlockPos::new(
+                        (section.x() + dx) * 16 + 8,
+                        (section.y() + dy) * 16 + 8,
+                        (section.z() + dz) * 16 + 8,
+                    );
+                    if !world.is_village(candidate) {
+                        continue;
+                    }
+                    let distance = dist_sqr(candidate, center);
+                    if best.is_none_or(|(_, best_distance)| distance < best_distance) {
+                        best = Some((candidate, distance));
+                    }
+                }
+            }
+        }
+        if let Some((candidate, _)) = best {
+            self.set_center(candidate);
+        }
+    }
+
+    /// Vanilla `Raid.findRandomSpawnPos`.
+    fn find_random_spawn_pos(&self, world: &World, attempts: i32) -> Option<BlockPos> {
+        let cooldown_seconds = self.pre_raid_ticks / 20;
+        let distance_scale = 0.22 * cooldown_seconds as f32 - 0.24;
+        let start_angle = rand::random::<f32>() * PI * 2.0;
+        let center = self.center();
+        for attempt in 0..attempts {
+            let angle = start_angle + PI * attempt as f32 / 8.0;
+            let x = center.x()
+                + (angle.cos() * 32.0 * distance_scale).floor() as i32
+                + rand::random_range(0..3) * distance_scale.floor() as i32;
+            let z = center.z()
+                + (angle.sin() * 32.0 * distance_scale).floor() as i32
+                + rand::random_range(0..3) * distance_scale.floor() as i32;
+            let Some(y) = world.height_at(HeightmapType::WorldSurface, x, z) else {
+                continue;
+            };
+            if (y - center.y()).abs() > 96 {
+                continue;
+            }
+            let pos = BlockPos::new(x, y, z);
+            if world.is_village(pos) && cooldown_seconds > 7 {
+                continue;
+            }
+            if world.has_chunks_at(x - 10, z - 10, x + 10, z + 10)
+                && world.is_entity_ticking_chunk_loaded(pos)
+                && (SpawnPlacementType::OnGround.is_spawn_position_ok(
+                    world,
+                    pos,
+                    &vanilla_entities::RAVAGER,
+                ) || (world.get_block_state(pos.below()).get_block() == &vanilla_blocks::SNOW
+                    && world.get_block_state(pos).is_air()))
+            {
+                return Some(pos);
+            }
+        }
+        None
+    }
+
+    /// Vanilla `Raid.spawnGroup`. Returns whether any raider joined.
+    fn spawn_group(&mut self, world: &Arc<World>, pos: BlockPos) -> bool {
+        let wave = self.groups_spawned + 1;
+        self.total_health = 0.0;
+        let difficulty = world.current_difficulty_at(pos).difficulty();
+        let bonus = self.should_spawn_bonus_group();
+        let mut joined = false;
+        for raider_type in &RAIDER_TYPES {
+            let count = self.default_num_spawns(raider_type, wave, bonus)
+                + Self::potential_bonus_spawns(raider_type, wave, difficulty, bonus);
+            let mut ravagers = 0;
+            for _ in 0..count {
+                // TODO: Pick a wave leader with an ominous banner once raider
+                // entities exist.
+                let Some(raider) = self.join_raid(world, wave, raider_type.entity_type, pos) else {
+                    break;
+                };
+                joined = true;
+                if raider_type.entity_type != &vanilla_entities::RAVAGER {
+                    continue;
+                }
+                let rider_type = if wave == Self::num_groups(Difficulty::Normal) {
+                    Some(&vanilla_entities::PILLAGER)
+                } else if wave >= Self::num_groups(Difficulty::Hard) {
+                    Some(if ravagers == 0 {
+                        &vanilla_entities::EVOKER
+                    } else {
+                        &vanilla_entities::VINDICATOR
+                    })
+                } else {
+                    None
+                };
+                ravagers += 1;
+                if let Some(rider_type) = rider_type
+                    && let Some(rider) = self.join_raid(world, wave, rider_type, pos)
+                {
+                    rider.start_riding(&raider);
+                }
+            }
+        }
+        self.wave_spawn_pos = None;
+        self.groups_spawned += 1;
+        self.update_bossbar(world);
+        joined
+    }
+
+    /// Spawns one raider for `wave` and tracks it.
+    fn join_raid(
+        &mut self,
+        world: &Arc<World>,
+        wave: i32,
+        entity_type: EntityTypeRef,
+        pos: BlockPos,
+    ) -> Option<SharedEntity> {
+        let spawn_pos = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 1.0,
+            f64::from(pos.z()) + 0.5,
+        );
+        let raider = ENTITIES.create(
+            entity_type,
+            next_entity_id(),
+            spawn_pos,
+            Arc::downgrade(world),
+        )?;
+        if let Some(mob) = raider.as_mob() {
+            mob.finalize_spawn(world, EntitySpawnReason::Event, None);
+        }
+        // TODO: Apply vanilla `Raider.applyRaidBuffs` once raider entities exist.
+        raider.set_on_ground(true);
+        world.try_add_entity(Arc::clone(&raider)).ok()?;
+        if let Some(living) = raider.as_living_entity() {
+            self.total_health += living.get_health();
+        }
+        self.raiders.push(RaidMember {
+            uuid: raider.uuid(),
+            wave,
+        });
+        Some(raider)
+    }
+
+    fn default_num_spawns(&self, raider_type: &RaiderType, wave: i32, bonus: bool) -> i32 {
+        let index = if bonus { self.num_groups } else { wave };
+        raider_type
+            .spawns_per_wave_before_bonus
+            .get(index as usize)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn potential_bonus_spawns(
+        raider_type: &RaiderType,
+        wave: i32,
+        difficulty: Difficulty,
+        bonus: bool,
+    ) -> i32 {
+        let easy = difficulty == Difficulty::Easy;
+        let normal = difficulty == Difficulty::Normal;
+        let entity_type = raider_type.entity_type;
+        let max = if entity_type == &vanilla_entities::VINDICATOR
+            || entity_type == &vanilla_entities::PILLAGER
+        {
+            if easy {
+                rand::random_range(0..2)
+            } else if normal {
+                1
+            } else {
+                2
+            }
+        } else if entity_type == &vanilla_entities::WITCH {
+            if easy || wave <= 2 || wave == 4 {
+                return 0;
+            }
+            1
+        } else if entity_type == &vanilla_entities::RAVAGER {
+            i32::from(!easy && bonus)
+        } else {
+            return 0;
+        };
+        if max > 0 {
+            rand::random_range(0..=max)
+        } else {
+            0
+        }
+    }
+
+    /// Plays the raid horn toward the wave for nearby players.
+    fn play_sound(&self, world: &World, pos: BlockPos) {
+        let seed = rand::random::<i64>();
+        let target = DVec3::new(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 0.5,
+            f64::from(pos.z()) + 0.5,
+        );
+        world.players.iter_players(|_, player| {
+            let position = player.position();
+            let distance =
+                ((target.x - position.x).powi(2) + (target.z - position.z).powi(2)).sqrt();
+            if distance <= RAID_HORN_RANGE || self.raid_event.has_player(player) {
+                let x = position.x + 13.0 / distance * (target.x - position.x);
+                let z = position.z + 13.0 / distance * (target.z - position.z);
+                player.send_packet(CSound::new(
+                    &sound_events::EVENT_RAID_HORN,
+                    SoundSource::Neutral,
+                    DVec3::new(x, position.y, z),
+                    64.0,
+                    1.0,
+                    seed,
+                ));
+            }
+            true
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bonus_wave_needs_raid_omen_above_one() {
+        let mut raid = Raid::new(1, BlockPos::new(0, 64, 0), Difficulty::Easy);
+        raid.groups_spawned = 3;
+        assert!(!raid.has_more_waves());
+
+        raid.raid_omen_level = 2;
+        assert!(raid.has_more_waves());
+        assert!(raid.should_spawn_bonus_group());
+        raid.groups_spawned = 4;
+        assert!(!raid.has_more_waves());
+    }
+
+    #[test]
+    fn raid_round_trips_through_toml() {
+        let mut raid = Raid::new(7, BlockPos::new(10, 70, -5), Difficulty::Hard);
+        raid.raid_omen_level = 3;
+        raid.heroes_of_the_village.insert(Uuid::new_v4());
+
+        let encoded = toml::to_string(&raid).expect("raid should serialize");
+        let decoded: Raid = toml::from_str(&encoded).expect("raid should deserialize");
+        assert_eq!(decoded.id(), 7);
+        assert_eq!(decoded.center(), BlockPos::new(10, 70, -5));
+        assert_eq!(decoded.num_groups, 7);
+        assert_eq!(decoded.raid_omen_level(), 3);
+        assert_eq!(decoded.heroes_of_the_village, raid.heroes_of_the_village);
+    }
+}
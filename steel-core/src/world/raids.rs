@@ -0,0 +1,222 @@
+//! Per-world raid bookkeeping and village detection.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use steel_registry::vanilla_game_rules::RAIDS;
+use steel_registry::vanilla_poi_type_tags::PoiTag;
+use steel_registry::{REGISTRY, RegistryExt as _, TaggedRegistryExt as _};
+use steel_utils::{BlockPos, SectionPos};
+
+use super::World;
+use super::raid::{MAX_RAID_OMEN_LEVEL, Raid, dist_sqr};
+use crate::entity::Entity as _;
+use crate::player::Player;
+use crate::poi::OccupationStatus;
+
+/// Squared distance within which a position belongs to a raid.
+const RAID_SEARCH_DISTANCE_SQR: i64 = 9216;
+/// Radius of the village POI search that places a new raid's center.
+const VILLAGE_SEARCH_RADIUS: i32 = 64;
+/// Vanilla `PoiManager.MAX_VILLAGE_DISTANCE`, in sections.
+const MAX_VILLAGE_DISTANCE: i32 = 6;
+
+/// Vanilla `Raids`, persisted as the world's `raids` saved data.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Raids {
+    next_available_id: i32,
+    tick: i32,
+    raids: Vec<Raid>,
+}
+
+impl Raids {
+    fn unique_id(&mut self) -> i32 {
+        self.next_available_id += 1;
+        self.next_available_id
+    }
+
+    /// Ticks every raid and drops the ones that stopped.
+    pub fn tick(&mut self, world: &Arc<World>) {
+        self.tick += 1;
+        let raids_enabled = world.get_game_rule(&RAIDS);
+        let centers: Vec<_> = self
+            .raids
+            .iter()
+            .filter(|raid| raid.is_active())
+            .map(|raid| (raid.id(), raid.center()))
+            .collect();
+        let raid_at = |pos: BlockPos| {
+            centers
+                .iter()
+                .filter(|(_, center)| dist_sqr(*center, pos) < RAID_SEARCH_DISTANCE_SQR)
+                .min_by_key(|(_, center)| dist_sqr(*center, pos))
+                .map(|(id, _)| *id)
+        };
+        for raid in &mut self.raids {
+            if !raids_enabled {
+                raid.stop();
+            }
+            raid.tick(world, &raid_at);
+        }
+        self.raids.retain(|raid| !raid.is_stopped());
+    }
+
+    /// Returns the closest active raid within `max_distance_sqr` of `pos`.
+    #[must_use]
+    pub fn get_nearby_raid(&self, pos: BlockPos, max_distance_sqr: i64) -> Option<&Raid> {
+        self.nearby_raid_index(pos, max_distance_sqr)
+            .map(|index| &self.raids[index])
+    }
+
+    fn nearby_raid_index(&self, pos: BlockPos, max_distance_sqr: i64) -> Option<usize> {
+        self.raids
+            .iter()
+            .enumerate()
+            .filter(|(_, raid)| raid.is_active())
+            .map(|(index, raid)| (index, dist_sqr(raid.center(), pos)))
+            .filter(|(_, distance)| *distance < max_distance_sqr)
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(index, _)| index)
+    }
+
+    /// Starts a raid at the village around `pos`, or feeds the player's raid
+    /// omen into the raid already there.
+    ///
+    /// Vanilla `Raids.createOrExtendRaid`.
+    pub fn create_or_extend_raid(&mut self, world: &World, player: &Player, pos: BlockPos) {
+        if player.is_spectator()
+            || !world.get_game_rule(&RAIDS)
+            || !world.dimension_type.can_start_raid
+        {
+            return;
+        }
+
+        let village = world.poi_storage.lock().get_in_circle(
+            &is_village_poi,
+            pos,
+            VILLAGE_SEARCH_RADIUS,
+            OccupationStatus::Occupied,
+        );
+        let center = if village.is_empty() {
+            pos
+        } else {
+            let count = village.len() as f64;
+            let (x, y, z) = village.iter().fold((0.0, 0.0, 0.0), |(x, y, z), (poi, _)| {
+                (
+                    x + f64::from(poi.x()),
+                    y + f64::from(poi.y()),
+                    z + f64::from(poi.z()),
+                )
+            });
+            BlockPos::new(
+                (x / count).floor() as i32,
+                (y / count).floor() as i32,
+                (z / count).floor() as i32,
+            )
+        };
+
+        let index = if let Some(index) = self.nearby_raid_index(center, RAID_SEARCH_DISTANCE_SQR) {
+            index
+        } else {
+            let id = self.unique_id();
+            self.raids.push(Raid::new(id, center, world.difficulty()));
+            self.raids.len() - 1
+        };
+        let raid = &mut self.raids[index];
+        if !raid.is_started() || raid.raid_omen_level() < MAX_RAID_OMEN_LEVEL {
+            raid.absorb_raid_omen(player);
+        }
+    }
+
+    /// Credits `hero` for a raider kill and drops the raider from its raid.
+    pub fn on_raider_death(&mut self, world: &World, raider: uuid::Uuid, hero: Option<&Player>) {
+        let Some(raid) = self.raids.iter_mut().find(|raid| raid.has_raider(raider)) else {
+            return;
+        };
+        if let Some(hero) = hero {
+            raid.add_hero_of_the_village(hero);
+        }
+        raid.remove_raider(world, raider);
+    }
+}
+
+fn is_village_poi(type_id: usize) -> bool {
+    REGISTRY
+        .poi_types
+        .by_id(type_id)
+        .is_some_and(|poi_type| REGISTRY.poi_types.is_in_tag(poi_type, &PoiTag::VILLAGE))
+}
+
+impl World {
+    /// Ticks this world's raids.
+    pub(super) fn tick_raids(self: &Arc<Self>) {
+        self.raids.lock().tick(self);
+    }
+
+    /// Returns the raid vanilla `ServerLevel.getRaidAt` would pick, as its
+    /// omen level.
+    #[must_use]
+    pub fn raid_omen_level_at(&self, pos: BlockPos) -> Option<i32> {
+        self.raids
+            .lock()
+            .get_nearby_raid(pos, RAID_SEARCH_DISTANCE_SQR)
+            .map(Raid::raid_omen_level)
+    }
+
+    /// Returns how many sections away the closest occupied village POI is,
+    /// capped at [`MAX_VILLAGE_DISTANCE`] plus one.
+    ///
+    /// Vanilla `PoiManager.sectionsToVillage`.
+    fn sections_to_village(&self, pos: BlockPos) -> i32 {
+        let section = SectionPos::from_block_pos(pos);
+        let center = BlockPos::new(
+            section.x() * 16 + 8,
+            section.y() * 16 + 8,
+            section.z() * 16 + 8,
+        );
+        self.poi_storage
+            .lock()
+            .get_in_square(
+                &is_village_poi,
+                center,
+                MAX_VILLAGE_DISTANCE * 16 + 8,
+                OccupationStatus::Occupied,
+            )
+            .into_iter()
+            .map(|(poi, _)| {
+                let poi = SectionPos::from_block_pos(poi);
+                (poi.x() - section.x())
+                    .abs()
+                    .max((poi.y() - section.y()).abs())
+                    .max((poi.z() - section.z()).abs())
+            })
+            .min()
+            .unwrap_or(MAX_VILLAGE_DISTANCE + 1)
+    }
+
+    /// Returns vanilla `ServerLevel.isVillage`.
+    #[must_use]
+    pub fn is_village(&self, pos: BlockPos) -> bool {
+        self.is_close_to_village(pos, 1)
+    }
+
+    /// Returns vanilla `ServerLevel.isCloseToVillage`.
+    #[must_use]
+    pub fn is_close_to_village(&self, pos: BlockPos, sections: i32) -> bool {
+        sections <= MAX_VILLAGE_DISTANCE && self.sections_to_village(pos) <= sections
+    }
+
+    /// Returns vanilla `LevelReader.hasChunksAt` for a horizontal block range.
+    pub(crate) fn has_chunks_at(&self, min_x: i32, min_z: i32, max_x: i32, max_z: i32) -> bool {
+        let min_chunk_x = SectionPos::block_to_section_coord(min_x);
+        let max_chunk_x = SectionPos::block_to_section_coord(max_x);
+        let min_chunk_z = SectionPos::block_to_section_coord(min_z);
+        let max_chunk_z = SectionPos::block_to_section_coord(max_z);
+        (min_chunk_x..=max_chunk_x).all(|chunk_x| {
+            (min_chunk_z..=max_chunk_z).all(|chunk_z| {
+                self.is_full_chunk_loaded_at(BlockPos::new(chunk_x * 16, 0, chunk_z * 16))
+            })
+        })
+    }
+}
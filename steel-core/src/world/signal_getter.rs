@@ -0,0 +1,268 @@
+//! Vanilla `SignalGetter`: redstone signal queries over a [`LevelReader`].
+//!
+//! Steel has no redstone power graph yet, so signals are read straight from the
+//! block states of power sources: redstone blocks, levers, buttons, pressure
+//! plates, detector rails and redstone torches. Redstone wire, repeaters and
+//! comparators do not emit a signal yet.
+
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::blocks::properties::{AttachFace, BlockStateProperties, Direction};
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::vanilla_blocks;
+use steel_utils::{BlockPos, BlockStateId};
+
+use crate::world::{LevelReader, World};
+
+/// Strongest redstone signal.
+const MAX_SIGNAL: i32 = 15;
+
+/// Redstone signal queries, available on every [`LevelReader`].
+pub trait SignalGetter: LevelReader {
+    /// Vanilla `SignalGetter.getDirectSignal`: the strong signal the block at
+    /// `pos` sends into its neighbor on the opposite side of `direction`.
+    fn get_direct_signal(&self, pos: BlockPos, direction: Direction) -> i32 {
+        direct_signal(self.get_block_state(pos), direction)
+    }
+
+    /// Vanilla `SignalGetter.getDirectSignalTo`: the strongest signal any
+    /// neighbor sends directly into `pos`.
+    fn get_direct_signal_to(&self, pos: BlockPos) -> i32 {
+        Direction::ALL
+            .iter()
+            .map(|&direction| self.get_direct_signal(pos.relative(direction), direction))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Vanilla `SignalGetter.getSignal`: the signal the block at `pos` emits
+    /// towards `direction`'s opposite side. Conductors pass on the strong
+    /// signal they receive.
+    fn get_signal(&self, pos: BlockPos, direction: Direction) -> i32 {
+        let state = self.get_block_state(pos);
+        let signal = signal(state, direction);
+        if World::is_redstone_conductor(state, pos) {
+            signal.max(self.get_direct_signal_to(pos))
+        } else {
+            signal
+        }
+    }
+
+    /// Vanilla `SignalGetter.hasSignal`.
+    fn has_signal(&self, pos: BlockPos, direction: Direction) -> bool {
+        self.get_signal(pos, direction) > 0
+    }
+
+    /// Vanilla `SignalGetter.hasNeighborSignal`: whether any neighbor of `pos`
+    /// powers it.
+    fn has_neighbor_signal(&self, pos: BlockPos) -> bool {
+        Direction::ALL
+            .iter()
+            .any(|&direction| self.has_signal(pos.relative(direction), direction))
+    }
+}
+
+impl<T: LevelReader + ?Sized> SignalGetter for T {}
+
+/// Vanilla `BlockState.getSignal` for the power sources Steel knows about.
+///
+/// `direction` points from the queried neighbor towards `state`.
+fn signal(state: BlockStateId, direction: Direction) -> i32 {
+    let block = state.get_block();
+    if block == &vanilla_blocks::REDSTONE_BLOCK {
+        return MAX_SIGNAL;
+    }
+    if block == &vanilla_blocks::LEVER
+        || block == &vanilla_blocks::DETECTOR_RAIL
+        || block.has_tag(&BlockTag::BUTTONS)
+    {
+        return powered_signal(state);
+    }
+    if block.has_tag(&BlockTag::PRESSURE_PLATES) {
+        return state
+            .try_get_value(&BlockStateProperties::POWER)
+            .map_or_else(|| powered_signal(state), i32::from);
+    }
+    if block == &vanilla_blocks::REDSTONE_TORCH {
+        return lit_signal(state, direction != Direction::Up);
+    }
+    if block == &vanilla_blocks::REDSTONE_WALL_TORCH {
+        let facing: Direction = state.get_value(&BlockStateProperties::HORIZONTAL_FACING);
+        return lit_signal(state, facing != direction);
+    }
+    0
+}
+
+/// Vanilla `BlockState.getDirectSignal` for the power sources Steel knows about.
+///
+/// `direction` points from the queried neighbor towards `state`.
+fn direct_signal(state: BlockStateId, direction: Direction) -> i32 {
+    let block = state.get_block();
+    if block == &vanilla_blocks::LEVER || block.has_tag(&BlockTag::BUTTONS) {
+        return if connected_direction(state) == direction {
+            powered_signal(state)
+        } else {
+            0
+        };
+    }
+    if block == &vanilla_blocks::DETECTOR_RAIL || block.has_tag(&BlockTag::PRESSURE_PLATES) {
+        return if direction == Direction::Up {
+            signal(state, direction)
+        } else {
+            0
+        };
+    }
+    if block == &vanilla_blocks::REDSTONE_TORCH || block == &vanilla_blocks::REDSTONE_WALL_TORCH {
+        return if direction == Direction::Down {
+            signal(state, direction)
+        } else {
+            0
+        };
+    }
+    0
+}
+
+fn powered_signal(state: BlockStateId) -> i32 {
+    if state.get_value(&BlockStateProperties::POWERED) {
+        MAX_SIGNAL
+    } else {
+        0
+    }
+}
+
+fn lit_signal(state: BlockStateId, emits: bool) -> i32 {
+    if emits && state.get_value(&BlockStateProperties::LIT) {
+        MAX_SIGNAL
+    } else {
+        0
+    }
+}
+
+/// Vanilla `FaceAttachedHorizontalDirectionalBlock.getConnectedDirection`:
+/// the direction from the support block towards a lever or button.
+fn connected_direction(state: BlockStateId) -> Direction {
+    let face: AttachFace = state.get_value(&BlockStateProperties::ATTACH_FACE);
+    match face {
+        AttachFace::Floor => Direction::Up,
+        AttachFace::Ceiling => Direction::Down,
+        AttachFace::Wall => state.get_value(&BlockStateProperties::HORIZONTAL_FACING),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rustc_hash::FxHashMap;
+    use steel_registry::test_support::init_test_registry;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct TestLevel {
+        blocks: FxHashMap<BlockPos, BlockStateId>,
+    }
+
+    impl TestLevel {
+        fn with(mut self, pos: BlockPos, state: BlockStateId) -> Self {
+            self.blocks.insert(pos, state);
+            self
+        }
+    }
+
+    impl LevelReader for TestLevel {
+        fn get_block_state(&self, pos: BlockPos) -> BlockStateId {
+            self.blocks
+                .get(&pos)
+                .copied()
+                .unwrap_or_else(|| vanilla_blocks::AIR.default_state())
+        }
+
+        fn raw_brightness(&self, _pos: BlockPos, _sky_darkening: u8) -> u8 {
+            15
+        }
+
+        fn min_y(&self) -> i32 {
+            -64
+        }
+
+        fn height(&self) -> i32 {
+            384
+        }
+    }
+
+    const POS: BlockPos = BlockPos::new(0, 64, 0);
+
+    fn lever(powered: bool) -> BlockStateId {
+        vanilla_blocks::LEVER
+            .default_state()
+            .set_value(&BlockStateProperties::ATTACH_FACE, AttachFace::Floor)
+            .set_value(&BlockStateProperties::POWERED, powered)
+    }
+
+    #[test]
+    fn adjacent_power_sources_signal_their_neighbors() {
+        init_test_registry();
+
+        assert!(!TestLevel::default().has_neighbor_signal(POS));
+        assert!(
+            TestLevel::default()
+                .with(POS.above(), vanilla_blocks::REDSTONE_BLOCK.default_state())
+                .has_neighbor_signal(POS)
+        );
+        assert!(
+            TestLevel::default()
+                .with(POS.east(), lever(true))
+                .has_neighbor_signal(POS)
+        );
+        assert!(
+            !TestLevel::default()
+                .with(POS.east(), lever(false))
+                .has_neighbor_signal(POS)
+        );
+    }
+
+    #[test]
+    fn powered_button_signals_its_neighbors() {
+        init_test_registry();
+
+        let button = vanilla_blocks::STONE_BUTTON
+            .default_state()
+            .set_value(&BlockStateProperties::ATTACH_FACE, AttachFace::Floor);
+        assert!(
+            !TestLevel::default()
+                .with(POS.west(), button)
+                .has_neighbor_signal(POS)
+        );
+        assert!(
+            TestLevel::default()
+                .with(
+                    POS.west(),
+                    button.set_value(&BlockStateProperties::POWERED, true)
+                )
+                .has_neighbor_signal(POS)
+        );
+    }
+
+    #[test]
+    fn lever_strongly_powers_its_support_block() {
+        init_test_registry();
+
+        let support = POS.below();
+        let level = TestLevel::default()
+            .with(support, vanilla_blocks::STONE.default_state())
+            .with(POS, lever(true));
+        assert_eq!(level.get_direct_signal_to(support), MAX_SIGNAL);
+        // The block beside the support block is powered through it.
+        assert!(level.has_neighbor_signal(support.east()));
+        assert_eq!(level.get_direct_signal_to(POS.above()), 0);
+    }
+
+    #[test]
+    fn redstone_torch_does_not_power_its_support_block() {
+        init_test_registry();
+
+        let torch = vanilla_blocks::REDSTONE_TORCH.default_state();
+        let level = TestLevel::default().with(POS, torch);
+        assert!(level.has_neighbor_signal(POS.above()));
+        assert!(level.has_neighbor_signal(POS.east()));
+        assert!(!level.has_neighbor_signal(POS.below()));
+    }
+}
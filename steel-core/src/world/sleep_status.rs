@@ -0,0 +1,261 @@
+//! Sleeping player counts and the night skip vote.
+
+use std::borrow::Cow;
+
+use steel_registry::vanilla_game_rules::{
+    ADVANCE_TIME, ADVANCE_WEATHER, PLAYERS_SLEEPING_PERCENTAGE,
+};
+use steel_utils::{Identifier, translations};
+use text_components::{TextComponent, translation::TranslatedMessage};
+
+use crate::entity::{Entity as _, LivingEntity as _};
+use crate::world::World;
+
+/// Vanilla `ClockTimeMarkers.WAKE_UP_FROM_SLEEP`.
+const WAKE_UP_FROM_SLEEP: Identifier = Identifier::vanilla_static("wake_up_from_sleep");
+
+/// Vanilla `SleepStatus`: how many players count toward the night skip and how
+/// many of them are in bed.
+#[derive(Debug, Default)]
+pub struct SleepStatus {
+    active_players: i32,
+    sleeping_players: i32,
+}
+
+impl SleepStatus {
+    /// Returns whether enough players are in bed for `percentage`.
+    #[must_use]
+    pub const fn are_enough_sleeping(&self, percentage: i32) -> bool {
+        self.sleeping_players >= self.sleepers_needed(percentage)
+    }
+
+    /// Returns whether `deep_sleepers` players have slept long enough for `percentage`.
+    #[must_use]
+    pub const fn are_enough_deep_sleeping(&self, percentage: i32, deep_sleepers: i32) -> bool {
+        deep_sleepers >= self.sleepers_needed(percentage)
+    }
+
+    /// Returns how many players must sleep for `percentage`, at least one.
+    #[must_use]
+    pub const fn sleepers_needed(&self, percentage: i32) -> i32 {
+        let needed = (self.active_players as f32 * percentage as f32 / 100.0).ceil() as i32;
+        if needed > 1 { needed } else { 1 }
+    }
+
+    /// Forgets every sleeper, used once the night has been skipped.
+    pub const fn remove_all_sleepers(&mut self) {
+        self.sleeping_players = 0;
+    }
+
+    /// Returns how many players are in bed.
+    #[must_use]
+    pub const fn amount_sleeping(&self) -> i32 {
+        self.sleeping_players
+    }
+
+    /// Recounts the players from `(spectator, sleeping)` flags.
+    ///
+    /// Returns whether the counts changed while anyone was or is asleep, which
+    /// is when vanilla announces the sleep status.
+    pub fn update(&mut self, players: impl IntoIterator<Item = (bool, bool)>) -> bool {
+        let old_active = self.active_players;
+        let old_sleeping = self.sleeping_players;
+        self.active_players = 0;
+        self.sleeping_players = 0;
+
+        for (spectator, sleeping) in players {
+            if !spectator {
+                self.active_players += 1;
+                if sleeping {
+                    self.sleeping_players += 1;
+                }
+            }
+        }
+
+        (old_sleeping > 0 || self.sleeping_players > 0)
+            && (old_active != self.active_players || old_sleeping != self.sleeping_players)
+    }
+}
+
+impl World {
+    /// Returns whether a bed rule value allows its action right now.
+    ///
+    /// Vanilla `BedRule.Rule.test`.
+    fn bed_rule_allows(&self, rule: &str) -> bool {
+        match rule {
+            "always" => true,
+            "when_dark" => self.is_dark_outside(),
+            _ => false,
+        }
+    }
+
+    /// Returns whether players can sleep in beds right now.
+    ///
+    /// Vanilla `BedRule.canSleep`.
+    #[must_use]
+    pub fn bed_rule_can_sleep(&self) -> bool {
+        self.bed_rule_allows(self.dimension_type.bed_rule.can_sleep)
+    }
+
+    /// Returns whether beds set the respawn point right now.
+    ///
+    /// Vanilla `BedRule.canSetSpawn`.
+    #[must_use]
+    pub fn bed_rule_can_set_spawn(&self) -> bool {
+        self.bed_rule_allows(self.dimension_type.bed_rule.can_set_spawn)
+    }
+
+    /// Returns the message shown when the bed rule refuses sleeping.
+    ///
+    /// Vanilla `BedRule.errorMessage`.
+    #[must_use]
+    pub fn bed_rule_error_message(&self) -> Option<TextComponent> {
+        self.dimension_type.bed_rule.error_message_key.map(|key| {
+            TextComponent::translated(TranslatedMessage {
+                key: Cow::Borrowed(key),
+                args: None,
+                fallback: None,
+            })
+        })
+    }
+
+    /// Returns whether sleeping can skip the night, which needs a day clock.
+    ///
+    /// Vanilla `ServerLevel.canSleepThroughNights`.
+    #[must_use]
+    pub fn can_sleep_through_nights(&self) -> bool {
+        self.dimension_type.default_clock.is_some()
+    }
+
+    /// Recounts the sleeping players and announces the new count.
+    ///
+    /// Vanilla `ServerLevel.updateSleepingPlayerList`.
+    pub fn update_sleeping_player_list(&self) {
+        let mut players = Vec::new();
+        self.players.iter_players(|_, player| {
+            players.push((player.is_spectator(), player.is_sleeping()));
+            true
+        });
+        if !players.is_empty() && self.sleep_status.lock().update(players) {
+            self.announce_sleep_status();
+        }
+    }
+
+    /// Vanilla `ServerLevel.announceSleepStatus`.
+    fn announce_sleep_status(&self) {
+        if !self.can_sleep_through_nights() {
+            return;
+        }
+        let percentage = self.get_game_rule(&PLAYERS_SLEEPING_PERCENTAGE);
+        let message = {
+            let status = self.sleep_status.lock();
+            if status.are_enough_sleeping(percentage) {
+                TextComponent::translated(translations::SLEEP_SKIPPING_NIGHT.msg())
+            } else {
+                translations::SLEEP_PLAYERS_SLEEPING
+                    .message([
+                        TextComponent::from(status.amount_sleeping().to_string()),
+                        TextComponent::from(status.sleepers_needed(percentage).to_string()),
+                    ])
+                    .into()
+            }
+        };
+        self.players.iter_players(|_, player| {
+            player.display_client_message(&message, true);
+            true
+        });
+    }
+
+    /// Skips the night once enough players have slept long enough.
+    ///
+    /// The night skip part of vanilla `ServerLevel.tick`.
+    pub(super) fn tick_sleep(&self) {
+        let percentage = self.get_game_rule(&PLAYERS_SLEEPING_PERCENTAGE);
+        if !self.sleep_status.lock().are_enough_sleeping(percentage) {
+            return;
+        }
+        let mut deep_sleepers = 0;
+        self.players.iter_players(|_, player| {
+            if player.is_sleeping_long_enough() {
+                deep_sleepers += 1;
+            }
+            true
+        });
+        if !self
+            .sleep_status
+            .lock()
+            .are_enough_deep_sleeping(percentage, deep_sleepers)
+        {
+            return;
+        }
+
+        if let Some(clock) = self.dimension_type.default_clock
+            && self.get_game_rule(&ADVANCE_TIME)
+        {
+            let _ = self.move_clock_to_time_marker(clock, &WAKE_UP_FROM_SLEEP);
+        }
+        self.wake_up_all_players();
+        if self.get_game_rule(&ADVANCE_WEATHER) && self.is_raining() {
+            self.reset_weather_cycle();
+        }
+    }
+
+    /// Vanilla `ServerLevel.wakeUpAllPlayers`.
+    fn wake_up_all_players(&self) {
+        self.sleep_status.lock().remove_all_sleepers();
+        let mut sleepers = Vec::new();
+        self.players.iter_players(|_, player| {
+            if player.is_sleeping() {
+                sleepers.push(player.clone());
+            }
+            true
+        });
+        for player in sleepers {
+            player.stop_sleep_in_bed(false, false);
+        }
+    }
+
+    /// Vanilla `ServerLevel.resetWeatherCycle`.
+    fn reset_weather_cycle(&self) {
+        let mut level_data = self.level_data.write();
+        level_data.set_rain_time(0);
+        level_data.set_raining(false);
+        level_data.set_thunder_time(0);
+        level_data.set_thundering(false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SleepStatus;
+
+    #[test]
+    fn sleepers_needed_rounds_up_and_needs_at_least_one() {
+        let mut status = SleepStatus::default();
+        status.update([(false, false); 3]);
+
+        assert_eq!(status.sleepers_needed(100), 3);
+        assert_eq!(status.sleepers_needed(50), 2);
+        assert_eq!(status.sleepers_needed(0), 1);
+    }
+
+    #[test]
+    fn spectators_do_not_count() {
+        let mut status = SleepStatus::default();
+        status.update([(false, true), (true, false), (false, false)]);
+
+        assert_eq!(status.amount_sleeping(), 1);
+        assert!(status.are_enough_sleeping(50));
+        assert!(!status.are_enough_sleeping(100));
+    }
+
+    #[test]
+    fn update_reports_changes_only_while_someone_sleeps() {
+        let mut status = SleepStatus::default();
+        assert!(!status.update([(false, false), (false, false)]));
+        assert!(status.update([(false, true), (false, false)]));
+        assert!(!status.update([(false, true), (false, false)]));
+        assert!(status.update([(false, false), (false, false)]));
+        assert!(!status.update([(false, false)]));
+    }
+}
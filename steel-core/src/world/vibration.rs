@@ -0,0 +1,627 @@
+//! Vibrations: game events that travel through the air to sculk listeners.
+//!
+//! A listener picks the closest (then highest frequency) vibration heard in a
+//! tick, and delivers it after it has travelled one tick per block.
+//!
+//! Vanilla equivalent: `VibrationSystem` with its `Listener`, `Ticker`,
+//! `VibrationSelector` and `VibrationInfo`.
+
+use std::sync::Arc;
+
+use glam::DVec3;
+use simdnbt::borrow::NbtCompound as NbtCompoundView;
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_registry::blocks::block_state_ext::BlockStateExt as _;
+use steel_registry::game_events::GameEventRef;
+use steel_registry::particle_type::{ParticleData, VibrationParticleOption};
+use steel_registry::position_source::{BlockPositionSource, PositionSource};
+use steel_registry::vanilla_block_tags::BlockTag;
+use steel_registry::vanilla_game_event_tags::GameEventsTag;
+use steel_registry::{
+    REGISTRY, RegistryExt as _, vanilla_game_events as events, vanilla_particle_types,
+    vanilla_position_source_types,
+};
+use steel_utils::locks::SyncMutex;
+use steel_utils::{BlockPos, Direction, Identifier, UuidExt as _};
+use uuid::Uuid;
+
+use crate::player::CriteriaTrigger;
+use crate::world::World;
+use crate::world::game_event_context::GameEventContext;
+use crate::world::game_event_listener::{GameEventDeliveryMode, GameEventListener};
+
+/// Vibration frequency of each game event, from 1 (steps) to 15 (deaths).
+static VIBRATION_FREQUENCIES: &[(GameEventRef, i32)] = &[
+    (&events::STEP, 1),
+    (&events::SWIM, 1),
+    (&events::FLAP, 1),
+    (&events::PROJECTILE_LAND, 2),
+    (&events::HIT_GROUND, 2),
+    (&events::SPLASH, 2),
+    (&events::ITEM_INTERACT_FINISH, 3),
+    (&events::PROJECTILE_SHOOT, 3),
+    (&events::INSTRUMENT_PLAY, 3),
+    (&events::ENTITY_ACTION, 4),
+    (&events::ELYTRA_GLIDE, 4),
+    (&events::UNEQUIP, 4),
+    (&events::ENTITY_DISMOUNT, 5),
+    (&events::EQUIP, 5),
+    (&events::ENTITY_INTERACT, 6),
+    (&events::SHEAR, 6),
+    (&events::ENTITY_MOUNT, 6),
+    (&events::ENTITY_DAMAGE, 7),
+    (&events::DRINK, 8),
+    (&events::EAT, 8),
+    (&events::CONTAINER_CLOSE, 9),
+    (&events::BLOCK_CLOSE, 9),
+    (&events::BLOCK_DEACTIVATE, 9),
+    (&events::BLOCK_DETACH, 9),
+    (&events::CONTAINER_OPEN, 10),
+    (&events::BLOCK_OPEN, 10),
+    (&events::BLOCK_ACTIVATE, 10),
+    (&events::BLOCK_ATTACH, 10),
+    (&events::PRIME_FUSE, 10),
+    (&events::NOTE_BLOCK_PLAY, 10),
+    (&events::BLOCK_CHANGE, 11),
+    (&events::BLOCK_DESTROY, 12),
+    (&events::FLUID_PICKUP, 12),
+    (&events::BLOCK_PLACE, 13),
+    (&events::FLUID_PLACE, 13),
+    (&events::ENTITY_PLACE, 14),
+    (&events::LIGHTNING_STRIKE, 14),
+    (&events::TELEPORT, 14),
+    (&events::ENTITY_DIE, 15),
+    (&events::EXPLODE, 15),
+];
+
+/// Resonance events re-emitted by amethyst, indexed by frequency - 1.
+static RESONATE_EVENTS: [GameEventRef; 15] = [
+    &events::RESONATE_1,
+    &events::RESONATE_2,
+    &events::RESONATE_3,
+    &events::RESONATE_4,
+    &events::RESONATE_5,
+    &events::RESONATE_6,
+    &events::RESONATE_7,
+    &events::RESONATE_8,
+    &events::RESONATE_9,
+    &events::RESONATE_10,
+    &events::RESONATE_11,
+    &events::RESONATE_12,
+    &events::RESONATE_13,
+    &events::RESONATE_14,
+    &events::RESONATE_15,
+];
+
+/// Returns the vibration frequency of `event`, or 0 if it has none.
+///
+/// Vanilla equivalent: `VibrationSystem.getGameEventFrequency`.
+#[must_use]
+pub fn game_event_frequency(event: GameEventRef) -> i32 {
+    if let Some(index) = RESONATE_EVENTS
+        .iter()
+        .position(|resonate| *resonate == event)
+    {
+        return index as i32 + 1;
+    }
+    VIBRATION_FREQUENCIES
+        .iter()
+        .find(|(candidate, _)| *candidate == event)
+        .map_or(0, |&(_, frequency)| frequency)
+}
+
+/// Returns the resonance event amethyst emits for `frequency`.
+///
+/// Vanilla equivalent: `VibrationSystem.getResonanceEventByFrequency`.
+#[must_use]
+pub fn resonance_event(frequency: i32) -> GameEventRef {
+    RESONATE_EVENTS[(frequency.clamp(1, 15) - 1) as usize]
+}
+
+/// Returns the redstone strength for a vibration heard `distance` blocks away.
+///
+/// Vanilla equivalent: `VibrationSystem.getRedstoneStrengthForDistance`.
+#[must_use]
+pub fn redstone_strength_for_distance(distance: f32, radius: i32) -> i32 {
+    let step = 15.0 / f64::from(radius);
+    (15 - (step * f64::from(distance)).floor() as i32).max(1)
+}
+
+/// A vibration on its way to a listener.
+///
+/// Vanilla equivalent: `VibrationInfo`.
+#[derive(Debug, Clone)]
+pub struct VibrationInfo {
+    /// The game event that caused the vibration.
+    pub event: GameEventRef,
+    /// Distance from the source to the listener.
+    pub distance: f32,
+    /// Where the vibration started.
+    pub pos: DVec3,
+    /// UUID of the entity that caused the vibration.
+    pub source: Option<Uuid>,
+    /// UUID of the owner when the source is a projectile.
+    pub projectile_owner: Option<Uuid>,
+}
+
+impl VibrationInfo {
+    fn new(event: GameEventRef, distance: f32, pos: DVec3, context: &GameEventContext<'_>) -> Self {
+        let source = context.source_entity();
+        Self {
+            event,
+            distance,
+            pos,
+            source: source.map(|entity| entity.uuid()),
+            projectile_owner: source.and_then(|entity| entity.projectile_owner_uuid()),
+        }
+    }
+
+    fn load(nbt: NbtCompoundView<'_, '_>) -> Option<Self> {
+        let key: Identifier = nbt.string("game_event")?.to_string().parse().ok()?;
+        let pos = nbt.list("pos")?.doubles()?;
+        let [x, y, z] = pos[..] else {
+            return None;
+        };
+        Some(Self {
+            event: REGISTRY.game_events.by_key(&key)?,
+            distance: nbt.float("distance")?,
+            pos: DVec3::new(x, y, z),
+            source: nbt
+                .int_array("source")
+                .and_then(|uuid| Uuid::from_int_array(&uuid)),
+            projectile_owner: nbt
+                .int_array("projectile_owner")
+                .and_then(|uuid| Uuid::from_int_array(&uuid)),
+        })
+    }
+
+    fn save(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+        nbt.insert("game_event", self.event.key.to_string());
+        nbt.insert("distance", self.distance);
+        nbt.insert(
+            "pos",
+            NbtTag::List(NbtList::Double(vec![self.pos.x, self.pos.y, self.pos.z])),
+        );
+        if let Some(source) = self.source {
+            nbt.insert("source", NbtTag::IntArray(source.to_int_array().to_vec()));
+        }
+        if let Some(owner) = self.projectile_owner {
+            nbt.insert(
+                "projectile_owner",
+                NbtTag::IntArray(owner.to_int_array().to_vec()),
+            );
+        }
+        nbt
+    }
+}
+
+/// Picks the vibration to deliver out of those heard in one tick.
+///
+/// Vanilla equivalent: `VibrationSelector`.
+#[derive(Debug, Clone, Default)]
+struct VibrationSelector {
+    candidate: Option<(VibrationInfo, i64)>,
+}
+
+impl VibrationSelector {
+    /// Keeps `info` if it is closer, or equally close with a higher frequency,
+    /// than the candidate heard this tick.
+    fn add_candidate(&mut self, info: VibrationInfo, tick: i64) {
+        if self.should_replace(&info, tick) {
+            self.candidate = Some((info, tick));
+        }
+    }
+
+    fn should_replace(&self, info: &VibrationInfo, tick: i64) -> bool {
+        let Some((candidate, candidate_tick)) = &self.candidate else {
+            return true;
+        };
+        if tick != *candidate_tick {
+            return false;
+        }
+        if info.distance < candidate.distance {
+            return true;
+        }
+        if info.distance > candidate.distance {
+            return false;
+        }
+        game_event_frequency(info.event) > game_event_frequency(candidate.event)
+    }
+
+    /// Returns the candidate once the tick it was heard in has passed.
+    fn chosen_candidate(&self, tick: i64) -> Option<VibrationInfo> {
+        self.candidate
+            .as_ref()
+            .filter(|(_, candidate_tick)| *candidate_tick < tick)
+            .map(|(info, _)| info.clone())
+    }
+
+    fn start_over(&mut self) {
+        self.candidate = None;
+    }
+}
+
+/// Vanilla equivalent: `VibrationSystem.Data`.
+#[derive(Debug, Default)]
+struct VibrationData {
+    current: Option<VibrationInfo>,
+    travel_time: i32,
+    selector: VibrationSelector,
+}
+
+/// A block that reacts to vibrations.
+///
+/// Vanilla equivalent: `VibrationSystem.User`.
+pub trait VibrationUser: Send + Sync {
+    /// Returns the block distance within which vibrations are heard.
+    fn listener_radius(&self) -> i32;
+
+    /// Returns the game event tag this user listens to.
+    fn listenable_events(&self) -> Identifier {
+        GameEventsTag::VIBRATIONS
+    }
+
+    /// Returns whether a vibration from `pos` may be received right now.
+    fn can_receive_vibration(
+        &self,
+        world: &World,
+        pos: BlockPos,
+        event: GameEventRef,
+        context: &GameEventContext<'_>,
+    ) -> bool;
+
+    /// Returns whether sneaking past this user grants `avoid_vibration`.
+    fn can_trigger_avoid_vibration(&self) -> bool {
+        false
+    }
+}
+
+/// The game event listener of a vibration-sensing block entity.
+///
+/// The vibration state lives here rather than in the block entity so the
+/// listener can be dispatched to while the block entity is locked.
+///
+/// Vanilla equivalent: `VibrationSystem.Listener` with its `Data`.
+pub struct VibrationListener<U> {
+    pos: BlockPos,
+    user: U,
+    data: SyncMutex<VibrationData>,
+}
+
+impl<U: VibrationUser> VibrationListener<U> {
+    /// Creates an idle listener at `pos`.
+    #[must_use]
+    pub fn new(pos: BlockPos, user: U) -> Self {
+        Self {
+            pos,
+            user,
+            data: SyncMutex::new(VibrationData::default()),
+        }
+    }
+
+    /// Returns the user deciding which vibrations this listener accepts.
+    #[must_use]
+    pub const fn user(&self) -> &U {
+        &self.user
+    }
+
+    /// Schedules a vibration without the validity and occlusion checks.
+    ///
+    /// Vanilla equivalent: `VibrationSystem.Listener.forceScheduleVibration`.
+    pub fn force_schedule_vibration(
+        &self,
+        world: &World,
+        event: GameEventRef,
+        context: &GameEventContext<'_>,
+        source_pos: DVec3,
+    ) {
+        self.schedule_vibration(world, event, context, source_pos, block_center(self.pos));
+    }
+
+    fn schedule_vibration(
+        &self,
+        world: &World,
+        event: GameEventRef,
+        context: &GameEventContext<'_>,
+        source_pos: DVec3,
+        destination: DVec3,
+    ) {
+        let distance = source_pos.distance(destination) as f32;
+        self.data.lock().selector.add_candidate(
+            VibrationInfo::new(event, distance, source_pos, context),
+            world.game_time(),
+        );
+    }
+
+    /// Starts the chosen vibration travelling, then delivers it on arrival.
+    ///
+    /// Returns the vibration on the tick it arrives. Vanilla equivalent:
+    /// `VibrationSystem.Ticker.tick`.
+    pub fn tick(&self, world: &World) -> Option<VibrationInfo> {
+        let mut data = self.data.lock();
+        if data.current.is_none()
+            && let Some(info) = data.selector.chosen_candidate(world.game_time())
+        {
+            // Vanilla `User.calculateTravelTimeInTicks`: one tick per block.
+            data.travel_time = info.distance.floor() as i32;
+            world.send_particles(
+                ParticleData::new(
+                    &vanilla_particle_types::VIBRATION,
+                    VibrationParticleOption::new(
+                        PositionSource::new(
+                            &vanilla_position_source_types::BLOCK,
+                            BlockPositionSource::new(self.pos),
+                        ),
+                        data.travel_time,
+                    ),
+                ),
+                info.pos,
+                1,
+                DVec3::ZERO,
+                0.0,
+            );
+            data.current = Some(info);
+            data.selector.start_over();
+            world.block_entity_changed(self.pos);
+        }
+
+        data.current.as_ref()?;
+        data.travel_time = (data.travel_time - 1).max(0);
+        if data.travel_time > 0 || !adjacent_chunks_ticking(world, self.pos) {
+            return None;
+        }
+        let arrived = data.current.take();
+        drop(data);
+        world.block_entity_changed(self.pos);
+        arrived
+    }
+
+    /// Restores the vibration state saved by [`Self::save`].
+    pub fn load(&self, nbt: NbtCompoundView<'_, '_>) {
+        let mut data = self.data.lock();
+        data.current = nbt.compound("event").and_then(VibrationInfo::load);
+        data.travel_time = nbt.int("event_delay").unwrap_or(0);
+        data.selector.candidate = nbt.compound("selector").and_then(|selector| {
+            let info = selector.compound("event").and_then(VibrationInfo::load)?;
+            Some((info, selector.long("tick")?))
+        });
+    }
+
+    /// Saves the vibration in flight and the pending candidate.
+    #[must_use]
+    pub fn save(&self) -> NbtCompound {
+        let data = self.data.lock();
+        let mut nbt = NbtCompound::new();
+        if let Some(current) = &data.current {
+            nbt.insert("event", NbtTag::Compound(current.save()));
+        }
+        let mut selector = NbtCompound::new();
+        if let Some((candidate, tick)) = &data.selector.candidate {
+            selector.insert("event", NbtTag::Compound(candidate.save()));
+            selector.insert("tick", *tick);
+        }
+        nbt.insert("selector", NbtTag::Compound(selector));
+        nbt.insert("event_delay", data.travel_time);
+        nbt
+    }
+
+    /// Vanilla equivalent: `VibrationSystem.User.isValidVibration`.
+    fn is_valid_vibration(&self, event: GameEventRef, context: &GameEventContext<'_>) -> bool {
+        if !event.has_tag(&self.user.listenable_events()) {
+            return false;
+        }
+        if let Some(entity) = context.source_entity() {
+            if entity.is_spectator() {
+                return false;
+            }
+            if entity.is_stepping_carefully()
+                && event.has_tag(&GameEventsTag::IGNORE_VIBRATIONS_SNEAKING)
+            {
+                if self.user.can_trigger_avoid_vibration()
+                    && let Some(player) = entity.as_player()
+                {
+                    player.trigger_criteria(CriteriaTrigger::AvoidVibration);
+                }
+                return false;
+            }
+            if entity.dampens_vibrations() {
+                return false;
+            }
+        }
+        context
+            .affected_state()
+            .is_none_or(|state| !state.get_block().has_tag(&BlockTag::DAMPENS_VIBRATIONS))
+    }
+}
+
+impl<U: VibrationUser> GameEventListener for VibrationListener<U> {
+    fn listener_pos(&self) -> Option<DVec3> {
+        Some(block_center(self.pos))
+    }
+
+    fn listener_radius(&self) -> i32 {
+        self.user.listener_radius()
+    }
+
+    fn delivery_mode(&self) -> GameEventDeliveryMode {
+        GameEventDeliveryMode::ByDistance
+    }
+
+    fn handle_game_event(
+        &self,
+        world: &Arc<World>,
+        event: GameEventRef,
+        context: &GameEventContext<'_>,
+        source_pos: DVec3,
+    ) -> bool {
+        if self.data.lock().current.is_some() || !self.is_valid_vibration(event, context) {
+            return false;
+        }
+        let destination = block_center(self.pos);
+        if !self
+            .user
+            .can_receive_vibration(world, BlockPos::from(source_pos), event, context)
+            || is_occluded(world, source_pos, destination)
+        {
+            return false;
+        }
+        self.schedule_vibration(world, event, context, source_pos, destination);
+        true
+    }
+}
+
+fn block_center(pos: BlockPos) -> DVec3 {
+    DVec3::new(
+        f64::from(pos.x()) + 0.5,
+        f64::from(pos.y()) + 0.5,
+        f64::from(pos.z()) + 0.5,
+    )
+}
+
+/// Vanilla equivalent: `VibrationSystem.Ticker.areAdjacentChunksTicking`.
+fn adjacent_chunks_ticking(world: &World, pos: BlockPos) -> bool {
+    (-1..=1).all(|dx| {
+        (-1..=1).all(|dz| world.is_entity_ticking_chunk_loaded(pos.offset(dx * 16, 0, dz * 16)))
+    })
+}
+
+/// Returns whether occluding blocks cut every line from the source block to
+/// the listener block, each nudged slightly towards one face.
+///
+/// Vanilla equivalent: `VibrationSystem.Listener.isOccluded`.
+fn is_occluded(world: &World, from: DVec3, to: DVec3) -> bool {
+    let from = from.floor() + 0.5;
+    let to = to.floor() + 0.5;
+    Direction::ALL.iter().all(|direction| {
+        let offset = from + direction.offset_vec().as_dvec3() * 1.0e-5;
+        is_block_in_line(world, offset, to, |pos| {
+            world
+                .get_block_state(pos)
+                .get_block()
+                .has_tag(&BlockTag::OCCLUDES_VIBRATION_SIGNALS)
+        })
+    })
+}
+
+/// Walks the blocks on the line from `from` to `to` and returns whether any
+/// matches `predicate`.
+///
+/// Vanilla equivalent: `BlockGetter.isBlockInLine` via `traverseBlocks`.
+fn is_block_in_line(
+    world: &World,
+    from: DVec3,
+    to: DVec3,
+    predicate: impl Fn(BlockPos) -> bool,
+) -> bool {
+    if from == to {
+        return false;
+    }
+    let adjust = -1.0e-7;
+    let end = to.lerp(from, adjust);
+    let start = from.lerp(to, adjust);
+    let mut block = BlockPos::from(start);
+    if predicate(block) {
+        return true;
+    }
+
+    let difference = end - start;
+    let step = difference.signum().as_ivec3();
+    let delta = DVec3::new(
+        if step.x == 0 {
+            f64::MAX
+        } else {
+            f64::from(step.x) / difference.x
+        },
+        if step.y == 0 {
+            f64::MAX
+        } else {
+            f64::from(step.y) / difference.y
+        },
+        if step.z == 0 {
+            f64::MAX
+        } else {
+            f64::from(step.z) / difference.z
+        },
+    );
+    let fraction = start - start.floor();
+    let mut next = DVec3::new(
+        delta.x
+            * if step.x > 0 {
+                1.0 - fraction.x
+            } else {
+                fraction.x
+            },
+        delta.y
+            * if step.y > 0 {
+                1.0 - fraction.y
+            } else {
+                fraction.y
+            },
+        delta.z
+            * if step.z > 0 {
+                1.0 - fraction.z
+            } else {
+                fraction.z
+            },
+    );
+
+    while next.x <= 1.0 || next.y <= 1.0 || next.z <= 1.0 {
+        if next.x < next.y && next.x < next.z {
+            block = block.offset(step.x, 0, 0);
+            next.x += delta.x;
+        } else if next.y < next.z {
+            block = block.offset(0, step.y, 0);
+            next.y += delta.y;
+        } else {
+            block = block.offset(0, 0, step.z);
+            next.z += delta.z;
+        }
+        if predicate(block) {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frequencies_follow_vanilla_table() {
+        assert_eq!(game_event_frequency(&events::STEP), 1);
+        assert_eq!(game_event_frequency(&events::CONTAINER_OPEN), 10);
+        assert_eq!(game_event_frequency(&events::EXPLODE), 15);
+        assert_eq!(game_event_frequency(&events::RESONATE_7), 7);
+        assert_eq!(game_event_frequency(&events::SHRIEK), 0);
+        assert!(resonance_event(7) == &events::RESONATE_7);
+    }
+
+    #[test]
+    fn redstone_strength_drops_with_distance() {
+        assert_eq!(redstone_strength_for_distance(0.0, 8), 15);
+        assert_eq!(redstone_strength_for_distance(4.0, 8), 8);
+        assert_eq!(redstone_strength_for_distance(8.0, 8), 1);
+    }
+
+    #[test]
+    fn selector_prefers_closer_then_higher_frequency() {
+        let info = |event, distance| VibrationInfo {
+            event,
+            distance,
+            pos: DVec3::ZERO,
+            source: None,
+            projectile_owner: None,
+        };
+        let mut selector = VibrationSelector::default();
+        selector.add_candidate(info(&events::STEP, 4.0), 10);
+        selector.add_candidate(info(&events::EXPLODE, 6.0), 10);
+        selector.add_candidate(info(&events::BLOCK_PLACE, 4.0), 10);
+        selector.add_candidate(info(&events::ENTITY_DIE, 1.0), 11);
+
+        assert!(selector.chosen_candidate(10).is_none());
+        let chosen = selector.chosen_candidate(11).map(|info| info.event);
+        assert!(chosen == Some(&events::BLOCK_PLACE));
+    }
+}
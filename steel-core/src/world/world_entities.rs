@@ -2,14 +2,15 @@
 use std::sync::Arc;
 
 use steel_protocol::packets::game::{CGameEvent, GameEventType};
+use steel_registry::stat::{CustomStat, Stat};
 use steel_registry::vanilla_entities;
 use steel_utils::ChunkPos;
 use tokio::time::Instant;
 
 use crate::{
     entity::{
-        Entity, EntityOwnership, NullEntityCallback, PlayerEntityCallback, RemovalReason,
-        SharedEntity,
+        Entity, EntityOwnership, LivingEntity, NullEntityCallback, PlayerEntityCallback,
+        RemovalReason, SharedEntity,
     },
     player::connection::NetworkConnection,
     player::player_data::PersistentPlayerData,
@@ -91,6 +92,7 @@ impl World {
         }
 
         self.register_respawned_player_entity(&player);
+        self.update_sleeping_player_list();
         player.send_packet(CGameEvent {
             event: GameEventType::LevelChunksLoadStart,
             data: 0.0,
@@ -108,6 +110,13 @@ impl World {
         };
         let entity_id = player.id();
         let domain = self.domain().to_owned();
+        player.award_stat(Stat::custom(CustomStat::LeaveGame));
+        // Vanilla `Player.remove` closes the open menu so containers see `stop_open`.
+        player.do_close_container();
+        // Vanilla `ServerPlayer.disconnect` wakes the player so the bed is freed.
+        if player.is_sleeping() {
+            player.stop_sleep_in_bed(true, false);
+        }
         let player_data = PersistentPlayerData::from_player(&player);
 
         self.unride_player_for_removal(&player, true);
@@ -119,6 +128,7 @@ impl World {
 
         self.player_area_map.on_player_leave(&player);
         self.chunk_map.remove_player(&player);
+        self.update_sleeping_player_list();
 
         let start = Instant::now();
 
@@ -137,6 +147,7 @@ impl World {
 
     async fn remove_detached_end_credits_player(self: &Arc<Self>, player: Arc<Player>) {
         let domain = self.domain().to_owned();
+        player.award_stat(Stat::custom(CustomStat::LeaveGame));
         let player_data = PersistentPlayerData::from_player(&player);
         let start = Instant::now();
 
@@ -169,6 +180,7 @@ impl World {
         self.player_area_map.on_player_leave(&player);
         // Note: no CRemovePlayerInfo — player stays in the global tab list
         self.chunk_map.remove_player(&player);
+        self.update_sleeping_player_list();
     }
 
     /// Removes a player during a domain switch after the caller has saved
@@ -185,6 +197,7 @@ impl World {
         self.entity_tracker().on_player_leave(entity_id);
         self.player_area_map.on_player_leave(&player);
         self.chunk_map.remove_player(&player);
+        self.update_sleeping_player_list();
     }
 
     /// Adds a player to the world.
@@ -201,6 +214,7 @@ impl World {
 
         self.register_player_entity(&player);
         self.chunk_map.update_player_status(&player);
+        self.update_sleeping_player_list();
 
         player.send_packet(CGameEvent {
             event: GameEventType::LevelChunksLoadStart,
@@ -39,7 +39,8 @@ impl FeatureDecorationRunner {
         true
     }
 
-    fn end_spikes_for_level(seed: i64) -> Vec<EndSpike> {
+    /// Vanilla `SpikeFeature.getSpikesForLevel`.
+    pub(crate) fn end_spikes_for_level(seed: i64) -> Vec<EndSpike> {
         let mut seed_random = LegacyRandom::from_seed(seed as u64);
         let cache_key = seed_random.next_i64() & 65_535;
         let mut random = LegacyRandom::from_seed(cache_key as u64);
@@ -143,7 +144,12 @@ impl FeatureDecorationRunner {
         }
     }
 
-    fn end_spike_iron_bars_state(x_edge: bool, z_edge: bool, dx: i32, dz: i32) -> BlockStateId {
+    pub(crate) fn end_spike_iron_bars_state(
+        x_edge: bool,
+        z_edge: bool,
+        dx: i32,
+        dz: i32,
+    ) -> BlockStateId {
         REGISTRY
             .blocks
             .get_default_state_id(&vanilla_blocks::IRON_BARS)
@@ -1,15 +1,19 @@
 use std::collections::{BTreeMap, BTreeSet};
-use std::io::{Cursor, Read};
+use std::io::{Cursor, Read, Write as _};
+use std::path::Path;
 use std::str::FromStr;
+use std::sync::{Arc, Weak};
 
+use flate2::Compression;
 use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
 use glam::{DVec3, IVec3};
 use simdnbt::borrow::{
     Nbt as BorrowedNbt, NbtCompound as BorrowedNbtCompound,
     NbtCompoundList as BorrowedNbtCompoundList, NbtList as BorrowedNbtList, read as read_nbt,
     read_compound as read_borrowed_compound,
 };
-use simdnbt::owned::{NbtCompound, NbtTag};
+use simdnbt::owned::{BaseNbt, NbtCompound, NbtList, NbtTag};
 use steel_registry::block_entity_type::BlockEntityTypeRef;
 use steel_registry::blocks::properties::Direction as BlockPropertyDirection;
 use steel_registry::blocks::properties::{BlockStateProperties, Half};
@@ -34,17 +38,20 @@ use steel_utils::random::worldgen_random::WorldgenRandom;
 use steel_utils::random::{PositionalRandom, Random, RandomSource};
 use steel_utils::value_providers::IntProvider;
 use steel_utils::{
-    BlockPos, BlockStateId, BoundingBox, Direction, Identifier, Rotation, types::UpdateFlags,
+    BlockPos, BlockStateId, BoundingBox, Direction, Identifier, Rotation, WorldAabb,
+    types::UpdateFlags,
 };
 use text_components::TextComponent;
 use uuid::Uuid;
 
 use crate::behavior::BLOCK_BEHAVIORS;
 use crate::chunk::heightmap::HeightmapType;
+use crate::entity::entities::PaintingEntity;
 use crate::entity::{
     DEFAULT_MAX_AIR_SUPPLY, ENTITIES, EntityBaseSaveData, EntityFireFreezeState, EntityLoadRequest,
-    MAX_ENTITY_TAGS,
+    MAX_ENTITY_TAGS, SharedEntity,
 };
+use crate::world::{LevelAccessor, World};
 use crate::worldgen::region::WorldGenRegion;
 use steel_worldgen::state_resolver::WorldgenStateResolver;
 use steel_worldgen::structure::{StructureBlockIgnore, StructureMirror};
@@ -130,6 +137,15 @@ impl StructureTemplate {
         Self::load_gzip_nbt(registry, bytes, &key.to_string())
     }
 
+    /// Loads a gzip-compressed structure `.nbt` file, such as the ones structure
+    /// blocks save under a world's `generated` directory.
+    pub(crate) fn load_file(registry: &Registry, path: &Path) -> Result<Self, String> {
+        let context = path.display().to_string();
+        let bytes = std::fs::read(path)
+            .map_err(|err| format!("failed to read structure template {context}: {err}"))?;
+        Self::load_gzip_nbt(registry, &bytes, &context)
+    }
+
     fn load_gzip_nbt(registry: &Registry, bytes: &[u8], context: &str) -> Result<Self, String> {
         let mut decoder = GzDecoder::new(bytes);
         let mut data = Vec::new();
@@ -170,6 +186,302 @@ impl StructureTemplate {
         })
     }
 
+    /// Vanilla `StructureTemplate.fillFromWorld`: captures the `size` box starting at
+    /// `origin`, skipping `ignore_blocks`, together with the non-player entities inside
+    /// it when `include_entities` is set.
+    ///
+    /// Returns `None` when any axis of `size` is smaller than one block.
+    pub(crate) fn fill_from_world(
+        world: &World,
+        registry: &Registry,
+        origin: BlockPos,
+        size: IVec3,
+        include_entities: bool,
+        ignore_blocks: &[BlockRef],
+    ) -> Option<Self> {
+        if size.cmplt(IVec3::ONE).any() {
+            return None;
+        }
+
+        let corner = BlockPos(origin.0 + size - IVec3::ONE);
+        let min = BlockPos::min(origin, corner);
+        let max = BlockPos::max(origin, corner);
+
+        let mut infos = Vec::new();
+        for y in min.y()..=max.y() {
+            for z in min.z()..=max.z() {
+                for x in min.x()..=max.x() {
+                    let pos = BlockPos::new(x, y, z);
+                    let state = world.get_block_state(pos);
+                    let block = Self::block_for_state(registry, state);
+                    if ignore_blocks.iter().any(|&ignored| ignored == block) {
+                        continue;
+                    }
+
+                    let nbt = world.get_block_entity(pos).map(|block_entity| {
+                        let block_entity = block_entity.lock();
+                        let mut nbt = block_entity.save_custom_only();
+                        nbt.insert("id", block_entity.get_type().key.to_string());
+                        nbt
+                    });
+                    infos.push(StructureBlockInfo {
+                        pos: BlockPos(pos.0 - min.0),
+                        state,
+                        nbt,
+                    });
+                }
+            }
+        }
+
+        let entities = if include_entities {
+            Self::fill_entity_list(world, min, max)
+        } else {
+            Vec::new()
+        };
+
+        Some(Self {
+            size,
+            palettes: vec![StructureTemplatePalette {
+                blocks: Self::build_info_list(registry, infos),
+            }],
+            entities,
+        })
+    }
+
+    /// Vanilla `StructureTemplate.fillEntityList`.
+    fn fill_entity_list(world: &World, min: BlockPos, max: BlockPos) -> Vec<StructureEntityInfo> {
+        let aabb = WorldAabb::new(
+            f64::from(min.x()),
+            f64::from(min.y()),
+            f64::from(min.z()),
+            f64::from(max.x() + 1),
+            f64::from(max.y() + 1),
+            f64::from(max.z() + 1),
+        );
+        let origin = DVec3::new(f64::from(min.x()), f64::from(min.y()), f64::from(min.z()));
+
+        world
+            .get_entities_in_aabb_matching(&aabb, |entity| entity.as_player().is_none())
+            .into_iter()
+            .map(|entity| {
+                let pos = entity.position() - origin;
+                let block_pos = entity.downcast_ref::<PaintingEntity>().map_or_else(
+                    || BlockPos::containing(pos.x, pos.y, pos.z),
+                    |painting| BlockPos(painting.block_pos().0 - min.0),
+                );
+                let mut nbt = NbtCompound::new();
+                entity.save_additional(&mut nbt);
+                StructureEntityInfo {
+                    pos,
+                    block_pos,
+                    entity_type: entity.entity_type(),
+                    rotation: entity.rotation(),
+                    velocity: entity.velocity(),
+                    fall_distance: entity.fall_distance(),
+                    fire_freeze: entity.fire_freeze_state(),
+                    on_ground: entity.on_ground(),
+                    save_data: entity.base().save_data(),
+                    nbt,
+                }
+            })
+            .collect()
+    }
+
+    /// Vanilla `StructureTemplate.save`.
+    // TODO: Write `DataVersion` once the targeted world data version is generated
+    // alongside `MINECRAFT_VERSION`.
+    pub(crate) fn save(&self, registry: &Registry) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+        let mut palettes: Vec<Vec<BlockStateId>> = vec![Vec::new(); self.palettes.len().max(1)];
+        let mut blocks = Vec::new();
+
+        if let Some(main) = self.palettes.first() {
+            for (index, block) in main.blocks.iter().enumerate() {
+                let id = Self::palette_id_for(&mut palettes[0], block.state);
+                let mut block_nbt = NbtCompound::new();
+                block_nbt.insert(
+                    "pos",
+                    NbtList::Int(vec![block.pos.x(), block.pos.y(), block.pos.z()]),
+                );
+                block_nbt.insert("state", id);
+                if let Some(block_entity) = &block.nbt {
+                    block_nbt.insert("nbt", NbtTag::Compound(block_entity.clone()));
+                }
+                blocks.push(block_nbt);
+
+                // Vanilla `SimplePalette.addMapping`: every other palette stores its
+                // state for this block at the main palette's id.
+                for (palette, states) in self.palettes.iter().zip(&mut palettes).skip(1) {
+                    let Some(other) = palette.blocks.get(index) else {
+                        continue;
+                    };
+                    let Ok(id) = usize::try_from(id) else {
+                        continue;
+                    };
+                    if states.len() <= id {
+                        states.resize(id + 1, vanilla_blocks::AIR.default_state());
+                    }
+                    states[id] = other.state;
+                }
+            }
+        }
+        nbt.insert("blocks", NbtList::Compound(blocks));
+
+        let mut written_palettes = palettes.iter().map(|states| {
+            NbtList::Compound(
+                states
+                    .iter()
+                    .map(|&state| Self::write_block_state(registry, state))
+                    .collect(),
+            )
+        });
+        if palettes.len() == 1 {
+            if let Some(palette) = written_palettes.next() {
+                nbt.insert("palette", palette);
+            }
+        } else {
+            nbt.insert("palettes", NbtList::List(written_palettes.collect()));
+        }
+
+        let entities = self
+            .entities
+            .iter()
+            .map(|entity| {
+                let mut entity_nbt = NbtCompound::new();
+                entity_nbt.insert(
+                    "pos",
+                    NbtList::Double(vec![entity.pos.x, entity.pos.y, entity.pos.z]),
+                );
+                entity_nbt.insert(
+                    "blockPos",
+                    NbtList::Int(vec![
+                        entity.block_pos.x(),
+                        entity.block_pos.y(),
+                        entity.block_pos.z(),
+                    ]),
+                );
+                entity_nbt.insert("nbt", NbtTag::Compound(Self::write_entity_nbt(entity)));
+                entity_nbt
+            })
+            .collect();
+        nbt.insert("entities", NbtList::Compound(entities));
+        nbt.insert(
+            "size",
+            NbtList::Int(vec![self.size.x, self.size.y, self.size.z]),
+        );
+        nbt
+    }
+
+    /// Gzip-compresses [`Self::save`] into the `.nbt` file format structure blocks write.
+    pub(crate) fn to_gzip_nbt(&self, registry: &Registry) -> Result<Vec<u8>, String> {
+        let mut bytes = Vec::new();
+        BaseNbt::new("", self.save(registry)).write(&mut bytes);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&bytes)
+            .and_then(|()| encoder.finish())
+            .map_err(|err| format!("failed to compress structure template: {err}"))
+    }
+
+    fn palette_id_for(states: &mut Vec<BlockStateId>, state: BlockStateId) -> i32 {
+        let index = states
+            .iter()
+            .position(|&known| known == state)
+            .unwrap_or_else(|| {
+                states.push(state);
+                states.len() - 1
+            });
+        i32::try_from(index).unwrap_or(i32::MAX)
+    }
+
+    /// Vanilla `NbtUtils.writeBlockState`.
+    fn write_block_state(registry: &Registry, state: BlockStateId) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+        nbt.insert(
+            "Name",
+            Self::block_for_state(registry, state).key.to_string(),
+        );
+        let properties = registry.blocks.get_properties(state);
+        if !properties.is_empty() {
+            let mut properties_nbt = NbtCompound::new();
+            for (name, value) in properties {
+                properties_nbt.insert(name, value.to_owned());
+            }
+            nbt.insert("Properties", NbtTag::Compound(properties_nbt));
+        }
+        nbt
+    }
+
+    /// Rebuilds the vanilla `Entity.save` compound that [`Self::read_entities`] splits
+    /// into base fields and type-specific data.
+    fn write_entity_nbt(entity: &StructureEntityInfo) -> NbtCompound {
+        let mut nbt = entity.nbt.clone();
+        let save_data = &entity.save_data;
+        nbt.insert("id", entity.entity_type.key.to_string());
+        nbt.insert(
+            "Pos",
+            NbtList::Double(vec![entity.pos.x, entity.pos.y, entity.pos.z]),
+        );
+        nbt.insert(
+            "Motion",
+            NbtList::Double(vec![
+                entity.velocity.x,
+                entity.velocity.y,
+                entity.velocity.z,
+            ]),
+        );
+        nbt.insert(
+            "Rotation",
+            NbtList::Float(vec![entity.rotation.0, entity.rotation.1]),
+        );
+        nbt.insert("fall_distance", entity.fall_distance);
+        nbt.insert(
+            "Fire",
+            NbtTag::Short(
+                i16::try_from(entity.fire_freeze.remaining_fire_ticks()).unwrap_or(i16::MAX),
+            ),
+        );
+        nbt.insert(
+            "Air",
+            NbtTag::Short(i16::try_from(save_data.air_supply).unwrap_or(i16::MAX)),
+        );
+        nbt.insert("OnGround", i8::from(entity.on_ground));
+        nbt.insert("Invulnerable", i8::from(save_data.invulnerable));
+        nbt.insert("PortalCooldown", save_data.portal_cooldown);
+        if let Some(custom_name) = &save_data.custom_name {
+            nbt.insert("CustomName", custom_name.to_codec_nbt());
+        }
+        if save_data.custom_name_visible {
+            nbt.insert("CustomNameVisible", 1_i8);
+        }
+        if save_data.silent {
+            nbt.insert("Silent", 1_i8);
+        }
+        if save_data.no_gravity {
+            nbt.insert("NoGravity", 1_i8);
+        }
+        if save_data.glowing {
+            nbt.insert("Glowing", 1_i8);
+        }
+        if entity.fire_freeze.ticks_frozen() > 0 {
+            nbt.insert("TicksFrozen", entity.fire_freeze.ticks_frozen());
+        }
+        if entity.fire_freeze.has_visual_fire() {
+            nbt.insert("HasVisualFire", 1_i8);
+        }
+        if !save_data.tags.is_empty() {
+            nbt.insert(
+                "Tags",
+                NbtList::from(save_data.tags.iter().cloned().collect::<Vec<_>>()),
+            );
+        }
+        if !save_data.custom_data.is_empty() {
+            nbt.insert("data", NbtTag::Compound(save_data.custom_data.clone()));
+        }
+        nbt
+    }
+
     fn read_vec3(
         list: Option<BorrowedNbtList<'_, '_>>,
         context: &str,
@@ -275,10 +587,7 @@ impl StructureTemplate {
         palette: &[BlockStateId],
         context: &str,
     ) -> Result<Vec<StructureBlockInfo>, String> {
-        let mut full_blocks = Vec::new();
-        let mut other_blocks = Vec::new();
-        let mut block_entities = Vec::new();
-
+        let mut infos = Vec::with_capacity(blocks.len());
         for block in blocks.clone() {
             let pos = Self::read_vec3(block.list("pos"), context, "block pos")?;
             let state_index = block
@@ -299,15 +608,30 @@ impl StructureTemplate {
                 ));
             };
             let nbt = block.compound("nbt").map(|nbt| nbt.to_owned());
-            let info = StructureBlockInfo {
+            infos.push(StructureBlockInfo {
                 pos: BlockPos::new(pos[0], pos[1], pos[2]),
                 state,
                 nbt,
-            };
+            });
+        }
+
+        Ok(Self::build_info_list(registry, infos))
+    }
+
+    /// Vanilla `StructureTemplate.buildInfoList`: full blocks first, then other
+    /// blocks, then block entities, each sorted by y, x, z.
+    fn build_info_list(
+        registry: &Registry,
+        infos: Vec<StructureBlockInfo>,
+    ) -> Vec<StructureBlockInfo> {
+        let mut full_blocks = Vec::new();
+        let mut other_blocks = Vec::new();
+        let mut block_entities = Vec::new();
 
+        for info in infos {
             if info.nbt.is_some() {
                 block_entities.push(info);
-            } else if Self::is_static_full_block(registry, state) {
+            } else if Self::is_static_full_block(registry, info.state) {
                 full_blocks.push(info);
             } else {
                 other_blocks.push(info);
@@ -320,7 +644,7 @@ impl StructureTemplate {
 
         full_blocks.extend(other_blocks);
         full_blocks.extend(block_entities);
-        Ok(full_blocks)
+        full_blocks
     }
 
     fn read_entities(
@@ -601,7 +925,7 @@ impl StructureTemplate {
         let Some(facing) = Self::entity_facing(nbt) else {
             return;
         };
-        let facing = Self::mirror_direction(rotation.rotate(facing), mirror);
+        let facing = mirror.mirror(rotation.rotate(facing));
         let _ = nbt.remove("Facing");
         nbt.insert("Facing", Self::entity_facing_value(facing));
     }
@@ -834,11 +1158,207 @@ impl StructureTemplate {
         true
     }
 
+    /// Vanilla `StructureTemplate.placeInWorld` against a live world, as used by
+    /// structure blocks.
+    ///
+    /// Blocks are placed with `UPDATE_CLIENTS` and then reshaped against their
+    /// neighbors, like vanilla's structure block placement. Only the settings'
+    /// block-ignore filter runs; structure voids keep whatever block the world
+    /// already has at their position.
+    // TODO: Run the remaining settings processors once they work on live worlds.
+    pub(crate) fn place_in_level(
+        &self,
+        world: &Arc<World>,
+        registry: &Registry,
+        position: BlockPos,
+        settings: &StructurePlaceSettings<'_>,
+        random: &mut WorldgenRandom,
+        include_entities: bool,
+    ) -> bool {
+        let Some(palette) = self.palette(settings, position, random) else {
+            return false;
+        };
+        if (palette.blocks.is_empty() && self.entities.is_empty())
+            || [self.size.x, self.size.y, self.size.z]
+                .iter()
+                .any(|&axis| axis < 1)
+        {
+            return false;
+        }
+
+        let flags = UpdateFlags::UPDATE_CLIENTS;
+        let mut placed_positions = Vec::with_capacity(palette.blocks.len());
+        let mut min = BlockPos::new(i32::MAX, i32::MAX, i32::MAX);
+        let mut max = BlockPos::new(i32::MIN, i32::MIN, i32::MIN);
+        for block in &palette.blocks {
+            if settings.block_ignore.ignores(registry, block.state)
+                || Self::block_for_state(registry, block.state) == &vanilla_blocks::STRUCTURE_VOID
+            {
+                continue;
+            }
+            let world_pos = Self::transformed_position(position, block.pos, settings);
+            if !settings.bounding_box.contains_blockpos(world_pos) {
+                continue;
+            }
+
+            let state =
+                Self::transform_state(registry, block.state, settings.mirror, settings.rotation);
+            if block.nbt.is_some() {
+                // Vanilla swaps in a barrier first so the old block entity is cleared
+                // without dropping its contents.
+                world.set_block(
+                    world_pos,
+                    vanilla_blocks::BARRIER.default_state(),
+                    UpdateFlags::UPDATE_INVISIBLE
+                        | UpdateFlags::UPDATE_SUPPRESS_DROPS
+                        | UpdateFlags::UPDATE_SKIP_ON_PLACE,
+                );
+            }
+            if !world.set_block(world_pos, state, flags) {
+                continue;
+            }
+            if let Some(nbt) = &block.nbt {
+                Self::load_level_block_entity(world, registry, world_pos, state, nbt, random);
+            }
+
+            min = BlockPos::new(
+                min.x().min(world_pos.x()),
+                min.y().min(world_pos.y()),
+                min.z().min(world_pos.z()),
+            );
+            max = BlockPos::new(
+                max.x().max(world_pos.x()),
+                max.y().max(world_pos.y()),
+                max.z().max(world_pos.z()),
+            );
+            placed_positions.push(world_pos);
+        }
+
+        if !placed_positions.is_empty() {
+            Self::update_shape_at_edge(world, flags, &placed_positions, min, max);
+            let placed_update_flags = flags | UpdateFlags::UPDATE_KNOWN_SHAPE;
+            for pos in placed_positions {
+                let state = world.get_block_state(pos);
+                let new_state = Self::update_from_neighbor_shapes(world, state, pos);
+                if state != new_state {
+                    world.set_block(pos, new_state, placed_update_flags);
+                }
+            }
+        }
+
+        if include_entities {
+            self.create_entities(position, settings, &Arc::downgrade(world), |entity| {
+                if let Err(error) = world.try_add_entity(entity) {
+                    log::warn!("failed to add structure template entity: {error}");
+                }
+            });
+        }
+
+        true
+    }
+
+    /// Places the whole template into a live world with only a mirror and rotation,
+    /// as structure blocks and `/place template` do.
+    ///
+    /// A `seed` of 0 uses a random seed, like vanilla `StructureBlockEntity.createRandom`.
+    // TODO: Apply `integrity` through the block rot processor and honour `strict`
+    // once live placement runs processors and accepts update flags.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "mirrors the settings vanilla's structure block and place command pass"
+    )]
+    pub(crate) fn place_with_transform(
+        &self,
+        world: &Arc<World>,
+        registry: &Registry,
+        position: BlockPos,
+        mirror: StructureMirror,
+        rotation: Rotation,
+        include_entities: bool,
+        seed: i64,
+    ) -> bool {
+        let settings = StructurePlaceSettings {
+            mirror,
+            rotation,
+            rotation_pivot: BlockPos::ZERO,
+            bounding_box: self.bounding_box_with_transform(
+                position,
+                rotation,
+                mirror,
+                BlockPos::ZERO,
+            ),
+            processors: &[],
+            block_ignore: StructureBlockIgnore::None,
+            late_block_ignore: StructureBlockIgnore::None,
+            replace_jigsaws: false,
+            projection: None,
+            processor_random: StructureProcessorRandom::Placement,
+            liquid_settings: LiquidSettingsData::ApplyWaterlogging,
+        };
+        let seed = if seed == 0 { rand::random() } else { seed };
+        let mut random = WorldgenRandom::from_seed(seed as u64);
+        self.place_in_level(
+            world,
+            registry,
+            position,
+            &settings,
+            &mut random,
+            include_entities,
+        )
+    }
+
+    /// Loads template block entity data into the block entity the world created for
+    /// `state` at `pos`.
+    fn load_level_block_entity(
+        world: &Arc<World>,
+        registry: &Registry,
+        pos: BlockPos,
+        state: BlockStateId,
+        nbt: &NbtCompound,
+        random: &mut WorldgenRandom,
+    ) {
+        let Some(block_entity) = world.get_block_entity(pos) else {
+            return;
+        };
+        let mut nbt = nbt.clone();
+        let block_entity_type = Self::block_entity_type_for_nbt_or_state(registry, state, &nbt);
+        if Self::should_reseed_template_loot(block_entity_type, &nbt) {
+            nbt.insert("LootTableSeed", NbtTag::Long(random.next_i64()));
+        }
+
+        let mut nbt_bytes = Vec::new();
+        nbt.write(&mut nbt_bytes);
+        let Ok(nbt) = read_borrowed_compound(&mut Cursor::new(&nbt_bytes)) else {
+            log::warn!(
+                "failed to reborrow owned NBT for structure template block entity at {pos:?}"
+            );
+            return;
+        };
+        let mut block_entity = block_entity.lock();
+        block_entity.load_additional(&nbt);
+        block_entity.set_changed();
+    }
+
     fn place_entities(
         &self,
         region: &mut WorldGenRegion<'_>,
         position: BlockPos,
         settings: &StructurePlaceSettings<'_>,
+    ) {
+        let world = region.weak_world();
+        self.create_entities(position, settings, &world, |entity| {
+            let _ = region.add_fresh_entity(entity);
+        });
+    }
+
+    /// Builds the template's entities at their transformed positions and hands each
+    /// one to `add`.
+    fn create_entities(
+        &self,
+        position: BlockPos,
+        settings: &StructurePlaceSettings<'_>,
+        world: &Weak<World>,
+        mut add: impl FnMut(SharedEntity),
     ) {
         if self.entities.is_empty() {
             return;
@@ -896,11 +1416,11 @@ impl StructureTemplate {
                     fire_freeze: entity.fire_freeze,
                     on_ground: entity.on_ground,
                     save_data: entity.save_data.clone(),
-                    world: region.weak_world(),
+                    world: world.clone(),
                 },
                 &nbt,
             );
-            let _ = region.add_fresh_entity(runtime_entity);
+            add(runtime_entity);
         }
     }
 
@@ -978,7 +1498,7 @@ impl StructureTemplate {
     }
 
     fn update_shape_at_edge(
-        region: &WorldGenRegion<'_>,
+        level: &dyn LevelAccessor,
         flags: UpdateFlags,
         placed_positions: &[BlockPos],
         min: BlockPos,
@@ -1001,51 +1521,44 @@ impl StructureTemplate {
             |direction, x, y, z| {
                 let pos = min.offset(x, y, z);
                 let neighbor_pos = pos.relative(direction);
-                let state = region.block_state(pos);
-                let neighbor_state = region.block_state(neighbor_pos);
+                let state = level.get_block_state(pos);
+                let neighbor_state = level.get_block_state(neighbor_pos);
                 let new_state = BLOCK_BEHAVIORS
                     .get_behavior(state.get_block())
-                    .update_shape(state, region, pos, direction, neighbor_pos, neighbor_state);
+                    .update_shape(state, level, pos, direction, neighbor_pos, neighbor_state);
                 if state != new_state {
-                    let _ = region.set_block_state(pos, new_state, edge_flags);
+                    let _ = level.set_block_state(pos, new_state, edge_flags);
                 }
 
                 let new_neighbor_state = BLOCK_BEHAVIORS
                     .get_behavior(neighbor_state.get_block())
                     .update_shape(
                         neighbor_state,
-                        region,
+                        level,
                         neighbor_pos,
                         direction.opposite(),
                         pos,
                         new_state,
                     );
                 if neighbor_state != new_neighbor_state {
-                    let _ = region.set_block_state(neighbor_pos, new_neighbor_state, edge_flags);
+                    let _ = level.set_block_state(neighbor_pos, new_neighbor_state, edge_flags);
                 }
             },
         );
     }
 
     fn update_from_neighbor_shapes(
-        region: &WorldGenRegion<'_>,
+        level: &dyn LevelAccessor,
         state: BlockStateId,
         pos: BlockPos,
     ) -> BlockStateId {
         let mut updated = state;
         for direction in Direction::UPDATE_SHAPE_ORDER {
             let neighbor_pos = pos.relative(direction);
-            let neighbor_state = region.block_state(neighbor_pos);
+            let neighbor_state = level.get_block_state(neighbor_pos);
             updated = BLOCK_BEHAVIORS
                 .get_behavior(updated.get_block())
-                .update_shape(
-                    updated,
-                    region,
-                    pos,
-                    direction,
-                    neighbor_pos,
-                    neighbor_state,
-                );
+                .update_shape(updated, level, pos, direction, neighbor_pos, neighbor_state);
         }
         updated
     }
@@ -2044,36 +2557,8 @@ impl StructureTemplate {
         mirror: StructureMirror,
         rotation: Rotation,
     ) -> BlockStateId {
-        if mirror == StructureMirror::None && rotation == Rotation::None {
-            return state;
-        }
-
-        let Some(block) = registry.blocks.by_state_id(state) else {
-            return state;
-        };
-        let mut properties = registry
-            .blocks
-            .get_properties(state)
-            .into_iter()
-            .map(|(name, value)| (name.to_owned(), value.to_owned()))
-            .collect::<Vec<_>>();
-
-        Self::mirror_string_properties(&mut properties, mirror);
-        Self::rotate_string_properties(&mut properties, rotation);
-        let property_refs = properties
-            .iter()
-            .map(|(name, value)| (name.as_str(), value.as_str()))
-            .collect::<Vec<_>>();
-        let Some(rotated) = registry
-            .blocks
-            .state_id_from_properties(&block.key, &property_refs)
-        else {
-            panic!(
-                "rotating block state {} produced invalid properties",
-                block.key
-            );
-        };
-        rotated
+        let mirrored = registry.blocks.mirror_state(state, mirror);
+        registry.blocks.rotate_state(mirrored, rotation)
     }
 
     fn block_for_state(registry: &Registry, state: BlockStateId) -> BlockRef {
@@ -2086,324 +2571,6 @@ impl StructureTemplate {
         block
     }
 
-    fn rotate_string_properties(properties: &mut [(String, String)], rotation: Rotation) {
-        let original = properties.to_vec();
-        for (name, value) in properties.iter_mut() {
-            match name.as_str() {
-                "axis"
-                    if matches!(
-                        rotation,
-                        Rotation::Clockwise90 | Rotation::CounterClockwise90
-                    ) =>
-                {
-                    match value.as_str() {
-                        "x" => "z".clone_into(value),
-                        "z" => "x".clone_into(value),
-                        _ => {}
-                    }
-                }
-                "facing" => {
-                    if let Some(direction) = Self::parse_direction(value) {
-                        rotation.rotate(direction).as_str().clone_into(value);
-                    }
-                }
-                "rotation" => {
-                    if let Ok(segment) = value.parse::<i32>() {
-                        let rotated = match rotation {
-                            Rotation::None => segment,
-                            Rotation::Clockwise90 => segment + 4,
-                            Rotation::Clockwise180 => segment + 8,
-                            Rotation::CounterClockwise90 => segment + 12,
-                        };
-                        *value = (rotated & 15).to_string();
-                    }
-                }
-                "shape" => {
-                    if let Some(rotated) = Self::rotate_rail_shape(value, rotation) {
-                        rotated.clone_into(value);
-                    }
-                }
-                "north" | "east" | "south" | "west" => {
-                    let from = Self::direction_from_property_name(name);
-                    let source = Self::inverse_rotate_direction(rotation, from);
-                    if let Some(source_name) = Self::property_name_from_direction(source)
-                        && let Some((_, source_value)) = original
-                            .iter()
-                            .find(|(original_name, _)| original_name == source_name)
-                    {
-                        value.clone_from(source_value);
-                    }
-                }
-                _ => {}
-            }
-        }
-    }
-
-    fn mirror_string_properties(properties: &mut [(String, String)], mirror: StructureMirror) {
-        if mirror == StructureMirror::None {
-            return;
-        }
-
-        let original = properties.to_vec();
-        let facing = original
-            .iter()
-            .find(|(name, _)| name == "facing")
-            .and_then(|(_, value)| Self::parse_direction(value));
-        let stair_shape = original
-            .iter()
-            .find(|(name, _)| name == "shape")
-            .and_then(|(_, value)| Self::parse_stair_shape(value));
-
-        let mirrored_stairs = facing
-            .zip(stair_shape)
-            .and_then(|(direction, shape)| Self::mirror_stair_shape(direction, shape, mirror));
-
-        for (name, value) in properties.iter_mut() {
-            match name.as_str() {
-                "facing" => {
-                    if let Some((mirrored_facing, _)) = mirrored_stairs {
-                        mirrored_facing.as_str().clone_into(value);
-                    } else if let Some(direction) = Self::parse_direction(value) {
-                        Self::mirror_direction(direction, mirror)
-                            .as_str()
-                            .clone_into(value);
-                    }
-                }
-                "rotation" => {
-                    if let Ok(segment) = value.parse::<i32>() {
-                        *value = Self::mirror_rotation_segment(segment, 16, mirror).to_string();
-                    }
-                }
-                "hinge" => match value.as_str() {
-                    "left" => "right".clone_into(value),
-                    "right" => "left".clone_into(value),
-                    _ => {}
-                },
-                "shape" => {
-                    if let Some((_, mirrored_shape)) = mirrored_stairs {
-                        mirrored_shape.clone_into(value);
-                    } else if let Some(mirrored_shape) = Self::mirror_rail_shape(value, mirror) {
-                        mirrored_shape.clone_into(value);
-                    }
-                }
-                "north" | "east" | "south" | "west" => {
-                    let from = Self::direction_from_property_name(name);
-                    let source = Self::mirror_direction(from, mirror);
-                    if let Some(source_name) = Self::property_name_from_direction(source)
-                        && let Some((_, source_value)) = original
-                            .iter()
-                            .find(|(original_name, _)| original_name == source_name)
-                    {
-                        value.clone_from(source_value);
-                    }
-                }
-                _ => {}
-            }
-        }
-    }
-
-    fn parse_direction(value: &str) -> Option<Direction> {
-        match value {
-            "down" => Some(BlockPropertyDirection::Down),
-            "up" => Some(BlockPropertyDirection::Up),
-            "north" => Some(BlockPropertyDirection::North),
-            "south" => Some(BlockPropertyDirection::South),
-            "west" => Some(BlockPropertyDirection::West),
-            "east" => Some(BlockPropertyDirection::East),
-            _ => None,
-        }
-    }
-
-    fn direction_from_property_name(name: &str) -> Direction {
-        match name {
-            "east" => BlockPropertyDirection::East,
-            "south" => BlockPropertyDirection::South,
-            "west" => BlockPropertyDirection::West,
-            _ => BlockPropertyDirection::North,
-        }
-    }
-
-    const fn mirror_direction(direction: Direction, mirror: StructureMirror) -> Direction {
-        match mirror {
-            StructureMirror::FrontBack => match direction {
-                BlockPropertyDirection::West => BlockPropertyDirection::East,
-                BlockPropertyDirection::East => BlockPropertyDirection::West,
-                other => other,
-            },
-            StructureMirror::LeftRight => match direction {
-                BlockPropertyDirection::North => BlockPropertyDirection::South,
-                BlockPropertyDirection::South => BlockPropertyDirection::North,
-                other => other,
-            },
-            StructureMirror::None => direction,
-        }
-    }
-
-    const fn mirror_rotation_segment(rotation: i32, steps: i32, mirror: StructureMirror) -> i32 {
-        let half_steps = steps / 2;
-        let corrected = if rotation > half_steps {
-            rotation - steps
-        } else {
-            rotation
-        };
-        match mirror {
-            StructureMirror::LeftRight => (half_steps - corrected + steps) % steps,
-            StructureMirror::FrontBack => (steps - corrected) % steps,
-            StructureMirror::None => rotation,
-        }
-    }
-
-    const fn inverse_rotate_direction(rotation: Rotation, direction: Direction) -> Direction {
-        match rotation {
-            Rotation::None => direction,
-            Rotation::Clockwise90 => Rotation::CounterClockwise90.rotate(direction),
-            Rotation::Clockwise180 => Rotation::Clockwise180.rotate(direction),
-            Rotation::CounterClockwise90 => Rotation::Clockwise90.rotate(direction),
-        }
-    }
-
-    const fn property_name_from_direction(direction: Direction) -> Option<&'static str> {
-        match direction {
-            BlockPropertyDirection::North => Some("north"),
-            BlockPropertyDirection::East => Some("east"),
-            BlockPropertyDirection::South => Some("south"),
-            BlockPropertyDirection::West => Some("west"),
-            BlockPropertyDirection::Down | BlockPropertyDirection::Up => None,
-        }
-    }
-
-    fn rotate_rail_shape(shape: &str, rotation: Rotation) -> Option<&'static str> {
-        match rotation {
-            Rotation::Clockwise180 => match shape {
-                "ascending_east" => Some("ascending_west"),
-                "ascending_west" => Some("ascending_east"),
-                "ascending_north" => Some("ascending_south"),
-                "ascending_south" => Some("ascending_north"),
-                "north_south" => Some("north_south"),
-                "east_west" => Some("east_west"),
-                "south_east" => Some("north_west"),
-                "south_west" => Some("north_east"),
-                "north_west" => Some("south_east"),
-                "north_east" => Some("south_west"),
-                _ => None,
-            },
-            Rotation::CounterClockwise90 => match shape {
-                "ascending_east" => Some("ascending_north"),
-                "ascending_west" => Some("ascending_south"),
-                "ascending_north" => Some("ascending_west"),
-                "ascending_south" => Some("ascending_east"),
-                "north_south" => Some("east_west"),
-                "east_west" => Some("north_south"),
-                "south_east" => Some("north_east"),
-                "south_west" => Some("south_east"),
-                "north_west" => Some("south_west"),
-                "north_east" => Some("north_west"),
-                _ => None,
-            },
-            Rotation::Clockwise90 => match shape {
-                "ascending_east" => Some("ascending_south"),
-                "ascending_west" => Some("ascending_north"),
-                "ascending_north" => Some("ascending_east"),
-                "ascending_south" => Some("ascending_west"),
-                "north_south" => Some("east_west"),
-                "east_west" => Some("north_south"),
-                "south_east" => Some("south_west"),
-                "south_west" => Some("north_west"),
-                "north_west" => Some("north_east"),
-                "north_east" => Some("south_east"),
-                _ => None,
-            },
-            Rotation::None => None,
-        }
-    }
-
-    fn mirror_rail_shape(shape: &str, mirror: StructureMirror) -> Option<&'static str> {
-        match mirror {
-            StructureMirror::LeftRight => match shape {
-                "ascending_north" => Some("ascending_south"),
-                "ascending_south" => Some("ascending_north"),
-                "north_south" => Some("north_south"),
-                "east_west" => Some("east_west"),
-                "south_east" => Some("north_east"),
-                "south_west" => Some("north_west"),
-                "north_west" => Some("south_west"),
-                "north_east" => Some("south_east"),
-                _ => None,
-            },
-            StructureMirror::FrontBack => match shape {
-                "ascending_east" => Some("ascending_west"),
-                "ascending_west" => Some("ascending_east"),
-                "ascending_north" => Some("ascending_north"),
-                "ascending_south" => Some("ascending_south"),
-                "north_south" => Some("north_south"),
-                "east_west" => Some("east_west"),
-                "south_east" => Some("south_west"),
-                "south_west" => Some("south_east"),
-                "north_west" => Some("north_east"),
-                "north_east" => Some("north_west"),
-                _ => None,
-            },
-            StructureMirror::None => None,
-        }
-    }
-
-    fn parse_stair_shape(shape: &str) -> Option<&'static str> {
-        match shape {
-            "straight" => Some("straight"),
-            "inner_left" => Some("inner_left"),
-            "inner_right" => Some("inner_right"),
-            "outer_left" => Some("outer_left"),
-            "outer_right" => Some("outer_right"),
-            _ => None,
-        }
-    }
-
-    fn mirror_stair_shape(
-        direction: Direction,
-        shape: &str,
-        mirror: StructureMirror,
-    ) -> Option<(Direction, &'static str)> {
-        match mirror {
-            StructureMirror::LeftRight
-                if matches!(
-                    direction,
-                    BlockPropertyDirection::North | BlockPropertyDirection::South
-                ) =>
-            {
-                Some((
-                    direction.opposite(),
-                    match shape {
-                        "outer_left" => "outer_right",
-                        "inner_right" => "inner_left",
-                        "inner_left" => "inner_right",
-                        "outer_right" => "outer_left",
-                        "straight" => "straight",
-                        _ => return None,
-                    },
-                ))
-            }
-            StructureMirror::FrontBack
-                if matches!(
-                    direction,
-                    BlockPropertyDirection::West | BlockPropertyDirection::East
-                ) =>
-            {
-                Some((
-                    direction.opposite(),
-                    match shape {
-                        "outer_left" => "outer_right",
-                        "outer_right" => "outer_left",
-                        "inner_left" => "inner_left",
-                        "inner_right" => "inner_right",
-                        "straight" => "straight",
-                        _ => return None,
-                    },
-                ))
-            }
-            StructureMirror::None | StructureMirror::LeftRight | StructureMirror::FrontBack => None,
-        }
-    }
-
     fn block_pos_seed(pos: BlockPos) -> i64 {
         let mut seed = i64::from(pos.x().wrapping_mul(3_129_871))
             ^ i64::from(pos.z()).wrapping_mul(116_129_781)
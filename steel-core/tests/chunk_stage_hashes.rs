@@ -265,6 +265,8 @@ fn create_test_world(
                 generation_settings,
                 view_distance: 2,
                 simulation_distance: 2,
+                entity_broadcast_range_percentage:
+                    steel_core::entity::DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE,
                 compression: None,
                 is_flat: false,
                 sea_level,
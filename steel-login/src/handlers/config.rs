@@ -1,29 +1,47 @@
 //! Configuration state packet handlers.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use steel_core::entity::next_entity_id;
 use steel_core::player::PlayerConnection;
 use steel_core::player::networking::JavaConnection;
-use steel_core::player::{ClientInformation, Player};
-use steel_protocol::packets::common::CCustomPayload;
-use steel_protocol::packets::common::{SClientInformation, SCustomPayload};
-use steel_protocol::packets::config::CFinishConfiguration;
+use steel_core::player::plugin_messages::{BRAND, brand_payload};
+use steel_core::player::{ClientInformation, Player, declines_required_pack};
+use steel_core::server::join_queue::{JoinQueuePoll, queue_position_dialog};
+use steel_protocol::packets::common::{CCustomPayload, CKeepAlive};
+use steel_protocol::packets::common::{SClientInformation, SCustomPayload, SResourcePack};
 use steel_protocol::packets::config::CSelectKnownPacks;
 use steel_protocol::packets::config::SSelectKnownPacks;
+use steel_protocol::packets::config::{CClearDialog, CFinishConfiguration, CShowDialog};
 use steel_protocol::packets::shared_implementation::KnownPack;
 use steel_protocol::utils::ConnectionProtocol;
-use steel_utils::Identifier;
+use steel_utils::translations;
+use text_components::TextComponent;
 
 use crate::tcp_client::{ConnectionAction, ConnectionUpdate, JavaTcpClient};
 
-const BRAND_PAYLOAD: [u8; 5] = *b"Steel";
+const SERVER_BRAND: &str = "Steel";
+/// Waiting players must hear from the server well within the client's
+/// 30 second read timeout.
+const MAX_JOIN_QUEUE_UPDATE_INTERVAL: Duration = Duration::from_secs(15);
 
 impl JavaTcpClient {
     /// Handles a custom payload packet during the configuration state.
-    #[expect(clippy::unused_self, reason = "this is an api function")]
-    pub fn handle_config_custom_payload(&self, packet: SCustomPayload) {
-        log::debug!("Custom payload packet: {packet:?}");
+    ///
+    /// Registered channels are only routed once the player exists, in play.
+    pub async fn handle_config_custom_payload(&self, packet: SCustomPayload) {
+        if !self
+            .client_channels
+            .lock()
+            .await
+            .handle(&packet.identifier, &packet.payload.0)
+        {
+            log::debug!(
+                "Ignoring plugin message on {} during configuration",
+                packet.identifier
+            );
+        }
     }
 
     /// Handles the client information packet during the configuration state.
@@ -33,10 +51,7 @@ impl JavaTcpClient {
         // Convert packet to our ClientInformation struct and store it
         let info = ClientInformation {
             language: packet.language,
-            view_distance: packet
-                .view_distance
-                .clamp(2, i32::from(self.server.config.view_distance).max(2))
-                as u8,
+            view_distance: packet.view_distance.clamp(2, i32::from(u8::MAX)) as u8,
             chat_visibility: packet.chat_visibility,
             chat_colors: packet.chat_colors,
             model_customization: packet.model_customization,
@@ -51,17 +66,20 @@ impl JavaTcpClient {
 
     /// Starts the configuration process by sending initial packets.
     pub async fn start_configuration(&self) {
-        self.send_bare_packet_now(CCustomPayload::new(
-            Identifier::vanilla_static("brand"),
-            Box::new(BRAND_PAYLOAD),
-        ))
-        .await;
+        self.send_bare_packet_now(CCustomPayload::new(BRAND, brand_payload(SERVER_BRAND)))
+            .await;
 
         // Send server links if enabled and configured
-        if let Some(server_links) = self.server.config.server_links_packet() {
+        let server_links = self.server.config.load().server_links_packet();
+        if let Some(server_links) = server_links {
             self.send_bare_packet_now(server_links).await;
         }
 
+        let report_details = self.server.config.load().report_details_packet();
+        if let Some(report_details) = report_details {
+            self.send_bare_packet_now(report_details).await;
+        }
+
         self.send_bare_packet_now(CSelectKnownPacks::new(vec![KnownPack::new(
             "minecraft".to_string(),
             "core".to_string(),
@@ -74,16 +92,109 @@ impl JavaTcpClient {
     pub async fn handle_select_known_packs(&self, packet: SSelectKnownPacks) {
         log::debug!("Select known packs packet: {packet:?}");
 
-        let registry_cache = self.server.registry_cache.registry_packets.clone();
+        let registry_cache = self.server.registry_cache.registry_packets();
         for encoded_packet in registry_cache.iter() {
             self.send_packet_now(encoded_packet).await;
         }
 
         // Send the packet for tags
-        self.send_packet_now(&self.server.registry_cache.tags_packet)
+        self.send_packet_now(&self.server.registry_cache.tags_packet())
             .await;
 
-        // Finish configuration with CFinishConfigurationPacket
+        let config = self.server.config.load_full();
+        if config.resource_packs.is_empty() {
+            self.finish_when_admitted().await;
+            return;
+        }
+        // Vanilla `ServerResourcePackConfigurationTask`: configuration finishes
+        // once the client is done with every pack.
+        for pack in &config.resource_packs {
+            self.send_bare_packet_now(pack.push_packet()).await;
+        }
+    }
+
+    /// Handles a resource pack response during the configuration state.
+    pub async fn handle_config_resource_pack(&self, packet: SResourcePack) {
+        log::debug!("Resource pack packet: {packet:?}");
+
+        if declines_required_pack(&self.server.config.load(), &packet) {
+            self.kick(TextComponent::translated(
+                translations::MULTIPLAYER_REQUIRED_TEXTURE_PROMPT_DISCONNECT.msg(),
+            ))
+            .await;
+            return;
+        }
+
+        let mut statuses = self.resource_packs.lock().await;
+        let was_done = statuses.all_terminal(&self.server.config.load());
+        statuses.record(packet.id, packet.action);
+        let done = statuses.all_terminal(&self.server.config.load());
+        drop(statuses);
+
+        if done && !was_done {
+            self.finish_when_admitted().await;
+        }
+    }
+
+    /// Sends `CFinishConfiguration` once the player has a slot.
+    ///
+    /// While the server is full, the player waits in the join queue with its
+    /// position shown in a dialog, or is disconnected if the queue is disabled.
+    async fn finish_when_admitted(&self) {
+        let Some(uuid) = self
+            .gameprofile
+            .lock()
+            .await
+            .as_ref()
+            .map(|profile| profile.id)
+        else {
+            return;
+        };
+        let mut ticket = self.server.join_ticket(uuid);
+        let mut keep_alive_id = 0;
+        let mut showing_position = false;
+        loop {
+            match self.server.poll_join_queue(&mut ticket, uuid) {
+                JoinQueuePoll::Admitted => break,
+                JoinQueuePoll::Full => {
+                    self.kick(TextComponent::translated(
+                        translations::MULTIPLAYER_DISCONNECT_SERVER_FULL.msg(),
+                    ))
+                    .await;
+                    return;
+                }
+                JoinQueuePoll::Waiting(position) => {
+                    keep_alive_id += 1;
+                    self.send_bare_packet_now(CKeepAlive::new(keep_alive_id))
+                        .await;
+                    let waiting = self.server.join_queue_len().max(position);
+                    self.send_bare_packet_now(CShowDialog::new(queue_position_dialog(
+                        position, waiting,
+                    )))
+                    .await;
+                    showing_position = true;
+                }
+            }
+
+            let interval = Duration::from_secs(
+                self.server
+                    .config
+                    .load()
+                    .join_queue
+                    .update_interval_seconds
+                    .max(1),
+            )
+            .min(MAX_JOIN_QUEUE_UPDATE_INTERVAL);
+            tokio::select! {
+                () = tokio::time::sleep(interval) => {}
+                () = self.cancel_token.cancelled() => return,
+            }
+        }
+
+        if showing_position {
+            self.send_bare_packet_now(CClearDialog {}).await;
+        }
+        *self.join_ticket.lock() = Some(ticket);
         self.send_bare_packet_now(CFinishConfiguration {}).await;
     }
 
@@ -92,6 +203,16 @@ impl JavaTcpClient {
     /// # Panics
     /// This function will panic if the game profile is empty, should be impossible at this point.
     pub(crate) async fn finish_configuration(&self) -> ConnectionAction {
+        // The ticket is only handed out with `CFinishConfiguration`, so a
+        // client finishing without one is skipping the join queue.
+        let join_ticket = self.join_ticket.lock().take();
+        let Some(join_ticket) = join_ticket else {
+            self.kick(TextComponent::translated(
+                translations::DISCONNECT_PACKET_ERROR.msg(),
+            ))
+            .await;
+            return ConnectionAction::none();
+        };
         self.protocol.store(ConnectionProtocol::Play);
 
         let gameprofile = self
@@ -102,6 +223,8 @@ impl JavaTcpClient {
             .expect("Game profile is empty");
 
         let client_info = self.client_information.lock().await.clone();
+        let resource_packs = self.resource_packs.lock().await.clone();
+        let client_channels = self.client_channels.lock().await.clone();
 
         let world = self.server.overworld().clone();
         let entity_id = next_entity_id();
@@ -113,7 +236,9 @@ impl JavaTcpClient {
                 self.compression.load(),
                 self.network_writer.clone(),
                 self.id,
+                self.transferred.load(),
                 player_weak.clone(),
+                Arc::clone(&self.traffic),
             );
             let connection = Arc::new(PlayerConnection::Java(java_connection));
 
@@ -128,6 +253,8 @@ impl JavaTcpClient {
                 client_info,
             )
         });
+        player.set_resource_pack_statuses(resource_packs);
+        player.set_client_channels(client_channels);
 
         let connection = Arc::clone(&player.connection);
         if self
@@ -144,6 +271,8 @@ impl JavaTcpClient {
             () = self.cancel_token.cancelled() => return ConnectionAction::none(),
         }
         self.server.queue_player_join(player);
+        // The player now counts as joining, so the claimed slot can go.
+        drop(join_ticket);
 
         ConnectionAction::upgrade(connection)
     }
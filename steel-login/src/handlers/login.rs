@@ -3,16 +3,21 @@
 use rsa::Pkcs1v15Encrypt;
 use sha1::Sha1;
 use sha2::Digest;
+use steel_core::config::ProxyForwarding;
 use steel_core::player::GameProfile;
 use steel_protocol::{
-    packets::login::{CHello, CLoginCompression, CLoginFinished, SHello, SKey},
+    packets::login::{
+        CCustomQuery, CHello, CLoginCompression, CLoginFinished, SCustomQueryAnswer, SHello, SKey,
+    },
     utils::ConnectionProtocol,
 };
 use steel_utils::translations;
 use text_components::TextComponent;
 
 use crate::{
-    AuthError, is_valid_player_name, mojang_authenticate, offline_uuid, signed_bytes_be_to_hex,
+    AuthError, is_valid_player_name, mojang_authenticate, offline_uuid,
+    proxy::{VELOCITY_CHANNEL, VELOCITY_FORWARDING_VERSION, parse_velocity_data},
+    signed_bytes_be_to_hex,
     tcp_client::{ConnectionAction, ConnectionUpdate, JavaTcpClient},
 };
 
@@ -27,10 +32,44 @@ impl JavaTcpClient {
             return ConnectionAction::none();
         }
 
-        let id = if self.server.config.online_mode {
-            packet.profile_id
+        let forwarding = self.server.config.load().proxy.forwarding;
+        match forwarding {
+            ProxyForwarding::None => {}
+            ProxyForwarding::BungeeCord => {
+                let Some(forwarded) = self.forwarded.lock().clone() else {
+                    self.kick("No forwarded player information".into()).await;
+                    return ConnectionAction::none();
+                };
+                let profile = GameProfile {
+                    id: forwarded.id,
+                    name: packet.name,
+                    properties: forwarded.properties,
+                    profile_actions: None,
+                };
+                *self.gameprofile.lock().await = Some(profile.clone());
+                return self.finish_login(&profile).await;
+            }
+            ProxyForwarding::Velocity => {
+                let transaction_id = rand::random();
+                self.velocity_query.store(Some(transaction_id));
+                self.send_bare_packet_now(CCustomQuery::new(
+                    transaction_id,
+                    VELOCITY_CHANNEL,
+                    Box::new([VELOCITY_FORWARDING_VERSION]),
+                ))
+                .await;
+                return ConnectionAction::none();
+            }
+        }
+
+        let (id, properties) = if self.server.config.load().online_mode {
+            // The authenticated profile replaces this one, textures included.
+            (packet.profile_id, vec![])
         } else {
-            offline_uuid(&packet.name)
+            (
+                offline_uuid(&packet.name),
+                self.server.resolve_offline_skin(&packet.name).await,
+            )
         };
 
         {
@@ -38,12 +77,12 @@ impl JavaTcpClient {
             *gameprofile = Some(GameProfile {
                 id,
                 name: packet.name.clone(),
-                properties: vec![],
+                properties: properties.clone(),
                 profile_actions: None,
             });
         }
 
-        if self.server.config.encryption {
+        if self.server.config.load().encryption {
             let challenge: [u8; 4] = rand::random();
             self.challenge.store(challenge);
 
@@ -59,7 +98,7 @@ impl JavaTcpClient {
                 .finish_login(&GameProfile {
                     id,
                     name: packet.name,
-                    properties: vec![],
+                    properties,
                     profile_actions: None,
                 })
                 .await;
@@ -124,7 +163,7 @@ impl JavaTcpClient {
             return ConnectionAction::none();
         };
 
-        if self.server.config.online_mode {
+        if self.server.config.load().online_mode {
             let server_hash = &Sha1::new()
                 .chain_update(secret_key)
                 .chain_update(&self.server.key_store.public_key_der)
@@ -132,13 +171,8 @@ impl JavaTcpClient {
 
             let server_hash = signed_bytes_be_to_hex(server_hash);
 
-            match mojang_authenticate(
-                &profile.name,
-                &server_hash,
-                self.server.config.auth_server.as_deref(),
-            )
-            .await
-            {
+            let auth_server = self.server.config.load().auth_server.clone();
+            match mojang_authenticate(&profile.name, &server_hash, auth_server.as_deref()).await {
                 Ok(new_profile) => *profile = new_profile,
                 Err(error) => {
                     self.kick(match error {
@@ -171,13 +205,61 @@ impl JavaTcpClient {
             .with_reader_encryption(secret_key)
     }
 
+    /// Handles a login plugin answer. Only Velocity's forwarding query is expected.
+    pub(crate) async fn handle_custom_query_answer(
+        &self,
+        packet: SCustomQueryAnswer,
+    ) -> ConnectionAction {
+        if self.velocity_query.load() != Some(packet.transaction_id) {
+            self.kick(TextComponent::translated(
+                translations::MULTIPLAYER_DISCONNECT_UNEXPECTED_QUERY.msg(),
+            ))
+            .await;
+            return ConnectionAction::none();
+        }
+        self.velocity_query.store(None);
+
+        let Some(payload) = packet.payload else {
+            self.kick("This server requires you to connect with Velocity.".into())
+                .await;
+            return ConnectionAction::none();
+        };
+        let secret = self.server.config.load().proxy.secret.clone();
+        let forwarded = match parse_velocity_data(secret.as_bytes(), &payload.0) {
+            Ok(forwarded) => forwarded,
+            Err(error) => {
+                log::warn!(
+                    "Rejected Velocity forwarding for client {}: {error}",
+                    self.id
+                );
+                self.kick("Unable to verify player details".into()).await;
+                return ConnectionAction::none();
+            }
+        };
+
+        let Some(name) = forwarded.name.clone() else {
+            self.kick("Unable to verify player details".into()).await;
+            return ConnectionAction::none();
+        };
+        let profile = GameProfile {
+            id: forwarded.id,
+            name,
+            properties: forwarded.properties.clone(),
+            profile_actions: None,
+        };
+        *self.forwarded.lock() = Some(forwarded);
+        *self.gameprofile.lock().await = Some(profile.clone());
+        self.finish_login(&profile).await
+    }
+
     /// Finishes the login process and transitions to the configuration state.
     ///
     /// # Panics
     /// This function will panic if the compression threshold cannot be converted to an i32.
     pub(crate) async fn finish_login(&self, profile: &GameProfile) -> ConnectionAction {
         let mut action = ConnectionAction::none();
-        if let Some(compression) = self.server.config.compression {
+        let compression = self.server.config.load().compression;
+        if let Some(compression) = compression {
             self.send_bare_packet_now(CLoginCompression::new(
                 compression
                     .threshold
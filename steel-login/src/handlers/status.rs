@@ -7,6 +7,7 @@ use steel_protocol::packets::{
 };
 use steel_registry::packets::CURRENT_MC_PROTOCOL;
 use steel_utils::MC_VERSION;
+use steel_utils::text::parse_markup;
 
 use crate::tcp_client::JavaTcpClient;
 
@@ -14,9 +15,9 @@ impl JavaTcpClient {
     /// Handles a status request from the client.
     pub async fn handle_status_request(&self) {
         let res_packet = CStatusResponse::new(Status {
-            description: self.server.config.motd.clone(),
+            description: parse_markup(&self.server.config.load().motd),
             players: Some(Players {
-                max: self.server.config.max_players.cast_signed(),
+                max: self.server.config.load().max_players.cast_signed(),
                 online: self.server.player_count() as i32,
                 sample: self
                     .server
@@ -25,8 +26,8 @@ impl JavaTcpClient {
                     .map(|(name, id)| Sample { name, id })
                     .collect(),
             }),
-            enforce_secure_chat: self.server.config.enforce_secure_chat,
-            favicon: load_favicon(&self.server.config),
+            enforce_secure_chat: self.server.config.load().enforce_secure_chat,
+            favicon: load_favicon(&self.server.config.load()),
             version: Some(Version {
                 name: MC_VERSION,
                 protocol: CURRENT_MC_PROTOCOL,
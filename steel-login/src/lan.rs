@@ -0,0 +1,79 @@
+//! Vanilla `LanServerPinger`: announces the server to clients on the local
+//! network, which list it under "Scanning for games on your local network".
+
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::sync::Arc;
+use std::time::Duration;
+
+use steel_core::server::Server;
+use steel_utils::text::{DisplayResolutor, parse_markup};
+use tokio::net::UdpSocket;
+use tokio::select;
+use tokio::time::{MissedTickBehavior, interval};
+use tokio_util::sync::CancellationToken;
+
+/// Vanilla `LanServerPinger.MULTICAST_GROUP`.
+const MULTICAST_GROUP: Ipv4Addr = Ipv4Addr::new(224, 0, 2, 60);
+/// Vanilla `LanServerPinger.PING_PORT`.
+const PING_PORT: u16 = 4445;
+/// Vanilla `LanServerPinger.PING_INTERVAL`.
+const PING_INTERVAL: Duration = Duration::from_millis(1500);
+
+/// Vanilla `LanServerPinger.createPingString`.
+#[must_use]
+pub fn lan_ping_message(motd: &str, port: u16) -> String {
+    format!("[MOTD]{motd}[/MOTD][AD]{port}[/AD]")
+}
+
+/// Periodically multicasts the server's MOTD and port to the local network.
+pub struct LanBroadcaster {
+    socket: UdpSocket,
+    server: Arc<Server>,
+    server_port: u16,
+}
+
+impl LanBroadcaster {
+    /// Binds the socket the announcements are sent from.
+    ///
+    /// # Errors
+    /// Returns an error if the socket cannot be bound.
+    pub async fn bind(server: Arc<Server>, server_port: u16) -> io::Result<Self> {
+        Ok(Self {
+            socket: UdpSocket::bind(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)).await?,
+            server,
+            server_port,
+        })
+    }
+
+    /// Announces the server until `cancel_token` is cancelled.
+    pub async fn run(self, cancel_token: CancellationToken) {
+        let target = SocketAddr::from((MULTICAST_GROUP, PING_PORT));
+        let mut ticker = interval(PING_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            select! {
+                () = cancel_token.cancelled() => break,
+                _ = ticker.tick() => {}
+            }
+            let motd = parse_markup(&self.server.config.load().motd).to_plain(&DisplayResolutor);
+            let message = lan_ping_message(&motd, self.server_port);
+            if let Err(err) = self.socket.send_to(message.as_bytes(), target).await {
+                log::debug!("Failed to send LAN announcement: {err}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lan_ping_message;
+
+    #[test]
+    fn ping_message_matches_vanilla_format() {
+        assert_eq!(
+            lan_ping_message("A Steel Server", 25565),
+            "[MOTD]A Steel Server[/MOTD][AD]25565[/AD]"
+        );
+    }
+}
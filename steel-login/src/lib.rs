@@ -9,6 +9,9 @@
 mod authentication;
 mod connection;
 mod handlers;
+mod lan;
+mod proxy;
+mod query;
 mod tcp_client;
 
 // Authentication
@@ -19,6 +22,15 @@ pub use steel_core::player::{
     ClientInformation, GameProfile, GameProfileAction, is_valid_player_name, offline_uuid,
 };
 
+// Proxy forwarding
+pub use proxy::{ForwardedPlayer, ForwardingError, parse_bungeecord_host, parse_velocity_data};
+
+// LAN discovery
+pub use lan::{LanBroadcaster, lan_ping_message};
+
+// Query protocol
+pub use query::{QueryInfo, QueryListener, QueryState};
+
 // Connection types
 pub use connection::JavaConnection;
 pub use tcp_client::{ConnectionUpdate, JavaTcpClient, ServerConnectionSession};
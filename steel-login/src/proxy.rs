@@ -0,0 +1,168 @@
+//! Player information forwarding from BungeeCord and Velocity proxies.
+//!
+//! Behind a proxy the backend runs in offline mode, so the player's real
+//! address and authenticated profile come from the proxy instead.
+
+use std::io::{self, Cursor};
+use std::net::IpAddr;
+
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
+use steel_protocol::packets::login::GameProfileProperty;
+use steel_utils::Identifier;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::{PrefixedRead, ReadFrom};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Velocity's login plugin channel for forwarded player information.
+pub const VELOCITY_CHANNEL: Identifier = Identifier::new_static("velocity", "player_info");
+/// Velocity's `MODERN_DEFAULT` forwarding version, the one Steel requests.
+pub const VELOCITY_FORWARDING_VERSION: u8 = 1;
+const VELOCITY_SIGNATURE_LEN: usize = 32;
+
+/// A player's connection details as forwarded by a proxy.
+#[derive(Debug, Clone)]
+pub struct ForwardedPlayer {
+    /// The player's real address.
+    pub address: IpAddr,
+    /// The profile id the proxy authenticated.
+    pub id: Uuid,
+    /// The name the proxy authenticated. BungeeCord forwards none.
+    pub name: Option<String>,
+    /// Profile properties such as skin textures.
+    pub properties: Vec<GameProfileProperty>,
+}
+
+/// An error reading Velocity forwarded data.
+#[derive(Error, Debug)]
+pub enum ForwardingError {
+    /// The data is not signed with the configured secret.
+    #[error("forwarded data is not signed with the configured secret")]
+    InvalidSignature,
+    /// The proxy answered with a version Steel does not understand.
+    #[error("unsupported forwarding version {0}")]
+    UnsupportedVersion(i32),
+    /// The data could not be decoded.
+    #[error("malformed forwarded data: {0}")]
+    Malformed(#[from] io::Error),
+}
+
+/// Parses BungeeCord's `host\0address\0uuid[\0properties]` handshake host.
+#[must_use]
+pub fn parse_bungeecord_host(host: &str) -> Option<ForwardedPlayer> {
+    let mut parts = host.split('\0').skip(1);
+    let address = parts.next()?.parse().ok()?;
+    let id = Uuid::parse_str(parts.next()?).ok()?;
+    let properties = match parts.next() {
+        Some(json) => serde_json::from_str(json).ok()?,
+        None => Vec::new(),
+    };
+    Some(ForwardedPlayer {
+        address,
+        id,
+        name: None,
+        properties,
+    })
+}
+
+/// Verifies and parses the answer to a `velocity:player_info` query.
+///
+/// # Errors
+/// Returns an error if the signature does not match `secret` or the data is malformed.
+pub fn parse_velocity_data(secret: &[u8], data: &[u8]) -> Result<ForwardedPlayer, ForwardingError> {
+    let (signature, signed) = data
+        .split_at_checked(VELOCITY_SIGNATURE_LEN)
+        .ok_or(ForwardingError::InvalidSignature)?;
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret).map_err(|_| ForwardingError::InvalidSignature)?;
+    mac.update(signed);
+    mac.verify_slice(signature)
+        .map_err(|_| ForwardingError::InvalidSignature)?;
+
+    let data = &mut Cursor::new(signed);
+    let version = VarInt::read(data)?.0;
+    if version < i32::from(VELOCITY_FORWARDING_VERSION) {
+        return Err(ForwardingError::UnsupportedVersion(version));
+    }
+    let address = String::read_prefixed_bound::<VarInt>(data, 255)?
+        .parse()
+        .map_err(io::Error::other)?;
+    let id = Uuid::read(data)?;
+    let name = String::read_prefixed_bound::<VarInt>(data, 16)?;
+    let count = VarInt::read(data)?.0;
+    let properties = (0..count)
+        .map(|_| read_property(data))
+        .collect::<io::Result<_>>()?;
+    // Newer versions append the chat signing key, which 1.19.3+ clients send in play.
+    Ok(ForwardedPlayer {
+        address,
+        id,
+        name: Some(name),
+        properties,
+    })
+}
+
+fn read_property(data: &mut Cursor<&[u8]>) -> io::Result<GameProfileProperty> {
+    Ok(GameProfileProperty {
+        name: String::read_prefixed::<VarInt>(data)?,
+        value: String::read_prefixed::<VarInt>(data)?,
+        signature: Option::<String>::read_prefixed::<VarInt>(data)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::serial::{PrefixedWrite as _, WriteTo as _};
+
+    use super::*;
+
+    fn velocity_data(secret: &[u8]) -> Vec<u8> {
+        let mut signed = Vec::new();
+        VarInt(1).write(&mut signed).expect("version should write");
+        "127.0.0.1"
+            .write_prefixed::<VarInt>(&mut signed)
+            .expect("address should write");
+        Uuid::nil().write(&mut signed).expect("uuid should write");
+        "Steve"
+            .write_prefixed::<VarInt>(&mut signed)
+            .expect("name should write");
+        VarInt(0)
+            .write(&mut signed)
+            .expect("properties should write");
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("any key length is valid");
+        mac.update(&signed);
+        let mut data = mac.finalize().into_bytes().to_vec();
+        data.extend(signed);
+        data
+    }
+
+    #[test]
+    fn velocity_data_requires_matching_secret() {
+        let data = velocity_data(b"secret");
+
+        let player = parse_velocity_data(b"secret", &data).expect("signature should verify");
+        assert_eq!(player.name.as_deref(), Some("Steve"));
+        assert_eq!(player.address, IpAddr::from([127, 0, 0, 1]));
+        assert!(matches!(
+            parse_velocity_data(b"other", &data),
+            Err(ForwardingError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn bungeecord_host_carries_address_and_id() {
+        let player = parse_bungeecord_host(
+            "play.example.com\u{0}10.0.0.5\u{0}069a79f444e94726a5befca90e38aaf5\u{0}[]",
+        )
+        .expect("forwarded host should parse");
+
+        assert_eq!(player.address, IpAddr::from([10, 0, 0, 5]));
+        assert_eq!(
+            player.id,
+            Uuid::parse_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").expect("valid uuid")
+        );
+        assert!(parse_bungeecord_host("play.example.com").is_none());
+    }
+}
@@ -0,0 +1,290 @@
+//! Vanilla `QueryThreadGs4`: the GameSpy4 UDP query protocol used by hosting
+//! panels and monitoring tools.
+//!
+//! A client first sends a handshake and receives a challenge token, then asks
+//! for the basic or full stat with that token.
+
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rustc_hash::FxHashMap;
+use steel_core::server::Server;
+use steel_utils::MC_VERSION;
+use steel_utils::text::{DisplayResolutor, parse_markup};
+use tokio::net::UdpSocket;
+use tokio::select;
+use tokio_util::sync::CancellationToken;
+
+const MAGIC: [u8; 2] = [0xFE, 0xFD];
+const TYPE_STAT: u8 = 0;
+const TYPE_HANDSHAKE: u8 = 9;
+/// A stat request padded to this length asks for the full stat.
+const FULL_STAT_REQUEST_LENGTH: usize = 15;
+const CHALLENGE_LIFETIME: Duration = Duration::from_secs(30);
+const MAX_PACKET_SIZE: usize = 1460;
+
+/// What a stat response reports about the server.
+#[derive(Debug, Clone)]
+pub struct QueryInfo {
+    /// The message of the day, as plain text.
+    pub motd: String,
+    /// The default world's name.
+    pub map: String,
+    /// Names of the online players.
+    pub players: Vec<String>,
+    /// The player limit.
+    pub max_players: u32,
+    /// The game port.
+    pub host_port: u16,
+    /// The address the server listens on.
+    pub host_ip: String,
+    /// Server software and plugins, in Bukkit's `software: plugin; plugin` format.
+    pub plugins: String,
+}
+
+struct Challenge {
+    token: i32,
+    created: Instant,
+}
+
+/// Challenge tokens handed out to query clients.
+#[derive(Default)]
+pub struct QueryState {
+    challenges: FxHashMap<SocketAddr, Challenge>,
+    last_prune: Option<Instant>,
+}
+
+impl QueryState {
+    /// Answers a query packet from `address`, or returns `None` to ignore it.
+    pub fn respond(
+        &mut self,
+        data: &[u8],
+        address: SocketAddr,
+        now: Instant,
+        info: impl FnOnce() -> QueryInfo,
+    ) -> Option<Vec<u8>> {
+        if self
+            .last_prune
+            .is_none_or(|last| now.duration_since(last) >= CHALLENGE_LIFETIME)
+        {
+            self.challenges
+                .retain(|_, challenge| now.duration_since(challenge.created) < CHALLENGE_LIFETIME);
+            self.last_prune = Some(now);
+        }
+
+        if data.len() < 7 || data[..2] != MAGIC {
+            return None;
+        }
+        let session = &data[3..7];
+        match data[2] {
+            TYPE_HANDSHAKE => {
+                // Vanilla `RequestChallenge`: a random 24-bit token.
+                let token = rand::random::<i32>() & 0x00FF_FFFF;
+                self.challenges.insert(
+                    address,
+                    Challenge {
+                        token,
+                        created: now,
+                    },
+                );
+                let mut response = vec![TYPE_HANDSHAKE];
+                response.extend_from_slice(session);
+                write_string(&mut response, &token.to_string());
+                Some(response)
+            }
+            TYPE_STAT => {
+                let token = i32::from_be_bytes(data.get(7..11)?.try_into().ok()?);
+                if self.challenges.get(&address)?.token != token {
+                    return None;
+                }
+                let info = info();
+                Some(if data.len() == FULL_STAT_REQUEST_LENGTH {
+                    full_stat(session, &info)
+                } else {
+                    basic_stat(session, &info)
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+fn write_string(out: &mut Vec<u8>, value: &str) {
+    out.extend_from_slice(value.as_bytes());
+    out.push(0);
+}
+
+fn basic_stat(session: &[u8], info: &QueryInfo) -> Vec<u8> {
+    let mut out = vec![TYPE_STAT];
+    out.extend_from_slice(session);
+    write_string(&mut out, &info.motd);
+    write_string(&mut out, "SMP");
+    write_string(&mut out, &info.map);
+    write_string(&mut out, &info.players.len().to_string());
+    write_string(&mut out, &info.max_players.to_string());
+    out.extend_from_slice(&info.host_port.to_le_bytes());
+    write_string(&mut out, &info.host_ip);
+    out
+}
+
+fn full_stat(session: &[u8], info: &QueryInfo) -> Vec<u8> {
+    let mut out = vec![TYPE_STAT];
+    out.extend_from_slice(session);
+    write_string(&mut out, "splitnum");
+    out.extend_from_slice(&[0x80, 0]);
+    for (key, value) in [
+        ("hostname", info.motd.as_str()),
+        ("gametype", "SMP"),
+        ("game_id", "MINECRAFT"),
+        ("version", MC_VERSION),
+        ("plugins", info.plugins.as_str()),
+        ("map", info.map.as_str()),
+        ("numplayers", info.players.len().to_string().as_str()),
+        ("maxplayers", info.max_players.to_string().as_str()),
+        ("hostport", info.host_port.to_string().as_str()),
+        ("hostip", info.host_ip.as_str()),
+    ] {
+        write_string(&mut out, key);
+        write_string(&mut out, value);
+    }
+    out.push(0);
+    out.push(1);
+    write_string(&mut out, "player_");
+    out.push(0);
+    for player in &info.players {
+        write_string(&mut out, player);
+    }
+    out.push(0);
+    out
+}
+
+/// Answers GameSpy4 queries on a UDP socket.
+pub struct QueryListener {
+    socket: UdpSocket,
+    server: Arc<Server>,
+    server_port: u16,
+    state: QueryState,
+}
+
+impl QueryListener {
+    /// Binds the query socket.
+    ///
+    /// # Errors
+    /// Returns an error if the socket cannot be bound.
+    pub async fn bind(
+        address: SocketAddr,
+        server: Arc<Server>,
+        server_port: u16,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            socket: UdpSocket::bind(address).await?,
+            server,
+            server_port,
+            state: QueryState::default(),
+        })
+    }
+
+    /// Answers queries until `cancel_token` is cancelled.
+    pub async fn run(mut self, cancel_token: CancellationToken) {
+        let mut buf = [0; MAX_PACKET_SIZE];
+        loop {
+            let (len, address) = select! {
+                () = cancel_token.cancelled() => break,
+                result = self.socket.recv_from(&mut buf) => match result {
+                    Ok(received) => received,
+                    Err(err) => {
+                        log::debug!("Failed to receive query packet: {err}");
+                        continue;
+                    }
+                },
+            };
+            let (socket, server, server_port) = (&self.socket, &self.server, self.server_port);
+            let Some(response) = self
+                .state
+                .respond(&buf[..len], address, Instant::now(), || {
+                    query_info(socket, server, server_port)
+                })
+            else {
+                continue;
+            };
+            if let Err(err) = self.socket.send_to(&response, address).await {
+                log::debug!("Failed to answer query from {address}: {err}");
+            }
+        }
+    }
+}
+
+fn query_info(socket: &UdpSocket, server: &Server, server_port: u16) -> QueryInfo {
+    let host_ip = socket
+        .local_addr()
+        .map_or_else(|_| "0.0.0.0".to_owned(), |address| address.ip().to_string());
+    QueryInfo {
+        motd: parse_markup(&server.config.load().motd).to_plain(&DisplayResolutor),
+        map: server.overworld().key.path.to_string(),
+        players: server
+            .get_players()
+            .iter()
+            .map(|player| player.gameprofile.name.clone())
+            .collect(),
+        max_players: server.config.load().max_players,
+        host_port: server_port,
+        host_ip,
+        // Steel has no plugins, so only the software is listed.
+        plugins: format!("Steel {}", env!("CARGO_PKG_VERSION")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info() -> QueryInfo {
+        QueryInfo {
+            motd: "A Steel Server".to_owned(),
+            map: "overworld".to_owned(),
+            players: vec!["Steve".to_owned()],
+            max_players: 20,
+            host_port: 25565,
+            host_ip: "0.0.0.0".to_owned(),
+            plugins: String::new(),
+        }
+    }
+
+    #[test]
+    fn stat_requires_handshake_token() {
+        let mut state = QueryState::default();
+        let address = SocketAddr::from(([127, 0, 0, 1], 50000));
+        let now = Instant::now();
+        let session = [0, 0, 0, 1];
+
+        let handshake = [0xFE, 0xFD, TYPE_HANDSHAKE, 0, 0, 0, 1];
+        let response = state
+            .respond(&handshake, address, now, info)
+            .expect("handshake should be answered");
+        assert_eq!(response[..5], [TYPE_HANDSHAKE, 0, 0, 0, 1]);
+        let token: i32 = String::from_utf8(response[5..response.len() - 1].to_vec())
+            .expect("token should be ascii")
+            .parse()
+            .expect("token should be a number");
+
+        let mut stat = vec![0xFE, 0xFD, TYPE_STAT];
+        stat.extend_from_slice(&session);
+        stat.extend_from_slice(&token.wrapping_add(1).to_be_bytes());
+        assert!(state.respond(&stat, address, now, info).is_none());
+
+        stat.truncate(7);
+        stat.extend_from_slice(&token.to_be_bytes());
+        let basic = state
+            .respond(&stat, address, now, info)
+            .expect("basic stat should be answered");
+        assert!(basic.ends_with(b"20\0\xdd\x630.0.0.0\0"));
+
+        stat.extend_from_slice(&[0; 4]);
+        let full = state
+            .respond(&stat, address, now, info)
+            .expect("full stat should be answered");
+        assert!(full.ends_with(b"\x01player_\0\0Steve\0\0"));
+    }
+}
@@ -6,27 +6,31 @@
 use std::{
     cmp::Ordering,
     fmt::{self, Debug, Formatter},
-    io::Cursor,
     net::SocketAddr,
     sync::Arc,
 };
 
 use crossbeam::atomic::AtomicCell;
+use steel_core::config::ProxyForwarding;
 use steel_core::player::{
-    ClientInformation, GameProfile, PlayerConnection,
+    ClientChannels, ClientInformation, GameProfile, PlayerConnection, ResourcePackStatuses,
     networking::{JavaNetworkWriter, OutboundPacket},
 };
-use steel_core::server::Server;
+use steel_core::server::{Server, join_queue::JoinTicket};
 use steel_protocol::{
     packet_reader::TCPNetworkDecoder,
     packet_traits::{ClientPacket, CompressionInfo, EncodedPacket, ServerPacket},
     packet_writer::TCPNetworkEncoder,
     packets::{
-        common::{CDisconnect, SClientInformation, SCustomPayload, SPingRequest},
+        common::{
+            CDisconnect, SClientInformation, SCookieResponse, SCustomPayload, SKeepAlive,
+            SPingRequest, SResourcePack,
+        },
         config::SSelectKnownPacks,
         handshake::{ClientIntent, SClientIntention},
-        login::{CLoginDisconnect, SHello, SKey},
+        login::{CLoginDisconnect, SCustomQueryAnswer, SHello, SKey},
     },
+    traffic::TrafficCounters,
     utils::{ConnectionProtocol, PacketError, RawPacket},
 };
 use steel_registry::packets::{
@@ -53,6 +57,12 @@ use tokio::{
 use tokio_util::{sync::CancellationToken, task::TaskTracker};
 use uuid::Uuid;
 
+use crate::proxy::{ForwardedPlayer, parse_bungeecord_host};
+
+/// Vanilla's bound on the handshake host. BungeeCord forwarding appends the
+/// player's address and profile, so the packet itself allows more.
+const MAX_HOSTNAME_LENGTH: usize = 255;
+
 /// Represents updates to the connection state.
 #[derive(Clone)]
 pub enum ConnectionUpdate {
@@ -134,10 +144,22 @@ pub struct JavaTcpClient {
     pub gameprofile: AsyncMutex<Option<GameProfile>>,
     /// The client's settings (view distance, language, etc.) received during config.
     pub client_information: AsyncMutex<ClientInformation>,
+    /// Responses to the server resource packs sent during config.
+    pub resource_packs: AsyncMutex<ResourcePackStatuses>,
+    /// The brand and plugin channels announced during config.
+    pub client_channels: AsyncMutex<ClientChannels>,
     /// The current connection state of the client (e.g., Handshaking, Status, Play).
     pub protocol: Arc<AtomicCell<ConnectionProtocol>>,
     /// The client's IP address.
     pub address: SocketAddr,
+    /// Whether the client connected with the transfer intent.
+    pub transferred: AtomicCell<bool>,
+    /// The slot claim held from the end of configuration until the player is queued to join.
+    pub join_ticket: SyncMutex<Option<JoinTicket>>,
+    /// Player information forwarded by a proxy.
+    pub forwarded: SyncMutex<Option<ForwardedPlayer>>,
+    /// Transaction id of the pending Velocity forwarding query.
+    pub velocity_query: AtomicCell<Option<i32>>,
     /// A token to cancel the client's operations. Called when the connection is closed.
     pub cancel_token: CancellationToken,
 
@@ -145,6 +167,8 @@ pub struct JavaTcpClient {
     pub outgoing_queue: UnboundedSender<OutboundPacket>,
     /// The packet encoder for outgoing packets.
     pub network_writer: JavaNetworkWriter,
+    /// Bytes and packets counted by the reader and writer, added to the server totals.
+    pub traffic: Arc<TrafficCounters>,
     /// Current compression settings.
     pub compression: Arc<AtomicCell<Option<CompressionInfo>>>,
 
@@ -182,19 +206,37 @@ impl JavaTcpClient {
         let (read, write) = tcp_stream.into_split();
         let (outgoing_queue, recv) = mpsc::unbounded_channel();
         let (connection_updates, _) = broadcast::channel(128);
+        let traffic = Arc::new(TrafficCounters::with_parent(Arc::clone(
+            &server.network_traffic,
+        )));
+        let mut reader = TCPNetworkDecoder::new(BufReader::new(read));
+        reader.set_traffic(Arc::clone(&traffic));
+        let mut writer = TCPNetworkEncoder::new(BufWriter::new(write));
+        writer.set_traffic(Arc::clone(&traffic));
+        reader.set_limits(
+            server
+                .config
+                .packet_limits
+                .limits(ConnectionProtocol::Handshake),
+        );
 
         let client = Self {
             id,
             gameprofile: AsyncMutex::new(None),
             client_information: AsyncMutex::new(ClientInformation::default()),
+            resource_packs: AsyncMutex::new(ResourcePackStatuses::default()),
+            client_channels: AsyncMutex::new(ClientChannels::default()),
             address,
+            transferred: AtomicCell::new(false),
+            join_ticket: SyncMutex::new(None),
+            forwarded: SyncMutex::new(None),
+            velocity_query: AtomicCell::new(None),
             protocol: Arc::new(AtomicCell::new(ConnectionProtocol::Handshake)),
             cancel_token,
 
             outgoing_queue,
-            network_writer: Arc::new(AsyncMutex::new(Some(TCPNetworkEncoder::new(
-                BufWriter::new(write),
-            )))),
+            network_writer: Arc::new(AsyncMutex::new(Some(writer))),
+            traffic,
             compression: Arc::new(AtomicCell::new(None)),
             server,
             connection_session,
@@ -204,7 +246,7 @@ impl JavaTcpClient {
             task_tracker,
         };
 
-        (client, recv, TCPNetworkDecoder::new(BufReader::new(read)))
+        (client, recv, reader)
     }
 
     /// Closes the connection.
@@ -212,6 +254,17 @@ impl JavaTcpClient {
         self.cancel_token.cancel();
     }
 
+    /// Returns the player's address, as forwarded by a proxy if there is one.
+    #[must_use]
+    pub fn remote_address(&self) -> SocketAddr {
+        self.forwarded
+            .lock()
+            .as_ref()
+            .map_or(self.address, |forwarded| {
+                SocketAddr::new(forwarded.address, self.address.port())
+            })
+    }
+
     /// Sends a packet immediately, without queuing.
     ///
     /// # Panics
@@ -422,6 +475,7 @@ impl JavaTcpClient {
 
         self.task_tracker.spawn(async move {
             let mut connection = None;
+            let mut limited_protocol = ConnectionProtocol::Handshake;
             loop {
                 select! {
                     () = cancel_token.cancelled() => {
@@ -438,6 +492,13 @@ impl JavaTcpClient {
                                         if let Some(compression) = action.reader_compression {
                                             reader.set_compression(compression.threshold);
                                         }
+                                        let protocol = self_clone.protocol.load();
+                                        if protocol != limited_protocol {
+                                            reader.set_limits(
+                                                self_clone.server.config.load().packet_limits.limits(protocol),
+                                            );
+                                            limited_protocol = protocol;
+                                        }
                                         if let Some(upgrade) = action.upgrade {
                                             connection = Some(upgrade);
                                             break;
@@ -450,6 +511,16 @@ impl JavaTcpClient {
                                     }
                                 }
                             }
+                            Err(err) if err.is_limit_exceeded() => {
+                                log::warn!("Client {id} exceeded packet limits: {err}");
+                                let reason = if matches!(err, PacketError::RateLimited(_)) {
+                                    translations::DISCONNECT_EXCEEDED_PACKET_RATE.msg()
+                                } else {
+                                    translations::DISCONNECT_PACKET_ERROR.msg()
+                                };
+                                self_clone.kick(TextComponent::translated(reason)).await;
+                                break;
+                            }
                             Err(err) => {
                                 log::info!("Failed to get raw packet from client {id}: {err}");
                                 cancel_token.cancel();
@@ -506,8 +577,8 @@ impl JavaTcpClient {
     }
 
     /// Handles a handshake packet.
-    pub async fn handle_handshake(&self, packet: RawPacket) -> Result<(), PacketError> {
-        let data = &mut Cursor::new(packet.payload.as_slice());
+    pub async fn handle_handshake(&self, mut packet: RawPacket) -> Result<(), PacketError> {
+        let data = &mut packet.payload;
 
         match packet.id {
             handshake::S_INTENTION => {
@@ -518,6 +589,35 @@ impl JavaTcpClient {
                 };
                 self.protocol.store(intent);
 
+                if packet.intention == ClientIntent::Transfer {
+                    if !self.server.config.load().accepts_transfers {
+                        self.kick(TextComponent::translated(
+                            translations::MULTIPLAYER_DISCONNECT_TRANSFERS_DISABLED.msg(),
+                        ))
+                        .await;
+                        return Ok(());
+                    }
+                    self.transferred.store(true);
+                }
+
+                if intent != ConnectionProtocol::Status
+                    && self.server.config.load().proxy.forwarding == ProxyForwarding::BungeeCord
+                {
+                    let Some(forwarded) = parse_bungeecord_host(&packet.hostname) else {
+                        self.kick(
+                            "If you wish to use IP forwarding, please enable it in your BungeeCord config as well!"
+                                .into(),
+                        )
+                        .await;
+                        return Ok(());
+                    };
+                    *self.forwarded.lock() = Some(forwarded);
+                } else if packet.hostname.len() > MAX_HOSTNAME_LENGTH {
+                    return Err(PacketError::MalformedValue(
+                        "hostname is too long".to_string(),
+                    ));
+                }
+
                 if intent != ConnectionProtocol::Status {
                     let reason = match packet.protocol_version.cmp(&CURRENT_MC_PROTOCOL) {
                         Ordering::Equal => return Ok(()),
@@ -542,8 +642,8 @@ impl JavaTcpClient {
     }
 
     /// Handles a status packet.
-    pub async fn handle_status(&self, packet: RawPacket) -> Result<(), PacketError> {
-        let data = &mut Cursor::new(packet.payload.as_slice());
+    pub async fn handle_status(&self, mut packet: RawPacket) -> Result<(), PacketError> {
+        let data = &mut packet.payload;
 
         match packet.id {
             status::S_STATUS_REQUEST => {
@@ -561,9 +661,9 @@ impl JavaTcpClient {
     /// Handles a login packet.
     pub(crate) async fn handle_login(
         &self,
-        packet: RawPacket,
+        mut packet: RawPacket,
     ) -> Result<ConnectionAction, PacketError> {
-        let data = &mut Cursor::new(packet.payload.as_slice());
+        let data = &mut packet.payload;
 
         match packet.id {
             login_packets::S_HELLO => Ok(self.handle_hello(SHello::read_packet(data)?).await),
@@ -572,6 +672,14 @@ impl JavaTcpClient {
                 self.handle_login_acknowledged().await;
                 Ok(ConnectionAction::none())
             }
+            login_packets::S_CUSTOM_QUERY_ANSWER => Ok(self
+                .handle_custom_query_answer(SCustomQueryAnswer::read_packet(data)?)
+                .await),
+            login_packets::S_COOKIE_RESPONSE => {
+                self.handle_cookie_response(SCookieResponse::read_packet(data)?)
+                    .await;
+                Ok(ConnectionAction::none())
+            }
             _ => Err(PacketError::InvalidProtocol("Login".to_string())),
         }
     }
@@ -579,13 +687,14 @@ impl JavaTcpClient {
     /// Handles a configuration packet.
     pub(crate) async fn handle_config(
         &self,
-        packet: RawPacket,
+        mut packet: RawPacket,
     ) -> Result<ConnectionAction, PacketError> {
-        let data = &mut Cursor::new(packet.payload.as_slice());
+        let data = &mut packet.payload;
 
         match packet.id {
             config::S_CUSTOM_PAYLOAD => {
-                self.handle_config_custom_payload(SCustomPayload::read_packet(data)?);
+                self.handle_config_custom_payload(SCustomPayload::read_packet(data)?)
+                    .await;
                 Ok(ConnectionAction::none())
             }
             config::S_CLIENT_INFORMATION => {
@@ -598,11 +707,36 @@ impl JavaTcpClient {
                     .await;
                 Ok(ConnectionAction::none())
             }
+            config::S_COOKIE_RESPONSE => {
+                self.handle_cookie_response(SCookieResponse::read_packet(data)?)
+                    .await;
+                Ok(ConnectionAction::none())
+            }
+            config::S_RESOURCE_PACK => {
+                self.handle_config_resource_pack(SResourcePack::read_packet(data)?)
+                    .await;
+                Ok(ConnectionAction::none())
+            }
+            config::S_KEEP_ALIVE => {
+                // Only sent while waiting in the join queue, which needs no reply.
+                SKeepAlive::read_packet(data)?;
+                Ok(ConnectionAction::none())
+            }
             config::S_FINISH_CONFIGURATION => Ok(self.finish_configuration().await),
             _ => Err(PacketError::InvalidProtocol("Config".to_string())),
         }
     }
 
+    /// Vanilla `handleCookieResponse`: the server sends no cookie requests
+    /// before play, so any response is unexpected.
+    pub async fn handle_cookie_response(&self, packet: SCookieResponse) {
+        log::debug!("Unexpected cookie response: {}", packet.key);
+        self.kick(TextComponent::translated(
+            translations::MULTIPLAYER_DISCONNECT_UNEXPECTED_QUERY.msg(),
+        ))
+        .await;
+    }
+
     /// Kicks the client with a given reason.
     pub async fn kick(&self, reason: TextComponent) {
         log::info!("Kicking client {}: {:p}", self.id, reason);
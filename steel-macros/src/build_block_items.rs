@@ -18,7 +18,7 @@ const KNOWN_ENTITY_CLASSES: &[&str] = &[
     "animal",
 ];
 
-const KNOWN_ENTITY_INTERFACES: &[&str] = &["item_steerable"];
+const KNOWN_ENTITY_INTERFACES: &[&str] = &["item_steerable", "merchant", "ranged_attack_mob"];
 
 /// Attribute macro for block behavior structs.
 ///
@@ -506,6 +506,87 @@ fn read_from_enum(e: syn::DataEnum, name: Ident, attrs: Vec<syn::Attribute>) ->
     })
 }
 
+/// Derives the `ReadFromBytes` trait for a struct with named fields.
+///
+/// `Bytes` fields are sliced out of the packet buffer without copying: unannotated
+/// they take the rest of it, and `#[read(as = Prefixed(..), bound = ..)]` reads a
+/// length-prefixed run. Every other field is read the way `ReadFrom` reads it.
+///
+/// # Panics
+/// - If the derive macro is used on anything but a struct with named fields.
+/// - If the `read` attribute is malformed.
+/// - If an unknown read strategy is specified.
+#[proc_macro_derive(ReadFromBytes, attributes(read))]
+pub fn read_from_bytes_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+
+    let Data::Struct(syn::DataStruct {
+        fields: Fields::Named(fields),
+        ..
+    }) = input.data
+    else {
+        panic!("ReadFromBytes can only be derived for structs with named fields");
+    };
+
+    let readers = fields.named.iter().map(|f| {
+        let field_name = f.ident.as_ref().expect("should have a named field");
+        let field_type = &f.ty;
+        let FieldReadAttributes { strategy, bound } = parse_read_attributes(f);
+
+        let read_code = match strategy {
+            Some(strat)
+                if is_bytes_type(field_type)
+                    && strat.name_str() == "Prefixed"
+                    && strat.inner.is_none() =>
+            {
+                let prefix = strat
+                    .prefix_type_tokens()
+                    .unwrap_or_else(|| quote! { steel_utils::codec::VarInt });
+                let bound = bound.map_or_else(|| quote! { 32767 }, |b| quote! { #b });
+                quote! { steel_utils::serial::read_prefixed_bytes::<#prefix>(data, #bound)? }
+            }
+            Some(strat) => {
+                let read_code = generate_read_code(&strat, field_type, bound.as_ref());
+                quote! { steel_utils::serial::read_with_cursor(data, |data| Ok(#read_code))? }
+            }
+            None => quote! {
+                <#field_type as steel_utils::serial::ReadFromBytes>::read_from_bytes(data)?
+            },
+        };
+        quote! {
+            let #field_name = #read_code;
+        }
+    });
+
+    let field_names = fields
+        .named
+        .iter()
+        .map(|f| f.ident.as_ref().expect("should have a named field"));
+
+    let expanded = quote! {
+        #[automatically_derived]
+        impl steel_utils::serial::ReadFromBytes for #name {
+            fn read_from_bytes(data: &mut bytes::Bytes) -> std::io::Result<Self> {
+                use steel_utils::serial::{PrefixedRead, ReadFrom};
+
+                #(#readers)*
+
+                Ok(Self {
+                    #(#field_names),*
+                })
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+fn is_bytes_type(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(path)
+        if path.path.segments.last().is_some_and(|segment| segment.ident == "Bytes"))
+}
+
 /// Derives the `WriteTo` trait for a struct.
 ///
 /// # Panics
@@ -0,0 +1,84 @@
+#![expect(missing_docs, reason = "benchmarks")]
+//! Compares pooled packet buffers against fresh allocations, on one thread
+//! and with buffers taken and recycled on different threads like the encoder
+//! and the connection writers do.
+use std::hint::black_box;
+use std::sync::mpsc;
+use std::thread;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use steel_protocol::buffer_pool;
+use steel_utils::FrontVec;
+
+/// A small movement packet and a typical larger packet.
+const SIZES: [usize; 2] = [64, 8 * 1024];
+/// Buffers handed from the encoding thread to the writer thread per iteration.
+const CROSS_THREAD_BATCH: usize = 1024;
+/// Packet bytes copied into each buffer.
+const PAYLOAD: [u8; SIZES[1]] = [0xAB; SIZES[1]];
+
+fn fill(buf: &mut FrontVec, size: usize) {
+    buf.extend_from_slice(&PAYLOAD[..size]);
+}
+
+fn bench_single_thread(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer pool");
+    for size in SIZES {
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::new("pooled", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut buf = buffer_pool::take(6);
+                fill(&mut buf, size);
+                buffer_pool::recycle(black_box(buf));
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("fresh", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut buf = FrontVec::new(6);
+                fill(&mut buf, size);
+                drop(black_box(buf));
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_cross_thread(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer pool cross-thread");
+    for size in SIZES {
+        group.throughput(Throughput::Bytes((size * CROSS_THREAD_BATCH) as u64));
+        for pooled in [true, false] {
+            let name = if pooled { "pooled" } else { "fresh" };
+            group.bench_with_input(BenchmarkId::new(name, size), &size, |b, &size| {
+                b.iter(|| {
+                    let (sender, receiver) = mpsc::channel::<FrontVec>();
+                    thread::scope(|scope| {
+                        scope.spawn(move || {
+                            for buf in receiver {
+                                if pooled {
+                                    buffer_pool::recycle(buf);
+                                }
+                            }
+                        });
+                        for _ in 0..CROSS_THREAD_BATCH {
+                            let mut buf = if pooled {
+                                buffer_pool::take(6)
+                            } else {
+                                FrontVec::new(6)
+                            };
+                            fill(&mut buf, size);
+                            if sender.send(buf).is_err() {
+                                break;
+                            }
+                        }
+                        drop(sender);
+                    });
+                });
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_single_thread, bench_cross_thread);
+criterion_main!(benches);
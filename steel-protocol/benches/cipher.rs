@@ -0,0 +1,32 @@
+#![expect(missing_docs, reason = "benchmarks")]
+//! Compare backends by running with and without `--features openssl`.
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use steel_protocol::cipher::{Aes128Cfb8Dec, Aes128Cfb8Enc, BACKEND};
+
+const KEY: [u8; 16] = [0x5A; 16];
+/// A small movement packet, a typical batched flush and a large chunk packet.
+const SIZES: [usize; 3] = [64, 8 * 1024, 256 * 1024];
+
+fn bench_cipher(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("aes-128-cfb8 {BACKEND}"));
+    for size in SIZES {
+        let mut data = vec![0xAB; size];
+        group.throughput(Throughput::Bytes(size as u64));
+
+        let mut encryptor = Aes128Cfb8Enc::new(&KEY);
+        group.bench_with_input(BenchmarkId::new("encrypt", size), &size, |b, _| {
+            b.iter(|| encryptor.encrypt(black_box(&mut data)));
+        });
+
+        let mut decryptor = Aes128Cfb8Dec::new(&KEY);
+        group.bench_with_input(BenchmarkId::new("decrypt", size), &size, |b, _| {
+            b.iter(|| decryptor.decrypt(black_box(&mut data)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_cipher);
+criterion_main!(benches);
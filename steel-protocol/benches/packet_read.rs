@@ -0,0 +1,67 @@
+#![expect(missing_docs, reason = "benchmarks")]
+use std::hint::black_box;
+use std::io::{Cursor, Read};
+use std::iter;
+
+use bytes::{Buf, Bytes};
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use steel_protocol::packet_traits::ServerPacket;
+use steel_protocol::packets::common::SCustomPayload;
+use steel_utils::Identifier;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::{PrefixedWrite, ReadFrom, WriteTo};
+
+/// A decompressed custom payload packet: id, channel and a 32 KiB body.
+fn custom_payload_packet() -> Vec<u8> {
+    let mut data = Vec::new();
+    VarInt(0x15).write(&mut data).expect("id should write");
+    "steel:bench"
+        .write_prefixed::<VarInt>(&mut data)
+        .expect("channel should write");
+    data.extend(iter::repeat_n(0xAB, 32 * 1024));
+    data
+}
+
+/// The reader before `Bytes`: copy the payload out, then copy the body again.
+fn read_copied(packet: &[u8]) -> (Identifier, Vec<u8>) {
+    let mut cursor = Cursor::new(packet);
+    VarInt::read(&mut cursor).expect("id should read");
+    let pos = cursor.position() as usize;
+    let payload = packet[pos..].to_vec();
+
+    let mut cursor = Cursor::new(payload.as_slice());
+    let identifier = Identifier::read(&mut cursor).expect("channel should read");
+    let mut body = Vec::new();
+    cursor.read_to_end(&mut body).expect("body should read");
+    (identifier, body)
+}
+
+fn read_shared(packet: Vec<u8>) -> SCustomPayload {
+    let mut cursor = Cursor::new(packet.as_slice());
+    VarInt::read(&mut cursor).expect("id should read");
+    let pos = cursor.position() as usize;
+    let mut payload = Bytes::from(packet);
+    payload.advance(pos);
+    SCustomPayload::read_packet(&mut payload).expect("packet should read")
+}
+
+fn bench_custom_payload(c: &mut Criterion) {
+    let packet = custom_payload_packet();
+    c.bench_function("custom payload 32KiB copied", |b| {
+        b.iter_batched(
+            || packet.clone(),
+            |packet| black_box(read_copied(&packet)),
+            BatchSize::SmallInput,
+        );
+    });
+    c.bench_function("custom payload 32KiB shared", |b| {
+        b.iter_batched(
+            || packet.clone(),
+            |packet| black_box(read_shared(packet)),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+criterion_group!(benches, bench_custom_payload);
+criterion_main!(benches);
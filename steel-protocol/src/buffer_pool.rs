@@ -0,0 +1,61 @@
+//! # Steel Protocol Buffer Pool
+//!
+//! Reuses packet encoding buffers so serializing a packet does not allocate a
+//! fresh `Vec` each time. Packets are encoded on game threads but dropped on
+//! connection writer threads, so all threads share one bounded lock-free pool;
+//! a per-thread pool would fill up on the writers and stay empty on the
+//! encoders.
+
+use std::sync::LazyLock;
+
+use crossbeam::queue::ArrayQueue;
+use steel_utils::FrontVec;
+
+/// Most buffers kept in the pool.
+const MAX_POOLED_BUFFERS: usize = 256;
+/// Buffers that grew past this are freed instead of pooled, so one large chunk
+/// packet does not pin its memory for the rest of the server's lifetime. With
+/// [`MAX_POOLED_BUFFERS`] this caps the pool at 16 MiB.
+const MAX_POOLED_CAPACITY: usize = 64 * 1024;
+
+static BUFFERS: LazyLock<ArrayQueue<Vec<u8>>> =
+    LazyLock::new(|| ArrayQueue::new(MAX_POOLED_BUFFERS));
+
+/// Takes an empty buffer with `reserve` bytes of front space from the pool.
+#[must_use]
+pub fn take(reserve: usize) -> FrontVec {
+    FrontVec::from_vec(BUFFERS.pop().unwrap_or_default(), reserve)
+}
+
+/// Returns a buffer to the pool once it is no longer needed.
+pub fn recycle(buf: FrontVec) {
+    let buf = buf.into_vec();
+    if buf.capacity() == 0 || buf.capacity() > MAX_POOLED_CAPACITY {
+        return;
+    }
+    // A full pool frees the buffer.
+    let _ = BUFFERS.push(buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn buffers_recycled_on_other_threads_are_reused_empty() {
+        let mut buf = take(4);
+        buf.extend_from_slice(&[1, 2, 3]);
+        assert!(thread::spawn(move || recycle(buf)).join().is_ok());
+
+        // Other tests share the pool, so look for a reused buffer instead of
+        // a specific one.
+        let buf = take(4);
+        assert!(buf.is_empty());
+
+        recycle(FrontVec::capacity(0, MAX_POOLED_CAPACITY + 1));
+        assert!(take(0).into_vec().capacity() <= MAX_POOLED_CAPACITY);
+        assert!(BUFFERS.len() <= MAX_POOLED_BUFFERS);
+    }
+}
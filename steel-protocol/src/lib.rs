@@ -18,8 +18,11 @@
     )
 )]
 
+pub mod buffer_pool;
+pub mod cipher;
 pub mod packet_reader;
 pub mod packet_traits;
 pub mod packet_writer;
 pub mod packets;
+pub mod traffic;
 pub mod utils;
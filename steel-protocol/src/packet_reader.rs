@@ -9,15 +9,19 @@ use std::{
     io::{self, Read},
     num::NonZeroU32,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
+    time::{Duration, Instant},
 };
 
-use aes::cipher::KeyIvInit;
+use bytes::{Buf, Bytes};
 use flate2::read::ZlibDecoder;
+use rustc_hash::FxHashMap;
 use steel_utils::codec::VarInt;
 use steel_utils::serial::ReadFrom;
 use tokio::io::{AsyncRead, AsyncReadExt, ReadBuf};
 
+use crate::traffic::TrafficCounters;
 use crate::utils::{
     Aes128Cfb8Dec, MAX_PACKET_DATA_SIZE, MAX_PACKET_SIZE, PacketError, RawPacket, StreamDecryptor,
 };
@@ -64,23 +68,68 @@ impl<R: AsyncRead + Unpin> AsyncRead for DecryptionReader<R> {
     }
 }
 
+/// Per-connection limits on incoming packets.
+#[derive(Debug, Clone, Default)]
+pub struct PacketLimits {
+    /// The most packets accepted per second, or `None` for no limit.
+    pub max_per_second: Option<NonZeroU32>,
+    /// The largest payload accepted for each listed packet id.
+    pub max_payload_sizes: FxHashMap<i32, usize>,
+}
+
 /// Decoder: Client -> Server
 /// Supports `ZLib` decoding/decompression
 /// Supports Aes128 Encryption
 pub struct TCPNetworkDecoder<R: AsyncRead + Unpin> {
     reader: DecryptionReader<R>,
     compression: Option<NonZeroU32>,
+    limits: PacketLimits,
+    rate_window_start: Instant,
+    rate_window_packets: u32,
+    traffic: Option<Arc<TrafficCounters>>,
 }
 
 impl<R: AsyncRead + Unpin> TCPNetworkDecoder<R> {
     /// Creates a new `TCPNetworkDecoder`.
-    pub const fn new(reader: R) -> Self {
+    pub fn new(reader: R) -> Self {
         Self {
             reader: DecryptionReader::None(reader),
             compression: None,
+            limits: PacketLimits::default(),
+            rate_window_start: Instant::now(),
+            rate_window_packets: 0,
+            traffic: None,
         }
     }
 
+    /// Counts packets read from now on in `traffic`.
+    pub fn set_traffic(&mut self, traffic: Arc<TrafficCounters>) {
+        self.traffic = Some(traffic);
+    }
+
+    /// Sets the limits applied to packets read from now on.
+    pub fn set_limits(&mut self, limits: PacketLimits) {
+        self.limits = limits;
+        self.rate_window_start = Instant::now();
+        self.rate_window_packets = 0;
+    }
+
+    fn check_rate(&mut self) -> Result<(), PacketError> {
+        let Some(max_per_second) = self.limits.max_per_second else {
+            return Ok(());
+        };
+        let now = Instant::now();
+        if now.duration_since(self.rate_window_start) >= Duration::from_secs(1) {
+            self.rate_window_start = now;
+            self.rate_window_packets = 0;
+        }
+        self.rate_window_packets += 1;
+        if self.rate_window_packets > max_per_second.get() {
+            return Err(PacketError::RateLimited(max_per_second.get()));
+        }
+        Ok(())
+    }
+
     /// Sets the compression threshold for the decoder.
     pub const fn set_compression(&mut self, threshold: NonZeroU32) {
         self.compression = Some(threshold);
@@ -90,12 +139,11 @@ impl<R: AsyncRead + Unpin> TCPNetworkDecoder<R> {
     ///
     /// # Panics
     /// - If the reader is already decrypting data.
-    /// - If the key is invalid.
     pub fn set_encryption(&mut self, key: &[u8; 16]) {
         if matches!(self.reader, DecryptionReader::Decrypt(_)) {
             panic!("Cannot upgrade a stream that already has a cipher!");
         }
-        let cipher = Aes128Cfb8Dec::new_from_slices(key, key).expect("invalid key");
+        let cipher = Aes128Cfb8Dec::new(key);
         replace_with::replace_with_or_abort(&mut self.reader, |decoder| decoder.upgrade(cipher));
     }
 
@@ -106,9 +154,11 @@ impl<R: AsyncRead + Unpin> TCPNetworkDecoder<R> {
     /// - If the packet is too long.
     /// - If the packet is not compressed when it should be.
     /// - If the packet fails to decompress.
+    /// - If the connection exceeds its [`PacketLimits`].
     #[expect(clippy::cast_sign_loss)]
     pub async fn get_raw_packet(&mut self) -> Result<RawPacket, PacketError> {
         let packet_len = VarInt::read_async(&mut self.reader).await? as usize;
+        self.check_rate()?;
 
         if packet_len > MAX_PACKET_SIZE {
             Err(PacketError::OutOfBounds)?;
@@ -152,11 +202,30 @@ impl<R: AsyncRead + Unpin> TCPNetworkDecoder<R> {
             packet_data
         };
 
+        if let Some(traffic) = &self.traffic {
+            traffic.record_in(
+                VarInt::written_size(packet_len as i32) + packet_len,
+                decompressed_data.len(),
+            );
+        }
+
         // Parse packet ID and payload from decompressed data
         let mut cursor = io::Cursor::new(decompressed_data.as_slice());
         let packet_id = VarInt::read(&mut cursor)?.0;
         let pos = cursor.position() as usize;
-        let payload = decompressed_data[pos..].to_vec();
+        let size = decompressed_data.len() - pos;
+        if let Some(&max) = self.limits.max_payload_sizes.get(&packet_id)
+            && size > max
+        {
+            return Err(PacketError::PayloadTooLarge {
+                id: packet_id,
+                size,
+                max,
+            });
+        }
+        // The payload shares the packet's allocation instead of being copied out.
+        let mut payload = Bytes::from(decompressed_data);
+        payload.advance(pos);
 
         Ok(RawPacket {
             id: packet_id,
@@ -165,6 +234,74 @@ impl<R: AsyncRead + Unpin> TCPNetworkDecoder<R> {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Frames an uncompressed packet whose length, id and payload all fit in
+    /// single byte var ints.
+    fn frame(id: u8, payload: &[u8]) -> Vec<u8> {
+        let mut packet = vec![(payload.len() + 1) as u8, id];
+        packet.extend_from_slice(payload);
+        packet
+    }
+
+    fn rate_limited(max_per_second: u32) -> TCPNetworkDecoder<&'static [u8]> {
+        let mut decoder = TCPNetworkDecoder::new(&[][..]);
+        decoder.set_limits(PacketLimits {
+            max_per_second: NonZeroU32::new(max_per_second),
+            ..PacketLimits::default()
+        });
+        decoder
+    }
+
+    #[test]
+    fn rate_limit_trips_once_the_window_is_full() {
+        let mut decoder = rate_limited(2);
+        assert!(decoder.check_rate().is_ok());
+        assert!(decoder.check_rate().is_ok());
+        assert!(matches!(
+            decoder.check_rate(),
+            Err(PacketError::RateLimited(2))
+        ));
+    }
+
+    #[test]
+    fn rate_limit_window_resets_after_a_second() {
+        let mut decoder = rate_limited(1);
+        assert!(decoder.check_rate().is_ok());
+        assert!(decoder.check_rate().is_err());
+
+        decoder.rate_window_start = Instant::now().checked_sub(Duration::from_secs(1)).unwrap();
+        assert!(decoder.check_rate().is_ok());
+        assert_eq!(decoder.rate_window_packets, 1);
+    }
+
+    #[tokio::test]
+    async fn oversized_payloads_are_rejected() {
+        let mut stream = frame(5, &[1, 2, 3]);
+        stream.extend(frame(5, &[1, 2, 3, 4]));
+        let mut decoder = TCPNetworkDecoder::new(stream.as_slice());
+        decoder.set_limits(PacketLimits {
+            max_payload_sizes: [(5, 3)].into_iter().collect(),
+            ..PacketLimits::default()
+        });
+
+        let packet = decoder.get_raw_packet().await.unwrap();
+        assert_eq!((packet.id, packet.payload.as_ref()), (5, &[1, 2, 3][..]));
+        let error = decoder.get_raw_packet().await.unwrap_err();
+        assert!(matches!(
+            error,
+            PacketError::PayloadTooLarge {
+                id: 5,
+                size: 4,
+                max: 3
+            }
+        ));
+        assert!(error.is_limit_exceeded());
+    }
+}
+
 /* TODO: Tests.
 #[cfg(test)]
 mod tests {
@@ -1,28 +1,26 @@
 //! # Steel Protocol Packet Traits
 //!
 //! This module contains the traits for the packets.
-use std::{
-    io::{Cursor, Write},
-    num::NonZeroU32,
-    sync::Arc,
-};
+use std::{io::Write, mem, num::NonZeroU32, sync::Arc};
 
+use bytes::Bytes;
 use flate2::{Compression, write::ZlibEncoder};
 use serde::Deserialize;
 use steel_utils::{
     FrontVec,
     codec::VarInt,
-    serial::{ReadFrom, WriteTo},
+    serial::{ReadFromBytes, WriteTo},
 };
 
+use crate::buffer_pool;
 use crate::utils::{ConnectionProtocol, MAX_PACKET_DATA_SIZE, MAX_PACKET_SIZE, PacketError};
 
 // These are the network read/write traits
 /// A trait for packets sent from the server to the client.
-pub trait ServerPacket: ReadFrom {
+pub trait ServerPacket: ReadFromBytes {
     /// Reads a packet from the given data.
-    fn read_packet(data: &mut Cursor<&[u8]>) -> Result<Self, PacketError> {
-        Self::read(data).map_err(PacketError::from)
+    fn read_packet(data: &mut Bytes) -> Result<Self, PacketError> {
+        Self::read_from_bytes(data).map_err(PacketError::from)
     }
 }
 
@@ -52,7 +50,7 @@ pub trait ClientPacket: WriteTo {
 }
 
 /// Information about compression.
-#[derive(Copy, Clone, Debug, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
 pub struct CompressionInfo {
     /// The compression threshold used when compression is enabled.
     /// Its an `NonZeroU32` to allow for nullptr optimization in `Option<Self>` cases
@@ -116,6 +114,8 @@ pub struct EncodedPacket {
     // This is optimized for reduces allocation
     /// The encoded data.
     pub encoded_data: Arc<FrontVec>,
+    /// Length of the packet id and payload before compression.
+    pub data_len: usize,
 }
 
 impl EncodedPacket {
@@ -132,6 +132,7 @@ impl EncodedPacket {
 
         Ok(Self {
             encoded_data: Arc::new(packet_data),
+            data_len,
         })
     }
 
@@ -146,7 +147,7 @@ impl EncodedPacket {
         }
 
         if data_len >= compression.threshold.get() as _ {
-            let mut buf = FrontVec::new(10);
+            let mut buf = buffer_pool::take(10);
             let mut compressor =
                 ZlibEncoder::new(&mut buf, Compression::new(compression.level as u32));
 
@@ -156,6 +157,7 @@ impl EncodedPacket {
             compressor
                 .finish()
                 .map_err(|e| PacketError::CompressionFailed(e.to_string()))?;
+            buffer_pool::recycle(packet_data);
 
             // compressed data cant be larger so we dont need to check the size again
             let varint_size = VarInt::written_size(data_len as i32);
@@ -170,6 +172,7 @@ impl EncodedPacket {
 
             Ok(Self {
                 encoded_data: Arc::new(buf),
+                data_len,
             })
         } else {
             // Pushed before data:
@@ -184,6 +187,7 @@ impl EncodedPacket {
 
             Ok(Self {
                 encoded_data: Arc::new(packet_data),
+                data_len,
             })
         }
     }
@@ -206,7 +210,7 @@ impl EncodedPacket {
         packet: P,
         protocol: ConnectionProtocol,
     ) -> Result<FrontVec, PacketError> {
-        let mut buf = FrontVec::new(6);
+        let mut buf = buffer_pool::take(6);
         packet.write_packet(&mut buf, protocol)?;
         Ok(buf)
     }
@@ -219,3 +223,12 @@ impl EncodedPacket {
         }
     }
 }
+
+impl Drop for EncodedPacket {
+    fn drop(&mut self) {
+        // The last clone hands its buffer back for the next packet to reuse.
+        if let Some(data) = Arc::get_mut(&mut self.encoded_data) {
+            buffer_pool::recycle(mem::take(data));
+        }
+    }
+}
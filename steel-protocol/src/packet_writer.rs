@@ -8,18 +8,25 @@ Credit to https://github.com/Pumpkin-MC/Pumpkin/ for this implementation.
 use std::{
     io,
     pin::Pin,
+    slice,
+    sync::Arc,
     task::{Context, Poll},
 };
 
-use aes::cipher::KeyIvInit;
 use thiserror::Error;
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use crate::{
-    packet_traits::EncodedPacket,
-    utils::{Aes128Cfb8Enc, PacketError, StreamEncryptor},
+    buffer_pool,
+    packet_traits::{CompressionInfo, EncodedPacket},
+    packets::game::CBundleDelimiter,
+    traffic::TrafficCounters,
+    utils::{Aes128Cfb8Enc, ConnectionProtocol, PacketError, StreamEncryptor},
 };
 
+/// Vanilla `BundlerInfo.BUNDLE_SIZE_LIMIT`: the most packets a client accepts in one bundle.
+pub const BUNDLE_SIZE_LIMIT: usize = 4096;
+
 // raw -> compress -> encrypt
 /// A writer that can encrypt data.
 pub enum EncryptionWriter<W: AsyncWrite + Unpin> {
@@ -93,6 +100,7 @@ impl<W: AsyncWrite + Unpin> AsyncWrite for EncryptionWriter<W> {
 /// Supports Aes128 Encryption
 pub struct TCPNetworkEncoder<W: AsyncWrite + Unpin> {
     writer: EncryptionWriter<W>,
+    traffic: Option<Arc<TrafficCounters>>,
 }
 
 impl<W: AsyncWrite + Unpin> TCPNetworkEncoder<W> {
@@ -100,19 +108,24 @@ impl<W: AsyncWrite + Unpin> TCPNetworkEncoder<W> {
     pub const fn new(writer: W) -> Self {
         Self {
             writer: EncryptionWriter::None(writer),
+            traffic: None,
         }
     }
 
+    /// Counts packets written from now on in `traffic`.
+    pub fn set_traffic(&mut self, traffic: Arc<TrafficCounters>) {
+        self.traffic = Some(traffic);
+    }
+
     /// NOTE: Encryption can only be set; a minecraft stream cannot go back to being unencrypted
     ///
     /// # Panics
     /// - If the stream is already encrypted.
-    /// - If the key is invalid.
     pub fn set_encryption(&mut self, key: &[u8; 16]) {
         if matches!(self.writer, EncryptionWriter::Encrypt(_)) {
             panic!("Cannot upgrade a stream that already has a cipher!");
         }
-        let cipher = Aes128Cfb8Enc::new_from_slices(key, key).expect("invalid key");
+        let cipher = Aes128Cfb8Enc::new(key);
         replace_with::replace_with_or_abort(&mut self.writer, |encoder| encoder.upgrade(cipher));
     }
 
@@ -122,10 +135,25 @@ impl<W: AsyncWrite + Unpin> TCPNetworkEncoder<W> {
     /// - If the packet fails to write.
     /// - If the stream fails to flush.
     pub async fn write_packet(&mut self, packet: &EncodedPacket) -> Result<(), PacketError> {
-        self.writer
-            .write_all(&packet.encoded_data)
-            .await
-            .map_err(|e| PacketError::EncryptionFailed(e.to_string()))?;
+        self.write_packets(slice::from_ref(packet)).await
+    }
+
+    /// Writes several packets to the stream and flushes once, so a batch of queued
+    /// packets goes out in as few TCP writes as the buffer allows.
+    ///
+    /// # Errors
+    /// - If a packet fails to write.
+    /// - If the stream fails to flush.
+    pub async fn write_packets(&mut self, packets: &[EncodedPacket]) -> Result<(), PacketError> {
+        for packet in packets {
+            self.writer
+                .write_all(&packet.encoded_data)
+                .await
+                .map_err(|e| PacketError::EncryptionFailed(e.to_string()))?;
+            if let Some(traffic) = &self.traffic {
+                traffic.record_out(packet.encoded_data.len(), packet.data_len);
+            }
+        }
 
         self.writer
             .flush()
@@ -134,6 +162,45 @@ impl<W: AsyncWrite + Unpin> TCPNetworkEncoder<W> {
     }
 }
 
+/// Joins `packets` into a single write wrapped in bundle delimiters, so the client
+/// applies them in the same frame and nothing else is queued in between.
+///
+/// More than [`BUNDLE_SIZE_LIMIT`] packets are split into consecutive bundles.
+///
+/// # Errors
+/// - If the delimiter fails to encode.
+pub fn encode_bundle(
+    packets: &[EncodedPacket],
+    compression: Option<CompressionInfo>,
+) -> Result<EncodedPacket, PacketError> {
+    let delimiter =
+        EncodedPacket::from_bare(CBundleDelimiter, compression, ConnectionProtocol::Play)?;
+    let delimiter_data_len = delimiter.data_len;
+    let delimiter = delimiter.encoded_data.as_slice();
+    let bundles = packets.len().div_ceil(BUNDLE_SIZE_LIMIT);
+    let size = packets
+        .iter()
+        .map(|packet| packet.encoded_data.len())
+        .sum::<usize>()
+        + delimiter.len() * 2 * bundles;
+
+    let mut data = buffer_pool::take(0);
+    data.reserve(size);
+    for bundle in packets.chunks(BUNDLE_SIZE_LIMIT) {
+        data.extend_from_slice(delimiter);
+        for packet in bundle {
+            data.extend_from_slice(packet.encoded_data.as_slice());
+        }
+        data.extend_from_slice(delimiter);
+    }
+    let data_len = packets.iter().map(|packet| packet.data_len).sum::<usize>()
+        + delimiter_data_len * 2 * bundles;
+    Ok(EncodedPacket {
+        encoded_data: Arc::new(data),
+        data_len,
+    })
+}
+
 /// An error that occurs when the compression level is invalid.
 #[derive(Error, Debug)]
 #[error("Invalid compression Level")]
@@ -0,0 +1,23 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::config::C_COOKIE_REQUEST;
+use steel_registry::packets::login::C_COOKIE_REQUEST as LOGIN_C_COOKIE_REQUEST;
+use steel_registry::packets::play::C_COOKIE_REQUEST as PLAY_C_COOKIE_REQUEST;
+use steel_utils::Identifier;
+
+/// Asks the client for the cookie stored under `key`.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(
+    Login = LOGIN_C_COOKIE_REQUEST,
+    Config = C_COOKIE_REQUEST,
+    Play = PLAY_C_COOKIE_REQUEST
+)]
+pub struct CCookieRequest {
+    pub key: Identifier,
+}
+
+impl CCookieRequest {
+    #[must_use]
+    pub const fn new(key: Identifier) -> Self {
+        Self { key }
+    }
+}
@@ -0,0 +1,40 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::config::C_CUSTOM_REPORT_DETAILS;
+use steel_registry::packets::play::C_CUSTOM_REPORT_DETAILS as PLAY_C_CUSTOM_REPORT_DETAILS;
+
+/// Vanilla `ClientboundCustomReportDetailsPacket.MAX_DETAIL_KEY_LENGTH`.
+pub const MAX_REPORT_DETAIL_KEY_LENGTH: usize = 128;
+/// Vanilla `ClientboundCustomReportDetailsPacket.MAX_DETAIL_VALUE_LENGTH`.
+pub const MAX_REPORT_DETAIL_VALUE_LENGTH: usize = 4096;
+/// Vanilla `ClientboundCustomReportDetailsPacket.MAX_DETAILS_SIZE`.
+pub const MAX_REPORT_DETAILS: usize = 32;
+
+/// Details the client adds to its crash and disconnect reports.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Config = C_CUSTOM_REPORT_DETAILS, Play = PLAY_C_CUSTOM_REPORT_DETAILS)]
+pub struct CCustomReportDetails {
+    #[write(as = Prefixed(VarInt))]
+    pub details: Vec<ReportDetail>,
+}
+
+#[derive(WriteTo, Clone, Debug)]
+pub struct ReportDetail {
+    #[write(as = Prefixed(VarInt))]
+    pub key: String,
+    #[write(as = Prefixed(VarInt))]
+    pub value: String,
+}
+
+impl CCustomReportDetails {
+    #[must_use]
+    pub const fn new(details: Vec<ReportDetail>) -> Self {
+        Self { details }
+    }
+}
+
+impl ReportDetail {
+    #[must_use]
+    pub const fn new(key: String, value: String) -> Self {
+        Self { key, value }
+    }
+}
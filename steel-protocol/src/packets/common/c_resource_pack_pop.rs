@@ -0,0 +1,11 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::config::C_RESOURCE_PACK_POP;
+use steel_registry::packets::play::C_RESOURCE_PACK_POP as PLAY_C_RESOURCE_PACK_POP;
+use uuid::Uuid;
+
+/// Removes one server resource pack from the client, or all of them when `id` is `None`.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Config = C_RESOURCE_PACK_POP, Play = PLAY_C_RESOURCE_PACK_POP)]
+pub struct CResourcePackPop {
+    pub id: Option<Uuid>,
+}
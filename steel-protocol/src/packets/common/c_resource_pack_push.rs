@@ -0,0 +1,19 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::config::C_RESOURCE_PACK_PUSH;
+use steel_registry::packets::play::C_RESOURCE_PACK_PUSH as PLAY_C_RESOURCE_PACK_PUSH;
+use text_components::TextComponent;
+use uuid::Uuid;
+
+/// Asks the client to download and apply a server resource pack.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Config = C_RESOURCE_PACK_PUSH, Play = PLAY_C_RESOURCE_PACK_PUSH)]
+pub struct CResourcePackPush {
+    pub id: Uuid,
+    #[write(as = Prefixed(VarInt))]
+    pub url: String,
+    /// Lowercase hex SHA-1 of the pack, or empty to skip verification.
+    #[write(as = Prefixed(VarInt), bound = 40)]
+    pub hash: String,
+    pub required: bool,
+    pub prompt: Option<TextComponent>,
+}
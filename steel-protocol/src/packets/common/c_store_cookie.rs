@@ -0,0 +1,23 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::config::C_STORE_COOKIE;
+use steel_registry::packets::play::C_STORE_COOKIE as PLAY_C_STORE_COOKIE;
+use steel_utils::Identifier;
+
+/// Maximum cookie payload size, vanilla `ClientboundStoreCookiePacket.PAYLOAD_STREAM_CODEC`.
+pub const MAX_COOKIE_SIZE: usize = 5120;
+
+/// Stores a cookie on the client. Cookies survive transfers to other servers.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Config = C_STORE_COOKIE, Play = PLAY_C_STORE_COOKIE)]
+pub struct CStoreCookie {
+    pub key: Identifier,
+    #[write(as = Prefixed(VarInt), bound = 5120)]
+    pub payload: Vec<u8>,
+}
+
+impl CStoreCookie {
+    #[must_use]
+    pub const fn new(key: Identifier, payload: Vec<u8>) -> Self {
+        Self { key, payload }
+    }
+}
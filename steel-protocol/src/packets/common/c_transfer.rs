@@ -0,0 +1,20 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::config::C_TRANSFER;
+use steel_registry::packets::play::C_TRANSFER as PLAY_C_TRANSFER;
+
+/// Tells the client to reconnect to another server with the transfer intent.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Config = C_TRANSFER, Play = PLAY_C_TRANSFER)]
+pub struct CTransfer {
+    #[write(as = Prefixed(VarInt))]
+    pub host: String,
+    #[write(as = VarInt)]
+    pub port: i32,
+}
+
+impl CTransfer {
+    #[must_use]
+    pub const fn new(host: String, port: i32) -> Self {
+        Self { host, port }
+    }
+}
@@ -1,20 +1,39 @@
+mod c_cookie_request;
 mod c_custom_payload;
+mod c_custom_report_details;
 mod c_disconnect;
 mod c_keep_alive;
 mod c_pong_response;
+mod c_resource_pack_pop;
+mod c_resource_pack_push;
+mod c_store_cookie;
+mod c_transfer;
 mod c_update_tags;
 mod s_client_information;
+mod s_cookie_response;
 mod s_custom_payload;
 mod s_keep_alive;
 mod s_ping_request;
+mod s_resource_pack;
 
+pub use c_cookie_request::CCookieRequest;
 pub use c_custom_payload::CCustomPayload;
+pub use c_custom_report_details::{
+    CCustomReportDetails, MAX_REPORT_DETAIL_KEY_LENGTH, MAX_REPORT_DETAIL_VALUE_LENGTH,
+    MAX_REPORT_DETAILS, ReportDetail,
+};
 pub use c_disconnect::CDisconnect;
 pub use c_keep_alive::CKeepAlive;
 pub use c_pong_response::CPongResponse;
+pub use c_resource_pack_pop::CResourcePackPop;
+pub use c_resource_pack_push::CResourcePackPush;
+pub use c_store_cookie::{CStoreCookie, MAX_COOKIE_SIZE};
+pub use c_transfer::CTransfer;
 pub use c_update_tags::CUpdateTags;
 pub use c_update_tags::TagCollection;
 pub use s_client_information::{ChatVisibility, HumanoidArm, ParticleStatus, SClientInformation};
-pub use s_custom_payload::SCustomPayload;
+pub use s_cookie_response::SCookieResponse;
+pub use s_custom_payload::{Payload, SCustomPayload};
 pub use s_keep_alive::SKeepAlive;
 pub use s_ping_request::SPingRequest;
+pub use s_resource_pack::{ResourcePackAction, SResourcePack};
@@ -0,0 +1,34 @@
+use steel_macros::{ReadFrom, ServerPacket};
+use steel_utils::Identifier;
+
+/// The client's answer to a `CCookieRequest`. `payload` is `None` when nothing is stored.
+#[derive(ReadFrom, ServerPacket, Clone, Debug)]
+pub struct SCookieResponse {
+    pub key: Identifier,
+    #[read(as = Prefixed(VarInt), bound = 5120)]
+    pub payload: Option<Vec<u8>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use steel_utils::serial::{ReadFrom as _, WriteTo as _};
+
+    use super::*;
+
+    #[test]
+    fn cookie_response_reads_optional_payload() {
+        let mut bytes = Vec::new();
+        Identifier::vanilla_static("session")
+            .write(&mut bytes)
+            .expect("identifier should write");
+        bytes.extend_from_slice(&[1, 3, 7, 8, 9]);
+
+        let packet = SCookieResponse::read(&mut Cursor::new(&bytes))
+            .unwrap_or_else(|error| panic!("cookie response should parse: {error}"));
+
+        assert_eq!(packet.key, Identifier::vanilla_static("session"));
+        assert_eq!(packet.payload, Some(vec![7, 8, 9]));
+    }
+}
@@ -1,24 +1,22 @@
-use std::io::{Cursor, Read};
+use std::io;
 
-use steel_macros::{ReadFrom, ServerPacket};
+use bytes::Bytes;
+use steel_macros::{ReadFromBytes, ServerPacket};
 use steel_utils::Identifier;
+use steel_utils::serial::ReadFromBytes;
 
-use steel_utils::serial::ReadFrom;
-
-#[derive(ReadFrom, ServerPacket, Clone, Debug)]
+#[derive(ReadFromBytes, ServerPacket, Clone, Debug)]
 pub struct SCustomPayload {
     pub identifier: Identifier,
-    //#[read(as = "vec")]
     pub payload: Payload,
 }
 
+/// The unprefixed rest of a packet, sliced out of the packet buffer.
 #[derive(Clone, Debug)]
-pub struct Payload(pub Vec<u8>);
+pub struct Payload(pub Bytes);
 
-impl ReadFrom for Payload {
-    fn read(data: &mut Cursor<&[u8]>) -> Result<Self, std::io::Error> {
-        let mut buf = vec![];
-        data.read_to_end(&mut buf)?;
-        Ok(Self(buf))
+impl ReadFromBytes for Payload {
+    fn read_from_bytes(data: &mut Bytes) -> io::Result<Self> {
+        Bytes::read_from_bytes(data).map(Self)
     }
 }
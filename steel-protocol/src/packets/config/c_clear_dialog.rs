@@ -0,0 +1,6 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::config::C_CLEAR_DIALOG;
+
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Config = C_CLEAR_DIALOG)]
+pub struct CClearDialog {}
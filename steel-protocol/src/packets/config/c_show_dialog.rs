@@ -0,0 +1,18 @@
+use simdnbt::owned::NbtTag;
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::config::C_SHOW_DIALOG;
+
+/// Opens a dialog. Unlike in play, the configuration packet always carries
+/// the dialog inline, since no registries are known yet.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Config = C_SHOW_DIALOG)]
+pub struct CShowDialog {
+    pub dialog: NbtTag,
+}
+
+impl CShowDialog {
+    #[must_use]
+    pub const fn new(dialog: NbtTag) -> Self {
+        Self { dialog }
+    }
+}
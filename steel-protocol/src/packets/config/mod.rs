@@ -1,10 +1,13 @@
+mod c_clear_dialog;
 mod c_finish_configuration;
 mod c_registry_data;
 mod c_select_known;
 mod c_server_links;
+mod c_show_dialog;
 mod s_finish_configuration;
 mod s_select_known;
 
+pub use c_clear_dialog::CClearDialog;
 pub use c_finish_configuration::CFinishConfiguration;
 pub use c_registry_data::CRegistryData;
 pub use c_registry_data::RegistryEntry;
@@ -12,5 +15,6 @@ pub use c_select_known::CSelectKnownPacks;
 pub use c_server_links::CServerLinks;
 pub use c_server_links::Link;
 pub use c_server_links::ServerLinksType;
+pub use c_show_dialog::CShowDialog;
 pub use s_finish_configuration::SFinishConfiguration;
 pub use s_select_known::SSelectKnownPacks;
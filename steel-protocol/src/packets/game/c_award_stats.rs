@@ -0,0 +1,28 @@
+use std::io::{Result, Write};
+
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_AWARD_STATS;
+use steel_registry::stat::Stat;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::WriteTo;
+
+/// The current value of one statistic.
+#[derive(Clone, Debug)]
+pub struct AwardedStat {
+    pub stat: Stat,
+    pub value: i32,
+}
+
+impl WriteTo for AwardedStat {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.stat.write(writer)?;
+        VarInt(self.value).write(writer)
+    }
+}
+
+/// Sends statistic values to the client, in reply to a stats request.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_AWARD_STATS)]
+pub struct CAwardStats {
+    pub stats: Vec<AwardedStat>,
+}
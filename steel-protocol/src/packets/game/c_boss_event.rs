@@ -0,0 +1,110 @@
+//! Packet that adds, updates or removes a boss bar on the client.
+
+use std::io::{Result, Write};
+
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_BOSS_EVENT;
+use steel_utils::{codec::VarInt, serial::WriteTo};
+use text_components::TextComponent;
+use uuid::Uuid;
+
+/// Color of a boss bar.
+#[derive(WriteTo, Copy, Clone, Debug, PartialEq, Eq)]
+#[write(as = VarInt)]
+pub enum BossBarColor {
+    Pink = 0,
+    Blue = 1,
+    Red = 2,
+    Green = 3,
+    Yellow = 4,
+    Purple = 5,
+    White = 6,
+}
+
+/// How a boss bar is divided into segments.
+#[derive(WriteTo, Copy, Clone, Debug, PartialEq, Eq)]
+#[write(as = VarInt)]
+pub enum BossBarOverlay {
+    Progress = 0,
+    Notched6 = 1,
+    Notched10 = 2,
+    Notched12 = 3,
+    Notched20 = 4,
+}
+
+/// Boss bar flag that darkens the sky.
+pub const BOSS_BAR_FLAG_DARKEN_SCREEN: u8 = 1;
+/// Boss bar flag that plays boss music.
+pub const BOSS_BAR_FLAG_PLAY_MUSIC: u8 = 2;
+/// Boss bar flag that creates world fog.
+pub const BOSS_BAR_FLAG_CREATE_WORLD_FOG: u8 = 4;
+
+/// The change a [`CBossEvent`] applies to a boss bar.
+#[derive(Clone, Debug)]
+pub enum BossEventOperation {
+    Add {
+        name: TextComponent,
+        progress: f32,
+        color: BossBarColor,
+        overlay: BossBarOverlay,
+        flags: u8,
+    },
+    Remove,
+    UpdateProgress(f32),
+    UpdateName(TextComponent),
+    UpdateStyle {
+        color: BossBarColor,
+        overlay: BossBarOverlay,
+    },
+    UpdateProperties(u8),
+}
+
+/// Clientbound packet for a single boss bar change.
+///
+/// Equivalent to `ClientboundBossEventPacket` in Minecraft.
+#[derive(ClientPacket, Clone, Debug)]
+#[packet_id(Play = C_BOSS_EVENT)]
+pub struct CBossEvent {
+    pub id: Uuid,
+    pub operation: BossEventOperation,
+}
+
+impl WriteTo for CBossEvent {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.id.write(writer)?;
+        match &self.operation {
+            BossEventOperation::Add {
+                name,
+                progress,
+                color,
+                overlay,
+                flags,
+            } => {
+                VarInt(0).write(writer)?;
+                name.write(writer)?;
+                progress.write(writer)?;
+                color.write(writer)?;
+                overlay.write(writer)?;
+                flags.write(writer)
+            }
+            BossEventOperation::Remove => VarInt(1).write(writer),
+            BossEventOperation::UpdateProgress(progress) => {
+                VarInt(2).write(writer)?;
+                progress.write(writer)
+            }
+            BossEventOperation::UpdateName(name) => {
+                VarInt(3).write(writer)?;
+                name.write(writer)
+            }
+            BossEventOperation::UpdateStyle { color, overlay } => {
+                VarInt(4).write(writer)?;
+                color.write(writer)?;
+                overlay.write(writer)
+            }
+            BossEventOperation::UpdateProperties(flags) => {
+                VarInt(5).write(writer)?;
+                flags.write(writer)
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use steel_macros::ClientPacket;
+use steel_registry::packets::play::C_DELETE_CHAT;
+use steel_utils::{codec::VarInt, serial::WriteTo};
+
+use super::c_player_chat::PreviousMessage;
+
+/// Clientbound packet that hides a signed chat message the client already shows.
+///
+/// The signature is packed like the previous messages of `CPlayerChat`: an id
+/// into the recipient's signature cache, or id 0 followed by the full signature.
+#[derive(ClientPacket, Clone, Debug)]
+#[packet_id(Play = C_DELETE_CHAT)]
+pub struct CDeleteChat {
+    pub message_signature: PreviousMessage,
+}
+
+impl WriteTo for CDeleteChat {
+    fn write(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        VarInt(self.message_signature.id).write(writer)?;
+        if self.message_signature.id == 0 {
+            let Some(signature) = &self.message_signature.signature else {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "PreviousMessage with id=0 must have signature",
+                ));
+            };
+            writer.write_all(signature)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+//! Clientbound explode packet - shows an explosion and pushes the receiving player.
+
+use glam::DVec3;
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_EXPLODE;
+use steel_registry::particle_type::ParticleData;
+
+/// A particle the client may spawn at each destroyed block, with its weight.
+///
+/// Vanilla `Weighted<ExplosionParticleInfo>`.
+#[derive(WriteTo, Clone, Debug)]
+pub struct ExplosionParticleInfo {
+    pub particle: ParticleData,
+    /// Scales the particle's offset from the destroyed block.
+    pub scaling: f32,
+    pub speed: f32,
+    #[write(as = VarInt)]
+    pub weight: i32,
+}
+
+/// Sent to every player near an explosion.
+///
+/// The client plays the sound, spawns the particles and adds
+/// `player_knockback` to its own velocity.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_EXPLODE)]
+pub struct CExplode {
+    pub center: DVec3,
+    pub radius: f32,
+    /// Number of blocks the explosion destroyed.
+    pub block_count: i32,
+    /// Knockback for the receiving player, if the explosion hit them.
+    pub player_knockback: Option<DVec3>,
+    pub explosion_particle: ParticleData,
+    /// The holder-encoded sound event ID (`VarInt`), registry id + 1.
+    #[write(as = VarInt)]
+    pub explosion_sound: i32,
+    #[write(as = Prefixed(VarInt))]
+    pub block_particles: Vec<ExplosionParticleInfo>,
+}
@@ -0,0 +1,119 @@
+use std::io::{Result, Write};
+
+use steel_macros::ClientPacket;
+use steel_registry::packets::play::C_MAP_ITEM_DATA;
+use steel_utils::{
+    codec::VarInt,
+    serial::{PrefixedWrite, WriteTo},
+};
+use text_components::TextComponent;
+
+/// A decoration drawn on a map, such as a player or banner marker.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MapDecoration {
+    /// Registry id of the `map_decoration_type`.
+    pub decoration_type: i32,
+    /// X position in half pixels from the map center, from -128 to 127.
+    pub x: i8,
+    /// Y position in half pixels from the map center, from -128 to 127.
+    pub y: i8,
+    /// Rotation in sixteenths of a full turn.
+    pub rot: u8,
+    /// Name shown next to the decoration.
+    pub name: Option<TextComponent>,
+}
+
+impl WriteTo for MapDecoration {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        VarInt(self.decoration_type).write(writer)?;
+        self.x.write(writer)?;
+        self.y.write(writer)?;
+        (self.rot & 15).write(writer)?;
+        self.name.write(writer)
+    }
+}
+
+/// A rectangle of map colors that changed since the last update.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MapPatch {
+    pub start_x: u8,
+    pub start_y: u8,
+    pub width: u8,
+    pub height: u8,
+    /// Packed colors in rows of `width`.
+    pub colors: Vec<u8>,
+}
+
+/// Clientbound packet with the colors and decorations of a map.
+///
+/// Equivalent to `ClientboundMapItemDataPacket` in Minecraft.
+#[derive(ClientPacket, Clone, Debug)]
+#[packet_id(Play = C_MAP_ITEM_DATA)]
+pub struct CMapItemData {
+    pub map_id: i32,
+    pub scale: u8,
+    pub locked: bool,
+    /// The full decoration list, or `None` when it did not change.
+    pub decorations: Option<Vec<MapDecoration>>,
+    /// The changed colors, or `None` when they did not change.
+    pub color_patch: Option<MapPatch>,
+}
+
+impl WriteTo for CMapItemData {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        VarInt(self.map_id).write(writer)?;
+        self.scale.write(writer)?;
+        self.locked.write(writer)?;
+        self.decorations.write(writer)?;
+        // Vanilla writes the patch without a presence flag: a width of zero
+        // means no patch.
+        let Some(patch) = &self.color_patch else {
+            return 0u8.write(writer);
+        };
+        patch.width.write(writer)?;
+        patch.height.write(writer)?;
+        patch.start_x.write(writer)?;
+        patch.start_y.write(writer)?;
+        patch.colors.write_prefixed::<VarInt>(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_zero_width_without_patch() {
+        let packet = CMapItemData {
+            map_id: 3,
+            scale: 1,
+            locked: true,
+            decorations: Some(vec![MapDecoration {
+                decoration_type: 0,
+                x: -2,
+                y: 5,
+                rot: 17,
+                name: None,
+            }]),
+            color_patch: None,
+        };
+        let mut bytes = Vec::new();
+        packet.write(&mut bytes).expect("packet should write");
+        assert_eq!(bytes, [3, 1, 1, 1, 1, 0, 0xFE, 5, 1, 0, 0]);
+
+        let patch = CMapItemData {
+            color_patch: Some(MapPatch {
+                start_x: 4,
+                start_y: 6,
+                width: 1,
+                height: 2,
+                colors: vec![7, 8],
+            }),
+            decorations: None,
+            ..packet
+        };
+        let mut bytes = Vec::new();
+        patch.write(&mut bytes).expect("packet should write");
+        assert_eq!(bytes, [3, 1, 1, 0, 1, 2, 4, 6, 2, 7, 8]);
+    }
+}
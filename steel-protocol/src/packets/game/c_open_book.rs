@@ -0,0 +1,26 @@
+use std::io::{Result, Write};
+
+use steel_macros::ClientPacket;
+use steel_registry::packets::play::C_OPEN_BOOK;
+use steel_utils::types::InteractionHand;
+use steel_utils::{codec::VarInt, serial::WriteTo};
+
+/// Clientbound packet that opens the written book held in `hand`.
+///
+/// Equivalent to `ClientboundOpenBookPacket` in Minecraft.
+#[derive(ClientPacket, Clone, Debug)]
+#[packet_id(Play = C_OPEN_BOOK)]
+pub struct COpenBook {
+    /// The hand holding the book.
+    pub hand: InteractionHand,
+}
+
+impl WriteTo for COpenBook {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        let id = match self.hand {
+            InteractionHand::MainHand => 0,
+            InteractionHand::OffHand => 1,
+        };
+        VarInt(id).write(writer)
+    }
+}
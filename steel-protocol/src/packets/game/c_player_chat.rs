@@ -121,6 +121,9 @@ impl steel_utils::serial::WriteTo for CPlayerChat {
             FilterType::PartiallyFiltered(_) => 2,
         })
         .write(writer)?;
+        if let FilterType::PartiallyFiltered(mask) = &self.filter_type {
+            mask.write(writer)?;
+        }
 
         self.chat_type.write(writer)?;
         Ok(())
@@ -0,0 +1,44 @@
+use std::io::{Result, Write};
+
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_RECIPE_BOOK_ADD;
+use steel_registry::recipe::RecipeDisplayEntry;
+use steel_utils::serial::WriteTo;
+
+/// Vanilla `ClientboundRecipeBookAddPacket.Entry`.
+#[derive(Clone, Debug)]
+pub struct RecipeBookAddEntry {
+    pub contents: RecipeDisplayEntry,
+    /// Shows the "new recipe unlocked" toast.
+    pub notification: bool,
+    /// Marks the recipe as not yet seen in the recipe book.
+    pub highlight: bool,
+}
+
+impl RecipeBookAddEntry {
+    const FLAG_NOTIFICATION: u8 = 1;
+    const FLAG_HIGHLIGHT: u8 = 2;
+}
+
+impl WriteTo for RecipeBookAddEntry {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.contents.write(writer)?;
+        let mut flags = 0;
+        if self.notification {
+            flags |= Self::FLAG_NOTIFICATION;
+        }
+        if self.highlight {
+            flags |= Self::FLAG_HIGHLIGHT;
+        }
+        flags.write(writer)
+    }
+}
+
+/// Adds recipes to the client's recipe book.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_RECIPE_BOOK_ADD)]
+pub struct CRecipeBookAdd {
+    pub entries: Vec<RecipeBookAddEntry>,
+    /// Whether the client should drop its known recipes first.
+    pub replace: bool,
+}
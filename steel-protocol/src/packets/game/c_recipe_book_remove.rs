@@ -0,0 +1,10 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_RECIPE_BOOK_REMOVE;
+
+/// Removes recipes from the client's recipe book by display id.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_RECIPE_BOOK_REMOVE)]
+pub struct CRecipeBookRemove {
+    #[write(as = Prefixed(VarInt, inner = VarInt))]
+    pub recipes: Vec<i32>,
+}
@@ -0,0 +1,16 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_RECIPE_BOOK_SETTINGS;
+
+/// Vanilla `RecipeBookSettings`: open and filtering state of each recipe book.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_RECIPE_BOOK_SETTINGS)]
+pub struct CRecipeBookSettings {
+    pub crafting_open: bool,
+    pub crafting_filtering: bool,
+    pub furnace_open: bool,
+    pub furnace_filtering: bool,
+    pub blast_furnace_open: bool,
+    pub blast_furnace_filtering: bool,
+    pub smoker_open: bool,
+    pub smoker_filtering: bool,
+}
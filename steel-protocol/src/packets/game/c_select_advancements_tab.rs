@@ -0,0 +1,13 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_SELECT_ADVANCEMENTS_TAB;
+use steel_utils::Identifier;
+
+/// Opens the given advancement tab, or closes the screen's selection with `None`.
+///
+/// Equivalent to `ClientboundSelectAdvancementsTabPacket` in Minecraft.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_SELECT_ADVANCEMENTS_TAB)]
+pub struct CSelectAdvancementsTab {
+    /// Key of the root advancement of the tab.
+    pub tab: Option<Identifier>,
+}
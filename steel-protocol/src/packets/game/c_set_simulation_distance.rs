@@ -0,0 +1,9 @@
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::packets::play::C_SET_SIMULATION_DISTANCE;
+
+#[derive(ClientPacket, WriteTo)]
+#[packet_id(Play = C_SET_SIMULATION_DISTANCE)]
+pub struct CSetSimulationDistance {
+    #[write(as = VarInt)]
+    pub simulation_distance: i32,
+}
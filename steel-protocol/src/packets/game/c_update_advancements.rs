@@ -0,0 +1,52 @@
+use std::io::{Result, Write};
+
+use steel_macros::{ClientPacket, WriteTo};
+use steel_registry::advancement::AdvancementRef;
+use steel_registry::packets::play::C_UPDATE_ADVANCEMENTS;
+use steel_utils::Identifier;
+use steel_utils::serial::WriteTo;
+
+/// An advancement together with its key, vanilla `AdvancementHolder`.
+#[derive(Clone, Debug)]
+pub struct AdvancementHolder(pub AdvancementRef);
+
+impl WriteTo for AdvancementHolder {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.0.key.write(writer)?;
+        self.0.write(writer)
+    }
+}
+
+/// The progress of one criterion; `obtained` is the unix time in milliseconds.
+#[derive(WriteTo, Clone, Debug)]
+pub struct CriterionProgressEntry {
+    #[write(as = Prefixed(VarInt))]
+    pub criterion: String,
+    pub obtained: Option<i64>,
+}
+
+/// The criteria progress of one advancement.
+#[derive(WriteTo, Clone, Debug)]
+pub struct AdvancementProgressEntry {
+    pub advancement: Identifier,
+    #[write(as = Prefixed(VarInt))]
+    pub criteria: Vec<CriterionProgressEntry>,
+}
+
+/// Adds, removes and updates the advancements visible to the client.
+///
+/// Equivalent to `ClientboundUpdateAdvancementsPacket` in Minecraft.
+#[derive(ClientPacket, WriteTo, Clone, Debug)]
+#[packet_id(Play = C_UPDATE_ADVANCEMENTS)]
+pub struct CUpdateAdvancements {
+    /// Whether the client should drop all advancements it knows first.
+    pub reset: bool,
+    #[write(as = Prefixed(VarInt))]
+    pub added: Vec<AdvancementHolder>,
+    #[write(as = Prefixed(VarInt))]
+    pub removed: Vec<Identifier>,
+    #[write(as = Prefixed(VarInt))]
+    pub progress: Vec<AdvancementProgressEntry>,
+    /// Whether toasts should be shown for newly completed advancements.
+    pub show_advancements: bool,
+}
@@ -1,10 +1,12 @@
 mod c_add_entity;
 mod c_animate;
+mod c_award_stats;
 mod c_block_changed_ack;
 mod c_block_destruction;
 mod c_block_entity_data;
 mod c_block_event;
 mod c_block_update;
+mod c_boss_event;
 mod c_bundle_delimiter;
 mod c_change_difficulty;
 mod c_chunk_batch_finished;
@@ -17,9 +19,11 @@ mod c_container_set_data;
 mod c_container_set_slot;
 mod c_cooldown;
 mod c_damage_event;
+mod c_delete_chat;
 mod c_disguised_chat;
 mod c_entity_event;
 mod c_entity_position_sync;
+mod c_explode;
 mod c_forget_level_chunk;
 mod c_game_event;
 mod c_hurt_animation;
@@ -29,8 +33,11 @@ mod c_level_event;
 mod c_level_particles;
 mod c_light_update;
 mod c_login;
+mod c_map_item_data;
+mod c_merchant_offers;
 mod c_move_entity;
 mod c_move_vehicle;
+mod c_open_book;
 mod c_open_screen;
 mod c_open_sign_editor;
 mod c_player_abilities;
@@ -39,12 +46,16 @@ mod c_player_combat_kill;
 mod c_player_info_update;
 mod c_player_look_at;
 mod c_player_position;
+mod c_recipe_book_add;
+mod c_recipe_book_remove;
+mod c_recipe_book_settings;
 mod c_remove_entities;
 mod c_remove_mob_effect;
 mod c_remove_player_info;
 mod c_respawn;
 mod c_rotate_head;
 mod c_section_blocks_update;
+mod c_select_advancements_tab;
 mod c_set_border_center;
 mod c_set_border_lerp_size;
 mod c_set_border_size;
@@ -63,6 +74,7 @@ mod c_set_experience;
 mod c_set_health;
 mod c_set_held_slot;
 mod c_set_passengers;
+mod c_set_simulation_distance;
 mod c_set_time;
 mod c_sound;
 mod c_system_chat;
@@ -72,6 +84,7 @@ mod c_take_item_entity;
 mod c_teleport_entity;
 mod c_ticking_state;
 mod c_ticking_step;
+mod c_update_advancements;
 mod c_update_attributes;
 mod c_update_mob_effect;
 mod chat_session_data;
@@ -92,18 +105,29 @@ mod s_container_button_click;
 mod s_container_click;
 mod s_container_close;
 mod s_container_slot_state_changed;
+mod s_edit_book;
 mod s_interact;
+mod s_jigsaw_generate;
 mod s_move_player;
 mod s_move_vehicle;
+mod s_paddle_boat;
 mod s_pick_item_from_block;
 mod s_player_abilities;
 mod s_player_action;
 mod s_player_command;
 mod s_player_input;
 mod s_player_load;
+mod s_recipe_book_change_settings;
+mod s_recipe_book_seen_recipe;
+mod s_seen_advancements;
+mod s_select_trade;
+mod s_set_beacon;
 mod s_set_carried_item;
+mod s_set_command_block;
 mod s_set_creative_mode_slot;
 mod s_set_held_item;
+mod s_set_jigsaw_block;
+mod s_set_structure_block;
 mod s_sign_update;
 mod s_spectator_action;
 mod s_swing;
@@ -112,11 +136,16 @@ mod s_use_item_on;
 
 pub use c_add_entity::{CAddEntity, write_lp_vec3};
 pub use c_animate::{AnimateAction, CAnimate};
+pub use c_award_stats::{AwardedStat, CAwardStats};
 pub use c_block_changed_ack::CBlockChangedAck;
 pub use c_block_destruction::CBlockDestruction;
 pub use c_block_entity_data::CBlockEntityData;
 pub use c_block_event::CBlockEvent;
 pub use c_block_update::CBlockUpdate;
+pub use c_boss_event::{
+    BOSS_BAR_FLAG_CREATE_WORLD_FOG, BOSS_BAR_FLAG_DARKEN_SCREEN, BOSS_BAR_FLAG_PLAY_MUSIC,
+    BossBarColor, BossBarOverlay, BossEventOperation, CBossEvent,
+};
 pub use c_bundle_delimiter::CBundleDelimiter;
 pub use c_change_difficulty::CChangeDifficulty;
 pub use c_chunk_batch_finished::CChunkBatchFinished;
@@ -132,9 +161,11 @@ pub use c_container_set_data::CContainerSetData;
 pub use c_container_set_slot::CContainerSetSlot;
 pub use c_cooldown::CCooldown;
 pub use c_damage_event::CDamageEvent;
+pub use c_delete_chat::CDeleteChat;
 pub use c_disguised_chat::CDisguisedChat;
 pub use c_entity_event::CEntityEvent;
 pub use c_entity_position_sync::CEntityPositionSync;
+pub use c_explode::{CExplode, ExplosionParticleInfo};
 pub use c_forget_level_chunk::CForgetLevelChunk;
 pub use c_game_event::CGameEvent;
 pub use c_game_event::GameEventType;
@@ -149,10 +180,13 @@ pub use c_level_particles::CLevelParticles;
 pub use c_light_update::CLightUpdate;
 pub use c_login::CLogin;
 pub use c_login::CommonPlayerSpawnInfo;
+pub use c_map_item_data::{CMapItemData, MapDecoration, MapPatch};
+pub use c_merchant_offers::CMerchantOffers;
 pub use c_move_entity::{
     CMoveEntityPos, CMoveEntityPosRot, CMoveEntityRot, PackedEntityDelta, calc_delta, to_angle_byte,
 };
 pub use c_move_vehicle::CMoveVehicle;
+pub use c_open_book::COpenBook;
 pub use c_open_screen::COpenScreen;
 pub use c_open_sign_editor::COpenSignEditor;
 pub use c_player_abilities::{CPlayerAbilities, ability_flags};
@@ -163,12 +197,16 @@ pub use c_player_info_update::{
 };
 pub use c_player_look_at::{CPlayerLookAt, LookAtAnchor};
 pub use c_player_position::{CPlayerPosition, RelativeMovement};
+pub use c_recipe_book_add::{CRecipeBookAdd, RecipeBookAddEntry};
+pub use c_recipe_book_remove::CRecipeBookRemove;
+pub use c_recipe_book_settings::CRecipeBookSettings;
 pub use c_remove_entities::CRemoveEntities;
 pub use c_remove_mob_effect::CRemoveMobEffect;
 pub use c_remove_player_info::CRemovePlayerInfo;
 pub use c_respawn::CRespawn;
 pub use c_rotate_head::CRotateHead;
 pub use c_section_blocks_update::{BlockChange, CSectionBlocksUpdate};
+pub use c_select_advancements_tab::CSelectAdvancementsTab;
 pub use c_set_border_center::CSetBorderCenter;
 pub use c_set_border_lerp_size::CSetBorderLerpSize;
 pub use c_set_border_size::CSetBorderSize;
@@ -187,6 +225,7 @@ pub use c_set_experience::CSetExperience;
 pub use c_set_health::CSetHealth;
 pub use c_set_held_slot::CSetHeldSlot;
 pub use c_set_passengers::CSetPassengers;
+pub use c_set_simulation_distance::CSetSimulationDistance;
 pub use c_set_time::CSetTime;
 pub use c_sound::{CSound, SoundSource};
 pub use c_system_chat::CSystemChat;
@@ -196,6 +235,9 @@ pub use c_take_item_entity::CTakeItemEntity;
 pub use c_teleport_entity::CTeleportEntity;
 pub use c_ticking_state::CTickingState;
 pub use c_ticking_step::CTickingStep;
+pub use c_update_advancements::{
+    AdvancementHolder, AdvancementProgressEntry, CUpdateAdvancements, CriterionProgressEntry,
+};
 pub use c_update_attributes::{
     AttributeModifierData, AttributeModifierOperation, AttributeSnapshot, CUpdateAttributes,
 };
@@ -218,20 +260,33 @@ pub use s_container_button_click::SContainerButtonClick;
 pub use s_container_click::{ClickType, HashedPatchMap, HashedStack, SContainerClick};
 pub use s_container_close::SContainerClose;
 pub use s_container_slot_state_changed::SContainerSlotStateChanged;
+pub use s_edit_book::SEditBook;
 pub use s_interact::SInteract;
+pub use s_jigsaw_generate::SJigsawGenerate;
 pub use s_move_player::{
     SMovePlayer, SMovePlayerPos, SMovePlayerPosRot, SMovePlayerRot, SMovePlayerStatusOnly,
 };
 pub use s_move_vehicle::SMoveVehicle;
+pub use s_paddle_boat::SPaddleBoat;
 pub use s_pick_item_from_block::SPickItemFromBlock;
 pub use s_player_abilities::SPlayerAbilities;
 pub use s_player_action::{PlayerAction, SPlayerAction};
 pub use s_player_command::{PlayerCommandAction, SPlayerCommand};
 pub use s_player_input::SPlayerInput;
 pub use s_player_load::SPlayerLoad;
+pub use s_recipe_book_change_settings::{RecipeBookType, SRecipeBookChangeSettings};
+pub use s_recipe_book_seen_recipe::SRecipeBookSeenRecipe;
+pub use s_seen_advancements::{SSeenAdvancements, SeenAdvancementsAction};
+pub use s_select_trade::SSelectTrade;
+pub use s_set_beacon::SSetBeacon;
 pub use s_set_carried_item::SSetCarriedItem;
+pub use s_set_command_block::{CommandBlockMode, SSetCommandBlock};
 pub use s_set_creative_mode_slot::SSetCreativeModeSlot;
 pub use s_set_held_item::SSetHeldItem;
+pub use s_set_jigsaw_block::SSetJigsawBlock;
+pub use s_set_structure_block::{
+    MAX_STRUCTURE_DATA_LENGTH, MAX_STRUCTURE_SIZE, SSetStructureBlock, StructureBlockUpdateType,
+};
 pub use s_sign_update::SSignUpdate;
 pub use s_spectator_action::SSpectatorAction;
 pub use s_swing::SSwing;
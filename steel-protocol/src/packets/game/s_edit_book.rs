@@ -0,0 +1,86 @@
+use std::io::{Cursor, Error, Result};
+
+use steel_macros::ServerPacket;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::{PrefixedRead, ReadFrom};
+
+/// Maximum characters per book page.
+pub const MAX_BOOK_PAGE_LENGTH: usize = 1024;
+/// Maximum pages in a book.
+pub const MAX_BOOK_PAGES: usize = 100;
+/// Maximum characters in a book title.
+pub const MAX_BOOK_TITLE_LENGTH: usize = 32;
+
+/// Serverbound packet sent when a player saves or signs a writable book.
+///
+/// Equivalent to `ServerboundEditBookPacket` in Minecraft.
+#[derive(ServerPacket, Clone, Debug)]
+pub struct SEditBook {
+    /// The inventory slot of the book: a hotbar slot or 40 for the offhand.
+    pub slot: i32,
+    /// The page texts.
+    pub pages: Vec<String>,
+    /// The title when the book is being signed.
+    pub title: Option<String>,
+}
+
+impl ReadFrom for SEditBook {
+    fn read(data: &mut Cursor<&[u8]>) -> Result<Self> {
+        let slot = VarInt::read(data)?.0;
+
+        let page_count = VarInt::read(data)?.0;
+        let page_count = usize::try_from(page_count)
+            .ok()
+            .filter(|count| *count <= MAX_BOOK_PAGES)
+            .ok_or_else(|| Error::other(format!("Invalid book page count {page_count}")))?;
+        let mut pages = Vec::with_capacity(page_count);
+        for _ in 0..page_count {
+            pages.push(String::read_prefixed_bound::<VarInt>(
+                data,
+                MAX_BOOK_PAGE_LENGTH,
+            )?);
+        }
+
+        let title = if bool::read(data)? {
+            Some(String::read_prefixed_bound::<VarInt>(
+                data,
+                MAX_BOOK_TITLE_LENGTH,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(Self { slot, pages, title })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use steel_utils::serial::PrefixedWrite as _;
+
+    use super::*;
+
+    fn book_bytes(page_count: u8, title: Option<&str>) -> Vec<u8> {
+        let mut bytes = vec![40];
+        bytes.push(page_count);
+        for _ in 0..page_count.min(2) {
+            "page".write_prefixed::<VarInt>(&mut bytes).unwrap();
+        }
+        bytes.push(u8::from(title.is_some()));
+        if let Some(title) = title {
+            title.write_prefixed::<VarInt>(&mut bytes).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn edit_book_reads_pages_and_title() {
+        let packet = SEditBook::read(&mut Cursor::new(&book_bytes(2, Some("Title"))))
+            .unwrap_or_else(|error| panic!("edit book packet should parse: {error}"));
+        assert_eq!(packet.slot, 40);
+        assert_eq!(packet.pages, ["page", "page"]);
+        assert_eq!(packet.title.as_deref(), Some("Title"));
+
+        assert!(SEditBook::read(&mut Cursor::new(&book_bytes(101, None))).is_err());
+    }
+}
@@ -0,0 +1,16 @@
+use steel_macros::{ReadFrom, ServerPacket};
+use steel_utils::BlockPos;
+
+/// Serverbound packet sent when a player presses "Generate" in the jigsaw block screen.
+///
+/// Equivalent to `ServerboundJigsawGeneratePacket` in Minecraft.
+#[derive(ReadFrom, ServerPacket, Clone, Debug)]
+pub struct SJigsawGenerate {
+    /// The position of the jigsaw block to generate from.
+    pub pos: BlockPos,
+    /// How many jigsaw levels deep to generate.
+    #[read(as = VarInt)]
+    pub levels: i32,
+    /// Whether jigsaw blocks are kept instead of being replaced by their final state.
+    pub keep_jigsaws: bool,
+}
@@ -0,0 +1,8 @@
+use steel_macros::{ReadFrom, ServerPacket};
+
+/// Serverbound boat paddle state, sent by the controlling passenger.
+#[derive(ReadFrom, ServerPacket, Clone, Debug)]
+pub struct SPaddleBoat {
+    pub left: bool,
+    pub right: bool,
+}
@@ -0,0 +1,19 @@
+use steel_macros::{ReadFrom, ServerPacket};
+
+/// Vanilla `RecipeBookType`: which menu's recipe book a setting belongs to.
+#[derive(ReadFrom, Clone, Copy, Debug, PartialEq, Eq)]
+#[read(as = VarInt)]
+pub enum RecipeBookType {
+    Crafting = 0,
+    Furnace = 1,
+    BlastFurnace = 2,
+    Smoker = 3,
+}
+
+/// Sent when the player opens, closes or toggles filtering in a recipe book.
+#[derive(ServerPacket, ReadFrom, Clone, Debug)]
+pub struct SRecipeBookChangeSettings {
+    pub book_type: RecipeBookType,
+    pub is_open: bool,
+    pub is_filtering: bool,
+}
@@ -0,0 +1,8 @@
+use steel_macros::{ReadFrom, ServerPacket};
+
+/// Sent when the player hovers a highlighted recipe in the recipe book.
+#[derive(ServerPacket, ReadFrom, Clone, Debug)]
+pub struct SRecipeBookSeenRecipe {
+    #[read(as = VarInt)]
+    pub recipe: i32,
+}
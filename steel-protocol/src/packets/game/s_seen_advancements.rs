@@ -0,0 +1,37 @@
+use std::io::{Cursor, Error, Result};
+
+use steel_macros::ServerPacket;
+use steel_utils::Identifier;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::ReadFrom;
+
+/// What the client did in the advancement screen.
+#[derive(Clone, Debug)]
+pub enum SeenAdvancementsAction {
+    /// The player opened the tab of the given root advancement.
+    OpenedTab(Identifier),
+    ClosedScreen,
+}
+
+/// Client -> Server: The player opened an advancement tab or closed the screen.
+///
+/// Equivalent to `ServerboundSeenAdvancementsPacket` in Minecraft.
+#[derive(ServerPacket, Clone, Debug)]
+pub struct SSeenAdvancements {
+    pub action: SeenAdvancementsAction,
+}
+
+impl ReadFrom for SSeenAdvancements {
+    fn read(reader: &mut Cursor<&[u8]>) -> Result<Self> {
+        let action = match VarInt::read(reader)?.0 {
+            0 => SeenAdvancementsAction::OpenedTab(Identifier::read(reader)?),
+            1 => SeenAdvancementsAction::ClosedScreen,
+            action => {
+                return Err(Error::other(format!(
+                    "invalid seen advancements action {action}"
+                )));
+            }
+        };
+        Ok(Self { action })
+    }
+}
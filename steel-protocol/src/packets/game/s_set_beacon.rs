@@ -0,0 +1,38 @@
+use std::io::{Cursor, Result};
+
+use steel_macros::ServerPacket;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::ReadFrom;
+
+/// Serverbound packet sent when a player confirms the beacon effects.
+///
+/// Equivalent to `ServerboundSetBeaconPacket` in Minecraft.
+#[derive(ServerPacket, Clone, Debug)]
+pub struct SSetBeacon {
+    /// Mob effect registry id of the primary power.
+    pub primary: Option<i32>,
+    /// Mob effect registry id of the secondary power.
+    pub secondary: Option<i32>,
+}
+
+impl ReadFrom for SSetBeacon {
+    fn read(data: &mut Cursor<&[u8]>) -> Result<Self> {
+        let primary = Option::<VarInt>::read(data)?.map(|id| id.0);
+        let secondary = Option::<VarInt>::read(data)?.map(|id| id.0);
+        Ok(Self { primary, secondary })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_optional_effect_ids() {
+        let mut data = Cursor::new([1, 3, 0].as_slice());
+        let packet = SSetBeacon::read(&mut data).expect("packet should parse");
+
+        assert_eq!(packet.primary, Some(3));
+        assert_eq!(packet.secondary, None);
+    }
+}
@@ -0,0 +1,78 @@
+use std::io::{Cursor, Result};
+
+use steel_macros::{ReadFrom, ServerPacket};
+use steel_utils::BlockPos;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::{PrefixedRead, ReadFrom};
+
+/// Vanilla `CommandBlockEntity.Mode`, picked in the command block screen.
+#[derive(ReadFrom, Clone, Copy, Debug, PartialEq, Eq)]
+#[read(as = VarInt)]
+pub enum CommandBlockMode {
+    /// Chain: runs after the command block pointing into it.
+    Sequence = 0,
+    /// Repeat: runs every tick while active.
+    Auto = 1,
+    /// Impulse: runs once per redstone pulse.
+    Redstone = 2,
+}
+
+/// Serverbound packet sent when a player applies the command block screen.
+///
+/// Equivalent to `ServerboundSetCommandBlockPacket` in Minecraft.
+#[derive(ServerPacket, Clone, Debug)]
+pub struct SSetCommandBlock {
+    /// The position of the command block.
+    pub pos: BlockPos,
+    /// The command to run, with or without a leading `/`.
+    pub command: String,
+    /// The selected command block mode.
+    pub mode: CommandBlockMode,
+    /// Whether the block keeps the output of its last command.
+    pub track_output: bool,
+    /// Whether the block only runs if the block behind it succeeded.
+    pub conditional: bool,
+    /// Whether the block runs without redstone power ("Always Active").
+    pub automatic: bool,
+}
+
+impl ReadFrom for SSetCommandBlock {
+    fn read(data: &mut Cursor<&[u8]>) -> Result<Self> {
+        let pos = BlockPos::read(data)?;
+        let command = String::read_prefixed::<VarInt>(data)?;
+        let mode = CommandBlockMode::read(data)?;
+        let flags = u8::read(data)?;
+
+        Ok(Self {
+            pos,
+            command,
+            mode,
+            track_output: flags & 1 != 0,
+            conditional: flags & 2 != 0,
+            automatic: flags & 4 != 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_command_block_flags() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0i64.to_be_bytes());
+        bytes.push(7);
+        bytes.extend_from_slice(b"/say hi");
+        bytes.extend_from_slice(&[1, 0b110]);
+
+        let packet = SSetCommandBlock::read(&mut Cursor::new(bytes.as_slice()))
+            .expect("packet should parse");
+
+        assert_eq!(packet.command, "/say hi");
+        assert_eq!(packet.mode, CommandBlockMode::Auto);
+        assert!(!packet.track_output);
+        assert!(packet.conditional);
+        assert!(packet.automatic);
+    }
+}
@@ -0,0 +1,55 @@
+use std::io::{Cursor, Result};
+
+use steel_macros::ServerPacket;
+use steel_registry::template_pool::JointType;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::{PrefixedRead, ReadFrom};
+use steel_utils::{BlockPos, Identifier};
+
+/// Serverbound packet sent when a player applies the jigsaw block screen.
+///
+/// Equivalent to `ServerboundSetJigsawBlockPacket` in Minecraft.
+#[derive(ServerPacket, Clone, Debug)]
+pub struct SSetJigsawBlock {
+    /// The position of the jigsaw block.
+    pub pos: BlockPos,
+    /// Name of this jigsaw connector.
+    pub name: Identifier,
+    /// Name of the connector this jigsaw attaches to.
+    pub target: Identifier,
+    /// Template pool pieces are drawn from.
+    pub pool: Identifier,
+    /// Block state string the jigsaw turns into after generation.
+    pub final_state: String,
+    /// How attached pieces may rotate around the connection.
+    pub joint: JointType,
+    /// Order in which this jigsaw is chosen as a connection target.
+    pub selection_priority: i32,
+    /// Order in which pieces attached to this jigsaw are processed.
+    pub placement_priority: i32,
+}
+
+impl ReadFrom for SSetJigsawBlock {
+    fn read(data: &mut Cursor<&[u8]>) -> Result<Self> {
+        let pos = BlockPos::read(data)?;
+        let name = Identifier::read(data)?;
+        let target = Identifier::read(data)?;
+        let pool = Identifier::read(data)?;
+        let final_state = String::read_prefixed::<VarInt>(data)?;
+        let joint = JointType::from_name(&String::read_prefixed::<VarInt>(data)?)
+            .unwrap_or(JointType::Aligned);
+        let selection_priority = VarInt::read(data)?.0;
+        let placement_priority = VarInt::read(data)?.0;
+
+        Ok(Self {
+            pos,
+            name,
+            target,
+            pool,
+            final_state,
+            joint,
+            selection_priority,
+            placement_priority,
+        })
+    }
+}
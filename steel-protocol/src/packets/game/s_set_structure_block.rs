@@ -0,0 +1,153 @@
+use std::io::{Cursor, Error, Result};
+
+use steel_macros::{ReadFrom, ServerPacket};
+use steel_registry::blocks::properties::StructureMode;
+use steel_utils::codec::{VarInt, VarLong};
+use steel_utils::serial::{PrefixedRead, ReadFrom};
+use steel_utils::{BlockPos, Mirror, Rotation};
+
+/// Largest structure block offset or size on any axis.
+pub const MAX_STRUCTURE_SIZE: i32 = 48;
+
+/// Maximum length of the data marker string of a structure block.
+pub const MAX_STRUCTURE_DATA_LENGTH: usize = 128;
+
+/// What the player asked the structure block to do after applying the settings.
+#[derive(ReadFrom, Clone, Copy, Debug, PartialEq, Eq)]
+#[read(as = VarInt)]
+pub enum StructureBlockUpdateType {
+    /// Only store the edited settings.
+    UpdateData = 0,
+    /// The "SAVE" button was pressed.
+    SaveArea = 1,
+    /// The "LOAD" button was pressed.
+    LoadArea = 2,
+    /// The "DETECT" button was pressed.
+    ScanArea = 3,
+}
+
+/// Serverbound packet sent when a player applies the structure block screen.
+///
+/// Equivalent to `ServerboundSetStructureBlockPacket` in Minecraft.
+#[derive(ServerPacket, Clone, Debug)]
+pub struct SSetStructureBlock {
+    /// The position of the structure block.
+    pub pos: BlockPos,
+    /// The action requested alongside the new settings.
+    pub update_type: StructureBlockUpdateType,
+    /// The selected structure block mode.
+    pub mode: StructureMode,
+    /// The structure template name.
+    pub name: String,
+    /// Offset of the structure from the block, clamped to [`MAX_STRUCTURE_SIZE`].
+    pub offset: BlockPos,
+    /// Size of the structure, clamped to [`MAX_STRUCTURE_SIZE`].
+    pub size: BlockPos,
+    /// Mirror applied when loading.
+    pub mirror: Mirror,
+    /// Rotation applied when loading.
+    pub rotation: Rotation,
+    /// Data marker for data mode blocks.
+    pub data: String,
+    /// Whether entities are left out when saving or loading.
+    pub ignore_entities: bool,
+    /// Whether loaded block states are placed without shape updates.
+    pub strict: bool,
+    /// Whether air blocks are rendered in the bounding box preview.
+    pub show_air: bool,
+    /// Whether the bounding box preview is rendered.
+    pub show_bounding_box: bool,
+    /// Fraction of blocks kept when loading.
+    pub integrity: f32,
+    /// Seed for the integrity processor, 0 for a random one.
+    pub seed: i64,
+}
+
+fn read_clamped_byte(data: &mut Cursor<&[u8]>, min: i32) -> Result<i32> {
+    Ok(i32::from(i8::read(data)?).clamp(min, MAX_STRUCTURE_SIZE))
+}
+
+fn read_structure_mode(data: &mut Cursor<&[u8]>) -> Result<StructureMode> {
+    match VarInt::read(data)?.0 {
+        0 => Ok(StructureMode::Save),
+        1 => Ok(StructureMode::Load),
+        2 => Ok(StructureMode::Corner),
+        3 => Ok(StructureMode::Data),
+        _ => Err(Error::other("Invalid StructureMode id")),
+    }
+}
+
+impl ReadFrom for SSetStructureBlock {
+    fn read(data: &mut Cursor<&[u8]>) -> Result<Self> {
+        let pos = BlockPos::read(data)?;
+        let update_type = StructureBlockUpdateType::read(data)?;
+        let mode = read_structure_mode(data)?;
+        let name = String::read_prefixed::<VarInt>(data)?;
+        let offset = BlockPos::new(
+            read_clamped_byte(data, -MAX_STRUCTURE_SIZE)?,
+            read_clamped_byte(data, -MAX_STRUCTURE_SIZE)?,
+            read_clamped_byte(data, -MAX_STRUCTURE_SIZE)?,
+        );
+        let size = BlockPos::new(
+            read_clamped_byte(data, 0)?,
+            read_clamped_byte(data, 0)?,
+            read_clamped_byte(data, 0)?,
+        );
+        let mirror = Mirror::read(data)?;
+        let rotation = Rotation::read(data)?;
+        let data_marker = String::read_prefixed_bound::<VarInt>(data, MAX_STRUCTURE_DATA_LENGTH)?;
+        let integrity = f32::read(data)?.clamp(0.0, 1.0);
+        let seed = VarLong::read(data)?.0;
+        let flags = u8::read(data)?;
+
+        Ok(Self {
+            pos,
+            update_type,
+            mode,
+            name,
+            offset,
+            size,
+            mirror,
+            rotation,
+            data: data_marker,
+            ignore_entities: flags & 1 != 0,
+            strict: flags & 8 != 0,
+            show_air: flags & 2 != 0,
+            show_bounding_box: flags & 4 != 0,
+            integrity,
+            seed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_and_clamps_structure_settings() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0i64.to_be_bytes());
+        bytes.extend_from_slice(&[1, 0, 3]);
+        bytes.extend_from_slice(b"foo");
+        bytes.extend_from_slice(&[0x7F, 0, 0xC0_u8, 100, 1, 2, 1, 3, 0]);
+        bytes.extend_from_slice(&2.0f32.to_be_bytes());
+        bytes.extend_from_slice(&[5, 0b0101]);
+
+        let packet = SSetStructureBlock::read(&mut Cursor::new(bytes.as_slice()))
+            .expect("packet should parse");
+
+        assert_eq!(packet.update_type, StructureBlockUpdateType::SaveArea);
+        assert_eq!(packet.mode, StructureMode::Save);
+        assert_eq!(packet.name, "foo");
+        assert_eq!(packet.offset, BlockPos::new(48, 0, -48));
+        assert_eq!(packet.size, BlockPos::new(48, 1, 2));
+        assert_eq!(packet.mirror, Mirror::LeftRight);
+        assert_eq!(packet.rotation, Rotation::CounterClockwise90);
+        assert!(packet.data.is_empty());
+        assert!((packet.integrity - 1.0).abs() < f32::EPSILON);
+        assert_eq!(packet.seed, 5);
+        assert!(packet.ignore_entities && packet.show_bounding_box);
+        assert!(!packet.strict && !packet.show_air);
+    }
+}
@@ -12,7 +12,9 @@ pub enum ClientIntent {
 pub struct SClientIntention {
     #[read(as = VarInt)]
     pub protocol_version: i32,
-    #[read(as = Prefixed(VarInt), bound = 255)]
+    /// Vanilla allows 255 characters; BungeeCord forwarding needs more, so the
+    /// login handler enforces vanilla's bound when forwarding is off.
+    #[read(as = Prefixed(VarInt), bound = 32767)]
     pub hostname: String,
     pub port: u16,
     pub intention: ClientIntent,
@@ -0,0 +1,36 @@
+use std::io::{Result, Write};
+
+use steel_macros::ClientPacket;
+use steel_registry::packets::login::C_CUSTOM_QUERY;
+use steel_utils::Identifier;
+use steel_utils::{codec::VarInt, serial::WriteTo};
+
+/// A login plugin request. The client answers with `SCustomQueryAnswer`
+/// carrying the same transaction id.
+#[derive(ClientPacket, Clone, Debug)]
+#[packet_id(Login = C_CUSTOM_QUERY)]
+pub struct CCustomQuery {
+    pub transaction_id: i32,
+    pub identifier: Identifier,
+    /// Written without a length prefix, up to the end of the packet.
+    pub payload: Box<[u8]>,
+}
+
+impl CCustomQuery {
+    #[must_use]
+    pub const fn new(transaction_id: i32, identifier: Identifier, payload: Box<[u8]>) -> Self {
+        Self {
+            transaction_id,
+            identifier,
+            payload,
+        }
+    }
+}
+
+impl WriteTo for CCustomQuery {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        VarInt(self.transaction_id).write(writer)?;
+        self.identifier.write(writer)?;
+        writer.write_all(&self.payload)
+    }
+}
@@ -1,15 +1,19 @@
+mod c_custom_query;
 mod c_hello;
 mod c_login_compression;
 mod c_login_disconnect;
 mod c_login_finished;
+mod s_custom_query_answer;
 mod s_hello;
 mod s_key;
 mod s_login_acknowledged;
 
+pub use c_custom_query::CCustomQuery;
 pub use c_hello::CHello;
 pub use c_login_compression::CLoginCompression;
 pub use c_login_disconnect::CLoginDisconnect;
 pub use c_login_finished::{CLoginFinished, LoginGameProfile};
+pub use s_custom_query_answer::SCustomQueryAnswer;
 pub use s_hello::SHello;
 pub use s_key::SKey;
 pub use s_login_acknowledged::SLoginAcknowledged;
@@ -0,0 +1,31 @@
+use std::io;
+
+use bytes::Bytes;
+use steel_macros::ServerPacket;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::ReadFromBytes;
+
+use crate::packets::common::Payload;
+
+/// The client's answer to a `CCustomQuery`. `payload` is `None` when the
+/// client does not understand the query's channel.
+#[derive(ServerPacket, Clone, Debug)]
+pub struct SCustomQueryAnswer {
+    pub transaction_id: i32,
+    pub payload: Option<Payload>,
+}
+
+impl ReadFromBytes for SCustomQueryAnswer {
+    fn read_from_bytes(data: &mut Bytes) -> io::Result<Self> {
+        let transaction_id = VarInt::read_from_bytes(data)?.0;
+        let payload = if bool::read_from_bytes(data)? {
+            Some(Payload::read_from_bytes(data)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            transaction_id,
+            payload,
+        })
+    }
+}
@@ -1,6 +1,7 @@
 use serde::Serialize;
 use steel_macros::{ClientPacket, WriteTo};
 use steel_registry::packets::status::C_STATUS_RESPONSE;
+use text_components::TextComponent;
 
 #[derive(Serialize, Clone, Debug)]
 pub struct Sample {
@@ -25,7 +26,7 @@ pub struct Version {
 
 #[derive(Clone, Debug, Serialize)]
 pub struct Status {
-    pub description: String,
+    pub description: TextComponent,
     pub players: Option<Players>,
     pub version: Option<Version>,
     pub favicon: Option<String>,
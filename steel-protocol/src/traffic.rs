@@ -0,0 +1,146 @@
+//! Byte and packet counters for connections.
+//!
+//! Each connection owns a [`TrafficCounters`] fed by its packet reader and
+//! writer. Counters created with a parent also add everything they record to
+//! it, which is how server-wide totals are kept.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+/// Packets and bytes sent and received, counted as they cross the socket.
+pub struct TrafficCounters {
+    started: Instant,
+    parent: Option<Arc<TrafficCounters>>,
+    packets_in: AtomicU64,
+    bytes_in: AtomicU64,
+    data_bytes_in: AtomicU64,
+    packets_out: AtomicU64,
+    bytes_out: AtomicU64,
+    data_bytes_out: AtomicU64,
+}
+
+/// A point-in-time copy of [`TrafficCounters`].
+///
+/// `bytes_*` are framed bytes on the wire, after compression. `data_bytes_*`
+/// are the packet ids and payloads before compression. Bundled packets are
+/// counted as one packet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrafficStats {
+    /// Packets received.
+    pub packets_in: u64,
+    /// Bytes received.
+    pub bytes_in: u64,
+    /// Bytes received, decompressed.
+    pub data_bytes_in: u64,
+    /// Packets sent.
+    pub packets_out: u64,
+    /// Bytes sent.
+    pub bytes_out: u64,
+    /// Bytes sent, before compression.
+    pub data_bytes_out: u64,
+    /// Time since the counters were created.
+    pub elapsed: Duration,
+}
+
+impl TrafficCounters {
+    /// Creates counters that only count for themselves.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_parent_opt(None)
+    }
+
+    /// Creates counters that also add everything to `parent`.
+    #[must_use]
+    pub fn with_parent(parent: Arc<Self>) -> Self {
+        Self::with_parent_opt(Some(parent))
+    }
+
+    fn with_parent_opt(parent: Option<Arc<Self>>) -> Self {
+        Self {
+            started: Instant::now(),
+            parent,
+            packets_in: AtomicU64::new(0),
+            bytes_in: AtomicU64::new(0),
+            data_bytes_in: AtomicU64::new(0),
+            packets_out: AtomicU64::new(0),
+            bytes_out: AtomicU64::new(0),
+            data_bytes_out: AtomicU64::new(0),
+        }
+    }
+
+    /// Records one received packet.
+    pub fn record_in(&self, bytes: usize, data_bytes: usize) {
+        self.packets_in.fetch_add(1, Ordering::Relaxed);
+        self.bytes_in.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.data_bytes_in
+            .fetch_add(data_bytes as u64, Ordering::Relaxed);
+        if let Some(parent) = &self.parent {
+            parent.record_in(bytes, data_bytes);
+        }
+    }
+
+    /// Records one sent packet.
+    pub fn record_out(&self, bytes: usize, data_bytes: usize) {
+        self.packets_out.fetch_add(1, Ordering::Relaxed);
+        self.bytes_out.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.data_bytes_out
+            .fetch_add(data_bytes as u64, Ordering::Relaxed);
+        if let Some(parent) = &self.parent {
+            parent.record_out(bytes, data_bytes);
+        }
+    }
+
+    /// Returns the current counts.
+    #[must_use]
+    pub fn snapshot(&self) -> TrafficStats {
+        TrafficStats {
+            packets_in: self.packets_in.load(Ordering::Relaxed),
+            bytes_in: self.bytes_in.load(Ordering::Relaxed),
+            data_bytes_in: self.data_bytes_in.load(Ordering::Relaxed),
+            packets_out: self.packets_out.load(Ordering::Relaxed),
+            bytes_out: self.bytes_out.load(Ordering::Relaxed),
+            data_bytes_out: self.data_bytes_out.load(Ordering::Relaxed),
+            elapsed: self.started.elapsed(),
+        }
+    }
+}
+
+impl Default for TrafficCounters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn child_counters_add_to_their_parent() {
+        let total = Arc::new(TrafficCounters::new());
+        let first = TrafficCounters::with_parent(Arc::clone(&total));
+        let second = TrafficCounters::with_parent(Arc::clone(&total));
+
+        first.record_in(10, 20);
+        second.record_out(5, 40);
+        second.record_out(7, 7);
+
+        let first = first.snapshot();
+        assert_eq!(
+            (first.packets_in, first.bytes_in, first.data_bytes_in),
+            (1, 10, 20)
+        );
+        assert_eq!(first.packets_out, 0);
+        let total = total.snapshot();
+        assert_eq!((total.packets_in, total.bytes_in), (1, 10));
+        assert_eq!(
+            (total.packets_out, total.bytes_out, total.data_bytes_out),
+            (2, 12, 47)
+        );
+    }
+}
@@ -7,14 +7,11 @@ use std::{
     task::{Context, Poll},
 };
 
-use aes::cipher::{Array, BlockModeDecrypt, BlockModeEncrypt, BlockSizeUser};
+use bytes::Bytes;
 use thiserror::Error;
 use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
 
-/// An AES-128 CFB-8 encryptor.
-pub type Aes128Cfb8Enc = cfb8::Encryptor<aes::Aes128>;
-/// An AES-128 CFB-8 decryptor.
-pub type Aes128Cfb8Dec = cfb8::Decryptor<aes::Aes128>;
+pub use crate::cipher::{Aes128Cfb8Dec, Aes128Cfb8Enc};
 
 /// The maximum size of a packet.
 pub const MAX_PACKET_SIZE: usize = 2_097_152;
@@ -52,8 +49,8 @@ pub enum ConnectionProtocol {
 pub struct RawPacket {
     /// The ID of the packet.
     pub id: i32,
-    /// Could be a `[Box<[u8]>]` but that requires a realloc `if cap != len`
-    pub payload: Vec<u8>,
+    /// The packet body, shared with any byte runs decoded from it.
+    pub payload: Bytes,
 }
 
 /// An error that can occur when handling packets.
@@ -101,6 +98,27 @@ pub enum PacketError {
     #[error("Invalid protocol: {0}")]
     /// The protocol is invalid.
     InvalidProtocol(String),
+    #[error("exceeded {0} packets per second")]
+    /// The connection sent more packets than its rate limit allows.
+    RateLimited(u32),
+    #[error("packet {id} payload of {size} bytes exceeds the {max} byte limit")]
+    /// A packet's payload is larger than its configured limit.
+    PayloadTooLarge {
+        /// The packet id.
+        id: i32,
+        /// The payload size in bytes.
+        size: usize,
+        /// The configured limit in bytes.
+        max: usize,
+    },
+}
+
+impl PacketError {
+    /// Returns whether this error is a connection exceeding its packet limits.
+    #[must_use]
+    pub const fn is_limit_exceeded(&self) -> bool {
+        matches!(self, Self::RateLimited(_) | Self::PayloadTooLarge { .. })
+    }
 }
 
 impl From<io::Error> for PacketError {
@@ -110,89 +128,82 @@ impl From<io::Error> for PacketError {
     }
 }
 
-///NOTE: This makes lots of small writes; make sure there is a buffer somewhere down the line
+/// A stream that encrypts data.
+///
+/// Each write is encrypted as a whole and held until the inner stream accepts
+/// it, since the cipher state has already advanced past those bytes.
 pub struct StreamEncryptor<W: AsyncWrite + Unpin> {
     cipher: Aes128Cfb8Enc,
     write: W,
-    last_unwritten_encrypted_byte: Option<u8>,
+    pending: Vec<u8>,
+    pending_pos: usize,
 }
 
 impl<W: AsyncWrite + Unpin> StreamEncryptor<W> {
     /// Creates a new `StreamEncryptor`.
-    pub fn new(cipher: Aes128Cfb8Enc, stream: W) -> Self {
-        debug_assert_eq!(Aes128Cfb8Enc::block_size(), 1);
+    pub const fn new(cipher: Aes128Cfb8Enc, stream: W) -> Self {
         Self {
             cipher,
             write: stream,
-            last_unwritten_encrypted_byte: None,
+            pending: Vec::new(),
+            pending_pos: 0,
+        }
+    }
+
+    /// Writes out encrypted bytes the inner stream has not accepted yet.
+    fn poll_pending(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.pending_pos < self.pending.len() {
+            let write = Pin::new(&mut self.write);
+            match write.poll_write(cx, &self.pending[self.pending_pos..]) {
+                Poll::Ready(Ok(0)) => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                Poll::Ready(Ok(written)) => self.pending_pos += written,
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                Poll::Pending => return Poll::Pending,
+            }
         }
+        self.pending.clear();
+        self.pending_pos = 0;
+        Poll::Ready(Ok(()))
     }
 }
 
 impl<W: AsyncWrite + Unpin> AsyncWrite for StreamEncryptor<W> {
-    #[expect(
-        clippy::unwrap_used,
-        reason = "CFB8 block size is one byte, so each chunk fits the cipher block type"
-    )]
     fn poll_write(
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
         buf: &[u8],
     ) -> Poll<io::Result<usize>> {
         let ref_self = self.get_mut();
-        let cipher = &mut ref_self.cipher;
-
-        let mut total_written = 0;
-        // Decrypt the raw data, note that our block size is 1 byte, so this is always safe
-        for block in buf.chunks(Aes128Cfb8Enc::block_size()) {
-            let mut out = [0u8];
-
-            if let Some(out_to_use) = ref_self.last_unwritten_encrypted_byte {
-                // This assumes that this `poll_write` is called on the same stream of bytes which I
-                // think is a fair assumption, since thats an invariant for the TCP stream anyway.
-
-                // This should never panic
-                out[0] = out_to_use;
-            } else {
-                // This is a stream cipher, so this value must be used
-                let out_block: &mut Array<u8, _> = (&mut out).into();
-                cipher.encrypt_block_b2b(block.try_into().unwrap(), out_block);
-            }
-
-            let write = Pin::new(&mut ref_self.write);
-            match write.poll_write(cx, &out) {
-                Poll::Pending => {
-                    ref_self.last_unwritten_encrypted_byte = Some(out[0]);
-                    if total_written == 0 {
-                        //If we didn't write anything, return pending
-                        return Poll::Pending;
-                    }
-                    // Otherwise, we actually did write something
-                    return Poll::Ready(Ok(total_written));
-                }
-                Poll::Ready(result) => {
-                    ref_self.last_unwritten_encrypted_byte = None;
-                    match result {
-                        Ok(written) => total_written += written,
-                        Err(err) => return Poll::Ready(Err(err)),
-                    }
-                }
-            }
+        match ref_self.poll_pending(cx) {
+            Poll::Ready(Ok(())) => {}
+            Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+            Poll::Pending => return Poll::Pending,
         }
 
-        Poll::Ready(Ok(total_written))
+        ref_self.pending.extend_from_slice(buf);
+        ref_self.cipher.encrypt(&mut ref_self.pending);
+        // The whole buffer is accepted now; whatever the inner stream does not
+        // take yet goes out on the next write or flush.
+        if let Poll::Ready(Err(err)) = ref_self.poll_pending(cx) {
+            return Poll::Ready(Err(err));
+        }
+        Poll::Ready(Ok(buf.len()))
     }
 
     fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         let ref_self = self.get_mut();
-        let write = Pin::new(&mut ref_self.write);
-        write.poll_flush(cx)
+        match ref_self.poll_pending(cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut ref_self.write).poll_flush(cx),
+            other => other,
+        }
     }
 
     fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         let ref_self = self.get_mut();
-        let write = Pin::new(&mut ref_self.write);
-        write.poll_shutdown(cx)
+        match ref_self.poll_pending(cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut ref_self.write).poll_shutdown(cx),
+            other => other,
+        }
     }
 }
 
@@ -213,10 +224,6 @@ impl<R: AsyncRead + Unpin> StreamDecryptor<R> {
 }
 
 impl<R: AsyncRead + Unpin> AsyncRead for StreamDecryptor<R> {
-    #[expect(
-        clippy::unwrap_used,
-        reason = "CFB8 block size is one byte, so each chunk fits the cipher block type"
-    )]
     fn poll_read(
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
@@ -224,7 +231,6 @@ impl<R: AsyncRead + Unpin> AsyncRead for StreamDecryptor<R> {
     ) -> Poll<io::Result<()>> {
         let ref_self = self.get_mut();
         let read = Pin::new(&mut ref_self.read);
-        let cipher = &mut ref_self.cipher;
 
         // Get the starting position
         let original_fill = buf.filled().len();
@@ -232,10 +238,10 @@ impl<R: AsyncRead + Unpin> AsyncRead for StreamDecryptor<R> {
         let internal_poll = read.poll_read(cx, buf);
 
         if matches!(internal_poll, Poll::Ready(Ok(()))) {
-            // Decrypt the raw data in-place, note that our block size is 1 byte, so this is always safe
-            for block in buf.filled_mut()[original_fill..].chunks_mut(Aes128Cfb8Dec::block_size()) {
-                cipher.decrypt_block(block.try_into().unwrap());
-            }
+            // Decrypt the raw data in-place
+            ref_self
+                .cipher
+                .decrypt(&mut buf.filled_mut()[original_fill..]);
         }
 
         internal_poll
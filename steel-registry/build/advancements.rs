@@ -0,0 +1,532 @@
+#![expect(
+    clippy::unwrap_used,
+    reason = "build script must fail immediately on invalid extracted advancement data"
+)]
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use crate::generator_functions::{generate_identifier, generate_option, generate_text_component};
+use crate::shared_structs::TextComponentJson;
+use heck::ToShoutySnakeCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use steel_utils::Identifier;
+
+#[derive(Deserialize, Debug)]
+struct AdvancementJson {
+    #[serde(default)]
+    parent: Option<Identifier>,
+    #[serde(default)]
+    display: Option<DisplayJson>,
+    criteria: BTreeMap<String, CriterionJson>,
+    #[serde(default)]
+    requirements: Option<Vec<Vec<String>>>,
+    #[serde(default)]
+    rewards: RewardsJson,
+    #[serde(default = "default_true")]
+    sends_telemetry_event: bool,
+}
+
+#[derive(Deserialize, Debug)]
+struct DisplayJson {
+    icon: IconJson,
+    title: TextComponentJson,
+    description: TextComponentJson,
+    #[serde(default)]
+    background: Option<Identifier>,
+    #[serde(default = "default_frame")]
+    frame: String,
+    #[serde(default = "default_true")]
+    show_toast: bool,
+    #[serde(default = "default_true")]
+    announce_to_chat: bool,
+    #[serde(default)]
+    hidden: bool,
+}
+
+#[derive(Deserialize, Debug)]
+struct IconJson {
+    id: Identifier,
+}
+
+#[derive(Deserialize, Debug)]
+struct CriterionJson {
+    trigger: Identifier,
+    #[serde(default)]
+    conditions: Option<Value>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct RewardsJson {
+    #[serde(default)]
+    experience: i32,
+    #[serde(default)]
+    loot: Vec<Identifier>,
+    #[serde(default)]
+    recipes: Vec<Identifier>,
+    #[serde(default)]
+    function: Option<Identifier>,
+}
+
+const fn default_true() -> bool {
+    true
+}
+
+fn default_frame() -> String {
+    "task".to_string()
+}
+
+fn read_advancements(
+    dir: &Path,
+    base_dir: &Path,
+    advancements: &mut Vec<(String, AdvancementJson)>,
+) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            // Recipe unlocks are handled by the inventory scan in the player.
+            if path.file_name().and_then(|s| s.to_str()) != Some("recipes") {
+                read_advancements(&path, base_dir, advancements);
+            }
+        } else if path.extension().and_then(|s| s.to_str()) == Some("json") {
+            let key = path
+                .strip_prefix(base_dir)
+                .unwrap()
+                .with_extension("")
+                .to_str()
+                .unwrap()
+                .replace('\\', "/");
+            let content = fs::read_to_string(&path).unwrap();
+            let advancement: AdvancementJson = serde_json::from_str(&content)
+                .unwrap_or_else(|e| panic!("Failed to parse advancement {key}: {e}"));
+            advancements.push((key, advancement));
+        }
+    }
+}
+
+/// What the loot conditions of a `ContextAwarePredicate` are tested on.
+#[derive(Clone, Copy)]
+enum Subject {
+    Block,
+    Entity,
+}
+
+/// Whether `fields` has no fields besides `allowed`, which are all the server
+/// evaluates.
+fn only_fields(fields: &Map<String, Value>, allowed: &[&str]) -> Option<()> {
+    fields
+        .keys()
+        .all(|field| allowed.contains(&field.as_str()))
+        .then_some(())
+}
+
+fn is_non_empty(value: &Value) -> bool {
+    match value {
+        Value::Array(values) => !values.is_empty(),
+        Value::Object(fields) => !fields.is_empty(),
+        _ => true,
+    }
+}
+
+fn parse_identifier(value: &Value) -> Option<Identifier> {
+    value.as_str()?.parse().ok()
+}
+
+/// Vanilla `MinMaxBounds.Ints`: an exact value or a `min`/`max` object.
+fn compile_int_bounds(value: Option<&Value>) -> Option<TokenStream> {
+    let (min, max) = match value {
+        None => (None, None),
+        Some(Value::Number(exact)) => {
+            let exact = i32::try_from(exact.as_i64()?).ok()?;
+            (Some(exact), Some(exact))
+        }
+        Some(Value::Object(bounds)) => {
+            only_fields(bounds, &["min", "max"])?;
+            let bound = |field: &str| match bounds.get(field) {
+                None => Some(None),
+                Some(value) => i32::try_from(value.as_i64()?).ok().map(Some),
+            };
+            (bound("min")?, bound("max")?)
+        }
+        Some(_) => return None,
+    };
+    let min = generate_option(&min, |min| quote! { #min });
+    let max = generate_option(&max, |max| quote! { #max });
+    Some(quote! { IntBounds { min: #min, max: #max } })
+}
+
+/// A vanilla `HolderSet`: a key, a `#tag` or a list of keys.
+fn compile_holder_set(value: &Value) -> Option<TokenStream> {
+    let keys = match value {
+        Value::String(entry) => {
+            if let Some(tag) = entry.strip_prefix('#') {
+                let tag = generate_identifier(&tag.parse().ok()?);
+                return Some(quote! { HolderSetPredicate::Tag(#tag) });
+            }
+            vec![entry.parse().ok()?]
+        }
+        Value::Array(entries) => entries
+            .iter()
+            .map(parse_identifier)
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+    let keys = keys.iter().map(generate_identifier);
+    Some(quote! { HolderSetPredicate::Keys(&[#(#keys),*]) })
+}
+
+fn compile_optional_holder_set(value: Option<&Value>) -> Option<TokenStream> {
+    Some(match value {
+        Some(value) => {
+            let set = compile_holder_set(value)?;
+            quote! { Some(#set) }
+        }
+        None => quote! { None },
+    })
+}
+
+/// Vanilla `StatePropertiesPredicate`, for exact values only.
+fn compile_state_properties(value: Option<&Value>) -> Option<TokenStream> {
+    let properties = match value {
+        None => Vec::new(),
+        Some(Value::Object(properties)) => properties
+            .iter()
+            .map(|(name, expected)| {
+                let expected = match expected {
+                    Value::String(value) => value.clone(),
+                    Value::Bool(_) | Value::Number(_) => expected.to_string(),
+                    _ => return None,
+                };
+                Some(quote! { (#name, #expected) })
+            })
+            .collect::<Option<Vec<_>>>()?,
+        Some(_) => return None,
+    };
+    Some(quote! { StatePropertiesPredicate(&[#(#properties),*]) })
+}
+
+/// Vanilla `ItemPredicate`, for the `items` and `count` fields.
+fn compile_item_predicate(value: &Value) -> Option<TokenStream> {
+    let predicate = value.as_object()?;
+    only_fields(predicate, &["items", "count"])?;
+    let items = compile_optional_holder_set(predicate.get("items"))?;
+    let count = compile_int_bounds(predicate.get("count"))?;
+    Some(quote! { ItemPredicate { items: #items, count: #count } })
+}
+
+fn compile_item_predicates(value: Option<&Value>) -> Option<TokenStream> {
+    let predicates = match value {
+        None => Vec::new(),
+        Some(value) => value
+            .as_array()?
+            .iter()
+            .map(compile_item_predicate)
+            .collect::<Option<Vec<_>>>()?,
+    };
+    Some(quote! { &[#(#predicates),*] })
+}
+
+/// A vanilla `ContextAwarePredicate`: a list of loot conditions.
+fn compile_loot_conditions(value: Option<&Value>, subject: Subject) -> Option<TokenStream> {
+    let conditions = match value {
+        None => Vec::new(),
+        Some(value) => value
+            .as_array()?
+            .iter()
+            .map(|condition| compile_loot_condition(condition, subject))
+            .collect::<Option<Vec<_>>>()?,
+    };
+    Some(quote! { &[#(#conditions),*] })
+}
+
+fn compile_loot_condition(condition: &Value, subject: Subject) -> Option<TokenStream> {
+    let fields = condition.as_object()?;
+    let kind = fields.get("condition")?.as_str()?;
+    let kind = kind.strip_prefix("minecraft:").unwrap_or(kind);
+    let terms = || {
+        fields
+            .get("terms")?
+            .as_array()?
+            .iter()
+            .map(|term| compile_loot_condition(term, subject))
+            .collect::<Option<Vec<_>>>()
+    };
+    match (kind, subject) {
+        ("all_of", _) => {
+            only_fields(fields, &["condition", "terms"])?;
+            let terms = terms()?;
+            Some(quote! { LootConditionPredicate::AllOf(&[#(#terms),*]) })
+        }
+        ("any_of", _) => {
+            only_fields(fields, &["condition", "terms"])?;
+            let terms = terms()?;
+            Some(quote! { LootConditionPredicate::AnyOf(&[#(#terms),*]) })
+        }
+        ("inverted", _) => {
+            only_fields(fields, &["condition", "term"])?;
+            let term = compile_loot_condition(fields.get("term")?, subject)?;
+            Some(quote! { LootConditionPredicate::Inverted(&#term) })
+        }
+        ("block_state_property", Subject::Block) => {
+            only_fields(fields, &["condition", "block", "properties"])?;
+            let block = generate_identifier(&parse_identifier(fields.get("block")?)?);
+            let properties = compile_state_properties(fields.get("properties"))?;
+            Some(quote! {
+                LootConditionPredicate::BlockStateProperty { block: #block, properties: #properties }
+            })
+        }
+        ("location_check", Subject::Block) => {
+            only_fields(fields, &["condition", "predicate"])?;
+            let predicate = fields.get("predicate")?.as_object()?;
+            only_fields(predicate, &["block"])?;
+            let block = predicate.get("block")?.as_object()?;
+            only_fields(block, &["blocks", "state"])?;
+            let blocks = compile_optional_holder_set(block.get("blocks"))?;
+            let state = compile_state_properties(block.get("state"))?;
+            Some(quote! {
+                LootConditionPredicate::LocationBlock { blocks: #blocks, state: #state }
+            })
+        }
+        ("entity_properties", Subject::Entity) => {
+            only_fields(fields, &["condition", "entity", "predicate"])?;
+            (fields.get("entity")?.as_str()? == "this").then_some(())?;
+            let predicate = fields.get("predicate")?.as_object()?;
+            only_fields(predicate, &["type"])?;
+            let types = compile_optional_holder_set(predicate.get("type"))?;
+            Some(quote! { LootConditionPredicate::EntityType(#types) })
+        }
+        _ => None,
+    }
+}
+
+/// Compiles the conditions of a criterion, or `None` if they use a trigger
+/// or predicate the server does not evaluate.
+fn compile_conditions(trigger: &Identifier, fields: &Map<String, Value>) -> Option<TokenStream> {
+    // Predicates on the player itself are not evaluated.
+    if fields.get("player").is_some_and(is_non_empty) || trigger.namespace != "minecraft" {
+        return None;
+    }
+    match trigger.path.as_ref() {
+        "slept_in_bed" | "avoid_vibration" | "voluntary_exile" | "hero_of_the_village" => {
+            only_fields(fields, &["player"])?;
+            Some(quote! { CriterionConditions::Player })
+        }
+        "inventory_changed" => {
+            only_fields(fields, &["player", "slots", "items"])?;
+            let no_slots = Map::new();
+            let slots = match fields.get("slots") {
+                Some(slots) => slots.as_object()?,
+                None => &no_slots,
+            };
+            only_fields(slots, &["occupied", "full", "empty"])?;
+            let occupied = compile_int_bounds(slots.get("occupied"))?;
+            let full = compile_int_bounds(slots.get("full"))?;
+            let empty = compile_int_bounds(slots.get("empty"))?;
+            let items = compile_item_predicates(fields.get("items"))?;
+            Some(quote! {
+                CriterionConditions::InventoryChanged {
+                    slots: SlotsPredicate { occupied: #occupied, full: #full, empty: #empty },
+                    items: #items,
+                }
+            })
+        }
+        "placed_block" => {
+            only_fields(fields, &["player", "location"])?;
+            let location = compile_loot_conditions(fields.get("location"), Subject::Block)?;
+            Some(quote! { CriterionConditions::PlacedBlock { location: #location } })
+        }
+        "player_killed_entity" => {
+            only_fields(fields, &["player", "entity"])?;
+            let entity = compile_loot_conditions(fields.get("entity"), Subject::Entity)?;
+            Some(quote! { CriterionConditions::PlayerKilledEntity { entity: #entity } })
+        }
+        "consume_item" => {
+            only_fields(fields, &["player", "item"])?;
+            let item = match fields.get("item") {
+                Some(item) => {
+                    let item = compile_item_predicate(item)?;
+                    quote! { Some(#item) }
+                }
+                None => quote! { None },
+            };
+            Some(quote! { CriterionConditions::ConsumeItem { item: #item } })
+        }
+        "recipe_crafted" => {
+            only_fields(fields, &["player", "recipe_id", "ingredients"])?;
+            let recipe_id = generate_identifier(&parse_identifier(fields.get("recipe_id")?)?);
+            let ingredients = compile_item_predicates(fields.get("ingredients"))?;
+            Some(quote! {
+                CriterionConditions::RecipeCrafted { recipe_id: #recipe_id, ingredients: #ingredients }
+            })
+        }
+        "bred_animals" => {
+            only_fields(fields, &["player", "parent", "partner", "child"])?;
+            let parent = compile_loot_conditions(fields.get("parent"), Subject::Entity)?;
+            let partner = compile_loot_conditions(fields.get("partner"), Subject::Entity)?;
+            let child = match fields.get("child") {
+                Some(child) => {
+                    let child = compile_loot_conditions(Some(child), Subject::Entity)?;
+                    quote! { Some(#child) }
+                }
+                None => quote! { None },
+            };
+            Some(quote! {
+                CriterionConditions::BredAnimals { parent: #parent, partner: #partner, child: #child }
+            })
+        }
+        "recipe_unlocked" => {
+            only_fields(fields, &["player", "recipe"])?;
+            let recipe = generate_identifier(&parse_identifier(fields.get("recipe")?)?);
+            Some(quote! { CriterionConditions::RecipeUnlocked { recipe: #recipe } })
+        }
+        "slide_down_block" => {
+            only_fields(fields, &["player", "block", "state"])?;
+            let block = match fields.get("block") {
+                Some(block) => Some(parse_identifier(block)?),
+                None => None,
+            };
+            let block = generate_option(&block, generate_identifier);
+            let state = compile_state_properties(fields.get("state"))?;
+            Some(quote! { CriterionConditions::SlideDownBlock { block: #block, state: #state } })
+        }
+        _ => None,
+    }
+}
+
+fn generate_conditions(trigger: &Identifier, conditions: Option<&Value>) -> TokenStream {
+    let empty = Map::new();
+    let fields = match conditions {
+        None => Some(&empty),
+        Some(conditions) => conditions.as_object(),
+    };
+    fields
+        .and_then(|fields| compile_conditions(trigger, fields))
+        .unwrap_or_else(|| quote! { CriterionConditions::Unsupported })
+}
+
+fn generate_frame(frame: &str) -> TokenStream {
+    match frame {
+        "task" => quote! { AdvancementType::Task },
+        "challenge" => quote! { AdvancementType::Challenge },
+        "goal" => quote! { AdvancementType::Goal },
+        _ => panic!("Unknown advancement frame: {frame}"),
+    }
+}
+
+fn generate_display(display: &DisplayJson) -> TokenStream {
+    let icon = generate_identifier(&display.icon.id);
+    let title = generate_text_component(&display.title);
+    let description = generate_text_component(&display.description);
+    let background = generate_option(&display.background, generate_identifier);
+    let frame = generate_frame(&display.frame);
+    let show_toast = display.show_toast;
+    let announce_to_chat = display.announce_to_chat;
+    let hidden = display.hidden;
+    quote! {
+        AdvancementDisplay {
+            icon: #icon,
+            title: #title,
+            description: #description,
+            background: #background,
+            frame: #frame,
+            show_toast: #show_toast,
+            announce_to_chat: #announce_to_chat,
+            hidden: #hidden,
+        }
+    }
+}
+
+pub(crate) fn build() -> TokenStream {
+    let advancement_dir = "../steel-utils/build_assets/builtin_datapacks/minecraft/advancement";
+    println!("cargo:rerun-if-changed={advancement_dir}");
+    let mut advancements = Vec::new();
+    read_advancements(
+        Path::new(advancement_dir),
+        Path::new(advancement_dir),
+        &mut advancements,
+    );
+    advancements.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut stream = TokenStream::new();
+
+    stream.extend(quote! {
+        use crate::advancement::{
+            Advancement, AdvancementCriterion, AdvancementDisplay, AdvancementRegistry,
+            AdvancementRewards, AdvancementType, CriterionConditions, HolderSetPredicate,
+            IntBounds, ItemPredicate, LootConditionPredicate, SlotsPredicate,
+            StatePropertiesPredicate,
+        };
+        use std::borrow::Cow;
+        use steel_utils::Identifier;
+        use text_components::{TextComponent, translation::TranslatedMessage};
+    });
+
+    let mut register_stream = TokenStream::new();
+    for (key, advancement) in &advancements {
+        let ident = Ident::new(
+            &key.replace('/', "_").to_shouty_snake_case(),
+            Span::call_site(),
+        );
+        let parent = generate_option(&advancement.parent, generate_identifier);
+        let display = generate_option(&advancement.display, generate_display);
+
+        let criteria = advancement.criteria.iter().map(|(name, criterion)| {
+            let trigger = generate_identifier(&criterion.trigger);
+            let conditions = generate_conditions(&criterion.trigger, criterion.conditions.as_ref());
+            quote! {
+                AdvancementCriterion {
+                    name: #name,
+                    trigger: #trigger,
+                    conditions: #conditions,
+                }
+            }
+        });
+
+        // Vanilla `AdvancementRequirements.allOf` when no requirements are given.
+        let requirements = advancement.requirements.clone().unwrap_or_else(|| {
+            advancement
+                .criteria
+                .keys()
+                .map(|name| vec![name.clone()])
+                .collect()
+        });
+        let requirements = requirements.iter().map(|group| quote! { &[#(#group),*] });
+
+        let experience = advancement.rewards.experience;
+        let loot = advancement.rewards.loot.iter().map(generate_identifier);
+        let recipes = advancement.rewards.recipes.iter().map(generate_identifier);
+        let function = generate_option(&advancement.rewards.function, generate_identifier);
+        let sends_telemetry_event = advancement.sends_telemetry_event;
+
+        stream.extend(quote! {
+            pub static #ident: Advancement = Advancement {
+                key: Identifier::vanilla_static(#key),
+                parent: #parent,
+                display: #display,
+                criteria: &[#(#criteria),*],
+                requirements: &[#(#requirements),*],
+                rewards: AdvancementRewards {
+                    experience: #experience,
+                    loot: &[#(#loot),*],
+                    recipes: &[#(#recipes),*],
+                    function: #function,
+                },
+                sends_telemetry_event: #sends_telemetry_event,
+            };
+        });
+
+        register_stream.extend(quote! {
+            registry.register(&#ident);
+        });
+    }
+
+    stream.extend(quote! {
+        pub fn register_advancements(registry: &mut AdvancementRegistry) {
+            #register_stream
+        }
+    });
+
+    stream
+}
@@ -85,10 +85,10 @@ pub struct BiomeJson {
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(from = "BiomeEffectsJson")]
 pub struct BiomeEffects {
-    fog_color: i32,
-    sky_color: i32,
+    fog_color: Option<i32>,
+    sky_color: Option<i32>,
     water_color: i32,
-    water_fog_color: i32,
+    water_fog_color: Option<i32>,
 
     #[serde(default)]
     foliage_color: Option<i32>,
@@ -101,7 +101,7 @@ pub struct BiomeEffects {
     grass_color_modifier: GrassColorModifier,
 
     #[serde(default)]
-    music: Option<Vec<WeightedMusic>>,
+    music: BackgroundMusic,
 
     #[serde(default)]
     ambient_sound: Option<Identifier>,
@@ -111,6 +111,9 @@ pub struct BiomeEffects {
     mood_sound: Option<MoodSound>,
     #[serde(default)]
     particle: Option<Particle>,
+
+    #[serde(default)]
+    other_attributes: serde_json::Map<String, Value>,
 }
 
 #[derive(Deserialize)]
@@ -134,19 +137,20 @@ fn default_water_color() -> String {
 impl From<BiomeEffectsJson> for BiomeEffects {
     fn from(json: BiomeEffectsJson) -> Self {
         BiomeEffects {
-            fog_color: 12638463, // Default value, will be overridden from attributes
-            sky_color: 8103167,  // Default value, will be overridden from attributes
+            fog_color: None, // Will be populated from attributes
+            sky_color: None, // Will be populated from attributes
             water_color: parse_hex_color(&json.water_color),
-            water_fog_color: 329011, // Default value, will be overridden from attributes
+            water_fog_color: None, // Will be populated from attributes
             foliage_color: json.foliage_color.map(|s| parse_hex_color(&s)),
             grass_color: json.grass_color.map(|s| parse_hex_color(&s)),
             dry_foliage_color: json.dry_foliage_color.map(|s| parse_hex_color(&s)),
             grass_color_modifier: json.grass_color_modifier,
-            music: None,           // Will be populated from attributes
-            ambient_sound: None,   // Will be populated from attributes
-            additions_sound: None, // Will be populated from attributes
-            mood_sound: None,      // Will be populated from attributes
-            particle: None,        // Will be populated from attributes
+            music: BackgroundMusic::default(), // Will be populated from attributes
+            ambient_sound: None,               // Will be populated from attributes
+            additions_sound: None,             // Will be populated from attributes
+            mood_sound: None,                  // Will be populated from attributes
+            particle: None,                    // Will be populated from attributes
+            other_attributes: serde_json::Map::new(), // Remaining attributes
         }
     }
 }
@@ -185,14 +189,9 @@ pub enum GrassColorModifier {
     Swamp,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct WeightedMusic {
-    data: Music,
-    weight: i32,
-}
-
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Music {
+    #[serde(default)]
     replace_current_music: bool,
     max_delay: i32,
     min_delay: i32,
@@ -225,25 +224,14 @@ pub struct ParticleOptions {
     particle_type: Identifier,
 }
 
-#[derive(Deserialize, Debug)]
-struct BackgroundMusicEntry {
-    max_delay: i32,
-    min_delay: i32,
-    sound: Identifier,
-}
-
-#[derive(Deserialize, Debug)]
-#[expect(
-    dead_code,
-    reason = "extracted music payload keeps alternate variants not generated yet"
-)]
-struct BackgroundMusic {
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct BackgroundMusic {
     #[serde(default)]
-    default: Option<BackgroundMusicEntry>,
+    default: Option<Music>,
     #[serde(default)]
-    creative: Option<BackgroundMusicEntry>,
+    creative: Option<Music>,
     #[serde(default)]
-    underwater: Option<BackgroundMusicEntry>,
+    underwater: Option<Music>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -262,40 +250,40 @@ struct AmbientParticle {
     probability: f32,
 }
 
+/// Attributes with a typed field in `BiomeEffects`. Everything else is kept
+/// verbatim in `other_attributes`.
+const EXTRACTED_ATTRIBUTES: [&str; 6] = [
+    "minecraft:visual/sky_color",
+    "minecraft:visual/fog_color",
+    "minecraft:visual/water_fog_color",
+    "minecraft:audio/background_music",
+    "minecraft:audio/ambient_sounds",
+    "minecraft:visual/ambient_particles",
+];
+
 fn extract_attributes_to_effects(
     effects: &mut BiomeEffects,
     attributes: &FxHashMap<String, Value>,
 ) {
     // Extract sky_color
     if let Some(Value::String(sky_color)) = attributes.get("minecraft:visual/sky_color") {
-        effects.sky_color = parse_hex_color(sky_color);
+        effects.sky_color = Some(parse_hex_color(sky_color));
     }
 
     // Extract fog_color
     if let Some(Value::String(fog_color)) = attributes.get("minecraft:visual/fog_color") {
-        effects.fog_color = parse_hex_color(fog_color);
+        effects.fog_color = Some(parse_hex_color(fog_color));
     }
 
     // Extract water_fog_color
     if let Some(Value::String(water_fog_color)) = attributes.get("minecraft:visual/water_fog_color")
     {
-        effects.water_fog_color = parse_hex_color(water_fog_color);
+        effects.water_fog_color = Some(parse_hex_color(water_fog_color));
     }
 
     // Extract background_music
-    if let Some(music_value) = attributes.get("minecraft:audio/background_music")
-        && let Ok(music) = serde_json::from_value::<BackgroundMusic>(music_value.clone())
-        && let Some(default) = music.default
-    {
-        effects.music = Some(vec![WeightedMusic {
-            data: Music {
-                replace_current_music: false,
-                max_delay: default.max_delay,
-                min_delay: default.min_delay,
-                sound: default.sound,
-            },
-            weight: 1,
-        }]);
+    if let Some(music_value) = attributes.get("minecraft:audio/background_music") {
+        effects.music = serde_json::from_value(music_value.clone()).unwrap();
     }
 
     // Extract ambient_sounds
@@ -319,6 +307,12 @@ fn extract_attributes_to_effects(
             probability: particle.probability,
         });
     }
+
+    effects.other_attributes = attributes
+        .iter()
+        .filter(|(key, _)| !EXTRACTED_ATTRIBUTES.contains(&key.as_str()))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
 }
 
 fn generate_temperature_modifier(modifier: &TemperatureModifier) -> TokenStream {
@@ -457,32 +451,40 @@ fn generate_music(music: &Music) -> TokenStream {
     }
 }
 
-fn generate_weighted_music(weighted: &WeightedMusic) -> TokenStream {
-    let data = generate_music(&weighted.data);
-    let weight = weighted.weight;
+fn generate_background_music(music: &BackgroundMusic) -> TokenStream {
+    let default = generate_option(&music.default, generate_music);
+    let creative = generate_option(&music.creative, generate_music);
+    let underwater = generate_option(&music.underwater, generate_music);
 
     quote! {
-        WeightedMusic {
-            data: #data,
-            weight: #weight,
+        BackgroundMusic {
+            default: #default,
+            creative: #creative,
+            underwater: #underwater,
         }
     }
 }
 
 fn generate_biome_effects(effects: &BiomeEffects) -> TokenStream {
-    let fog_color = effects.fog_color;
-    let sky_color = effects.sky_color;
+    let fog_color = generate_option(&effects.fog_color, |&v| quote! { #v });
+    let sky_color = generate_option(&effects.sky_color, |&v| quote! { #v });
     let water_color = effects.water_color;
-    let water_fog_color = effects.water_fog_color;
+    let water_fog_color = generate_option(&effects.water_fog_color, |&v| quote! { #v });
     let foliage_color = generate_option(&effects.foliage_color, |&v| quote! { #v });
     let grass_color = generate_option(&effects.grass_color, |&v| quote! { #v });
     let dry_foliage_color = generate_option(&effects.dry_foliage_color, |&v| quote! { #v });
     let grass_color_modifier = generate_grass_color_modifier(&effects.grass_color_modifier);
-    let music = generate_option(&effects.music, |m| generate_vec(m, generate_weighted_music));
+    let music = generate_background_music(&effects.music);
     let ambient_sound = generate_option(&effects.ambient_sound, generate_sound_event_ref);
     let additions_sound = generate_option(&effects.additions_sound, generate_additions_sound);
     let mood_sound = generate_option(&effects.mood_sound, generate_mood_sound);
     let particle = generate_option(&effects.particle, generate_particle);
+    let other_attributes = if effects.other_attributes.is_empty() {
+        quote! { serde_json::Map::new() }
+    } else {
+        let json = serde_json::to_string(&effects.other_attributes).unwrap();
+        quote! { serde_json::from_str(#json).unwrap_or_default() }
+    };
 
     quote! {
         BiomeEffects {
@@ -499,6 +501,7 @@ fn generate_biome_effects(effects: &BiomeEffects) -> TokenStream {
             additions_sound: #additions_sound,
             mood_sound: #mood_sound,
             particle: #particle,
+            other_attributes: #other_attributes,
         }
     }
 }
@@ -531,7 +534,7 @@ pub(crate) fn build() -> TokenStream {
     stream.extend(quote! {
         use crate::biome::{
             Biome, BiomeEffects, BiomeRegistry, TemperatureModifier, GrassColorModifier,
-            SpawnerData, SpawnCost, WeightedMusic, Music, AdditionsSound, MoodSound,
+            SpawnerData, SpawnCost, BackgroundMusic, Music, AdditionsSound, MoodSound,
             Particle, ParticleOptions,
         };
         use steel_utils::Identifier;
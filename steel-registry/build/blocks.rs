@@ -124,6 +124,19 @@ pub struct StateBooleanData {
     pub overwrites: Vec<BooleanOverwrite>,
 }
 
+#[derive(Deserialize, Clone, Debug)]
+pub struct MapColorOverwrite {
+    pub offset: u16,
+    pub value: u8,
+}
+
+/// Vanilla `MapColor` ids per block state.
+#[derive(Deserialize, Clone, Debug)]
+pub struct StateMapColorData {
+    pub default: u8,
+    pub overwrites: Vec<MapColorOverwrite>,
+}
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct Block {
     #[expect(
@@ -144,6 +157,7 @@ pub struct Block {
     pub visual_shapes: ShapeData,
     pub light_properties: LightPropertiesData,
     pub suffocating: StateBooleanData,
+    pub map_color: StateMapColorData,
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -832,6 +846,18 @@ pub(crate) fn build() -> TokenStream {
             })
             .collect::<Vec<_>>();
 
+        let map_color_default = block.map_color.default;
+        let map_color_overwrites = block
+            .map_color
+            .overwrites
+            .iter()
+            .map(|overwrite| {
+                let offset = overwrite.offset;
+                let value = overwrite.value;
+                quote! { StateMapColorOverwrite::new(#offset, MapColor::from_id(#value)) }
+            })
+            .collect::<Vec<_>>();
+
         // Shape function references (now using deduplicated function IDs)
         let collision_fn = Ident::new(
             &format!("shape_fn_{}", info.collision_fn_id),
@@ -906,6 +932,11 @@ pub(crate) fn build() -> TokenStream {
                     #suffocating_default,
                     &[#(#suffocating_overwrites),*],
                 ),
+            ).with_map_color(
+                StateMapColorData::new(
+                    MapColor::from_id(#map_color_default),
+                    &[#(#map_color_overwrites),*],
+                ),
             ) #shape_offsets #default_state;
         });
     }
@@ -926,8 +957,9 @@ pub(crate) fn build() -> TokenStream {
                 behavior::{BlockConfig, OffsetType, PushReaction},
                 shapes::ShapeOffsetFlags,
                 Block, BlockLightProperties, BlockRegistry, StateBooleanData,
-                StateBooleanOverwrite, offset,
+                StateBooleanOverwrite, StateMapColorData, StateMapColorOverwrite, offset,
             },
+            map_color::MapColor,
             blocks::properties::{self, BlockStateProperties, NoteBlockInstrument},
             blocks::shapes::VoxelShape,
         };
@@ -26,6 +26,7 @@
 
 use std::{env, fs, path::Path, process::Command};
 
+mod advancements;
 mod attributes;
 mod banner_patterns;
 mod biome_tags;
@@ -95,6 +96,7 @@ mod enchantments;
 mod banner_pattern_tags;
 mod damage_type_tags;
 mod entity_type_tags;
+mod game_event_tags;
 mod generator_functions;
 mod instrument_tags;
 mod painting_variant_tags;
@@ -154,6 +156,7 @@ const TIMELINES: &str = "timelines";
 const TIMELINE_TAGS: &str = "timeline_tags";
 const ZOMBIE_NAUTILUS_VARIANTS: &str = "zombie_nautilus_variants";
 const RECIPES: &str = "recipes";
+const ADVANCEMENTS: &str = "advancements";
 const VANILLA_ENTITIES: &str = "entities";
 const ENTITY_DATA: &str = "entity_data";
 const FLUIDS: &str = "fluids";
@@ -166,6 +169,7 @@ const LOOT_TABLES: &str = "loot_tables";
 const BLOCK_ENTITY_TYPES: &str = "block_entity_types";
 const GAME_RULES: &str = "game_rules";
 const GAME_EVENTS: &str = "game_events";
+const GAME_EVENT_TAGS: &str = "game_event_tags";
 const LEVEL_EVENTS: &str = "level_events";
 const SOUND_EVENTS: &str = "sound_events";
 const SOUND_TYPES: &str = "sound_types";
@@ -237,6 +241,7 @@ pub fn main() {
         (timeline_tags::build(), TIMELINE_TAGS),
         (zombie_nautilus_variants::build(), ZOMBIE_NAUTILUS_VARIANTS),
         (recipes::build(), RECIPES),
+        (advancements::build(), ADVANCEMENTS),
         (entities::build(), VANILLA_ENTITIES),
         (entity_data::build(), ENTITY_DATA),
         (fluids::build(), FLUIDS),
@@ -245,6 +250,7 @@ pub fn main() {
         (block_entity_types::build(), BLOCK_ENTITY_TYPES),
         (game_rules::build(), GAME_RULES),
         (game_events::build(), GAME_EVENTS),
+        (game_event_tags::build(), GAME_EVENT_TAGS),
         (level_events::build(), LEVEL_EVENTS),
         (sound_events::build(), SOUND_EVENTS),
         (sound_types::build(), SOUND_TYPES),
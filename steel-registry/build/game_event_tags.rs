@@ -0,0 +1,5 @@
+use proc_macro2::TokenStream;
+
+pub(crate) fn build() -> TokenStream {
+    super::tag_utils::build_simple_tags("game_event", "game_events", "GameEventRegistry")
+}
@@ -0,0 +1,813 @@
+//! Vanilla advancements loaded from the builtin datapack.
+//!
+//! Criterion conditions are compiled by the build script into
+//! [`CriterionConditions`] for the criteria triggers the server implements.
+//! Conditions using anything else compile to
+//! [`CriterionConditions::Unsupported`].
+
+use std::io::{Error, Result, Write};
+
+use rustc_hash::FxHashMap;
+use steel_utils::Identifier;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::{PrefixedWrite, WriteTo};
+use text_components::TextComponent;
+
+use crate::blocks::block_state_ext::BlockStateExt as _;
+use crate::entity_type::EntityTypeRef;
+use crate::item_stack::ItemStack;
+use crate::{REGISTRY, RegistryExt, TaggedRegistryExt};
+use steel_utils::BlockStateId;
+
+/// Vanilla `AdvancementType`: the frame drawn around an advancement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvancementType {
+    Task,
+    Challenge,
+    Goal,
+}
+
+impl AdvancementType {
+    /// Returns the vanilla serialized name, also used by `chat.type.advancement.*`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Task => "task",
+            Self::Challenge => "challenge",
+            Self::Goal => "goal",
+        }
+    }
+}
+
+/// Vanilla `DisplayInfo`: how an advancement appears in the advancement screen.
+#[derive(Debug)]
+pub struct AdvancementDisplay {
+    /// Key of the icon item.
+    pub icon: Identifier,
+    pub title: TextComponent,
+    pub description: TextComponent,
+    pub background: Option<Identifier>,
+    pub frame: AdvancementType,
+    pub show_toast: bool,
+    pub announce_to_chat: bool,
+    pub hidden: bool,
+}
+
+/// One named criterion of an advancement.
+#[derive(Debug)]
+pub struct AdvancementCriterion {
+    pub name: &'static str,
+    /// Key of the criteria trigger, e.g. `minecraft:inventory_changed`.
+    pub trigger: Identifier,
+    pub conditions: CriterionConditions,
+}
+
+/// Vanilla `MinMaxBounds.Ints`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntBounds {
+    pub min: Option<i32>,
+    pub max: Option<i32>,
+}
+
+impl IntBounds {
+    pub const ANY: Self = Self {
+        min: None,
+        max: None,
+    };
+
+    #[must_use]
+    pub fn matches(self, value: i32) -> bool {
+        self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+    }
+}
+
+/// A vanilla `HolderSet` in a predicate: a list of keys or a tag.
+#[derive(Debug)]
+pub enum HolderSetPredicate {
+    Keys(&'static [Identifier]),
+    Tag(Identifier),
+}
+
+impl HolderSetPredicate {
+    #[must_use]
+    pub fn matches(&self, key: &Identifier, in_tag: impl FnOnce(&Identifier) -> bool) -> bool {
+        match self {
+            Self::Keys(keys) => keys.contains(key),
+            Self::Tag(tag) => in_tag(tag),
+        }
+    }
+}
+
+/// Vanilla `StatePropertiesPredicate`, for exact values only.
+#[derive(Debug, Clone, Copy)]
+pub struct StatePropertiesPredicate(pub &'static [(&'static str, &'static str)]);
+
+impl StatePropertiesPredicate {
+    #[must_use]
+    pub fn matches(self, state: BlockStateId) -> bool {
+        self.0.iter().all(|(name, expected)| {
+            state
+                .get_property_str(name)
+                .is_some_and(|value| value == *expected)
+        })
+    }
+}
+
+/// Vanilla `ItemPredicate`, for the `items` and `count` fields.
+#[derive(Debug)]
+pub struct ItemPredicate {
+    pub items: Option<HolderSetPredicate>,
+    pub count: IntBounds,
+}
+
+impl ItemPredicate {
+    /// Vanilla `ItemPredicate.test`.
+    #[must_use]
+    pub fn matches(&self, stack: &ItemStack) -> bool {
+        let item = stack.item();
+        self.items
+            .as_ref()
+            .is_none_or(|items| items.matches(&item.key, |tag| REGISTRY.items.is_in_tag(item, tag)))
+            && self.count.matches(stack.count())
+    }
+}
+
+/// Vanilla `InventoryChangeTrigger.TriggerInstance.Slots`.
+#[derive(Debug)]
+pub struct SlotsPredicate {
+    pub occupied: IntBounds,
+    pub full: IntBounds,
+    pub empty: IntBounds,
+}
+
+/// One loot condition of a vanilla `ContextAwarePredicate`.
+#[derive(Debug)]
+pub enum LootConditionPredicate {
+    AllOf(&'static [Self]),
+    AnyOf(&'static [Self]),
+    Inverted(&'static Self),
+    /// `block_state_property`.
+    BlockStateProperty {
+        block: Identifier,
+        properties: StatePropertiesPredicate,
+    },
+    /// `location_check` with only a block predicate.
+    LocationBlock {
+        blocks: Option<HolderSetPredicate>,
+        state: StatePropertiesPredicate,
+    },
+    /// `entity_properties` on `this` with only a type predicate.
+    EntityType(Option<HolderSetPredicate>),
+}
+
+impl LootConditionPredicate {
+    /// Tests the condition on a block, as in a `location` predicate.
+    #[must_use]
+    pub fn matches_block(&self, state: BlockStateId) -> bool {
+        let block = state.get_block();
+        match self {
+            Self::AllOf(terms) => terms.iter().all(|term| term.matches_block(state)),
+            Self::AnyOf(terms) => terms.iter().any(|term| term.matches_block(state)),
+            Self::Inverted(term) => !term.matches_block(state),
+            Self::BlockStateProperty {
+                block: key,
+                properties,
+            } => *key == block.key && properties.matches(state),
+            Self::LocationBlock {
+                blocks,
+                state: properties,
+            } => {
+                blocks.as_ref().is_none_or(|blocks| {
+                    blocks.matches(&block.key, |tag| REGISTRY.blocks.is_in_tag(block, tag))
+                }) && properties.matches(state)
+            }
+            Self::EntityType(_) => false,
+        }
+    }
+
+    /// Tests the condition on an entity, as in an `entity` predicate.
+    #[must_use]
+    pub fn matches_entity(&self, entity_type: EntityTypeRef) -> bool {
+        match self {
+            Self::AllOf(terms) => terms.iter().all(|term| term.matches_entity(entity_type)),
+            Self::AnyOf(terms) => terms.iter().any(|term| term.matches_entity(entity_type)),
+            Self::Inverted(term) => !term.matches_entity(entity_type),
+            Self::EntityType(types) => types.as_ref().is_none_or(|types| {
+                types.matches(&entity_type.key, |tag| {
+                    REGISTRY.entity_types.is_in_tag(entity_type, tag)
+                })
+            }),
+            Self::BlockStateProperty { .. } | Self::LocationBlock { .. } => false,
+        }
+    }
+}
+
+/// The conditions of a criterion, compiled from its datapack JSON.
+///
+/// Each variant holds the vanilla `TriggerInstance` fields of one criteria
+/// trigger. A `ContextAwarePredicate` is a list of loot conditions that must
+/// all hold, so an empty list matches anything.
+#[derive(Debug)]
+pub enum CriterionConditions {
+    /// Uses a trigger or predicate the server does not evaluate. Such a
+    /// criterion is only granted by commands.
+    Unsupported,
+    /// Vanilla `PlayerTrigger`, which has no conditions besides the player.
+    Player,
+    /// Vanilla `InventoryChangeTrigger`.
+    InventoryChanged {
+        slots: SlotsPredicate,
+        items: &'static [ItemPredicate],
+    },
+    /// Vanilla `ItemUsedOnLocationTrigger` for `placed_block`.
+    PlacedBlock {
+        location: &'static [LootConditionPredicate],
+    },
+    /// Vanilla `KilledTrigger` for `player_killed_entity`.
+    PlayerKilledEntity {
+        entity: &'static [LootConditionPredicate],
+    },
+    /// Vanilla `ConsumeItemTrigger`.
+    ConsumeItem { item: Option<ItemPredicate> },
+    /// Vanilla `RecipeCraftedTrigger` for `recipe_crafted`.
+    RecipeCrafted {
+        recipe_id: Identifier,
+        ingredients: &'static [ItemPredicate],
+    },
+    /// Vanilla `BredAnimalsTrigger`.
+    BredAnimals {
+        parent: &'static [LootConditionPredicate],
+        partner: &'static [LootConditionPredicate],
+        child: Option<&'static [LootConditionPredicate]>,
+    },
+    /// Vanilla `RecipeUnlockedTrigger`.
+    RecipeUnlocked { recipe: Identifier },
+    /// Vanilla `SlideDownBlockTrigger`.
+    SlideDownBlock {
+        block: Option<Identifier>,
+        state: StatePropertiesPredicate,
+    },
+}
+
+/// Vanilla `AdvancementRewards`.
+#[derive(Debug)]
+pub struct AdvancementRewards {
+    pub experience: i32,
+    pub loot: &'static [Identifier],
+    pub recipes: &'static [Identifier],
+    pub function: Option<Identifier>,
+}
+
+/// Vanilla `Advancement`.
+#[derive(Debug)]
+pub struct Advancement {
+    pub key: Identifier,
+    pub parent: Option<Identifier>,
+    pub display: Option<AdvancementDisplay>,
+    pub criteria: &'static [AdvancementCriterion],
+    /// Vanilla `AdvancementRequirements`: every group needs one obtained criterion.
+    pub requirements: &'static [&'static [&'static str]],
+    pub rewards: AdvancementRewards,
+    pub sends_telemetry_event: bool,
+}
+
+pub type AdvancementRef = &'static Advancement;
+
+impl Advancement {
+    /// Returns the criterion named `name`.
+    #[must_use]
+    pub fn criterion(&self, name: &str) -> Option<&AdvancementCriterion> {
+        self.criteria
+            .iter()
+            .find(|criterion| criterion.name == name)
+    }
+
+    /// Vanilla `AdvancementRequirements.test`.
+    #[must_use]
+    pub fn requirements_met(&self, is_obtained: impl Fn(&str) -> bool) -> bool {
+        !self.requirements.is_empty()
+            && self
+                .requirements
+                .iter()
+                .all(|group| group.iter().any(|criterion| is_obtained(criterion)))
+    }
+
+    /// Vanilla `AdvancementRequirements.count`: how many groups are fulfilled.
+    #[must_use]
+    pub fn requirements_count(&self, is_obtained: impl Fn(&str) -> bool) -> usize {
+        self.requirements
+            .iter()
+            .filter(|group| group.iter().any(|criterion| is_obtained(criterion)))
+            .count()
+    }
+}
+
+impl WriteTo for Advancement {
+    /// Vanilla `Advancement.STREAM_CODEC`.
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.parent.write(writer)?;
+        match &self.display {
+            Some(display) => {
+                true.write(writer)?;
+                write_display(self, display, writer)?;
+            }
+            None => false.write(writer)?,
+        }
+
+        let groups = i32::try_from(self.requirements.len())
+            .map_err(|_| Error::other("too many advancement requirements"))?;
+        VarInt(groups).write(writer)?;
+        for group in self.requirements {
+            let len = i32::try_from(group.len())
+                .map_err(|_| Error::other("too many advancement requirements"))?;
+            VarInt(len).write(writer)?;
+            for criterion in *group {
+                criterion.write_prefixed::<VarInt>(writer)?;
+            }
+        }
+        self.sends_telemetry_event.write(writer)
+    }
+}
+
+/// Vanilla `DisplayInfo.STREAM_CODEC`.
+fn write_display(
+    advancement: &Advancement,
+    display: &AdvancementDisplay,
+    writer: &mut impl Write,
+) -> Result<()> {
+    const FLAG_BACKGROUND: i32 = 1;
+    const FLAG_SHOW_TOAST: i32 = 2;
+    const FLAG_HIDDEN: i32 = 4;
+
+    display.title.write(writer)?;
+    display.description.write(writer)?;
+    REGISTRY
+        .items
+        .by_key(&display.icon)
+        .map_or_else(ItemStack::empty, ItemStack::new)
+        .write(writer)?;
+    VarInt(display.frame as i32).write(writer)?;
+
+    let mut flags = 0;
+    if display.background.is_some() {
+        flags |= FLAG_BACKGROUND;
+    }
+    if display.show_toast {
+        flags |= FLAG_SHOW_TOAST;
+    }
+    if display.hidden {
+        flags |= FLAG_HIDDEN;
+    }
+    flags.write(writer)?;
+    if let Some(background) = &display.background {
+        background.write(writer)?;
+    }
+
+    let (x, y) = REGISTRY.advancements.position(advancement);
+    x.write(writer)?;
+    y.write(writer)
+}
+
+/// Registry of all advancements, with their tree and screen layout.
+pub struct AdvancementRegistry {
+    advancements_by_id: Vec<AdvancementRef>,
+    advancements_by_key: FxHashMap<Identifier, usize>,
+    /// Children of each advancement, filled in on freeze.
+    children: Vec<Vec<usize>>,
+    /// Vanilla `TreeNodePosition` layout of each displayed advancement.
+    positions: Vec<(f32, f32)>,
+    allows_registering: bool,
+}
+
+impl AdvancementRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            advancements_by_id: Vec::new(),
+            advancements_by_key: FxHashMap::default(),
+            children: Vec::new(),
+            positions: Vec::new(),
+            allows_registering: true,
+        }
+    }
+
+    /// Returns the parent of `advancement`, if it has one.
+    #[must_use]
+    pub fn parent(&self, advancement: &Advancement) -> Option<AdvancementRef> {
+        self.by_key(advancement.parent.as_ref()?)
+    }
+
+    /// Returns the root of the tree `advancement` belongs to.
+    #[must_use]
+    pub fn root(&self, advancement: AdvancementRef) -> AdvancementRef {
+        let mut root = advancement;
+        while let Some(parent) = self.parent(root) {
+            root = parent;
+        }
+        root
+    }
+
+    /// Iterates over the direct children of `advancement`.
+    pub fn children(&self, advancement: &Advancement) -> impl Iterator<Item = AdvancementRef> + '_ {
+        self.advancements_by_key
+            .get(&advancement.key)
+            .and_then(|&id| self.children.get(id))
+            .into_iter()
+            .flatten()
+            .map(|&child| self.advancements_by_id[child])
+    }
+
+    /// Returns the screen position of `advancement`, or the origin if it is not displayed.
+    #[must_use]
+    pub fn position(&self, advancement: &Advancement) -> (f32, f32) {
+        self.advancements_by_key
+            .get(&advancement.key)
+            .and_then(|&id| self.positions.get(id))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Vanilla `AdvancementTree.addAll`: links parents to children, dropping
+    /// the links of advancements whose parent is missing.
+    fn build_tree(&mut self) {
+        self.children = vec![Vec::new(); self.advancements_by_id.len()];
+        for (id, advancement) in self.advancements_by_id.iter().enumerate() {
+            let Some(parent) = &advancement.parent else {
+                continue;
+            };
+            match self.advancements_by_key.get(parent) {
+                Some(&parent) => self.children[parent].push(id),
+                None => log::warn!(
+                    "Advancement {} has missing parent {parent}",
+                    advancement.key
+                ),
+            }
+        }
+
+        self.positions = vec![(0.0, 0.0); self.advancements_by_id.len()];
+        for id in 0..self.advancements_by_id.len() {
+            let advancement = self.advancements_by_id[id];
+            if advancement.parent.is_none() && advancement.display.is_some() {
+                TreeLayout::run(self, id);
+            }
+        }
+    }
+}
+
+crate::impl_standard_methods!(
+    AdvancementRegistry,
+    AdvancementRef,
+    advancements_by_id,
+    advancements_by_key,
+    allows_registering
+);
+
+impl RegistryExt for AdvancementRegistry {
+    type Entry = Advancement;
+
+    fn freeze(&mut self) {
+        if self.allows_registering {
+            self.build_tree();
+        }
+        self.allows_registering = false;
+    }
+
+    fn by_id(&self, id: usize) -> Option<AdvancementRef> {
+        self.advancements_by_id.get(id).copied()
+    }
+
+    fn by_key(&self, key: &Identifier) -> Option<AdvancementRef> {
+        self.advancements_by_key
+            .get(key)
+            .and_then(|&id| self.advancements_by_id.get(id).copied())
+    }
+
+    fn id_from_key(&self, key: &Identifier) -> Option<usize> {
+        self.advancements_by_key.get(key).copied()
+    }
+
+    fn len(&self) -> usize {
+        self.advancements_by_id.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.advancements_by_id.is_empty()
+    }
+}
+
+crate::impl_registry_entry!(Advancement, advancements);
+
+/// One displayed advancement in vanilla's `TreeNodePosition` layout.
+struct LayoutNode {
+    advancement: usize,
+    parent: Option<usize>,
+    previous_sibling: Option<usize>,
+    child_index: usize,
+    children: Vec<usize>,
+    ancestor: usize,
+    thread: Option<usize>,
+    x: i32,
+    y: f32,
+    modifier: f32,
+    change: f32,
+    shift: f32,
+}
+
+/// Vanilla `TreeNodePosition`: a Walker tree layout of one advancement tab.
+///
+/// Advancements without a display are skipped and their children attached
+/// to the closest displayed ancestor.
+struct TreeLayout<'a> {
+    registry: &'a AdvancementRegistry,
+    nodes: Vec<LayoutNode>,
+}
+
+impl TreeLayout<'_> {
+    fn run(registry: &mut AdvancementRegistry, root: usize) {
+        let mut layout = TreeLayout {
+            registry,
+            nodes: Vec::new(),
+        };
+        layout.create(root, None, None, 1, 0);
+        layout.first_walk(0);
+        let min = layout.second_walk(0, 0.0, 0, layout.nodes[0].y);
+        if min < 0.0 {
+            layout.third_walk(0, -min);
+        }
+
+        let positions: Vec<_> = layout
+            .nodes
+            .iter()
+            .map(|node| (node.advancement, node.x, node.y))
+            .collect();
+        for (advancement, x, y) in positions {
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "advancement tree depths are tiny"
+            )]
+            let x = x as f32;
+            registry.positions[advancement] = (x, y);
+        }
+    }
+
+    fn create(
+        &mut self,
+        advancement: usize,
+        parent: Option<usize>,
+        previous_sibling: Option<usize>,
+        child_index: usize,
+        depth: i32,
+    ) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(LayoutNode {
+            advancement,
+            parent,
+            previous_sibling,
+            child_index,
+            children: Vec::new(),
+            ancestor: index,
+            thread: None,
+            x: depth,
+            y: -1.0,
+            modifier: 0.0,
+            change: 0.0,
+            shift: 0.0,
+        });
+
+        let mut previous = None;
+        for &child in &self.registry.children[advancement] {
+            previous = self.add_child(index, child, previous);
+        }
+        index
+    }
+
+    fn add_child(
+        &mut self,
+        parent: usize,
+        advancement: usize,
+        mut previous: Option<usize>,
+    ) -> Option<usize> {
+        if self.registry.advancements_by_id[advancement]
+            .display
+            .is_some()
+        {
+            let child_index = self.nodes[parent].children.len() + 1;
+            let depth = self.nodes[parent].x + 1;
+            let child = self.create(advancement, Some(parent), previous, child_index, depth);
+            self.nodes[parent].children.push(child);
+            return Some(child);
+        }
+        for &grandchild in &self.registry.children[advancement] {
+            previous = self.add_child(parent, grandchild, previous);
+        }
+        previous
+    }
+
+    fn first_walk(&mut self, node: usize) {
+        let children = self.nodes[node].children.clone();
+        let previous_y = self.nodes[node]
+            .previous_sibling
+            .map(|previous| self.nodes[previous].y);
+        let (Some(&first), Some(&last)) = (children.first(), children.last()) else {
+            self.nodes[node].y = previous_y.map_or(0.0, |y| y + 1.0);
+            return;
+        };
+
+        let mut default_ancestor = None;
+        for &child in &children {
+            self.first_walk(child);
+            default_ancestor = Some(self.apportion(child, default_ancestor.unwrap_or(child)));
+        }
+        self.execute_shifts(node);
+
+        let midpoint = (self.nodes[first].y + self.nodes[last].y) / 2.0;
+        let node = &mut self.nodes[node];
+        if let Some(previous_y) = previous_y {
+            node.y = previous_y + 1.0;
+            node.modifier = node.y - midpoint;
+        } else {
+            node.y = midpoint;
+        }
+    }
+
+    fn second_walk(&mut self, node: usize, modifier_sum: f32, depth: i32, mut min: f32) -> f32 {
+        self.nodes[node].y += modifier_sum;
+        self.nodes[node].x = depth;
+        min = min.min(self.nodes[node].y);
+        let modifier = self.nodes[node].modifier;
+        for child in self.nodes[node].children.clone() {
+            min = self.second_walk(child, modifier_sum + modifier, depth + 1, min);
+        }
+        min
+    }
+
+    fn third_walk(&mut self, node: usize, offset: f32) {
+        self.nodes[node].y += offset;
+        for child in self.nodes[node].children.clone() {
+            self.third_walk(child, offset);
+        }
+    }
+
+    fn execute_shifts(&mut self, node: usize) {
+        let mut shift = 0.0;
+        let mut change = 0.0;
+        for child in self.nodes[node].children.clone().into_iter().rev() {
+            let child = &mut self.nodes[child];
+            child.y += shift;
+            child.modifier += shift;
+            change += child.change;
+            shift += child.shift + change;
+        }
+    }
+
+    fn previous_or_thread(&self, node: usize) -> Option<usize> {
+        let node = &self.nodes[node];
+        node.thread.or_else(|| node.children.first().copied())
+    }
+
+    fn next_or_thread(&self, node: usize) -> Option<usize> {
+        let node = &self.nodes[node];
+        node.thread.or_else(|| node.children.last().copied())
+    }
+
+    fn apportion(&mut self, node: usize, mut default_ancestor: usize) -> usize {
+        let (Some(previous), Some(parent)) =
+            (self.nodes[node].previous_sibling, self.nodes[node].parent)
+        else {
+            return default_ancestor;
+        };
+
+        let mut inner_right = node;
+        let mut outer_right = node;
+        let mut inner_left = previous;
+        let mut outer_left = self.nodes[parent].children[0];
+        let mut sum_inner_right = self.nodes[node].modifier;
+        let mut sum_outer_right = self.nodes[node].modifier;
+        let mut sum_inner_left = self.nodes[inner_left].modifier;
+        let mut sum_outer_left = self.nodes[outer_left].modifier;
+
+        while let (Some(next_left), Some(next_right)) = (
+            self.next_or_thread(inner_left),
+            self.previous_or_thread(inner_right),
+        ) {
+            let (Some(next_outer_left), Some(next_outer_right)) = (
+                self.previous_or_thread(outer_left),
+                self.next_or_thread(outer_right),
+            ) else {
+                break;
+            };
+            inner_left = next_left;
+            inner_right = next_right;
+            outer_left = next_outer_left;
+            outer_right = next_outer_right;
+            self.nodes[outer_right].ancestor = node;
+
+            let shift = self.nodes[inner_left].y + sum_inner_left
+                - (self.nodes[inner_right].y + sum_inner_right)
+                + 1.0;
+            if shift > 0.0 {
+                let ancestor = self.ancestor_of(inner_left, node, default_ancestor);
+                self.move_subtree(ancestor, node, shift);
+                sum_inner_right += shift;
+                sum_outer_right += shift;
+            }
+
+            sum_inner_left += self.nodes[inner_left].modifier;
+            sum_inner_right += self.nodes[inner_right].modifier;
+            sum_outer_left += self.nodes[outer_left].modifier;
+            sum_outer_right += self.nodes[outer_right].modifier;
+        }
+
+        if let Some(thread) = self.next_or_thread(inner_left)
+            && self.next_or_thread(outer_right).is_none()
+        {
+            self.nodes[outer_right].thread = Some(thread);
+            self.nodes[outer_right].modifier += sum_inner_left - sum_outer_right;
+        } else {
+            if let Some(thread) = self.previous_or_thread(inner_right)
+                && self.previous_or_thread(outer_left).is_none()
+            {
+                self.nodes[outer_left].thread = Some(thread);
+                self.nodes[outer_left].modifier += sum_inner_right - sum_outer_left;
+            }
+            default_ancestor = node;
+        }
+        default_ancestor
+    }
+
+    #[expect(clippy::cast_precision_loss, reason = "sibling indices are tiny")]
+    fn move_subtree(&mut self, left: usize, right: usize, shift: f32) {
+        let subtrees = self.nodes[right].child_index as f32 - self.nodes[left].child_index as f32;
+        if subtrees != 0.0 {
+            self.nodes[right].change -= shift / subtrees;
+            self.nodes[left].change += shift / subtrees;
+        }
+        let right = &mut self.nodes[right];
+        right.shift += shift;
+        right.y += shift;
+        right.modifier += shift;
+    }
+
+    fn ancestor_of(&self, node: usize, other: usize, default_ancestor: usize) -> usize {
+        let ancestor = self.nodes[node].ancestor;
+        let is_sibling = self.nodes[other]
+            .parent
+            .is_some_and(|parent| self.nodes[parent].children.contains(&ancestor));
+        if is_sibling {
+            ancestor
+        } else {
+            default_ancestor
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::init_test_registry;
+
+    #[test]
+    fn predicates_match_bounds_and_holder_sets() {
+        init_test_registry();
+        let bounds = IntBounds {
+            min: Some(1),
+            max: Some(3),
+        };
+        assert!(bounds.matches(1) && bounds.matches(3));
+        assert!(!bounds.matches(0) && !bounds.matches(4));
+        assert!(IntBounds::ANY.matches(i32::MIN));
+
+        let stack = ItemStack::new(&crate::vanilla_items::OAK_LOG);
+        let logs = ItemPredicate {
+            items: Some(HolderSetPredicate::Tag(Identifier::vanilla_static("logs"))),
+            count: IntBounds::ANY,
+        };
+        assert!(logs.matches(&stack));
+        let two_logs = ItemPredicate {
+            items: None,
+            count: IntBounds {
+                min: Some(2),
+                max: None,
+            },
+        };
+        assert!(!two_logs.matches(&stack));
+    }
+
+    #[test]
+    fn story_tab_is_laid_out_from_the_root() {
+        init_test_registry();
+        let Some(root) = REGISTRY
+            .advancements
+            .by_key(&Identifier::vanilla_static("story/root"))
+        else {
+            panic!("story root should be registered");
+        };
+
+        assert!(REGISTRY.advancements.parent(root).is_none());
+        assert!(REGISTRY.advancements.position(root).0.abs() < f32::EPSILON);
+        for child in REGISTRY.advancements.children(root) {
+            assert!((REGISTRY.advancements.position(child).0 - 1.0).abs() < f32::EPSILON);
+            assert_eq!(REGISTRY.advancements.root(child).key, root.key);
+        }
+    }
+}
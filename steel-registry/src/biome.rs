@@ -1,15 +1,17 @@
 use std::sync::OnceLock;
 
 use rustc_hash::FxHashMap;
+use serde_json::{Map, Value};
 use simdnbt::ToNbtTag;
-use simdnbt::owned::NbtTag;
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
 use steel_utils::Identifier;
 
 use crate::REGISTRY;
 use crate::TaggedRegistryExt;
+use crate::registry_overlay::json_to_nbt;
 use crate::sound_event::SoundEventRef;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Biome {
     pub key: Identifier,
     pub has_precipitation: bool,
@@ -31,26 +33,47 @@ impl Biome {
     pub fn has_tag(&'static self, tag: &Identifier) -> bool {
         REGISTRY.biomes.is_in_tag(self, tag)
     }
+
+    /// Copies `base` under a new key, for defining a custom biome that differs
+    /// from `base` only in the fields changed afterwards.
+    ///
+    /// The copy is not registered; the server's datapack manager syncs it to
+    /// clients as a registry overlay entry.
+    #[must_use]
+    pub fn derive(key: Identifier, base: &Self) -> Self {
+        Self {
+            key,
+            id: OnceLock::new(),
+            ..base.clone()
+        }
+    }
 }
 
-#[derive(Debug)]
+/// Client-side looks of a biome.
+///
+/// Since 1.21.11 most of these are environment attributes; they are synced in
+/// the biome's `attributes` and only the colors vanilla still keeps in
+/// `effects` are synced there.
+#[derive(Debug, Clone, Default)]
 pub struct BiomeEffects {
-    pub fog_color: i32,
-    pub sky_color: i32,
+    pub fog_color: Option<i32>,
+    pub sky_color: Option<i32>,
     pub water_color: i32,
-    pub water_fog_color: i32,
+    pub water_fog_color: Option<i32>,
     pub foliage_color: Option<i32>,
     pub grass_color: Option<i32>,
     pub dry_foliage_color: Option<i32>,
     pub grass_color_modifier: GrassColorModifier,
-    pub music: Option<Vec<WeightedMusic>>,
+    pub music: BackgroundMusic,
     pub ambient_sound: Option<SoundEventRef>,
     pub additions_sound: Option<AdditionsSound>,
     pub mood_sound: Option<MoodSound>,
     pub particle: Option<Particle>,
+    /// Environment attributes not modelled above, synced as they are.
+    pub other_attributes: Map<String, Value>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SpawnerData {
     pub entity_type: Identifier,
     pub weight: i32,
@@ -58,33 +81,43 @@ pub struct SpawnerData {
     pub max_count: i32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SpawnCost {
     pub energy_budget: f64,
     pub charge: f64,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum TemperatureModifier {
     #[default]
     None,
     Frozen,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum GrassColorModifier {
+    #[default]
     None,
     DarkForest,
     Swamp,
 }
 
-#[derive(Debug)]
-pub struct WeightedMusic {
-    pub data: Music,
-    pub weight: i32,
+/// Vanilla `BackgroundMusic`: the music played by game mode and surroundings.
+#[derive(Debug, Clone, Default)]
+pub struct BackgroundMusic {
+    pub default: Option<Music>,
+    pub creative: Option<Music>,
+    pub underwater: Option<Music>,
+}
+
+impl BackgroundMusic {
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.default.is_none() && self.creative.is_none() && self.underwater.is_none()
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Music {
     pub replace_current_music: bool,
     pub max_delay: i32,
@@ -92,13 +125,13 @@ pub struct Music {
     pub sound: SoundEventRef,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AdditionsSound {
     pub sound: SoundEventRef,
     pub tick_chance: f64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MoodSound {
     pub sound: SoundEventRef,
     pub tick_delay: i32,
@@ -106,52 +139,49 @@ pub struct MoodSound {
     pub offset: f64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Particle {
     pub options: ParticleOptions,
     pub probability: f32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ParticleOptions {
     pub particle_type: Identifier,
 }
 
-impl ToNbtTag for &Biome {
-    fn to_nbt_tag(self) -> NbtTag {
-        use simdnbt::owned::{NbtCompound, NbtList};
-        let mut compound = NbtCompound::new();
-        compound.insert("has_precipitation", self.has_precipitation);
-        compound.insert("temperature", self.temperature);
-        compound.insert("downfall", self.downfall);
-        compound.insert(
-            "temperature_modifier",
-            match self.temperature_modifier {
-                TemperatureModifier::None => "none",
-                TemperatureModifier::Frozen => "frozen",
-            },
-        );
-        compound.insert(
-            "creature_spawn_probability",
-            self.creature_spawn_probability,
-        );
+/// Encodes an RGB color the way vanilla writes it, as `#rrggbb`.
+fn color_tag(color: i32) -> NbtTag {
+    NbtTag::String(format!("#{:06x}", color & 0x00FF_FFFF).as_str().into())
+}
 
-        // Effects
+fn music_tag(music: &Music) -> NbtTag {
+    let mut compound = NbtCompound::new();
+    let sound = music.sound.key.to_string();
+    compound.insert("sound", sound.as_str());
+    compound.insert("min_delay", music.min_delay);
+    compound.insert("max_delay", music.max_delay);
+    if music.replace_current_music {
+        compound.insert("replace_current_music", music.replace_current_music);
+    }
+    NbtTag::Compound(compound)
+}
+
+impl BiomeEffects {
+    /// Builds the biome's `effects` compound.
+    fn effects_tag(&self) -> NbtCompound {
         let mut effects = NbtCompound::new();
-        effects.insert("fog_color", self.effects.fog_color);
-        effects.insert("sky_color", self.effects.sky_color);
-        effects.insert("water_color", self.effects.water_color);
-        effects.insert("water_fog_color", self.effects.water_fog_color);
-        if let Some(fc) = self.effects.foliage_color {
-            effects.insert("foliage_color", fc);
+        effects.insert("water_color", color_tag(self.water_color));
+        if let Some(color) = self.foliage_color {
+            effects.insert("foliage_color", color_tag(color));
         }
-        if let Some(gc) = self.effects.grass_color {
-            effects.insert("grass_color", gc);
+        if let Some(color) = self.grass_color {
+            effects.insert("grass_color", color_tag(color));
         }
-        if let Some(dfc) = self.effects.dry_foliage_color {
-            effects.insert("dry_foliage_color", dfc);
+        if let Some(color) = self.dry_foliage_color {
+            effects.insert("dry_foliage_color", color_tag(color));
         }
-        match self.effects.grass_color_modifier {
+        match self.grass_color_modifier {
             GrassColorModifier::None => {}
             GrassColorModifier::DarkForest => {
                 effects.insert("grass_color_modifier", "dark_forest");
@@ -160,54 +190,116 @@ impl ToNbtTag for &Biome {
                 effects.insert("grass_color_modifier", "swamp");
             }
         }
-        if let Some(ambient_sound) = &self.effects.ambient_sound {
-            let s = ambient_sound.key.to_string();
-            effects.insert("ambient_sound", s.as_str());
+        effects
+    }
+
+    /// Builds the biome's `attributes` compound. Modelled fields win over
+    /// entries of [`Self::other_attributes`] with the same id.
+    fn attributes_tag(&self) -> NbtCompound {
+        let mut attributes = NbtCompound::new();
+
+        // Visual attributes
+        if let Some(color) = self.sky_color {
+            attributes.insert("minecraft:visual/sky_color", color_tag(color));
         }
-        if let Some(additions) = &self.effects.additions_sound {
-            let mut a = NbtCompound::new();
-            let s = additions.sound.key.to_string();
-            a.insert("sound", s.as_str());
-            a.insert("tick_chance", additions.tick_chance);
-            effects.insert("additions_sound", NbtTag::Compound(a));
+        if let Some(color) = self.fog_color {
+            attributes.insert("minecraft:visual/fog_color", color_tag(color));
         }
-        if let Some(mood) = &self.effects.mood_sound {
-            let mut m = NbtCompound::new();
-            let s = mood.sound.key.to_string();
-            m.insert("sound", s.as_str());
-            m.insert("tick_delay", mood.tick_delay);
-            m.insert("block_search_extent", mood.block_search_extent);
-            m.insert("offset", mood.offset);
-            effects.insert("mood_sound", NbtTag::Compound(m));
+        if let Some(color) = self.water_fog_color {
+            attributes.insert("minecraft:visual/water_fog_color", color_tag(color));
         }
-        if let Some(particle) = &self.effects.particle {
-            let mut p = NbtCompound::new();
-            let mut opts = NbtCompound::new();
-            let s = particle.options.particle_type.to_string();
-            opts.insert("type", s.as_str());
-            p.insert("options", NbtTag::Compound(opts));
-            p.insert("probability", particle.probability);
-            effects.insert("particle", NbtTag::Compound(p));
+        if let Some(particle) = &self.particle {
+            let mut options = NbtCompound::new();
+            let particle_type = particle.options.particle_type.to_string();
+            options.insert("type", particle_type.as_str());
+            let mut entry = NbtCompound::new();
+            entry.insert("particle", NbtTag::Compound(options));
+            entry.insert("probability", particle.probability);
+            attributes.insert(
+                "minecraft:visual/ambient_particles",
+                NbtTag::List(NbtList::Compound(vec![entry])),
+            );
         }
-        if let Some(music_list) = &self.effects.music {
-            let music_nbt: Vec<NbtCompound> = music_list
-                .iter()
-                .map(|wm| {
-                    let mut wmc = NbtCompound::new();
-                    let mut data = NbtCompound::new();
-                    data.insert("replace_current_music", wm.data.replace_current_music);
-                    data.insert("max_delay", wm.data.max_delay);
-                    data.insert("min_delay", wm.data.min_delay);
-                    let s = wm.data.sound.key.to_string();
-                    data.insert("sound", s.as_str());
-                    wmc.insert("data", NbtTag::Compound(data));
-                    wmc.insert("weight", wm.weight);
-                    wmc
-                })
-                .collect();
-            effects.insert("music", NbtTag::List(NbtList::Compound(music_nbt)));
+
+        // Audio attributes
+        if !self.music.is_empty() {
+            let mut music = NbtCompound::new();
+            for (name, entry) in [
+                ("default", &self.music.default),
+                ("creative", &self.music.creative),
+                ("underwater", &self.music.underwater),
+            ] {
+                if let Some(entry) = entry {
+                    music.insert(name, music_tag(entry));
+                }
+            }
+            attributes.insert("minecraft:audio/background_music", NbtTag::Compound(music));
+        }
+        if self.ambient_sound.is_some()
+            || self.additions_sound.is_some()
+            || self.mood_sound.is_some()
+        {
+            let mut ambient_sounds = NbtCompound::new();
+            if let Some(sound) = self.ambient_sound {
+                let sound = sound.key.to_string();
+                ambient_sounds.insert("loop", sound.as_str());
+            }
+            if let Some(additions) = &self.additions_sound {
+                let mut compound = NbtCompound::new();
+                let sound = additions.sound.key.to_string();
+                compound.insert("sound", sound.as_str());
+                compound.insert("tick_chance", additions.tick_chance);
+                ambient_sounds.insert("additions", NbtTag::Compound(compound));
+            }
+            if let Some(mood) = &self.mood_sound {
+                let mut compound = NbtCompound::new();
+                let sound = mood.sound.key.to_string();
+                compound.insert("sound", sound.as_str());
+                compound.insert("tick_delay", mood.tick_delay);
+                compound.insert("block_search_extent", mood.block_search_extent);
+                compound.insert("offset", mood.offset);
+                ambient_sounds.insert("mood", NbtTag::Compound(compound));
+            }
+            attributes.insert(
+                "minecraft:audio/ambient_sounds",
+                NbtTag::Compound(ambient_sounds),
+            );
+        }
+
+        for (id, value) in &self.other_attributes {
+            if !attributes.contains(id)
+                && let Some(tag) = json_to_nbt(value)
+            {
+                attributes.insert(id.as_str(), tag);
+            }
         }
-        compound.insert("effects", NbtTag::Compound(effects));
+        attributes
+    }
+}
+
+impl ToNbtTag for &Biome {
+    fn to_nbt_tag(self) -> NbtTag {
+        let mut compound = NbtCompound::new();
+        compound.insert("has_precipitation", self.has_precipitation);
+        compound.insert("temperature", self.temperature);
+        compound.insert("downfall", self.downfall);
+        compound.insert(
+            "temperature_modifier",
+            match self.temperature_modifier {
+                TemperatureModifier::None => "none",
+                TemperatureModifier::Frozen => "frozen",
+            },
+        );
+        compound.insert(
+            "creature_spawn_probability",
+            self.creature_spawn_probability,
+        );
+
+        compound.insert(
+            "attributes",
+            NbtTag::Compound(self.effects.attributes_tag()),
+        );
+        compound.insert("effects", NbtTag::Compound(self.effects.effects_tag()));
 
         // Spawners
         let mut spawners_compound = NbtCompound::new();
@@ -318,7 +410,12 @@ impl Default for BiomeRegistry {
 }
 
 crate::impl_registry_ext!(BiomeRegistry, Biome, biomes_by_id, biomes_by_key);
-crate::impl_tagged_registry!(BiomeRegistry, biomes_by_key, "biome");
+crate::impl_tagged_registry!(
+    BiomeRegistry,
+    biomes_by_key,
+    "biome",
+    overlay = crate::BIOMES_REGISTRY
+);
 
 crate::impl_registry_entry_eq!(Biome);
 
@@ -331,3 +428,62 @@ impl crate::RegistryEntry for Biome {
         self.id.get().copied()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn biome_nbt_syncs_effects_as_attributes_and_hex_colors() {
+        let mut other_attributes = Map::new();
+        other_attributes.insert("minecraft:visual/fog_color".to_owned(), json!("#000000"));
+        other_attributes.insert("minecraft:visual/cloud_color".to_owned(), json!("#ffffff"));
+        let biome = Biome {
+            key: Identifier::new_static("test", "custom"),
+            has_precipitation: false,
+            temperature: 0.5,
+            downfall: 0.0,
+            temperature_modifier: TemperatureModifier::None,
+            effects: BiomeEffects {
+                fog_color: Some(0x00C0_D8FF),
+                water_color: 0x003F_76E4,
+                grass_color: Some(0x0012_3456),
+                other_attributes,
+                ..BiomeEffects::default()
+            },
+            creature_spawn_probability: 0.0,
+            spawners: FxHashMap::default(),
+            spawn_costs: FxHashMap::default(),
+            carvers: Vec::new(),
+            features: Vec::new(),
+            id: OnceLock::new(),
+        };
+
+        let NbtTag::Compound(compound) = (&biome).to_nbt_tag() else {
+            panic!("biome NBT should be a compound");
+        };
+        let (Some(NbtTag::Compound(attributes)), Some(NbtTag::Compound(effects))) =
+            (compound.get("attributes"), compound.get("effects"))
+        else {
+            panic!("biome NBT should include attributes and effects");
+        };
+        let string =
+            |compound: &NbtCompound, key: &str| compound.string(key).map(ToString::to_string);
+
+        // The modelled fog color wins over the raw attribute.
+        assert_eq!(
+            string(attributes, "minecraft:visual/fog_color").as_deref(),
+            Some("#c0d8ff")
+        );
+        assert_eq!(
+            string(attributes, "minecraft:visual/cloud_color").as_deref(),
+            Some("#ffffff")
+        );
+        assert!(!attributes.contains("minecraft:visual/sky_color"));
+        assert_eq!(string(effects, "water_color").as_deref(), Some("#3f76e4"));
+        assert_eq!(string(effects, "grass_color").as_deref(), Some("#123456"));
+        assert!(!effects.contains("foliage_color"));
+    }
+}
@@ -6,10 +6,12 @@ use crate::{
         properties::{Direction, Property},
         shapes::{OffsetVoxelShape, SupportType},
     },
+    map_color::MapColor,
 };
 use glam::DVec3;
 use steel_utils::BlockPos;
 use steel_utils::BlockStateId;
+use steel_utils::{Mirror, Rotation};
 
 pub trait BlockStateExt {
     fn get_block(&self) -> BlockRef;
@@ -22,6 +24,12 @@ pub trait BlockStateExt {
     fn set_value<P: Property>(&self, property: &P, value: P::Value) -> BlockStateId;
     fn get_property_str(&self, name: &str) -> Option<String>;
     fn with_properties_of(&self, source: BlockStateId) -> BlockStateId;
+    /// Returns this state rotated around the Y axis, like vanilla `BlockState.rotate`.
+    #[must_use]
+    fn rotate(&self, rotation: Rotation) -> BlockStateId;
+    /// Returns this state mirrored across a template axis, like vanilla `BlockState.mirror`.
+    #[must_use]
+    fn mirror(&self, mirror: Mirror) -> BlockStateId;
     fn get_static_collision_shape(&self) -> blocks::shapes::VoxelShape;
     fn get_collision_shape_at(&self, pos: BlockPos) -> OffsetVoxelShape;
     fn get_static_support_shape(&self) -> blocks::shapes::VoxelShape;
@@ -67,6 +75,8 @@ pub trait BlockStateExt {
     fn is_solid_render(&self) -> bool;
     /// Returns vanilla `BlockState.isSuffocating`.
     fn is_suffocating(&self) -> bool;
+    /// Returns vanilla `BlockState.getMapColor`, the color this state shows on maps.
+    fn get_map_color(&self) -> MapColor;
     /// Returns if a block can be replaced extracted from the minecraft data
     fn is_replaceable(&self) -> bool;
 }
@@ -83,6 +93,12 @@ impl BlockStateExt for BlockStateId {
             .blocks
             .copy_matching_properties(source, self.get_block())
     }
+    fn rotate(&self, rotation: Rotation) -> BlockStateId {
+        REGISTRY.blocks.rotate_state(*self, rotation)
+    }
+    fn mirror(&self, mirror: Mirror) -> BlockStateId {
+        REGISTRY.blocks.mirror_state(*self, mirror)
+    }
     fn is_air(&self) -> bool {
         self.get_block().config.is_air
     }
@@ -228,6 +244,10 @@ impl BlockStateExt for BlockStateId {
         REGISTRY.blocks.is_suffocating(*self)
     }
 
+    fn get_map_color(&self) -> MapColor {
+        REGISTRY.blocks.get_map_color(*self)
+    }
+
     fn is_replaceable(&self) -> bool {
         self.get_block().config.replaceable
     }
@@ -241,6 +261,7 @@ mod tests {
     use crate::blocks::shapes::{ShapeChannel, SupportType};
     use crate::test_support::init_test_registry;
     use steel_utils::Direction;
+    use steel_utils::axis::Axis;
 
     #[test]
     fn solid_render_uses_occlusion_shape_not_collision_shape() {
@@ -317,6 +338,24 @@ mod tests {
         assert!(farmland.is_suffocating());
     }
 
+    #[test]
+    fn map_color_uses_extracted_vanilla_state_colors() {
+        init_test_registry();
+
+        let grass = vanilla_blocks::GRASS_BLOCK.default_state();
+        assert_eq!(grass.get_map_color(), MapColor::GRASS);
+        assert_eq!(
+            vanilla_blocks::AIR.default_state().get_map_color(),
+            MapColor::NONE
+        );
+
+        // Logs show their top color upright and their bark color on their side.
+        let log = vanilla_blocks::SPRUCE_LOG.default_state();
+        assert_eq!(log.get_map_color(), MapColor::PODZOL);
+        let sideways = log.set_value(&BlockStateProperties::AXIS, Axis::X);
+        assert_eq!(sideways.get_map_color(), MapColor::COLOR_BROWN);
+    }
+
     #[test]
     fn vanilla_air_variants_are_air() {
         init_test_registry();
@@ -374,4 +413,60 @@ mod tests {
 
         assert_eq!(target.with_properties_of(source), target);
     }
+
+    #[test]
+    fn rotate_turns_facing_and_swaps_connections() {
+        init_test_registry();
+
+        let stairs = vanilla_blocks::OAK_STAIRS
+            .default_state()
+            .set_value(&BlockStateProperties::HORIZONTAL_FACING, Direction::North);
+        assert_eq!(
+            stairs
+                .rotate(Rotation::Clockwise90)
+                .get_value(&BlockStateProperties::HORIZONTAL_FACING),
+            Direction::East
+        );
+
+        let fence = vanilla_blocks::OAK_FENCE
+            .default_state()
+            .set_value(&BlockStateProperties::NORTH, true);
+        let rotated = fence.rotate(Rotation::Clockwise90);
+        assert!(rotated.get_value(&BlockStateProperties::EAST));
+        assert!(!rotated.get_value(&BlockStateProperties::NORTH));
+
+        let log = vanilla_blocks::OAK_LOG
+            .default_state()
+            .set_value(&BlockStateProperties::AXIS, Axis::X);
+        assert_eq!(
+            log.rotate(Rotation::CounterClockwise90)
+                .get_value(&BlockStateProperties::AXIS),
+            Axis::Z
+        );
+    }
+
+    #[test]
+    fn mirror_flips_facing_and_sign_rotation() {
+        init_test_registry();
+
+        let furnace = vanilla_blocks::FURNACE
+            .default_state()
+            .set_value(&BlockStateProperties::HORIZONTAL_FACING, Direction::East);
+        assert_eq!(
+            furnace
+                .mirror(Mirror::FrontBack)
+                .get_value(&BlockStateProperties::HORIZONTAL_FACING),
+            Direction::West
+        );
+        assert_eq!(furnace.mirror(Mirror::LeftRight), furnace);
+
+        let sign = vanilla_blocks::OAK_SIGN
+            .default_state()
+            .set_value(&BlockStateProperties::ROTATION_16, 4);
+        assert_eq!(
+            sign.mirror(Mirror::FrontBack)
+                .get_value(&BlockStateProperties::ROTATION_16),
+            12
+        );
+    }
 }
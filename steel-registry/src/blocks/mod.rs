@@ -10,6 +10,7 @@ pub mod behavior;
 pub mod block_state_ext;
 pub mod properties;
 pub mod shapes;
+mod transform;
 
 use std::sync::OnceLock;
 
@@ -19,6 +20,7 @@ use rustc_hash::FxHashMap;
 use crate::blocks::behavior::BlockConfig;
 use crate::blocks::properties::Property;
 use crate::blocks::shapes::ShapeChannel;
+use crate::map_color::MapColor;
 use crate::{RegistryExt, TaggedRegistryExt};
 use steel_utils::{BlockPos, BlockStateId};
 
@@ -82,6 +84,45 @@ impl StateBooleanData {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct StateMapColorOverwrite {
+    pub offset: u16,
+    pub color: MapColor,
+}
+
+impl StateMapColorOverwrite {
+    #[must_use]
+    pub const fn new(offset: u16, color: MapColor) -> Self {
+        Self { offset, color }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StateMapColorData {
+    pub default: MapColor,
+    pub overwrites: &'static [StateMapColorOverwrite],
+}
+
+impl StateMapColorData {
+    pub const NONE: Self = Self::new(MapColor::NONE, &[]);
+
+    #[must_use]
+    pub const fn new(default: MapColor, overwrites: &'static [StateMapColorOverwrite]) -> Self {
+        Self {
+            default,
+            overwrites,
+        }
+    }
+
+    #[must_use]
+    pub fn value(self, offset: u16) -> MapColor {
+        self.overwrites
+            .iter()
+            .find(|overwrite| overwrite.offset == offset)
+            .map_or(self.default, |overwrite| overwrite.color)
+    }
+}
+
 pub struct Block {
     pub key: Identifier,
     pub config: BlockConfig,
@@ -89,6 +130,8 @@ pub struct Block {
     pub default_state_offset: u16,
     /// Vanilla `BlockState.isSuffocating` values indexed by block-local state offset.
     pub suffocating: StateBooleanData,
+    /// Vanilla `BlockState.getMapColor` values indexed by block-local state offset.
+    pub map_color: StateMapColorData,
     /// Extracted vanilla light properties indexed by block-local state offset.
     pub light_properties: LightPropertiesFn,
     /// Function to get collision shape for a state offset
@@ -146,6 +189,7 @@ impl Block {
             properties,
             default_state_offset: 0,
             suffocating: StateBooleanData::TRUE,
+            map_color: StateMapColorData::NONE,
             light_properties: opaque_full_block_light_properties,
             collision_shape: full_block_shape,
             support_shape: full_block_shape,
@@ -183,6 +227,12 @@ impl Block {
         self
     }
 
+    /// Sets the extracted vanilla `BlockState.getMapColor` values for this block.
+    pub const fn with_map_color(mut self, map_color: StateMapColorData) -> Self {
+        self.map_color = map_color;
+        self
+    }
+
     /// Sets the extracted vanilla light properties for this block.
     pub const fn with_light_properties(mut self, light_properties: LightPropertiesFn) -> Self {
         self.light_properties = light_properties;
@@ -680,7 +730,12 @@ impl crate::RegistryEntry for Block {
         self.id.get().copied()
     }
 }
-crate::impl_tagged_registry!(BlockRegistry, blocks_by_key, "block");
+crate::impl_tagged_registry!(
+    BlockRegistry,
+    blocks_by_key,
+    "block",
+    overlay = crate::BLOCKS_REGISTRY
+);
 
 // Shape lookup methods
 impl BlockRegistry {
@@ -748,6 +803,15 @@ impl BlockRegistry {
         block.suffocating.value(offset)
     }
 
+    /// Returns vanilla `BlockState.getMapColor`.
+    #[must_use]
+    pub fn get_map_color(&self, state_id: BlockStateId) -> MapColor {
+        let Some((block, offset)) = self.block_and_state_offset(state_id) else {
+            return MapColor::NONE;
+        };
+        block.map_color.value(offset)
+    }
+
     #[must_use]
     pub fn get_collision_shape_at(
         &self,
@@ -0,0 +1,344 @@
+//! Vanilla `BlockState.rotate` / `BlockState.mirror` for registered block states.
+//!
+//! Vanilla implements these per block class. Steel derives them from the state's
+//! property names instead, which covers every directional property vanilla
+//! rotates: `axis`, `facing`, the 16-step `rotation`, rail and stair `shape`,
+//! door `hinge`, and the `north`/`east`/`south`/`west` connection flags.
+
+use steel_utils::{BlockStateId, Direction, Mirror, Rotation};
+
+use super::BlockRegistry;
+
+impl BlockRegistry {
+    /// Returns `state` rotated around the Y axis, matching vanilla `BlockState.rotate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rotated properties do not name a state of the same block.
+    #[must_use]
+    pub fn rotate_state(&self, state: BlockStateId, rotation: Rotation) -> BlockStateId {
+        if rotation == Rotation::None {
+            return state;
+        }
+        self.transform_properties(state, |properties| {
+            Self::rotate_string_properties(properties, rotation);
+        })
+    }
+
+    /// Returns `state` mirrored across a template axis, matching vanilla `BlockState.mirror`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mirrored properties do not name a state of the same block.
+    #[must_use]
+    pub fn mirror_state(&self, state: BlockStateId, mirror: Mirror) -> BlockStateId {
+        if mirror == Mirror::None {
+            return state;
+        }
+        self.transform_properties(state, |properties| {
+            Self::mirror_string_properties(properties, mirror);
+        })
+    }
+
+    fn transform_properties(
+        &self,
+        state: BlockStateId,
+        transform: impl FnOnce(&mut [(String, String)]),
+    ) -> BlockStateId {
+        let Some(block) = self.by_state_id(state) else {
+            return state;
+        };
+        let mut properties = self
+            .get_properties(state)
+            .into_iter()
+            .map(|(name, value)| (name.to_owned(), value.to_owned()))
+            .collect::<Vec<_>>();
+
+        transform(&mut properties);
+        let property_refs = properties
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect::<Vec<_>>();
+        let Some(transformed) = self.state_id_from_block_properties(block, &property_refs) else {
+            panic!(
+                "transforming block state {} produced invalid properties",
+                block.key
+            );
+        };
+        transformed
+    }
+
+    fn rotate_string_properties(properties: &mut [(String, String)], rotation: Rotation) {
+        let original = properties.to_vec();
+        for (name, value) in properties.iter_mut() {
+            match name.as_str() {
+                "axis"
+                    if matches!(
+                        rotation,
+                        Rotation::Clockwise90 | Rotation::CounterClockwise90
+                    ) =>
+                {
+                    match value.as_str() {
+                        "x" => "z".clone_into(value),
+                        "z" => "x".clone_into(value),
+                        _ => {}
+                    }
+                }
+                "facing" => {
+                    if let Some(direction) = Self::parse_direction(value) {
+                        rotation.rotate(direction).as_str().clone_into(value);
+                    }
+                }
+                "rotation" => {
+                    if let Ok(segment) = value.parse::<i32>() {
+                        let rotated = match rotation {
+                            Rotation::None => segment,
+                            Rotation::Clockwise90 => segment + 4,
+                            Rotation::Clockwise180 => segment + 8,
+                            Rotation::CounterClockwise90 => segment + 12,
+                        };
+                        *value = (rotated & 15).to_string();
+                    }
+                }
+                "shape" => {
+                    if let Some(rotated) = Self::rotate_rail_shape(value, rotation) {
+                        rotated.clone_into(value);
+                    }
+                }
+                "north" | "east" | "south" | "west" => {
+                    let from = Self::direction_from_property_name(name);
+                    let source = Self::inverse_rotate_direction(rotation, from);
+                    if let Some(source_name) = Self::property_name_from_direction(source)
+                        && let Some((_, source_value)) = original
+                            .iter()
+                            .find(|(original_name, _)| original_name == source_name)
+                    {
+                        value.clone_from(source_value);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn mirror_string_properties(properties: &mut [(String, String)], mirror: Mirror) {
+        if mirror == Mirror::None {
+            return;
+        }
+
+        let original = properties.to_vec();
+        let facing = original
+            .iter()
+            .find(|(name, _)| name == "facing")
+            .and_then(|(_, value)| Self::parse_direction(value));
+        let stair_shape = original
+            .iter()
+            .find(|(name, _)| name == "shape")
+            .and_then(|(_, value)| Self::parse_stair_shape(value));
+
+        let mirrored_stairs = facing
+            .zip(stair_shape)
+            .and_then(|(direction, shape)| Self::mirror_stair_shape(direction, shape, mirror));
+
+        for (name, value) in properties.iter_mut() {
+            match name.as_str() {
+                "facing" => {
+                    if let Some((mirrored_facing, _)) = mirrored_stairs {
+                        mirrored_facing.as_str().clone_into(value);
+                    } else if let Some(direction) = Self::parse_direction(value) {
+                        mirror.mirror(direction).as_str().clone_into(value);
+                    }
+                }
+                "rotation" => {
+                    if let Ok(segment) = value.parse::<i32>() {
+                        *value = mirror.mirror_rotation(segment, 16).to_string();
+                    }
+                }
+                "hinge" => match value.as_str() {
+                    "left" => "right".clone_into(value),
+                    "right" => "left".clone_into(value),
+                    _ => {}
+                },
+                "shape" => {
+                    if let Some((_, mirrored_shape)) = mirrored_stairs {
+                        mirrored_shape.clone_into(value);
+                    } else if let Some(mirrored_shape) = Self::mirror_rail_shape(value, mirror) {
+                        mirrored_shape.clone_into(value);
+                    }
+                }
+                "north" | "east" | "south" | "west" => {
+                    let from = Self::direction_from_property_name(name);
+                    let source = mirror.mirror(from);
+                    if let Some(source_name) = Self::property_name_from_direction(source)
+                        && let Some((_, source_value)) = original
+                            .iter()
+                            .find(|(original_name, _)| original_name == source_name)
+                    {
+                        value.clone_from(source_value);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_direction(value: &str) -> Option<Direction> {
+        match value {
+            "down" => Some(Direction::Down),
+            "up" => Some(Direction::Up),
+            "north" => Some(Direction::North),
+            "south" => Some(Direction::South),
+            "west" => Some(Direction::West),
+            "east" => Some(Direction::East),
+            _ => None,
+        }
+    }
+
+    fn direction_from_property_name(name: &str) -> Direction {
+        match name {
+            "east" => Direction::East,
+            "south" => Direction::South,
+            "west" => Direction::West,
+            _ => Direction::North,
+        }
+    }
+
+    const fn inverse_rotate_direction(rotation: Rotation, direction: Direction) -> Direction {
+        match rotation {
+            Rotation::None => direction,
+            Rotation::Clockwise90 => Rotation::CounterClockwise90.rotate(direction),
+            Rotation::Clockwise180 => Rotation::Clockwise180.rotate(direction),
+            Rotation::CounterClockwise90 => Rotation::Clockwise90.rotate(direction),
+        }
+    }
+
+    const fn property_name_from_direction(direction: Direction) -> Option<&'static str> {
+        match direction {
+            Direction::North => Some("north"),
+            Direction::East => Some("east"),
+            Direction::South => Some("south"),
+            Direction::West => Some("west"),
+            Direction::Down | Direction::Up => None,
+        }
+    }
+
+    fn rotate_rail_shape(shape: &str, rotation: Rotation) -> Option<&'static str> {
+        match rotation {
+            Rotation::Clockwise180 => match shape {
+                "ascending_east" => Some("ascending_west"),
+                "ascending_west" => Some("ascending_east"),
+                "ascending_north" => Some("ascending_south"),
+                "ascending_south" => Some("ascending_north"),
+                "north_south" => Some("north_south"),
+                "east_west" => Some("east_west"),
+                "south_east" => Some("north_west"),
+                "south_west" => Some("north_east"),
+                "north_west" => Some("south_east"),
+                "north_east" => Some("south_west"),
+                _ => None,
+            },
+            Rotation::CounterClockwise90 => match shape {
+                "ascending_east" => Some("ascending_north"),
+                "ascending_west" => Some("ascending_south"),
+                "ascending_north" => Some("ascending_west"),
+                "ascending_south" => Some("ascending_east"),
+                "north_south" => Some("east_west"),
+                "east_west" => Some("north_south"),
+                "south_east" => Some("north_east"),
+                "south_west" => Some("south_east"),
+                "north_west" => Some("south_west"),
+                "north_east" => Some("north_west"),
+                _ => None,
+            },
+            Rotation::Clockwise90 => match shape {
+                "ascending_east" => Some("ascending_south"),
+                "ascending_west" => Some("ascending_north"),
+                "ascending_north" => Some("ascending_east"),
+                "ascending_south" => Some("ascending_west"),
+                "north_south" => Some("east_west"),
+                "east_west" => Some("north_south"),
+                "south_east" => Some("south_west"),
+                "south_west" => Some("north_west"),
+                "north_west" => Some("north_east"),
+                "north_east" => Some("south_east"),
+                _ => None,
+            },
+            Rotation::None => None,
+        }
+    }
+
+    fn mirror_rail_shape(shape: &str, mirror: Mirror) -> Option<&'static str> {
+        match mirror {
+            Mirror::LeftRight => match shape {
+                "ascending_north" => Some("ascending_south"),
+                "ascending_south" => Some("ascending_north"),
+                "north_south" => Some("north_south"),
+                "east_west" => Some("east_west"),
+                "south_east" => Some("north_east"),
+                "south_west" => Some("north_west"),
+                "north_west" => Some("south_west"),
+                "north_east" => Some("south_east"),
+                _ => None,
+            },
+            Mirror::FrontBack => match shape {
+                "ascending_east" => Some("ascending_west"),
+                "ascending_west" => Some("ascending_east"),
+                "ascending_north" => Some("ascending_north"),
+                "ascending_south" => Some("ascending_south"),
+                "north_south" => Some("north_south"),
+                "east_west" => Some("east_west"),
+                "south_east" => Some("south_west"),
+                "south_west" => Some("south_east"),
+                "north_west" => Some("north_east"),
+                "north_east" => Some("north_west"),
+                _ => None,
+            },
+            Mirror::None => None,
+        }
+    }
+
+    fn parse_stair_shape(shape: &str) -> Option<&'static str> {
+        match shape {
+            "straight" => Some("straight"),
+            "inner_left" => Some("inner_left"),
+            "inner_right" => Some("inner_right"),
+            "outer_left" => Some("outer_left"),
+            "outer_right" => Some("outer_right"),
+            _ => None,
+        }
+    }
+
+    fn mirror_stair_shape(
+        direction: Direction,
+        shape: &str,
+        mirror: Mirror,
+    ) -> Option<(Direction, &'static str)> {
+        match mirror {
+            Mirror::LeftRight if matches!(direction, Direction::North | Direction::South) => {
+                Some((
+                    direction.opposite(),
+                    match shape {
+                        "outer_left" => "outer_right",
+                        "inner_right" => "inner_left",
+                        "inner_left" => "inner_right",
+                        "outer_right" => "outer_left",
+                        "straight" => "straight",
+                        _ => return None,
+                    },
+                ))
+            }
+            Mirror::FrontBack if matches!(direction, Direction::West | Direction::East) => Some((
+                direction.opposite(),
+                match shape {
+                    "outer_left" => "outer_right",
+                    "outer_right" => "outer_left",
+                    "inner_left" => "inner_left",
+                    "inner_right" => "inner_right",
+                    "straight" => "straight",
+                    _ => return None,
+                },
+            )),
+            Mirror::None | Mirror::LeftRight | Mirror::FrontBack => None,
+        }
+    }
+}
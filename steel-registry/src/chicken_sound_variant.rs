@@ -2,7 +2,9 @@ use rustc_hash::FxHashMap;
 use simdnbt::ToNbtTag;
 use simdnbt::owned::NbtTag;
 use steel_utils::Identifier;
+use steel_utils::random::Random;
 
+use crate::RegistryExt;
 use crate::sound_event::SoundEventRef;
 
 /// Represents a set of sounds for a chicken variant from a data pack JSON file.
@@ -63,6 +65,16 @@ impl ChickenSoundVariantRegistry {
             allows_registering: true,
         }
     }
+
+    #[must_use]
+    pub fn pick_random(&self, random: &mut impl Random) -> Option<ChickenSoundVariantRef> {
+        let bound = i32::try_from(self.len()).ok()?;
+        if bound == 0 {
+            return None;
+        }
+
+        self.by_id(random.next_i32_bounded(bound) as usize)
+    }
 }
 
 crate::impl_standard_methods!(
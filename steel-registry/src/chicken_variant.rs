@@ -1,4 +1,9 @@
-use crate::shared_structs::{SpawnConditionEntry, insert_spawn_conditions};
+use steel_utils::random::Random;
+
+use crate::biome::BiomeRef;
+use crate::shared_structs::{
+    SpawnConditionEntry, insert_spawn_conditions, pick_spawn_conditioned_entry,
+};
 use rustc_hash::FxHashMap;
 use simdnbt::ToNbtTag;
 use simdnbt::owned::NbtTag;
@@ -57,6 +62,20 @@ impl ChickenVariantRegistry {
             allows_registering: true,
         }
     }
+
+    #[must_use]
+    pub fn select_spawn_variant(
+        &self,
+        biome: BiomeRef,
+        random: &mut impl Random,
+    ) -> Option<ChickenVariantRef> {
+        pick_spawn_conditioned_entry(
+            self.iter().map(|(_, variant)| variant),
+            |variant| variant.spawn_conditions,
+            biome,
+            random,
+        )
+    }
 }
 
 crate::impl_standard_methods!(
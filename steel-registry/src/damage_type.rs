@@ -114,4 +114,9 @@ crate::impl_registry!(
     damage_types_by_key,
     damage_types
 );
-crate::impl_tagged_registry!(DamageTypeRegistry, damage_types_by_key, "damage type");
+crate::impl_tagged_registry!(
+    DamageTypeRegistry,
+    damage_types_by_key,
+    "damage type",
+    overlay = crate::DAMAGE_TYPE_REGISTRY
+);
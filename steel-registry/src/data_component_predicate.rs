@@ -356,7 +356,7 @@ impl DataComponentExactPredicate {
         &self.values
     }
 
-    fn from_owned_nbt(tag: &NbtTag) -> Option<Self> {
+    pub(crate) fn from_owned_nbt(tag: &NbtTag) -> Option<Self> {
         let compound = tag.compound()?;
         let mut values = Vec::with_capacity(compound.len());
         for (key, value) in compound.iter() {
@@ -370,7 +370,7 @@ impl DataComponentExactPredicate {
         Self::new(values)
     }
 
-    fn to_nbt_value(&self) -> NbtTag {
+    pub(crate) fn to_nbt_value(&self) -> NbtTag {
         let mut compound = NbtCompound::new();
         for (entry, value) in &self.values {
             if !entry.is_persistent() {
@@ -609,7 +609,7 @@ impl HashComponent for DataComponentMatchers {
     }
 }
 
-fn write_registry_id(
+pub(crate) fn write_registry_id(
     entry: &impl RegistryEntry,
     writer: &mut impl Write,
     name: &str,
@@ -13,6 +13,7 @@ use super::Bees;
 use crate::ItemStackTemplate;
 use crate::data_components::registry::ValidatePersistentComponent;
 use crate::data_components::vanilla_components::{BEES, BUNDLE_CONTENTS};
+use crate::item_stack::ItemStack;
 
 macro_rules! impl_template_wrapper_codecs {
     ($type:ty, $field:ident) => {
@@ -176,6 +177,72 @@ impl BundleContents {
         }
         Ok(weight)
     }
+
+    /// Returns how many items of `stack` still fit in the bundle.
+    ///
+    /// Vanilla `BundleContents.Mutable.getMaxAmountToAdd`.
+    #[must_use]
+    pub fn max_amount_to_add(&self, stack: &ItemStack) -> i32 {
+        let (Ok(weight), Ok(item_weight)) = (self.compute_weight(), bundle_stack_weight(stack))
+        else {
+            return 0;
+        };
+        weight.remaining_multiples_of(item_weight)
+    }
+
+    /// Moves as many items of `stack` into the bundle as fit, returning how many moved.
+    ///
+    /// Vanilla `BundleContents.Mutable.tryInsert`: the items go to the front of the
+    /// bundle, merged with an entry of the same item and components.
+    pub fn try_insert(&mut self, stack: &mut ItemStack) -> i32 {
+        if stack.is_empty() || !stack.can_fit_inside_container_items() {
+            return 0;
+        }
+        let count = stack.count().min(self.max_amount_to_add(stack));
+        if count <= 0 {
+            return 0;
+        }
+
+        let existing = self
+            .items
+            .iter()
+            .position(|item| ItemStack::is_same_item_same_components(&item.create(), stack));
+        let merged_count = existing.map_or(count, |index| self.items[index].count() + count);
+        let Ok(merged) = ItemStackTemplate::from_stack(&stack.copy_with_count(merged_count)) else {
+            return 0;
+        };
+
+        if let Some(index) = existing {
+            self.items.remove(index);
+        }
+        self.items.insert(0, merged);
+        stack.shrink(count);
+        count
+    }
+
+    /// Takes the front entry out of the bundle.
+    ///
+    /// Vanilla `BundleContents.Mutable.removeOne`.
+    pub fn remove_one(&mut self) -> Option<ItemStack> {
+        if self.items.is_empty() {
+            return None;
+        }
+        Some(self.items.remove(0).create())
+    }
+}
+
+/// Vanilla `BundleContents.getWeight` for a live stack.
+fn bundle_stack_weight(stack: &ItemStack) -> Result<CheckedFraction> {
+    if let Some(bundle) = stack.get(BUNDLE_CONTENTS) {
+        return bundle.compute_weight()?.add(CheckedFraction::new(1, 16)?);
+    }
+    if stack
+        .get(BEES)
+        .is_some_and(|bees: &Bees| !bees.bees().is_empty())
+    {
+        return Ok(CheckedFraction::ONE);
+    }
+    CheckedFraction::new(1, stack.max_stack_size())
 }
 
 fn bundle_item_weight(item: &ItemStackTemplate) -> Result<CheckedFraction> {
@@ -256,6 +323,17 @@ impl CheckedFraction {
         })
     }
 
+    /// Returns how many whole `item`s fit in `1 - self`, never less than zero.
+    fn remaining_multiples_of(self, item: Self) -> i32 {
+        let remaining = i64::from(self.denominator) - i64::from(self.numerator);
+        if remaining <= 0 || item.numerator <= 0 {
+            return 0;
+        }
+        let multiples = remaining * i64::from(item.denominator)
+            / (i64::from(self.denominator) * i64::from(item.numerator));
+        i32::try_from(multiples).unwrap_or(i32::MAX)
+    }
+
     /// Mirrors multiplying by Commons Lang `Fraction.getFraction(value, 1)`.
     fn multiply(self, value: i32) -> Result<Self> {
         if value < 0 {
@@ -366,6 +444,32 @@ impl ItemContainerContents {
         &self.items
     }
 
+    /// Vanilla `ItemContainerContents.fromItems`: keeps slots up to the last non-empty one.
+    #[must_use]
+    pub fn from_items(stacks: &[ItemStack]) -> Self {
+        let len = stacks
+            .iter()
+            .rposition(|stack| !stack.is_empty())
+            .map_or(0, |last| last + 1)
+            .min(Self::MAX_SIZE);
+        let items = stacks[..len]
+            .iter()
+            .map(|stack| ItemStackTemplate::from_stack(stack).ok())
+            .collect();
+        Self { items }
+    }
+
+    /// Vanilla `ItemContainerContents.copyInto`: fills `stacks`, emptying slots past the contents.
+    pub fn copy_into(&self, stacks: &mut [ItemStack]) {
+        for (slot, stack) in stacks.iter_mut().enumerate() {
+            *stack = self
+                .items
+                .get(slot)
+                .and_then(Option::as_ref)
+                .map_or_else(ItemStack::empty, ItemStackTemplate::create);
+        }
+    }
+
     fn from_slots(slots: Vec<ContainerSlot>) -> Option<Self> {
         if slots.len() > Self::MAX_SIZE {
             return None;
@@ -667,6 +771,7 @@ mod tests {
     use crate::data_components::vanilla_components::{
         BEES, BUNDLE_CONTENTS, CHARGED_PROJECTILES, CONTAINER, MAX_STACK_SIZE, USE_REMAINDER,
     };
+    use crate::item_stack::ItemStack;
     use crate::test_support::init_test_registry;
     use crate::{ItemStackTemplate, REGISTRY, vanilla_entities, vanilla_items};
 
@@ -789,6 +894,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bundle_insert_stops_at_one_full_stack_of_weight() {
+        init_test_registry();
+
+        let mut bundle = BundleContents::empty();
+        let mut pearls = ItemStack::with_count(&vanilla_items::ENDER_PEARL, 10);
+        assert_eq!(bundle.try_insert(&mut pearls), 10);
+        assert!(pearls.is_empty());
+
+        let mut stone = ItemStack::with_count(&vanilla_items::STONE, 64);
+        assert_eq!(bundle.max_amount_to_add(&stone), 24);
+        assert_eq!(bundle.try_insert(&mut stone), 24);
+        assert_eq!(stone.count(), 40);
+        assert_eq!(bundle.items()[0].item().key, vanilla_items::STONE.key);
+
+        let removed = bundle.remove_one().expect("bundle should hold stone");
+        assert_eq!(removed.count(), 24);
+        assert_eq!(bundle.items().len(), 1);
+    }
+
+    #[test]
+    fn bundle_insert_merges_matching_entries() {
+        init_test_registry();
+
+        let mut bundle = BundleContents::empty();
+        let mut first = ItemStack::with_count(&vanilla_items::DIAMOND, 3);
+        let mut second = ItemStack::with_count(&vanilla_items::DIAMOND, 4);
+        bundle.try_insert(&mut first);
+        bundle.try_insert(&mut second);
+
+        assert_eq!(bundle.items().len(), 1);
+        assert_eq!(bundle.items()[0].count(), 7);
+    }
+
     #[test]
     fn bundle_weight_rejects_commons_fraction_denominator_overflow() {
         init_test_registry();
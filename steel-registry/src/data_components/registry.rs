@@ -6,7 +6,7 @@
 //! - [`DataComponentPatch`] - Diff representation for network/storage
 //! - [`DataComponentType`] - Type-safe handle for accessing components
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use simdnbt::{
     FromNbtTag, ToNbtTag,
     borrow::{NbtTag as BorrowedNbtTag, read_tag},
@@ -893,6 +893,37 @@ impl DataComponentPatch {
         });
     }
 
+    /// Re-expresses this patch over `from` as a patch over `to` that yields the
+    /// same effective components.
+    #[must_use]
+    pub fn rebase(&self, from: &DataComponentMap, to: &DataComponentMap) -> Self {
+        let keys: FxHashSet<&Identifier> = from
+            .keys()
+            .chain(to.keys())
+            .chain(self.entries.keys())
+            .collect();
+        let mut rebased = Self::new();
+        for key in keys {
+            let effective = match self.entries.get(key) {
+                Some(ComponentPatchEntry::Set(data)) => Some(data),
+                Some(ComponentPatchEntry::Removed) => None,
+                None => from.get_raw(key),
+            };
+            match (effective, to.get_raw(key)) {
+                (Some(data), prototype) if prototype != Some(data) => {
+                    rebased.set_component_data(key.clone(), data.clone());
+                }
+                (None, Some(_)) => {
+                    rebased
+                        .entries
+                        .insert(key.clone(), ComponentPatchEntry::Removed);
+                }
+                _ => {}
+            }
+        }
+        rebased
+    }
+
     /// Computes Vanilla's `HashOps` value for the persistent patch codec.
     pub fn compute_persistent_hash(&self) -> Result<i32> {
         use crate::{REGISTRY, RegistryExt};
@@ -102,4 +102,9 @@ crate::impl_registry!(
     dialogs_by_key,
     dialogs
 );
-crate::impl_tagged_registry!(DialogRegistry, dialogs_by_key, "dialog");
+crate::impl_tagged_registry!(
+    DialogRegistry,
+    dialogs_by_key,
+    "dialog",
+    overlay = crate::DIALOG_REGISTRY
+);
@@ -1,3 +1,4 @@
+use rand::RngExt as _;
 use rustc_hash::FxHashMap;
 use simdnbt::ToNbtTag;
 use simdnbt::owned::NbtTag;
@@ -6,7 +7,7 @@ use steel_utils::Identifier;
 use crate::sound_event::SoundEventRef;
 use crate::world_clock::WorldClockRef;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BedRule {
     pub can_set_spawn: &'static str,
     pub can_sleep: &'static str,
@@ -14,7 +15,7 @@ pub struct BedRule {
     pub error_message_key: Option<&'static str>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MoodSound {
     pub sound: SoundEventRef,
     pub tick_delay: i32,
@@ -22,7 +23,7 @@ pub struct MoodSound {
     pub offset: f64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MusicEntry {
     pub sound: SoundEventRef,
     pub min_delay: i32,
@@ -30,14 +31,14 @@ pub struct MusicEntry {
     pub replace_current_music: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BackgroundMusic {
     pub default: MusicEntry,
     pub creative: Option<MusicEntry>,
 }
 
 /// Represents a full dimension type definition from a data pack JSON file.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DimensionType {
     pub key: Identifier,
     pub fixed_time: Option<i64>,
@@ -96,7 +97,7 @@ impl DimensionType {
 }
 
 /// Represents the complex structure for monster spawn light level.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum MonsterSpawnLightLevel {
     Simple(i32),
     Complex {
@@ -106,6 +107,20 @@ pub enum MonsterSpawnLightLevel {
     },
 }
 
+impl MonsterSpawnLightLevel {
+    /// Samples vanilla `DimensionType.monsterSpawnLightTest`.
+    pub fn sample(&self, rng: &mut impl rand::Rng) -> i32 {
+        match self {
+            Self::Simple(value) => *value,
+            Self::Complex {
+                min_inclusive,
+                max_inclusive,
+                ..
+            } => rng.random_range(*min_inclusive..=*max_inclusive),
+        }
+    }
+}
+
 impl ToNbtTag for &DimensionType {
     fn to_nbt_tag(self) -> NbtTag {
         use simdnbt::owned::{NbtCompound, NbtTag};
@@ -8,6 +8,8 @@ use steel_utils::codec::VarInt;
 use steel_utils::hash::{ComponentHasher, HashComponent};
 use steel_utils::serial::{ReadFrom, WriteTo};
 
+use crate::map_color::MapColor;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DyeColor {
     White,
@@ -204,6 +206,28 @@ impl DyeColor {
         })
     }
 
+    #[must_use]
+    pub const fn map_color(self) -> MapColor {
+        match self {
+            Self::White => MapColor::SNOW,
+            Self::Orange => MapColor::COLOR_ORANGE,
+            Self::Magenta => MapColor::COLOR_MAGENTA,
+            Self::LightBlue => MapColor::COLOR_LIGHT_BLUE,
+            Self::Yellow => MapColor::COLOR_YELLOW,
+            Self::Lime => MapColor::COLOR_LIGHT_GREEN,
+            Self::Pink => MapColor::COLOR_PINK,
+            Self::Gray => MapColor::COLOR_GRAY,
+            Self::LightGray => MapColor::COLOR_LIGHT_GRAY,
+            Self::Cyan => MapColor::COLOR_CYAN,
+            Self::Purple => MapColor::COLOR_PURPLE,
+            Self::Blue => MapColor::COLOR_BLUE,
+            Self::Brown => MapColor::COLOR_BROWN,
+            Self::Green => MapColor::COLOR_GREEN,
+            Self::Red => MapColor::COLOR_RED,
+            Self::Black => MapColor::COLOR_BLACK,
+        }
+    }
+
     #[must_use]
     pub fn by_firework_color(color: i32) -> Option<Self> {
         Self::VALUES
@@ -15,6 +15,14 @@ pub struct EnchantmentCost {
     pub per_level_above_first: i32,
 }
 
+impl EnchantmentCost {
+    /// Vanilla `Enchantment.Cost.calculate`.
+    #[must_use]
+    pub const fn calculate(self, level: u32) -> i32 {
+        self.base + self.per_level_above_first * (level as i32 - 1)
+    }
+}
+
 #[derive(Debug)]
 pub struct Enchantment {
     pub key: Identifier,
@@ -116,6 +124,32 @@ impl Enchantment {
         REGISTRY.items.is_in_tag(item, &tag)
     }
 
+    /// Vanilla `Enchantment::isPrimaryItem`: enchanting tables only offer primary items.
+    pub fn is_primary_item(&self, item: ItemRef) -> bool {
+        if !self.can_enchant(item) {
+            return false;
+        }
+        let Some(primary_items) = self.primary_items else {
+            return true;
+        };
+        match parse_tag_ref(primary_items) {
+            Some(tag) => REGISTRY.items.is_in_tag(item, &tag),
+            None => item.key.to_string() == primary_items,
+        }
+    }
+
+    /// Vanilla `Enchantment::getMinCost`.
+    #[must_use]
+    pub const fn get_min_cost(&self, level: u32) -> i32 {
+        self.min_cost.calculate(level)
+    }
+
+    /// Vanilla `Enchantment::getMaxCost`.
+    #[must_use]
+    pub const fn get_max_cost(&self, level: u32) -> i32 {
+        self.max_cost.calculate(level)
+    }
+
     /// Checks if two enchantments are compatible (neither's `exclusive_set` contains the other).
     #[must_use]
     pub fn are_compatible(a: EnchantmentRef, b: EnchantmentRef) -> bool {
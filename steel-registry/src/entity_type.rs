@@ -16,6 +16,56 @@ pub enum MobCategory {
 }
 
 impl MobCategory {
+    /// All categories except `Misc`, in vanilla `NaturalSpawner.SPAWNING_CATEGORIES` order.
+    pub const SPAWNING_CATEGORIES: [Self; 7] = [
+        Self::Monster,
+        Self::Creature,
+        Self::Ambient,
+        Self::Axolotls,
+        Self::UndergroundWaterCreature,
+        Self::WaterCreature,
+        Self::WaterAmbient,
+    ];
+
+    /// Returns the vanilla serialized name, as used for biome spawner lists.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Monster => "monster",
+            Self::Creature => "creature",
+            Self::Ambient => "ambient",
+            Self::Axolotls => "axolotls",
+            Self::UndergroundWaterCreature => "underground_water_creature",
+            Self::WaterCreature => "water_creature",
+            Self::WaterAmbient => "water_ambient",
+            Self::Misc => "misc",
+        }
+    }
+
+    /// Returns the mob cap for a single 17x17 chunk spawning area.
+    #[must_use]
+    pub const fn max_instances_per_chunk(self) -> i32 {
+        match self {
+            Self::Monster => 70,
+            Self::Creature => 10,
+            Self::Ambient => 15,
+            Self::Axolotls | Self::UndergroundWaterCreature | Self::WaterCreature => 5,
+            Self::WaterAmbient => 20,
+            Self::Misc => -1,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_friendly(self) -> bool {
+        !matches!(self, Self::Monster)
+    }
+
+    /// Returns whether mobs of this category only spawn on the slower persistent cycle.
+    #[must_use]
+    pub const fn is_persistent(self) -> bool {
+        matches!(self, Self::Creature | Self::Misc)
+    }
+
     #[must_use]
     pub const fn despawn_distance(self) -> i32 {
         match self {
@@ -343,7 +393,12 @@ crate::impl_registry!(
     entity_types
 );
 
-crate::impl_tagged_registry!(EntityTypeRegistry, types_by_key, "entity type");
+crate::impl_tagged_registry!(
+    EntityTypeRegistry,
+    types_by_key,
+    "entity type",
+    overlay = crate::ENTITY_TYPE_REGISTRY
+);
 
 #[cfg(test)]
 mod tests {
@@ -241,7 +241,12 @@ impl FluidRegistry {
 }
 
 crate::impl_registry!(FluidRegistry, Fluid, fluids_by_id, fluids_by_key, fluids);
-crate::impl_tagged_registry!(FluidRegistry, fluids_by_key, "fluid");
+crate::impl_tagged_registry!(
+    FluidRegistry,
+    fluids_by_key,
+    "fluid",
+    overlay = crate::FLUID_REGISTRY
+);
 
 use crate::REGISTRY;
 
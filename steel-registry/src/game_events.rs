@@ -1,28 +1,37 @@
 use rustc_hash::FxHashMap;
 use steel_utils::Identifier;
 
+use crate::{REGISTRY, TaggedRegistryExt};
+
 #[derive(Debug, Clone)]
 pub struct GameEvent {
     pub key: Identifier,
     pub notification_radius: i32,
 }
 
+impl GameEvent {
+    /// Returns `true` if this game event is tagged with the given tag.
+    pub fn has_tag(&'static self, tag: &Identifier) -> bool {
+        REGISTRY.game_events.is_in_tag(self, tag)
+    }
+}
+
 pub type GameEventRef = &'static GameEvent;
 
 pub struct GameEventRegistry {
     game_events_by_id: Vec<GameEventRef>,
     game_events_by_key: FxHashMap<Identifier, usize>,
+    tags: FxHashMap<Identifier, Vec<Identifier>>,
     allows_registering: bool,
 }
 
-// TODO: GameEventListenerRegistry per Chunk Section
-
 impl GameEventRegistry {
     #[must_use]
     pub fn new() -> Self {
         Self {
             game_events_by_id: Vec::new(),
             game_events_by_key: FxHashMap::default(),
+            tags: FxHashMap::default(),
             allows_registering: true,
         }
     }
@@ -43,3 +52,4 @@ crate::impl_registry!(
     game_events_by_key,
     game_events
 );
+crate::impl_tagged_registry!(GameEventRegistry, game_events_by_key, "game event");
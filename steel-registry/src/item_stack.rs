@@ -20,16 +20,17 @@ use crate::{
         vanilla_components::{
             ATTACK_RANGE, ATTRIBUTE_MODIFIERS, AttackRange, BUNDLE_CONTENTS, CHARGED_PROJECTILES,
             CONTAINER, CUSTOM_DATA, DAMAGE, DAMAGE_RESISTANT, DAMAGE_TYPE, ENCHANTABLE,
-            ENCHANTMENTS, EQUIPPABLE, Equippable, ItemAttributeModifiers, ItemEnchantments,
-            MAX_DAMAGE, MAX_STACK_SIZE, MINIMUM_ATTACK_CHARGE, OMINOUS_BOTTLE_AMPLIFIER,
-            OminousBottleAmplifier, PIERCING_WEAPON, PiercingWeapon, REPAIRABLE, TOOL, Tool,
-            UNBREAKABLE, WEAPON, Weapon,
+            ENCHANTMENTS, EQUIPPABLE, Equippable, ItemAttributeModifiers, ItemContainerContents,
+            ItemEnchantments, MAX_DAMAGE, MAX_STACK_SIZE, MINIMUM_ATTACK_CHARGE,
+            OMINOUS_BOTTLE_AMPLIFIER, OminousBottleAmplifier, PIERCING_WEAPON, PiercingWeapon,
+            REPAIRABLE, STORED_ENCHANTMENTS, TOOL, Tool, UNBREAKABLE, WEAPON, Weapon,
         },
     },
     enchantment_effect::EnchantmentEffectComponent,
     equipment::EquipmentSlot,
     item_stack_template::ItemStackTemplate,
     items::ItemRef,
+    vanilla_block_tags::BlockTag,
     vanilla_items,
 };
 
@@ -158,6 +159,12 @@ impl ItemStack {
         }
     }
 
+    /// Vanilla `ItemStack.transmuteCopy`: the same count and components on another item.
+    #[must_use]
+    pub fn transmute_copy(&self, item: ItemRef) -> Self {
+        Self::with_count_and_patch(item, self.count, self.patch.clone())
+    }
+
     /// Returns true if this item can stack (max stack size > 1 and not damaged).
     /// Damaged items cannot stack.
     #[must_use]
@@ -171,6 +178,17 @@ impl ItemStack {
         self.has(MAX_DAMAGE) && !self.has(UNBREAKABLE) && self.has(DAMAGE)
     }
 
+    /// Returns true if this item may be stored inside container items like shulker boxes.
+    ///
+    /// Mirrors vanilla `Item.canFitInsideContainerItems`, which only rejects shulker boxes.
+    #[must_use]
+    pub fn can_fit_inside_container_items(&self) -> bool {
+        REGISTRY
+            .blocks
+            .by_key(&self.item().key)
+            .is_none_or(|block| !block.has_tag(&BlockTag::SHULKER_BOXES))
+    }
+
     /// Returns true if this item has taken damage.
     #[must_use]
     pub fn is_damaged(&self) -> bool {
@@ -662,15 +680,25 @@ impl ItemStack {
     }
 
     /// Copies components from a source (block entity, attacker, etc.) to this item.
-    pub const fn copy_components<R: rand::Rng>(
+    pub fn copy_components<R: rand::Rng>(
         &mut self,
-        _source: crate::loot_table::CopySource,
-        _include: &[Identifier],
-        _ctx: &crate::loot_table::LootContext<'_, R>,
+        source: crate::loot_table::CopySource,
+        include: &[Identifier],
+        ctx: &crate::loot_table::LootContext<'_, R>,
     ) {
-        // TODO: Implement when block entity system is ready
-        // 1. Get the source entity/block entity from context
-        // 2. For each component in `include`, copy it to this item's patch
+        // TODO: Copy from entity sources and the remaining block entity components
+        let crate::loot_table::CopySource::BlockEntity = source else {
+            return;
+        };
+        let Some(block_entity) = &ctx.block_entity else {
+            return;
+        };
+
+        if let Some(inventory) = block_entity.inventory
+            && include.contains(&CONTAINER.key)
+        {
+            self.set(CONTAINER, ItemContainerContents::from_items(inventory));
+        }
     }
 
     /// Copies block state properties to this item (for blocks like `note_block`).
@@ -777,13 +805,20 @@ impl ItemStack {
     }
 
     /// Vanilla `ItemStack.enchant` → `Mutable.upgrade`: keeps the higher of existing vs new level.
+    ///
+    /// Enchanted books store the enchantment instead, like `EnchantmentHelper.getComponentType`.
     pub fn upgrade_enchantment(&mut self, enchantment: Identifier, level: u32) {
+        let component = if self.is(&vanilla_items::ENCHANTED_BOOK) {
+            STORED_ENCHANTMENTS
+        } else {
+            ENCHANTMENTS
+        };
         let mut current = self
-            .get(ENCHANTMENTS)
+            .get(component.clone())
             .cloned()
             .unwrap_or_else(ItemEnchantments::empty);
         current.upgrade(enchantment, level);
-        self.set(ENCHANTMENTS, current);
+        self.set(component, current);
     }
 
     /// Changes the item type entirely.
@@ -1020,10 +1055,17 @@ impl WriteTo for ItemStack {
             VarInt(0).write(writer)?;
         } else {
             VarInt(self.count).write(writer)?;
-            // Write item ID as VarInt
-            VarInt(self.item.id() as i32).write(writer)?;
-            // Write DataComponentPatch
-            self.patch.write(writer)?;
+            // Custom items are sent as the vanilla item clients know.
+            match self.item.client_view(&self.patch) {
+                Some((client_item, client_patch)) => {
+                    VarInt(client_item.id() as i32).write(writer)?;
+                    client_patch.write(writer)?;
+                }
+                None => {
+                    VarInt(self.item.id() as i32).write(writer)?;
+                    self.patch.write(writer)?;
+                }
+            }
         }
         Ok(())
     }
@@ -1046,6 +1088,7 @@ impl ReadFrom for ItemStack {
 
         // Read DataComponentPatch
         let patch = DataComponentPatch::read(data)?;
+        let (item, patch) = REGISTRY.items.resolve_client_stack(item, patch);
 
         Ok(Self::with_count_and_patch(item, count, patch))
     }
@@ -1070,6 +1113,7 @@ impl ItemStack {
             .by_id(item_id)
             .ok_or_else(|| std::io::Error::other(format!("Unknown item id: {item_id}")))?;
         let patch = DataComponentPatch::read_delimited(data)?;
+        let (item, patch) = REGISTRY.items.resolve_client_stack(item, patch);
 
         let stack = Self::with_count_and_patch(item, count, patch);
         stack.validate_persistent_encoding()?;
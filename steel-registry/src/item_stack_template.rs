@@ -237,11 +237,16 @@ impl ItemStackTemplate {
 impl WriteTo for ItemStackTemplate {
     fn write(&self, writer: &mut impl Write) -> Result<()> {
         let _depth = TemplateDepthGuard::enter()?;
-        let item_id = i32::try_from(self.item.id())
-            .map_err(|_| Error::other(format!("Item id is too large: {}", self.item.id())))?;
+        let client_view = self.item.client_view(&self.components);
+        let (item, components) = match &client_view {
+            Some((client_item, client_patch)) => (*client_item, client_patch),
+            None => (self.item, &self.components),
+        };
+        let item_id = i32::try_from(item.id())
+            .map_err(|_| Error::other(format!("Item id is too large: {}", item.id())))?;
         VarInt(item_id).write(writer)?;
         VarInt(self.count).write(writer)?;
-        self.components.write(writer)
+        components.write(writer)
     }
 }
 
@@ -257,6 +262,7 @@ impl ReadFrom for ItemStackTemplate {
             .ok_or_else(|| Error::other(format!("Unknown item id: {item_id}")))?;
         let count = VarInt::read(data)?.0;
         let components = DataComponentPatch::read(data)?;
+        let (item, components) = REGISTRY.items.resolve_client_stack(item, components);
         Self::from_stream(item, count, components)
     }
 }
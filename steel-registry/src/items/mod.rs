@@ -1,6 +1,7 @@
 use std::sync::OnceLock;
 
 use rustc_hash::FxHashMap;
+use simdnbt::owned::NbtTag;
 
 use steel_utils::{DowncastType, Identifier};
 use text_components::TextComponent;
@@ -11,13 +12,16 @@ use crate::{
     REGISTRY, RegistryExt, TaggedRegistryExt,
     blocks::BlockRef,
     data_components::{
-        DataComponentMap,
-        vanilla_components::{ITEM_MODEL, ITEM_NAME},
+        ComponentPatchEntry, CustomData, DataComponentMap, DataComponentPatch,
+        vanilla_components::{CUSTOM_DATA, ITEM_MODEL, ITEM_NAME},
     },
     item_stack::ItemStack,
     vanilla_items,
 };
 
+/// `custom_data` field naming the custom item a client-side stack stands for.
+pub const CUSTOM_ITEM_MARKER: &str = "steel:item";
+
 /// A Minecraft item type.
 pub struct Item {
     pub key: Identifier,
@@ -27,6 +31,8 @@ pub struct Item {
     pub craft_remainder: Option<Identifier>,
     /// Cached registry ID, set during registration for O(1) lookup on hot paths.
     pub id: OnceLock<usize>,
+    /// Vanilla item clients see in place of this one. Only set for custom items.
+    pub client_item: Option<ItemRef>,
 }
 
 impl std::fmt::Debug for Item {
@@ -51,7 +57,53 @@ impl Item {
             components,
             craft_remainder,
             id: OnceLock::new(),
+            client_item: None,
+        }
+    }
+
+    /// Creates a server-side item starting from `base`'s components, which
+    /// clients see as `base` or, for a custom base, as its client item.
+    #[must_use]
+    pub fn custom(key: Identifier, item_name: TextComponent, base: ItemRef) -> Self {
+        let mut components = base.components.clone();
+        components.set(ITEM_NAME, Some(item_name));
+        components.set(ITEM_MODEL, Some(key.clone()));
+        Self {
+            key,
+            components,
+            craft_remainder: None,
+            id: OnceLock::new(),
+            client_item: Some(base.network_item()),
+        }
+    }
+
+    /// Returns the item clients know this item as.
+    #[must_use]
+    pub fn network_item(&'static self) -> ItemRef {
+        self.client_item.unwrap_or(self)
+    }
+
+    /// Returns the item and component patch clients are sent for a stack of
+    /// this item, or `None` when clients know this item.
+    ///
+    /// Custom items are sent as their client item with the differing components
+    /// patched in, and with [`CUSTOM_ITEM_MARKER`] added to `custom_data` so
+    /// stacks the client sends back map to the custom item again.
+    #[must_use]
+    pub fn client_view(&self, patch: &DataComponentPatch) -> Option<(ItemRef, DataComponentPatch)> {
+        let client_item = self.client_item?;
+        let mut tag = match patch.get_entry(&CUSTOM_DATA.key) {
+            Some(ComponentPatchEntry::Set(data)) => data.downcast_ref::<CustomData>(),
+            Some(ComponentPatchEntry::Removed) => None,
+            None => self.components.get_ref(CUSTOM_DATA),
         }
+        .map(CustomData::copy_tag)
+        .unwrap_or_default();
+        tag.insert(CUSTOM_ITEM_MARKER, self.key.to_string());
+
+        let mut client_patch = patch.rebase(&self.components, &client_item.components);
+        client_patch.set(CUSTOM_DATA, CustomData::try_from_compound(tag)?);
+        Some((client_item, client_patch))
     }
 
     #[must_use]
@@ -166,6 +218,41 @@ impl ItemRegistry {
             .map_or(&vanilla_items::AIR, |&item_id| self.items_by_id[item_id])
     }
 
+    /// Maps a stack received from a client back to the custom item named by its
+    /// [`CUSTOM_ITEM_MARKER`], reversing [`Item::client_view`].
+    #[must_use]
+    pub fn resolve_client_stack(
+        &self,
+        item: ItemRef,
+        mut patch: DataComponentPatch,
+    ) -> (ItemRef, DataComponentPatch) {
+        let Some(ComponentPatchEntry::Set(data)) = patch.get_entry(&CUSTOM_DATA.key) else {
+            return (item, patch);
+        };
+        let Some(custom_data) = data.downcast_ref::<CustomData>() else {
+            return (item, patch);
+        };
+        let Some(custom_item) = custom_data
+            .as_compound()
+            .get(CUSTOM_ITEM_MARKER)
+            .and_then(NbtTag::string)
+            .and_then(|key| key.to_string().parse::<Identifier>().ok())
+            .and_then(|key| self.by_key(&key))
+            .filter(|custom_item| custom_item.client_item == Some(item))
+        else {
+            return (item, patch);
+        };
+
+        let custom_data = custom_data.clone().without_field(CUSTOM_ITEM_MARKER);
+        if custom_data.is_empty() {
+            patch.remove(CUSTOM_DATA);
+        } else {
+            patch.set(CUSTOM_DATA, custom_data);
+        }
+        let patch = patch.rebase(&item.components, &custom_item.components);
+        (custom_item, patch)
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (usize, ItemRef)> + '_ {
         self.items_by_id
             .iter()
@@ -179,10 +266,13 @@ mod tests {
     use steel_utils::Identifier;
     use text_components::TextComponent;
 
-    use super::Item;
+    use super::{Item, ItemRef, ItemRegistry};
     use crate::{
         REGISTRY,
-        data_components::vanilla_components::{ITEM_MODEL, ITEM_NAME},
+        data_components::{
+            DataComponentPatch,
+            vanilla_components::{CUSTOM_DATA, ITEM_MODEL, ITEM_NAME, MAX_STACK_SIZE},
+        },
         test_support::init_test_registry,
         vanilla_blocks, vanilla_items,
     };
@@ -197,6 +287,34 @@ mod tests {
         assert_eq!(item.components.get_ref(ITEM_NAME), Some(&name));
     }
 
+    #[test]
+    fn custom_items_round_trip_through_their_client_view() {
+        init_test_registry();
+        let ruby: ItemRef = Box::leak(Box::new(
+            Item::custom(
+                Identifier::new_static("steel", "ruby"),
+                TextComponent::plain("Ruby"),
+                &vanilla_items::EMERALD,
+            )
+            .builder_set(MAX_STACK_SIZE, Some(16)),
+        ));
+        let mut registry = ItemRegistry::new();
+        registry.register(ruby);
+
+        let mut patch = DataComponentPatch::new();
+        patch.set(MAX_STACK_SIZE, 8);
+        let (client_item, client_patch) = ruby
+            .client_view(&patch)
+            .expect("custom items have a client view");
+        assert_eq!(client_item, &*vanilla_items::EMERALD);
+        assert!(client_patch.get_entry(&CUSTOM_DATA.key).is_some());
+
+        let (item, resolved) = registry.resolve_client_stack(client_item, client_patch);
+        assert_eq!(item, ruby);
+        assert_eq!(resolved, patch);
+        assert!(vanilla_items::EMERALD.client_view(&patch).is_none());
+    }
+
     #[test]
     fn extracted_block_item_associations_match_vanilla() {
         init_test_registry();
@@ -225,7 +343,12 @@ mod tests {
 }
 
 crate::impl_registry_ext!(ItemRegistry, Item, items_by_id, items_by_key);
-crate::impl_tagged_registry!(ItemRegistry, items_by_key, "item");
+crate::impl_tagged_registry!(
+    ItemRegistry,
+    items_by_key,
+    "item",
+    overlay = crate::ITEMS_REGISTRY
+);
 
 crate::impl_registry_entry_eq!(Item);
 
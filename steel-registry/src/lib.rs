@@ -30,6 +30,7 @@
 use crate::game_events::GameEventRegistry;
 use crate::world_clock::WorldClockRegistry;
 use crate::{
+    advancement::AdvancementRegistry,
     attribute::AttributeRegistry,
     banner_pattern::BannerPatternRegistry,
     biome::BiomeRegistry,
@@ -88,6 +89,7 @@ use crate::{
 };
 use std::{fmt::Debug, ops::Deref, sync::OnceLock};
 use steel_utils::Identifier;
+pub mod advancement;
 pub mod attribute;
 pub mod banner_pattern;
 pub mod biome;
@@ -129,6 +131,7 @@ pub mod items;
 pub mod jukebox_song;
 pub mod loot_table;
 mod macros;
+pub mod map_color;
 pub mod map_decoration_type;
 pub mod menu_type;
 pub mod mob_effect;
@@ -141,14 +144,18 @@ pub mod poi;
 pub mod position_source;
 pub mod potion;
 pub mod recipe;
+pub mod registry_overlay;
 pub mod registry_reference;
 pub mod resolvable_profile;
 pub mod sound_event;
+pub mod stat;
 pub mod structure;
 pub mod structure_processor;
 pub mod structure_set;
+pub mod tag_overlay;
 pub mod template_pool;
 pub mod timeline;
+pub mod trading;
 pub mod trim_material;
 pub mod trim_pattern;
 pub mod villager_profession;
@@ -385,6 +392,11 @@ pub mod vanilla_timeline_tags;
 #[path = "generated/vanilla_recipes.rs"]
 pub mod vanilla_recipes;
 
+#[expect(warnings)]
+#[rustfmt::skip]
+#[path = "generated/vanilla_advancements.rs"]
+pub mod vanilla_advancements;
+
 #[expect(warnings)]
 #[rustfmt::skip]
 #[path = "generated/vanilla_entities.rs"]
@@ -469,6 +481,11 @@ pub mod vanilla_game_rules;
 #[path = "generated/vanilla_game_events.rs"]
 pub mod vanilla_game_events;
 
+#[expect(warnings)]
+#[rustfmt::skip]
+#[path = "generated/vanilla_game_event_tags.rs"]
+pub mod vanilla_game_event_tags;
+
 #[expect(warnings)]
 #[rustfmt::skip]
 #[path = "generated/vanilla_level_events.rs"]
@@ -711,6 +728,7 @@ pub struct Registry {
     pub zombie_nautilus_variants: ZombieNautilusVariantRegistry,
     pub timelines: TimelineRegistry,
     pub recipes: RecipeRegistry,
+    pub advancements: AdvancementRegistry,
     pub entity_types: EntityTypeRegistry,
     pub loot_tables: LootTableRegistry,
     pub block_entity_types: BlockEntityTypeRegistry,
@@ -820,6 +838,7 @@ impl Registry {
         vanilla_timelines::register_timelines(&mut registry.timelines);
         vanilla_timeline_tags::TimelineTag::register_timeline_tags(&mut registry.timelines);
         vanilla_recipes::register_recipes(&mut registry.recipes);
+        vanilla_advancements::register_advancements(&mut registry.advancements);
         vanilla_entities::register_entity_types(&mut registry.entity_types);
         vanilla_entity_type_tags::EntityTypeTag::register_entity_type_tags(
             &mut registry.entity_types,
@@ -828,6 +847,9 @@ impl Registry {
         vanilla_block_entity_types::register_block_entity_types(&mut registry.block_entity_types);
         vanilla_game_rules::register_game_rules(&mut registry.game_rules);
         vanilla_game_events::register_game_events(&mut registry.game_events);
+        vanilla_game_event_tags::GameEventsTag::register_game_events_tags(
+            &mut registry.game_events,
+        );
         sound_events::register_sound_events(&mut registry.sound_events);
 
         vanilla_fluids::register_fluids(&mut registry.fluids);
@@ -901,6 +923,7 @@ impl Registry {
         self.zombie_nautilus_variants.freeze();
         self.timelines.freeze();
         self.recipes.freeze();
+        self.advancements.freeze();
         self.entity_types.freeze();
         self.loot_tables.freeze();
         self.block_entity_types.freeze();
@@ -1157,6 +1180,7 @@ impl Registry {
             zombie_nautilus_variants: ZombieNautilusVariantRegistry::new(),
             timelines: TimelineRegistry::new(),
             recipes: RecipeRegistry::new(),
+            advancements: AdvancementRegistry::new(),
             entity_types: EntityTypeRegistry::new(),
             loot_tables: LootTableRegistry::new(),
             block_entity_types: BlockEntityTypeRegistry::new(),
@@ -1183,7 +1207,7 @@ mod tests {
     use rustc_hash::FxHashMap;
     use steel_utils::Identifier;
 
-    use crate::biome::{Biome, BiomeEffects, GrassColorModifier, TemperatureModifier};
+    use crate::biome::{Biome, BiomeEffects, TemperatureModifier};
 
     use super::{Registry, RegistryExt};
 
@@ -1194,21 +1218,7 @@ mod tests {
             temperature: 0.5,
             downfall: 0.0,
             temperature_modifier: TemperatureModifier::None,
-            effects: BiomeEffects {
-                fog_color: 0,
-                sky_color: 0,
-                water_color: 0,
-                water_fog_color: 0,
-                foliage_color: None,
-                grass_color: None,
-                dry_foliage_color: None,
-                grass_color_modifier: GrassColorModifier::None,
-                music: None,
-                ambient_sound: None,
-                additions_sound: None,
-                mood_sound: None,
-                particle: None,
-            },
+            effects: BiomeEffects::default(),
             creature_spawn_probability: 0.0,
             spawners: FxHashMap::default(),
             spawn_costs: FxHashMap::default(),
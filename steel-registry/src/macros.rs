@@ -132,9 +132,12 @@ macro_rules! impl_registry {
 }
 
 /// Implements `TaggedRegistryExt` for a registry with tag support.
+///
+/// With `overlay = <registry key>`, tag lookups consult the datapack
+/// [`tag_overlay`](crate::tag_overlay) before the registry's own tags.
 #[macro_export]
 macro_rules! impl_tagged_registry {
-    ($Registry:ty, $key_field:ident, $entity_name:literal) => {
+    ($Registry:ty, $key_field:ident, $entity_name:literal $(, overlay = $registry_key:expr)?) => {
         impl $crate::TaggedRegistryExt for $Registry {
             fn register_tag(&mut self, tag: steel_utils::Identifier, keys: &[&'static str]) {
                 assert!(
@@ -182,6 +185,15 @@ macro_rules! impl_tagged_registry {
             }
 
             fn is_in_tag(&self, entry: &Self::Entry, tag: &steel_utils::Identifier) -> bool {
+                $(
+                    if let Some(contains) =
+                        $crate::tag_overlay::with_overlaid_tag(&$registry_key, tag, |entries| {
+                            entries.contains(&entry.key)
+                        })
+                    {
+                        return contains;
+                    }
+                )?
                 self.tags
                     .get(tag)
                     .is_some_and(|entries| entries.contains(&entry.key))
@@ -189,6 +201,18 @@ macro_rules! impl_tagged_registry {
 
             fn get_tag(&self, tag: &steel_utils::Identifier) -> Option<Vec<&'static Self::Entry>> {
                 use $crate::RegistryExt;
+                $(
+                    if let Some(entries) =
+                        $crate::tag_overlay::with_overlaid_tag(&$registry_key, tag, |entries| {
+                            entries
+                                .iter()
+                                .filter_map(|ident| self.by_key(ident))
+                                .collect()
+                        })
+                    {
+                        return Some(entries);
+                    }
+                )?
                 self.tags.get(tag).map(|idents| {
                     idents
                         .iter()
@@ -202,10 +226,19 @@ macro_rules! impl_tagged_registry {
                 tag: &steel_utils::Identifier,
             ) -> impl Iterator<Item = &'static Self::Entry> + '_ {
                 use $crate::RegistryExt;
-                self.tags
-                    .get(tag)
-                    .into_iter()
-                    .flat_map(|v| v.iter().filter_map(|ident| self.by_key(ident)))
+                let overlaid: Option<Vec<&'static Self::Entry>> = None $(.or_else(|| {
+                    $crate::tag_overlay::with_overlaid_tag(&$registry_key, tag, |entries| {
+                        entries
+                            .iter()
+                            .filter_map(|ident| self.by_key(ident))
+                            .collect()
+                    })
+                }))?;
+                let own = if overlaid.is_some() { None } else { self.tags.get(tag) };
+                overlaid.into_iter().flatten().chain(
+                    own.into_iter()
+                        .flat_map(|v| v.iter().filter_map(|ident| self.by_key(ident))),
+                )
             }
 
             fn tag_keys(&self) -> impl Iterator<Item = &steel_utils::Identifier> + '_ {
@@ -0,0 +1,140 @@
+//! Vanilla map colors.
+//!
+//! The color each block state shows on maps is extracted per state and read
+//! through `BlockStateExt::get_map_color`.
+
+/// How bright a map color is drawn, used for terrain shading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapColorBrightness {
+    Low,
+    Normal,
+    High,
+    Lowest,
+}
+
+impl MapColorBrightness {
+    #[must_use]
+    pub const fn id(self) -> u8 {
+        match self {
+            Self::Low => 0,
+            Self::Normal => 1,
+            Self::High => 2,
+            Self::Lowest => 3,
+        }
+    }
+
+    /// The factor out of 255 the base color is multiplied by.
+    #[must_use]
+    pub const fn modifier(self) -> u8 {
+        match self {
+            Self::Low => 180,
+            Self::Normal => 220,
+            Self::High => 255,
+            Self::Lowest => 135,
+        }
+    }
+}
+
+/// One of vanilla's 62 base map colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MapColor(u8);
+
+impl MapColor {
+    pub const NONE: Self = Self(0);
+    pub const GRASS: Self = Self(1);
+    pub const SAND: Self = Self(2);
+    pub const WOOL: Self = Self(3);
+    pub const FIRE: Self = Self(4);
+    pub const ICE: Self = Self(5);
+    pub const METAL: Self = Self(6);
+    pub const PLANT: Self = Self(7);
+    pub const SNOW: Self = Self(8);
+    pub const CLAY: Self = Self(9);
+    pub const DIRT: Self = Self(10);
+    pub const STONE: Self = Self(11);
+    pub const WATER: Self = Self(12);
+    pub const WOOD: Self = Self(13);
+    pub const QUARTZ: Self = Self(14);
+    pub const COLOR_ORANGE: Self = Self(15);
+    pub const COLOR_MAGENTA: Self = Self(16);
+    pub const COLOR_LIGHT_BLUE: Self = Self(17);
+    pub const COLOR_YELLOW: Self = Self(18);
+    pub const COLOR_LIGHT_GREEN: Self = Self(19);
+    pub const COLOR_PINK: Self = Self(20);
+    pub const COLOR_GRAY: Self = Self(21);
+    pub const COLOR_LIGHT_GRAY: Self = Self(22);
+    pub const COLOR_CYAN: Self = Self(23);
+    pub const COLOR_PURPLE: Self = Self(24);
+    pub const COLOR_BLUE: Self = Self(25);
+    pub const COLOR_BROWN: Self = Self(26);
+    pub const COLOR_GREEN: Self = Self(27);
+    pub const COLOR_RED: Self = Self(28);
+    pub const COLOR_BLACK: Self = Self(29);
+    pub const GOLD: Self = Self(30);
+    pub const DIAMOND: Self = Self(31);
+    pub const LAPIS: Self = Self(32);
+    pub const EMERALD: Self = Self(33);
+    pub const PODZOL: Self = Self(34);
+    pub const NETHER: Self = Self(35);
+    pub const TERRACOTTA_WHITE: Self = Self(36);
+    pub const TERRACOTTA_ORANGE: Self = Self(37);
+    pub const TERRACOTTA_MAGENTA: Self = Self(38);
+    pub const TERRACOTTA_LIGHT_BLUE: Self = Self(39);
+    pub const TERRACOTTA_YELLOW: Self = Self(40);
+    pub const TERRACOTTA_LIGHT_GREEN: Self = Self(41);
+    pub const TERRACOTTA_PINK: Self = Self(42);
+    pub const TERRACOTTA_GRAY: Self = Self(43);
+    pub const TERRACOTTA_LIGHT_GRAY: Self = Self(44);
+    pub const TERRACOTTA_CYAN: Self = Self(45);
+    pub const TERRACOTTA_PURPLE: Self = Self(46);
+    pub const TERRACOTTA_BLUE: Self = Self(47);
+    pub const TERRACOTTA_BROWN: Self = Self(48);
+    pub const TERRACOTTA_GREEN: Self = Self(49);
+    pub const TERRACOTTA_RED: Self = Self(50);
+    pub const TERRACOTTA_BLACK: Self = Self(51);
+    pub const CRIMSON_NYLIUM: Self = Self(52);
+    pub const CRIMSON_STEM: Self = Self(53);
+    pub const CRIMSON_HYPHAE: Self = Self(54);
+    pub const WARPED_NYLIUM: Self = Self(55);
+    pub const WARPED_STEM: Self = Self(56);
+    pub const WARPED_HYPHAE: Self = Self(57);
+    pub const WARPED_WART_BLOCK: Self = Self(58);
+    pub const DEEPSLATE: Self = Self(59);
+    pub const RAW_IRON: Self = Self(60);
+    pub const GLOW_LICHEN: Self = Self(61);
+
+    /// Returns the map color with the given vanilla id.
+    #[must_use]
+    pub const fn from_id(id: u8) -> Self {
+        Self(id)
+    }
+
+    #[must_use]
+    pub const fn id(self) -> u8 {
+        self.0
+    }
+
+    /// The color byte stored in map data and sent to clients.
+    #[must_use]
+    pub const fn packed_id(self, brightness: MapColorBrightness) -> u8 {
+        (self.0 << 2) | brightness.id()
+    }
+
+    /// Returns the base color of a packed color byte.
+    #[must_use]
+    pub const fn from_packed_id(packed: u8) -> Self {
+        Self(packed >> 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_brightness_into_the_low_bits() {
+        assert_eq!(MapColor::WATER.packed_id(MapColorBrightness::High), 50);
+        assert_eq!(MapColor::from_packed_id(50), MapColor::WATER);
+        assert_eq!(MapColor::from_id(51), MapColor::TERRACOTTA_BLACK);
+    }
+}
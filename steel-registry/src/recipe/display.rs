@@ -0,0 +1,283 @@
+//! Recipe book displays sent to the client.
+//!
+//! The client never sees recipes themselves, only these display descriptions
+//! keyed by a numeric display id.
+
+use std::io::{Error, Result, Write};
+
+use steel_utils::Identifier;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::WriteTo;
+
+use super::crafting::{CraftingCategory, CraftingRecipe, ShapedRecipe, ShapelessRecipe};
+use super::ingredient::Ingredient;
+use crate::data_component_predicate::write_registry_id;
+use crate::data_components::DataComponentPatch;
+use crate::item_stack_template::ItemStackTemplate;
+use crate::items::ItemRef;
+use crate::{RegistryEntry, vanilla_items};
+
+/// Vanilla `SlotDisplay`: what the recipe book shows in a single slot.
+#[derive(Debug, Clone)]
+pub enum SlotDisplay {
+    Empty,
+    AnyFuel,
+    Item(ItemRef),
+    ItemStack(ItemStackTemplate),
+    Tag(Identifier),
+    Composite(Vec<SlotDisplay>),
+}
+
+impl SlotDisplay {
+    /// Returns the id of this display's type in the vanilla `slot_display` registry.
+    const fn type_id(&self) -> i32 {
+        match self {
+            Self::Empty => 0,
+            Self::AnyFuel => 1,
+            Self::Item(_) => 2,
+            Self::ItemStack(_) => 3,
+            Self::Tag(_) => 4,
+            Self::Composite(_) => 7,
+        }
+    }
+
+    /// Vanilla `Ingredient.display`.
+    #[must_use]
+    pub fn from_ingredient(ingredient: &Ingredient) -> Self {
+        match ingredient {
+            Ingredient::Empty => Self::Empty,
+            Ingredient::Item(item) => Self::Item(*item),
+            Ingredient::Tag(tag) => Self::Tag(tag.clone()),
+            Ingredient::Choice(items) => {
+                Self::Composite(items.iter().map(|item| Self::Item(*item)).collect())
+            }
+        }
+    }
+
+    /// Returns the display for a recipe result.
+    fn from_result(item: ItemRef, count: i32) -> Self {
+        if count == 1 {
+            return Self::Item(item);
+        }
+        ItemStackTemplate::try_with_count_and_patch(item, count, DataComponentPatch::new())
+            .map_or(Self::Item(item), Self::ItemStack)
+    }
+}
+
+impl WriteTo for SlotDisplay {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        VarInt(self.type_id()).write(writer)?;
+        match self {
+            Self::Empty | Self::AnyFuel => Ok(()),
+            Self::Item(item) => write_registry_id(item.network_item(), writer, "item"),
+            Self::ItemStack(template) => template.write(writer),
+            Self::Tag(tag) => tag.write(writer),
+            Self::Composite(contents) => contents.write(writer),
+        }
+    }
+}
+
+/// Vanilla `RecipeDisplay` for the recipe types the server supports.
+#[derive(Debug, Clone)]
+pub enum RecipeDisplay {
+    CraftingShapeless {
+        ingredients: Vec<SlotDisplay>,
+        result: SlotDisplay,
+        crafting_station: SlotDisplay,
+    },
+    CraftingShaped {
+        width: i32,
+        height: i32,
+        ingredients: Vec<SlotDisplay>,
+        result: SlotDisplay,
+        crafting_station: SlotDisplay,
+    },
+}
+
+impl WriteTo for RecipeDisplay {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        match self {
+            Self::CraftingShapeless {
+                ingredients,
+                result,
+                crafting_station,
+            } => {
+                VarInt(0).write(writer)?;
+                ingredients.write(writer)?;
+                result.write(writer)?;
+                crafting_station.write(writer)
+            }
+            Self::CraftingShaped {
+                width,
+                height,
+                ingredients,
+                result,
+                crafting_station,
+            } => {
+                VarInt(1).write(writer)?;
+                VarInt(*width).write(writer)?;
+                VarInt(*height).write(writer)?;
+                ingredients.write(writer)?;
+                result.write(writer)?;
+                crafting_station.write(writer)
+            }
+        }
+    }
+}
+
+/// Vanilla `RecipeBookCategory`: the recipe book tab a recipe is listed under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecipeBookCategory {
+    CraftingBuildingBlocks,
+    CraftingRedstone,
+    CraftingEquipment,
+    CraftingMisc,
+    FurnaceFood,
+    FurnaceBlocks,
+    FurnaceMisc,
+    BlastFurnaceBlocks,
+    BlastFurnaceMisc,
+    SmokerFood,
+    Stonecutter,
+    Smithing,
+    Campfire,
+}
+
+impl RecipeBookCategory {
+    /// Returns the id of this category in the vanilla `recipe_book_category` registry.
+    #[must_use]
+    pub const fn id(self) -> i32 {
+        self as i32
+    }
+}
+
+impl From<CraftingCategory> for RecipeBookCategory {
+    /// Vanilla `CraftingRecipe.recipeBookCategory`.
+    fn from(category: CraftingCategory) -> Self {
+        match category {
+            CraftingCategory::Building => Self::CraftingBuildingBlocks,
+            CraftingCategory::Redstone => Self::CraftingRedstone,
+            CraftingCategory::Equipment => Self::CraftingEquipment,
+            CraftingCategory::Misc => Self::CraftingMisc,
+        }
+    }
+}
+
+impl WriteTo for Ingredient {
+    /// Vanilla `Ingredient.CONTENTS_STREAM_CODEC`: a tag or a direct item holder set.
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        if let Self::Tag(tag) = self {
+            VarInt(0).write(writer)?;
+            return tag.write(writer);
+        }
+        let items = self.get_items();
+        let len = i32::try_from(items.len())
+            .map_err(|_| Error::other("ingredient has too many items"))?;
+        VarInt(len + 1).write(writer)?;
+        for item in items {
+            write_registry_id(item.network_item(), writer, "item")?;
+        }
+        Ok(())
+    }
+}
+
+/// Vanilla `RecipeDisplayEntry`: one recipe as listed in the client's recipe book.
+#[derive(Debug, Clone)]
+pub struct RecipeDisplayEntry {
+    /// The display id the client refers back to this recipe with.
+    pub id: i32,
+    pub display: RecipeDisplay,
+    pub group: Option<i32>,
+    pub category: RecipeBookCategory,
+    /// The ingredients the client uses to mark the recipe as craftable.
+    pub crafting_requirements: Option<Vec<Ingredient>>,
+}
+
+impl WriteTo for RecipeDisplayEntry {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        VarInt(self.id).write(writer)?;
+        self.display.write(writer)?;
+        VarInt(self.group.map_or(0, |group| group + 1)).write(writer)?;
+        VarInt(self.category.id()).write(writer)?;
+        self.crafting_requirements.write(writer)
+    }
+}
+
+impl ShapedRecipe {
+    /// Vanilla `ShapedRecipe.display`.
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap,
+        reason = "shaped recipe patterns are at most 3x3"
+    )]
+    pub fn display(&self) -> RecipeDisplay {
+        RecipeDisplay::CraftingShaped {
+            width: self.width as i32,
+            height: self.height as i32,
+            ingredients: self
+                .pattern
+                .iter()
+                .map(SlotDisplay::from_ingredient)
+                .collect(),
+            result: SlotDisplay::from_result(self.result.item, self.result.count),
+            crafting_station: SlotDisplay::Item(&vanilla_items::CRAFTING_TABLE),
+        }
+    }
+}
+
+impl ShapelessRecipe {
+    /// Vanilla `ShapelessRecipe.display`.
+    #[must_use]
+    pub fn display(&self) -> RecipeDisplay {
+        RecipeDisplay::CraftingShapeless {
+            ingredients: self
+                .ingredients
+                .iter()
+                .map(SlotDisplay::from_ingredient)
+                .collect(),
+            result: SlotDisplay::from_result(self.result.item, self.result.count),
+            crafting_station: SlotDisplay::Item(&vanilla_items::CRAFTING_TABLE),
+        }
+    }
+}
+
+impl CraftingRecipe {
+    /// Returns the ingredients a player has to obtain to unlock this recipe.
+    #[must_use]
+    pub fn ingredients(&self) -> Vec<&'static Ingredient> {
+        match self {
+            Self::Shaped(r) => r.pattern.iter().filter(|i| !i.is_empty()).collect(),
+            Self::Shapeless(r) => r.ingredients.iter().collect(),
+        }
+    }
+
+    /// Returns whether unlocking this recipe shows a toast on the client.
+    #[must_use]
+    pub const fn show_notification(&self) -> bool {
+        match self {
+            Self::Shaped(r) => r.show_notification,
+            Self::Shapeless(_) => true,
+        }
+    }
+
+    /// Vanilla `RecipeManager` display entry for this recipe.
+    ///
+    /// The display id is the recipe's registry id.
+    #[must_use]
+    pub fn display_entry(&self) -> Option<RecipeDisplayEntry> {
+        let id = i32::try_from(self.try_id()?).ok()?;
+        let display = match self {
+            Self::Shaped(r) => r.display(),
+            Self::Shapeless(r) => r.display(),
+        };
+        Some(RecipeDisplayEntry {
+            id,
+            display,
+            // TODO: Recipe groups once they are loaded from the recipe json.
+            group: None,
+            category: self.category().into(),
+            crafting_requirements: Some(self.ingredients().into_iter().cloned().collect()),
+        })
+    }
+}
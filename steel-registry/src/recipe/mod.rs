@@ -5,6 +5,7 @@
 
 mod cooking;
 mod crafting;
+mod display;
 mod ingredient;
 mod registry;
 
@@ -13,5 +14,6 @@ pub use crafting::{
     CraftingCategory, CraftingInput, CraftingRecipe, PositionedCraftingInput, RecipeResult,
     ShapedRecipe, ShapelessRecipe,
 };
+pub use display::{RecipeBookCategory, RecipeDisplay, RecipeDisplayEntry, SlotDisplay};
 pub use ingredient::Ingredient;
 pub use registry::RecipeRegistry;
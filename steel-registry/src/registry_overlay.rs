@@ -0,0 +1,182 @@
+//! Datapack-defined entries layered over the frozen synced registries.
+//!
+//! Steel's gameplay uses the compiled entries, so the overlay only changes what
+//! is synced to clients: an entry with a vanilla key replaces that entry's data
+//! in place, and any other key is appended after the compiled entries. Compiled
+//! network ids therefore never shift. A reload swaps the whole overlay at once.
+
+use std::mem;
+use std::sync::{Arc, LazyLock};
+
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_utils::Identifier;
+use steel_utils::locks::SyncRwLock;
+
+use crate::{
+    BIOMES_REGISTRY, CHAT_TYPE_REGISTRY, DAMAGE_TYPE_REGISTRY, DIALOG_REGISTRY,
+    DIMENSION_TYPE_REGISTRY,
+};
+
+/// Registries datapacks may add entries to, with their datapack directory.
+pub const OVERLAY_REGISTRIES: [(Identifier, &str); 5] = [
+    (DIMENSION_TYPE_REGISTRY, "dimension_type"),
+    (BIOMES_REGISTRY, "worldgen/biome"),
+    (CHAT_TYPE_REGISTRY, "chat_type"),
+    (DAMAGE_TYPE_REGISTRY, "damage_type"),
+    (DIALOG_REGISTRY, "dialog"),
+];
+
+static OVERLAY: LazyLock<SyncRwLock<Option<Arc<RegistryOverlay>>>> =
+    LazyLock::new(|| SyncRwLock::new(None));
+
+/// Datapack entries per registry, in load order.
+#[derive(Debug, Default)]
+pub struct RegistryOverlay {
+    entries: FxHashMap<Identifier, Vec<(Identifier, NbtTag)>>,
+}
+
+impl RegistryOverlay {
+    /// Sets the data of `key` in `registry`, replacing an earlier pack's entry.
+    pub fn insert(&mut self, registry: Identifier, key: Identifier, data: NbtTag) {
+        let entries = self.entries.entry(registry).or_default();
+        match entries.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, existing)) => *existing = data,
+            None => entries.push((key, data)),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.values().all(Vec::is_empty)
+    }
+
+    /// Returns how many entries the overlay defines.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.values().map(Vec::len).sum()
+    }
+
+    /// Returns the data the overlay defines for `key` in `registry`.
+    #[must_use]
+    pub fn get(&self, registry: &Identifier, key: &Identifier) -> Option<&NbtTag> {
+        self.entries
+            .get(registry)?
+            .iter()
+            .find_map(|(existing, data)| (existing == key).then_some(data))
+    }
+
+    /// Returns the entries of `registry` that `is_compiled` does not know, in
+    /// the order they follow the compiled entries.
+    pub fn additions<'a, F: Fn(&Identifier) -> bool + 'a>(
+        &'a self,
+        registry: &Identifier,
+        is_compiled: F,
+    ) -> impl Iterator<Item = (&'a Identifier, &'a NbtTag)> + use<'a, F> {
+        self.entries
+            .get(registry)
+            .into_iter()
+            .flatten()
+            .filter(move |(key, _)| !is_compiled(key))
+            .map(|(key, data)| (key, data))
+    }
+}
+
+/// Installs `overlay`, replacing the previous one. An empty overlay restores
+/// the compiled registries.
+pub fn set_registry_overlay(overlay: RegistryOverlay) {
+    *OVERLAY.write() = (!overlay.is_empty()).then(|| Arc::new(overlay));
+}
+
+/// Returns the installed overlay, if any.
+#[must_use]
+pub fn registry_overlay() -> Option<Arc<RegistryOverlay>> {
+    OVERLAY.read().clone()
+}
+
+/// Converts datapack JSON into the NBT sent in registry data.
+///
+/// Mirrors vanilla's `JsonOps` to `NbtOps` conversion: booleans become bytes and
+/// numeric lists are widened so they stay homogeneous. Returns `None` for
+/// `null` and for lists mixing incompatible element types.
+#[must_use]
+pub fn json_to_nbt(value: &Value) -> Option<NbtTag> {
+    Some(match value {
+        Value::Null => return None,
+        Value::Bool(value) => NbtTag::Byte(i8::from(*value)),
+        Value::Number(number) => number_to_nbt(number, false)?,
+        Value::String(value) => NbtTag::String(value.as_str().into()),
+        Value::Array(values) => NbtTag::List(json_list_to_nbt(values)?),
+        Value::Object(object) => {
+            let mut compound = NbtCompound::new();
+            for (key, value) in object {
+                compound.insert(key.as_str(), json_to_nbt(value)?);
+            }
+            NbtTag::Compound(compound)
+        }
+    })
+}
+
+fn number_to_nbt(number: &serde_json::Number, as_double: bool) -> Option<NbtTag> {
+    if !as_double && let Some(value) = number.as_i64() {
+        return Some(match i32::try_from(value) {
+            Ok(value) => NbtTag::Int(value),
+            Err(_) => NbtTag::Long(value),
+        });
+    }
+    number.as_f64().map(NbtTag::Double)
+}
+
+fn json_list_to_nbt(values: &[Value]) -> Option<NbtList> {
+    if values.is_empty() {
+        return Some(NbtList::Empty);
+    }
+    if values.iter().all(Value::is_number) {
+        let as_double = values.iter().any(Value::is_f64);
+        let tags = values
+            .iter()
+            .filter_map(Value::as_number)
+            .map(|number| number_to_nbt(number, as_double))
+            .collect::<Option<Vec<_>>>()?;
+        return Some(NbtList::from(tags));
+    }
+    let tags = values.iter().map(json_to_nbt).collect::<Option<Vec<_>>>()?;
+    let first = mem::discriminant(tags.first()?);
+    if tags.iter().any(|tag| mem::discriminant(tag) != first) {
+        return None;
+    }
+    Some(NbtList::from(tags))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_lists_widen_to_doubles() {
+        let Some(NbtTag::List(list)) = json_to_nbt(&serde_json::json!([1, 2.5])) else {
+            panic!("numeric list should convert");
+        };
+        assert_eq!(
+            list,
+            NbtList::from(vec![NbtTag::Double(1.0), NbtTag::Double(2.5)])
+        );
+        assert!(json_to_nbt(&serde_json::json!([1, "a"])).is_none());
+    }
+
+    #[test]
+    fn overlay_replaces_entries_by_key() {
+        let mut overlay = RegistryOverlay::default();
+        let key = Identifier::vanilla_static("custom");
+        overlay.insert(CHAT_TYPE_REGISTRY, key.clone(), NbtTag::Int(1));
+        overlay.insert(CHAT_TYPE_REGISTRY, key.clone(), NbtTag::Int(2));
+        assert_eq!(overlay.len(), 1);
+        assert_eq!(
+            overlay.get(&CHAT_TYPE_REGISTRY, &key),
+            Some(&NbtTag::Int(2))
+        );
+        assert_eq!(overlay.additions(&CHAT_TYPE_REGISTRY, |_| false).count(), 1);
+        assert_eq!(overlay.additions(&CHAT_TYPE_REGISTRY, |_| true).count(), 0);
+    }
+}
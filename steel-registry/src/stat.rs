@@ -0,0 +1,298 @@
+//! Vanilla statistics: the `stat_type` and `custom_stat` registries.
+//!
+//! Both registries are fixed in vanilla, so they are listed here in
+//! registration order instead of being generated.
+
+use std::io::{Error, Result, Write};
+
+use steel_utils::Identifier;
+use steel_utils::codec::VarInt;
+use steel_utils::serial::WriteTo;
+
+use crate::blocks::BlockRef;
+use crate::entity_type::EntityTypeRef;
+use crate::items::ItemRef;
+use crate::{REGISTRY, RegistryEntry, RegistryExt};
+
+macro_rules! vanilla_enum_registry {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $key:literal,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            /// Every entry, in registry order.
+            pub const ALL: &[Self] = &[$(Self::$variant,)*];
+
+            /// Returns the registry id.
+            #[must_use]
+            pub const fn id(self) -> i32 {
+                self as i32
+            }
+
+            /// Returns the registry key path.
+            #[must_use]
+            pub const fn key(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $key,)*
+                }
+            }
+
+            /// Looks an entry up by its registry key.
+            #[must_use]
+            pub fn from_key(key: &Identifier) -> Option<Self> {
+                if key.namespace != Identifier::VANILLA_NAMESPACE {
+                    return None;
+                }
+                Self::ALL.iter().copied().find(|entry| entry.key() == key.path.as_ref())
+            }
+        }
+    };
+}
+
+vanilla_enum_registry! {
+    /// Vanilla `StatType`, the kind of thing a stat counts.
+    StatType {
+        Mined => "mined",
+        Crafted => "crafted",
+        Used => "used",
+        Broken => "broken",
+        PickedUp => "picked_up",
+        Dropped => "dropped",
+        Killed => "killed",
+        KilledBy => "killed_by",
+        Custom => "custom",
+    }
+}
+
+vanilla_enum_registry! {
+    /// Vanilla `Stats` custom stats, the values of the `minecraft:custom` stat type.
+    CustomStat {
+        LeaveGame => "leave_game",
+        PlayTime => "play_time",
+        TotalWorldTime => "total_world_time",
+        TimeSinceDeath => "time_since_death",
+        TimeSinceRest => "time_since_rest",
+        CrouchTime => "sneak_time",
+        WalkOneCm => "walk_one_cm",
+        CrouchOneCm => "crouch_one_cm",
+        SprintOneCm => "sprint_one_cm",
+        WalkOnWaterOneCm => "walk_on_water_one_cm",
+        FallOneCm => "fall_one_cm",
+        ClimbOneCm => "climb_one_cm",
+        FlyOneCm => "fly_one_cm",
+        WalkUnderWaterOneCm => "walk_under_water_one_cm",
+        MinecartOneCm => "minecart_one_cm",
+        BoatOneCm => "boat_one_cm",
+        PigOneCm => "pig_one_cm",
+        HappyGhastOneCm => "happy_ghast_one_cm",
+        HorseOneCm => "horse_one_cm",
+        AviateOneCm => "aviate_one_cm",
+        SwimOneCm => "swim_one_cm",
+        StriderOneCm => "strider_one_cm",
+        Jump => "jump",
+        Drop => "drop",
+        DamageDealt => "damage_dealt",
+        DamageDealtAbsorbed => "damage_dealt_absorbed",
+        DamageDealtResisted => "damage_dealt_resisted",
+        DamageTaken => "damage_taken",
+        DamageBlockedByShield => "damage_blocked_by_shield",
+        DamageAbsorbed => "damage_absorbed",
+        DamageResisted => "damage_resisted",
+        Deaths => "deaths",
+        MobKills => "mob_kills",
+        AnimalsBred => "animals_bred",
+        PlayerKills => "player_kills",
+        FishCaught => "fish_caught",
+        TalkedToVillager => "talked_to_villager",
+        TradedWithVillager => "traded_with_villager",
+        EatCakeSlice => "eat_cake_slice",
+        FillCauldron => "fill_cauldron",
+        UseCauldron => "use_cauldron",
+        CleanArmor => "clean_armor",
+        CleanBanner => "clean_banner",
+        CleanShulkerBox => "clean_shulker_box",
+        InteractWithBrewingstand => "interact_with_brewingstand",
+        InteractWithBeacon => "interact_with_beacon",
+        InspectDropper => "inspect_dropper",
+        InspectHopper => "inspect_hopper",
+        InspectDispenser => "inspect_dispenser",
+        PlayNoteblock => "play_noteblock",
+        TuneNoteblock => "tune_noteblock",
+        PotFlower => "pot_flower",
+        TriggerTrappedChest => "trigger_trapped_chest",
+        OpenEnderchest => "open_enderchest",
+        EnchantItem => "enchant_item",
+        PlayRecord => "play_record",
+        InteractWithFurnace => "interact_with_furnace",
+        InteractWithCraftingTable => "interact_with_crafting_table",
+        OpenChest => "open_chest",
+        SleepInBed => "sleep_in_bed",
+        OpenShulkerBox => "open_shulker_box",
+        OpenBarrel => "open_barrel",
+        InteractWithBlastFurnace => "interact_with_blast_furnace",
+        InteractWithSmoker => "interact_with_smoker",
+        InteractWithLectern => "interact_with_lectern",
+        InteractWithCampfire => "interact_with_campfire",
+        InteractWithCartographyTable => "interact_with_cartography_table",
+        InteractWithLoom => "interact_with_loom",
+        InteractWithStonecutter => "interact_with_stonecutter",
+        BellRing => "bell_ring",
+        RaidTrigger => "raid_trigger",
+        RaidWin => "raid_win",
+        InteractWithAnvil => "interact_with_anvil",
+        InteractWithGrindstone => "interact_with_grindstone",
+        TargetHit => "target_hit",
+        InteractWithSmithingTable => "interact_with_smithing_table",
+    }
+}
+
+/// Vanilla `Stat`: a stat type paired with one entry of the registry it counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Stat {
+    pub stat_type: StatType,
+    /// Id of the counted entry in the stat type's registry.
+    pub value: usize,
+}
+
+impl Stat {
+    /// Vanilla `Stats.BLOCK_MINED`.
+    #[must_use]
+    pub fn mined(block: BlockRef) -> Self {
+        Self::new(StatType::Mined, block.id())
+    }
+
+    /// Vanilla `Stats.ITEM_CRAFTED`.
+    #[must_use]
+    pub fn crafted(item: ItemRef) -> Self {
+        Self::new(StatType::Crafted, item.id())
+    }
+
+    /// Vanilla `Stats.ITEM_USED`.
+    #[must_use]
+    pub fn used(item: ItemRef) -> Self {
+        Self::new(StatType::Used, item.id())
+    }
+
+    /// Vanilla `Stats.ITEM_BROKEN`.
+    #[must_use]
+    pub fn broken(item: ItemRef) -> Self {
+        Self::new(StatType::Broken, item.id())
+    }
+
+    /// Vanilla `Stats.ITEM_PICKED_UP`.
+    #[must_use]
+    pub fn picked_up(item: ItemRef) -> Self {
+        Self::new(StatType::PickedUp, item.id())
+    }
+
+    /// Vanilla `Stats.ITEM_DROPPED`.
+    #[must_use]
+    pub fn dropped(item: ItemRef) -> Self {
+        Self::new(StatType::Dropped, item.id())
+    }
+
+    /// Vanilla `Stats.ENTITY_KILLED`.
+    #[must_use]
+    pub fn killed(entity_type: EntityTypeRef) -> Self {
+        Self::new(StatType::Killed, entity_type.id())
+    }
+
+    /// Vanilla `Stats.ENTITY_KILLED_BY`.
+    #[must_use]
+    pub fn killed_by(entity_type: EntityTypeRef) -> Self {
+        Self::new(StatType::KilledBy, entity_type.id())
+    }
+
+    /// Vanilla `Stats.CUSTOM`.
+    #[must_use]
+    pub const fn custom(stat: CustomStat) -> Self {
+        Self::new(StatType::Custom, stat as usize)
+    }
+
+    const fn new(stat_type: StatType, value: usize) -> Self {
+        Self { stat_type, value }
+    }
+
+    /// Returns whether clients know the counted entry. Stats of custom items
+    /// are tracked and saved but never sent.
+    #[must_use]
+    pub fn is_synced(&self) -> bool {
+        match self.stat_type {
+            StatType::Crafted
+            | StatType::Used
+            | StatType::Broken
+            | StatType::PickedUp
+            | StatType::Dropped => REGISTRY
+                .items
+                .by_id(self.value)
+                .is_some_and(|item| item.client_item.is_none()),
+            _ => true,
+        }
+    }
+
+    /// Returns the key of the counted entry, e.g. `minecraft:stone` for a mined stat.
+    #[must_use]
+    pub fn value_key(&self) -> Option<Identifier> {
+        match self.stat_type {
+            StatType::Mined => REGISTRY.blocks.by_id(self.value).map(|b| b.key().clone()),
+            StatType::Crafted
+            | StatType::Used
+            | StatType::Broken
+            | StatType::PickedUp
+            | StatType::Dropped => REGISTRY.items.by_id(self.value).map(|i| i.key().clone()),
+            StatType::Killed | StatType::KilledBy => REGISTRY
+                .entity_types
+                .by_id(self.value)
+                .map(|e| e.key().clone()),
+            StatType::Custom => CustomStat::ALL
+                .get(self.value)
+                .map(|stat| Identifier::vanilla_static(stat.key())),
+        }
+    }
+
+    /// Looks a stat up by its type and value keys, as stored in vanilla stat files.
+    #[must_use]
+    pub fn from_keys(stat_type: &Identifier, value: &Identifier) -> Option<Self> {
+        let stat_type = StatType::from_key(stat_type)?;
+        let value = match stat_type {
+            StatType::Mined => REGISTRY.blocks.id_from_key(value)?,
+            StatType::Crafted
+            | StatType::Used
+            | StatType::Broken
+            | StatType::PickedUp
+            | StatType::Dropped => REGISTRY.items.id_from_key(value)?,
+            StatType::Killed | StatType::KilledBy => REGISTRY.entity_types.id_from_key(value)?,
+            StatType::Custom => CustomStat::from_key(value)? as usize,
+        };
+        Some(Self::new(stat_type, value))
+    }
+}
+
+impl WriteTo for Stat {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        VarInt(self.stat_type.id()).write(writer)?;
+        let value =
+            i32::try_from(self.value).map_err(|_| Error::other("stat value id out of range"))?;
+        VarInt(value).write(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_stat_keys_roundtrip() {
+        let key = Identifier::vanilla_static("play_time");
+
+        assert_eq!(CustomStat::from_key(&key), Some(CustomStat::PlayTime));
+        assert_eq!(CustomStat::PlayTime.id(), 1);
+        assert_eq!(
+            Stat::custom(CustomStat::CrouchTime).value_key(),
+            Some(Identifier::vanilla_static("sneak_time"))
+        );
+    }
+}
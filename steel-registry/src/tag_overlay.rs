@@ -0,0 +1,86 @@
+//! Runtime tag contents layered over the frozen registry tags.
+//!
+//! Datapacks resolve the final contents of every tag they touch into a
+//! [`TagOverlay`]; tagged registries that opt in consult it before their own
+//! tags. A reload swaps the whole overlay at once, so lookups never observe a
+//! half-applied datapack.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock};
+
+use rustc_hash::FxHashMap;
+use steel_utils::Identifier;
+use steel_utils::locks::SyncRwLock;
+
+/// Set while an overlay is installed, so registries skip the lock otherwise.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static OVERLAY: LazyLock<SyncRwLock<Option<Arc<TagOverlay>>>> =
+    LazyLock::new(|| SyncRwLock::new(None));
+
+/// Resolved tag contents per registry, replacing the registry's own contents.
+#[derive(Debug, Default)]
+pub struct TagOverlay {
+    tags: FxHashMap<Identifier, FxHashMap<Identifier, Vec<Identifier>>>,
+}
+
+impl TagOverlay {
+    /// Sets the resolved contents of `tag` in the registry `registry`.
+    pub fn insert(&mut self, registry: Identifier, tag: Identifier, entries: Vec<Identifier>) {
+        self.tags.entry(registry).or_default().insert(tag, entries);
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tags.values().all(FxHashMap::is_empty)
+    }
+
+    /// Returns how many tags the overlay replaces or adds.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tags.values().map(FxHashMap::len).sum()
+    }
+
+    /// Returns the overlaid tags of `registry`.
+    pub fn tags(
+        &self,
+        registry: &Identifier,
+    ) -> impl Iterator<Item = (&Identifier, &[Identifier])> {
+        self.tags
+            .get(registry)
+            .into_iter()
+            .flatten()
+            .map(|(tag, entries)| (tag, entries.as_slice()))
+    }
+}
+
+/// Installs `overlay`, replacing the previous one. An empty overlay restores
+/// the registry tags.
+pub fn set_tag_overlay(overlay: TagOverlay) {
+    let overlay = (!overlay.is_empty()).then(|| Arc::new(overlay));
+    let active = overlay.is_some();
+    *OVERLAY.write() = overlay;
+    ACTIVE.store(active, Ordering::Release);
+}
+
+/// Returns the installed overlay, if any.
+#[must_use]
+pub fn tag_overlay() -> Option<Arc<TagOverlay>> {
+    if !ACTIVE.load(Ordering::Acquire) {
+        return None;
+    }
+    OVERLAY.read().clone()
+}
+
+/// Calls `f` with the overlaid contents of `tag`, if the overlay replaces it.
+pub fn with_overlaid_tag<R>(
+    registry: &Identifier,
+    tag: &Identifier,
+    f: impl FnOnce(&[Identifier]) -> R,
+) -> Option<R> {
+    if !ACTIVE.load(Ordering::Acquire) {
+        return None;
+    }
+    let overlay = OVERLAY.read();
+    let entries = overlay.as_ref()?.tags.get(registry)?.get(tag)?;
+    Some(f(entries))
+}
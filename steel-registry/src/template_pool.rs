@@ -121,6 +121,27 @@ pub enum JointType {
     Aligned,
 }
 
+impl JointType {
+    /// The serialized name used in jigsaw block NBT and packets.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Rollable => "rollable",
+            Self::Aligned => "aligned",
+        }
+    }
+
+    /// Parses a serialized joint name, matching vanilla's `JointType.CODEC.byName`.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "rollable" => Some(Self::Rollable),
+            "aligned" => Some(Self::Aligned),
+            _ => None,
+        }
+    }
+}
+
 /// A jigsaw connector block extracted from a structure template.
 #[derive(Debug, Clone)]
 pub struct JigsawBlock {
@@ -0,0 +1,430 @@
+//! Vanilla merchant trade offers (`ItemCost`, `MerchantOffer`, `MerchantOffers`).
+
+use std::io::{Result, Write};
+use std::ops::{Deref, DerefMut};
+
+use simdnbt::borrow::NbtCompound as NbtCompoundView;
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use steel_utils::Identifier;
+use steel_utils::codec::VarInt;
+use steel_utils::nbt::NbtNumeric as _;
+use steel_utils::serial::WriteTo;
+
+use crate::data_component_predicate::{DataComponentExactPredicate, write_registry_id};
+use crate::data_components::DataComponentPatch;
+use crate::item_stack::ItemStack;
+use crate::items::ItemRef;
+use crate::{REGISTRY, RegistryExt};
+
+/// Vanilla `ItemCost`: an item, a count and exact component requirements.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemCost {
+    pub item: ItemRef,
+    pub count: i32,
+    pub components: DataComponentExactPredicate,
+}
+
+impl ItemCost {
+    #[must_use]
+    pub const fn new(item: ItemRef, count: i32) -> Self {
+        Self {
+            item,
+            count,
+            components: DataComponentExactPredicate::EMPTY,
+        }
+    }
+
+    /// Builds the stack a client displays for this cost.
+    #[must_use]
+    pub fn item_stack(&self) -> ItemStack {
+        let mut patch = DataComponentPatch::new();
+        for (entry, value) in self.components.values() {
+            patch.set_raw(entry.key.clone(), value.clone());
+        }
+        ItemStack::with_count_and_patch(self.item, self.count, patch)
+    }
+
+    /// Mirrors vanilla `ItemCost.test`.
+    #[must_use]
+    pub fn test(&self, stack: &ItemStack) -> bool {
+        stack.is(self.item)
+            && self
+                .components
+                .values()
+                .iter()
+                .all(|(entry, value)| stack.get_effective_value_raw(&entry.key) == Some(value))
+    }
+
+    #[must_use]
+    pub fn from_borrowed_compound(compound: &NbtCompoundView<'_, '_>) -> Option<Self> {
+        let id = compound.string("id")?.to_str().parse::<Identifier>().ok()?;
+        let item = REGISTRY.items.by_key(&id)?;
+        let count = match compound.get("count") {
+            Some(tag) => tag.codec_i32().filter(|count| *count > 0)?,
+            None => 1,
+        };
+        let components = match compound.get("components") {
+            Some(tag) => DataComponentExactPredicate::from_owned_nbt(&tag.to_owned())?,
+            None => DataComponentExactPredicate::EMPTY,
+        };
+        Some(Self {
+            item,
+            count,
+            components,
+        })
+    }
+
+    #[must_use]
+    pub fn to_nbt(&self) -> NbtCompound {
+        let mut compound = NbtCompound::new();
+        compound.insert("id", self.item.key.to_string());
+        compound.insert("count", self.count);
+        if !self.components.is_empty() {
+            compound.insert("components", self.components.to_nbt_value());
+        }
+        compound
+    }
+}
+
+impl WriteTo for ItemCost {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        write_registry_id(self.item.network_item(), writer, "item")?;
+        VarInt(self.count).write(writer)?;
+        self.components.write(writer)
+    }
+}
+
+/// Vanilla `MerchantOffer`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MerchantOffer {
+    pub base_cost_a: ItemCost,
+    pub cost_b: Option<ItemCost>,
+    pub result: ItemStack,
+    pub uses: i32,
+    pub max_uses: i32,
+    pub reward_exp: bool,
+    pub special_price_diff: i32,
+    pub demand: i32,
+    pub price_multiplier: f32,
+    pub xp: i32,
+}
+
+impl MerchantOffer {
+    #[must_use]
+    pub const fn new(
+        base_cost_a: ItemCost,
+        cost_b: Option<ItemCost>,
+        result: ItemStack,
+        max_uses: i32,
+        xp: i32,
+        price_multiplier: f32,
+    ) -> Self {
+        Self {
+            base_cost_a,
+            cost_b,
+            result,
+            uses: 0,
+            max_uses,
+            reward_exp: true,
+            special_price_diff: 0,
+            demand: 0,
+            price_multiplier,
+            xp,
+        }
+    }
+
+    /// Returns the first cost with demand and special price adjustments applied.
+    #[must_use]
+    pub fn get_cost_a(&self) -> ItemStack {
+        self.base_cost_a
+            .item_stack()
+            .copy_with_count(self.get_modified_cost_count(&self.base_cost_a))
+    }
+
+    #[must_use]
+    pub fn get_cost_b(&self) -> ItemStack {
+        self.cost_b
+            .as_ref()
+            .map_or_else(ItemStack::empty, ItemCost::item_stack)
+    }
+
+    fn get_modified_cost_count(&self, cost: &ItemCost) -> i32 {
+        let base_count = cost.count;
+        let demand_diff =
+            ((base_count * self.demand) as f32 * self.price_multiplier).floor() as i32;
+        (base_count + demand_diff.max(0) + self.special_price_diff)
+            .clamp(1, cost.item_stack().max_stack_size())
+    }
+
+    #[must_use]
+    pub fn assemble(&self) -> ItemStack {
+        self.result.clone()
+    }
+
+    pub const fn increase_uses(&mut self) {
+        self.uses += 1;
+    }
+
+    pub const fn reset_uses(&mut self) {
+        self.uses = 0;
+    }
+
+    pub const fn update_demand(&mut self) {
+        self.demand = self.demand + self.uses - (self.max_uses - self.uses);
+    }
+
+    pub const fn add_to_special_price_diff(&mut self, add: i32) {
+        self.special_price_diff += add;
+    }
+
+    pub const fn reset_special_price_diff(&mut self) {
+        self.special_price_diff = 0;
+    }
+
+    #[must_use]
+    pub const fn is_out_of_stock(&self) -> bool {
+        self.uses >= self.max_uses
+    }
+
+    pub const fn set_to_out_of_stock(&mut self) {
+        self.uses = self.max_uses;
+    }
+
+    #[must_use]
+    pub const fn needs_restock(&self) -> bool {
+        self.uses > 0
+    }
+
+    /// Mirrors vanilla `MerchantOffer.satisfiedBy`.
+    #[must_use]
+    pub fn satisfied_by(&self, buy_a: &ItemStack, buy_b: &ItemStack) -> bool {
+        if !self.base_cost_a.test(buy_a)
+            || buy_a.count() < self.get_modified_cost_count(&self.base_cost_a)
+        {
+            return false;
+        }
+
+        match &self.cost_b {
+            Some(cost_b) => cost_b.test(buy_b) && buy_b.count() >= cost_b.count,
+            None => buy_b.is_empty(),
+        }
+    }
+
+    /// Consumes the payment from both stacks, returning whether the offer was satisfied.
+    pub fn take(&self, buy_a: &mut ItemStack, buy_b: &mut ItemStack) -> bool {
+        if !self.satisfied_by(buy_a, buy_b) {
+            return false;
+        }
+
+        buy_a.shrink(self.get_cost_a().count());
+        let cost_b = self.get_cost_b();
+        if !cost_b.is_empty() {
+            buy_b.shrink(cost_b.count());
+        }
+        true
+    }
+
+    #[must_use]
+    pub fn from_borrowed_compound(compound: &NbtCompoundView<'_, '_>) -> Option<Self> {
+        let base_cost_a = ItemCost::from_borrowed_compound(&compound.compound("buy")?)?;
+        let cost_b = compound
+            .compound("buyB")
+            .and_then(|cost_b| ItemCost::from_borrowed_compound(&cost_b));
+        let result = ItemStack::from_borrowed_compound(&compound.compound("sell")?)?;
+        let int = |key: &str, default: i32| {
+            compound
+                .get(key)
+                .and_then(|tag| tag.codec_i32())
+                .unwrap_or(default)
+        };
+
+        Some(Self {
+            base_cost_a,
+            cost_b,
+            result,
+            uses: int("uses", 0),
+            max_uses: int("maxUses", 4),
+            reward_exp: compound
+                .get("rewardExp")
+                .and_then(|tag| tag.codec_bool())
+                .unwrap_or(true),
+            special_price_diff: int("specialPrice", 0),
+            demand: int("demand", 0),
+            price_multiplier: compound
+                .get("priceMultiplier")
+                .and_then(|tag| tag.codec_f32())
+                .unwrap_or(0.0),
+            xp: int("xp", 1),
+        })
+    }
+
+    #[must_use]
+    pub fn to_nbt(&self) -> NbtCompound {
+        let mut compound = NbtCompound::new();
+        compound.insert("buy", NbtTag::Compound(self.base_cost_a.to_nbt()));
+        if let Some(cost_b) = &self.cost_b {
+            compound.insert("buyB", NbtTag::Compound(cost_b.to_nbt()));
+        }
+        compound.insert("sell", self.result.to_nbt_tag_ref());
+        compound.insert("uses", self.uses);
+        compound.insert("maxUses", self.max_uses);
+        compound.insert("rewardExp", i8::from(self.reward_exp));
+        compound.insert("specialPrice", self.special_price_diff);
+        compound.insert("demand", self.demand);
+        compound.insert("priceMultiplier", self.price_multiplier);
+        compound.insert("xp", self.xp);
+        compound
+    }
+}
+
+impl WriteTo for MerchantOffer {
+    fn write(&self, writer: &mut impl Write) -> Result<()> {
+        self.base_cost_a.write(writer)?;
+        self.result.write(writer)?;
+        self.cost_b.write(writer)?;
+        self.is_out_of_stock().write(writer)?;
+        self.uses.write(writer)?;
+        self.max_uses.write(writer)?;
+        self.xp.write(writer)?;
+        self.special_price_diff.write(writer)?;
+        self.price_multiplier.write(writer)?;
+        self.demand.write(writer)
+    }
+}
+
+/// Vanilla `MerchantOffers`: the ordered trade list of a merchant.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MerchantOffers(Vec<MerchantOffer>);
+
+impl MerchantOffers {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Mirrors vanilla `MerchantOffers.getRecipeFor`, returning the offer index.
+    #[must_use]
+    pub fn get_recipe_for(
+        &self,
+        buy_a: &ItemStack,
+        buy_b: &ItemStack,
+        selection_hint: usize,
+    ) -> Option<usize> {
+        if selection_hint > 0 && selection_hint < self.0.len() {
+            return self.0[selection_hint]
+                .satisfied_by(buy_a, buy_b)
+                .then_some(selection_hint);
+        }
+
+        self.0
+            .iter()
+            .position(|offer| offer.satisfied_by(buy_a, buy_b))
+    }
+
+    #[must_use]
+    pub fn to_nbt(&self) -> NbtList {
+        NbtList::Compound(self.0.iter().map(MerchantOffer::to_nbt).collect())
+    }
+}
+
+impl Deref for MerchantOffers {
+    type Target = Vec<MerchantOffer>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for MerchantOffers {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl FromIterator<MerchantOffer> for MerchantOffers {
+    fn from_iter<T: IntoIterator<Item = MerchantOffer>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::init_test_registry;
+    use crate::vanilla_items;
+
+    fn emerald_for_wheat() -> MerchantOffer {
+        MerchantOffer::new(
+            ItemCost::new(&vanilla_items::WHEAT, 20),
+            None,
+            ItemStack::new(&vanilla_items::EMERALD),
+            16,
+            2,
+            0.05,
+        )
+    }
+
+    #[test]
+    fn demand_raises_first_cost() {
+        init_test_registry();
+
+        let mut offer = emerald_for_wheat();
+        offer.uses = 16;
+        offer.update_demand();
+
+        assert_eq!(offer.demand, 16);
+        assert_eq!(offer.get_cost_a().count(), 36);
+    }
+
+    #[test]
+    fn special_price_is_clamped_to_one() {
+        init_test_registry();
+
+        let mut offer = emerald_for_wheat();
+        offer.add_to_special_price_diff(-64);
+
+        assert_eq!(offer.get_cost_a().count(), 1);
+    }
+
+    #[test]
+    fn take_consumes_modified_cost() {
+        init_test_registry();
+
+        let offer = emerald_for_wheat();
+        let mut buy_a = ItemStack::with_count(&vanilla_items::WHEAT, 25);
+        let mut buy_b = ItemStack::empty();
+
+        assert!(offer.take(&mut buy_a, &mut buy_b));
+        assert_eq!(buy_a.count(), 5);
+        assert!(!offer.take(&mut buy_a, &mut buy_b));
+    }
+
+    #[test]
+    fn recipe_lookup_honors_selection_hint() {
+        init_test_registry();
+
+        let offers: MerchantOffers = [
+            emerald_for_wheat(),
+            MerchantOffer::new(
+                ItemCost::new(&vanilla_items::WHEAT, 10),
+                None,
+                ItemStack::new(&vanilla_items::BREAD),
+                16,
+                1,
+                0.05,
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let wheat = ItemStack::with_count(&vanilla_items::WHEAT, 12);
+
+        assert_eq!(
+            offers.get_recipe_for(&wheat, &ItemStack::empty(), 0),
+            Some(1)
+        );
+        assert_eq!(
+            offers.get_recipe_for(&wheat, &ItemStack::empty(), 1),
+            Some(1)
+        );
+    }
+}
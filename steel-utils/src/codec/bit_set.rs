@@ -9,6 +9,20 @@ use super::VarInt;
 pub struct BitSet(pub Box<[u64]>);
 
 impl BitSet {
+    /// Creates a bit set with room for `bits` bits, all cleared.
+    #[must_use]
+    pub fn with_capacity(bits: usize) -> Self {
+        Self(vec![0; bits.div_ceil(64)].into_boxed_slice())
+    }
+
+    /// Returns the bit at the given index, `false` when out of range.
+    #[must_use]
+    pub fn get(&self, index: usize) -> bool {
+        self.0
+            .get(index / 64)
+            .is_some_and(|word| word & (1 << (index % 64)) != 0)
+    }
+
     /// Sets the bit at the given index.
     pub fn set(&mut self, index: usize, value: bool) {
         let u64_index = index / 64;
@@ -36,6 +36,24 @@ impl FrontVec {
         Self::capacity(reserve, 0)
     }
 
+    /// Reuses the allocation of `buf` for an empty `FrontVec` with the given reserved front space.
+    #[must_use]
+    pub fn from_vec(mut buf: Vec<u8>, reserve: usize) -> Self {
+        buf.clear();
+        buf.resize(reserve, 0);
+
+        Self {
+            buf,
+            front_space: reserve,
+        }
+    }
+
+    /// Returns the backing buffer, including any unused front space.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<u8> {
+        self.buf
+    }
+
     /// Returns the length of the `FrontVec`.
     #[must_use]
     pub const fn len(&self) -> usize {
@@ -48,6 +66,11 @@ impl FrontVec {
         (self.buf.len() - self.front_space) == 0
     }
 
+    /// Reserves capacity for at least `additional` more bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(additional);
+    }
+
     /// Pushes a value to the back of the `FrontVec`.
     pub fn push(&mut self, value: u8) {
         self.buf.push(value);
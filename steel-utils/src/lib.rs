@@ -28,6 +28,7 @@ pub mod java;
 pub mod locks;
 /// Utilities for Steel logging.
 pub mod logger;
+pub mod mirror;
 /// Vanilla-compatible NBT helpers.
 pub mod nbt;
 pub mod random;
@@ -71,6 +72,7 @@ pub use direction::Direction;
 pub use downcast::{Downcast, DowncastType, DowncastTypeKey, ErasedType};
 pub use front_vec::FrontVec;
 pub use geometry::{BlockLocalAabb, BoundingBox, WorldAabb};
+pub use mirror::Mirror;
 pub use rotation::Rotation;
 pub use types::BlockPos;
 pub use types::BlockStateId;
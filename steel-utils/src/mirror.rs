@@ -0,0 +1,120 @@
+//! Vanilla's `Mirror` — horizontal reflections across a template axis.
+
+use std::io::{self, Cursor};
+
+use crate::Direction;
+use crate::axis::Axis;
+use crate::codec::VarInt;
+use crate::rotation::Rotation;
+use crate::serial::ReadFrom;
+
+/// Horizontal reflection used by structure placement and block state transforms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mirror {
+    /// No mirror transform.
+    None,
+    /// Mirror across the template left/right axis (flips Z).
+    LeftRight,
+    /// Mirror across the template front/back axis (flips X).
+    FrontBack,
+}
+
+const ALL_MIRRORS: [Mirror; 3] = [Mirror::None, Mirror::LeftRight, Mirror::FrontBack];
+
+impl ReadFrom for Mirror {
+    fn read(data: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        let id = VarInt::read(data)?.0;
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| ALL_MIRRORS.get(id).copied())
+            .ok_or_else(|| io::Error::other("Invalid Mirror id"))
+    }
+}
+
+impl Mirror {
+    /// The vanilla enum constant name, as stored in structure block NBT.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::None => "NONE",
+            Self::LeftRight => "LEFT_RIGHT",
+            Self::FrontBack => "FRONT_BACK",
+        }
+    }
+
+    /// Parses a vanilla enum constant name written by [`Self::name`].
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        ALL_MIRRORS.into_iter().find(|mirror| mirror.name() == name)
+    }
+
+    /// Matches vanilla's `Mirror.mirror(Direction)`.
+    ///
+    /// Vertical directions and directions parallel to the mirror plane are unchanged.
+    #[must_use]
+    pub const fn mirror(self, dir: Direction) -> Direction {
+        match (self, dir.get_axis()) {
+            (Self::FrontBack, Axis::X) | (Self::LeftRight, Axis::Z) => dir.opposite(),
+            _ => dir,
+        }
+    }
+
+    /// Matches vanilla's `Mirror.mirror(int rotation, int steps)` for segmented
+    /// rotations such as the 16 sign and skull rotations.
+    #[must_use]
+    pub const fn mirror_rotation(self, rotation: i32, steps: i32) -> i32 {
+        let half_steps = steps / 2;
+        let corrected = if rotation > half_steps {
+            rotation - steps
+        } else {
+            rotation
+        };
+        match self {
+            Self::LeftRight => (half_steps - corrected + steps) % steps,
+            Self::FrontBack => (steps - corrected) % steps,
+            Self::None => rotation,
+        }
+    }
+
+    /// Matches vanilla's `Mirror.getRotation(Direction)`: the rotation that has the
+    /// same effect as this mirror on `dir`.
+    #[must_use]
+    pub const fn get_rotation(self, dir: Direction) -> Rotation {
+        match (self, dir.get_axis()) {
+            (Self::FrontBack, Axis::X) | (Self::LeftRight, Axis::Z) => Rotation::Clockwise180,
+            _ => Rotation::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirror_direction_flips_only_the_mirrored_axis() {
+        assert_eq!(Mirror::FrontBack.mirror(Direction::East), Direction::West);
+        assert_eq!(Mirror::FrontBack.mirror(Direction::North), Direction::North);
+        assert_eq!(Mirror::LeftRight.mirror(Direction::North), Direction::South);
+        assert_eq!(Mirror::LeftRight.mirror(Direction::East), Direction::East);
+        assert_eq!(Mirror::None.mirror(Direction::West), Direction::West);
+        assert_eq!(Mirror::LeftRight.mirror(Direction::Up), Direction::Up);
+    }
+
+    #[test]
+    fn mirror_rotation_matches_vanilla_segments() {
+        assert_eq!(Mirror::FrontBack.mirror_rotation(4, 16), 12);
+        assert_eq!(Mirror::FrontBack.mirror_rotation(0, 16), 0);
+        assert_eq!(Mirror::LeftRight.mirror_rotation(0, 16), 8);
+        assert_eq!(Mirror::LeftRight.mirror_rotation(4, 16), 4);
+        assert_eq!(Mirror::None.mirror_rotation(7, 16), 7);
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for mirror in ALL_MIRRORS {
+            assert_eq!(Mirror::from_name(mirror.name()), Some(mirror));
+        }
+        assert_eq!(Mirror::from_name("left_right"), None);
+    }
+}
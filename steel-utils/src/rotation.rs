@@ -1,10 +1,14 @@
 //! Vanilla's `Rotation` — horizontal rotations around the Y axis.
 
+use std::io::{self, Cursor};
+
 use glam::IVec3;
 
 use crate::Direction;
+use crate::codec::VarInt;
 use crate::geometry::BoundingBox;
 use crate::random::Random;
+use crate::serial::ReadFrom;
 
 /// Horizontal rotation around the Y axis.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,7 +30,36 @@ const ALL_ROTATIONS: [Rotation; 4] = [
     Rotation::CounterClockwise90,
 ];
 
+impl ReadFrom for Rotation {
+    fn read(data: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        let id = VarInt::read(data)?.0;
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| ALL_ROTATIONS.get(id).copied())
+            .ok_or_else(|| io::Error::other("Invalid Rotation id"))
+    }
+}
+
 impl Rotation {
+    /// The vanilla enum constant name, as stored in structure block NBT.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::None => "NONE",
+            Self::Clockwise90 => "CLOCKWISE_90",
+            Self::Clockwise180 => "CLOCKWISE_180",
+            Self::CounterClockwise90 => "COUNTERCLOCKWISE_90",
+        }
+    }
+
+    /// Parses a vanilla enum constant name written by [`Self::name`].
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        ALL_ROTATIONS
+            .into_iter()
+            .find(|rotation| rotation.name() == name)
+    }
+
     /// Matches vanilla's `Rotation.getRandom(random)`.
     #[must_use]
     pub fn get_random(rng: &mut impl Random) -> Self {
@@ -20,6 +20,10 @@ pub mod names {
 
     /// Vanilla `TicketStorage.TYPE`, persisted as `data/chunk_tickets.toml`.
     pub const CHUNK_TICKETS: SavedDataName = SavedDataName::trusted("chunk_tickets");
+    /// Vanilla `Raids.TYPE`, persisted as `data/raids.toml`.
+    pub const RAIDS: SavedDataName = SavedDataName::trusted("raids");
+    /// Vanilla `EndDragonFight.Data`, persisted as `data/ender_dragon_fight.toml`.
+    pub const ENDER_DRAGON_FIGHT: SavedDataName = SavedDataName::trusted("ender_dragon_fight");
     /// Cached concentric-ring positions, persisted as `data/structure_rings.bin`.
     pub const STRUCTURE_RINGS: WincodeSavedDataName =
         WincodeSavedDataName::trusted("structure_rings", *b"STLR", 2);
@@ -27,6 +31,8 @@ pub mod names {
     pub const SCOREBOARD: SavedDataName = SavedDataName::trusted("scoreboard");
     /// Domain command storage, persisted through the domain default world.
     pub const COMMAND_STORAGE: SavedDataName = SavedDataName::trusted("command_storage");
+    /// Domain filled-map data, persisted through the domain default world.
+    pub const MAPS: WincodeSavedDataName = WincodeSavedDataName::trusted("maps", *b"STLM", 1);
 }
 
 /// Name of a per-world saved data entry.
@@ -0,0 +1,67 @@
+use std::io::{Cursor, Error, Result};
+use std::mem;
+
+use bytes::{Buf, Bytes};
+
+use crate::serial::{ReadFrom, ReadFromBytes};
+
+/// Runs a cursor-based `read` over the front of `data`, then advances past what it consumed.
+pub fn read_with_cursor<T>(
+    data: &mut Bytes,
+    read: impl FnOnce(&mut Cursor<&[u8]>) -> Result<T>,
+) -> Result<T> {
+    let mut cursor = Cursor::new(data.as_ref());
+    let value = read(&mut cursor)?;
+    let consumed = cursor.position() as usize;
+    data.advance(consumed);
+    Ok(value)
+}
+
+/// Reads a length prefix and slices that many bytes out of `data` without copying.
+pub fn read_prefixed_bytes<P: TryInto<usize> + ReadFrom>(
+    data: &mut Bytes,
+    bound: usize,
+) -> Result<Bytes> {
+    let len: usize = P::read_from_bytes(data)?
+        .try_into()
+        .map_err(|_| Error::other("Invalid Prefix"))?;
+
+    if len > bound {
+        Err(Error::other("To long"))?;
+    }
+    if len > data.len() {
+        Err(Error::other("Not enough bytes"))?;
+    }
+    Ok(data.split_to(len))
+}
+
+impl<T: ReadFrom> ReadFromBytes for T {
+    fn read_from_bytes(data: &mut Bytes) -> Result<Self> {
+        read_with_cursor(data, T::read)
+    }
+}
+
+/// Takes the rest of the buffer, like vanilla's unprefixed trailing payloads.
+impl ReadFromBytes for Bytes {
+    fn read_from_bytes(data: &mut Bytes) -> Result<Self> {
+        Ok(mem::take(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::VarInt;
+
+    #[test]
+    fn prefixed_bytes_share_the_source_buffer() {
+        let source = Bytes::from_static(&[3, b'a', b'b', b'c', 7]);
+        let mut data = source.clone();
+
+        let run = read_prefixed_bytes::<VarInt>(&mut data, 16).expect("run should read");
+        assert_eq!(run.as_ref(), b"abc");
+        assert_eq!(run.as_ptr(), source[1..].as_ptr());
+        assert_eq!(u8::read_from_bytes(&mut data).expect("trailing byte"), 7);
+        assert!(read_prefixed_bytes::<VarInt>(&mut Bytes::from_static(&[5, 0]), 16).is_err());
+    }
+}
@@ -1,6 +1,10 @@
 //! This module contains traits for serializing and deserializing data.
 use std::io::{Cursor, Result, Write};
 
+use bytes::Bytes;
+
+/// A module for reading data out of shared buffers.
+pub mod bytes_read;
 /// A module for reading prefixed data.
 pub mod prefixed_read;
 /// A module for writing prefixed data.
@@ -10,6 +14,7 @@ pub mod read;
 /// A module for writing data.
 pub mod write;
 
+pub use bytes_read::{read_prefixed_bytes, read_with_cursor};
 pub use write::OptionalNbt;
 
 const DEFAULT_BOUND: usize = i16::MAX as _;
@@ -20,6 +25,15 @@ pub trait ReadFrom: Sized {
     fn read(data: &mut Cursor<&[u8]>) -> Result<Self>;
 }
 
+/// A trait for reading data out of a shared [`Bytes`] buffer.
+///
+/// Every [`ReadFrom`] type implements it by reading through a cursor. Large byte
+/// runs implement it directly so they are sliced out of the buffer, not copied.
+pub trait ReadFromBytes: Sized {
+    /// Reads data from the front of `data`, advancing past it.
+    fn read_from_bytes(data: &mut Bytes) -> Result<Self>;
+}
+
 /// A trait for writing data to a writer.
 pub trait WriteTo {
     /// Writes data to a writer.
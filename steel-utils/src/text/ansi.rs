@@ -0,0 +1,215 @@
+//! ANSI rendering for the console.
+//!
+//! Colors map to the 16 standard terminal colors, hex colors to 24-bit
+//! escapes. Obfuscated text is shown as is. Translations resolve through
+//! [`DisplayResolutor`].
+
+use std::fmt::Write as _;
+
+use text_components::{
+    TextComponent,
+    content::Content,
+    format::{Color, Format},
+    resolving::TextResolutor,
+};
+
+use super::DisplayResolutor;
+
+const RESET: &str = "\x1b[0m";
+
+/// The style a piece of text ends up with after inheriting from its parents.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct AnsiStyle {
+    color: Option<Color>,
+    bold: bool,
+    italic: bool,
+    underlined: bool,
+    strikethrough: bool,
+}
+
+impl AnsiStyle {
+    fn inherit(&self, format: &Format) -> Self {
+        Self {
+            color: format.color.clone().or_else(|| self.color.clone()),
+            bold: format.bold.unwrap_or(self.bold),
+            italic: format.italic.unwrap_or(self.italic),
+            underlined: format.underlined.unwrap_or(self.underlined),
+            strikethrough: format.strikethrough.unwrap_or(self.strikethrough),
+        }
+    }
+
+    fn write_escape(&self, out: &mut String) {
+        out.push_str("\x1b[0");
+        for (on, code) in [
+            (self.bold, "1"),
+            (self.italic, "3"),
+            (self.underlined, "4"),
+            (self.strikethrough, "9"),
+        ] {
+            if on {
+                out.push(';');
+                out.push_str(code);
+            }
+        }
+        match &self.color {
+            Some(Color::Rgb(r, g, b)) => {
+                let _ = write!(out, ";38;2;{r};{g};{b}");
+            }
+            Some(color) => {
+                let _ = write!(out, ";{}", ansi_color(color));
+            }
+            None => {}
+        }
+        out.push('m');
+    }
+}
+
+const fn ansi_color(color: &Color) -> u8 {
+    match color {
+        Color::Black => 30,
+        Color::DarkBlue => 34,
+        Color::DarkGreen => 32,
+        Color::DarkAqua => 36,
+        Color::DarkRed => 31,
+        Color::DarkPurple => 35,
+        Color::Gold => 33,
+        Color::Gray => 37,
+        Color::DarkGray => 90,
+        Color::Blue => 94,
+        Color::Green => 92,
+        Color::Aqua => 96,
+        Color::Red => 91,
+        Color::LightPurple => 95,
+        Color::Yellow => 93,
+        Color::White | Color::Rgb(..) => 97,
+    }
+}
+
+struct AnsiWriter {
+    out: String,
+    /// Style of the last text written, to skip redundant escapes.
+    current: AnsiStyle,
+}
+
+impl AnsiWriter {
+    fn text(&mut self, style: &AnsiStyle, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        if *style != self.current {
+            style.write_escape(&mut self.out);
+            self.current = style.clone();
+        }
+        self.out.push_str(text);
+    }
+
+    fn component(&mut self, component: &TextComponent, parent: &AnsiStyle) {
+        let style = parent.inherit(&component.format);
+        match &component.content {
+            Content::Text { text } => self.text(&style, text),
+            Content::Translate(message) => {
+                let args = message.args.as_deref().unwrap_or_default();
+                match DisplayResolutor.translate(&message.key) {
+                    Some(format) => self.translation(&format, args, &style),
+                    None => {
+                        let fallback = message.fallback.as_deref().unwrap_or(&message.key);
+                        self.text(&style, fallback);
+                    }
+                }
+            }
+            content => {
+                let plain = TextComponent {
+                    content: content.clone(),
+                    ..Default::default()
+                }
+                .to_plain(&DisplayResolutor);
+                self.text(&style, &plain);
+            }
+        }
+        for child in &component.children {
+            self.component(child, &style);
+        }
+    }
+
+    /// Fills in `%s` and `%1$s` the way vanilla `TranslatableContents` does.
+    fn translation(&mut self, format: &str, args: &[TextComponent], style: &AnsiStyle) {
+        let mut next_arg = 0;
+        let mut rest = format;
+        while let Some(index) = rest.find('%') {
+            self.text(style, &rest[..index]);
+            let spec = &rest[index + 1..];
+            if let Some(after) = spec.strip_prefix('%') {
+                self.text(style, "%");
+                rest = after;
+                continue;
+            }
+            let digits = spec.bytes().take_while(u8::is_ascii_digit).count();
+            let (arg, after) = if let Some(after) = spec[digits..].strip_prefix("$s")
+                && digits > 0
+            {
+                (
+                    spec[..digits]
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|n| n.checked_sub(1)),
+                    after,
+                )
+            } else if let Some(after) = spec.strip_prefix('s') {
+                next_arg += 1;
+                (Some(next_arg - 1), after)
+            } else {
+                self.text(style, "%");
+                rest = spec;
+                continue;
+            };
+            if let Some(arg) = arg.and_then(|arg| args.get(arg)) {
+                self.component(arg, style);
+            }
+            rest = after;
+        }
+        self.text(style, rest);
+    }
+}
+
+/// Renders a component as text with ANSI escapes for its colors and
+/// decorations, ending with a reset.
+#[must_use]
+pub fn to_ansi(component: &TextComponent) -> String {
+    let mut writer = AnsiWriter {
+        out: String::new(),
+        current: AnsiStyle::default(),
+    };
+    writer.component(component, &AnsiStyle::default());
+    if writer.current != AnsiStyle::default() {
+        writer.out.push_str(RESET);
+    }
+    writer.out
+}
+
+#[cfg(test)]
+mod tests {
+    use text_components::{Modifier as _, translation::TranslatedMessage};
+
+    use super::*;
+
+    #[test]
+    fn renders_inherited_styles_and_translation_arguments() {
+        let component = TextComponent::plain("Hi ")
+            .color(Color::Red)
+            .add_child(TextComponent::plain("bold").bold(true))
+            .add_child(TextComponent::plain(" #").color(Color::Rgb(1, 2, 3)));
+        assert_eq!(
+            to_ansi(&component),
+            "\x1b[0;91mHi \x1b[0;1;91mbold\x1b[0;38;2;1;2;3m #\x1b[0m"
+        );
+
+        let joined = TextComponent::translated(TranslatedMessage {
+            key: "multiplayer.player.joined".into(),
+            fallback: None,
+            args: Some(Box::new([
+                TextComponent::plain("Steve").color(Color::Yellow)
+            ])),
+        });
+        assert_eq!(to_ansi(&joined), "\x1b[0;93mSteve\x1b[0m joined the game");
+    }
+}
@@ -0,0 +1,173 @@
+//! Vanilla JSON text format.
+//!
+//! Since 1.21.5 the JSON and NBT forms of a component use the same codec, so
+//! JSON is read by converting it to NBT and decoding that. This covers every
+//! content type and event the NBT decoder does, including item and entity
+//! hovers, score and selector components and nested `with` arguments.
+
+use std::mem;
+
+use serde_json::{Map, Number, Value};
+use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+use text_components::TextComponent;
+
+/// Parses a component from vanilla JSON text.
+pub fn component_from_json(json: &str) -> Result<TextComponent, String> {
+    let value: Value = serde_json::from_str(json).map_err(|error| error.to_string())?;
+    component_from_json_value(&value)
+}
+
+/// Parses a component from a JSON value, such as a `pack.mcmeta`
+/// description.
+///
+/// A list is read as its first element with the others appended, and numbers
+/// and booleans as their text, like vanilla's `ComponentSerialization`.
+pub fn component_from_json_value(value: &Value) -> Result<TextComponent, String> {
+    match value {
+        Value::Null => Err("null is not a text component".to_owned()),
+        Value::Bool(_) | Value::Number(_) => Ok(TextComponent::plain(value.to_string())),
+        Value::Array(values) => {
+            let mut components = values
+                .iter()
+                .map(component_from_json_value)
+                .collect::<Result<Vec<_>, _>>()?;
+            if components.is_empty() {
+                return Err("empty list is not a text component".to_owned());
+            }
+            let first = components.remove(0);
+            Ok(first.add_children(components))
+        }
+        Value::String(_) | Value::Object(_) => {
+            let tag = json_to_nbt(value)?;
+            TextComponent::try_from_nbt(&tag).map_err(|error| error.to_string())
+        }
+    }
+}
+
+fn json_to_nbt(value: &Value) -> Result<NbtTag, String> {
+    Ok(match value {
+        Value::Null => return Err("unexpected null".to_owned()),
+        Value::Bool(value) => NbtTag::Byte(i8::from(*value)),
+        Value::Number(number) => number_to_nbt(number)?,
+        Value::String(value) => NbtTag::String(value.as_str().into()),
+        Value::Array(values) => NbtTag::List(json_list_to_nbt(values)?),
+        Value::Object(object) => NbtTag::Compound(json_object_to_nbt(object)?),
+    })
+}
+
+fn json_object_to_nbt(object: &Map<String, Value>) -> Result<NbtCompound, String> {
+    let mut compound = NbtCompound::new();
+    for (key, value) in object {
+        compound.insert(key.as_str(), json_to_nbt(value)?);
+    }
+    Ok(compound)
+}
+
+fn number_to_nbt(number: &Number) -> Result<NbtTag, String> {
+    if let Some(value) = number.as_i64() {
+        return Ok(match i32::try_from(value) {
+            Ok(value) => NbtTag::Int(value),
+            Err(_) => NbtTag::Long(value),
+        });
+    }
+    number
+        .as_f64()
+        .map(NbtTag::Double)
+        .ok_or_else(|| format!("unsupported number {number}"))
+}
+
+/// Converts a list, turning mixed component lists such as
+/// `["a", {"text": "b"}]` into compounds so the list stays homogeneous.
+fn json_list_to_nbt(values: &[Value]) -> Result<NbtList, String> {
+    let tags = values
+        .iter()
+        .map(json_to_nbt)
+        .collect::<Result<Vec<_>, _>>()?;
+    let Some(first) = tags.first().map(mem::discriminant) else {
+        return Ok(NbtList::Empty);
+    };
+    if tags.iter().all(|tag| mem::discriminant(tag) == first) {
+        return Ok(NbtList::from(tags));
+    }
+    let compounds = values
+        .iter()
+        .zip(tags)
+        .map(|(value, tag)| match tag {
+            NbtTag::Compound(compound) => compound,
+            _ => {
+                let text = value
+                    .as_str()
+                    .map_or_else(|| value.to_string(), str::to_owned);
+                let mut compound = NbtCompound::new();
+                compound.insert("text", NbtTag::String(text.as_str().into()));
+                compound
+            }
+        })
+        .collect();
+    Ok(NbtList::Compound(compounds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::DisplayResolutor;
+    use text_components::{
+        content::{Content, Resolvable},
+        interactivity::HoverEvent,
+    };
+
+    #[test]
+    fn parses_nested_arguments_resolvables_and_entity_hovers() {
+        let component = component_from_json(
+            r#"{
+                "translate": "chat.type.text",
+                "with": [
+                    {"selector": "@p", "hover_event": {
+                        "action": "show_entity",
+                        "id": "minecraft:pig",
+                        "uuid": [1, 2, 3, 4],
+                        "name": "Pig"
+                    }},
+                    {"translate": "multiplayer.player.joined", "with": ["Steve"]}
+                ],
+                "extra": [" ", {"score": {"name": "@s", "objective": "kills"}}, "!"]
+            }"#,
+        )
+        .expect("component should parse");
+
+        let Content::Translate(message) = &component.content else {
+            panic!("root should be translatable");
+        };
+        let args = message.args.as_deref().unwrap_or_default();
+        assert_eq!(args.len(), 2);
+        assert!(matches!(
+            args[0].content,
+            Content::Resolvable(Resolvable::Entity { .. })
+        ));
+        assert!(matches!(
+            args[0].interactions.hover,
+            Some(HoverEvent::ShowEntity { .. })
+        ));
+        assert!(matches!(args[1].content, Content::Translate(_)));
+        assert_eq!(component.children.len(), 3);
+        assert!(matches!(
+            component.children[1].content,
+            Content::Resolvable(Resolvable::Scoreboard { .. })
+        ));
+
+        let json = serde_json::to_string(&component).expect("component should serialize");
+        let reparsed = component_from_json(&json).expect("serialized component should parse");
+        assert_eq!(reparsed, component);
+        let nbt = component.to_codec_nbt();
+        assert_eq!(TextComponent::try_from_nbt(&nbt).ok(), Some(component));
+    }
+
+    #[test]
+    fn parses_lists_and_primitives() {
+        let component = component_from_json(r#"["Hello", {"text": " world", "bold": true}, 1]"#)
+            .expect("list should parse");
+        assert_eq!(component.to_plain(&DisplayResolutor), "Hello world1");
+        assert_eq!(component.children[0].format.bold, Some(true));
+        assert!(component_from_json("null").is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! Legacy `§` formatting codes.
+//!
+//! `§c` and friends predate components but are still common in configs and
+//! plugin messages. A color code resets the decorations before it, as it does
+//! in vanilla, and `§r` resets everything. Hex colors use the `§x§r§r§g§g§b§b`
+//! form or, with `&`, the shorter `&#rrggbb`.
+
+use std::mem;
+
+use text_components::{TextComponent, format::Color};
+
+/// The section sign vanilla uses for formatting codes.
+pub const LEGACY_SECTION: char = '§';
+/// The ampersand most configs use in place of [`LEGACY_SECTION`].
+pub const LEGACY_AMPERSAND: char = '&';
+
+#[derive(Debug, Clone, Default)]
+struct LegacyStyle {
+    color: Option<Color>,
+    bold: bool,
+    italic: bool,
+    underlined: bool,
+    strikethrough: bool,
+    obfuscated: bool,
+}
+
+impl LegacyStyle {
+    fn apply(&self, text: String) -> TextComponent {
+        let mut component = TextComponent::plain(text);
+        component.format.color.clone_from(&self.color);
+        let flag = |on: bool| on.then_some(true);
+        component.format.bold = flag(self.bold);
+        component.format.italic = flag(self.italic);
+        component.format.underlined = flag(self.underlined);
+        component.format.strikethrough = flag(self.strikethrough);
+        component.format.obfuscated = flag(self.obfuscated);
+        component
+    }
+}
+
+const fn legacy_color(code: char) -> Option<Color> {
+    Some(match code {
+        '0' => Color::Black,
+        '1' => Color::DarkBlue,
+        '2' => Color::DarkGreen,
+        '3' => Color::DarkAqua,
+        '4' => Color::DarkRed,
+        '5' => Color::DarkPurple,
+        '6' => Color::Gold,
+        '7' => Color::Gray,
+        '8' => Color::DarkGray,
+        '9' => Color::Blue,
+        'a' => Color::Green,
+        'b' => Color::Aqua,
+        'c' => Color::Red,
+        'd' => Color::LightPurple,
+        'e' => Color::Yellow,
+        'f' => Color::White,
+        _ => return None,
+    })
+}
+
+/// Reads the six hex digits of a hex color, each preceded by `prefix` when
+/// `prefixed` is set. Returns the color and the number of bytes read.
+fn hex_color(input: &str, prefix: char, prefixed: bool) -> Option<(Color, usize)> {
+    let mut digits = String::with_capacity(6);
+    let mut chars = input.chars();
+    let mut read = 0;
+    for _ in 0..6 {
+        if prefixed {
+            if chars.next()? != prefix {
+                return None;
+            }
+            read += prefix.len_utf8();
+        }
+        let digit = chars.next().filter(char::is_ascii_hexdigit)?;
+        digits.push(digit);
+        read += 1;
+    }
+    let [_, r, g, b] = u32::from_str_radix(&digits, 16).ok()?.to_be_bytes();
+    Some((Color::Rgb(r, g, b), read))
+}
+
+/// Parses text with legacy formatting codes introduced by `prefix`, usually
+/// [`LEGACY_SECTION`] or [`LEGACY_AMPERSAND`]. Unknown codes are kept as text.
+#[must_use]
+pub fn parse_legacy(input: &str, prefix: char) -> TextComponent {
+    let mut children = Vec::new();
+    let mut style = LegacyStyle::default();
+    let mut text = String::new();
+    let mut rest = input;
+    while let Some(index) = rest.find(prefix) {
+        text.push_str(&rest[..index]);
+        let after = &rest[index + prefix.len_utf8()..];
+        let Some(code) = after.chars().next().map(|code| code.to_ascii_lowercase()) else {
+            rest = after;
+            text.push(prefix);
+            break;
+        };
+        let code_end = code.len_utf8();
+        let hex = match code {
+            'x' => hex_color(&after[code_end..], prefix, true),
+            '#' if prefix == LEGACY_AMPERSAND => hex_color(&after[code_end..], prefix, false),
+            _ => None,
+        };
+        let mut next = style.clone();
+        let consumed = if let Some((color, read)) = hex {
+            next = LegacyStyle {
+                color: Some(color),
+                ..LegacyStyle::default()
+            };
+            code_end + read
+        } else if let Some(color) = legacy_color(code) {
+            next = LegacyStyle {
+                color: Some(color),
+                ..LegacyStyle::default()
+            };
+            code_end
+        } else {
+            match code {
+                'k' => next.obfuscated = true,
+                'l' => next.bold = true,
+                'm' => next.strikethrough = true,
+                'n' => next.underlined = true,
+                'o' => next.italic = true,
+                'r' => next = LegacyStyle::default(),
+                _ => {
+                    text.push(prefix);
+                    rest = after;
+                    continue;
+                }
+            }
+            code_end
+        };
+        if !text.is_empty() {
+            children.push(style.apply(mem::take(&mut text)));
+        }
+        style = next;
+        rest = &after[consumed..];
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        children.push(style.apply(text));
+    }
+    match children.len() {
+        1 => children.remove(0),
+        _ => TextComponent::new().add_children(children),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::DisplayResolutor;
+
+    #[test]
+    fn color_codes_reset_decorations_and_unknown_codes_stay_text() {
+        let component = parse_legacy("&l&cHi &lthere&r &#ff8800orange &z", LEGACY_AMPERSAND);
+        assert_eq!(component.to_plain(&DisplayResolutor), "Hi there orange &z");
+        let children = &component.children;
+        assert_eq!(children[0].format.color, Some(Color::Red));
+        assert_eq!(children[0].format.bold, None);
+        assert_eq!(children[1].format.bold, Some(true));
+        assert_eq!(children[2].format.color, None);
+        assert_eq!(children[3].format.color, Some(Color::Rgb(0xff, 0x88, 0x00)));
+
+        let hex = parse_legacy("§x§1§2§3§4§5§6Hex", LEGACY_SECTION);
+        assert_eq!(hex.format.color, Some(Color::Rgb(0x12, 0x34, 0x56)));
+    }
+}
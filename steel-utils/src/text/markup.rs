@@ -0,0 +1,607 @@
+//! MiniMessage-like text markup.
+//!
+//! `<red><bold>Hi</bold> <click:run_command:/spawn>go</click>` parses into a
+//! [`TextComponent`] tree. Supported tags:
+//!
+//! - Colors: `<red>`, `<color:dark_aqua>` and `<#ff8800>`.
+//! - Decorations: `<bold>`/`<b>`, `<italic>`/`<i>`/`<em>`, `<underlined>`/`<u>`,
+//!   `<strikethrough>`/`<st>` and `<obfuscated>`/`<obf>`. `<!bold>` turns a
+//!   decoration off.
+//! - `<click:action:value>`, `<hover:show_text:'markup'>`, `<insert:text>` and
+//!   `<font:id>`.
+//! - `<lang:key:'arg'...>`, `<key:keybind>`, `<newline>`/`<br>` and `<reset>`.
+//!
+//! `</tag>` closes the innermost open tag of the same kind and everything
+//! opened inside it, `</>` closes the innermost tag. Arguments may be quoted
+//! with `'` or `"`, inside which `\` escapes the next character. Outside tags
+//! `\<` is a literal `<`. Unknown or malformed tags are kept as text.
+//!
+//! [`parse_markup_with`] also resolves placeholder tags, such as `<name>`, to
+//! the components given for them.
+
+use std::borrow::Cow;
+use std::fmt::Write as _;
+use std::mem;
+
+use text_components::{
+    Modifier as _, TextComponent,
+    content::Content,
+    format::{Color, Format},
+    interactivity::{ClickEvent, HoverEvent},
+    translation::TranslatedMessage,
+};
+
+const NAMED_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::DarkBlue,
+    Color::DarkGreen,
+    Color::DarkAqua,
+    Color::DarkRed,
+    Color::DarkPurple,
+    Color::Gold,
+    Color::Gray,
+    Color::DarkGray,
+    Color::Blue,
+    Color::Green,
+    Color::Aqua,
+    Color::Red,
+    Color::LightPurple,
+    Color::Yellow,
+    Color::White,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Decoration {
+    Bold,
+    Italic,
+    Underlined,
+    Strikethrough,
+    Obfuscated,
+}
+
+impl Decoration {
+    const ALL: [Self; 5] = [
+        Self::Bold,
+        Self::Italic,
+        Self::Underlined,
+        Self::Strikethrough,
+        Self::Obfuscated,
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "bold" | "b" => Self::Bold,
+            "italic" | "i" | "em" => Self::Italic,
+            "underlined" | "u" => Self::Underlined,
+            "strikethrough" | "st" => Self::Strikethrough,
+            "obfuscated" | "obf" => Self::Obfuscated,
+            _ => return None,
+        })
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Bold => "bold",
+            Self::Italic => "italic",
+            Self::Underlined => "underlined",
+            Self::Strikethrough => "strikethrough",
+            Self::Obfuscated => "obfuscated",
+        }
+    }
+
+    const fn field(self, format: &mut Format) -> &mut Option<bool> {
+        match self {
+            Self::Bold => &mut format.bold,
+            Self::Italic => &mut format.italic,
+            Self::Underlined => &mut format.underlined,
+            Self::Strikethrough => &mut format.strikethrough,
+            Self::Obfuscated => &mut format.obfuscated,
+        }
+    }
+
+    const fn value(self, format: &Format) -> Option<bool> {
+        match self {
+            Self::Bold => format.bold,
+            Self::Italic => format.italic,
+            Self::Underlined => format.underlined,
+            Self::Strikethrough => format.strikethrough,
+            Self::Obfuscated => format.obfuscated,
+        }
+    }
+}
+
+/// What a closing tag is matched against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TagKind {
+    Color,
+    Decoration(Decoration),
+    Click,
+    Hover,
+    Insertion,
+    Font,
+}
+
+impl TagKind {
+    fn from_name(name: &str) -> Option<Self> {
+        let name = name.strip_prefix('!').unwrap_or(name);
+        if let Some(decoration) = Decoration::from_name(name) {
+            return Some(Self::Decoration(decoration));
+        }
+        Some(match name {
+            "color" | "colour" | "c" => Self::Color,
+            "click" => Self::Click,
+            "hover" => Self::Hover,
+            "insert" | "insertion" => Self::Insertion,
+            "font" => Self::Font,
+            _ if parse_color(name).is_some() => Self::Color,
+            _ => return None,
+        })
+    }
+}
+
+fn parse_color(name: &str) -> Option<Color> {
+    if let Some(hex) = name.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let rgb = u32::from_str_radix(hex, 16).ok()?;
+        let [_, r, g, b] = rgb.to_be_bytes();
+        return Some(Color::Rgb(r, g, b));
+    }
+    let name = match name {
+        "grey" => "gray",
+        "dark_grey" => "dark_gray",
+        name => name,
+    };
+    NAMED_COLORS
+        .into_iter()
+        .find(|color| color.to_string() == name)
+}
+
+struct Frame {
+    /// `None` for the root.
+    kind: Option<TagKind>,
+    component: TextComponent,
+}
+
+/// Placeholder tags and the components they insert.
+pub type Placeholders<'a> = [(&'a str, &'a TextComponent)];
+
+struct Parser<'a> {
+    stack: Vec<Frame>,
+    text: String,
+    placeholders: &'a Placeholders<'a>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(placeholders: &'a Placeholders<'a>) -> Self {
+        Self {
+            stack: vec![Frame {
+                kind: None,
+                component: TextComponent::new(),
+            }],
+            text: String::new(),
+            placeholders,
+        }
+    }
+
+    fn top(&mut self) -> &mut TextComponent {
+        let index = self.stack.len() - 1;
+        &mut self.stack[index].component
+    }
+
+    fn flush(&mut self) {
+        if !self.text.is_empty() {
+            let text = mem::take(&mut self.text);
+            self.push_child(TextComponent::plain(text));
+        }
+    }
+
+    fn push_child(&mut self, child: TextComponent) {
+        self.top().children.push(child);
+    }
+
+    /// Closes frames down to, and including, `depth`. The root stays open.
+    fn close_to(&mut self, depth: usize) {
+        while self.stack.len() > depth.max(1) {
+            let Some(frame) = self.stack.pop() else {
+                break;
+            };
+            self.push_child(frame.component);
+        }
+    }
+
+    /// Applies the tag between `<` and `>`. Returns `false` if it is not a
+    /// known tag, in which case it stays text.
+    fn apply_tag(&mut self, tag: &str) -> bool {
+        if let Some(name) = tag.strip_prefix('/') {
+            return self.close_tag(name);
+        }
+        let Some(args) = split_args(tag) else {
+            return false;
+        };
+        let name = args[0].to_ascii_lowercase();
+        let rest = &args[1..];
+        match name.as_str() {
+            "newline" | "br" => self.text.push('\n'),
+            "reset" => {
+                self.flush();
+                self.close_to(1);
+            }
+            "lang" | "tr" | "translate" => {
+                let Some((key, args)) = rest.split_first() else {
+                    return false;
+                };
+                let args: Vec<_> = args
+                    .iter()
+                    .map(|arg| parse_markup_with(arg, self.placeholders))
+                    .collect();
+                self.flush();
+                self.push_child(TextComponent::translated(TranslatedMessage {
+                    key: Cow::Owned(key.clone()),
+                    fallback: None,
+                    args: (!args.is_empty()).then(|| args.into_boxed_slice()),
+                }));
+            }
+            "key" => {
+                if rest.is_empty() {
+                    return false;
+                }
+                self.flush();
+                self.push_child(TextComponent {
+                    content: Content::Keybind {
+                        keybind: rest.join(":").into(),
+                    },
+                    ..Default::default()
+                });
+            }
+            _ => {
+                if rest.is_empty()
+                    && let Some((_, placeholder)) =
+                        self.placeholders.iter().find(|(key, _)| *key == name)
+                {
+                    self.flush();
+                    self.push_child((*placeholder).clone());
+                    return true;
+                }
+                let Some(frame) = open_tag(&name, rest, self.placeholders) else {
+                    return false;
+                };
+                self.flush();
+                self.stack.push(frame);
+            }
+        }
+        true
+    }
+
+    fn close_tag(&mut self, name: &str) -> bool {
+        let name = name.to_ascii_lowercase();
+        let name = name.split(':').next().unwrap_or_default();
+        if name.is_empty() {
+            self.flush();
+            self.close_to(self.stack.len() - 1);
+            return true;
+        }
+        let Some(kind) = TagKind::from_name(name) else {
+            return false;
+        };
+        // A closing tag without a matching open tag is dropped.
+        if let Some(depth) = self
+            .stack
+            .iter()
+            .rposition(|frame| frame.kind == Some(kind))
+        {
+            self.flush();
+            self.close_to(depth);
+        }
+        true
+    }
+
+    fn finish(mut self) -> TextComponent {
+        self.flush();
+        self.close_to(1);
+        let Some(mut root) = self.stack.pop().map(|frame| frame.component) else {
+            return TextComponent::new();
+        };
+        if root.children.len() == 1 {
+            return root.children.remove(0);
+        }
+        root
+    }
+}
+
+/// Builds the frame of a style tag.
+fn open_tag(name: &str, args: &[String], placeholders: &Placeholders<'_>) -> Option<Frame> {
+    let value = args.join(":");
+    let mut component = TextComponent::new();
+    let kind = if let Some(decoration) = Decoration::from_name(name.trim_start_matches('!')) {
+        *decoration.field(&mut component.format) = Some(!name.starts_with('!'));
+        TagKind::Decoration(decoration)
+    } else {
+        match name {
+            "color" | "colour" | "c" => {
+                component.format.color = Some(parse_color(&value.to_ascii_lowercase())?);
+            }
+            "click" => {
+                let (action, value) = args.split_first()?;
+                component = component.click_event(click_event(action, value.join(":"))?);
+            }
+            "hover" => {
+                let (action, value) = args.split_first()?;
+                if action != "show_text" {
+                    return None;
+                }
+                component = component.hover_event(HoverEvent::show_text(parse_markup_with(
+                    &value.join(":"),
+                    placeholders,
+                )));
+            }
+            "insert" | "insertion" => component = component.insertion(value),
+            "font" if !value.is_empty() => component.format.font = Some(value.into()),
+            _ if args.is_empty() => component.format.color = Some(parse_color(name)?),
+            _ => return None,
+        }
+        TagKind::from_name(name)?
+    };
+    Some(Frame {
+        kind: Some(kind),
+        component,
+    })
+}
+
+fn click_event(action: &str, value: String) -> Option<ClickEvent> {
+    Some(match action {
+        "open_url" => ClickEvent::OpenUrl { url: value.into() },
+        "run_command" => ClickEvent::run_command(value),
+        "suggest_command" => ClickEvent::suggest_command(value),
+        "change_page" => ClickEvent::ChangePage {
+            page: value.parse().ok()?,
+        },
+        "copy_to_clipboard" => ClickEvent::CopyToClipboard {
+            value: value.into(),
+        },
+        _ => return None,
+    })
+}
+
+/// Splits a tag into its `:`-separated arguments, unquoting them. Returns
+/// `None` for an unterminated quote or an empty name.
+fn split_args(tag: &str) -> Option<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+    let mut chars = tag.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(_), '\\') => current.push(chars.next()?),
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), c) => current.push(c),
+            (None, '\'' | '"') => quote = Some(c),
+            (None, ':') => args.push(mem::take(&mut current)),
+            (None, c) => current.push(c),
+        }
+    }
+    if quote.is_some() {
+        return None;
+    }
+    args.push(current);
+    (!args[0].is_empty()).then_some(args)
+}
+
+/// Returns the index of the `>` ending the tag that starts `input`, skipping
+/// quoted arguments.
+fn tag_end(input: &str) -> Option<usize> {
+    let mut quote = None;
+    let mut chars = input.char_indices().skip(1);
+    while let Some((index, c)) = chars.next() {
+        match (quote, c) {
+            (Some(_), '\\') => {
+                chars.next();
+            }
+            (Some(open), c) if c == open => quote = None,
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '<') => return None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parses markup into a component. Never fails: anything that is not a valid
+/// tag is kept as text.
+#[must_use]
+pub fn parse_markup(input: &str) -> TextComponent {
+    parse_markup_with(input, &[])
+}
+
+/// Parses markup like [`parse_markup`], replacing each `<key>` tag of
+/// `placeholders` with its component.
+#[must_use]
+pub fn parse_markup_with(input: &str, placeholders: &Placeholders<'_>) -> TextComponent {
+    let mut parser = Parser::new(placeholders);
+    let mut rest = input;
+    while let Some(index) = rest.find(['<', '\\']) {
+        parser.text.push_str(&rest[..index]);
+        let tail = &rest[index..];
+        if let Some(escaped) = tail.strip_prefix('\\') {
+            match escaped.chars().next() {
+                Some(c @ ('<' | '\\')) => {
+                    parser.text.push(c);
+                    rest = &escaped[1..];
+                }
+                _ => {
+                    parser.text.push('\\');
+                    rest = escaped;
+                }
+            }
+            continue;
+        }
+        match tag_end(tail) {
+            Some(end) if parser.apply_tag(&tail[1..end]) => rest = &tail[end + 1..],
+            _ => {
+                parser.text.push('<');
+                rest = &tail[1..];
+            }
+        }
+    }
+    parser.text.push_str(rest);
+    parser.finish()
+}
+
+/// Escapes `text` so [`parse_markup`] reads it back literally.
+#[must_use]
+pub fn escape_markup(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('<', "\\<")
+}
+
+/// Serializes a component back to markup.
+///
+/// Content and events markup cannot express, such as entity selectors or
+/// item hovers, are left out.
+#[must_use]
+pub fn to_markup(component: &TextComponent) -> String {
+    let mut out = String::new();
+    write_markup(component, &mut out);
+    out
+}
+
+fn write_markup(component: &TextComponent, out: &mut String) {
+    let mut closing = Vec::new();
+    let mut open = |out: &mut String, tag: String, close: &str| {
+        let _ = write!(out, "<{tag}>");
+        closing.push(close.to_owned());
+    };
+
+    if let Some(color) = &component.format.color {
+        let color = color.to_string().to_ascii_lowercase();
+        open(out, color.clone(), &color);
+    }
+    for decoration in Decoration::ALL {
+        if let Some(value) = decoration.value(&component.format) {
+            let name = if value {
+                decoration.name().to_owned()
+            } else {
+                format!("!{}", decoration.name())
+            };
+            open(out, name.clone(), &name);
+        }
+    }
+    if let Some(font) = &component.format.font {
+        open(out, format!("font:{}", quote_arg(font)), "font");
+    }
+    if let Some(insertion) = &component.interactions.insertion {
+        open(out, format!("insert:{}", quote_arg(insertion)), "insert");
+    }
+    if let Some(HoverEvent::ShowText { value }) = &component.interactions.hover {
+        let value = to_markup(value);
+        open(
+            out,
+            format!("hover:show_text:{}", quote_arg(&value)),
+            "hover",
+        );
+    }
+    if let Some(click) = &component.interactions.click
+        && let Some((action, value)) = click_parts(click)
+    {
+        open(
+            out,
+            format!("click:{action}:{}", quote_arg(&value)),
+            "click",
+        );
+    }
+
+    match &component.content {
+        Content::Text { text } => out.push_str(&escape_markup(text)),
+        Content::Translate(message) => {
+            let _ = write!(out, "<lang:{}", quote_arg(&message.key));
+            for arg in message.args.as_deref().unwrap_or_default() {
+                let _ = write!(out, ":{}", quote_arg(&to_markup(arg)));
+            }
+            out.push('>');
+        }
+        Content::Keybind { keybind } => {
+            let _ = write!(out, "<key:{}>", quote_arg(keybind));
+        }
+        _ => {}
+    }
+    for child in &component.children {
+        write_markup(child, out);
+    }
+    for tag in closing.iter().rev() {
+        let _ = write!(out, "</{tag}>");
+    }
+}
+
+fn click_parts(event: &ClickEvent) -> Option<(&'static str, String)> {
+    Some(match event {
+        ClickEvent::OpenUrl { url } => ("open_url", url.to_string()),
+        ClickEvent::RunCommand { command } => ("run_command", command.to_string()),
+        ClickEvent::SuggestCommand { command } => ("suggest_command", command.to_string()),
+        ClickEvent::ChangePage { page } => ("change_page", page.to_string()),
+        ClickEvent::CopyToClipboard { value } => ("copy_to_clipboard", value.to_string()),
+        _ => return None,
+    })
+}
+
+/// Quotes a tag argument if it would otherwise not read back as one.
+fn quote_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([':', '<', '>', '\'', '"', '\\']) {
+        return arg.to_owned();
+    }
+    let mut quoted = String::with_capacity(arg.len() + 2);
+    quoted.push('\'');
+    for c in arg.chars() {
+        if matches!(c, '\'' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('\'');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::DisplayResolutor;
+
+    #[test]
+    fn parses_nested_tags_and_keeps_unknown_ones_as_text() {
+        let component = parse_markup("<red><bold>Hi</bold> <click:run_command:/spawn>go</click>");
+        assert_eq!(component.format.color, Some(Color::Red));
+        assert_eq!(component.children.len(), 3);
+        assert_eq!(component.children[0].format.bold, Some(true));
+        assert!(matches!(
+            component.children[2].interactions.click,
+            Some(ClickEvent::RunCommand { ref command }) if command.as_ref() == "/spawn"
+        ));
+        assert_eq!(component.to_plain(&DisplayResolutor), "Hi go");
+
+        let literal = parse_markup("a < b <unknown>\\<red> <red");
+        assert_eq!(
+            literal.to_plain(&DisplayResolutor),
+            "a < b <unknown><red> <red"
+        );
+        assert_eq!(literal.format.color, None);
+
+        let name = TextComponent::plain("Steve");
+        let line = parse_markup_with("<gray><name>: <player>", &[("name", &name)]);
+        assert_eq!(line.to_plain(&DisplayResolutor), "Steve: <player>");
+    }
+
+    #[test]
+    fn markup_round_trips() {
+        let markup = "<#ff8800>Orange <!italic>text</!italic></#ff8800>\
+            <hover:show_text:'<red>Click \\'here\\'</red>'><click:open_url:'https://example.com'>link</click></hover>\
+            <lang:chat.type.text:Steve:'<bold>hi</bold>'>";
+        let component = parse_markup(markup);
+        assert_eq!(
+            component.children[0].format.color,
+            Some(Color::Rgb(0xff, 0x88, 0x00))
+        );
+        let serialized = to_markup(&component);
+        assert_eq!(serialized, markup);
+        assert_eq!(to_markup(&parse_markup(&serialized)), markup);
+    }
+}
@@ -1,6 +1,14 @@
 //! This module contains everything related to text components.
+mod ansi;
+mod json;
+mod legacy;
+mod markup;
 mod nbt;
 
+pub use ansi::to_ansi;
+pub use json::{component_from_json, component_from_json_value};
+pub use legacy::{LEGACY_AMPERSAND, LEGACY_SECTION, parse_legacy};
+pub use markup::{Placeholders, escape_markup, parse_markup, parse_markup_with, to_markup};
 pub use nbt::command_nbt_component;
 
 use crate::{
@@ -38,6 +46,23 @@ impl TextResolutor for DisplayResolutor {
     }
 }
 
+/// Returns whether `component` has score, selector or NBT content anywhere,
+/// which resolves differently for each viewer.
+#[must_use]
+pub fn has_resolvable_content(component: &TextComponent) -> bool {
+    let content = match &component.content {
+        Content::Resolvable(_) => true,
+        Content::Translate(message) => message
+            .args
+            .as_deref()
+            .is_some_and(|args| args.iter().any(has_resolvable_content)),
+        _ => false,
+    };
+    content
+        || matches!(&component.interactions.hover, Some(HoverEvent::ShowText { value }) if has_resolvable_content(value))
+        || component.children.iter().any(has_resolvable_content)
+}
+
 impl ReadFrom for TextComponent {
     fn read(data: &mut Cursor<&[u8]>) -> io::Result<Self> {
         // ComponentSerialization.STREAM_CODEC writes one unnamed NBT tag.
@@ -805,9 +830,13 @@ mod tests {
 
     use simdnbt::owned::{NbtList, NbtTag, read_tag};
     use text_components::{
-        Modifier as _, TextComponent, interactivity::HoverEvent, translation::TranslatedMessage,
+        Modifier as _, TextComponent,
+        content::{Content, Resolvable},
+        interactivity::HoverEvent,
+        translation::TranslatedMessage,
     };
 
+    use super::has_resolvable_content;
     use crate::serial::{ReadFrom as _, WriteTo as _};
 
     #[test]
@@ -864,4 +893,26 @@ mod tests {
 
         assert_eq!(tag, NbtTag::String("hello".into()));
     }
+
+    #[test]
+    fn resolvable_content_is_found_in_arguments_and_hovers() {
+        let score = TextComponent {
+            content: Content::Resolvable(Resolvable::Scoreboard {
+                selector: "@s".into(),
+                objective: "kills".into(),
+            }),
+            ..Default::default()
+        };
+        assert!(!has_resolvable_content(&TextComponent::plain("plain")));
+        assert!(has_resolvable_content(
+            &TextComponent::plain("").hover_event(HoverEvent::show_text(score.clone()))
+        ));
+        assert!(has_resolvable_content(&TextComponent::translated(
+            TranslatedMessage {
+                key: Cow::Borrowed("test.message"),
+                fallback: None,
+                args: Some(Box::new([score])),
+            }
+        )));
+    }
 }
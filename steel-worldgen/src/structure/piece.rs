@@ -151,15 +151,7 @@ pub struct RuinedPortalProperties {
 }
 
 /// Vanilla `Mirror` modes used by template placement.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum StructureMirror {
-    /// No mirror transform.
-    None,
-    /// Mirror across the template front/back axis.
-    FrontBack,
-    /// Mirror across the template left/right axis.
-    LeftRight,
-}
+pub use steel_utils::Mirror as StructureMirror;
 
 /// Hardcoded vanilla block-ignore processors used by template placement.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
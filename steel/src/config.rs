@@ -1,8 +1,9 @@
 //! Server configuration loading.
 //!
 //! This module handles loading the server configuration from disk.
-//! The config is loaded once at startup, split into creation-time values
+//! The config is loaded at startup, split into creation-time values
 //! (consumed by the server constructor) and a `RuntimeConfig` (stored on `Server`).
+//! `/steel reload config` re-reads it through [`FileConfigReloader`].
 
 use serde::{Deserialize, Serialize};
 use std::{
@@ -17,21 +18,31 @@ use tracing_subscriber::filter::Directive;
 use futures::future::BoxFuture;
 use reqwest::Url;
 use steel_core::config::{
-    CompressionInfo, RuntimeConfig, ServerLinks, WorldsConfig, validate_login_security,
+    ChatConfig, CommandAliasesConfig, CompressionInfo, ItemsConfig, JoinQueueConfig,
+    OfflineSkinsConfig, PacketLimitConfig, ProxyConfig, ProxyForwarding, ReportDetailsConfig,
+    ResourcePackConfig, RuntimeConfig, ServerLinks, WatchdogConfig, WorldsConfig,
+    validate_login_security,
 };
+use steel_core::entity::DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE;
 use steel_core::permission::{
     PermissionGroupConfig, PermissionGroupStore, PermissionGroupStoreError, PermissionGroups,
     PermissionGroupsConfig, PermissionMetadataRuleConfig, PermissionMetadataValue,
 };
+use steel_core::plugin::PluginsConfig;
+use steel_core::server::config_reload::ConfigReloader;
 use tokio::fs as async_fs;
 use toml::ser::Error as TomlSerializeError;
 
+use crate::logger::CommandLogger;
+
 #[cfg(feature = "stand-alone")]
 const DEFAULT_FAVICON: &[u8] = include_bytes!("../../package-content/favicon.png");
 
 const DEFAULT_CONFIG: &str = include_str!("../../package-content/config.toml");
 const DEFAULT_WORLDS: &str = include_str!("../../package-content/worlds.toml");
 const DEFAULT_GROUPS: &str = include_str!("../../package-content/groups.toml");
+const DEFAULT_ITEMS: &str = include_str!("../../package-content/items.toml");
+const DEFAULT_COMMANDS: &str = include_str!("../../package-content/commands.toml");
 const GROUPS_CONFIG_HEADER: &str = concat!(
     "#:schema https://raw.githubusercontent.com/Steel-Foundation/SteelMC/refs/heads/master/",
     "package-content/groups.schema.json\n",
@@ -49,6 +60,12 @@ pub struct SteelConfig {
     /// World and domain configuration from `worlds.toml`.
     #[serde(skip, default = "empty_worlds_config")]
     pub worlds: WorldsConfig,
+    /// Custom item configuration from `items.toml`.
+    #[serde(skip, default)]
+    pub items: ItemsConfig,
+    /// Command aliases and macros from `commands.toml`.
+    #[serde(skip, default)]
+    pub commands: CommandAliasesConfig,
     /// Permission group configuration from `groups.toml`.
     #[serde(skip, default)]
     pub groups: PermissionGroupsConfig,
@@ -81,6 +98,32 @@ impl FilePermissionGroupStore {
     }
 }
 
+/// Re-reads `config.toml` for `/steel reload config`.
+pub struct FileConfigReloader {
+    path: PathBuf,
+    logger: Arc<CommandLogger>,
+}
+
+impl FileConfigReloader {
+    /// Creates a reloader for the config at `path` that also updates the
+    /// formatting of `logger`.
+    #[must_use]
+    pub const fn new(path: PathBuf, logger: Arc<CommandLogger>) -> Self {
+        Self { path, logger }
+    }
+}
+
+impl ConfigReloader for FileConfigReloader {
+    fn reload(&self) -> Result<RuntimeConfig, String> {
+        let config = load(&self.path)?;
+        let commands = load_or_create_commands(&self.path.with_file_name("commands.toml"))?;
+        self.logger.set_format(config.log.as_ref());
+        let mut runtime = config.server.into_runtime_config();
+        runtime.command_aliases = commands;
+        Ok(runtime)
+    }
+}
+
 impl PermissionGroupStore for FilePermissionGroupStore {
     fn save_groups(
         &self,
@@ -239,6 +282,10 @@ const fn default_spam_threshold_seconds() -> i32 {
     10
 }
 
+const fn default_entity_broadcast_range_percentage() -> u16 {
+    DEFAULT_ENTITY_BROADCAST_RANGE_PERCENTAGE
+}
+
 fn default_log_path() -> String {
     "./.logs".to_string()
 }
@@ -269,6 +316,9 @@ pub struct ServerConfig {
     pub view_distance: u8,
     /// The simulation distance of the server.
     pub simulation_distance: u8,
+    /// Percentage applied to vanilla entity tracking ranges.
+    #[serde(default = "default_entity_broadcast_range_percentage")]
+    pub entity_broadcast_range_percentage: u16,
     /// Whether the server is in online mode.
     pub online_mode: bool,
     /// Optional authentication endpoint for online-mode `hasJoined` checks.
@@ -280,6 +330,9 @@ pub struct ServerConfig {
     /// Whether vanilla floating/flying movement checks permit unauthorized flight.
     #[serde(default)]
     pub allow_flight: bool,
+    /// Whether clients transferred from another server may join.
+    #[serde(default)]
+    pub accepts_transfers: bool,
     /// The message of the day.
     pub motd: String,
     /// Whether to use a favicon.
@@ -298,9 +351,45 @@ pub struct ServerConfig {
     pub compression: Option<CompressionInfo>,
     /// All settings and configurations for server links.
     pub server_links: Option<ServerLinks>,
+    /// Details added to client crash and disconnect reports.
+    #[serde(default)]
+    pub report_details: ReportDetailsConfig,
+    /// Server resource packs offered to players during configuration.
+    #[serde(default)]
+    pub resource_packs: Vec<ResourcePackConfig>,
+    /// Player information forwarding from a proxy.
+    #[serde(default)]
+    pub proxy: ProxyConfig,
+    /// Per-connection limits on what clients may send.
+    #[serde(default)]
+    pub packet_limits: PacketLimitConfig,
+    /// Main tick stall detection.
+    #[serde(default)]
+    pub watchdog: WatchdogConfig,
     /// Thread counts for server thread pools.
     #[serde(default)]
     pub threads: ThreadConfig,
+    /// GameSpy4 query listener settings.
+    #[serde(default)]
+    pub query: QueryConfig,
+    /// Local network discovery settings.
+    #[serde(default)]
+    pub lan: LanConfig,
+    /// Graceful shutdown settings.
+    #[serde(default)]
+    pub shutdown: ShutdownConfig,
+    /// Plugin loading settings.
+    #[serde(default)]
+    pub plugins: PluginsConfig,
+    /// Chat message formatting.
+    #[serde(default)]
+    pub chat: ChatConfig,
+    /// Skin lookup for players in offline mode.
+    #[serde(default)]
+    pub offline_skins: OfflineSkinsConfig,
+    /// Waiting for a free slot when the server is full.
+    #[serde(default)]
+    pub join_queue: JoinQueueConfig,
 }
 
 impl ServerConfig {
@@ -311,11 +400,13 @@ impl ServerConfig {
             max_players: self.max_players,
             view_distance: self.view_distance,
             simulation_distance: self.simulation_distance,
+            entity_broadcast_range_percentage: self.entity_broadcast_range_percentage,
             online_mode: self.online_mode,
             auth_server: self.auth_server,
             profile_server: self.profile_server,
             encryption: self.encryption,
             allow_flight: self.allow_flight,
+            accepts_transfers: self.accepts_transfers,
             motd: self.motd,
             use_favicon: self.use_favicon,
             favicon: self.favicon,
@@ -324,7 +415,17 @@ impl ServerConfig {
             command_spam_threshold_seconds: self.command_spam_threshold_seconds,
             compression: self.compression,
             server_links: self.server_links,
+            report_details: self.report_details,
             chunk_generation_threads: self.threads.chunk_generation,
+            random_tick_threads: self.threads.random_ticking,
+            resource_packs: self.resource_packs,
+            proxy: self.proxy,
+            packet_limits: self.packet_limits,
+            watchdog: self.watchdog,
+            chat: self.chat,
+            offline_skins: self.offline_skins,
+            join_queue: self.join_queue,
+            command_aliases: CommandAliasesConfig::default(),
         }
     }
 }
@@ -341,6 +442,47 @@ pub struct ThreadConfig {
     pub chunk_runtime: Option<usize>,
     /// Worker threads for the Rayon chunk generation pool.
     pub chunk_generation: Option<usize>,
+    /// Worker threads for running random block ticks of independent chunk
+    /// regions in parallel. Unset, `0` and `1` keep them on the world thread.
+    pub random_ticking: Option<usize>,
+}
+
+/// GameSpy4 query listener settings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct QueryConfig {
+    /// Whether to answer UDP queries.
+    pub enable: bool,
+    /// UDP port for queries. Defaults to `server_port`.
+    pub port: Option<u16>,
+}
+
+/// Local network discovery settings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LanConfig {
+    /// Whether to announce the server to clients on the local network like
+    /// vanilla's "Open to LAN".
+    pub broadcast: bool,
+}
+
+/// Graceful shutdown settings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShutdownConfig {
+    /// Message players are disconnected with. Defaults to vanilla's "Server closed".
+    pub kick_message: Option<String>,
+    /// Seconds the whole shutdown may take before the process exits without finishing it.
+    pub timeout_seconds: u64,
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self {
+            kick_message: None,
+            timeout_seconds: 60,
+        }
+    }
 }
 
 /// Logging configuration
@@ -433,16 +575,21 @@ impl LogLevel {
     }
 }
 
+/// Loads and validates the `config.toml` at `path`, without the other config files.
+pub fn load(path: &Path) -> Result<SteelConfig, String> {
+    let config_str = fs::read_to_string(path)
+        .map_err(|e| format!("failed to read config file {}: {e}", path.display()))?;
+    let config: SteelConfig =
+        toml::from_str(config_str.as_str()).map_err(|e| format!("failed to parse config: {e}"))?;
+    validate(&config.server).map_err(|e| format!("failed to validate config: {e}"))?;
+    Ok(config)
+}
+
 /// Loads the server configuration from the given path, or creates it if it doesn't exist.
 ///
 pub fn load_or_create(path: &Path) -> Result<SteelConfig, String> {
     let mut config = if path.exists() {
-        let config_str = fs::read_to_string(path)
-            .map_err(|e| format!("failed to read config file {}: {e}", path.display()))?;
-        let config: SteelConfig = toml::from_str(config_str.as_str())
-            .map_err(|e| format!("failed to parse config: {e}"))?;
-        validate(&config.server).map_err(|e| format!("failed to validate config: {e}"))?;
-        config
+        load(path)?
     } else {
         let parent = path
             .parent()
@@ -466,6 +613,16 @@ pub fn load_or_create(path: &Path) -> Result<SteelConfig, String> {
         .ok_or_else(|| format!("failed to get config directory for {}", path.display()))?
         .join("worlds.toml");
     config.worlds = load_or_create_worlds(&worlds_path)?;
+    let items_path = path
+        .parent()
+        .ok_or_else(|| format!("failed to get config directory for {}", path.display()))?
+        .join("items.toml");
+    config.items = load_or_create_items(&items_path)?;
+    let commands_path = path
+        .parent()
+        .ok_or_else(|| format!("failed to get config directory for {}", path.display()))?
+        .join("commands.toml");
+    config.commands = load_or_create_commands(&commands_path)?;
     let groups_path = path
         .parent()
         .ok_or_else(|| format!("failed to get config directory for {}", path.display()))?
@@ -501,6 +658,46 @@ fn load_or_create_worlds(path: &Path) -> Result<WorldsConfig, String> {
     }
 }
 
+fn load_or_create_items(path: &Path) -> Result<ItemsConfig, String> {
+    if path.exists() {
+        let items_str = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read items config file {}: {e}", path.display()))?;
+        toml::from_str(items_str.as_str())
+            .map_err(|e| format!("failed to parse items config {}: {e}", path.display()))
+    } else {
+        fs::write(path, DEFAULT_ITEMS)
+            .map_err(|e| format!("failed to write items config file {}: {e}", path.display()))?;
+        toml::from_str(DEFAULT_ITEMS)
+            .map_err(|e| format!("failed to parse default items config: {e}"))
+    }
+}
+
+fn load_or_create_commands(path: &Path) -> Result<CommandAliasesConfig, String> {
+    let commands: CommandAliasesConfig = if path.exists() {
+        let commands_str = fs::read_to_string(path).map_err(|e| {
+            format!(
+                "failed to read commands config file {}: {e}",
+                path.display()
+            )
+        })?;
+        toml::from_str(commands_str.as_str())
+            .map_err(|e| format!("failed to parse commands config {}: {e}", path.display()))?
+    } else {
+        fs::write(path, DEFAULT_COMMANDS).map_err(|e| {
+            format!(
+                "failed to write commands config file {}: {e}",
+                path.display()
+            )
+        })?;
+        toml::from_str(DEFAULT_COMMANDS)
+            .map_err(|e| format!("failed to parse default commands config: {e}"))?
+    };
+    commands
+        .validate()
+        .map_err(|e| format!("failed to validate commands config {}: {e}", path.display()))?;
+    Ok(commands)
+}
+
 fn load_or_create_groups(path: &Path) -> Result<PermissionGroupsConfig, String> {
     let config: PermissionGroupsConfig = if path.exists() {
         let contents = fs::read_to_string(path)
@@ -554,6 +751,12 @@ fn validate(config: &ServerConfig) -> Result<(), &'static str> {
     if config.simulation_distance > config.view_distance {
         return Err("Simulation distance must be less than or equal to view distance");
     }
+    if !(10..=1000).contains(&config.entity_broadcast_range_percentage) {
+        return Err("Entity broadcast range percentage must be between 10 and 1000");
+    }
+    if config.shutdown.timeout_seconds == 0 {
+        return Err("shutdown timeout_seconds must be at least 1");
+    }
     if let Some(compression) = config.compression {
         if compression.threshold.get() < 256 {
             return Err("Compression threshold must be greater than or equal to 256");
@@ -562,6 +765,29 @@ fn validate(config: &ServerConfig) -> Result<(), &'static str> {
             return Err("Compression level must be between 1 and 9");
         }
     }
+    for resource_pack in &config.resource_packs {
+        let Ok(url) = Url::parse(&resource_pack.url) else {
+            return Err("resource pack url must be an absolute URL");
+        };
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err("resource pack url must use http or https");
+        }
+        if !resource_pack.sha1.is_empty()
+            && (resource_pack.sha1.len() != 40
+                || !resource_pack.sha1.chars().all(|c| c.is_ascii_hexdigit()))
+        {
+            return Err("resource pack sha1 must be 40 hex characters");
+        }
+    }
+    if config.proxy.forwarding != ProxyForwarding::None && config.online_mode {
+        return Err("online_mode must be false when proxy forwarding is enabled");
+    }
+    if config.proxy.forwarding == ProxyForwarding::Velocity && config.proxy.secret.is_empty() {
+        return Err("proxy secret must be set for velocity forwarding");
+    }
+    if config.watchdog.enable && config.watchdog.stall_threshold_seconds == 0 {
+        return Err("watchdog stall threshold must be at least 1 second");
+    }
     if config.enforce_secure_chat {
         if !config.online_mode {
             return Err("online_mode must be true when enforce_secure_chat is enabled");
@@ -569,6 +795,9 @@ fn validate(config: &ServerConfig) -> Result<(), &'static str> {
         if !config.encryption {
             return Err("encryption must be true when enforce_secure_chat is enabled");
         }
+        if config.chat.strip_signatures {
+            return Err("chat.strip_signatures must be false when enforce_secure_chat is enabled");
+        }
     }
     Ok(())
 }
@@ -608,6 +837,20 @@ mod tests {
             "# Documentation: https://steelmc.dev/configuration/world-configuration/\n\n",
         )));
         assert!(!worlds.domains.is_empty());
+        let items: ItemsConfig = toml::from_str(DEFAULT_ITEMS).expect("default items parse");
+        assert!(DEFAULT_ITEMS.starts_with(concat!(
+            "#:schema https://raw.githubusercontent.com/Steel-Foundation/SteelMC/refs/heads/master/",
+            "package-content/items.schema.json\n",
+        )));
+        assert!(items.items.is_empty());
+        let commands: CommandAliasesConfig =
+            toml::from_str(DEFAULT_COMMANDS).expect("default commands parse");
+        assert!(DEFAULT_COMMANDS.starts_with(concat!(
+            "#:schema https://raw.githubusercontent.com/Steel-Foundation/SteelMC/refs/heads/master/",
+            "package-content/commands.schema.json\n",
+        )));
+        commands.validate().expect("default commands validate");
+        assert!(commands.aliases.is_empty());
         let groups: PermissionGroupsConfig =
             toml::from_str(DEFAULT_GROUPS).expect("default groups parse");
         PermissionGroups::from_config(groups).expect("default groups validate");
@@ -738,15 +981,41 @@ mod tests {
         let config_toml = DEFAULT_CONFIG
             .replace("main_runtime = 0", "main_runtime = 3")
             .replace("chunk_runtime = 0", "chunk_runtime = 4")
-            .replace("chunk_generation = 0", "chunk_generation = 5");
+            .replace("chunk_generation = 0", "chunk_generation = 5")
+            .replace("random_ticking = 0", "random_ticking = 6");
         let config: SteelConfig = toml::from_str(&config_toml).expect("config parses");
 
         assert_eq!(config.server.threads.main_runtime, Some(3));
         assert_eq!(config.server.threads.chunk_runtime, Some(4));
         assert_eq!(config.server.threads.chunk_generation, Some(5));
+        assert_eq!(config.server.threads.random_ticking, Some(6));
+        let runtime = config.server.into_runtime_config();
+        assert_eq!(runtime.chunk_generation_threads, Some(5));
+        assert_eq!(runtime.random_tick_threads, Some(6));
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_entity_broadcast_percentage() {
+        let config_toml = DEFAULT_CONFIG.replace(
+            "entity_broadcast_range_percentage = 100",
+            "entity_broadcast_range_percentage = 5",
+        );
+        let config: SteelConfig = toml::from_str(&config_toml).expect("config parses");
+
+        assert_eq!(
+            validate(&config.server),
+            Err("Entity broadcast range percentage must be between 10 and 1000")
+        );
+    }
+
+    #[test]
+    fn validate_rejects_zero_shutdown_timeout() {
+        let config_toml = DEFAULT_CONFIG.replace("timeout_seconds = 60", "timeout_seconds = 0");
+        let config: SteelConfig = toml::from_str(&config_toml).expect("config parses");
+
         assert_eq!(
-            config.server.into_runtime_config().chunk_generation_threads,
-            Some(5)
+            validate(&config.server),
+            Err("shutdown timeout_seconds must be at least 1")
         );
     }
 
@@ -772,6 +1041,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_rejects_stripped_signatures_with_enforced_secure_chat() {
+        let config_toml = DEFAULT_CONFIG
+            .replace("enforce_secure_chat = false", "enforce_secure_chat = true")
+            .replace("strip_signatures = false", "strip_signatures = true");
+        let config: SteelConfig = toml::from_str(&config_toml).expect("config parses");
+
+        assert_eq!(
+            validate(&config.server),
+            Err("chat.strip_signatures must be false when enforce_secure_chat is enabled")
+        );
+    }
+
     #[test]
     fn validate_allows_offline_mode_without_encryption() {
         let config_toml = DEFAULT_CONFIG
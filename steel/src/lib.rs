@@ -5,13 +5,16 @@
 use std::{
     error::Error,
     fmt, io,
-    net::{Ipv4Addr, SocketAddrV4},
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
     sync::{Arc, OnceLock},
+    time::Duration,
 };
 
 use steel_core::{command::CommandRegistry, permission::PermissionGroupManager, server::Server};
-use steel_login::{JavaTcpClient, ServerConnectionSession};
-use tokio::{net::TcpListener, runtime::Runtime, select};
+use steel_login::{JavaTcpClient, LanBroadcaster, QueryListener, ServerConnectionSession};
+use steel_utils::translations;
+use text_components::TextComponent;
+use tokio::{net::TcpListener, runtime::Runtime, select, time};
 use tokio_util::{sync::CancellationToken, task::TaskTracker};
 
 /// Server configuration module.
@@ -22,18 +25,31 @@ pub mod logger;
 /// Static access to the server
 pub static SERVER: OnceLock<Arc<Server>> = OnceLock::new();
 
+/// How long kicked players get to receive their disconnect packet before
+/// their connections are dropped.
+const DISCONNECT_GRACE: Duration = Duration::from_secs(5);
+
 /// The main server struct.
 pub struct SteelServer {
     /// The TCP listener for incoming connections.
     pub tcp_listener: TcpListener,
     /// The cancellation token for graceful shutdown.
     pub cancel_token: CancellationToken,
+    /// Cancels all client connections. Only cancelled by
+    /// [`SteelServer::close_connections`], after players were kicked.
+    pub connection_token: CancellationToken,
     /// The next client ID to be assigned.
     pub client_id: u64,
     /// The shared server state.
     pub server: Arc<Server>,
     /// Session id UUID state
     pub connection_session: Arc<ServerConnectionSession>,
+    /// The GameSpy4 query listener, if enabled. Taken when the server starts.
+    pub query_listener: Option<QueryListener>,
+    /// The local network announcer, if enabled. Taken when the server starts.
+    pub lan_broadcaster: Option<LanBroadcaster>,
+    /// Graceful shutdown settings.
+    pub shutdown: config::ShutdownConfig,
 }
 
 /// Startup error for expected operational failures.
@@ -91,20 +107,26 @@ impl SteelServer {
 
         let permission_group_store = steel_config.permission_group_store();
         let server_port = steel_config.server.server_port;
+        let query_config = steel_config.server.query.clone();
+        let lan_config = steel_config.server.lan.clone();
+        let shutdown = steel_config.server.shutdown.clone();
         let worlds_config = steel_config.worlds;
+        let items_config = steel_config.items;
         let permission_groups =
             PermissionGroupManager::new(steel_config.groups, permission_group_store).map_err(
                 |error| {
                     SteelServerError::Core(format!("failed to validate groups config: {error}"))
                 },
             )?;
-        let runtime_config = steel_config.server.into_runtime_config();
+        let mut runtime_config = steel_config.server.into_runtime_config();
+        runtime_config.command_aliases = steel_config.commands;
 
         let server = Server::new_with_commands(
             chunk_runtime,
             cancel_token.clone(),
             runtime_config,
             worlds_config,
+            items_config,
             permission_groups,
             command_registry,
         )
@@ -118,12 +140,47 @@ impl SteelServer {
                 source,
             })?;
 
+        let server = Arc::new(server);
+        let query_listener = if query_config.enable {
+            let query_port = query_config.port.unwrap_or(server_port);
+            let address = SocketAddr::from((Ipv4Addr::UNSPECIFIED, query_port));
+            let listener = QueryListener::bind(address, Arc::clone(&server), server_port)
+                .await
+                .map_err(|source| SteelServerError::Bind {
+                    port: query_port,
+                    source,
+                })?;
+            log::info!("Query listening on UDP port {query_port}");
+            Some(listener)
+        } else {
+            None
+        };
+
+        let lan_broadcaster = if lan_config.broadcast {
+            match LanBroadcaster::bind(Arc::clone(&server), server_port).await {
+                Ok(broadcaster) => {
+                    log::info!("Announcing the server on the local network");
+                    Some(broadcaster)
+                }
+                Err(err) => {
+                    log::warn!("Failed to bind the LAN announcement socket: {err}");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         Ok(Self {
             tcp_listener,
             cancel_token,
+            connection_token: CancellationToken::new(),
             client_id: 0,
-            server: Arc::new(server),
+            server,
             connection_session: Arc::new(ServerConnectionSession::default()),
+            query_listener,
+            lan_broadcaster,
+            shutdown,
         })
     }
 
@@ -136,6 +193,12 @@ impl SteelServer {
         let server_handle = tokio::spawn(async move {
             server.run(token).await;
         });
+        if let Some(query_listener) = self.query_listener.take() {
+            task_tracker.spawn(query_listener.run(self.cancel_token.clone()));
+        }
+        if let Some(lan_broadcaster) = self.lan_broadcaster.take() {
+            task_tracker.spawn(lan_broadcaster.run(self.cancel_token.clone()));
+        }
 
         loop {
             select! {
@@ -153,7 +216,7 @@ impl SteelServer {
                         connection,
                         address,
                         self.client_id,
-                        self.cancel_token.child_token(),
+                        self.connection_token.child_token(),
                         self.server.clone(),
                         self.connection_session.clone(),
                         task_tracker.clone(),
@@ -171,4 +234,34 @@ impl SteelServer {
         }
         let _ = server_handle.await;
     }
+    /// Kicks every player with the configured shutdown message and closes all
+    /// connections.
+    ///
+    /// Call after [`SteelServer::start`] returned, so no new connections are
+    /// accepted. Players stay in their worlds so they can still be saved.
+    pub async fn close_connections(&self, task_tracker: &TaskTracker) {
+        let reason = self.shutdown.kick_message.as_ref().map_or_else(
+            || {
+                TextComponent::translated(
+                    translations::MULTIPLAYER_DISCONNECT_SERVER_SHUTDOWN.msg(),
+                )
+            },
+            |message| TextComponent::plain(message.clone()),
+        );
+        let players = self.server.get_players();
+        log::info!("Disconnecting {} players", players.len());
+        for player in players {
+            player.disconnect(reason.clone());
+        }
+
+        task_tracker.close();
+        if time::timeout(DISCONNECT_GRACE, task_tracker.wait())
+            .await
+            .is_err()
+        {
+            log::warn!("Connections still open after {DISCONNECT_GRACE:?}, dropping them");
+        }
+        self.connection_token.cancel();
+        task_tracker.wait().await;
+    }
 }
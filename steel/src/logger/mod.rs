@@ -9,7 +9,7 @@ use std::{
     sync::Arc,
     time::{self, Instant},
 };
-use steel_utils::locks::AsyncRwLock;
+use steel_utils::locks::{AsyncRwLock, SyncRwLock};
 use steel_utils::logger::{Level, LogData, STEEL_LOGGER, SteelLogger};
 use tokio::{sync::mpsc, task, time::timeout};
 use tokio_util::sync::CancellationToken;
@@ -42,6 +42,31 @@ pub(crate) enum Move {
     Down,
 }
 
+/// How log lines are formatted. Replaced when the config is reloaded.
+#[derive(Clone, Copy)]
+struct LogFormat {
+    time: LogTimeFormat,
+    module_path: bool,
+    extra: bool,
+}
+
+impl LogFormat {
+    fn new(log_config: Option<&LogConfig>) -> Self {
+        log_config.map_or(
+            Self {
+                time: LogTimeFormat::None,
+                module_path: false,
+                extra: false,
+            },
+            |l| Self {
+                time: l.time,
+                module_path: l.module_path,
+                extra: l.extra,
+            },
+        )
+    }
+}
+
 /// A logger implementation with commands suggestions
 pub struct CommandLogger {
     input: Arc<AsyncRwLock<LogState>>,
@@ -51,6 +76,7 @@ pub struct CommandLogger {
     log_stopped: CancellationToken,
     start_time: Instant,
     log_config: Option<LogConfig>,
+    format: SyncRwLock<LogFormat>,
 }
 
 impl CommandLogger {
@@ -72,6 +98,7 @@ impl CommandLogger {
             stopped: CancellationToken::new(),
             log_stopped: CancellationToken::new(),
             start_time: Instant::now(),
+            format: SyncRwLock::new(LogFormat::new(log_config.as_ref())),
             log_config,
         });
         task::spawn(log.clone().log_loop(receiver));
@@ -82,6 +109,12 @@ impl CommandLogger {
         Ok(log)
     }
 
+    /// Applies the time, module path and extra data settings of a reloaded
+    /// config. The log file settings only change on restart.
+    pub fn set_format(&self, log_config: Option<&LogConfig>) {
+        *self.format.write() = LogFormat::new(log_config);
+    }
+
     /// Stops the logger and waits for cleanup to complete
     pub async fn stop(&self) {
         self.cancel_token.cancel();
@@ -190,21 +223,21 @@ impl CommandLogger {
     }
 
     fn format_time(&self) -> String {
-        match self.log_config.as_ref().map(|l| &l.time) {
-            Some(LogTimeFormat::Date) => {
+        match self.format.read().time {
+            LogTimeFormat::Date => {
                 let time: chrono::DateTime<Utc> = time::SystemTime::now().into();
                 format!("{} ", time.format("%T:%3f"))
             }
-            Some(LogTimeFormat::Uptime) => {
+            LogTimeFormat::Uptime => {
                 let elapsed = self.start_time.elapsed();
                 format!("{:>6.2}s ", elapsed.as_secs_f64())
             }
-            _ => String::new(),
+            LogTimeFormat::None => String::new(),
         }
     }
 
     fn format_module_path(&self, data: &LogData, color: bool) -> String {
-        if self.log_config.as_ref().is_some_and(|l| l.module_path) {
+        if self.format.read().module_path {
             if color {
                 format!(
                     " {}{}{} ",
@@ -221,7 +254,7 @@ impl CommandLogger {
     }
 
     fn format_extra(&self, data: &LogData, color: bool) -> String {
-        if self.log_config.as_ref().is_some_and(|l| l.extra) {
+        if self.format.read().extra {
             if color {
                 format!(
                     "{}{}{}",
@@ -4,19 +4,24 @@
 use std::backtrace::{Backtrace, BacktraceStatus};
 use std::num::NonZero;
 use std::panic::AssertUnwindSafe;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::{io, panic, thread};
+use std::time::Duration;
+use std::{io, panic, process, thread};
 
 use crossterm::style::Attribute::{Bold, Dim, Reset};
 use crossterm::style::{Color, ResetColor, SetForegroundColor};
 use futures::FutureExt;
-use steel::config::{self, LogConfig};
+use steel::config::{self, FileConfigReloader, LogConfig};
 use steel::logger::CommandLogger;
 use steel::{SERVER, SteelServer, logger::LoggerLayer};
+use steel_core::command::CommandRegistry;
+use steel_core::crash_report::{CRASH_REPORT_DIR, CrashReport};
 use steel_core::player::player_data::PersistentPlayerData;
 use steel_core::player::player_data_storage::GlobalPlayerData;
+use steel_core::plugin::PluginManager;
 use steel_core::server::Server;
+use steel_core::server::profiler::profiler_layer;
 use steel_utils::text::DisplayResolutor;
 use text_components::fmt::set_display_resolutor;
 use tokio::runtime::{Builder, Runtime};
@@ -24,9 +29,16 @@ use tokio_util::{sync::CancellationToken, task::TaskTracker};
 #[cfg(feature = "jaeger")]
 use tracing::Subscriber;
 use tracing::{Level, error};
-use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
 #[cfg(feature = "jaeger")]
-use tracing_subscriber::{Layer, registry::LookupSpan};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, util::SubscriberInitExt};
+
+/// Path of the main config file, re-read by `/steel reload config`.
+const CONFIG_PATH: &str = "config/config.toml";
+/// Directory native plugins are loaded from.
+const PLUGIN_DIR: &str = "plugins";
+/// Directory server-side translations are loaded from.
+const LANG_DIR: &str = "lang";
 
 #[cfg(feature = "jaeger")]
 fn init_jaeger<S>() -> impl Layer<S> + Send + Sync
@@ -78,7 +90,8 @@ async fn init_tracing(
         .as_ref()
         .map_or(Level::INFO.into(), |l| l.log_level.to_directive());
 
-    let tracing = tracing_subscriber::registry();
+    // Filters are per layer so the profiler can enable spans the log level hides.
+    let tracing = tracing_subscriber::registry().with(profiler_layer());
 
     #[cfg(feature = "jaeger")]
     let tracing = tracing.with(init_jaeger());
@@ -88,12 +101,12 @@ async fn init_tracing(
         .map_err(|err| format!("failed to initialize logger: {err}"))?;
     let logger = layer.0.clone();
 
-    let tracing = tracing.with(layer);
-
     let tracing = tracing.with(
-        EnvFilter::builder()
-            .with_default_directive(log_level)
-            .from_env_lossy(),
+        layer.with_filter(
+            EnvFilter::builder()
+                .with_default_directive(log_level)
+                .from_env_lossy(),
+        ),
     );
 
     set_display_resolutor(&DisplayResolutor);
@@ -148,7 +161,7 @@ fn steel_main() {
     let _profiler = dhat::Profiler::new_heap();
 
     // Load config once at startup
-    let steel_config = match config::load_or_create(Path::new("config/config.toml")) {
+    let steel_config = match config::load_or_create(Path::new(CONFIG_PATH)) {
         Ok(config) => config,
         Err(error) => {
             eprintln!("Failed to load configuration: {error}");
@@ -218,24 +231,23 @@ async fn main_async(chunk_runtime: Arc<Runtime>, steel_config: config::SteelConf
         let current_thread = thread::current();
         let thread_name = current_thread.name().unwrap_or("unnamed");
         let thread_id = current_thread.id();
-        if let Some(location) = panic_info.location() {
-            error!(
-                "{}Thread '{thread_name}' ({}) has panicked at {}:{}:{}{}",
-                SetForegroundColor(Color::Red),
-                thread_id.as_u64(),
-                location.file(),
-                location.line(),
-                location.column(),
-                ResetColor
-            );
-        } else {
-            error!(
-                "{}Thread '{thread_name}' ({}) has panicked at an unknown location{}",
-                SetForegroundColor(Color::Red),
-                thread_id.as_u64(),
-                ResetColor
-            );
-        }
+        let location = panic_info.location().map_or_else(
+            || "an unknown location".to_owned(),
+            |location| {
+                format!(
+                    "{}:{}:{}",
+                    location.file(),
+                    location.line(),
+                    location.column()
+                )
+            },
+        );
+        error!(
+            "{}Thread '{thread_name}' ({}) has panicked at {location}{}",
+            SetForegroundColor(Color::Red),
+            thread_id.as_u64(),
+            ResetColor
+        );
         error!(
             "{}{}[FATAL ERROR]{}{} {message}{}",
             SetForegroundColor(Color::Red),
@@ -246,43 +258,75 @@ async fn main_async(chunk_runtime: Arc<Runtime>, steel_config: config::SteelConf
         );
 
         let backtrace = Backtrace::capture();
-        match backtrace.status() {
+        let backtrace = match backtrace.status() {
             BacktraceStatus::Captured => {
                 error!("Stack Backtrace:");
                 let string = backtrace.to_string();
-                let traces = string.split('\n');
-                for trace in traces {
+                for trace in string.split('\n') {
                     error!("{}", trace.trim_start());
                 }
+                string
             }
             BacktraceStatus::Disabled => {
                 error!(
                     "{}Backtrace is disabled. Run with RUST_BACKTRACE=1 to enable it.{}",
                     Dim, Reset
                 );
+                "Backtrace is disabled. Run with RUST_BACKTRACE=1 to enable it.".to_owned()
             }
             BacktraceStatus::Unsupported => {
                 error!(
                     "{}Backtrace capability is not supported on this platform.{}",
                     Dim, Reset
                 );
+                "Backtrace capability is not supported on this platform.".to_owned()
             }
-            _ => {}
+            _ => String::new(),
+        };
+
+        let mut report = CrashReport::new(
+            "Unexpected panic",
+            format!("Thread '{thread_name}' panicked at {location}:\n{message}\n\n{backtrace}"),
+        );
+        if let Some(server) = SERVER.get() {
+            server.fill_crash_report(&mut report);
+        }
+        match report.save_to_file(Path::new(CRASH_REPORT_DIR)) {
+            Ok(path) => error!("This crash report has been saved to: {}", path.display()),
+            Err(err) => error!("Could not save crash report: {err}"),
         }
 
         panic_token.cancel();
     }));
 
-    let run_result = AssertUnwindSafe(run_server(chunk_runtime, cancel_token, steel_config))
-        .catch_unwind()
-        .await;
+    let run_result = AssertUnwindSafe(run_server(
+        chunk_runtime,
+        cancel_token,
+        steel_config,
+        Arc::clone(&logger),
+    ))
+    .catch_unwind()
+    .await;
+    let mut timed_out = false;
     let panic_payload = match run_result {
-        Ok(Ok(())) => None,
+        Ok(Ok(ServerExit::Stopped)) => None,
+        Ok(Ok(ServerExit::ShutdownTimedOut)) => {
+            timed_out = true;
+            None
+        }
         Ok(Err(error)) => {
             log::error!("Server startup failed: {error}");
             None
         }
-        Err(payload) => Some(payload),
+        Err(payload) => {
+            // The panic skipped the normal shutdown, so still try to save the worlds.
+            if let Some(server) = SERVER.get() {
+                let _ = AssertUnwindSafe(shutdown_worlds(server))
+                    .catch_unwind()
+                    .await;
+            }
+            Some(payload)
+        }
     };
 
     logger.stop().await;
@@ -290,6 +334,18 @@ async fn main_async(chunk_runtime: Arc<Runtime>, steel_config: config::SteelConf
     if let Some(payload) = panic_payload {
         panic::resume_unwind(payload);
     }
+    if timed_out {
+        // Tasks stuck in the shutdown would otherwise keep the runtimes alive.
+        process::exit(1);
+    }
+}
+
+/// How [`run_server`] ended.
+enum ServerExit {
+    /// The shutdown finished.
+    Stopped,
+    /// The shutdown ran past `shutdown.timeout_seconds` and was abandoned.
+    ShutdownTimedOut,
 }
 
 fn spawn_shutdown_signal_listener(cancel_token: CancellationToken) {
@@ -333,7 +389,8 @@ async fn run_server(
     chunk_runtime: Arc<Runtime>,
     cancel_token: CancellationToken,
     steel_config: config::SteelConfig,
-) -> Result<(), String> {
+    logger: Arc<CommandLogger>,
+) -> Result<ServerExit, String> {
     #[cfg(feature = "deadlock_detection")]
     {
         // only for #[cfg]
@@ -362,32 +419,65 @@ async fn run_server(
         });
     }
 
-    let mut steel = SteelServer::new(chunk_runtime.clone(), cancel_token.clone(), steel_config)
-        .await
-        .map_err(|e| e.to_string())?;
+    let mut plugin_manager = PluginManager::new();
+    if let Err(error) = plugin_manager.load_dir(Path::new(PLUGIN_DIR), &steel_config.server.plugins)
+    {
+        log::error!("Failed to read the plugin directory: {error}");
+    }
+    let mut commands = CommandRegistry::new();
+    plugin_manager.register_commands(&mut commands);
+
+    let mut steel = SteelServer::new_with_commands(
+        chunk_runtime.clone(),
+        cancel_token.clone(),
+        steel_config,
+        commands,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
 
     let server = steel.server.clone();
+    server.set_plugin_manager(plugin_manager);
+    match server.translations.load_dir(Path::new(LANG_DIR)) {
+        Ok(0) => {}
+        Ok(loaded) => log::info!("Loaded {loaded} translation files"),
+        Err(error) => log::error!("Failed to read the translation directory: {error}"),
+    }
+    server.set_config_reloader(Box::new(FileConfigReloader::new(
+        PathBuf::from(CONFIG_PATH),
+        logger,
+    )));
 
     if !server.prepare_spawn_area().await {
         shutdown_worlds(&server).await;
-        return Ok(());
+        return Ok(ServerExit::Stopped);
     }
 
     SERVER.set(steel.server.clone()).ok();
 
+    if let Some(plugin_manager) = server.plugin_manager() {
+        plugin_manager.enable_all(&server);
+    }
+
     let task_tracker = TaskTracker::new();
 
     steel.start(task_tracker.clone()).await;
 
-    log::info!("Waiting for pending tasks...");
-
-    task_tracker.close();
-    task_tracker.wait().await;
-
-    shutdown_worlds(&server).await;
+    let timeout = Duration::from_secs(steel.shutdown.timeout_seconds);
+    let shutdown = async {
+        if let Some(plugin_manager) = server.plugin_manager() {
+            plugin_manager.disable_all(&server);
+        }
+        steel.close_connections(&task_tracker).await;
+        shutdown_worlds(&server).await;
+    };
+    if tokio::time::timeout(timeout, shutdown).await.is_err() {
+        log::error!("Shutdown did not finish within {timeout:?}, exiting without completing it");
+        return Ok(ServerExit::ShutdownTimedOut);
+    }
 
     log::info!("Server stopped");
-    Ok(())
+    Ok(ServerExit::Stopped)
 }
 
 async fn shutdown_worlds(server: &Arc<Server>) {
@@ -423,6 +513,10 @@ async fn shutdown_worlds(server: &Arc<Server>) {
         Ok(saved) => log::info!("Saved {saved} domain command storages"),
         Err(error) => log::error!("Failed to save domain command storage: {error}"),
     }
+    match server.save_maps().await {
+        Ok(saved) => log::info!("Saved maps of {saved} domains"),
+        Err(error) => log::error!("Failed to save domain maps: {error}"),
+    }
     let mut total_saved = 0;
     for world in server.worlds.values() {
         world.cleanup(&mut total_saved).await;